@@ -4,7 +4,9 @@ use anyhow::{anyhow, Result};
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle, RawDisplayHandle};
 use smallvec::{smallvec, SmallVec};
 use vulkanite::{
-    flagbits, include_spirv, vk, window, DefaultAllocator, Dispatcher, DynamicDispatcher,
+    flagbits, include_spirv,
+    vk::{self, codes::AcquireNextImageKHRStatus},
+    window, DefaultAllocator, Dispatcher, DynamicDispatcher,
 };
 use winit::{
     application::ApplicationHandler,
@@ -161,7 +163,7 @@ impl VulkanApplication {
         let has_validation = layers
             .into_iter()
             .any(|layer| layer.get_layer_name() == VALIDATION_LAYER);
-        let enabled_layers = has_validation.then_some(VALIDATION_LAYER.as_ptr());
+        let enabled_layers = has_validation.then_some(vk::CStrPtr::new(VALIDATION_LAYER));
 
         // enable VK_EXT_debug_utils only if the validation layer is enabled
         let mut enabled_extensions =
@@ -544,7 +546,11 @@ impl VulkanApplication {
             None,
         )?;
         let image_idx = image_idx as usize;
-        assert!(status == vk::Status::Success || status == vk::Status::SuboptimalKHR);
+        // the status is only narrowed when the status-codes feature is enabled
+        assert!(matches!(
+            status,
+            AcquireNextImageKHRStatus::Success | AcquireNextImageKHRStatus::SuboptimalKHR
+        ));
 
         let render_finished = &self.render_finished[curr_index];
 
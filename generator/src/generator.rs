@@ -1,8 +1,9 @@
 use std::{
     borrow::{Borrow, Cow},
     cell::RefCell,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io::Write,
+    iter,
 };
 
 use anyhow::{anyhow, Result};
@@ -21,21 +22,37 @@ use crate::{
 };
 
 mod advanced_commands;
+mod chain;
+mod codes;
+mod deletion;
 mod dispatcher;
 mod enums;
 mod extensions;
 mod formats;
 mod handles;
+mod layout;
+mod limits;
+mod owned;
 mod raw_commands;
+mod recording;
+mod spirv;
 mod structs;
+mod sync;
+mod video;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum GeneratedCommandType {
     Basic,
+    /// Command buffer commands restricted by the queue capabilities, level and render pass scope of the command buffer
+    Typed,
+    /// Commands of handles which must be externally synchronized, taking `&mut self` when this is the case
+    Exclusive,
 }
 
 pub struct Generator<'a> {
     registry: &'a xml::Registry,
+    /// Registry of the video std headers (video.xml)
+    video_registry: &'a xml::Registry,
     ext_names: Vec<&'a str>,
     enums: HashMap<&'a str, Enum<'a>>,
     constants: HashMap<&'a str, Constant<'a>>,
@@ -43,10 +60,17 @@ pub struct Generator<'a> {
     structs: HashMap<&'a str, Struct<'a>>,
     commands: HashMap<&'a str, Command<'a>>,
     mapping: RefCell<HashMap<&'a str, MappingEntry<'a>>>,
+    /// Types, commands and enum values only required by platform-specific, vendor or provisional extensions,
+    /// with the conditions of the extensions requiring them
+    item_conditions: HashMap<&'a str, Vec<ExtensionCondition<'a>>>,
 }
 
 impl<'a> Generator<'a> {
-    pub fn new(_api: Api, registry: &'a xml::Registry) -> Result<Generator> {
+    pub fn new(
+        _api: Api,
+        registry: &'a xml::Registry,
+        video_registry: &'a xml::Registry,
+    ) -> Result<Generator<'a>> {
         if registry
             .enums
             .get(0)
@@ -67,10 +91,36 @@ impl<'a> Generator<'a> {
             .map(|tag| tag.name.as_str())
             .collect();
 
+        let video_constants = video_registry
+            .extensions
+            .iter()
+            .flat_map(|exts| &exts.extension)
+            .flat_map(|ext| &ext.require)
+            .flat_map(|req| &req.content)
+            .filter_map(|cnt| match cnt {
+                // the spec version and extension name are handled separately
+                xml::RequireContent::Enum(xml::RequireEnum {
+                    name,
+                    value: Some(value),
+                    ..
+                }) if !name.ends_with("_SPEC_VERSION") && !name.ends_with("_EXTENSION_NAME") => {
+                    Some((
+                        name.as_str(),
+                        Constant::Field {
+                            name: name.clone(),
+                            ty: CType::Uint32,
+                            value,
+                        },
+                    ))
+                }
+                _ => None,
+            });
+
         let constants = registry.enums[0]
             .enums
             .iter()
             .map(|cst| Ok((cst.name.as_str(), Constant::try_from(cst)?)))
+            .chain(video_constants.map(Ok))
             .collect::<Result<_>>()?;
 
         let enums = registry
@@ -78,6 +128,7 @@ impl<'a> Generator<'a> {
             .iter()
             // Skip the constants from above
             .skip(1)
+            .chain(&video_registry.enums)
             .map(|it| {
                 let mut my_enum = Enum::try_from(it, &ext_names)?;
                 if it.name == "VkResult" {
@@ -89,6 +140,7 @@ impl<'a> Generator<'a> {
             .collect::<Result<_>>()?;
 
         let all_types = registry.types.iter().flat_map(|tys| &tys.types);
+        let video_types = video_registry.types.iter().flat_map(|tys| &tys.types);
 
         let find_name = |ty: &'a xml::TypeContent| match ty {
             xml::TypeContent::Name(name) => Some(name.as_str()),
@@ -109,6 +161,7 @@ impl<'a> Generator<'a> {
 
         let structs = all_types
             .clone()
+            .chain(video_types.filter(|ty| ty.category.as_ref().is_some_and(|cat| cat == "struct")))
             .filter(|ty| {
                 ty.category
                     .as_ref()
@@ -155,7 +208,14 @@ impl<'a> Generator<'a> {
             ("void", "c_void"),
             // Types from external headers
             ("Window", "c_ulong"),
+            ("VisualID", "c_ulong"),
+            ("RROutput", "c_ulong"),
             ("xcb_window_t", "u32"),
+            ("xcb_visualid_t", "u32"),
+            ("DWORD", "u32"),
+            ("zx_handle_t", "u32"),
+            ("GgpStreamDescriptor", "u32"),
+            ("GgpFrameToken", "u64"),
             // Custom types for QoL improvements
             ("VoidPtr", "VoidPtr"),
             ("ApiVersion", "ApiVersion"),
@@ -178,8 +238,9 @@ impl<'a> Generator<'a> {
         })
         .collect();
 
-        let gen = Generator {
+        let mut gen = Generator {
             registry,
+            video_registry,
             ext_names,
             enums,
             constants,
@@ -187,7 +248,9 @@ impl<'a> Generator<'a> {
             structs,
             commands,
             mapping: RefCell::new(mapping),
+            item_conditions: HashMap::new(),
         };
+        gen.item_conditions = gen.compute_item_conditions();
 
         gen.extend_enums()?;
         gen.extend_handles()?;
@@ -217,6 +280,30 @@ impl<'a> Generator<'a> {
         formats::generate(self)
     }
 
+    pub fn generate_limits(&self) -> Result<String> {
+        limits::generate(self)
+    }
+
+    pub fn generate_owned_structs(&self) -> Result<String> {
+        owned::generate(self)
+    }
+
+    pub fn generate_chain_structs(&self) -> Result<String> {
+        chain::generate(self)
+    }
+
+    pub fn generate_destroyable(&self) -> Result<String> {
+        deletion::generate(self)
+    }
+
+    pub fn generate_spirv(&self) -> Result<String> {
+        spirv::generate(self)
+    }
+
+    pub fn generate_sync(&self) -> Result<String> {
+        sync::generate(self)
+    }
+
     pub fn generate_dispatcher(&self) -> Result<String> {
         dispatcher::generate(self)
     }
@@ -225,6 +312,26 @@ impl<'a> Generator<'a> {
         raw_commands::generate(self)
     }
 
+    pub fn generate_recording_infos(&self) -> Result<String> {
+        recording::generate(self)
+    }
+
+    pub fn generate_codes(&self) -> Result<String> {
+        codes::generate(self)
+    }
+
+    pub fn generate_video(&self) -> Result<String> {
+        video::generate(self)
+    }
+
+    pub fn generate_layout_tests(&self) -> Result<String> {
+        layout::generate_tests(self)
+    }
+
+    pub fn check_layout_with_c_compiler(&self) -> Result<()> {
+        layout::check_with_c_compiler(self)
+    }
+
     pub fn generate_advanced_commands<'b>(
         &'b self,
         gen_ty: GeneratedCommandType,
@@ -608,6 +715,8 @@ impl<'a> Generator<'a> {
         // add include types
         for ty in self.all_types() {
             match (&ty.requires, &ty.name_attr, &ty.category, &ty.alias) {
+                // the video std structures are defined in video.xml
+                (Some(_), Some(name), ..) if self.structs.contains_key(name.as_str()) => {}
                 (Some(_), Some(name), ..) => {
                     // only insert if it is not already custom defined
                     mapping.entry(name).or_insert_with(|| MappingEntry {
@@ -778,7 +887,8 @@ impl<'a> Generator<'a> {
             Type::Path(ty)
             | Type::ArrayEnum { ty, .. }
             | Type::ArrayCst { ty, .. }
-            | Type::ArrayDoubleCst { ty, .. } => self.compute_name_lifetime(ty),
+            | Type::ArrayDoubleCst { ty, .. }
+            | Type::ArrayDoubleEnum { ty, .. } => self.compute_name_lifetime(ty),
         }
     }
 
@@ -831,6 +941,62 @@ impl<'a> Generator<'a> {
         }
     }
 
+    /// Return if the struct only contains plain data (no pointers, handles or platform-specific types)
+    /// apart from its sType and pNext, in which case it can be serialized
+    fn is_plain_data_struct(&self, name: &str) -> bool {
+        if name.starts_with("StdVideo") {
+            return false;
+        }
+        match self.get_struct(name) {
+            Some(my_struct) if !my_struct.is_union => my_struct.fields.iter().all(|field| {
+                let is_chain_field = my_struct.s_type.is_some()
+                    && (field.vk_name == "sType" || field.vk_name == "pNext");
+                is_chain_field || self.is_plain_data_type(&self.compute_advanced_type(&field.ty))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Same as [Self::is_plain_data_struct] for the type of a struct field
+    fn is_plain_data_type(&self, ty: &AdvancedType<'a>) -> bool {
+        type AT<'a> = AdvancedType<'a>;
+        match ty {
+            AT::Bool32 | AT::Enum(_) | AT::CharArray(_) => true,
+            AT::Struct(name) => self.is_plain_data_struct(name),
+            AT::Other(name)
+            | AT::OtherArrayWithCst(name, _)
+            | AT::OtherArrayWithEnum(name, _)
+            | AT::OtherDoubleArray(name, _, _) => self.is_plain_data_name(name),
+            _ => false,
+        }
+    }
+
+    fn is_plain_data_name(&self, name: &'a str) -> bool {
+        const NUMERIC_TYPES: &[&str] = &[
+            "uint8_t", "int8_t", "uint16_t", "int16_t", "uint32_t", "int32_t", "uint64_t",
+            "int64_t", "size_t", "float", "double", "int",
+        ];
+        if NUMERIC_TYPES.contains(&name) || name == "ApiVersion" {
+            return true;
+        }
+        match self.compute_advanced_type(&Type::Path(name)) {
+            // base types such as VkDeviceSize, types from external headers are not plain data
+            AdvancedType::Other(_) => match self.structs.get(name) {
+                Some(Struct::BaseType(base_type)) => {
+                    self.is_plain_data_type(&self.compute_advanced_type(&base_type.ty))
+                }
+                // bitmasks without any bit are stored as integers
+                _ => {
+                    name.starts_with("Vk")
+                        && self
+                            .get_mapping_name(name)
+                            .is_ok_and(|ty| ty == "u32" || ty == "u64")
+                }
+            },
+            ty => self.is_plain_data_type(&ty),
+        }
+    }
+
     fn compute_advanced_type(&self, ty: &Type<'a>) -> AdvancedType<'a> {
         type AT<'a> = AdvancedType<'a>;
         match ty {
@@ -871,10 +1037,160 @@ impl<'a> Generator<'a> {
             }
             Type::ArrayCst { ty, size } => AT::OtherArrayWithCst(ty, *size),
             Type::ArrayDoubleCst { ty, size1, size2 } => AT::OtherDoubleArray(ty, *size1, *size2),
+            Type::ArrayDoubleEnum { ty, size1, size2 } => {
+                AT::OtherDoubleArrayWithEnum(ty, size1, size2)
+            }
             Type::Bitfield { ty, bitsize } => AT::Bitfield(ty, *bitsize),
         }
     }
 
+    fn compute_item_conditions(&self) -> HashMap<&'a str, Vec<ExtensionCondition<'a>>> {
+        let mut item_conditions: HashMap<&str, Vec<ExtensionCondition>> = HashMap::new();
+        let mut unconditional_items = HashSet::new();
+
+        // the other vendors of platform extensions (GGP, FUCHSIA...) are the platform itself and have no feature
+        let vendor_features: HashSet<_> = self
+            .filtered_extensions()
+            .map(ExtensionCondition::from_extension)
+            .filter(|condition| condition.platform.is_none())
+            .filter_map(|condition| condition.vendor)
+            .collect();
+
+        let requires = self
+            .filtered_features()
+            .flat_map(|feat| &feat.require)
+            .map(|req| (ExtensionCondition::default(), req))
+            .chain(self.filtered_extensions().flat_map(|ext| {
+                let mut condition = ExtensionCondition::from_extension(ext);
+                if condition
+                    .vendor
+                    .as_ref()
+                    .is_some_and(|vendor| !vendor_features.contains(vendor))
+                {
+                    condition.vendor = None;
+                }
+                ext.require.iter().map(move |req| (condition.clone(), req))
+            }));
+        for (condition, require) in requires {
+            for content in &require.content {
+                let name = match content {
+                    xml::RequireContent::Type(xml::RequireType { name, .. })
+                    | xml::RequireContent::Command(xml::RequireCommand { name, .. })
+                    | xml::RequireContent::Enum(xml::RequireEnum { name, .. }) => name.as_str(),
+                    _ => continue,
+                };
+                if condition.is_unconditional() {
+                    unconditional_items.insert(name);
+                    continue;
+                }
+                let conditions = item_conditions.entry(name).or_default();
+                if !conditions.contains(&condition) {
+                    conditions.push(condition.clone());
+                }
+            }
+        }
+
+        // an item also required by a feature or an extension without conditions is always generated
+        item_conditions.retain(|name, _| !unconditional_items.contains(name));
+
+        // and so are the types of the members of such structures
+        loop {
+            let member_types: Vec<_> = self
+                .structs
+                .iter()
+                .filter(|(name, _)| !item_conditions.contains_key(*name))
+                .filter_map(|(_, my_struct)| match my_struct {
+                    Struct::Standard(my_struct) => Some(&my_struct.fields),
+                    Struct::BaseType(_) => None,
+                })
+                .flatten()
+                .filter_map(|field| match field.ty {
+                    Type::Path(ty)
+                    | Type::Ptr(ty)
+                    | Type::DoublePtr(ty)
+                    | Type::ArrayEnum { ty, .. }
+                    | Type::ArrayCst { ty, .. }
+                    | Type::ArrayDoubleCst { ty, .. }
+                    | Type::ArrayDoubleEnum { ty, .. }
+                    | Type::Bitfield { ty, .. } => Some(ty),
+                    _ => None,
+                })
+                .filter(|ty| item_conditions.contains_key(ty))
+                .collect();
+            if member_types.is_empty() {
+                break;
+            }
+            for ty in member_types {
+                item_conditions.remove(ty);
+                // handles also need their object type
+                if let Some(handle) = self.handles.get(ty) {
+                    item_conditions.remove(handle.object_type);
+                }
+            }
+        }
+        item_conditions
+    }
+
+    /// cfg attribute enabling the given type or command only when one of the extensions requiring it is enabled
+    /// Returns None if the item is always generated
+    fn item_cfg(&self, name: &str) -> Option<TokenStream> {
+        let condition = self.item_cfg_condition(name)?;
+        Some(quote!(#[cfg(#condition)]))
+    }
+
+    /// Predicate of the cfg attribute returned by [Self::item_cfg]
+    fn item_cfg_condition(&self, name: &str) -> Option<TokenStream> {
+        let conditions = self.item_conditions.get(name)?;
+        Some(ExtensionCondition::to_cfg_condition(conditions))
+    }
+
+    /// Same as [Self::item_cfg] for enum values, which are only restricted by the extension families
+    /// and always present on all platforms
+    fn enum_value_cfg(&self, name: &str) -> Option<TokenStream> {
+        let condition = self.enum_value_cfg_condition(name)?;
+        Some(quote!(#[cfg(#condition)]))
+    }
+
+    /// Predicate of the cfg attribute returned by [Self::enum_value_cfg]
+    fn enum_value_cfg_condition(&self, name: &str) -> Option<TokenStream> {
+        let conditions = self.item_conditions.get(name)?;
+        if conditions.iter().any(|cond| cond.platform.is_some()) {
+            return None;
+        }
+        Some(ExtensionCondition::to_cfg_condition(conditions))
+    }
+
+    /// Put all the items generated for the given type or command behind its cfg
+    fn with_item_cfg(&self, name: &str, items: TokenStream) -> Result<TokenStream> {
+        let Some(cfg) = self.item_cfg(name) else {
+            return Ok(items);
+        };
+        let file: syn::File = syn::parse2(items)?;
+        let items = file.items.iter().map(|item| quote!(#cfg #item));
+        Ok(quote!(#(#items)*))
+    }
+
+    fn header_version(&self) -> Result<u32> {
+        self.all_types()
+            .find_map(|ty| {
+                // we want to find the following:
+                // <type api="vulkan" category="define">// Version of this file #define <name>VK_HEADER_VERSION</name> 281</type>
+                match (ty, ty.content.as_slice()) {
+                    (
+                        xml::Type {
+                            category: Some(cat),
+                            ..
+                        },
+                        [xml::TypeContent::Text(_), xml::TypeContent::Name(name), xml::TypeContent::Text(value)],
+                    ) if cat == "define" && name == "VK_HEADER_VERSION" => Some(value),
+                    _ => None,
+                }
+            })
+            .map(|v| v.parse::<u32>().ok())
+            .unwrap_or_default()
+            .ok_or_else(|| anyhow!("Failed to find VK_HEADER_VERSION"))
+    }
+
     // remove VulkanSC only features
     fn filtered_features(&self) -> impl Iterator<Item = &'a xml::Feature> {
         self.registry
@@ -890,8 +1206,6 @@ impl<'a> Generator<'a> {
             .iter()
             .flat_map(|exts| &exts.extension)
             .filter(|ext| ext.supported.contains(&xml::ExtensionSupported::Vulkan))
-            // for the time being, ignore video extensions
-            .filter(|ext| !ext.name.starts_with("VK_KHR_video"))
     }
 
     fn all_types(&self) -> impl Iterator<Item = &'a xml::Type> {
@@ -927,11 +1241,23 @@ impl<'a> Generator<'a> {
             }
         }
 
+        // opaque data whose size is given by the caller and then written by the command,
+        // it is queried by a first call like any other array
+        let is_output_data = |param: &CommandParam| {
+            !param.is_const
+                && param.xml.len.as_ref().is_some_and(|len| {
+                    cmd.params.iter().any(|other| {
+                        other.vk_name == len && !other.is_const && matches!(other.ty, Type::Ptr(_))
+                    })
+                })
+        };
+
         // find return types
         for param in &cmd.params {
             let ptr_content = match param.ty {
                 Type::Ptr(name) => name,
                 Type::DoublePtr("void") => "VoidPtr",
+                Type::VoidPtr if is_output_data(param) => "uint8_t",
                 _ => continue,
             };
 
@@ -1091,6 +1417,71 @@ impl<'a> Generator<'a> {
         })
     }
 
+    /// Return why no wrapper can be generated for the given command, if this is the case
+    fn unsupported_command_reason(&self, parsed_cmd: &CommandParamsParsed) -> Option<String> {
+        if parsed_cmd.output_fields.len() <= 1 {
+            return None;
+        }
+
+        for (vk_name, param) in &parsed_cmd.output_fields {
+            let Type::Ptr(ty) = param.ty else {
+                continue;
+            };
+            if self.get_handle(ty).is_some() {
+                return Some(format!(
+                    "the handle {vk_name} is returned along with other outputs"
+                ));
+            }
+            if param.xml.len.is_some() {
+                continue;
+            }
+            // the structure must be filled by the caller with pointers to memory it allocated
+            if let Some(member) = self.get_struct(ty).and_then(|my_struct| {
+                my_struct.fields.iter().find(|field| {
+                    field.vk_name != "pNext"
+                        && matches!(
+                            field.ty,
+                            Type::VoidPtr | Type::Ptr(_) | Type::DoublePtr(_) | Type::CStrArr
+                        )
+                })
+            }) {
+                return Some(format!(
+                    "the output {vk_name} contains the pointer {}, which must be allocated by the caller",
+                    member.vk_name
+                ));
+            }
+        }
+        None
+    }
+
+    /// List the commands for which no wrapper is generated, along with the reason why
+    pub fn unsupported_commands(&self) -> Result<Vec<(&'a str, String)>> {
+        let mut listed_commands = HashSet::new();
+        let mut result = Vec::new();
+        let requires = self
+            .filtered_features()
+            .flat_map(|feat| &feat.require)
+            .chain(self.filtered_extensions().flat_map(|ext| &ext.require));
+        for require in requires {
+            for content in &require.content {
+                let xml::RequireContent::Command(cmd) = content else {
+                    continue;
+                };
+                let Some(cmd) = self.commands.get(cmd.name.as_str()) else {
+                    continue;
+                };
+                if !listed_commands.insert(cmd.vk_name) {
+                    continue;
+                }
+                let parsed_cmd = self.parse_cmd_params(cmd)?;
+                if let Some(reason) = self.unsupported_command_reason(&parsed_cmd) {
+                    result.push((cmd.vk_name, reason));
+                }
+            }
+        }
+        Ok(result)
+    }
+
     /// Generate the raw type, as it is stored
     fn generate_type_inner(
         &self,
@@ -1165,6 +1556,13 @@ impl<'a> Generator<'a> {
                 let ty: Ident = self.get_ident_name(ty)?;
                 quote! ([[#ty #lifetime; #size2 as _]; #size1 as _])
             }
+            AT::OtherDoubleArrayWithEnum(ty, size1, size2) => {
+                let lifetime = get_lifetime(ty);
+                let ty: Ident = self.get_ident_name(ty)?;
+                let size1: Ident = self.get_ident_name(size1)?;
+                let size2: Ident = self.get_ident_name(size2)?;
+                quote! ([[#ty #lifetime; #size2 as _]; #size1 as _])
+            }
             AT::CharArray(size) => {
                 let size: Ident = self.get_ident_name(size)?;
                 quote! ([c_char; #size as _])
@@ -1413,9 +1811,9 @@ impl<'a> Generator<'a> {
             }
             AdvancedType::CStringPtr => Ok(SliceType {
                 template_param: None,
-                input_ty: wrap_ty(quote!(impl AsSlice<'a, *const c_char>)),
+                input_ty: wrap_ty(quote!(impl AsSlice<'a, CStrPtr<'a>>)),
                 affectation: simple_affectation,
-                output_ty: quote!(&'a [*const c_char]),
+                output_ty: quote!(&'a [CStrPtr<'a>]),
                 access,
             }),
             AdvancedType::OtherArrayWithCst(name, _)
@@ -1513,6 +1911,10 @@ impl<'a> Generator<'a> {
                 Ok(quote!(array::from_fn(|_| #value_default)))
             }
             AdvancedType::CharArray(_) => Ok(quote!(array::from_fn(|_| Default::default()))),
+            // Default is only implemented for arrays of size up to 32
+            AdvancedType::OtherDoubleArrayWithEnum(_, _, _) => Ok(quote!(array::from_fn(|_| {
+                array::from_fn(|_| Default::default())
+            }))),
             AdvancedType::Bitfield(_, _bitsize) => Ok(quote!(Default::default())),
         }
     }
@@ -1585,6 +1987,122 @@ fn parse_value(value: &str, ty: CType) -> TokenStream {
     rust_value.parse().unwrap()
 }
 
+/// Cargo features an extension depends on
+#[derive(Clone, Default, PartialEq, Eq)]
+struct ExtensionCondition<'a> {
+    /// `platform-*` feature, enabled by default on the matching target OSes
+    platform: Option<&'a str>,
+    /// `vendor-*` feature of vendor extensions
+    /// Platform extensions only have one if their vendor also has extensions not tied to a platform
+    vendor: Option<String>,
+    /// `provisional` feature of beta extensions
+    provisional: bool,
+}
+
+impl<'a> ExtensionCondition<'a> {
+    fn from_extension(ext: &'a xml::Extension) -> Self {
+        let platform = ext
+            .platform
+            .as_deref()
+            .filter(|platform| *platform != "provisional");
+        let provisional =
+            ext.provisional.is_some() || ext.platform.as_deref() == Some("provisional");
+        // the vendor tag is the second part of the extension name (VK_NV_...)
+        let vendor = ext
+            .name
+            .split('_')
+            .nth(1)
+            .filter(|tag| !matches!(*tag, "KHR" | "EXT" | "KHX"))
+            .map(|tag| format!("vendor-{}", tag.to_ascii_lowercase()));
+        Self {
+            platform,
+            vendor,
+            provisional,
+        }
+    }
+
+    fn is_unconditional(&self) -> bool {
+        self.platform.is_none() && self.vendor.is_none() && !self.provisional
+    }
+
+    /// Conditions on the platform, enabled either by a feature or by the target OS
+    fn platform_conditions(platform: &str) -> impl Iterator<Item = TokenStream> {
+        let feature = format!("platform-{}", platform.replace('_', "-"));
+        iter::once(quote!(feature = #feature)).chain(
+            platform_target_os(platform)
+                .iter()
+                .map(|os| quote!(target_os = #os)),
+        )
+    }
+
+    /// Condition satisfied when any of the given ones is
+    fn any_condition(conditions: Vec<TokenStream>) -> TokenStream {
+        match conditions.as_slice() {
+            [condition] => condition.clone(),
+            _ => quote!(any(#(#conditions),*)),
+        }
+    }
+
+    /// cfg predicate satisfied when any of the given conditions is
+    fn to_cfg_condition(conditions: &[Self]) -> TokenStream {
+        // keep a flat list when only platforms are involved
+        if let Some(platforms) = conditions
+            .iter()
+            .map(|cond| {
+                cond.platform
+                    .filter(|_| cond.vendor.is_none() && !cond.provisional)
+            })
+            .collect::<Option<Vec<_>>>()
+        {
+            let platform_conditions = platforms
+                .into_iter()
+                .flat_map(Self::platform_conditions)
+                .collect();
+            return Self::any_condition(platform_conditions);
+        }
+
+        let exprs = conditions
+            .iter()
+            .map(|cond| {
+                let mut parts: Vec<TokenStream> = Vec::new();
+                if let Some(platform) = cond.platform {
+                    parts.push(Self::any_condition(
+                        Self::platform_conditions(platform).collect(),
+                    ));
+                }
+                if let Some(vendor) = &cond.vendor {
+                    parts.push(quote!(feature = #vendor));
+                }
+                if cond.provisional {
+                    parts.push(quote!(feature = "provisional"));
+                }
+                match parts.as_slice() {
+                    [part] => part.clone(),
+                    _ => quote!(all(#(#parts),*)),
+                }
+            })
+            .collect();
+        Self::any_condition(exprs)
+    }
+}
+
+/// Target OSes on which the content of a platform is generated even without its `platform-*` feature
+fn platform_target_os(platform: &str) -> &'static [&'static str] {
+    match platform {
+        "xlib" | "xlib_xrandr" | "xcb" | "wayland" => {
+            &["linux", "freebsd", "dragonfly", "netbsd", "openbsd"]
+        }
+        "android" => &["android"],
+        "win32" => &["windows"],
+        "ios" => &["ios"],
+        "macos" => &["macos"],
+        "metal" => &["macos", "ios"],
+        "fuchsia" => &["fuchsia"],
+        "screen" => &["nto"],
+        _ => &[],
+    }
+}
+
 fn get_doc_url(item_name: &str) -> String {
     format!(
         "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/{item_name}.html>"
@@ -1592,6 +2110,10 @@ fn get_doc_url(item_name: &str) -> String {
 }
 
 fn make_doc_link(item_name: &str) -> TokenStream {
+    // the video std types have no man page
+    if item_name.starts_with("StdVideo") || item_name.starts_with("STD_VIDEO") {
+        return quote!();
+    }
     let doc_name = get_doc_url(item_name);
     let alias_name = item_name
         .to_ascii_lowercase()
@@ -9,11 +9,13 @@ use quote::{format_ident, quote};
 
 use crate::{
     helpers::camel_case_to_snake_case,
-    structs::{CommandParamsParsed, ReturnType, Type},
+    structs::{CommandParamsParsed, Handle, ReturnType, Type},
     xml,
 };
 
-use super::{make_doc_link, GeneratedCommandType, Generator};
+use super::{
+    make_doc_link, raw_commands, recording::command_info_name, GeneratedCommandType, Generator,
+};
 
 pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) -> Result<String> {
     let mut listed_cmds = HashSet::new();
@@ -63,6 +65,17 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
 
     let is_complex_handle = |name: &str| handle_cmds.contains_key(name);
 
+    if gen_ty == GeneratedCommandType::Typed {
+        let cmds = handle_cmds
+            .get("VkCommandBuffer")
+            .ok_or_else(|| anyhow!("Could not find the command buffer commands"))?;
+        return generate_typed_commands(gen, cmds, is_complex_handle);
+    }
+
+    if gen_ty == GeneratedCommandType::Exclusive {
+        return generate_exclusive_commands(gen, &handles_order, &handle_cmds, is_complex_handle);
+    }
+
     let create_methods = |cmds: &BTreeMap<usize, CommandParamsParsed>| -> Result<Vec<TokenStream>> {
         cmds.values()
             .map(|cmd_parsed| {
@@ -75,14 +88,25 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
                             .map(|(vk_name, name)| (*vk_name, name.as_str())),
                     )
                     .map(|(vk_name, name)| {
-                        generate_advanced_command(
+                        let mut method = generate_advanced_command(
                             gen,
                             name,
                             vk_name,
                             cmd_parsed,
                             gen_ty,
                             is_complex_handle,
-                        )
+                            false,
+                        )?;
+                        method.extend(generate_advanced_command(
+                            gen,
+                            name,
+                            vk_name,
+                            cmd_parsed,
+                            gen_ty,
+                            is_complex_handle,
+                            true,
+                        )?);
+                        Ok(method)
                     })
                     .collect::<Result<Vec<_>>>()?;
                 Ok(quote! (#(#all_variants)*))
@@ -146,7 +170,7 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
                 }
             });
 
-            result.push(quote! {
+            let items = quote! {
                 #[repr(C)]
                 #[derive(Clone)]
                 #doc_tag
@@ -191,12 +215,13 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
 
                     #(#methods)*
                 }
-            });
+            };
+            result.push(gen.with_item_cfg(handle_name, items)?);
         } else {
             let id_name = format_ident!("{}", handle.name);
             let doc_tag = make_doc_link(handle_name);
 
-            result.push(quote! {
+            let items = quote! {
                 #[repr(C)]
                 #[derive(Clone, Copy)]
                 #doc_tag
@@ -221,11 +246,15 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
                         }
                     }
                 }
-            });
+            };
+            result.push(gen.with_item_cfg(handle_name, items)?);
             for alias_name in handle.aliases.borrow().iter() {
-                let doc_tag = make_doc_link(&format!("Vk{alias_name}"));
+                let alias_vk_name = format!("Vk{alias_name}");
+                let doc_tag = make_doc_link(&alias_vk_name);
+                let alias_cfg = gen.item_cfg(&alias_vk_name);
                 let alias_name = format_ident!("{alias_name}");
-                result.push(quote! (#doc_tag pub type #alias_name = raw::#id_name;))
+                let items = quote! (#alias_cfg #doc_tag pub type #alias_name = raw::#id_name;);
+                result.push(gen.with_item_cfg(handle_name, items)?);
             }
         }
     }
@@ -236,7 +265,10 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
             ffi::{c_int, CStr},
             ops::Deref,
         };
-        use crate::{vk::*, Alias, Allocator, AdvancedDynamicArray, AsSlice, DefaultAllocator, Dispatcher, DynamicArray, DynamicDispatcher, Handle, StructureChainOut};
+        // c_ulong is only used by platform commands
+        #[allow(unused_imports)]
+        use std::ffi::c_ulong;
+        use crate::{vk::*, Alias, Allocator, AdvancedDynamicArray, AsSlice, DefaultAllocator, Dispatcher, DynamicArray, DynamicDispatcher, Handle, StructureChain, StructureChainOut};
 
         #(#result)*
     }
@@ -245,13 +277,212 @@ pub fn generate<'a, 'b>(gen: &'b Generator<'a>, gen_ty: GeneratedCommandType) ->
     Generator::format_result(result)
 }
 
+/// Commands changing the render pass state of a typed command buffer, they are implemented by hand
+const TYPED_TRANSITION_COMMANDS: [&str; 6] = [
+    "vkCmdBeginRenderPass",
+    "vkCmdBeginRenderPass2",
+    "vkCmdEndRenderPass",
+    "vkCmdEndRenderPass2",
+    "vkCmdBeginRendering",
+    "vkCmdEndRendering",
+];
+
+/// Commands only valid inside a render pass instance begun with a render pass object
+const SUBPASS_COMMANDS: [&str; 2] = ["vkCmdNextSubpass", "vkCmdNextSubpass2"];
+
+fn generate_typed_commands<'a, 'b, F>(
+    gen: &'b Generator<'a>,
+    cmds: &BTreeMap<usize, CommandParamsParsed>,
+    is_complex_handle: F,
+) -> Result<String>
+where
+    F: Fn(&str) -> bool + Copy,
+{
+    // commands sharing the same requirements are put in the same impl block
+    let mut impl_order = Vec::new();
+    let mut impl_methods: HashMap<(&str, &str, &str), Vec<TokenStream>> = HashMap::new();
+
+    for cmd_parsed in cmds.values() {
+        let cmd = cmd_parsed.command;
+        if !cmd.vk_name.starts_with("vkCmd") || TYPED_TRANSITION_COMMANDS.contains(&cmd.vk_name) {
+            continue;
+        }
+
+        let queues = &cmd.xml.queues;
+        let has_queue = |queue: fn(&xml::Queue) -> bool| queues.iter().any(queue);
+        let queue_bound = match (
+            has_queue(|queue| matches!(queue, xml::Queue::Graphics)),
+            has_queue(|queue| matches!(queue, xml::Queue::Compute)),
+            has_queue(|queue| matches!(queue, xml::Queue::Transfer)),
+        ) {
+            // video and optical flow commands are not supported by the typed command buffer
+            (false, false, false) => continue,
+            (true, false, false) => "SupportsGraphics",
+            (false, true, false) => "SupportsCompute",
+            (false, false, true) => "SupportsTransfer",
+            (true, true, false) => "SupportsGraphicsOrCompute",
+            (true, false, true) => "SupportsGraphicsOrTransfer",
+            (false, true, true) => "SupportsComputeOrTransfer",
+            (true, true, true) => "QueueType",
+        };
+        let level = match cmd.xml.cmd_buffer_level.as_slice() {
+            [xml::CmdBufferLevel::Primary] => "Primary",
+            [xml::CmdBufferLevel::Secondary] => "Secondary",
+            _ => "L",
+        };
+        let render_pass = match cmd.xml.renderpass.first() {
+            // there are no subpasses in a dynamic render pass instance
+            _ if SUBPASS_COMMANDS.contains(&cmd.vk_name) => "subpass",
+            Some(xml::Renderpass::Inside) => "inside",
+            Some(xml::Renderpass::Outside) => "outside",
+            _ => "both",
+        };
+
+        let methods = iter::once((cmd.vk_name, cmd.name.as_str()))
+            .chain(
+                cmd.aliases
+                    .borrow()
+                    .iter()
+                    .map(|(vk_name, name)| (*vk_name, name.as_str())),
+            )
+            .map(|(vk_name, name)| {
+                generate_advanced_command(
+                    gen,
+                    name,
+                    vk_name,
+                    cmd_parsed,
+                    GeneratedCommandType::Typed,
+                    is_complex_handle,
+                    false,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let key = (queue_bound, level, render_pass);
+        if !impl_methods.contains_key(&key) {
+            impl_order.push(key);
+        }
+        impl_methods.entry(key).or_default().extend(methods);
+    }
+
+    let impls = impl_order.into_iter().map(|key| {
+        let (queue_bound, level, render_pass) = key;
+        let methods = &impl_methods[&key];
+        let queue_bound = format_ident!("{queue_bound}");
+        let level_ident = format_ident!("{level}");
+        let level_param = (level == "L").then(|| quote!(L: Level,));
+        let (render_pass_ident, render_pass_param) = match render_pass {
+            "subpass" => (quote!(InsideRenderPass), None),
+            "inside" => (quote!(P), Some(quote!(P: InsideRenderPassState,))),
+            "outside" => (quote!(OutsideRenderPass), None),
+            _ => (quote!(P), Some(quote!(P: RenderPassState,))),
+        };
+        quote! {
+            impl<Q: #queue_bound, #level_param #render_pass_param D: Dispatcher, A: Allocator> CommandBuffer<Q, #level_ident, #render_pass_ident, D, A> {
+                #(#methods)*
+            }
+        }
+    });
+
+    let result = quote! {
+        // the methods mirror the Vulkan commands, which can take many parameters
+        #![allow(clippy::too_many_arguments)]
+        use crate::{vk::*, vk::typed::*, Alias, Allocator, AsSlice, Dispatcher};
+
+        #(#impls)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Generate the methods of the handles whose commands require the handle to be externally synchronized
+fn generate_exclusive_commands<'a, 'b, F>(
+    gen: &'b Generator<'a>,
+    handles_order: &[(&str, &Handle)],
+    handle_cmds: &HashMap<&str, BTreeMap<usize, CommandParamsParsed>>,
+    is_complex_handle: F,
+) -> Result<String>
+where
+    F: Fn(&str) -> bool + Copy,
+{
+    let mut impls = Vec::new();
+    for (handle_name, handle) in handles_order {
+        // the instance and device only need to be externally synchronized when destroyed
+        if !handle.is_dispatchable || matches!(*handle_name, "VkInstance" | "VkDevice") {
+            continue;
+        }
+        let Some(cmds) = handle_cmds.get(handle_name) else {
+            continue;
+        };
+        let is_externsync =
+            |cmd: &CommandParamsParsed| cmd.command.params[0].xml.externsync.is_some();
+        if !cmds.values().any(is_externsync) {
+            continue;
+        }
+
+        let methods = cmds
+            .values()
+            .map(|cmd_parsed| {
+                let cmd = cmd_parsed.command;
+                iter::once((cmd.vk_name, cmd.name.as_str()))
+                    .chain(
+                        cmd.aliases
+                            .borrow()
+                            .iter()
+                            .map(|(vk_name, name)| (*vk_name, name.as_str())),
+                    )
+                    .map(|(vk_name, name)| {
+                        generate_advanced_command(
+                            gen,
+                            name,
+                            vk_name,
+                            cmd_parsed,
+                            GeneratedCommandType::Exclusive,
+                            is_complex_handle,
+                            false,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten();
+
+        let id_name = format_ident!("{}", handle.name);
+        impls.push(quote! {
+            impl<D: Dispatcher, A: Allocator> #id_name<D, A> {
+                #(#methods)*
+            }
+        });
+    }
+
+    let result = quote! {
+        // the methods mirror the Vulkan commands, which can take many parameters
+        #![allow(clippy::too_many_arguments)]
+        use super::*;
+        use crate::{vk::*, Alias, Allocator, AsSlice, Dispatcher};
+        // only used by vendor commands
+        #[allow(unused_imports)]
+        use crate::DynamicArray;
+
+        #(#impls)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// When into_chain is true, generate the variant of the command filling a structure chain given by the caller
+/// instead of returning it, or nothing if the command does not output a structure chain
 fn generate_advanced_command<'a, 'b, F>(
     gen: &'b Generator<'a>,
     name: &str,
     vk_name: &str,
     cmd_parsed: &CommandParamsParsed,
-    _gen_ty: GeneratedCommandType,
+    gen_ty: GeneratedCommandType,
     is_complex_handle: F,
+    into_chain: bool,
 ) -> Result<TokenStream>
 where
     F: Fn(&str) -> bool,
@@ -259,10 +490,23 @@ where
     let cmd = cmd_parsed.command;
     let arg_template = &cmd_parsed.parsed_arg_templates;
 
-    if cmd_parsed.output_fields.len() > 1 {
+    if gen.unsupported_command_reason(cmd_parsed).is_some() {
         return Ok(quote!());
     }
 
+    let chain_param = if into_chain {
+        let outputs = raw_commands::parse_output_fields(gen, cmd_parsed, name)?;
+        match raw_commands::chain_output(&outputs) {
+            Some(output) => {
+                let (field_name, template) = output.chain_param();
+                Some((field_name.clone(), template))
+            }
+            None => return Ok(quote!()),
+        }
+    } else {
+        None
+    };
+
     // the first element is usually the handle, skip it
     let nb_to_skip = if cmd_parsed.handle.is_empty() { 0 } else { 1 };
 
@@ -310,8 +554,21 @@ where
         }
     }
 
-    let fn_name = format_ident!("{new_name}");
-    let raw_fn_name = format_ident!("{name}");
+    let (fn_name, raw_fn_name) = if chain_param.is_some() {
+        (
+            format_ident!("{new_name}_into"),
+            format_ident!("{name}_into"),
+        )
+    } else {
+        (format_ident!("{new_name}"), format_ident!("{name}"))
+    };
+
+    // the result and success status of the command, narrowed by the codes module
+    // when the status-codes feature is enabled
+    let codes_name = &vk_name["vk".len()..];
+    let result_ty = format_ident!("{codes_name}Result");
+    let status_ty = format_ident!("{codes_name}Status");
+    let (result_ty, status_ty) = (quote!(codes::#result_ty), quote!(codes::#status_ty));
 
     let (ret_type, ret_template, pre_call, post_call) = match cmd.return_ty {
         ReturnType::BaseType(name) => {
@@ -322,16 +579,43 @@ where
                 (quote! (-> #ty_name), None, None, None)
             }
         }
-        ReturnType::Result { nb_successes, .. } if cmd_parsed.output_fields.is_empty() => (
-            if nb_successes > 1 {
-                quote! (-> Result<Status>)
-            } else {
-                quote! (-> Result<()>)
-            },
-            None,
-            None,
-            None,
-        ),
+        ReturnType::Result { nb_successes, .. }
+            if cmd_parsed.output_fields.is_empty() || chain_param.is_some() =>
+        {
+            (
+                if nb_successes > 1 {
+                    quote! (-> #result_ty<#status_ty>)
+                } else {
+                    quote! (-> #result_ty<()>)
+                },
+                None,
+                None,
+                None,
+            )
+        }
+        _ if chain_param.is_some() => (quote!(), None, None, None),
+        _ if cmd_parsed.output_fields.len() > 1 => {
+            // none of the outputs is a handle, so the raw command result can be forwarded as is
+            let has_status = matches!(cmd.return_ty, ReturnType::Result { .. });
+            let has_many_successes = matches!(cmd.return_ty, ReturnType::Result { nb_successes, .. } if nb_successes > 1);
+            let outputs = raw_commands::parse_output_fields(gen, cmd_parsed, name)?;
+            let result_types = outputs.iter().map(|output| output.result_type());
+            let ret_templates = outputs.iter().map(|output| output.template_param());
+
+            let mut result_quote = quote! ((#(#result_types),*));
+            if has_status {
+                if has_many_successes {
+                    result_quote = quote! ((#status_ty, #result_quote))
+                }
+                result_quote = quote! (#result_ty<#result_quote>)
+            }
+            (
+                quote! (-> #result_quote),
+                Some(quote! (#(#ret_templates)*)),
+                None,
+                None,
+            )
+        }
         _ if !cmd_parsed.output_fields.is_empty() => {
             let has_status = matches!(cmd.return_ty, ReturnType::Result { .. });
             let has_many_successes = matches!(cmd.return_ty, ReturnType::Result { nb_successes, .. } if nb_successes > 1);
@@ -339,6 +623,8 @@ where
             let ret_type = match field.ty {
                 Type::Ptr(name) => name,
                 Type::DoublePtr("void") => "VoidPtr",
+                // opaque data is returned as bytes
+                Type::VoidPtr => "uint8_t",
                 _ => return Err(anyhow!("Could not use return field for {name}")),
             };
             let mut ret_name = gen.get_ident_name(ret_type)?;
@@ -425,9 +711,9 @@ where
             }
             if has_status {
                 if has_many_successes {
-                    result_quote = quote! ((Status, #result_quote))
+                    result_quote = quote! ((#status_ty, #result_quote))
                 }
-                result_quote = quote! (Result<#result_quote>)
+                result_quote = quote! (#result_ty<#result_quote>)
             }
 
             let ret_template = if is_vec && needs_transformation {
@@ -454,18 +740,151 @@ where
     let allocator_param =
         has_allocator.then(|| quote!(self.alloc.get_allocation_callbacks().as_ref(),));
 
-    let doc_tag = make_doc_link(vk_name);
+    let mut doc_tag = make_doc_link(vk_name);
+    let item_cfg = gen.item_cfg(vk_name);
     let unsafe_tag = name.starts_with("destroy").then(|| quote!(unsafe));
     let lifetime = (!cmd_parsed.vec_fields.is_empty()).then(|| quote! ('a, ));
 
+    if matches!(
+        gen_ty,
+        GeneratedCommandType::Typed | GeneratedCommandType::Exclusive
+    ) {
+        let receiver = if gen_ty == GeneratedCommandType::Exclusive
+            && cmd.params[0].xml.externsync.is_some()
+        {
+            quote!(&mut self)
+        } else {
+            quote!(&self)
+        };
+        // forward the call to the wrapped handle
+        return Ok(quote! {
+            #item_cfg
+            #doc_tag
+            pub #unsafe_tag fn #fn_name<#lifetime #ret_template #(#arg_template),*>(#receiver, #(#arg_outer_name: #arg_outer_type),*) #ret_type {
+                self.inner.#fn_name(#(#arg_outer_name),*)
+            }
+        });
+    }
+
+    let (chain_template, chain_arg, chain_name) = match &chain_param {
+        Some((field_name, template)) => {
+            let description = format!(
+                "Same as [Self::{}], filling the structure chain given by the caller",
+                &new_name
+            );
+            doc_tag = quote!(#[doc = #description] #doc_tag);
+            (
+                Some(template),
+                Some(quote!(#field_name: &mut S,)),
+                Some(quote!(#field_name,)),
+            )
+        }
+        None => (None, None, None),
+    };
+
+    let (pre_hook, post_hook) = recording_hooks(cmd.vk_name);
+    let recording_cfg = quote!(#[cfg(all(feature = "recording-checks", debug_assertions))]);
+    let pre_hook = pre_hook.map(|hook| quote!(#recording_cfg #hook;));
+    // convert the vk::Result returned by the raw command into the result type of the codes module
+    let narrow = match cmd.return_ty {
+        ReturnType::Result { nb_successes, .. } => {
+            let status_only = cmd_parsed.output_fields.is_empty() || chain_param.is_some();
+            // a success code the command is not expected to return ends up in the Other variant of the error
+            let map_status = (nb_successes > 1).then(|| {
+                if status_only {
+                    quote!(.and_then(Status::narrow_success))
+                } else {
+                    quote!(.and_then(|(status, value)| Ok((status.narrow_success()?, value))))
+                }
+            });
+            Some(quote!(#map_status.narrow_err()))
+        }
+        _ => None,
+    };
+    // the recording hooks take the raw result, so the conversion is done last
+    let (call_narrow, post_call_narrow, result_narrow) = match (&post_hook, &post_call) {
+        (Some(_), _) => (None, None, narrow),
+        (None, Some(_)) => (None, narrow, None),
+        (None, None) => (narrow, None, None),
+    };
+
+    let mut body = quote! {
+        #pre_call
+        unsafe {
+            raw::#raw_fn_name(#caller #(#arg_outer_name,)* #allocator_param #chain_name self.disp.get_command_dispatcher()) #call_narrow
+        }
+        #post_call #post_call_narrow
+    };
+    let mut allow_attr = None;
+    if let Some(hook) = post_hook {
+        allow_attr = result_narrow.is_none().then(|| {
+            // the second binding is removed when the recording checks are disabled
+            quote!(#[allow(clippy::let_and_return)])
+        });
+        body = quote! {
+            let result = { #body };
+            #recording_cfg
+            let result = #hook;
+            result #result_narrow
+        };
+    }
+
     Ok(quote! {
+        #item_cfg
         #doc_tag
-        pub #unsafe_tag fn #fn_name<#lifetime #ret_template #(#arg_template),*>(&self, #(#arg_outer_name: #arg_outer_type),*) #ret_type {
-            #pre_call
-            unsafe {
-                raw::#raw_fn_name(#caller #(#arg_outer_name,)* #allocator_param self.disp.get_command_dispatcher())
-            }
-            #post_call
+        #allow_attr
+        pub #unsafe_tag fn #fn_name<#lifetime #ret_template #chain_template #(#arg_template),*>(&self, #(#arg_outer_name: #arg_outer_type,)* #chain_arg) #ret_type {
+            #pre_hook
+            #body
         }
     })
 }
+
+/// Calls to the recording state validator (vk::recording) done before and after calling the given command
+fn recording_hooks(vk_name: &str) -> (Option<TokenStream>, Option<TokenStream>) {
+    let recording = quote!(crate::vk::recording);
+    match vk_name {
+        "vkBeginCommandBuffer" => (Some(quote!(#recording::begin(self, p_begin_info))), None),
+        "vkEndCommandBuffer" => (Some(quote!(#recording::end(self))), None),
+        "vkResetCommandBuffer" => (Some(quote!(#recording::reset(self))), None),
+        "vkResetCommandPool" => (
+            Some(quote!(#recording::reset_command_pool(command_pool))),
+            None,
+        ),
+        "vkDestroyCommandPool" => (
+            Some(quote!(#recording::destroy_command_pool(command_pool))),
+            None,
+        ),
+        "vkFreeCommandBuffers" => (
+            Some(quote!(#recording::free_command_buffers(p_command_buffers.as_slice()))),
+            None,
+        ),
+        "vkDestroyDevice" => (Some(quote!(#recording::destroy_device(self))), None),
+        "vkCreateDevice" => (None, Some(quote!(#recording::create_device(self, result)))),
+        "vkCreateCommandPool" => (
+            None,
+            Some(quote!(#recording::create_command_pool(self, p_create_info, result))),
+        ),
+        "vkAllocateCommandBuffers" => (
+            None,
+            Some(quote!(#recording::allocate_command_buffers(p_allocate_info, result))),
+        ),
+        "vkCmdBindPipeline" => {
+            let info = command_info_name(vk_name);
+            (
+                Some(
+                    quote!(#recording::bind_pipeline(self, &#recording::commands::#info, pipeline_bind_point)),
+                ),
+                None,
+            )
+        }
+        _ if vk_name.starts_with("vkCmd") => {
+            let info = command_info_name(vk_name);
+            (
+                Some(quote!(#recording::check(self, &#recording::commands::#info))),
+                None,
+            )
+        }
+        _ => (None, None),
+    }
+}
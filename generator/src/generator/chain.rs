@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{structs::Struct, xml};
+
+use super::Generator;
+
+pub fn generate(gen: &Generator) -> Result<String> {
+    let mut listed_structs = HashSet::new();
+    let mapping = gen.mapping.borrow();
+    let structure_type = |vk_name: &str, s_type: &str| -> Result<TokenStream> {
+        Ok(mapping
+            .get(s_type)
+            .ok_or_else(|| anyhow!("Failed to find structure type for {vk_name}"))?
+            .name
+            .parse()
+            .unwrap())
+    };
+
+    // same order as in structs.rs
+    let infos = gen
+        .filtered_features()
+        .flat_map(|feature| &feature.require)
+        .chain(gen.filtered_extensions().flat_map(|ext| &ext.require))
+        .flat_map(|require| &require.content)
+        .filter_map(|item| match item {
+            xml::RequireContent::Type(xml::RequireType { name, .. }) => {
+                match gen.structs.get(name.as_str()) {
+                    Some(Struct::Standard(my_struct))
+                        if my_struct.s_type.is_some() && listed_structs.insert(name.as_str()) =>
+                    {
+                        Some((name.as_str(), my_struct))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .map(|(vk_name, my_struct)| {
+            let name = format_ident!("{}", my_struct.name);
+            let s_type = structure_type(vk_name, my_struct.s_type.unwrap())?;
+            let own_cfg = gen.item_cfg(vk_name);
+            let extends = my_struct
+                .extends
+                .iter()
+                .map(|extended_name| {
+                    let Some(Struct::Standard(extended)) = gen.structs.get(extended_name.as_str())
+                    else {
+                        return Err(anyhow!("Failed to find extension {extended_name}"));
+                    };
+                    let extended_s_type = extended
+                        .s_type
+                        .ok_or_else(|| anyhow!("{extended_name} has no structure type"))?;
+                    let extended_s_type = structure_type(extended_name, extended_s_type)?;
+                    // the extended structure may be gated behind a feature this one does not depend on
+                    let extended_cfg = gen.item_cfg(extended_name).filter(|cfg| {
+                        Some(cfg.to_string()) != own_cfg.as_ref().map(|c| c.to_string())
+                    });
+                    Ok(quote!(#extended_cfg #extended_s_type))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(quote! {
+                #own_cfg
+                StructureInfo {
+                    structure_type: #s_type,
+                    name: #vk_name,
+                    extends: &[#(#extends),*],
+                    create: || Box::new(#name::default()),
+                },
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = quote! {
+        use super::StructureInfo;
+        use crate::vk::*;
+
+        /// All the structures with a structure type, in the order they appear in the registry
+        pub(super) static STRUCTURES: &[StructureInfo] = &[
+            #(#infos)*
+        ];
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
@@ -0,0 +1,239 @@
+use std::{cell::RefCell, collections::HashSet};
+
+use anyhow::{anyhow, Result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    structs::{Command, ReturnType},
+    xml,
+};
+
+use super::Generator;
+
+pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
+    let listed_commands = RefCell::new(HashSet::new());
+    let generate_group_codes = |require: &'a xml::Require| -> Result<TokenStream> {
+        let codes = require
+            .content
+            .iter()
+            .filter_map(|req| match req {
+                xml::RequireContent::Command(cmd) => gen
+                    .commands
+                    .get(cmd.name.as_str())
+                    .filter(|_| listed_commands.borrow_mut().insert(&cmd.name))
+                    .map(|cmd| generate_command_codes(gen, cmd)),
+                _ => None,
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! (#(#codes)*))
+    };
+
+    let features = gen.filtered_features().flat_map(|feat| &feat.require);
+    let extensions = gen
+        .filtered_extensions()
+        .flat_map(|ext: &xml::Extension| &ext.require);
+
+    let codes = features
+        .chain(extensions)
+        .map(generate_group_codes)
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = quote! {
+        use crate::vk::Status;
+        #[cfg(feature = "status-codes")]
+        use crate::vk::SuccessCode;
+
+        #(#codes)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Generate the narrowed success (if the command has multiple success codes) and error enums of a command,
+/// along with the result type returned by its wrappers
+fn generate_command_codes<'a>(gen: &Generator<'a>, cmd: &Command<'a>) -> Result<TokenStream> {
+    if !matches!(cmd.return_ty, ReturnType::Result { .. }) {
+        return Ok(quote!());
+    }
+
+    // VK_INCOMPLETE is handled by the implementation and never returned
+    let success_codes: Vec<_> = cmd
+        .xml
+        .success_codes
+        .iter()
+        .filter(|code| code.as_str() != "VK_INCOMPLETE")
+        .collect();
+
+    // remove the vk prefix
+    let base_name = &cmd.vk_name[2..];
+    let aliases: Vec<_> = cmd
+        .aliases
+        .borrow()
+        .iter()
+        .map(|(alias, _)| &alias[2..])
+        .collect();
+
+    let success_enum = (success_codes.len() > 1)
+        .then(|| {
+            let doc = format!("Success codes which can be returned by `{}`", cmd.vk_name);
+            generate_code_enum(
+                gen,
+                base_name,
+                &aliases,
+                CodeKind::Success,
+                &doc,
+                &success_codes,
+            )
+        })
+        .transpose()?;
+
+    let error_codes: Vec<_> = cmd.xml.error_codes.iter().collect();
+    let doc = format!("Error codes which can be returned by `{}`", cmd.vk_name);
+    let error_enum = generate_code_enum(
+        gen,
+        base_name,
+        &aliases,
+        CodeKind::Error,
+        &doc,
+        &error_codes,
+    )?;
+
+    let result_name = format_ident!("{base_name}Result");
+    let error_name = format_ident!("{base_name}Error");
+    let result_doc = format!(
+        "Result returned by `{}`, its error is only narrowed when the `status-codes` feature is enabled",
+        cmd.vk_name
+    );
+    let result_aliases = aliases.iter().map(|alias| {
+        let alias = format_ident!("{alias}Result");
+        quote! (pub type #alias<T> = #result_name<T>;)
+    });
+
+    gen.with_item_cfg(
+        cmd.vk_name,
+        quote! {
+            #success_enum
+            #error_enum
+            #[doc = #result_doc]
+            pub type #result_name<T> = core::result::Result<T, #error_name>;
+            #(#result_aliases)*
+        },
+    )
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CodeKind {
+    Success,
+    Error,
+}
+
+fn generate_code_enum(
+    gen: &Generator,
+    base_name: &str,
+    aliases: &[&str],
+    kind: CodeKind,
+    doc: &str,
+    codes: &[&String],
+) -> Result<TokenStream> {
+    let suffix = match kind {
+        CodeKind::Success => "Status",
+        CodeKind::Error => "Error",
+    };
+    let name = format_ident!("{base_name}{suffix}");
+    let mut listed = HashSet::new();
+    let mut variants = Vec::new();
+    let mut statuses = Vec::new();
+    let mut cfgs = Vec::new();
+    for code in codes {
+        let status = gen.get_mapping_name(code)?;
+        let variant = status
+            .strip_prefix("Status::")
+            .ok_or_else(|| anyhow!("Code {code} is not a VkResult value"))?;
+        // the same code can appear multiple times through aliases
+        if !listed.insert(variant.to_owned()) {
+            continue;
+        }
+        variants.push(format_ident!("{variant}"));
+        statuses.push(status.parse::<TokenStream>().unwrap());
+        // codes coming from a vendor extension only exist when it is enabled
+        cfgs.push(gen.enum_value_cfg(code));
+    }
+
+    let alias_decls = aliases.iter().map(|alias| {
+        let alias = format_ident!("{alias}{suffix}");
+        quote! (pub type #alias = #name;)
+    });
+
+    let feature_cfg = quote!(#[cfg(feature = "status-codes")]);
+    // the success codes are exhaustive as a command can only return a new one if the application
+    // enabled the extension adding it, an unexpected error is kept in the Other variant
+    let (other_variant, other_into, from_status) = match kind {
+        CodeKind::Success => (
+            None,
+            None,
+            quote! {
+                #feature_cfg
+                impl SuccessCode for #name {
+                    fn from_status(status: Status) -> Option<Self> {
+                        match status {
+                            #(#cfgs #statuses => Some(#name::#variants),)*
+                            _ => None,
+                        }
+                    }
+                }
+            },
+        ),
+        CodeKind::Error => (
+            Some(quote! {
+                /// Code not listed by the specification for this command, which can be returned by a newer driver or layer
+                Other(Status),
+            }),
+            Some(quote!(#name::Other(status) => status,)),
+            quote! {
+                #feature_cfg
+                impl From<Status> for #name {
+                    fn from(value: Status) -> Self {
+                        match value {
+                            #(#cfgs #statuses => #name::#variants,)*
+                            status => #name::Other(status),
+                        }
+                    }
+                }
+                #feature_cfg
+                impl core::fmt::Display for #name {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        core::fmt::Display::fmt(&Status::from(*self), f)
+                    }
+                }
+                #feature_cfg
+                impl std::error::Error for #name {}
+            },
+        ),
+    };
+
+    Ok(quote! {
+        #feature_cfg
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum #name {
+            #(#cfgs #variants,)*
+            #other_variant
+        }
+        #[cfg(not(feature = "status-codes"))]
+        pub type #name = Status;
+        #(#alias_decls)*
+        #feature_cfg
+        impl From<#name> for Status {
+            fn from(value: #name) -> Status {
+                match value {
+                    #(#cfgs #name::#variants => #statuses,)*
+                    #other_into
+                }
+            }
+        }
+        #from_status
+    })
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use quote::{format_ident, quote};
+
+use crate::{
+    structs::{Command, Type},
+    xml,
+};
+
+use super::Generator;
+
+/// Return the handle destroyed by the command if it is a device command of the form
+/// vkDestroyObject(device, object, pAllocator) or vkFreeObject(device, object, pAllocator)
+fn destroyed_handle<'a>(gen: &Generator<'a>, cmd: &Command<'a>) -> Option<&'a str> {
+    if !cmd.vk_name.starts_with("vkDestroy") && !cmd.vk_name.starts_with("vkFree") {
+        return None;
+    }
+    match cmd.params.as_slice() {
+        [device, object, allocator]
+            if matches!(device.ty, Type::Path("VkDevice"))
+                && matches!(allocator.ty, Type::Ptr("VkAllocationCallbacks")) =>
+        {
+            let Type::Path(handle) = object.ty else {
+                return None;
+            };
+            gen.handles
+                .get(handle)
+                .filter(|handle| !handle.is_dispatchable)
+                .map(|_| handle)
+        }
+        _ => None,
+    }
+}
+
+pub fn generate(gen: &Generator) -> Result<String> {
+    let mut listed_cmds = HashSet::new();
+
+    let destroyables = gen
+        .filtered_features()
+        .flat_map(|feature| &feature.require)
+        .chain(gen.filtered_extensions().flat_map(|ext| &ext.require))
+        .flat_map(|require| &require.content)
+        .filter_map(|item| match item {
+            xml::RequireContent::Command(xml::RequireCommand { name, .. }) => {
+                gen.commands.get(name.as_str())
+            }
+            _ => None,
+        })
+        .filter(|cmd| listed_cmds.insert(cmd.vk_name))
+        .filter_map(|cmd| Some((cmd, destroyed_handle(gen, cmd)?)))
+        .map(|(cmd, vk_handle)| {
+            let handle = &gen.handles[vk_handle];
+            let name = format_ident!("{}", handle.name);
+            let object_type = gen.get_mapping_name(handle.object_type)?;
+            let object_type = object_type
+                .strip_prefix("ObjectType::")
+                .ok_or_else(|| anyhow!("Unexpected object type {object_type}"))?;
+            let object_type = format_ident!("{object_type}");
+            let destroy_fn = format_ident!("{}", cmd.name);
+            let object = if cmd.params[1].optional {
+                quote!(Some(self))
+            } else {
+                quote!(self)
+            };
+            let call = quote!(device.#destroy_fn(#object));
+            // the generated vkFree* commands are not marked as unsafe
+            let call = if cmd.name.starts_with("destroy") {
+                quote!(unsafe { #call })
+            } else {
+                call
+            };
+            let cfg = gen.item_cfg(cmd.vk_name);
+
+            Ok((
+                quote! {
+                    #cfg
+                    impl Destroyable for raw::#name {
+                        #[inline]
+                        unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+                            #call
+                        }
+                    }
+                },
+                quote! {
+                    #cfg
+                    vk::ObjectType::#object_type => Some(destroy_erased::<raw::#name, D, A>),
+                },
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (impls, arms): (Vec<_>, Vec<_>) = destroyables.into_iter().unzip();
+
+    let result = quote! {
+        use super::{destroy_erased, DestroyFn, Destroyable};
+        use crate::{vk::{self, raw}, Allocator, Dispatcher};
+
+        #(#impls)*
+
+        /// Return the function destroying the objects of the given type, or None if this type is not [Destroyable]
+        pub(super) fn destroy_fn<D: Dispatcher, A: Allocator>(
+            object_type: vk::ObjectType,
+        ) -> Option<DestroyFn<D, A>> {
+            match object_type {
+                #(#arms)*
+                _ => None,
+            }
+        }
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
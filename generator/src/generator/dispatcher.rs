@@ -60,7 +60,9 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
 
         use std::mem;
         use std::cell::Cell;
-        use std::ffi::{c_char, c_int, c_void};
+        // c_ulong is only used by platform commands
+        #[allow(unused_imports)]
+        use std::ffi::{c_char, c_int, c_ulong, c_void};
 
         #[derive(Default, Clone)]
         pub struct CommandsDispatcher {
@@ -122,41 +124,48 @@ fn generate_dispatch_command<'a>(
         .map(|param| gen.generate_type_inner(&param.advanced_ty.get().unwrap(), false))
         .collect::<Result<Vec<_>>>()?;
 
+    // the fields and their loading are only present when the extensions requiring the command are enabled
     let aliases = cmd.aliases.borrow();
-    let names = iter::once(cmd.name.as_str())
-        .chain(aliases.iter().map(|(_, alias)| alias.as_str()))
-        .map(|name| {
-            let name = format_ident!("{name}");
-            quote! (pub #name: Cell<Option<unsafe extern "system" fn(#(#params),*) #ret_type>>,)
-        });
+    let all_names: Vec<_> = iter::once((cmd.vk_name, cmd.name.as_str()))
+        .chain(
+            aliases
+                .iter()
+                .map(|(vk_name, alias)| (*vk_name, alias.as_str())),
+        )
+        .collect();
+    let names = all_names.iter().map(|(vk_name, name)| {
+        let item_cfg = gen.item_cfg(vk_name);
+        let name = format_ident!("{name}");
+        quote! (#item_cfg pub #name: Cell<Option<unsafe extern "system" fn(#(#params),*) #ret_type>>,)
+    });
 
     let main_name = format_ident!("{}", cmd.name);
-    let update_fallback = |loader: &mut Vec<TokenStream>, alias: &Ident| {
+    let main_cfg = gen.item_cfg(cmd.vk_name);
+    let update_fallback = |loader: &mut Vec<TokenStream>,
+                           alias: &Ident,
+                           alias_cfg: &Option<TokenStream>| {
         if alias == &main_name {
             return;
         }
 
-        loader.push(quote! (self.#main_name.set(self.#main_name.get().or(self.#alias.get()));));
+        loader.push(quote! (#main_cfg #alias_cfg self.#main_name.set(self.#main_name.get().or(self.#alias.get()));));
     };
 
-    for (vk_name, name) in iter::once((cmd.vk_name, cmd.name.as_str())).chain(
-        aliases
-            .iter()
-            .map(|(vk_name, alias)| (*vk_name, alias.as_str())),
-    ) {
+    for (vk_name, name) in &all_names {
+        let item_cfg = gen.item_cfg(vk_name);
         let name = format_ident!("{name}");
-        let name_cstr = LitCStr::new(&CString::new(vk_name).unwrap(), Span::call_site());
+        let name_cstr = LitCStr::new(&CString::new(*vk_name).unwrap(), Span::call_site());
         if let Some(handle_name) = cmd.handle.get() {
-            instance_loader.push(quote! (self.#name.set(mem::transmute(get_instance_proc_addr(get_instance(), #name_cstr.as_ptr())));));
-            update_fallback(instance_loader, &name);
+            instance_loader.push(quote! (#item_cfg self.#name.set(mem::transmute(get_instance_proc_addr(get_instance(), #name_cstr.as_ptr())));));
+            update_fallback(instance_loader, &name, &item_cfg);
 
             if handle_name != "VkInstance" && handle_name != "VkPhysicalDevice" {
-                device_loader.push(quote! (self.#name.set(mem::transmute(get_device_proc_addr(get_device(), #name_cstr.as_ptr())));));
-                update_fallback(device_loader, &name);
+                device_loader.push(quote! (#item_cfg self.#name.set(mem::transmute(get_device_proc_addr(get_device(), #name_cstr.as_ptr())));));
+                update_fallback(device_loader, &name, &item_cfg);
             }
         } else {
-            proc_addr_loader.push(quote! (self.#name.set(mem::transmute(get_instance_proc_addr(None, #name_cstr.as_ptr())));));
-            update_fallback(proc_addr_loader, &name);
+            proc_addr_loader.push(quote! (#item_cfg self.#name.set(mem::transmute(get_instance_proc_addr(None, #name_cstr.as_ptr())));));
+            update_fallback(proc_addr_loader, &name, &item_cfg);
         }
     }
 
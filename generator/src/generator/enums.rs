@@ -69,7 +69,10 @@ fn generate_group_enums<'a>(
             let result = if let Some(constant) = gen.constants.get(name) {
                 Some(generate_constant(gen, name, constant))
             } else if let Some(value) = gen.enums.get(name) {
-                Some(generate_enum(gen, name, value))
+                Some(
+                    generate_enum(gen, name, value)
+                        .and_then(|items| gen.with_item_cfg(name, items)),
+                )
             } else {
                 None
             };
@@ -85,8 +88,13 @@ fn generate_group_enums<'a>(
     })
 }
 
-fn generate_constant(gen: &Generator, const_name: &str, value: &Constant) -> Result<TokenStream> {
+pub(super) fn generate_constant(
+    gen: &Generator,
+    const_name: &str,
+    value: &Constant,
+) -> Result<TokenStream> {
     let doc_tag = make_doc_link(const_name);
+    let value_cfg = gen.enum_value_cfg(const_name);
     match value {
         Constant::Aliased { name, alias } => {
             let (alias, ty) = gen
@@ -105,19 +113,19 @@ fn generate_constant(gen: &Generator, const_name: &str, value: &Constant) -> Res
             let value = format_ident!("{alias}");
             let ty: Ident = ty.into();
 
-            Ok(quote! { #doc_tag pub const #name: #ty = #value; })
+            Ok(quote! { #value_cfg #doc_tag pub const #name: #ty = #value; })
         }
         Constant::Field { name, ty, value } => {
             let name = format_ident!("{name}");
             let value = parse_value(value, *ty);
             let ty: Ident = ty.into();
 
-            Ok(quote! { #doc_tag pub const #name: #ty = #value; })
+            Ok(quote! { #value_cfg #doc_tag pub const #name: #ty = #value; })
         }
     }
 }
 
-fn generate_enum(_gen: &Generator, enum_name: &str, value: &Enum) -> Result<TokenStream> {
+pub(super) fn generate_enum(gen: &Generator, enum_name: &str, value: &Enum) -> Result<TokenStream> {
     let has_negative = value.values.borrow().iter().any(|(_, field)| match field {
         EnumValue::Variant(EnumVariant { value, .. }) => value.starts_with("-"),
         _ => false,
@@ -136,9 +144,52 @@ fn generate_enum(_gen: &Generator, enum_name: &str, value: &Enum) -> Result<Toke
     // Can happen with VK_SURFACE_COUNTER_VBLANK_BIT_EXT and VK_SURFACE_COUNTER_VBLANK_EXT both resolving to Vblank
     let mut found_values = HashSet::new();
 
+    // a value aliased by an ungated value must be present as well, even if the extension defining it is disabled
+    let mut always_present: HashSet<&str> = enum_fields
+        .iter()
+        .filter(|(vk_name, _)| gen.enum_value_cfg(vk_name).is_none())
+        .map(|(vk_name, _)| *vk_name)
+        .collect();
+    loop {
+        let aliased: Vec<_> = enum_fields
+            .iter()
+            .filter_map(|(vk_name, field)| match field {
+                EnumValue::Aliased(EnumAliased { alias, .. })
+                    if always_present.contains(vk_name) =>
+                {
+                    Some(*alias)
+                }
+                _ => None,
+            })
+            .filter(|alias| !always_present.contains(alias))
+            .collect();
+        if aliased.is_empty() {
+            break;
+        }
+        always_present.extend(aliased);
+    }
+
+    let cfg_of = |vk_name: &str| {
+        (!always_present.contains(vk_name))
+            .then(|| gen.enum_value_cfg(vk_name))
+            .flatten()
+    };
     let fields = enum_fields
         .iter()
-        .map(|(_, field)| match field {
+        .map(|(vk_name, field)| {
+            let mut value_cfg = cfg_of(vk_name);
+            // an alias also needs the value it refers to, which can come from another vendor
+            if let EnumValue::Aliased(EnumAliased { alias, .. }) = field {
+                let alias_cfg = cfg_of(alias);
+                let same_cfg = alias_cfg.as_ref().map(|cfg| cfg.to_string())
+                    == value_cfg.as_ref().map(|cfg| cfg.to_string());
+                if alias_cfg.is_some() && !same_cfg {
+                    value_cfg = Some(quote!(#value_cfg #alias_cfg));
+                }
+            }
+            (value_cfg, field)
+        })
+        .map(|(value_cfg, field)| match field {
             EnumValue::Aliased(EnumAliased { name, alias }) => {
                 if found_values.contains(name) {
                     // duplicate field
@@ -159,9 +210,15 @@ fn generate_enum(_gen: &Generator, enum_name: &str, value: &Enum) -> Result<Toke
                 let name = format_ident!("{name}");
                 let value = format_ident!("{alias}");
                 if is_bitflag {
-                    Ok((true, quote! {const #name = Self::#value.bits(); }))
+                    Ok((
+                        true,
+                        quote! {#value_cfg const #name = Self::#value.bits(); },
+                    ))
                 } else {
-                    Ok((false, quote! {pub const #name : Self = Self::#value;}))
+                    Ok((
+                        false,
+                        quote! {#value_cfg pub const #name : Self = Self::#value;},
+                    ))
                 }
             }
             EnumValue::Variant(EnumVariant { name, value }) => {
@@ -170,9 +227,9 @@ fn generate_enum(_gen: &Generator, enum_name: &str, value: &Enum) -> Result<Toke
                 let value = parse_value(value, ty);
 
                 if is_bitflag {
-                    Ok((true, quote! {const #name = #value;}))
+                    Ok((true, quote! {#value_cfg const #name = #value;}))
                 } else {
-                    Ok((true, quote! {#name = #value,}))
+                    Ok((true, quote! {#value_cfg #name = #value,}))
                 }
             }
             EnumValue::Flag(EnumFlag { name, bitpos }) => {
@@ -180,7 +237,7 @@ fn generate_enum(_gen: &Generator, enum_name: &str, value: &Enum) -> Result<Toke
                 let name = format_ident!("{name}");
                 let value: TokenStream = format!("1{} << {}", ty_token, bitpos).parse().unwrap();
 
-                Ok((true, quote! {const #name = #value;}))
+                Ok((true, quote! {#value_cfg const #name = #value;}))
             }
         })
         .collect::<Result<Vec<_>>>()?;
@@ -204,12 +261,14 @@ fn generate_enum(_gen: &Generator, enum_name: &str, value: &Enum) -> Result<Toke
         .map(|(alias_name, alias)| {
             let alias = format_ident!("{alias}");
             let doc_tag = make_doc_link(alias_name);
-            quote! (#doc_tag pub type #alias = #name;)
+            let alias_cfg = gen.item_cfg(alias_name);
+            quote! (#alias_cfg #doc_tag pub type #alias = #name;)
         })
         .collect::<Vec<_>>();
     let doc_tag = make_doc_link(enum_name);
     let result = quote! {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #doc_tag
         #pre_qualifier
         pub #struct_ty #name #post_qualifier {
@@ -11,25 +11,7 @@ use super::Generator;
 
 pub fn generate(gen: &Generator) -> Result<String> {
     // also bundle the header version along with all the extensions
-    let header_version = gen
-        .all_types()
-        .find_map(|ty| {
-            // we want to find the following:
-            // <type api="vulkan" category="define">// Version of this file #define <name>VK_HEADER_VERSION</name> 281</type>
-            match (ty, ty.content.as_slice()) {
-                (
-                    xml::Type {
-                        category: Some(cat),
-                        ..
-                    },
-                    [xml::TypeContent::Text(_), xml::TypeContent::Name(name), xml::TypeContent::Text(value)],
-                ) if cat == "define" && name == "VK_HEADER_VERSION" => Some(value),
-                _ => None,
-            }
-        })
-        .map(|v| v.parse::<u32>().ok())
-        .unwrap_or_default()
-        .ok_or_else(|| anyhow!("Failed to find VK_HEADER_VERSION"))?;
+    let header_version = gen.header_version()?;
 
     let extensions = gen
         .filtered_extensions()
@@ -1,15 +1,41 @@
 use super::Generator;
-use anyhow::Result;
+use crate::{helpers::screaming_snake_to_pascal_case, xml};
+use anyhow::{anyhow, Result};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use syn::Ident;
 
 pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
     let mut names = Vec::new();
+    let mut cfgs = Vec::new();
     let mut block_texels = Vec::new();
     let mut block_sizes = Vec::new();
     let mut block_extents = Vec::new();
     let mut component_counts = Vec::new();
     let mut compressed_formats = Vec::new();
+    let mut components = Vec::new();
+    let mut numeric_formats = Vec::new();
+    let mut depth_formats = Vec::new();
+    let mut stencil_formats = Vec::new();
+    let mut aspect_masks = Vec::new();
+    let mut packed_formats = Vec::new();
+    let mut chroma_formats = Vec::new();
+    let mut plane_counts = Vec::new();
+    let mut planes = Vec::new();
+    let mut classes: Vec<&str> = Vec::new();
+    let mut format_classes = Vec::new();
+    let mut srgb_counterparts = Vec::new();
+    let mut linear_counterparts = Vec::new();
+    let mut texel_layouts = Vec::new();
+
+    let format_names: HashSet<&str> = gen
+        .registry
+        .formats
+        .iter()
+        .flat_map(|formats| &formats.format)
+        .map(|format| format.name.as_str())
+        .collect();
 
     for format in gen
         .registry
@@ -19,14 +45,17 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
     {
         let format_name: TokenStream = gen.get_mapping_name(&format.name)?.parse().unwrap();
         names.push(format_name.clone());
+        // formats added by vendor extensions are only present when the extension is enabled
+        let format_cfg = gen.enum_value_cfg(&format.name);
+        cfgs.push(format_cfg.clone());
 
         if format.compressed.is_some() {
-            compressed_formats.push(format_name.clone());
+            compressed_formats.push(quote! {#format_cfg #format_name => true,});
         }
 
         if format.texels_per_block > 1 {
             let texels_per_block = format.texels_per_block;
-            block_texels.push(quote! {#format_name => #texels_per_block,});
+            block_texels.push(quote! {#format_cfg #format_name => #texels_per_block,});
         }
 
         block_sizes.push(format.block_size);
@@ -39,20 +68,245 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
             let y = format.block_extent[1];
             let z = format.block_extent[2];
 
-            block_extents.push(quote! {#format_name => [#x, #y, #z],});
+            block_extents.push(quote! {#format_cfg #format_name => [#x, #y, #z],});
+        };
+
+        let format_components = format
+            .component
+            .iter()
+            .map(|component| {
+                let name = format_ident!("{}", component.name);
+                let bits = match component.bits.as_str() {
+                    "compressed" => quote!(None),
+                    bits => {
+                        let bits: u8 = bits.parse()?;
+                        quote!(Some(#bits))
+                    }
+                };
+                let numeric_format = numeric_format_ident(&component.numeric_format);
+                let plane = match &component.plane_index {
+                    Some(index) => {
+                        let index = plane_index(index);
+                        quote!(Some(#index))
+                    }
+                    None => quote!(None),
+                };
+                Ok(quote! {
+                    FormatComponent {
+                        name: ComponentName::#name,
+                        bits: #bits,
+                        numeric_format: NumericFormat::#numeric_format,
+                        plane: #plane,
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        components.push(quote!(&[#(#format_components),*]));
+
+        // the numeric format of a format is the one of its first color or depth component,
+        // the alpha component comes first in some packed formats and is always UNORM for sRGB formats
+        let first_component = format
+            .component
+            .iter()
+            .find(|component| component.name != "A")
+            .unwrap_or(&format.component[0]);
+        let numeric_format = numeric_format_ident(&first_component.numeric_format);
+        numeric_formats.push(quote!(NumericFormat::#numeric_format));
+
+        let has_component = |name: &str| format.component.iter().any(|comp| comp.name == name);
+        let (is_depth, is_stencil) = (has_component("D"), has_component("S"));
+        if is_depth {
+            depth_formats.push(quote! {#format_cfg #format_name => true,});
+        }
+        if is_stencil {
+            stencil_formats.push(quote! {#format_cfg #format_name => true,});
+        }
+        let aspect_mask = match (is_depth, is_stencil) {
+            (true, true) => Some(quote!(
+                ImageAspectFlags::Depth.union(ImageAspectFlags::Stencil)
+            )),
+            (true, false) => Some(quote!(ImageAspectFlags::Depth)),
+            (false, true) => Some(quote!(ImageAspectFlags::Stencil)),
+            (false, false) => None,
         };
+        if let Some(aspect_mask) = aspect_mask {
+            aspect_masks.push(quote! {#format_cfg #format_name => #aspect_mask,});
+        }
+
+        if let Some(packed) = format.packed {
+            packed_formats.push(quote! {#format_cfg #format_name => Some(#packed),});
+        }
+
+        if let Some(chroma) = &format.chroma {
+            let chroma = match chroma {
+                xml::Chroma::Chroma420 => quote!(Chroma420),
+                xml::Chroma::Chroma422 => quote!(Chroma422),
+                xml::Chroma::Chroma444 => quote!(Chroma444),
+            };
+            chroma_formats
+                .push(quote! {#format_cfg #format_name => Some(ChromaSubsampling::#chroma),});
+        }
+
+        if !format.plane.is_empty() {
+            let mut format_planes: Vec<_> = format.plane.iter().collect();
+            format_planes.sort_by_key(|plane| plane_index(&plane.index));
+            let format_planes = format_planes
+                .into_iter()
+                .map(|plane| {
+                    let plane_format: TokenStream =
+                        gen.get_mapping_name(&plane.compatible)?.parse().unwrap();
+                    let width_divisor = plane_divisor(&plane.width_divisor);
+                    let height_divisor = plane_divisor(&plane.height_divisor);
+                    Ok(quote! {
+                        FormatPlane {
+                            format: #plane_format,
+                            width_divisor: #width_divisor,
+                            height_divisor: #height_divisor,
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let plane_count = format_planes.len() as u8;
+            plane_counts.push(quote! {#format_cfg #format_name => #plane_count,});
+            planes.push(quote! {#format_cfg #format_name => &[#(#format_planes),*],});
+        }
+
+        if !classes.contains(&format.class.as_str()) {
+            classes.push(&format.class);
+        }
+        let class_name = class_ident(&format.class);
+        format_classes.push(quote!(CompatibilityClass::#class_name));
+
+        // sRGB formats share their name with their UNORM counterpart (VK_FORMAT_R8G8B8A8_SRGB and VK_FORMAT_R8G8B8A8_UNORM)
+        if let Some((prefix, suffix)) = format.name.split_once("_SRGB") {
+            let linear_name = format!("{prefix}_UNORM{suffix}");
+            if format_names.contains(linear_name.as_str()) {
+                let linear_format: TokenStream =
+                    gen.get_mapping_name(&linear_name)?.parse().unwrap();
+                let linear_cfg = gen.enum_value_cfg(&linear_name);
+                srgb_counterparts.push(quote! {#linear_cfg #linear_format => Some(#format_name),});
+                linear_counterparts
+                    .push(quote! {#format_cfg #format_name => Some(#linear_format),});
+            }
+        }
+
+        if let Some(layout) = texel_layout(format)? {
+            texel_layouts.push(quote! {#format_cfg #format_name => Some(&[#(#layout),*]),});
+        }
     }
 
     // Note: is it better to panic or return a default value for vk::Format::Undefined ?
+    let class_names = classes.iter().map(|class| class_ident(class));
+
     let result = quote! {
-        use super::Format;
+        use super::{Format, ImageAspectFlags};
+
+        /// Compatibility class of a [Format].
+        /// Images with formats of the same compatibility class can be reinterpreted as one another
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum CompatibilityClass {
+            #(
+                #[doc = #classes]
+                #class_names,
+            )*
+        }
+
+        /// Numeric format of a component of a [Format]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum NumericFormat {
+            /// Unsigned normalized value in the range [0, 1]
+            Unorm,
+            /// Signed normalized value in the range [-1, 1]
+            Snorm,
+            /// Unsigned integer value converted to a float in the range [0, 2^n - 1]
+            Uscaled,
+            /// Signed integer value converted to a float in the range [-2^(n-1), 2^(n-1) - 1]
+            Sscaled,
+            /// Unsigned integer value
+            Uint,
+            /// Signed integer value
+            Sint,
+            /// Unsigned floating-point value
+            Ufloat,
+            /// Signed floating-point value
+            Sfloat,
+            /// Unsigned normalized value using the sRGB nonlinear encoding
+            Srgb,
+            /// Signed fixed-point value with 5 fractional bits
+            Sfixed5,
+        }
+
+        /// Name of a component of a [Format]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ComponentName {
+            /// Red component, or Cr for YCbCr formats
+            R,
+            /// Green component, or Y for YCbCr formats
+            G,
+            /// Blue component, or Cb for YCbCr formats
+            B,
+            /// Alpha component
+            A,
+            /// Depth component
+            D,
+            /// Stencil component
+            S,
+        }
+
+        /// Description of a component of a [Format]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct FormatComponent {
+            pub name: ComponentName,
+            /// Number of bits of this component, None for compressed formats
+            pub bits: Option<u8>,
+            pub numeric_format: NumericFormat,
+            /// Index of the plane containing this component for multi-planar formats
+            pub plane: Option<u8>,
+        }
+
+        /// Location in memory of a component of a texel, see [Format::texel_layout]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct TexelComponent {
+            pub name: ComponentName,
+            pub numeric_format: NumericFormat,
+            /// Number of bits of this component
+            pub bits: u8,
+            /// Offset in bytes in the texel of the element containing this component
+            pub offset: u8,
+            /// Size in bytes of the element containing this component, which is stored with the host endianness
+            pub size: u8,
+            /// Position of the least significant bit of this component in its element
+            pub shift: u8,
+        }
+
+        /// Chroma subsampling of a YCbCr [Format]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ChromaSubsampling {
+            /// The chroma components are sampled at half the width and half the height of the image
+            Chroma420,
+            /// The chroma components are sampled at half the width of the image
+            Chroma422,
+            /// The chroma components are sampled at the full resolution of the image
+            Chroma444,
+        }
+
+        /// Description of a plane of a multi-planar [Format]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct FormatPlane {
+            /// Single-plane format compatible with this plane
+            pub format: Format,
+            /// The width of this plane is the width of the image divided by this value
+            pub width_divisor: u8,
+            /// The height of this plane is the height of the image divided by this value
+            pub height_divisor: u8,
+        }
 
         impl Format {
             /// Return the number of components of this format.
             pub const fn component_count(self) -> u8 {
                 match self {
                     Format::Undefined => panic!("Trying to get the component count of vk::Format::Undefined"),
-                    #(#names => #component_counts,)*
+                    #(#cfgs #names => #component_counts,)*
                 }
             }
 
@@ -60,7 +314,7 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
             pub const fn block_size(self) -> u8 {
                 match self {
                     Format::Undefined => panic!("Trying to get the block size of vk::Format::Undefined"),
-                    #(#names => #block_sizes,)*
+                    #(#cfgs #names => #block_sizes,)*
                 }
             }
 
@@ -85,13 +339,277 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
             /// Return true if this format is a compressed format
             pub const fn is_compressed(self) -> bool {
                 match self {
-                    #(#compressed_formats)|* => true,
+                    #(#compressed_formats)*
                     _ => false,
                 }
             }
+
+            /// Return the description of each component of this format, in the order of the format name
+            pub const fn components(self) -> &'static [FormatComponent] {
+                match self {
+                    Format::Undefined => panic!("Trying to get the components of vk::Format::Undefined"),
+                    #(#cfgs #names => #components,)*
+                }
+            }
+
+            /// Return the numeric format of this format.
+            /// For formats with components of different numeric formats (sRGB formats with an alpha component
+            /// or depth/stencil formats), this is the numeric format of the first color or depth component
+            pub const fn numeric_format(self) -> NumericFormat {
+                match self {
+                    Format::Undefined => panic!("Trying to get the numeric format of vk::Format::Undefined"),
+                    #(#cfgs #names => #numeric_formats,)*
+                }
+            }
+
+            /// Return true if this format has a depth component
+            #[allow(clippy::match_like_matches_macro)]
+            pub const fn is_depth(self) -> bool {
+                match self {
+                    #(#depth_formats)*
+                    _ => false,
+                }
+            }
+
+            /// Return true if this format has a stencil component
+            #[allow(clippy::match_like_matches_macro)]
+            pub const fn is_stencil(self) -> bool {
+                match self {
+                    #(#stencil_formats)*
+                    _ => false,
+                }
+            }
+
+            /// Return the aspects of an image with this format.
+            /// The planes of multi-planar formats are part of the color aspect
+            pub const fn aspect_mask(self) -> ImageAspectFlags {
+                match self {
+                    Format::Undefined => panic!("Trying to get the aspect mask of vk::Format::Undefined"),
+                    #(#aspect_masks)*
+                    _ => ImageAspectFlags::Color,
+                }
+            }
+
+            /// If this format is a packed format, return the size in bits of each packed element
+            pub const fn packed(self) -> Option<u8> {
+                match self {
+                    #(#packed_formats)*
+                    _ => None,
+                }
+            }
+
+            /// If this format is a YCbCr format, return the subsampling of its chroma components
+            pub const fn chroma_subsampling(self) -> Option<ChromaSubsampling> {
+                match self {
+                    #(#chroma_formats)*
+                    _ => None,
+                }
+            }
+
+            /// Return the number of planes of this format, 1 for formats which are not multi-planar
+            pub const fn plane_count(self) -> u8 {
+                match self {
+                    Format::Undefined => panic!("Trying to get the plane count of vk::Format::Undefined"),
+                    #(#plane_counts)*
+                    _ => 1,
+                }
+            }
+
+            /// Return the description of each plane of a multi-planar format,
+            /// or an empty slice if this format is not multi-planar
+            pub const fn planes(self) -> &'static [FormatPlane] {
+                match self {
+                    Format::Undefined => panic!("Trying to get the planes of vk::Format::Undefined"),
+                    #(#planes)*
+                    _ => &[],
+                }
+            }
+
+            /// Return the compatibility class of this format
+            pub const fn compatibility_class(self) -> CompatibilityClass {
+                match self {
+                    Format::Undefined => panic!("Trying to get the compatibility class of vk::Format::Undefined"),
+                    #(#cfgs #names => #format_classes,)*
+                }
+            }
+
+            /// Return true if both formats belong to the same compatibility class,
+            /// in which case an image view with one of these formats can be created for an image with the other one
+            pub const fn is_compatible_with(self, other: Format) -> bool {
+                self.compatibility_class() as u32 == other.compatibility_class() as u32
+            }
+
+            /// Return true if both formats have the same texel block size,
+            /// in which case images with these formats can be copied to one another
+            pub const fn is_size_compatible_with(self, other: Format) -> bool {
+                self.block_size() == other.block_size()
+            }
+
+            /// If this format is a UNORM format with an sRGB counterpart, return this sRGB format
+            pub const fn srgb_counterpart(self) -> Option<Format> {
+                match self {
+                    #(#srgb_counterparts)*
+                    _ => None,
+                }
+            }
+
+            /// If this format is an sRGB format, return its UNORM counterpart
+            pub const fn linear_counterpart(self) -> Option<Format> {
+                match self {
+                    #(#linear_counterparts)*
+                    _ => None,
+                }
+            }
+
+            /// Return the location in memory of each component of a texel of this format.
+            /// Returns None for compressed, multi-planar, subsampled and combined depth/stencil formats,
+            /// whose texels cannot be accessed independently
+            pub const fn texel_layout(self) -> Option<&'static [TexelComponent]> {
+                match self {
+                    #(#texel_layouts)*
+                    _ => None,
+                }
+            }
         }
     }
     .to_string();
 
     Generator::format_result(result)
 }
+
+fn numeric_format_ident(numeric_format: &xml::NumericFormat) -> TokenStream {
+    match numeric_format {
+        xml::NumericFormat::SFloat => quote!(Sfloat),
+        xml::NumericFormat::SInt => quote!(Sint),
+        xml::NumericFormat::SNorm => quote!(Snorm),
+        xml::NumericFormat::SRgb => quote!(Srgb),
+        xml::NumericFormat::SFixed5 => quote!(Sfixed5),
+        xml::NumericFormat::SScaled => quote!(Sscaled),
+        xml::NumericFormat::UFloat => quote!(Ufloat),
+        xml::NumericFormat::UInt => quote!(Uint),
+        xml::NumericFormat::UNorm => quote!(Unorm),
+        xml::NumericFormat::UScaled => quote!(Uscaled),
+    }
+}
+
+/// Return for each component of a format with an independent texel layout
+/// the offset and size in bytes of the element containing it and its position in this element
+fn texel_layout(format: &xml::Format) -> Result<Option<Vec<TokenStream>>> {
+    let has_component = |name: &str| format.component.iter().any(|comp| comp.name == name);
+    if format.compressed.is_some()
+        || !format.plane.is_empty()
+        || !format.block_extent.is_empty()
+        || (has_component("D") && has_component("S"))
+    {
+        return Ok(None);
+    }
+
+    // the components in the format name, including the padding and the shared exponent, are listed
+    // from the most significant bits to the least significant ones (VK_FORMAT_X8_D24_UNORM_PACK32)
+    let numeric_formats = [
+        "UNORM", "SNORM", "USCALED", "SSCALED", "UINT", "SINT", "UFLOAT", "SFLOAT", "SRGB",
+        "SFIXED5",
+    ];
+    let layout_name: String = format
+        .name
+        .trim_start_matches("VK_FORMAT_")
+        .split('_')
+        .take_while(|token| !numeric_formats.contains(token))
+        .collect();
+    let mut layout = Vec::new();
+    let mut chars = layout_name.char_indices().peekable();
+    while let Some((start, name)) = chars.next() {
+        let mut end = start + 1;
+        while let Some((idx, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+            end = idx + 1;
+        }
+        let bits: u8 = layout_name[start + 1..end].parse()?;
+        layout.push((name, bits));
+    }
+
+    let block_bits = format.block_size as u32 * 8;
+    let layout_error = || anyhow!("Failed to parse the layout of {}", format.name);
+    let mut components = format.component.iter();
+    let mut result = Vec::new();
+    let mut offset = 0u8;
+    let mut remaining_bits = block_bits;
+    for (name, bits) in layout {
+        remaining_bits = remaining_bits
+            .checked_sub(bits as u32)
+            .ok_or_else(layout_error)?;
+        if matches!(name, 'X' | 'E') {
+            continue;
+        }
+        let component = components.next().ok_or_else(layout_error)?;
+        if !component.name.starts_with(name) || component.bits != bits.to_string() {
+            return Err(layout_error());
+        }
+        let (element_offset, size, shift) = match format.packed {
+            // all the components are packed in a single element
+            Some(packed) if packed as u32 == block_bits => {
+                (0, format.block_size, remaining_bits as u8)
+            }
+            // each component is in the most significant bits of its own element (VK_FORMAT_R10X6G10X6_UNORM_2PACK16)
+            Some(packed) => (offset, packed / 8, packed - bits),
+            None => (offset, bits / 8, 0),
+        };
+        offset += size;
+
+        let name = format_ident!("{}", component.name);
+        let numeric_format = numeric_format_ident(&component.numeric_format);
+        result.push(quote! {
+            TexelComponent {
+                name: ComponentName::#name,
+                numeric_format: NumericFormat::#numeric_format,
+                bits: #bits,
+                offset: #element_offset,
+                size: #size,
+                shift: #shift,
+            }
+        });
+    }
+    if components.next().is_some() || remaining_bits != 0 {
+        return Err(layout_error());
+    }
+    Ok(Some(result))
+}
+
+fn plane_index(index: &xml::PlaneIndex) -> u8 {
+    match index {
+        xml::PlaneIndex::P0 => 0,
+        xml::PlaneIndex::P1 => 1,
+        xml::PlaneIndex::P2 => 2,
+    }
+}
+
+fn plane_divisor(divisor: &xml::PlaneDivisor) -> u8 {
+    match divisor {
+        xml::PlaneDivisor::D1 => 1,
+        xml::PlaneDivisor::D2 => 2,
+    }
+}
+
+/// Turn a format class (8-bit, 32-bit G8B8G8R8, 8-bit 3-plane 420, ASTC_4x4...) into a valid identifier
+fn class_ident(class: &str) -> Ident {
+    // identifiers cannot start with a digit and numbers must not be merged together
+    let words: Vec<_> = class
+        .split(' ')
+        .map(|word| {
+            let word = if let Some(bits) = word.strip_suffix("-bit") {
+                format!("Bit{bits}")
+            } else if let Some(planes) = word.strip_suffix("-plane") {
+                format!("Plane{planes}")
+            } else if word.chars().all(|c| c.is_ascii_digit()) {
+                format!("Chroma{word}")
+            } else {
+                word.to_owned()
+            };
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    format_ident!("{}", screaming_snake_to_pascal_case(&words.join("_")))
+}
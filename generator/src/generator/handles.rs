@@ -21,7 +21,10 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
             })
             .filter_map(|name| gen.handles.get(name.as_str()).map(|handle| (name, handle)))
             .filter(|(name, _)| listed_handles.borrow_mut().insert(*name))
-            .map(|(name, handle)| generate_handle(gen, handle, name))
+            .map(|(name, handle)| {
+                let items = generate_handle(gen, handle, name)?;
+                gen.with_item_cfg(name, items)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         Ok(quote! { #(#handles)*})
@@ -81,9 +84,11 @@ fn generate_handle<'a>(
         .borrow()
         .iter()
         .map(|alias| {
-            let doc_tag = make_doc_link(&format!("Vk{alias}"));
+            let alias_vk_name = format!("Vk{alias}");
+            let doc_tag = make_doc_link(&alias_vk_name);
+            let alias_cfg = gen.item_cfg(&alias_vk_name);
             let alias = format_ident!("{alias}");
-            quote! ( #doc_tag pub type #alias = #name; )
+            quote! ( #alias_cfg #doc_tag pub type #alias = #name; )
         })
         .collect::<Vec<_>>();
 
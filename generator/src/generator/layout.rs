@@ -0,0 +1,443 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Write,
+    fs, io,
+    process::{Command, Output},
+};
+
+use anyhow::{anyhow, Result};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::{
+    helpers::camel_case_to_snake_case,
+    structs::{Constant, Struct, StructBasetype, StructStandard, Type},
+    xml,
+};
+
+use super::{structs::pack_bitfields, Generator};
+
+/// The layouts are computed for 64-bit targets
+const POINTER_SIZE: u64 = 8;
+
+#[derive(Clone, Copy)]
+struct Layout {
+    size: u64,
+    align: u64,
+    /// The layout contains a C `unsigned long`, whose size differs between Windows and other 64-bit targets
+    has_c_long: bool,
+}
+
+impl Layout {
+    const fn scalar(size: u64) -> Self {
+        Self {
+            size,
+            align: size,
+            has_c_long: false,
+        }
+    }
+
+    const POINTER: Self = Self::scalar(POINTER_SIZE);
+}
+
+/// Layout of a structure or union and the offsets of its members
+struct StructLayout<'a> {
+    layout: Layout,
+    /// (vk member name, offset in bytes, bit offset for bitfields)
+    members: Vec<(&'a str, u64, Option<u64>)>,
+}
+
+fn align_up(value: u64, align: u64) -> u64 {
+    value.div_ceil(align) * align
+}
+
+/// Compute the layout of the types following the C rules
+struct LayoutComputer<'a, 'b> {
+    gen: &'b Generator<'a>,
+    types: HashMap<&'a str, &'a xml::Type>,
+    cache: RefCell<HashMap<&'a str, Option<Layout>>>,
+}
+
+impl<'a, 'b> LayoutComputer<'a, 'b> {
+    fn new(gen: &'b Generator<'a>) -> Self {
+        let types = gen
+            .all_types()
+            .chain(gen.video_registry.types.iter().flat_map(|tys| &tys.types))
+            .filter_map(|ty| {
+                let name = ty.name_attr.as_deref().or_else(|| {
+                    ty.content.iter().find_map(|cnt| match cnt {
+                        xml::TypeContent::Name(name) => Some(name.as_str()),
+                        _ => None,
+                    })
+                })?;
+                Some((name, ty))
+            })
+            .collect();
+        Self {
+            gen,
+            types,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Layout of the given C type, None if it comes from an external header with an unknown layout
+    fn type_layout(&self, name: &'a str) -> Result<Option<Layout>> {
+        if let Some(layout) = self.cache.borrow().get(name) {
+            return Ok(*layout);
+        }
+        let layout = self.compute_type_layout(name)?;
+        self.cache.borrow_mut().insert(name, layout);
+        Ok(layout)
+    }
+
+    fn compute_type_layout(&self, name: &'a str) -> Result<Option<Layout>> {
+        let layout = match name {
+            "char" | "int8_t" | "uint8_t" => Layout::scalar(1),
+            "int16_t" | "uint16_t" => Layout::scalar(2),
+            "int" | "int32_t" | "uint32_t" | "float" => Layout::scalar(4),
+            "int64_t" | "uint64_t" | "double" | "size_t" => Layout::scalar(8),
+            // Types from external headers used by value
+            "Window" | "VisualID" | "RROutput" => Layout {
+                has_c_long: true,
+                ..Layout::scalar(8)
+            },
+            "xcb_window_t" | "xcb_visualid_t" | "DWORD" | "zx_handle_t" | "GgpStreamDescriptor" => {
+                Layout::scalar(4)
+            }
+            "GgpFrameToken" => Layout::scalar(8),
+            "HANDLE" | "HINSTANCE" | "HWND" | "HMONITOR" | "LPCWSTR" => Layout::POINTER,
+            _ if self.gen.handles.contains_key(name) => Layout::POINTER,
+            _ => match self.gen.structs.get(name) {
+                Some(Struct::Standard(my_struct)) => {
+                    return Ok(self.struct_layout(my_struct)?.map(|layout| layout.layout))
+                }
+                Some(Struct::BaseType(StructBasetype { ty, .. })) => match ty {
+                    Type::Path(ty) => return self.type_layout(ty),
+                    Type::VoidPtr | Type::Ptr(_) => Layout::POINTER,
+                    _ => return Ok(None),
+                },
+                None => return self.registry_type_layout(name),
+            },
+        };
+        Ok(Some(layout))
+    }
+
+    /// Layout of the enums, bitmasks, function pointers and aliases
+    fn registry_type_layout(&self, name: &'a str) -> Result<Option<Layout>> {
+        let Some(ty) = self.types.get(name) else {
+            return Ok(None);
+        };
+        if let Some(alias) = &ty.alias {
+            return self.type_layout(alias);
+        }
+        let layout = match ty.category.as_deref() {
+            Some("enum") => match self.gen.enums.get(name) {
+                Some(my_enum) if my_enum.width_is_64 => Layout::scalar(8),
+                _ => Layout::scalar(4),
+            },
+            Some("bitmask") => {
+                // typedef VkFlags VkAccessFlags;
+                let base = ty
+                    .content
+                    .iter()
+                    .find_map(|cnt| match cnt {
+                        xml::TypeContent::Type(base) => Some(base.as_str()),
+                        _ => None,
+                    })
+                    .ok_or_else(|| anyhow!("Failed to find the type of bitmask {name}"))?;
+                return self.type_layout(base);
+            }
+            Some("funcpointer") => Layout::POINTER,
+            _ => return Ok(None),
+        };
+        Ok(Some(layout))
+    }
+
+    fn constant_value(&self, name: &str) -> Result<u64> {
+        match self.gen.constants.get(name) {
+            Some(Constant::Aliased { alias, .. }) => self.constant_value(alias),
+            Some(Constant::Field { value, .. }) => match value.strip_prefix("0x") {
+                Some(hex) => Ok(u64::from_str_radix(hex, 16)?),
+                None => Ok(value.parse()?),
+            },
+            None => Err(anyhow!("Failed to find constant {name}")),
+        }
+    }
+
+    /// Layout of a member and its number of elements
+    fn member_layout(&self, ty: &Type<'a>) -> Result<Option<(Layout, u64)>> {
+        let (ty, count) = match ty {
+            Type::VoidPtr | Type::Ptr(_) | Type::DoublePtr(_) | Type::CStrArr => {
+                return Ok(Some((Layout::POINTER, 1)))
+            }
+            Type::Void => return Err(anyhow!("Unexpected void member")),
+            Type::Path(ty) | Type::Bitfield { ty, .. } => (ty, 1),
+            Type::ArrayEnum { ty, size } => (ty, self.constant_value(size)?),
+            Type::ArrayCst { ty, size } => (ty, *size as u64),
+            Type::ArrayDoubleCst { ty, size1, size2 } => (ty, *size1 as u64 * *size2 as u64),
+            Type::ArrayDoubleEnum { ty, size1, size2 } => (
+                ty,
+                self.constant_value(size1)? * self.constant_value(size2)?,
+            ),
+        };
+        Ok(self.type_layout(ty)?.map(|layout| (layout, count)))
+    }
+
+    fn struct_layout(&self, my_struct: &StructStandard<'a>) -> Result<Option<StructLayout<'a>>> {
+        let mut members = Vec::with_capacity(my_struct.fields.len());
+        let mut layout = Layout {
+            size: 0,
+            align: 1,
+            has_c_long: false,
+        };
+        // position in bits, to handle bitfields
+        let mut position = 0u64;
+        for field in &my_struct.fields {
+            let Some((field_layout, count)) = self.member_layout(&field.ty)? else {
+                return Ok(None);
+            };
+            layout.align = layout.align.max(field_layout.align);
+            layout.has_c_long |= field_layout.has_c_long;
+
+            if my_struct.is_union {
+                members.push((field.vk_name, 0, None));
+                layout.size = layout.size.max(field_layout.size * count);
+                continue;
+            }
+
+            if let Type::Bitfield { bitsize, .. } = field.ty {
+                // a bitfield never straddles the storage units of its type
+                let unit_bits = field_layout.size * 8;
+                let bitsize = bitsize as u64;
+                if position / unit_bits != (position + bitsize - 1) / unit_bits {
+                    position = align_up(position, unit_bits);
+                }
+                members.push((field.vk_name, position / 8, Some(position % 8)));
+                position += bitsize;
+            } else {
+                let offset = align_up(position.div_ceil(8), field_layout.align);
+                members.push((field.vk_name, offset, None));
+                position = (offset + field_layout.size * count) * 8;
+            }
+        }
+        if !my_struct.is_union {
+            layout.size = position.div_ceil(8);
+        }
+        layout.size = align_up(layout.size, layout.align);
+
+        Ok(Some(StructLayout { layout, members }))
+    }
+}
+
+/// Structures and unions generated in vk::structs and vk::video, in order
+fn generated_structs<'a, 'b>(gen: &'b Generator<'a>) -> Vec<(&'a str, &'b StructStandard<'a>)> {
+    let mut listed = HashSet::new();
+    gen.filtered_features()
+        .flat_map(|feature| &feature.require)
+        .chain(gen.filtered_extensions().flat_map(|ext| &ext.require))
+        .chain(
+            gen.video_registry
+                .extensions
+                .iter()
+                .flat_map(|exts| &exts.extension)
+                .flat_map(|header| &header.require),
+        )
+        .flat_map(|req| &req.content)
+        .filter_map(|cnt| match cnt {
+            xml::RequireContent::Type(xml::RequireType { name, .. }) => Some(name.as_str()),
+            _ => None,
+        })
+        .filter(|name| listed.insert(*name))
+        .filter_map(|name| match gen.structs.get(name) {
+            Some(Struct::Standard(my_struct)) => Some((name, my_struct)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generate the tests checking the size, alignment and field offsets of all structures and unions
+/// against the layout computed from the registry
+pub fn generate_tests(gen: &Generator) -> Result<String> {
+    let computer = LayoutComputer::new(gen);
+    let mut test_names = HashSet::new();
+
+    let tests = generated_structs(gen)
+        .into_iter()
+        .map(|(vk_name, my_struct)| -> Result<TokenStream> {
+            // structures using types of external headers we know nothing about are not checked
+            let Some(struct_layout) = computer.struct_layout(my_struct)? else {
+                return Ok(quote!());
+            };
+
+            let test_name = camel_case_to_snake_case(&my_struct.name);
+            if !test_names.insert(test_name.clone()) {
+                return Err(anyhow!("Two layout tests are named {test_name}"));
+            }
+            let test_name = format_ident!("{test_name}");
+            let name = format_ident!("{}", my_struct.name);
+            let lifetime = my_struct
+                .has_lifetime
+                .get()
+                .unwrap_or_default()
+                .then(|| quote!(<'static>));
+            let ty = quote!(#name #lifetime);
+
+            let size = Literal::u64_unsuffixed(struct_layout.layout.size);
+            let align = Literal::u64_unsuffixed(struct_layout.layout.align);
+
+            // offset_of! on union fields requires a more recent compiler, union_offset_of! is used instead
+            let packed_bitfields = pack_bitfields(my_struct);
+            let offset_of = if my_struct.is_union {
+                quote!(union_offset_of)
+            } else {
+                quote!(offset_of)
+            };
+            let offsets = my_struct
+                .fields
+                .iter()
+                .zip(&struct_layout.members)
+                .filter_map(|(field, (_, offset, bit_offset))| {
+                    let field_name = match (packed_bitfields.get(field.vk_name), bit_offset) {
+                        (Some(bitfield), _) if bitfield.offset == 0 => bitfield.unit_name.clone(),
+                        (Some(_), _) => return None,
+                        // bitfields filling whole bytes are stored as byte arrays
+                        (None, Some(0) | None) => format_ident!("{}", field.name),
+                        (None, Some(_)) => return None,
+                    };
+                    let offset = Literal::u64_unsuffixed(*offset);
+                    Some(quote!(assert_eq!(#offset_of!(#ty, #field_name), #offset);))
+                });
+
+            let windows_cfg = struct_layout
+                .layout
+                .has_c_long
+                .then(|| quote!(#[cfg(not(windows))]));
+            let items = quote! {
+                #[test]
+                #windows_cfg
+                fn #test_name() {
+                    assert_eq!(size_of::<#ty>(), #size);
+                    assert_eq!(align_of::<#ty>(), #align);
+                    #(#offsets)*
+                }
+            };
+            gen.with_item_cfg(vk_name, items)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // offset of a union field, only its address is computed and the uninitialized value is never read
+    let result = quote! {
+        use super::*;
+        use std::mem::{align_of, offset_of, size_of};
+
+        macro_rules! union_offset_of {
+            ($ty:ty, $field:ident) => {{
+                let value = std::mem::MaybeUninit::<$ty>::uninit();
+                let base = value.as_ptr();
+                let field = unsafe { std::ptr::addr_of!((*base).$field) };
+                field as usize - base as usize
+            }};
+        }
+
+        #(#tests)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Generate a C file asserting that the layouts computed from the registry match the ones of the Vulkan headers
+/// Only the structures of vulkan_core.h and vulkan_beta.h are checked, the platform headers need external ones
+pub fn generate_c_check(gen: &Generator) -> Result<String> {
+    let computer = LayoutComputer::new(gen);
+    let mut result = String::new();
+
+    writeln!(result, "#define VK_ENABLE_BETA_EXTENSIONS")?;
+    writeln!(result, "#include <stddef.h>")?;
+    writeln!(result, "#include <vulkan/vulkan_core.h>")?;
+    writeln!(result, "#include <vulkan/vulkan_beta.h>")?;
+    writeln!(result)?;
+
+    for (vk_name, my_struct) in generated_structs(gen) {
+        let is_platform = gen
+            .item_conditions
+            .get(vk_name)
+            .is_some_and(|conditions| conditions.iter().any(|cond| cond.platform.is_some()));
+        if is_platform {
+            continue;
+        }
+        let Some(struct_layout) = computer.struct_layout(my_struct)? else {
+            continue;
+        };
+        let Layout { size, align, .. } = struct_layout.layout;
+        writeln!(
+            result,
+            "_Static_assert(sizeof({vk_name}) == {size}, \"size of {vk_name}\");"
+        )?;
+        writeln!(
+            result,
+            "_Static_assert(_Alignof({vk_name}) == {align}, \"alignment of {vk_name}\");"
+        )?;
+        for (member, offset, bit_offset) in &struct_layout.members {
+            // offsetof cannot be used on bitfields
+            if bit_offset.is_none() {
+                writeln!(
+                    result,
+                    "_Static_assert(offsetof({vk_name}, {member}) == {offset}, \"offset of {vk_name}::{member}\");"
+                )?;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Compile the C layout check when a C compiler and the Vulkan headers matching the registry are available
+/// The include directory of the headers can be given with the VULKAN_HEADERS_INCLUDE_DIR environment variable
+pub fn check_with_c_compiler(gen: &Generator) -> Result<()> {
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let include_dir = env::var_os("VULKAN_HEADERS_INCLUDE_DIR");
+    let compile = |file_name: &str, source: &str| -> Result<Option<Output>> {
+        let path = env::temp_dir().join(file_name);
+        fs::write(&path, source)?;
+        let mut command = Command::new(&compiler);
+        command.args(["-std=c11", "-fsyntax-only"]);
+        if let Some(include_dir) = &include_dir {
+            command.arg("-I").arg(include_dir);
+        }
+        match command.arg(&path).output() {
+            Ok(output) => Ok(Some(output)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    };
+
+    // first make sure the headers are there and were generated from the same registry
+    let probe = format!(
+        "#include <vulkan/vulkan_core.h>\n_Static_assert(VK_HEADER_VERSION == {}, \"header version\");\n",
+        gen.header_version()?
+    );
+    match compile("vulkanite_layout_probe.c", &probe)? {
+        None => {
+            eprintln!("No C compiler found, skipping the layout check against the C headers");
+            return Ok(());
+        }
+        Some(output) if !output.status.success() => {
+            eprintln!("No Vulkan headers matching vk.xml found, skipping the layout check against the C headers");
+            return Ok(());
+        }
+        Some(_) => {}
+    }
+
+    let output = compile("vulkanite_layout.c", &generate_c_check(gen)?)?
+        .ok_or_else(|| anyhow!("The C compiler {compiler} disappeared"))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "The layouts computed from vk.xml do not match the C headers:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,344 @@
+use super::Generator;
+use crate::{
+    structs::{AdvancedType, Struct, StructStandard},
+    xml,
+};
+use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+
+/// How a limit of a structure is compared, deduced from its limittype
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LimitKind {
+    /// Larger values are better
+    Max,
+    /// Smaller values are better
+    Min,
+    /// All the bits of the required value must be supported
+    Bitmask,
+    /// The supported range must contain the required range
+    Range,
+    /// The value must match exactly
+    Exact,
+    /// Structure with limits of its own
+    Struct,
+}
+
+impl LimitKind {
+    fn from_limit_types(limit_types: &[xml::LimitType], ty: &AdvancedType) -> Option<Self> {
+        use xml::LimitType as LT;
+        let has = |limit_type: LT| limit_types.contains(&limit_type);
+        if limit_types.is_empty() || has(LT::Noauto) {
+            None
+        } else if has(LT::Struct) {
+            Some(Self::Struct)
+        } else if has(LT::Range) {
+            Some(Self::Range)
+        } else if has(LT::Exact) {
+            // a boolean limit is only required when it is set to VK_TRUE
+            Some(match ty {
+                AdvancedType::Bool32 => Self::Max,
+                _ => Self::Exact,
+            })
+        } else if has(LT::Not) {
+            // a VK_TRUE value is less supportive than VK_FALSE
+            Some(Self::Min)
+        } else if has(LT::Bitmask) {
+            // a VK_TRUE value is more supportive than VK_FALSE
+            Some(match ty {
+                AdvancedType::Bool32 => Self::Max,
+                _ => Self::Bitmask,
+            })
+        } else if has(LT::Max) || has(LT::Bits) {
+            Some(Self::Max)
+        } else if has(LT::Min) || has(LT::Pot) || has(LT::Mul) {
+            // alignments and granularities are only given as pot or mul
+            Some(Self::Min)
+        } else {
+            None
+        }
+    }
+}
+
+/// Comparison of a single limit, against `required` in `satisfies_limits` and `other` in `merge_limits`
+struct LimitCode {
+    satisfies: TokenStream,
+    merge: TokenStream,
+}
+
+pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
+    let mut listed_structs = HashSet::new();
+    let mut impls = Vec::new();
+
+    let requires = gen
+        .filtered_features()
+        .flat_map(|feature| &feature.require)
+        .chain(gen.filtered_extensions().flat_map(|ext| &ext.require));
+    for require in requires {
+        for item in &require.content {
+            let xml::RequireContent::Type(xml::RequireType { name, .. }) = item else {
+                continue;
+            };
+            let Some(Struct::Standard(my_struct)) = gen.structs.get(name.as_str()) else {
+                continue;
+            };
+            if !has_limits(my_struct) || !listed_structs.insert(name.as_str()) {
+                continue;
+            }
+
+            let struct_ident = format_ident!("{}", my_struct.name);
+            let lifetime = gen.compute_name_lifetime(name).then(|| quote!(<'_>));
+            let (satisfies, merge): (Vec<_>, Vec<_>) = my_struct
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    let ty = gen.compute_advanced_type(&field.ty);
+                    let kind = LimitKind::from_limit_types(&field.xml.limit_type, &ty)?;
+                    let field_ident = format_ident!("{}", field.name);
+                    limit_code(gen, quote!(#field_ident), &ty, kind)
+                })
+                .map(|code| (code.satisfies, code.merge))
+                .unzip();
+            let satisfies = if satisfies.is_empty() {
+                quote!(true)
+            } else {
+                quote!(#(#satisfies)&&*)
+            };
+            let mut least_restrictive = Vec::new();
+            for field in &my_struct.fields {
+                let ty = gen.compute_advanced_type(&field.ty);
+                let Some(kind) = LimitKind::from_limit_types(&field.xml.limit_type, &ty) else {
+                    continue;
+                };
+                if let Some(value) = least_restrictive_value(gen, &ty, kind)? {
+                    let field_ident = format_ident!("{}", field.name);
+                    least_restrictive.push(quote!(.#field_ident(#value)));
+                }
+            }
+            // use the setters as structures with a lifetime have a private phantom member
+            let least_restrictive = quote!(Self::default() #(#least_restrictive)*);
+
+            let items = quote! {
+                impl #struct_ident #lifetime {
+                    /// Return true if these limits satisfy the `required` ones: maximum limits must be at least
+                    /// the required value, minimum limits at most the required value, bitmasks must contain
+                    /// all the required bits and ranges must contain the required range.
+                    /// Boolean limits are only required when they are set to true.
+                    /// Members which are not limits (identifiers, UUIDs, ...) are ignored
+                    pub fn satisfies_limits(&self, required: &Self) -> bool {
+                        #satisfies
+                    }
+
+                    /// Restrict these limits to the ones also supported by `other`. Merging the limits of
+                    /// several devices this way gives limits satisfied by all of them.
+                    /// Limits which must match exactly keep their current value
+                    pub fn merge_limits(&mut self, other: &Self) {
+                        #(#merge)*
+                    }
+
+                    /// Limits satisfied by every device, to be used as the base of the `required` limits
+                    /// given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits
+                    /// (alignments, granularities, ...) are the largest possible value and ranges are empty.
+                    /// Limits which must match exactly and are not booleans keep their default value
+                    pub fn least_restrictive() -> Self {
+                        #least_restrictive
+                    }
+                }
+            };
+            impls.push(gen.with_item_cfg(name, items)?);
+        }
+    }
+
+    let result = quote! {
+        use super::*;
+
+        #(#impls)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Return true if the struct has at least one member with a limit which can be compared
+fn has_limits(my_struct: &StructStandard) -> bool {
+    my_struct.fields.iter().any(|field| {
+        !field.xml.limit_type.is_empty() && !field.xml.limit_type.contains(&xml::LimitType::Noauto)
+    })
+}
+
+/// Value of a limit in `least_restrictive`, or None if the default value is already satisfied by every device
+fn least_restrictive_value<'a>(
+    gen: &Generator<'a>,
+    ty: &AdvancedType<'a>,
+    kind: LimitKind,
+) -> Result<Option<TokenStream>> {
+    let value = match (ty, kind) {
+        (AdvancedType::Struct(name), LimitKind::Struct) => match gen.structs.get(name) {
+            Some(Struct::Standard(my_struct)) if has_limits(my_struct) => {
+                let struct_ident = gen.get_ident_name(name)?;
+                Some(quote!(#struct_ident::least_restrictive()))
+            }
+            _ => None,
+        },
+        (AdvancedType::Struct(name), _) => {
+            let Some(Struct::Standard(my_struct)) = gen.structs.get(name) else {
+                return Ok(None);
+            };
+            let mut fields = Vec::new();
+            for field in &my_struct.fields {
+                let field_ty = gen.compute_advanced_type(&field.ty);
+                if let Some(value) = least_restrictive_value(gen, &field_ty, kind)? {
+                    let field_ident = format_ident!("{}", field.name);
+                    fields.push(quote!(.#field_ident(#value)));
+                }
+            }
+            let struct_ident = gen.get_ident_name(name)?;
+            (!fields.is_empty()).then(|| quote!(#struct_ident::default() #(#fields)*))
+        }
+        (
+            AdvancedType::OtherArrayWithCst(elem, _) | AdvancedType::OtherArrayWithEnum(elem, _),
+            LimitKind::Range,
+        ) => {
+            // an empty range, contained in any other range
+            let elem = gen.get_ident_name(elem)?;
+            Some(quote!([#elem::MAX, #elem::MIN]))
+        }
+        (AdvancedType::OtherArrayWithCst(elem, size), LimitKind::Min) => {
+            let elem = gen.get_ident_name(elem)?;
+            Some(quote!([#elem::MAX; #size as _]))
+        }
+        (AdvancedType::OtherArrayWithEnum(elem, size), LimitKind::Min) => {
+            let elem = gen.get_ident_name(elem)?;
+            let size = gen.get_ident_name(size)?;
+            Some(quote!([#elem::MAX; #size as _]))
+        }
+        (AdvancedType::Bool32, LimitKind::Min) => Some(quote!(Bool32::True)),
+        (AdvancedType::Other(name), LimitKind::Min) => {
+            let name = gen.get_ident_name(name)?;
+            Some(quote!(#name::MAX))
+        }
+        // maximum limits and bitmasks are satisfied by 0
+        _ => None,
+    };
+    Ok(value)
+}
+
+/// Code comparing the limit at `path` (relative to `self`, `required` and `other`)
+fn limit_code<'a>(
+    gen: &Generator<'a>,
+    path: TokenStream,
+    ty: &AdvancedType<'a>,
+    kind: LimitKind,
+) -> Option<LimitCode> {
+    // compare `actual` with `expected` (the required limit or the one from the other struct),
+    // the merged value is written to `actual`
+    let scalar = |actual: TokenStream, expected: TokenStream| {
+        // Bool32 is not ordered, but VK_FALSE < VK_TRUE
+        let cmp_value = |value: &TokenStream| match ty {
+            AdvancedType::Bool32 => quote!((#value as u32)),
+            _ => value.clone(),
+        };
+        let (actual_cmp, expected_cmp) = (cmp_value(&actual), cmp_value(&expected));
+        match kind {
+            LimitKind::Max => LimitCode {
+                satisfies: quote!(#actual_cmp >= #expected_cmp),
+                merge: quote! {
+                    if #expected_cmp < #actual_cmp {
+                        #actual = #expected;
+                    }
+                },
+            },
+            LimitKind::Min => LimitCode {
+                satisfies: quote!(#actual_cmp <= #expected_cmp),
+                merge: quote! {
+                    if #expected_cmp > #actual_cmp {
+                        #actual = #expected;
+                    }
+                },
+            },
+            LimitKind::Bitmask => LimitCode {
+                satisfies: quote!(#actual & #expected == #expected),
+                merge: quote!(#actual &= #expected;),
+            },
+            LimitKind::Exact => LimitCode {
+                satisfies: quote!(#actual == #expected),
+                merge: quote!(),
+            },
+            LimitKind::Range | LimitKind::Struct => unreachable!(),
+        }
+    };
+
+    match (ty, kind) {
+        (AdvancedType::Struct(name), LimitKind::Struct) => {
+            match gen.structs.get(name) {
+                Some(Struct::Standard(my_struct)) if has_limits(my_struct) => {}
+                _ => return None,
+            }
+            Some(LimitCode {
+                satisfies: quote!(self.#path.satisfies_limits(&required.#path)),
+                merge: quote!(self.#path.merge_limits(&other.#path);),
+            })
+        }
+        // a structure without limits of its own (such as an extent) whose members are all compared the same way
+        (AdvancedType::Struct(name), _) => {
+            let Some(Struct::Standard(my_struct)) = gen.structs.get(name) else {
+                return None;
+            };
+            let (satisfies, merge): (Vec<_>, Vec<_>) = my_struct
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    let field_ty = gen.compute_advanced_type(&field.ty);
+                    let field_ident = format_ident!("{}", field.name);
+                    limit_code(gen, quote!(#path.#field_ident), &field_ty, kind)
+                })
+                .map(|code| (code.satisfies, code.merge))
+                .unzip();
+            (!satisfies.is_empty()).then(|| LimitCode {
+                satisfies: quote!(#(#satisfies)&&*),
+                merge: quote!(#(#merge)*),
+            })
+        }
+        (
+            AdvancedType::OtherArrayWithCst(..) | AdvancedType::OtherArrayWithEnum(..),
+            LimitKind::Range,
+        ) => {
+            // [lower bound, upper bound]
+            Some(LimitCode {
+                satisfies: quote!(self.#path[0] <= required.#path[0] && self.#path[1] >= required.#path[1]),
+                merge: quote! {
+                    if other.#path[0] > self.#path[0] {
+                        self.#path[0] = other.#path[0];
+                    }
+                    if other.#path[1] < self.#path[1] {
+                        self.#path[1] = other.#path[1];
+                    }
+                },
+            })
+        }
+        (AdvancedType::OtherArrayWithCst(..) | AdvancedType::OtherArrayWithEnum(..), _) => {
+            let satisfies = scalar(quote!(*actual), quote!(*required)).satisfies;
+            let merge_element = scalar(quote!(*actual), quote!(*other)).merge;
+            Some(LimitCode {
+                satisfies: quote! {
+                    self.#path
+                        .iter()
+                        .zip(&required.#path)
+                        .all(|(actual, required)| #satisfies)
+                },
+                merge: quote! {
+                    for (actual, other) in self.#path.iter_mut().zip(&other.#path) {
+                        #merge_element
+                    }
+                },
+            })
+        }
+        (_, LimitKind::Range | LimitKind::Struct) => None,
+        _ => {
+            let satisfies = scalar(quote!(self.#path), quote!(required.#path)).satisfies;
+            let merge = scalar(quote!(self.#path), quote!(other.#path)).merge;
+            Some(LimitCode { satisfies, merge })
+        }
+    }
+}
@@ -0,0 +1,1158 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    structs::{AdvancedType, Struct, StructBasetype, StructField, StructStandard, Type},
+    xml,
+};
+
+use super::{
+    structs::{array_setter_name, compute_field_layout, simple_setter_name, FieldLayout},
+    Generator,
+};
+
+pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
+    // all the generated structures, in the same order as in structs.rs
+    let mut listed_structs = HashSet::new();
+    let all_structs: Vec<_> = gen
+        .filtered_features()
+        .flat_map(|feature| &feature.require)
+        .chain(gen.filtered_extensions().flat_map(|ext| &ext.require))
+        .flat_map(|require| &require.content)
+        .filter_map(|item| match item {
+            xml::RequireContent::Type(xml::RequireType { name, .. }) => {
+                match gen.structs.get(name.as_str()) {
+                    Some(Struct::Standard(my_struct))
+                        if !my_struct.is_union && listed_structs.insert(name.as_str()) =>
+                    {
+                        Some((name.as_str(), my_struct))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+
+    let owned_structs = compute_owned_structs(gen, &all_structs);
+    // plain structures can be used as is in an owned structure chain
+    let is_plain_chain_struct = |vk_name: &str, my_struct: &StructStandard| {
+        my_struct.s_type.is_some()
+            && gen.is_plain_data_struct(vk_name)
+            && extends_owned_struct(gen, my_struct, &owned_structs)
+    };
+    let compared_structs =
+        compute_compared_structs(gen, &all_structs, &owned_structs, is_plain_chain_struct)?;
+
+    let mut nb_compared_structs = 0;
+    let mut chain_elements = Vec::new();
+    let structs = all_structs
+        .iter()
+        .map(|(vk_name, my_struct)| {
+            let name = format_ident!("{}", my_struct.name);
+            let mut items = if owned_structs.contains(my_struct.name.as_str()) {
+                if my_struct.s_type.is_some() {
+                    chain_elements.push((
+                        vk_name,
+                        my_struct,
+                        quote!(Box::new(#name::from_raw_unlinked(&*raw.cast()))),
+                    ));
+                }
+                generate_owned_struct(gen, my_struct, vk_name)?
+            } else if is_plain_chain_struct(vk_name, my_struct) {
+                chain_elements.push((
+                    vk_name,
+                    my_struct,
+                    quote!(Box::new(ptr::read(raw.cast::<vk::#name<'static>>()))),
+                ));
+                quote!(impl_owned_extendable!(#name);)
+            } else {
+                quote!()
+            };
+            if compared_structs.contains(my_struct.name.as_str()) {
+                nb_compared_structs += 1;
+                items.extend(generate_structural_eq(gen, vk_name, my_struct)?);
+            }
+            if items.is_empty() {
+                return Ok(quote!());
+            }
+            gen.with_item_cfg(vk_name, items)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if nb_compared_structs != compared_structs.len() {
+        return Err(anyhow!(
+            "Some of the structures used by owned structures are not generated"
+        ));
+    }
+
+    let chain_arms = chain_elements
+        .into_iter()
+        .map(|(vk_name, my_struct, value)| {
+            let s_type = my_struct.s_type.unwrap();
+            let s_type_value: TokenStream = gen
+                .mapping
+                .borrow()
+                .get(s_type)
+                .ok_or_else(|| anyhow!("Failed to find structure type for {s_type}"))?
+                .name
+                .parse()
+                .unwrap();
+            let cfg = gen.item_cfg(vk_name);
+            Ok(quote!(#cfg #s_type_value => #value,))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = quote! {
+        use super::{
+            clone_handles, floats_eq, floats_hash, func_addr, option_eq, option_hash, raw_slice, slice_eq,
+            slice_hash, slice_ptr, union_bytes, ChainElement, OwnedChain, OwnedExtendable,
+            StructuralEq,
+        };
+        use crate::*;
+        use crate::vk::*;
+        use std::{hash::{Hash, Hasher}, mem, ptr};
+        // some of these types are only used by platform or vendor structures
+        #[allow(unused_imports)]
+        use std::ffi::{c_char, c_ulong, CStr, CString};
+
+        #(#structs)*
+
+        /// Deep copy of a structure found in a `p_next` chain, apart from its own chain
+        /// Returns None if the structure does not have an owned version
+        pub(super) unsafe fn clone_chain_element(header: &Header) -> Option<Box<dyn ChainElement>> {
+            let raw = ptr::from_ref(header);
+            let element: Box<dyn ChainElement> = match header.s_type {
+                #(#chain_arms)*
+                _ => return None,
+            };
+            Some(element)
+        }
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// How a member (or the content it points to) is stored in an owned structure
+#[derive(Clone, Copy)]
+enum Storage<'a> {
+    /// In the Vulkan structure
+    Value,
+    /// Pointer to a structure with an owned version, given with its Rust name
+    Owned(&'a str),
+    /// Structure with an owned version contained in the Vulkan structure
+    Inline(&'a str),
+    /// Pointer to plain data
+    Boxed(&'a str),
+    CString,
+    /// Array of structures with an owned version
+    OwnedArray(&'a str),
+    /// Array of plain data
+    Array(&'a str),
+    HandleArray(&'a str),
+    Bytes,
+    CStringArray,
+}
+
+impl<'a> Storage<'a> {
+    fn owned_struct(self) -> Option<&'a str> {
+        match self {
+            Storage::Owned(name) | Storage::Inline(name) | Storage::OwnedArray(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Members set with their own custom setter
+fn custom_array_field(struct_vk_name: &str, field_vk_name: &str) -> bool {
+    matches!(
+        (struct_vk_name, field_vk_name),
+        ("VkShaderModuleCreateInfo", "pCode")
+            | ("VkPipelineMultisampleStateCreateInfo", "pSampleMask")
+    )
+}
+
+/// Opaque platform objects (like ANativeWindow or wl_display) are not owned, the same way as handles
+fn is_platform_object(gen: &Generator, name: &str) -> bool {
+    matches!(
+        gen.structs.get(name),
+        Some(Struct::BaseType(StructBasetype { ty: Type::Void, .. }))
+    ) || gen
+        .mapping
+        .borrow()
+        .get(name)
+        .is_some_and(|entry| entry.name == "VoidPtr")
+}
+
+/// Returns how the given member is stored or None if it is not supported
+fn get_storage<'a, 'b>(
+    gen: &'b Generator<'a>,
+    field: &StructField<'a>,
+    is_array: bool,
+) -> Option<Storage<'b>> {
+    let ty = field.advanced_ty.get().unwrap();
+    type AT<'a> = AdvancedType<'a>;
+    let storage = match ty {
+        AT::OtherPtr(name) if gen.compute_name_lifetime(name) => {
+            // the video std headers are generated separately
+            if name.starts_with("StdVideo") {
+                return None;
+            }
+            let owned_name = gen.get_struct(name)?.name.as_str();
+            if is_array {
+                Storage::OwnedArray(owned_name)
+            } else {
+                Storage::Owned(owned_name)
+            }
+        }
+        AT::OtherPtr(name) if !is_array && is_platform_object(gen, name) => Storage::Value,
+        AT::OtherPtr(name) if is_array => Storage::Array(name),
+        AT::OtherPtr(name) => Storage::Boxed(name),
+        AT::CString if !is_array => Storage::CString,
+        AT::HandlePtr(name) if is_array => Storage::HandleArray(name),
+        AT::VoidPtr if is_array => Storage::Bytes,
+        AT::CStringPtr if is_array => Storage::CStringArray,
+        AT::HandlePtr(_)
+        | AT::OtherDoublePtr(_)
+        | AT::CString
+        | AT::CStringPtr
+        | AT::Bitfield(..)
+        | AT::HandleArray(..) => return None,
+        AT::Struct(name) if gen.compute_name_lifetime(name) => {
+            if is_array || name.starts_with("StdVideo") {
+                return None;
+            }
+            Storage::Inline(gen.get_struct(name)?.name.as_str())
+        }
+        _ if is_array => return None,
+        _ => Storage::Value,
+    };
+    Some(storage)
+}
+
+/// Storage of all the members of a structure apart from sType and pNext,
+/// returns None if one of them is not supported
+fn get_struct_storage<'a, 'b, 'c>(
+    gen: &'b Generator<'a>,
+    my_struct: &'c StructStandard<'a>,
+    struct_vk_name: &str,
+) -> Option<Vec<(&'c StructField<'a>, Option<Storage<'b>>)>> {
+    if my_struct.is_union || my_struct.return_only || struct_vk_name.starts_with("StdVideo") {
+        return None;
+    }
+    let FieldLayout {
+        simple_fields,
+        length_fields,
+        ..
+    } = compute_field_layout(my_struct, struct_vk_name).ok()?;
+    let array_fields: HashSet<_> = length_fields
+        .values()
+        .flat_map(|field_with_len| &field_with_len.array_fields)
+        .map(|field| field.vk_name)
+        .collect();
+
+    my_struct
+        .fields
+        .iter()
+        .filter(|field| {
+            my_struct.s_type.is_none() || (field.vk_name != "sType" && field.vk_name != "pNext")
+        })
+        .map(|field| {
+            let storage = if simple_fields.contains(field.vk_name) {
+                Some(get_storage(gen, field, false)?)
+            } else if array_fields.contains(field.vk_name)
+                || custom_array_field(struct_vk_name, field.vk_name)
+            {
+                Some(get_storage(gen, field, true)?)
+            } else if length_fields.contains_key(field.vk_name)
+                || matches!(
+                    (struct_vk_name, field.vk_name),
+                    ("VkShaderModuleCreateInfo", "codeSize")
+                        | (
+                            "VkPipelineMultisampleStateCreateInfo",
+                            "rasterizationSamples"
+                        )
+                )
+            {
+                // set alongside the arrays
+                None
+            } else {
+                // members without a setter
+                return None;
+            };
+            Some((field, storage))
+        })
+        .collect()
+}
+
+/// Rust names of the structures which get an owned version:
+/// the create infos, the structures they point to and the structures that can extend them
+fn compute_owned_structs<'a, 'b>(
+    gen: &'b Generator<'a>,
+    all_structs: &[(&'a str, &'b StructStandard<'a>)],
+) -> HashSet<&'b str> {
+    let standard_structs = || all_structs.iter().copied();
+
+    let mut owned_structs: HashSet<&str> = standard_structs()
+        .filter(|(_, my_struct)| {
+            my_struct.name.contains("CreateInfo") && my_struct.has_lifetime.get() == Some(true)
+        })
+        .map(|(_, my_struct)| my_struct.name.as_str())
+        .collect();
+
+    // add the structures pointed to by owned structures as well as their extensions
+    loop {
+        let mut new_structs = Vec::new();
+        for (vk_name, my_struct) in standard_structs() {
+            if owned_structs.contains(my_struct.name.as_str()) {
+                let pointed_structs = my_struct.fields.iter().filter_map(|field| {
+                    match field.advanced_ty.get().unwrap() {
+                        AdvancedType::OtherPtr(name) | AdvancedType::Struct(name)
+                            if gen.compute_name_lifetime(name) =>
+                        {
+                            gen.get_struct(name)
+                        }
+                        _ => None,
+                    }
+                });
+                new_structs.extend(pointed_structs.map(|pointed| pointed.name.as_str()));
+            } else if my_struct.s_type.is_some()
+                && !gen.is_plain_data_struct(vk_name)
+                && extends_owned_struct(gen, my_struct, &owned_structs)
+            {
+                new_structs.push(my_struct.name.as_str());
+            }
+        }
+        let nb_structs = owned_structs.len();
+        owned_structs.extend(new_structs);
+        if owned_structs.len() == nb_structs {
+            break;
+        }
+    }
+
+    // then remove the ones which are not supported, as well as the ones pointing to them
+    loop {
+        let unsupported: Vec<_> = standard_structs()
+            .filter(|(_, my_struct)| owned_structs.contains(my_struct.name.as_str()))
+            .filter(|(vk_name, my_struct)| {
+                get_struct_storage(gen, my_struct, vk_name).is_none_or(|fields| {
+                    fields
+                        .iter()
+                        .filter_map(|(_, storage)| storage.as_ref()?.owned_struct())
+                        .any(|name| !owned_structs.contains(name))
+                })
+            })
+            .map(|(_, my_struct)| my_struct.name.as_str())
+            .collect();
+        if unsupported.is_empty() {
+            break;
+        }
+        for name in unsupported {
+            owned_structs.remove(name);
+        }
+    }
+
+    owned_structs
+}
+
+fn extends_owned_struct(
+    gen: &Generator,
+    my_struct: &StructStandard,
+    owned_structs: &HashSet<&str>,
+) -> bool {
+    my_struct.extends.iter().any(|extended| {
+        gen.get_struct(extended)
+            .is_some_and(|extended| owned_structs.contains(extended.name.as_str()))
+    })
+}
+
+/// How two values of a Vulkan type are compared in an owned structure
+#[derive(Clone, Copy)]
+enum Comparison<'a, 'b> {
+    /// Using [Eq] and [Hash]
+    Eq,
+    /// Using the bit representation
+    Float,
+    /// Using the address of the function
+    Func,
+    /// Plain structure, using StructuralEq
+    Struct(&'b StructStandard<'a>),
+    /// Byte by byte
+    Union,
+}
+
+fn get_comparison<'a, 'b>(gen: &'b Generator<'a>, name: &str) -> Comparison<'a, 'b> {
+    match name {
+        "float" | "double" => Comparison::Float,
+        _ => match gen.get_struct(name) {
+            Some(my_struct) if my_struct.is_union => Comparison::Union,
+            Some(my_struct) => Comparison::Struct(my_struct),
+            None => Comparison::Eq,
+        },
+    }
+}
+
+/// Comparison of a member of a Vulkan structure and whether this member is an array
+fn field_comparison<'a, 'b>(
+    gen: &'b Generator<'a>,
+    field: &StructField<'a>,
+) -> Result<(Comparison<'a, 'b>, bool)> {
+    type AT<'a> = AdvancedType<'a>;
+    let result = match field.advanced_ty.get().unwrap() {
+        AT::Struct(name) | AT::Other(name) => (get_comparison(gen, name), false),
+        AT::OtherArrayWithEnum(name, _) | AT::OtherArrayWithCst(name, _) => {
+            (get_comparison(gen, name), true)
+        }
+        AT::OtherDoubleArray(name, ..) | AT::OtherDoubleArrayWithEnum(name, ..) => {
+            match get_comparison(gen, name) {
+                Comparison::Eq => (Comparison::Eq, false),
+                _ => return Err(anyhow!("Unsupported comparison of {}", field.vk_name)),
+            }
+        }
+        AT::Func(_) => (Comparison::Func, false),
+        AT::Bitfield(..) => return Err(anyhow!("Unsupported comparison of {}", field.vk_name)),
+        _ => (Comparison::Eq, false),
+    };
+    Ok(result)
+}
+
+/// Code comparing the values a and b and code hashing the value a into state
+fn compare_values(
+    comparison: Comparison,
+    is_array: bool,
+    a: TokenStream,
+    b: TokenStream,
+) -> (TokenStream, TokenStream) {
+    match (comparison, is_array) {
+        (Comparison::Eq, _) => (quote!(#a == #b), quote!(#a.hash(state);)),
+        (Comparison::Float, false) => (
+            quote!(#a.to_bits() == #b.to_bits()),
+            quote!(#a.to_bits().hash(state);),
+        ),
+        (Comparison::Func, _) => (
+            quote!(func_addr(#a) == func_addr(#b)),
+            quote!(func_addr(#a).hash(state);),
+        ),
+        (Comparison::Float, true) => (
+            quote!(floats_eq(&#a, &#b)),
+            quote!(floats_hash(&#a, state);),
+        ),
+        (Comparison::Struct(_), false) => (
+            quote!(#a.structural_eq(&#b)),
+            quote!(#a.structural_hash(state);),
+        ),
+        (Comparison::Struct(_), true) => {
+            (quote!(slice_eq(&#a, &#b)), quote!(slice_hash(&#a, state);))
+        }
+        (Comparison::Union, _) => (
+            quote!(union_bytes(&#a) == union_bytes(&#b)),
+            quote!(union_bytes(&#a).hash(state);),
+        ),
+    }
+}
+
+/// Rust names of the plain structures which need a StructuralEq implementation:
+/// the ones found in owned structures or in their chains, as well as the ones they contain
+fn compute_compared_structs<'a, 'b>(
+    gen: &'b Generator<'a>,
+    all_structs: &[(&'a str, &'b StructStandard<'a>)],
+    owned_structs: &HashSet<&str>,
+    is_plain_chain_struct: impl Fn(&str, &StructStandard) -> bool,
+) -> Result<HashSet<&'b str>> {
+    let mut to_visit = Vec::new();
+    for (vk_name, my_struct) in all_structs {
+        if owned_structs.contains(my_struct.name.as_str()) {
+            let fields = get_struct_storage(gen, my_struct, vk_name).unwrap();
+            for (field, storage) in fields {
+                let comparison = match storage {
+                    None | Some(Storage::Value) => field_comparison(gen, field)?.0,
+                    Some(Storage::Boxed(name) | Storage::Array(name)) => get_comparison(gen, name),
+                    _ => continue,
+                };
+                if let Comparison::Struct(compared) = comparison {
+                    to_visit.push(compared);
+                }
+            }
+        } else if is_plain_chain_struct(vk_name, my_struct) {
+            to_visit.push(my_struct);
+        }
+    }
+
+    let mut compared_structs = HashSet::new();
+    while let Some(my_struct) = to_visit.pop() {
+        if !compared_structs.insert(my_struct.name.as_str()) {
+            continue;
+        }
+        for field in &my_struct.fields {
+            if let (Comparison::Struct(compared), _) = field_comparison(gen, field)? {
+                to_visit.push(compared);
+            }
+        }
+    }
+    Ok(compared_structs)
+}
+
+fn generate_structural_eq<'a>(
+    gen: &Generator<'a>,
+    struct_vk_name: &str,
+    my_struct: &StructStandard<'a>,
+) -> Result<TokenStream> {
+    let (eqs, hashes): (Vec<_>, Vec<_>) = my_struct
+        .fields
+        .iter()
+        .filter(|field| {
+            my_struct.s_type.is_none() || (field.vk_name != "sType" && field.vk_name != "pNext")
+        })
+        .map(|field| {
+            let name = format_ident!("{}", field.name);
+            let (comparison, is_array) = field_comparison(gen, field)?;
+            Ok(compare_values(
+                comparison,
+                is_array,
+                quote!(self.#name),
+                quote!(other.#name),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let name = format_ident!("{}", my_struct.name);
+    let lifetime = gen
+        .compute_name_lifetime(struct_vk_name)
+        .then(|| quote!(<'_>));
+    Ok(quote! {
+        impl StructuralEq for vk::#name #lifetime {
+            fn structural_eq(&self, other: &Self) -> bool {
+                #(#eqs)&&*
+            }
+
+            fn structural_hash<H: Hasher>(&self, state: &mut H) {
+                #(#hashes)*
+            }
+        }
+    })
+}
+
+fn generate_owned_struct<'a>(
+    gen: &Generator<'a>,
+    my_struct: &StructStandard<'a>,
+    struct_vk_name: &str,
+) -> Result<TokenStream> {
+    let FieldLayout { length_fields, .. } = compute_field_layout(my_struct, struct_vk_name)?;
+    let fields = get_struct_storage(gen, my_struct, struct_vk_name)
+        .ok_or_else(|| anyhow!("Owned version of {struct_vk_name} is not supported"))?;
+
+    let storage_ty = |storage: Storage| -> Result<Option<TokenStream>> {
+        let ident = |name: &str| format_ident!("{name}");
+        let result = match storage {
+            Storage::Value => return Ok(None),
+            Storage::Owned(name) => {
+                let name = ident(name);
+                quote!(Option<Box<#name>>)
+            }
+            Storage::Inline(name) => {
+                let name = ident(name);
+                quote!(#name)
+            }
+            Storage::Boxed(name) => {
+                let name = gen.get_ident_name(name)?;
+                quote!(Option<Box<#name>>)
+            }
+            Storage::CString => quote!(Option<CString>),
+            Storage::OwnedArray(name) => {
+                let name = ident(name);
+                quote!(Vec<#name>)
+            }
+            Storage::Array(name) => {
+                let name = gen.get_ident_name(name)?;
+                quote!(Vec<#name>)
+            }
+            Storage::HandleArray(name) => {
+                let name = gen.get_ident_name(name)?;
+                quote!(Vec<raw::#name>)
+            }
+            Storage::Bytes => quote!(Vec<u8>),
+            Storage::CStringArray => quote!(Vec<CString>),
+        };
+        Ok(Some(result))
+    };
+
+    // length of the arrays, as read from the Vulkan structure
+    let mut array_lens: HashMap<&str, TokenStream> = length_fields
+        .values()
+        .flat_map(|length_field| {
+            let length_name = format_ident!("{}", length_field.len_field.name);
+            length_field
+                .array_fields
+                .iter()
+                .map(move |field| (field.vk_name, quote!(value.#length_name as _)))
+        })
+        .collect();
+    match struct_vk_name {
+        "VkShaderModuleCreateInfo" => {
+            array_lens.insert("pCode", quote!(value.code_size / 4));
+        }
+        "VkPipelineMultisampleStateCreateInfo" => {
+            array_lens.insert(
+                "pSampleMask",
+                quote!(value.rasterization_samples.bits().div_ceil(32) as _),
+            );
+        }
+        _ => (),
+    }
+
+    let mut storage_decls = Vec::new();
+    let mut storage_clones = Vec::new();
+    let mut storage_from_raw = Vec::new();
+    let mut link_stmts = Vec::new();
+    let mut eqs = Vec::new();
+    let mut hashes = Vec::new();
+    for (field, storage) in &fields {
+        let name = format_ident!("{}", field.name);
+        let (eq, hash) = match storage {
+            None | Some(Storage::Value) => {
+                let (comparison, is_array) = field_comparison(gen, field)?;
+                compare_values(
+                    comparison,
+                    is_array,
+                    quote!(self.raw.#name),
+                    quote!(other.raw.#name),
+                )
+            }
+            Some(Storage::Boxed(ty)) => match get_comparison(gen, ty) {
+                Comparison::Eq => compare_values(
+                    Comparison::Eq,
+                    false,
+                    quote!(self.#name),
+                    quote!(other.#name),
+                ),
+                Comparison::Struct(_) => (
+                    quote!(option_eq(self.#name.as_deref(), other.#name.as_deref())),
+                    quote!(option_hash(self.#name.as_deref(), state);),
+                ),
+                _ => {
+                    return Err(anyhow!(
+                        "Unsupported comparison of {struct_vk_name}::{}",
+                        field.vk_name
+                    ))
+                }
+            },
+            Some(Storage::Array(ty)) => compare_values(
+                get_comparison(gen, ty),
+                true,
+                quote!(self.#name),
+                quote!(other.#name),
+            ),
+            Some(_) => compare_values(
+                Comparison::Eq,
+                false,
+                quote!(self.#name),
+                quote!(other.#name),
+            ),
+        };
+        eqs.push(eq);
+        hashes.push(hash);
+
+        let Some(storage) = *storage else { continue };
+        let Some(ty) = storage_ty(storage)? else {
+            continue;
+        };
+        let raw_name = format_ident!("{}_raw", field.name);
+        storage_decls.push(quote!(#name: #ty,));
+        storage_clones.push(match storage {
+            Storage::HandleArray(_) => quote!(#name: clone_handles(&self.#name),),
+            _ => quote!(#name: self.#name.clone(),),
+        });
+        let len = || {
+            array_lens
+                .get(field.vk_name)
+                .ok_or_else(|| anyhow!("Unknown length for {struct_vk_name}::{}", field.vk_name))
+        };
+        storage_from_raw.push(match storage {
+            Storage::Value => unreachable!(),
+            Storage::Owned(child) => {
+                let child = format_ident!("{child}");
+                quote!(#name: unsafe { value.#name.as_ref() }.map(|v| Box::new(#child::from(v))),)
+            }
+            Storage::Inline(child) => {
+                let child = format_ident!("{child}");
+                quote!(#name: #child::from(&value.#name),)
+            }
+            Storage::Boxed(_) => {
+                quote!(#name: unsafe { value.#name.as_ref() }.copied().map(Box::new),)
+            }
+            Storage::CString => quote! {
+                #name: unsafe { value.#name.as_ref().map(|v| CStr::from_ptr(v).to_owned()) },
+            },
+            Storage::OwnedArray(child) => {
+                let child = format_ident!("{child}");
+                let len = len()?;
+                quote! {
+                    #name: unsafe { raw_slice(value.#name, #len) }.iter().map(#child::from).collect(),
+                    #raw_name: Vec::new(),
+                }
+            }
+            Storage::Array(_) => {
+                let len = len()?;
+                quote!(#name: unsafe { raw_slice(value.#name, #len) }.to_vec(),)
+            }
+            Storage::HandleArray(_) => {
+                let len = len()?;
+                quote!(#name: clone_handles(unsafe { raw_slice(value.#name, #len) }),)
+            }
+            Storage::Bytes => {
+                let len = len()?;
+                quote!(#name: unsafe { raw_slice(value.#name.cast::<u8>(), #len) }.to_vec(),)
+            }
+            Storage::CStringArray => {
+                let len = len()?;
+                quote! {
+                    #name: unsafe {
+                        raw_slice(value.#name, #len).iter().map(|v| CStr::from_ptr(*v).to_owned()).collect()
+                    },
+                    #raw_name: Vec::new(),
+                }
+            }
+        });
+        match storage {
+            Storage::OwnedArray(child) => {
+                let child = format_ident!("{child}");
+                storage_decls.push(quote!(#raw_name: Vec<vk::#child<'static>>,));
+                storage_clones.push(quote!(#raw_name: Vec::new(),));
+            }
+            Storage::CStringArray => {
+                storage_decls.push(quote!(#raw_name: Vec<*const c_char>,));
+                storage_clones.push(quote!(#raw_name: Vec::new(),));
+            }
+            _ => (),
+        }
+        link_stmts.push(match storage {
+            Storage::Value => unreachable!(),
+            Storage::Owned(_) => quote! {
+                self.raw.#name = self.#name.as_deref().map_or(ptr::null(), |v| ptr::from_ref(&v.raw));
+            },
+            // the content of the structure is stored on the heap and does not move with it
+            Storage::Inline(_) => quote! {
+                self.raw.#name = unsafe { ptr::read(&self.#name.raw) };
+            },
+            Storage::Boxed(_) => quote! {
+                self.raw.#name = self.#name.as_deref().map_or(ptr::null(), ptr::from_ref);
+            },
+            Storage::CString => quote! {
+                self.raw.#name = self.#name.as_deref().map_or(ptr::null(), CStr::as_ptr);
+            },
+            Storage::OwnedArray(_) => quote! {
+                self.#raw_name = self.#name.iter().map(|v| unsafe { ptr::read(&v.raw) }).collect();
+                self.raw.#name = slice_ptr(&self.#raw_name);
+            },
+            Storage::CStringArray => quote! {
+                self.#raw_name = self.#name.iter().map(|v| v.as_ptr()).collect();
+                self.raw.#name = slice_ptr(&self.#raw_name);
+            },
+            Storage::Array(_) | Storage::HandleArray(_) | Storage::Bytes => quote! {
+                self.raw.#name = slice_ptr(&self.#name);
+            },
+        });
+    }
+
+    let simple_setters = fields
+        .iter()
+        .filter_map(|(field, storage)| Some((field, (*storage)?)))
+        .filter(|(field, _)| {
+            !length_fields.values().any(|group| {
+                group
+                    .array_fields
+                    .iter()
+                    .any(|arr| arr.vk_name == field.vk_name)
+            })
+        })
+        .filter(|(field, _)| !custom_array_field(struct_vk_name, field.vk_name))
+        .map(|(field, storage)| {
+            let name = format_ident!("{}", field.name);
+            let fn_name = format_ident!("{}", simple_setter_name(field));
+            let optional = field.optional;
+            let (value_ty, body) = match storage {
+                Storage::Value => {
+                    let ty = field.advanced_ty.get().unwrap();
+                    let value_ty = gen.generate_type_outer(&ty, optional, false)?;
+                    let value = match ty {
+                        AdvancedType::OtherPtr(_) if optional => {
+                            quote!(value.map_or(ptr::null(), ptr::from_ref))
+                        }
+                        _ => {
+                            gen.generate_type_outer_to_inner(&ty, optional, format_ident!("value"))?
+                        }
+                    };
+                    return Ok(quote! {
+                        #[inline]
+                        pub fn #fn_name(mut self, value: #value_ty) -> Self {
+                            self.raw.#name = #value;
+                            self
+                        }
+                    });
+                }
+                Storage::Inline(child) => {
+                    let child = format_ident!("{child}");
+                    return Ok(quote! {
+                        #[inline]
+                        pub fn #fn_name(mut self, value: #child) -> Self {
+                            self.#name = value;
+                            self.link();
+                            self
+                        }
+                    });
+                }
+                Storage::Owned(child) => {
+                    let child = format_ident!("{child}");
+                    (quote!(#child), quote!(Box::new))
+                }
+                Storage::Boxed(ty) => {
+                    let ty = gen.get_ident_name(ty)?;
+                    (quote!(#ty), quote!(Box::new))
+                }
+                Storage::CString => (quote!(&CStr), quote!(CStr::to_owned)),
+                _ => {
+                    return Err(anyhow!(
+                        "Unexpected storage for {struct_vk_name}::{}",
+                        field.vk_name
+                    ))
+                }
+            };
+            let (value_ty, value) = if optional {
+                (quote!(Option<#value_ty>), quote!(value.map(#body)))
+            } else {
+                (value_ty, quote!(Some(#body(value))))
+            };
+            Ok(quote! {
+                #[inline]
+                pub fn #fn_name(mut self, value: #value_ty) -> Self {
+                    self.#name = #value;
+                    self.link();
+                    self
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let custom_setters = match struct_vk_name {
+        "VkShaderModuleCreateInfo" => quote! {
+            #[inline]
+            pub fn code(mut self, value: &[u32]) -> Self {
+                self.p_code = value.to_vec();
+                self.raw.code_size = value.len() * 4;
+                self.link();
+                self
+            }
+        },
+        "VkPipelineMultisampleStateCreateInfo" => quote! {
+            #[inline]
+            pub fn rasterization_samples_with_mask(mut self, samples: SampleCountFlags, mask: Option<&[u32]>) -> Self {
+                let count = samples.bits();
+                assert!(count.is_power_of_two());
+                assert!(mask.map_or(true, |arr| arr.len() as u32 == count.div_ceil(32)));
+                self.raw.rasterization_samples = samples;
+                self.p_sample_mask = mask.map(<[u32]>::to_vec).unwrap_or_default();
+                self.link();
+                self
+            }
+        },
+        _ => quote!(),
+    };
+
+    let array_setters = my_struct
+        .fields
+        .iter()
+        .filter_map(|field| length_fields.get(field.vk_name))
+        .map(|length_field| {
+            let setter_name = format_ident!("{}", array_setter_name(length_field)?);
+            let length_name = format_ident!("{}", length_field.len_field.name);
+            let can_be_optional = length_field.array_fields.len() > 1;
+            let is_optional = |field: &StructField| can_be_optional && field.optional;
+
+            let mut template_args = Vec::new();
+            let mut params = Vec::new();
+            let mut affectations = Vec::new();
+            // slices are only borrowed for the duration of the call
+            let mut borrows_slice = false;
+            for (idx, field) in length_field.array_fields.iter().enumerate() {
+                let name = format_ident!("{}", field.name);
+                let storage = get_storage(gen, field, true).ok_or_else(|| {
+                    anyhow!("Unsupported array {struct_vk_name}::{}", field.vk_name)
+                })?;
+                let to_storage = |v: TokenStream| match storage {
+                    Storage::OwnedArray(_) => quote!(#v.into_iter().collect()),
+                    Storage::Array(_) | Storage::Bytes => quote!(#v.as_slice().to_vec()),
+                    Storage::HandleArray(_) => quote!(clone_handles(#v.as_slice())),
+                    _ => quote!(#v.into_iter().map(|s| s.as_ref().to_owned()).collect()),
+                };
+                borrows_slice |= matches!(
+                    storage,
+                    Storage::Array(_) | Storage::HandleArray(_) | Storage::Bytes
+                );
+                let param_ty = match storage {
+                    Storage::OwnedArray(child) => {
+                        let child = format_ident!("{child}");
+                        quote!(impl IntoIterator<Item = #child>)
+                    }
+                    Storage::Array(ty) => {
+                        let ty = gen.get_ident_name(ty)?;
+                        quote!(impl AsSlice<'b, #ty>)
+                    }
+                    Storage::HandleArray(handle) => {
+                        let handle = gen.get_ident_name(handle)?;
+                        let template_ty = format_ident!("V{idx}");
+                        template_args.push(quote!(#template_ty: Alias<raw::#handle> + 'b));
+                        quote!(impl AsSlice<'b, #template_ty>)
+                    }
+                    Storage::Bytes => quote!(impl AsSlice<'b, u8>),
+                    Storage::CStringArray => quote!(impl IntoIterator<Item = impl AsRef<CStr>>),
+                    _ => {
+                        return Err(anyhow!(
+                            "Unexpected storage for {struct_vk_name}::{}",
+                            field.vk_name
+                        ))
+                    }
+                };
+                if is_optional(field) {
+                    let value = to_storage(quote!(v));
+                    params.push(quote!(#name: Option<#param_ty>));
+                    affectations
+                        .push(quote!(self.#name = #name.map(|v| #value).unwrap_or_default();));
+                } else {
+                    let value = to_storage(quote!(#name));
+                    params.push(quote!(#name: #param_ty));
+                    affectations.push(quote!(self.#name = #value;));
+                }
+            }
+
+            if borrows_slice {
+                template_args.insert(0, quote!('b));
+            }
+
+            let field_names: Vec<_> = length_field
+                .array_fields
+                .iter()
+                .map(|field| format_ident!("{}", field.name))
+                .collect();
+            let (len_checks, len_value) = if field_names.len() == 1 {
+                let name = &field_names[0];
+                (None, quote!(self.#name.len()))
+            } else {
+                // all the arrays sharing the same length must have the same size
+                let (len, checks) = match length_field
+                    .array_fields
+                    .iter()
+                    .position(|field| !is_optional(field))
+                {
+                    Some(idx) => {
+                        let used_field = &field_names[idx];
+                        (quote!(self.#used_field.len()), Some(idx))
+                    }
+                    None => {
+                        let first_field = &field_names[0];
+                        let other_fields = &field_names[1..];
+                        (
+                            quote!(self.#first_field.len() #(.max(self.#other_fields.len()))*),
+                            None,
+                        )
+                    }
+                };
+                let len_checks = length_field
+                    .array_fields
+                    .iter()
+                    .zip(&field_names)
+                    .enumerate()
+                    .filter(|(idx, _)| Some(*idx) != checks)
+                    .map(|(_, (field, name))| {
+                        if is_optional(field) {
+                            quote!(assert!(self.#name.is_empty() || self.#name.len() == len);)
+                        } else {
+                            quote!(assert!(self.#name.len() == len);)
+                        }
+                    });
+                (Some(quote!(let len = #len; #(#len_checks)*)), quote!(len))
+            };
+
+            Ok(quote! {
+                #[inline]
+                pub fn #setter_name<#(#template_args),*>(mut self, #(#params),*) -> Self {
+                    #(#affectations)*
+                    #len_checks
+                    self.raw.#length_name = #len_value as _;
+                    self.link();
+                    self
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let name = format_ident!("{}", my_struct.name);
+    let doc = format!("Owned version of [vk::{name}]");
+
+    let aliases = my_struct
+        .aliases
+        .borrow()
+        .iter()
+        .map(|alias| gen.get_ident_name(alias))
+        .collect::<Result<Vec<_>>>()?;
+    let alias_cfgs = my_struct
+        .aliases
+        .borrow()
+        .iter()
+        .map(|alias| gen.item_cfg(alias))
+        .collect::<Vec<_>>();
+
+    let has_chain = my_struct.s_type.is_some();
+    let chain_decl = has_chain.then(|| quote!(p_next: OwnedChain,));
+    let chain_clone = has_chain.then(|| quote!(p_next: self.p_next.clone(),));
+    let chain_default = has_chain.then(|| quote!(p_next: OwnedChain::default(),));
+    let chain_link = has_chain.then(|| quote!(self.p_next.link(&self.raw.p_next);));
+    if has_chain {
+        eqs.push(quote!(self.p_next == other.p_next));
+        hashes.push(quote!(self.p_next.hash(state);));
+    }
+    let from_raw_body = quote! {
+        let mut result = Self {
+            raw: unsafe { ptr::read(ptr::from_ref(value).cast()) },
+            #chain_default
+            #(#storage_from_raw)*
+        };
+        result.link();
+        result
+    };
+    let from_doc = if has_chain {
+        quote! {
+            /// Deep copy of a Vulkan structure
+            ///
+            /// # Panics
+            /// If a structure of the `p_next` chain does not have an owned version
+        }
+    } else {
+        quote!(#[doc = "Deep copy of a Vulkan structure"])
+    };
+    let (from_raw_unlinked, from_raw) = if has_chain {
+        (
+            Some(quote! {
+                /// Deep copy of a Vulkan structure, apart from its `p_next` chain
+                fn from_raw_unlinked(value: &vk::#name<'_>) -> Self {
+                    #from_raw_body
+                }
+            }),
+            quote! {
+                let mut result = Self::from_raw_unlinked(value);
+                result.p_next = unsafe { OwnedChain::from_raw(value.p_next.get()) };
+                result.link();
+                result
+            },
+        )
+    } else {
+        (None, from_raw_body)
+    };
+    let (extendable_impl, push_next) = if has_chain {
+        (
+            Some(quote! {
+                unsafe impl OwnedExtendable for #name {
+                    type Raw = vk::#name<'static>;
+
+                    fn raw_header(&self) -> &Header {
+                        unsafe { &*self.raw.header() }
+                    }
+
+                    fn clone_unlinked(&self) -> Self {
+                        self.clone()
+                    }
+
+                    fn take_next(&mut self) -> OwnedChain {
+                        let chain = mem::take(&mut self.p_next);
+                        self.link();
+                        chain
+                    }
+                }
+            }),
+            Some(quote! {
+                #[inline]
+                pub fn push_next<T: OwnedExtendable>(mut self, ext: T) -> Self
+                where
+                    T::Raw: ExtendingStructure<vk::#name<'static>>,
+                {
+                    self.p_next.push(ext);
+                    self.link();
+                    self
+                }
+            }),
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Default)]
+        pub struct #name {
+            raw: vk::#name<'static>,
+            #chain_decl
+            #(#storage_decls)*
+        }
+        unsafe impl Send for #name {}
+        unsafe impl Sync for #name {}
+        #extendable_impl
+
+        impl Clone for #name {
+            fn clone(&self) -> Self {
+                let mut result = Self {
+                    raw: unsafe { ptr::read(&self.raw) },
+                    #chain_clone
+                    #(#storage_clones)*
+                };
+                result.link();
+                result
+            }
+        }
+
+        #from_doc
+        impl From<&vk::#name<'_>> for #name {
+            fn from(value: &vk::#name<'_>) -> Self {
+                #from_raw
+            }
+        }
+
+        impl PartialEq for #name {
+            fn eq(&self, other: &Self) -> bool {
+                #(#eqs)&&*
+            }
+        }
+        impl Eq for #name {}
+
+        impl Hash for #name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                #(#hashes)*
+            }
+        }
+
+        impl #name {
+            #[inline]
+            pub fn as_raw(&self) -> vk::#name<'_> {
+                unsafe { ptr::read(&self.raw) }
+            }
+
+            #from_raw_unlinked
+
+            /// Point the Vulkan structure to the content owned by this structure
+            fn link(&mut self) {
+                #chain_link
+                #(#link_stmts)*
+            }
+
+            #(#simple_setters)*
+            #custom_setters
+            #(#array_setters)*
+            #push_next
+        }
+
+        #(#alias_cfgs pub type #aliases = #name;)*
+    })
+}
@@ -3,11 +3,12 @@ use std::{cell::RefCell, collections::HashSet, iter};
 use anyhow::{anyhow, Result};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use syn::Ident;
 
 use crate::{
     generator::make_doc_link,
     helpers::convert_len_case,
-    structs::{CommandParamsParsed, ReturnType, Type},
+    structs::{CommandParam, CommandParamsParsed, ReturnType, Type},
     xml,
 };
 
@@ -35,7 +36,16 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
                                     .map(|(vk_name, name)| (*vk_name, name.as_str())),
                             )
                             .map(|(vk_name, name)| {
-                                generate_raw_command(gen, &cmd_params, vk_name, name)
+                                let mut raw_cmd =
+                                    generate_raw_command(gen, &cmd_params, vk_name, name, false)?;
+                                raw_cmd.extend(generate_raw_command(
+                                    gen,
+                                    &cmd_params,
+                                    vk_name,
+                                    name,
+                                    true,
+                                )?);
+                                gen.with_item_cfg(vk_name, raw_cmd)
                             })
                             .collect::<Result<Vec<_>>>()?;
                         Ok(quote! (#(#raw_cmds)*))
@@ -62,7 +72,9 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
         #![allow(unused_unsafe)]
         #![allow(unused_mut)]
 
-        use std::ffi::{c_int, CStr};
+        // c_ulong is only used by platform commands
+        #[allow(unused_imports)]
+        use std::ffi::{c_int, c_ulong, CStr};
 
         use crate::*;
         use crate::vk::*;
@@ -75,11 +87,14 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
     Generator::format_result(result)
 }
 
+/// When into_chain is true, generate the variant of the command filling a structure chain given by the caller
+/// instead of returning it, or nothing if the command does not output a structure chain
 fn generate_raw_command<'a, 'b>(
     gen: &'b Generator<'a>,
     parsed_cmd: &CommandParamsParsed<'a, 'b>,
     vk_name: &str,
     name: &str,
+    into_chain: bool,
 ) -> Result<TokenStream> {
     let CommandParamsParsed {
         output_length,
@@ -93,13 +108,22 @@ fn generate_raw_command<'a, 'b>(
         ..
     } = parsed_cmd;
 
-    if output_fields.len() > 1 {
-        // TODO
+    if gen.unsupported_command_reason(parsed_cmd).is_some() {
         return Ok(quote!());
     }
 
     assert!(output_fields.is_empty() || !matches!(cmd.return_ty, ReturnType::BaseType(_)));
 
+    let outputs = parse_output_fields(gen, parsed_cmd, name)?;
+    let chain_output = if into_chain {
+        match chain_output(&outputs) {
+            Some(output) => Some(output),
+            None => return Ok(quote!()),
+        }
+    } else {
+        None
+    };
+
     let args_inner = cmd
         .params
         .iter()
@@ -127,26 +151,23 @@ fn generate_raw_command<'a, 'b>(
                     param.optional,
                 )?;
                 Ok(slice_ty.affectation)
-            } else if output_fields
+            } else if let Some(output) = outputs
                 .iter()
-                .any(|(vk_name, _)| *vk_name == param.vk_name)
+                .find(|output| output.vk_name == param.vk_name)
             {
-                let param_name = match param.ty {
-                    Type::Ptr(name) => name,
-                    Type::DoublePtr("void") => "VoidPtr",
-                    _ => return Err(anyhow!("Param {} should be a pointer", param.vk_name)),
-                };
-                let is_structure_type = gen
-                    .get_struct(param_name)
-                    .is_some_and(|my_struct| my_struct.s_type.is_some());
-                if output_length.is_some() {
-                    Ok(quote! (#name))
-                } else if param.xml.len.is_some() {
-                    Ok(quote! (#name.get_content_mut_ptr()))
-                } else if is_structure_type {
-                    Ok(quote! (S::get_uninit_head_ptr(#name.as_mut_ptr())))
+                let field_name = &output.field_name;
+                if chain_output.is_some() {
+                    Ok(quote! (ptr::from_mut(#field_name.as_mut())))
+                } else if output.is_external && matches!(param.ty, Type::VoidPtr) {
+                    Ok(quote! (#field_name as VoidPtr))
+                } else if output.is_external {
+                    Ok(quote! (#field_name))
+                } else if output.is_vec() {
+                    Ok(quote! (#field_name.get_content_mut_ptr()))
+                } else if let Some(template) = &output.template {
+                    Ok(quote! (#template::get_uninit_head_ptr(#field_name.as_mut_ptr())))
                 } else {
-                    Ok(quote! (#name.as_mut_ptr()))
+                    Ok(quote! (#field_name.as_mut_ptr()))
                 }
             } else if output_length.is_some_and(|len| len.vk_name == param.vk_name) {
                 Ok(quote! (#name))
@@ -174,126 +195,140 @@ fn generate_raw_command<'a, 'b>(
                 (quote! (-> #ty_name), None, None, None, None)
             }
         }
-        ReturnType::Result { nb_successes, .. } if output_fields.is_empty() => (
-            if nb_successes > 1 {
-                quote! (-> Result<Status>)
-            } else {
-                quote! (-> Result<()>)
-            },
-            None,
-            if nb_successes > 1 {
-                Some(quote! (.into_result()))
-            } else {
-                Some(quote! (.map_success(|| ())))
-            },
-            None,
-            None,
-        ),
+        ReturnType::Result { nb_successes, .. }
+            if output_fields.is_empty() || chain_output.is_some() =>
+        {
+            (
+                if nb_successes > 1 {
+                    quote! (-> Result<Status>)
+                } else {
+                    quote! (-> Result<()>)
+                },
+                None,
+                if nb_successes > 1 {
+                    Some(quote! (.into_result()))
+                } else {
+                    Some(quote! (.map_success(|| ())))
+                },
+                None,
+                None,
+            )
+        }
+        _ if chain_output.is_some() => (quote!(), None, None, None, None),
         _ if !output_fields.is_empty() => {
             let has_status = matches!(cmd.return_ty, ReturnType::Result { .. });
             let has_many_successes = matches!(cmd.return_ty, ReturnType::Result { nb_successes, .. } if nb_successes > 1);
-            let (_, field) = output_fields[0];
-            let ret_type = match field.ty {
-                Type::Ptr(name) => name,
-                Type::DoublePtr("void") => "VoidPtr",
-                _ => return Err(anyhow!("Could not use return field for {name}")),
+            let external_length = output_length.map(|param| format_ident!("{}", param.name));
+            // the arrays whose length is queried by a first call
+            let nb_external_vecs = outputs.iter().filter(|output| output.is_external).count();
+            let external_vec_name = |output: &OutputField| {
+                if nb_external_vecs > 1 {
+                    format_ident!("vk_vec_{}", output.field_name)
+                } else {
+                    format_ident!("vk_vec")
+                }
             };
-            let mut ret_name = gen.get_ident_name(ret_type)?;
-            let field_name = format_ident!("{}", field.name);
 
-            let external_length = output_length.map(|param| format_ident!("{}", param.name));
-            let internal_length = field
-                .xml
-                .altlen
-                .as_ref()
-                .or(field.xml.len.as_ref())
-                .filter(|_| external_length.is_none())
-                .map(|len| {
-                    if let Some(param) = length_mappings.get(len.as_str()) {
-                        let param_name = format_ident!("{}", param.name);
-                        Ok(quote! (#param_name.as_slice().len()))
-                    } else {
-                        convert_len_case(len).parse::<TokenStream>()
-                    }
-                })
-                .transpose()
-                .map_err(|_| anyhow!("Failed to parse length of {}", field.vk_name))?;
-
-            let is_vec = external_length.is_some() || internal_length.is_some();
-            let is_structure_type = gen
-                .get_struct(ret_type)
-                .is_some_and(|my_struct| my_struct.s_type.is_some());
-            let is_handle = gen.get_handle(ret_type).is_some();
-
-            let lifetime =
-                (!is_handle && gen.compute_name_lifetime(ret_type)).then(|| quote! (<'static>));
-
-            if !is_vec && ret_type == "VkBool32" {
-                assert!(has_status);
-                ret_name = format_ident!("bool");
+            let mut ret_templates = Vec::new();
+            let mut result_quotes = Vec::new();
+            let mut return_casts = Vec::new();
+            let mut param_inits = Vec::new();
+            for output in &outputs {
+                let OutputField {
+                    field_name,
+                    internal_length,
+                    is_structure_type,
+                    template,
+                    ..
+                } = output;
+                result_quotes.push(output.result_type());
+                ret_templates.push(output.template_param());
+                return_casts.push(if let Some(internal_length) = internal_length {
+                    quote! (#field_name.resize_with_len(#internal_length as _); #field_name)
+                } else if output.is_external {
+                    let vk_vec = external_vec_name(output);
+                    quote!(#vk_vec.resize_with_len(vk_len as _); #vk_vec)
+                } else if *is_structure_type {
+                    let template = template.as_ref().unwrap();
+                    quote! (#template::setup_cleanup(#field_name.as_mut_ptr());#field_name.assume_init())
+                } else if output.ret_type == "VkBool32" {
+                    quote! (#field_name.assume_init().into())
+                } else {
+                    quote! (#field_name.assume_init())
+                });
+                if let Some(internal_length) = internal_length {
+                    let template = template.as_ref().unwrap();
+                    param_inits.push(
+                        quote! (let mut #field_name = #template::create_with_capacity(#internal_length as _);),
+                    );
+                } else if output.is_external {
+                    // initialized along with the length
+                } else if *is_structure_type {
+                    let template = template.as_ref().unwrap();
+                    param_inits.push(
+                        quote! (let mut #field_name = MaybeUninit::uninit(); #template::setup_uninit(&mut #field_name);),
+                    );
+                } else {
+                    param_inits.push(quote! (let mut #field_name = MaybeUninit::uninit();));
+                }
             }
 
-            let mut result_quote = quote! (#ret_name #lifetime);
-            if is_vec {
-                result_quote = quote!(R)
-            } else if is_structure_type {
-                result_quote = quote!(S)
+            let external_outputs = outputs
+                .iter()
+                .filter(|output| output.is_external)
+                .collect::<Vec<_>>();
+            let external_fields = external_outputs
+                .iter()
+                .map(|output| &output.field_name)
+                .collect::<Vec<_>>();
+            let external_vecs = external_outputs
+                .iter()
+                .map(|output| external_vec_name(output))
+                .collect::<Vec<_>>();
+            let external_templates = external_outputs
+                .iter()
+                .map(|output| output.template.as_ref().unwrap())
+                .collect::<Vec<_>>();
+
+            let prev_affectation = has_status.then(|| quote! (let vk_status = ));
+            if let Some(external_length) = &external_length {
+                let first_call_args = args_inner.clone();
+                let map_success = has_status.then(|| quote! (.map_success(|| ())?));
+                param_inits.push(quote! {
+                    let mut vk_len = MaybeUninit::uninit();
+                    let #external_length = vk_len.as_mut_ptr();
+                    #(let #external_fields = ptr::null_mut();)*
+                    vulkan_command(#(#first_call_args),*)#map_success;
+                    let mut vk_len = vk_len.assume_init();
+                    #(let mut #external_vecs = #external_templates::create_with_capacity(vk_len as _);)*
+                    let mut #external_length = ptr::from_mut(&mut vk_len);
+                    #(let mut #external_fields = #external_vecs.get_content_mut_ptr();)*
+                });
             }
+            param_inits.push(quote!(#prev_affectation));
+
+            let mut result_quote = if result_quotes.len() > 1 {
+                quote! ((#(#result_quotes),*))
+            } else {
+                quote! (#(#result_quotes)*)
+            };
             if has_status {
                 if has_many_successes {
                     result_quote = quote! ((Status, #result_quote))
                 }
                 result_quote = quote! (Result<#result_quote>)
             }
-            let prev_affectation = has_status.then(|| quote! (let vk_status = ));
-            let return_cast = if let Some(internal_length) = &internal_length {
-                quote! (#field_name.resize_with_len(#internal_length as _); #field_name)
-            } else if external_length.is_some() {
-                quote!(vk_vec.resize_with_len(vk_len as _); vk_vec)
-            } else if is_structure_type {
-                quote! (S::setup_cleanup(#field_name.as_mut_ptr());#field_name.assume_init())
-            } else if ret_type == "VkBool32" {
-                quote! (#field_name.assume_init().into())
+            let return_cast = if return_casts.len() > 1 {
+                quote! ((#({#return_casts}),*))
             } else {
-                quote! (#field_name.assume_init())
+                quote! (#(#return_casts)*)
             };
             let return_result = match (has_status, has_many_successes) {
                 (true, true) => quote! (; vk_status.map_successes(|| {#return_cast})),
                 (true, false) => quote! (; vk_status.map_success(|| {#return_cast})),
                 _ => quote! (; #return_cast),
             };
-            let param_init = if let Some(internal_length) = &internal_length {
-                Some(
-                    quote! (let mut #field_name = R::create_with_capacity(#internal_length as _); #prev_affectation),
-                )
-            } else if let Some(external_length) = &external_length {
-                let first_call_args = args_inner.clone();
-                let map_success = has_status.then(|| quote! (.map_success(|| ())?));
-                Some(quote! {
-                    let mut vk_len = MaybeUninit::uninit();
-                    let #external_length = vk_len.as_mut_ptr();
-                    let #field_name = ptr::null_mut();
-                    vulkan_command(#(#first_call_args),*)#map_success;
-                    let mut vk_len = vk_len.assume_init();
-                    let mut vk_vec = R::create_with_capacity(vk_len as _);
-                    let mut #external_length = ptr::from_mut(&mut vk_len);
-                    let mut #field_name = vk_vec.get_content_mut_ptr();
-                    #prev_affectation
-                })
-            } else if is_structure_type {
-                Some(
-                    quote! (let mut #field_name = MaybeUninit::uninit(); S::setup_uninit(&mut #field_name); #prev_affectation),
-                )
-            } else {
-                Some(quote! (let mut #field_name = MaybeUninit::uninit(); #prev_affectation))
-            };
-            let ret_template = if internal_length.is_some() || external_length.is_some() {
-                Some(quote! (R: DynamicArray<#ret_name #lifetime>,))
-            } else if is_structure_type {
-                Some(quote! (S: StructureChainOut<#ret_name #lifetime> ))
-            } else {
-                None
-            };
+            let ret_template = quote! (#(#ret_templates)*);
             let inner_call = match (&cmd.return_ty, external_length) {
                 (ReturnType::Result { has_incomplete, .. }, Some(external_length))
                     if *has_incomplete =>
@@ -304,9 +339,9 @@ fn generate_raw_command<'a, 'b>(
                             if status != Status::Incomplete {
                                 break status;
                             }
-                            vk_vec.update_with_capacity(vk_len as _);
+                            #(#external_vecs.update_with_capacity(vk_len as _);)*
                             #external_length = ptr::from_mut(&mut vk_len);
-                            #field_name = vk_vec.get_content_mut_ptr();
+                            #(#external_fields = #external_vecs.get_content_mut_ptr();)*
                         }
                     })
                 }
@@ -314,9 +349,9 @@ fn generate_raw_command<'a, 'b>(
             };
             (
                 quote! (-> #result_quote),
-                Some(param_init),
+                Some(quote!(#(#param_inits)*)),
                 Some(return_result),
-                ret_template,
+                Some(ret_template),
                 inner_call,
             )
         }
@@ -326,11 +361,25 @@ fn generate_raw_command<'a, 'b>(
     let inner_call = inner_call.unwrap_or_else(|| quote! (vulkan_command(#(#args_inner),*)));
 
     let func_name = format_ident!("{name}");
-    let doc = make_doc_link(vk_name);
+    let mut doc = make_doc_link(vk_name);
     let lifetime = (!vec_fields.is_empty()).then(|| quote! ('a, ));
+    let (fn_name, chain_template, chain_arg) = match chain_output {
+        Some(output) => {
+            let (field_name, chain_template) = output.chain_param();
+            let description =
+                format!("Same as [{func_name}], filling the structure chain given by the caller");
+            doc = quote!(#[doc = #description] #doc);
+            (
+                format_ident!("{name}_into"),
+                Some(chain_template),
+                Some(quote!(#field_name: &mut S,)),
+            )
+        }
+        None => (func_name.clone(), None, None),
+    };
     Ok(quote! {
         #doc
-        pub unsafe fn #func_name<#lifetime #ret_template #(#templates),*>(#(#args_outer_name: #args_outer_type,)* dispatcher: &CommandsDispatcher ) #ret_type {
+        pub unsafe fn #fn_name<#lifetime #ret_template #chain_template #(#templates),*>(#(#args_outer_name: #args_outer_type,)* #chain_arg dispatcher: &CommandsDispatcher ) #ret_type {
             let vulkan_command = dispatcher.#func_name.get().expect("Vulkan command not loaded.");
             #pre_call
             #inner_call
@@ -338,3 +387,176 @@ fn generate_raw_command<'a, 'b>(
         }
     })
 }
+
+pub(super) struct OutputField<'a> {
+    vk_name: &'a str,
+    ret_type: &'a str,
+    ret_name: Ident,
+    lifetime: Option<TokenStream>,
+    field_name: Ident,
+    /// The length of the array, when it is known before calling the command
+    internal_length: Option<TokenStream>,
+    /// The field is an array whose length is queried by calling the command a first time
+    is_external: bool,
+    is_structure_type: bool,
+    /// R for arrays, S for structures with a structure chain
+    template: Option<Ident>,
+}
+
+impl OutputField<'_> {
+    fn is_vec(&self) -> bool {
+        self.is_external || self.internal_length.is_some()
+    }
+
+    /// R
+    pub(super) fn result_type(&self) -> TokenStream {
+        let OutputField {
+            ret_name, lifetime, ..
+        } = self;
+        match &self.template {
+            Some(template) => quote!(#template),
+            None => quote! (#ret_name #lifetime),
+        }
+    }
+
+    /// p_properties and S: StructureChain<PhysicalDeviceProperties2<'static>>,
+    /// for the variant of the command filling a structure chain given by the caller
+    pub(super) fn chain_param(&self) -> (&Ident, TokenStream) {
+        let OutputField {
+            ret_name,
+            lifetime,
+            field_name,
+            ..
+        } = self;
+        (field_name, quote! (S: StructureChain<#ret_name #lifetime>,))
+    }
+
+    /// R: DynamicArray<u64>,
+    pub(super) fn template_param(&self) -> Option<TokenStream> {
+        let OutputField {
+            ret_name, lifetime, ..
+        } = self;
+        let template = self.template.as_ref()?;
+        if self.is_vec() {
+            Some(quote! (#template: DynamicArray<#ret_name #lifetime>,))
+        } else {
+            Some(quote! (#template: StructureChainOut<#ret_name #lifetime>,))
+        }
+    }
+}
+
+/// The output of the command if it is a single structure chain, in which case
+/// a variant of the command filling a structure chain given by the caller is also generated
+pub(super) fn chain_output<'a, 'b>(outputs: &'b [OutputField<'a>]) -> Option<&'b OutputField<'a>> {
+    match outputs {
+        [output] if output.is_structure_type && !output.is_vec() => Some(output),
+        _ => None,
+    }
+}
+
+/// Parse the output fields of the command, giving a different template name to each array and structure chain
+pub(super) fn parse_output_fields<'a>(
+    gen: &Generator,
+    parsed_cmd: &CommandParamsParsed<'a, '_>,
+    name: &str,
+) -> Result<Vec<OutputField<'a>>> {
+    let mut outputs = parsed_cmd
+        .output_fields
+        .iter()
+        .map(|(_, field)| parse_output_field(gen, parsed_cmd, field, name))
+        .collect::<Result<Vec<_>>>()?;
+
+    // when multiple outputs share the same kind of template, each one gets a number
+    let nb_vecs = outputs.iter().filter(|output| output.is_vec()).count();
+    let nb_structures = outputs
+        .iter()
+        .filter(|output| !output.is_vec() && output.is_structure_type)
+        .count();
+    let (mut vec_idx, mut structure_idx) = (0, 0);
+    for output in &mut outputs {
+        output.template = if output.is_vec() {
+            vec_idx += 1;
+            Some(match nb_vecs {
+                1 => format_ident!("R"),
+                _ => format_ident!("R{vec_idx}"),
+            })
+        } else if output.is_structure_type {
+            structure_idx += 1;
+            Some(match nb_structures {
+                1 => format_ident!("S"),
+                _ => format_ident!("S{structure_idx}"),
+            })
+        } else {
+            None
+        };
+    }
+    Ok(outputs)
+}
+
+fn parse_output_field<'a>(
+    gen: &Generator,
+    parsed_cmd: &CommandParamsParsed<'a, '_>,
+    field: &CommandParam<'a>,
+    name: &str,
+) -> Result<OutputField<'a>> {
+    let CommandParamsParsed {
+        output_length,
+        length_mappings,
+        command: cmd,
+        ..
+    } = parsed_cmd;
+
+    let ret_type = match field.ty {
+        Type::Ptr(name) => name,
+        Type::DoublePtr("void") => "VoidPtr",
+        // opaque data is returned as bytes
+        Type::VoidPtr => "uint8_t",
+        _ => return Err(anyhow!("Could not use return field for {name}")),
+    };
+    let mut ret_name = gen.get_ident_name(ret_type)?;
+    let field_name = format_ident!("{}", field.name);
+
+    let is_external =
+        output_length.is_some_and(|len| field.xml.len.as_deref() == Some(len.vk_name));
+    let internal_length = field
+        .xml
+        .altlen
+        .as_ref()
+        .or(field.xml.len.as_ref())
+        .filter(|_| !is_external)
+        .map(|len| {
+            if let Some(param) = length_mappings.get(len.as_str()) {
+                let param_name = format_ident!("{}", param.name);
+                Ok(quote! (#param_name.as_slice().len()))
+            } else {
+                convert_len_case(len).parse::<TokenStream>()
+            }
+        })
+        .transpose()
+        .map_err(|_| anyhow!("Failed to parse length of {}", field.vk_name))?;
+
+    let is_vec = is_external || internal_length.is_some();
+    let is_structure_type = gen
+        .get_struct(ret_type)
+        .is_some_and(|my_struct| my_struct.s_type.is_some());
+    let is_handle = gen.get_handle(ret_type).is_some();
+
+    let lifetime = (!is_handle && gen.compute_name_lifetime(ret_type)).then(|| quote! (<'static>));
+
+    if !is_vec && ret_type == "VkBool32" {
+        assert!(matches!(cmd.return_ty, ReturnType::Result { .. }));
+        ret_name = format_ident!("bool");
+    }
+
+    Ok(OutputField {
+        vk_name: field.vk_name,
+        ret_type,
+        ret_name,
+        lifetime,
+        field_name,
+        internal_length,
+        is_external,
+        is_structure_type,
+        template: None,
+    })
+}
@@ -0,0 +1,117 @@
+use std::{cell::RefCell, collections::HashSet};
+
+use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{helpers::camel_case_to_snake_case, structs::Command, xml};
+
+use super::Generator;
+
+pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
+    let listed_commands = RefCell::new(HashSet::new());
+    let generate_group_infos = |require: &'a xml::Require| -> TokenStream {
+        let infos = require.content.iter().filter_map(|req| match req {
+            xml::RequireContent::Command(cmd) => gen
+                .commands
+                .get(cmd.name.as_str())
+                .filter(|cmd| cmd.vk_name.starts_with("vkCmd"))
+                .filter(|_| listed_commands.borrow_mut().insert(&cmd.name))
+                .map(|cmd| generate_command_info(gen, cmd)),
+            _ => None,
+        });
+
+        quote! (#(#infos)*)
+    };
+
+    let features = gen.filtered_features().flat_map(|feat| &feat.require);
+    let extensions = gen
+        .filtered_extensions()
+        .flat_map(|ext: &xml::Extension| &ext.require);
+
+    let infos = features.chain(extensions).map(generate_group_infos);
+
+    let result = quote! {
+        use super::{CommandInfo, RenderPassKind, RenderPassScope, StateChange};
+        use crate::vk::{PipelineBindPoint, QueueFlags};
+
+        #(#infos)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Name of the constant holding the requirements of the given command, for example CMD_DRAW for vkCmdDraw
+pub fn command_info_name(vk_name: &str) -> syn::Ident {
+    format_ident!(
+        "{}",
+        camel_case_to_snake_case(&vk_name["vk".len()..]).to_uppercase()
+    )
+}
+
+fn generate_command_info(gen: &Generator, cmd: &Command) -> TokenStream {
+    let vk_name = cmd.vk_name;
+    let const_name = command_info_name(vk_name);
+
+    let queues = cmd.xml.queues.iter().map(|queue| match queue {
+        xml::Queue::Graphics => quote!(Graphics),
+        xml::Queue::Compute => quote!(Compute),
+        xml::Queue::Transfer => quote!(Transfer),
+        xml::Queue::SparseBinding => quote!(SparseBinding),
+        xml::Queue::Opticalflow => quote!(OpticalFlowNV),
+        xml::Queue::Decode => quote!(VideoDecodeKHR),
+        xml::Queue::Encode => quote!(VideoEncodeKHR),
+    });
+    let levels = &cmd.xml.cmd_buffer_level;
+    let primary = levels.is_empty()
+        || levels
+            .iter()
+            .any(|level| matches!(level, xml::CmdBufferLevel::Primary));
+    let secondary = levels.is_empty()
+        || levels
+            .iter()
+            .any(|level| matches!(level, xml::CmdBufferLevel::Secondary));
+    let render_pass = match cmd.xml.renderpass.first() {
+        Some(xml::Renderpass::Inside) => quote!(Inside),
+        Some(xml::Renderpass::Outside) => quote!(Outside),
+        _ => quote!(Both),
+    };
+
+    let bind_point = if vk_name.starts_with("vkCmdDraw") {
+        quote!(Some(PipelineBindPoint::Graphics))
+    } else if vk_name.starts_with("vkCmdDispatch") && !vk_name.starts_with("vkCmdDispatchGraph") {
+        quote!(Some(PipelineBindPoint::Compute))
+    } else if vk_name.starts_with("vkCmdTraceRays") {
+        quote!(Some(PipelineBindPoint::RayTracingKHR))
+    } else {
+        quote!(None)
+    };
+
+    let state_change = match vk_name {
+        "vkCmdBeginRenderPass" | "vkCmdBeginRenderPass2" => {
+            quote!(BeginRenderPass(RenderPassKind::RenderPass))
+        }
+        "vkCmdEndRenderPass" | "vkCmdEndRenderPass2" => {
+            quote!(EndRenderPass(RenderPassKind::RenderPass))
+        }
+        "vkCmdBeginRendering" => quote!(BeginRenderPass(RenderPassKind::Rendering)),
+        "vkCmdEndRendering" => quote!(EndRenderPass(RenderPassKind::Rendering)),
+        "vkCmdBindShadersEXT" => quote!(BindShaders),
+        _ => quote!(None),
+    };
+
+    let item_cfg = gen.item_cfg(vk_name);
+    quote! {
+        #item_cfg
+        pub(crate) const #const_name: CommandInfo = CommandInfo {
+            name: #vk_name,
+            queues: QueueFlags::empty()#(.union(QueueFlags::#queues))*,
+            primary: #primary,
+            secondary: #secondary,
+            render_pass: RenderPassScope::#render_pass,
+            bind_point: #bind_point,
+            state_change: StateChange::#state_change,
+        };
+    }
+}
@@ -0,0 +1,482 @@
+use super::Generator;
+use crate::{
+    helpers::{camel_case_to_snake_case, screaming_snake_to_pascal_case},
+    xml,
+};
+use anyhow::{anyhow, Result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+
+/// A requirement of a SPIR-V capability or extension along with
+/// the cfg predicates which must be satisfied for it to be generated
+struct Requirement {
+    value: TokenStream,
+    conditions: Vec<TokenStream>,
+}
+
+pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
+    let mut image_format_names = Vec::new();
+    let mut image_format_idents = Vec::new();
+    let mut image_format_cfgs = Vec::new();
+    let mut image_format_formats = Vec::new();
+    for format in gen
+        .registry
+        .formats
+        .iter()
+        .flat_map(|formats| &formats.format)
+    {
+        let Some(spirv_format) = &format.spirv_format else {
+            continue;
+        };
+        if image_format_names.contains(&spirv_format.name.as_str()) {
+            continue;
+        }
+        image_format_names.push(spirv_format.name.as_str());
+        image_format_idents.push(format_ident!("{}", spirv_format.name));
+        image_format_cfgs.push(gen.enum_value_cfg(&format.name));
+        let format_name: TokenStream = gen.get_mapping_name(&format.name)?.parse().unwrap();
+        image_format_formats.push(format_name);
+    }
+
+    let device_extensions: HashSet<&str> = gen
+        .filtered_extensions()
+        .filter(|ext| matches!(ext.ty, Some(xml::ExtensionType::Device)))
+        .map(|ext| ext.name.as_str())
+        .collect();
+
+    let mut extension_names = Vec::new();
+    let mut extension_idents = Vec::new();
+    let mut extension_requirements = Vec::new();
+    for extension in gen
+        .registry
+        .spirvextensions
+        .iter()
+        .flat_map(|exts| &exts.extensions)
+    {
+        let ident_name = extension
+            .name
+            .strip_prefix("SPV_")
+            .ok_or_else(|| anyhow!("SPIR-V extension {} should start with SPV_", extension.name))?;
+        let ident = format_ident!(
+            "{}",
+            screaming_snake_to_pascal_case(&ident_name.to_ascii_uppercase())
+        );
+        let requirements = extension
+            .enable
+            .iter()
+            .map(|enable| match (&enable.version, &enable.extension) {
+                (Some(version), None) => version_requirement(version),
+                (None, Some(ext)) => extension_requirement(&device_extensions, ext),
+                _ => Err(anyhow!(
+                    "Unsupported enable for {}: {enable:?}",
+                    extension.name
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        extension_requirements.push(requirements_arm(&ident, requirements)?);
+        extension_names.push(extension.name.as_str());
+        extension_idents.push(ident);
+    }
+
+    let mut capability_names = Vec::new();
+    let mut capability_idents = Vec::new();
+    let mut capability_requirements = Vec::new();
+    for capability in gen
+        .registry
+        .spirvcapabilities
+        .iter()
+        .flat_map(|caps| &caps.capabilities)
+    {
+        let ident = format_ident!("{}", capability.name);
+        let requirements = capability
+            .enable
+            .iter()
+            .map(|enable| {
+                if let Some(version) = &enable.version {
+                    version_requirement(version)
+                } else if let Some(ext) = &enable.extension {
+                    extension_requirement(&device_extensions, ext)
+                } else if let (Some(structure), Some(feature)) =
+                    (&enable.structure, &enable.feature)
+                {
+                    feature_requirement(gen, structure, feature)
+                } else if let (Some(property), Some(member), Some(value)) =
+                    (&enable.property, &enable.member, &enable.value)
+                {
+                    property_requirement(gen, property, member, value)
+                } else {
+                    Err(anyhow!(
+                        "Unsupported enable for {}: {enable:?}",
+                        capability.name
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        capability_requirements.push(requirements_arm(&ident, requirements)?);
+        capability_names.push(capability.name.as_str());
+        capability_idents.push(ident);
+    }
+
+    let result = quote! {
+        use super::*;
+        use crate::ExtendableStructure;
+        use std::{mem::offset_of, ptr};
+
+        /// Image format of an OpTypeImage instruction, along with the matching [Format]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum SpirvImageFormat {
+            #(
+                #image_format_cfgs
+                #image_format_idents,
+            )*
+        }
+
+        impl SpirvImageFormat {
+            /// Name of this image format in the SPIR-V specification
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #(
+                        #image_format_cfgs
+                        Self::#image_format_idents => #image_format_names,
+                    )*
+                }
+            }
+
+            /// Return the image format with the given SPIR-V name
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(
+                        #image_format_cfgs
+                        #image_format_names => Some(Self::#image_format_idents),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Vulkan format matching this image format
+            pub const fn format(self) -> Format {
+                match self {
+                    #(
+                        #image_format_cfgs
+                        Self::#image_format_idents => #image_format_formats,
+                    )*
+                }
+            }
+        }
+
+        impl Format {
+            /// SPIR-V image format matching this format, if any
+            pub const fn spirv_image_format(self) -> Option<SpirvImageFormat> {
+                match self {
+                    #(
+                        #image_format_cfgs
+                        #image_format_formats => Some(SpirvImageFormat::#image_format_idents),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
+        /// Requirement allowing a shader module to use a SPIR-V capability or extension.
+        /// A capability or extension can be used if any of its requirements is satisfied
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum SpirvRequirement {
+            /// The device supports this Vulkan version
+            Version(ApiVersion),
+            /// This device extension is enabled
+            Extension(DeviceExtension),
+            /// This device feature is enabled
+            Feature(SpirvFeature),
+            /// This device property contains the given value
+            Property(SpirvProperty),
+        }
+
+        /// Boolean feature of a structure extending [PhysicalDeviceFeatures2]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct SpirvFeature {
+            /// Structure type of the structure containing this feature,
+            /// the features of [PhysicalDeviceFeatures] are part of [PhysicalDeviceFeatures2]
+            pub structure_type: StructureType,
+            /// Offset in bytes of the [Bool32] feature in this structure
+            pub offset: usize,
+            /// Name of the structure in the Vulkan specification
+            pub structure: &'static str,
+            /// Name of the feature in the Vulkan specification
+            pub feature: &'static str,
+        }
+
+        impl SpirvFeature {
+            /// Return whether this feature is enabled in the given structure
+            /// or None if this feature is not part of this structure
+            pub fn is_enabled_in<S: ExtendableStructure>(&self, structure: &S) -> Option<bool> {
+                if S::STRUCTURE_TYPE != self.structure_type {
+                    return None;
+                }
+                // SAFETY: the structure type identifies the structure, which contains a Bool32 at this offset
+                let value = unsafe {
+                    ptr::from_ref(structure)
+                        .cast::<u8>()
+                        .add(self.offset)
+                        .cast::<u32>()
+                        .read()
+                };
+                Some(value != 0)
+            }
+        }
+
+        /// 32-bit member of a structure extending [PhysicalDeviceProperties2]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct SpirvProperty {
+            /// Structure type of the structure containing this property
+            pub structure_type: StructureType,
+            /// Offset in bytes of the property in this structure
+            pub offset: usize,
+            /// Name of the structure in the Vulkan specification
+            pub structure: &'static str,
+            /// Name of the member in the Vulkan specification
+            pub member: &'static str,
+            /// Bits which must be set in the property, [TRUE] is represented as 1
+            pub value: u32,
+        }
+
+        impl SpirvProperty {
+            /// Return whether this property contains the required value in the given structure
+            /// or None if this property is not part of this structure
+            pub fn is_satisfied_by<S: ExtendableStructure>(&self, structure: &S) -> Option<bool> {
+                if S::STRUCTURE_TYPE != self.structure_type {
+                    return None;
+                }
+                // SAFETY: the structure type identifies the structure, which contains a 32-bit value at this offset
+                let value = unsafe {
+                    ptr::from_ref(structure)
+                        .cast::<u8>()
+                        .add(self.offset)
+                        .cast::<u32>()
+                        .read()
+                };
+                Some(value & self.value == self.value)
+            }
+        }
+
+        /// SPIR-V extension a shader module can declare with OpExtension
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum SpirvExtension {
+            #(
+                #[doc = #extension_names]
+                #extension_idents,
+            )*
+        }
+
+        impl SpirvExtension {
+            /// Name of this extension in the SPIR-V specification
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #(Self::#extension_idents => #extension_names,)*
+                }
+            }
+
+            /// Return the extension with the given SPIR-V name
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#extension_names => Some(Self::#extension_idents),)*
+                    _ => None,
+                }
+            }
+
+            /// Ways to allow a shader module to use this extension, only one of them needs to be satisfied
+            pub fn requirements(self) -> &'static [SpirvRequirement] {
+                match self {
+                    #(#extension_requirements)*
+                }
+            }
+        }
+
+        /// SPIR-V capability a shader module can declare with OpCapability
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum SpirvCapability {
+            #(#capability_idents,)*
+        }
+
+        impl SpirvCapability {
+            /// Name of this capability in the SPIR-V specification
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #(Self::#capability_idents => #capability_names,)*
+                }
+            }
+
+            /// Return the capability with the given SPIR-V name
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#capability_names => Some(Self::#capability_idents),)*
+                    _ => None,
+                }
+            }
+
+            /// Ways to allow a shader module to use this capability, only one of them needs to be satisfied
+            pub fn requirements(self) -> &'static [SpirvRequirement] {
+                match self {
+                    #(#capability_requirements)*
+                }
+            }
+        }
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Match arm returning the requirements of a capability or extension
+/// Requirements only present with some features enabled get an arm for each combination of these features
+fn requirements_arm(ident: &syn::Ident, requirements: Vec<Requirement>) -> Result<TokenStream> {
+    let mut conditions: Vec<(String, TokenStream)> = Vec::new();
+    for condition in requirements.iter().flat_map(|req| &req.conditions) {
+        let key = condition.to_string();
+        if !conditions.iter().any(|(other, _)| *other == key) {
+            conditions.push((key, condition.clone()));
+        }
+    }
+    if conditions.len() > 4 {
+        return Err(anyhow!(
+            "Too many cfg conditions for the requirements of {ident}"
+        ));
+    }
+
+    let arms = (0..(1u32 << conditions.len())).map(|enabled_mask| {
+        let is_enabled = |key: &str| {
+            let idx = conditions
+                .iter()
+                .position(|(other, _)| other == key)
+                .unwrap();
+            enabled_mask & (1 << idx) != 0
+        };
+        let values = requirements
+            .iter()
+            .filter(|req| {
+                req.conditions
+                    .iter()
+                    .all(|cond| is_enabled(&cond.to_string()))
+            })
+            .map(|req| &req.value);
+        let cfg = (!conditions.is_empty()).then(|| {
+            let predicates: Vec<_> = conditions
+                .iter()
+                .enumerate()
+                .map(|(idx, (_, cond))| {
+                    if enabled_mask & (1 << idx) != 0 {
+                        cond.clone()
+                    } else {
+                        quote!(not(#cond))
+                    }
+                })
+                .collect();
+            match predicates.as_slice() {
+                [predicate] => quote!(#[cfg(#predicate)]),
+                _ => quote!(#[cfg(all(#(#predicates),*))]),
+            }
+        });
+        quote! {
+            #cfg
+            Self::#ident => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[#(#values),*];
+                REQUIREMENTS
+            }
+        }
+    });
+    Ok(quote!(#(#arms)*))
+}
+
+fn version_requirement(version: &str) -> Result<Requirement> {
+    let (major, minor) = version
+        .strip_prefix("VK_VERSION_")
+        .and_then(|version| version.split_once('_'))
+        .and_then(|(major, minor)| Some((major.parse::<u32>().ok()?, minor.parse::<u32>().ok()?)))
+        .ok_or_else(|| anyhow!("Failed to parse version {version}"))?;
+    Ok(Requirement {
+        value: quote!(SpirvRequirement::Version(ApiVersion::new(0, #major, #minor, 0))),
+        conditions: Vec::new(),
+    })
+}
+
+fn extension_requirement(
+    device_extensions: &HashSet<&str>,
+    extension: &str,
+) -> Result<Requirement> {
+    if !device_extensions.contains(extension) {
+        return Err(anyhow!(
+            "SPIR-V requirement {extension} is not a supported device extension"
+        ));
+    }
+    let ext_ident = format_ident!("{}", extension["VK_".len()..].to_ascii_uppercase());
+    Ok(Requirement {
+        value: quote!(SpirvRequirement::Extension(#ext_ident)),
+        conditions: Vec::new(),
+    })
+}
+
+fn feature_requirement(gen: &Generator, structure: &str, feature: &str) -> Result<Requirement> {
+    let field = format_ident!("{}", camel_case_to_snake_case(feature));
+    // the core 1.0 features are only available as part of PhysicalDeviceFeatures2
+    let (structure_ident, offset) = if structure == "VkPhysicalDeviceFeatures" {
+        (
+            quote!(PhysicalDeviceFeatures2),
+            quote!(
+                offset_of!(PhysicalDeviceFeatures2, features)
+                    + offset_of!(PhysicalDeviceFeatures, #field)
+            ),
+        )
+    } else {
+        let structure_ident = struct_ident(structure);
+        (
+            quote!(#structure_ident),
+            quote!(offset_of!(#structure_ident, #field)),
+        )
+    };
+    Ok(Requirement {
+        value: quote! {
+            SpirvRequirement::Feature(SpirvFeature {
+                structure_type: #structure_ident::STRUCTURE_TYPE,
+                offset: #offset,
+                structure: #structure,
+                feature: #feature,
+            })
+        },
+        conditions: gen.item_cfg_condition(structure).into_iter().collect(),
+    })
+}
+
+fn property_requirement(
+    gen: &Generator,
+    structure: &str,
+    member: &str,
+    value: &str,
+) -> Result<Requirement> {
+    let structure_ident = struct_ident(structure);
+    let field = format_ident!("{}", camel_case_to_snake_case(member));
+    let mut conditions: Vec<TokenStream> = gen.item_cfg_condition(structure).into_iter().collect();
+    let value_expr = if value == "VK_TRUE" {
+        quote!(1)
+    } else {
+        // the mapping name of a flag contains its bitflags type
+        let value_name: TokenStream = gen.get_mapping_name(value)?.parse().unwrap();
+        conditions.extend(gen.enum_value_cfg_condition(value));
+        quote!(#value_name.bits())
+    };
+    Ok(Requirement {
+        value: quote! {
+            SpirvRequirement::Property(SpirvProperty {
+                structure_type: #structure_ident::STRUCTURE_TYPE,
+                offset: offset_of!(#structure_ident, #field),
+                structure: #structure,
+                member: #member,
+                value: #value_expr,
+            })
+        },
+        conditions,
+    })
+}
+
+fn struct_ident(name: &str) -> syn::Ident {
+    format_ident!("{}", name.strip_prefix("Vk").unwrap_or(name))
+}
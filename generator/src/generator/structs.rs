@@ -4,7 +4,7 @@ use std::{
 };
 
 use anyhow::{anyhow, Result};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::{
@@ -29,22 +29,27 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
                     .structs
                     .get(ty_name.as_str())
                     .filter(|_| listed_structs.borrow_mut().insert(&ty_name))
-                    .map(|my_struct| match my_struct {
-                        Struct::BaseType(StructBasetype {
-                            name,
-                            ty,
-                            has_lifetime,
-                        }) => {
-                            let advanced_type = gen.compute_advanced_type(ty);
-                            let ty = gen
-                                .generate_type_inner(&advanced_type, true)
-                                .expect("Failed to get type");
-                            let name = format_ident!("{name}");
-                            let doc_tag = make_doc_link(ty_name);
-                            let lifetime = has_lifetime.get().unwrap().then(|| quote! (<'a>));
-                            Ok(quote! (#doc_tag pub type #name #lifetime = #ty;))
-                        }
-                        Struct::Standard(my_struct) => generate_struct(gen, my_struct, ty_name),
+                    .map(|my_struct| -> Result<TokenStream> {
+                        let items = match my_struct {
+                            Struct::BaseType(StructBasetype {
+                                name,
+                                ty,
+                                has_lifetime,
+                            }) => {
+                                let advanced_type = gen.compute_advanced_type(ty);
+                                let ty = gen
+                                    .generate_type_inner(&advanced_type, true)
+                                    .expect("Failed to get type");
+                                let name = format_ident!("{name}");
+                                let doc_tag = make_doc_link(ty_name);
+                                let lifetime = has_lifetime.get().unwrap().then(|| quote! (<'a>));
+                                quote! (#doc_tag pub type #name #lifetime = #ty;)
+                            }
+                            Struct::Standard(my_struct) => {
+                                generate_struct(gen, my_struct, ty_name)?
+                            }
+                        };
+                        gen.with_item_cfg(ty_name, items)
                     }),
                 _ => None,
             })
@@ -68,7 +73,10 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
         use crate::*;
         use crate::vk::*;
         use crate::vk::raw::*;
-        use std::{array, ffi::{c_char, c_int, c_ulong, c_void}, marker::PhantomData, mem::ManuallyDrop, ptr, slice};
+        use std::{array, marker::PhantomData, mem::ManuallyDrop, ptr, slice};
+        // some of these types are only used by platform or vendor structures
+        #[allow(unused_imports)]
+        use std::ffi::{c_char, c_int, c_ulong, c_void};
 
         #(#struct_features)*
         #(#struct_extensions)*
@@ -79,103 +87,46 @@ pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
     Ok(formatted_result)
 }
 
-fn generate_struct<'a>(
+pub(super) fn generate_struct<'a>(
     gen: &Generator<'a>,
     my_struct: &StructStandard<'a>,
     struct_vk_name: &str,
 ) -> Result<TokenStream> {
     let mapping = gen.mapping.borrow();
-    let all_fields: HashMap<_, _> = my_struct.fields.iter().map(|f| (f.vk_name, f)).collect();
-    let mut simple_fields: HashSet<_> = all_fields.keys().cloned().collect();
-    let mut char_arr_fields = Vec::new();
-    if my_struct.s_type.is_some() {
-        // remove preemptively s_type and p_next
-        simple_fields.remove("sType");
-        simple_fields.remove("pNext");
-    }
-
-    struct FieldWithLen<'a, 'b> {
-        len_field: &'b StructField<'a>,
-        array_fields: Vec<&'b StructField<'a>>,
-    }
-    let mut length_fields = HashMap::new();
-
-    // retrieve all arrays with a len
-    for field in &my_struct.fields {
-        let mut len = match &field.xml.len {
-            Some(len) => len.as_str(),
-            _ => continue,
-        };
-
-        if len == "null-terminated" {
-            if let Some(AdvancedType::CharArray(size)) = field.advanced_ty.get() {
-                simple_fields.remove(field.vk_name);
-                char_arr_fields.push((field, size));
-            }
-            continue;
-        }
-
-        if len.ends_with(",null-terminated") {
-            // just ask for an array of const char* for the time being
-            len = &len[..(len.len() - ",null-terminated".len())]
-        }
-
-        if field.xml.alt_len.is_some() {
-            // handled with specific code (only concern 5 structs so far)
-            simple_fields.remove(field.vk_name);
-            continue;
-        }
-
-        if len.contains(',') {
-            // TODO: handle
-            len = len.split(',').next().unwrap();
-        }
-
-        let len_field = *all_fields
-            .get(len)
-            .ok_or_else(|| anyhow!("Failed to find length field {len} for {struct_vk_name}"))?;
-
-        simple_fields.remove(field.vk_name);
-        simple_fields.remove(len_field.vk_name);
-
-        length_fields
-            .entry(len)
-            .or_insert_with(|| FieldWithLen {
-                len_field,
-                array_fields: Vec::new(),
-            })
-            .array_fields
-            .push(field);
-    }
-
-    for (_, field_with_len) in &length_fields {
-        if field_with_len
-            .array_fields
-            .iter()
-            .all(|field| field.optional)
-        {
-            // if all slice fields are optional, we must give the option to set the length alone
-            // (for example descriptorCount in VkDescriptorSetLayoutBinding )
-            simple_fields.insert(field_with_len.len_field.vk_name);
-        }
-    }
+    let FieldLayout {
+        simple_fields,
+        length_fields,
+        char_arr_fields,
+        packed_bitfields,
+    } = compute_field_layout(my_struct, struct_vk_name)?;
 
-    // remove length parameter associated with altlen fields
-    match struct_vk_name {
-        "VkShaderModuleCreateInfo" => {
-            simple_fields.remove("codeSize");
-        }
-        "VkPipelineMultisampleStateCreateInfo" => {
-            simple_fields.remove("rasterizationSamples");
-        }
-        _ => (),
-    };
+    // structs with only plain data can be serialized, the pNext chain is skipped
+    let is_plain_data = gen.is_plain_data_struct(struct_vk_name);
+    let serde_skip = is_plain_data.then(|| quote!(#[cfg_attr(feature = "serde", serde(skip))]));
 
     let iter = my_struct
         .fields
         .iter()
+        // only the first bitfield of each storage unit is kept
+        .filter(|field| {
+            packed_bitfields
+                .get(field.vk_name)
+                .is_none_or(|bitfield| bitfield.offset == 0)
+        })
         .map(|field| {
             let field_name = format_ident!("{}", field.name);
+            let serde_attr = match field.vk_name {
+                "sType" | "pNext" if my_struct.s_type.is_some() => serde_skip.clone(),
+                // c_char arrays are (de)serialized as strings
+                _ if is_plain_data
+                    && matches!(field.advanced_ty.get(), Some(AdvancedType::CharArray(_))) =>
+                {
+                    Some(quote! {
+                        #[cfg_attr(feature = "serde", serde(with = "crate::vk::serde_helpers::char_array"))]
+                    })
+                }
+                _ => None,
+            };
             let (ty_name, vis, default_impl) = match field.vk_name {
                 "sType" if my_struct.s_type.is_some() => (
                     quote!(StructureType),
@@ -187,6 +138,16 @@ fn generate_struct<'a>(
                     quote! (pub(crate)),
                     quote! {p_next: Cell::new(ptr::null())},
                 ),
+                _ if packed_bitfields.contains_key(field.vk_name) => {
+                    let bitfield = &packed_bitfields[field.vk_name];
+                    let unit_name = &bitfield.unit_name;
+                    let unit_ty = gen.get_ident_name(bitfield.unit_ty)?;
+                    (
+                        quote!(#unit_ty),
+                        quote!(pub(crate)),
+                        quote!(#unit_name: Default::default()),
+                    )
+                }
                 _ => {
                     let default_value = if field.name.ends_with("queue_family_index")
                         && matches!(field.ty, Type::Path("uint32_t"))
@@ -212,11 +173,18 @@ fn generate_struct<'a>(
                     )
                 }
             };
-            Ok((quote! {#vis #field_name: #ty_name,}, default_impl))
+            let field_name = packed_bitfields
+                .get(field.vk_name)
+                .map_or(field_name, |bitfield| bitfield.unit_name.clone());
+            Ok((quote! {#serde_attr #vis #field_name: #ty_name,}, default_impl))
         })
         .collect::<Result<Vec<_>>>()?;
 
     let (fields, default_impl): (Vec<_>, Vec<_>) = iter.into_iter().unzip();
+    // Default can be derived if every member uses its own default value
+    let derivable_default = default_impl
+        .iter()
+        .all(|default| default.to_string().ends_with(": Default :: default ()"));
 
     let simple_accessors = my_struct
         .fields
@@ -224,14 +192,7 @@ fn generate_struct<'a>(
         .filter(|field| simple_fields.contains(field.vk_name))
         .map(|field| {
             let name = format_ident!("{}", field.name);
-            let fn_name = if field.name.starts_with("p_") {
-                &field.name[2..]
-            } else if field.name.starts_with("pp_") {
-                &field.name[3..]
-            } else {
-                &field.name
-            };
-            let fn_name = format_ident!("{fn_name}");
+            let fn_name = format_ident!("{}", simple_setter_name(field));
             let ty = field.advanced_ty.get().unwrap();
             let ty_name = gen.generate_type_outer(&ty, field.optional, true)?;
             let value =
@@ -246,6 +207,64 @@ fn generate_struct<'a>(
         })
         .collect::<Result<Vec<_>>>()?;
 
+    let bitfield_accessors = my_struct
+        .fields
+        .iter()
+        .filter(|field| !field.name.starts_with("reserved"))
+        .filter_map(|field| Some((field, packed_bitfields.get(field.vk_name)?)))
+        .map(|(field, bitfield)| {
+            let name = format_ident!("{}", field.name);
+            let getter_name = format_ident!("get_{}", field.name);
+            let unit_name = &bitfield.unit_name;
+            let unit_ty = gen.get_ident_name(bitfield.unit_ty)?;
+            // a shift by 0 would be flagged by clippy
+            let shift = |value: TokenStream| match bitfield.offset {
+                0 => value,
+                offset => {
+                    let offset = Literal::u8_unsuffixed(offset);
+                    quote!((#value << #offset))
+                }
+            };
+            if bitfield.bitsize == 1 {
+                let bit = shift(quote!(1));
+                let value = shift(quote!((value as #unit_ty)));
+                Ok(quote! {
+                    #[inline]
+                    pub fn #name(mut self, value: bool) -> Self {
+                        self.#unit_name = (self.#unit_name & !#bit) | #value;
+                        self
+                    }
+                    #[inline]
+                    pub fn #getter_name(&self) -> bool {
+                        self.#unit_name & #bit != 0
+                    }
+                })
+            } else {
+                let mask = Literal::u64_unsuffixed((1u64 << bitfield.bitsize) - 1);
+                let unit_mask = shift(quote!(#mask));
+                let value = shift(quote!((value & #mask)));
+                let getter_value = match bitfield.offset {
+                    0 => quote!(self.#unit_name & #mask),
+                    offset => {
+                        let offset = Literal::u8_unsuffixed(offset);
+                        quote!((self.#unit_name >> #offset) & #mask)
+                    }
+                };
+                Ok(quote! {
+                    #[inline]
+                    pub fn #name(mut self, value: #unit_ty) -> Self {
+                        self.#unit_name = (self.#unit_name & !#unit_mask) | #value;
+                        self
+                    }
+                    #[inline]
+                    pub fn #getter_name(&self) -> #unit_ty {
+                        #getter_value
+                    }
+                })
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
     let char_arr_setters = char_arr_fields.into_iter().map(|(field, size)| {
         let name = format_ident!("{}", field.name);
         let fn_name = format_ident!("get_{}", field.name);
@@ -285,6 +304,16 @@ fn generate_struct<'a>(
                 self
             }
         },
+        // used to give the std header version of a video session
+        "VkExtensionProperties" => quote! {
+            #[inline]
+            pub fn extension_name(mut self, value: &CStr) -> Self {
+                let name = value.to_bytes_with_nul();
+                assert!(name.len() <= MAX_EXTENSION_NAME_SIZE as _);
+                self.extension_name = array::from_fn(|i| name.get(i).copied().unwrap_or_default() as c_char);
+                self
+            }
+        },
         "VkAccelerationStructureVersionInfoKHR" | "VkMicromapVersionInfoEXT" => quote! {
             #[inline]
             pub fn version_data(mut self, value: &'a [u8; (2*UUID_SIZE) as _]) -> Self {
@@ -300,21 +329,7 @@ fn generate_struct<'a>(
         .iter()
         .filter_map(|field| length_fields.get(field.vk_name))
         .map(|length_field| {
-            // get the length field name
-            let len_field = length_field.len_field;
-            let var_name = if length_field.array_fields.len() == 1 && length_field.array_fields[0].name.starts_with("p_") {
-                &length_field.array_fields[0].name[("p_".len())..]
-            } else if len_field.name.ends_with("_count") {
-                 &len_field.name[..(len_field.name.len() - "_count".len())]
-            } else if len_field.name.ends_with("_size") {
-                &len_field.name[..(len_field.name.len() - "_size".len())]
-            } else if len_field.name == "size" {
-                "values"  
-            } else {
-                return Err(anyhow!("field length name not expected: {}", len_field.name))
-            };
-
-            let setter_name = format_ident!("{var_name}");
+            let setter_name = format_ident!("{}", array_setter_name(length_field)?);
             let length_name = format_ident!("{}", length_field.len_field.name);
 
             // a slice of size 0 can be seen as a none, no need for options if there is just one field
@@ -326,13 +341,28 @@ fn generate_struct<'a>(
                 format_ident!("{}",field.name)
             }).collect::<Vec<_>>();
 
+            let is_optional = |field: &StructField| can_be_optional && field.optional;
             // find a parameter which cannot be optional to use it as the length if possible
-            let len_value = if let Some((idx,_)) = length_field.array_fields.iter().enumerate().find(|(_,field)| !can_be_optional || !field.optional) {
+            let (used_idx, len_value) = if let Some((idx,_)) = length_field.array_fields.iter().enumerate().find(|(_,field)| !is_optional(field)) {
                 let used_field = &field_names[idx];
-                quote! (#used_field.as_slice().len() as _)
+                (idx, quote! (#used_field.as_slice().len()))
             } else {
                 let first_field = &field_names[0];
-                quote! (#first_field.map(|p| p.as_slice().len()).unwrap_or_default() as _)
+                let other_fields = &field_names[1..];
+                (0, quote! (#first_field.map(|p| p.as_slice().len())#(.or(#other_fields.map(|p| p.as_slice().len())))*.unwrap_or_default()))
+            };
+            // all the slices sharing the same length must have the same size
+            let len_checks = length_field.array_fields.iter().zip(&field_names).enumerate().filter(|(idx, _)| *idx != used_idx).map(|(_, (field, field_name))| {
+                if is_optional(field) {
+                    quote! (assert!(#field_name.map_or(true, |p| p.as_slice().len() == len));)
+                } else {
+                    quote! (assert!(#field_name.as_slice().len() == len);)
+                }
+            }).collect::<Vec<_>>();
+            let (len_checks, len_value) = if len_checks.is_empty() {
+                (None, quote! (#len_value as _))
+            } else {
+                (Some(quote! (let len = #len_value; #(#len_checks)*)), quote!(len as _))
             };
 
             let template_arg = ty_tokens.iter().map(|t| &t.template_param).filter_map(|x| x.as_ref());
@@ -343,6 +373,7 @@ fn generate_struct<'a>(
                 #[inline]
                 pub fn #setter_name<#(#template_arg),*>(mut self, #(#field_names: #slice_ty),*) -> Self {
                     #(#affectations;)*
+                    #len_checks
                     self.#length_name = #len_value;
                     self
                 }
@@ -350,12 +381,14 @@ fn generate_struct<'a>(
 
             let getters = ty_tokens.into_iter().zip(length_field.array_fields.iter()).map(|(slice_ty, field)| {
                 let mut var_name = field.name.as_str();
-                if var_name.starts_with("p_") {
-                    var_name = &var_name[2..];
-                } else if var_name.starts_with("pp_") 
-                    // TODO: handle this properly (not hardcoded)
-                    && var_name != "pp_geometries" && var_name != "pp_usage_counts" {
-                    var_name = &var_name[3..];
+                if let Some(name) = var_name.strip_prefix("p_") {
+                    var_name = name;
+                } else if let Some(name) = var_name.strip_prefix("pp_") {
+                    // keep the prefix if the same array is also given as a pointer to the elements
+                    let p_name = format!("p_{name}");
+                    if !my_struct.fields.iter().any(|other| other.name == p_name) {
+                        var_name = name;
+                    }
                 }
                 let getter_name = format_ident!("get_{var_name}");
                 let ret_ty = &slice_ty.output_ty;
@@ -384,6 +417,12 @@ fn generate_struct<'a>(
         .iter()
         .map(|name| format_ident!("{}", mapping.get(name).unwrap().name))
         .collect::<Vec<_>>();
+    let alias_cfgs = my_struct
+        .aliases
+        .borrow()
+        .iter()
+        .map(|name| gen.item_cfg(name))
+        .collect::<Vec<_>>();
 
     let name = format_ident!("{}", my_struct.name);
     let doc_tag = make_doc_link(struct_vk_name);
@@ -391,7 +430,7 @@ fn generate_struct<'a>(
     let has_lifetime = my_struct.has_lifetime.get().unwrap();
     let lifetime = has_lifetime.then(|| quote! (<'a>));
     let require_phantom = has_lifetime;
-    let phantom_decl = require_phantom.then(|| quote! (phantom: PhantomData<&'a ()>,));
+    let phantom_decl = require_phantom.then(|| quote! (#serde_skip phantom: PhantomData<&'a ()>,));
     let phantom_default = require_phantom.then(|| quote! (phantom: PhantomData,));
     let (s_type_impl, p_next_impl) = if let Some(s_type) = my_struct.s_type {
         let s_type_value: TokenStream = mapping
@@ -419,19 +458,30 @@ fn generate_struct<'a>(
         (None, None)
     };
 
+    let own_cfg = gen.item_cfg(struct_vk_name).map(|cfg| cfg.to_string());
     let struct_extensions = my_struct
         .extends
         .iter()
-        .map(|name| {
+        .map(|extended_name| {
             mapping
-                .get(name.as_str())
-                .map(|entry| format_ident!("{}", entry.name))
-                .ok_or_else(|| anyhow!("Failed to find extension {}", name))
+                .get(extended_name.as_str())
+                .map(|entry| {
+                    // the extended structure may be gated behind a feature this one does not depend on
+                    let extended_cfg = gen
+                        .item_cfg(extended_name)
+                        .filter(|cfg| Some(cfg.to_string()) != own_cfg);
+                    let extended = format_ident!("{}", entry.name);
+                    quote! (#extended_cfg unsafe impl<'a, 'b> ExtendingStructure<#extended<'b>> for #name<'a> {})
+                })
+                .ok_or_else(|| anyhow!("Failed to find extension {}", extended_name))
         })
         .collect::<Result<Vec<_>>>()?;
 
     // for the time being, do not implement clone for types with a lifetime
     let derives = (!has_lifetime).then(|| quote! (#[derive(Clone, Copy)]));
+    let serde_derives = is_plain_data.then(|| {
+        quote! (#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))])
+    });
 
     if my_struct.is_union {
         // union are much more lightweight
@@ -461,13 +511,30 @@ fn generate_struct<'a>(
                 }
             }
 
-            #(pub type #aliases #lifetime = #name #lifetime;)*
+            #(#alias_cfgs pub type #aliases #lifetime = #name #lifetime;)*
         });
     }
 
+    let (derives, default_impl) = if derivable_default && !require_phantom {
+        (Some(quote!(#[derive(Clone, Copy, Default)])), None)
+    } else {
+        let default_impl = quote! {
+            impl #lifetime Default for #name #lifetime {
+                fn default() -> Self {
+                    Self {
+                        #(#default_impl,)*
+                        #phantom_default
+                    }
+                }
+            }
+        };
+        (derives, Some(default_impl))
+    };
+
     Ok(quote! {
         #[repr(C)]
         #derives
+        #serde_derives
         #doc_tag
         pub struct #name #lifetime {
             #(#fields)*
@@ -476,25 +543,236 @@ fn generate_struct<'a>(
         #s_type_impl
         unsafe impl #lifetime Send for #name #lifetime {}
         unsafe impl #lifetime Sync for #name #lifetime {}
-        #(unsafe impl<'a, 'b> ExtendingStructure<#struct_extensions<'b>> for #name<'a> {})*
+        #(#struct_extensions)*
 
-        impl #lifetime Default for #name #lifetime {
-            fn default() -> Self {
-                Self {
-                    #(#default_impl,)*
-                    #phantom_default
-                }
-            }
-        }
+        #default_impl
 
         impl #lifetime #name #lifetime {
             #(#simple_accessors)*
+            #(#bitfield_accessors)*
             #(#char_arr_setters)*
             #custom_accessors
             #(#array_accessors)*
             #p_next_impl
         }
 
-        #(pub type #aliases #lifetime = #name #lifetime;)*
+        #(#alias_cfgs pub type #aliases #lifetime = #name #lifetime;)*
+    })
+}
+
+/// Arrays sharing the same length member
+pub(super) struct FieldWithLen<'a, 'b> {
+    pub len_field: &'b StructField<'a>,
+    pub array_fields: Vec<&'b StructField<'a>>,
+}
+
+/// Name of the setter of a member which is not an array
+pub(super) fn simple_setter_name<'b>(field: &'b StructField) -> &'b str {
+    if field.name.starts_with("p_") {
+        &field.name[2..]
+    } else if field.name.starts_with("pp_") {
+        &field.name[3..]
+    } else {
+        &field.name
+    }
+}
+
+/// Name of the setter of arrays sharing the same length member
+pub(super) fn array_setter_name<'b>(length_field: &FieldWithLen<'_, 'b>) -> Result<&'b str> {
+    let len_field = length_field.len_field;
+    if length_field.array_fields.len() == 1 && length_field.array_fields[0].name.starts_with("p_") {
+        Ok(&length_field.array_fields[0].name[("p_".len())..])
+    } else if let Some(name) = len_field.name.strip_suffix("_count") {
+        Ok(name)
+    } else if let Some(name) = len_field.name.strip_suffix("_size") {
+        Ok(name)
+    } else if len_field.name == "size" {
+        Ok("values")
+    } else {
+        Err(anyhow!(
+            "field length name not expected: {}",
+            len_field.name
+        ))
+    }
+}
+
+/// How the members of a structure are exposed
+pub(super) struct FieldLayout<'a, 'b> {
+    /// Members with a setter of their own
+    pub simple_fields: HashSet<&'a str>,
+    /// Arrays set together, indexed by the name of their length member
+    pub length_fields: HashMap<&'a str, FieldWithLen<'a, 'b>>,
+    /// Null-terminated strings stored inside the structure, with their maximum size
+    pub char_arr_fields: Vec<(&'b StructField<'a>, &'a str)>,
+    pub packed_bitfields: HashMap<&'a str, PackedBitfield<'a>>,
+}
+
+pub(super) fn compute_field_layout<'a, 'b>(
+    my_struct: &'b StructStandard<'a>,
+    struct_vk_name: &str,
+) -> Result<FieldLayout<'a, 'b>> {
+    let all_fields: HashMap<_, _> = my_struct.fields.iter().map(|f| (f.vk_name, f)).collect();
+    let mut simple_fields: HashSet<_> = all_fields.keys().cloned().collect();
+    let mut char_arr_fields = Vec::new();
+    if my_struct.s_type.is_some() {
+        // remove preemptively s_type and p_next
+        simple_fields.remove("sType");
+        simple_fields.remove("pNext");
+    }
+
+    let mut length_fields = HashMap::new();
+
+    // retrieve all arrays with a len
+    for field in &my_struct.fields {
+        let mut len = match &field.xml.len {
+            Some(len) => len.as_str(),
+            _ => continue,
+        };
+
+        if len == "null-terminated" {
+            if let Some(AdvancedType::CharArray(size)) = field.advanced_ty.get() {
+                simple_fields.remove(field.vk_name);
+                char_arr_fields.push((field, size));
+            }
+            continue;
+        }
+
+        if field.xml.alt_len.is_some() {
+            // handled with specific code (only concern 5 structs so far)
+            simple_fields.remove(field.vk_name);
+            continue;
+        }
+
+        // for arrays of pointers, the length has one more component for the content of each pointer
+        if let Some((outer_len, inner_len)) = len.split_once(',') {
+            let is_supported = match inner_len {
+                // array of strings
+                "null-terminated" => matches!(field.ty, Type::CStrArr | Type::Ptr(_)),
+                // array of references
+                "1" => matches!(field.ty, Type::DoublePtr(_)),
+                _ => false,
+            };
+            if !is_supported {
+                return Err(anyhow!(
+                    "Unsupported length {len} for {}::{}",
+                    struct_vk_name,
+                    field.vk_name
+                ));
+            }
+            len = outer_len;
+        }
+
+        let len_field = *all_fields
+            .get(len)
+            .ok_or_else(|| anyhow!("Failed to find length field {len} for {struct_vk_name}"))?;
+
+        simple_fields.remove(field.vk_name);
+        simple_fields.remove(len_field.vk_name);
+
+        length_fields
+            .entry(len)
+            .or_insert_with(|| FieldWithLen {
+                len_field,
+                array_fields: Vec::new(),
+            })
+            .array_fields
+            .push(field);
+    }
+
+    for (_, field_with_len) in &length_fields {
+        if field_with_len
+            .array_fields
+            .iter()
+            .all(|field| field.optional)
+        {
+            // if all slice fields are optional, we must give the option to set the length alone
+            // (for example descriptorCount in VkDescriptorSetLayoutBinding )
+            simple_fields.insert(field_with_len.len_field.vk_name);
+        }
+    }
+
+    let packed_bitfields = pack_bitfields(my_struct);
+    for field_name in packed_bitfields.keys() {
+        simple_fields.remove(field_name);
+    }
+
+    // remove length parameter associated with altlen fields
+    match struct_vk_name {
+        "VkShaderModuleCreateInfo" => {
+            simple_fields.remove("codeSize");
+        }
+        "VkPipelineMultisampleStateCreateInfo" => {
+            simple_fields.remove("rasterizationSamples");
+        }
+        _ => (),
+    };
+
+    Ok(FieldLayout {
+        simple_fields,
+        length_fields,
+        char_arr_fields,
+        packed_bitfields,
     })
 }
+
+/// Bitfield stored with the bitfields next to it in an integer of its type
+pub(super) struct PackedBitfield<'a> {
+    pub unit_name: proc_macro2::Ident,
+    pub unit_ty: &'a str,
+    pub offset: u8,
+    pub bitsize: u8,
+}
+
+/// Bitfields which do not fill whole bytes (the flags of the video std headers) cannot be represented
+/// as byte arrays, instead pack them the same way a C compiler does
+pub(super) fn pack_bitfields<'a>(
+    my_struct: &StructStandard<'a>,
+) -> HashMap<&'a str, PackedBitfield<'a>> {
+    let has_partial_bytes = my_struct
+        .fields
+        .iter()
+        .any(|field| matches!(field.ty, Type::Bitfield { bitsize, .. } if bitsize % 8 != 0));
+    if !has_partial_bytes {
+        return HashMap::new();
+    }
+
+    // (unit index, offset in the unit) for each bitfield
+    let mut units = Vec::new();
+    let mut current: Option<(usize, u8)> = None;
+    let mut nb_units = 0;
+    for field in &my_struct.fields {
+        let Type::Bitfield { ty, bitsize } = field.ty else {
+            current = None;
+            continue;
+        };
+        let (unit, offset) = match current {
+            Some((unit, offset)) if offset + bitsize <= 32 => (unit, offset),
+            _ => {
+                nb_units += 1;
+                (nb_units - 1, 0)
+            }
+        };
+        current = Some((unit, offset + bitsize));
+        units.push((field.vk_name, ty, unit, offset, bitsize));
+    }
+
+    units
+        .into_iter()
+        .map(|(vk_name, unit_ty, unit, offset, bitsize)| {
+            let unit_name = if nb_units == 1 {
+                format_ident!("bitfields")
+            } else {
+                format_ident!("bitfields{unit}")
+            };
+            (
+                vk_name,
+                PackedBitfield {
+                    unit_name,
+                    unit_ty,
+                    offset,
+                    bitsize,
+                },
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,227 @@
+use super::Generator;
+use crate::xml;
+use anyhow::{anyhow, Result};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+
+/// A flag of a bitmask along with its cfg attribute
+struct Flag {
+    value: TokenStream,
+    cfg: Option<TokenStream>,
+}
+
+pub fn generate<'a>(gen: &Generator<'a>) -> Result<String> {
+    let sync = gen
+        .registry
+        .syncs
+        .first()
+        .ok_or_else(|| anyhow!("Registry is missing the sync section"))?;
+
+    let flag = |name: &str| -> Result<Flag> {
+        Ok(Flag {
+            value: gen.get_mapping_name(name)?.parse().unwrap(),
+            cfg: gen.enum_value_cfg(name),
+        })
+    };
+
+    // meta stages can be equivalent to other meta stages, flatten them
+    let equivalents: HashMap<&str, &[String]> = sync
+        .sync_stages
+        .iter()
+        .filter_map(|stage| {
+            Some((
+                stage.name.as_str(),
+                stage.equivalent.as_ref()?.stage.as_slice(),
+            ))
+        })
+        .collect();
+    let mut meta_stages = Vec::new();
+    for stage in &sync.sync_stages {
+        if !equivalents.contains_key(stage.name.as_str()) {
+            continue;
+        }
+        let mut expanded: Vec<&str> = Vec::new();
+        let mut to_expand = vec![stage.name.as_str()];
+        while let Some(name) = to_expand.pop() {
+            for equivalent in equivalents.get(name).copied().unwrap_or_default() {
+                if !expanded.contains(&equivalent.as_str()) && *equivalent != stage.name {
+                    expanded.push(equivalent);
+                    to_expand.push(equivalent);
+                }
+            }
+        }
+        let expanded = expanded.into_iter().map(flag).collect::<Result<Vec<_>>>()?;
+        let meta = flag(&stage.name)?;
+        let (meta_cfg, meta) = (meta.cfg, meta.value);
+        let union = flags_union(quote!(stages), &expanded, meta_cfg.as_ref());
+        meta_stages.push(quote! {
+            #meta_cfg
+            if self.contains(#meta) {
+                #union
+            }
+        });
+    }
+
+    // stages without queue restrictions are supported by all queues
+    let mut all_queues_stages = Vec::new();
+    let mut queue_stages: Vec<(&xml::Queue, Vec<Flag>)> = Vec::new();
+    for stage in &sync.sync_stages {
+        let queues = stage
+            .support
+            .as_ref()
+            .map(|support| support.queues.as_slice())
+            .unwrap_or_default();
+        if queues.is_empty() && !stage.name.ends_with("_NONE") {
+            all_queues_stages.push(flag(&stage.name)?);
+        }
+        for queue in queues {
+            let idx = match queue_stages
+                .iter()
+                .position(|(other, _)| queue_flag_name(other) == queue_flag_name(queue))
+            {
+                Some(idx) => idx,
+                None => {
+                    queue_stages.push((queue, Vec::new()));
+                    queue_stages.len() - 1
+                }
+            };
+            queue_stages[idx].1.push(flag(&stage.name)?);
+        }
+    }
+    let all_queues_union = flags_union(quote!(stages), &all_queues_stages, None);
+    let queue_supports = queue_stages
+        .iter()
+        .map(|(queue, stages)| {
+            let queue = flag(queue_flag_name(queue))?;
+            let (queue_cfg, queue) = (queue.cfg, queue.value);
+            let union = flags_union(quote!(stages), stages, queue_cfg.as_ref());
+            Ok(quote! {
+                #queue_cfg
+                if self.contains(#queue) {
+                    #union
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // accesses without stage restrictions can be used with any stage
+    let all_commands = flag("VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT")?.value;
+    let access_supports = sync
+        .sync_accesses
+        .iter()
+        .filter(|access| !access.name.ends_with("_NONE"))
+        .map(|access| {
+            let access_flag = flag(&access.name)?;
+            let (access_cfg, access_value) = (access_flag.cfg, access_flag.value);
+            let stages = match access.support.as_ref() {
+                Some(support) if !support.stage.is_empty() => support
+                    .stage
+                    .iter()
+                    .map(|name| flag(name))
+                    .collect::<Result<Vec<_>>>()?,
+                _ => vec![Flag {
+                    value: all_commands.clone(),
+                    cfg: None,
+                }],
+            };
+            let union = flags_union(quote!(stages), &stages, access_cfg.as_ref());
+            Ok(quote! {
+                #access_cfg
+                if self.contains(#access_value) {
+                    #union
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = quote! {
+        use super::{AccessFlags2, PipelineStageFlags2, QueueFlags};
+
+        impl PipelineStageFlags2 {
+            /// Add to these stages all the stages the meta stages they contain are equivalent to.
+            /// For example [PipelineStageFlags2::AllGraphics] adds all the graphics stages.
+            /// [PipelineStageFlags2::AllCommands] is not expanded, as it depends on the queue
+            pub const fn expand_meta_stages(self) -> Self {
+                let mut stages = self.bits();
+                #(#meta_stages)*
+                Self::from_bits_retain(stages)
+            }
+        }
+
+        impl QueueFlags {
+            /// Pipeline stages which can be used on a queue with these capabilities
+            pub const fn supported_stages(self) -> PipelineStageFlags2 {
+                let mut stages = 0;
+                #all_queues_union
+                #(#queue_supports)*
+                PipelineStageFlags2::from_bits_retain(stages)
+            }
+        }
+
+        impl AccessFlags2 {
+            /// Pipeline stages with which these accesses can be used.
+            /// An access is valid in a barrier if its stage mask contains one of its supported stages,
+            /// accesses supported by [PipelineStageFlags2::AllCommands] are valid with any stage
+            pub const fn supported_stages(self) -> PipelineStageFlags2 {
+                let mut stages = 0;
+                #(#access_supports)*
+                PipelineStageFlags2::from_bits_retain(stages)
+            }
+        }
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+/// Name of the queue flag matching a queue of the sync section
+fn queue_flag_name(queue: &xml::Queue) -> &'static str {
+    match queue {
+        xml::Queue::Compute => "VK_QUEUE_COMPUTE_BIT",
+        xml::Queue::Decode => "VK_QUEUE_VIDEO_DECODE_BIT_KHR",
+        xml::Queue::Encode => "VK_QUEUE_VIDEO_ENCODE_BIT_KHR",
+        xml::Queue::Graphics => "VK_QUEUE_GRAPHICS_BIT",
+        xml::Queue::Opticalflow => "VK_QUEUE_OPTICAL_FLOW_BIT_NV",
+        xml::Queue::SparseBinding => "VK_QUEUE_SPARSE_BINDING_BIT",
+        xml::Queue::Transfer => "VK_QUEUE_TRANSFER_BIT",
+    }
+}
+
+/// Add the given flags to the bits in `target`, the flags behind the cfg `outer_cfg` are already in its scope.
+/// Attributes are not allowed on expressions, so flags behind a cfg are added in a block
+fn flags_union(
+    target: TokenStream,
+    flags: &[Flag],
+    outer_cfg: Option<&TokenStream>,
+) -> TokenStream {
+    let outer_cfg = outer_cfg.map(|cfg| cfg.to_string());
+    let mut groups: Vec<(Option<&TokenStream>, Vec<&TokenStream>)> = Vec::new();
+    for flag in flags {
+        let cfg = flag
+            .cfg
+            .as_ref()
+            .filter(|cfg| Some(cfg.to_string()) != outer_cfg);
+        let key = cfg.map(|cfg| cfg.to_string());
+        match groups
+            .iter_mut()
+            .find(|(other, _)| other.map(|cfg| cfg.to_string()) == key)
+        {
+            Some((_, values)) => values.push(&flag.value),
+            None => groups.push((cfg, vec![&flag.value])),
+        }
+    }
+    let groups = groups.into_iter().map(|(cfg, values)| {
+        let union = quote!(#target |= #(#values.bits())|*;);
+        match cfg {
+            Some(cfg) => quote! {
+                #cfg
+                {
+                    #union
+                }
+            },
+            None => union,
+        }
+    });
+    quote!(#(#groups)*)
+}
@@ -0,0 +1,94 @@
+use std::ffi::CString;
+
+use anyhow::{anyhow, Result};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::LitCStr;
+
+use crate::{structs::Struct, xml};
+
+use super::{enums, structs, Generator};
+
+/// Generate the types of the video std headers (vk_video/*.h) described in video.xml
+pub fn generate(gen: &Generator) -> Result<String> {
+    let headers = gen
+        .video_registry
+        .extensions
+        .iter()
+        .flat_map(|exts| &exts.extension)
+        .map(|header| {
+            let items = header
+                .require
+                .iter()
+                .flat_map(|req| &req.content)
+                .map(|cnt| match cnt {
+                    xml::RequireContent::Type(xml::RequireType { name, .. }) => {
+                        generate_type(gen, name)
+                    }
+                    xml::RequireContent::Enum(req_enum) => generate_header_enum(gen, req_enum),
+                    _ => Ok(quote!()),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(quote!(#(#items)*))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = quote! {
+        use std::{array, ffi::CStr, marker::PhantomData, ptr};
+
+        /// Equivalent of the `VK_MAKE_VIDEO_STD_VERSION` macro
+        pub const fn make_video_std_version(major: u32, minor: u32, patch: u32) -> u32 {
+            (major << 22) | (minor << 12) | patch
+        }
+
+        #(#headers)*
+    }
+    .to_string();
+
+    Generator::format_result(result)
+}
+
+fn generate_type(gen: &Generator, name: &str) -> Result<TokenStream> {
+    if let Some(my_enum) = gen.enums.get(name) {
+        enums::generate_enum(gen, name, my_enum)
+    } else if let Some(Struct::Standard(my_struct)) = gen.structs.get(name) {
+        structs::generate_struct(gen, my_struct, name)
+    } else {
+        // includes and version defines
+        Ok(quote!())
+    }
+}
+
+fn generate_header_enum(gen: &Generator, req_enum: &xml::RequireEnum) -> Result<TokenStream> {
+    let vk_name = req_enum.name.as_str();
+    if let Some(constant) = gen.constants.get(vk_name) {
+        return enums::generate_constant(gen, vk_name, constant);
+    }
+
+    let value = req_enum
+        .value
+        .as_ref()
+        .ok_or_else(|| anyhow!("Expected a value for {vk_name}"))?;
+    let name = format_ident!("{}", &vk_name["VK_".len()..]);
+    if vk_name.ends_with("_SPEC_VERSION") {
+        // VK_STD_VULKAN_VIDEO_CODEC_H264_DECODE_API_VERSION_1_0_0
+        let version = value
+            .rsplitn(4, '_')
+            .take(3)
+            .map(|nb| nb.parse().map(Literal::u32_unsuffixed))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [patch, minor, major] = &version[..] else {
+            return Err(anyhow!("Failed to parse the version {value}"));
+        };
+        Ok(quote! (pub const #name: u32 = make_video_std_version(#major, #minor, #patch);))
+    } else if vk_name.ends_with("_EXTENSION_NAME") {
+        let header_name = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .ok_or_else(|| anyhow!("{value} should be in quotes"))?;
+        let header_name = LitCStr::new(&CString::new(header_name)?, Span::call_site());
+        Ok(quote! (pub const #name: &CStr = #header_name;))
+    } else {
+        Err(anyhow!("Unexpected enum {vk_name} in video std header"))
+    }
+}
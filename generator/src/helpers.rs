@@ -142,8 +142,11 @@ pub(crate) fn camel_case_to_snake_case(name: &str) -> String {
     let mut can_put_separation = false;
     for c in name.chars() {
         if c == '_' {
-            // just for textureCompressionASTC_HDR..
-            can_put_separation = true;
+            // textureCompressionASTC_HDR or the snake case fields of the video std headers
+            if !result.is_empty() && !result.ends_with('_') {
+                result.push('_');
+            }
+            can_put_separation = false;
             continue;
         } else if c.is_ascii_uppercase() && can_put_separation {
             result.push('_');
@@ -157,10 +160,11 @@ pub(crate) fn camel_case_to_snake_case(name: &str) -> String {
 }
 
 pub(crate) fn longuest_common_prefix<'a, 'b>(str1: &'a str, str2: &'b str) -> &'a str {
+    // case insensitive as the video std enums use AV1 while their values become Av1
     let prefix_size = str1
         .chars()
         .zip(str2.chars())
-        .take_while(|(c1, c2)| c1 == c2)
+        .take_while(|(c1, c2)| c1.eq_ignore_ascii_case(c2))
         .count();
     &str1[..prefix_size]
 }
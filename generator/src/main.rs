@@ -15,10 +15,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     let reader = BufReader::new(file);
     let registry: Registry = from_reader(reader)?;
 
-    let generator = Generator::new(Api::Vulkan, &registry)?;
+    let video_file = std::fs::File::open("../video.xml")?;
+    let video_registry: Registry = from_reader(BufReader::new(video_file))?;
+
+    let generator = Generator::new(Api::Vulkan, &registry, &video_registry)?;
 
     let main_crate_name = "vulkanite";
-    let crate_vk = PathBuf::from(&format!("{main_crate_name}/src/vk"));
+    let crate_src = PathBuf::from(&format!("{main_crate_name}/src"));
+    let crate_vk = crate_src.join("vk");
 
     let extensions = generator.generate_extensions()?;
     fs::write(crate_vk.join("extensions.rs"), extensions)?;
@@ -32,9 +36,31 @@ fn main() -> Result<(), Box<dyn Error>> {
     let structs = generator.generate_structs()?;
     fs::write(crate_vk.join("structs.rs"), structs)?;
 
+    let owned_structs = generator.generate_owned_structs()?;
+    fs::write(crate_vk.join("owned/structs.rs"), owned_structs)?;
+
+    let chain_structs = generator.generate_chain_structs()?;
+    fs::write(crate_vk.join("chain/structs.rs"), chain_structs)?;
+
+    let video = generator.generate_video()?;
+    fs::write(crate_vk.join("video.rs"), video)?;
+
+    let layout_tests = generator.generate_layout_tests()?;
+    fs::write(crate_vk.join("layout.rs"), layout_tests)?;
+    generator.check_layout_with_c_compiler()?;
+
     let formats = generator.generate_formats()?;
     fs::write(crate_vk.join("formats.rs"), formats)?;
 
+    let limits = generator.generate_limits()?;
+    fs::write(crate_vk.join("limits.rs"), limits)?;
+
+    let spirv = generator.generate_spirv()?;
+    fs::write(crate_vk.join("spirv.rs"), spirv)?;
+
+    let sync = generator.generate_sync()?;
+    fs::write(crate_vk.join("sync.rs"), sync)?;
+
     let dispatcher = generator.generate_dispatcher()?;
     fs::write(crate_vk.join("dispatcher.rs"), dispatcher)?;
 
@@ -44,5 +70,29 @@ fn main() -> Result<(), Box<dyn Error>> {
     let basic_commands = generator.generate_advanced_commands(GeneratedCommandType::Basic)?;
     fs::write(crate_vk.join("rs/commands.rs"), basic_commands)?;
 
+    let typed_commands = generator.generate_advanced_commands(GeneratedCommandType::Typed)?;
+    fs::write(crate_vk.join("typed/commands.rs"), typed_commands)?;
+
+    let exclusive_commands =
+        generator.generate_advanced_commands(GeneratedCommandType::Exclusive)?;
+    fs::write(crate_vk.join("exclusive/commands.rs"), exclusive_commands)?;
+
+    let recording_infos = generator.generate_recording_infos()?;
+    fs::write(crate_vk.join("recording/commands.rs"), recording_infos)?;
+
+    let codes = generator.generate_codes()?;
+    fs::write(crate_vk.join("codes.rs"), codes)?;
+
+    let destroyable = generator.generate_destroyable()?;
+    fs::write(crate_src.join("deletion/destroyable.rs"), destroyable)?;
+
+    let unsupported_commands = generator.unsupported_commands()?;
+    if !unsupported_commands.is_empty() {
+        eprintln!("The following commands were skipped:");
+        for (name, reason) in unsupported_commands {
+            eprintln!("  {name}: {reason}");
+        }
+    }
+
     Ok(())
 }
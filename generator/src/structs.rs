@@ -28,10 +28,12 @@ pub struct Enum<'a> {
 
 impl<'a> Enum<'a> {
     pub fn parse_name(name: &str) -> String {
-        // remove the Vk prefix
+        // remove the Vk prefix (the video std enums have none)
         // and replace FlagBits by Flags
         // VkMemoryAllocateFlagBitsKHR -> MemoryAllocateFlagsKHR
-        name[2..].replace("FlagBits", "Flags")
+        name.strip_prefix("Vk")
+            .unwrap_or(name)
+            .replace("FlagBits", "Flags")
     }
 }
 
@@ -418,6 +420,17 @@ impl<'a> StructField<'a> {
                 };
                 (ty, name)
             }
+            // <type>uint8_t</type> <name>ScalingList4x4</name>[<enum>STD_VIDEO_H264_SCALING_LIST_4X4_NUM_LISTS</enum>][<enum>STD_VIDEO_H264_SCALING_LIST_4X4_NUM_ELEMENTS</enum>]
+            [Ty::Type(ty), Ty::Name(name), Ty::Text(op), Ty::Enum(size1), Ty::Text(mid), Ty::Enum(size2), Ty::Text(cl)]
+                if op == "[" && mid == "][" && cl == "]" =>
+            {
+                let ty = Type::ArrayDoubleEnum {
+                    ty,
+                    size1: size1.as_str(),
+                    size2: size2.as_str(),
+                };
+                (ty, name)
+            }
             // const <type>char</type>*     <name>pApplicationName</name>
             [Ty::Type(ty), Ty::Text(star), Ty::Name(name)] if star == "*" => {
                 if ty == "void" {
@@ -468,8 +481,9 @@ impl<'a> StructField<'a> {
                 (Type::DoublePtr(ty), name)
             }
             // <type>uint32_t</type>                                                <name>mask</name>:8
-            [Ty::Type(ty), Ty::Name(name), Ty::Text(bit)] if bit.starts_with(':') => {
-                let bitsize = bit[1..].parse()?;
+            // <type>uint32_t</type> <name>constraint_set0_flag</name> : 1
+            [Ty::Type(ty), Ty::Name(name), Ty::Text(bit)] if bit.trim_start().starts_with(':') => {
+                let bitsize = bit.trim_start()[1..].trim().parse()?;
                 (Type::Bitfield { ty: &ty, bitsize }, name)
             }
             _ => return Err(anyhow!("Failed to parse member {:?}", value)),
@@ -504,6 +518,7 @@ pub struct Command<'a> {
     pub params: Vec<CommandParam<'a>>,
     pub handle: Cell<Option<&'a str>>,
     pub aliases: RefCell<Vec<(&'a str, String)>>,
+    pub xml: &'a xml::Command,
 }
 
 impl<'a> TryFrom<&'a xml::Command> for Command<'a> {
@@ -545,6 +560,7 @@ impl<'a> TryFrom<&'a xml::Command> for Command<'a> {
             params,
             handle: Cell::new(None),
             aliases: RefCell::new(Vec::new()),
+            xml: value,
         })
     }
 }
@@ -648,6 +664,7 @@ pub enum AdvancedType<'a> {
     OtherArrayWithEnum(&'a str, &'a str),
     OtherArrayWithCst(&'a str, u16),
     OtherDoubleArray(&'a str, u8, u8),
+    OtherDoubleArrayWithEnum(&'a str, &'a str, &'a str),
     CharArray(&'a str),
     CString,
     CStringPtr,
@@ -668,13 +685,32 @@ pub enum Type<'a> {
     // const char* const*
     CStrArr,
     // char[VK_MAX_PHYSICAL_DEVICE_NAME_SIZE]
-    ArrayEnum { ty: &'a str, size: &'a str },
+    ArrayEnum {
+        ty: &'a str,
+        size: &'a str,
+    },
     // VkExtent[2]
-    ArrayCst { ty: &'a str, size: u16 },
+    ArrayCst {
+        ty: &'a str,
+        size: u16,
+    },
     // float[3][4]
-    ArrayDoubleCst { ty: &'a str, size1: u8, size2: u8 },
+    ArrayDoubleCst {
+        ty: &'a str,
+        size1: u8,
+        size2: u8,
+    },
+    // uint8_t[STD_VIDEO_H264_SCALING_LIST_4X4_NUM_LISTS][STD_VIDEO_H264_SCALING_LIST_4X4_NUM_ELEMENTS]
+    ArrayDoubleEnum {
+        ty: &'a str,
+        size1: &'a str,
+        size2: &'a str,
+    },
     // uint32_t:8
-    Bitfield { ty: &'a str, bitsize: u8 },
+    Bitfield {
+        ty: &'a str,
+        bitsize: u8,
+    },
 }
 
 #[derive(Clone, Copy)]
@@ -858,7 +894,7 @@ pub fn convert_field_to_snake_case(
         // add something relevant in front
         [
             "Type", "Count", "Depth", "Size", "Rate", "Format", "Result", "Controls", "Chroma",
-            "Image",
+            "Image", "Level", "Ratio", "Idc",
         ]
         .into_iter()
         .find(|kw| container_name.contains(kw))
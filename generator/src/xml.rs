@@ -97,7 +97,8 @@ pub struct Tag {
 
 #[derive(Debug, Deserialize)]
 pub struct Types {
-    #[serde(rename = "@comment")]
+    // video.xml does not comment its types
+    #[serde(default, rename = "@comment")]
     pub comment: String,
     #[serde(rename = "$value", deserialize_with = "deserialize_types")]
     pub types: Vec<Type>,
@@ -206,7 +207,7 @@ pub enum Api {
     Vulkansc,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum LimitType {
     Bitmask,
@@ -494,7 +495,7 @@ pub struct Remove {}
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct Extensions {
-    #[serde(rename = "@comment")]
+    #[serde(default, rename = "@comment")]
     pub comment: String,
     pub extension: Vec<Extension>,
 }
@@ -504,7 +505,8 @@ pub struct Extensions {
 pub struct Extension {
     #[serde(rename = "@name")]
     pub name: String,
-    #[serde(rename = "@number")]
+    // the video std headers of video.xml have no extension number
+    #[serde(default, rename = "@number")]
     pub number: u32,
     #[serde(rename = "@supported", deserialize_with = "comma_separated")]
     pub supported: Vec<ExtensionSupported>,
@@ -710,6 +712,7 @@ pub struct Spirvcapabilities {
 pub struct SpirvCapability {
     #[serde(rename = "@name")]
     pub name: String,
+    pub enable: Vec<SpirvCapabilityEnable>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -728,6 +731,8 @@ pub struct SpirvCapabilityEnable {
     pub value: Option<String>,
     #[serde(rename = "@struct")]
     pub structure: Option<String>,
+    #[serde(rename = "@feature")]
+    pub feature: Option<String>,
     #[serde(rename = "@alias")]
     pub alias: Option<String>,
 }
@@ -759,14 +764,19 @@ pub struct SyncStage {
 
 #[derive(Deserialize, Debug)]
 pub struct SyncEquivalent {
-    #[serde(rename = "@stage", deserialize_with = "comma_separated")]
+    #[serde(default, rename = "@stage", deserialize_with = "comma_separated")]
     pub stage: Vec<String>,
+    #[serde(default, rename = "@access", deserialize_with = "comma_separated")]
+    pub access: Vec<String>,
 }
 
+/// Queues supporting a stage, or stages supporting an access
 #[derive(Deserialize, Debug)]
 pub struct SyncSupport {
-    #[serde(rename = "@queues", deserialize_with = "comma_separated")]
+    #[serde(default, rename = "@queues", deserialize_with = "comma_separated")]
     pub queues: Vec<Queue>,
+    #[serde(default, rename = "@stage", deserialize_with = "comma_separated")]
+    pub stage: Vec<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -775,6 +785,10 @@ pub struct SyncAccess {
     pub name: String,
     #[serde(rename = "@alias")]
     pub alias: Option<String>,
+    #[serde(rename = "syncequivalent")]
+    pub equivalent: Option<SyncEquivalent>,
+    #[serde(rename = "syncsupport")]
+    pub support: Option<SyncSupport>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -0,0 +1,158 @@
+//! Future resolved from a background thread, shared by the waiters of [fence](crate::fence) and [timeline](crate::timeline)
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::vk;
+
+#[derive(Default)]
+pub(crate) struct Completion {
+    result: Option<vk::Result<()>>,
+    waker: Option<Waker>,
+    cancelled: bool,
+}
+
+pub(crate) type SharedCompletion = Arc<Mutex<Completion>>;
+
+/// Resolve the future of this completion, waking up the task polling it
+pub(crate) fn complete(completion: &SharedCompletion, result: vk::Result<()>) {
+    let mut completion = completion.lock().unwrap();
+    completion.result = Some(result);
+    if let Some(waker) = completion.waker.take() {
+        waker.wake();
+    }
+}
+
+/// Return true if the future of this completion has been dropped
+pub(crate) fn is_cancelled(completion: &SharedCompletion) -> bool {
+    completion.lock().unwrap().cancelled
+}
+
+/// Notified when a pending future is dropped, so the waiter can stop waiting on it early
+pub(crate) trait Cancel: Send + Sync {
+    fn cancel(&self);
+}
+
+/// Future resolved by a call to [complete]
+/// Once resolved, polling the future again returns the same result
+pub(crate) struct CompletionFuture {
+    completion: SharedCompletion,
+    on_cancel: Option<Arc<dyn Cancel>>,
+}
+
+impl CompletionFuture {
+    pub(crate) fn new(on_cancel: Option<Arc<dyn Cancel>>) -> (Self, SharedCompletion) {
+        let completion = SharedCompletion::default();
+        (
+            Self {
+                completion: completion.clone(),
+                on_cancel,
+            },
+            completion,
+        )
+    }
+}
+
+impl Future for CompletionFuture {
+    type Output = vk::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut completion = self.completion.lock().unwrap();
+        match completion.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                completion.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for CompletionFuture {
+    fn drop(&mut self) {
+        let pending = {
+            let mut completion = self.completion.lock().unwrap();
+            completion.cancelled = true;
+            completion.result.is_none()
+        };
+        if let (true, Some(on_cancel)) = (pending, &self.on_cancel) {
+            on_cancel.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use super::{complete, is_cancelled, Cancel, CompletionFuture, SharedCompletion};
+    use crate::vk;
+
+    /// Waiter counting the number of cancelled futures
+    #[derive(Default)]
+    struct MockCancel(AtomicUsize);
+
+    impl Cancel for MockCancel {
+        fn cancel(&self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Waker counting the number of times it has been woken up
+    #[derive(Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn future() -> (CompletionFuture, SharedCompletion, Arc<MockCancel>) {
+        let cancel = Arc::new(MockCancel::default());
+        let (future, completion) = CompletionFuture::new(Some(cancel.clone()));
+        (future, completion, cancel)
+    }
+
+    fn poll(future: &mut CompletionFuture, waker: &Arc<CountingWaker>) -> Poll<vk::Result<()>> {
+        let waker = Waker::from(waker.clone());
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn ready_is_repeated() {
+        let (mut future, completion, cancel) = future();
+        let waker = Arc::new(CountingWaker::default());
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+
+        complete(&completion, Err(vk::Status::ErrorDeviceLost));
+        assert_eq!(waker.0.load(Ordering::SeqCst), 1);
+        for _ in 0..2 {
+            assert_eq!(
+                poll(&mut future, &waker),
+                Poll::Ready(Err(vk::Status::ErrorDeviceLost))
+            );
+        }
+
+        // the waiter does not need to be notified for a resolved future
+        drop(future);
+        assert_eq!(cancel.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn dropping_pending_future_cancels_it() {
+        let (mut future, completion, cancel) = future();
+        let waker = Arc::new(CountingWaker::default());
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+
+        drop(future);
+        assert_eq!(cancel.0.load(Ordering::SeqCst), 1);
+        assert!(is_cancelled(&completion));
+    }
+}
@@ -0,0 +1,289 @@
+//! Deferred destruction of Vulkan objects
+//!
+//! Destroying an object which is still referenced by a command buffer being executed by the GPU is undefined behavior.
+//! A [DeletionQueue] keeps objects alive until the GPU has passed a given point (a fence being signaled
+//! or a timeline semaphore reaching a value) and only then calls the matching destroy command.
+//!
+//! ```ignore
+//! let mut deletion_queue = DeletionQueue::new(&device);
+//! // the buffer is used by the submission signaling `timeline` to `frame_value`
+//! unsafe { deletion_queue.push(buffer, RetirePoint::Timeline(timeline, frame_value)) };
+//! ...
+//! // destroy all objects which are no longer in use
+//! deletion_queue.collect()?;
+//! ```
+//!
+//! A [RetirePoint::Fence] only tells whether the fence is currently signaled, not which submission signaled it.
+//! This conflicts with fences which are reused, such as the per-frame fences of a [FrameRing](crate::frame::FrameRing)
+//! using [FrameSync::Fence](crate::frame::FrameSync::Fence): an object pushed before [FrameRing::submit_fence](crate::frame::FrameRing::submit_fence)
+//! resets the fence is destroyed by a call to [DeletionQueue::collect] while the fence is still signaled by the previous
+//! submission of the frame. Objects waiting on such a fence must only be pushed once the fence has been reset for the
+//! submission using them. Prefer [RetirePoint::Timeline], whose values are never reused, or [Frame::defer](crate::frame::Frame::defer).
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::num::NonZeroU64;
+use std::ops::Deref;
+
+use crate::{vk, Allocator, DefaultAllocator, Dispatcher, DynamicDispatcher, Handle};
+
+mod destroyable;
+
+/// Point in the GPU execution after which an object is no longer in use
+#[derive(Clone, Copy)]
+pub enum RetirePoint {
+    /// The object can be destroyed once the fence is signaled
+    /// The fence must already be unsignaled for the submission using the object when the object is pushed,
+    /// see the [module level documentation](self). If the fence is reset again before [DeletionQueue::collect]
+    /// sees it signaled, the object is only destroyed once the fence is signaled by a later submission
+    Fence(vk::rs::Fence),
+    /// The object can be destroyed once the timeline semaphore counter has reached the given value
+    Timeline(vk::rs::Semaphore, u64),
+}
+
+/// Non-dispatchable handle that can be destroyed using a device destroy command
+pub trait Destroyable: Handle<InnerType = NonZeroU64> {
+    /// Call the destroy command of this object
+    /// # Safety
+    /// The object must have been created from `device` and must no longer be in use
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>);
+}
+
+/// Type-erased [Destroyable::destroy]
+type DestroyFn<D, A> = unsafe fn(&vk::rs::Device<D, A>, NonZeroU64);
+
+unsafe fn destroy_erased<H: Destroyable, D: Dispatcher, A: Allocator>(
+    device: &vk::rs::Device<D, A>,
+    handle: NonZeroU64,
+) {
+    unsafe { H::from_raw(handle).destroy(device) }
+}
+
+struct PendingObject<D: Dispatcher, A: Allocator> {
+    handle: NonZeroU64,
+    destroy: DestroyFn<D, A>,
+    retire_point: RetirePoint,
+}
+
+/// Queue of objects waiting to be destroyed, see the [module level documentation](self)
+pub struct DeletionQueue<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    device: vk::rs::Device<D, A>,
+    pending: Vec<PendingObject<D, A>>,
+}
+
+impl<D: Dispatcher, A: Allocator> DeletionQueue<D, A> {
+    pub fn new(device: &vk::rs::Device<D, A>) -> Self {
+        Self {
+            device: device.clone(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Number of objects waiting to be destroyed
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Destroy the object once the GPU has passed `retire_point`
+    /// # Safety
+    /// The object must have been created from the queue device and not be destroyed by anything else.
+    /// Neither the host nor any work retiring after `retire_point` can use the object after this call
+    pub unsafe fn push<H: Destroyable>(
+        &mut self,
+        object: impl Deref<Target = H>,
+        retire_point: RetirePoint,
+    ) {
+        self.pending.push(PendingObject {
+            handle: object.as_raw(),
+            destroy: destroy_erased::<H, D, A>,
+            retire_point,
+        });
+    }
+
+    /// Same as [DeletionQueue::push] for a type-erased handle
+    /// Return false and does not push the object if objects of type `object_type` are not [Destroyable]
+    /// # Safety
+    /// `handle` must be a valid handle of type `object_type`, the requirements of [DeletionQueue::push] also apply
+    pub unsafe fn push_raw(
+        &mut self,
+        object_type: vk::ObjectType,
+        handle: NonZeroU64,
+        retire_point: RetirePoint,
+    ) -> bool {
+        let Some(destroy) = destroyable::destroy_fn(object_type) else {
+            return false;
+        };
+        self.pending.push(PendingObject {
+            handle,
+            destroy,
+            retire_point,
+        });
+        true
+    }
+
+    /// Destroy all the objects whose retire point has been reached by the GPU
+    /// Return the number of objects that were destroyed
+    pub fn collect(&mut self) -> vk::Result<usize> {
+        let device = &self.device;
+        let retired = take_retired(
+            &mut self.pending,
+            |fence| {
+                let status = device.get_fence_status(fence)?;
+                Ok(status == vk::codes::GetFenceStatusStatus::Success)
+            },
+            |semaphore| device.get_semaphore_counter_value(semaphore),
+        )?;
+
+        for object in &retired {
+            // SAFETY: the object was pushed with this contract and its retire point has been reached
+            unsafe { (object.destroy)(&self.device, object.handle) };
+        }
+        Ok(retired.len())
+    }
+
+    /// Destroy all the objects in the queue, regardless of their retire point
+    /// # Safety
+    /// The GPU must no longer be using any of the objects (for example after a call to [vk::rs::Device::wait_idle])
+    pub unsafe fn flush(&mut self) {
+        for object in self.pending.drain(..) {
+            unsafe { (object.destroy)(&self.device, object.handle) };
+        }
+    }
+}
+
+/// Remove from `pending` and return the objects whose retire point has been reached,
+/// the status of each fence and semaphore is only queried once
+fn take_retired<D: Dispatcher, A: Allocator>(
+    pending: &mut Vec<PendingObject<D, A>>,
+    mut is_signaled: impl FnMut(&vk::rs::Fence) -> vk::Result<bool>,
+    mut counter_value: impl FnMut(&vk::rs::Semaphore) -> vk::Result<u64>,
+) -> vk::Result<Vec<PendingObject<D, A>>> {
+    let mut fences: HashMap<NonZeroU64, bool> = HashMap::new();
+    let mut timelines: HashMap<NonZeroU64, u64> = HashMap::new();
+    for object in pending.iter() {
+        match &object.retire_point {
+            RetirePoint::Fence(fence) => {
+                if let Entry::Vacant(entry) = fences.entry(fence.as_raw()) {
+                    entry.insert(is_signaled(fence)?);
+                }
+            }
+            RetirePoint::Timeline(semaphore, _) => {
+                if let Entry::Vacant(entry) = timelines.entry(semaphore.as_raw()) {
+                    entry.insert(counter_value(semaphore)?);
+                }
+            }
+        }
+    }
+
+    let (retired, still_pending): (Vec<_>, Vec<_>) =
+        std::mem::take(pending)
+            .into_iter()
+            .partition(|object| match &object.retire_point {
+                RetirePoint::Fence(fence) => fences[&fence.as_raw()],
+                RetirePoint::Timeline(semaphore, value) => timelines[&semaphore.as_raw()] >= *value,
+            });
+    *pending = still_pending;
+    Ok(retired)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::num::NonZeroU64;
+
+    use super::{take_retired, PendingObject, RetirePoint};
+    use crate::{vk, DefaultAllocator, DynamicDispatcher, Handle};
+
+    type Pending = PendingObject<DynamicDispatcher, DefaultAllocator>;
+
+    unsafe fn never_destroyed(
+        _: &vk::rs::Device<DynamicDispatcher, DefaultAllocator>,
+        _: NonZeroU64,
+    ) {
+        unreachable!()
+    }
+
+    fn raw(value: u64) -> NonZeroU64 {
+        NonZeroU64::new(value).unwrap()
+    }
+
+    fn pending(handle: u64, retire_point: RetirePoint) -> Pending {
+        PendingObject {
+            handle: raw(handle),
+            destroy: never_destroyed,
+            retire_point,
+        }
+    }
+
+    fn fence(value: u64) -> vk::rs::Fence {
+        vk::rs::Fence::from_inner(unsafe { vk::raw::Fence::from_raw(raw(value)) })
+    }
+
+    fn timeline(value: u64, counter: u64) -> RetirePoint {
+        let semaphore = unsafe { vk::raw::Semaphore::from_raw(raw(value)) };
+        RetirePoint::Timeline(vk::rs::Semaphore::from_inner(semaphore), counter)
+    }
+
+    fn handles(objects: &[Pending]) -> Vec<u64> {
+        objects.iter().map(|object| object.handle.get()).collect()
+    }
+
+    #[test]
+    fn retires_reached_points() {
+        let mut objects = vec![
+            pending(1, RetirePoint::Fence(fence(10))),
+            pending(2, RetirePoint::Fence(fence(11))),
+            pending(3, timeline(20, 5)),
+            pending(4, timeline(20, 6)),
+            pending(5, RetirePoint::Fence(fence(10))),
+        ];
+        let queried = RefCell::new(Vec::new());
+        let retired = take_retired(
+            &mut objects,
+            |fence| {
+                queried.borrow_mut().push(fence.as_raw().get());
+                Ok(fence.as_raw().get() == 10)
+            },
+            |semaphore| {
+                queried.borrow_mut().push(semaphore.as_raw().get());
+                Ok(5)
+            },
+        )
+        .unwrap();
+
+        assert_eq!(handles(&retired), [1, 3, 5]);
+        assert_eq!(handles(&objects), [2, 4]);
+        // each fence and semaphore is only queried once
+        assert_eq!(*queried.borrow(), [10, 11, 20]);
+    }
+
+    #[test]
+    fn fence_reset_before_collect_delays_retirement() {
+        // the fence was signaled then reset for the next submission before the queue was collected,
+        // the object is kept until the fence is signaled again
+        let mut objects = vec![pending(1, RetirePoint::Fence(fence(10)))];
+        let retired = take_retired(&mut objects, |_| Ok(false), |_| unreachable!()).unwrap();
+        assert!(retired.is_empty());
+        let retired = take_retired(&mut objects, |_| Ok(true), |_| unreachable!()).unwrap();
+        assert_eq!(handles(&retired), [1]);
+        assert!(objects.is_empty());
+    }
+
+    #[test]
+    fn errors_keep_objects_pending() {
+        let mut objects = vec![
+            pending(1, timeline(20, 1)),
+            pending(2, RetirePoint::Fence(fence(10))),
+        ];
+        let result = take_retired(
+            &mut objects,
+            |_| Err(vk::Status::ErrorDeviceLost),
+            |_| Ok(1),
+        );
+        assert!(matches!(result, Err(vk::Status::ErrorDeviceLost)));
+        assert_eq!(handles(&objects), [1, 2]);
+    }
+}
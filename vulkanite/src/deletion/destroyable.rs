@@ -0,0 +1,340 @@
+use super::{destroy_erased, DestroyFn, Destroyable};
+use crate::{
+    vk::{self, raw},
+    Allocator, Dispatcher,
+};
+impl Destroyable for raw::DeviceMemory {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        device.free_memory(Some(self))
+    }
+}
+impl Destroyable for raw::Fence {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_fence(Some(self)) }
+    }
+}
+impl Destroyable for raw::Semaphore {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_semaphore(Some(self)) }
+    }
+}
+impl Destroyable for raw::Event {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_event(Some(self)) }
+    }
+}
+impl Destroyable for raw::QueryPool {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_query_pool(Some(self)) }
+    }
+}
+impl Destroyable for raw::Buffer {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_buffer(Some(self)) }
+    }
+}
+impl Destroyable for raw::BufferView {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_buffer_view(Some(self)) }
+    }
+}
+impl Destroyable for raw::Image {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_image(Some(self)) }
+    }
+}
+impl Destroyable for raw::ImageView {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_image_view(Some(self)) }
+    }
+}
+impl Destroyable for raw::ShaderModule {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_shader_module(Some(self)) }
+    }
+}
+impl Destroyable for raw::PipelineCache {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_pipeline_cache(Some(self)) }
+    }
+}
+impl Destroyable for raw::Pipeline {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_pipeline(Some(self)) }
+    }
+}
+impl Destroyable for raw::PipelineLayout {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_pipeline_layout(Some(self)) }
+    }
+}
+impl Destroyable for raw::Sampler {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_sampler(Some(self)) }
+    }
+}
+impl Destroyable for raw::DescriptorSetLayout {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_descriptor_set_layout(Some(self)) }
+    }
+}
+impl Destroyable for raw::DescriptorPool {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_descriptor_pool(Some(self)) }
+    }
+}
+impl Destroyable for raw::Framebuffer {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_framebuffer(Some(self)) }
+    }
+}
+impl Destroyable for raw::RenderPass {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_render_pass(Some(self)) }
+    }
+}
+impl Destroyable for raw::CommandPool {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_command_pool(Some(self)) }
+    }
+}
+impl Destroyable for raw::SamplerYcbcrConversion {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_sampler_ycbcr_conversion(Some(self)) }
+    }
+}
+impl Destroyable for raw::DescriptorUpdateTemplate {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_descriptor_update_template(Some(self)) }
+    }
+}
+impl Destroyable for raw::PrivateDataSlot {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_private_data_slot(Some(self)) }
+    }
+}
+impl Destroyable for raw::SwapchainKHR {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_swapchain_khr(Some(self)) }
+    }
+}
+impl Destroyable for raw::VideoSessionKHR {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_video_session_khr(Some(self)) }
+    }
+}
+impl Destroyable for raw::VideoSessionParametersKHR {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_video_session_parameters_khr(Some(self)) }
+    }
+}
+#[cfg(feature = "vendor-nvx")]
+impl Destroyable for raw::CuModuleNVX {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_cu_module_nvx(self) }
+    }
+}
+#[cfg(feature = "vendor-nvx")]
+impl Destroyable for raw::CuFunctionNVX {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_cu_function_nvx(self) }
+    }
+}
+impl Destroyable for raw::AccelerationStructureKHR {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_acceleration_structure_khr(Some(self)) }
+    }
+}
+impl Destroyable for raw::ValidationCacheEXT {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_validation_cache_ext(Some(self)) }
+    }
+}
+#[cfg(feature = "vendor-nv")]
+impl Destroyable for raw::AccelerationStructureNV {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_acceleration_structure_nv(Some(self)) }
+    }
+}
+impl Destroyable for raw::DeferredOperationKHR {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_deferred_operation_khr(Some(self)) }
+    }
+}
+#[cfg(feature = "vendor-nv")]
+impl Destroyable for raw::IndirectCommandsLayoutNV {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_indirect_commands_layout_nv(Some(self)) }
+    }
+}
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+impl Destroyable for raw::CudaModuleNV {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_cuda_module_nv(self) }
+    }
+}
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+impl Destroyable for raw::CudaFunctionNV {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_cuda_function_nv(self) }
+    }
+}
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+impl Destroyable for raw::BufferCollectionFUCHSIA {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_buffer_collection_fuchsia(self) }
+    }
+}
+impl Destroyable for raw::MicromapEXT {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_micromap_ext(Some(self)) }
+    }
+}
+#[cfg(feature = "vendor-nv")]
+impl Destroyable for raw::OpticalFlowSessionNV {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_optical_flow_session_nv(self) }
+    }
+}
+impl Destroyable for raw::ShaderEXT {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_shader_ext(Some(self)) }
+    }
+}
+impl Destroyable for raw::PipelineBinaryKHR {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_pipeline_binary_khr(Some(self)) }
+    }
+}
+impl Destroyable for raw::IndirectCommandsLayoutEXT {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_indirect_commands_layout_ext(Some(self)) }
+    }
+}
+impl Destroyable for raw::IndirectExecutionSetEXT {
+    #[inline]
+    unsafe fn destroy<D: Dispatcher, A: Allocator>(&self, device: &vk::rs::Device<D, A>) {
+        unsafe { device.destroy_indirect_execution_set_ext(Some(self)) }
+    }
+}
+#[doc = r" Return the function destroying the objects of the given type, or None if this type is not [Destroyable]"]
+pub(super) fn destroy_fn<D: Dispatcher, A: Allocator>(
+    object_type: vk::ObjectType,
+) -> Option<DestroyFn<D, A>> {
+    match object_type {
+        vk::ObjectType::DeviceMemory => Some(destroy_erased::<raw::DeviceMemory, D, A>),
+        vk::ObjectType::Fence => Some(destroy_erased::<raw::Fence, D, A>),
+        vk::ObjectType::Semaphore => Some(destroy_erased::<raw::Semaphore, D, A>),
+        vk::ObjectType::Event => Some(destroy_erased::<raw::Event, D, A>),
+        vk::ObjectType::QueryPool => Some(destroy_erased::<raw::QueryPool, D, A>),
+        vk::ObjectType::Buffer => Some(destroy_erased::<raw::Buffer, D, A>),
+        vk::ObjectType::BufferView => Some(destroy_erased::<raw::BufferView, D, A>),
+        vk::ObjectType::Image => Some(destroy_erased::<raw::Image, D, A>),
+        vk::ObjectType::ImageView => Some(destroy_erased::<raw::ImageView, D, A>),
+        vk::ObjectType::ShaderModule => Some(destroy_erased::<raw::ShaderModule, D, A>),
+        vk::ObjectType::PipelineCache => Some(destroy_erased::<raw::PipelineCache, D, A>),
+        vk::ObjectType::Pipeline => Some(destroy_erased::<raw::Pipeline, D, A>),
+        vk::ObjectType::PipelineLayout => Some(destroy_erased::<raw::PipelineLayout, D, A>),
+        vk::ObjectType::Sampler => Some(destroy_erased::<raw::Sampler, D, A>),
+        vk::ObjectType::DescriptorSetLayout => {
+            Some(destroy_erased::<raw::DescriptorSetLayout, D, A>)
+        }
+        vk::ObjectType::DescriptorPool => Some(destroy_erased::<raw::DescriptorPool, D, A>),
+        vk::ObjectType::Framebuffer => Some(destroy_erased::<raw::Framebuffer, D, A>),
+        vk::ObjectType::RenderPass => Some(destroy_erased::<raw::RenderPass, D, A>),
+        vk::ObjectType::CommandPool => Some(destroy_erased::<raw::CommandPool, D, A>),
+        vk::ObjectType::SamplerYcbcrConversion => {
+            Some(destroy_erased::<raw::SamplerYcbcrConversion, D, A>)
+        }
+        vk::ObjectType::DescriptorUpdateTemplate => {
+            Some(destroy_erased::<raw::DescriptorUpdateTemplate, D, A>)
+        }
+        vk::ObjectType::PrivateDataSlot => Some(destroy_erased::<raw::PrivateDataSlot, D, A>),
+        vk::ObjectType::SwapchainKHR => Some(destroy_erased::<raw::SwapchainKHR, D, A>),
+        vk::ObjectType::VideoSessionKHR => Some(destroy_erased::<raw::VideoSessionKHR, D, A>),
+        vk::ObjectType::VideoSessionParametersKHR => {
+            Some(destroy_erased::<raw::VideoSessionParametersKHR, D, A>)
+        }
+        #[cfg(feature = "vendor-nvx")]
+        vk::ObjectType::CuModuleNVX => Some(destroy_erased::<raw::CuModuleNVX, D, A>),
+        #[cfg(feature = "vendor-nvx")]
+        vk::ObjectType::CuFunctionNVX => Some(destroy_erased::<raw::CuFunctionNVX, D, A>),
+        vk::ObjectType::AccelerationStructureKHR => {
+            Some(destroy_erased::<raw::AccelerationStructureKHR, D, A>)
+        }
+        vk::ObjectType::ValidationCacheEXT => Some(destroy_erased::<raw::ValidationCacheEXT, D, A>),
+        #[cfg(feature = "vendor-nv")]
+        vk::ObjectType::AccelerationStructureNV => {
+            Some(destroy_erased::<raw::AccelerationStructureNV, D, A>)
+        }
+        vk::ObjectType::DeferredOperationKHR => {
+            Some(destroy_erased::<raw::DeferredOperationKHR, D, A>)
+        }
+        #[cfg(feature = "vendor-nv")]
+        vk::ObjectType::IndirectCommandsLayoutNV => {
+            Some(destroy_erased::<raw::IndirectCommandsLayoutNV, D, A>)
+        }
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+        vk::ObjectType::CudaModuleNV => Some(destroy_erased::<raw::CudaModuleNV, D, A>),
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+        vk::ObjectType::CudaFunctionNV => Some(destroy_erased::<raw::CudaFunctionNV, D, A>),
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+        vk::ObjectType::BufferCollectionFUCHSIA => {
+            Some(destroy_erased::<raw::BufferCollectionFUCHSIA, D, A>)
+        }
+        vk::ObjectType::MicromapEXT => Some(destroy_erased::<raw::MicromapEXT, D, A>),
+        #[cfg(feature = "vendor-nv")]
+        vk::ObjectType::OpticalFlowSessionNV => {
+            Some(destroy_erased::<raw::OpticalFlowSessionNV, D, A>)
+        }
+        vk::ObjectType::ShaderEXT => Some(destroy_erased::<raw::ShaderEXT, D, A>),
+        vk::ObjectType::PipelineBinaryKHR => Some(destroy_erased::<raw::PipelineBinaryKHR, D, A>),
+        vk::ObjectType::IndirectCommandsLayoutEXT => {
+            Some(destroy_erased::<raw::IndirectCommandsLayoutEXT, D, A>)
+        }
+        vk::ObjectType::IndirectExecutionSetEXT => {
+            Some(destroy_erased::<raw::IndirectExecutionSetEXT, D, A>)
+        }
+        _ => None,
+    }
+}
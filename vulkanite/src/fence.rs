@@ -0,0 +1,715 @@
+//! Futures resolved once a fence is signaled
+//!
+//! Calling [vk::rs::Device::wait_for_fences] from an async task blocks the executor thread it is running on.
+//! A [FenceWaiter] instead owns a background thread which waits on all the pending fences at once
+//! (using a single `vkWaitForFences` call with `waitAll` set to false) and resolves the matching [FenceFuture]
+//! once a fence is signaled. As it only needs a [FenceSource], the waiter can be used with any executor.
+//!
+//! A `vkWaitForFences` call cannot be interrupted and a fence cannot be signaled from the host, so a thread
+//! blocked in a wait cannot be woken up when a fence is added. Such a fence is instead handed to an idle
+//! thread of the waiter, woken up through a condition variable, or to a new thread if all of them are waiting.
+//! A thread whose wait returns picks the fences added in the meantime up and exits if another thread is already idle,
+//! so a fence never waits for the wait of another one to return. The time spent in a single call is bounded by
+//! the batch timeout (1s by default), which only bounds how long the fences of dropped futures keep being waited on.
+//! The threads sleep without polling when there is no pending fence.
+//!
+//! ```ignore
+//! // a single waiter can be shared by the whole application
+//! let waiter = FenceWaiter::new(device.clone())?;
+//! queue.submit(&[submit_info], Some(&fence))?;
+//! waiter.wait(fence).await?;
+//! ```
+//!
+//! On Linux, when VK_KHR_external_fence_fd is enabled and the fence has been created as exportable to
+//! a sync file, [SyncFdWaiter] can be used instead: the fences are then waited for using epoll, on a single thread.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll};
+
+use crate::completion::{complete, is_cancelled, Cancel, CompletionFuture, SharedCompletion};
+use crate::{vk, Allocator, Dispatcher};
+
+/// Default maximum time spent in a single `vkWaitForFences` call, in nanoseconds
+/// This bounds how long the fences of dropped futures keep being waited on, new fences never wait for it
+pub const DEFAULT_BATCH_TIMEOUT: u64 = 1_000_000_000;
+
+/// Object able to wait on a set of fences
+/// This is implemented by [vk::rs::Device] and can be implemented by a mock object to test the batching logic
+pub trait FenceSource: Send + Sync + 'static {
+    type Fence: Copy + Send + 'static;
+
+    /// Wait for at least one of the fences to be signaled
+    /// Return true if it happened before the timeout expired
+    fn wait_any(&self, fences: &[Self::Fence], timeout: u64) -> vk::Result<bool>;
+
+    /// Return true if the fence is currently signaled
+    fn is_signaled(&self, fence: &Self::Fence) -> vk::Result<bool>;
+}
+
+impl<D, A> FenceSource for vk::rs::Device<D, A>
+where
+    D: Dispatcher + Send + Sync + 'static,
+    A: Allocator + Send + Sync + 'static,
+{
+    type Fence = vk::rs::Fence;
+
+    fn wait_any(&self, fences: &[Self::Fence], timeout: u64) -> vk::Result<bool> {
+        Ok(
+            self.wait_for_fences(fences, false, timeout)?
+                == vk::codes::WaitForFencesStatus::Success,
+        )
+    }
+
+    fn is_signaled(&self, fence: &Self::Fence) -> vk::Result<bool> {
+        Ok(self.get_fence_status(fence)? == vk::codes::GetFenceStatusStatus::Success)
+    }
+}
+
+/// Future resolved once a fence is signaled, see the [module level documentation](self)
+/// Once resolved, polling the future again returns the same result
+pub struct FenceFuture(CompletionFuture);
+
+impl FenceFuture {
+    fn new(on_cancel: Option<Arc<dyn Cancel>>) -> (Self, SharedCompletion) {
+        let (future, completion) = CompletionFuture::new(on_cancel);
+        (Self(future), completion)
+    }
+}
+
+impl Future for FenceFuture {
+    type Output = vk::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+/// Set of fences being waited on by a waiter thread
+struct Batch<F> {
+    entries: Vec<(F, SharedCompletion)>,
+}
+
+impl<F: Copy> Batch<F> {
+    /// Drop the cancelled entries, wait for any of the remaining fences to be signaled
+    /// and resolve the futures of all the signaled fences
+    fn process<S: FenceSource<Fence = F>>(&mut self, source: &S, timeout: u64) {
+        self.entries
+            .retain(|(_, completion)| !is_cancelled(completion));
+        if self.entries.is_empty() {
+            return;
+        }
+
+        let fences: Vec<F> = self.entries.iter().map(|(fence, _)| *fence).collect();
+        match source.wait_any(&fences, timeout) {
+            Ok(true) => (),
+            Ok(false) => return,
+            Err(err) => {
+                for (_, completion) in self.entries.drain(..) {
+                    complete(&completion, Err(err));
+                }
+                return;
+            }
+        }
+
+        self.entries
+            .retain(|(fence, completion)| match source.is_signaled(fence) {
+                Ok(false) => true,
+                Ok(true) => {
+                    complete(completion, Ok(()));
+                    false
+                }
+                Err(err) => {
+                    complete(completion, Err(err));
+                    false
+                }
+            });
+    }
+}
+
+struct WaiterState<F> {
+    incoming: Vec<(F, SharedCompletion)>,
+    /// Number of threads sleeping until a fence is added
+    idle: usize,
+    shutdown: bool,
+}
+
+struct WaiterShared<S: FenceSource> {
+    source: S,
+    batch_timeout: u64,
+    state: Mutex<WaiterState<S::Fence>>,
+    /// Wakes up the idle thread when a fence is added
+    condvar: Condvar,
+}
+
+impl<S: FenceSource> WaiterShared<S> {
+    fn spawn(self: &Arc<Self>) -> io::Result<()> {
+        let shared = self.clone();
+        std::thread::Builder::new()
+            .name("vulkanite-fence-waiter".to_owned())
+            .spawn(move || shared.run())?;
+        Ok(())
+    }
+
+    fn run(&self) {
+        let mut batch = Batch {
+            entries: Vec::new(),
+        };
+        loop {
+            {
+                let mut state = self.state.lock().unwrap();
+                if batch.entries.is_empty() && state.incoming.is_empty() {
+                    // a single idle thread is kept to pick the next fences up
+                    if state.shutdown || state.idle > 0 {
+                        return;
+                    }
+                    state.idle += 1;
+                    while state.incoming.is_empty() && !state.shutdown {
+                        state = self.condvar.wait(state).unwrap();
+                    }
+                    state.idle -= 1;
+                }
+                // the fences added during the previous wait and not picked by another thread join this one
+                batch.entries.append(&mut state.incoming);
+                if batch.entries.is_empty() {
+                    return;
+                }
+            }
+            batch.process(&self.source, self.batch_timeout);
+        }
+    }
+}
+
+/// Background threads resolving [FenceFuture], see the [module level documentation](self)
+/// When the waiter is dropped, its threads keep running until all the pending fences
+/// are signaled or their future dropped
+pub struct FenceWaiter<S: FenceSource> {
+    shared: Arc<WaiterShared<S>>,
+}
+
+impl<S: FenceSource> FenceWaiter<S> {
+    pub fn new(source: S) -> io::Result<Self> {
+        Self::with_batch_timeout(source, DEFAULT_BATCH_TIMEOUT)
+    }
+
+    /// Create a waiter whose threads spend at most `batch_timeout` nanoseconds in a single wait
+    /// A lower value lets the fences of dropped futures stop being waited on sooner but increases the number of wait calls made
+    pub fn with_batch_timeout(source: S, batch_timeout: u64) -> io::Result<Self> {
+        let shared = Arc::new(WaiterShared {
+            source,
+            batch_timeout,
+            state: Mutex::new(WaiterState {
+                incoming: Vec::new(),
+                idle: 0,
+                shutdown: false,
+            }),
+            condvar: Condvar::new(),
+        });
+        shared.spawn()?;
+        Ok(Self { shared })
+    }
+
+    /// Return a future resolved once the fence is signaled
+    /// The fence must not be reset or destroyed before the future is resolved
+    pub fn wait(&self, fence: S::Fence) -> FenceFuture {
+        let (future, completion) = FenceFuture::new(None);
+        let idle = {
+            let mut state = self.shared.state.lock().unwrap();
+            state.incoming.push((fence, completion));
+            state.idle > 0
+        };
+        if idle {
+            self.shared.condvar.notify_one();
+        } else {
+            // if no thread can be started, the fence is picked up once a wait returns
+            let _ = self.shared.spawn();
+        }
+        future
+    }
+}
+
+impl<S: FenceSource> Drop for FenceWaiter<S> {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().shutdown = true;
+        self.shared.condvar.notify_all();
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use sync_fd::SyncFdWaiter;
+
+#[cfg(target_os = "linux")]
+mod sync_fd {
+    use std::collections::HashMap;
+    use std::ffi::{c_int, c_uint};
+    use std::fs::File;
+    use std::io::{self, Read, Write};
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::sync::{Arc, Mutex};
+
+    use super::{complete, is_cancelled, Cancel, FenceFuture, SharedCompletion};
+    use crate::{vk, Allocator, Dispatcher};
+
+    const EPOLL_CLOEXEC: c_int = 0o2000000;
+    const EPOLL_CTL_ADD: c_int = 1;
+    const EPOLLIN: u32 = 0x001;
+    const EPOLLERR: u32 = 0x008;
+    const EFD_CLOEXEC: c_int = 0o2000000;
+    const MAX_EVENTS: usize = 32;
+    /// Token used for the eventfd waking the waiter thread
+    const WAKE_TOKEN: u64 = u64::MAX;
+
+    #[repr(C)]
+    #[cfg_attr(target_arch = "x86_64", repr(packed))]
+    #[derive(Clone, Copy, Default)]
+    struct EpollEvent {
+        events: u32,
+        data: u64,
+    }
+
+    extern "C" {
+        fn epoll_create1(flags: c_int) -> c_int;
+        fn epoll_ctl(epfd: c_int, op: c_int, fd: c_int, event: *mut EpollEvent) -> c_int;
+        fn epoll_wait(
+            epfd: c_int,
+            events: *mut EpollEvent,
+            maxevents: c_int,
+            timeout: c_int,
+        ) -> c_int;
+        fn eventfd(initval: c_uint, flags: c_int) -> c_int;
+    }
+
+    fn check_fd(fd: c_int) -> vk::Result<OwnedFd> {
+        if fd < 0 {
+            Err(vk::Status::ErrorInitializationFailed)
+        } else {
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        }
+    }
+
+    struct SyncFdState {
+        incoming: Vec<(OwnedFd, SharedCompletion)>,
+        shutdown: bool,
+        /// Set when epoll failed, the waiter thread has then stopped
+        failed: bool,
+    }
+
+    struct SyncFdShared {
+        state: Mutex<SyncFdState>,
+        wake: File,
+    }
+
+    impl SyncFdShared {
+        fn wake(&self) {
+            let _ = (&self.wake).write(&1u64.to_ne_bytes());
+        }
+    }
+
+    impl Cancel for SyncFdShared {
+        fn cancel(&self) {
+            // the waiter thread closes the file descriptors of the dropped futures once woken up
+            self.wake();
+        }
+    }
+
+    /// Linux-only fence waiter using sync files and epoll instead of `vkWaitForFences`
+    /// This requires VK_KHR_external_fence_fd to be enabled and the fences to be created with a
+    /// [vk::ExportFenceCreateInfo] containing [vk::ExternalFenceHandleTypeFlags::SyncFd]
+    pub struct SyncFdWaiter {
+        shared: Arc<SyncFdShared>,
+    }
+
+    impl SyncFdWaiter {
+        pub fn new() -> vk::Result<Self> {
+            let epoll = check_fd(unsafe { epoll_create1(EPOLL_CLOEXEC) })?;
+            let wake = File::from(check_fd(unsafe { eventfd(0, EFD_CLOEXEC) })?);
+
+            let mut event = EpollEvent {
+                events: EPOLLIN,
+                data: WAKE_TOKEN,
+            };
+            if unsafe {
+                epoll_ctl(
+                    epoll.as_raw_fd(),
+                    EPOLL_CTL_ADD,
+                    wake.as_raw_fd(),
+                    &mut event,
+                )
+            } < 0
+            {
+                return Err(vk::Status::ErrorInitializationFailed);
+            }
+
+            let shared = Arc::new(SyncFdShared {
+                state: Mutex::new(SyncFdState {
+                    incoming: Vec::new(),
+                    shutdown: false,
+                    failed: false,
+                }),
+                wake,
+            });
+            let thread_shared = shared.clone();
+            std::thread::Builder::new()
+                .name("vulkanite-sync-fd-waiter".to_owned())
+                .spawn(move || Self::run(epoll, &thread_shared))
+                .map_err(|_| vk::Status::ErrorInitializationFailed)?;
+
+            Ok(Self { shared })
+        }
+
+        /// Export the fence as a sync file and return a future resolved once it is signaled
+        /// Note that, as required by the specification, exporting a sync file resets the fence
+        pub fn wait<D: Dispatcher, A: Allocator>(
+            &self,
+            device: &vk::rs::Device<D, A>,
+            fence: &vk::rs::Fence,
+        ) -> vk::Result<FenceFuture> {
+            let fd = device.get_fence_fd_khr(
+                &vk::FenceGetFdInfoKHR::default()
+                    .fence(fence)
+                    .handle_type(vk::ExternalFenceHandleTypeFlags::SyncFd),
+            )?;
+
+            let (future, completion) = FenceFuture::new(Some(self.shared.clone()));
+            if fd == -1 {
+                // the fence was already signaled
+                complete(&completion, Ok(()));
+                return Ok(future);
+            }
+
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            let mut state = self.shared.state.lock().unwrap();
+            if state.failed {
+                complete(&completion, Err(vk::Status::ErrorUnknown));
+                return Ok(future);
+            }
+            state.incoming.push((fd, completion));
+            drop(state);
+            self.shared.wake();
+            Ok(future)
+        }
+
+        fn run(epoll: OwnedFd, shared: &SyncFdShared) {
+            let mut entries: HashMap<u64, (OwnedFd, SharedCompletion)> = HashMap::new();
+            let mut next_token = 0u64;
+            let mut events = [EpollEvent::default(); MAX_EVENTS];
+
+            loop {
+                let count = unsafe {
+                    epoll_wait(
+                        epoll.as_raw_fd(),
+                        events.as_mut_ptr(),
+                        MAX_EVENTS as c_int,
+                        -1,
+                    )
+                };
+                if count < 0 {
+                    if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                        continue;
+                    }
+                    let mut state = shared.state.lock().unwrap();
+                    state.failed = true;
+                    let pending = entries.drain().map(|(_, (_, completion))| completion);
+                    for completion in pending.chain(state.incoming.drain(..).map(|(_, c)| c)) {
+                        complete(&completion, Err(vk::Status::ErrorUnknown));
+                    }
+                    return;
+                }
+
+                for event in &events[..count as usize] {
+                    let (flags, token) = (event.events, event.data);
+                    if token == WAKE_TOKEN {
+                        let mut buf = [0u8; 8];
+                        let _ = (&shared.wake).read(&mut buf);
+                        continue;
+                    }
+                    if let Some((_, completion)) = entries.remove(&token) {
+                        let result = if flags & EPOLLIN != 0 {
+                            Ok(())
+                        } else if flags & EPOLLERR != 0 {
+                            Err(vk::Status::ErrorDeviceLost)
+                        } else {
+                            Err(vk::Status::ErrorUnknown)
+                        };
+                        complete(&completion, result);
+                    }
+                }
+
+                let mut state = shared.state.lock().unwrap();
+                for (fd, completion) in state.incoming.drain(..) {
+                    let mut event = EpollEvent {
+                        events: EPOLLIN,
+                        data: next_token,
+                    };
+                    let res = unsafe {
+                        epoll_ctl(epoll.as_raw_fd(), EPOLL_CTL_ADD, fd.as_raw_fd(), &mut event)
+                    };
+                    if res < 0 {
+                        complete(&completion, Err(vk::Status::ErrorUnknown));
+                        continue;
+                    }
+                    entries.insert(next_token, (fd, completion));
+                    next_token += 1;
+                }
+                // closing the file descriptor also removes it from the epoll set
+                entries.retain(|_, (_, completion)| !is_cancelled(completion));
+                if state.shutdown && entries.is_empty() {
+                    return;
+                }
+            }
+        }
+    }
+
+    impl Drop for SyncFdWaiter {
+        fn drop(&mut self) {
+            self.shared.state.lock().unwrap().shutdown = true;
+            self.shared.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+    use std::time::Duration;
+
+    use super::{Batch, FenceFuture, FenceSource, FenceWaiter, WaiterShared, WaiterState};
+    use crate::vk;
+
+    #[derive(Default)]
+    struct MockState {
+        signaled: HashSet<u32>,
+        device_lost: bool,
+        wait_calls: usize,
+        /// Largest number of fences given to a single wait
+        max_batch: usize,
+    }
+
+    /// Fence source whose fences are integers signaled by the test
+    #[derive(Clone, Default)]
+    struct MockSource {
+        state: Arc<(Mutex<MockState>, Condvar)>,
+    }
+
+    impl MockSource {
+        fn signal(&self, fence: u32) {
+            self.state.0.lock().unwrap().signaled.insert(fence);
+            self.state.1.notify_all();
+        }
+
+        fn lose_device(&self) {
+            self.state.0.lock().unwrap().device_lost = true;
+            self.state.1.notify_all();
+        }
+
+        fn wait_calls(&self) -> usize {
+            self.state.0.lock().unwrap().wait_calls
+        }
+
+        fn max_batch(&self) -> usize {
+            self.state.0.lock().unwrap().max_batch
+        }
+    }
+
+    impl FenceSource for MockSource {
+        type Fence = u32;
+
+        fn wait_any(&self, fences: &[u32], timeout: u64) -> vk::Result<bool> {
+            let (state, condvar) = &*self.state;
+            let mut state = state.lock().unwrap();
+            state.wait_calls += 1;
+            state.max_batch = state.max_batch.max(fences.len());
+            let (state, _) = condvar
+                .wait_timeout_while(state, Duration::from_nanos(timeout), |state| {
+                    !state.device_lost && !fences.iter().any(|f| state.signaled.contains(f))
+                })
+                .unwrap();
+            if state.device_lost {
+                return Err(vk::Status::ErrorDeviceLost);
+            }
+            Ok(fences.iter().any(|f| state.signaled.contains(f)))
+        }
+
+        fn is_signaled(&self, fence: &u32) -> vk::Result<bool> {
+            let state = self.state.0.lock().unwrap();
+            if state.device_lost {
+                return Err(vk::Status::ErrorDeviceLost);
+            }
+            Ok(state.signaled.contains(fence))
+        }
+    }
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn poll(future: &mut FenceFuture) -> Poll<vk::Result<()>> {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    fn block_on(mut future: FenceFuture) -> vk::Result<()> {
+        loop {
+            match poll(&mut future) {
+                Poll::Ready(result) => return result,
+                Poll::Pending => thread::park_timeout(Duration::from_secs(1)),
+            }
+        }
+    }
+
+    fn batch_with(fences: &[u32]) -> (Batch<u32>, Vec<FenceFuture>) {
+        let (entries, futures) = fences
+            .iter()
+            .map(|&fence| {
+                let (future, completion) = FenceFuture::new(None);
+                ((fence, completion), future)
+            })
+            .unzip();
+        (Batch { entries }, futures)
+    }
+
+    #[test]
+    fn process_signaled() {
+        let source = MockSource::default();
+        let (mut batch, mut futures) = batch_with(&[1, 2]);
+        source.signal(1);
+        batch.process(&source, 0);
+
+        assert_eq!(poll(&mut futures[0]), Poll::Ready(Ok(())));
+        assert_eq!(poll(&mut futures[1]), Poll::Pending);
+        assert_eq!(batch.entries.len(), 1);
+        assert_eq!(batch.entries[0].0, 2);
+        // a resolved future keeps returning its result
+        assert_eq!(poll(&mut futures[0]), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn process_pending_timeout() {
+        let source = MockSource::default();
+        let (mut batch, mut futures) = batch_with(&[1, 2]);
+        batch.process(&source, 1_000);
+
+        assert_eq!(source.wait_calls(), 1);
+        assert_eq!(batch.entries.len(), 2);
+        assert!(futures.iter_mut().all(|future| poll(future).is_pending()));
+    }
+
+    #[test]
+    fn process_cancelled() {
+        let source = MockSource::default();
+        let (mut batch, mut futures) = batch_with(&[1, 2]);
+        drop(futures.remove(0));
+        batch.process(&source, 0);
+        assert_eq!(batch.entries.len(), 1);
+        assert_eq!(batch.entries[0].0, 2);
+
+        // no wait is made once all the futures are dropped
+        drop(futures);
+        batch.process(&source, 0);
+        assert!(batch.entries.is_empty());
+        assert_eq!(source.wait_calls(), 1);
+    }
+
+    #[test]
+    fn process_device_lost() {
+        let source = MockSource::default();
+        let (mut batch, mut futures) = batch_with(&[1, 2]);
+        source.lose_device();
+        batch.process(&source, 0);
+
+        assert!(batch.entries.is_empty());
+        for future in &mut futures {
+            assert_eq!(poll(future), Poll::Ready(Err(vk::Status::ErrorDeviceLost)));
+        }
+    }
+
+    #[test]
+    fn waiter_does_not_delay_new_fences() {
+        let source = MockSource::default();
+        // a fence added during a wait would time out the test if it had to wait for it to return
+        let waiter = FenceWaiter::with_batch_timeout(source.clone(), u64::MAX).unwrap();
+        let first = waiter.wait(1);
+        while source.wait_calls() == 0 {
+            thread::yield_now();
+        }
+        let second = waiter.wait(2);
+        let third = waiter.wait(3);
+
+        source.signal(3);
+        assert_eq!(block_on(third), Ok(()));
+        source.signal(2);
+        assert_eq!(block_on(second), Ok(()));
+        source.signal(1);
+        assert_eq!(block_on(first), Ok(()));
+    }
+
+    fn shared_with(
+        source: &MockSource,
+        fences: &[u32],
+        idle: usize,
+    ) -> (WaiterShared<MockSource>, Vec<FenceFuture>) {
+        let (batch, futures) = batch_with(fences);
+        let shared = WaiterShared {
+            source: source.clone(),
+            batch_timeout: 0,
+            state: Mutex::new(WaiterState {
+                incoming: batch.entries,
+                idle,
+                shutdown: true,
+            }),
+            condvar: Condvar::new(),
+        };
+        (shared, futures)
+    }
+
+    #[test]
+    fn run_batches_incoming_fences() {
+        let source = MockSource::default();
+        let (shared, futures) = shared_with(&source, &[1, 2, 3], 0);
+        for fence in 1..=3 {
+            source.signal(fence);
+        }
+        // the thread waits on all the incoming fences at once and exits once they are signaled
+        shared.run();
+        assert_eq!(source.wait_calls(), 1);
+        assert_eq!(source.max_batch(), 3);
+        for mut future in futures {
+            assert_eq!(poll(&mut future), Poll::Ready(Ok(())));
+        }
+    }
+
+    #[test]
+    fn run_keeps_a_single_idle_thread() {
+        let source = MockSource::default();
+        let (shared, _) = shared_with(&source, &[], 1);
+        shared.state.lock().unwrap().shutdown = false;
+        // another thread is already idle so this one exits instead of sleeping
+        shared.run();
+        assert_eq!(shared.state.lock().unwrap().idle, 1);
+        assert_eq!(source.wait_calls(), 0);
+    }
+
+    #[test]
+    fn waiter_sleeps_without_fences() {
+        let source = MockSource::default();
+        let waiter = FenceWaiter::new(source.clone()).unwrap();
+        source.signal(1);
+        assert_eq!(block_on(waiter.wait(1)), Ok(()));
+
+        // once the fence is signaled, no wait is made until a new fence is added
+        let wait_calls = source.wait_calls();
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(source.wait_calls(), wait_calls);
+    }
+}
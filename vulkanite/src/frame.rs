@@ -0,0 +1,472 @@
+//! Ring of per-frame objects used to have multiple frames in flight
+//!
+//! Each of the N frames of a [FrameRing] owns a command pool, a primary command buffer allocated from it,
+//! a binary semaphore and the synchronization primitive used to know when the GPU is done with the frame.
+//! This is either a fence or, when using [FrameSync::Timeline], a value of a timeline semaphore shared by all frames.
+//!
+//! ```ignore
+//! let mut frames: FrameRing<2> = FrameRing::new(&device, queue_family, FrameSync::Fence)?;
+//! loop {
+//!     // wait for the frame submitted two frames ago to be done and reset its command pool
+//!     frames.begin_frame()?;
+//!     let frame = frames.current();
+//!     frame.command_buffer().begin(&Default::default())?;
+//!     ...
+//!     queue.submit2(&[submit_info], frames.submit_fence()?)?;
+//!     frames.mark_submitted();
+//! }
+//! ```
+//!
+//! A frame is only waited on once [FrameRing::mark_submitted] has been called, after the submission signaling
+//! its fence or timeline value succeeded. A frame can therefore be skipped (for example when the swapchain is out of date),
+//! or its submission fail, without the next [FrameRing::begin_frame] on the same slot waiting indefinitely.
+
+use crate::{vk, Allocator, DefaultAllocator, Dispatcher, DynamicDispatcher};
+
+/// Synchronization primitive used to know when a frame has been retired by the GPU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameSync {
+    /// Each frame owns a fence which must be signaled by the last submission of the frame,
+    /// see [FrameRing::submit_fence]
+    Fence,
+    /// All frames share a timeline semaphore, the last submission of each frame must signal
+    /// the value given by [FrameRing::signal_info]
+    /// This requires the timelineSemaphore feature (core in Vulkan 1.2) to be enabled
+    Timeline,
+}
+
+/// Objects owned by a single frame of a [FrameRing]
+pub struct Frame<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    command_pool: vk::rs::CommandPool,
+    command_buffer: vk::rs::CommandBuffer<D, A>,
+    semaphore: vk::rs::Semaphore,
+    fence: Option<vk::rs::Fence>,
+    timeline_value: u64,
+    /// True once a submission signaling the fence or timeline value of the frame succeeded
+    submitted: bool,
+    deferred: Vec<Box<dyn FnOnce()>>,
+}
+
+impl<D: Dispatcher, A: Allocator> Frame<D, A> {
+    /// Command pool of the frame, it is reset by [FrameRing::begin_frame]
+    pub fn command_pool(&self) -> &vk::rs::CommandPool {
+        &self.command_pool
+    }
+
+    /// Primary command buffer allocated from the frame command pool
+    pub fn command_buffer(&self) -> &vk::rs::CommandBuffer<D, A> {
+        &self.command_buffer
+    }
+
+    /// Binary semaphore owned by the frame (it can for example be used to wait for the swapchain image acquisition)
+    pub fn semaphore(&self) -> &vk::rs::Semaphore {
+        &self.semaphore
+    }
+
+    /// Fence signaled by the last submission of the frame, use [FrameRing::submit_fence] to get it
+    /// for the submission
+    /// This is None when using [FrameSync::Timeline]
+    pub fn fence(&self) -> Option<&vk::rs::Fence> {
+        self.fence.as_ref()
+    }
+
+    /// Value of the ring timeline semaphore signaled when this frame is retired
+    /// This is always 0 when using [FrameSync::Fence]
+    pub fn timeline_value(&self) -> u64 {
+        self.timeline_value
+    }
+
+    /// Run the given function once the frame has been retired by the GPU
+    /// This is typically used to destroy objects which are still used by the frame command buffer
+    pub fn defer(&mut self, f: impl FnOnce() + 'static) {
+        self.deferred.push(Box::new(f));
+    }
+}
+
+/// Ring of N [Frame], see the [module level documentation](self)
+pub struct FrameRing<
+    const N: usize,
+    D: Dispatcher = DynamicDispatcher,
+    A: Allocator = DefaultAllocator,
+> {
+    device: vk::rs::Device<D, A>,
+    frames: [Frame<D, A>; N],
+    timeline: Option<vk::rs::Semaphore>,
+    frame_count: u64,
+}
+
+impl<const N: usize, D: Dispatcher, A: Allocator> FrameRing<N, D, A> {
+    /// Create the N frames, the command pools are created for the given queue family
+    pub fn new(
+        device: &vk::rs::Device<D, A>,
+        queue_family_index: u32,
+        sync: FrameSync,
+    ) -> vk::Result<Self> {
+        assert!(N > 0, "A frame ring must contain at least one frame");
+
+        let timeline = match sync {
+            FrameSync::Fence => None,
+            FrameSync::Timeline => {
+                let mut type_info = vk::SemaphoreTypeCreateInfo::default()
+                    .semaphore_type(vk::SemaphoreType::Timeline)
+                    .initial_value(0);
+                Some(device.create_semaphore(
+                    &vk::SemaphoreCreateInfo::default().push_next(&mut type_info),
+                )?)
+            }
+        };
+
+        let mut frames = Vec::with_capacity(N);
+        for _ in 0..N {
+            match Self::create_frame(device, queue_family_index, sync) {
+                Ok(frame) => frames.push(frame),
+                Err(err) => {
+                    unsafe {
+                        for frame in &frames {
+                            Self::destroy_frame(device, frame);
+                        }
+                        device.destroy_semaphore(timeline.as_deref());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        let Ok(frames) = frames.try_into() else {
+            unreachable!()
+        };
+
+        Ok(Self {
+            device: device.clone(),
+            frames,
+            timeline,
+            frame_count: 0,
+        })
+    }
+
+    /// Number of frames begun since the ring creation
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Index in the ring of the current frame
+    pub fn current_index(&self) -> usize {
+        (self.frame_count.max(1) - 1) as usize % N
+    }
+
+    /// Return the current frame, [FrameRing::begin_frame] must have been called at least once
+    pub fn current(&self) -> &Frame<D, A> {
+        &self.frames[self.current_index()]
+    }
+
+    pub fn current_mut(&mut self) -> &mut Frame<D, A> {
+        let index = self.current_index();
+        &mut self.frames[index]
+    }
+
+    pub fn frames(&self) -> &[Frame<D, A>; N] {
+        &self.frames
+    }
+
+    /// Timeline semaphore shared by all frames when using [FrameSync::Timeline]
+    pub fn timeline_semaphore(&self) -> Option<&vk::rs::Semaphore> {
+        self.timeline.as_ref()
+    }
+
+    /// Return the semaphore signal operation that must be part of the last submission of the current frame
+    /// when using [FrameSync::Timeline]
+    /// [FrameRing::mark_submitted] must be called once this submission succeeded
+    pub fn signal_info(&self) -> Option<vk::SemaphoreSubmitInfo<'_>> {
+        self.timeline.as_ref().map(|timeline| {
+            let frame = self.current();
+            vk::SemaphoreSubmitInfo::default()
+                .semaphore(timeline)
+                .value(frame.timeline_value)
+                .stage_mask(vk::PipelineStageFlags2::AllCommands)
+        })
+    }
+
+    /// Reset the fence of the current frame and return it, it must be given to the last submission of the frame
+    /// [FrameRing::mark_submitted] must be called once this submission succeeded
+    /// This is None when using [FrameSync::Timeline]
+    pub fn submit_fence(&mut self) -> vk::Result<Option<&vk::raw::Fence>> {
+        let index = self.current_index();
+        let frame = &self.frames[index];
+        if let Some(fence) = &frame.fence {
+            // the fence is in use by the submission of this frame if it has already been marked as submitted
+            if !frame.submitted {
+                self.device.reset_fences(fence)?;
+            }
+        }
+        Ok(frame.fence.as_deref())
+    }
+
+    /// Mark the current frame as submitted, to be called once the submission signaling the fence given by
+    /// [FrameRing::submit_fence] or the value given by [FrameRing::signal_info] succeeded
+    /// The frame is then waited on before being reused
+    /// If this is not called, the frame is not waited on: calling it before a successful submission would
+    /// make the next [FrameRing::begin_frame] on this frame wait indefinitely
+    pub fn mark_submitted(&mut self) {
+        self.current_mut().submitted = true;
+    }
+
+    /// Move to the next frame of the ring
+    /// This waits for the GPU to be done with the work previously submitted using this frame, runs
+    /// the functions deferred by this frame then resets its command pool
+    /// If the previous use of this frame was never marked as submitted, there is nothing to wait for
+    /// If the wait fails, the current frame is left unchanged and the next call waits on the same frame again
+    pub fn begin_frame(&mut self) -> vk::Result<&mut Frame<D, A>> {
+        let (device, timeline) = (&self.device, self.timeline.as_ref());
+        let index = advance_frame(
+            &mut self.frames,
+            &mut self.frame_count,
+            timeline.is_some(),
+            |frame| Self::wait_frame(device, timeline, frame),
+        )?;
+
+        let frame = &mut self.frames[index];
+        self.device
+            .reset_command_pool(&frame.command_pool, vk::CommandPoolResetFlags::empty())?;
+
+        Ok(frame)
+    }
+
+    /// Wait for all the frames to be retired, run their deferred functions
+    /// then destroy all the objects owned by the ring
+    /// # Safety
+    /// The ring must not be used after this call
+    pub unsafe fn destroy(&mut self) -> vk::Result<()> {
+        for frame in &mut self.frames {
+            retire_frame(frame, |frame| {
+                Self::wait_frame(&self.device, self.timeline.as_ref(), frame)
+            })?;
+        }
+        unsafe {
+            for frame in &self.frames {
+                Self::destroy_frame(&self.device, frame);
+            }
+            self.device.destroy_semaphore(self.timeline.as_deref());
+        }
+        Ok(())
+    }
+
+    fn wait_frame(
+        device: &vk::rs::Device<D, A>,
+        timeline: Option<&vk::rs::Semaphore>,
+        frame: &Frame<D, A>,
+    ) -> vk::Result<()> {
+        if let Some(fence) = &frame.fence {
+            device.wait_for_fences(fence, true, u64::MAX)?;
+        }
+        if let Some(timeline) = timeline {
+            device.wait_semaphores(
+                &vk::SemaphoreWaitInfo::default().semaphore(timeline, &frame.timeline_value),
+                u64::MAX,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn create_frame(
+        device: &vk::rs::Device<D, A>,
+        queue_family_index: u32,
+        sync: FrameSync,
+    ) -> vk::Result<Frame<D, A>> {
+        let command_pool = device.create_command_pool(
+            &vk::CommandPoolCreateInfo::default()
+                .flags(vk::CommandPoolCreateFlags::Transient)
+                .queue_family_index(queue_family_index),
+        )?;
+
+        let result = (|| {
+            let command_buffer = device
+                .allocate_command_buffers::<Vec<_>>(
+                    &vk::CommandBufferAllocateInfo::default()
+                        .command_pool(&command_pool)
+                        .level(vk::CommandBufferLevel::Primary)
+                        .command_buffer_count(1),
+                )?
+                .pop()
+                .ok_or(vk::Status::ErrorInitializationFailed)?;
+
+            let semaphore = device.create_semaphore(&Default::default())?;
+            let fence = match sync {
+                FrameSync::Fence => match device.create_fence(
+                    &vk::FenceCreateInfo::default().flags(vk::FenceCreateFlags::Signaled),
+                ) {
+                    Ok(fence) => Some(fence),
+                    Err(err) => {
+                        unsafe { device.destroy_semaphore(Some(&semaphore)) };
+                        return Err(err);
+                    }
+                },
+                FrameSync::Timeline => None,
+            };
+
+            Ok(Frame {
+                command_pool,
+                command_buffer,
+                semaphore,
+                fence,
+                timeline_value: 0,
+                submitted: false,
+                deferred: Vec::new(),
+            })
+        })();
+
+        if result.is_err() {
+            // this also frees the command buffer
+            unsafe { device.destroy_command_pool(Some(&command_pool)) };
+        }
+        result
+    }
+
+    unsafe fn destroy_frame(device: &vk::rs::Device<D, A>, frame: &Frame<D, A>) {
+        unsafe {
+            device.destroy_fence(frame.fence.as_deref());
+            device.destroy_semaphore(Some(&frame.semaphore));
+            device.destroy_command_pool(Some(&frame.command_pool));
+        }
+    }
+}
+
+/// Wait for the frame to be retired using `wait` if it has been submitted, then run its deferred functions
+fn retire_frame<D: Dispatcher, A: Allocator>(
+    frame: &mut Frame<D, A>,
+    wait: impl FnOnce(&Frame<D, A>) -> vk::Result<()>,
+) -> vk::Result<()> {
+    if frame.submitted {
+        wait(frame)?;
+        frame.submitted = false;
+    }
+    for f in frame.deferred.drain(..) {
+        f();
+    }
+    Ok(())
+}
+
+/// Retire the frame following the `frame_count` first ones then make it the current frame and return its index
+/// The frame count is only increased once the frame has been retired
+fn advance_frame<D: Dispatcher, A: Allocator>(
+    frames: &mut [Frame<D, A>],
+    frame_count: &mut u64,
+    timeline: bool,
+    wait: impl FnOnce(&Frame<D, A>) -> vk::Result<()>,
+) -> vk::Result<usize> {
+    let index = (*frame_count % frames.len() as u64) as usize;
+    let frame = &mut frames[index];
+    retire_frame(frame, wait)?;
+
+    *frame_count += 1;
+    if timeline {
+        frame.timeline_value = *frame_count;
+    }
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::num::{NonZeroU64, NonZeroUsize};
+    use std::rc::Rc;
+
+    use super::{advance_frame, Frame};
+    use crate::{vk, DefaultAllocator, DynamicDispatcher, Handle};
+
+    fn raw(value: u64) -> NonZeroU64 {
+        NonZeroU64::new(value).unwrap()
+    }
+
+    /// Frame whose handles are never given to Vulkan
+    fn frame(index: u64) -> Frame {
+        let command_buffer = unsafe {
+            vk::rs::CommandBuffer::from_inner(
+                vk::raw::CommandBuffer::from_raw(
+                    NonZeroUsize::new(10 * index as usize + 1).unwrap(),
+                ),
+                DynamicDispatcher(()),
+                DefaultAllocator,
+            )
+        };
+        Frame {
+            command_pool: vk::rs::CommandPool::from_inner(unsafe {
+                vk::raw::CommandPool::from_raw(raw(10 * index + 2))
+            }),
+            command_buffer,
+            semaphore: vk::rs::Semaphore::from_inner(unsafe {
+                vk::raw::Semaphore::from_raw(raw(10 * index + 3))
+            }),
+            fence: None,
+            timeline_value: 0,
+            submitted: false,
+            deferred: Vec::new(),
+        }
+    }
+
+    fn frames<const N: usize>() -> [Frame; N] {
+        std::array::from_fn(|index| frame(index as u64))
+    }
+
+    #[test]
+    fn slots_are_used_in_turn() {
+        let mut frames = frames::<3>();
+        let mut frame_count = 0;
+        let indices: Vec<usize> = (0..5)
+            .map(|_| advance_frame(&mut frames, &mut frame_count, true, |_| Ok(())).unwrap())
+            .collect();
+
+        assert_eq!(indices, [0, 1, 2, 0, 1]);
+        assert_eq!(frame_count, 5);
+        // each frame signals the timeline value of its last use
+        let values: Vec<u64> = frames.iter().map(|frame| frame.timeline_value).collect();
+        assert_eq!(values, [4, 5, 3]);
+    }
+
+    #[test]
+    fn only_submitted_frames_are_waited_on() {
+        let mut frames = frames::<2>();
+        let mut frame_count = 0;
+        let waited = Cell::new(0);
+        let wait = |_: &Frame| {
+            waited.set(waited.get() + 1);
+            Ok(())
+        };
+
+        advance_frame(&mut frames, &mut frame_count, false, wait).unwrap();
+        assert_eq!(waited.get(), 0);
+
+        frames[1].submitted = true;
+        advance_frame(&mut frames, &mut frame_count, false, wait).unwrap();
+        assert_eq!(waited.get(), 1);
+        assert!(!frames[1].submitted);
+        // the fence synchronization does not use timeline values
+        assert!(frames.iter().all(|frame| frame.timeline_value == 0));
+    }
+
+    #[test]
+    fn failed_wait_keeps_current_frame() {
+        let mut frames = frames::<2>();
+        let mut frame_count = 2;
+        let deferred_calls = Rc::new(Cell::new(0));
+        let calls = deferred_calls.clone();
+        frames[0].submitted = true;
+        frames[0]
+            .deferred
+            .push(Box::new(move || calls.set(calls.get() + 1)));
+
+        let result = advance_frame(&mut frames, &mut frame_count, true, |_| {
+            Err(vk::Status::ErrorDeviceLost)
+        });
+        assert_eq!(result, Err(vk::Status::ErrorDeviceLost));
+        assert_eq!(frame_count, 2);
+        assert!(frames[0].submitted);
+        assert_eq!(deferred_calls.get(), 0);
+
+        // the next call waits on the same frame again
+        let index = advance_frame(&mut frames, &mut frame_count, true, |_| Ok(())).unwrap();
+        assert_eq!(index, 0);
+        assert_eq!(frame_count, 3);
+        assert_eq!(frames[0].timeline_value, 3);
+        assert!(!frames[0].submitted);
+        assert_eq!(deferred_calls.get(), 1);
+    }
+}
@@ -139,6 +139,36 @@
 //! let device = physical_device.create_device(device_info.as_ref())?;
 //! ```
 //!
+//! ## Structure chains at runtime
+//! When the structures of a chain are only known at runtime, [vk::chain] lets you walk a `p_next` chain using its structure types
+//! and build a [StructureChainVec] from a list of structure types. Commands returning a structure chain have a `_into` variant
+//! filling such a chain:
+//!
+//! ```ignore
+//! let mut chain = StructureChainVec::from_structure_types(vk::PhysicalDeviceProperties2::default(), structure_types);
+//! physical_device.get_properties2_into(&mut chain);
+//! for header in Header::of(chain.as_ref()).iter() {
+//!     println!("{}", header.info().unwrap().name());
+//! }
+//! ```
+//!
+//! ## Owned structures
+//! The structures above borrow everything they point to. To build a create-info dynamically or to store it, the [vk::owned] module
+//! provides for each create-info structure an owned version which stores its arrays, pointed structures, strings and `p_next` chain:
+//!
+//! ```ignore
+//! let info = vk::owned::GraphicsPipelineCreateInfo::default()
+//!     .stages(stages)
+//!     .layout(Some(&layout))
+//!     .push_next(vk::owned::PipelineRenderingCreateInfo::default().color_attachment_formats(&formats));
+//! let cached_info = info.clone();
+//! let (_, pipelines): (_, Vec<_>) = device.create_graphics_pipelines(None, &info.as_raw())?;
+//! ```
+//!
+//! Owned structures implement `Eq` and `Hash` by comparing everything they point to, including their `p_next` chain,
+//! and can be deep-copied from a Vulkan structure with `From`. This makes them usable as the key of a pipeline cache:
+//! `HashMap<vk::owned::GraphicsPipelineCreateInfo, vk::rs::Pipeline>`.
+//!
 //! # Features
 //!
 //! The following features are available:
@@ -146,6 +176,13 @@
 //! - `smallvec`: Add support for the smallvec crate to minimize heap allocations, enabling this feature allows the following: `let physical_devices: SmallVec<[_; 3]> = instance.enumerate_physical_devices()?;`.
 //! - `arrayvec`: Add support for the arrayvec crate to minimize heap allocations, enabling this feature allows the following: `let pipeline: ArrayVec<_; 1> = device.create_compute_pipelines(None, &create_info)?;`.
 //! - `raw-window-handle`: Add interoperability with the raw-window-handle crate, to create surfaces from raw handles, see the [window] module
+//! - `async`: Add futures resolved once the GPU reaches a given point, which can be used with any async executor, see the [fence] module and [timeline::TimelineWaiter]
+//! - `status-codes`: Narrow the result of each command returning a `VkResult` to enums containing only the success and error codes this command can return, an unexpected code being kept in the `Other` variant of the error enum, see the [vk::codes] module
+//! - `recording-checks`: In debug builds, check that each command recorded in a command buffer is valid for its current state (recording, inside or outside a render pass instance, bound pipelines, level and queue family) and panic with a descriptive message otherwise. This catches mistakes even without the validation layers
+//! - `serde`: Derive `Serialize` and `Deserialize` for the enums, bitflags and the structs only containing plain data (such as [vk::PhysicalDeviceProperties] or [vk::SamplerCreateInfo]), which can be used to store device capabilities or load pipeline states. The `s_type` and `p_next` members are skipped and fixed-size strings are (de)serialized as strings
+//! - `platform-*` (`platform-win32`, `platform-android`, `platform-metal`, `platform-xlib`, `platform-wayland`...): Generate the types and commands of the extensions specific to this platform. Each of them is already enabled when compiling for a matching target OS (`platform-win32` on Windows, `platform-xlib`, `platform-xcb` and `platform-wayland` on Linux and BSDs...), the features are only needed to use them on other targets
+//! - `vendor-extensions` (enabled by default): Generate the types, enum values and commands of the vendor extensions (`VK_NV_*`, `VK_AMD_*`, `VK_INTEL_*`, `VK_QCOM_*`...). Each vendor also has its own feature (`vendor-nv`, `vendor-amd`, `vendor-intel`...): disabling the default features and only enabling the vendors you use reduces the size of the bindings and the compilation time
+//! - `provisional` (enabled by default): Generate the types and commands of the provisional (beta) extensions, whose API can still change
 //!
 //! # MSRV
 //! The current MSRV for this crate is Rust 1.77 (C-String literals are heavily used). It is not planned to increase
@@ -156,8 +193,16 @@
 //!
 //! Please be aware that this crate should not be considered production ready yet, breaking changes are to be expected in the future versions.
 
+#[cfg(feature = "async")]
+mod completion;
+pub mod deletion;
+#[cfg(feature = "async")]
+pub mod fence;
+pub mod frame;
 #[cfg(feature = "loaded")]
 mod loaded;
+pub mod swapchain;
+pub mod timeline;
 pub mod vk;
 #[cfg(feature = "raw-window-handle")]
 pub mod window;
@@ -1021,6 +1066,21 @@ where
         }
     }
 
+    /// Create a structure chain containing the default value of each given structure type,
+    /// which can then be filled by a command like [vk::rs::PhysicalDevice::get_properties2_into]
+    /// Will panic if one of these structure types is unknown or cannot extend H
+    pub fn from_structure_types(
+        head: H,
+        structure_types: impl IntoIterator<Item = vk::StructureType>,
+    ) -> Self {
+        let structure_types = structure_types.into_iter();
+        let mut chain = Self::new_with_capacity(head, structure_types.size_hint().0);
+        for structure_type in structure_types {
+            chain.push_structure_type(structure_type);
+        }
+        chain
+    }
+
     /// Add a new structure to the structure chain
     /// Note: No check is done that the structure is not already part of this structure chain
     /// When pushing a structure, it is pushed in a linked state
@@ -1029,6 +1089,22 @@ where
         self.content.push((Box::new(structure), Cell::new(true)));
     }
 
+    /// Same as [StructureChainVec::push] with the default value of the structure with the given type
+    /// Will panic if this structure type is unknown or cannot extend H
+    pub fn push_structure_type(&mut self, structure_type: vk::StructureType) {
+        let info = vk::chain::structure_info(structure_type)
+            .unwrap_or_else(|| panic!("Unknown structure type {structure_type:?}"));
+        if !info.extends().contains(&H::STRUCTURE_TYPE) {
+            panic!(
+                "{} cannot extend {}",
+                info.name(),
+                std::any::type_name::<H>()
+            )
+        }
+        self.has_changed.set(true);
+        self.content.push((info.create(), Cell::new(true)));
+    }
+
     fn perform_linking(&self) {
         self.has_changed.set(false);
         let mut prev_ptr = ptr::null();
@@ -1074,38 +1150,38 @@ where
     fn get_mut<T: ExtendingStructure<H>>(&mut self) -> &mut T {
         if H::STRUCTURE_TYPE == T::STRUCTURE_TYPE {
             self.perform_linking();
-            return unsafe { mem::transmute(self) };
+            return unsafe { &mut *ptr::from_mut(&mut self.head).cast() };
         }
 
         for (structure, _) in &mut self.content {
             let header = structure.header_mut();
-            if unsafe { (*header).s_type } == H::STRUCTURE_TYPE {
+            if unsafe { (*header).s_type } == T::STRUCTURE_TYPE {
                 return unsafe { mem::transmute(header) };
             }
         }
 
         panic!(
             "Type {} is not part of the structure chain",
-            std::any::type_name::<H>()
+            std::any::type_name::<T>()
         )
     }
 
     fn get<T: ExtendingStructure<H>>(&self) -> &T {
         if H::STRUCTURE_TYPE == T::STRUCTURE_TYPE {
             self.perform_linking();
-            return unsafe { mem::transmute(self) };
+            return unsafe { &*ptr::from_ref(&self.head).cast() };
         }
 
         for (structure, _) in &self.content {
             let header = structure.header();
-            if unsafe { (*header).s_type } == H::STRUCTURE_TYPE {
+            if unsafe { (*header).s_type } == T::STRUCTURE_TYPE {
                 return unsafe { mem::transmute(header) };
             }
         }
 
         panic!(
             "Type {} is not part of the structure chain",
-            std::any::type_name::<H>()
+            std::any::type_name::<T>()
         )
     }
 
@@ -1117,7 +1193,7 @@ where
 
         for (structure, is_linked) in &self.content {
             let header = structure.header();
-            if unsafe { (*header).s_type } == H::STRUCTURE_TYPE {
+            if unsafe { (*header).s_type } == T::STRUCTURE_TYPE {
                 is_linked.set(false);
                 return;
             }
@@ -1125,7 +1201,7 @@ where
 
         panic!(
             "Type {} is not part of the structure chain",
-            std::any::type_name::<H>()
+            std::any::type_name::<T>()
         )
     }
 
@@ -1137,7 +1213,7 @@ where
 
         for (structure, is_linked) in &self.content {
             let header = structure.header();
-            if unsafe { (*header).s_type } == H::STRUCTURE_TYPE {
+            if unsafe { (*header).s_type } == T::STRUCTURE_TYPE {
                 is_linked.set(true);
                 return;
             }
@@ -1145,7 +1221,7 @@ where
 
         panic!(
             "Type {} is not part of the structure chain",
-            std::any::type_name::<H>()
+            std::any::type_name::<T>()
         )
     }
 }
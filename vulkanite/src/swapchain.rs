@@ -0,0 +1,619 @@
+//! Swapchain helper taking care of the boilerplate needed to present images to a surface
+//!
+//! The [Swapchain] object selects a surface format, present mode and extent according to
+//! some [SwapchainPreferences], creates the swapchain images views along with the per-image
+//! synchronization primitives and recreates everything when the swapchain becomes out of date
+//! or suboptimal (for example after the window has been resized).
+//!
+//! A typical frame looks like:
+//! ```ignore
+//! let Some(image) = swapchain.acquire_next_image(u64::MAX)? else {
+//!     return Ok(());
+//! };
+//! // record and submit some work which waits on image.acquire_semaphore
+//! // and signals image.present_semaphore
+//! swapchain.present(&queue, &image)?;
+//! ```
+//!
+//! When the `swapchainMaintenance1` feature of VK_EXT_swapchain_maintenance1 has been enabled on the device,
+//! a fence is attached to every present operation. This allows the old swapchain resources to be released as soon
+//! as the presentation engine is done with them instead of waiting for the presentation queue to be idle.
+
+use crate::{
+    vk::{self, WidenResult},
+    Allocator, DefaultAllocator, Dispatcher, DynamicDispatcher,
+};
+
+/// Preferences used when selecting the swapchain properties
+/// For each property, if none of the preferred values is supported by the surface,
+/// a supported value is chosen instead
+#[derive(Clone)]
+pub struct SwapchainPreferences {
+    /// Surface formats, sorted from the most to the least preferred
+    pub formats: Vec<vk::SurfaceFormatKHR>,
+    /// Present modes, sorted from the most to the least preferred
+    /// If none of them is supported, [vk::PresentModeKHR::Fifo] (which is always supported) is used
+    pub present_modes: Vec<vk::PresentModeKHR>,
+    /// Extent used when the surface lets the swapchain choose its extent (usually the window size)
+    /// It is clamped to the extent limits of the surface
+    pub extent: vk::Extent2D,
+    /// Number of swapchain images to request, clamped to the surface limits
+    /// If 0, the minimum number of images supported by the surface plus one is used
+    pub image_count: u32,
+    pub image_usage: vk::ImageUsageFlags,
+    pub composite_alpha: vk::CompositeAlphaFlagsKHR,
+}
+
+impl Default for SwapchainPreferences {
+    fn default() -> Self {
+        Self {
+            formats: vec![
+                vk::SurfaceFormatKHR {
+                    format: vk::Format::B8G8R8A8Srgb,
+                    color_space: vk::ColorSpaceKHR::SrgbNonlinear,
+                },
+                vk::SurfaceFormatKHR {
+                    format: vk::Format::R8G8B8A8Srgb,
+                    color_space: vk::ColorSpaceKHR::SrgbNonlinear,
+                },
+            ],
+            present_modes: vec![
+                vk::PresentModeKHR::Mailbox,
+                vk::PresentModeKHR::FifoRelaxed,
+                vk::PresentModeKHR::Fifo,
+            ],
+            extent: vk::Extent2D {
+                width: 0,
+                height: 0,
+            },
+            image_count: 0,
+            image_usage: vk::ImageUsageFlags::ColorAttachment,
+            composite_alpha: vk::CompositeAlphaFlagsKHR::Opaque,
+        }
+    }
+}
+
+impl SwapchainPreferences {
+    pub fn formats(mut self, formats: &[vk::SurfaceFormatKHR]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    pub fn present_modes(mut self, present_modes: &[vk::PresentModeKHR]) -> Self {
+        self.present_modes = present_modes.to_vec();
+        self
+    }
+
+    pub fn extent(mut self, extent: vk::Extent2D) -> Self {
+        self.extent = extent;
+        self
+    }
+
+    pub fn image_count(mut self, image_count: u32) -> Self {
+        self.image_count = image_count;
+        self
+    }
+
+    pub fn image_usage(mut self, image_usage: vk::ImageUsageFlags) -> Self {
+        self.image_usage = image_usage;
+        self
+    }
+
+    pub fn composite_alpha(mut self, composite_alpha: vk::CompositeAlphaFlagsKHR) -> Self {
+        self.composite_alpha = composite_alpha;
+        self
+    }
+}
+
+/// Swapchain image returned by [Swapchain::acquire_next_image]
+/// The work rendering to this image must wait on `acquire_semaphore` and signal `present_semaphore`
+/// before [Swapchain::present] is called
+#[derive(Clone, Copy)]
+pub struct AcquiredImage {
+    pub index: u32,
+    pub image: vk::rs::Image,
+    pub view: vk::rs::ImageView,
+    pub acquire_semaphore: vk::rs::Semaphore,
+    pub present_semaphore: vk::rs::Semaphore,
+    /// True if the swapchain no longer matches the surface properties exactly
+    /// It is still possible to present the image, the swapchain will be recreated on the next acquire
+    pub suboptimal: bool,
+}
+
+/// Objects whose lifetime is bound to a single `VkSwapchainKHR`
+struct SwapchainResources {
+    swapchain: vk::rs::SwapchainKHR,
+    images: Vec<vk::rs::Image>,
+    views: Vec<vk::rs::ImageView>,
+    /// One acquire semaphore per image, plus a spare one which is used for the next acquire
+    /// and then swapped with the semaphore of the image that was acquired
+    acquire_semaphores: Vec<vk::rs::Semaphore>,
+    spare_semaphore: vk::rs::Semaphore,
+    present_semaphores: Vec<vk::rs::Semaphore>,
+    /// Only used if VK_EXT_swapchain_maintenance1 is enabled, fences are created in the signaled state
+    present_fences: Vec<vk::rs::Fence>,
+}
+
+/// Swapchain helper, see the [module level documentation](self)
+pub struct Swapchain<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    device: vk::rs::Device<D, A>,
+    physical_device: vk::rs::PhysicalDevice<D, A>,
+    surface: vk::rs::SurfaceKHR,
+    preferences: SwapchainPreferences,
+    use_present_fences: bool,
+    /// Last queue used by [Swapchain::present], waited on before destroying old resources without present fences
+    present_queue: Option<vk::rs::Queue<D, A>>,
+    format: vk::SurfaceFormatKHR,
+    present_mode: vk::PresentModeKHR,
+    extent: vk::Extent2D,
+    resources: Option<SwapchainResources>,
+    needs_recreation: bool,
+}
+
+impl<D: Dispatcher, A: Allocator> Swapchain<D, A> {
+    /// Create a swapchain for the given surface
+    /// The surface must be supported by at least one queue family of the physical device
+    /// If the surface currently has a zero extent (for example the window is minimized),
+    /// [vk::Status::ErrorOutOfDateKHR] is returned
+    ///
+    /// `swapchain_maintenance1` must only be true if the `swapchainMaintenance1` feature of
+    /// VK_EXT_swapchain_maintenance1 has been enabled when creating the device, present fences are then used
+    pub fn new(
+        device: &vk::rs::Device<D, A>,
+        physical_device: &vk::rs::PhysicalDevice<D, A>,
+        surface: &vk::rs::SurfaceKHR,
+        preferences: SwapchainPreferences,
+        swapchain_maintenance1: bool,
+    ) -> vk::Result<Self> {
+        let mut result = Self {
+            device: device.clone(),
+            physical_device: physical_device.clone(),
+            surface: *surface,
+            preferences,
+            use_present_fences: swapchain_maintenance1,
+            present_queue: None,
+            format: vk::SurfaceFormatKHR {
+                format: vk::Format::Undefined,
+                color_space: vk::ColorSpaceKHR::SrgbNonlinear,
+            },
+            present_mode: vk::PresentModeKHR::Fifo,
+            extent: vk::Extent2D {
+                width: 0,
+                height: 0,
+            },
+            resources: None,
+            needs_recreation: true,
+        };
+        result.recreate()?;
+        Ok(result)
+    }
+
+    pub fn handle(&self) -> Option<vk::rs::SwapchainKHR> {
+        self.resources.as_ref().map(|res| res.swapchain)
+    }
+
+    pub fn format(&self) -> vk::SurfaceFormatKHR {
+        self.format
+    }
+
+    pub fn present_mode(&self) -> vk::PresentModeKHR {
+        self.present_mode
+    }
+
+    pub fn extent(&self) -> vk::Extent2D {
+        self.extent
+    }
+
+    pub fn images(&self) -> &[vk::rs::Image] {
+        self.resources
+            .as_ref()
+            .map(|res| res.images.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn image_views(&self) -> &[vk::rs::ImageView] {
+        self.resources
+            .as_ref()
+            .map(|res| res.views.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Return true if present operations are tracked using VK_EXT_swapchain_maintenance1 fences
+    pub fn uses_present_fences(&self) -> bool {
+        self.use_present_fences
+    }
+
+    /// Return true if the swapchain will be recreated during the next call to [Swapchain::acquire_next_image]
+    pub fn needs_recreation(&self) -> bool {
+        self.needs_recreation
+    }
+
+    /// Notify the swapchain that the surface has been resized, the swapchain will be recreated
+    /// during the next call to [Swapchain::acquire_next_image]
+    pub fn resize(&mut self, extent: vk::Extent2D) {
+        self.preferences.extent = extent;
+        self.needs_recreation = true;
+    }
+
+    /// Change the swapchain preferences, the swapchain will be recreated
+    /// during the next call to [Swapchain::acquire_next_image]
+    pub fn set_preferences(&mut self, preferences: SwapchainPreferences) {
+        self.preferences = preferences;
+        self.needs_recreation = true;
+    }
+
+    /// Acquire the next swapchain image, recreating the swapchain first if needed
+    /// Return None if no image could be acquired before the timeout expired
+    /// If the surface currently has a zero extent (for example the window is minimized),
+    /// [vk::Status::ErrorOutOfDateKHR] is returned and the frame should be skipped
+    ///
+    /// The acquire semaphore of an image is reused once the same image is acquired again, the caller must
+    /// make sure by this time the submission waiting on it has completed (which is the case when using one fence
+    /// per frame in flight and waiting on it before acquiring the next image)
+    pub fn acquire_next_image(&mut self, timeout: u64) -> vk::Result<Option<AcquiredImage>> {
+        loop {
+            if self.needs_recreation {
+                self.recreate()?;
+            }
+            let res = self
+                .resources
+                .as_mut()
+                .ok_or(vk::Status::ErrorOutOfDateKHR)?;
+
+            let acquire_result = self.device.acquire_next_image_khr(
+                &res.swapchain,
+                timeout,
+                Some(&res.spare_semaphore),
+                None,
+            );
+            let (status, index) = match acquire_result.widen_err() {
+                Ok((
+                    vk::codes::AcquireNextImageKHRStatus::Timeout
+                    | vk::codes::AcquireNextImageKHRStatus::NotReady,
+                    _,
+                )) => return Ok(None),
+                Ok(result) => result,
+                Err(vk::Status::ErrorOutOfDateKHR) => {
+                    self.needs_recreation = true;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            let suboptimal = status == vk::codes::AcquireNextImageKHRStatus::SuboptimalKHR;
+            if suboptimal {
+                self.needs_recreation = true;
+            }
+
+            let idx = index as usize;
+            std::mem::swap(&mut res.spare_semaphore, &mut res.acquire_semaphores[idx]);
+            return Ok(Some(AcquiredImage {
+                index,
+                image: res.images[idx],
+                view: res.views[idx],
+                acquire_semaphore: res.acquire_semaphores[idx],
+                present_semaphore: res.present_semaphores[idx],
+                suboptimal,
+            }));
+        }
+    }
+
+    /// Present an image acquired with [Swapchain::acquire_next_image]
+    /// The presentation waits on the `present_semaphore` of the image
+    /// If the swapchain is out of date or suboptimal, it is marked for recreation and no error is returned
+    pub fn present(
+        &mut self,
+        queue: &vk::rs::Queue<D, A>,
+        image: &AcquiredImage,
+    ) -> vk::Result<()> {
+        let res = self
+            .resources
+            .as_ref()
+            .ok_or(vk::Status::ErrorOutOfDateKHR)?;
+        let idx = image.index as usize;
+
+        let present_info = vk::PresentInfoKHR::default()
+            .wait_semaphores(&image.present_semaphore)
+            .swapchain(&res.swapchain, &image.index, None::<()>);
+
+        if !self.use_present_fences {
+            self.present_queue = Some(queue.clone());
+        }
+
+        let result = if self.use_present_fences {
+            let fence = &res.present_fences[idx];
+            // the fence is still used by the previous presentation of this image
+            self.device.wait_for_fences(fence, true, u64::MAX)?;
+            self.device.reset_fences(fence)?;
+
+            let mut fence_info = vk::SwapchainPresentFenceInfoEXT::default().fences(fence);
+            queue.present_khr(&present_info.push_next(&mut fence_info))
+        } else {
+            queue.present_khr(&present_info)
+        };
+
+        match result.widen_err() {
+            Ok(vk::codes::QueuePresentKHRStatus::SuboptimalKHR)
+            | Err(vk::Status::ErrorOutOfDateKHR) => {
+                self.needs_recreation = true;
+                Ok(())
+            }
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Recreate the swapchain now, this is done automatically by [Swapchain::acquire_next_image] when needed
+    /// The old swapchain is given as `old_swapchain` to the new one and its resources are destroyed
+    /// once they are no longer in use. If waiting for them fails, they are kept and destroyed by the next
+    /// successful recreation or by [Swapchain::destroy]
+    pub fn recreate(&mut self) -> vk::Result<()> {
+        let capabilities = self
+            .physical_device
+            .get_surface_capabilities_khr(&self.surface)?;
+
+        let extent = if capabilities.current_extent.width != u32::MAX {
+            capabilities.current_extent
+        } else {
+            let min_ex = capabilities.min_image_extent;
+            let max_ex = capabilities.max_image_extent;
+            vk::Extent2D {
+                width: self
+                    .preferences
+                    .extent
+                    .width
+                    .clamp(min_ex.width, max_ex.width),
+                height: self
+                    .preferences
+                    .extent
+                    .height
+                    .clamp(min_ex.height, max_ex.height),
+            }
+        };
+        if extent.width == 0 || extent.height == 0 {
+            // the surface can't be presented to right now, keep the old swapchain around
+            self.needs_recreation = true;
+            return Err(vk::Status::ErrorOutOfDateKHR);
+        }
+
+        let format = self.select_format()?;
+        let present_mode = self.select_present_mode()?;
+
+        let max_image_count = if capabilities.max_image_count != 0 {
+            capabilities.max_image_count
+        } else {
+            u32::MAX
+        };
+        let image_count = if self.preferences.image_count != 0 {
+            self.preferences.image_count
+        } else {
+            capabilities.min_image_count + 1
+        }
+        .clamp(capabilities.min_image_count, max_image_count);
+
+        let composite_alpha = if capabilities
+            .supported_composite_alpha
+            .contains(self.preferences.composite_alpha)
+        {
+            self.preferences.composite_alpha
+        } else {
+            [
+                vk::CompositeAlphaFlagsKHR::Opaque,
+                vk::CompositeAlphaFlagsKHR::Inherit,
+                vk::CompositeAlphaFlagsKHR::PreMultiplied,
+                vk::CompositeAlphaFlagsKHR::PostMultiplied,
+            ]
+            .into_iter()
+            .find(|alpha| capabilities.supported_composite_alpha.contains(*alpha))
+            .ok_or(vk::Status::ErrorInitializationFailed)?
+        };
+
+        let old_swapchain = self.resources.as_ref().map(|res| res.swapchain);
+        let swapchain_info = vk::SwapchainCreateInfoKHR::default()
+            .surface(&self.surface)
+            .min_image_count(image_count)
+            .image_format(format.format)
+            .image_color_space(format.color_space)
+            .image_extent(extent)
+            .image_array_layers(1)
+            .image_usage(self.preferences.image_usage)
+            .image_sharing_mode(vk::SharingMode::Exclusive)
+            .pre_transform(capabilities.current_transform)
+            .composite_alpha(composite_alpha)
+            .present_mode(present_mode)
+            .clipped(true)
+            .old_swapchain(old_swapchain.as_deref());
+        let swapchain = self.device.create_swapchain_khr(&swapchain_info)?;
+
+        // the old swapchain is retired even if the resource creation below fails
+        if let Some(old_resources) = &self.resources {
+            // if waiting fails, the old resources are kept to be destroyed later
+            if let Err(err) = self.wait_resources_unused(old_resources) {
+                unsafe { self.device.destroy_swapchain_khr(Some(&swapchain)) };
+                self.needs_recreation = true;
+                return Err(err);
+            }
+        }
+        if let Some(old_resources) = self.resources.take() {
+            unsafe { self.destroy_objects(&old_resources, true) };
+        }
+
+        let resources = self.create_resources(swapchain, format.format);
+        let resources = match resources {
+            Ok(resources) => resources,
+            Err(err) => {
+                unsafe { self.device.destroy_swapchain_khr(Some(&swapchain)) };
+                self.needs_recreation = true;
+                return Err(err);
+            }
+        };
+
+        self.resources = Some(resources);
+        self.format = format;
+        self.present_mode = present_mode;
+        self.extent = extent;
+        self.needs_recreation = false;
+        Ok(())
+    }
+
+    /// Destroy the swapchain and all the objects it owns, waiting first for them to no longer be in use.
+    /// If waiting fails, nothing is destroyed and this function can be called again
+    /// # Safety
+    /// The swapchain must not be used after this call and no work submitted by the caller
+    /// can be using the swapchain images, views or semaphores anymore
+    pub unsafe fn destroy(&mut self) -> vk::Result<()> {
+        if let Some(resources) = &self.resources {
+            self.wait_resources_unused(resources)?;
+        }
+        if let Some(resources) = self.resources.take() {
+            unsafe { self.destroy_objects(&resources, true) };
+        }
+        Ok(())
+    }
+
+    fn select_format(&self) -> vk::Result<vk::SurfaceFormatKHR> {
+        let available: Vec<_> = self
+            .physical_device
+            .get_surface_formats_khr(Some(&self.surface))?;
+
+        let first_available = available
+            .first()
+            .copied()
+            .ok_or(vk::Status::ErrorFormatNotSupported)?;
+
+        // some implementations report a single undefined format, meaning any format can be used
+        if available.len() == 1 && first_available.format == vk::Format::Undefined {
+            return Ok(self
+                .preferences
+                .formats
+                .first()
+                .copied()
+                .unwrap_or(vk::SurfaceFormatKHR {
+                    format: vk::Format::B8G8R8A8Srgb,
+                    color_space: first_available.color_space,
+                }));
+        }
+
+        Ok(self
+            .preferences
+            .formats
+            .iter()
+            .find(|pref| {
+                available
+                    .iter()
+                    .any(|fmt| fmt.format == pref.format && fmt.color_space == pref.color_space)
+            })
+            .copied()
+            .unwrap_or(first_available))
+    }
+
+    fn select_present_mode(&self) -> vk::Result<vk::PresentModeKHR> {
+        let available: Vec<_> = self
+            .physical_device
+            .get_surface_present_modes_khr(Some(&self.surface))?;
+
+        Ok(self
+            .preferences
+            .present_modes
+            .iter()
+            .find(|pref| available.contains(pref))
+            .copied()
+            .unwrap_or(vk::PresentModeKHR::Fifo))
+    }
+
+    fn create_resources(
+        &self,
+        swapchain: vk::rs::SwapchainKHR,
+        format: vk::Format,
+    ) -> vk::Result<SwapchainResources> {
+        let mut resources = SwapchainResources {
+            swapchain,
+            images: self.device.get_swapchain_images_khr(&swapchain)?,
+            views: Vec::new(),
+            acquire_semaphores: Vec::new(),
+            spare_semaphore: self.device.create_semaphore(&Default::default())?,
+            present_semaphores: Vec::new(),
+            present_fences: Vec::new(),
+        };
+
+        let result = (|| {
+            for image in &resources.images {
+                resources.views.push(
+                    self.device.create_image_view(
+                        &vk::ImageViewCreateInfo::default()
+                            .image(image)
+                            .view_type(vk::ImageViewType::Type2D)
+                            .format(format)
+                            .subresource_range(vk::ImageSubresourceRange {
+                                aspect_mask: vk::ImageAspectFlags::Color,
+                                base_mip_level: 0,
+                                level_count: 1,
+                                base_array_layer: 0,
+                                layer_count: 1,
+                            }),
+                    )?,
+                );
+                resources
+                    .acquire_semaphores
+                    .push(self.device.create_semaphore(&Default::default())?);
+                resources
+                    .present_semaphores
+                    .push(self.device.create_semaphore(&Default::default())?);
+                if self.use_present_fences {
+                    resources.present_fences.push(self.device.create_fence(
+                        &vk::FenceCreateInfo::default().flags(vk::FenceCreateFlags::Signaled),
+                    )?);
+                }
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => Ok(resources),
+            Err(err) => {
+                // nothing has been used yet, the objects can be destroyed right away
+                unsafe { self.destroy_objects(&resources, false) };
+                Err(err)
+            }
+        }
+    }
+
+    /// Wait for the resources to no longer be used by the presentation engine then destroy them
+    /// Wait for the presentation engine to be done with the semaphores of these resources
+    fn wait_resources_unused(&self, resources: &SwapchainResources) -> vk::Result<()> {
+        if self.use_present_fences {
+            if !resources.present_fences.is_empty() {
+                self.device
+                    .wait_for_fences(resources.present_fences.as_slice(), true, u64::MAX)?;
+            }
+        } else if let Some(queue) = &self.present_queue {
+            // without VK_EXT_swapchain_maintenance1, there is no way to know when
+            // the presentation engine is done with the semaphores other than waiting for the queue
+            queue.wait_idle()?;
+        }
+        Ok(())
+    }
+
+    unsafe fn destroy_objects(&self, resources: &SwapchainResources, with_swapchain: bool) {
+        unsafe {
+            for view in &resources.views {
+                self.device.destroy_image_view(Some(view));
+            }
+            for semaphore in resources
+                .acquire_semaphores
+                .iter()
+                .chain(&resources.present_semaphores)
+                .chain(std::iter::once(&resources.spare_semaphore))
+            {
+                self.device.destroy_semaphore(Some(semaphore));
+            }
+            for fence in &resources.present_fences {
+                self.device.destroy_fence(Some(fence));
+            }
+            if with_swapchain {
+                self.device
+                    .destroy_swapchain_khr(Some(&resources.swapchain));
+            }
+        }
+    }
+}
@@ -0,0 +1,448 @@
+//! Timeline semaphore wrapper
+//!
+//! A [TimelineSemaphore] keeps track of the next value to signal, which makes it easy to
+//! build the [vk::SemaphoreSubmitInfo] entries given to [vk::rs::Queue::submit2] and to wait on the host
+//! for a given submission to be done.
+//!
+//! ```ignore
+//! let mut timeline = TimelineSemaphore::new(&device, 0)?;
+//! let value = timeline.next_signal_value();
+//! queue.submit2(
+//!     &[vk::SubmitInfo2::default()
+//!         .command_buffer_infos(&command_buffer_info)
+//!         .signal_semaphore_infos(&timeline.submit_info(value, vk::PipelineStageFlags2::AllCommands))],
+//!     None,
+//! )?;
+//! if timeline.wait(value, 1_000_000_000)? == WaitResult::Timeout {
+//!     println!("The GPU took more than one second to execute the command buffer");
+//! }
+//! ```
+//!
+//! With the `async` feature, a [TimelineWaiter] resolves futures once a semaphore counter reaches a given value,
+//! using a single background thread for all the waits:
+//!
+//! ```ignore
+//! let waiter = TimelineWaiter::new(&device)?;
+//! timeline.wait_async(&waiter, value).await?;
+//! ```
+
+use crate::{vk, Allocator, DefaultAllocator, Dispatcher, DynamicDispatcher};
+
+/// Result of a wait on the host
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaitResult {
+    /// The semaphore counter reached the value waited for
+    Reached,
+    /// The timeout expired before the semaphore counter reached the value waited for
+    Timeout,
+}
+
+/// Timeline semaphore along with the next value to signal, see the [module level documentation](self)
+/// This requires the timelineSemaphore feature (core in Vulkan 1.2) to be enabled
+pub struct TimelineSemaphore<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    device: vk::rs::Device<D, A>,
+    semaphore: vk::rs::Semaphore,
+    next_value: u64,
+}
+
+impl<D: Dispatcher, A: Allocator> TimelineSemaphore<D, A> {
+    /// Create a timeline semaphore whose counter is initially `initial_value`
+    /// The first value returned by [TimelineSemaphore::next_signal_value] is `initial_value + 1`,
+    /// [vk::Status::ErrorInitializationFailed] is returned if `initial_value` is `u64::MAX`
+    pub fn new(device: &vk::rs::Device<D, A>, initial_value: u64) -> vk::Result<Self> {
+        let next_value = initial_value
+            .checked_add(1)
+            .ok_or(vk::Status::ErrorInitializationFailed)?;
+        let mut type_info = vk::SemaphoreTypeCreateInfo::default()
+            .semaphore_type(vk::SemaphoreType::Timeline)
+            .initial_value(initial_value);
+        let semaphore = device
+            .create_semaphore(&vk::SemaphoreCreateInfo::default().push_next(&mut type_info))?;
+
+        Ok(Self {
+            device: device.clone(),
+            semaphore,
+            next_value,
+        })
+    }
+
+    pub fn semaphore(&self) -> &vk::rs::Semaphore {
+        &self.semaphore
+    }
+
+    /// Value that will be returned by the next call to [TimelineSemaphore::next_signal_value]
+    pub fn peek_next_value(&self) -> u64 {
+        self.next_value
+    }
+
+    /// Last value returned by [TimelineSemaphore::next_signal_value]
+    /// Waiting for this value means waiting for all the signal operations scheduled so far
+    pub fn last_signal_value(&self) -> u64 {
+        self.next_value - 1
+    }
+
+    /// Reserve and return the next value to signal
+    pub fn next_signal_value(&mut self) -> u64 {
+        let value = self.next_value;
+        self.next_value += 1;
+        value
+    }
+
+    /// Return a semaphore operation for [vk::rs::Queue::submit2]
+    /// Used as a signal operation, it sets the counter to `value`, which is usually retrieved using
+    /// [TimelineSemaphore::next_signal_value]. Used as a wait operation, it waits for the counter to reach `value`
+    pub fn submit_info(
+        &self,
+        value: u64,
+        stage_mask: vk::PipelineStageFlags2,
+    ) -> vk::SemaphoreSubmitInfo<'_> {
+        vk::SemaphoreSubmitInfo::default()
+            .semaphore(&self.semaphore)
+            .value(value)
+            .stage_mask(stage_mask)
+    }
+
+    /// Current value of the semaphore counter
+    pub fn value(&self) -> vk::Result<u64> {
+        self.device.get_semaphore_counter_value(&self.semaphore)
+    }
+
+    /// Return true if the semaphore counter has reached `value`
+    pub fn is_reached(&self, value: u64) -> vk::Result<bool> {
+        Ok(self.value()? >= value)
+    }
+
+    /// Signal the semaphore from the host, `value` must be greater than the current counter value
+    /// and less than the value of any pending signal operation
+    pub fn signal(&self, value: u64) -> vk::Result<()> {
+        self.device.signal_semaphore(
+            &vk::SemaphoreSignalInfo::default()
+                .semaphore(&self.semaphore)
+                .value(value),
+        )
+    }
+
+    /// Wait on the host for the semaphore counter to reach `value`
+    /// `timeout` is in nanoseconds, as for any other Vulkan wait command
+    pub fn wait(&self, value: u64, timeout: u64) -> vk::Result<WaitResult> {
+        let status = self.device.wait_semaphores(
+            &vk::SemaphoreWaitInfo::default().semaphore(&self.semaphore, &value),
+            timeout,
+        )?;
+        Ok(match status {
+            vk::codes::WaitSemaphoresStatus::Timeout => WaitResult::Timeout,
+            _ => WaitResult::Reached,
+        })
+    }
+
+    /// Destroy the semaphore
+    /// # Safety
+    /// The semaphore must not be used by any pending submission anymore
+    pub unsafe fn destroy(&self) {
+        unsafe { self.device.destroy_semaphore(Some(&self.semaphore)) }
+    }
+}
+
+#[cfg(feature = "async")]
+mod future {
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+
+    use super::TimelineSemaphore;
+    use crate::completion::{complete, is_cancelled, Cancel, CompletionFuture, SharedCompletion};
+    use crate::{
+        vk::{self, WidenResult},
+        Allocator, Dispatcher, Handle,
+    };
+
+    /// Future resolved once a timeline semaphore counter reaches a given value, see [TimelineWaiter]
+    /// Once resolved, polling the future again returns the same result
+    pub struct TimelineFuture(CompletionFuture);
+
+    impl Future for TimelineFuture {
+        type Output = vk::Result<()>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            Pin::new(&mut self.0).poll(cx)
+        }
+    }
+
+    struct Entry {
+        semaphore: vk::rs::Semaphore,
+        value: u64,
+        completion: SharedCompletion,
+    }
+
+    struct WaiterState {
+        incoming: Vec<Entry>,
+        /// Last value signaled on the wake semaphore
+        wake_value: u64,
+        shutdown: bool,
+        /// Error returned by the last wait or wake-up, the thread has stopped or will stop if this is set
+        failed: Option<vk::Status>,
+    }
+
+    impl WaiterState {
+        /// Resolve the futures not yet picked by the waiter thread with `err` and reject the following waits
+        fn fail(&mut self, err: vk::Status) {
+            self.failed = Some(err);
+            for entry in self.incoming.drain(..) {
+                complete(&entry.completion, Err(err));
+            }
+        }
+    }
+
+    struct WaiterShared<D: Dispatcher, A: Allocator> {
+        /// Timeline semaphore signaled from the host to wake up the waiter thread
+        wake: TimelineSemaphore<D, A>,
+        state: Mutex<WaiterState>,
+    }
+
+    impl<D, A> Cancel for WaiterShared<D, A>
+    where
+        D: Dispatcher + Send + Sync + 'static,
+        A: Allocator + Send + Sync + 'static,
+    {
+        fn cancel(&self) {
+            // the waiter thread removes the semaphore from its wait list once woken up
+            self.notify();
+        }
+    }
+
+    impl<D: Dispatcher, A: Allocator> WaiterShared<D, A> {
+        /// Wake up the waiter thread
+        fn notify(&self) {
+            let mut state = self.state.lock().unwrap();
+            if state.failed.is_some() {
+                return;
+            }
+            state.wake_value += 1;
+            // the waiter thread would never pick the new waits up, fail them instead
+            if let Err(err) = self.wake.signal(state.wake_value) {
+                state.fail(err);
+            }
+        }
+    }
+
+    impl<D: Dispatcher, A: Allocator> Drop for WaiterShared<D, A> {
+        fn drop(&mut self) {
+            // the waiter thread holds a reference to the shared state so it has already exited
+            unsafe { self.wake.destroy() }
+        }
+    }
+
+    impl<D: Dispatcher, A: Allocator> WaiterShared<D, A> {
+        fn run(&self) {
+            let device = &self.wake.device;
+            let mut entries: Vec<Entry> = Vec::new();
+            loop {
+                let wake_value = {
+                    let mut state = self.state.lock().unwrap();
+                    if let Some(err) = state.failed {
+                        for entry in entries.drain(..) {
+                            complete(&entry.completion, Err(err));
+                        }
+                        return;
+                    }
+                    entries.append(&mut state.incoming);
+                    entries.retain(|entry| !is_cancelled(&entry.completion));
+                    if state.shutdown && entries.is_empty() {
+                        return;
+                    }
+                    state.wake_value
+                };
+
+                // only wait for the smallest pending value of each semaphore
+                let mut targets = HashMap::new();
+                for entry in &entries {
+                    targets
+                        .entry(entry.semaphore.as_raw())
+                        .and_modify(|(_, value): &mut (_, u64)| *value = (*value).min(entry.value))
+                        .or_insert((entry.semaphore, entry.value));
+                }
+                let (mut semaphores, mut values): (Vec<vk::rs::Semaphore>, Vec<u64>) =
+                    targets.into_values().unzip();
+                semaphores.push(*self.wake.semaphore());
+                values.push(wake_value + 1);
+
+                let result = device
+                    .wait_semaphores(
+                        &vk::SemaphoreWaitInfo::default()
+                            .flags(vk::SemaphoreWaitFlags::Any)
+                            .semaphore(semaphores.as_slice(), values.as_slice()),
+                        u64::MAX,
+                    )
+                    .widen_err();
+                if let Err(err) = result {
+                    let mut state = self.state.lock().unwrap();
+                    state.fail(err);
+                    for entry in entries.drain(..) {
+                        complete(&entry.completion, Err(err));
+                    }
+                    return;
+                }
+
+                let mut counters = HashMap::new();
+                entries.retain(|entry| {
+                    let counter = *counters
+                        .entry(entry.semaphore.as_raw())
+                        .or_insert_with(|| device.get_semaphore_counter_value(&entry.semaphore));
+                    match counter {
+                        Ok(counter) if counter < entry.value => true,
+                        Ok(_) => {
+                            complete(&entry.completion, Ok(()));
+                            false
+                        }
+                        Err(err) => {
+                            complete(&entry.completion, Err(err));
+                            false
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    /// Background thread resolving [TimelineFuture]
+    /// The thread waits on all the pending semaphores at once using a single `vkWaitSemaphores` call, along with
+    /// a private timeline semaphore signaled from the host to wake it up when a wait is added or a future dropped.
+    /// When the waiter is dropped, its thread keeps running until all the pending futures are resolved or dropped
+    pub struct TimelineWaiter<D, A>
+    where
+        D: Dispatcher + Send + Sync + 'static,
+        A: Allocator + Send + Sync + 'static,
+    {
+        shared: Arc<WaiterShared<D, A>>,
+    }
+
+    impl<D, A> TimelineWaiter<D, A>
+    where
+        D: Dispatcher + Send + Sync + 'static,
+        A: Allocator + Send + Sync + 'static,
+    {
+        pub fn new(device: &vk::rs::Device<D, A>) -> vk::Result<Self> {
+            let shared = Arc::new(WaiterShared {
+                wake: TimelineSemaphore::new(device, 0)?,
+                state: Mutex::new(WaiterState {
+                    incoming: Vec::new(),
+                    wake_value: 0,
+                    shutdown: false,
+                    failed: None,
+                }),
+            });
+
+            let thread_shared = shared.clone();
+            std::thread::spawn(move || thread_shared.run());
+
+            Ok(Self { shared })
+        }
+
+        /// Return a future resolved once the semaphore counter reaches `value`
+        /// The semaphore must not be destroyed before the future is resolved, dropping the future
+        /// does not lift this requirement
+        pub fn wait(&self, semaphore: &vk::rs::Semaphore, value: u64) -> TimelineFuture {
+            let (future, completion) = CompletionFuture::new(Some(self.shared.clone()));
+            let failed = {
+                let mut state = self.shared.state.lock().unwrap();
+                if state.failed.is_none() {
+                    state.incoming.push(Entry {
+                        semaphore: *semaphore,
+                        value,
+                        completion: completion.clone(),
+                    });
+                }
+                state.failed
+            };
+            match failed {
+                Some(err) => complete(&completion, Err(err)),
+                None => self.shared.notify(),
+            }
+
+            TimelineFuture(future)
+        }
+    }
+
+    impl<D, A> Drop for TimelineWaiter<D, A>
+    where
+        D: Dispatcher + Send + Sync + 'static,
+        A: Allocator + Send + Sync + 'static,
+    {
+        fn drop(&mut self) {
+            self.shared.state.lock().unwrap().shutdown = true;
+            self.shared.notify();
+        }
+    }
+
+    impl<D, A> TimelineSemaphore<D, A>
+    where
+        D: Dispatcher + Send + Sync + 'static,
+        A: Allocator + Send + Sync + 'static,
+    {
+        /// Return a future resolved once the semaphore counter reaches `value`, see [TimelineWaiter::wait]
+        pub fn wait_async(&self, waiter: &TimelineWaiter<D, A>, value: u64) -> TimelineFuture {
+            waiter.wait(&self.semaphore, value)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::future::Future;
+        use std::num::NonZeroU64;
+        use std::pin::Pin;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        use super::{CompletionFuture, Entry, TimelineFuture, WaiterState};
+        use crate::{vk, Handle};
+
+        /// Waker counting the number of times it has been woken up
+        #[derive(Default)]
+        struct CountingWaker(AtomicUsize);
+
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        fn poll(future: &mut TimelineFuture, waker: &Arc<CountingWaker>) -> Poll<vk::Result<()>> {
+            let waker = Waker::from(waker.clone());
+            Pin::new(future).poll(&mut Context::from_waker(&waker))
+        }
+
+        #[test]
+        fn failing_waiter_resolves_incoming() {
+            let (future, completion) = CompletionFuture::new(None);
+            let mut future = TimelineFuture(future);
+            let waker = Arc::new(CountingWaker::default());
+            let mut state = WaiterState {
+                incoming: vec![Entry {
+                    semaphore: vk::rs::Semaphore::from_inner(unsafe {
+                        vk::raw::Semaphore::from_raw(NonZeroU64::new(1).unwrap())
+                    }),
+                    value: 1,
+                    completion,
+                }],
+                wake_value: 0,
+                shutdown: false,
+                failed: None,
+            };
+            assert_eq!(poll(&mut future, &waker), Poll::Pending);
+
+            state.fail(vk::Status::ErrorDeviceLost);
+            assert!(state.incoming.is_empty());
+            assert_eq!(state.failed, Some(vk::Status::ErrorDeviceLost));
+            assert_eq!(
+                poll(&mut future, &waker),
+                Poll::Ready(Err(vk::Status::ErrorDeviceLost))
+            );
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use future::{TimelineFuture, TimelineWaiter};
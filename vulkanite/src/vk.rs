@@ -1,21 +1,58 @@
+mod barrier;
+pub mod chain;
+/// Result types of the commands returning a `VkResult`
+/// With the `status-codes` feature, their success and error codes are narrowed to the codes each command can return:
+/// ```ignore
+/// use vk::codes::{AcquireNextImageKHRError, AcquireNextImageKHRStatus};
+/// match device.acquire_next_image_khr(&swapchain, timeout, Some(&semaphore), None) {
+///     Ok((AcquireNextImageKHRStatus::SuboptimalKHR, index)) => ...,
+///     Ok((_, index)) => ...,
+///     Err(AcquireNextImageKHRError::ErrorOutOfDateKHR) => ...,
+///     Err(err) => ...,
+/// }
+/// ```
+/// Without the feature, these types are aliases of [Status]
+pub mod codes;
 mod custom;
 mod dispatcher;
 pub mod enums;
+pub mod exclusive;
 pub mod extensions;
 mod formats;
+// the expected layouts are computed for 64-bit targets
+#[cfg(all(test, target_pointer_width = "64"))]
+mod layout;
+mod limits;
+pub mod owned;
 pub mod raw;
+#[cfg(all(feature = "recording-checks", debug_assertions))]
+mod recording;
 pub mod rs;
+#[cfg(feature = "serde")]
+mod serde_helpers;
+mod spirv;
 pub mod structs;
+mod sync;
+#[cfg(test)]
+mod tests;
+mod texel;
+pub mod typed;
+pub mod video;
 
 use std::ffi::CStr;
 
+pub use barrier::*;
 #[doc(inline)]
 pub use custom::*;
 #[doc(inline)]
 pub use dispatcher::*;
 pub use enums::*;
 pub use extensions::*;
+pub use formats::*;
+pub use spirv::*;
 pub use structs::*;
+pub use texel::*;
+pub use video::*;
 
 impl Status {
     #[inline]
@@ -65,6 +102,55 @@ impl Status {
             Err(self)
         }
     }
+
+    /// Convert the status into the narrowed error enum of a command, see the [codes] module
+    /// A status the command is not expected to return is kept in the `Other` variant
+    pub fn narrow<T: From<Status>>(self) -> T {
+        T::from(self)
+    }
+
+    /// Convert the status into the narrowed success enum of a command, see the [codes] module
+    /// A status the command is not expected to return is given back as an error
+    pub fn narrow_success<T: SuccessCode>(self) -> Result<T> {
+        T::from_status(self).ok_or(self)
+    }
+}
+
+/// Success codes a command can return, see the [codes] module
+pub trait SuccessCode: Sized + Into<Status> {
+    /// Return the success code matching the status, or `None` if the command cannot return it
+    fn from_status(status: Status) -> Option<Self>;
+}
+
+impl SuccessCode for Status {
+    fn from_status(status: Status) -> Option<Self> {
+        Some(status)
+    }
+}
+
+/// Narrow the error side of a [`Result`], see the [codes] module
+/// The wrappers of [rs] already return narrowed results, this is used by the generated code and to convert
+/// the result of a raw command
+pub trait NarrowResult<T> {
+    /// An error the command is not expected to return is kept in the `Other` variant
+    fn narrow_err<E: From<Status>>(self) -> core::result::Result<T, E>;
+}
+
+impl<T> NarrowResult<T> for Result<T> {
+    fn narrow_err<E: From<Status>>(self) -> core::result::Result<T, E> {
+        self.map_err(Status::narrow)
+    }
+}
+
+/// Convert the narrowed error of a command back into a [Status], to handle the results of different commands together
+pub trait WidenResult<T> {
+    fn widen_err(self) -> Result<T>;
+}
+
+impl<T, E: Into<Status>> WidenResult<T> for core::result::Result<T, E> {
+    fn widen_err(self) -> Result<T> {
+        self.map_err(Into::into)
+    }
 }
 
 impl core::fmt::Display for Status {
@@ -0,0 +1,72 @@
+//! Offline validation of memory barriers, using the synchronization tables generated from the registry
+use std::fmt;
+
+use super::{AccessFlags2, MemoryBarrier2, PipelineStageFlags2, QueueFlags};
+
+/// Reason why a memory barrier is invalid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarrierError {
+    /// These stages of the source or destination stage mask are not supported by the queue
+    UnsupportedStages(PipelineStageFlags2),
+    /// This access is not supported by any stage of the stage mask it is used with
+    UnsupportedAccess {
+        access: AccessFlags2,
+        stages: PipelineStageFlags2,
+    },
+}
+
+impl fmt::Display for BarrierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnsupportedStages(stages) => {
+                write!(f, "stages {stages:?} are not supported by the queue")
+            }
+            Self::UnsupportedAccess { access, stages } => {
+                write!(f, "access {access:?} cannot be used with stages {stages:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BarrierError {}
+
+/// Check that the stage masks of a barrier are supported by a queue with the given capabilities
+/// and that each access of the access masks is supported by one of the stages of its stage mask.
+/// This only covers the rules described by the registry, not the ones depending on enabled features
+pub fn validate_barrier(
+    barrier: &MemoryBarrier2,
+    queue_flags: QueueFlags,
+) -> Result<(), BarrierError> {
+    validate_scope(barrier.src_stage_mask, barrier.src_access_mask, queue_flags)?;
+    validate_scope(barrier.dst_stage_mask, barrier.dst_access_mask, queue_flags)
+}
+
+fn validate_scope(
+    stages: PipelineStageFlags2,
+    accesses: AccessFlags2,
+    queue_flags: QueueFlags,
+) -> Result<(), BarrierError> {
+    let unsupported_stages = stages.difference(queue_flags.supported_stages());
+    if !unsupported_stages.is_empty() {
+        return Err(BarrierError::UnsupportedStages(unsupported_stages));
+    }
+
+    if stages.contains(PipelineStageFlags2::AllCommands) {
+        return Ok(());
+    }
+    let expanded_stages = stages.expand_meta_stages();
+    // each access must be checked on its own, an access mask being the union of the supported stages
+    for bit in 0..u64::BITS {
+        let access = AccessFlags2::from_bits_retain(accesses.bits() & (1 << bit));
+        if access.is_empty() {
+            continue;
+        }
+        let access_stages = access.supported_stages();
+        if !access_stages.contains(PipelineStageFlags2::AllCommands)
+            && !access_stages.intersects(expanded_stages)
+        {
+            return Err(BarrierError::UnsupportedAccess { access, stages });
+        }
+    }
+    Ok(())
+}
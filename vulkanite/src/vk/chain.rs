@@ -0,0 +1,186 @@
+//! Runtime inspection and construction of structure chains
+//!
+//! The structure types of [crate::StructureChain] are known at compile time. This module gives access to
+//! the structures of a `p_next` chain using their [vk::StructureType] instead, through [Header]:
+//! ```ignore
+//! let info: &vk::DeviceCreateInfo = ...;
+//! for header in Header::of(info).iter().skip(1) {
+//!     println!("{:?}", header.structure_type());
+//!     if let Some(features) = header.downcast_ref::<vk::PhysicalDeviceVulkan12Features>() {
+//!         println!("timeline semaphores: {}", features.timeline_semaphore);
+//!     }
+//! }
+//! ```
+//!
+//! It also allows building a [crate::StructureChainVec] from a list of structure types known only at runtime.
+//! Commands outputting a structure chain have a `_into` variant filling a chain built by the caller:
+//! ```ignore
+//! // query every properties structure from the list
+//! let types = [
+//!     vk::StructureType::PhysicalDeviceVulkan11Properties,
+//!     vk::StructureType::PhysicalDeviceDriverProperties,
+//!     vk::StructureType::PhysicalDeviceMeshShaderPropertiesEXT,
+//! ];
+//! let mut chain = StructureChainVec::from_structure_types(vk::PhysicalDeviceProperties2::default(), types);
+//! physical_device.get_properties2_into(&mut chain);
+//! for header in Header::of(chain.as_ref()).iter().skip(1) {
+//!     println!("{}", header.info().unwrap().name());
+//! }
+//! ```
+//! [extending_structures] lists all the structures which can be part of a given chain.
+
+use std::{fmt, marker::PhantomData, ptr};
+
+use crate::{vk, ExtendableStructure, ExtendableStructureBase, Header};
+
+mod structs;
+
+/// Information about a Vulkan structure which has a structure type
+#[derive(Clone, Copy)]
+pub struct StructureInfo {
+    structure_type: vk::StructureType,
+    name: &'static str,
+    extends: &'static [vk::StructureType],
+    create: fn() -> Box<dyn ExtendableStructureBase>,
+}
+
+impl StructureInfo {
+    #[inline]
+    pub fn structure_type(&self) -> vk::StructureType {
+        self.structure_type
+    }
+
+    /// Name of the structure in the Vulkan specification, for example `VkPhysicalDeviceVulkan12Properties`
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Structure types whose `p_next` chain can contain this structure
+    #[inline]
+    pub fn extends(&self) -> &'static [vk::StructureType] {
+        self.extends
+    }
+
+    /// Create a structure of this type with its default value, on the heap
+    pub fn create(&self) -> Box<dyn ExtendableStructureBase> {
+        (self.create)()
+    }
+}
+
+impl fmt::Debug for StructureInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StructureInfo")
+            .field("structure_type", &self.structure_type)
+            .field("name", &self.name)
+            .field("extends", &self.extends)
+            .finish()
+    }
+}
+
+/// Return the information about the structure with the given type, or None if this type is unknown
+pub fn structure_info(structure_type: vk::StructureType) -> Option<&'static StructureInfo> {
+    structs::STRUCTURES
+        .iter()
+        .find(|info| info.structure_type == structure_type)
+}
+
+/// Iterate over all the structures which can be part of the `p_next` chain of the given structure type
+pub fn extending_structures(
+    structure_type: vk::StructureType,
+) -> impl Iterator<Item = &'static StructureInfo> {
+    structs::STRUCTURES
+        .iter()
+        .filter(move |info| info.extends.contains(&structure_type))
+}
+
+impl Header {
+    /// Return the header of the given structure
+    #[inline]
+    pub fn of<T: ExtendableStructureBase>(structure: &T) -> &Header {
+        // SAFETY: T is a C struct which starts with the fields from Header
+        unsafe { &*structure.header() }
+    }
+
+    /// Same as [Header::of] for a mutable structure
+    #[inline]
+    pub fn of_mut<T: ExtendableStructureBase>(structure: &mut T) -> &mut Header {
+        // SAFETY: Same as [Header::of]
+        unsafe { &mut *structure.header_mut() }
+    }
+
+    #[inline]
+    pub fn structure_type(&self) -> vk::StructureType {
+        self.s_type
+    }
+
+    /// Return the information about this structure, or None if its type is unknown
+    pub fn info(&self) -> Option<&'static StructureInfo> {
+        structure_info(self.s_type)
+    }
+
+    /// Iterate over this structure followed by all the structures of its `p_next` chain
+    pub fn iter(&self) -> ChainIter<'_> {
+        ChainIter {
+            next: ptr::from_ref(self),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Return this structure as a T, or None if it has a different type
+    pub fn downcast_ref<T: ExtendableStructure>(&self) -> Option<&T> {
+        // SAFETY: the structure type tells this is a T
+        (self.s_type == T::STRUCTURE_TYPE).then(|| unsafe { &*ptr::from_ref(self).cast() })
+    }
+
+    /// Same as [Header::downcast_ref] for a mutable structure
+    pub fn downcast_mut<T: ExtendableStructure>(&mut self) -> Option<&mut T> {
+        // SAFETY: the structure type tells this is a T
+        (self.s_type == T::STRUCTURE_TYPE).then(|| unsafe { &mut *ptr::from_mut(self).cast() })
+    }
+
+    /// Return the first structure of type T among this structure and its `p_next` chain
+    pub fn find<T: ExtendableStructure>(&self) -> Option<&T> {
+        self.iter().find_map(|header| header.downcast_ref())
+    }
+
+    /// Same as [Header::find] for a mutable structure chain
+    pub fn find_mut<T: ExtendableStructure>(&mut self) -> Option<&mut T> {
+        let mut next = ptr::from_mut(self);
+        // SAFETY: the structures of the chain are borrowed along with its head
+        while let Some(header) = unsafe { next.as_mut() } {
+            if header.s_type == T::STRUCTURE_TYPE {
+                return header.downcast_mut();
+            }
+            next = header.p_next.get().cast_mut();
+        }
+        None
+    }
+}
+
+impl fmt::Debug for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Header")
+            .field("s_type", &self.s_type)
+            .field("p_next", &self.p_next.get())
+            .finish()
+    }
+}
+
+/// Iterator over the structures of a `p_next` chain, see [Header::iter]
+#[derive(Clone)]
+pub struct ChainIter<'a> {
+    next: *const Header,
+    phantom: PhantomData<&'a Header>,
+}
+
+impl<'a> Iterator for ChainIter<'a> {
+    type Item = &'a Header;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: the structures of the chain are borrowed along with its head
+        let header = unsafe { self.next.as_ref() }?;
+        self.next = header.p_next.get();
+        Some(header)
+    }
+}
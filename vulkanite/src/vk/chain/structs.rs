@@ -0,0 +1,6740 @@
+use super::StructureInfo;
+use crate::vk::*;
+#[doc = r" All the structures with a structure type, in the order they appear in the registry"]
+pub(super) static STRUCTURES: &[StructureInfo] = &[
+    StructureInfo {
+        structure_type: StructureType::BufferMemoryBarrier,
+        name: "VkBufferMemoryBarrier",
+        extends: &[],
+        create: || Box::new(BufferMemoryBarrier::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageMemoryBarrier,
+        name: "VkImageMemoryBarrier",
+        extends: &[],
+        create: || Box::new(ImageMemoryBarrier::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryBarrier,
+        name: "VkMemoryBarrier",
+        extends: &[],
+        create: || Box::new(MemoryBarrier::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ApplicationInfo,
+        name: "VkApplicationInfo",
+        extends: &[],
+        create: || Box::new(ApplicationInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::InstanceCreateInfo,
+        name: "VkInstanceCreateInfo",
+        extends: &[],
+        create: || Box::new(InstanceCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceCreateInfo,
+        name: "VkDeviceCreateInfo",
+        extends: &[],
+        create: || Box::new(DeviceCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceQueueCreateInfo,
+        name: "VkDeviceQueueCreateInfo",
+        extends: &[],
+        create: || Box::new(DeviceQueueCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubmitInfo,
+        name: "VkSubmitInfo",
+        extends: &[],
+        create: || Box::new(SubmitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MappedMemoryRange,
+        name: "VkMappedMemoryRange",
+        extends: &[],
+        create: || Box::new(MappedMemoryRange::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryAllocateInfo,
+        name: "VkMemoryAllocateInfo",
+        extends: &[],
+        create: || Box::new(MemoryAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindSparseInfo,
+        name: "VkBindSparseInfo",
+        extends: &[],
+        create: || Box::new(BindSparseInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FenceCreateInfo,
+        name: "VkFenceCreateInfo",
+        extends: &[],
+        create: || Box::new(FenceCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SemaphoreCreateInfo,
+        name: "VkSemaphoreCreateInfo",
+        extends: &[],
+        create: || Box::new(SemaphoreCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::EventCreateInfo,
+        name: "VkEventCreateInfo",
+        extends: &[],
+        create: || Box::new(EventCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueryPoolCreateInfo,
+        name: "VkQueryPoolCreateInfo",
+        extends: &[],
+        create: || Box::new(QueryPoolCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferCreateInfo,
+        name: "VkBufferCreateInfo",
+        extends: &[],
+        create: || Box::new(BufferCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferViewCreateInfo,
+        name: "VkBufferViewCreateInfo",
+        extends: &[],
+        create: || Box::new(BufferViewCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageCreateInfo,
+        name: "VkImageCreateInfo",
+        extends: &[],
+        create: || Box::new(ImageCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageViewCreateInfo,
+        name: "VkImageViewCreateInfo",
+        extends: &[],
+        create: || Box::new(ImageViewCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ShaderModuleCreateInfo,
+        name: "VkShaderModuleCreateInfo",
+        extends: &[StructureType::PipelineShaderStageCreateInfo],
+        create: || Box::new(ShaderModuleCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineCacheCreateInfo,
+        name: "VkPipelineCacheCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineCacheCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ComputePipelineCreateInfo,
+        name: "VkComputePipelineCreateInfo",
+        extends: &[],
+        create: || Box::new(ComputePipelineCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::GraphicsPipelineCreateInfo,
+        name: "VkGraphicsPipelineCreateInfo",
+        extends: &[],
+        create: || Box::new(GraphicsPipelineCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineColorBlendStateCreateInfo,
+        name: "VkPipelineColorBlendStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineColorBlendStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineDepthStencilStateCreateInfo,
+        name: "VkPipelineDepthStencilStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineDepthStencilStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineDynamicStateCreateInfo,
+        name: "VkPipelineDynamicStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineDynamicStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineInputAssemblyStateCreateInfo,
+        name: "VkPipelineInputAssemblyStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineInputAssemblyStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineMultisampleStateCreateInfo,
+        name: "VkPipelineMultisampleStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineMultisampleStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationStateCreateInfo,
+        name: "VkPipelineRasterizationStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineRasterizationStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineShaderStageCreateInfo,
+        name: "VkPipelineShaderStageCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineShaderStageCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineTessellationStateCreateInfo,
+        name: "VkPipelineTessellationStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineTessellationStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineVertexInputStateCreateInfo,
+        name: "VkPipelineVertexInputStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineVertexInputStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportStateCreateInfo,
+        name: "VkPipelineViewportStateCreateInfo",
+        extends: &[],
+        create: || Box::new(PipelineViewportStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineLayoutCreateInfo,
+        name: "VkPipelineLayoutCreateInfo",
+        extends: &[
+            StructureType::BindDescriptorSetsInfoKHR,
+            StructureType::PushConstantsInfoKHR,
+            StructureType::PushDescriptorSetInfoKHR,
+            StructureType::PushDescriptorSetWithTemplateInfoKHR,
+            StructureType::SetDescriptorBufferOffsetsInfoEXT,
+            StructureType::BindDescriptorBufferEmbeddedSamplersInfoEXT,
+            StructureType::IndirectCommandsLayoutCreateInfoEXT,
+        ],
+        create: || Box::new(PipelineLayoutCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerCreateInfo,
+        name: "VkSamplerCreateInfo",
+        extends: &[],
+        create: || Box::new(SamplerCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyDescriptorSet,
+        name: "VkCopyDescriptorSet",
+        extends: &[],
+        create: || Box::new(CopyDescriptorSet::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorPoolCreateInfo,
+        name: "VkDescriptorPoolCreateInfo",
+        extends: &[],
+        create: || Box::new(DescriptorPoolCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetAllocateInfo,
+        name: "VkDescriptorSetAllocateInfo",
+        extends: &[],
+        create: || Box::new(DescriptorSetAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetLayoutCreateInfo,
+        name: "VkDescriptorSetLayoutCreateInfo",
+        extends: &[],
+        create: || Box::new(DescriptorSetLayoutCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::WriteDescriptorSet,
+        name: "VkWriteDescriptorSet",
+        extends: &[],
+        create: || Box::new(WriteDescriptorSet::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FramebufferCreateInfo,
+        name: "VkFramebufferCreateInfo",
+        extends: &[],
+        create: || Box::new(FramebufferCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassCreateInfo,
+        name: "VkRenderPassCreateInfo",
+        extends: &[],
+        create: || Box::new(RenderPassCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandPoolCreateInfo,
+        name: "VkCommandPoolCreateInfo",
+        extends: &[],
+        create: || Box::new(CommandPoolCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandBufferAllocateInfo,
+        name: "VkCommandBufferAllocateInfo",
+        extends: &[],
+        create: || Box::new(CommandBufferAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandBufferBeginInfo,
+        name: "VkCommandBufferBeginInfo",
+        extends: &[],
+        create: || Box::new(CommandBufferBeginInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandBufferInheritanceInfo,
+        name: "VkCommandBufferInheritanceInfo",
+        extends: &[],
+        create: || Box::new(CommandBufferInheritanceInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassBeginInfo,
+        name: "VkRenderPassBeginInfo",
+        extends: &[],
+        create: || Box::new(RenderPassBeginInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSubgroupProperties,
+        name: "VkPhysicalDeviceSubgroupProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceSubgroupProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindBufferMemoryInfo,
+        name: "VkBindBufferMemoryInfo",
+        extends: &[],
+        create: || Box::new(BindBufferMemoryInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindImageMemoryInfo,
+        name: "VkBindImageMemoryInfo",
+        extends: &[],
+        create: || Box::new(BindImageMemoryInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevice16BitStorageFeatures,
+        name: "VkPhysicalDevice16BitStorageFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevice16BitStorageFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryDedicatedRequirements,
+        name: "VkMemoryDedicatedRequirements",
+        extends: &[StructureType::MemoryRequirements2],
+        create: || Box::new(MemoryDedicatedRequirements::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryDedicatedAllocateInfo,
+        name: "VkMemoryDedicatedAllocateInfo",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(MemoryDedicatedAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryAllocateFlagsInfo,
+        name: "VkMemoryAllocateFlagsInfo",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(MemoryAllocateFlagsInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupRenderPassBeginInfo,
+        name: "VkDeviceGroupRenderPassBeginInfo",
+        extends: &[
+            StructureType::RenderPassBeginInfo,
+            StructureType::RenderingInfo,
+        ],
+        create: || Box::new(DeviceGroupRenderPassBeginInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupCommandBufferBeginInfo,
+        name: "VkDeviceGroupCommandBufferBeginInfo",
+        extends: &[StructureType::CommandBufferBeginInfo],
+        create: || Box::new(DeviceGroupCommandBufferBeginInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupSubmitInfo,
+        name: "VkDeviceGroupSubmitInfo",
+        extends: &[StructureType::SubmitInfo],
+        create: || Box::new(DeviceGroupSubmitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupBindSparseInfo,
+        name: "VkDeviceGroupBindSparseInfo",
+        extends: &[StructureType::BindSparseInfo],
+        create: || Box::new(DeviceGroupBindSparseInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindBufferMemoryDeviceGroupInfo,
+        name: "VkBindBufferMemoryDeviceGroupInfo",
+        extends: &[StructureType::BindBufferMemoryInfo],
+        create: || Box::new(BindBufferMemoryDeviceGroupInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindImageMemoryDeviceGroupInfo,
+        name: "VkBindImageMemoryDeviceGroupInfo",
+        extends: &[StructureType::BindImageMemoryInfo],
+        create: || Box::new(BindImageMemoryDeviceGroupInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceGroupProperties,
+        name: "VkPhysicalDeviceGroupProperties",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceGroupProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupDeviceCreateInfo,
+        name: "VkDeviceGroupDeviceCreateInfo",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(DeviceGroupDeviceCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferMemoryRequirementsInfo2,
+        name: "VkBufferMemoryRequirementsInfo2",
+        extends: &[],
+        create: || Box::new(BufferMemoryRequirementsInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageMemoryRequirementsInfo2,
+        name: "VkImageMemoryRequirementsInfo2",
+        extends: &[],
+        create: || Box::new(ImageMemoryRequirementsInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageSparseMemoryRequirementsInfo2,
+        name: "VkImageSparseMemoryRequirementsInfo2",
+        extends: &[],
+        create: || Box::new(ImageSparseMemoryRequirementsInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryRequirements2,
+        name: "VkMemoryRequirements2",
+        extends: &[],
+        create: || Box::new(MemoryRequirements2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SparseImageMemoryRequirements2,
+        name: "VkSparseImageMemoryRequirements2",
+        extends: &[],
+        create: || Box::new(SparseImageMemoryRequirements2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFeatures2,
+        name: "VkPhysicalDeviceFeatures2",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(PhysicalDeviceFeatures2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceProperties2,
+        name: "VkPhysicalDeviceProperties2",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceProperties2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FormatProperties2,
+        name: "VkFormatProperties2",
+        extends: &[],
+        create: || Box::new(FormatProperties2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageFormatProperties2,
+        name: "VkImageFormatProperties2",
+        extends: &[],
+        create: || Box::new(ImageFormatProperties2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageFormatInfo2,
+        name: "VkPhysicalDeviceImageFormatInfo2",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceImageFormatInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueueFamilyProperties2,
+        name: "VkQueueFamilyProperties2",
+        extends: &[],
+        create: || Box::new(QueueFamilyProperties2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMemoryProperties2,
+        name: "VkPhysicalDeviceMemoryProperties2",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceMemoryProperties2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SparseImageFormatProperties2,
+        name: "VkSparseImageFormatProperties2",
+        extends: &[],
+        create: || Box::new(SparseImageFormatProperties2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSparseImageFormatInfo2,
+        name: "VkPhysicalDeviceSparseImageFormatInfo2",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceSparseImageFormatInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePointClippingProperties,
+        name: "VkPhysicalDevicePointClippingProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePointClippingProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassInputAttachmentAspectCreateInfo,
+        name: "VkRenderPassInputAttachmentAspectCreateInfo",
+        extends: &[StructureType::RenderPassCreateInfo],
+        create: || Box::new(RenderPassInputAttachmentAspectCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageViewUsageCreateInfo,
+        name: "VkImageViewUsageCreateInfo",
+        extends: &[StructureType::ImageViewCreateInfo],
+        create: || Box::new(ImageViewUsageCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineTessellationDomainOriginStateCreateInfo,
+        name: "VkPipelineTessellationDomainOriginStateCreateInfo",
+        extends: &[StructureType::PipelineTessellationStateCreateInfo],
+        create: || Box::new(PipelineTessellationDomainOriginStateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassMultiviewCreateInfo,
+        name: "VkRenderPassMultiviewCreateInfo",
+        extends: &[StructureType::RenderPassCreateInfo],
+        create: || Box::new(RenderPassMultiviewCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiviewFeatures,
+        name: "VkPhysicalDeviceMultiviewFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMultiviewFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiviewProperties,
+        name: "VkPhysicalDeviceMultiviewProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMultiviewProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVariablePointersFeatures,
+        name: "VkPhysicalDeviceVariablePointersFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVariablePointersFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceProtectedMemoryFeatures,
+        name: "VkPhysicalDeviceProtectedMemoryFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceProtectedMemoryFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceProtectedMemoryProperties,
+        name: "VkPhysicalDeviceProtectedMemoryProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceProtectedMemoryProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceQueueInfo2,
+        name: "VkDeviceQueueInfo2",
+        extends: &[],
+        create: || Box::new(DeviceQueueInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ProtectedSubmitInfo,
+        name: "VkProtectedSubmitInfo",
+        extends: &[StructureType::SubmitInfo],
+        create: || Box::new(ProtectedSubmitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerYcbcrConversionCreateInfo,
+        name: "VkSamplerYcbcrConversionCreateInfo",
+        extends: &[],
+        create: || Box::new(SamplerYcbcrConversionCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerYcbcrConversionInfo,
+        name: "VkSamplerYcbcrConversionInfo",
+        extends: &[
+            StructureType::SamplerCreateInfo,
+            StructureType::ImageViewCreateInfo,
+        ],
+        create: || Box::new(SamplerYcbcrConversionInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindImagePlaneMemoryInfo,
+        name: "VkBindImagePlaneMemoryInfo",
+        extends: &[StructureType::BindImageMemoryInfo],
+        create: || Box::new(BindImagePlaneMemoryInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImagePlaneMemoryRequirementsInfo,
+        name: "VkImagePlaneMemoryRequirementsInfo",
+        extends: &[StructureType::ImageMemoryRequirementsInfo2],
+        create: || Box::new(ImagePlaneMemoryRequirementsInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSamplerYcbcrConversionFeatures,
+        name: "VkPhysicalDeviceSamplerYcbcrConversionFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSamplerYcbcrConversionFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerYcbcrConversionImageFormatProperties,
+        name: "VkSamplerYcbcrConversionImageFormatProperties",
+        extends: &[StructureType::ImageFormatProperties2],
+        create: || Box::new(SamplerYcbcrConversionImageFormatProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorUpdateTemplateCreateInfo,
+        name: "VkDescriptorUpdateTemplateCreateInfo",
+        extends: &[],
+        create: || Box::new(DescriptorUpdateTemplateCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalImageFormatInfo,
+        name: "VkPhysicalDeviceExternalImageFormatInfo",
+        extends: &[StructureType::PhysicalDeviceImageFormatInfo2],
+        create: || Box::new(PhysicalDeviceExternalImageFormatInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalImageFormatProperties,
+        name: "VkExternalImageFormatProperties",
+        extends: &[StructureType::ImageFormatProperties2],
+        create: || Box::new(ExternalImageFormatProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalBufferInfo,
+        name: "VkPhysicalDeviceExternalBufferInfo",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceExternalBufferInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalBufferProperties,
+        name: "VkExternalBufferProperties",
+        extends: &[],
+        create: || Box::new(ExternalBufferProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceIdProperties,
+        name: "VkPhysicalDeviceIDProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceIDProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalMemoryImageCreateInfo,
+        name: "VkExternalMemoryImageCreateInfo",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ExternalMemoryImageCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalMemoryBufferCreateInfo,
+        name: "VkExternalMemoryBufferCreateInfo",
+        extends: &[StructureType::BufferCreateInfo],
+        create: || Box::new(ExternalMemoryBufferCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExportMemoryAllocateInfo,
+        name: "VkExportMemoryAllocateInfo",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ExportMemoryAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalFenceInfo,
+        name: "VkPhysicalDeviceExternalFenceInfo",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceExternalFenceInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalFenceProperties,
+        name: "VkExternalFenceProperties",
+        extends: &[],
+        create: || Box::new(ExternalFenceProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExportFenceCreateInfo,
+        name: "VkExportFenceCreateInfo",
+        extends: &[StructureType::FenceCreateInfo],
+        create: || Box::new(ExportFenceCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExportSemaphoreCreateInfo,
+        name: "VkExportSemaphoreCreateInfo",
+        extends: &[StructureType::SemaphoreCreateInfo],
+        create: || Box::new(ExportSemaphoreCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalSemaphoreInfo,
+        name: "VkPhysicalDeviceExternalSemaphoreInfo",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceExternalSemaphoreInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalSemaphoreProperties,
+        name: "VkExternalSemaphoreProperties",
+        extends: &[],
+        create: || Box::new(ExternalSemaphoreProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance3Properties,
+        name: "VkPhysicalDeviceMaintenance3Properties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMaintenance3Properties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetLayoutSupport,
+        name: "VkDescriptorSetLayoutSupport",
+        extends: &[],
+        create: || Box::new(DescriptorSetLayoutSupport::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderDrawParametersFeatures,
+        name: "VkPhysicalDeviceShaderDrawParametersFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderDrawParametersFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkan11Features,
+        name: "VkPhysicalDeviceVulkan11Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVulkan11Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkan11Properties,
+        name: "VkPhysicalDeviceVulkan11Properties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceVulkan11Properties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkan12Features,
+        name: "VkPhysicalDeviceVulkan12Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVulkan12Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkan12Properties,
+        name: "VkPhysicalDeviceVulkan12Properties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceVulkan12Properties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageFormatListCreateInfo,
+        name: "VkImageFormatListCreateInfo",
+        extends: &[
+            StructureType::ImageCreateInfo,
+            StructureType::SwapchainCreateInfoKHR,
+            StructureType::PhysicalDeviceImageFormatInfo2,
+        ],
+        create: || Box::new(ImageFormatListCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassCreateInfo2,
+        name: "VkRenderPassCreateInfo2",
+        extends: &[],
+        create: || Box::new(RenderPassCreateInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AttachmentDescription2,
+        name: "VkAttachmentDescription2",
+        extends: &[],
+        create: || Box::new(AttachmentDescription2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AttachmentReference2,
+        name: "VkAttachmentReference2",
+        extends: &[],
+        create: || Box::new(AttachmentReference2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubpassDescription2,
+        name: "VkSubpassDescription2",
+        extends: &[],
+        create: || Box::new(SubpassDescription2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubpassDependency2,
+        name: "VkSubpassDependency2",
+        extends: &[],
+        create: || Box::new(SubpassDependency2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubpassBeginInfo,
+        name: "VkSubpassBeginInfo",
+        extends: &[],
+        create: || Box::new(SubpassBeginInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubpassEndInfo,
+        name: "VkSubpassEndInfo",
+        extends: &[],
+        create: || Box::new(SubpassEndInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevice8BitStorageFeatures,
+        name: "VkPhysicalDevice8BitStorageFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevice8BitStorageFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDriverProperties,
+        name: "VkPhysicalDeviceDriverProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDriverProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderAtomicInt64Features,
+        name: "VkPhysicalDeviceShaderAtomicInt64Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderAtomicInt64Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderFloat16Int8Features,
+        name: "VkPhysicalDeviceShaderFloat16Int8Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderFloat16Int8Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFloatControlsProperties,
+        name: "VkPhysicalDeviceFloatControlsProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFloatControlsProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetLayoutBindingFlagsCreateInfo,
+        name: "VkDescriptorSetLayoutBindingFlagsCreateInfo",
+        extends: &[StructureType::DescriptorSetLayoutCreateInfo],
+        create: || Box::new(DescriptorSetLayoutBindingFlagsCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorIndexingFeatures,
+        name: "VkPhysicalDeviceDescriptorIndexingFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDescriptorIndexingFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorIndexingProperties,
+        name: "VkPhysicalDeviceDescriptorIndexingProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDescriptorIndexingProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetVariableDescriptorCountAllocateInfo,
+        name: "VkDescriptorSetVariableDescriptorCountAllocateInfo",
+        extends: &[StructureType::DescriptorSetAllocateInfo],
+        create: || Box::new(DescriptorSetVariableDescriptorCountAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetVariableDescriptorCountLayoutSupport,
+        name: "VkDescriptorSetVariableDescriptorCountLayoutSupport",
+        extends: &[StructureType::DescriptorSetLayoutSupport],
+        create: || Box::new(DescriptorSetVariableDescriptorCountLayoutSupport::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubpassDescriptionDepthStencilResolve,
+        name: "VkSubpassDescriptionDepthStencilResolve",
+        extends: &[StructureType::SubpassDescription2],
+        create: || Box::new(SubpassDescriptionDepthStencilResolve::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDepthStencilResolveProperties,
+        name: "VkPhysicalDeviceDepthStencilResolveProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDepthStencilResolveProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceScalarBlockLayoutFeatures,
+        name: "VkPhysicalDeviceScalarBlockLayoutFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceScalarBlockLayoutFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageStencilUsageCreateInfo,
+        name: "VkImageStencilUsageCreateInfo",
+        extends: &[
+            StructureType::ImageCreateInfo,
+            StructureType::PhysicalDeviceImageFormatInfo2,
+        ],
+        create: || Box::new(ImageStencilUsageCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerReductionModeCreateInfo,
+        name: "VkSamplerReductionModeCreateInfo",
+        extends: &[StructureType::SamplerCreateInfo],
+        create: || Box::new(SamplerReductionModeCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSamplerFilterMinmaxProperties,
+        name: "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceSamplerFilterMinmaxProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkanMemoryModelFeatures,
+        name: "VkPhysicalDeviceVulkanMemoryModelFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVulkanMemoryModelFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImagelessFramebufferFeatures,
+        name: "VkPhysicalDeviceImagelessFramebufferFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImagelessFramebufferFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FramebufferAttachmentsCreateInfo,
+        name: "VkFramebufferAttachmentsCreateInfo",
+        extends: &[StructureType::FramebufferCreateInfo],
+        create: || Box::new(FramebufferAttachmentsCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FramebufferAttachmentImageInfo,
+        name: "VkFramebufferAttachmentImageInfo",
+        extends: &[],
+        create: || Box::new(FramebufferAttachmentImageInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassAttachmentBeginInfo,
+        name: "VkRenderPassAttachmentBeginInfo",
+        extends: &[StructureType::RenderPassBeginInfo],
+        create: || Box::new(RenderPassAttachmentBeginInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceUniformBufferStandardLayoutFeatures,
+        name: "VkPhysicalDeviceUniformBufferStandardLayoutFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceUniformBufferStandardLayoutFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderSubgroupExtendedTypesFeatures,
+        name: "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderSubgroupExtendedTypesFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSeparateDepthStencilLayoutsFeatures,
+        name: "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSeparateDepthStencilLayoutsFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AttachmentReferenceStencilLayout,
+        name: "VkAttachmentReferenceStencilLayout",
+        extends: &[StructureType::AttachmentReference2],
+        create: || Box::new(AttachmentReferenceStencilLayout::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AttachmentDescriptionStencilLayout,
+        name: "VkAttachmentDescriptionStencilLayout",
+        extends: &[StructureType::AttachmentDescription2],
+        create: || Box::new(AttachmentDescriptionStencilLayout::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceHostQueryResetFeatures,
+        name: "VkPhysicalDeviceHostQueryResetFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceHostQueryResetFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTimelineSemaphoreFeatures,
+        name: "VkPhysicalDeviceTimelineSemaphoreFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceTimelineSemaphoreFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTimelineSemaphoreProperties,
+        name: "VkPhysicalDeviceTimelineSemaphoreProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceTimelineSemaphoreProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SemaphoreTypeCreateInfo,
+        name: "VkSemaphoreTypeCreateInfo",
+        extends: &[
+            StructureType::SemaphoreCreateInfo,
+            StructureType::PhysicalDeviceExternalSemaphoreInfo,
+        ],
+        create: || Box::new(SemaphoreTypeCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::TimelineSemaphoreSubmitInfo,
+        name: "VkTimelineSemaphoreSubmitInfo",
+        extends: &[StructureType::SubmitInfo, StructureType::BindSparseInfo],
+        create: || Box::new(TimelineSemaphoreSubmitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SemaphoreWaitInfo,
+        name: "VkSemaphoreWaitInfo",
+        extends: &[],
+        create: || Box::new(SemaphoreWaitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SemaphoreSignalInfo,
+        name: "VkSemaphoreSignalInfo",
+        extends: &[],
+        create: || Box::new(SemaphoreSignalInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceBufferDeviceAddressFeatures,
+        name: "VkPhysicalDeviceBufferDeviceAddressFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceBufferDeviceAddressFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferDeviceAddressInfo,
+        name: "VkBufferDeviceAddressInfo",
+        extends: &[],
+        create: || Box::new(BufferDeviceAddressInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferOpaqueCaptureAddressCreateInfo,
+        name: "VkBufferOpaqueCaptureAddressCreateInfo",
+        extends: &[StructureType::BufferCreateInfo],
+        create: || Box::new(BufferOpaqueCaptureAddressCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryOpaqueCaptureAddressAllocateInfo,
+        name: "VkMemoryOpaqueCaptureAddressAllocateInfo",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(MemoryOpaqueCaptureAddressAllocateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceMemoryOpaqueCaptureAddressInfo,
+        name: "VkDeviceMemoryOpaqueCaptureAddressInfo",
+        extends: &[],
+        create: || Box::new(DeviceMemoryOpaqueCaptureAddressInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkan13Features,
+        name: "VkPhysicalDeviceVulkan13Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVulkan13Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVulkan13Properties,
+        name: "VkPhysicalDeviceVulkan13Properties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceVulkan13Properties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineCreationFeedbackCreateInfo,
+        name: "VkPipelineCreationFeedbackCreateInfo",
+        extends: &[
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::ComputePipelineCreateInfo,
+            #[cfg(feature = "vendor-nv")]
+            StructureType::RayTracingPipelineCreateInfoNV,
+            StructureType::RayTracingPipelineCreateInfoKHR,
+            #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+            StructureType::ExecutionGraphPipelineCreateInfoAMDX,
+        ],
+        create: || Box::new(PipelineCreationFeedbackCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderTerminateInvocationFeatures,
+        name: "VkPhysicalDeviceShaderTerminateInvocationFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderTerminateInvocationFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceToolProperties,
+        name: "VkPhysicalDeviceToolProperties",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceToolProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderDemoteToHelperInvocationFeatures,
+        name: "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderDemoteToHelperInvocationFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePrivateDataFeatures,
+        name: "VkPhysicalDevicePrivateDataFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePrivateDataFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DevicePrivateDataCreateInfo,
+        name: "VkDevicePrivateDataCreateInfo",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(DevicePrivateDataCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PrivateDataSlotCreateInfo,
+        name: "VkPrivateDataSlotCreateInfo",
+        extends: &[],
+        create: || Box::new(PrivateDataSlotCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineCreationCacheControlFeatures,
+        name: "VkPhysicalDevicePipelineCreationCacheControlFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelineCreationCacheControlFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryBarrier2,
+        name: "VkMemoryBarrier2",
+        extends: &[StructureType::SubpassDependency2],
+        create: || Box::new(MemoryBarrier2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferMemoryBarrier2,
+        name: "VkBufferMemoryBarrier2",
+        extends: &[],
+        create: || Box::new(BufferMemoryBarrier2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageMemoryBarrier2,
+        name: "VkImageMemoryBarrier2",
+        extends: &[],
+        create: || Box::new(ImageMemoryBarrier2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DependencyInfo,
+        name: "VkDependencyInfo",
+        extends: &[],
+        create: || Box::new(DependencyInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubmitInfo2,
+        name: "VkSubmitInfo2",
+        extends: &[],
+        create: || Box::new(SubmitInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SemaphoreSubmitInfo,
+        name: "VkSemaphoreSubmitInfo",
+        extends: &[],
+        create: || Box::new(SemaphoreSubmitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandBufferSubmitInfo,
+        name: "VkCommandBufferSubmitInfo",
+        extends: &[],
+        create: || Box::new(CommandBufferSubmitInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSynchronization2Features,
+        name: "VkPhysicalDeviceSynchronization2Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSynchronization2Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures,
+        name: "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageRobustnessFeatures,
+        name: "VkPhysicalDeviceImageRobustnessFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageRobustnessFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyBufferInfo2,
+        name: "VkCopyBufferInfo2",
+        extends: &[],
+        create: || Box::new(CopyBufferInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyImageInfo2,
+        name: "VkCopyImageInfo2",
+        extends: &[],
+        create: || Box::new(CopyImageInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyBufferToImageInfo2,
+        name: "VkCopyBufferToImageInfo2",
+        extends: &[],
+        create: || Box::new(CopyBufferToImageInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyImageToBufferInfo2,
+        name: "VkCopyImageToBufferInfo2",
+        extends: &[],
+        create: || Box::new(CopyImageToBufferInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BlitImageInfo2,
+        name: "VkBlitImageInfo2",
+        extends: &[],
+        create: || Box::new(BlitImageInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ResolveImageInfo2,
+        name: "VkResolveImageInfo2",
+        extends: &[],
+        create: || Box::new(ResolveImageInfo2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferCopy2,
+        name: "VkBufferCopy2",
+        extends: &[],
+        create: || Box::new(BufferCopy2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageCopy2,
+        name: "VkImageCopy2",
+        extends: &[],
+        create: || Box::new(ImageCopy2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageBlit2,
+        name: "VkImageBlit2",
+        extends: &[],
+        create: || Box::new(ImageBlit2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferImageCopy2,
+        name: "VkBufferImageCopy2",
+        extends: &[],
+        create: || Box::new(BufferImageCopy2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageResolve2,
+        name: "VkImageResolve2",
+        extends: &[],
+        create: || Box::new(ImageResolve2::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSubgroupSizeControlFeatures,
+        name: "VkPhysicalDeviceSubgroupSizeControlFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSubgroupSizeControlFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSubgroupSizeControlProperties,
+        name: "VkPhysicalDeviceSubgroupSizeControlProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceSubgroupSizeControlProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineShaderStageRequiredSubgroupSizeCreateInfo,
+        name: "VkPipelineShaderStageRequiredSubgroupSizeCreateInfo",
+        extends: &[
+            StructureType::PipelineShaderStageCreateInfo,
+            StructureType::ShaderCreateInfoEXT,
+        ],
+        create: || Box::new(PipelineShaderStageRequiredSubgroupSizeCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceInlineUniformBlockFeatures,
+        name: "VkPhysicalDeviceInlineUniformBlockFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceInlineUniformBlockFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceInlineUniformBlockProperties,
+        name: "VkPhysicalDeviceInlineUniformBlockProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceInlineUniformBlockProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::WriteDescriptorSetInlineUniformBlock,
+        name: "VkWriteDescriptorSetInlineUniformBlock",
+        extends: &[StructureType::WriteDescriptorSet],
+        create: || Box::new(WriteDescriptorSetInlineUniformBlock::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorPoolInlineUniformBlockCreateInfo,
+        name: "VkDescriptorPoolInlineUniformBlockCreateInfo",
+        extends: &[StructureType::DescriptorPoolCreateInfo],
+        create: || Box::new(DescriptorPoolInlineUniformBlockCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTextureCompressionAstcHdrFeatures,
+        name: "VkPhysicalDeviceTextureCompressionASTCHDRFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceTextureCompressionASTCHDRFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingInfo,
+        name: "VkRenderingInfo",
+        extends: &[],
+        create: || Box::new(RenderingInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingAttachmentInfo,
+        name: "VkRenderingAttachmentInfo",
+        extends: &[],
+        create: || Box::new(RenderingAttachmentInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRenderingCreateInfo,
+        name: "VkPipelineRenderingCreateInfo",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(PipelineRenderingCreateInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDynamicRenderingFeatures,
+        name: "VkPhysicalDeviceDynamicRenderingFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDynamicRenderingFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandBufferInheritanceRenderingInfo,
+        name: "VkCommandBufferInheritanceRenderingInfo",
+        extends: &[StructureType::CommandBufferInheritanceInfo],
+        create: || Box::new(CommandBufferInheritanceRenderingInfo::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderIntegerDotProductFeatures,
+        name: "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderIntegerDotProductFeatures::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderIntegerDotProductProperties,
+        name: "VkPhysicalDeviceShaderIntegerDotProductProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderIntegerDotProductProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTexelBufferAlignmentProperties,
+        name: "VkPhysicalDeviceTexelBufferAlignmentProperties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceTexelBufferAlignmentProperties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FormatProperties3,
+        name: "VkFormatProperties3",
+        extends: &[StructureType::FormatProperties2],
+        create: || Box::new(FormatProperties3::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance4Features,
+        name: "VkPhysicalDeviceMaintenance4Features",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMaintenance4Features::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance4Properties,
+        name: "VkPhysicalDeviceMaintenance4Properties",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMaintenance4Properties::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceBufferMemoryRequirements,
+        name: "VkDeviceBufferMemoryRequirements",
+        extends: &[],
+        create: || Box::new(DeviceBufferMemoryRequirements::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceImageMemoryRequirements,
+        name: "VkDeviceImageMemoryRequirements",
+        extends: &[],
+        create: || Box::new(DeviceImageMemoryRequirements::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SwapchainCreateInfoKHR,
+        name: "VkSwapchainCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(SwapchainCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PresentInfoKHR,
+        name: "VkPresentInfoKHR",
+        extends: &[],
+        create: || Box::new(PresentInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageSwapchainCreateInfoKHR,
+        name: "VkImageSwapchainCreateInfoKHR",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ImageSwapchainCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindImageMemorySwapchainInfoKHR,
+        name: "VkBindImageMemorySwapchainInfoKHR",
+        extends: &[StructureType::BindImageMemoryInfo],
+        create: || Box::new(BindImageMemorySwapchainInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AcquireNextImageInfoKHR,
+        name: "VkAcquireNextImageInfoKHR",
+        extends: &[],
+        create: || Box::new(AcquireNextImageInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupPresentCapabilitiesKHR,
+        name: "VkDeviceGroupPresentCapabilitiesKHR",
+        extends: &[],
+        create: || Box::new(DeviceGroupPresentCapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupPresentInfoKHR,
+        name: "VkDeviceGroupPresentInfoKHR",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(DeviceGroupPresentInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceGroupSwapchainCreateInfoKHR,
+        name: "VkDeviceGroupSwapchainCreateInfoKHR",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(DeviceGroupSwapchainCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayModeCreateInfoKHR,
+        name: "VkDisplayModeCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(DisplayModeCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplaySurfaceCreateInfoKHR,
+        name: "VkDisplaySurfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(DisplaySurfaceCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayPresentInfoKHR,
+        name: "VkDisplayPresentInfoKHR",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(DisplayPresentInfoKHR::default()),
+    },
+    #[cfg(any(
+        feature = "platform-xlib",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    StructureInfo {
+        structure_type: StructureType::XlibSurfaceCreateInfoKHR,
+        name: "VkXlibSurfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(XlibSurfaceCreateInfoKHR::default()),
+    },
+    #[cfg(any(
+        feature = "platform-xcb",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    StructureInfo {
+        structure_type: StructureType::XcbSurfaceCreateInfoKHR,
+        name: "VkXcbSurfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(XcbSurfaceCreateInfoKHR::default()),
+    },
+    #[cfg(any(
+        feature = "platform-wayland",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    StructureInfo {
+        structure_type: StructureType::WaylandSurfaceCreateInfoKHR,
+        name: "VkWaylandSurfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(WaylandSurfaceCreateInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::AndroidSurfaceCreateInfoKHR,
+        name: "VkAndroidSurfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(AndroidSurfaceCreateInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::Win32SurfaceCreateInfoKHR,
+        name: "VkWin32SurfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(Win32SurfaceCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugReportCallbackCreateInfoEXT,
+        name: "VkDebugReportCallbackCreateInfoEXT",
+        extends: &[StructureType::InstanceCreateInfo],
+        create: || Box::new(DebugReportCallbackCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationStateRasterizationOrderAMD,
+        name: "VkPipelineRasterizationStateRasterizationOrderAMD",
+        extends: &[StructureType::PipelineRasterizationStateCreateInfo],
+        create: || Box::new(PipelineRasterizationStateRasterizationOrderAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugMarkerObjectNameInfoEXT,
+        name: "VkDebugMarkerObjectNameInfoEXT",
+        extends: &[],
+        create: || Box::new(DebugMarkerObjectNameInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugMarkerObjectTagInfoEXT,
+        name: "VkDebugMarkerObjectTagInfoEXT",
+        extends: &[],
+        create: || Box::new(DebugMarkerObjectTagInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugMarkerMarkerInfoEXT,
+        name: "VkDebugMarkerMarkerInfoEXT",
+        extends: &[],
+        create: || Box::new(DebugMarkerMarkerInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueueFamilyQueryResultStatusPropertiesKHR,
+        name: "VkQueueFamilyQueryResultStatusPropertiesKHR",
+        extends: &[StructureType::QueueFamilyProperties2],
+        create: || Box::new(QueueFamilyQueryResultStatusPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueueFamilyVideoPropertiesKHR,
+        name: "VkQueueFamilyVideoPropertiesKHR",
+        extends: &[StructureType::QueueFamilyProperties2],
+        create: || Box::new(QueueFamilyVideoPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoProfileInfoKHR,
+        name: "VkVideoProfileInfoKHR",
+        extends: &[
+            StructureType::QueryPoolCreateInfo,
+            StructureType::CommandBufferInheritanceInfo,
+        ],
+        create: || Box::new(VideoProfileInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoProfileListInfoKHR,
+        name: "VkVideoProfileListInfoKHR",
+        extends: &[
+            StructureType::PhysicalDeviceImageFormatInfo2,
+            StructureType::PhysicalDeviceVideoFormatInfoKHR,
+            StructureType::ImageCreateInfo,
+            StructureType::BufferCreateInfo,
+        ],
+        create: || Box::new(VideoProfileListInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoCapabilitiesKHR,
+        name: "VkVideoCapabilitiesKHR",
+        extends: &[],
+        create: || Box::new(VideoCapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVideoFormatInfoKHR,
+        name: "VkPhysicalDeviceVideoFormatInfoKHR",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceVideoFormatInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoFormatPropertiesKHR,
+        name: "VkVideoFormatPropertiesKHR",
+        extends: &[],
+        create: || Box::new(VideoFormatPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoPictureResourceInfoKHR,
+        name: "VkVideoPictureResourceInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoPictureResourceInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoReferenceSlotInfoKHR,
+        name: "VkVideoReferenceSlotInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoReferenceSlotInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoSessionMemoryRequirementsKHR,
+        name: "VkVideoSessionMemoryRequirementsKHR",
+        extends: &[],
+        create: || Box::new(VideoSessionMemoryRequirementsKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindVideoSessionMemoryInfoKHR,
+        name: "VkBindVideoSessionMemoryInfoKHR",
+        extends: &[],
+        create: || Box::new(BindVideoSessionMemoryInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoSessionCreateInfoKHR,
+        name: "VkVideoSessionCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoSessionCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoSessionParametersCreateInfoKHR,
+        name: "VkVideoSessionParametersCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoSessionParametersCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoSessionParametersUpdateInfoKHR,
+        name: "VkVideoSessionParametersUpdateInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoSessionParametersUpdateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoBeginCodingInfoKHR,
+        name: "VkVideoBeginCodingInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoBeginCodingInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEndCodingInfoKHR,
+        name: "VkVideoEndCodingInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEndCodingInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoCodingControlInfoKHR,
+        name: "VkVideoCodingControlInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoCodingControlInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeCapabilitiesKHR,
+        name: "VkVideoDecodeCapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoDecodeCapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeUsageInfoKHR,
+        name: "VkVideoDecodeUsageInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoDecodeUsageInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeInfoKHR,
+        name: "VkVideoDecodeInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoDecodeInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::DedicatedAllocationImageCreateInfoNV,
+        name: "VkDedicatedAllocationImageCreateInfoNV",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(DedicatedAllocationImageCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::DedicatedAllocationBufferCreateInfoNV,
+        name: "VkDedicatedAllocationBufferCreateInfoNV",
+        extends: &[StructureType::BufferCreateInfo],
+        create: || Box::new(DedicatedAllocationBufferCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::DedicatedAllocationMemoryAllocateInfoNV,
+        name: "VkDedicatedAllocationMemoryAllocateInfoNV",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(DedicatedAllocationMemoryAllocateInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTransformFeedbackFeaturesEXT,
+        name: "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceTransformFeedbackFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTransformFeedbackPropertiesEXT,
+        name: "VkPhysicalDeviceTransformFeedbackPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceTransformFeedbackPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationStateStreamCreateInfoEXT,
+        name: "VkPipelineRasterizationStateStreamCreateInfoEXT",
+        extends: &[StructureType::PipelineRasterizationStateCreateInfo],
+        create: || Box::new(PipelineRasterizationStateStreamCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::CuModuleCreateInfoNVX,
+        name: "VkCuModuleCreateInfoNVX",
+        extends: &[],
+        create: || Box::new(CuModuleCreateInfoNVX::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::CuFunctionCreateInfoNVX,
+        name: "VkCuFunctionCreateInfoNVX",
+        extends: &[],
+        create: || Box::new(CuFunctionCreateInfoNVX::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::CuLaunchInfoNVX,
+        name: "VkCuLaunchInfoNVX",
+        extends: &[],
+        create: || Box::new(CuLaunchInfoNVX::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::ImageViewHandleInfoNVX,
+        name: "VkImageViewHandleInfoNVX",
+        extends: &[],
+        create: || Box::new(ImageViewHandleInfoNVX::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::ImageViewAddressPropertiesNVX,
+        name: "VkImageViewAddressPropertiesNVX",
+        extends: &[],
+        create: || Box::new(ImageViewAddressPropertiesNVX::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264CapabilitiesKHR,
+        name: "VkVideoEncodeH264CapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoEncodeH264CapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264QualityLevelPropertiesKHR,
+        name: "VkVideoEncodeH264QualityLevelPropertiesKHR",
+        extends: &[StructureType::VideoEncodeQualityLevelPropertiesKHR],
+        create: || Box::new(VideoEncodeH264QualityLevelPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264SessionCreateInfoKHR,
+        name: "VkVideoEncodeH264SessionCreateInfoKHR",
+        extends: &[StructureType::VideoSessionCreateInfoKHR],
+        create: || Box::new(VideoEncodeH264SessionCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264SessionParametersCreateInfoKHR,
+        name: "VkVideoEncodeH264SessionParametersCreateInfoKHR",
+        extends: &[StructureType::VideoSessionParametersCreateInfoKHR],
+        create: || Box::new(VideoEncodeH264SessionParametersCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264SessionParametersAddInfoKHR,
+        name: "VkVideoEncodeH264SessionParametersAddInfoKHR",
+        extends: &[StructureType::VideoSessionParametersUpdateInfoKHR],
+        create: || Box::new(VideoEncodeH264SessionParametersAddInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264SessionParametersGetInfoKHR,
+        name: "VkVideoEncodeH264SessionParametersGetInfoKHR",
+        extends: &[StructureType::VideoEncodeSessionParametersGetInfoKHR],
+        create: || Box::new(VideoEncodeH264SessionParametersGetInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264SessionParametersFeedbackInfoKHR,
+        name: "VkVideoEncodeH264SessionParametersFeedbackInfoKHR",
+        extends: &[StructureType::VideoEncodeSessionParametersFeedbackInfoKHR],
+        create: || Box::new(VideoEncodeH264SessionParametersFeedbackInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264PictureInfoKHR,
+        name: "VkVideoEncodeH264PictureInfoKHR",
+        extends: &[StructureType::VideoEncodeInfoKHR],
+        create: || Box::new(VideoEncodeH264PictureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264DpbSlotInfoKHR,
+        name: "VkVideoEncodeH264DpbSlotInfoKHR",
+        extends: &[StructureType::VideoReferenceSlotInfoKHR],
+        create: || Box::new(VideoEncodeH264DpbSlotInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264NaluSliceInfoKHR,
+        name: "VkVideoEncodeH264NaluSliceInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeH264NaluSliceInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264ProfileInfoKHR,
+        name: "VkVideoEncodeH264ProfileInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoEncodeH264ProfileInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264RateControlInfoKHR,
+        name: "VkVideoEncodeH264RateControlInfoKHR",
+        extends: &[
+            StructureType::VideoCodingControlInfoKHR,
+            StructureType::VideoBeginCodingInfoKHR,
+        ],
+        create: || Box::new(VideoEncodeH264RateControlInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264RateControlLayerInfoKHR,
+        name: "VkVideoEncodeH264RateControlLayerInfoKHR",
+        extends: &[StructureType::VideoEncodeRateControlLayerInfoKHR],
+        create: || Box::new(VideoEncodeH264RateControlLayerInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH264GopRemainingFrameInfoKHR,
+        name: "VkVideoEncodeH264GopRemainingFrameInfoKHR",
+        extends: &[StructureType::VideoBeginCodingInfoKHR],
+        create: || Box::new(VideoEncodeH264GopRemainingFrameInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265CapabilitiesKHR,
+        name: "VkVideoEncodeH265CapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoEncodeH265CapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265SessionCreateInfoKHR,
+        name: "VkVideoEncodeH265SessionCreateInfoKHR",
+        extends: &[StructureType::VideoSessionCreateInfoKHR],
+        create: || Box::new(VideoEncodeH265SessionCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265QualityLevelPropertiesKHR,
+        name: "VkVideoEncodeH265QualityLevelPropertiesKHR",
+        extends: &[StructureType::VideoEncodeQualityLevelPropertiesKHR],
+        create: || Box::new(VideoEncodeH265QualityLevelPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265SessionParametersCreateInfoKHR,
+        name: "VkVideoEncodeH265SessionParametersCreateInfoKHR",
+        extends: &[StructureType::VideoSessionParametersCreateInfoKHR],
+        create: || Box::new(VideoEncodeH265SessionParametersCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265SessionParametersAddInfoKHR,
+        name: "VkVideoEncodeH265SessionParametersAddInfoKHR",
+        extends: &[StructureType::VideoSessionParametersUpdateInfoKHR],
+        create: || Box::new(VideoEncodeH265SessionParametersAddInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265SessionParametersGetInfoKHR,
+        name: "VkVideoEncodeH265SessionParametersGetInfoKHR",
+        extends: &[StructureType::VideoEncodeSessionParametersGetInfoKHR],
+        create: || Box::new(VideoEncodeH265SessionParametersGetInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265SessionParametersFeedbackInfoKHR,
+        name: "VkVideoEncodeH265SessionParametersFeedbackInfoKHR",
+        extends: &[StructureType::VideoEncodeSessionParametersFeedbackInfoKHR],
+        create: || Box::new(VideoEncodeH265SessionParametersFeedbackInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265PictureInfoKHR,
+        name: "VkVideoEncodeH265PictureInfoKHR",
+        extends: &[StructureType::VideoEncodeInfoKHR],
+        create: || Box::new(VideoEncodeH265PictureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265DpbSlotInfoKHR,
+        name: "VkVideoEncodeH265DpbSlotInfoKHR",
+        extends: &[StructureType::VideoReferenceSlotInfoKHR],
+        create: || Box::new(VideoEncodeH265DpbSlotInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265NaluSliceSegmentInfoKHR,
+        name: "VkVideoEncodeH265NaluSliceSegmentInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeH265NaluSliceSegmentInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265ProfileInfoKHR,
+        name: "VkVideoEncodeH265ProfileInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoEncodeH265ProfileInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265RateControlInfoKHR,
+        name: "VkVideoEncodeH265RateControlInfoKHR",
+        extends: &[
+            StructureType::VideoCodingControlInfoKHR,
+            StructureType::VideoBeginCodingInfoKHR,
+        ],
+        create: || Box::new(VideoEncodeH265RateControlInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265RateControlLayerInfoKHR,
+        name: "VkVideoEncodeH265RateControlLayerInfoKHR",
+        extends: &[StructureType::VideoEncodeRateControlLayerInfoKHR],
+        create: || Box::new(VideoEncodeH265RateControlLayerInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeH265GopRemainingFrameInfoKHR,
+        name: "VkVideoEncodeH265GopRemainingFrameInfoKHR",
+        extends: &[StructureType::VideoBeginCodingInfoKHR],
+        create: || Box::new(VideoEncodeH265GopRemainingFrameInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH264ProfileInfoKHR,
+        name: "VkVideoDecodeH264ProfileInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoDecodeH264ProfileInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH264CapabilitiesKHR,
+        name: "VkVideoDecodeH264CapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoDecodeH264CapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH264SessionParametersCreateInfoKHR,
+        name: "VkVideoDecodeH264SessionParametersCreateInfoKHR",
+        extends: &[StructureType::VideoSessionParametersCreateInfoKHR],
+        create: || Box::new(VideoDecodeH264SessionParametersCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH264SessionParametersAddInfoKHR,
+        name: "VkVideoDecodeH264SessionParametersAddInfoKHR",
+        extends: &[StructureType::VideoSessionParametersUpdateInfoKHR],
+        create: || Box::new(VideoDecodeH264SessionParametersAddInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH264PictureInfoKHR,
+        name: "VkVideoDecodeH264PictureInfoKHR",
+        extends: &[StructureType::VideoDecodeInfoKHR],
+        create: || Box::new(VideoDecodeH264PictureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH264DpbSlotInfoKHR,
+        name: "VkVideoDecodeH264DpbSlotInfoKHR",
+        extends: &[StructureType::VideoReferenceSlotInfoKHR],
+        create: || Box::new(VideoDecodeH264DpbSlotInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::TextureLodGatherFormatPropertiesAMD,
+        name: "VkTextureLODGatherFormatPropertiesAMD",
+        extends: &[StructureType::ImageFormatProperties2],
+        create: || Box::new(TextureLODGatherFormatPropertiesAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingFragmentShadingRateAttachmentInfoKHR,
+        name: "VkRenderingFragmentShadingRateAttachmentInfoKHR",
+        extends: &[StructureType::RenderingInfo],
+        create: || Box::new(RenderingFragmentShadingRateAttachmentInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingFragmentDensityMapAttachmentInfoEXT,
+        name: "VkRenderingFragmentDensityMapAttachmentInfoEXT",
+        extends: &[StructureType::RenderingInfo],
+        create: || Box::new(RenderingFragmentDensityMapAttachmentInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::AttachmentSampleCountInfoAMD,
+        name: "VkAttachmentSampleCountInfoAMD",
+        extends: &[
+            StructureType::CommandBufferInheritanceInfo,
+            StructureType::GraphicsPipelineCreateInfo,
+        ],
+        create: || Box::new(AttachmentSampleCountInfoAMD::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::MultiviewPerViewAttributesInfoNVX,
+        name: "VkMultiviewPerViewAttributesInfoNVX",
+        extends: &[
+            StructureType::CommandBufferInheritanceInfo,
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::RenderingInfo,
+        ],
+        create: || Box::new(MultiviewPerViewAttributesInfoNVX::default()),
+    },
+    #[cfg(feature = "platform-ggp")]
+    StructureInfo {
+        structure_type: StructureType::StreamDescriptorSurfaceCreateInfoGGP,
+        name: "VkStreamDescriptorSurfaceCreateInfoGGP",
+        extends: &[],
+        create: || Box::new(StreamDescriptorSurfaceCreateInfoGGP::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCornerSampledImageFeaturesNV,
+        name: "VkPhysicalDeviceCornerSampledImageFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCornerSampledImageFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::ExternalMemoryImageCreateInfoNV,
+        name: "VkExternalMemoryImageCreateInfoNV",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ExternalMemoryImageCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::ExportMemoryAllocateInfoNV,
+        name: "VkExportMemoryAllocateInfoNV",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ExportMemoryAllocateInfoNV::default()),
+    },
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
+    StructureInfo {
+        structure_type: StructureType::ImportMemoryWin32HandleInfoNV,
+        name: "VkImportMemoryWin32HandleInfoNV",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMemoryWin32HandleInfoNV::default()),
+    },
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
+    StructureInfo {
+        structure_type: StructureType::ExportMemoryWin32HandleInfoNV,
+        name: "VkExportMemoryWin32HandleInfoNV",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ExportMemoryWin32HandleInfoNV::default()),
+    },
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
+    StructureInfo {
+        structure_type: StructureType::Win32KeyedMutexAcquireReleaseInfoNV,
+        name: "VkWin32KeyedMutexAcquireReleaseInfoNV",
+        extends: &[StructureType::SubmitInfo, StructureType::SubmitInfo2],
+        create: || Box::new(Win32KeyedMutexAcquireReleaseInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ValidationFlagsEXT,
+        name: "VkValidationFlagsEXT",
+        extends: &[StructureType::InstanceCreateInfo],
+        create: || Box::new(ValidationFlagsEXT::default()),
+    },
+    #[cfg(feature = "platform-vi")]
+    StructureInfo {
+        structure_type: StructureType::ViSurfaceCreateInfoNN,
+        name: "VkViSurfaceCreateInfoNN",
+        extends: &[],
+        create: || Box::new(ViSurfaceCreateInfoNN::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageViewAstcDecodeModeEXT,
+        name: "VkImageViewASTCDecodeModeEXT",
+        extends: &[StructureType::ImageViewCreateInfo],
+        create: || Box::new(ImageViewASTCDecodeModeEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAstcDecodeFeaturesEXT,
+        name: "VkPhysicalDeviceASTCDecodeFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceASTCDecodeFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineRobustnessFeaturesEXT,
+        name: "VkPhysicalDevicePipelineRobustnessFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelineRobustnessFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineRobustnessPropertiesEXT,
+        name: "VkPhysicalDevicePipelineRobustnessPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePipelineRobustnessPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRobustnessCreateInfoEXT,
+        name: "VkPipelineRobustnessCreateInfoEXT",
+        extends: &[
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::ComputePipelineCreateInfo,
+            StructureType::PipelineShaderStageCreateInfo,
+            StructureType::RayTracingPipelineCreateInfoKHR,
+        ],
+        create: || Box::new(PipelineRobustnessCreateInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMemoryWin32HandleInfoKHR,
+        name: "VkImportMemoryWin32HandleInfoKHR",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMemoryWin32HandleInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMemoryWin32HandleInfoKHR,
+        name: "VkExportMemoryWin32HandleInfoKHR",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ExportMemoryWin32HandleInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::MemoryWin32HandlePropertiesKHR,
+        name: "VkMemoryWin32HandlePropertiesKHR",
+        extends: &[],
+        create: || Box::new(MemoryWin32HandlePropertiesKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::MemoryGetWin32HandleInfoKHR,
+        name: "VkMemoryGetWin32HandleInfoKHR",
+        extends: &[],
+        create: || Box::new(MemoryGetWin32HandleInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImportMemoryFdInfoKHR,
+        name: "VkImportMemoryFdInfoKHR",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMemoryFdInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryFdPropertiesKHR,
+        name: "VkMemoryFdPropertiesKHR",
+        extends: &[],
+        create: || Box::new(MemoryFdPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryGetFdInfoKHR,
+        name: "VkMemoryGetFdInfoKHR",
+        extends: &[],
+        create: || Box::new(MemoryGetFdInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::Win32KeyedMutexAcquireReleaseInfoKHR,
+        name: "VkWin32KeyedMutexAcquireReleaseInfoKHR",
+        extends: &[StructureType::SubmitInfo, StructureType::SubmitInfo2],
+        create: || Box::new(Win32KeyedMutexAcquireReleaseInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::ImportSemaphoreWin32HandleInfoKHR,
+        name: "VkImportSemaphoreWin32HandleInfoKHR",
+        extends: &[],
+        create: || Box::new(ImportSemaphoreWin32HandleInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::ExportSemaphoreWin32HandleInfoKHR,
+        name: "VkExportSemaphoreWin32HandleInfoKHR",
+        extends: &[StructureType::SemaphoreCreateInfo],
+        create: || Box::new(ExportSemaphoreWin32HandleInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::D3D12FenceSubmitInfoKHR,
+        name: "VkD3D12FenceSubmitInfoKHR",
+        extends: &[StructureType::SubmitInfo],
+        create: || Box::new(D3D12FenceSubmitInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::SemaphoreGetWin32HandleInfoKHR,
+        name: "VkSemaphoreGetWin32HandleInfoKHR",
+        extends: &[],
+        create: || Box::new(SemaphoreGetWin32HandleInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImportSemaphoreFdInfoKHR,
+        name: "VkImportSemaphoreFdInfoKHR",
+        extends: &[],
+        create: || Box::new(ImportSemaphoreFdInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SemaphoreGetFdInfoKHR,
+        name: "VkSemaphoreGetFdInfoKHR",
+        extends: &[],
+        create: || Box::new(SemaphoreGetFdInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePushDescriptorPropertiesKHR,
+        name: "VkPhysicalDevicePushDescriptorPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePushDescriptorPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ConditionalRenderingBeginInfoEXT,
+        name: "VkConditionalRenderingBeginInfoEXT",
+        extends: &[],
+        create: || Box::new(ConditionalRenderingBeginInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceConditionalRenderingFeaturesEXT,
+        name: "VkPhysicalDeviceConditionalRenderingFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceConditionalRenderingFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CommandBufferInheritanceConditionalRenderingInfoEXT,
+        name: "VkCommandBufferInheritanceConditionalRenderingInfoEXT",
+        extends: &[StructureType::CommandBufferInheritanceInfo],
+        create: || Box::new(CommandBufferInheritanceConditionalRenderingInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PresentRegionsKHR,
+        name: "VkPresentRegionsKHR",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(PresentRegionsKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportWScalingStateCreateInfoNV,
+        name: "VkPipelineViewportWScalingStateCreateInfoNV",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportWScalingStateCreateInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfaceCapabilities2EXT,
+        name: "VkSurfaceCapabilities2EXT",
+        extends: &[],
+        create: || Box::new(SurfaceCapabilities2EXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayPowerInfoEXT,
+        name: "VkDisplayPowerInfoEXT",
+        extends: &[],
+        create: || Box::new(DisplayPowerInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceEventInfoEXT,
+        name: "VkDeviceEventInfoEXT",
+        extends: &[],
+        create: || Box::new(DeviceEventInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayEventInfoEXT,
+        name: "VkDisplayEventInfoEXT",
+        extends: &[],
+        create: || Box::new(DisplayEventInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SwapchainCounterCreateInfoEXT,
+        name: "VkSwapchainCounterCreateInfoEXT",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(SwapchainCounterCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-google")]
+    StructureInfo {
+        structure_type: StructureType::PresentTimesInfoGOOGLE,
+        name: "VkPresentTimesInfoGOOGLE",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(PresentTimesInfoGOOGLE::default()),
+    },
+    #[cfg(feature = "vendor-nvx")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX,
+        name: "VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportSwizzleStateCreateInfoNV,
+        name: "VkPipelineViewportSwizzleStateCreateInfoNV",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportSwizzleStateCreateInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDiscardRectanglePropertiesEXT,
+        name: "VkPhysicalDeviceDiscardRectanglePropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDiscardRectanglePropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineDiscardRectangleStateCreateInfoEXT,
+        name: "VkPipelineDiscardRectangleStateCreateInfoEXT",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(PipelineDiscardRectangleStateCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceConservativeRasterizationPropertiesEXT,
+        name: "VkPhysicalDeviceConservativeRasterizationPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceConservativeRasterizationPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationConservativeStateCreateInfoEXT,
+        name: "VkPipelineRasterizationConservativeStateCreateInfoEXT",
+        extends: &[StructureType::PipelineRasterizationStateCreateInfo],
+        create: || Box::new(PipelineRasterizationConservativeStateCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDepthClipEnableFeaturesEXT,
+        name: "VkPhysicalDeviceDepthClipEnableFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDepthClipEnableFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationDepthClipStateCreateInfoEXT,
+        name: "VkPipelineRasterizationDepthClipStateCreateInfoEXT",
+        extends: &[StructureType::PipelineRasterizationStateCreateInfo],
+        create: || Box::new(PipelineRasterizationDepthClipStateCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::HdrMetadataEXT,
+        name: "VkHdrMetadataEXT",
+        extends: &[],
+        create: || Box::new(HdrMetadataEXT::default()),
+    },
+    #[cfg(feature = "vendor-img")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRelaxedLineRasterizationFeaturesIMG,
+        name: "VkPhysicalDeviceRelaxedLineRasterizationFeaturesIMG",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRelaxedLineRasterizationFeaturesIMG::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SharedPresentSurfaceCapabilitiesKHR,
+        name: "VkSharedPresentSurfaceCapabilitiesKHR",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(SharedPresentSurfaceCapabilitiesKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::ImportFenceWin32HandleInfoKHR,
+        name: "VkImportFenceWin32HandleInfoKHR",
+        extends: &[],
+        create: || Box::new(ImportFenceWin32HandleInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::ExportFenceWin32HandleInfoKHR,
+        name: "VkExportFenceWin32HandleInfoKHR",
+        extends: &[StructureType::FenceCreateInfo],
+        create: || Box::new(ExportFenceWin32HandleInfoKHR::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::FenceGetWin32HandleInfoKHR,
+        name: "VkFenceGetWin32HandleInfoKHR",
+        extends: &[],
+        create: || Box::new(FenceGetWin32HandleInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImportFenceFdInfoKHR,
+        name: "VkImportFenceFdInfoKHR",
+        extends: &[],
+        create: || Box::new(ImportFenceFdInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FenceGetFdInfoKHR,
+        name: "VkFenceGetFdInfoKHR",
+        extends: &[],
+        create: || Box::new(FenceGetFdInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePerformanceQueryFeaturesKHR,
+        name: "VkPhysicalDevicePerformanceQueryFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePerformanceQueryFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePerformanceQueryPropertiesKHR,
+        name: "VkPhysicalDevicePerformanceQueryPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePerformanceQueryPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PerformanceCounterKHR,
+        name: "VkPerformanceCounterKHR",
+        extends: &[],
+        create: || Box::new(PerformanceCounterKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PerformanceCounterDescriptionKHR,
+        name: "VkPerformanceCounterDescriptionKHR",
+        extends: &[],
+        create: || Box::new(PerformanceCounterDescriptionKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueryPoolPerformanceCreateInfoKHR,
+        name: "VkQueryPoolPerformanceCreateInfoKHR",
+        extends: &[StructureType::QueryPoolCreateInfo],
+        create: || Box::new(QueryPoolPerformanceCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AcquireProfilingLockInfoKHR,
+        name: "VkAcquireProfilingLockInfoKHR",
+        extends: &[],
+        create: || Box::new(AcquireProfilingLockInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PerformanceQuerySubmitInfoKHR,
+        name: "VkPerformanceQuerySubmitInfoKHR",
+        extends: &[StructureType::SubmitInfo, StructureType::SubmitInfo2],
+        create: || Box::new(PerformanceQuerySubmitInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PerformanceQueryReservationInfoKHR,
+        name: "VkPerformanceQueryReservationInfoKHR",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(PerformanceQueryReservationInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSurfaceInfo2KHR,
+        name: "VkPhysicalDeviceSurfaceInfo2KHR",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceSurfaceInfo2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfaceCapabilities2KHR,
+        name: "VkSurfaceCapabilities2KHR",
+        extends: &[],
+        create: || Box::new(SurfaceCapabilities2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfaceFormat2KHR,
+        name: "VkSurfaceFormat2KHR",
+        extends: &[],
+        create: || Box::new(SurfaceFormat2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayProperties2KHR,
+        name: "VkDisplayProperties2KHR",
+        extends: &[],
+        create: || Box::new(DisplayProperties2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayPlaneProperties2KHR,
+        name: "VkDisplayPlaneProperties2KHR",
+        extends: &[],
+        create: || Box::new(DisplayPlaneProperties2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayModeProperties2KHR,
+        name: "VkDisplayModeProperties2KHR",
+        extends: &[],
+        create: || Box::new(DisplayModeProperties2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayPlaneInfo2KHR,
+        name: "VkDisplayPlaneInfo2KHR",
+        extends: &[],
+        create: || Box::new(DisplayPlaneInfo2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DisplayPlaneCapabilities2KHR,
+        name: "VkDisplayPlaneCapabilities2KHR",
+        extends: &[],
+        create: || Box::new(DisplayPlaneCapabilities2KHR::default()),
+    },
+    #[cfg(any(feature = "platform-ios", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::IosSurfaceCreateInfoMVK,
+        name: "VkIOSSurfaceCreateInfoMVK",
+        extends: &[],
+        create: || Box::new(IOSSurfaceCreateInfoMVK::default()),
+    },
+    #[cfg(any(feature = "platform-macos", target_os = "macos"))]
+    StructureInfo {
+        structure_type: StructureType::MacosSurfaceCreateInfoMVK,
+        name: "VkMacOSSurfaceCreateInfoMVK",
+        extends: &[],
+        create: || Box::new(MacOSSurfaceCreateInfoMVK::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugUtilsLabelEXT,
+        name: "VkDebugUtilsLabelEXT",
+        extends: &[],
+        create: || Box::new(DebugUtilsLabelEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugUtilsMessengerCallbackDataEXT,
+        name: "VkDebugUtilsMessengerCallbackDataEXT",
+        extends: &[],
+        create: || Box::new(DebugUtilsMessengerCallbackDataEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugUtilsMessengerCreateInfoEXT,
+        name: "VkDebugUtilsMessengerCreateInfoEXT",
+        extends: &[StructureType::InstanceCreateInfo],
+        create: || Box::new(DebugUtilsMessengerCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugUtilsObjectNameInfoEXT,
+        name: "VkDebugUtilsObjectNameInfoEXT",
+        extends: &[StructureType::PipelineShaderStageCreateInfo],
+        create: || Box::new(DebugUtilsObjectNameInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DebugUtilsObjectTagInfoEXT,
+        name: "VkDebugUtilsObjectTagInfoEXT",
+        extends: &[],
+        create: || Box::new(DebugUtilsObjectTagInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::AndroidHardwareBufferUsageANDROID,
+        name: "VkAndroidHardwareBufferUsageANDROID",
+        extends: &[StructureType::ImageFormatProperties2],
+        create: || Box::new(AndroidHardwareBufferUsageANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::AndroidHardwareBufferPropertiesANDROID,
+        name: "VkAndroidHardwareBufferPropertiesANDROID",
+        extends: &[],
+        create: || Box::new(AndroidHardwareBufferPropertiesANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::AndroidHardwareBufferFormatPropertiesANDROID,
+        name: "VkAndroidHardwareBufferFormatPropertiesANDROID",
+        extends: &[StructureType::AndroidHardwareBufferPropertiesANDROID],
+        create: || Box::new(AndroidHardwareBufferFormatPropertiesANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::ImportAndroidHardwareBufferInfoANDROID,
+        name: "VkImportAndroidHardwareBufferInfoANDROID",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportAndroidHardwareBufferInfoANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::MemoryGetAndroidHardwareBufferInfoANDROID,
+        name: "VkMemoryGetAndroidHardwareBufferInfoANDROID",
+        extends: &[],
+        create: || Box::new(MemoryGetAndroidHardwareBufferInfoANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::ExternalFormatANDROID,
+        name: "VkExternalFormatANDROID",
+        extends: &[
+            StructureType::ImageCreateInfo,
+            StructureType::SamplerYcbcrConversionCreateInfo,
+            StructureType::AttachmentDescription2,
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::CommandBufferInheritanceInfo,
+        ],
+        create: || Box::new(ExternalFormatANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::AndroidHardwareBufferFormatProperties2ANDROID,
+        name: "VkAndroidHardwareBufferFormatProperties2ANDROID",
+        extends: &[StructureType::AndroidHardwareBufferPropertiesANDROID],
+        create: || Box::new(AndroidHardwareBufferFormatProperties2ANDROID::default()),
+    },
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderEnqueueFeaturesAMDX,
+        name: "VkPhysicalDeviceShaderEnqueueFeaturesAMDX",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderEnqueueFeaturesAMDX::default()),
+    },
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderEnqueuePropertiesAMDX,
+        name: "VkPhysicalDeviceShaderEnqueuePropertiesAMDX",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderEnqueuePropertiesAMDX::default()),
+    },
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::ExecutionGraphPipelineScratchSizeAMDX,
+        name: "VkExecutionGraphPipelineScratchSizeAMDX",
+        extends: &[],
+        create: || Box::new(ExecutionGraphPipelineScratchSizeAMDX::default()),
+    },
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::ExecutionGraphPipelineCreateInfoAMDX,
+        name: "VkExecutionGraphPipelineCreateInfoAMDX",
+        extends: &[],
+        create: || Box::new(ExecutionGraphPipelineCreateInfoAMDX::default()),
+    },
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PipelineShaderStageNodeCreateInfoAMDX,
+        name: "VkPipelineShaderStageNodeCreateInfoAMDX",
+        extends: &[StructureType::PipelineShaderStageCreateInfo],
+        create: || Box::new(PipelineShaderStageNodeCreateInfoAMDX::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SampleLocationsInfoEXT,
+        name: "VkSampleLocationsInfoEXT",
+        extends: &[
+            StructureType::ImageMemoryBarrier,
+            StructureType::ImageMemoryBarrier2,
+        ],
+        create: || Box::new(SampleLocationsInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassSampleLocationsBeginInfoEXT,
+        name: "VkRenderPassSampleLocationsBeginInfoEXT",
+        extends: &[StructureType::RenderPassBeginInfo],
+        create: || Box::new(RenderPassSampleLocationsBeginInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineSampleLocationsStateCreateInfoEXT,
+        name: "VkPipelineSampleLocationsStateCreateInfoEXT",
+        extends: &[StructureType::PipelineMultisampleStateCreateInfo],
+        create: || Box::new(PipelineSampleLocationsStateCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSampleLocationsPropertiesEXT,
+        name: "VkPhysicalDeviceSampleLocationsPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceSampleLocationsPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MultisamplePropertiesEXT,
+        name: "VkMultisamplePropertiesEXT",
+        extends: &[],
+        create: || Box::new(MultisamplePropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceBlendOperationAdvancedFeaturesEXT,
+        name: "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceBlendOperationAdvancedFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceBlendOperationAdvancedPropertiesEXT,
+        name: "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceBlendOperationAdvancedPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineColorBlendAdvancedStateCreateInfoEXT,
+        name: "VkPipelineColorBlendAdvancedStateCreateInfoEXT",
+        extends: &[StructureType::PipelineColorBlendStateCreateInfo],
+        create: || Box::new(PipelineColorBlendAdvancedStateCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineCoverageToColorStateCreateInfoNV,
+        name: "VkPipelineCoverageToColorStateCreateInfoNV",
+        extends: &[StructureType::PipelineMultisampleStateCreateInfo],
+        create: || Box::new(PipelineCoverageToColorStateCreateInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureGeometryTrianglesDataKHR,
+        name: "VkAccelerationStructureGeometryTrianglesDataKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureGeometryTrianglesDataKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureBuildGeometryInfoKHR,
+        name: "VkAccelerationStructureBuildGeometryInfoKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureBuildGeometryInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureGeometryAabbsDataKHR,
+        name: "VkAccelerationStructureGeometryAabbsDataKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureGeometryAabbsDataKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureGeometryInstancesDataKHR,
+        name: "VkAccelerationStructureGeometryInstancesDataKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureGeometryInstancesDataKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureGeometryKHR,
+        name: "VkAccelerationStructureGeometryKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureGeometryKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureCreateInfoKHR,
+        name: "VkAccelerationStructureCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::WriteDescriptorSetAccelerationStructureKHR,
+        name: "VkWriteDescriptorSetAccelerationStructureKHR",
+        extends: &[StructureType::WriteDescriptorSet],
+        create: || Box::new(WriteDescriptorSetAccelerationStructureKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAccelerationStructureFeaturesKHR,
+        name: "VkPhysicalDeviceAccelerationStructureFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceAccelerationStructureFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAccelerationStructurePropertiesKHR,
+        name: "VkPhysicalDeviceAccelerationStructurePropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceAccelerationStructurePropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureDeviceAddressInfoKHR,
+        name: "VkAccelerationStructureDeviceAddressInfoKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureDeviceAddressInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureVersionInfoKHR,
+        name: "VkAccelerationStructureVersionInfoKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureVersionInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyAccelerationStructureToMemoryInfoKHR,
+        name: "VkCopyAccelerationStructureToMemoryInfoKHR",
+        extends: &[],
+        create: || Box::new(CopyAccelerationStructureToMemoryInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyMemoryToAccelerationStructureInfoKHR,
+        name: "VkCopyMemoryToAccelerationStructureInfoKHR",
+        extends: &[],
+        create: || Box::new(CopyMemoryToAccelerationStructureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyAccelerationStructureInfoKHR,
+        name: "VkCopyAccelerationStructureInfoKHR",
+        extends: &[],
+        create: || Box::new(CopyAccelerationStructureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureBuildSizesInfoKHR,
+        name: "VkAccelerationStructureBuildSizesInfoKHR",
+        extends: &[],
+        create: || Box::new(AccelerationStructureBuildSizesInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RayTracingShaderGroupCreateInfoKHR,
+        name: "VkRayTracingShaderGroupCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(RayTracingShaderGroupCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RayTracingPipelineCreateInfoKHR,
+        name: "VkRayTracingPipelineCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(RayTracingPipelineCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingPipelineFeaturesKHR,
+        name: "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayTracingPipelineFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingPipelinePropertiesKHR,
+        name: "VkPhysicalDeviceRayTracingPipelinePropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceRayTracingPipelinePropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RayTracingPipelineInterfaceCreateInfoKHR,
+        name: "VkRayTracingPipelineInterfaceCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(RayTracingPipelineInterfaceCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayQueryFeaturesKHR,
+        name: "VkPhysicalDeviceRayQueryFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayQueryFeaturesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineCoverageModulationStateCreateInfoNV,
+        name: "VkPipelineCoverageModulationStateCreateInfoNV",
+        extends: &[StructureType::PipelineMultisampleStateCreateInfo],
+        create: || Box::new(PipelineCoverageModulationStateCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderSmBuiltinsPropertiesNV,
+        name: "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderSMBuiltinsPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderSmBuiltinsFeaturesNV,
+        name: "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderSMBuiltinsFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DrmFormatModifierPropertiesListEXT,
+        name: "VkDrmFormatModifierPropertiesListEXT",
+        extends: &[StructureType::FormatProperties2],
+        create: || Box::new(DrmFormatModifierPropertiesListEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageDrmFormatModifierInfoEXT,
+        name: "VkPhysicalDeviceImageDrmFormatModifierInfoEXT",
+        extends: &[StructureType::PhysicalDeviceImageFormatInfo2],
+        create: || Box::new(PhysicalDeviceImageDrmFormatModifierInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageDrmFormatModifierListCreateInfoEXT,
+        name: "VkImageDrmFormatModifierListCreateInfoEXT",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ImageDrmFormatModifierListCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageDrmFormatModifierExplicitCreateInfoEXT,
+        name: "VkImageDrmFormatModifierExplicitCreateInfoEXT",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ImageDrmFormatModifierExplicitCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageDrmFormatModifierPropertiesEXT,
+        name: "VkImageDrmFormatModifierPropertiesEXT",
+        extends: &[],
+        create: || Box::new(ImageDrmFormatModifierPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DrmFormatModifierPropertiesList2EXT,
+        name: "VkDrmFormatModifierPropertiesList2EXT",
+        extends: &[StructureType::FormatProperties2],
+        create: || Box::new(DrmFormatModifierPropertiesList2EXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ValidationCacheCreateInfoEXT,
+        name: "VkValidationCacheCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(ValidationCacheCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ShaderModuleValidationCacheCreateInfoEXT,
+        name: "VkShaderModuleValidationCacheCreateInfoEXT",
+        extends: &[
+            StructureType::ShaderModuleCreateInfo,
+            StructureType::PipelineShaderStageCreateInfo,
+        ],
+        create: || Box::new(ShaderModuleValidationCacheCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "provisional")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePortabilitySubsetFeaturesKHR,
+        name: "VkPhysicalDevicePortabilitySubsetFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePortabilitySubsetFeaturesKHR::default()),
+    },
+    #[cfg(feature = "provisional")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePortabilitySubsetPropertiesKHR,
+        name: "VkPhysicalDevicePortabilitySubsetPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePortabilitySubsetPropertiesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportShadingRateImageStateCreateInfoNV,
+        name: "VkPipelineViewportShadingRateImageStateCreateInfoNV",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportShadingRateImageStateCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShadingRateImageFeaturesNV,
+        name: "VkPhysicalDeviceShadingRateImageFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShadingRateImageFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShadingRateImagePropertiesNV,
+        name: "VkPhysicalDeviceShadingRateImagePropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShadingRateImagePropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportCoarseSampleOrderStateCreateInfoNV,
+        name: "VkPipelineViewportCoarseSampleOrderStateCreateInfoNV",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportCoarseSampleOrderStateCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::RayTracingShaderGroupCreateInfoNV,
+        name: "VkRayTracingShaderGroupCreateInfoNV",
+        extends: &[],
+        create: || Box::new(RayTracingShaderGroupCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::RayTracingPipelineCreateInfoNV,
+        name: "VkRayTracingPipelineCreateInfoNV",
+        extends: &[],
+        create: || Box::new(RayTracingPipelineCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GeometryTrianglesNV,
+        name: "VkGeometryTrianglesNV",
+        extends: &[],
+        create: || Box::new(GeometryTrianglesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GeometryAabbNV,
+        name: "VkGeometryAABBNV",
+        extends: &[],
+        create: || Box::new(GeometryAABBNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GeometryNV,
+        name: "VkGeometryNV",
+        extends: &[],
+        create: || Box::new(GeometryNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureInfoNV,
+        name: "VkAccelerationStructureInfoNV",
+        extends: &[],
+        create: || Box::new(AccelerationStructureInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureCreateInfoNV,
+        name: "VkAccelerationStructureCreateInfoNV",
+        extends: &[],
+        create: || Box::new(AccelerationStructureCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::BindAccelerationStructureMemoryInfoNV,
+        name: "VkBindAccelerationStructureMemoryInfoNV",
+        extends: &[],
+        create: || Box::new(BindAccelerationStructureMemoryInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::WriteDescriptorSetAccelerationStructureNV,
+        name: "VkWriteDescriptorSetAccelerationStructureNV",
+        extends: &[StructureType::WriteDescriptorSet],
+        create: || Box::new(WriteDescriptorSetAccelerationStructureNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureMemoryRequirementsInfoNV,
+        name: "VkAccelerationStructureMemoryRequirementsInfoNV",
+        extends: &[],
+        create: || Box::new(AccelerationStructureMemoryRequirementsInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingPropertiesNV,
+        name: "VkPhysicalDeviceRayTracingPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceRayTracingPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRepresentativeFragmentTestFeaturesNV,
+        name: "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRepresentativeFragmentTestFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineRepresentativeFragmentTestStateCreateInfoNV,
+        name: "VkPipelineRepresentativeFragmentTestStateCreateInfoNV",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(PipelineRepresentativeFragmentTestStateCreateInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageViewImageFormatInfoEXT,
+        name: "VkPhysicalDeviceImageViewImageFormatInfoEXT",
+        extends: &[StructureType::PhysicalDeviceImageFormatInfo2],
+        create: || Box::new(PhysicalDeviceImageViewImageFormatInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FilterCubicImageViewImageFormatPropertiesEXT,
+        name: "VkFilterCubicImageViewImageFormatPropertiesEXT",
+        extends: &[StructureType::ImageFormatProperties2],
+        create: || Box::new(FilterCubicImageViewImageFormatPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImportMemoryHostPointerInfoEXT,
+        name: "VkImportMemoryHostPointerInfoEXT",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMemoryHostPointerInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryHostPointerPropertiesEXT,
+        name: "VkMemoryHostPointerPropertiesEXT",
+        extends: &[],
+        create: || Box::new(MemoryHostPointerPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalMemoryHostPropertiesEXT,
+        name: "VkPhysicalDeviceExternalMemoryHostPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceExternalMemoryHostPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderClockFeaturesKHR,
+        name: "VkPhysicalDeviceShaderClockFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderClockFeaturesKHR::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PipelineCompilerControlCreateInfoAMD,
+        name: "VkPipelineCompilerControlCreateInfoAMD",
+        extends: &[
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::ComputePipelineCreateInfo,
+            #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+            StructureType::ExecutionGraphPipelineCreateInfoAMDX,
+        ],
+        create: || Box::new(PipelineCompilerControlCreateInfoAMD::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderCorePropertiesAMD,
+        name: "VkPhysicalDeviceShaderCorePropertiesAMD",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderCorePropertiesAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH265ProfileInfoKHR,
+        name: "VkVideoDecodeH265ProfileInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoDecodeH265ProfileInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH265CapabilitiesKHR,
+        name: "VkVideoDecodeH265CapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoDecodeH265CapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH265SessionParametersCreateInfoKHR,
+        name: "VkVideoDecodeH265SessionParametersCreateInfoKHR",
+        extends: &[StructureType::VideoSessionParametersCreateInfoKHR],
+        create: || Box::new(VideoDecodeH265SessionParametersCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH265SessionParametersAddInfoKHR,
+        name: "VkVideoDecodeH265SessionParametersAddInfoKHR",
+        extends: &[StructureType::VideoSessionParametersUpdateInfoKHR],
+        create: || Box::new(VideoDecodeH265SessionParametersAddInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH265PictureInfoKHR,
+        name: "VkVideoDecodeH265PictureInfoKHR",
+        extends: &[StructureType::VideoDecodeInfoKHR],
+        create: || Box::new(VideoDecodeH265PictureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeH265DpbSlotInfoKHR,
+        name: "VkVideoDecodeH265DpbSlotInfoKHR",
+        extends: &[StructureType::VideoReferenceSlotInfoKHR],
+        create: || Box::new(VideoDecodeH265DpbSlotInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceQueueGlobalPriorityCreateInfoKHR,
+        name: "VkDeviceQueueGlobalPriorityCreateInfoKHR",
+        extends: &[StructureType::DeviceQueueCreateInfo],
+        create: || Box::new(DeviceQueueGlobalPriorityCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceGlobalPriorityQueryFeaturesKHR,
+        name: "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceGlobalPriorityQueryFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueueFamilyGlobalPriorityPropertiesKHR,
+        name: "VkQueueFamilyGlobalPriorityPropertiesKHR",
+        extends: &[StructureType::QueueFamilyProperties2],
+        create: || Box::new(QueueFamilyGlobalPriorityPropertiesKHR::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::DeviceMemoryOverallocationCreateInfoAMD,
+        name: "VkDeviceMemoryOverallocationCreateInfoAMD",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(DeviceMemoryOverallocationCreateInfoAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVertexAttributeDivisorPropertiesEXT,
+        name: "VkPhysicalDeviceVertexAttributeDivisorPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceVertexAttributeDivisorPropertiesEXT::default()),
+    },
+    #[cfg(feature = "platform-ggp")]
+    StructureInfo {
+        structure_type: StructureType::PresentFrameTokenGGP,
+        name: "VkPresentFrameTokenGGP",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(PresentFrameTokenGGP::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMeshShaderFeaturesNV,
+        name: "VkPhysicalDeviceMeshShaderFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMeshShaderFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMeshShaderPropertiesNV,
+        name: "VkPhysicalDeviceMeshShaderPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMeshShaderPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderImageFootprintFeaturesNV,
+        name: "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderImageFootprintFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportExclusiveScissorStateCreateInfoNV,
+        name: "VkPipelineViewportExclusiveScissorStateCreateInfoNV",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportExclusiveScissorStateCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExclusiveScissorFeaturesNV,
+        name: "VkPhysicalDeviceExclusiveScissorFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExclusiveScissorFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::QueueFamilyCheckpointPropertiesNV,
+        name: "VkQueueFamilyCheckpointPropertiesNV",
+        extends: &[StructureType::QueueFamilyProperties2],
+        create: || Box::new(QueueFamilyCheckpointPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::CheckpointDataNV,
+        name: "VkCheckpointDataNV",
+        extends: &[],
+        create: || Box::new(CheckpointDataNV::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL,
+        name: "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::InitializePerformanceApiInfoINTEL,
+        name: "VkInitializePerformanceApiInfoINTEL",
+        extends: &[],
+        create: || Box::new(InitializePerformanceApiInfoINTEL::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::QueryPoolPerformanceQueryCreateInfoINTEL,
+        name: "VkQueryPoolPerformanceQueryCreateInfoINTEL",
+        extends: &[StructureType::QueryPoolCreateInfo],
+        create: || Box::new(QueryPoolPerformanceQueryCreateInfoINTEL::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::PerformanceMarkerInfoINTEL,
+        name: "VkPerformanceMarkerInfoINTEL",
+        extends: &[],
+        create: || Box::new(PerformanceMarkerInfoINTEL::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::PerformanceStreamMarkerInfoINTEL,
+        name: "VkPerformanceStreamMarkerInfoINTEL",
+        extends: &[],
+        create: || Box::new(PerformanceStreamMarkerInfoINTEL::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::PerformanceOverrideInfoINTEL,
+        name: "VkPerformanceOverrideInfoINTEL",
+        extends: &[],
+        create: || Box::new(PerformanceOverrideInfoINTEL::default()),
+    },
+    #[cfg(feature = "vendor-intel")]
+    StructureInfo {
+        structure_type: StructureType::PerformanceConfigurationAcquireInfoINTEL,
+        name: "VkPerformanceConfigurationAcquireInfoINTEL",
+        extends: &[],
+        create: || Box::new(PerformanceConfigurationAcquireInfoINTEL::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePciBusInfoPropertiesEXT,
+        name: "VkPhysicalDevicePCIBusInfoPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePCIBusInfoPropertiesEXT::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::DisplayNativeHdrSurfaceCapabilitiesAMD,
+        name: "VkDisplayNativeHdrSurfaceCapabilitiesAMD",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(DisplayNativeHdrSurfaceCapabilitiesAMD::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::SwapchainDisplayNativeHdrCreateInfoAMD,
+        name: "VkSwapchainDisplayNativeHdrCreateInfoAMD",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(SwapchainDisplayNativeHdrCreateInfoAMD::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::ImagepipeSurfaceCreateInfoFUCHSIA,
+        name: "VkImagePipeSurfaceCreateInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(ImagePipeSurfaceCreateInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::MetalSurfaceCreateInfoEXT,
+        name: "VkMetalSurfaceCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(MetalSurfaceCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentDensityMapFeaturesEXT,
+        name: "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentDensityMapFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentDensityMapPropertiesEXT,
+        name: "VkPhysicalDeviceFragmentDensityMapPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFragmentDensityMapPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassFragmentDensityMapCreateInfoEXT,
+        name: "VkRenderPassFragmentDensityMapCreateInfoEXT",
+        extends: &[
+            StructureType::RenderPassCreateInfo,
+            StructureType::RenderPassCreateInfo2,
+        ],
+        create: || Box::new(RenderPassFragmentDensityMapCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FragmentShadingRateAttachmentInfoKHR,
+        name: "VkFragmentShadingRateAttachmentInfoKHR",
+        extends: &[StructureType::SubpassDescription2],
+        create: || Box::new(FragmentShadingRateAttachmentInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineFragmentShadingRateStateCreateInfoKHR,
+        name: "VkPipelineFragmentShadingRateStateCreateInfoKHR",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(PipelineFragmentShadingRateStateCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShadingRateFeaturesKHR,
+        name: "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentShadingRateFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShadingRatePropertiesKHR,
+        name: "VkPhysicalDeviceFragmentShadingRatePropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFragmentShadingRatePropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShadingRateKHR,
+        name: "VkPhysicalDeviceFragmentShadingRateKHR",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceFragmentShadingRateKHR::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderCoreProperties2AMD,
+        name: "VkPhysicalDeviceShaderCoreProperties2AMD",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderCoreProperties2AMD::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCoherentMemoryFeaturesAMD,
+        name: "VkPhysicalDeviceCoherentMemoryFeaturesAMD",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCoherentMemoryFeaturesAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDynamicRenderingLocalReadFeaturesKHR,
+        name: "VkPhysicalDeviceDynamicRenderingLocalReadFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDynamicRenderingLocalReadFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingAttachmentLocationInfoKHR,
+        name: "VkRenderingAttachmentLocationInfoKHR",
+        extends: &[
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::CommandBufferInheritanceInfo,
+        ],
+        create: || Box::new(RenderingAttachmentLocationInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingInputAttachmentIndexInfoKHR,
+        name: "VkRenderingInputAttachmentIndexInfoKHR",
+        extends: &[
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::CommandBufferInheritanceInfo,
+        ],
+        create: || Box::new(RenderingInputAttachmentIndexInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT,
+        name: "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderImageAtomicInt64FeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderQuadControlFeaturesKHR,
+        name: "VkPhysicalDeviceShaderQuadControlFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderQuadControlFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMemoryBudgetPropertiesEXT,
+        name: "VkPhysicalDeviceMemoryBudgetPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceMemoryProperties2],
+        create: || Box::new(PhysicalDeviceMemoryBudgetPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMemoryPriorityFeaturesEXT,
+        name: "VkPhysicalDeviceMemoryPriorityFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMemoryPriorityFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryPriorityAllocateInfoEXT,
+        name: "VkMemoryPriorityAllocateInfoEXT",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(MemoryPriorityAllocateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfaceProtectedCapabilitiesKHR,
+        name: "VkSurfaceProtectedCapabilitiesKHR",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(SurfaceProtectedCapabilitiesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV,
+        name: "VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceBufferDeviceAddressFeaturesEXT,
+        name: "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceBufferDeviceAddressFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferDeviceAddressCreateInfoEXT,
+        name: "VkBufferDeviceAddressCreateInfoEXT",
+        extends: &[StructureType::BufferCreateInfo],
+        create: || Box::new(BufferDeviceAddressCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ValidationFeaturesEXT,
+        name: "VkValidationFeaturesEXT",
+        extends: &[
+            StructureType::InstanceCreateInfo,
+            StructureType::ShaderModuleCreateInfo,
+            StructureType::ShaderCreateInfoEXT,
+        ],
+        create: || Box::new(ValidationFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePresentWaitFeaturesKHR,
+        name: "VkPhysicalDevicePresentWaitFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePresentWaitFeaturesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::CooperativeMatrixPropertiesNV,
+        name: "VkCooperativeMatrixPropertiesNV",
+        extends: &[],
+        create: || Box::new(CooperativeMatrixPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCooperativeMatrixFeaturesNV,
+        name: "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCooperativeMatrixFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCooperativeMatrixPropertiesNV,
+        name: "VkPhysicalDeviceCooperativeMatrixPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceCooperativeMatrixPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCoverageReductionModeFeaturesNV,
+        name: "VkPhysicalDeviceCoverageReductionModeFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCoverageReductionModeFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineCoverageReductionStateCreateInfoNV,
+        name: "VkPipelineCoverageReductionStateCreateInfoNV",
+        extends: &[StructureType::PipelineMultisampleStateCreateInfo],
+        create: || Box::new(PipelineCoverageReductionStateCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::FramebufferMixedSamplesCombinationNV,
+        name: "VkFramebufferMixedSamplesCombinationNV",
+        extends: &[],
+        create: || Box::new(FramebufferMixedSamplesCombinationNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+        name: "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentShaderInterlockFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceYcbcrImageArraysFeaturesEXT,
+        name: "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceYcbcrImageArraysFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceProvokingVertexFeaturesEXT,
+        name: "VkPhysicalDeviceProvokingVertexFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceProvokingVertexFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceProvokingVertexPropertiesEXT,
+        name: "VkPhysicalDeviceProvokingVertexPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceProvokingVertexPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationProvokingVertexStateCreateInfoEXT,
+        name: "VkPipelineRasterizationProvokingVertexStateCreateInfoEXT",
+        extends: &[StructureType::PipelineRasterizationStateCreateInfo],
+        create: || Box::new(PipelineRasterizationProvokingVertexStateCreateInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::SurfaceFullScreenExclusiveInfoEXT,
+        name: "VkSurfaceFullScreenExclusiveInfoEXT",
+        extends: &[
+            StructureType::PhysicalDeviceSurfaceInfo2KHR,
+            StructureType::SwapchainCreateInfoKHR,
+        ],
+        create: || Box::new(SurfaceFullScreenExclusiveInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::SurfaceCapabilitiesFullScreenExclusiveEXT,
+        name: "VkSurfaceCapabilitiesFullScreenExclusiveEXT",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(SurfaceCapabilitiesFullScreenExclusiveEXT::default()),
+    },
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    StructureInfo {
+        structure_type: StructureType::SurfaceFullScreenExclusiveWin32InfoEXT,
+        name: "VkSurfaceFullScreenExclusiveWin32InfoEXT",
+        extends: &[
+            StructureType::PhysicalDeviceSurfaceInfo2KHR,
+            StructureType::SwapchainCreateInfoKHR,
+        ],
+        create: || Box::new(SurfaceFullScreenExclusiveWin32InfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::HeadlessSurfaceCreateInfoEXT,
+        name: "VkHeadlessSurfaceCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(HeadlessSurfaceCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+        name: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderAtomicFloatFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExtendedDynamicStateFeaturesEXT,
+        name: "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExtendedDynamicStateFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineExecutablePropertiesFeaturesKHR,
+        name: "VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelineExecutablePropertiesFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineInfoKHR,
+        name: "VkPipelineInfoKHR",
+        extends: &[],
+        create: || Box::new(PipelineInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineExecutablePropertiesKHR,
+        name: "VkPipelineExecutablePropertiesKHR",
+        extends: &[],
+        create: || Box::new(PipelineExecutablePropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineExecutableInfoKHR,
+        name: "VkPipelineExecutableInfoKHR",
+        extends: &[],
+        create: || Box::new(PipelineExecutableInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineExecutableStatisticKHR,
+        name: "VkPipelineExecutableStatisticKHR",
+        extends: &[],
+        create: || Box::new(PipelineExecutableStatisticKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineExecutableInternalRepresentationKHR,
+        name: "VkPipelineExecutableInternalRepresentationKHR",
+        extends: &[],
+        create: || Box::new(PipelineExecutableInternalRepresentationKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceHostImageCopyFeaturesEXT,
+        name: "VkPhysicalDeviceHostImageCopyFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceHostImageCopyFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceHostImageCopyPropertiesEXT,
+        name: "VkPhysicalDeviceHostImageCopyPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceHostImageCopyPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryToImageCopyEXT,
+        name: "VkMemoryToImageCopyEXT",
+        extends: &[],
+        create: || Box::new(MemoryToImageCopyEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageToMemoryCopyEXT,
+        name: "VkImageToMemoryCopyEXT",
+        extends: &[],
+        create: || Box::new(ImageToMemoryCopyEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyMemoryToImageInfoEXT,
+        name: "VkCopyMemoryToImageInfoEXT",
+        extends: &[],
+        create: || Box::new(CopyMemoryToImageInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyImageToMemoryInfoEXT,
+        name: "VkCopyImageToMemoryInfoEXT",
+        extends: &[],
+        create: || Box::new(CopyImageToMemoryInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyImageToImageInfoEXT,
+        name: "VkCopyImageToImageInfoEXT",
+        extends: &[],
+        create: || Box::new(CopyImageToImageInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::HostImageLayoutTransitionInfoEXT,
+        name: "VkHostImageLayoutTransitionInfoEXT",
+        extends: &[],
+        create: || Box::new(HostImageLayoutTransitionInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubresourceHostMemcpySizeEXT,
+        name: "VkSubresourceHostMemcpySizeEXT",
+        extends: &[StructureType::SubresourceLayout2KHR],
+        create: || Box::new(SubresourceHostMemcpySizeEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::HostImageCopyDevicePerformanceQueryEXT,
+        name: "VkHostImageCopyDevicePerformanceQueryEXT",
+        extends: &[StructureType::ImageFormatProperties2],
+        create: || Box::new(HostImageCopyDevicePerformanceQueryEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryMapInfoKHR,
+        name: "VkMemoryMapInfoKHR",
+        extends: &[],
+        create: || Box::new(MemoryMapInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryUnmapInfoKHR,
+        name: "VkMemoryUnmapInfoKHR",
+        extends: &[],
+        create: || Box::new(MemoryUnmapInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMapMemoryPlacedFeaturesEXT,
+        name: "VkPhysicalDeviceMapMemoryPlacedFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMapMemoryPlacedFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMapMemoryPlacedPropertiesEXT,
+        name: "VkPhysicalDeviceMapMemoryPlacedPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMapMemoryPlacedPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MemoryMapPlacedInfoEXT,
+        name: "VkMemoryMapPlacedInfoEXT",
+        extends: &[StructureType::MemoryMapInfoKHR],
+        create: || Box::new(MemoryMapPlacedInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderAtomicFloat2FeaturesEXT,
+        name: "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderAtomicFloat2FeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfacePresentModeEXT,
+        name: "VkSurfacePresentModeEXT",
+        extends: &[StructureType::PhysicalDeviceSurfaceInfo2KHR],
+        create: || Box::new(SurfacePresentModeEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfacePresentScalingCapabilitiesEXT,
+        name: "VkSurfacePresentScalingCapabilitiesEXT",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(SurfacePresentScalingCapabilitiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SurfacePresentModeCompatibilityEXT,
+        name: "VkSurfacePresentModeCompatibilityEXT",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(SurfacePresentModeCompatibilityEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSwapchainMaintenance1FeaturesEXT,
+        name: "VkPhysicalDeviceSwapchainMaintenance1FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSwapchainMaintenance1FeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SwapchainPresentFenceInfoEXT,
+        name: "VkSwapchainPresentFenceInfoEXT",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(SwapchainPresentFenceInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SwapchainPresentModesCreateInfoEXT,
+        name: "VkSwapchainPresentModesCreateInfoEXT",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(SwapchainPresentModesCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SwapchainPresentModeInfoEXT,
+        name: "VkSwapchainPresentModeInfoEXT",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(SwapchainPresentModeInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SwapchainPresentScalingCreateInfoEXT,
+        name: "VkSwapchainPresentScalingCreateInfoEXT",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(SwapchainPresentScalingCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ReleaseSwapchainImagesInfoEXT,
+        name: "VkReleaseSwapchainImagesInfoEXT",
+        extends: &[],
+        create: || Box::new(ReleaseSwapchainImagesInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV,
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDeviceGeneratedCommandsPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV,
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDeviceGeneratedCommandsFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GraphicsShaderGroupCreateInfoNV,
+        name: "VkGraphicsShaderGroupCreateInfoNV",
+        extends: &[],
+        create: || Box::new(GraphicsShaderGroupCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GraphicsPipelineShaderGroupsCreateInfoNV,
+        name: "VkGraphicsPipelineShaderGroupsCreateInfoNV",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(GraphicsPipelineShaderGroupsCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::IndirectCommandsLayoutTokenNV,
+        name: "VkIndirectCommandsLayoutTokenNV",
+        extends: &[],
+        create: || Box::new(IndirectCommandsLayoutTokenNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::IndirectCommandsLayoutCreateInfoNV,
+        name: "VkIndirectCommandsLayoutCreateInfoNV",
+        extends: &[],
+        create: || Box::new(IndirectCommandsLayoutCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GeneratedCommandsInfoNV,
+        name: "VkGeneratedCommandsInfoNV",
+        extends: &[],
+        create: || Box::new(GeneratedCommandsInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GeneratedCommandsMemoryRequirementsInfoNV,
+        name: "VkGeneratedCommandsMemoryRequirementsInfoNV",
+        extends: &[],
+        create: || Box::new(GeneratedCommandsMemoryRequirementsInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceInheritedViewportScissorFeaturesNV,
+        name: "VkPhysicalDeviceInheritedViewportScissorFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceInheritedViewportScissorFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::CommandBufferInheritanceViewportScissorInfoNV,
+        name: "VkCommandBufferInheritanceViewportScissorInfoNV",
+        extends: &[StructureType::CommandBufferInheritanceInfo],
+        create: || Box::new(CommandBufferInheritanceViewportScissorInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTexelBufferAlignmentFeaturesEXT,
+        name: "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceTexelBufferAlignmentFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::RenderPassTransformBeginInfoQCOM,
+        name: "VkRenderPassTransformBeginInfoQCOM",
+        extends: &[StructureType::RenderPassBeginInfo],
+        create: || Box::new(RenderPassTransformBeginInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::CommandBufferInheritanceRenderPassTransformInfoQCOM,
+        name: "VkCommandBufferInheritanceRenderPassTransformInfoQCOM",
+        extends: &[StructureType::CommandBufferInheritanceInfo],
+        create: || Box::new(CommandBufferInheritanceRenderPassTransformInfoQCOM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDepthBiasControlFeaturesEXT,
+        name: "VkPhysicalDeviceDepthBiasControlFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDepthBiasControlFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DepthBiasInfoEXT,
+        name: "VkDepthBiasInfoEXT",
+        extends: &[],
+        create: || Box::new(DepthBiasInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DepthBiasRepresentationInfoEXT,
+        name: "VkDepthBiasRepresentationInfoEXT",
+        extends: &[
+            StructureType::DepthBiasInfoEXT,
+            StructureType::PipelineRasterizationStateCreateInfo,
+        ],
+        create: || Box::new(DepthBiasRepresentationInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDeviceMemoryReportFeaturesEXT,
+        name: "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDeviceMemoryReportFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceDeviceMemoryReportCreateInfoEXT,
+        name: "VkDeviceDeviceMemoryReportCreateInfoEXT",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(DeviceDeviceMemoryReportCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceMemoryReportCallbackDataEXT,
+        name: "VkDeviceMemoryReportCallbackDataEXT",
+        extends: &[],
+        create: || Box::new(DeviceMemoryReportCallbackDataEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRobustness2FeaturesEXT,
+        name: "VkPhysicalDeviceRobustness2FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRobustness2FeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRobustness2PropertiesEXT,
+        name: "VkPhysicalDeviceRobustness2PropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceRobustness2PropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerCustomBorderColorCreateInfoEXT,
+        name: "VkSamplerCustomBorderColorCreateInfoEXT",
+        extends: &[StructureType::SamplerCreateInfo],
+        create: || Box::new(SamplerCustomBorderColorCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCustomBorderColorPropertiesEXT,
+        name: "VkPhysicalDeviceCustomBorderColorPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceCustomBorderColorPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCustomBorderColorFeaturesEXT,
+        name: "VkPhysicalDeviceCustomBorderColorFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCustomBorderColorFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineLibraryCreateInfoKHR,
+        name: "VkPipelineLibraryCreateInfoKHR",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(PipelineLibraryCreateInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePresentBarrierFeaturesNV,
+        name: "VkPhysicalDevicePresentBarrierFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePresentBarrierFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::SurfaceCapabilitiesPresentBarrierNV,
+        name: "VkSurfaceCapabilitiesPresentBarrierNV",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(SurfaceCapabilitiesPresentBarrierNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::SwapchainPresentBarrierCreateInfoNV,
+        name: "VkSwapchainPresentBarrierCreateInfoNV",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(SwapchainPresentBarrierCreateInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PresentIdKHR,
+        name: "VkPresentIdKHR",
+        extends: &[StructureType::PresentInfoKHR],
+        create: || Box::new(PresentIdKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePresentIdFeaturesKHR,
+        name: "VkPhysicalDevicePresentIdFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePresentIdFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeInfoKHR,
+        name: "VkVideoEncodeInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeCapabilitiesKHR,
+        name: "VkVideoEncodeCapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoEncodeCapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::QueryPoolVideoEncodeFeedbackCreateInfoKHR,
+        name: "VkQueryPoolVideoEncodeFeedbackCreateInfoKHR",
+        extends: &[StructureType::QueryPoolCreateInfo],
+        create: || Box::new(QueryPoolVideoEncodeFeedbackCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeUsageInfoKHR,
+        name: "VkVideoEncodeUsageInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoEncodeUsageInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeRateControlInfoKHR,
+        name: "VkVideoEncodeRateControlInfoKHR",
+        extends: &[
+            StructureType::VideoCodingControlInfoKHR,
+            StructureType::VideoBeginCodingInfoKHR,
+        ],
+        create: || Box::new(VideoEncodeRateControlInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeRateControlLayerInfoKHR,
+        name: "VkVideoEncodeRateControlLayerInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeRateControlLayerInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+        name: "VkPhysicalDeviceVideoEncodeQualityLevelInfoKHR",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceVideoEncodeQualityLevelInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeQualityLevelPropertiesKHR,
+        name: "VkVideoEncodeQualityLevelPropertiesKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeQualityLevelPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeQualityLevelInfoKHR,
+        name: "VkVideoEncodeQualityLevelInfoKHR",
+        extends: &[
+            StructureType::VideoCodingControlInfoKHR,
+            StructureType::VideoSessionParametersCreateInfoKHR,
+        ],
+        create: || Box::new(VideoEncodeQualityLevelInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeSessionParametersGetInfoKHR,
+        name: "VkVideoEncodeSessionParametersGetInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeSessionParametersGetInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoEncodeSessionParametersFeedbackInfoKHR,
+        name: "VkVideoEncodeSessionParametersFeedbackInfoKHR",
+        extends: &[],
+        create: || Box::new(VideoEncodeSessionParametersFeedbackInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDiagnosticsConfigFeaturesNV,
+        name: "VkPhysicalDeviceDiagnosticsConfigFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDiagnosticsConfigFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::DeviceDiagnosticsConfigCreateInfoNV,
+        name: "VkDeviceDiagnosticsConfigCreateInfoNV",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(DeviceDiagnosticsConfigCreateInfoNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::CudaModuleCreateInfoNV,
+        name: "VkCudaModuleCreateInfoNV",
+        extends: &[],
+        create: || Box::new(CudaModuleCreateInfoNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::CudaFunctionCreateInfoNV,
+        name: "VkCudaFunctionCreateInfoNV",
+        extends: &[],
+        create: || Box::new(CudaFunctionCreateInfoNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::CudaLaunchInfoNV,
+        name: "VkCudaLaunchInfoNV",
+        extends: &[],
+        create: || Box::new(CudaLaunchInfoNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCudaKernelLaunchFeaturesNV,
+        name: "VkPhysicalDeviceCudaKernelLaunchFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCudaKernelLaunchFeaturesNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCudaKernelLaunchPropertiesNV,
+        name: "VkPhysicalDeviceCudaKernelLaunchPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceCudaKernelLaunchPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::QueryLowLatencySupportNV,
+        name: "VkQueryLowLatencySupportNV",
+        extends: &[StructureType::SemaphoreCreateInfo],
+        create: || Box::new(QueryLowLatencySupportNV::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalObjectCreateInfoEXT,
+        name: "VkExportMetalObjectCreateInfoEXT",
+        extends: &[
+            StructureType::InstanceCreateInfo,
+            StructureType::MemoryAllocateInfo,
+            StructureType::ImageCreateInfo,
+            StructureType::ImageViewCreateInfo,
+            StructureType::BufferViewCreateInfo,
+            StructureType::SemaphoreCreateInfo,
+            StructureType::EventCreateInfo,
+        ],
+        create: || Box::new(ExportMetalObjectCreateInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalObjectsInfoEXT,
+        name: "VkExportMetalObjectsInfoEXT",
+        extends: &[],
+        create: || Box::new(ExportMetalObjectsInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalDeviceInfoEXT,
+        name: "VkExportMetalDeviceInfoEXT",
+        extends: &[StructureType::ExportMetalObjectsInfoEXT],
+        create: || Box::new(ExportMetalDeviceInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalCommandQueueInfoEXT,
+        name: "VkExportMetalCommandQueueInfoEXT",
+        extends: &[StructureType::ExportMetalObjectsInfoEXT],
+        create: || Box::new(ExportMetalCommandQueueInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalBufferInfoEXT,
+        name: "VkExportMetalBufferInfoEXT",
+        extends: &[StructureType::ExportMetalObjectsInfoEXT],
+        create: || Box::new(ExportMetalBufferInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMetalBufferInfoEXT,
+        name: "VkImportMetalBufferInfoEXT",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMetalBufferInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalTextureInfoEXT,
+        name: "VkExportMetalTextureInfoEXT",
+        extends: &[StructureType::ExportMetalObjectsInfoEXT],
+        create: || Box::new(ExportMetalTextureInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMetalTextureInfoEXT,
+        name: "VkImportMetalTextureInfoEXT",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ImportMetalTextureInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalIoSurfaceInfoEXT,
+        name: "VkExportMetalIOSurfaceInfoEXT",
+        extends: &[StructureType::ExportMetalObjectsInfoEXT],
+        create: || Box::new(ExportMetalIOSurfaceInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMetalIoSurfaceInfoEXT,
+        name: "VkImportMetalIOSurfaceInfoEXT",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ImportMetalIOSurfaceInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ExportMetalSharedEventInfoEXT,
+        name: "VkExportMetalSharedEventInfoEXT",
+        extends: &[StructureType::ExportMetalObjectsInfoEXT],
+        create: || Box::new(ExportMetalSharedEventInfoEXT::default()),
+    },
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMetalSharedEventInfoEXT,
+        name: "VkImportMetalSharedEventInfoEXT",
+        extends: &[
+            StructureType::SemaphoreCreateInfo,
+            StructureType::EventCreateInfo,
+        ],
+        create: || Box::new(ImportMetalSharedEventInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::QueueFamilyCheckpointProperties2NV,
+        name: "VkQueueFamilyCheckpointProperties2NV",
+        extends: &[StructureType::QueueFamilyProperties2],
+        create: || Box::new(QueueFamilyCheckpointProperties2NV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::CheckpointData2NV,
+        name: "VkCheckpointData2NV",
+        extends: &[],
+        create: || Box::new(CheckpointData2NV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorBufferPropertiesEXT,
+        name: "VkPhysicalDeviceDescriptorBufferPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDescriptorBufferPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT,
+        name: "VkPhysicalDeviceDescriptorBufferDensityMapPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorBufferFeaturesEXT,
+        name: "VkPhysicalDeviceDescriptorBufferFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDescriptorBufferFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorAddressInfoEXT,
+        name: "VkDescriptorAddressInfoEXT",
+        extends: &[],
+        create: || Box::new(DescriptorAddressInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorBufferBindingInfoEXT,
+        name: "VkDescriptorBufferBindingInfoEXT",
+        extends: &[],
+        create: || Box::new(DescriptorBufferBindingInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorBufferBindingPushDescriptorBufferHandleEXT,
+        name: "VkDescriptorBufferBindingPushDescriptorBufferHandleEXT",
+        extends: &[StructureType::DescriptorBufferBindingInfoEXT],
+        create: || Box::new(DescriptorBufferBindingPushDescriptorBufferHandleEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DescriptorGetInfoEXT,
+        name: "VkDescriptorGetInfoEXT",
+        extends: &[],
+        create: || Box::new(DescriptorGetInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferCaptureDescriptorDataInfoEXT,
+        name: "VkBufferCaptureDescriptorDataInfoEXT",
+        extends: &[],
+        create: || Box::new(BufferCaptureDescriptorDataInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageCaptureDescriptorDataInfoEXT,
+        name: "VkImageCaptureDescriptorDataInfoEXT",
+        extends: &[],
+        create: || Box::new(ImageCaptureDescriptorDataInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageViewCaptureDescriptorDataInfoEXT,
+        name: "VkImageViewCaptureDescriptorDataInfoEXT",
+        extends: &[],
+        create: || Box::new(ImageViewCaptureDescriptorDataInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerCaptureDescriptorDataInfoEXT,
+        name: "VkSamplerCaptureDescriptorDataInfoEXT",
+        extends: &[],
+        create: || Box::new(SamplerCaptureDescriptorDataInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::OpaqueCaptureDescriptorDataCreateInfoEXT,
+        name: "VkOpaqueCaptureDescriptorDataCreateInfoEXT",
+        extends: &[
+            StructureType::BufferCreateInfo,
+            StructureType::ImageCreateInfo,
+            StructureType::ImageViewCreateInfo,
+            StructureType::SamplerCreateInfo,
+            StructureType::AccelerationStructureCreateInfoKHR,
+            #[cfg(feature = "vendor-nv")]
+            StructureType::AccelerationStructureCreateInfoNV,
+        ],
+        create: || Box::new(OpaqueCaptureDescriptorDataCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureCaptureDescriptorDataInfoEXT,
+        name: "VkAccelerationStructureCaptureDescriptorDataInfoEXT",
+        extends: &[],
+        create: || Box::new(AccelerationStructureCaptureDescriptorDataInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT,
+        name: "VkPhysicalDeviceGraphicsPipelineLibraryFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT,
+        name: "VkPhysicalDeviceGraphicsPipelineLibraryPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::GraphicsPipelineLibraryCreateInfoEXT,
+        name: "VkGraphicsPipelineLibraryCreateInfoEXT",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(GraphicsPipelineLibraryCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD,
+        name: "VkPhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShaderBarycentricFeaturesKHR,
+        name: "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentShaderBarycentricFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR,
+        name: "VkPhysicalDeviceFragmentShaderBarycentricPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFragmentShaderBarycentricPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR,
+        name: "VkPhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV,
+        name: "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentShadingRateEnumsFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV,
+        name: "VkPhysicalDeviceFragmentShadingRateEnumsPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFragmentShadingRateEnumsPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineFragmentShadingRateEnumStateCreateInfoNV,
+        name: "VkPipelineFragmentShadingRateEnumStateCreateInfoNV",
+        extends: &[StructureType::GraphicsPipelineCreateInfo],
+        create: || Box::new(PipelineFragmentShadingRateEnumStateCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureGeometryMotionTrianglesDataNV,
+        name: "VkAccelerationStructureGeometryMotionTrianglesDataNV",
+        extends: &[StructureType::AccelerationStructureGeometryTrianglesDataKHR],
+        create: || Box::new(AccelerationStructureGeometryMotionTrianglesDataNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureMotionInfoNV,
+        name: "VkAccelerationStructureMotionInfoNV",
+        extends: &[StructureType::AccelerationStructureCreateInfoKHR],
+        create: || Box::new(AccelerationStructureMotionInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingMotionBlurFeaturesNV,
+        name: "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayTracingMotionBlurFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMeshShaderFeaturesEXT,
+        name: "VkPhysicalDeviceMeshShaderFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMeshShaderFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMeshShaderPropertiesEXT,
+        name: "VkPhysicalDeviceMeshShaderPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMeshShaderPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT,
+        name: "VkPhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentDensityMap2FeaturesEXT,
+        name: "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentDensityMap2FeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentDensityMap2PropertiesEXT,
+        name: "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFragmentDensityMap2PropertiesEXT::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::CopyCommandTransformInfoQCOM,
+        name: "VkCopyCommandTransformInfoQCOM",
+        extends: &[StructureType::BufferImageCopy2, StructureType::ImageBlit2],
+        create: || Box::new(CopyCommandTransformInfoQCOM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR,
+        name: "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageCompressionControlFeaturesEXT,
+        name: "VkPhysicalDeviceImageCompressionControlFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageCompressionControlFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageCompressionControlEXT,
+        name: "VkImageCompressionControlEXT",
+        extends: &[
+            StructureType::ImageCreateInfo,
+            StructureType::SwapchainCreateInfoKHR,
+            StructureType::PhysicalDeviceImageFormatInfo2,
+        ],
+        create: || Box::new(ImageCompressionControlEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageCompressionPropertiesEXT,
+        name: "VkImageCompressionPropertiesEXT",
+        extends: &[
+            StructureType::ImageFormatProperties2,
+            StructureType::SurfaceFormat2KHR,
+            StructureType::SubresourceLayout2KHR,
+        ],
+        create: || Box::new(ImageCompressionPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT,
+        name: "VkPhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevice4444FormatsFeaturesEXT,
+        name: "VkPhysicalDevice4444FormatsFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevice4444FormatsFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFaultFeaturesEXT,
+        name: "VkPhysicalDeviceFaultFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFaultFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceFaultCountsEXT,
+        name: "VkDeviceFaultCountsEXT",
+        extends: &[],
+        create: || Box::new(DeviceFaultCountsEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceFaultInfoEXT,
+        name: "VkDeviceFaultInfoEXT",
+        extends: &[],
+        create: || Box::new(DeviceFaultInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRgba10X6FormatsFeaturesEXT,
+        name: "VkPhysicalDeviceRGBA10X6FormatsFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRGBA10X6FormatsFeaturesEXT::default()),
+    },
+    #[cfg(feature = "platform-directfb")]
+    StructureInfo {
+        structure_type: StructureType::DirectfbSurfaceCreateInfoEXT,
+        name: "VkDirectFBSurfaceCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(DirectFBSurfaceCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVertexInputDynamicStateFeaturesEXT,
+        name: "VkPhysicalDeviceVertexInputDynamicStateFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVertexInputDynamicStateFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VertexInputBindingDescription2EXT,
+        name: "VkVertexInputBindingDescription2EXT",
+        extends: &[],
+        create: || Box::new(VertexInputBindingDescription2EXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VertexInputAttributeDescription2EXT,
+        name: "VkVertexInputAttributeDescription2EXT",
+        extends: &[],
+        create: || Box::new(VertexInputAttributeDescription2EXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDrmPropertiesEXT,
+        name: "VkPhysicalDeviceDrmPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDrmPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAddressBindingReportFeaturesEXT,
+        name: "VkPhysicalDeviceAddressBindingReportFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceAddressBindingReportFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceAddressBindingCallbackDataEXT,
+        name: "VkDeviceAddressBindingCallbackDataEXT",
+        extends: &[StructureType::DebugUtilsMessengerCallbackDataEXT],
+        create: || Box::new(DeviceAddressBindingCallbackDataEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDepthClipControlFeaturesEXT,
+        name: "VkPhysicalDeviceDepthClipControlFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDepthClipControlFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportDepthClipControlCreateInfoEXT,
+        name: "VkPipelineViewportDepthClipControlCreateInfoEXT",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportDepthClipControlCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT,
+        name: "VkPhysicalDevicePrimitiveTopologyListRestartFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePresentModeFifoLatestReadyFeaturesEXT,
+        name: "VkPhysicalDevicePresentModeFifoLatestReadyFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePresentModeFifoLatestReadyFeaturesEXT::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMemoryZirconHandleInfoFUCHSIA,
+        name: "VkImportMemoryZirconHandleInfoFUCHSIA",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMemoryZirconHandleInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::MemoryZirconHandlePropertiesFUCHSIA,
+        name: "VkMemoryZirconHandlePropertiesFUCHSIA",
+        extends: &[],
+        create: || Box::new(MemoryZirconHandlePropertiesFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::MemoryGetZirconHandleInfoFUCHSIA,
+        name: "VkMemoryGetZirconHandleInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(MemoryGetZirconHandleInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::ImportSemaphoreZirconHandleInfoFUCHSIA,
+        name: "VkImportSemaphoreZirconHandleInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(ImportSemaphoreZirconHandleInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::SemaphoreGetZirconHandleInfoFUCHSIA,
+        name: "VkSemaphoreGetZirconHandleInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(SemaphoreGetZirconHandleInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::BufferCollectionCreateInfoFUCHSIA,
+        name: "VkBufferCollectionCreateInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(BufferCollectionCreateInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::ImportMemoryBufferCollectionFUCHSIA,
+        name: "VkImportMemoryBufferCollectionFUCHSIA",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportMemoryBufferCollectionFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::BufferCollectionImageCreateInfoFUCHSIA,
+        name: "VkBufferCollectionImageCreateInfoFUCHSIA",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(BufferCollectionImageCreateInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::BufferConstraintsInfoFUCHSIA,
+        name: "VkBufferConstraintsInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(BufferConstraintsInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::BufferCollectionBufferCreateInfoFUCHSIA,
+        name: "VkBufferCollectionBufferCreateInfoFUCHSIA",
+        extends: &[StructureType::BufferCreateInfo],
+        create: || Box::new(BufferCollectionBufferCreateInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::BufferCollectionPropertiesFUCHSIA,
+        name: "VkBufferCollectionPropertiesFUCHSIA",
+        extends: &[],
+        create: || Box::new(BufferCollectionPropertiesFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::SysmemColorSpaceFUCHSIA,
+        name: "VkSysmemColorSpaceFUCHSIA",
+        extends: &[],
+        create: || Box::new(SysmemColorSpaceFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::ImageConstraintsInfoFUCHSIA,
+        name: "VkImageConstraintsInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(ImageConstraintsInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::ImageFormatConstraintsInfoFUCHSIA,
+        name: "VkImageFormatConstraintsInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(ImageFormatConstraintsInfoFUCHSIA::default()),
+    },
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    StructureInfo {
+        structure_type: StructureType::BufferCollectionConstraintsInfoFUCHSIA,
+        name: "VkBufferCollectionConstraintsInfoFUCHSIA",
+        extends: &[],
+        create: || Box::new(BufferCollectionConstraintsInfoFUCHSIA::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::SubpassShadingPipelineCreateInfoHUAWEI,
+        name: "VkSubpassShadingPipelineCreateInfoHUAWEI",
+        extends: &[StructureType::ComputePipelineCreateInfo],
+        create: || Box::new(SubpassShadingPipelineCreateInfoHUAWEI::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSubpassShadingFeaturesHUAWEI,
+        name: "VkPhysicalDeviceSubpassShadingFeaturesHUAWEI",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSubpassShadingFeaturesHUAWEI::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSubpassShadingPropertiesHUAWEI,
+        name: "VkPhysicalDeviceSubpassShadingPropertiesHUAWEI",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceSubpassShadingPropertiesHUAWEI::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceInvocationMaskFeaturesHUAWEI,
+        name: "VkPhysicalDeviceInvocationMaskFeaturesHUAWEI",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceInvocationMaskFeaturesHUAWEI::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::MemoryGetRemoteAddressInfoNV,
+        name: "VkMemoryGetRemoteAddressInfoNV",
+        extends: &[],
+        create: || Box::new(MemoryGetRemoteAddressInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalMemoryRdmaFeaturesNV,
+        name: "VkPhysicalDeviceExternalMemoryRDMAFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExternalMemoryRDMAFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelinePropertiesIdentifierEXT,
+        name: "VkPipelinePropertiesIdentifierEXT",
+        extends: &[],
+        create: || Box::new(PipelinePropertiesIdentifierEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelinePropertiesFeaturesEXT,
+        name: "VkPhysicalDevicePipelinePropertiesFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelinePropertiesFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFrameBoundaryFeaturesEXT,
+        name: "VkPhysicalDeviceFrameBoundaryFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFrameBoundaryFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::FrameBoundaryEXT,
+        name: "VkFrameBoundaryEXT",
+        extends: &[
+            StructureType::SubmitInfo,
+            StructureType::SubmitInfo2,
+            StructureType::PresentInfoKHR,
+            StructureType::BindSparseInfo,
+        ],
+        create: || Box::new(FrameBoundaryEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT,
+        name: "VkPhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubpassResolvePerformanceQueryEXT,
+        name: "VkSubpassResolvePerformanceQueryEXT",
+        extends: &[StructureType::FormatProperties2],
+        create: || Box::new(SubpassResolvePerformanceQueryEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MultisampledRenderToSingleSampledInfoEXT,
+        name: "VkMultisampledRenderToSingleSampledInfoEXT",
+        extends: &[
+            StructureType::SubpassDescription2,
+            StructureType::RenderingInfo,
+        ],
+        create: || Box::new(MultisampledRenderToSingleSampledInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExtendedDynamicState2FeaturesEXT,
+        name: "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExtendedDynamicState2FeaturesEXT::default()),
+    },
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    StructureInfo {
+        structure_type: StructureType::ScreenSurfaceCreateInfoQNX,
+        name: "VkScreenSurfaceCreateInfoQNX",
+        extends: &[],
+        create: || Box::new(ScreenSurfaceCreateInfoQNX::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceColorWriteEnableFeaturesEXT,
+        name: "VkPhysicalDeviceColorWriteEnableFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceColorWriteEnableFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineColorWriteCreateInfoEXT,
+        name: "VkPipelineColorWriteCreateInfoEXT",
+        extends: &[StructureType::PipelineColorBlendStateCreateInfo],
+        create: || Box::new(PipelineColorWriteCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT,
+        name: "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingMaintenance1FeaturesKHR,
+        name: "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayTracingMaintenance1FeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageViewMinLodFeaturesEXT,
+        name: "VkPhysicalDeviceImageViewMinLodFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageViewMinLodFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageViewMinLodCreateInfoEXT,
+        name: "VkImageViewMinLodCreateInfoEXT",
+        extends: &[StructureType::ImageViewCreateInfo],
+        create: || Box::new(ImageViewMinLodCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiDrawFeaturesEXT,
+        name: "VkPhysicalDeviceMultiDrawFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMultiDrawFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiDrawPropertiesEXT,
+        name: "VkPhysicalDeviceMultiDrawPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMultiDrawPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImage2DViewOf3DFeaturesEXT,
+        name: "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImage2DViewOf3DFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderTileImageFeaturesEXT,
+        name: "VkPhysicalDeviceShaderTileImageFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderTileImageFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderTileImagePropertiesEXT,
+        name: "VkPhysicalDeviceShaderTileImagePropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderTileImagePropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MicromapBuildInfoEXT,
+        name: "VkMicromapBuildInfoEXT",
+        extends: &[],
+        create: || Box::new(MicromapBuildInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MicromapCreateInfoEXT,
+        name: "VkMicromapCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(MicromapCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceOpacityMicromapFeaturesEXT,
+        name: "VkPhysicalDeviceOpacityMicromapFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceOpacityMicromapFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceOpacityMicromapPropertiesEXT,
+        name: "VkPhysicalDeviceOpacityMicromapPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceOpacityMicromapPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MicromapVersionInfoEXT,
+        name: "VkMicromapVersionInfoEXT",
+        extends: &[],
+        create: || Box::new(MicromapVersionInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyMicromapToMemoryInfoEXT,
+        name: "VkCopyMicromapToMemoryInfoEXT",
+        extends: &[],
+        create: || Box::new(CopyMicromapToMemoryInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyMemoryToMicromapInfoEXT,
+        name: "VkCopyMemoryToMicromapInfoEXT",
+        extends: &[],
+        create: || Box::new(CopyMemoryToMicromapInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CopyMicromapInfoEXT,
+        name: "VkCopyMicromapInfoEXT",
+        extends: &[],
+        create: || Box::new(CopyMicromapInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MicromapBuildSizesInfoEXT,
+        name: "VkMicromapBuildSizesInfoEXT",
+        extends: &[],
+        create: || Box::new(MicromapBuildSizesInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureTrianglesOpacityMicromapEXT,
+        name: "VkAccelerationStructureTrianglesOpacityMicromapEXT",
+        extends: &[StructureType::AccelerationStructureGeometryTrianglesDataKHR],
+        create: || Box::new(AccelerationStructureTrianglesOpacityMicromapEXT::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDisplacementMicromapFeaturesNV,
+        name: "VkPhysicalDeviceDisplacementMicromapFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDisplacementMicromapFeaturesNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDisplacementMicromapPropertiesNV,
+        name: "VkPhysicalDeviceDisplacementMicromapPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDisplacementMicromapPropertiesNV::default()),
+    },
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    StructureInfo {
+        structure_type: StructureType::AccelerationStructureTrianglesDisplacementMicromapNV,
+        name: "VkAccelerationStructureTrianglesDisplacementMicromapNV",
+        extends: &[StructureType::AccelerationStructureGeometryTrianglesDataKHR],
+        create: || Box::new(AccelerationStructureTrianglesDisplacementMicromapNV::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI,
+        name: "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceClusterCullingShaderFeaturesHUAWEI::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI,
+        name: "VkPhysicalDeviceClusterCullingShaderPropertiesHUAWEI",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceClusterCullingShaderPropertiesHUAWEI::default()),
+    },
+    #[cfg(feature = "vendor-huawei")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceClusterCullingShaderVrsFeaturesHUAWEI,
+        name: "VkPhysicalDeviceClusterCullingShaderVrsFeaturesHUAWEI",
+        extends: &[StructureType::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI],
+        create: || Box::new(PhysicalDeviceClusterCullingShaderVrsFeaturesHUAWEI::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceBorderColorSwizzleFeaturesEXT,
+        name: "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceBorderColorSwizzleFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SamplerBorderColorComponentMappingCreateInfoEXT,
+        name: "VkSamplerBorderColorComponentMappingCreateInfoEXT",
+        extends: &[StructureType::SamplerCreateInfo],
+        create: || Box::new(SamplerBorderColorComponentMappingCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT,
+        name: "VkPhysicalDevicePageableDeviceLocalMemoryFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderCorePropertiesARM,
+        name: "VkPhysicalDeviceShaderCorePropertiesARM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderCorePropertiesARM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderSubgroupRotateFeaturesKHR,
+        name: "VkPhysicalDeviceShaderSubgroupRotateFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderSubgroupRotateFeaturesKHR::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::DeviceQueueShaderCoreControlCreateInfoARM,
+        name: "VkDeviceQueueShaderCoreControlCreateInfoARM",
+        extends: &[
+            StructureType::DeviceQueueCreateInfo,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(DeviceQueueShaderCoreControlCreateInfoARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSchedulingControlsFeaturesARM,
+        name: "VkPhysicalDeviceSchedulingControlsFeaturesARM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSchedulingControlsFeaturesARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSchedulingControlsPropertiesARM,
+        name: "VkPhysicalDeviceSchedulingControlsPropertiesARM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceSchedulingControlsPropertiesARM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT,
+        name: "VkPhysicalDeviceImageSlicedViewOf3DFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageSlicedViewOf3DFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageViewSlicedCreateInfoEXT,
+        name: "VkImageViewSlicedCreateInfoEXT",
+        extends: &[StructureType::ImageViewCreateInfo],
+        create: || Box::new(ImageViewSlicedCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-valve")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE,
+        name: "VkPhysicalDeviceDescriptorSetHostMappingFeaturesVALVE",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE::default()),
+    },
+    #[cfg(feature = "vendor-valve")]
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetBindingReferenceVALVE,
+        name: "VkDescriptorSetBindingReferenceVALVE",
+        extends: &[],
+        create: || Box::new(DescriptorSetBindingReferenceVALVE::default()),
+    },
+    #[cfg(feature = "vendor-valve")]
+    StructureInfo {
+        structure_type: StructureType::DescriptorSetLayoutHostMappingInfoVALVE,
+        name: "VkDescriptorSetLayoutHostMappingInfoVALVE",
+        extends: &[],
+        create: || Box::new(DescriptorSetLayoutHostMappingInfoVALVE::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDepthClampZeroOneFeaturesEXT,
+        name: "VkPhysicalDeviceDepthClampZeroOneFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDepthClampZeroOneFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT,
+        name: "VkPhysicalDeviceNonSeamlessCubeMapFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceNonSeamlessCubeMapFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRenderPassStripedFeaturesARM,
+        name: "VkPhysicalDeviceRenderPassStripedFeaturesARM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRenderPassStripedFeaturesARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRenderPassStripedPropertiesARM,
+        name: "VkPhysicalDeviceRenderPassStripedPropertiesARM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceRenderPassStripedPropertiesARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::RenderPassStripeBeginInfoARM,
+        name: "VkRenderPassStripeBeginInfoARM",
+        extends: &[
+            StructureType::RenderingInfo,
+            StructureType::RenderPassBeginInfo,
+        ],
+        create: || Box::new(RenderPassStripeBeginInfoARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::RenderPassStripeInfoARM,
+        name: "VkRenderPassStripeInfoARM",
+        extends: &[],
+        create: || Box::new(RenderPassStripeInfoARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::RenderPassStripeSubmitInfoARM,
+        name: "VkRenderPassStripeSubmitInfoARM",
+        extends: &[StructureType::CommandBufferSubmitInfo],
+        create: || Box::new(RenderPassStripeSubmitInfoARM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM,
+        name: "VkPhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM,
+        name: "VkPhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::SubpassFragmentDensityMapOffsetEndInfoQCOM,
+        name: "VkSubpassFragmentDensityMapOffsetEndInfoQCOM",
+        extends: &[StructureType::SubpassEndInfo],
+        create: || Box::new(SubpassFragmentDensityMapOffsetEndInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCopyMemoryIndirectFeaturesNV,
+        name: "VkPhysicalDeviceCopyMemoryIndirectFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCopyMemoryIndirectFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCopyMemoryIndirectPropertiesNV,
+        name: "VkPhysicalDeviceCopyMemoryIndirectPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceCopyMemoryIndirectPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMemoryDecompressionFeaturesNV,
+        name: "VkPhysicalDeviceMemoryDecompressionFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMemoryDecompressionFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMemoryDecompressionPropertiesNV,
+        name: "VkPhysicalDeviceMemoryDecompressionPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMemoryDecompressionPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV,
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::ComputePipelineIndirectBufferInfoNV,
+        name: "VkComputePipelineIndirectBufferInfoNV",
+        extends: &[StructureType::ComputePipelineCreateInfo],
+        create: || Box::new(ComputePipelineIndirectBufferInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PipelineIndirectDeviceAddressInfoNV,
+        name: "VkPipelineIndirectDeviceAddressInfoNV",
+        extends: &[],
+        create: || Box::new(PipelineIndirectDeviceAddressInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLinearColorAttachmentFeaturesNV,
+        name: "VkPhysicalDeviceLinearColorAttachmentFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceLinearColorAttachmentFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR,
+        name: "VkPhysicalDeviceShaderMaximalReconvergenceFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT,
+        name: "VkPhysicalDeviceImageCompressionControlSwapchainFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::ImageViewSampleWeightCreateInfoQCOM,
+        name: "VkImageViewSampleWeightCreateInfoQCOM",
+        extends: &[StructureType::ImageViewCreateInfo],
+        create: || Box::new(ImageViewSampleWeightCreateInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageProcessingFeaturesQCOM,
+        name: "VkPhysicalDeviceImageProcessingFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageProcessingFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageProcessingPropertiesQCOM,
+        name: "VkPhysicalDeviceImageProcessingPropertiesQCOM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceImageProcessingPropertiesQCOM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceNestedCommandBufferFeaturesEXT,
+        name: "VkPhysicalDeviceNestedCommandBufferFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceNestedCommandBufferFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceNestedCommandBufferPropertiesEXT,
+        name: "VkPhysicalDeviceNestedCommandBufferPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceNestedCommandBufferPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ExternalMemoryAcquireUnmodifiedEXT,
+        name: "VkExternalMemoryAcquireUnmodifiedEXT",
+        extends: &[
+            StructureType::BufferMemoryBarrier,
+            StructureType::BufferMemoryBarrier2,
+            StructureType::ImageMemoryBarrier,
+            StructureType::ImageMemoryBarrier2,
+        ],
+        create: || Box::new(ExternalMemoryAcquireUnmodifiedEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExtendedDynamicState3FeaturesEXT,
+        name: "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExtendedDynamicState3FeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExtendedDynamicState3PropertiesEXT,
+        name: "VkPhysicalDeviceExtendedDynamicState3PropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceExtendedDynamicState3PropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT,
+        name: "VkPhysicalDeviceSubpassMergeFeedbackFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceSubpassMergeFeedbackFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassCreationControlEXT,
+        name: "VkRenderPassCreationControlEXT",
+        extends: &[
+            StructureType::RenderPassCreateInfo2,
+            StructureType::SubpassDescription2,
+        ],
+        create: || Box::new(RenderPassCreationControlEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassCreationFeedbackCreateInfoEXT,
+        name: "VkRenderPassCreationFeedbackCreateInfoEXT",
+        extends: &[StructureType::RenderPassCreateInfo2],
+        create: || Box::new(RenderPassCreationFeedbackCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderPassSubpassFeedbackCreateInfoEXT,
+        name: "VkRenderPassSubpassFeedbackCreateInfoEXT",
+        extends: &[StructureType::SubpassDescription2],
+        create: || Box::new(RenderPassSubpassFeedbackCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-lunarg")]
+    StructureInfo {
+        structure_type: StructureType::DirectDriverLoadingInfoLUNARG,
+        name: "VkDirectDriverLoadingInfoLUNARG",
+        extends: &[],
+        create: || Box::new(DirectDriverLoadingInfoLUNARG::default()),
+    },
+    #[cfg(feature = "vendor-lunarg")]
+    StructureInfo {
+        structure_type: StructureType::DirectDriverLoadingListLUNARG,
+        name: "VkDirectDriverLoadingListLUNARG",
+        extends: &[StructureType::InstanceCreateInfo],
+        create: || Box::new(DirectDriverLoadingListLUNARG::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderModuleIdentifierFeaturesEXT,
+        name: "VkPhysicalDeviceShaderModuleIdentifierFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderModuleIdentifierFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderModuleIdentifierPropertiesEXT,
+        name: "VkPhysicalDeviceShaderModuleIdentifierPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderModuleIdentifierPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineShaderStageModuleIdentifierCreateInfoEXT,
+        name: "VkPipelineShaderStageModuleIdentifierCreateInfoEXT",
+        extends: &[StructureType::PipelineShaderStageCreateInfo],
+        create: || Box::new(PipelineShaderStageModuleIdentifierCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ShaderModuleIdentifierEXT,
+        name: "VkShaderModuleIdentifierEXT",
+        extends: &[],
+        create: || Box::new(ShaderModuleIdentifierEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT,
+        name: "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceOpticalFlowFeaturesNV,
+        name: "VkPhysicalDeviceOpticalFlowFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceOpticalFlowFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceOpticalFlowPropertiesNV,
+        name: "VkPhysicalDeviceOpticalFlowPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceOpticalFlowPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::OpticalFlowImageFormatInfoNV,
+        name: "VkOpticalFlowImageFormatInfoNV",
+        extends: &[
+            StructureType::PhysicalDeviceImageFormatInfo2,
+            StructureType::ImageCreateInfo,
+        ],
+        create: || Box::new(OpticalFlowImageFormatInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::OpticalFlowImageFormatPropertiesNV,
+        name: "VkOpticalFlowImageFormatPropertiesNV",
+        extends: &[],
+        create: || Box::new(OpticalFlowImageFormatPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::OpticalFlowSessionCreateInfoNV,
+        name: "VkOpticalFlowSessionCreateInfoNV",
+        extends: &[],
+        create: || Box::new(OpticalFlowSessionCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::OpticalFlowSessionCreatePrivateDataInfoNV,
+        name: "VkOpticalFlowSessionCreatePrivateDataInfoNV",
+        extends: &[StructureType::OpticalFlowSessionCreateInfoNV],
+        create: || Box::new(OpticalFlowSessionCreatePrivateDataInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::OpticalFlowExecuteInfoNV,
+        name: "VkOpticalFlowExecuteInfoNV",
+        extends: &[],
+        create: || Box::new(OpticalFlowExecuteInfoNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLegacyDitheringFeaturesEXT,
+        name: "VkPhysicalDeviceLegacyDitheringFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceLegacyDitheringFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineProtectedAccessFeaturesEXT,
+        name: "VkPhysicalDevicePipelineProtectedAccessFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelineProtectedAccessFeaturesEXT::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalFormatResolveFeaturesANDROID,
+        name: "VkPhysicalDeviceExternalFormatResolveFeaturesANDROID",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExternalFormatResolveFeaturesANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalFormatResolvePropertiesANDROID,
+        name: "VkPhysicalDeviceExternalFormatResolvePropertiesANDROID",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceExternalFormatResolvePropertiesANDROID::default()),
+    },
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    StructureInfo {
+        structure_type: StructureType::AndroidHardwareBufferFormatResolvePropertiesANDROID,
+        name: "VkAndroidHardwareBufferFormatResolvePropertiesANDROID",
+        extends: &[StructureType::AndroidHardwareBufferPropertiesANDROID],
+        create: || Box::new(AndroidHardwareBufferFormatResolvePropertiesANDROID::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance5FeaturesKHR,
+        name: "VkPhysicalDeviceMaintenance5FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMaintenance5FeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance5PropertiesKHR,
+        name: "VkPhysicalDeviceMaintenance5PropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMaintenance5PropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::RenderingAreaInfoKHR,
+        name: "VkRenderingAreaInfoKHR",
+        extends: &[],
+        create: || Box::new(RenderingAreaInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DeviceImageSubresourceInfoKHR,
+        name: "VkDeviceImageSubresourceInfoKHR",
+        extends: &[],
+        create: || Box::new(DeviceImageSubresourceInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ImageSubresource2KHR,
+        name: "VkImageSubresource2KHR",
+        extends: &[],
+        create: || Box::new(ImageSubresource2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SubresourceLayout2KHR,
+        name: "VkSubresourceLayout2KHR",
+        extends: &[],
+        create: || Box::new(SubresourceLayout2KHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineCreateFlags2CreateInfoKHR,
+        name: "VkPipelineCreateFlags2CreateInfoKHR",
+        extends: &[
+            StructureType::ComputePipelineCreateInfo,
+            StructureType::GraphicsPipelineCreateInfo,
+            #[cfg(feature = "vendor-nv")]
+            StructureType::RayTracingPipelineCreateInfoNV,
+            StructureType::RayTracingPipelineCreateInfoKHR,
+        ],
+        create: || Box::new(PipelineCreateFlags2CreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BufferUsageFlags2CreateInfoKHR,
+        name: "VkBufferUsageFlags2CreateInfoKHR",
+        extends: &[
+            StructureType::BufferViewCreateInfo,
+            StructureType::BufferCreateInfo,
+            StructureType::PhysicalDeviceExternalBufferInfo,
+            StructureType::DescriptorBufferBindingInfoEXT,
+        ],
+        create: || Box::new(BufferUsageFlags2CreateInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAntiLagFeaturesAMD,
+        name: "VkPhysicalDeviceAntiLagFeaturesAMD",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceAntiLagFeaturesAMD::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::AntiLagDataAMD,
+        name: "VkAntiLagDataAMD",
+        extends: &[],
+        create: || Box::new(AntiLagDataAMD::default()),
+    },
+    #[cfg(feature = "vendor-amd")]
+    StructureInfo {
+        structure_type: StructureType::AntiLagPresentationInfoAMD,
+        name: "VkAntiLagPresentationInfoAMD",
+        extends: &[],
+        create: || Box::new(AntiLagPresentationInfoAMD::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingPositionFetchFeaturesKHR,
+        name: "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayTracingPositionFetchFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderObjectFeaturesEXT,
+        name: "VkPhysicalDeviceShaderObjectFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderObjectFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderObjectPropertiesEXT,
+        name: "VkPhysicalDeviceShaderObjectPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderObjectPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ShaderCreateInfoEXT,
+        name: "VkShaderCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(ShaderCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineBinaryFeaturesKHR,
+        name: "VkPhysicalDevicePipelineBinaryFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelineBinaryFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineBinaryPropertiesKHR,
+        name: "VkPhysicalDevicePipelineBinaryPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDevicePipelineBinaryPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::DevicePipelineBinaryInternalCacheControlKHR,
+        name: "VkDevicePipelineBinaryInternalCacheControlKHR",
+        extends: &[StructureType::DeviceCreateInfo],
+        create: || Box::new(DevicePipelineBinaryInternalCacheControlKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineBinaryKeyKHR,
+        name: "VkPipelineBinaryKeyKHR",
+        extends: &[],
+        create: || Box::new(PipelineBinaryKeyKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineBinaryCreateInfoKHR,
+        name: "VkPipelineBinaryCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(PipelineBinaryCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineBinaryInfoKHR,
+        name: "VkPipelineBinaryInfoKHR",
+        extends: &[
+            StructureType::GraphicsPipelineCreateInfo,
+            StructureType::ComputePipelineCreateInfo,
+            StructureType::RayTracingPipelineCreateInfoKHR,
+        ],
+        create: || Box::new(PipelineBinaryInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::ReleaseCapturedPipelineDataInfoKHR,
+        name: "VkReleaseCapturedPipelineDataInfoKHR",
+        extends: &[],
+        create: || Box::new(ReleaseCapturedPipelineDataInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineBinaryDataInfoKHR,
+        name: "VkPipelineBinaryDataInfoKHR",
+        extends: &[],
+        create: || Box::new(PipelineBinaryDataInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineCreateInfoKHR,
+        name: "VkPipelineCreateInfoKHR",
+        extends: &[],
+        create: || Box::new(PipelineCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineBinaryHandlesInfoKHR,
+        name: "VkPipelineBinaryHandlesInfoKHR",
+        extends: &[],
+        create: || Box::new(PipelineBinaryHandlesInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceTilePropertiesFeaturesQCOM,
+        name: "VkPhysicalDeviceTilePropertiesFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceTilePropertiesFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::TilePropertiesQCOM,
+        name: "VkTilePropertiesQCOM",
+        extends: &[],
+        create: || Box::new(TilePropertiesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-sec")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAmigoProfilingFeaturesSEC,
+        name: "VkPhysicalDeviceAmigoProfilingFeaturesSEC",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceAmigoProfilingFeaturesSEC::default()),
+    },
+    #[cfg(feature = "vendor-sec")]
+    StructureInfo {
+        structure_type: StructureType::AmigoProfilingSubmitInfoSEC,
+        name: "VkAmigoProfilingSubmitInfoSEC",
+        extends: &[StructureType::SubmitInfo],
+        create: || Box::new(AmigoProfilingSubmitInfoSEC::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM,
+        name: "VkPhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingInvocationReorderPropertiesNV,
+        name: "VkPhysicalDeviceRayTracingInvocationReorderPropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceRayTracingInvocationReorderPropertiesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingInvocationReorderFeaturesNV,
+        name: "VkPhysicalDeviceRayTracingInvocationReorderFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayTracingInvocationReorderFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV,
+        name: "VkPhysicalDeviceExtendedSparseAddressSpaceFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExtendedSparseAddressSpacePropertiesNV,
+        name: "VkPhysicalDeviceExtendedSparseAddressSpacePropertiesNV",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceExtendedSparseAddressSpacePropertiesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMutableDescriptorTypeFeaturesEXT,
+        name: "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMutableDescriptorTypeFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::MutableDescriptorTypeCreateInfoEXT,
+        name: "VkMutableDescriptorTypeCreateInfoEXT",
+        extends: &[
+            StructureType::DescriptorSetLayoutCreateInfo,
+            StructureType::DescriptorPoolCreateInfo,
+        ],
+        create: || Box::new(MutableDescriptorTypeCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLegacyVertexAttributesFeaturesEXT,
+        name: "VkPhysicalDeviceLegacyVertexAttributesFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceLegacyVertexAttributesFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLegacyVertexAttributesPropertiesEXT,
+        name: "VkPhysicalDeviceLegacyVertexAttributesPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceLegacyVertexAttributesPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::LayerSettingsCreateInfoEXT,
+        name: "VkLayerSettingsCreateInfoEXT",
+        extends: &[StructureType::InstanceCreateInfo],
+        create: || Box::new(LayerSettingsCreateInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderCoreBuiltinsFeaturesARM,
+        name: "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderCoreBuiltinsFeaturesARM::default()),
+    },
+    #[cfg(feature = "vendor-arm")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderCoreBuiltinsPropertiesARM,
+        name: "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceShaderCoreBuiltinsPropertiesARM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT,
+        name: "VkPhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT,
+        name: "VkPhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::LatencySleepModeInfoNV,
+        name: "VkLatencySleepModeInfoNV",
+        extends: &[],
+        create: || Box::new(LatencySleepModeInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::LatencySleepInfoNV,
+        name: "VkLatencySleepInfoNV",
+        extends: &[],
+        create: || Box::new(LatencySleepInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::SetLatencyMarkerInfoNV,
+        name: "VkSetLatencyMarkerInfoNV",
+        extends: &[],
+        create: || Box::new(SetLatencyMarkerInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::GetLatencyMarkerInfoNV,
+        name: "VkGetLatencyMarkerInfoNV",
+        extends: &[],
+        create: || Box::new(GetLatencyMarkerInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::LatencyTimingsFrameReportNV,
+        name: "VkLatencyTimingsFrameReportNV",
+        extends: &[],
+        create: || Box::new(LatencyTimingsFrameReportNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::LatencySubmissionPresentIdNV,
+        name: "VkLatencySubmissionPresentIdNV",
+        extends: &[StructureType::SubmitInfo, StructureType::SubmitInfo2],
+        create: || Box::new(LatencySubmissionPresentIdNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::SwapchainLatencyCreateInfoNV,
+        name: "VkSwapchainLatencyCreateInfoNV",
+        extends: &[StructureType::SwapchainCreateInfoKHR],
+        create: || Box::new(SwapchainLatencyCreateInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::OutOfBandQueueTypeInfoNV,
+        name: "VkOutOfBandQueueTypeInfoNV",
+        extends: &[],
+        create: || Box::new(OutOfBandQueueTypeInfoNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::LatencySurfaceCapabilitiesNV,
+        name: "VkLatencySurfaceCapabilitiesNV",
+        extends: &[StructureType::SurfaceCapabilities2KHR],
+        create: || Box::new(LatencySurfaceCapabilitiesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CooperativeMatrixPropertiesKHR,
+        name: "VkCooperativeMatrixPropertiesKHR",
+        extends: &[],
+        create: || Box::new(CooperativeMatrixPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCooperativeMatrixFeaturesKHR,
+        name: "VkPhysicalDeviceCooperativeMatrixFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCooperativeMatrixFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCooperativeMatrixPropertiesKHR,
+        name: "VkPhysicalDeviceCooperativeMatrixPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceCooperativeMatrixPropertiesKHR::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM,
+        name: "VkPhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::MultiviewPerViewRenderAreasRenderPassBeginInfoQCOM,
+        name: "VkMultiviewPerViewRenderAreasRenderPassBeginInfoQCOM",
+        extends: &[
+            StructureType::RenderPassBeginInfo,
+            StructureType::RenderingInfo,
+        ],
+        create: || Box::new(MultiviewPerViewRenderAreasRenderPassBeginInfoQCOM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceComputeShaderDerivativesFeaturesKHR,
+        name: "VkPhysicalDeviceComputeShaderDerivativesFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceComputeShaderDerivativesFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceComputeShaderDerivativesPropertiesKHR,
+        name: "VkPhysicalDeviceComputeShaderDerivativesPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceComputeShaderDerivativesPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeAv1ProfileInfoKHR,
+        name: "VkVideoDecodeAV1ProfileInfoKHR",
+        extends: &[
+            StructureType::VideoProfileInfoKHR,
+            StructureType::QueryPoolCreateInfo,
+        ],
+        create: || Box::new(VideoDecodeAV1ProfileInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeAv1CapabilitiesKHR,
+        name: "VkVideoDecodeAV1CapabilitiesKHR",
+        extends: &[StructureType::VideoCapabilitiesKHR],
+        create: || Box::new(VideoDecodeAV1CapabilitiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeAv1SessionParametersCreateInfoKHR,
+        name: "VkVideoDecodeAV1SessionParametersCreateInfoKHR",
+        extends: &[StructureType::VideoSessionParametersCreateInfoKHR],
+        create: || Box::new(VideoDecodeAV1SessionParametersCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeAv1PictureInfoKHR,
+        name: "VkVideoDecodeAV1PictureInfoKHR",
+        extends: &[StructureType::VideoDecodeInfoKHR],
+        create: || Box::new(VideoDecodeAV1PictureInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoDecodeAv1DpbSlotInfoKHR,
+        name: "VkVideoDecodeAV1DpbSlotInfoKHR",
+        extends: &[StructureType::VideoReferenceSlotInfoKHR],
+        create: || Box::new(VideoDecodeAV1DpbSlotInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVideoMaintenance1FeaturesKHR,
+        name: "VkPhysicalDeviceVideoMaintenance1FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVideoMaintenance1FeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::VideoInlineQueryInfoKHR,
+        name: "VkVideoInlineQueryInfoKHR",
+        extends: &[
+            StructureType::VideoDecodeInfoKHR,
+            StructureType::VideoEncodeInfoKHR,
+        ],
+        create: || Box::new(VideoInlineQueryInfoKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDevicePerStageDescriptorSetFeaturesNV,
+        name: "VkPhysicalDevicePerStageDescriptorSetFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDevicePerStageDescriptorSetFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageProcessing2FeaturesQCOM,
+        name: "VkPhysicalDeviceImageProcessing2FeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageProcessing2FeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageProcessing2PropertiesQCOM,
+        name: "VkPhysicalDeviceImageProcessing2PropertiesQCOM",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceImageProcessing2PropertiesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::SamplerBlockMatchWindowCreateInfoQCOM,
+        name: "VkSamplerBlockMatchWindowCreateInfoQCOM",
+        extends: &[StructureType::SamplerCreateInfo],
+        create: || Box::new(SamplerBlockMatchWindowCreateInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCubicWeightsFeaturesQCOM,
+        name: "VkPhysicalDeviceCubicWeightsFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCubicWeightsFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::SamplerCubicWeightsCreateInfoQCOM,
+        name: "VkSamplerCubicWeightsCreateInfoQCOM",
+        extends: &[StructureType::SamplerCreateInfo],
+        create: || Box::new(SamplerCubicWeightsCreateInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::BlitImageCubicWeightsInfoQCOM,
+        name: "VkBlitImageCubicWeightsInfoQCOM",
+        extends: &[StructureType::BlitImageInfo2],
+        create: || Box::new(BlitImageCubicWeightsInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceYcbcrDegammaFeaturesQCOM,
+        name: "VkPhysicalDeviceYcbcrDegammaFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceYcbcrDegammaFeaturesQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::SamplerYcbcrConversionYcbcrDegammaCreateInfoQCOM,
+        name: "VkSamplerYcbcrConversionYcbcrDegammaCreateInfoQCOM",
+        extends: &[StructureType::SamplerYcbcrConversionCreateInfo],
+        create: || Box::new(SamplerYcbcrConversionYcbcrDegammaCreateInfoQCOM::default()),
+    },
+    #[cfg(feature = "vendor-qcom")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCubicClampFeaturesQCOM,
+        name: "VkPhysicalDeviceCubicClampFeaturesQCOM",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCubicClampFeaturesQCOM::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT,
+        name: "VkPhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVertexAttributeDivisorPropertiesKHR,
+        name: "VkPhysicalDeviceVertexAttributeDivisorPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceVertexAttributeDivisorPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineVertexInputDivisorStateCreateInfoKHR,
+        name: "VkPipelineVertexInputDivisorStateCreateInfoKHR",
+        extends: &[StructureType::PipelineVertexInputStateCreateInfo],
+        create: || Box::new(PipelineVertexInputDivisorStateCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceVertexAttributeDivisorFeaturesKHR,
+        name: "VkPhysicalDeviceVertexAttributeDivisorFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceVertexAttributeDivisorFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderFloatControls2FeaturesKHR,
+        name: "VkPhysicalDeviceShaderFloatControls2FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderFloatControls2FeaturesKHR::default()),
+    },
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    StructureInfo {
+        structure_type: StructureType::ScreenBufferPropertiesQNX,
+        name: "VkScreenBufferPropertiesQNX",
+        extends: &[],
+        create: || Box::new(ScreenBufferPropertiesQNX::default()),
+    },
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    StructureInfo {
+        structure_type: StructureType::ScreenBufferFormatPropertiesQNX,
+        name: "VkScreenBufferFormatPropertiesQNX",
+        extends: &[StructureType::ScreenBufferPropertiesQNX],
+        create: || Box::new(ScreenBufferFormatPropertiesQNX::default()),
+    },
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    StructureInfo {
+        structure_type: StructureType::ImportScreenBufferInfoQNX,
+        name: "VkImportScreenBufferInfoQNX",
+        extends: &[StructureType::MemoryAllocateInfo],
+        create: || Box::new(ImportScreenBufferInfoQNX::default()),
+    },
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    StructureInfo {
+        structure_type: StructureType::ExternalFormatQNX,
+        name: "VkExternalFormatQNX",
+        extends: &[
+            StructureType::ImageCreateInfo,
+            StructureType::SamplerYcbcrConversionCreateInfo,
+        ],
+        create: || Box::new(ExternalFormatQNX::default()),
+    },
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX,
+        name: "VkPhysicalDeviceExternalMemoryScreenBufferFeaturesQNX",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX::default()),
+    },
+    #[cfg(feature = "vendor-msft")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLayeredDriverPropertiesMSFT,
+        name: "VkPhysicalDeviceLayeredDriverPropertiesMSFT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceLayeredDriverPropertiesMSFT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceIndexTypeUint8FeaturesKHR,
+        name: "VkPhysicalDeviceIndexTypeUint8FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceIndexTypeUint8FeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLineRasterizationFeaturesKHR,
+        name: "VkPhysicalDeviceLineRasterizationFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceLineRasterizationFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLineRasterizationPropertiesKHR,
+        name: "VkPhysicalDeviceLineRasterizationPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceLineRasterizationPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineRasterizationLineStateCreateInfoKHR,
+        name: "VkPipelineRasterizationLineStateCreateInfoKHR",
+        extends: &[StructureType::PipelineRasterizationStateCreateInfo],
+        create: || Box::new(PipelineRasterizationLineStateCreateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::CalibratedTimestampInfoKHR,
+        name: "VkCalibratedTimestampInfoKHR",
+        extends: &[],
+        create: || Box::new(CalibratedTimestampInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderExpectAssumeFeaturesKHR,
+        name: "VkPhysicalDeviceShaderExpectAssumeFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderExpectAssumeFeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance6FeaturesKHR,
+        name: "VkPhysicalDeviceMaintenance6FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMaintenance6FeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance6PropertiesKHR,
+        name: "VkPhysicalDeviceMaintenance6PropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMaintenance6PropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindMemoryStatusKHR,
+        name: "VkBindMemoryStatusKHR",
+        extends: &[
+            StructureType::BindBufferMemoryInfo,
+            StructureType::BindImageMemoryInfo,
+        ],
+        create: || Box::new(BindMemoryStatusKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindDescriptorSetsInfoKHR,
+        name: "VkBindDescriptorSetsInfoKHR",
+        extends: &[],
+        create: || Box::new(BindDescriptorSetsInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PushConstantsInfoKHR,
+        name: "VkPushConstantsInfoKHR",
+        extends: &[],
+        create: || Box::new(PushConstantsInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PushDescriptorSetInfoKHR,
+        name: "VkPushDescriptorSetInfoKHR",
+        extends: &[],
+        create: || Box::new(PushDescriptorSetInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PushDescriptorSetWithTemplateInfoKHR,
+        name: "VkPushDescriptorSetWithTemplateInfoKHR",
+        extends: &[],
+        create: || Box::new(PushDescriptorSetWithTemplateInfoKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::SetDescriptorBufferOffsetsInfoEXT,
+        name: "VkSetDescriptorBufferOffsetsInfoEXT",
+        extends: &[],
+        create: || Box::new(SetDescriptorBufferOffsetsInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::BindDescriptorBufferEmbeddedSamplersInfoEXT,
+        name: "VkBindDescriptorBufferEmbeddedSamplersInfoEXT",
+        extends: &[],
+        create: || Box::new(BindDescriptorBufferEmbeddedSamplersInfoEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDescriptorPoolOverallocationFeaturesNV,
+        name: "VkPhysicalDeviceDescriptorPoolOverallocationFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDescriptorPoolOverallocationFeaturesNV::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRawAccessChainsFeaturesNV,
+        name: "VkPhysicalDeviceRawAccessChainsFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRawAccessChainsFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderRelaxedExtendedInstructionFeaturesKHR,
+        name: "VkPhysicalDeviceShaderRelaxedExtendedInstructionFeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderRelaxedExtendedInstructionFeaturesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceCommandBufferInheritanceFeaturesNV,
+        name: "VkPhysicalDeviceCommandBufferInheritanceFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceCommandBufferInheritanceFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance7FeaturesKHR,
+        name: "VkPhysicalDeviceMaintenance7FeaturesKHR",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceMaintenance7FeaturesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceMaintenance7PropertiesKHR,
+        name: "VkPhysicalDeviceMaintenance7PropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceMaintenance7PropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLayeredApiPropertiesListKHR,
+        name: "VkPhysicalDeviceLayeredApiPropertiesListKHR",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceLayeredApiPropertiesListKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLayeredApiPropertiesKHR,
+        name: "VkPhysicalDeviceLayeredApiPropertiesKHR",
+        extends: &[],
+        create: || Box::new(PhysicalDeviceLayeredApiPropertiesKHR::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceLayeredApiVulkanPropertiesKHR,
+        name: "VkPhysicalDeviceLayeredApiVulkanPropertiesKHR",
+        extends: &[StructureType::PhysicalDeviceLayeredApiPropertiesKHR],
+        create: || Box::new(PhysicalDeviceLayeredApiVulkanPropertiesKHR::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV,
+        name: "VkPhysicalDeviceShaderAtomicFloat16VectorFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceShaderReplicatedCompositesFeaturesEXT,
+        name: "VkPhysicalDeviceShaderReplicatedCompositesFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceShaderReplicatedCompositesFeaturesEXT::default()),
+    },
+    #[cfg(feature = "vendor-nv")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceRayTracingValidationFeaturesNV,
+        name: "VkPhysicalDeviceRayTracingValidationFeaturesNV",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceRayTracingValidationFeaturesNV::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDeviceGeneratedCommandsFeaturesEXT,
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDeviceGeneratedCommandsFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDeviceGeneratedCommandsPropertiesEXT,
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesEXT",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceDeviceGeneratedCommandsPropertiesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::GeneratedCommandsMemoryRequirementsInfoEXT,
+        name: "VkGeneratedCommandsMemoryRequirementsInfoEXT",
+        extends: &[],
+        create: || Box::new(GeneratedCommandsMemoryRequirementsInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::IndirectExecutionSetCreateInfoEXT,
+        name: "VkIndirectExecutionSetCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(IndirectExecutionSetCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::IndirectExecutionSetPipelineInfoEXT,
+        name: "VkIndirectExecutionSetPipelineInfoEXT",
+        extends: &[],
+        create: || Box::new(IndirectExecutionSetPipelineInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::IndirectExecutionSetShaderInfoEXT,
+        name: "VkIndirectExecutionSetShaderInfoEXT",
+        extends: &[],
+        create: || Box::new(IndirectExecutionSetShaderInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::GeneratedCommandsInfoEXT,
+        name: "VkGeneratedCommandsInfoEXT",
+        extends: &[],
+        create: || Box::new(GeneratedCommandsInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::WriteIndirectExecutionSetPipelineEXT,
+        name: "VkWriteIndirectExecutionSetPipelineEXT",
+        extends: &[],
+        create: || Box::new(WriteIndirectExecutionSetPipelineEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::IndirectCommandsLayoutCreateInfoEXT,
+        name: "VkIndirectCommandsLayoutCreateInfoEXT",
+        extends: &[],
+        create: || Box::new(IndirectCommandsLayoutCreateInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::IndirectCommandsLayoutTokenEXT,
+        name: "VkIndirectCommandsLayoutTokenEXT",
+        extends: &[],
+        create: || Box::new(IndirectCommandsLayoutTokenEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::IndirectExecutionSetShaderLayoutInfoEXT,
+        name: "VkIndirectExecutionSetShaderLayoutInfoEXT",
+        extends: &[],
+        create: || Box::new(IndirectExecutionSetShaderLayoutInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::GeneratedCommandsPipelineInfoEXT,
+        name: "VkGeneratedCommandsPipelineInfoEXT",
+        extends: &[
+            StructureType::GeneratedCommandsInfoEXT,
+            StructureType::GeneratedCommandsMemoryRequirementsInfoEXT,
+        ],
+        create: || Box::new(GeneratedCommandsPipelineInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::GeneratedCommandsShaderInfoEXT,
+        name: "VkGeneratedCommandsShaderInfoEXT",
+        extends: &[
+            StructureType::GeneratedCommandsInfoEXT,
+            StructureType::GeneratedCommandsMemoryRequirementsInfoEXT,
+        ],
+        create: || Box::new(GeneratedCommandsShaderInfoEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::WriteIndirectExecutionSetShaderEXT,
+        name: "VkWriteIndirectExecutionSetShaderEXT",
+        extends: &[],
+        create: || Box::new(WriteIndirectExecutionSetShaderEXT::default()),
+    },
+    #[cfg(feature = "vendor-mesa")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageAlignmentControlFeaturesMESA,
+        name: "VkPhysicalDeviceImageAlignmentControlFeaturesMESA",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceImageAlignmentControlFeaturesMESA::default()),
+    },
+    #[cfg(feature = "vendor-mesa")]
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceImageAlignmentControlPropertiesMESA,
+        name: "VkPhysicalDeviceImageAlignmentControlPropertiesMESA",
+        extends: &[StructureType::PhysicalDeviceProperties2],
+        create: || Box::new(PhysicalDeviceImageAlignmentControlPropertiesMESA::default()),
+    },
+    #[cfg(feature = "vendor-mesa")]
+    StructureInfo {
+        structure_type: StructureType::ImageAlignmentControlCreateInfoMESA,
+        name: "VkImageAlignmentControlCreateInfoMESA",
+        extends: &[StructureType::ImageCreateInfo],
+        create: || Box::new(ImageAlignmentControlCreateInfoMESA::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PhysicalDeviceDepthClampControlFeaturesEXT,
+        name: "VkPhysicalDeviceDepthClampControlFeaturesEXT",
+        extends: &[
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::DeviceCreateInfo,
+        ],
+        create: || Box::new(PhysicalDeviceDepthClampControlFeaturesEXT::default()),
+    },
+    StructureInfo {
+        structure_type: StructureType::PipelineViewportDepthClampControlCreateInfoEXT,
+        name: "VkPipelineViewportDepthClampControlCreateInfoEXT",
+        extends: &[StructureType::PipelineViewportStateCreateInfo],
+        create: || Box::new(PipelineViewportDepthClampControlCreateInfoEXT::default()),
+    },
+];
@@ -0,0 +1,719 @@
+use crate::vk::Status;
+#[cfg(feature = "status-codes")]
+use crate::vk::SuccessCode;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkQueueSubmit`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueueSubmitError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type QueueSubmitError = Status;
+#[cfg(feature = "status-codes")]
+impl From<QueueSubmitError> for Status {
+    fn from(value: QueueSubmitError) -> Status {
+        match value {
+            QueueSubmitError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            QueueSubmitError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            QueueSubmitError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            QueueSubmitError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for QueueSubmitError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => QueueSubmitError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => QueueSubmitError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => QueueSubmitError::ErrorDeviceLost,
+            status => QueueSubmitError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for QueueSubmitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for QueueSubmitError {}
+#[doc = "Result returned by `vkQueueSubmit`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type QueueSubmitResult<T> = core::result::Result<T, QueueSubmitError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkQueueWaitIdle`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueueWaitIdleError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type QueueWaitIdleError = Status;
+#[cfg(feature = "status-codes")]
+impl From<QueueWaitIdleError> for Status {
+    fn from(value: QueueWaitIdleError) -> Status {
+        match value {
+            QueueWaitIdleError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            QueueWaitIdleError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            QueueWaitIdleError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            QueueWaitIdleError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for QueueWaitIdleError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => QueueWaitIdleError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => QueueWaitIdleError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => QueueWaitIdleError::ErrorDeviceLost,
+            status => QueueWaitIdleError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for QueueWaitIdleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for QueueWaitIdleError {}
+#[doc = "Result returned by `vkQueueWaitIdle`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type QueueWaitIdleResult<T> = core::result::Result<T, QueueWaitIdleError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkDeviceWaitIdle`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceWaitIdleError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type DeviceWaitIdleError = Status;
+#[cfg(feature = "status-codes")]
+impl From<DeviceWaitIdleError> for Status {
+    fn from(value: DeviceWaitIdleError) -> Status {
+        match value {
+            DeviceWaitIdleError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            DeviceWaitIdleError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            DeviceWaitIdleError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            DeviceWaitIdleError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for DeviceWaitIdleError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => DeviceWaitIdleError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => DeviceWaitIdleError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => DeviceWaitIdleError::ErrorDeviceLost,
+            status => DeviceWaitIdleError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for DeviceWaitIdleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for DeviceWaitIdleError {}
+#[doc = "Result returned by `vkDeviceWaitIdle`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type DeviceWaitIdleResult<T> = core::result::Result<T, DeviceWaitIdleError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Success codes which can be returned by `vkGetFenceStatus`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetFenceStatusStatus {
+    Success,
+    NotReady,
+}
+#[cfg(not(feature = "status-codes"))]
+pub type GetFenceStatusStatus = Status;
+#[cfg(feature = "status-codes")]
+impl From<GetFenceStatusStatus> for Status {
+    fn from(value: GetFenceStatusStatus) -> Status {
+        match value {
+            GetFenceStatusStatus::Success => Status::Success,
+            GetFenceStatusStatus::NotReady => Status::NotReady,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl SuccessCode for GetFenceStatusStatus {
+    fn from_status(status: Status) -> Option<Self> {
+        match status {
+            Status::Success => Some(GetFenceStatusStatus::Success),
+            Status::NotReady => Some(GetFenceStatusStatus::NotReady),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkGetFenceStatus`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetFenceStatusError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type GetFenceStatusError = Status;
+#[cfg(feature = "status-codes")]
+impl From<GetFenceStatusError> for Status {
+    fn from(value: GetFenceStatusError) -> Status {
+        match value {
+            GetFenceStatusError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            GetFenceStatusError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            GetFenceStatusError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            GetFenceStatusError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for GetFenceStatusError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => GetFenceStatusError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => GetFenceStatusError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => GetFenceStatusError::ErrorDeviceLost,
+            status => GetFenceStatusError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for GetFenceStatusError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for GetFenceStatusError {}
+#[doc = "Result returned by `vkGetFenceStatus`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type GetFenceStatusResult<T> = core::result::Result<T, GetFenceStatusError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Success codes which can be returned by `vkWaitForFences`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitForFencesStatus {
+    Success,
+    Timeout,
+}
+#[cfg(not(feature = "status-codes"))]
+pub type WaitForFencesStatus = Status;
+#[cfg(feature = "status-codes")]
+impl From<WaitForFencesStatus> for Status {
+    fn from(value: WaitForFencesStatus) -> Status {
+        match value {
+            WaitForFencesStatus::Success => Status::Success,
+            WaitForFencesStatus::Timeout => Status::Timeout,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl SuccessCode for WaitForFencesStatus {
+    fn from_status(status: Status) -> Option<Self> {
+        match status {
+            Status::Success => Some(WaitForFencesStatus::Success),
+            Status::Timeout => Some(WaitForFencesStatus::Timeout),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkWaitForFences`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitForFencesError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type WaitForFencesError = Status;
+#[cfg(feature = "status-codes")]
+impl From<WaitForFencesError> for Status {
+    fn from(value: WaitForFencesError) -> Status {
+        match value {
+            WaitForFencesError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            WaitForFencesError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            WaitForFencesError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            WaitForFencesError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for WaitForFencesError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => WaitForFencesError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => WaitForFencesError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => WaitForFencesError::ErrorDeviceLost,
+            status => WaitForFencesError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for WaitForFencesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for WaitForFencesError {}
+#[doc = "Result returned by `vkWaitForFences`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type WaitForFencesResult<T> = core::result::Result<T, WaitForFencesError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Success codes which can be returned by `vkGetEventStatus`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetEventStatusStatus {
+    EventSet,
+    EventReset,
+}
+#[cfg(not(feature = "status-codes"))]
+pub type GetEventStatusStatus = Status;
+#[cfg(feature = "status-codes")]
+impl From<GetEventStatusStatus> for Status {
+    fn from(value: GetEventStatusStatus) -> Status {
+        match value {
+            GetEventStatusStatus::EventSet => Status::EventSet,
+            GetEventStatusStatus::EventReset => Status::EventReset,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl SuccessCode for GetEventStatusStatus {
+    fn from_status(status: Status) -> Option<Self> {
+        match status {
+            Status::EventSet => Some(GetEventStatusStatus::EventSet),
+            Status::EventReset => Some(GetEventStatusStatus::EventReset),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkGetEventStatus`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetEventStatusError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type GetEventStatusError = Status;
+#[cfg(feature = "status-codes")]
+impl From<GetEventStatusError> for Status {
+    fn from(value: GetEventStatusError) -> Status {
+        match value {
+            GetEventStatusError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            GetEventStatusError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            GetEventStatusError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            GetEventStatusError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for GetEventStatusError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => GetEventStatusError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => GetEventStatusError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => GetEventStatusError::ErrorDeviceLost,
+            status => GetEventStatusError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for GetEventStatusError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for GetEventStatusError {}
+#[doc = "Result returned by `vkGetEventStatus`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type GetEventStatusResult<T> = core::result::Result<T, GetEventStatusError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Success codes which can be returned by `vkWaitSemaphores`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitSemaphoresStatus {
+    Success,
+    Timeout,
+}
+#[cfg(not(feature = "status-codes"))]
+pub type WaitSemaphoresStatus = Status;
+pub type WaitSemaphoresKHRStatus = WaitSemaphoresStatus;
+#[cfg(feature = "status-codes")]
+impl From<WaitSemaphoresStatus> for Status {
+    fn from(value: WaitSemaphoresStatus) -> Status {
+        match value {
+            WaitSemaphoresStatus::Success => Status::Success,
+            WaitSemaphoresStatus::Timeout => Status::Timeout,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl SuccessCode for WaitSemaphoresStatus {
+    fn from_status(status: Status) -> Option<Self> {
+        match status {
+            Status::Success => Some(WaitSemaphoresStatus::Success),
+            Status::Timeout => Some(WaitSemaphoresStatus::Timeout),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkWaitSemaphores`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitSemaphoresError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type WaitSemaphoresError = Status;
+pub type WaitSemaphoresKHRError = WaitSemaphoresError;
+#[cfg(feature = "status-codes")]
+impl From<WaitSemaphoresError> for Status {
+    fn from(value: WaitSemaphoresError) -> Status {
+        match value {
+            WaitSemaphoresError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            WaitSemaphoresError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            WaitSemaphoresError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            WaitSemaphoresError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for WaitSemaphoresError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => WaitSemaphoresError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => WaitSemaphoresError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => WaitSemaphoresError::ErrorDeviceLost,
+            status => WaitSemaphoresError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for WaitSemaphoresError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for WaitSemaphoresError {}
+#[doc = "Result returned by `vkWaitSemaphores`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type WaitSemaphoresResult<T> = core::result::Result<T, WaitSemaphoresError>;
+pub type WaitSemaphoresKHRResult<T> = WaitSemaphoresResult<T>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkQueueSubmit2`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueueSubmit2Error {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type QueueSubmit2Error = Status;
+pub type QueueSubmit2KHRError = QueueSubmit2Error;
+#[cfg(feature = "status-codes")]
+impl From<QueueSubmit2Error> for Status {
+    fn from(value: QueueSubmit2Error) -> Status {
+        match value {
+            QueueSubmit2Error::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            QueueSubmit2Error::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            QueueSubmit2Error::ErrorDeviceLost => Status::ErrorDeviceLost,
+            QueueSubmit2Error::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for QueueSubmit2Error {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => QueueSubmit2Error::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => QueueSubmit2Error::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => QueueSubmit2Error::ErrorDeviceLost,
+            status => QueueSubmit2Error::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for QueueSubmit2Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for QueueSubmit2Error {}
+#[doc = "Result returned by `vkQueueSubmit2`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type QueueSubmit2Result<T> = core::result::Result<T, QueueSubmit2Error>;
+pub type QueueSubmit2KHRResult<T> = QueueSubmit2Result<T>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkCreateSwapchainKHR`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateSwapchainKHRError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    ErrorSurfaceLostKHR,
+    ErrorNativeWindowInUseKHR,
+    ErrorInitializationFailed,
+    ErrorCompressionExhaustedEXT,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type CreateSwapchainKHRError = Status;
+#[cfg(feature = "status-codes")]
+impl From<CreateSwapchainKHRError> for Status {
+    fn from(value: CreateSwapchainKHRError) -> Status {
+        match value {
+            CreateSwapchainKHRError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            CreateSwapchainKHRError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            CreateSwapchainKHRError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            CreateSwapchainKHRError::ErrorSurfaceLostKHR => Status::ErrorSurfaceLostKHR,
+            CreateSwapchainKHRError::ErrorNativeWindowInUseKHR => Status::ErrorNativeWindowInUseKHR,
+            CreateSwapchainKHRError::ErrorInitializationFailed => Status::ErrorInitializationFailed,
+            CreateSwapchainKHRError::ErrorCompressionExhaustedEXT => {
+                Status::ErrorCompressionExhaustedEXT
+            }
+            CreateSwapchainKHRError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for CreateSwapchainKHRError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => CreateSwapchainKHRError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => CreateSwapchainKHRError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => CreateSwapchainKHRError::ErrorDeviceLost,
+            Status::ErrorSurfaceLostKHR => CreateSwapchainKHRError::ErrorSurfaceLostKHR,
+            Status::ErrorNativeWindowInUseKHR => CreateSwapchainKHRError::ErrorNativeWindowInUseKHR,
+            Status::ErrorInitializationFailed => CreateSwapchainKHRError::ErrorInitializationFailed,
+            Status::ErrorCompressionExhaustedEXT => {
+                CreateSwapchainKHRError::ErrorCompressionExhaustedEXT
+            }
+            status => CreateSwapchainKHRError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for CreateSwapchainKHRError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for CreateSwapchainKHRError {}
+#[doc = "Result returned by `vkCreateSwapchainKHR`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type CreateSwapchainKHRResult<T> = core::result::Result<T, CreateSwapchainKHRError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Success codes which can be returned by `vkAcquireNextImageKHR`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcquireNextImageKHRStatus {
+    Success,
+    Timeout,
+    NotReady,
+    SuboptimalKHR,
+}
+#[cfg(not(feature = "status-codes"))]
+pub type AcquireNextImageKHRStatus = Status;
+#[cfg(feature = "status-codes")]
+impl From<AcquireNextImageKHRStatus> for Status {
+    fn from(value: AcquireNextImageKHRStatus) -> Status {
+        match value {
+            AcquireNextImageKHRStatus::Success => Status::Success,
+            AcquireNextImageKHRStatus::Timeout => Status::Timeout,
+            AcquireNextImageKHRStatus::NotReady => Status::NotReady,
+            AcquireNextImageKHRStatus::SuboptimalKHR => Status::SuboptimalKHR,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl SuccessCode for AcquireNextImageKHRStatus {
+    fn from_status(status: Status) -> Option<Self> {
+        match status {
+            Status::Success => Some(AcquireNextImageKHRStatus::Success),
+            Status::Timeout => Some(AcquireNextImageKHRStatus::Timeout),
+            Status::NotReady => Some(AcquireNextImageKHRStatus::NotReady),
+            Status::SuboptimalKHR => Some(AcquireNextImageKHRStatus::SuboptimalKHR),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkAcquireNextImageKHR`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcquireNextImageKHRError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    ErrorOutOfDateKHR,
+    ErrorSurfaceLostKHR,
+    ErrorFullScreenExclusiveModeLostEXT,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type AcquireNextImageKHRError = Status;
+#[cfg(feature = "status-codes")]
+impl From<AcquireNextImageKHRError> for Status {
+    fn from(value: AcquireNextImageKHRError) -> Status {
+        match value {
+            AcquireNextImageKHRError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            AcquireNextImageKHRError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            AcquireNextImageKHRError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            AcquireNextImageKHRError::ErrorOutOfDateKHR => Status::ErrorOutOfDateKHR,
+            AcquireNextImageKHRError::ErrorSurfaceLostKHR => Status::ErrorSurfaceLostKHR,
+            AcquireNextImageKHRError::ErrorFullScreenExclusiveModeLostEXT => {
+                Status::ErrorFullScreenExclusiveModeLostEXT
+            }
+            AcquireNextImageKHRError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for AcquireNextImageKHRError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => AcquireNextImageKHRError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => AcquireNextImageKHRError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => AcquireNextImageKHRError::ErrorDeviceLost,
+            Status::ErrorOutOfDateKHR => AcquireNextImageKHRError::ErrorOutOfDateKHR,
+            Status::ErrorSurfaceLostKHR => AcquireNextImageKHRError::ErrorSurfaceLostKHR,
+            Status::ErrorFullScreenExclusiveModeLostEXT => {
+                AcquireNextImageKHRError::ErrorFullScreenExclusiveModeLostEXT
+            }
+            status => AcquireNextImageKHRError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for AcquireNextImageKHRError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for AcquireNextImageKHRError {}
+#[doc = "Result returned by `vkAcquireNextImageKHR`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type AcquireNextImageKHRResult<T> = core::result::Result<T, AcquireNextImageKHRError>;
+
+#[cfg(feature = "status-codes")]
+#[doc = "Success codes which can be returned by `vkQueuePresentKHR`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueuePresentKHRStatus {
+    Success,
+    SuboptimalKHR,
+}
+#[cfg(not(feature = "status-codes"))]
+pub type QueuePresentKHRStatus = Status;
+#[cfg(feature = "status-codes")]
+impl From<QueuePresentKHRStatus> for Status {
+    fn from(value: QueuePresentKHRStatus) -> Status {
+        match value {
+            QueuePresentKHRStatus::Success => Status::Success,
+            QueuePresentKHRStatus::SuboptimalKHR => Status::SuboptimalKHR,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl SuccessCode for QueuePresentKHRStatus {
+    fn from_status(status: Status) -> Option<Self> {
+        match status {
+            Status::Success => Some(QueuePresentKHRStatus::Success),
+            Status::SuboptimalKHR => Some(QueuePresentKHRStatus::SuboptimalKHR),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+#[doc = "Error codes which can be returned by `vkQueuePresentKHR`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueuePresentKHRError {
+    ErrorOutOfHostMemory,
+    ErrorOutOfDeviceMemory,
+    ErrorDeviceLost,
+    ErrorOutOfDateKHR,
+    ErrorSurfaceLostKHR,
+    ErrorFullScreenExclusiveModeLostEXT,
+    #[doc = r" Code not listed by the specification for this command, which can be returned by a newer driver or layer"]
+    Other(Status),
+}
+#[cfg(not(feature = "status-codes"))]
+pub type QueuePresentKHRError = Status;
+#[cfg(feature = "status-codes")]
+impl From<QueuePresentKHRError> for Status {
+    fn from(value: QueuePresentKHRError) -> Status {
+        match value {
+            QueuePresentKHRError::ErrorOutOfHostMemory => Status::ErrorOutOfHostMemory,
+            QueuePresentKHRError::ErrorOutOfDeviceMemory => Status::ErrorOutOfDeviceMemory,
+            QueuePresentKHRError::ErrorDeviceLost => Status::ErrorDeviceLost,
+            QueuePresentKHRError::ErrorOutOfDateKHR => Status::ErrorOutOfDateKHR,
+            QueuePresentKHRError::ErrorSurfaceLostKHR => Status::ErrorSurfaceLostKHR,
+            QueuePresentKHRError::ErrorFullScreenExclusiveModeLostEXT => {
+                Status::ErrorFullScreenExclusiveModeLostEXT
+            }
+            QueuePresentKHRError::Other(status) => status,
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl From<Status> for QueuePresentKHRError {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::ErrorOutOfHostMemory => QueuePresentKHRError::ErrorOutOfHostMemory,
+            Status::ErrorOutOfDeviceMemory => QueuePresentKHRError::ErrorOutOfDeviceMemory,
+            Status::ErrorDeviceLost => QueuePresentKHRError::ErrorDeviceLost,
+            Status::ErrorOutOfDateKHR => QueuePresentKHRError::ErrorOutOfDateKHR,
+            Status::ErrorSurfaceLostKHR => QueuePresentKHRError::ErrorSurfaceLostKHR,
+            Status::ErrorFullScreenExclusiveModeLostEXT => {
+                QueuePresentKHRError::ErrorFullScreenExclusiveModeLostEXT
+            }
+            status => QueuePresentKHRError::Other(status),
+        }
+    }
+}
+#[cfg(feature = "status-codes")]
+impl core::fmt::Display for QueuePresentKHRError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Status::from(*self), f)
+    }
+}
+#[cfg(feature = "status-codes")]
+impl std::error::Error for QueuePresentKHRError {}
+#[doc = "Result returned by `vkQueuePresentKHR`, its error is only narrowed when the `status-codes` feature is enabled"]
+pub type QueuePresentKHRResult<T> = core::result::Result<T, QueuePresentKHRError>;
@@ -1,6 +1,7 @@
 //! Some custom Vulkan types which are implemented as Quality-Of-Life improvements over the existing ones
 use std::ffi::c_char;
 use std::ffi::{c_void, CStr};
+use std::marker::PhantomData;
 
 use crate::vk;
 
@@ -10,6 +11,7 @@ pub(crate) type FuncPtr = *const ();
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkBool32.html>
 ///
 /// According to the Vulkan specification:
@@ -49,6 +51,11 @@ impl From<Bool32> for bool {
 
 /// API Version used by Vulkan
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 #[repr(transparent)]
 pub struct ApiVersion(u32);
 
@@ -119,6 +126,44 @@ impl std::fmt::Debug for ApiVersion {
     }
 }
 
+/// Null-terminated string with the same layout as a `const char*`
+///
+/// `&CStr` is a fat pointer, this type is used instead for arrays of strings,
+/// for example [vk::InstanceCreateInfo::enabled_layer]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct CStrPtr<'a>(*const c_char, PhantomData<&'a CStr>);
+
+impl<'a> CStrPtr<'a> {
+    pub const fn new(value: &'a CStr) -> Self {
+        Self(value.as_ptr(), PhantomData)
+    }
+
+    pub fn get(&self) -> &'a CStr {
+        unsafe { CStr::from_ptr(self.0) }
+    }
+}
+
+impl<'a> From<&'a CStr> for CStrPtr<'a> {
+    fn from(value: &'a CStr) -> Self {
+        Self::new(value)
+    }
+}
+
+impl PartialEq for CStrPtr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for CStrPtr<'_> {}
+
+impl std::fmt::Debug for CStrPtr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
 macro_rules! extension_name_decl {
     ($name:ident) => {
         #[derive(Clone, Copy)]
@@ -2,7 +2,9 @@ use crate::vk::raw::*;
 use crate::vk::*;
 use crate::*;
 use std::cell::Cell;
-use std::ffi::{c_char, c_int, c_void};
+// c_ulong is only used by platform commands
+#[allow(unused_imports)]
+use std::ffi::{c_char, c_int, c_ulong, c_void};
 use std::mem;
 #[derive(Default, Clone)]
 pub struct CommandsDispatcher {
@@ -1402,6 +1404,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-amd")]
     pub cmd_draw_indirect_count_amd: Cell<
         Option<
             unsafe extern "system" fn(
@@ -1441,6 +1444,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-amd")]
     pub cmd_draw_indexed_indirect_count_amd: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2165,6 +2169,14 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-xlib",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub create_xlib_surface_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2175,16 +2187,32 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-xlib",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub get_physical_device_xlib_presentation_support_khr: Cell<
         Option<
             unsafe extern "system" fn(
                 Option<PhysicalDevice>,
                 u32,
                 *const VoidPtr,
-                VoidPtr,
+                c_ulong,
             ) -> Bool32,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-xcb",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub create_xcb_surface_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2195,16 +2223,27 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-xcb",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub get_physical_device_xcb_presentation_support_khr: Cell<
         Option<
-            unsafe extern "system" fn(
-                Option<PhysicalDevice>,
-                u32,
-                *const VoidPtr,
-                VoidPtr,
-            ) -> Bool32,
+            unsafe extern "system" fn(Option<PhysicalDevice>, u32, *const VoidPtr, u32) -> Bool32,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-wayland",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub create_wayland_surface_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2215,9 +2254,18 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-wayland",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub get_physical_device_wayland_presentation_support_khr: Cell<
         Option<unsafe extern "system" fn(Option<PhysicalDevice>, u32, *const VoidPtr) -> Bool32>,
     >,
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
     pub create_android_surface_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2228,6 +2276,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub create_win32_surface_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2238,6 +2287,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_physical_device_win32_presentation_support_khr:
         Cell<Option<unsafe extern "system" fn(Option<PhysicalDevice>, u32) -> Bool32>>,
     pub create_debug_report_callback_ext: Cell<
@@ -2293,6 +2343,103 @@ pub struct CommandsDispatcher {
     pub cmd_debug_marker_insert_ext: Cell<
         Option<unsafe extern "system" fn(Option<CommandBuffer>, *const DebugMarkerMarkerInfoEXT)>,
     >,
+    pub get_physical_device_video_capabilities_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<PhysicalDevice>,
+                *const VideoProfileInfoKHR,
+                *const VideoCapabilitiesKHR,
+            ) -> Status,
+        >,
+    >,
+    pub get_physical_device_video_format_properties_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<PhysicalDevice>,
+                *const PhysicalDeviceVideoFormatInfoKHR,
+                *const u32,
+                *const VideoFormatPropertiesKHR,
+            ) -> Status,
+        >,
+    >,
+    pub create_video_session_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                *const VideoSessionCreateInfoKHR,
+                *const AllocationCallbacks,
+                *const VideoSessionKHR,
+            ) -> Status,
+        >,
+    >,
+    pub destroy_video_session_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                Option<VideoSessionKHR>,
+                *const AllocationCallbacks,
+            ),
+        >,
+    >,
+    pub get_video_session_memory_requirements_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                Option<VideoSessionKHR>,
+                *const u32,
+                *const VideoSessionMemoryRequirementsKHR,
+            ) -> Status,
+        >,
+    >,
+    pub bind_video_session_memory_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                Option<VideoSessionKHR>,
+                u32,
+                *const BindVideoSessionMemoryInfoKHR,
+            ) -> Status,
+        >,
+    >,
+    pub create_video_session_parameters_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                *const VideoSessionParametersCreateInfoKHR,
+                *const AllocationCallbacks,
+                *const VideoSessionParametersKHR,
+            ) -> Status,
+        >,
+    >,
+    pub update_video_session_parameters_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                Option<VideoSessionParametersKHR>,
+                *const VideoSessionParametersUpdateInfoKHR,
+            ) -> Status,
+        >,
+    >,
+    pub destroy_video_session_parameters_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                Option<VideoSessionParametersKHR>,
+                *const AllocationCallbacks,
+            ),
+        >,
+    >,
+    pub cmd_begin_video_coding_khr: Cell<
+        Option<unsafe extern "system" fn(Option<CommandBuffer>, *const VideoBeginCodingInfoKHR)>,
+    >,
+    pub cmd_end_video_coding_khr: Cell<
+        Option<unsafe extern "system" fn(Option<CommandBuffer>, *const VideoEndCodingInfoKHR)>,
+    >,
+    pub cmd_control_video_coding_khr: Cell<
+        Option<unsafe extern "system" fn(Option<CommandBuffer>, *const VideoCodingControlInfoKHR)>,
+    >,
+    pub cmd_decode_video_khr:
+        Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, *const VideoDecodeInfoKHR)>>,
     pub cmd_bind_transform_feedback_buffers_ext: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2353,6 +2500,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nvx")]
     pub create_cu_module_nvx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2363,6 +2511,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nvx")]
     pub create_cu_function_nvx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2373,6 +2522,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nvx")]
     pub destroy_cu_module_nvx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2382,6 +2532,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nvx")]
     pub destroy_cu_function_nvx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2391,11 +2542,14 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nvx")]
     pub cmd_cu_launch_kernel_nvx:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, *const CuLaunchInfoNVX)>>,
+    #[cfg(feature = "vendor-nvx")]
     pub get_image_view_handle_nvx: Cell<
         Option<unsafe extern "system" fn(Option<Device>, *const ImageViewHandleInfoNVX) -> u32>,
     >,
+    #[cfg(feature = "vendor-nvx")]
     pub get_image_view_address_nvx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2405,6 +2559,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-amd")]
     pub get_shader_info_amd: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2417,6 +2572,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "platform-ggp")]
     pub create_stream_descriptor_surface_ggp: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2427,6 +2583,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_physical_device_external_image_format_properties_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2441,6 +2598,10 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
     pub get_memory_win32_handle_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2451,6 +2612,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "platform-vi")]
     pub create_vi_surface_nn: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2461,6 +2623,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_memory_win32_handle_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2470,6 +2633,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_memory_win32_handle_properties_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2499,6 +2663,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub import_semaphore_win32_handle_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2507,6 +2672,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_semaphore_win32_handle_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2563,6 +2729,7 @@ pub struct CommandsDispatcher {
     >,
     pub cmd_end_conditional_rendering_ext:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_viewport_wscaling_nv: Cell<
         Option<
             unsafe extern "system" fn(Option<CommandBuffer>, u32, u32, *const ViewportWScalingNV),
@@ -2571,6 +2738,14 @@ pub struct CommandsDispatcher {
     pub release_display_ext: Cell<
         Option<unsafe extern "system" fn(Option<PhysicalDevice>, Option<DisplayKHR>) -> Status>,
     >,
+    #[cfg(any(
+        feature = "platform-xlib-xrandr",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub acquire_xlib_display_ext: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2580,12 +2755,20 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(
+        feature = "platform-xlib-xrandr",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     pub get_rand_routput_display_ext: Cell<
         Option<
             unsafe extern "system" fn(
                 Option<PhysicalDevice>,
                 *const VoidPtr,
-                VoidPtr,
+                c_ulong,
                 *const DisplayKHR,
             ) -> Status,
         >,
@@ -2639,6 +2822,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-google")]
     pub get_refresh_cycle_duration_google: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2648,6 +2832,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-google")]
     pub get_past_presentation_timing_google: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2676,6 +2861,7 @@ pub struct CommandsDispatcher {
     >,
     pub get_swapchain_status_khr:
         Cell<Option<unsafe extern "system" fn(Option<Device>, Option<SwapchainKHR>) -> Status>>,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub import_fence_win32_handle_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2684,6 +2870,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_fence_win32_handle_khr: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2787,6 +2974,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-ios", target_os = "ios"))]
     pub create_iossurface_mvk: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2797,6 +2985,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-macos", target_os = "macos"))]
     pub create_mac_ossurface_mvk: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2857,6 +3046,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
     pub get_android_hardware_buffer_properties_android: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2866,6 +3056,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
     pub get_memory_android_hardware_buffer_android: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2875,6 +3066,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub create_execution_graph_pipelines_amdx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2887,6 +3079,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub get_execution_graph_pipeline_scratch_size_amdx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2896,6 +3089,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub get_execution_graph_pipeline_node_index_amdx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2906,8 +3100,10 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub cmd_initialize_graph_scratch_memory_amdx:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, DeviceAddress)>>,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub cmd_dispatch_graph_amdx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2917,6 +3113,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub cmd_dispatch_graph_indirect_amdx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -2926,6 +3123,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     pub cmd_dispatch_graph_indirect_count_amdx: Cell<
         Option<unsafe extern "system" fn(Option<CommandBuffer>, DeviceAddress, DeviceAddress)>,
     >,
@@ -3136,6 +3334,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_ray_tracing_shader_group_handles_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3232,14 +3431,17 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_bind_shading_rate_image_nv: Cell<
         Option<unsafe extern "system" fn(Option<CommandBuffer>, Option<ImageView>, ImageLayout)>,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_viewport_shading_rate_palette_nv: Cell<
         Option<
             unsafe extern "system" fn(Option<CommandBuffer>, u32, u32, *const ShadingRatePaletteNV),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coarse_sample_order_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3250,6 +3452,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub create_acceleration_structure_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3260,6 +3463,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub destroy_acceleration_structure_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3269,6 +3473,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_acceleration_structure_memory_requirements_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3278,6 +3483,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub bind_acceleration_structure_memory_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3287,6 +3493,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_build_acceleration_structure_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3302,6 +3509,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_copy_acceleration_structure_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3312,6 +3520,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_trace_rays_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3333,6 +3542,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub create_ray_tracing_pipelines_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3345,6 +3555,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_acceleration_structure_handle_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3355,6 +3566,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_write_acceleration_structures_properties_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3367,6 +3579,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub compile_deferred_nv:
         Cell<Option<unsafe extern "system" fn(Option<Device>, Option<Pipeline>, u32) -> Status>>,
     pub get_memory_host_pointer_properties_ext: Cell<
@@ -3379,6 +3592,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-amd")]
     pub cmd_write_buffer_marker_amd: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3390,13 +3604,16 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_draw_mesh_tasks_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32, u32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_draw_mesh_tasks_indirect_nv: Cell<
         Option<
             unsafe extern "system" fn(Option<CommandBuffer>, Option<Buffer>, DeviceSize, u32, u32),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_draw_mesh_tasks_indirect_count_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3410,14 +3627,19 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_exclusive_scissor_enable_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32, u32, *const Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_exclusive_scissor_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32, u32, *const Rect2D)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_checkpoint_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, VoidPtr)>>,
+    #[cfg(feature = "vendor-nv")]
     pub get_queue_checkpoint_data_nv:
         Cell<Option<unsafe extern "system" fn(Option<Queue>, *const u32, *const CheckpointDataNV)>>,
+    #[cfg(feature = "vendor-intel")]
     pub initialize_performance_api_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3426,7 +3648,9 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub uninitialize_performance_api_intel: Cell<Option<unsafe extern "system" fn(Option<Device>)>>,
+    #[cfg(feature = "vendor-intel")]
     pub cmd_set_performance_marker_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3435,6 +3659,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub cmd_set_performance_stream_marker_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3443,6 +3668,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub cmd_set_performance_override_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3451,6 +3677,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub acquire_performance_configuration_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3460,6 +3687,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub release_performance_configuration_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3468,6 +3696,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub queue_set_performance_configuration_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3476,6 +3705,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-intel")]
     pub get_performance_parameter_intel: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3485,8 +3715,10 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-amd")]
     pub set_local_dimming_amd:
         Cell<Option<unsafe extern "system" fn(Option<Device>, Option<SwapchainKHR>, Bool32)>>,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub create_image_pipe_surface_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3497,6 +3729,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
     pub create_metal_surface_ext: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3544,6 +3777,7 @@ pub struct CommandsDispatcher {
     pub wait_for_present_khr: Cell<
         Option<unsafe extern "system" fn(Option<Device>, Option<SwapchainKHR>, u64, u64) -> Status>,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_physical_device_cooperative_matrix_properties_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3553,6 +3787,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_physical_device_supported_framebuffer_mixed_samples_combinations_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3562,6 +3797,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_physical_device_surface_present_modes2_ext: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3572,10 +3808,13 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub acquire_full_screen_exclusive_mode_ext:
         Cell<Option<unsafe extern "system" fn(Option<Device>, Option<SwapchainKHR>) -> Status>>,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub release_full_screen_exclusive_mode_ext:
         Cell<Option<unsafe extern "system" fn(Option<Device>, Option<SwapchainKHR>) -> Status>>,
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     pub get_device_group_surface_present_modes2_ext: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3694,6 +3933,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_generated_commands_memory_requirements_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3703,9 +3943,11 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_preprocess_generated_commands_nv: Cell<
         Option<unsafe extern "system" fn(Option<CommandBuffer>, *const GeneratedCommandsInfoNV)>,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_execute_generated_commands_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3715,6 +3957,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_bind_pipeline_shader_group_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3725,6 +3968,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub create_indirect_commands_layout_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3735,6 +3979,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub destroy_indirect_commands_layout_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3761,6 +4006,29 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    pub cmd_encode_video_khr:
+        Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, *const VideoEncodeInfoKHR)>>,
+    pub get_physical_device_video_encode_quality_level_properties_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<PhysicalDevice>,
+                *const PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+                *const VideoEncodeQualityLevelPropertiesKHR,
+            ) -> Status,
+        >,
+    >,
+    pub get_encoded_video_session_parameters_khr: Cell<
+        Option<
+            unsafe extern "system" fn(
+                Option<Device>,
+                *const VideoEncodeSessionParametersGetInfoKHR,
+                *const VideoEncodeSessionParametersFeedbackInfoKHR,
+                *const usize,
+                VoidPtr,
+            ) -> Status,
+        >,
+    >,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     pub create_cuda_module_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3771,6 +4039,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     pub get_cuda_module_cache_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3781,6 +4050,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     pub create_cuda_function_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3791,6 +4061,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     pub destroy_cuda_module_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3800,6 +4071,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     pub destroy_cuda_function_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3809,10 +4081,13 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     pub cmd_cuda_launch_kernel_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, *const CudaLaunchInfoNV)>>,
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
     pub export_metal_objects_ext:
         Cell<Option<unsafe extern "system" fn(Option<Device>, *const ExportMetalObjectsInfoEXT)>>,
+    #[cfg(feature = "vendor-amd")]
     pub cmd_write_buffer_marker2_amd: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3824,6 +4099,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_queue_checkpoint_data2_nv: Cell<
         Option<unsafe extern "system" fn(Option<Queue>, *const u32, *const CheckpointData2NV)>,
     >,
@@ -3928,6 +4204,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_fragment_shading_rate_enum_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3966,12 +4243,21 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
     pub acquire_winrt_display_nv: Cell<
         Option<unsafe extern "system" fn(Option<PhysicalDevice>, Option<DisplayKHR>) -> Status>,
     >,
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
     pub get_winrt_display_nv: Cell<
         Option<unsafe extern "system" fn(Option<PhysicalDevice>, u32, *const DisplayKHR) -> Status>,
     >,
+    #[cfg(feature = "platform-directfb")]
     pub create_direct_fbsurface_ext: Cell<
         Option<
             unsafe extern "system" fn(
@@ -3982,6 +4268,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "platform-directfb")]
     pub get_physical_device_direct_fbpresentation_support_ext: Cell<
         Option<unsafe extern "system" fn(Option<PhysicalDevice>, u32, *const VoidPtr) -> Bool32>,
     >,
@@ -3996,25 +4283,28 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub get_memory_zircon_handle_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
                 Option<Device>,
                 *const MemoryGetZirconHandleInfoFUCHSIA,
-                *const VoidPtr,
+                *const u32,
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub get_memory_zircon_handle_properties_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
                 Option<Device>,
                 ExternalMemoryHandleTypeFlags,
-                VoidPtr,
+                u32,
                 *const MemoryZirconHandlePropertiesFUCHSIA,
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub import_semaphore_zircon_handle_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4023,15 +4313,17 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub get_semaphore_zircon_handle_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
                 Option<Device>,
                 *const SemaphoreGetZirconHandleInfoFUCHSIA,
-                *const VoidPtr,
+                *const u32,
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub create_buffer_collection_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4042,6 +4334,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub set_buffer_collection_image_constraints_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4051,6 +4344,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub set_buffer_collection_buffer_constraints_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4060,6 +4354,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub destroy_buffer_collection_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4069,6 +4364,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     pub get_buffer_collection_properties_fuchsia: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4078,6 +4374,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-huawei")]
     pub get_device_subpass_shading_max_workgroup_size_huawei: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4087,10 +4384,13 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-huawei")]
     pub cmd_subpass_shading_huawei: Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>)>>,
+    #[cfg(feature = "vendor-huawei")]
     pub cmd_bind_invocation_mask_huawei: Cell<
         Option<unsafe extern "system" fn(Option<CommandBuffer>, Option<ImageView>, ImageLayout)>,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_memory_remote_address_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4113,6 +4413,7 @@ pub struct CommandsDispatcher {
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32)>>,
     pub cmd_set_logic_op_ext:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, LogicOp)>>,
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
     pub create_screen_surface_qnx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4123,6 +4424,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
     pub get_physical_device_screen_presentation_support_qnx: Cell<
         Option<unsafe extern "system" fn(Option<PhysicalDevice>, u32, *const VoidPtr) -> Bool32>,
     >,
@@ -4270,12 +4572,15 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-huawei")]
     pub cmd_draw_cluster_huawei:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32, u32, u32)>>,
+    #[cfg(feature = "vendor-huawei")]
     pub cmd_draw_cluster_indirect_huawei:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Option<Buffer>, DeviceSize)>>,
     pub set_device_memory_priority_ext:
         Cell<Option<unsafe extern "system" fn(Option<Device>, Option<DeviceMemory>, f32)>>,
+    #[cfg(feature = "vendor-valve")]
     pub get_descriptor_set_layout_host_mapping_info_valve: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4285,13 +4590,16 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-valve")]
     pub get_descriptor_set_host_mapping_valve: Cell<
         Option<
             unsafe extern "system" fn(Option<Device>, Option<DescriptorSet>, *const *const c_void),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_copy_memory_indirect_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, DeviceAddress, u32, u32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_copy_memory_to_image_indirect_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4305,14 +4613,17 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_decompress_memory_nv: Cell<
         Option<
             unsafe extern "system" fn(Option<CommandBuffer>, u32, *const DecompressMemoryRegionNV),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_decompress_memory_indirect_count_nv: Cell<
         Option<unsafe extern "system" fn(Option<CommandBuffer>, DeviceAddress, DeviceAddress, u32)>,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_pipeline_indirect_memory_requirements_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4322,11 +4633,13 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_update_pipeline_indirect_buffer_nv: Cell<
         Option<
             unsafe extern "system" fn(Option<CommandBuffer>, PipelineBindPoint, Option<Pipeline>),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_pipeline_indirect_device_address_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4400,27 +4713,37 @@ pub struct CommandsDispatcher {
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
     pub cmd_set_depth_clip_negative_one_to_one_ext:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_viewport_wscaling_enable_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_viewport_swizzle_nv: Cell<
         Option<
             unsafe extern "system" fn(Option<CommandBuffer>, u32, u32, *const ViewportSwizzleNV),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coverage_to_color_enable_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coverage_to_color_location_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coverage_modulation_mode_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, CoverageModulationModeNV)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coverage_modulation_table_enable_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coverage_modulation_table_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, u32, *const f32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_shading_rate_image_enable_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_representative_fragment_test_enable_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, Bool32)>>,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_set_coverage_reduction_mode_nv:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, CoverageReductionModeNV)>>,
     pub get_shader_module_identifier_ext: Cell<
@@ -4441,6 +4764,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_physical_device_optical_flow_image_formats_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4451,6 +4775,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub create_optical_flow_session_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4461,6 +4786,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub destroy_optical_flow_session_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4470,6 +4796,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub bind_optical_flow_session_image_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4481,6 +4808,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub cmd_optical_flow_execute_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4535,6 +4863,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-amd")]
     pub anti_lag_update_amd:
         Cell<Option<unsafe extern "system" fn(Option<Device>, *const AntiLagDataAMD)>>,
     pub create_shaders_ext: Cell<
@@ -4634,6 +4963,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-qcom")]
     pub get_framebuffer_tile_properties_qcom: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4644,6 +4974,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-qcom")]
     pub get_dynamic_rendering_tile_properties_qcom: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4653,6 +4984,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub set_latency_sleep_mode_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4662,6 +4994,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub latency_sleep_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4671,6 +5004,7 @@ pub struct CommandsDispatcher {
             ) -> Status,
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub set_latency_marker_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4680,6 +5014,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub get_latency_timings_nv: Cell<
         Option<
             unsafe extern "system" fn(
@@ -4689,6 +5024,7 @@ pub struct CommandsDispatcher {
             ),
         >,
     >,
+    #[cfg(feature = "vendor-nv")]
     pub queue_notify_out_of_band_nv:
         Cell<Option<unsafe extern "system" fn(Option<Queue>, *const OutOfBandQueueTypeInfoNV)>>,
     pub get_physical_device_cooperative_matrix_properties_khr: Cell<
@@ -4702,6 +5038,7 @@ pub struct CommandsDispatcher {
     >,
     pub cmd_set_attachment_feedback_loop_enable_ext:
         Cell<Option<unsafe extern "system" fn(Option<CommandBuffer>, ImageAspectFlags)>>,
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
     pub get_screen_buffer_properties_qnx: Cell<
         Option<
             unsafe extern "system" fn(
@@ -5987,11 +6324,13 @@ impl CommandsDispatcher {
                 .get()
                 .or(self.cmd_draw_indirect_count_khr.get()),
         );
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indirect_count_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDrawIndirectCountAMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indirect_count.set(
             self.cmd_draw_indirect_count
                 .get()
@@ -6012,11 +6351,13 @@ impl CommandsDispatcher {
                 .get()
                 .or(self.cmd_draw_indexed_indirect_count_khr.get()),
         );
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indexed_indirect_count_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDrawIndexedIndirectCountAMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indexed_indirect_count.set(
             self.cmd_draw_indexed_indirect_count
                 .get()
@@ -6856,46 +7197,97 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCreateSharedSwapchainsKHR".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-xlib",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.create_xlib_surface_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateXlibSurfaceKHR".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-xlib",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.get_physical_device_xlib_presentation_support_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceXlibPresentationSupportKHR".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-xcb",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.create_xcb_surface_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateXcbSurfaceKHR".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-xcb",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.get_physical_device_xcb_presentation_support_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceXcbPresentationSupportKHR".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-wayland",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.create_wayland_surface_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateWaylandSurfaceKHR".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-wayland",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.get_physical_device_wayland_presentation_support_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceWaylandPresentationSupportKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-android", target_os = "android"))]
         self.create_android_surface_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateAndroidSurfaceKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.create_win32_surface_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateWin32SurfaceKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_physical_device_win32_presentation_support_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -6941,6 +7333,71 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdDebugMarkerInsertEXT".as_ptr(),
             )));
+        self.get_physical_device_video_capabilities_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkGetPhysicalDeviceVideoCapabilitiesKHR".as_ptr(),
+            )));
+        self.get_physical_device_video_format_properties_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkGetPhysicalDeviceVideoFormatPropertiesKHR".as_ptr(),
+            )));
+        self.create_video_session_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCreateVideoSessionKHR".as_ptr(),
+            )));
+        self.destroy_video_session_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkDestroyVideoSessionKHR".as_ptr(),
+            )));
+        self.get_video_session_memory_requirements_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkGetVideoSessionMemoryRequirementsKHR".as_ptr(),
+            )));
+        self.bind_video_session_memory_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkBindVideoSessionMemoryKHR".as_ptr(),
+            )));
+        self.create_video_session_parameters_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCreateVideoSessionParametersKHR".as_ptr(),
+            )));
+        self.update_video_session_parameters_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkUpdateVideoSessionParametersKHR".as_ptr(),
+            )));
+        self.destroy_video_session_parameters_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkDestroyVideoSessionParametersKHR".as_ptr(),
+            )));
+        self.cmd_begin_video_coding_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCmdBeginVideoCodingKHR".as_ptr(),
+            )));
+        self.cmd_end_video_coding_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCmdEndVideoCodingKHR".as_ptr(),
+            )));
+        self.cmd_control_video_coding_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCmdControlVideoCodingKHR".as_ptr(),
+            )));
+        self.cmd_decode_video_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCmdDecodeVideoKHR".as_ptr(),
+            )));
         self.cmd_bind_transform_feedback_buffers_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -6971,71 +7428,88 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdDrawIndirectByteCountEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.create_cu_module_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateCuModuleNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.create_cu_function_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateCuFunctionNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.destroy_cu_module_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyCuModuleNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.destroy_cu_function_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyCuFunctionNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.cmd_cu_launch_kernel_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdCuLaunchKernelNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.get_image_view_handle_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetImageViewHandleNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.get_image_view_address_nvx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetImageViewAddressNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.get_shader_info_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetShaderInfoAMD".as_ptr(),
             )));
+        #[cfg(feature = "platform-ggp")]
         self.create_stream_descriptor_surface_ggp
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateStreamDescriptorSurfaceGGP".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_physical_device_external_image_format_properties_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceExternalImageFormatPropertiesNV".as_ptr(),
             )));
+        #[cfg(all(
+            any(feature = "platform-win32", target_os = "windows"),
+            feature = "vendor-nv"
+        ))]
         self.get_memory_win32_handle_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetMemoryWin32HandleNV".as_ptr(),
             )));
+        #[cfg(feature = "platform-vi")]
         self.create_vi_surface_nn
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateViSurfaceNN".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_memory_win32_handle_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetMemoryWin32HandleKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_memory_win32_handle_properties_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7051,11 +7525,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetMemoryFdPropertiesKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.import_semaphore_win32_handle_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkImportSemaphoreWin32HandleKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_semaphore_win32_handle_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7091,6 +7567,7 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdEndConditionalRenderingEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_wscaling_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7101,11 +7578,27 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkReleaseDisplayEXT".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-xlib-xrandr",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.acquire_xlib_display_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkAcquireXlibDisplayEXT".as_ptr(),
             )));
+        #[cfg(any(
+            feature = "platform-xlib-xrandr",
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
         self.get_rand_routput_display_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7136,11 +7629,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetSwapchainCounterEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-google")]
         self.get_refresh_cycle_duration_google
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetRefreshCycleDurationGOOGLE".as_ptr(),
             )));
+        #[cfg(feature = "vendor-google")]
         self.get_past_presentation_timing_google
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7171,11 +7666,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetSwapchainStatusKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.import_fence_win32_handle_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkImportFenceWin32HandleKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_fence_win32_handle_khr
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7241,11 +7738,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetDisplayPlaneCapabilities2KHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-ios", target_os = "ios"))]
         self.create_iossurface_mvk
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateIOSSurfaceMVK".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-macos", target_os = "macos"))]
         self.create_mac_ossurface_mvk
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7306,46 +7805,55 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkSubmitDebugUtilsMessageEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-android", target_os = "android"))]
         self.get_android_hardware_buffer_properties_android
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetAndroidHardwareBufferPropertiesANDROID".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-android", target_os = "android"))]
         self.get_memory_android_hardware_buffer_android
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetMemoryAndroidHardwareBufferANDROID".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.create_execution_graph_pipelines_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateExecutionGraphPipelinesAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.get_execution_graph_pipeline_scratch_size_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetExecutionGraphPipelineScratchSizeAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.get_execution_graph_pipeline_node_index_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetExecutionGraphPipelineNodeIndexAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_initialize_graph_scratch_memory_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdInitializeGraphScratchMemoryAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_dispatch_graph_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDispatchGraphAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_dispatch_graph_indirect_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDispatchGraphIndirectAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_dispatch_graph_indirect_count_amdx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7456,11 +7964,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetRayTracingShaderGroupHandlesKHR".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_ray_tracing_shader_group_handles_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetRayTracingShaderGroupHandlesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_ray_tracing_shader_group_handles_khr.set(
             self.get_ray_tracing_shader_group_handles_khr
                 .get()
@@ -7511,71 +8021,85 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetValidationCacheDataEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_bind_shading_rate_image_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdBindShadingRateImageNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_shading_rate_palette_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetViewportShadingRatePaletteNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coarse_sample_order_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCoarseSampleOrderNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_acceleration_structure_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.destroy_acceleration_structure_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_acceleration_structure_memory_requirements_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetAccelerationStructureMemoryRequirementsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.bind_acceleration_structure_memory_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkBindAccelerationStructureMemoryNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_build_acceleration_structure_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdBuildAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_copy_acceleration_structure_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdCopyAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_trace_rays_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdTraceRaysNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_ray_tracing_pipelines_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateRayTracingPipelinesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_acceleration_structure_handle_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetAccelerationStructureHandleNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_write_acceleration_structures_properties_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdWriteAccelerationStructuresPropertiesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.compile_deferred_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7586,101 +8110,121 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetMemoryHostPointerPropertiesEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_write_buffer_marker_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdWriteBufferMarkerAMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_draw_mesh_tasks_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDrawMeshTasksNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_draw_mesh_tasks_indirect_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDrawMeshTasksIndirectNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_draw_mesh_tasks_indirect_count_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDrawMeshTasksIndirectCountNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_exclusive_scissor_enable_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetExclusiveScissorEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_exclusive_scissor_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetExclusiveScissorNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_checkpoint_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCheckpointNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_queue_checkpoint_data_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetQueueCheckpointDataNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.initialize_performance_api_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkInitializePerformanceApiINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.uninitialize_performance_api_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkUninitializePerformanceApiINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.cmd_set_performance_marker_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetPerformanceMarkerINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.cmd_set_performance_stream_marker_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetPerformanceStreamMarkerINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.cmd_set_performance_override_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetPerformanceOverrideINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.acquire_performance_configuration_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkAcquirePerformanceConfigurationINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.release_performance_configuration_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkReleasePerformanceConfigurationINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.queue_set_performance_configuration_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkQueueSetPerformanceConfigurationINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.get_performance_parameter_intel
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPerformanceParameterINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.set_local_dimming_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkSetLocalDimmingAMD".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.create_image_pipe_surface_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateImagePipeSurfaceFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
         self.create_metal_surface_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7711,31 +8255,37 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkWaitForPresentKHR".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_physical_device_cooperative_matrix_properties_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_physical_device_supported_framebuffer_mixed_samples_combinations_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_physical_device_surface_present_modes2_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceSurfacePresentModes2EXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.acquire_full_screen_exclusive_mode_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkAcquireFullScreenExclusiveModeEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.release_full_screen_exclusive_mode_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkReleaseFullScreenExclusiveModeEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_device_group_surface_present_modes2_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7821,31 +8371,37 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkReleaseSwapchainImagesEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_generated_commands_memory_requirements_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetGeneratedCommandsMemoryRequirementsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_preprocess_generated_commands_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdPreprocessGeneratedCommandsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_execute_generated_commands_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdExecuteGeneratedCommandsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_bind_pipeline_shader_group_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdBindPipelineShaderGroupNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_indirect_commands_layout_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateIndirectCommandsLayoutNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.destroy_indirect_commands_layout_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7866,46 +8422,70 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetDrmDisplayEXT".as_ptr(),
             )));
+        self.cmd_encode_video_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkCmdEncodeVideoKHR".as_ptr(),
+            )));
+        self.get_physical_device_video_encode_quality_level_properties_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR".as_ptr(),
+            )));
+        self.get_encoded_video_session_parameters_khr
+            .set(mem::transmute(get_instance_proc_addr(
+                get_instance(),
+                c"vkGetEncodedVideoSessionParametersKHR".as_ptr(),
+            )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.create_cuda_module_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateCudaModuleNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.get_cuda_module_cache_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetCudaModuleCacheNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.create_cuda_function_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateCudaFunctionNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.destroy_cuda_module_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyCudaModuleNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.destroy_cuda_function_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyCudaFunctionNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.cmd_cuda_launch_kernel_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdCudaLaunchKernelNV".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
         self.export_metal_objects_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkExportMetalObjectsEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_write_buffer_marker2_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdWriteBufferMarker2AMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_queue_checkpoint_data2_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7966,6 +8546,7 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_fragment_shading_rate_enum_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -7991,21 +8572,31 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetDeviceFaultInfoEXT".as_ptr(),
             )));
+        #[cfg(all(
+            any(feature = "platform-win32", target_os = "windows"),
+            feature = "vendor-nv"
+        ))]
         self.acquire_winrt_display_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkAcquireWinrtDisplayNV".as_ptr(),
             )));
+        #[cfg(all(
+            any(feature = "platform-win32", target_os = "windows"),
+            feature = "vendor-nv"
+        ))]
         self.get_winrt_display_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetWinrtDisplayNV".as_ptr(),
             )));
+        #[cfg(feature = "platform-directfb")]
         self.create_direct_fbsurface_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateDirectFBSurfaceEXT".as_ptr(),
             )));
+        #[cfg(feature = "platform-directfb")]
         self.get_physical_device_direct_fbpresentation_support_ext
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8016,66 +8607,79 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdSetVertexInputEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_memory_zircon_handle_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetMemoryZirconHandleFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_memory_zircon_handle_properties_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetMemoryZirconHandlePropertiesFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.import_semaphore_zircon_handle_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkImportSemaphoreZirconHandleFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_semaphore_zircon_handle_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetSemaphoreZirconHandleFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.create_buffer_collection_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateBufferCollectionFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.set_buffer_collection_image_constraints_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkSetBufferCollectionImageConstraintsFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.set_buffer_collection_buffer_constraints_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkSetBufferCollectionBufferConstraintsFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.destroy_buffer_collection_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyBufferCollectionFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_buffer_collection_properties_fuchsia
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetBufferCollectionPropertiesFUCHSIA".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.get_device_subpass_shading_max_workgroup_size_huawei
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_subpass_shading_huawei
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSubpassShadingHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_bind_invocation_mask_huawei
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdBindInvocationMaskHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_memory_remote_address_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8096,11 +8700,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdSetLogicOpEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-screen", target_os = "nto"))]
         self.create_screen_surface_qnx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateScreenSurfaceQNX".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-screen", target_os = "nto"))]
         self.get_physical_device_screen_presentation_support_qnx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8196,11 +8802,13 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetMicromapBuildSizesEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_draw_cluster_huawei
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDrawClusterHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_draw_cluster_indirect_huawei
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8211,46 +8819,55 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkSetDeviceMemoryPriorityEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-valve")]
         self.get_descriptor_set_layout_host_mapping_info_valve
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetDescriptorSetLayoutHostMappingInfoVALVE".as_ptr(),
             )));
+        #[cfg(feature = "vendor-valve")]
         self.get_descriptor_set_host_mapping_valve
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetDescriptorSetHostMappingVALVE".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_copy_memory_indirect_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdCopyMemoryIndirectNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_copy_memory_to_image_indirect_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdCopyMemoryToImageIndirectNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_decompress_memory_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDecompressMemoryNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_decompress_memory_indirect_count_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdDecompressMemoryIndirectCountNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_pipeline_indirect_memory_requirements_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPipelineIndirectMemoryRequirementsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_update_pipeline_indirect_buffer_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdUpdatePipelineIndirectBufferNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_pipeline_indirect_device_address_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8361,51 +8978,61 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdSetDepthClipNegativeOneToOneEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_wscaling_enable_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetViewportWScalingEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_swizzle_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetViewportSwizzleNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_to_color_enable_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCoverageToColorEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_to_color_location_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCoverageToColorLocationNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_modulation_mode_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCoverageModulationModeNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_modulation_table_enable_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCoverageModulationTableEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_modulation_table_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetCoverageModulationTableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_shading_rate_image_enable_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetShadingRateImageEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_representative_fragment_test_enable_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCmdSetRepresentativeFragmentTestEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_reduction_mode_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8421,26 +9048,31 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkGetShaderModuleCreateInfoIdentifierEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_physical_device_optical_flow_image_formats_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetPhysicalDeviceOpticalFlowImageFormatsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_optical_flow_session_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkCreateOpticalFlowSessionNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.destroy_optical_flow_session_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkDestroyOpticalFlowSessionNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.bind_optical_flow_session_image_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkBindOpticalFlowSessionImageNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_optical_flow_execute_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8476,6 +9108,7 @@ impl CommandsDispatcher {
                 .get()
                 .or(self.get_image_subresource_layout2_ext.get()),
         );
+        #[cfg(feature = "vendor-amd")]
         self.anti_lag_update_amd
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8531,36 +9164,43 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkReleaseCapturedPipelineDataKHR".as_ptr(),
             )));
+        #[cfg(feature = "vendor-qcom")]
         self.get_framebuffer_tile_properties_qcom
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetFramebufferTilePropertiesQCOM".as_ptr(),
             )));
+        #[cfg(feature = "vendor-qcom")]
         self.get_dynamic_rendering_tile_properties_qcom
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetDynamicRenderingTilePropertiesQCOM".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.set_latency_sleep_mode_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkSetLatencySleepModeNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.latency_sleep_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkLatencySleepNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.set_latency_marker_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkSetLatencyMarkerNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_latency_timings_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
                 c"vkGetLatencyTimingsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.queue_notify_out_of_band_nv
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -8576,6 +9216,7 @@ impl CommandsDispatcher {
                 get_instance(),
                 c"vkCmdSetAttachmentFeedbackLoopEnableEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-screen", target_os = "nto"))]
         self.get_screen_buffer_properties_qnx
             .set(mem::transmute(get_instance_proc_addr(
                 get_instance(),
@@ -9537,11 +10178,13 @@ impl CommandsDispatcher {
                 .get()
                 .or(self.cmd_draw_indirect_count_khr.get()),
         );
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indirect_count_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDrawIndirectCountAMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indirect_count.set(
             self.cmd_draw_indirect_count
                 .get()
@@ -9562,11 +10205,13 @@ impl CommandsDispatcher {
                 .get()
                 .or(self.cmd_draw_indexed_indirect_count_khr.get()),
         );
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indexed_indirect_count_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDrawIndexedIndirectCountAMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_draw_indexed_indirect_count.set(
             self.cmd_draw_indexed_indirect_count
                 .get()
@@ -10349,6 +10994,61 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdDebugMarkerInsertEXT".as_ptr(),
             )));
+        self.create_video_session_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCreateVideoSessionKHR".as_ptr(),
+            )));
+        self.destroy_video_session_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkDestroyVideoSessionKHR".as_ptr(),
+            )));
+        self.get_video_session_memory_requirements_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkGetVideoSessionMemoryRequirementsKHR".as_ptr(),
+            )));
+        self.bind_video_session_memory_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkBindVideoSessionMemoryKHR".as_ptr(),
+            )));
+        self.create_video_session_parameters_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCreateVideoSessionParametersKHR".as_ptr(),
+            )));
+        self.update_video_session_parameters_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkUpdateVideoSessionParametersKHR".as_ptr(),
+            )));
+        self.destroy_video_session_parameters_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkDestroyVideoSessionParametersKHR".as_ptr(),
+            )));
+        self.cmd_begin_video_coding_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCmdBeginVideoCodingKHR".as_ptr(),
+            )));
+        self.cmd_end_video_coding_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCmdEndVideoCodingKHR".as_ptr(),
+            )));
+        self.cmd_control_video_coding_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCmdControlVideoCodingKHR".as_ptr(),
+            )));
+        self.cmd_decode_video_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCmdDecodeVideoKHR".as_ptr(),
+            )));
         self.cmd_bind_transform_feedback_buffers_ext
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10379,56 +11079,70 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdDrawIndirectByteCountEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.create_cu_module_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateCuModuleNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.create_cu_function_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateCuFunctionNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.destroy_cu_module_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyCuModuleNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.destroy_cu_function_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyCuFunctionNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.cmd_cu_launch_kernel_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdCuLaunchKernelNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.get_image_view_handle_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetImageViewHandleNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nvx")]
         self.get_image_view_address_nvx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetImageViewAddressNVX".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.get_shader_info_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetShaderInfoAMD".as_ptr(),
             )));
+        #[cfg(all(
+            any(feature = "platform-win32", target_os = "windows"),
+            feature = "vendor-nv"
+        ))]
         self.get_memory_win32_handle_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetMemoryWin32HandleNV".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_memory_win32_handle_khr
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetMemoryWin32HandleKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_memory_win32_handle_properties_khr
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10444,11 +11158,13 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetMemoryFdPropertiesKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.import_semaphore_win32_handle_khr
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkImportSemaphoreWin32HandleKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_semaphore_win32_handle_khr
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10484,6 +11200,7 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdEndConditionalRenderingEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_wscaling_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10509,11 +11226,13 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetSwapchainCounterEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-google")]
         self.get_refresh_cycle_duration_google
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetRefreshCycleDurationGOOGLE".as_ptr(),
             )));
+        #[cfg(feature = "vendor-google")]
         self.get_past_presentation_timing_google
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10544,11 +11263,13 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetSwapchainStatusKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.import_fence_win32_handle_khr
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkImportFenceWin32HandleKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_fence_win32_handle_khr
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10614,46 +11335,55 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdInsertDebugUtilsLabelEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-android", target_os = "android"))]
         self.get_android_hardware_buffer_properties_android
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetAndroidHardwareBufferPropertiesANDROID".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-android", target_os = "android"))]
         self.get_memory_android_hardware_buffer_android
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetMemoryAndroidHardwareBufferANDROID".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.create_execution_graph_pipelines_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateExecutionGraphPipelinesAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.get_execution_graph_pipeline_scratch_size_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetExecutionGraphPipelineScratchSizeAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.get_execution_graph_pipeline_node_index_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetExecutionGraphPipelineNodeIndexAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_initialize_graph_scratch_memory_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdInitializeGraphScratchMemoryAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_dispatch_graph_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDispatchGraphAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_dispatch_graph_indirect_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDispatchGraphIndirectAMDX".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         self.cmd_dispatch_graph_indirect_count_amdx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10759,11 +11489,13 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetRayTracingShaderGroupHandlesKHR".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_ray_tracing_shader_group_handles_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetRayTracingShaderGroupHandlesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_ray_tracing_shader_group_handles_khr.set(
             self.get_ray_tracing_shader_group_handles_khr
                 .get()
@@ -10814,71 +11546,85 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetValidationCacheDataEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_bind_shading_rate_image_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdBindShadingRateImageNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_shading_rate_palette_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetViewportShadingRatePaletteNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coarse_sample_order_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCoarseSampleOrderNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_acceleration_structure_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.destroy_acceleration_structure_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_acceleration_structure_memory_requirements_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetAccelerationStructureMemoryRequirementsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.bind_acceleration_structure_memory_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkBindAccelerationStructureMemoryNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_build_acceleration_structure_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdBuildAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_copy_acceleration_structure_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdCopyAccelerationStructureNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_trace_rays_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdTraceRaysNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_ray_tracing_pipelines_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateRayTracingPipelinesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_acceleration_structure_handle_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetAccelerationStructureHandleNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_write_acceleration_structures_properties_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdWriteAccelerationStructuresPropertiesNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.compile_deferred_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10889,91 +11635,109 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetMemoryHostPointerPropertiesEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_write_buffer_marker_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdWriteBufferMarkerAMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_draw_mesh_tasks_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDrawMeshTasksNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_draw_mesh_tasks_indirect_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDrawMeshTasksIndirectNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_draw_mesh_tasks_indirect_count_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDrawMeshTasksIndirectCountNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_exclusive_scissor_enable_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetExclusiveScissorEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_exclusive_scissor_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetExclusiveScissorNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_checkpoint_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCheckpointNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_queue_checkpoint_data_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetQueueCheckpointDataNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.initialize_performance_api_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkInitializePerformanceApiINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.uninitialize_performance_api_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkUninitializePerformanceApiINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.cmd_set_performance_marker_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetPerformanceMarkerINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.cmd_set_performance_stream_marker_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetPerformanceStreamMarkerINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.cmd_set_performance_override_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetPerformanceOverrideINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.acquire_performance_configuration_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkAcquirePerformanceConfigurationINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.release_performance_configuration_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkReleasePerformanceConfigurationINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.queue_set_performance_configuration_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkQueueSetPerformanceConfigurationINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-intel")]
         self.get_performance_parameter_intel
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetPerformanceParameterINTEL".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.set_local_dimming_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -10999,16 +11763,19 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkWaitForPresentKHR".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.acquire_full_screen_exclusive_mode_ext
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkAcquireFullScreenExclusiveModeEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.release_full_screen_exclusive_mode_ext
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkReleaseFullScreenExclusiveModeEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-win32", target_os = "windows"))]
         self.get_device_group_surface_present_modes2_ext
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11089,31 +11856,37 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkReleaseSwapchainImagesEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_generated_commands_memory_requirements_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetGeneratedCommandsMemoryRequirementsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_preprocess_generated_commands_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdPreprocessGeneratedCommandsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_execute_generated_commands_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdExecuteGeneratedCommandsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_bind_pipeline_shader_group_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdBindPipelineShaderGroupNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_indirect_commands_layout_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateIndirectCommandsLayoutNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.destroy_indirect_commands_layout_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11124,46 +11897,65 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdSetDepthBias2EXT".as_ptr(),
             )));
+        self.cmd_encode_video_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkCmdEncodeVideoKHR".as_ptr(),
+            )));
+        self.get_encoded_video_session_parameters_khr
+            .set(mem::transmute(get_device_proc_addr(
+                get_device(),
+                c"vkGetEncodedVideoSessionParametersKHR".as_ptr(),
+            )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.create_cuda_module_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateCudaModuleNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.get_cuda_module_cache_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetCudaModuleCacheNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.create_cuda_function_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateCudaFunctionNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.destroy_cuda_module_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyCudaModuleNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.destroy_cuda_function_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyCudaFunctionNV".as_ptr(),
             )));
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         self.cmd_cuda_launch_kernel_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdCudaLaunchKernelNV".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
         self.export_metal_objects_ext
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkExportMetalObjectsEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-amd")]
         self.cmd_write_buffer_marker2_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdWriteBufferMarker2AMD".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_queue_checkpoint_data2_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11224,6 +12016,7 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_fragment_shading_rate_enum_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11254,66 +12047,79 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdSetVertexInputEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_memory_zircon_handle_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetMemoryZirconHandleFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_memory_zircon_handle_properties_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetMemoryZirconHandlePropertiesFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.import_semaphore_zircon_handle_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkImportSemaphoreZirconHandleFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_semaphore_zircon_handle_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetSemaphoreZirconHandleFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.create_buffer_collection_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateBufferCollectionFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.set_buffer_collection_image_constraints_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkSetBufferCollectionImageConstraintsFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.set_buffer_collection_buffer_constraints_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkSetBufferCollectionBufferConstraintsFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.destroy_buffer_collection_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyBufferCollectionFUCHSIA".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
         self.get_buffer_collection_properties_fuchsia
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetBufferCollectionPropertiesFUCHSIA".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.get_device_subpass_shading_max_workgroup_size_huawei
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_subpass_shading_huawei
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSubpassShadingHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_bind_invocation_mask_huawei
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdBindInvocationMaskHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_memory_remote_address_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11424,11 +12230,13 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetMicromapBuildSizesEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_draw_cluster_huawei
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDrawClusterHUAWEI".as_ptr(),
             )));
+        #[cfg(feature = "vendor-huawei")]
         self.cmd_draw_cluster_indirect_huawei
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11439,46 +12247,55 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkSetDeviceMemoryPriorityEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-valve")]
         self.get_descriptor_set_layout_host_mapping_info_valve
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetDescriptorSetLayoutHostMappingInfoVALVE".as_ptr(),
             )));
+        #[cfg(feature = "vendor-valve")]
         self.get_descriptor_set_host_mapping_valve
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetDescriptorSetHostMappingVALVE".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_copy_memory_indirect_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdCopyMemoryIndirectNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_copy_memory_to_image_indirect_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdCopyMemoryToImageIndirectNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_decompress_memory_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDecompressMemoryNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_decompress_memory_indirect_count_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdDecompressMemoryIndirectCountNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_pipeline_indirect_memory_requirements_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetPipelineIndirectMemoryRequirementsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_update_pipeline_indirect_buffer_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdUpdatePipelineIndirectBufferNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_pipeline_indirect_device_address_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11589,51 +12406,61 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdSetDepthClipNegativeOneToOneEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_wscaling_enable_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetViewportWScalingEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_viewport_swizzle_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetViewportSwizzleNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_to_color_enable_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCoverageToColorEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_to_color_location_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCoverageToColorLocationNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_modulation_mode_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCoverageModulationModeNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_modulation_table_enable_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCoverageModulationTableEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_modulation_table_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetCoverageModulationTableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_shading_rate_image_enable_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetShadingRateImageEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_representative_fragment_test_enable_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCmdSetRepresentativeFragmentTestEnableNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_set_coverage_reduction_mode_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11649,21 +12476,25 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkGetShaderModuleCreateInfoIdentifierEXT".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.create_optical_flow_session_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkCreateOpticalFlowSessionNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.destroy_optical_flow_session_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkDestroyOpticalFlowSessionNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.bind_optical_flow_session_image_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkBindOpticalFlowSessionImageNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.cmd_optical_flow_execute_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11699,6 +12530,7 @@ impl CommandsDispatcher {
                 .get()
                 .or(self.get_image_subresource_layout2_ext.get()),
         );
+        #[cfg(feature = "vendor-amd")]
         self.anti_lag_update_amd
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11754,36 +12586,43 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkReleaseCapturedPipelineDataKHR".as_ptr(),
             )));
+        #[cfg(feature = "vendor-qcom")]
         self.get_framebuffer_tile_properties_qcom
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetFramebufferTilePropertiesQCOM".as_ptr(),
             )));
+        #[cfg(feature = "vendor-qcom")]
         self.get_dynamic_rendering_tile_properties_qcom
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetDynamicRenderingTilePropertiesQCOM".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.set_latency_sleep_mode_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkSetLatencySleepModeNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.latency_sleep_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkLatencySleepNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.set_latency_marker_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkSetLatencyMarkerNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.get_latency_timings_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
                 c"vkGetLatencyTimingsNV".as_ptr(),
             )));
+        #[cfg(feature = "vendor-nv")]
         self.queue_notify_out_of_band_nv
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
@@ -11794,6 +12633,7 @@ impl CommandsDispatcher {
                 get_device(),
                 c"vkCmdSetAttachmentFeedbackLoopEnableEXT".as_ptr(),
             )));
+        #[cfg(any(feature = "platform-screen", target_os = "nto"))]
         self.get_screen_buffer_properties_qnx
             .set(mem::transmute(get_device_proc_addr(
                 get_device(),
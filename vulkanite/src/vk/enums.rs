@@ -1,5 +1,6 @@
 use bitflags::bitflags;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkResult.html>"]
 #[doc(alias = "VkResult")]
 #[repr(i32)]
@@ -34,7 +35,14 @@ pub enum Status {
     ErrorOutOfDateKHR = -1000001004,
     ErrorIncompatibleDisplayKHR = -1000003001,
     ErrorValidationFailedEXT = -1000011001,
+    #[cfg(feature = "vendor-nv")]
     ErrorInvalidShaderNV = -1000012000,
+    ErrorImageUsageNotSupportedKHR = -1000023000,
+    ErrorVideoPictureLayoutNotSupportedKHR = -1000023001,
+    ErrorVideoProfileOperationNotSupportedKHR = -1000023002,
+    ErrorVideoProfileFormatNotSupportedKHR = -1000023003,
+    ErrorVideoProfileCodecNotSupportedKHR = -1000023004,
+    ErrorVideoStdVersionNotSupportedKHR = -1000023005,
     ErrorInvalidDrmFormatModifierPlaneLayoutEXT = -1000158000,
     ErrorNotPermittedKHR = -1000174001,
     ErrorFullScreenExclusiveModeLostEXT = -1000255000,
@@ -42,6 +50,7 @@ pub enum Status {
     ThreadDoneKHR = 1000268001,
     OperationDeferredKHR = 1000268002,
     OperationNotDeferredKHR = 1000268003,
+    ErrorInvalidVideoStdParametersKHR = -1000299000,
     ErrorCompressionExhaustedEXT = -1000338000,
     IncompatibleShaderBinaryEXT = 1000482000,
     PipelineBinaryMissingKHR = 1000483000,
@@ -59,7 +68,8 @@ impl Status {
     pub const ErrorPipelineCompileRequiredEXT: Self = Self::PipelineCompileRequired;
     pub const ErrorIncompatibleShaderBinaryEXT: Self = Self::IncompatibleShaderBinaryEXT;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkStructureType.html>"]
 #[doc(alias = "VkStructureType")]
 #[repr(u32)]
@@ -297,29 +307,98 @@ pub enum StructureType {
     AndroidSurfaceCreateInfoKHR = 1000008000,
     Win32SurfaceCreateInfoKHR = 1000009000,
     DebugReportCallbackCreateInfoEXT = 1000011000,
+    #[cfg(feature = "vendor-amd")]
     PipelineRasterizationStateRasterizationOrderAMD = 1000018000,
     DebugMarkerObjectNameInfoEXT = 1000022000,
     DebugMarkerObjectTagInfoEXT = 1000022001,
     DebugMarkerMarkerInfoEXT = 1000022002,
+    VideoProfileInfoKHR = 1000023000,
+    VideoCapabilitiesKHR = 1000023001,
+    VideoPictureResourceInfoKHR = 1000023002,
+    VideoSessionMemoryRequirementsKHR = 1000023003,
+    BindVideoSessionMemoryInfoKHR = 1000023004,
+    VideoSessionCreateInfoKHR = 1000023005,
+    VideoSessionParametersCreateInfoKHR = 1000023006,
+    VideoSessionParametersUpdateInfoKHR = 1000023007,
+    VideoBeginCodingInfoKHR = 1000023008,
+    VideoEndCodingInfoKHR = 1000023009,
+    VideoCodingControlInfoKHR = 1000023010,
+    VideoReferenceSlotInfoKHR = 1000023011,
+    QueueFamilyVideoPropertiesKHR = 1000023012,
+    VideoProfileListInfoKHR = 1000023013,
+    PhysicalDeviceVideoFormatInfoKHR = 1000023014,
+    VideoFormatPropertiesKHR = 1000023015,
+    QueueFamilyQueryResultStatusPropertiesKHR = 1000023016,
+    VideoDecodeInfoKHR = 1000024000,
+    VideoDecodeCapabilitiesKHR = 1000024001,
+    VideoDecodeUsageInfoKHR = 1000024002,
+    #[cfg(feature = "vendor-nv")]
     DedicatedAllocationImageCreateInfoNV = 1000026000,
+    #[cfg(feature = "vendor-nv")]
     DedicatedAllocationBufferCreateInfoNV = 1000026001,
+    #[cfg(feature = "vendor-nv")]
     DedicatedAllocationMemoryAllocateInfoNV = 1000026002,
     PhysicalDeviceTransformFeedbackFeaturesEXT = 1000028000,
     PhysicalDeviceTransformFeedbackPropertiesEXT = 1000028001,
     PipelineRasterizationStateStreamCreateInfoEXT = 1000028002,
+    #[cfg(feature = "vendor-nvx")]
     CuModuleCreateInfoNVX = 1000029000,
+    #[cfg(feature = "vendor-nvx")]
     CuFunctionCreateInfoNVX = 1000029001,
+    #[cfg(feature = "vendor-nvx")]
     CuLaunchInfoNVX = 1000029002,
+    #[cfg(feature = "vendor-nvx")]
     ImageViewHandleInfoNVX = 1000030000,
+    #[cfg(feature = "vendor-nvx")]
     ImageViewAddressPropertiesNVX = 1000030001,
+    VideoEncodeH264CapabilitiesKHR = 1000038000,
+    VideoEncodeH264SessionParametersCreateInfoKHR = 1000038001,
+    VideoEncodeH264SessionParametersAddInfoKHR = 1000038002,
+    VideoEncodeH264PictureInfoKHR = 1000038003,
+    VideoEncodeH264DpbSlotInfoKHR = 1000038004,
+    VideoEncodeH264NaluSliceInfoKHR = 1000038005,
+    VideoEncodeH264GopRemainingFrameInfoKHR = 1000038006,
+    VideoEncodeH264ProfileInfoKHR = 1000038007,
+    VideoEncodeH264RateControlInfoKHR = 1000038008,
+    VideoEncodeH264RateControlLayerInfoKHR = 1000038009,
+    VideoEncodeH264SessionCreateInfoKHR = 1000038010,
+    VideoEncodeH264QualityLevelPropertiesKHR = 1000038011,
+    VideoEncodeH264SessionParametersGetInfoKHR = 1000038012,
+    VideoEncodeH264SessionParametersFeedbackInfoKHR = 1000038013,
+    VideoEncodeH265CapabilitiesKHR = 1000039000,
+    VideoEncodeH265SessionParametersCreateInfoKHR = 1000039001,
+    VideoEncodeH265SessionParametersAddInfoKHR = 1000039002,
+    VideoEncodeH265PictureInfoKHR = 1000039003,
+    VideoEncodeH265DpbSlotInfoKHR = 1000039004,
+    VideoEncodeH265NaluSliceSegmentInfoKHR = 1000039005,
+    VideoEncodeH265GopRemainingFrameInfoKHR = 1000039006,
+    VideoEncodeH265ProfileInfoKHR = 1000039007,
+    VideoEncodeH265RateControlInfoKHR = 1000039009,
+    VideoEncodeH265RateControlLayerInfoKHR = 1000039010,
+    VideoEncodeH265SessionCreateInfoKHR = 1000039011,
+    VideoEncodeH265QualityLevelPropertiesKHR = 1000039012,
+    VideoEncodeH265SessionParametersGetInfoKHR = 1000039013,
+    VideoEncodeH265SessionParametersFeedbackInfoKHR = 1000039014,
+    VideoDecodeH264CapabilitiesKHR = 1000040000,
+    VideoDecodeH264PictureInfoKHR = 1000040001,
+    VideoDecodeH264ProfileInfoKHR = 1000040003,
+    VideoDecodeH264SessionParametersCreateInfoKHR = 1000040004,
+    VideoDecodeH264SessionParametersAddInfoKHR = 1000040005,
+    VideoDecodeH264DpbSlotInfoKHR = 1000040006,
+    #[cfg(feature = "vendor-amd")]
     TextureLodGatherFormatPropertiesAMD = 1000041000,
     RenderingFragmentShadingRateAttachmentInfoKHR = 1000044006,
     RenderingFragmentDensityMapAttachmentInfoEXT = 1000044007,
+    #[cfg(feature = "vendor-amd")]
     AttachmentSampleCountInfoAMD = 1000044008,
+    #[cfg(feature = "vendor-nvx")]
     MultiviewPerViewAttributesInfoNVX = 1000044009,
     StreamDescriptorSurfaceCreateInfoGGP = 1000049000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCornerSampledImageFeaturesNV = 1000050000,
+    #[cfg(feature = "vendor-nv")]
     ExternalMemoryImageCreateInfoNV = 1000056000,
+    #[cfg(feature = "vendor-nv")]
     ExportMemoryAllocateInfoNV = 1000056001,
     ImportMemoryWin32HandleInfoNV = 1000057000,
     ExportMemoryWin32HandleInfoNV = 1000057001,
@@ -350,14 +429,18 @@ pub enum StructureType {
     PhysicalDeviceConditionalRenderingFeaturesEXT = 1000081001,
     ConditionalRenderingBeginInfoEXT = 1000081002,
     PresentRegionsKHR = 1000084000,
+    #[cfg(feature = "vendor-nv")]
     PipelineViewportWScalingStateCreateInfoNV = 1000087000,
     SurfaceCapabilities2EXT = 1000090000,
     DisplayPowerInfoEXT = 1000091000,
     DeviceEventInfoEXT = 1000091001,
     DisplayEventInfoEXT = 1000091002,
     SwapchainCounterCreateInfoEXT = 1000091003,
+    #[cfg(feature = "vendor-google")]
     PresentTimesInfoGOOGLE = 1000092000,
+    #[cfg(feature = "vendor-nvx")]
     PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX = 1000097000,
+    #[cfg(feature = "vendor-nv")]
     PipelineViewportSwizzleStateCreateInfoNV = 1000098000,
     PhysicalDeviceDiscardRectanglePropertiesEXT = 1000099000,
     PipelineDiscardRectangleStateCreateInfoEXT = 1000099001,
@@ -366,6 +449,7 @@ pub enum StructureType {
     PhysicalDeviceDepthClipEnableFeaturesEXT = 1000102000,
     PipelineRasterizationDepthClipStateCreateInfoEXT = 1000102001,
     HdrMetadataEXT = 1000105000,
+    #[cfg(feature = "vendor-img")]
     PhysicalDeviceRelaxedLineRasterizationFeaturesIMG = 1000110000,
     SharedPresentSurfaceCapabilitiesKHR = 1000111000,
     ImportFenceWin32HandleInfoKHR = 1000114000,
@@ -403,10 +487,15 @@ pub enum StructureType {
     MemoryGetAndroidHardwareBufferInfoANDROID = 1000129004,
     ExternalFormatANDROID = 1000129005,
     AndroidHardwareBufferFormatProperties2ANDROID = 1000129006,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     PhysicalDeviceShaderEnqueueFeaturesAMDX = 1000134000,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     PhysicalDeviceShaderEnqueuePropertiesAMDX = 1000134001,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     ExecutionGraphPipelineScratchSizeAMDX = 1000134002,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     ExecutionGraphPipelineCreateInfoAMDX = 1000134003,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     PipelineShaderStageNodeCreateInfoAMDX = 1000134004,
     SampleLocationsInfoEXT = 1000143000,
     RenderPassSampleLocationsBeginInfoEXT = 1000143001,
@@ -416,6 +505,7 @@ pub enum StructureType {
     PhysicalDeviceBlendOperationAdvancedFeaturesEXT = 1000148000,
     PhysicalDeviceBlendOperationAdvancedPropertiesEXT = 1000148001,
     PipelineColorBlendAdvancedStateCreateInfoEXT = 1000148002,
+    #[cfg(feature = "vendor-nv")]
     PipelineCoverageToColorStateCreateInfoNV = 1000149000,
     WriteDescriptorSetAccelerationStructureKHR = 1000150007,
     AccelerationStructureBuildGeometryInfoKHR = 1000150000,
@@ -438,8 +528,11 @@ pub enum StructureType {
     RayTracingShaderGroupCreateInfoKHR = 1000150016,
     RayTracingPipelineInterfaceCreateInfoKHR = 1000150018,
     PhysicalDeviceRayQueryFeaturesKHR = 1000348013,
+    #[cfg(feature = "vendor-nv")]
     PipelineCoverageModulationStateCreateInfoNV = 1000152000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceShaderSmBuiltinsFeaturesNV = 1000154000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceShaderSmBuiltinsPropertiesNV = 1000154001,
     DrmFormatModifierPropertiesListEXT = 1000158000,
     PhysicalDeviceImageDrmFormatModifierInfoEXT = 1000158002,
@@ -449,24 +542,43 @@ pub enum StructureType {
     DrmFormatModifierPropertiesList2EXT = 1000158006,
     ValidationCacheCreateInfoEXT = 1000160000,
     ShaderModuleValidationCacheCreateInfoEXT = 1000160001,
+    #[cfg(feature = "provisional")]
     PhysicalDevicePortabilitySubsetFeaturesKHR = 1000163000,
+    #[cfg(feature = "provisional")]
     PhysicalDevicePortabilitySubsetPropertiesKHR = 1000163001,
+    #[cfg(feature = "vendor-nv")]
     PipelineViewportShadingRateImageStateCreateInfoNV = 1000164000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceShadingRateImageFeaturesNV = 1000164001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceShadingRateImagePropertiesNV = 1000164002,
+    #[cfg(feature = "vendor-nv")]
     PipelineViewportCoarseSampleOrderStateCreateInfoNV = 1000164005,
+    #[cfg(feature = "vendor-nv")]
     RayTracingPipelineCreateInfoNV = 1000165000,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureCreateInfoNV = 1000165001,
+    #[cfg(feature = "vendor-nv")]
     GeometryNV = 1000165003,
+    #[cfg(feature = "vendor-nv")]
     GeometryTrianglesNV = 1000165004,
+    #[cfg(feature = "vendor-nv")]
     GeometryAabbNV = 1000165005,
+    #[cfg(feature = "vendor-nv")]
     BindAccelerationStructureMemoryInfoNV = 1000165006,
+    #[cfg(feature = "vendor-nv")]
     WriteDescriptorSetAccelerationStructureNV = 1000165007,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureMemoryRequirementsInfoNV = 1000165008,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRayTracingPropertiesNV = 1000165009,
+    #[cfg(feature = "vendor-nv")]
     RayTracingShaderGroupCreateInfoNV = 1000165011,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureInfoNV = 1000165012,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRepresentativeFragmentTestFeaturesNV = 1000166000,
+    #[cfg(feature = "vendor-nv")]
     PipelineRepresentativeFragmentTestStateCreateInfoNV = 1000166001,
     PhysicalDeviceImageViewImageFormatInfoEXT = 1000170000,
     FilterCubicImageViewImageFormatPropertiesEXT = 1000170001,
@@ -474,30 +586,55 @@ pub enum StructureType {
     MemoryHostPointerPropertiesEXT = 1000178001,
     PhysicalDeviceExternalMemoryHostPropertiesEXT = 1000178002,
     PhysicalDeviceShaderClockFeaturesKHR = 1000181000,
+    #[cfg(feature = "vendor-amd")]
     PipelineCompilerControlCreateInfoAMD = 1000183000,
+    #[cfg(feature = "vendor-amd")]
     PhysicalDeviceShaderCorePropertiesAMD = 1000185000,
+    VideoDecodeH265CapabilitiesKHR = 1000187000,
+    VideoDecodeH265SessionParametersCreateInfoKHR = 1000187001,
+    VideoDecodeH265SessionParametersAddInfoKHR = 1000187002,
+    VideoDecodeH265ProfileInfoKHR = 1000187003,
+    VideoDecodeH265PictureInfoKHR = 1000187004,
+    VideoDecodeH265DpbSlotInfoKHR = 1000187005,
     DeviceQueueGlobalPriorityCreateInfoKHR = 1000174000,
     PhysicalDeviceGlobalPriorityQueryFeaturesKHR = 1000388000,
     QueueFamilyGlobalPriorityPropertiesKHR = 1000388001,
+    #[cfg(feature = "vendor-amd")]
     DeviceMemoryOverallocationCreateInfoAMD = 1000189000,
     PhysicalDeviceVertexAttributeDivisorPropertiesEXT = 1000190000,
     PresentFrameTokenGGP = 1000191000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceMeshShaderFeaturesNV = 1000202000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceMeshShaderPropertiesNV = 1000202001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceShaderImageFootprintFeaturesNV = 1000204000,
+    #[cfg(feature = "vendor-nv")]
     PipelineViewportExclusiveScissorStateCreateInfoNV = 1000205000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceExclusiveScissorFeaturesNV = 1000205002,
+    #[cfg(feature = "vendor-nv")]
     CheckpointDataNV = 1000206000,
+    #[cfg(feature = "vendor-nv")]
     QueueFamilyCheckpointPropertiesNV = 1000206001,
+    #[cfg(feature = "vendor-intel")]
     PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL = 1000209000,
+    #[cfg(feature = "vendor-intel")]
     QueryPoolPerformanceQueryCreateInfoINTEL = 1000210000,
+    #[cfg(feature = "vendor-intel")]
     InitializePerformanceApiInfoINTEL = 1000210001,
+    #[cfg(feature = "vendor-intel")]
     PerformanceMarkerInfoINTEL = 1000210002,
+    #[cfg(feature = "vendor-intel")]
     PerformanceStreamMarkerInfoINTEL = 1000210003,
+    #[cfg(feature = "vendor-intel")]
     PerformanceOverrideInfoINTEL = 1000210004,
+    #[cfg(feature = "vendor-intel")]
     PerformanceConfigurationAcquireInfoINTEL = 1000210005,
     PhysicalDevicePciBusInfoPropertiesEXT = 1000212000,
+    #[cfg(feature = "vendor-amd")]
     DisplayNativeHdrSurfaceCapabilitiesAMD = 1000213000,
+    #[cfg(feature = "vendor-amd")]
     SwapchainDisplayNativeHdrCreateInfoAMD = 1000213001,
     ImagepipeSurfaceCreateInfoFUCHSIA = 1000214000,
     MetalSurfaceCreateInfoEXT = 1000217000,
@@ -509,7 +646,9 @@ pub enum StructureType {
     PhysicalDeviceFragmentShadingRatePropertiesKHR = 1000226002,
     PhysicalDeviceFragmentShadingRateFeaturesKHR = 1000226003,
     PhysicalDeviceFragmentShadingRateKHR = 1000226004,
+    #[cfg(feature = "vendor-amd")]
     PhysicalDeviceShaderCoreProperties2AMD = 1000227000,
+    #[cfg(feature = "vendor-amd")]
     PhysicalDeviceCoherentMemoryFeaturesAMD = 1000229000,
     PhysicalDeviceDynamicRenderingLocalReadFeaturesKHR = 1000232000,
     RenderingAttachmentLocationInfoKHR = 1000232001,
@@ -520,16 +659,23 @@ pub enum StructureType {
     PhysicalDeviceMemoryPriorityFeaturesEXT = 1000238000,
     MemoryPriorityAllocateInfoEXT = 1000238001,
     SurfaceProtectedCapabilitiesKHR = 1000239000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV = 1000240000,
     PhysicalDeviceBufferDeviceAddressFeaturesEXT = 1000244000,
     BufferDeviceAddressCreateInfoEXT = 1000244002,
     ValidationFeaturesEXT = 1000247000,
     PhysicalDevicePresentWaitFeaturesKHR = 1000248000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCooperativeMatrixFeaturesNV = 1000249000,
+    #[cfg(feature = "vendor-nv")]
     CooperativeMatrixPropertiesNV = 1000249001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCooperativeMatrixPropertiesNV = 1000249002,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCoverageReductionModeFeaturesNV = 1000250000,
+    #[cfg(feature = "vendor-nv")]
     PipelineCoverageReductionStateCreateInfoNV = 1000250001,
+    #[cfg(feature = "vendor-nv")]
     FramebufferMixedSamplesCombinationNV = 1000250002,
     PhysicalDeviceFragmentShaderInterlockFeaturesEXT = 1000251000,
     PhysicalDeviceYcbcrImageArraysFeaturesEXT = 1000252000,
@@ -573,18 +719,30 @@ pub enum StructureType {
     SwapchainPresentModeInfoEXT = 1000275003,
     SwapchainPresentScalingCreateInfoEXT = 1000275004,
     ReleaseSwapchainImagesInfoEXT = 1000275005,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceDeviceGeneratedCommandsPropertiesNV = 1000277000,
+    #[cfg(feature = "vendor-nv")]
     GraphicsShaderGroupCreateInfoNV = 1000277001,
+    #[cfg(feature = "vendor-nv")]
     GraphicsPipelineShaderGroupsCreateInfoNV = 1000277002,
+    #[cfg(feature = "vendor-nv")]
     IndirectCommandsLayoutTokenNV = 1000277003,
+    #[cfg(feature = "vendor-nv")]
     IndirectCommandsLayoutCreateInfoNV = 1000277004,
+    #[cfg(feature = "vendor-nv")]
     GeneratedCommandsInfoNV = 1000277005,
+    #[cfg(feature = "vendor-nv")]
     GeneratedCommandsMemoryRequirementsInfoNV = 1000277006,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceDeviceGeneratedCommandsFeaturesNV = 1000277007,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceInheritedViewportScissorFeaturesNV = 1000278000,
+    #[cfg(feature = "vendor-nv")]
     CommandBufferInheritanceViewportScissorInfoNV = 1000278001,
     PhysicalDeviceTexelBufferAlignmentFeaturesEXT = 1000281000,
+    #[cfg(feature = "vendor-qcom")]
     CommandBufferInheritanceRenderPassTransformInfoQCOM = 1000282000,
+    #[cfg(feature = "vendor-qcom")]
     RenderPassTransformBeginInfoQCOM = 1000282001,
     PhysicalDeviceDepthBiasControlFeaturesEXT = 1000283000,
     DepthBiasInfoEXT = 1000283001,
@@ -598,18 +756,40 @@ pub enum StructureType {
     PhysicalDeviceCustomBorderColorPropertiesEXT = 1000287001,
     PhysicalDeviceCustomBorderColorFeaturesEXT = 1000287002,
     PipelineLibraryCreateInfoKHR = 1000290000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDevicePresentBarrierFeaturesNV = 1000292000,
+    #[cfg(feature = "vendor-nv")]
     SurfaceCapabilitiesPresentBarrierNV = 1000292001,
+    #[cfg(feature = "vendor-nv")]
     SwapchainPresentBarrierCreateInfoNV = 1000292002,
     PresentIdKHR = 1000294000,
     PhysicalDevicePresentIdFeaturesKHR = 1000294001,
+    VideoEncodeInfoKHR = 1000299000,
+    VideoEncodeRateControlInfoKHR = 1000299001,
+    VideoEncodeRateControlLayerInfoKHR = 1000299002,
+    VideoEncodeCapabilitiesKHR = 1000299003,
+    VideoEncodeUsageInfoKHR = 1000299004,
+    QueryPoolVideoEncodeFeedbackCreateInfoKHR = 1000299005,
+    PhysicalDeviceVideoEncodeQualityLevelInfoKHR = 1000299006,
+    VideoEncodeQualityLevelPropertiesKHR = 1000299007,
+    VideoEncodeQualityLevelInfoKHR = 1000299008,
+    VideoEncodeSessionParametersGetInfoKHR = 1000299009,
+    VideoEncodeSessionParametersFeedbackInfoKHR = 1000299010,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceDiagnosticsConfigFeaturesNV = 1000300000,
+    #[cfg(feature = "vendor-nv")]
     DeviceDiagnosticsConfigCreateInfoNV = 1000300001,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaModuleCreateInfoNV = 1000307000,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaFunctionCreateInfoNV = 1000307001,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaLaunchInfoNV = 1000307002,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     PhysicalDeviceCudaKernelLaunchFeaturesNV = 1000307003,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     PhysicalDeviceCudaKernelLaunchPropertiesNV = 1000307004,
+    #[cfg(feature = "vendor-nv")]
     QueryLowLatencySupportNV = 1000310000,
     ExportMetalObjectCreateInfoEXT = 1000311000,
     ExportMetalObjectsInfoEXT = 1000311001,
@@ -623,7 +803,9 @@ pub enum StructureType {
     ImportMetalIoSurfaceInfoEXT = 1000311009,
     ExportMetalSharedEventInfoEXT = 1000311010,
     ImportMetalSharedEventInfoEXT = 1000311011,
+    #[cfg(feature = "vendor-nv")]
     QueueFamilyCheckpointProperties2NV = 1000314008,
+    #[cfg(feature = "vendor-nv")]
     CheckpointData2NV = 1000314009,
     PhysicalDeviceDescriptorBufferPropertiesEXT = 1000316000,
     PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT = 1000316001,
@@ -641,21 +823,29 @@ pub enum StructureType {
     PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT = 1000320000,
     PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT = 1000320001,
     GraphicsPipelineLibraryCreateInfoEXT = 1000320002,
+    #[cfg(feature = "vendor-amd")]
     PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD = 1000321000,
     PhysicalDeviceFragmentShaderBarycentricFeaturesKHR = 1000203000,
     PhysicalDeviceFragmentShaderBarycentricPropertiesKHR = 1000322000,
     PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR = 1000323000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceFragmentShadingRateEnumsPropertiesNV = 1000326000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceFragmentShadingRateEnumsFeaturesNV = 1000326001,
+    #[cfg(feature = "vendor-nv")]
     PipelineFragmentShadingRateEnumStateCreateInfoNV = 1000326002,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureGeometryMotionTrianglesDataNV = 1000327000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRayTracingMotionBlurFeaturesNV = 1000327001,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureMotionInfoNV = 1000327002,
     PhysicalDeviceMeshShaderFeaturesEXT = 1000328000,
     PhysicalDeviceMeshShaderPropertiesEXT = 1000328001,
     PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT = 1000330000,
     PhysicalDeviceFragmentDensityMap2FeaturesEXT = 1000332000,
     PhysicalDeviceFragmentDensityMap2PropertiesEXT = 1000332001,
+    #[cfg(feature = "vendor-qcom")]
     CopyCommandTransformInfoQCOM = 1000333000,
     PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR = 1000336000,
     PhysicalDeviceImageCompressionControlFeaturesEXT = 1000338000,
@@ -693,11 +883,17 @@ pub enum StructureType {
     ImageFormatConstraintsInfoFUCHSIA = 1000366007,
     SysmemColorSpaceFUCHSIA = 1000366008,
     BufferCollectionConstraintsInfoFUCHSIA = 1000366009,
+    #[cfg(feature = "vendor-huawei")]
     SubpassShadingPipelineCreateInfoHUAWEI = 1000369000,
+    #[cfg(feature = "vendor-huawei")]
     PhysicalDeviceSubpassShadingFeaturesHUAWEI = 1000369001,
+    #[cfg(feature = "vendor-huawei")]
     PhysicalDeviceSubpassShadingPropertiesHUAWEI = 1000369002,
+    #[cfg(feature = "vendor-huawei")]
     PhysicalDeviceInvocationMaskFeaturesHUAWEI = 1000370000,
+    #[cfg(feature = "vendor-nv")]
     MemoryGetRemoteAddressInfoNV = 1000371000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceExternalMemoryRdmaFeaturesNV = 1000371001,
     PipelinePropertiesIdentifierEXT = 1000372000,
     PhysicalDevicePipelinePropertiesFeaturesEXT = 1000372001,
@@ -729,47 +925,79 @@ pub enum StructureType {
     MicromapCreateInfoEXT = 1000396007,
     MicromapBuildSizesInfoEXT = 1000396008,
     AccelerationStructureTrianglesOpacityMicromapEXT = 1000396009,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     PhysicalDeviceDisplacementMicromapFeaturesNV = 1000397000,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     PhysicalDeviceDisplacementMicromapPropertiesNV = 1000397001,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     AccelerationStructureTrianglesDisplacementMicromapNV = 1000397002,
+    #[cfg(feature = "vendor-huawei")]
     PhysicalDeviceClusterCullingShaderFeaturesHUAWEI = 1000404000,
+    #[cfg(feature = "vendor-huawei")]
     PhysicalDeviceClusterCullingShaderPropertiesHUAWEI = 1000404001,
+    #[cfg(feature = "vendor-huawei")]
     PhysicalDeviceClusterCullingShaderVrsFeaturesHUAWEI = 1000404002,
     PhysicalDeviceBorderColorSwizzleFeaturesEXT = 1000411000,
     SamplerBorderColorComponentMappingCreateInfoEXT = 1000411001,
     PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT = 1000412000,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceShaderCorePropertiesARM = 1000415000,
     PhysicalDeviceShaderSubgroupRotateFeaturesKHR = 1000416000,
+    #[cfg(feature = "vendor-arm")]
     DeviceQueueShaderCoreControlCreateInfoARM = 1000417000,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceSchedulingControlsFeaturesARM = 1000417001,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceSchedulingControlsPropertiesARM = 1000417002,
     PhysicalDeviceImageSlicedViewOf3DFeaturesEXT = 1000418000,
     ImageViewSlicedCreateInfoEXT = 1000418001,
+    #[cfg(feature = "vendor-valve")]
     PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE = 1000420000,
+    #[cfg(feature = "vendor-valve")]
     DescriptorSetBindingReferenceVALVE = 1000420001,
+    #[cfg(feature = "vendor-valve")]
     DescriptorSetLayoutHostMappingInfoVALVE = 1000420002,
     PhysicalDeviceDepthClampZeroOneFeaturesEXT = 1000421000,
     PhysicalDeviceNonSeamlessCubeMapFeaturesEXT = 1000422000,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceRenderPassStripedFeaturesARM = 1000424000,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceRenderPassStripedPropertiesARM = 1000424001,
+    #[cfg(feature = "vendor-arm")]
     RenderPassStripeBeginInfoARM = 1000424002,
+    #[cfg(feature = "vendor-arm")]
     RenderPassStripeInfoARM = 1000424003,
+    #[cfg(feature = "vendor-arm")]
     RenderPassStripeSubmitInfoARM = 1000424004,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM = 1000425000,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM = 1000425001,
+    #[cfg(feature = "vendor-qcom")]
     SubpassFragmentDensityMapOffsetEndInfoQCOM = 1000425002,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCopyMemoryIndirectFeaturesNV = 1000426000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCopyMemoryIndirectPropertiesNV = 1000426001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceMemoryDecompressionFeaturesNV = 1000427000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceMemoryDecompressionPropertiesNV = 1000427001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV = 1000428000,
+    #[cfg(feature = "vendor-nv")]
     ComputePipelineIndirectBufferInfoNV = 1000428001,
+    #[cfg(feature = "vendor-nv")]
     PipelineIndirectDeviceAddressInfoNV = 1000428002,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceLinearColorAttachmentFeaturesNV = 1000430000,
     PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR = 1000434000,
     PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT = 1000437000,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceImageProcessingFeaturesQCOM = 1000440000,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceImageProcessingPropertiesQCOM = 1000440001,
+    #[cfg(feature = "vendor-qcom")]
     ImageViewSampleWeightCreateInfoQCOM = 1000440002,
     PhysicalDeviceNestedCommandBufferFeaturesEXT = 1000451000,
     PhysicalDeviceNestedCommandBufferPropertiesEXT = 1000451001,
@@ -780,19 +1008,28 @@ pub enum StructureType {
     RenderPassCreationControlEXT = 1000458001,
     RenderPassCreationFeedbackCreateInfoEXT = 1000458002,
     RenderPassSubpassFeedbackCreateInfoEXT = 1000458003,
+    #[cfg(feature = "vendor-lunarg")]
     DirectDriverLoadingInfoLUNARG = 1000459000,
+    #[cfg(feature = "vendor-lunarg")]
     DirectDriverLoadingListLUNARG = 1000459001,
     PhysicalDeviceShaderModuleIdentifierFeaturesEXT = 1000462000,
     PhysicalDeviceShaderModuleIdentifierPropertiesEXT = 1000462001,
     PipelineShaderStageModuleIdentifierCreateInfoEXT = 1000462002,
     ShaderModuleIdentifierEXT = 1000462003,
     PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT = 1000342000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceOpticalFlowFeaturesNV = 1000464000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceOpticalFlowPropertiesNV = 1000464001,
+    #[cfg(feature = "vendor-nv")]
     OpticalFlowImageFormatInfoNV = 1000464002,
+    #[cfg(feature = "vendor-nv")]
     OpticalFlowImageFormatPropertiesNV = 1000464003,
+    #[cfg(feature = "vendor-nv")]
     OpticalFlowSessionCreateInfoNV = 1000464004,
+    #[cfg(feature = "vendor-nv")]
     OpticalFlowExecuteInfoNV = 1000464005,
+    #[cfg(feature = "vendor-nv")]
     OpticalFlowSessionCreatePrivateDataInfoNV = 1000464010,
     PhysicalDeviceLegacyDitheringFeaturesEXT = 1000465000,
     PhysicalDevicePipelineProtectedAccessFeaturesEXT = 1000466000,
@@ -807,8 +1044,11 @@ pub enum StructureType {
     ImageSubresource2KHR = 1000338003,
     PipelineCreateFlags2CreateInfoKHR = 1000470005,
     BufferUsageFlags2CreateInfoKHR = 1000470006,
+    #[cfg(feature = "vendor-amd")]
     PhysicalDeviceAntiLagFeaturesAMD = 1000476000,
+    #[cfg(feature = "vendor-amd")]
     AntiLagDataAMD = 1000476001,
+    #[cfg(feature = "vendor-amd")]
     AntiLagPresentationInfoAMD = 1000476002,
     PhysicalDeviceRayTracingPositionFetchFeaturesKHR = 1000481000,
     PhysicalDeviceShaderObjectFeaturesEXT = 1000482000,
@@ -824,49 +1064,88 @@ pub enum StructureType {
     PipelineCreateInfoKHR = 1000483007,
     DevicePipelineBinaryInternalCacheControlKHR = 1000483008,
     PipelineBinaryHandlesInfoKHR = 1000483009,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceTilePropertiesFeaturesQCOM = 1000484000,
+    #[cfg(feature = "vendor-qcom")]
     TilePropertiesQCOM = 1000484001,
+    #[cfg(feature = "vendor-sec")]
     PhysicalDeviceAmigoProfilingFeaturesSEC = 1000485000,
+    #[cfg(feature = "vendor-sec")]
     AmigoProfilingSubmitInfoSEC = 1000485001,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM = 1000488000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRayTracingInvocationReorderFeaturesNV = 1000490000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRayTracingInvocationReorderPropertiesNV = 1000490001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV = 1000492000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceExtendedSparseAddressSpacePropertiesNV = 1000492001,
     PhysicalDeviceMutableDescriptorTypeFeaturesEXT = 1000351000,
     MutableDescriptorTypeCreateInfoEXT = 1000351002,
     PhysicalDeviceLegacyVertexAttributesFeaturesEXT = 1000495000,
     PhysicalDeviceLegacyVertexAttributesPropertiesEXT = 1000495001,
     LayerSettingsCreateInfoEXT = 1000496000,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceShaderCoreBuiltinsFeaturesARM = 1000497000,
+    #[cfg(feature = "vendor-arm")]
     PhysicalDeviceShaderCoreBuiltinsPropertiesARM = 1000497001,
     PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT = 1000498000,
     PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT = 1000499000,
+    #[cfg(feature = "vendor-nv")]
     LatencySleepModeInfoNV = 1000505000,
+    #[cfg(feature = "vendor-nv")]
     LatencySleepInfoNV = 1000505001,
+    #[cfg(feature = "vendor-nv")]
     SetLatencyMarkerInfoNV = 1000505002,
+    #[cfg(feature = "vendor-nv")]
     GetLatencyMarkerInfoNV = 1000505003,
+    #[cfg(feature = "vendor-nv")]
     LatencyTimingsFrameReportNV = 1000505004,
+    #[cfg(feature = "vendor-nv")]
     LatencySubmissionPresentIdNV = 1000505005,
+    #[cfg(feature = "vendor-nv")]
     OutOfBandQueueTypeInfoNV = 1000505006,
+    #[cfg(feature = "vendor-nv")]
     SwapchainLatencyCreateInfoNV = 1000505007,
+    #[cfg(feature = "vendor-nv")]
     LatencySurfaceCapabilitiesNV = 1000505008,
     PhysicalDeviceCooperativeMatrixFeaturesKHR = 1000506000,
     CooperativeMatrixPropertiesKHR = 1000506001,
     PhysicalDeviceCooperativeMatrixPropertiesKHR = 1000506002,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM = 1000510000,
+    #[cfg(feature = "vendor-qcom")]
     MultiviewPerViewRenderAreasRenderPassBeginInfoQCOM = 1000510001,
     PhysicalDeviceComputeShaderDerivativesFeaturesKHR = 1000201000,
     PhysicalDeviceComputeShaderDerivativesPropertiesKHR = 1000511000,
+    VideoDecodeAv1CapabilitiesKHR = 1000512000,
+    VideoDecodeAv1PictureInfoKHR = 1000512001,
+    VideoDecodeAv1ProfileInfoKHR = 1000512003,
+    VideoDecodeAv1SessionParametersCreateInfoKHR = 1000512004,
+    VideoDecodeAv1DpbSlotInfoKHR = 1000512005,
+    PhysicalDeviceVideoMaintenance1FeaturesKHR = 1000515000,
+    VideoInlineQueryInfoKHR = 1000515001,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDevicePerStageDescriptorSetFeaturesNV = 1000516000,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceImageProcessing2FeaturesQCOM = 1000518000,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceImageProcessing2PropertiesQCOM = 1000518001,
+    #[cfg(feature = "vendor-qcom")]
     SamplerBlockMatchWindowCreateInfoQCOM = 1000518002,
+    #[cfg(feature = "vendor-qcom")]
     SamplerCubicWeightsCreateInfoQCOM = 1000519000,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceCubicWeightsFeaturesQCOM = 1000519001,
+    #[cfg(feature = "vendor-qcom")]
     BlitImageCubicWeightsInfoQCOM = 1000519002,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceYcbcrDegammaFeaturesQCOM = 1000520000,
+    #[cfg(feature = "vendor-qcom")]
     SamplerYcbcrConversionYcbcrDegammaCreateInfoQCOM = 1000520001,
+    #[cfg(feature = "vendor-qcom")]
     PhysicalDeviceCubicClampFeaturesQCOM = 1000521000,
     PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT = 1000524000,
     PhysicalDeviceVertexAttributeDivisorPropertiesKHR = 1000525000,
@@ -878,6 +1157,7 @@ pub enum StructureType {
     ImportScreenBufferInfoQNX = 1000529002,
     ExternalFormatQNX = 1000529003,
     PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX = 1000529004,
+    #[cfg(feature = "vendor-msft")]
     PhysicalDeviceLayeredDriverPropertiesMSFT = 1000530000,
     PhysicalDeviceIndexTypeUint8FeaturesKHR = 1000265000,
     PhysicalDeviceLineRasterizationFeaturesKHR = 1000259000,
@@ -894,17 +1174,22 @@ pub enum StructureType {
     PushDescriptorSetWithTemplateInfoKHR = 1000545006,
     SetDescriptorBufferOffsetsInfoEXT = 1000545007,
     BindDescriptorBufferEmbeddedSamplersInfoEXT = 1000545008,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceDescriptorPoolOverallocationFeaturesNV = 1000546000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRawAccessChainsFeaturesNV = 1000555000,
     PhysicalDeviceShaderRelaxedExtendedInstructionFeaturesKHR = 1000558000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceCommandBufferInheritanceFeaturesNV = 1000559000,
     PhysicalDeviceMaintenance7FeaturesKHR = 1000562000,
     PhysicalDeviceMaintenance7PropertiesKHR = 1000562001,
     PhysicalDeviceLayeredApiPropertiesListKHR = 1000562002,
     PhysicalDeviceLayeredApiPropertiesKHR = 1000562003,
     PhysicalDeviceLayeredApiVulkanPropertiesKHR = 1000562004,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV = 1000563000,
     PhysicalDeviceShaderReplicatedCompositesFeaturesEXT = 1000564000,
+    #[cfg(feature = "vendor-nv")]
     PhysicalDeviceRayTracingValidationFeaturesNV = 1000568000,
     PhysicalDeviceDeviceGeneratedCommandsFeaturesEXT = 1000572000,
     PhysicalDeviceDeviceGeneratedCommandsPropertiesEXT = 1000572001,
@@ -920,8 +1205,11 @@ pub enum StructureType {
     IndirectExecutionSetShaderLayoutInfoEXT = 1000572012,
     GeneratedCommandsPipelineInfoEXT = 1000572013,
     GeneratedCommandsShaderInfoEXT = 1000572014,
+    #[cfg(feature = "vendor-mesa")]
     PhysicalDeviceImageAlignmentControlFeaturesMESA = 1000575000,
+    #[cfg(feature = "vendor-mesa")]
     PhysicalDeviceImageAlignmentControlPropertiesMESA = 1000575001,
+    #[cfg(feature = "vendor-mesa")]
     ImageAlignmentControlCreateInfoMESA = 1000575002,
     PhysicalDeviceDepthClampControlFeaturesEXT = 1000582000,
     PipelineViewportDepthClampControlCreateInfoEXT = 1000582001,
@@ -940,6 +1228,8 @@ impl StructureType {
         Self::PhysicalDeviceDynamicRenderingFeatures;
     pub const CommandBufferInheritanceRenderingInfoKHR: Self =
         Self::CommandBufferInheritanceRenderingInfo;
+    #[cfg(feature = "vendor-nv")]
+    #[cfg(feature = "vendor-amd")]
     pub const AttachmentSampleCountInfoNV: Self = Self::AttachmentSampleCountInfoAMD;
     pub const RenderPassMultiviewCreateInfoKHR: Self = Self::RenderPassMultiviewCreateInfo;
     pub const PhysicalDeviceMultiviewFeaturesKHR: Self = Self::PhysicalDeviceMultiviewFeatures;
@@ -1076,8 +1366,10 @@ impl StructureType {
         Self::PhysicalDeviceDepthStencilResolveProperties;
     pub const SubpassDescriptionDepthStencilResolveKHR: Self =
         Self::SubpassDescriptionDepthStencilResolve;
+    #[cfg(feature = "vendor-nv")]
     pub const PhysicalDeviceComputeShaderDerivativesFeaturesNV: Self =
         Self::PhysicalDeviceComputeShaderDerivativesFeaturesKHR;
+    #[cfg(feature = "vendor-nv")]
     pub const PhysicalDeviceFragmentShaderBarycentricFeaturesNV: Self =
         Self::PhysicalDeviceFragmentShaderBarycentricFeaturesKHR;
     pub const PhysicalDeviceTimelineSemaphoreFeaturesKHR: Self =
@@ -1088,6 +1380,7 @@ impl StructureType {
     pub const TimelineSemaphoreSubmitInfoKHR: Self = Self::TimelineSemaphoreSubmitInfo;
     pub const SemaphoreWaitInfoKHR: Self = Self::SemaphoreWaitInfo;
     pub const SemaphoreSignalInfoKHR: Self = Self::SemaphoreSignalInfo;
+    #[cfg(feature = "vendor-intel")]
     pub const QueryPoolCreateInfoINTEL: Self = Self::QueryPoolPerformanceQueryCreateInfoINTEL;
     pub const PhysicalDeviceVulkanMemoryModelFeaturesKHR: Self =
         Self::PhysicalDeviceVulkanMemoryModelFeatures;
@@ -1171,10 +1464,13 @@ impl StructureType {
     pub const ImageResolve2KHR: Self = Self::ImageResolve2;
     pub const SubresourceLayout2EXT: Self = Self::SubresourceLayout2KHR;
     pub const ImageSubresource2EXT: Self = Self::ImageSubresource2KHR;
+    #[cfg(feature = "vendor-arm")]
     pub const PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesARM: Self =
         Self::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT;
+    #[cfg(feature = "vendor-valve")]
     pub const PhysicalDeviceMutableDescriptorTypeFeaturesVALVE: Self =
         Self::PhysicalDeviceMutableDescriptorTypeFeaturesEXT;
+    #[cfg(feature = "vendor-valve")]
     pub const MutableDescriptorTypeCreateInfoVALVE: Self = Self::MutableDescriptorTypeCreateInfoEXT;
     pub const FormatProperties3KHR: Self = Self::FormatProperties3;
     pub const PipelineInfoEXT: Self = Self::PipelineInfoKHR;
@@ -1230,14 +1526,16 @@ pub const MAX_DESCRIPTION_SIZE: u32 = 256;
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_MEMORY_HEAPS.html>"]
 #[doc(alias = "VK_MAX_MEMORY_HEAPS")]
 pub const MAX_MEMORY_HEAPS: u32 = 16;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCacheHeaderVersion.html>"]
 #[doc(alias = "VkPipelineCacheHeaderVersion")]
 #[repr(u32)]
 pub enum PipelineCacheHeaderVersion {
     One = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkObjectType.html>"]
 #[doc(alias = "VkObjectType")]
 #[repr(u32)]
@@ -1276,19 +1574,28 @@ pub enum ObjectType {
     DisplayKHR = 1000002000,
     DisplayModeKHR = 1000002001,
     DebugReportCallbackEXT = 1000011000,
+    VideoSessionKHR = 1000023000,
+    VideoSessionParametersKHR = 1000023001,
+    #[cfg(feature = "vendor-nvx")]
     CuModuleNVX = 1000029000,
+    #[cfg(feature = "vendor-nvx")]
     CuFunctionNVX = 1000029001,
     DebugUtilsMessengerEXT = 1000128000,
     AccelerationStructureKHR = 1000150000,
     ValidationCacheEXT = 1000160000,
     AccelerationStructureNV = 1000165000,
+    #[cfg(feature = "vendor-intel")]
     PerformanceConfigurationINTEL = 1000210000,
     DeferredOperationKHR = 1000268000,
+    #[cfg(feature = "vendor-nv")]
     IndirectCommandsLayoutNV = 1000277000,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaModuleNV = 1000307000,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaFunctionNV = 1000307001,
     BufferCollectionFUCHSIA = 1000366000,
     MicromapEXT = 1000396000,
+    #[cfg(feature = "vendor-nv")]
     OpticalFlowSessionNV = 1000464000,
     ShaderEXT = 1000482000,
     PipelineBinaryKHR = 1000483000,
@@ -1301,7 +1608,8 @@ impl ObjectType {
     pub const SamplerYcbcrConversionKHR: Self = Self::SamplerYcbcrConversion;
     pub const PrivateDataSlotEXT: Self = Self::PrivateDataSlot;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVendorId.html>"]
 #[doc(alias = "VkVendorId")]
 #[repr(u32)]
@@ -1315,7 +1623,8 @@ pub enum VendorId {
     Pocl = 0x10006,
     Mobileye = 0x10007,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFormat.html>"]
 #[doc(alias = "VkFormat")]
 #[repr(u32)]
@@ -1559,14 +1868,23 @@ pub enum Format {
     Astc10x10SfloatBlock = 1000066011,
     Astc12x10SfloatBlock = 1000066012,
     Astc12x12SfloatBlock = 1000066013,
+    #[cfg(feature = "vendor-img")]
     Pvrtc12BppUnormBlockIMG = 1000054000,
+    #[cfg(feature = "vendor-img")]
     Pvrtc14BppUnormBlockIMG = 1000054001,
+    #[cfg(feature = "vendor-img")]
     Pvrtc22BppUnormBlockIMG = 1000054002,
+    #[cfg(feature = "vendor-img")]
     Pvrtc24BppUnormBlockIMG = 1000054003,
+    #[cfg(feature = "vendor-img")]
     Pvrtc12BppSrgbBlockIMG = 1000054004,
+    #[cfg(feature = "vendor-img")]
     Pvrtc14BppSrgbBlockIMG = 1000054005,
+    #[cfg(feature = "vendor-img")]
     Pvrtc22BppSrgbBlockIMG = 1000054006,
+    #[cfg(feature = "vendor-img")]
     Pvrtc24BppSrgbBlockIMG = 1000054007,
+    #[cfg(feature = "vendor-nv")]
     R16G16Sfixed5NV = 1000464000,
     A1B5G5R5UnormPack16KHR = 1000470000,
     A8UnormKHR = 1000470001,
@@ -1643,12 +1961,14 @@ impl Format {
     pub const G16B16R162Plane444UnormEXT: Self = Self::G16B16R162Plane444Unorm;
     pub const A4R4G4B4UnormPack16EXT: Self = Self::A4R4G4B4UnormPack16;
     pub const A4B4G4R4UnormPack16EXT: Self = Self::A4B4G4R4UnormPack16;
+    #[cfg(feature = "vendor-nv")]
     pub const R16G16S105NV: Self = Self::R16G16Sfixed5NV;
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFormatFeatureFlagBits.html>"]
     #[doc(alias = "VkFormatFeatureFlagBits")]
     pub struct FormatFeatureFlags : u32 {
@@ -1675,7 +1995,10 @@ bitflags! {
         const Disjoint = 1u32 << 22;
         const CositedChromaSamples = 1u32 << 23;
         const SampledImageFilterMinmax = 1u32 << 16;
+        #[cfg(feature = "vendor-img")]
         const SampledImageFilterCubicIMG = Self::SampledImageFilterCubicEXT.bits();
+        const VideoDecodeOutputKHR = 1u32 << 25;
+        const VideoDecodeDpbKHR = 1u32 << 26;
         const TransferSrcKHR = Self::TransferSrc.bits();
         const TransferDstKHR = Self::TransferDst.bits();
         const SampledImageFilterMinmaxEXT = Self::SampledImageFilterMinmax.bits();
@@ -1690,12 +2013,15 @@ bitflags! {
         const SampledImageFilterCubicEXT = 1u32 << 13;
         const FragmentDensityMapEXT = 1u32 << 24;
         const FragmentShadingRateAttachmentKHR = 1u32 << 30;
+        const VideoEncodeInputKHR = 1u32 << 27;
+        const VideoEncodeDpbKHR = 1u32 << 28;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageCreateFlagBits.html>"]
     #[doc(alias = "VkImageCreateFlagBits")]
     pub struct ImageCreateFlags : u32 {
@@ -1711,6 +2037,7 @@ bitflags! {
         const ExtendedUsage = 1u32 << 8;
         const Protected = 1u32 << 11;
         const Disjoint = 1u32 << 9;
+        #[cfg(feature = "vendor-nv")]
         const CornerSampledNV = 1u32 << 13;
         const SplitInstanceBindRegionsKHR = Self::SplitInstanceBindRegions.bits();
         const Image2DArrayCompatibleKHR = Self::Image2DArrayCompatible.bits();
@@ -1723,10 +2050,13 @@ bitflags! {
         const DescriptorBufferCaptureReplayEXT = 1u32 << 16;
         const MultisampledRenderToSingleSampledEXT = 1u32 << 18;
         const Image2DViewCompatibleEXT = 1u32 << 17;
+        #[cfg(feature = "vendor-qcom")]
         const FragmentDensityMapOffsetQCOM = 1u32 << 15;
+        const VideoProfileIndependentKHR = 1u32 << 20;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageTiling.html>"]
 #[doc(alias = "VkImageTiling")]
 #[repr(u32)]
@@ -1735,7 +2065,8 @@ pub enum ImageTiling {
     Linear = 1,
     DrmFormatModifierEXT = 1000158000,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageType.html>"]
 #[doc(alias = "VkImageType")]
 #[repr(u32)]
@@ -1747,7 +2078,8 @@ pub enum ImageType {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageUsageFlagBits.html>"]
     #[doc(alias = "VkImageUsageFlagBits")]
     pub struct ImageUsageFlags : u32 {
@@ -1759,27 +2091,39 @@ bitflags! {
         const DepthStencilAttachment = 1u32 << 5;
         const TransientAttachment = 1u32 << 6;
         const InputAttachment = 1u32 << 7;
+        const VideoDecodeDstKHR = 1u32 << 10;
+        const VideoDecodeSrcKHR = 1u32 << 11;
+        const VideoDecodeDpbKHR = 1u32 << 12;
+        #[cfg(feature = "vendor-nv")]
         const ShadingRateImageNV = Self::FragmentShadingRateAttachmentKHR.bits();
         const FragmentDensityMapEXT = 1u32 << 9;
         const FragmentShadingRateAttachmentKHR = 1u32 << 8;
         const HostTransferEXT = 1u32 << 22;
+        const VideoEncodeDstKHR = 1u32 << 13;
+        const VideoEncodeSrcKHR = 1u32 << 14;
+        const VideoEncodeDpbKHR = 1u32 << 15;
         const AttachmentFeedbackLoopEXT = 1u32 << 19;
+        #[cfg(feature = "vendor-huawei")]
         const InvocationMaskHUAWEI = 1u32 << 18;
+        #[cfg(feature = "vendor-qcom")]
         const SampleWeightQCOM = 1u32 << 20;
+        #[cfg(feature = "vendor-qcom")]
         const SampleBlockMatchQCOM = 1u32 << 21;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkInstanceCreateFlagBits.html>"]
     #[doc(alias = "VkInstanceCreateFlagBits")]
     pub struct InstanceCreateFlags : u32 {
         const EnumeratePortabilityKHR = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkInternalAllocationType.html>"]
 #[doc(alias = "VkInternalAllocationType")]
 #[repr(u32)]
@@ -1789,7 +2133,8 @@ pub enum InternalAllocationType {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryHeapFlagBits.html>"]
     #[doc(alias = "VkMemoryHeapFlagBits")]
     pub struct MemoryHeapFlags : u32 {
@@ -1801,7 +2146,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryPropertyFlagBits.html>"]
     #[doc(alias = "VkMemoryPropertyFlagBits")]
     pub struct MemoryPropertyFlags : u32 {
@@ -1811,12 +2157,16 @@ bitflags! {
         const HostCached = 1u32 << 3;
         const LazilyAllocated = 1u32 << 4;
         const Protected = 1u32 << 5;
+        #[cfg(feature = "vendor-amd")]
         const DeviceCoherentAMD = 1u32 << 6;
+        #[cfg(feature = "vendor-amd")]
         const DeviceUncachedAMD = 1u32 << 7;
+        #[cfg(feature = "vendor-nv")]
         const RdmaCapableNV = 1u32 << 8;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceType.html>"]
 #[doc(alias = "VkPhysicalDeviceType")]
 #[repr(u32)]
@@ -1830,7 +2180,8 @@ pub enum PhysicalDeviceType {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueueFlagBits.html>"]
     #[doc(alias = "VkQueueFlagBits")]
     pub struct QueueFlags : u32 {
@@ -1839,13 +2190,17 @@ bitflags! {
         const Transfer = 1u32 << 2;
         const SparseBinding = 1u32 << 3;
         const Protected = 1u32 << 4;
+        const VideoDecodeKHR = 1u32 << 5;
+        const VideoEncodeKHR = 1u32 << 6;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowNV = 1u32 << 8;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSampleCountFlagBits.html>"]
     #[doc(alias = "VkSampleCountFlagBits")]
     pub struct SampleCountFlags : u32 {
@@ -1858,7 +2213,8 @@ bitflags! {
         const Count64 = 1u32 << 6;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSystemAllocationScope.html>"]
 #[doc(alias = "VkSystemAllocationScope")]
 #[repr(u32)]
@@ -1872,7 +2228,8 @@ pub enum SystemAllocationScope {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineStageFlagBits.html>"]
     #[doc(alias = "VkPipelineStageFlagBits")]
     pub struct PipelineStageFlags : u32 {
@@ -1898,10 +2255,15 @@ bitflags! {
         const ConditionalRenderingEXT = 1u32 << 18;
         const AccelerationStructureBuildKHR = 1u32 << 25;
         const RayTracingShaderKHR = 1u32 << 21;
+        #[cfg(feature = "vendor-nv")]
         const ShadingRateImageNV = Self::FragmentShadingRateAttachmentKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const RayTracingShaderNV = Self::RayTracingShaderKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const AccelerationStructureBuildNV = Self::AccelerationStructureBuildKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const TaskShaderNV = Self::TaskShaderEXT.bits();
+        #[cfg(feature = "vendor-nv")]
         const MeshShaderNV = Self::MeshShaderEXT.bits();
         const FragmentDensityProcessEXT = 1u32 << 23;
         const FragmentShadingRateAttachmentKHR = 1u32 << 22;
@@ -1915,7 +2277,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryMapFlagBits.html>"]
     #[doc(alias = "VkMemoryMapFlagBits")]
     pub struct MemoryMapFlags : u32 {
@@ -1925,7 +2288,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageAspectFlagBits.html>"]
     #[doc(alias = "VkImageAspectFlagBits")]
     pub struct ImageAspectFlags : u32 {
@@ -1950,7 +2314,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSparseImageFormatFlagBits.html>"]
     #[doc(alias = "VkSparseImageFormatFlagBits")]
     pub struct SparseImageFormatFlags : u32 {
@@ -1962,7 +2327,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSparseMemoryBindFlagBits.html>"]
     #[doc(alias = "VkSparseMemoryBindFlagBits")]
     pub struct SparseMemoryBindFlags : u32 {
@@ -1972,7 +2338,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFenceCreateFlagBits.html>"]
     #[doc(alias = "VkFenceCreateFlagBits")]
     pub struct FenceCreateFlags : u32 {
@@ -1982,7 +2349,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkEventCreateFlagBits.html>"]
     #[doc(alias = "VkEventCreateFlagBits")]
     pub struct EventCreateFlags : u32 {
@@ -1993,7 +2361,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryPipelineStatisticFlagBits.html>"]
     #[doc(alias = "VkQueryPipelineStatisticFlagBits")]
     pub struct QueryPipelineStatisticFlags : u32 {
@@ -2010,13 +2379,15 @@ bitflags! {
         const ComputeShaderInvocations = 1u32 << 10;
         const TaskShaderInvocationsEXT = 1u32 << 11;
         const MeshShaderInvocationsEXT = 1u32 << 12;
+        #[cfg(feature = "vendor-huawei")]
         const ClusterCullingShaderInvocationsHUAWEI = 1u32 << 13;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryResultFlagBits.html>"]
     #[doc(alias = "VkQueryResultFlagBits")]
     pub struct QueryResultFlags : u32 {
@@ -2024,9 +2395,11 @@ bitflags! {
         const Wait = 1u32 << 1;
         const WithAvailability = 1u32 << 2;
         const Partial = 1u32 << 3;
+        const WithStatusKHR = 1u32 << 4;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryType.html>"]
 #[doc(alias = "VkQueryType")]
 #[repr(u32)]
@@ -2034,12 +2407,16 @@ pub enum QueryType {
     Occlusion = 0,
     PipelineStatistics = 1,
     Timestamp = 2,
+    ResultStatusOnlyKHR = 1000023000,
     TransformFeedbackStreamEXT = 1000028004,
     PerformanceQueryKHR = 1000116000,
     AccelerationStructureCompactedSizeKHR = 1000150000,
     AccelerationStructureSerializationSizeKHR = 1000150001,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureCompactedSizeNV = 1000165000,
+    #[cfg(feature = "vendor-intel")]
     PerformanceQueryINTEL = 1000210000,
+    VideoEncodeFeedbackKHR = 1000299000,
     MeshPrimitivesGeneratedEXT = 1000328000,
     PrimitivesGeneratedEXT = 1000382000,
     AccelerationStructureSerializationBottomLevelPointersKHR = 1000386000,
@@ -2050,7 +2427,8 @@ pub enum QueryType {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferCreateFlagBits.html>"]
     #[doc(alias = "VkBufferCreateFlagBits")]
     pub struct BufferCreateFlags : u32 {
@@ -2062,12 +2440,14 @@ bitflags! {
         const DeviceAddressCaptureReplayEXT = Self::DeviceAddressCaptureReplay.bits();
         const DeviceAddressCaptureReplayKHR = Self::DeviceAddressCaptureReplay.bits();
         const DescriptorBufferCaptureReplayEXT = 1u32 << 5;
+        const VideoProfileIndependentKHR = 1u32 << 6;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferUsageFlagBits.html>"]
     #[doc(alias = "VkBufferUsageFlagBits")]
     pub struct BufferUsageFlags : u32 {
@@ -2081,16 +2461,22 @@ bitflags! {
         const VertexBuffer = 1u32 << 7;
         const IndirectBuffer = 1u32 << 8;
         const ShaderDeviceAddress = 1u32 << 17;
+        const VideoDecodeSrcKHR = 1u32 << 13;
+        const VideoDecodeDstKHR = 1u32 << 14;
         const TransformFeedbackBufferEXT = 1u32 << 11;
         const TransformFeedbackCounterBufferEXT = 1u32 << 12;
         const ConditionalRenderingEXT = 1u32 << 9;
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         const ExecutionGraphScratchAMDX = 1u32 << 25;
         const AccelerationStructureBuildInputReadOnlyKHR = 1u32 << 19;
         const AccelerationStructureStorageKHR = 1u32 << 20;
         const ShaderBindingTableKHR = 1u32 << 10;
+        #[cfg(feature = "vendor-nv")]
         const RayTracingNV = Self::ShaderBindingTableKHR.bits();
         const ShaderDeviceAddressEXT = Self::ShaderDeviceAddress.bits();
         const ShaderDeviceAddressKHR = Self::ShaderDeviceAddress.bits();
+        const VideoEncodeDstKHR = 1u32 << 15;
+        const VideoEncodeSrcKHR = 1u32 << 16;
         const SamplerDescriptorBufferEXT = 1u32 << 21;
         const ResourceDescriptorBufferEXT = 1u32 << 22;
         const PushDescriptorsDescriptorBufferEXT = 1u32 << 26;
@@ -2098,7 +2484,8 @@ bitflags! {
         const MicromapStorageEXT = 1u32 << 24;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSharingMode.html>"]
 #[doc(alias = "VkSharingMode")]
 #[repr(u32)]
@@ -2106,7 +2493,8 @@ pub enum SharingMode {
     Exclusive = 0,
     Concurrent = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageLayout.html>"]
 #[doc(alias = "VkImageLayout")]
 #[repr(u32)]
@@ -2129,10 +2517,16 @@ pub enum ImageLayout {
     ReadOnlyOptimal = 1000314000,
     AttachmentOptimal = 1000314001,
     PresentSrcKHR = 1000001002,
+    VideoDecodeDstKHR = 1000024000,
+    VideoDecodeSrcKHR = 1000024001,
+    VideoDecodeDpbKHR = 1000024002,
     SharedPresentKHR = 1000111000,
     FragmentDensityMapOptimalEXT = 1000218000,
     FragmentShadingRateAttachmentOptimalKHR = 1000164003,
     RenderingLocalReadKHR = 1000232000,
+    VideoEncodeDstKHR = 1000299000,
+    VideoEncodeSrcKHR = 1000299001,
+    VideoEncodeDpbKHR = 1000299002,
     AttachmentFeedbackLoopOptimalEXT = 1000339000,
 }
 #[allow(non_upper_case_globals)]
@@ -2141,6 +2535,7 @@ impl ImageLayout {
         Self::DepthReadOnlyStencilAttachmentOptimal;
     pub const DepthAttachmentStencilReadOnlyOptimalKHR: Self =
         Self::DepthAttachmentStencilReadOnlyOptimal;
+    #[cfg(feature = "vendor-nv")]
     pub const ShadingRateOptimalNV: Self = Self::FragmentShadingRateAttachmentOptimalKHR;
     pub const DepthAttachmentOptimalKHR: Self = Self::DepthAttachmentOptimal;
     pub const DepthReadOnlyOptimalKHR: Self = Self::DepthReadOnlyOptimal;
@@ -2149,7 +2544,8 @@ impl ImageLayout {
     pub const ReadOnlyOptimalKHR: Self = Self::ReadOnlyOptimal;
     pub const AttachmentOptimalKHR: Self = Self::AttachmentOptimal;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkComponentSwizzle.html>"]
 #[doc(alias = "VkComponentSwizzle")]
 #[repr(u32)]
@@ -2165,7 +2561,8 @@ pub enum ComponentSwizzle {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageViewCreateFlagBits.html>"]
     #[doc(alias = "VkImageViewCreateFlagBits")]
     pub struct ImageViewCreateFlags : u32 {
@@ -2174,7 +2571,8 @@ bitflags! {
         const FragmentDensityMapDeferredEXT = 1u32 << 1;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageViewType.html>"]
 #[doc(alias = "VkImageViewType")]
 #[repr(u32)]
@@ -2187,7 +2585,8 @@ pub enum ImageViewType {
     Type2DArray = 5,
     CubeArray = 6,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBlendFactor.html>"]
 #[doc(alias = "VkBlendFactor")]
 #[repr(u32)]
@@ -2212,7 +2611,8 @@ pub enum BlendFactor {
     Src1Alpha = 17,
     OneMinusSrc1Alpha = 18,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBlendOp.html>"]
 #[doc(alias = "VkBlendOp")]
 #[repr(u32)]
@@ -2272,7 +2672,8 @@ pub enum BlendOp {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkColorComponentFlagBits.html>"]
     #[doc(alias = "VkColorComponentFlagBits")]
     pub struct ColorComponentFlags : u32 {
@@ -2282,7 +2683,8 @@ bitflags! {
         const A = 1u32 << 3;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCompareOp.html>"]
 #[doc(alias = "VkCompareOp")]
 #[repr(u32)]
@@ -2299,7 +2701,8 @@ pub enum CompareOp {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCullModeFlagBits.html>"]
     #[doc(alias = "VkCullModeFlagBits")]
     pub struct CullModeFlags : u32 {
@@ -2309,7 +2712,8 @@ bitflags! {
         const FrontAndBack = 0x00000003;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDynamicState.html>"]
 #[doc(alias = "VkDynamicState")]
 #[repr(u32)]
@@ -2338,15 +2742,20 @@ pub enum DynamicState {
     RasterizerDiscardEnable = 1000377001,
     DepthBiasEnable = 1000377002,
     PrimitiveRestartEnable = 1000377004,
+    #[cfg(feature = "vendor-nv")]
     ViewportWScalingNV = 1000087000,
     DiscardRectangleEXT = 1000099000,
     DiscardRectangleEnableEXT = 1000099001,
     DiscardRectangleModeEXT = 1000099002,
     SampleLocationsEXT = 1000143000,
     RayTracingPipelineStackSizeKHR = 1000347000,
+    #[cfg(feature = "vendor-nv")]
     ViewportShadingRatePaletteNV = 1000164004,
+    #[cfg(feature = "vendor-nv")]
     ViewportCoarseSampleOrderNV = 1000164006,
+    #[cfg(feature = "vendor-nv")]
     ExclusiveScissorEnableNV = 1000205000,
+    #[cfg(feature = "vendor-nv")]
     ExclusiveScissorNV = 1000205001,
     FragmentShadingRateKHR = 1000226000,
     VertexInputEXT = 1000352000,
@@ -2374,15 +2783,25 @@ pub enum DynamicState {
     LineRasterizationModeEXT = 1000455020,
     LineStippleEnableEXT = 1000455021,
     DepthClipNegativeOneToOneEXT = 1000455022,
+    #[cfg(feature = "vendor-nv")]
     ViewportWScalingEnableNV = 1000455023,
+    #[cfg(feature = "vendor-nv")]
     ViewportSwizzleNV = 1000455024,
+    #[cfg(feature = "vendor-nv")]
     CoverageToColorEnableNV = 1000455025,
+    #[cfg(feature = "vendor-nv")]
     CoverageToColorLocationNV = 1000455026,
+    #[cfg(feature = "vendor-nv")]
     CoverageModulationModeNV = 1000455027,
+    #[cfg(feature = "vendor-nv")]
     CoverageModulationTableEnableNV = 1000455028,
+    #[cfg(feature = "vendor-nv")]
     CoverageModulationTableNV = 1000455029,
+    #[cfg(feature = "vendor-nv")]
     ShadingRateImageEnableNV = 1000455030,
+    #[cfg(feature = "vendor-nv")]
     RepresentativeFragmentTestEnableNV = 1000455031,
+    #[cfg(feature = "vendor-nv")]
     CoverageReductionModeNV = 1000455032,
     AttachmentFeedbackLoopEnableEXT = 1000524000,
     LineStippleKHR = 1000259000,
@@ -2407,7 +2826,8 @@ impl DynamicState {
     pub const DepthBiasEnableEXT: Self = Self::DepthBiasEnable;
     pub const PrimitiveRestartEnableEXT: Self = Self::PrimitiveRestartEnable;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFrontFace.html>"]
 #[doc(alias = "VkFrontFace")]
 #[repr(u32)]
@@ -2415,7 +2835,8 @@ pub enum FrontFace {
     CounterClockwise = 0,
     Clockwise = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkLogicOp.html>"]
 #[doc(alias = "VkLogicOp")]
 #[repr(u32)]
@@ -2440,7 +2861,8 @@ pub enum LogicOp {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCreateFlagBits.html>"]
     #[doc(alias = "VkPipelineCreateFlagBits")]
     pub struct PipelineCreateFlags : u32 {
@@ -2464,9 +2886,11 @@ bitflags! {
         const RayTracingSkipTrianglesKHR = 1u32 << 12;
         const RayTracingSkipAabbsKHR = 1u32 << 13;
         const RayTracingShaderGroupHandleCaptureReplayKHR = 1u32 << 19;
+        #[cfg(feature = "vendor-nv")]
         const DeferCompileNV = 1u32 << 5;
         const CaptureStatisticsKHR = 1u32 << 6;
         const CaptureInternalRepresentationsKHR = 1u32 << 7;
+        #[cfg(feature = "vendor-nv")]
         const IndirectBindableNV = 1u32 << 18;
         const LibraryKHR = 1u32 << 11;
         const FailOnPipelineCompileRequiredEXT = Self::FailOnPipelineCompileRequired.bits();
@@ -2474,10 +2898,12 @@ bitflags! {
         const DescriptorBufferEXT = 1u32 << 29;
         const RetainLinkTimeOptimizationInfoEXT = 1u32 << 23;
         const LinkTimeOptimizationEXT = 1u32 << 10;
+        #[cfg(feature = "vendor-nv")]
         const RayTracingAllowMotionNV = 1u32 << 20;
         const ColorAttachmentFeedbackLoopEXT = 1u32 << 25;
         const DepthStencilAttachmentFeedbackLoopEXT = 1u32 << 26;
         const RayTracingOpacityMicromapEXT = 1u32 << 24;
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         const RayTracingDisplacementMicromapNV = 1u32 << 28;
         const NoProtectedAccessEXT = 1u32 << 27;
         const ProtectedAccessOnlyEXT = 1u32 << 30;
@@ -2486,7 +2912,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineShaderStageCreateFlagBits.html>"]
     #[doc(alias = "VkPipelineShaderStageCreateFlagBits")]
     pub struct PipelineShaderStageCreateFlags : u32 {
@@ -2496,7 +2923,8 @@ bitflags! {
         const RequireFullSubgroupsEXT = Self::RequireFullSubgroups.bits();
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPolygonMode.html>"]
 #[doc(alias = "VkPolygonMode")]
 #[repr(u32)]
@@ -2504,9 +2932,11 @@ pub enum PolygonMode {
     Fill = 0,
     Line = 1,
     Point = 2,
+    #[cfg(feature = "vendor-nv")]
     FillRectangleNV = 1000153000,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPrimitiveTopology.html>"]
 #[doc(alias = "VkPrimitiveTopology")]
 #[repr(u32)]
@@ -2526,7 +2956,8 @@ pub enum PrimitiveTopology {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderStageFlagBits.html>"]
     #[doc(alias = "VkShaderStageFlagBits")]
     pub struct ShaderStageFlags : u32 {
@@ -2544,21 +2975,32 @@ bitflags! {
         const MissKHR = 1u32 << 11;
         const IntersectionKHR = 1u32 << 12;
         const CallableKHR = 1u32 << 13;
+        #[cfg(feature = "vendor-nv")]
         const RaygenNV = Self::RaygenKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const AnyHitNV = Self::AnyHitKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const ClosestHitNV = Self::ClosestHitKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const MissNV = Self::MissKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const IntersectionNV = Self::IntersectionKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const CallableNV = Self::CallableKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const TaskNV = Self::TaskEXT.bits();
+        #[cfg(feature = "vendor-nv")]
         const MeshNV = Self::MeshEXT.bits();
         const TaskEXT = 1u32 << 6;
         const MeshEXT = 1u32 << 7;
+        #[cfg(feature = "vendor-huawei")]
         const SubpassShadingHUAWEI = 1u32 << 14;
+        #[cfg(feature = "vendor-huawei")]
         const ClusterCullingHUAWEI = 1u32 << 19;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkStencilOp.html>"]
 #[doc(alias = "VkStencilOp")]
 #[repr(u32)]
@@ -2572,7 +3014,8 @@ pub enum StencilOp {
     IncrementAndWrap = 6,
     DecrementAndWrap = 7,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVertexInputRate.html>"]
 #[doc(alias = "VkVertexInputRate")]
 #[repr(u32)]
@@ -2580,7 +3023,8 @@ pub enum VertexInputRate {
     Vertex = 0,
     Instance = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBorderColor.html>"]
 #[doc(alias = "VkBorderColor")]
 #[repr(u32)]
@@ -2594,7 +3038,8 @@ pub enum BorderColor {
     FloatCustomEXT = 1000287003,
     IntCustomEXT = 1000287004,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFilter.html>"]
 #[doc(alias = "VkFilter")]
 #[repr(u32)]
@@ -2605,9 +3050,11 @@ pub enum Filter {
 }
 #[allow(non_upper_case_globals)]
 impl Filter {
+    #[cfg(feature = "vendor-img")]
     pub const CubicIMG: Self = Self::CubicEXT;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerAddressMode.html>"]
 #[doc(alias = "VkSamplerAddressMode")]
 #[repr(u32)]
@@ -2625,7 +3072,8 @@ impl SamplerAddressMode {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerCreateFlagBits.html>"]
     #[doc(alias = "VkSamplerCreateFlagBits")]
     pub struct SamplerCreateFlags : u32 {
@@ -2633,10 +3081,12 @@ bitflags! {
         const SubsampledCoarseReconstructionEXT = 1u32 << 1;
         const DescriptorBufferCaptureReplayEXT = 1u32 << 3;
         const NonSeamlessCubeMapEXT = 1u32 << 2;
+        #[cfg(feature = "vendor-qcom")]
         const ImageProcessingQCOM = 1u32 << 4;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerMipmapMode.html>"]
 #[doc(alias = "VkSamplerMipmapMode")]
 #[repr(u32)]
@@ -2647,23 +3097,28 @@ pub enum SamplerMipmapMode {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorPoolCreateFlagBits.html>"]
     #[doc(alias = "VkDescriptorPoolCreateFlagBits")]
     pub struct DescriptorPoolCreateFlags : u32 {
         const FreeDescriptorSet = 1u32 << 0;
         const UpdateAfterBind = 1u32 << 1;
         const UpdateAfterBindEXT = Self::UpdateAfterBind.bits();
+        #[cfg(feature = "vendor-valve")]
         const HostOnlyVALVE = Self::HostOnlyEXT.bits();
         const HostOnlyEXT = 1u32 << 2;
+        #[cfg(feature = "vendor-nv")]
         const AllowOverallocationSetsNV = 1u32 << 3;
+        #[cfg(feature = "vendor-nv")]
         const AllowOverallocationPoolsNV = 1u32 << 4;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorSetLayoutCreateFlagBits.html>"]
     #[doc(alias = "VkDescriptorSetLayoutCreateFlagBits")]
     pub struct DescriptorSetLayoutCreateFlags : u32 {
@@ -2672,13 +3127,17 @@ bitflags! {
         const UpdateAfterBindPoolEXT = Self::UpdateAfterBindPool.bits();
         const DescriptorBufferEXT = 1u32 << 4;
         const EmbeddedImmutableSamplersEXT = 1u32 << 5;
+        #[cfg(feature = "vendor-valve")]
         const HostOnlyPoolVALVE = Self::HostOnlyPoolEXT.bits();
+        #[cfg(feature = "vendor-nv")]
         const IndirectBindableNV = 1u32 << 7;
         const HostOnlyPoolEXT = 1u32 << 2;
+        #[cfg(feature = "vendor-nv")]
         const PerStageNV = 1u32 << 6;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorType.html>"]
 #[doc(alias = "VkDescriptorType")]
 #[repr(u32)]
@@ -2696,20 +3155,25 @@ pub enum DescriptorType {
     InputAttachment = 10,
     InlineUniformBlock = 1000138000,
     AccelerationStructureKHR = 1000150000,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureNV = 1000165000,
+    #[cfg(feature = "vendor-qcom")]
     SampleWeightImageQCOM = 1000440000,
+    #[cfg(feature = "vendor-qcom")]
     BlockMatchImageQCOM = 1000440001,
     MutableEXT = 1000351000,
 }
 #[allow(non_upper_case_globals)]
 impl DescriptorType {
     pub const InlineUniformBlockEXT: Self = Self::InlineUniformBlock;
+    #[cfg(feature = "vendor-valve")]
     pub const MutableVALVE: Self = Self::MutableEXT;
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccessFlagBits.html>"]
     #[doc(alias = "VkAccessFlagBits")]
     pub struct AccessFlags : u32 {
@@ -2738,8 +3202,11 @@ bitflags! {
         const ColorAttachmentReadNoncoherentEXT = 1u32 << 19;
         const AccelerationStructureReadKHR = 1u32 << 21;
         const AccelerationStructureWriteKHR = 1u32 << 22;
+        #[cfg(feature = "vendor-nv")]
         const ShadingRateImageReadNV = Self::FragmentShadingRateAttachmentReadKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const AccelerationStructureReadNV = Self::AccelerationStructureReadKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const AccelerationStructureWriteNV = Self::AccelerationStructureWriteKHR.bits();
         const FragmentDensityMapReadEXT = 1u32 << 24;
         const FragmentShadingRateAttachmentReadKHR = 1u32 << 23;
@@ -2753,14 +3220,16 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAttachmentDescriptionFlagBits.html>"]
     #[doc(alias = "VkAttachmentDescriptionFlagBits")]
     pub struct AttachmentDescriptionFlags : u32 {
         const MayAlias = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAttachmentLoadOp.html>"]
 #[doc(alias = "VkAttachmentLoadOp")]
 #[repr(u32)]
@@ -2774,7 +3243,8 @@ pub enum AttachmentLoadOp {
 impl AttachmentLoadOp {
     pub const NoneEXT: Self = Self::NoneKHR;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAttachmentStoreOp.html>"]
 #[doc(alias = "VkAttachmentStoreOp")]
 #[repr(u32)]
@@ -2786,13 +3256,15 @@ pub enum AttachmentStoreOp {
 #[allow(non_upper_case_globals)]
 impl AttachmentStoreOp {
     pub const NoneKHR: Self = Self::None;
+    #[cfg(feature = "vendor-qcom")]
     pub const NoneQCOM: Self = Self::None;
     pub const NoneEXT: Self = Self::None;
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDependencyFlagBits.html>"]
     #[doc(alias = "VkDependencyFlagBits")]
     pub struct DependencyFlags : u32 {
@@ -2807,7 +3279,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFramebufferCreateFlagBits.html>"]
     #[doc(alias = "VkFramebufferCreateFlagBits")]
     pub struct FramebufferCreateFlags : u32 {
@@ -2815,44 +3288,58 @@ bitflags! {
         const ImagelessKHR = Self::Imageless.bits();
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineBindPoint.html>"]
 #[doc(alias = "VkPipelineBindPoint")]
 #[repr(u32)]
 pub enum PipelineBindPoint {
     Graphics = 0,
     Compute = 1,
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     ExecutionGraphAMDX = 1000134000,
     RayTracingKHR = 1000165000,
+    #[cfg(feature = "vendor-huawei")]
     SubpassShadingHUAWEI = 1000369003,
 }
 #[allow(non_upper_case_globals)]
 impl PipelineBindPoint {
+    #[cfg(feature = "vendor-nv")]
     pub const RayTracingNV: Self = Self::RayTracingKHR;
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRenderPassCreateFlagBits.html>"]
     #[doc(alias = "VkRenderPassCreateFlagBits")]
     pub struct RenderPassCreateFlags : u32 {
+        #[cfg(feature = "vendor-qcom")]
         const TransformQCOM = 1u32 << 1;
     }
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubpassDescriptionFlagBits.html>"]
     #[doc(alias = "VkSubpassDescriptionFlagBits")]
     pub struct SubpassDescriptionFlags : u32 {
+        #[cfg(feature = "vendor-nvx")]
         const PerViewAttributesNVX = 1u32 << 0;
+        #[cfg(feature = "vendor-nvx")]
         const PerViewPositionXOnlyNVX = 1u32 << 1;
+        #[cfg(feature = "vendor-qcom")]
         const FragmentRegionQCOM = 1u32 << 2;
+        #[cfg(feature = "vendor-qcom")]
         const ShaderResolveQCOM = 1u32 << 3;
+        #[cfg(feature = "vendor-arm")]
         const RasterizationOrderAttachmentColorAccessARM = Self::RasterizationOrderAttachmentColorAccessEXT.bits();
+        #[cfg(feature = "vendor-arm")]
         const RasterizationOrderAttachmentDepthAccessARM = Self::RasterizationOrderAttachmentDepthAccessEXT.bits();
+        #[cfg(feature = "vendor-arm")]
         const RasterizationOrderAttachmentStencilAccessARM = Self::RasterizationOrderAttachmentStencilAccessEXT.bits();
         const RasterizationOrderAttachmentColorAccessEXT = 1u32 << 4;
         const RasterizationOrderAttachmentDepthAccessEXT = 1u32 << 5;
@@ -2863,7 +3350,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandPoolCreateFlagBits.html>"]
     #[doc(alias = "VkCommandPoolCreateFlagBits")]
     pub struct CommandPoolCreateFlags : u32 {
@@ -2875,14 +3363,16 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandPoolResetFlagBits.html>"]
     #[doc(alias = "VkCommandPoolResetFlagBits")]
     pub struct CommandPoolResetFlags : u32 {
         const ReleaseResources = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandBufferLevel.html>"]
 #[doc(alias = "VkCommandBufferLevel")]
 #[repr(u32)]
@@ -2893,7 +3383,8 @@ pub enum CommandBufferLevel {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandBufferResetFlagBits.html>"]
     #[doc(alias = "VkCommandBufferResetFlagBits")]
     pub struct CommandBufferResetFlags : u32 {
@@ -2903,7 +3394,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandBufferUsageFlagBits.html>"]
     #[doc(alias = "VkCommandBufferUsageFlagBits")]
     pub struct CommandBufferUsageFlags : u32 {
@@ -2915,14 +3407,16 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryControlFlagBits.html>"]
     #[doc(alias = "VkQueryControlFlagBits")]
     pub struct QueryControlFlags : u32 {
         const Precise = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndexType.html>"]
 #[doc(alias = "VkIndexType")]
 #[repr(u32)]
@@ -2934,13 +3428,15 @@ pub enum IndexType {
 }
 #[allow(non_upper_case_globals)]
 impl IndexType {
+    #[cfg(feature = "vendor-nv")]
     pub const NoneNV: Self = Self::NoneKHR;
     pub const Uint8EXT: Self = Self::Uint8KHR;
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkStencilFaceFlagBits.html>"]
     #[doc(alias = "VkStencilFaceFlagBits")]
     pub struct StencilFaceFlags : u32 {
@@ -2950,7 +3446,8 @@ bitflags! {
         const rontAndBack = Self::FrontAndBack.bits();
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubpassContents.html>"]
 #[doc(alias = "VkSubpassContents")]
 #[repr(u32)]
@@ -2966,7 +3463,8 @@ impl SubpassContents {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubgroupFeatureFlagBits.html>"]
     #[doc(alias = "VkSubgroupFeatureFlagBits")]
     pub struct SubgroupFeatureFlags : u32 {
@@ -2978,6 +3476,7 @@ bitflags! {
         const ShuffleRelative = 1u32 << 5;
         const Clustered = 1u32 << 6;
         const Quad = 1u32 << 7;
+        #[cfg(feature = "vendor-nv")]
         const PartitionedNV = 1u32 << 8;
         const RotateKHR = 1u32 << 9;
         const RotateClusteredKHR = 1u32 << 10;
@@ -2986,7 +3485,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPeerMemoryFeatureFlagBits.html>"]
     #[doc(alias = "VkPeerMemoryFeatureFlagBits")]
     pub struct PeerMemoryFeatureFlags : u32 {
@@ -3006,7 +3506,8 @@ pub type PeerMemoryFeatureFlagsKHR = PeerMemoryFeatureFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryAllocateFlagBits.html>"]
     #[doc(alias = "VkMemoryAllocateFlagBits")]
     pub struct MemoryAllocateFlags : u32 {
@@ -3024,7 +3525,8 @@ pub type MemoryAllocateFlagsKHR = MemoryAllocateFlags;
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_DEVICE_GROUP_SIZE.html>"]
 #[doc(alias = "VK_MAX_DEVICE_GROUP_SIZE")]
 pub const MAX_DEVICE_GROUP_SIZE: u32 = 32;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPointClippingBehavior.html>"]
 #[doc(alias = "VkPointClippingBehavior")]
 #[repr(u32)]
@@ -3040,7 +3542,8 @@ impl PointClippingBehavior {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPointClippingBehaviorKHR.html>"]
 #[doc(alias = "VkPointClippingBehaviorKHR")]
 pub type PointClippingBehaviorKHR = PointClippingBehavior;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkTessellationDomainOrigin.html>"]
 #[doc(alias = "VkTessellationDomainOrigin")]
 #[repr(u32)]
@@ -3059,14 +3562,16 @@ pub type TessellationDomainOriginKHR = TessellationDomainOrigin;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceQueueCreateFlagBits.html>"]
     #[doc(alias = "VkDeviceQueueCreateFlagBits")]
     pub struct DeviceQueueCreateFlags : u32 {
         const Protected = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerYcbcrModelConversion.html>"]
 #[doc(alias = "VkSamplerYcbcrModelConversion")]
 #[repr(u32)]
@@ -3088,7 +3593,8 @@ impl SamplerYcbcrModelConversion {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerYcbcrModelConversionKHR.html>"]
 #[doc(alias = "VkSamplerYcbcrModelConversionKHR")]
 pub type SamplerYcbcrModelConversionKHR = SamplerYcbcrModelConversion;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerYcbcrRange.html>"]
 #[doc(alias = "VkSamplerYcbcrRange")]
 #[repr(u32)]
@@ -3104,7 +3610,8 @@ impl SamplerYcbcrRange {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerYcbcrRangeKHR.html>"]
 #[doc(alias = "VkSamplerYcbcrRangeKHR")]
 pub type SamplerYcbcrRangeKHR = SamplerYcbcrRange;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkChromaLocation.html>"]
 #[doc(alias = "VkChromaLocation")]
 #[repr(u32)]
@@ -3120,7 +3627,8 @@ impl ChromaLocation {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkChromaLocationKHR.html>"]
 #[doc(alias = "VkChromaLocationKHR")]
 pub type ChromaLocationKHR = ChromaLocation;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorUpdateTemplateType.html>"]
 #[doc(alias = "VkDescriptorUpdateTemplateType")]
 #[repr(u32)]
@@ -3141,7 +3649,8 @@ pub const LUID_SIZE: u32 = 8;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryHandleTypeFlagBits.html>"]
     #[doc(alias = "VkExternalMemoryHandleTypeFlagBits")]
     pub struct ExternalMemoryHandleTypeFlags : u32 {
@@ -3164,6 +3673,7 @@ bitflags! {
         const HostAllocationEXT = 1u32 << 7;
         const HostMappedForeignMemoryEXT = 1u32 << 8;
         const ZirconVmoFUCHSIA = 1u32 << 11;
+        #[cfg(feature = "vendor-nv")]
         const RdmaAddressNV = 1u32 << 12;
         const ScreenBufferQNX = 1u32 << 14;
     }
@@ -3174,7 +3684,8 @@ pub type ExternalMemoryHandleTypeFlagsKHR = ExternalMemoryHandleTypeFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryFeatureFlagBits.html>"]
     #[doc(alias = "VkExternalMemoryFeatureFlagBits")]
     pub struct ExternalMemoryFeatureFlags : u32 {
@@ -3195,7 +3706,8 @@ pub const QUEUE_FAMILY_EXTERNAL: u32 = !1u32;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalFenceHandleTypeFlagBits.html>"]
     #[doc(alias = "VkExternalFenceHandleTypeFlagBits")]
     pub struct ExternalFenceHandleTypeFlags : u32 {
@@ -3215,7 +3727,8 @@ pub type ExternalFenceHandleTypeFlagsKHR = ExternalFenceHandleTypeFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalFenceFeatureFlagBits.html>"]
     #[doc(alias = "VkExternalFenceFeatureFlagBits")]
     pub struct ExternalFenceFeatureFlags : u32 {
@@ -3231,7 +3744,8 @@ pub type ExternalFenceFeatureFlagsKHR = ExternalFenceFeatureFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFenceImportFlagBits.html>"]
     #[doc(alias = "VkFenceImportFlagBits")]
     pub struct FenceImportFlags : u32 {
@@ -3245,7 +3759,8 @@ pub type FenceImportFlagsKHR = FenceImportFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSemaphoreImportFlagBits.html>"]
     #[doc(alias = "VkSemaphoreImportFlagBits")]
     pub struct SemaphoreImportFlags : u32 {
@@ -3259,7 +3774,8 @@ pub type SemaphoreImportFlagsKHR = SemaphoreImportFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalSemaphoreHandleTypeFlagBits.html>"]
     #[doc(alias = "VkExternalSemaphoreHandleTypeFlagBits")]
     pub struct ExternalSemaphoreHandleTypeFlags : u32 {
@@ -3283,7 +3799,8 @@ pub type ExternalSemaphoreHandleTypeFlagsKHR = ExternalSemaphoreHandleTypeFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalSemaphoreFeatureFlagBits.html>"]
     #[doc(alias = "VkExternalSemaphoreFeatureFlagBits")]
     pub struct ExternalSemaphoreFeatureFlags : u32 {
@@ -3302,7 +3819,8 @@ pub const MAX_DRIVER_NAME_SIZE: u32 = 256;
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_DRIVER_INFO_SIZE.html>"]
 #[doc(alias = "VK_MAX_DRIVER_INFO_SIZE")]
 pub const MAX_DRIVER_INFO_SIZE: u32 = 256;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDriverId.html>"]
 #[doc(alias = "VkDriverId")]
 #[repr(u32)]
@@ -3353,7 +3871,8 @@ impl DriverId {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDriverIdKHR.html>"]
 #[doc(alias = "VkDriverIdKHR")]
 pub type DriverIdKHR = DriverId;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderFloatControlsIndependence.html>"]
 #[doc(alias = "VkShaderFloatControlsIndependence")]
 #[repr(u32)]
@@ -3374,7 +3893,8 @@ pub type ShaderFloatControlsIndependenceKHR = ShaderFloatControlsIndependence;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorBindingFlagBits.html>"]
     #[doc(alias = "VkDescriptorBindingFlagBits")]
     pub struct DescriptorBindingFlags : u32 {
@@ -3394,7 +3914,8 @@ pub type DescriptorBindingFlagsEXT = DescriptorBindingFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkResolveModeFlagBits.html>"]
     #[doc(alias = "VkResolveModeFlagBits")]
     pub struct ResolveModeFlags : u32 {
@@ -3414,7 +3935,8 @@ bitflags! {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkResolveModeFlagBitsKHR.html>"]
 #[doc(alias = "VkResolveModeFlagBitsKHR")]
 pub type ResolveModeFlagsKHR = ResolveModeFlags;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerReductionMode.html>"]
 #[doc(alias = "VkSamplerReductionMode")]
 #[repr(u32)]
@@ -3422,6 +3944,7 @@ pub enum SamplerReductionMode {
     WeightedAverage = 0,
     Min = 1,
     Max = 2,
+    #[cfg(feature = "vendor-qcom")]
     WeightedAverageRangeclampQCOM = 1000521000,
 }
 #[allow(non_upper_case_globals)]
@@ -3433,7 +3956,8 @@ impl SamplerReductionMode {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerReductionModeEXT.html>"]
 #[doc(alias = "VkSamplerReductionModeEXT")]
 pub type SamplerReductionModeEXT = SamplerReductionMode;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSemaphoreType.html>"]
 #[doc(alias = "VkSemaphoreType")]
 #[repr(u32)]
@@ -3452,7 +3976,8 @@ pub type SemaphoreTypeKHR = SemaphoreType;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSemaphoreWaitFlagBits.html>"]
     #[doc(alias = "VkSemaphoreWaitFlagBits")]
     pub struct SemaphoreWaitFlags : u32 {
@@ -3466,7 +3991,8 @@ pub type SemaphoreWaitFlagsKHR = SemaphoreWaitFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCreationFeedbackFlagBits.html>"]
     #[doc(alias = "VkPipelineCreationFeedbackFlagBits")]
     pub struct PipelineCreationFeedbackFlags : u32 {
@@ -3484,7 +4010,8 @@ pub type PipelineCreationFeedbackFlagsEXT = PipelineCreationFeedbackFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkToolPurposeFlagBits.html>"]
     #[doc(alias = "VkToolPurposeFlagBits")]
     pub struct ToolPurposeFlags : u32 {
@@ -3508,7 +4035,8 @@ pub type ToolPurposeFlagsEXT = ToolPurposeFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineStageFlagBits2.html>"]
     #[doc(alias = "VkPipelineStageFlagBits2")]
     pub struct PipelineStageFlags2 : u64 {
@@ -3564,27 +4092,39 @@ bitflags! {
         const VertexAttributeInputKHR = Self::VertexAttributeInput.bits();
         const PreRasterizationShaders = 1u64 << 38;
         const PreRasterizationShadersKHR = Self::PreRasterizationShaders.bits();
+        const VideoDecodeKHR = 1u64 << 26;
+        const VideoEncodeKHR = 1u64 << 27;
         const TransformFeedbackEXT = 1u64 << 24;
         const ConditionalRenderingEXT = 1u64 << 18;
         const CommandPreprocessNV = 1u64 << 17;
         const CommandPreprocessEXT = Self::CommandPreprocessNV.bits();
         const FragmentShadingRateAttachmentKHR = 1u64 << 22;
+        #[cfg(feature = "vendor-nv")]
         const ShadingRateImageNV = Self::FragmentShadingRateAttachmentKHR.bits();
         const AccelerationStructureBuildKHR = 1u64 << 25;
         const RayTracingShaderKHR = 1u64 << 21;
+        #[cfg(feature = "vendor-nv")]
         const RayTracingShaderNV = Self::RayTracingShaderKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const AccelerationStructureBuildNV = Self::AccelerationStructureBuildKHR.bits();
         const FragmentDensityProcessEXT = 1u64 << 23;
+        #[cfg(feature = "vendor-nv")]
         const TaskShaderNV = Self::TaskShaderEXT.bits();
+        #[cfg(feature = "vendor-nv")]
         const MeshShaderNV = Self::MeshShaderEXT.bits();
         const TaskShaderEXT = 1u64 << 19;
         const MeshShaderEXT = 1u64 << 20;
+        #[cfg(feature = "vendor-huawei")]
         const SubpassShaderHUAWEI = 1u64 << 39;
+        #[cfg(feature = "vendor-huawei")]
         const SubpassShadingHUAWEI = Self::SubpassShaderHUAWEI.bits();
+        #[cfg(feature = "vendor-huawei")]
         const InvocationMaskHUAWEI = 1u64 << 40;
         const AccelerationStructureCopyKHR = 1u64 << 28;
         const MicromapBuildEXT = 1u64 << 30;
+        #[cfg(feature = "vendor-huawei")]
         const ClusterCullingShaderHUAWEI = 1u64 << 41;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowNV = 1u64 << 29;
     }
 }
@@ -3594,7 +4134,8 @@ pub type PipelineStageFlags2KHR = PipelineStageFlags2;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccessFlagBits2.html>"]
     #[doc(alias = "VkAccessFlagBits2")]
     pub struct AccessFlags2 : u64 {
@@ -3640,6 +4181,10 @@ bitflags! {
         const ShaderStorageReadKHR = Self::ShaderStorageRead.bits();
         const ShaderStorageWrite = 1u64 << 34;
         const ShaderStorageWriteKHR = Self::ShaderStorageWrite.bits();
+        const VideoDecodeReadKHR = 1u64 << 35;
+        const VideoDecodeWriteKHR = 1u64 << 36;
+        const VideoEncodeReadKHR = 1u64 << 37;
+        const VideoEncodeWriteKHR = 1u64 << 38;
         const TransformFeedbackWriteEXT = 1u64 << 25;
         const TransformFeedbackCounterReadEXT = 1u64 << 26;
         const TransformFeedbackCounterWriteEXT = 1u64 << 27;
@@ -3649,19 +4194,25 @@ bitflags! {
         const CommandPreprocessReadEXT = Self::CommandPreprocessReadNV.bits();
         const CommandPreprocessWriteEXT = Self::CommandPreprocessWriteNV.bits();
         const FragmentShadingRateAttachmentReadKHR = 1u64 << 23;
+        #[cfg(feature = "vendor-nv")]
         const ShadingRateImageReadNV = Self::FragmentShadingRateAttachmentReadKHR.bits();
         const AccelerationStructureReadKHR = 1u64 << 21;
         const AccelerationStructureWriteKHR = 1u64 << 22;
+        #[cfg(feature = "vendor-nv")]
         const AccelerationStructureReadNV = Self::AccelerationStructureReadKHR.bits();
+        #[cfg(feature = "vendor-nv")]
         const AccelerationStructureWriteNV = Self::AccelerationStructureWriteKHR.bits();
         const FragmentDensityMapReadEXT = 1u64 << 24;
         const ColorAttachmentReadNoncoherentEXT = 1u64 << 19;
         const DescriptorBufferReadEXT = 1u64 << 41;
+        #[cfg(feature = "vendor-huawei")]
         const InvocationMaskReadHUAWEI = 1u64 << 39;
         const ShaderBindingTableReadKHR = 1u64 << 40;
         const MicromapReadEXT = 1u64 << 44;
         const MicromapWriteEXT = 1u64 << 45;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowReadNV = 1u64 << 42;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowWriteNV = 1u64 << 43;
     }
 }
@@ -3671,7 +4222,8 @@ pub type AccessFlags2KHR = AccessFlags2;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubmitFlagBits.html>"]
     #[doc(alias = "VkSubmitFlagBits")]
     pub struct SubmitFlags : u32 {
@@ -3685,7 +4237,8 @@ pub type SubmitFlagsKHR = SubmitFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRenderingFlagBits.html>"]
     #[doc(alias = "VkRenderingFlagBits")]
     pub struct RenderingFlags : u32 {
@@ -3706,7 +4259,8 @@ pub type RenderingFlagsKHR = RenderingFlags;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFormatFeatureFlagBits2.html>"]
     #[doc(alias = "VkFormatFeatureFlagBits2")]
     pub struct FormatFeatureFlags2 : u64 {
@@ -3764,17 +4318,29 @@ bitflags! {
         const StorageWriteWithoutFormatKHR = Self::StorageWriteWithoutFormat.bits();
         const SampledImageDepthComparison = 1u64 << 33;
         const SampledImageDepthComparisonKHR = Self::SampledImageDepthComparison.bits();
+        const VideoDecodeOutputKHR = 1u64 << 25;
+        const VideoDecodeDpbKHR = 1u64 << 26;
         const AccelerationStructureVertexBufferKHR = 1u64 << 29;
         const FragmentDensityMapEXT = 1u64 << 24;
         const FragmentShadingRateAttachmentKHR = 1u64 << 30;
         const HostImageTransferEXT = 1u64 << 46;
+        const VideoEncodeInputKHR = 1u64 << 27;
+        const VideoEncodeDpbKHR = 1u64 << 28;
+        #[cfg(feature = "vendor-nv")]
         const LinearColorAttachmentNV = 1u64 << 38;
+        #[cfg(feature = "vendor-qcom")]
         const WeightImageQCOM = 1u64 << 34;
+        #[cfg(feature = "vendor-qcom")]
         const WeightSampledImageQCOM = 1u64 << 35;
+        #[cfg(feature = "vendor-qcom")]
         const BlockMatchingQCOM = 1u64 << 36;
+        #[cfg(feature = "vendor-qcom")]
         const BoxFilterSampledQCOM = 1u64 << 37;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowImageNV = 1u64 << 40;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowVectorNV = 1u64 << 41;
+        #[cfg(feature = "vendor-nv")]
         const OpticalFlowCostNV = 1u64 << 42;
     }
 }
@@ -3784,7 +4350,8 @@ pub type FormatFeatureFlags2KHR = FormatFeatureFlags2;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSurfaceTransformFlagBitsKHR.html>"]
     #[doc(alias = "VkSurfaceTransformFlagBitsKHR")]
     pub struct SurfaceTransformFlagsKHR : u32 {
@@ -3799,7 +4366,8 @@ bitflags! {
         const Inherit = 1u32 << 8;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPresentModeKHR.html>"]
 #[doc(alias = "VkPresentModeKHR")]
 #[repr(u32)]
@@ -3812,7 +4380,8 @@ pub enum PresentModeKHR {
     SharedContinuousRefresh = 1000111001,
     FifoLatestReadyEXT = 1000361000,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkColorSpaceKHR.html>"]
 #[doc(alias = "VkColorSpaceKHR")]
 #[repr(u32)]
@@ -3832,6 +4401,7 @@ pub enum ColorSpaceKHR {
     AdobergbNonlinearEXT = 1000104012,
     PassThroughEXT = 1000104013,
     ExtendedSrgbNonlinearEXT = 1000104014,
+    #[cfg(feature = "vendor-amd")]
     DisplayNativeAMD = 1000213000,
 }
 #[allow(non_upper_case_globals)]
@@ -3842,7 +4412,8 @@ impl ColorSpaceKHR {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCompositeAlphaFlagBitsKHR.html>"]
     #[doc(alias = "VkCompositeAlphaFlagBitsKHR")]
     pub struct CompositeAlphaFlagsKHR : u32 {
@@ -3855,7 +4426,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSwapchainCreateFlagBitsKHR.html>"]
     #[doc(alias = "VkSwapchainCreateFlagBitsKHR")]
     pub struct SwapchainCreateFlagsKHR : u32 {
@@ -3868,7 +4440,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceGroupPresentModeFlagBitsKHR.html>"]
     #[doc(alias = "VkDeviceGroupPresentModeFlagBitsKHR")]
     pub struct DeviceGroupPresentModeFlagsKHR : u32 {
@@ -3881,7 +4454,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplayPlaneAlphaFlagBitsKHR.html>"]
     #[doc(alias = "VkDisplayPlaneAlphaFlagBitsKHR")]
     pub struct DisplayPlaneAlphaFlagsKHR : u32 {
@@ -3894,7 +4468,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugReportFlagBitsEXT.html>"]
     #[doc(alias = "VkDebugReportFlagBitsEXT")]
     pub struct DebugReportFlagsEXT : u32 {
@@ -3905,7 +4480,8 @@ bitflags! {
         const Debug = 1u32 << 4;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugReportObjectTypeEXT.html>"]
 #[doc(alias = "VkDebugReportObjectTypeEXT")]
 #[repr(u32)]
@@ -3944,11 +4520,16 @@ pub enum DebugReportObjectTypeEXT {
     ValidationCacheExt = 33,
     SamplerYcbcrConversion = 1000156000,
     DescriptorUpdateTemplate = 1000085000,
+    #[cfg(feature = "vendor-nvx")]
     CuModuleNVX = 1000029000,
+    #[cfg(feature = "vendor-nvx")]
     CuFunctionNVX = 1000029001,
     AccelerationStructureKHR = 1000150000,
+    #[cfg(feature = "vendor-nv")]
     AccelerationStructureNV = 1000165000,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaModuleNV = 1000307000,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     CudaFunctionNV = 1000307001,
     BufferCollectionFUCHSIA = 1000366000,
 }
@@ -3959,7 +4540,9 @@ impl DebugReportObjectTypeEXT {
     pub const DescriptorUpdateTemplateKHR: Self = Self::DescriptorUpdateTemplate;
     pub const SamplerYcbcrConversionKHR: Self = Self::SamplerYcbcrConversion;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-amd")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRasterizationOrderAMD.html>"]
 #[doc(alias = "VkRasterizationOrderAMD")]
 #[repr(u32)]
@@ -3967,7 +4550,299 @@ pub enum RasterizationOrderAMD {
     Strict = 0,
     Relaxed = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoCodecOperationFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoCodecOperationFlagBitsKHR")]
+    pub struct VideoCodecOperationFlagsKHR : u32 {
+        const None = 0;
+        const EncodeH264 = 1u32 << 16;
+        const EncodeH265 = 1u32 << 17;
+        const DecodeH264 = 1u32 << 0;
+        const DecodeH265 = 1u32 << 1;
+        const DecodeAv1 = 1u32 << 2;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoChromaSubsamplingFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoChromaSubsamplingFlagBitsKHR")]
+    pub struct VideoChromaSubsamplingFlagsKHR : u32 {
+        const Invalid = 0;
+        const Monochrome = 1u32 << 0;
+        const Chroma420 = 1u32 << 1;
+        const Chroma422 = 1u32 << 2;
+        const Chroma444 = 1u32 << 3;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoComponentBitDepthFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoComponentBitDepthFlagBitsKHR")]
+    pub struct VideoComponentBitDepthFlagsKHR : u32 {
+        const Invalid = 0;
+        const Depth8 = 1u32 << 0;
+        const Depth10 = 1u32 << 2;
+        const Depth12 = 1u32 << 4;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoCapabilityFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoCapabilityFlagBitsKHR")]
+    pub struct VideoCapabilityFlagsKHR : u32 {
+        const ProtectedContent = 1u32 << 0;
+        const SeparateReferenceImages = 1u32 << 1;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoSessionCreateFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoSessionCreateFlagBitsKHR")]
+    pub struct VideoSessionCreateFlagsKHR : u32 {
+        const ProtectedContent = 1u32 << 0;
+        const AllowEncodeParameterOptimizations = 1u32 << 1;
+        const InlineQueries = 1u32 << 2;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoCodingControlFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoCodingControlFlagBitsKHR")]
+    pub struct VideoCodingControlFlagsKHR : u32 {
+        const Reset = 1u32 << 0;
+        const EncodeRateControl = 1u32 << 1;
+        const EncodeQualityLevel = 1u32 << 2;
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryResultStatusKHR.html>"]
+#[doc(alias = "VkQueryResultStatusKHR")]
+#[repr(i32)]
+pub enum QueryResultStatusKHR {
+    Error = -1,
+    NotReady = 0,
+    Complete = 1,
+    InsufficientBitstreamBufferRange = -1000299000,
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoDecodeCapabilityFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoDecodeCapabilityFlagBitsKHR")]
+    pub struct VideoDecodeCapabilityFlagsKHR : u32 {
+        const DpbAndOutputCoincide = 1u32 << 0;
+        const DpbAndOutputDistinct = 1u32 << 1;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoDecodeUsageFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoDecodeUsageFlagBitsKHR")]
+    pub struct VideoDecodeUsageFlagsKHR : u32 {
+        const Default = 0;
+        const Transcoding = 1u32 << 0;
+        const Offline = 1u32 << 1;
+        const Streaming = 1u32 << 2;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH264CapabilityFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH264CapabilityFlagBitsKHR")]
+    pub struct VideoEncodeH264CapabilityFlagsKHR : u32 {
+        const HrdCompliance = 1u32 << 0;
+        const PredictionWeightTableGenerated = 1u32 << 1;
+        const RowUnalignedSlice = 1u32 << 2;
+        const DifferentSliceType = 1u32 << 3;
+        const BFrameInL0List = 1u32 << 4;
+        const BFrameInL1List = 1u32 << 5;
+        const PerPictureTypeMinMaxQp = 1u32 << 6;
+        const PerSliceConstantQp = 1u32 << 7;
+        const GeneratePrefixNalu = 1u32 << 8;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH264StdFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH264StdFlagBitsKHR")]
+    pub struct VideoEncodeH264StdFlagsKHR : u32 {
+        const SeparateColorPlaneFlagSet = 1u32 << 0;
+        const QpprimeYZeroTransformBypassFlagSet = 1u32 << 1;
+        const ScalingMatrixPresentFlagSet = 1u32 << 2;
+        const ChromaQpIndexOffset = 1u32 << 3;
+        const SecondChromaQpIndexOffset = 1u32 << 4;
+        const PicInitQpMinus26 = 1u32 << 5;
+        const WeightedPredFlagSet = 1u32 << 6;
+        const WeightedBipredIdcExplicit = 1u32 << 7;
+        const WeightedBipredIdcImplicit = 1u32 << 8;
+        const Transform8X8ModeFlagSet = 1u32 << 9;
+        const DirectSpatialMvPredFlagUnset = 1u32 << 10;
+        const EntropyCodingModeFlagUnset = 1u32 << 11;
+        const EntropyCodingModeFlagSet = 1u32 << 12;
+        const Direct8X8InferenceFlagUnset = 1u32 << 13;
+        const ConstrainedIntraPredFlagSet = 1u32 << 14;
+        const DeblockingFilterDisabled = 1u32 << 15;
+        const DeblockingFilterEnabled = 1u32 << 16;
+        const DeblockingFilterPartial = 1u32 << 17;
+        const SliceQpDelta = 1u32 << 19;
+        const DifferentSliceQpDelta = 1u32 << 20;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH264RateControlFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH264RateControlFlagBitsKHR")]
+    pub struct VideoEncodeH264RateControlFlagsKHR : u32 {
+        const AttemptHrdCompliance = 1u32 << 0;
+        const RegularGop = 1u32 << 1;
+        const ReferencePatternFlat = 1u32 << 2;
+        const ReferencePatternDyadic = 1u32 << 3;
+        const TemporalLayerPatternDyadic = 1u32 << 4;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265CapabilityFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH265CapabilityFlagBitsKHR")]
+    pub struct VideoEncodeH265CapabilityFlagsKHR : u32 {
+        const HrdCompliance = 1u32 << 0;
+        const PredictionWeightTableGenerated = 1u32 << 1;
+        const RowUnalignedSliceSegment = 1u32 << 2;
+        const DifferentSliceSegmentType = 1u32 << 3;
+        const BFrameInL0List = 1u32 << 4;
+        const BFrameInL1List = 1u32 << 5;
+        const PerPictureTypeMinMaxQp = 1u32 << 6;
+        const PerSliceSegmentConstantQp = 1u32 << 7;
+        const MultipleTilesPerSliceSegment = 1u32 << 8;
+        const MultipleSliceSegmentsPerTile = 1u32 << 9;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265StdFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH265StdFlagBitsKHR")]
+    pub struct VideoEncodeH265StdFlagsKHR : u32 {
+        const SeparateColorPlaneFlagSet = 1u32 << 0;
+        const SampleAdaptiveOffsetEnabledFlagSet = 1u32 << 1;
+        const ScalingListDataPresentFlagSet = 1u32 << 2;
+        const PcmEnabledFlagSet = 1u32 << 3;
+        const SpsTemporalMvpEnabledFlagSet = 1u32 << 4;
+        const InitQpMinus26 = 1u32 << 5;
+        const WeightedPredFlagSet = 1u32 << 6;
+        const WeightedBipredFlagSet = 1u32 << 7;
+        const Log2ParallelMergeLevelMinus2 = 1u32 << 8;
+        const SignDataHidingEnabledFlagSet = 1u32 << 9;
+        const TransformSkipEnabledFlagSet = 1u32 << 10;
+        const TransformSkipEnabledFlagUnset = 1u32 << 11;
+        const PpsSliceChromaQpOffsetsPresentFlagSet = 1u32 << 12;
+        const TransquantBypassEnabledFlagSet = 1u32 << 13;
+        const ConstrainedIntraPredFlagSet = 1u32 << 14;
+        const EntropyCodingSyncEnabledFlagSet = 1u32 << 15;
+        const DeblockingFilterOverrideEnabledFlagSet = 1u32 << 16;
+        const DependentSliceSegmentsEnabledFlagSet = 1u32 << 17;
+        const DependentSliceSegmentFlagSet = 1u32 << 18;
+        const SliceQpDelta = 1u32 << 19;
+        const DifferentSliceQpDelta = 1u32 << 20;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265CtbSizeFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH265CtbSizeFlagBitsKHR")]
+    pub struct VideoEncodeH265CtbSizeFlagsKHR : u32 {
+        const Size16 = 1u32 << 0;
+        const Size32 = 1u32 << 1;
+        const Size64 = 1u32 << 2;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265TransformBlockSizeFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH265TransformBlockSizeFlagBitsKHR")]
+    pub struct VideoEncodeH265TransformBlockSizeFlagsKHR : u32 {
+        const Size4 = 1u32 << 0;
+        const Size8 = 1u32 << 1;
+        const Size16 = 1u32 << 2;
+        const Size32 = 1u32 << 3;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265RateControlFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeH265RateControlFlagBitsKHR")]
+    pub struct VideoEncodeH265RateControlFlagsKHR : u32 {
+        const AttemptHrdCompliance = 1u32 << 0;
+        const RegularGop = 1u32 << 1;
+        const ReferencePatternFlat = 1u32 << 2;
+        const ReferencePatternDyadic = 1u32 << 3;
+        const TemporalSubLayerPatternDyadic = 1u32 << 4;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoDecodeH264PictureLayoutFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoDecodeH264PictureLayoutFlagBitsKHR")]
+    pub struct VideoDecodeH264PictureLayoutFlagsKHR : u32 {
+        const Progressive = 0;
+        const InterlacedInterleavedLines = 1u32 << 0;
+        const InterlacedSeparatePlanes = 1u32 << 1;
+    }
+}
+#[cfg(feature = "vendor-amd")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderInfoTypeAMD.html>"]
 #[doc(alias = "VkShaderInfoTypeAMD")]
 #[repr(u32)]
@@ -3976,10 +4851,12 @@ pub enum ShaderInfoTypeAMD {
     Binary = 1,
     Disassembly = 2,
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryHandleTypeFlagBitsNV.html>"]
     #[doc(alias = "VkExternalMemoryHandleTypeFlagBitsNV")]
     pub struct ExternalMemoryHandleTypeFlagsNV : u32 {
@@ -3989,10 +4866,12 @@ bitflags! {
         const D3D11ImageKmt = 1u32 << 3;
     }
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryFeatureFlagBitsNV.html>"]
     #[doc(alias = "VkExternalMemoryFeatureFlagBitsNV")]
     pub struct ExternalMemoryFeatureFlagsNV : u32 {
@@ -4001,7 +4880,8 @@ bitflags! {
         const Importable = 1u32 << 2;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkValidationCheckEXT.html>"]
 #[doc(alias = "VkValidationCheckEXT")]
 #[repr(u32)]
@@ -4009,7 +4889,8 @@ pub enum ValidationCheckEXT {
     All = 0,
     Shaders = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineRobustnessBufferBehaviorEXT.html>"]
 #[doc(alias = "VkPipelineRobustnessBufferBehaviorEXT")]
 #[repr(u32)]
@@ -4019,7 +4900,8 @@ pub enum PipelineRobustnessBufferBehaviorEXT {
     RobustBufferAccess = 2,
     RobustBufferAccess2 = 3,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineRobustnessImageBehaviorEXT.html>"]
 #[doc(alias = "VkPipelineRobustnessImageBehaviorEXT")]
 #[repr(u32)]
@@ -4041,7 +4923,8 @@ pub const QUEUE_FAMILY_EXTERNAL_KHR: u32 = QUEUE_FAMILY_EXTERNAL;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkConditionalRenderingFlagBitsEXT.html>"]
     #[doc(alias = "VkConditionalRenderingFlagBitsEXT")]
     pub struct ConditionalRenderingFlagsEXT : u32 {
@@ -4051,14 +4934,16 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSurfaceCounterFlagBitsEXT.html>"]
     #[doc(alias = "VkSurfaceCounterFlagBitsEXT")]
     pub struct SurfaceCounterFlagsEXT : u32 {
         const Vblank = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplayPowerStateEXT.html>"]
 #[doc(alias = "VkDisplayPowerStateEXT")]
 #[repr(u32)]
@@ -4067,21 +4952,25 @@ pub enum DisplayPowerStateEXT {
     Suspend = 1,
     On = 2,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceEventTypeEXT.html>"]
 #[doc(alias = "VkDeviceEventTypeEXT")]
 #[repr(u32)]
 pub enum DeviceEventTypeEXT {
     DisplayHotplug = 0,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplayEventTypeEXT.html>"]
 #[doc(alias = "VkDisplayEventTypeEXT")]
 #[repr(u32)]
 pub enum DisplayEventTypeEXT {
     FirstPixelOut = 0,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkViewportCoordinateSwizzleNV.html>"]
 #[doc(alias = "VkViewportCoordinateSwizzleNV")]
 #[repr(u32)]
@@ -4095,7 +4984,8 @@ pub enum ViewportCoordinateSwizzleNV {
     PositiveW = 6,
     NegativeW = 7,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDiscardRectangleModeEXT.html>"]
 #[doc(alias = "VkDiscardRectangleModeEXT")]
 #[repr(u32)]
@@ -4103,7 +4993,8 @@ pub enum DiscardRectangleModeEXT {
     Inclusive = 0,
     Exclusive = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkConservativeRasterizationModeEXT.html>"]
 #[doc(alias = "VkConservativeRasterizationModeEXT")]
 #[repr(u32)]
@@ -4115,7 +5006,8 @@ pub enum ConservativeRasterizationModeEXT {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceCounterDescriptionFlagBitsKHR.html>"]
     #[doc(alias = "VkPerformanceCounterDescriptionFlagBitsKHR")]
     pub struct PerformanceCounterDescriptionFlagsKHR : u32 {
@@ -4123,7 +5015,8 @@ bitflags! {
         const ConcurrentlyImpacted = 1u32 << 1;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceCounterScopeKHR.html>"]
 #[doc(alias = "VkPerformanceCounterScopeKHR")]
 #[repr(u32)]
@@ -4138,7 +5031,8 @@ impl PerformanceCounterScopeKHR {
     pub const QueryScopeRenderPass: Self = Self::RenderPass;
     pub const QueryScopeCommand: Self = Self::Command;
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceCounterStorageKHR.html>"]
 #[doc(alias = "VkPerformanceCounterStorageKHR")]
 #[repr(u32)]
@@ -4150,7 +5044,8 @@ pub enum PerformanceCounterStorageKHR {
     Float32 = 4,
     Float64 = 5,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceCounterUnitKHR.html>"]
 #[doc(alias = "VkPerformanceCounterUnitKHR")]
 #[repr(u32)]
@@ -4170,7 +5065,8 @@ pub enum PerformanceCounterUnitKHR {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAcquireProfilingLockFlagBitsKHR.html>"]
     #[doc(alias = "VkAcquireProfilingLockFlagBitsKHR")]
     pub struct AcquireProfilingLockFlagsKHR : u32 {
@@ -4182,7 +5078,8 @@ pub const QUEUE_FAMILY_FOREIGN_EXT: u32 = !2u32;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessageSeverityFlagBitsEXT.html>"]
     #[doc(alias = "VkDebugUtilsMessageSeverityFlagBitsEXT")]
     pub struct DebugUtilsMessageSeverityFlagsEXT : u32 {
@@ -4195,7 +5092,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessageTypeFlagBitsEXT.html>"]
     #[doc(alias = "VkDebugUtilsMessageTypeFlagBitsEXT")]
     pub struct DebugUtilsMessageTypeFlagsEXT : u32 {
@@ -4205,10 +5103,12 @@ bitflags! {
         const DeviceAddressBinding = 1u32 << 3;
     }
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_SHADER_INDEX_UNUSED_AMDX.html>"]
 #[doc(alias = "VK_SHADER_INDEX_UNUSED_AMDX")]
 pub const SHADER_INDEX_UNUSED_AMDX: u32 = !0u32;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBlendOverlapEXT.html>"]
 #[doc(alias = "VkBlendOverlapEXT")]
 #[repr(u32)]
@@ -4217,7 +5117,8 @@ pub enum BlendOverlapEXT {
     Disjoint = 1,
     Conjoint = 2,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureTypeKHR.html>"]
 #[doc(alias = "VkAccelerationStructureTypeKHR")]
 #[repr(u32)]
@@ -4228,13 +5129,17 @@ pub enum AccelerationStructureTypeKHR {
 }
 #[allow(non_upper_case_globals)]
 impl AccelerationStructureTypeKHR {
+    #[cfg(feature = "vendor-nv")]
     pub const TopLevelNV: Self = Self::TopLevel;
+    #[cfg(feature = "vendor-nv")]
     pub const BottomLevelNV: Self = Self::BottomLevel;
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureTypeNV.html>"]
 #[doc(alias = "VkAccelerationStructureTypeNV")]
 pub type AccelerationStructureTypeNV = AccelerationStructureTypeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureBuildTypeKHR.html>"]
 #[doc(alias = "VkAccelerationStructureBuildTypeKHR")]
 #[repr(u32)]
@@ -4246,23 +5151,28 @@ pub enum AccelerationStructureBuildTypeKHR {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryFlagBitsKHR.html>"]
     #[doc(alias = "VkGeometryFlagBitsKHR")]
     pub struct GeometryFlagsKHR : u32 {
         const Opaque = 1u32 << 0;
         const NoDuplicateAnyHitInvocation = 1u32 << 1;
+        #[cfg(feature = "vendor-nv")]
         const OpaqueNV = Self::Opaque.bits();
+        #[cfg(feature = "vendor-nv")]
         const NoDuplicateAnyHitInvocationNV = Self::NoDuplicateAnyHitInvocation.bits();
     }
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryFlagBitsNV.html>"]
 #[doc(alias = "VkGeometryFlagBitsNV")]
 pub type GeometryFlagsNV = GeometryFlagsKHR;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryInstanceFlagBitsKHR.html>"]
     #[doc(alias = "VkGeometryInstanceFlagBitsKHR")]
     pub struct GeometryInstanceFlagsKHR : u32 {
@@ -4271,21 +5181,27 @@ bitflags! {
         const ForceOpaque = 1u32 << 2;
         const ForceNoOpaque = 1u32 << 3;
         const TriangleFrontCounterclockwise = Self::TriangleFlipFacing.bits();
+        #[cfg(feature = "vendor-nv")]
         const TriangleCullDisableNV = Self::TriangleFacingCullDisable.bits();
+        #[cfg(feature = "vendor-nv")]
         const TriangleFrontCounterclockwiseNV = Self::TriangleFrontCounterclockwise.bits();
+        #[cfg(feature = "vendor-nv")]
         const ForceOpaqueNV = Self::ForceOpaque.bits();
+        #[cfg(feature = "vendor-nv")]
         const ForceNoOpaqueNV = Self::ForceNoOpaque.bits();
         const ForceOpacityMicromap2StateEXT = 1u32 << 4;
         const DisableOpacityMicromapsEXT = 1u32 << 5;
     }
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryInstanceFlagBitsNV.html>"]
 #[doc(alias = "VkGeometryInstanceFlagBitsNV")]
 pub type GeometryInstanceFlagsNV = GeometryInstanceFlagsKHR;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildAccelerationStructureFlagBitsKHR.html>"]
     #[doc(alias = "VkBuildAccelerationStructureFlagBitsKHR")]
     pub struct BuildAccelerationStructureFlagsKHR : u32 {
@@ -4294,23 +5210,32 @@ bitflags! {
         const PreferFastTrace = 1u32 << 2;
         const PreferFastBuild = 1u32 << 3;
         const LowMemory = 1u32 << 4;
+        #[cfg(feature = "vendor-nv")]
         const AllowUpdateNV = Self::AllowUpdate.bits();
+        #[cfg(feature = "vendor-nv")]
         const AllowCompactionNV = Self::AllowCompaction.bits();
+        #[cfg(feature = "vendor-nv")]
         const PreferFastTraceNV = Self::PreferFastTrace.bits();
+        #[cfg(feature = "vendor-nv")]
         const PreferFastBuildNV = Self::PreferFastBuild.bits();
+        #[cfg(feature = "vendor-nv")]
         const LowMemoryNV = Self::LowMemory.bits();
+        #[cfg(feature = "vendor-nv")]
         const MotionNV = 1u32 << 5;
         const AllowOpacityMicromapUpdateEXT = 1u32 << 6;
         const AllowDisableOpacityMicromapsEXT = 1u32 << 7;
         const AllowOpacityMicromapDataUpdateEXT = 1u32 << 8;
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         const AllowDisplacementMicromapUpdateNV = 1u32 << 9;
         const AllowDataAccess = 1u32 << 11;
     }
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildAccelerationStructureFlagBitsNV.html>"]
 #[doc(alias = "VkBuildAccelerationStructureFlagBitsNV")]
 pub type BuildAccelerationStructureFlagsNV = BuildAccelerationStructureFlagsKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCopyAccelerationStructureModeKHR.html>"]
 #[doc(alias = "VkCopyAccelerationStructureModeKHR")]
 #[repr(u32)]
@@ -4322,13 +5247,17 @@ pub enum CopyAccelerationStructureModeKHR {
 }
 #[allow(non_upper_case_globals)]
 impl CopyAccelerationStructureModeKHR {
+    #[cfg(feature = "vendor-nv")]
     pub const CloneNV: Self = Self::Clone;
+    #[cfg(feature = "vendor-nv")]
     pub const CompactNV: Self = Self::Compact;
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCopyAccelerationStructureModeNV.html>"]
 #[doc(alias = "VkCopyAccelerationStructureModeNV")]
 pub type CopyAccelerationStructureModeNV = CopyAccelerationStructureModeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryTypeKHR.html>"]
 #[doc(alias = "VkGeometryTypeKHR")]
 #[repr(u32)]
@@ -4339,13 +5268,17 @@ pub enum GeometryTypeKHR {
 }
 #[allow(non_upper_case_globals)]
 impl GeometryTypeKHR {
+    #[cfg(feature = "vendor-nv")]
     pub const TrianglesNV: Self = Self::Triangles;
+    #[cfg(feature = "vendor-nv")]
     pub const AabbsNV: Self = Self::Aabbs;
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryTypeNV.html>"]
 #[doc(alias = "VkGeometryTypeNV")]
 pub type GeometryTypeNV = GeometryTypeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureCompatibilityKHR.html>"]
 #[doc(alias = "VkAccelerationStructureCompatibilityKHR")]
 #[repr(u32)]
@@ -4356,16 +5289,19 @@ pub enum AccelerationStructureCompatibilityKHR {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureCreateFlagBitsKHR.html>"]
     #[doc(alias = "VkAccelerationStructureCreateFlagBitsKHR")]
     pub struct AccelerationStructureCreateFlagsKHR : u32 {
         const DeviceAddressCaptureReplay = 1u32 << 0;
         const DescriptorBufferCaptureReplayEXT = 1u32 << 3;
+        #[cfg(feature = "vendor-nv")]
         const MotionNV = 1u32 << 2;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildAccelerationStructureModeKHR.html>"]
 #[doc(alias = "VkBuildAccelerationStructureModeKHR")]
 #[repr(u32)]
@@ -4376,7 +5312,8 @@ pub enum BuildAccelerationStructureModeKHR {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_SHADER_UNUSED_KHR.html>"]
 #[doc(alias = "VK_SHADER_UNUSED_KHR")]
 pub const SHADER_UNUSED_KHR: u32 = !0u32;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRayTracingShaderGroupTypeKHR.html>"]
 #[doc(alias = "VkRayTracingShaderGroupTypeKHR")]
 #[repr(u32)]
@@ -4387,14 +5324,19 @@ pub enum RayTracingShaderGroupTypeKHR {
 }
 #[allow(non_upper_case_globals)]
 impl RayTracingShaderGroupTypeKHR {
+    #[cfg(feature = "vendor-nv")]
     pub const GeneralNV: Self = Self::General;
+    #[cfg(feature = "vendor-nv")]
     pub const TrianglesHitGroupNV: Self = Self::TrianglesHitGroup;
+    #[cfg(feature = "vendor-nv")]
     pub const ProceduralHitGroupNV: Self = Self::ProceduralHitGroup;
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRayTracingShaderGroupTypeNV.html>"]
 #[doc(alias = "VkRayTracingShaderGroupTypeNV")]
 pub type RayTracingShaderGroupTypeNV = RayTracingShaderGroupTypeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderGroupShaderKHR.html>"]
 #[doc(alias = "VkShaderGroupShaderKHR")]
 #[repr(u32)]
@@ -4404,7 +5346,9 @@ pub enum ShaderGroupShaderKHR {
     AnyHit = 2,
     Intersection = 3,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCoverageModulationModeNV.html>"]
 #[doc(alias = "VkCoverageModulationModeNV")]
 #[repr(u32)]
@@ -4414,14 +5358,17 @@ pub enum CoverageModulationModeNV {
     Alpha = 2,
     Rgba = 3,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkValidationCacheHeaderVersionEXT.html>"]
 #[doc(alias = "VkValidationCacheHeaderVersionEXT")]
 #[repr(u32)]
 pub enum ValidationCacheHeaderVersionEXT {
     One = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShadingRatePaletteEntryNV.html>"]
 #[doc(alias = "VkShadingRatePaletteEntryNV")]
 #[repr(u32)]
@@ -4439,7 +5386,9 @@ pub enum ShadingRatePaletteEntryNV {
     Rate1InvocationPer2X4Pixels = 10,
     Rate1InvocationPer4X4Pixels = 11,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCoarseSampleOrderTypeNV.html>"]
 #[doc(alias = "VkCoarseSampleOrderTypeNV")]
 #[repr(u32)]
@@ -4449,10 +5398,13 @@ pub enum CoarseSampleOrderTypeNV {
     PixelMajor = 2,
     SampleMajor = 3,
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_SHADER_UNUSED_NV.html>"]
 #[doc(alias = "VK_SHADER_UNUSED_NV")]
 pub const SHADER_UNUSED_NV: u32 = SHADER_UNUSED_KHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureMemoryRequirementsTypeNV.html>"]
 #[doc(alias = "VkAccelerationStructureMemoryRequirementsTypeNV")]
 #[repr(u32)]
@@ -4461,10 +5413,12 @@ pub enum AccelerationStructureMemoryRequirementsTypeNV {
     BuildScratch = 1,
     UpdateScratch = 2,
 }
+#[cfg(feature = "vendor-amd")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCompilerControlFlagBitsAMD.html>"]
     #[doc(alias = "VkPipelineCompilerControlFlagBitsAMD")]
     pub struct PipelineCompilerControlFlagsAMD : u32 {
@@ -4473,7 +5427,8 @@ bitflags! {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_GLOBAL_PRIORITY_SIZE_KHR.html>"]
 #[doc(alias = "VK_MAX_GLOBAL_PRIORITY_SIZE_KHR")]
 pub const MAX_GLOBAL_PRIORITY_SIZE_KHR: u32 = 16;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueueGlobalPriorityKHR.html>"]
 #[doc(alias = "VkQueueGlobalPriorityKHR")]
 #[repr(u32)]
@@ -4493,7 +5448,9 @@ impl QueueGlobalPriorityKHR {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueueGlobalPriorityEXT.html>"]
 #[doc(alias = "VkQueueGlobalPriorityEXT")]
 pub type QueueGlobalPriorityEXT = QueueGlobalPriorityKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-amd")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryOverallocationBehaviorAMD.html>"]
 #[doc(alias = "VkMemoryOverallocationBehaviorAMD")]
 #[repr(u32)]
@@ -4508,21 +5465,27 @@ pub const MAX_DRIVER_NAME_SIZE_KHR: u32 = MAX_DRIVER_NAME_SIZE;
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_DRIVER_INFO_SIZE_KHR.html>"]
 #[doc(alias = "VK_MAX_DRIVER_INFO_SIZE_KHR")]
 pub const MAX_DRIVER_INFO_SIZE_KHR: u32 = MAX_DRIVER_INFO_SIZE;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-intel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceConfigurationTypeINTEL.html>"]
 #[doc(alias = "VkPerformanceConfigurationTypeINTEL")]
 #[repr(u32)]
 pub enum PerformanceConfigurationTypeINTEL {
     CommandQueueMetricsDiscoveryActivated = 0,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-intel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryPoolSamplingModeINTEL.html>"]
 #[doc(alias = "VkQueryPoolSamplingModeINTEL")]
 #[repr(u32)]
 pub enum QueryPoolSamplingModeINTEL {
     Manual = 0,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-intel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceOverrideTypeINTEL.html>"]
 #[doc(alias = "VkPerformanceOverrideTypeINTEL")]
 #[repr(u32)]
@@ -4530,7 +5493,9 @@ pub enum PerformanceOverrideTypeINTEL {
     NullHardware = 0,
     FlushGpuCaches = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-intel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceParameterTypeINTEL.html>"]
 #[doc(alias = "VkPerformanceParameterTypeINTEL")]
 #[repr(u32)]
@@ -4538,7 +5503,9 @@ pub enum PerformanceParameterTypeINTEL {
     HwCountersSupported = 0,
     StreamMarkerValidBits = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-intel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceValueTypeINTEL.html>"]
 #[doc(alias = "VkPerformanceValueTypeINTEL")]
 #[repr(u32)]
@@ -4549,7 +5516,8 @@ pub enum PerformanceValueTypeINTEL {
     Bool = 3,
     String = 4,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFragmentShadingRateCombinerOpKHR.html>"]
 #[doc(alias = "VkFragmentShadingRateCombinerOpKHR")]
 #[repr(u32)]
@@ -4560,16 +5528,19 @@ pub enum FragmentShadingRateCombinerOpKHR {
     Max = 3,
     Mul = 4,
 }
+#[cfg(feature = "vendor-amd")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderCorePropertiesFlagBitsAMD.html>"]
     #[doc(alias = "VkShaderCorePropertiesFlagBitsAMD")]
     pub struct ShaderCorePropertiesFlagsAMD : u32 {
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkValidationFeatureEnableEXT.html>"]
 #[doc(alias = "VkValidationFeatureEnableEXT")]
 #[repr(u32)]
@@ -4580,7 +5551,8 @@ pub enum ValidationFeatureEnableEXT {
     DebugPrintf = 3,
     SynchronizationValidation = 4,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkValidationFeatureDisableEXT.html>"]
 #[doc(alias = "VkValidationFeatureDisableEXT")]
 #[repr(u32)]
@@ -4594,7 +5566,9 @@ pub enum ValidationFeatureDisableEXT {
     UniqueHandles = 6,
     ShaderValidationCache = 7,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCoverageReductionModeNV.html>"]
 #[doc(alias = "VkCoverageReductionModeNV")]
 #[repr(u32)]
@@ -4602,7 +5576,8 @@ pub enum CoverageReductionModeNV {
     Merge = 0,
     Truncate = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkProvokingVertexModeEXT.html>"]
 #[doc(alias = "VkProvokingVertexModeEXT")]
 #[repr(u32)]
@@ -4610,7 +5585,9 @@ pub enum ProvokingVertexModeEXT {
     FirstVertex = 0,
     LastVertex = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFullScreenExclusiveEXT.html>"]
 #[doc(alias = "VkFullScreenExclusiveEXT")]
 #[repr(u32)]
@@ -4620,7 +5597,8 @@ pub enum FullScreenExclusiveEXT {
     Disallowed = 2,
     ApplicationControlled = 3,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineExecutableStatisticFormatKHR.html>"]
 #[doc(alias = "VkPipelineExecutableStatisticFormatKHR")]
 #[repr(u32)]
@@ -4633,7 +5611,8 @@ pub enum PipelineExecutableStatisticFormatKHR {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkHostImageCopyFlagBitsEXT.html>"]
     #[doc(alias = "VkHostImageCopyFlagBitsEXT")]
     pub struct HostImageCopyFlagsEXT : u32 {
@@ -4643,7 +5622,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryUnmapFlagBitsKHR.html>"]
     #[doc(alias = "VkMemoryUnmapFlagBitsKHR")]
     pub struct MemoryUnmapFlagsKHR : u32 {
@@ -4653,7 +5633,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPresentScalingFlagBitsEXT.html>"]
     #[doc(alias = "VkPresentScalingFlagBitsEXT")]
     pub struct PresentScalingFlagsEXT : u32 {
@@ -4665,7 +5646,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPresentGravityFlagBitsEXT.html>"]
     #[doc(alias = "VkPresentGravityFlagBitsEXT")]
     pub struct PresentGravityFlagsEXT : u32 {
@@ -4674,17 +5656,21 @@ bitflags! {
         const Centered = 1u32 << 2;
     }
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectStateFlagBitsNV.html>"]
     #[doc(alias = "VkIndirectStateFlagBitsNV")]
     pub struct IndirectStateFlagsNV : u32 {
         const FlagFrontface = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsTokenTypeNV.html>"]
 #[doc(alias = "VkIndirectCommandsTokenTypeNV")]
 #[repr(u32)]
@@ -4701,10 +5687,12 @@ pub enum IndirectCommandsTokenTypeNV {
     Pipeline = 1000428003,
     Dispatch = 1000428004,
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsLayoutUsageFlagBitsNV.html>"]
     #[doc(alias = "VkIndirectCommandsLayoutUsageFlagBitsNV")]
     pub struct IndirectCommandsLayoutUsageFlagsNV : u32 {
@@ -4713,7 +5701,8 @@ bitflags! {
         const UnorderedSequences = 1u32 << 2;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDepthBiasRepresentationEXT.html>"]
 #[doc(alias = "VkDepthBiasRepresentationEXT")]
 #[repr(u32)]
@@ -4722,7 +5711,8 @@ pub enum DepthBiasRepresentationEXT {
     LeastRepresentableValueForceUnorm = 1,
     Float = 2,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceMemoryReportEventTypeEXT.html>"]
 #[doc(alias = "VkDeviceMemoryReportEventTypeEXT")]
 #[repr(u32)]
@@ -4736,7 +5726,8 @@ pub enum DeviceMemoryReportEventTypeEXT {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCacheCreateFlagBits.html>"]
     #[doc(alias = "VkPipelineCacheCreateFlagBits")]
     pub struct PipelineCacheCreateFlags : u32 {
@@ -4747,7 +5738,89 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeCapabilityFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeCapabilityFlagBitsKHR")]
+    pub struct VideoEncodeCapabilityFlagsKHR : u32 {
+        const PrecedingExternallyEncodedBytes = 1u32 << 0;
+        const InsufficientBitstreamBufferRangeDetection = 1u32 << 1;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeRateControlModeFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeRateControlModeFlagBitsKHR")]
+    pub struct VideoEncodeRateControlModeFlagsKHR : u32 {
+        const Default = 0;
+        const Disabled = 1u32 << 0;
+        const Cbr = 1u32 << 1;
+        const Vbr = 1u32 << 2;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeFeedbackFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeFeedbackFlagBitsKHR")]
+    pub struct VideoEncodeFeedbackFlagsKHR : u32 {
+        const BitstreamBufferOffset = 1u32 << 0;
+        const BitstreamBytesWritten = 1u32 << 1;
+        const BitstreamHasOverrides = 1u32 << 2;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeUsageFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeUsageFlagBitsKHR")]
+    pub struct VideoEncodeUsageFlagsKHR : u32 {
+        const Default = 0;
+        const Transcoding = 1u32 << 0;
+        const Streaming = 1u32 << 1;
+        const Recording = 1u32 << 2;
+        const Conferencing = 1u32 << 3;
+    }
+}
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeContentFlagBitsKHR.html>"]
+    #[doc(alias = "VkVideoEncodeContentFlagBitsKHR")]
+    pub struct VideoEncodeContentFlagsKHR : u32 {
+        const Default = 0;
+        const Camera = 1u32 << 0;
+        const Desktop = 1u32 << 1;
+        const Rendered = 1u32 << 2;
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeTuningModeKHR.html>"]
+#[doc(alias = "VkVideoEncodeTuningModeKHR")]
+#[repr(u32)]
+pub enum VideoEncodeTuningModeKHR {
+    Default = 0,
+    HighQuality = 1,
+    LowLatency = 2,
+    UltraLowLatency = 3,
+    Lossless = 4,
+}
+#[cfg(feature = "vendor-nv")]
+bitflags! {
+    #[derive(Default)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceDiagnosticsConfigFlagBitsNV.html>"]
     #[doc(alias = "VkDeviceDiagnosticsConfigFlagBitsNV")]
     pub struct DeviceDiagnosticsConfigFlagsNV : u32 {
@@ -4757,10 +5830,12 @@ bitflags! {
         const EnableShaderErrorReporting = 1u32 << 3;
     }
 }
+#[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExportMetalObjectTypeFlagBitsEXT.html>"]
     #[doc(alias = "VkExportMetalObjectTypeFlagBitsEXT")]
     pub struct ExportMetalObjectTypeFlagsEXT : u32 {
@@ -4775,7 +5850,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGraphicsPipelineLibraryFlagBitsEXT.html>"]
     #[doc(alias = "VkGraphicsPipelineLibraryFlagBitsEXT")]
     pub struct GraphicsPipelineLibraryFlagsEXT : u32 {
@@ -4788,14 +5864,17 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineLayoutCreateFlagBits.html>"]
     #[doc(alias = "VkPipelineLayoutCreateFlagBits")]
     pub struct PipelineLayoutCreateFlags : u32 {
         const IndependentSetsEXT = 1u32 << 1;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFragmentShadingRateNV.html>"]
 #[doc(alias = "VkFragmentShadingRateNV")]
 #[repr(u32)]
@@ -4813,7 +5892,9 @@ pub enum FragmentShadingRateNV {
     Rate16InvocationsPerPixel = 14,
     NoInvocations = 15,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFragmentShadingRateTypeNV.html>"]
 #[doc(alias = "VkFragmentShadingRateTypeNV")]
 #[repr(u32)]
@@ -4821,7 +5902,9 @@ pub enum FragmentShadingRateTypeNV {
     FragmentSize = 0,
     Enums = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureMotionInstanceTypeNV.html>"]
 #[doc(alias = "VkAccelerationStructureMotionInstanceTypeNV")]
 #[repr(u32)]
@@ -4833,7 +5916,8 @@ pub enum AccelerationStructureMotionInstanceTypeNV {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageCompressionFlagBitsEXT.html>"]
     #[doc(alias = "VkImageCompressionFlagBitsEXT")]
     pub struct ImageCompressionFlagsEXT : u32 {
@@ -4846,7 +5930,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageCompressionFixedRateFlagBitsEXT.html>"]
     #[doc(alias = "VkImageCompressionFixedRateFlagBitsEXT")]
     pub struct ImageCompressionFixedRateFlagsEXT : u32 {
@@ -4877,7 +5962,8 @@ bitflags! {
         const Rate24Bpc = 1u32 << 23;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceFaultAddressTypeEXT.html>"]
 #[doc(alias = "VkDeviceFaultAddressTypeEXT")]
 #[repr(u32)]
@@ -4890,7 +5976,8 @@ pub enum DeviceFaultAddressTypeEXT {
     InstructionPointerInvalid = 5,
     InstructionPointerFault = 6,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceFaultVendorBinaryHeaderVersionEXT.html>"]
 #[doc(alias = "VkDeviceFaultVendorBinaryHeaderVersionEXT")]
 #[repr(u32)]
@@ -4900,14 +5987,16 @@ pub enum DeviceFaultVendorBinaryHeaderVersionEXT {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceAddressBindingFlagBitsEXT.html>"]
     #[doc(alias = "VkDeviceAddressBindingFlagBitsEXT")]
     pub struct DeviceAddressBindingFlagsEXT : u32 {
         const InternalObject = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceAddressBindingTypeEXT.html>"]
 #[doc(alias = "VkDeviceAddressBindingTypeEXT")]
 #[repr(u32)]
@@ -4915,10 +6004,12 @@ pub enum DeviceAddressBindingTypeEXT {
     Bind = 0,
     Unbind = 1,
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageConstraintsInfoFlagBitsFUCHSIA.html>"]
     #[doc(alias = "VkImageConstraintsInfoFlagBitsFUCHSIA")]
     pub struct ImageConstraintsInfoFlagsFUCHSIA : u32 {
@@ -4932,7 +6023,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFrameBoundaryFlagBitsEXT.html>"]
     #[doc(alias = "VkFrameBoundaryFlagBitsEXT")]
     pub struct FrameBoundaryFlagsEXT : u32 {
@@ -4942,18 +6034,21 @@ bitflags! {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_GLOBAL_PRIORITY_SIZE_EXT.html>"]
 #[doc(alias = "VK_MAX_GLOBAL_PRIORITY_SIZE_EXT")]
 pub const MAX_GLOBAL_PRIORITY_SIZE_EXT: u32 = MAX_GLOBAL_PRIORITY_SIZE_KHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMicromapTypeEXT.html>"]
 #[doc(alias = "VkMicromapTypeEXT")]
 #[repr(u32)]
 pub enum MicromapTypeEXT {
     OpacityMicromap = 0,
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     DisplacementMicromapNV = 1000397000,
 }
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildMicromapFlagBitsEXT.html>"]
     #[doc(alias = "VkBuildMicromapFlagBitsEXT")]
     pub struct BuildMicromapFlagsEXT : u32 {
@@ -4962,7 +6057,8 @@ bitflags! {
         const AllowCompaction = 1u32 << 2;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCopyMicromapModeEXT.html>"]
 #[doc(alias = "VkCopyMicromapModeEXT")]
 #[repr(u32)]
@@ -4975,21 +6071,24 @@ pub enum CopyMicromapModeEXT {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMicromapCreateFlagBitsEXT.html>"]
     #[doc(alias = "VkMicromapCreateFlagBitsEXT")]
     pub struct MicromapCreateFlagsEXT : u32 {
         const DeviceAddressCaptureReplay = 1u32 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildMicromapModeEXT.html>"]
 #[doc(alias = "VkBuildMicromapModeEXT")]
 #[repr(u32)]
 pub enum BuildMicromapModeEXT {
     Build = 0,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpacityMicromapFormatEXT.html>"]
 #[doc(alias = "VkOpacityMicromapFormatEXT")]
 #[repr(u32)]
@@ -4997,7 +6096,8 @@ pub enum OpacityMicromapFormatEXT {
     Format2State = 1,
     Format4State = 2,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpacityMicromapSpecialIndexEXT.html>"]
 #[doc(alias = "VkOpacityMicromapSpecialIndexEXT")]
 #[repr(i32)]
@@ -5007,7 +6107,9 @@ pub enum OpacityMicromapSpecialIndexEXT {
     FullyUnknownTransparent = -3,
     FullyUnknownOpaque = -4,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplacementMicromapFormatNV.html>"]
 #[doc(alias = "VkDisplacementMicromapFormatNV")]
 #[repr(u32)]
@@ -5016,10 +6118,12 @@ pub enum DisplacementMicromapFormatNV {
     Format256Triangles128Bytes = 2,
     Format1024Triangles128Bytes = 3,
 }
+#[cfg(feature = "vendor-arm")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceSchedulingControlsFlagBitsARM.html>"]
     #[doc(alias = "VkPhysicalDeviceSchedulingControlsFlagBitsARM")]
     pub struct PhysicalDeviceSchedulingControlsFlagsARM : u64 {
@@ -5029,17 +6133,20 @@ bitflags! {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_REMAINING_3D_SLICES_EXT.html>"]
 #[doc(alias = "VK_REMAINING_3D_SLICES_EXT")]
 pub const REMAINING_3D_SLICES_EXT: u32 = !0u32;
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryDecompressionMethodFlagBitsNV.html>"]
     #[doc(alias = "VkMemoryDecompressionMethodFlagBitsNV")]
     pub struct MemoryDecompressionMethodFlagsNV : u64 {
         const Gdeflate10 = 1u64 << 0;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubpassMergeStatusEXT.html>"]
 #[doc(alias = "VkSubpassMergeStatusEXT")]
 #[repr(u32)]
@@ -5059,7 +6166,9 @@ pub enum SubpassMergeStatusEXT {
     NotMergedSingleSubpass = 12,
     NotMergedUnspecified = 13,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-lunarg")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDirectDriverLoadingModeLUNARG.html>"]
 #[doc(alias = "VkDirectDriverLoadingModeLUNARG")]
 #[repr(u32)]
@@ -5073,10 +6182,12 @@ pub const MAX_SHADER_MODULE_IDENTIFIER_SIZE_EXT: u32 = 32;
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineColorBlendStateCreateFlagBits.html>"]
     #[doc(alias = "VkPipelineColorBlendStateCreateFlagBits")]
     pub struct PipelineColorBlendStateCreateFlags : u32 {
+        #[cfg(feature = "vendor-arm")]
         const RasterizationOrderAttachmentAccessARM = Self::RasterizationOrderAttachmentAccessEXT.bits();
         const RasterizationOrderAttachmentAccessEXT = 1u32 << 0;
     }
@@ -5084,20 +6195,25 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineDepthStencilStateCreateFlagBits.html>"]
     #[doc(alias = "VkPipelineDepthStencilStateCreateFlagBits")]
     pub struct PipelineDepthStencilStateCreateFlags : u32 {
+        #[cfg(feature = "vendor-arm")]
         const RasterizationOrderAttachmentDepthAccessARM = Self::RasterizationOrderAttachmentDepthAccessEXT.bits();
+        #[cfg(feature = "vendor-arm")]
         const RasterizationOrderAttachmentStencilAccessARM = Self::RasterizationOrderAttachmentStencilAccessEXT.bits();
         const RasterizationOrderAttachmentDepthAccessEXT = 1u32 << 0;
         const RasterizationOrderAttachmentStencilAccessEXT = 1u32 << 1;
     }
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowUsageFlagBitsNV.html>"]
     #[doc(alias = "VkOpticalFlowUsageFlagBitsNV")]
     pub struct OpticalFlowUsageFlagsNV : u32 {
@@ -5109,10 +6225,12 @@ bitflags! {
         const GlobalFlow = 1u32 << 4;
     }
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowGridSizeFlagBitsNV.html>"]
     #[doc(alias = "VkOpticalFlowGridSizeFlagBitsNV")]
     pub struct OpticalFlowGridSizeFlagsNV : u32 {
@@ -5123,7 +6241,9 @@ bitflags! {
         const Size8X8 = 1u32 << 3;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowPerformanceLevelNV.html>"]
 #[doc(alias = "VkOpticalFlowPerformanceLevelNV")]
 #[repr(u32)]
@@ -5133,7 +6253,9 @@ pub enum OpticalFlowPerformanceLevelNV {
     Medium = 2,
     Fast = 3,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowSessionBindingPointNV.html>"]
 #[doc(alias = "VkOpticalFlowSessionBindingPointNV")]
 #[repr(u32)]
@@ -5148,10 +6270,12 @@ pub enum OpticalFlowSessionBindingPointNV {
     BackwardCost = 7,
     GlobalFlow = 8,
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowSessionCreateFlagBitsNV.html>"]
     #[doc(alias = "VkOpticalFlowSessionCreateFlagBitsNV")]
     pub struct OpticalFlowSessionCreateFlagsNV : u32 {
@@ -5162,10 +6286,12 @@ bitflags! {
         const BothDirections = 1u32 << 4;
     }
 }
+#[cfg(feature = "vendor-nv")]
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowExecuteFlagBitsNV.html>"]
     #[doc(alias = "VkOpticalFlowExecuteFlagBitsNV")]
     pub struct OpticalFlowExecuteFlagsNV : u32 {
@@ -5175,7 +6301,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCreateFlagBits2KHR.html>"]
     #[doc(alias = "VkPipelineCreateFlagBits2KHR")]
     pub struct PipelineCreateFlags2KHR : u64 {
@@ -5185,6 +6312,7 @@ bitflags! {
         const EnableLegacyDitheringEXT = 1u64 << 34;
         const ViewIndexFromDeviceIndex = 1u64 << 3;
         const DispatchBase = 1u64 << 4;
+        #[cfg(feature = "vendor-nv")]
         const DeferCompileNV = 1u64 << 5;
         const CaptureStatistics = 1u64 << 6;
         const CaptureInternalRepresentations = 1u64 << 7;
@@ -5200,7 +6328,9 @@ bitflags! {
         const RayTracingNoNullMissShaders = 1u64 << 16;
         const RayTracingNoNullIntersectionShaders = 1u64 << 17;
         const RayTracingShaderGroupHandleCaptureReplay = 1u64 << 19;
+        #[cfg(feature = "vendor-nv")]
         const IndirectBindableNV = 1u64 << 18;
+        #[cfg(feature = "vendor-nv")]
         const RayTracingAllowMotionNV = 1u64 << 20;
         const RenderingFragmentShadingRateAttachment = 1u64 << 21;
         const RenderingFragmentDensityMapAttachmentEXT = 1u64 << 22;
@@ -5209,6 +6339,7 @@ bitflags! {
         const DepthStencilAttachmentFeedbackLoopEXT = 1u64 << 26;
         const NoProtectedAccessEXT = 1u64 << 27;
         const ProtectedAccessOnlyEXT = 1u64 << 30;
+        #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
         const RayTracingDisplacementMicromapNV = 1u64 << 28;
         const DescriptorBufferEXT = 1u64 << 29;
         const CaptureData = 1u64 << 31;
@@ -5218,7 +6349,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferUsageFlagBits2KHR.html>"]
     #[doc(alias = "VkBufferUsageFlagBits2KHR")]
     pub struct BufferUsageFlags2KHR : u64 {
@@ -5231,9 +6363,11 @@ bitflags! {
         const IndexBuffer = 1u64 << 6;
         const VertexBuffer = 1u64 << 7;
         const IndirectBuffer = 1u64 << 8;
+        #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
         const ExecutionGraphScratchAMDX = 1u64 << 25;
         const ConditionalRenderingEXT = 1u64 << 9;
         const ShaderBindingTable = 1u64 << 10;
+        #[cfg(feature = "vendor-nv")]
         const RayTracingNV = Self::ShaderBindingTable.bits();
         const TransformFeedbackBufferEXT = 1u64 << 11;
         const TransformFeedbackCounterBufferEXT = 1u64 << 12;
@@ -5252,7 +6386,9 @@ bitflags! {
         const PreprocessBufferEXT = 1u64 << 31;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-amd")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAntiLagModeAMD.html>"]
 #[doc(alias = "VkAntiLagModeAMD")]
 #[repr(u32)]
@@ -5261,7 +6397,9 @@ pub enum AntiLagModeAMD {
     On = 1,
     Off = 2,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-amd")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAntiLagStageAMD.html>"]
 #[doc(alias = "VkAntiLagStageAMD")]
 #[repr(u32)]
@@ -5272,7 +6410,8 @@ pub enum AntiLagStageAMD {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderCreateFlagBitsEXT.html>"]
     #[doc(alias = "VkShaderCreateFlagBitsEXT")]
     pub struct ShaderCreateFlagsEXT : u32 {
@@ -5286,7 +6425,8 @@ bitflags! {
         const IndirectBindable = 1u32 << 7;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderCodeTypeEXT.html>"]
 #[doc(alias = "VkShaderCodeTypeEXT")]
 #[repr(u32)]
@@ -5297,7 +6437,9 @@ pub enum ShaderCodeTypeEXT {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_PIPELINE_BINARY_KEY_SIZE_KHR.html>"]
 #[doc(alias = "VK_MAX_PIPELINE_BINARY_KEY_SIZE_KHR")]
 pub const MAX_PIPELINE_BINARY_KEY_SIZE_KHR: u32 = 32;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRayTracingInvocationReorderModeNV.html>"]
 #[doc(alias = "VkRayTracingInvocationReorderModeNV")]
 #[repr(u32)]
@@ -5305,7 +6447,8 @@ pub enum RayTracingInvocationReorderModeNV {
     None = 0,
     Reorder = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkLayerSettingTypeEXT.html>"]
 #[doc(alias = "VkLayerSettingTypeEXT")]
 #[repr(u32)]
@@ -5319,7 +6462,9 @@ pub enum LayerSettingTypeEXT {
     Float64 = 6,
     String = 7,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkLatencyMarkerNV.html>"]
 #[doc(alias = "VkLatencyMarkerNV")]
 #[repr(u32)]
@@ -5337,7 +6482,9 @@ pub enum LatencyMarkerNV {
     OutOfBandPresentStart = 10,
     OutOfBandPresentEnd = 11,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-nv")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOutOfBandQueueTypeNV.html>"]
 #[doc(alias = "VkOutOfBandQueueTypeNV")]
 #[repr(u32)]
@@ -5345,7 +6492,8 @@ pub enum OutOfBandQueueTypeNV {
     Render = 0,
     Present = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkScopeKHR.html>"]
 #[doc(alias = "VkScopeKHR")]
 #[repr(u32)]
@@ -5357,15 +6505,21 @@ pub enum ScopeKHR {
 }
 #[allow(non_upper_case_globals)]
 impl ScopeKHR {
+    #[cfg(feature = "vendor-nv")]
     pub const DeviceNV: Self = Self::Device;
+    #[cfg(feature = "vendor-nv")]
     pub const WorkgroupNV: Self = Self::Workgroup;
+    #[cfg(feature = "vendor-nv")]
     pub const SubgroupNV: Self = Self::Subgroup;
+    #[cfg(feature = "vendor-nv")]
     pub const QueueFamilyNV: Self = Self::QueueFamily;
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkScopeNV.html>"]
 #[doc(alias = "VkScopeNV")]
 pub type ScopeNV = ScopeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkComponentTypeKHR.html>"]
 #[doc(alias = "VkComponentTypeKHR")]
 #[repr(u32)]
@@ -5384,22 +6538,39 @@ pub enum ComponentTypeKHR {
 }
 #[allow(non_upper_case_globals)]
 impl ComponentTypeKHR {
+    #[cfg(feature = "vendor-nv")]
     pub const Float16NV: Self = Self::Float16;
+    #[cfg(feature = "vendor-nv")]
     pub const Float32NV: Self = Self::Float32;
+    #[cfg(feature = "vendor-nv")]
     pub const Float64NV: Self = Self::Float64;
+    #[cfg(feature = "vendor-nv")]
     pub const Sint8NV: Self = Self::Sint8;
+    #[cfg(feature = "vendor-nv")]
     pub const Sint16NV: Self = Self::Sint16;
+    #[cfg(feature = "vendor-nv")]
     pub const Sint32NV: Self = Self::Sint32;
+    #[cfg(feature = "vendor-nv")]
     pub const Sint64NV: Self = Self::Sint64;
+    #[cfg(feature = "vendor-nv")]
     pub const Uint8NV: Self = Self::Uint8;
+    #[cfg(feature = "vendor-nv")]
     pub const Uint16NV: Self = Self::Uint16;
+    #[cfg(feature = "vendor-nv")]
     pub const Uint32NV: Self = Self::Uint32;
+    #[cfg(feature = "vendor-nv")]
     pub const Uint64NV: Self = Self::Uint64;
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkComponentTypeNV.html>"]
 #[doc(alias = "VkComponentTypeNV")]
 pub type ComponentTypeNV = ComponentTypeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_MAX_VIDEO_AV1_REFERENCES_PER_FRAME_KHR.html>"]
+#[doc(alias = "VK_MAX_VIDEO_AV1_REFERENCES_PER_FRAME_KHR")]
+pub const MAX_VIDEO_AV1_REFERENCES_PER_FRAME_KHR: u32 = 7;
+#[cfg(feature = "vendor-qcom")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBlockMatchWindowCompareModeQCOM.html>"]
 #[doc(alias = "VkBlockMatchWindowCompareModeQCOM")]
 #[repr(u32)]
@@ -5407,7 +6578,9 @@ pub enum BlockMatchWindowCompareModeQCOM {
     Min = 0,
     Max = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-qcom")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCubicFilterWeightsQCOM.html>"]
 #[doc(alias = "VkCubicFilterWeightsQCOM")]
 #[repr(u32)]
@@ -5417,7 +6590,9 @@ pub enum CubicFilterWeightsQCOM {
     BSpline = 2,
     MitchellNetravali = 3,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "vendor-msft")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkLayeredDriverUnderlyingApiMSFT.html>"]
 #[doc(alias = "VkLayeredDriverUnderlyingApiMSFT")]
 #[repr(u32)]
@@ -5425,7 +6600,8 @@ pub enum LayeredDriverUnderlyingApiMSFT {
     None = 0,
     D3D12 = 1,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkLineRasterizationModeKHR.html>"]
 #[doc(alias = "VkLineRasterizationModeKHR")]
 #[repr(u32)]
@@ -5445,7 +6621,8 @@ impl LineRasterizationModeKHR {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkLineRasterizationModeEXT.html>"]
 #[doc(alias = "VkLineRasterizationModeEXT")]
 pub type LineRasterizationModeEXT = LineRasterizationModeKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkTimeDomainKHR.html>"]
 #[doc(alias = "VkTimeDomainKHR")]
 #[repr(u32)]
@@ -5465,7 +6642,8 @@ impl TimeDomainKHR {
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkTimeDomainEXT.html>"]
 #[doc(alias = "VkTimeDomainEXT")]
 pub type TimeDomainEXT = TimeDomainKHR;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceLayeredApiKHR.html>"]
 #[doc(alias = "VkPhysicalDeviceLayeredApiKHR")]
 #[repr(u32)]
@@ -5476,7 +6654,8 @@ pub enum PhysicalDeviceLayeredApiKHR {
     Opengl = 3,
     Opengles = 4,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsTokenTypeEXT.html>"]
 #[doc(alias = "VkIndirectCommandsTokenTypeEXT")]
 #[repr(u32)]
@@ -5491,13 +6670,16 @@ pub enum IndirectCommandsTokenTypeEXT {
     DrawIndexedCount = 7,
     DrawCount = 8,
     Dispatch = 9,
+    #[cfg(feature = "vendor-nv")]
     DrawMeshTasksNV = 1000202002,
+    #[cfg(feature = "vendor-nv")]
     DrawMeshTasksCountNV = 1000202003,
     DrawMeshTasks = 1000328000,
     DrawMeshTasksCount = 1000328001,
     TraceRays2 = 1000386004,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectExecutionSetInfoTypeEXT.html>"]
 #[doc(alias = "VkIndirectExecutionSetInfoTypeEXT")]
 #[repr(u32)]
@@ -5508,7 +6690,8 @@ pub enum IndirectExecutionSetInfoTypeEXT {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsLayoutUsageFlagBitsEXT.html>"]
     #[doc(alias = "VkIndirectCommandsLayoutUsageFlagBitsEXT")]
     pub struct IndirectCommandsLayoutUsageFlagsEXT : u32 {
@@ -5519,7 +6702,8 @@ bitflags! {
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsInputModeFlagBitsEXT.html>"]
     #[doc(alias = "VkIndirectCommandsInputModeFlagBitsEXT")]
     pub struct IndirectCommandsInputModeFlagsEXT : u32 {
@@ -5527,7 +6711,8 @@ bitflags! {
         const DxgiIndexBuffer = 1u32 << 1;
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDepthClampModeEXT.html>"]
 #[doc(alias = "VkDepthClampModeEXT")]
 #[repr(u32)]
@@ -0,0 +1,261 @@
+//! Wrappers enforcing the external synchronization requirements of the Vulkan specification
+//!
+//! Some Vulkan commands require the application to externally synchronize access to one of their parameters:
+//! two threads must not call such a command at the same time on the same object. The handles of [vk::rs] can be
+//! copied and all their methods take `&self`, so nothing prevents this from happening.
+//!
+//! The types of this module cannot be copied or cloned and the commands which require external synchronization
+//! take `&mut self`, while the other ones still take `&self`. Sharing one of these objects across threads therefore
+//! requires an internal lock such as a [std::sync::Mutex]:
+//! ```ignore
+//! let queue = Mutex::new(unsafe { exclusive::Queue::from_shared(queue) });
+//! // in any thread
+//! queue.lock().unwrap().submit(&submit_info, None)?;
+//! ```
+//!
+//! Command buffers are implicitly synchronized with the command pool they were allocated from, an
+//! [exclusive::CommandBuffer](CommandBuffer) can only be accessed mutably through the [CommandPool] owning it.
+//! The same is true for the descriptor sets of a [DescriptorPool].
+//! Handles which are never externally synchronized (device memory, buffers, images...) remain freely shareable
+//! and are used through [vk::rs] as usual.
+
+use std::ops::Deref;
+
+use crate::{
+    vk, AdvancedDynamicArray, Alias, Allocator, AsSlice, DefaultAllocator, Dispatcher,
+    DynamicDispatcher, Handle,
+};
+
+mod commands;
+
+/// Queue whose submission and wait commands require exclusive access
+pub struct Queue<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    inner: vk::rs::Queue<D, A>,
+}
+
+impl<D: Dispatcher, A: Allocator> Queue<D, A> {
+    /// Take exclusive ownership of a queue
+    /// # Safety
+    /// No other copy of `queue` must be used to call an externally synchronized command
+    /// while this object is alive
+    pub unsafe fn from_shared(queue: vk::rs::Queue<D, A>) -> Self {
+        Self { inner: queue }
+    }
+
+    pub fn into_shared(self) -> vk::rs::Queue<D, A> {
+        self.inner
+    }
+}
+
+impl<D: Dispatcher, A: Allocator> Deref for Queue<D, A> {
+    type Target = vk::raw::Queue;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+/// Command buffer whose commands all require exclusive access
+/// It can only be obtained through the [CommandPool] it was allocated from
+pub struct CommandBuffer<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    inner: vk::rs::CommandBuffer<D, A>,
+}
+
+impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
+    /// Copy of the underlying command buffer, for example to submit it
+    /// # Safety
+    /// The returned command buffer must not be used to record commands or reset it
+    pub unsafe fn shared(&self) -> vk::rs::CommandBuffer<D, A>
+    where
+        D: Clone,
+        A: Clone,
+    {
+        self.inner.clone()
+    }
+}
+
+impl<D: Dispatcher, A: Allocator> Deref for CommandBuffer<D, A> {
+    type Target = vk::raw::CommandBuffer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+/// Command pool owning the command buffers allocated from it
+///
+/// Recording a command buffer requires mutable access to its pool, as recording commands in two command buffers
+/// allocated from the same pool at the same time is not allowed
+pub struct CommandPool<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    device: vk::rs::Device<D, A>,
+    pool: vk::rs::CommandPool,
+    command_buffers: Vec<CommandBuffer<D, A>>,
+}
+
+impl<D: Dispatcher, A: Allocator> CommandPool<D, A> {
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCommandPool.html>"]
+    #[doc(alias = "vkCreateCommandPool")]
+    pub fn new(
+        device: vk::rs::Device<D, A>,
+        create_info: &vk::CommandPoolCreateInfo,
+    ) -> vk::Result<Self> {
+        let pool = device.create_command_pool(create_info)?;
+        Ok(Self {
+            device,
+            pool,
+            command_buffers: Vec::new(),
+        })
+    }
+
+    /// Allocate `count` command buffers of the given level and return them
+    /// They are also added to the command buffers owned by the pool
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateCommandBuffers.html>"]
+    #[doc(alias = "vkAllocateCommandBuffers")]
+    pub fn allocate(
+        &mut self,
+        level: vk::CommandBufferLevel,
+        count: u32,
+    ) -> vk::Result<&mut [CommandBuffer<D, A>]> {
+        let allocate_info = vk::CommandBufferAllocateInfo::default()
+            .command_pool(&self.pool)
+            .level(level)
+            .command_buffer_count(count);
+        let allocated: Vec<vk::rs::CommandBuffer<D, A>> =
+            self.device.allocate_command_buffers(&allocate_info)?;
+
+        let start = self.command_buffers.len();
+        self.command_buffers
+            .extend(allocated.into_iter().map(|inner| CommandBuffer { inner }));
+        Ok(&mut self.command_buffers[start..])
+    }
+
+    /// Command buffers allocated from this pool, in allocation order
+    pub fn command_buffers(&self) -> &[CommandBuffer<D, A>] {
+        &self.command_buffers
+    }
+
+    pub fn command_buffers_mut(&mut self) -> &mut [CommandBuffer<D, A>] {
+        &mut self.command_buffers
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandPool.html>"]
+    #[doc(alias = "vkResetCommandPool")]
+    pub fn reset(&mut self, flags: vk::CommandPoolResetFlags) -> vk::Result<()> {
+        self.device.reset_command_pool(&self.pool, flags)
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkTrimCommandPool.html>"]
+    #[doc(alias = "vkTrimCommandPool")]
+    pub fn trim(&mut self) {
+        self.device.trim_command_pool(&self.pool, 0)
+    }
+
+    /// Free all the command buffers owned by this pool
+    /// # Safety
+    /// None of the command buffers must be in the pending state
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeCommandBuffers.html>"]
+    #[doc(alias = "vkFreeCommandBuffers")]
+    pub unsafe fn free(&mut self) {
+        if self.command_buffers.is_empty() {
+            return;
+        }
+        let command_buffers: Vec<_> = self
+            .command_buffers
+            .iter()
+            .map(|command_buffer| command_buffer.inner.borrow())
+            .collect();
+        self.device
+            .free_command_buffers(&self.pool, command_buffers.as_slice());
+        self.command_buffers.clear();
+    }
+
+    /// Destroy the command pool, which frees all its command buffers
+    /// # Safety
+    /// None of the command buffers must be in the pending state
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCommandPool.html>"]
+    #[doc(alias = "vkDestroyCommandPool")]
+    pub unsafe fn destroy(self) {
+        unsafe { self.device.destroy_command_pool(Some(&self.pool)) }
+    }
+}
+
+impl<D: Dispatcher, A: Allocator> Deref for CommandPool<D, A> {
+    type Target = vk::rs::CommandPool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
+
+/// Descriptor pool whose allocation commands require exclusive access
+pub struct DescriptorPool<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
+    device: vk::rs::Device<D, A>,
+    pool: vk::rs::DescriptorPool,
+}
+
+impl<D: Dispatcher, A: Allocator> DescriptorPool<D, A> {
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorPool.html>"]
+    #[doc(alias = "vkCreateDescriptorPool")]
+    pub fn new(
+        device: vk::rs::Device<D, A>,
+        create_info: &vk::DescriptorPoolCreateInfo,
+    ) -> vk::Result<Self> {
+        let pool = device.create_descriptor_pool(create_info)?;
+        Ok(Self { device, pool })
+    }
+
+    /// Allocate one descriptor set for each of the given layouts
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateDescriptorSets.html>"]
+    #[doc(alias = "vkAllocateDescriptorSets")]
+    pub fn allocate<
+        'a,
+        V: Alias<vk::raw::DescriptorSetLayout> + 'a,
+        R: AdvancedDynamicArray<vk::rs::DescriptorSet, vk::raw::DescriptorSet>,
+    >(
+        &'a mut self,
+        set_layouts: impl AsSlice<'a, V>,
+    ) -> vk::Result<R> {
+        let allocate_info = vk::DescriptorSetAllocateInfo::default()
+            .descriptor_pool(&self.pool)
+            .set_layouts(set_layouts);
+        self.device.allocate_descriptor_sets(&allocate_info)
+    }
+
+    /// # Safety
+    /// The descriptor sets must have been allocated from this pool, which must have been created with
+    /// [vk::DescriptorPoolCreateFlags::FreeDescriptorSet], and must not be used by a command buffer in the pending state
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeDescriptorSets.html>"]
+    #[doc(alias = "vkFreeDescriptorSets")]
+    pub unsafe fn free<'a, V: Alias<vk::raw::DescriptorSet> + 'a>(
+        &mut self,
+        descriptor_sets: impl AsSlice<'a, V>,
+    ) -> vk::Result<()> {
+        self.device
+            .free_descriptor_sets(&self.pool, descriptor_sets)
+    }
+
+    /// Free all the descriptor sets allocated from this pool
+    /// # Safety
+    /// None of the descriptor sets must be used by a command buffer in the pending state
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetDescriptorPool.html>"]
+    #[doc(alias = "vkResetDescriptorPool")]
+    pub unsafe fn reset(&mut self) -> vk::Result<()> {
+        self.device.reset_descriptor_pool(&self.pool, 0)
+    }
+
+    /// # Safety
+    /// None of the descriptor sets must be used by a command buffer in the pending state
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDescriptorPool.html>"]
+    #[doc(alias = "vkDestroyDescriptorPool")]
+    pub unsafe fn destroy(self) {
+        unsafe { self.device.destroy_descriptor_pool(Some(&self.pool)) }
+    }
+}
+
+impl<D: Dispatcher, A: Allocator> Deref for DescriptorPool<D, A> {
+    type Target = vk::rs::DescriptorPool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
@@ -74,6 +74,14 @@ pub const EXT_DEBUG_MARKER: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_EXT_debug_marker") },
     spec: 4u32,
 };
+pub const KHR_VIDEO_QUEUE: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_queue") },
+    spec: 8u32,
+};
+pub const KHR_VIDEO_DECODE_QUEUE: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_decode_queue") },
+    spec: 8u32,
+};
 pub const AMD_GCN_SHADER: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_AMD_gcn_shader") },
     spec: 1u32,
@@ -110,6 +118,18 @@ pub const AMD_SHADER_BALLOT: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_AMD_shader_ballot") },
     spec: 1u32,
 };
+pub const KHR_VIDEO_ENCODE_H264: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_encode_h264") },
+    spec: 14u32,
+};
+pub const KHR_VIDEO_ENCODE_H265: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_encode_h265") },
+    spec: 14u32,
+};
+pub const KHR_VIDEO_DECODE_H264: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_decode_h264") },
+    spec: 9u32,
+};
 pub const AMD_TEXTURE_GATHER_BIAS_LOD: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_AMD_texture_gather_bias_lod") },
     spec: 1u32,
@@ -592,6 +612,10 @@ pub const AMD_SHADER_CORE_PROPERTIES: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_AMD_shader_core_properties") },
     spec: 2u32,
 };
+pub const KHR_VIDEO_DECODE_H265: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_decode_h265") },
+    spec: 8u32,
+};
 pub const KHR_GLOBAL_PRIORITY: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_KHR_global_priority") },
     spec: 1u32,
@@ -940,6 +964,10 @@ pub const EXT_PIPELINE_CREATION_CACHE_CONTROL: DeviceExtension = DeviceExtension
     name: unsafe { DeviceExtensionName::new(c"VK_EXT_pipeline_creation_cache_control") },
     spec: 3u32,
 };
+pub const KHR_VIDEO_ENCODE_QUEUE: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_encode_queue") },
+    spec: 12u32,
+};
 pub const NV_DEVICE_DIAGNOSTICS_CONFIG: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_NV_device_diagnostics_config") },
     spec: 2u32,
@@ -1388,6 +1416,14 @@ pub const KHR_COMPUTE_SHADER_DERIVATIVES: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_KHR_compute_shader_derivatives") },
     spec: 1u32,
 };
+pub const KHR_VIDEO_DECODE_AV1: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_decode_av1") },
+    spec: 1u32,
+};
+pub const KHR_VIDEO_MAINTENANCE1: DeviceExtension = DeviceExtension {
+    name: unsafe { DeviceExtensionName::new(c"VK_KHR_video_maintenance1") },
+    spec: 1u32,
+};
 pub const NV_PER_STAGE_DESCRIPTOR_SET: DeviceExtension = DeviceExtension {
     name: unsafe { DeviceExtensionName::new(c"VK_NV_per_stage_descriptor_set") },
     spec: 1u32,
@@ -1,4 +1,259 @@
-use super::Format;
+use super::{Format, ImageAspectFlags};
+#[doc = r" Compatibility class of a [Format]."]
+#[doc = r" Images with formats of the same compatibility class can be reinterpreted as one another"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompatibilityClass {
+    #[doc = "8-bit"]
+    Bit8,
+    #[doc = "16-bit"]
+    Bit16,
+    #[doc = "8-bit alpha"]
+    Bit8Alpha,
+    #[doc = "24-bit"]
+    Bit24,
+    #[doc = "32-bit"]
+    Bit32,
+    #[doc = "48-bit"]
+    Bit48,
+    #[doc = "64-bit"]
+    Bit64,
+    #[doc = "96-bit"]
+    Bit96,
+    #[doc = "128-bit"]
+    Bit128,
+    #[doc = "192-bit"]
+    Bit192,
+    #[doc = "256-bit"]
+    Bit256,
+    #[doc = "D16"]
+    D16,
+    #[doc = "D24"]
+    D24,
+    #[doc = "D32"]
+    D32,
+    #[doc = "S8"]
+    S8,
+    #[doc = "D16S8"]
+    D16S8,
+    #[doc = "D24S8"]
+    D24S8,
+    #[doc = "D32S8"]
+    D32S8,
+    #[doc = "BC1_RGB"]
+    Bc1Rgb,
+    #[doc = "BC1_RGBA"]
+    Bc1Rgba,
+    #[doc = "BC2"]
+    Bc2,
+    #[doc = "BC3"]
+    Bc3,
+    #[doc = "BC4"]
+    Bc4,
+    #[doc = "BC5"]
+    Bc5,
+    #[doc = "BC6H"]
+    Bc6H,
+    #[doc = "BC7"]
+    Bc7,
+    #[doc = "ETC2_RGB"]
+    Etc2Rgb,
+    #[doc = "ETC2_RGBA"]
+    Etc2Rgba,
+    #[doc = "ETC2_EAC_RGBA"]
+    Etc2EacRgba,
+    #[doc = "EAC_R"]
+    EacR,
+    #[doc = "EAC_RG"]
+    EacRg,
+    #[doc = "ASTC_4x4"]
+    Astc4x4,
+    #[doc = "ASTC_5x4"]
+    Astc5x4,
+    #[doc = "ASTC_5x5"]
+    Astc5x5,
+    #[doc = "ASTC_6x5"]
+    Astc6x5,
+    #[doc = "ASTC_6x6"]
+    Astc6x6,
+    #[doc = "ASTC_8x5"]
+    Astc8x5,
+    #[doc = "ASTC_8x6"]
+    Astc8x6,
+    #[doc = "ASTC_8x8"]
+    Astc8x8,
+    #[doc = "ASTC_10x5"]
+    Astc10x5,
+    #[doc = "ASTC_10x6"]
+    Astc10x6,
+    #[doc = "ASTC_10x8"]
+    Astc10x8,
+    #[doc = "ASTC_10x10"]
+    Astc10x10,
+    #[doc = "ASTC_12x10"]
+    Astc12x10,
+    #[doc = "ASTC_12x12"]
+    Astc12x12,
+    #[doc = "32-bit G8B8G8R8"]
+    Bit32G8B8G8R8,
+    #[doc = "32-bit B8G8R8G8"]
+    Bit32B8G8R8G8,
+    #[doc = "8-bit 3-plane 420"]
+    Bit8Plane3Chroma420,
+    #[doc = "8-bit 2-plane 420"]
+    Bit8Plane2Chroma420,
+    #[doc = "8-bit 3-plane 422"]
+    Bit8Plane3Chroma422,
+    #[doc = "8-bit 2-plane 422"]
+    Bit8Plane2Chroma422,
+    #[doc = "8-bit 3-plane 444"]
+    Bit8Plane3Chroma444,
+    #[doc = "64-bit R10G10B10A10"]
+    Bit64R10G10B10A10,
+    #[doc = "64-bit G10B10G10R10"]
+    Bit64G10B10G10R10,
+    #[doc = "64-bit B10G10R10G10"]
+    Bit64B10G10R10G10,
+    #[doc = "10-bit 3-plane 420"]
+    Bit10Plane3Chroma420,
+    #[doc = "10-bit 2-plane 420"]
+    Bit10Plane2Chroma420,
+    #[doc = "10-bit 3-plane 422"]
+    Bit10Plane3Chroma422,
+    #[doc = "10-bit 2-plane 422"]
+    Bit10Plane2Chroma422,
+    #[doc = "10-bit 3-plane 444"]
+    Bit10Plane3Chroma444,
+    #[doc = "64-bit R12G12B12A12"]
+    Bit64R12G12B12A12,
+    #[doc = "64-bit G12B12G12R12"]
+    Bit64G12B12G12R12,
+    #[doc = "64-bit B12G12R12G12"]
+    Bit64B12G12R12G12,
+    #[doc = "12-bit 3-plane 420"]
+    Bit12Plane3Chroma420,
+    #[doc = "12-bit 2-plane 420"]
+    Bit12Plane2Chroma420,
+    #[doc = "12-bit 3-plane 422"]
+    Bit12Plane3Chroma422,
+    #[doc = "12-bit 2-plane 422"]
+    Bit12Plane2Chroma422,
+    #[doc = "12-bit 3-plane 444"]
+    Bit12Plane3Chroma444,
+    #[doc = "64-bit G16B16G16R16"]
+    Bit64G16B16G16R16,
+    #[doc = "64-bit B16G16R16G16"]
+    Bit64B16G16R16G16,
+    #[doc = "16-bit 3-plane 420"]
+    Bit16Plane3Chroma420,
+    #[doc = "16-bit 2-plane 420"]
+    Bit16Plane2Chroma420,
+    #[doc = "16-bit 3-plane 422"]
+    Bit16Plane3Chroma422,
+    #[doc = "16-bit 2-plane 422"]
+    Bit16Plane2Chroma422,
+    #[doc = "16-bit 3-plane 444"]
+    Bit16Plane3Chroma444,
+    #[doc = "PVRTC1_2BPP"]
+    Pvrtc12Bpp,
+    #[doc = "PVRTC1_4BPP"]
+    Pvrtc14Bpp,
+    #[doc = "PVRTC2_2BPP"]
+    Pvrtc22Bpp,
+    #[doc = "PVRTC2_4BPP"]
+    Pvrtc24Bpp,
+    #[doc = "8-bit 2-plane 444"]
+    Bit8Plane2Chroma444,
+    #[doc = "10-bit 2-plane 444"]
+    Bit10Plane2Chroma444,
+    #[doc = "12-bit 2-plane 444"]
+    Bit12Plane2Chroma444,
+    #[doc = "16-bit 2-plane 444"]
+    Bit16Plane2Chroma444,
+}
+#[doc = r" Numeric format of a component of a [Format]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumericFormat {
+    #[doc = r" Unsigned normalized value in the range [0, 1]"]
+    Unorm,
+    #[doc = r" Signed normalized value in the range [-1, 1]"]
+    Snorm,
+    #[doc = r" Unsigned integer value converted to a float in the range [0, 2^n - 1]"]
+    Uscaled,
+    #[doc = r" Signed integer value converted to a float in the range [-2^(n-1), 2^(n-1) - 1]"]
+    Sscaled,
+    #[doc = r" Unsigned integer value"]
+    Uint,
+    #[doc = r" Signed integer value"]
+    Sint,
+    #[doc = r" Unsigned floating-point value"]
+    Ufloat,
+    #[doc = r" Signed floating-point value"]
+    Sfloat,
+    #[doc = r" Unsigned normalized value using the sRGB nonlinear encoding"]
+    Srgb,
+    #[doc = r" Signed fixed-point value with 5 fractional bits"]
+    Sfixed5,
+}
+#[doc = r" Name of a component of a [Format]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentName {
+    #[doc = r" Red component, or Cr for YCbCr formats"]
+    R,
+    #[doc = r" Green component, or Y for YCbCr formats"]
+    G,
+    #[doc = r" Blue component, or Cb for YCbCr formats"]
+    B,
+    #[doc = r" Alpha component"]
+    A,
+    #[doc = r" Depth component"]
+    D,
+    #[doc = r" Stencil component"]
+    S,
+}
+#[doc = r" Description of a component of a [Format]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormatComponent {
+    pub name: ComponentName,
+    #[doc = r" Number of bits of this component, None for compressed formats"]
+    pub bits: Option<u8>,
+    pub numeric_format: NumericFormat,
+    #[doc = r" Index of the plane containing this component for multi-planar formats"]
+    pub plane: Option<u8>,
+}
+#[doc = r" Location in memory of a component of a texel, see [Format::texel_layout]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TexelComponent {
+    pub name: ComponentName,
+    pub numeric_format: NumericFormat,
+    #[doc = r" Number of bits of this component"]
+    pub bits: u8,
+    #[doc = r" Offset in bytes in the texel of the element containing this component"]
+    pub offset: u8,
+    #[doc = r" Size in bytes of the element containing this component, which is stored with the host endianness"]
+    pub size: u8,
+    #[doc = r" Position of the least significant bit of this component in its element"]
+    pub shift: u8,
+}
+#[doc = r" Chroma subsampling of a YCbCr [Format]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChromaSubsampling {
+    #[doc = r" The chroma components are sampled at half the width and half the height of the image"]
+    Chroma420,
+    #[doc = r" The chroma components are sampled at half the width of the image"]
+    Chroma422,
+    #[doc = r" The chroma components are sampled at the full resolution of the image"]
+    Chroma444,
+}
+#[doc = r" Description of a plane of a multi-planar [Format]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatPlane {
+    #[doc = r" Single-plane format compatible with this plane"]
+    pub format: Format,
+    #[doc = r" The width of this plane is the width of the image divided by this value"]
+    pub width_divisor: u8,
+    #[doc = r" The height of this plane is the height of the image divided by this value"]
+    pub height_divisor: u8,
+}
 impl Format {
     #[doc = r" Return the number of components of this format."]
     pub const fn component_count(self) -> u8 {
@@ -226,13 +481,21 @@ impl Format {
             Format::G16B16R163Plane422Unorm => 3u8,
             Format::G16B16R162Plane422Unorm => 3u8,
             Format::G16B16R163Plane444Unorm => 3u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc12BppUnormBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc14BppUnormBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc22BppUnormBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc24BppUnormBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc12BppSrgbBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc14BppSrgbBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc22BppSrgbBlockIMG => 4u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc24BppSrgbBlockIMG => 4u8,
             Format::Astc4x4SfloatBlock => 4u8,
             Format::Astc5x4SfloatBlock => 4u8,
@@ -254,6 +517,7 @@ impl Format {
             Format::G16B16R162Plane444Unorm => 3u8,
             Format::A4R4G4B4UnormPack16 => 4u8,
             Format::A4B4G4R4UnormPack16 => 4u8,
+            #[cfg(feature = "vendor-nv")]
             Format::R16G16Sfixed5NV => 2u8,
         }
     }
@@ -481,13 +745,21 @@ impl Format {
             Format::G16B16R163Plane422Unorm => 6u8,
             Format::G16B16R162Plane422Unorm => 6u8,
             Format::G16B16R163Plane444Unorm => 6u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc12BppUnormBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc14BppUnormBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc22BppUnormBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc24BppUnormBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc12BppSrgbBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc14BppSrgbBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc22BppSrgbBlockIMG => 8u8,
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc24BppSrgbBlockIMG => 8u8,
             Format::Astc4x4SfloatBlock => 16u8,
             Format::Astc5x4SfloatBlock => 16u8,
@@ -509,6 +781,7 @@ impl Format {
             Format::G16B16R162Plane444Unorm => 6u8,
             Format::A4R4G4B4UnormPack16 => 2u8,
             Format::A4B4G4R4UnormPack16 => 2u8,
+            #[cfg(feature = "vendor-nv")]
             Format::R16G16Sfixed5NV => 4u8,
         }
     }
@@ -655,13 +928,21 @@ impl Format {
             Format::B12X4G12X4R12X4G12X4422Unorm4Pack16 => [2u8, 1u8, 1u8],
             Format::G16B16G16R16422Unorm => [2u8, 1u8, 1u8],
             Format::B16G16R16G16422Unorm => [2u8, 1u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc12BppUnormBlockIMG => [8u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc14BppUnormBlockIMG => [4u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc22BppUnormBlockIMG => [8u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc24BppUnormBlockIMG => [4u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc12BppSrgbBlockIMG => [8u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc14BppSrgbBlockIMG => [4u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc22BppSrgbBlockIMG => [8u8, 4u8, 1u8],
+            #[cfg(feature = "vendor-img")]
             Format::Pvrtc24BppSrgbBlockIMG => [4u8, 4u8, 1u8],
             Format::Astc4x4SfloatBlock => [4u8, 4u8, 1u8],
             Format::Astc5x4SfloatBlock => [5u8, 4u8, 1u8],
@@ -683,83 +964,9768 @@ impl Format {
     #[doc = r" Return true if this format is a compressed format"]
     pub const fn is_compressed(self) -> bool {
         match self {
-            Format::Bc1RgbUnormBlock
-            | Format::Bc1RgbSrgbBlock
-            | Format::Bc1RgbaUnormBlock
-            | Format::Bc1RgbaSrgbBlock
-            | Format::Bc2UnormBlock
-            | Format::Bc2SrgbBlock
-            | Format::Bc3UnormBlock
-            | Format::Bc3SrgbBlock
-            | Format::Bc4UnormBlock
-            | Format::Bc4SnormBlock
-            | Format::Bc5UnormBlock
-            | Format::Bc5SnormBlock
-            | Format::Bc6HUfloatBlock
-            | Format::Bc6HSfloatBlock
-            | Format::Bc7UnormBlock
-            | Format::Bc7SrgbBlock
-            | Format::Etc2R8G8B8UnormBlock
-            | Format::Etc2R8G8B8SrgbBlock
-            | Format::Etc2R8G8B8A1UnormBlock
-            | Format::Etc2R8G8B8A1SrgbBlock
-            | Format::Etc2R8G8B8A8UnormBlock
-            | Format::Etc2R8G8B8A8SrgbBlock
-            | Format::EacR11UnormBlock
-            | Format::EacR11SnormBlock
-            | Format::EacR11G11UnormBlock
-            | Format::EacR11G11SnormBlock
-            | Format::Astc4x4UnormBlock
-            | Format::Astc4x4SrgbBlock
-            | Format::Astc5x4UnormBlock
-            | Format::Astc5x4SrgbBlock
-            | Format::Astc5x5UnormBlock
-            | Format::Astc5x5SrgbBlock
-            | Format::Astc6x5UnormBlock
-            | Format::Astc6x5SrgbBlock
-            | Format::Astc6x6UnormBlock
-            | Format::Astc6x6SrgbBlock
-            | Format::Astc8x5UnormBlock
-            | Format::Astc8x5SrgbBlock
-            | Format::Astc8x6UnormBlock
-            | Format::Astc8x6SrgbBlock
-            | Format::Astc8x8UnormBlock
-            | Format::Astc8x8SrgbBlock
-            | Format::Astc10x5UnormBlock
-            | Format::Astc10x5SrgbBlock
-            | Format::Astc10x6UnormBlock
-            | Format::Astc10x6SrgbBlock
-            | Format::Astc10x8UnormBlock
-            | Format::Astc10x8SrgbBlock
-            | Format::Astc10x10UnormBlock
-            | Format::Astc10x10SrgbBlock
-            | Format::Astc12x10UnormBlock
-            | Format::Astc12x10SrgbBlock
-            | Format::Astc12x12UnormBlock
-            | Format::Astc12x12SrgbBlock
-            | Format::Pvrtc12BppUnormBlockIMG
-            | Format::Pvrtc14BppUnormBlockIMG
-            | Format::Pvrtc22BppUnormBlockIMG
-            | Format::Pvrtc24BppUnormBlockIMG
-            | Format::Pvrtc12BppSrgbBlockIMG
-            | Format::Pvrtc14BppSrgbBlockIMG
-            | Format::Pvrtc22BppSrgbBlockIMG
-            | Format::Pvrtc24BppSrgbBlockIMG
-            | Format::Astc4x4SfloatBlock
-            | Format::Astc5x4SfloatBlock
-            | Format::Astc5x5SfloatBlock
-            | Format::Astc6x5SfloatBlock
-            | Format::Astc6x6SfloatBlock
-            | Format::Astc8x5SfloatBlock
-            | Format::Astc8x6SfloatBlock
-            | Format::Astc8x8SfloatBlock
-            | Format::Astc10x5SfloatBlock
-            | Format::Astc10x6SfloatBlock
-            | Format::Astc10x8SfloatBlock
-            | Format::Astc10x10SfloatBlock
-            | Format::Astc12x10SfloatBlock
-            | Format::Astc12x12SfloatBlock => true,
+            Format::Bc1RgbUnormBlock => true,
+            Format::Bc1RgbSrgbBlock => true,
+            Format::Bc1RgbaUnormBlock => true,
+            Format::Bc1RgbaSrgbBlock => true,
+            Format::Bc2UnormBlock => true,
+            Format::Bc2SrgbBlock => true,
+            Format::Bc3UnormBlock => true,
+            Format::Bc3SrgbBlock => true,
+            Format::Bc4UnormBlock => true,
+            Format::Bc4SnormBlock => true,
+            Format::Bc5UnormBlock => true,
+            Format::Bc5SnormBlock => true,
+            Format::Bc6HUfloatBlock => true,
+            Format::Bc6HSfloatBlock => true,
+            Format::Bc7UnormBlock => true,
+            Format::Bc7SrgbBlock => true,
+            Format::Etc2R8G8B8UnormBlock => true,
+            Format::Etc2R8G8B8SrgbBlock => true,
+            Format::Etc2R8G8B8A1UnormBlock => true,
+            Format::Etc2R8G8B8A1SrgbBlock => true,
+            Format::Etc2R8G8B8A8UnormBlock => true,
+            Format::Etc2R8G8B8A8SrgbBlock => true,
+            Format::EacR11UnormBlock => true,
+            Format::EacR11SnormBlock => true,
+            Format::EacR11G11UnormBlock => true,
+            Format::EacR11G11SnormBlock => true,
+            Format::Astc4x4UnormBlock => true,
+            Format::Astc4x4SrgbBlock => true,
+            Format::Astc5x4UnormBlock => true,
+            Format::Astc5x4SrgbBlock => true,
+            Format::Astc5x5UnormBlock => true,
+            Format::Astc5x5SrgbBlock => true,
+            Format::Astc6x5UnormBlock => true,
+            Format::Astc6x5SrgbBlock => true,
+            Format::Astc6x6UnormBlock => true,
+            Format::Astc6x6SrgbBlock => true,
+            Format::Astc8x5UnormBlock => true,
+            Format::Astc8x5SrgbBlock => true,
+            Format::Astc8x6UnormBlock => true,
+            Format::Astc8x6SrgbBlock => true,
+            Format::Astc8x8UnormBlock => true,
+            Format::Astc8x8SrgbBlock => true,
+            Format::Astc10x5UnormBlock => true,
+            Format::Astc10x5SrgbBlock => true,
+            Format::Astc10x6UnormBlock => true,
+            Format::Astc10x6SrgbBlock => true,
+            Format::Astc10x8UnormBlock => true,
+            Format::Astc10x8SrgbBlock => true,
+            Format::Astc10x10UnormBlock => true,
+            Format::Astc10x10SrgbBlock => true,
+            Format::Astc12x10UnormBlock => true,
+            Format::Astc12x10SrgbBlock => true,
+            Format::Astc12x12UnormBlock => true,
+            Format::Astc12x12SrgbBlock => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppUnormBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppUnormBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppUnormBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppUnormBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppSrgbBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppSrgbBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppSrgbBlockIMG => true,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppSrgbBlockIMG => true,
+            Format::Astc4x4SfloatBlock => true,
+            Format::Astc5x4SfloatBlock => true,
+            Format::Astc5x5SfloatBlock => true,
+            Format::Astc6x5SfloatBlock => true,
+            Format::Astc6x6SfloatBlock => true,
+            Format::Astc8x5SfloatBlock => true,
+            Format::Astc8x6SfloatBlock => true,
+            Format::Astc8x8SfloatBlock => true,
+            Format::Astc10x5SfloatBlock => true,
+            Format::Astc10x6SfloatBlock => true,
+            Format::Astc10x8SfloatBlock => true,
+            Format::Astc10x10SfloatBlock => true,
+            Format::Astc12x10SfloatBlock => true,
+            Format::Astc12x12SfloatBlock => true,
+            _ => false,
+        }
+    }
+    #[doc = r" Return the description of each component of this format, in the order of the format name"]
+    pub const fn components(self) -> &'static [FormatComponent] {
+        match self {
+            Format::Undefined => panic!("Trying to get the components of vk::Format::Undefined"),
+            Format::R4G4UnormPack8 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R4G4B4A4UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B4G4R4A4UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R5G6B5UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(6u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B5G6R5UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(6u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R5G5B5A1UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(1u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B5G5R5A1UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(1u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A1R5G5B5UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(1u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A1B5G5R5UnormPack16KHR => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(1u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(5u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A8UnormKHR => &[FormatComponent {
+                name: ComponentName::A,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::R8Unorm => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::R8Snorm => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Snorm,
+                plane: None,
+            }],
+            Format::R8Uscaled => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Uscaled,
+                plane: None,
+            }],
+            Format::R8Sscaled => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Sscaled,
+                plane: None,
+            }],
+            Format::R8Uint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Uint,
+                plane: None,
+            }],
+            Format::R8Sint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Sint,
+                plane: None,
+            }],
+            Format::R8Srgb => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Srgb,
+                plane: None,
+            }],
+            Format::R8G8Unorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R8G8Snorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::R8G8Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::R8G8Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::R8G8Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R8G8Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R8G8Srgb => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Unorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Snorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8Srgb => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Unorm => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Snorm => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Uint => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Sint => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8Srgb => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Unorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Snorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R8G8B8A8Srgb => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Unorm => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Snorm => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Uint => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Sint => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8A8Srgb => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8UnormPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8SnormPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8UscaledPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8SscaledPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8UintPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8SintPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::A8B8G8R8SrgbPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+            ],
+            Format::A2R10G10B10UnormPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A2R10G10B10SnormPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::A2R10G10B10UscaledPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::A2R10G10B10SscaledPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::A2R10G10B10UintPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::A2R10G10B10SintPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::A2B10G10R10UnormPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A2B10G10R10SnormPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::A2B10G10R10UscaledPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::A2B10G10R10SscaledPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::A2B10G10R10UintPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::A2B10G10R10SintPack32 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(2u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R16Unorm => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::R16Snorm => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Snorm,
+                plane: None,
+            }],
+            Format::R16Uscaled => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Uscaled,
+                plane: None,
+            }],
+            Format::R16Sscaled => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Sscaled,
+                plane: None,
+            }],
+            Format::R16Uint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Uint,
+                plane: None,
+            }],
+            Format::R16Sint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Sint,
+                plane: None,
+            }],
+            Format::R16Sfloat => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Sfloat,
+                plane: None,
+            }],
+            Format::R16G16Unorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R16G16Snorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::R16G16Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::R16G16Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::R16G16Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R16G16Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R16G16Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Unorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Snorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Unorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Snorm => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Uscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uscaled,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Sscaled => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sscaled,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R16G16B16A16Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R32Uint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(32u8),
+                numeric_format: NumericFormat::Uint,
+                plane: None,
+            }],
+            Format::R32Sint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(32u8),
+                numeric_format: NumericFormat::Sint,
+                plane: None,
+            }],
+            Format::R32Sfloat => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(32u8),
+                numeric_format: NumericFormat::Sfloat,
+                plane: None,
+            }],
+            Format::R32G32Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R32G32Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R32G32Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R32G32B32Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R32G32B32Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R32G32B32Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R32G32B32A32Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R32G32B32A32Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R32G32B32A32Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R64Uint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(64u8),
+                numeric_format: NumericFormat::Uint,
+                plane: None,
+            }],
+            Format::R64Sint => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(64u8),
+                numeric_format: NumericFormat::Sint,
+                plane: None,
+            }],
+            Format::R64Sfloat => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(64u8),
+                numeric_format: NumericFormat::Sfloat,
+                plane: None,
+            }],
+            Format::R64G64Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R64G64Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R64G64Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R64G64B64Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R64G64B64Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R64G64B64Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::R64G64B64A64Uint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::R64G64B64A64Sint => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sint,
+                    plane: None,
+                },
+            ],
+            Format::R64G64B64A64Sfloat => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(64u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::B10G11R11UfloatPack32 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(11u8),
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(11u8),
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+            ],
+            Format::E5B9G9R9UfloatPack32 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(9u8),
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(9u8),
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(9u8),
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+            ],
+            Format::D16Unorm => &[FormatComponent {
+                name: ComponentName::D,
+                bits: Some(16u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::X8D24UnormPack32 => &[FormatComponent {
+                name: ComponentName::D,
+                bits: Some(24u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::D32Sfloat => &[FormatComponent {
+                name: ComponentName::D,
+                bits: Some(32u8),
+                numeric_format: NumericFormat::Sfloat,
+                plane: None,
+            }],
+            Format::S8Uint => &[FormatComponent {
+                name: ComponentName::S,
+                bits: Some(8u8),
+                numeric_format: NumericFormat::Uint,
+                plane: None,
+            }],
+            Format::D16UnormS8Uint => &[
+                FormatComponent {
+                    name: ComponentName::D,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::S,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::D24UnormS8Uint => &[
+                FormatComponent {
+                    name: ComponentName::D,
+                    bits: Some(24u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::S,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::D32SfloatS8Uint => &[
+                FormatComponent {
+                    name: ComponentName::D,
+                    bits: Some(32u8),
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::S,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Uint,
+                    plane: None,
+                },
+            ],
+            Format::Bc1RgbUnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc1RgbSrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+            ],
+            Format::Bc1RgbaUnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc1RgbaSrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc2UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc2SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc3UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc3SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc4UnormBlock => &[FormatComponent {
+                name: ComponentName::R,
+                bits: None,
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::Bc4SnormBlock => &[FormatComponent {
+                name: ComponentName::R,
+                bits: None,
+                numeric_format: NumericFormat::Snorm,
+                plane: None,
+            }],
+            Format::Bc5UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc5SnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc6HUfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Ufloat,
+                    plane: None,
+                },
+            ],
+            Format::Bc6HSfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Bc7UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Bc7SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Etc2R8G8B8UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Etc2R8G8B8SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+            ],
+            Format::Etc2R8G8B8A1UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Etc2R8G8B8A1SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Etc2R8G8B8A8UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Etc2R8G8B8A8SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::EacR11UnormBlock => &[FormatComponent {
+                name: ComponentName::R,
+                bits: None,
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::EacR11SnormBlock => &[FormatComponent {
+                name: ComponentName::R,
+                bits: None,
+                numeric_format: NumericFormat::Snorm,
+                plane: None,
+            }],
+            Format::EacR11G11UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::EacR11G11SnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Snorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc4x4UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc4x4SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc5x4UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc5x4SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc5x5UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc5x5SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc6x5UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc6x5SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc6x6UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc6x6SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x5UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x5SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x6UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x6SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x8UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x8SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x5UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x5SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x6UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x6SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x8UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x8SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x10UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x10SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc12x10UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc12x10SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc12x12UnormBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc12x12SrgbBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G8B8G8R8422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B8G8R8G8422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G8B8R83Plane420Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G8B8R82Plane420Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G8B8R83Plane422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G8B8R82Plane422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G8B8R83Plane444Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::R10X6UnormPack16 => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(10u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::R10X6G10X6Unorm2Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R10X6G10X6B10X6A10X6Unorm4Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G10X6B10X6G10X6R10X6422Unorm4Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B10X6G10X6R10X6G10X6422Unorm4Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::R12X4UnormPack16 => &[FormatComponent {
+                name: ComponentName::R,
+                bits: Some(12u8),
+                numeric_format: NumericFormat::Unorm,
+                plane: None,
+            }],
+            Format::R12X4G12X4Unorm2Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::R12X4G12X4B12X4A12X4Unorm4Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G12X4B12X4G12X4R12X4422Unorm4Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B12X4G12X4R12X4G12X4422Unorm4Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G16B16G16R16422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::B16G16R16G16422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::G16B16R163Plane420Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G16B16R162Plane420Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G16B16R163Plane422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            Format::G16B16R162Plane422Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G16B16R163Plane444Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(2u8),
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppUnormBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppUnormBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppUnormBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppUnormBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppSrgbBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppSrgbBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppSrgbBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppSrgbBlockIMG => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Srgb,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::Astc4x4SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc5x4SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc5x5SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc6x5SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc6x6SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x5SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x6SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc8x8SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x5SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x6SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x8SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc10x10SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc12x10SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::Astc12x12SfloatBlock => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: None,
+                    numeric_format: NumericFormat::Sfloat,
+                    plane: None,
+                },
+            ],
+            Format::G8B8R82Plane444Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(8u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(10u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(12u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::G16B16R162Plane444Unorm => &[
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(0u8),
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: Some(1u8),
+                },
+            ],
+            Format::A4R4G4B4UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            Format::A4B4G4R4UnormPack16 => &[
+                FormatComponent {
+                    name: ComponentName::A,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::B,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(4u8),
+                    numeric_format: NumericFormat::Unorm,
+                    plane: None,
+                },
+            ],
+            #[cfg(feature = "vendor-nv")]
+            Format::R16G16Sfixed5NV => &[
+                FormatComponent {
+                    name: ComponentName::R,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfixed5,
+                    plane: None,
+                },
+                FormatComponent {
+                    name: ComponentName::G,
+                    bits: Some(16u8),
+                    numeric_format: NumericFormat::Sfixed5,
+                    plane: None,
+                },
+            ],
+        }
+    }
+    #[doc = r" Return the numeric format of this format."]
+    #[doc = r" For formats with components of different numeric formats (sRGB formats with an alpha component"]
+    #[doc = r" or depth/stencil formats), this is the numeric format of the first color or depth component"]
+    pub const fn numeric_format(self) -> NumericFormat {
+        match self {
+            Format::Undefined => {
+                panic!("Trying to get the numeric format of vk::Format::Undefined")
+            }
+            Format::R4G4UnormPack8 => NumericFormat::Unorm,
+            Format::R4G4B4A4UnormPack16 => NumericFormat::Unorm,
+            Format::B4G4R4A4UnormPack16 => NumericFormat::Unorm,
+            Format::R5G6B5UnormPack16 => NumericFormat::Unorm,
+            Format::B5G6R5UnormPack16 => NumericFormat::Unorm,
+            Format::R5G5B5A1UnormPack16 => NumericFormat::Unorm,
+            Format::B5G5R5A1UnormPack16 => NumericFormat::Unorm,
+            Format::A1R5G5B5UnormPack16 => NumericFormat::Unorm,
+            Format::A1B5G5R5UnormPack16KHR => NumericFormat::Unorm,
+            Format::A8UnormKHR => NumericFormat::Unorm,
+            Format::R8Unorm => NumericFormat::Unorm,
+            Format::R8Snorm => NumericFormat::Snorm,
+            Format::R8Uscaled => NumericFormat::Uscaled,
+            Format::R8Sscaled => NumericFormat::Sscaled,
+            Format::R8Uint => NumericFormat::Uint,
+            Format::R8Sint => NumericFormat::Sint,
+            Format::R8Srgb => NumericFormat::Srgb,
+            Format::R8G8Unorm => NumericFormat::Unorm,
+            Format::R8G8Snorm => NumericFormat::Snorm,
+            Format::R8G8Uscaled => NumericFormat::Uscaled,
+            Format::R8G8Sscaled => NumericFormat::Sscaled,
+            Format::R8G8Uint => NumericFormat::Uint,
+            Format::R8G8Sint => NumericFormat::Sint,
+            Format::R8G8Srgb => NumericFormat::Srgb,
+            Format::R8G8B8Unorm => NumericFormat::Unorm,
+            Format::R8G8B8Snorm => NumericFormat::Snorm,
+            Format::R8G8B8Uscaled => NumericFormat::Uscaled,
+            Format::R8G8B8Sscaled => NumericFormat::Sscaled,
+            Format::R8G8B8Uint => NumericFormat::Uint,
+            Format::R8G8B8Sint => NumericFormat::Sint,
+            Format::R8G8B8Srgb => NumericFormat::Srgb,
+            Format::B8G8R8Unorm => NumericFormat::Unorm,
+            Format::B8G8R8Snorm => NumericFormat::Snorm,
+            Format::B8G8R8Uscaled => NumericFormat::Uscaled,
+            Format::B8G8R8Sscaled => NumericFormat::Sscaled,
+            Format::B8G8R8Uint => NumericFormat::Uint,
+            Format::B8G8R8Sint => NumericFormat::Sint,
+            Format::B8G8R8Srgb => NumericFormat::Srgb,
+            Format::R8G8B8A8Unorm => NumericFormat::Unorm,
+            Format::R8G8B8A8Snorm => NumericFormat::Snorm,
+            Format::R8G8B8A8Uscaled => NumericFormat::Uscaled,
+            Format::R8G8B8A8Sscaled => NumericFormat::Sscaled,
+            Format::R8G8B8A8Uint => NumericFormat::Uint,
+            Format::R8G8B8A8Sint => NumericFormat::Sint,
+            Format::R8G8B8A8Srgb => NumericFormat::Srgb,
+            Format::B8G8R8A8Unorm => NumericFormat::Unorm,
+            Format::B8G8R8A8Snorm => NumericFormat::Snorm,
+            Format::B8G8R8A8Uscaled => NumericFormat::Uscaled,
+            Format::B8G8R8A8Sscaled => NumericFormat::Sscaled,
+            Format::B8G8R8A8Uint => NumericFormat::Uint,
+            Format::B8G8R8A8Sint => NumericFormat::Sint,
+            Format::B8G8R8A8Srgb => NumericFormat::Srgb,
+            Format::A8B8G8R8UnormPack32 => NumericFormat::Unorm,
+            Format::A8B8G8R8SnormPack32 => NumericFormat::Snorm,
+            Format::A8B8G8R8UscaledPack32 => NumericFormat::Uscaled,
+            Format::A8B8G8R8SscaledPack32 => NumericFormat::Sscaled,
+            Format::A8B8G8R8UintPack32 => NumericFormat::Uint,
+            Format::A8B8G8R8SintPack32 => NumericFormat::Sint,
+            Format::A8B8G8R8SrgbPack32 => NumericFormat::Srgb,
+            Format::A2R10G10B10UnormPack32 => NumericFormat::Unorm,
+            Format::A2R10G10B10SnormPack32 => NumericFormat::Snorm,
+            Format::A2R10G10B10UscaledPack32 => NumericFormat::Uscaled,
+            Format::A2R10G10B10SscaledPack32 => NumericFormat::Sscaled,
+            Format::A2R10G10B10UintPack32 => NumericFormat::Uint,
+            Format::A2R10G10B10SintPack32 => NumericFormat::Sint,
+            Format::A2B10G10R10UnormPack32 => NumericFormat::Unorm,
+            Format::A2B10G10R10SnormPack32 => NumericFormat::Snorm,
+            Format::A2B10G10R10UscaledPack32 => NumericFormat::Uscaled,
+            Format::A2B10G10R10SscaledPack32 => NumericFormat::Sscaled,
+            Format::A2B10G10R10UintPack32 => NumericFormat::Uint,
+            Format::A2B10G10R10SintPack32 => NumericFormat::Sint,
+            Format::R16Unorm => NumericFormat::Unorm,
+            Format::R16Snorm => NumericFormat::Snorm,
+            Format::R16Uscaled => NumericFormat::Uscaled,
+            Format::R16Sscaled => NumericFormat::Sscaled,
+            Format::R16Uint => NumericFormat::Uint,
+            Format::R16Sint => NumericFormat::Sint,
+            Format::R16Sfloat => NumericFormat::Sfloat,
+            Format::R16G16Unorm => NumericFormat::Unorm,
+            Format::R16G16Snorm => NumericFormat::Snorm,
+            Format::R16G16Uscaled => NumericFormat::Uscaled,
+            Format::R16G16Sscaled => NumericFormat::Sscaled,
+            Format::R16G16Uint => NumericFormat::Uint,
+            Format::R16G16Sint => NumericFormat::Sint,
+            Format::R16G16Sfloat => NumericFormat::Sfloat,
+            Format::R16G16B16Unorm => NumericFormat::Unorm,
+            Format::R16G16B16Snorm => NumericFormat::Snorm,
+            Format::R16G16B16Uscaled => NumericFormat::Uscaled,
+            Format::R16G16B16Sscaled => NumericFormat::Sscaled,
+            Format::R16G16B16Uint => NumericFormat::Uint,
+            Format::R16G16B16Sint => NumericFormat::Sint,
+            Format::R16G16B16Sfloat => NumericFormat::Sfloat,
+            Format::R16G16B16A16Unorm => NumericFormat::Unorm,
+            Format::R16G16B16A16Snorm => NumericFormat::Snorm,
+            Format::R16G16B16A16Uscaled => NumericFormat::Uscaled,
+            Format::R16G16B16A16Sscaled => NumericFormat::Sscaled,
+            Format::R16G16B16A16Uint => NumericFormat::Uint,
+            Format::R16G16B16A16Sint => NumericFormat::Sint,
+            Format::R16G16B16A16Sfloat => NumericFormat::Sfloat,
+            Format::R32Uint => NumericFormat::Uint,
+            Format::R32Sint => NumericFormat::Sint,
+            Format::R32Sfloat => NumericFormat::Sfloat,
+            Format::R32G32Uint => NumericFormat::Uint,
+            Format::R32G32Sint => NumericFormat::Sint,
+            Format::R32G32Sfloat => NumericFormat::Sfloat,
+            Format::R32G32B32Uint => NumericFormat::Uint,
+            Format::R32G32B32Sint => NumericFormat::Sint,
+            Format::R32G32B32Sfloat => NumericFormat::Sfloat,
+            Format::R32G32B32A32Uint => NumericFormat::Uint,
+            Format::R32G32B32A32Sint => NumericFormat::Sint,
+            Format::R32G32B32A32Sfloat => NumericFormat::Sfloat,
+            Format::R64Uint => NumericFormat::Uint,
+            Format::R64Sint => NumericFormat::Sint,
+            Format::R64Sfloat => NumericFormat::Sfloat,
+            Format::R64G64Uint => NumericFormat::Uint,
+            Format::R64G64Sint => NumericFormat::Sint,
+            Format::R64G64Sfloat => NumericFormat::Sfloat,
+            Format::R64G64B64Uint => NumericFormat::Uint,
+            Format::R64G64B64Sint => NumericFormat::Sint,
+            Format::R64G64B64Sfloat => NumericFormat::Sfloat,
+            Format::R64G64B64A64Uint => NumericFormat::Uint,
+            Format::R64G64B64A64Sint => NumericFormat::Sint,
+            Format::R64G64B64A64Sfloat => NumericFormat::Sfloat,
+            Format::B10G11R11UfloatPack32 => NumericFormat::Ufloat,
+            Format::E5B9G9R9UfloatPack32 => NumericFormat::Ufloat,
+            Format::D16Unorm => NumericFormat::Unorm,
+            Format::X8D24UnormPack32 => NumericFormat::Unorm,
+            Format::D32Sfloat => NumericFormat::Sfloat,
+            Format::S8Uint => NumericFormat::Uint,
+            Format::D16UnormS8Uint => NumericFormat::Unorm,
+            Format::D24UnormS8Uint => NumericFormat::Unorm,
+            Format::D32SfloatS8Uint => NumericFormat::Sfloat,
+            Format::Bc1RgbUnormBlock => NumericFormat::Unorm,
+            Format::Bc1RgbSrgbBlock => NumericFormat::Srgb,
+            Format::Bc1RgbaUnormBlock => NumericFormat::Unorm,
+            Format::Bc1RgbaSrgbBlock => NumericFormat::Srgb,
+            Format::Bc2UnormBlock => NumericFormat::Unorm,
+            Format::Bc2SrgbBlock => NumericFormat::Srgb,
+            Format::Bc3UnormBlock => NumericFormat::Unorm,
+            Format::Bc3SrgbBlock => NumericFormat::Srgb,
+            Format::Bc4UnormBlock => NumericFormat::Unorm,
+            Format::Bc4SnormBlock => NumericFormat::Snorm,
+            Format::Bc5UnormBlock => NumericFormat::Unorm,
+            Format::Bc5SnormBlock => NumericFormat::Snorm,
+            Format::Bc6HUfloatBlock => NumericFormat::Ufloat,
+            Format::Bc6HSfloatBlock => NumericFormat::Sfloat,
+            Format::Bc7UnormBlock => NumericFormat::Unorm,
+            Format::Bc7SrgbBlock => NumericFormat::Srgb,
+            Format::Etc2R8G8B8UnormBlock => NumericFormat::Unorm,
+            Format::Etc2R8G8B8SrgbBlock => NumericFormat::Srgb,
+            Format::Etc2R8G8B8A1UnormBlock => NumericFormat::Unorm,
+            Format::Etc2R8G8B8A1SrgbBlock => NumericFormat::Srgb,
+            Format::Etc2R8G8B8A8UnormBlock => NumericFormat::Unorm,
+            Format::Etc2R8G8B8A8SrgbBlock => NumericFormat::Srgb,
+            Format::EacR11UnormBlock => NumericFormat::Unorm,
+            Format::EacR11SnormBlock => NumericFormat::Snorm,
+            Format::EacR11G11UnormBlock => NumericFormat::Unorm,
+            Format::EacR11G11SnormBlock => NumericFormat::Snorm,
+            Format::Astc4x4UnormBlock => NumericFormat::Unorm,
+            Format::Astc4x4SrgbBlock => NumericFormat::Srgb,
+            Format::Astc5x4UnormBlock => NumericFormat::Unorm,
+            Format::Astc5x4SrgbBlock => NumericFormat::Srgb,
+            Format::Astc5x5UnormBlock => NumericFormat::Unorm,
+            Format::Astc5x5SrgbBlock => NumericFormat::Srgb,
+            Format::Astc6x5UnormBlock => NumericFormat::Unorm,
+            Format::Astc6x5SrgbBlock => NumericFormat::Srgb,
+            Format::Astc6x6UnormBlock => NumericFormat::Unorm,
+            Format::Astc6x6SrgbBlock => NumericFormat::Srgb,
+            Format::Astc8x5UnormBlock => NumericFormat::Unorm,
+            Format::Astc8x5SrgbBlock => NumericFormat::Srgb,
+            Format::Astc8x6UnormBlock => NumericFormat::Unorm,
+            Format::Astc8x6SrgbBlock => NumericFormat::Srgb,
+            Format::Astc8x8UnormBlock => NumericFormat::Unorm,
+            Format::Astc8x8SrgbBlock => NumericFormat::Srgb,
+            Format::Astc10x5UnormBlock => NumericFormat::Unorm,
+            Format::Astc10x5SrgbBlock => NumericFormat::Srgb,
+            Format::Astc10x6UnormBlock => NumericFormat::Unorm,
+            Format::Astc10x6SrgbBlock => NumericFormat::Srgb,
+            Format::Astc10x8UnormBlock => NumericFormat::Unorm,
+            Format::Astc10x8SrgbBlock => NumericFormat::Srgb,
+            Format::Astc10x10UnormBlock => NumericFormat::Unorm,
+            Format::Astc10x10SrgbBlock => NumericFormat::Srgb,
+            Format::Astc12x10UnormBlock => NumericFormat::Unorm,
+            Format::Astc12x10SrgbBlock => NumericFormat::Srgb,
+            Format::Astc12x12UnormBlock => NumericFormat::Unorm,
+            Format::Astc12x12SrgbBlock => NumericFormat::Srgb,
+            Format::G8B8G8R8422Unorm => NumericFormat::Unorm,
+            Format::B8G8R8G8422Unorm => NumericFormat::Unorm,
+            Format::G8B8R83Plane420Unorm => NumericFormat::Unorm,
+            Format::G8B8R82Plane420Unorm => NumericFormat::Unorm,
+            Format::G8B8R83Plane422Unorm => NumericFormat::Unorm,
+            Format::G8B8R82Plane422Unorm => NumericFormat::Unorm,
+            Format::G8B8R83Plane444Unorm => NumericFormat::Unorm,
+            Format::R10X6UnormPack16 => NumericFormat::Unorm,
+            Format::R10X6G10X6Unorm2Pack16 => NumericFormat::Unorm,
+            Format::R10X6G10X6B10X6A10X6Unorm4Pack16 => NumericFormat::Unorm,
+            Format::G10X6B10X6G10X6R10X6422Unorm4Pack16 => NumericFormat::Unorm,
+            Format::B10X6G10X6R10X6G10X6422Unorm4Pack16 => NumericFormat::Unorm,
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => NumericFormat::Unorm,
+            Format::R12X4UnormPack16 => NumericFormat::Unorm,
+            Format::R12X4G12X4Unorm2Pack16 => NumericFormat::Unorm,
+            Format::R12X4G12X4B12X4A12X4Unorm4Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4G12X4R12X4422Unorm4Pack16 => NumericFormat::Unorm,
+            Format::B12X4G12X4R12X4G12X4422Unorm4Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G16B16G16R16422Unorm => NumericFormat::Unorm,
+            Format::B16G16R16G16422Unorm => NumericFormat::Unorm,
+            Format::G16B16R163Plane420Unorm => NumericFormat::Unorm,
+            Format::G16B16R162Plane420Unorm => NumericFormat::Unorm,
+            Format::G16B16R163Plane422Unorm => NumericFormat::Unorm,
+            Format::G16B16R162Plane422Unorm => NumericFormat::Unorm,
+            Format::G16B16R163Plane444Unorm => NumericFormat::Unorm,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppUnormBlockIMG => NumericFormat::Unorm,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppUnormBlockIMG => NumericFormat::Unorm,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppUnormBlockIMG => NumericFormat::Unorm,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppUnormBlockIMG => NumericFormat::Unorm,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppSrgbBlockIMG => NumericFormat::Srgb,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppSrgbBlockIMG => NumericFormat::Srgb,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppSrgbBlockIMG => NumericFormat::Srgb,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppSrgbBlockIMG => NumericFormat::Srgb,
+            Format::Astc4x4SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc5x4SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc5x5SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc6x5SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc6x6SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc8x5SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc8x6SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc8x8SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc10x5SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc10x6SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc10x8SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc10x10SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc12x10SfloatBlock => NumericFormat::Sfloat,
+            Format::Astc12x12SfloatBlock => NumericFormat::Sfloat,
+            Format::G8B8R82Plane444Unorm => NumericFormat::Unorm,
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => NumericFormat::Unorm,
+            Format::G16B16R162Plane444Unorm => NumericFormat::Unorm,
+            Format::A4R4G4B4UnormPack16 => NumericFormat::Unorm,
+            Format::A4B4G4R4UnormPack16 => NumericFormat::Unorm,
+            #[cfg(feature = "vendor-nv")]
+            Format::R16G16Sfixed5NV => NumericFormat::Sfixed5,
+        }
+    }
+    #[doc = r" Return true if this format has a depth component"]
+    #[allow(clippy::match_like_matches_macro)]
+    pub const fn is_depth(self) -> bool {
+        match self {
+            Format::D16Unorm => true,
+            Format::X8D24UnormPack32 => true,
+            Format::D32Sfloat => true,
+            Format::D16UnormS8Uint => true,
+            Format::D24UnormS8Uint => true,
+            Format::D32SfloatS8Uint => true,
+            _ => false,
+        }
+    }
+    #[doc = r" Return true if this format has a stencil component"]
+    #[allow(clippy::match_like_matches_macro)]
+    pub const fn is_stencil(self) -> bool {
+        match self {
+            Format::S8Uint => true,
+            Format::D16UnormS8Uint => true,
+            Format::D24UnormS8Uint => true,
+            Format::D32SfloatS8Uint => true,
             _ => false,
         }
     }
+    #[doc = r" Return the aspects of an image with this format."]
+    #[doc = r" The planes of multi-planar formats are part of the color aspect"]
+    pub const fn aspect_mask(self) -> ImageAspectFlags {
+        match self {
+            Format::Undefined => panic!("Trying to get the aspect mask of vk::Format::Undefined"),
+            Format::D16Unorm => ImageAspectFlags::Depth,
+            Format::X8D24UnormPack32 => ImageAspectFlags::Depth,
+            Format::D32Sfloat => ImageAspectFlags::Depth,
+            Format::S8Uint => ImageAspectFlags::Stencil,
+            Format::D16UnormS8Uint => ImageAspectFlags::Depth.union(ImageAspectFlags::Stencil),
+            Format::D24UnormS8Uint => ImageAspectFlags::Depth.union(ImageAspectFlags::Stencil),
+            Format::D32SfloatS8Uint => ImageAspectFlags::Depth.union(ImageAspectFlags::Stencil),
+            _ => ImageAspectFlags::Color,
+        }
+    }
+    #[doc = r" If this format is a packed format, return the size in bits of each packed element"]
+    pub const fn packed(self) -> Option<u8> {
+        match self {
+            Format::R4G4UnormPack8 => Some(8u8),
+            Format::R4G4B4A4UnormPack16 => Some(16u8),
+            Format::B4G4R4A4UnormPack16 => Some(16u8),
+            Format::R5G6B5UnormPack16 => Some(16u8),
+            Format::B5G6R5UnormPack16 => Some(16u8),
+            Format::R5G5B5A1UnormPack16 => Some(16u8),
+            Format::B5G5R5A1UnormPack16 => Some(16u8),
+            Format::A1R5G5B5UnormPack16 => Some(16u8),
+            Format::A1B5G5R5UnormPack16KHR => Some(16u8),
+            Format::A8B8G8R8UnormPack32 => Some(32u8),
+            Format::A8B8G8R8SnormPack32 => Some(32u8),
+            Format::A8B8G8R8UscaledPack32 => Some(32u8),
+            Format::A8B8G8R8SscaledPack32 => Some(32u8),
+            Format::A8B8G8R8UintPack32 => Some(32u8),
+            Format::A8B8G8R8SintPack32 => Some(32u8),
+            Format::A8B8G8R8SrgbPack32 => Some(32u8),
+            Format::A2R10G10B10UnormPack32 => Some(32u8),
+            Format::A2R10G10B10SnormPack32 => Some(32u8),
+            Format::A2R10G10B10UscaledPack32 => Some(32u8),
+            Format::A2R10G10B10SscaledPack32 => Some(32u8),
+            Format::A2R10G10B10UintPack32 => Some(32u8),
+            Format::A2R10G10B10SintPack32 => Some(32u8),
+            Format::A2B10G10R10UnormPack32 => Some(32u8),
+            Format::A2B10G10R10SnormPack32 => Some(32u8),
+            Format::A2B10G10R10UscaledPack32 => Some(32u8),
+            Format::A2B10G10R10SscaledPack32 => Some(32u8),
+            Format::A2B10G10R10UintPack32 => Some(32u8),
+            Format::A2B10G10R10SintPack32 => Some(32u8),
+            Format::B10G11R11UfloatPack32 => Some(32u8),
+            Format::E5B9G9R9UfloatPack32 => Some(32u8),
+            Format::X8D24UnormPack32 => Some(32u8),
+            Format::R10X6UnormPack16 => Some(16u8),
+            Format::R10X6G10X6Unorm2Pack16 => Some(16u8),
+            Format::R10X6G10X6B10X6A10X6Unorm4Pack16 => Some(16u8),
+            Format::G10X6B10X6G10X6R10X6422Unorm4Pack16 => Some(16u8),
+            Format::B10X6G10X6R10X6G10X6422Unorm4Pack16 => Some(16u8),
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => Some(16u8),
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => Some(16u8),
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => Some(16u8),
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => Some(16u8),
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => Some(16u8),
+            Format::R12X4UnormPack16 => Some(16u8),
+            Format::R12X4G12X4Unorm2Pack16 => Some(16u8),
+            Format::R12X4G12X4B12X4A12X4Unorm4Pack16 => Some(16u8),
+            Format::G12X4B12X4G12X4R12X4422Unorm4Pack16 => Some(16u8),
+            Format::B12X4G12X4R12X4G12X4422Unorm4Pack16 => Some(16u8),
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => Some(16u8),
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => Some(16u8),
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => Some(16u8),
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => Some(16u8),
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => Some(16u8),
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => Some(16u8),
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => Some(16u8),
+            Format::A4R4G4B4UnormPack16 => Some(16u8),
+            Format::A4B4G4R4UnormPack16 => Some(16u8),
+            _ => None,
+        }
+    }
+    #[doc = r" If this format is a YCbCr format, return the subsampling of its chroma components"]
+    pub const fn chroma_subsampling(self) -> Option<ChromaSubsampling> {
+        match self {
+            Format::G8B8G8R8422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::B8G8R8G8422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::G8B8R83Plane420Unorm => Some(ChromaSubsampling::Chroma420),
+            Format::G8B8R82Plane420Unorm => Some(ChromaSubsampling::Chroma420),
+            Format::G8B8R83Plane422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::G8B8R82Plane422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::G8B8R83Plane444Unorm => Some(ChromaSubsampling::Chroma444),
+            Format::G10X6B10X6G10X6R10X6422Unorm4Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::B10X6G10X6R10X6G10X6422Unorm4Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => Some(ChromaSubsampling::Chroma420),
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => Some(ChromaSubsampling::Chroma420),
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => Some(ChromaSubsampling::Chroma444),
+            Format::G12X4B12X4G12X4R12X4422Unorm4Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::B12X4G12X4R12X4G12X4422Unorm4Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => Some(ChromaSubsampling::Chroma420),
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => Some(ChromaSubsampling::Chroma420),
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => Some(ChromaSubsampling::Chroma422),
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => Some(ChromaSubsampling::Chroma444),
+            Format::G16B16G16R16422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::B16G16R16G16422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::G16B16R163Plane420Unorm => Some(ChromaSubsampling::Chroma420),
+            Format::G16B16R162Plane420Unorm => Some(ChromaSubsampling::Chroma420),
+            Format::G16B16R163Plane422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::G16B16R162Plane422Unorm => Some(ChromaSubsampling::Chroma422),
+            Format::G16B16R163Plane444Unorm => Some(ChromaSubsampling::Chroma444),
+            Format::G8B8R82Plane444Unorm => Some(ChromaSubsampling::Chroma444),
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => Some(ChromaSubsampling::Chroma444),
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => Some(ChromaSubsampling::Chroma444),
+            Format::G16B16R162Plane444Unorm => Some(ChromaSubsampling::Chroma444),
+            _ => None,
+        }
+    }
+    #[doc = r" Return the number of planes of this format, 1 for formats which are not multi-planar"]
+    pub const fn plane_count(self) -> u8 {
+        match self {
+            Format::Undefined => panic!("Trying to get the plane count of vk::Format::Undefined"),
+            Format::G8B8R83Plane420Unorm => 3u8,
+            Format::G8B8R82Plane420Unorm => 2u8,
+            Format::G8B8R83Plane422Unorm => 3u8,
+            Format::G8B8R82Plane422Unorm => 2u8,
+            Format::G8B8R83Plane444Unorm => 3u8,
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => 3u8,
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => 2u8,
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => 3u8,
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => 2u8,
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => 3u8,
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => 3u8,
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => 2u8,
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => 3u8,
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => 2u8,
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => 3u8,
+            Format::G16B16R163Plane420Unorm => 3u8,
+            Format::G16B16R162Plane420Unorm => 2u8,
+            Format::G16B16R163Plane422Unorm => 3u8,
+            Format::G16B16R162Plane422Unorm => 2u8,
+            Format::G16B16R163Plane444Unorm => 3u8,
+            Format::G8B8R82Plane444Unorm => 2u8,
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => 2u8,
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => 2u8,
+            Format::G16B16R162Plane444Unorm => 2u8,
+            _ => 1,
+        }
+    }
+    #[doc = r" Return the description of each plane of a multi-planar format,"]
+    #[doc = r" or an empty slice if this format is not multi-planar"]
+    pub const fn planes(self) -> &'static [FormatPlane] {
+        match self {
+            Format::Undefined => panic!("Trying to get the planes of vk::Format::Undefined"),
+            Format::G8B8R83Plane420Unorm => &[
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G8B8R82Plane420Unorm => &[
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8G8Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G8B8R83Plane422Unorm => &[
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G8B8R82Plane422Unorm => &[
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8G8Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G8B8R83Plane444Unorm => &[
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6G10X6Unorm2Pack16,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6G10X6Unorm2Pack16,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4G12X4Unorm2Pack16,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4G12X4Unorm2Pack16,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G16B16R163Plane420Unorm => &[
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G16B16R162Plane420Unorm => &[
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16G16Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 2u8,
+                },
+            ],
+            Format::G16B16R163Plane422Unorm => &[
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G16B16R162Plane422Unorm => &[
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16G16Unorm,
+                    width_divisor: 2u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G16B16R163Plane444Unorm => &[
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G8B8R82Plane444Unorm => &[
+                FormatPlane {
+                    format: Format::R8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R8G8Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R10X6UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R10X6G10X6Unorm2Pack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => &[
+                FormatPlane {
+                    format: Format::R12X4UnormPack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R12X4G12X4Unorm2Pack16,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            Format::G16B16R162Plane444Unorm => &[
+                FormatPlane {
+                    format: Format::R16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+                FormatPlane {
+                    format: Format::R16G16Unorm,
+                    width_divisor: 1u8,
+                    height_divisor: 1u8,
+                },
+            ],
+            _ => &[],
+        }
+    }
+    #[doc = r" Return the compatibility class of this format"]
+    pub const fn compatibility_class(self) -> CompatibilityClass {
+        match self {
+            Format::Undefined => {
+                panic!("Trying to get the compatibility class of vk::Format::Undefined")
+            }
+            Format::R4G4UnormPack8 => CompatibilityClass::Bit8,
+            Format::R4G4B4A4UnormPack16 => CompatibilityClass::Bit16,
+            Format::B4G4R4A4UnormPack16 => CompatibilityClass::Bit16,
+            Format::R5G6B5UnormPack16 => CompatibilityClass::Bit16,
+            Format::B5G6R5UnormPack16 => CompatibilityClass::Bit16,
+            Format::R5G5B5A1UnormPack16 => CompatibilityClass::Bit16,
+            Format::B5G5R5A1UnormPack16 => CompatibilityClass::Bit16,
+            Format::A1R5G5B5UnormPack16 => CompatibilityClass::Bit16,
+            Format::A1B5G5R5UnormPack16KHR => CompatibilityClass::Bit16,
+            Format::A8UnormKHR => CompatibilityClass::Bit8Alpha,
+            Format::R8Unorm => CompatibilityClass::Bit8,
+            Format::R8Snorm => CompatibilityClass::Bit8,
+            Format::R8Uscaled => CompatibilityClass::Bit8,
+            Format::R8Sscaled => CompatibilityClass::Bit8,
+            Format::R8Uint => CompatibilityClass::Bit8,
+            Format::R8Sint => CompatibilityClass::Bit8,
+            Format::R8Srgb => CompatibilityClass::Bit8,
+            Format::R8G8Unorm => CompatibilityClass::Bit16,
+            Format::R8G8Snorm => CompatibilityClass::Bit16,
+            Format::R8G8Uscaled => CompatibilityClass::Bit16,
+            Format::R8G8Sscaled => CompatibilityClass::Bit16,
+            Format::R8G8Uint => CompatibilityClass::Bit16,
+            Format::R8G8Sint => CompatibilityClass::Bit16,
+            Format::R8G8Srgb => CompatibilityClass::Bit16,
+            Format::R8G8B8Unorm => CompatibilityClass::Bit24,
+            Format::R8G8B8Snorm => CompatibilityClass::Bit24,
+            Format::R8G8B8Uscaled => CompatibilityClass::Bit24,
+            Format::R8G8B8Sscaled => CompatibilityClass::Bit24,
+            Format::R8G8B8Uint => CompatibilityClass::Bit24,
+            Format::R8G8B8Sint => CompatibilityClass::Bit24,
+            Format::R8G8B8Srgb => CompatibilityClass::Bit24,
+            Format::B8G8R8Unorm => CompatibilityClass::Bit24,
+            Format::B8G8R8Snorm => CompatibilityClass::Bit24,
+            Format::B8G8R8Uscaled => CompatibilityClass::Bit24,
+            Format::B8G8R8Sscaled => CompatibilityClass::Bit24,
+            Format::B8G8R8Uint => CompatibilityClass::Bit24,
+            Format::B8G8R8Sint => CompatibilityClass::Bit24,
+            Format::B8G8R8Srgb => CompatibilityClass::Bit24,
+            Format::R8G8B8A8Unorm => CompatibilityClass::Bit32,
+            Format::R8G8B8A8Snorm => CompatibilityClass::Bit32,
+            Format::R8G8B8A8Uscaled => CompatibilityClass::Bit32,
+            Format::R8G8B8A8Sscaled => CompatibilityClass::Bit32,
+            Format::R8G8B8A8Uint => CompatibilityClass::Bit32,
+            Format::R8G8B8A8Sint => CompatibilityClass::Bit32,
+            Format::R8G8B8A8Srgb => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Unorm => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Snorm => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Uscaled => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Sscaled => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Uint => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Sint => CompatibilityClass::Bit32,
+            Format::B8G8R8A8Srgb => CompatibilityClass::Bit32,
+            Format::A8B8G8R8UnormPack32 => CompatibilityClass::Bit32,
+            Format::A8B8G8R8SnormPack32 => CompatibilityClass::Bit32,
+            Format::A8B8G8R8UscaledPack32 => CompatibilityClass::Bit32,
+            Format::A8B8G8R8SscaledPack32 => CompatibilityClass::Bit32,
+            Format::A8B8G8R8UintPack32 => CompatibilityClass::Bit32,
+            Format::A8B8G8R8SintPack32 => CompatibilityClass::Bit32,
+            Format::A8B8G8R8SrgbPack32 => CompatibilityClass::Bit32,
+            Format::A2R10G10B10UnormPack32 => CompatibilityClass::Bit32,
+            Format::A2R10G10B10SnormPack32 => CompatibilityClass::Bit32,
+            Format::A2R10G10B10UscaledPack32 => CompatibilityClass::Bit32,
+            Format::A2R10G10B10SscaledPack32 => CompatibilityClass::Bit32,
+            Format::A2R10G10B10UintPack32 => CompatibilityClass::Bit32,
+            Format::A2R10G10B10SintPack32 => CompatibilityClass::Bit32,
+            Format::A2B10G10R10UnormPack32 => CompatibilityClass::Bit32,
+            Format::A2B10G10R10SnormPack32 => CompatibilityClass::Bit32,
+            Format::A2B10G10R10UscaledPack32 => CompatibilityClass::Bit32,
+            Format::A2B10G10R10SscaledPack32 => CompatibilityClass::Bit32,
+            Format::A2B10G10R10UintPack32 => CompatibilityClass::Bit32,
+            Format::A2B10G10R10SintPack32 => CompatibilityClass::Bit32,
+            Format::R16Unorm => CompatibilityClass::Bit16,
+            Format::R16Snorm => CompatibilityClass::Bit16,
+            Format::R16Uscaled => CompatibilityClass::Bit16,
+            Format::R16Sscaled => CompatibilityClass::Bit16,
+            Format::R16Uint => CompatibilityClass::Bit16,
+            Format::R16Sint => CompatibilityClass::Bit16,
+            Format::R16Sfloat => CompatibilityClass::Bit16,
+            Format::R16G16Unorm => CompatibilityClass::Bit32,
+            Format::R16G16Snorm => CompatibilityClass::Bit32,
+            Format::R16G16Uscaled => CompatibilityClass::Bit32,
+            Format::R16G16Sscaled => CompatibilityClass::Bit32,
+            Format::R16G16Uint => CompatibilityClass::Bit32,
+            Format::R16G16Sint => CompatibilityClass::Bit32,
+            Format::R16G16Sfloat => CompatibilityClass::Bit32,
+            Format::R16G16B16Unorm => CompatibilityClass::Bit48,
+            Format::R16G16B16Snorm => CompatibilityClass::Bit48,
+            Format::R16G16B16Uscaled => CompatibilityClass::Bit48,
+            Format::R16G16B16Sscaled => CompatibilityClass::Bit48,
+            Format::R16G16B16Uint => CompatibilityClass::Bit48,
+            Format::R16G16B16Sint => CompatibilityClass::Bit48,
+            Format::R16G16B16Sfloat => CompatibilityClass::Bit48,
+            Format::R16G16B16A16Unorm => CompatibilityClass::Bit64,
+            Format::R16G16B16A16Snorm => CompatibilityClass::Bit64,
+            Format::R16G16B16A16Uscaled => CompatibilityClass::Bit64,
+            Format::R16G16B16A16Sscaled => CompatibilityClass::Bit64,
+            Format::R16G16B16A16Uint => CompatibilityClass::Bit64,
+            Format::R16G16B16A16Sint => CompatibilityClass::Bit64,
+            Format::R16G16B16A16Sfloat => CompatibilityClass::Bit64,
+            Format::R32Uint => CompatibilityClass::Bit32,
+            Format::R32Sint => CompatibilityClass::Bit32,
+            Format::R32Sfloat => CompatibilityClass::Bit32,
+            Format::R32G32Uint => CompatibilityClass::Bit64,
+            Format::R32G32Sint => CompatibilityClass::Bit64,
+            Format::R32G32Sfloat => CompatibilityClass::Bit64,
+            Format::R32G32B32Uint => CompatibilityClass::Bit96,
+            Format::R32G32B32Sint => CompatibilityClass::Bit96,
+            Format::R32G32B32Sfloat => CompatibilityClass::Bit96,
+            Format::R32G32B32A32Uint => CompatibilityClass::Bit128,
+            Format::R32G32B32A32Sint => CompatibilityClass::Bit128,
+            Format::R32G32B32A32Sfloat => CompatibilityClass::Bit128,
+            Format::R64Uint => CompatibilityClass::Bit64,
+            Format::R64Sint => CompatibilityClass::Bit64,
+            Format::R64Sfloat => CompatibilityClass::Bit64,
+            Format::R64G64Uint => CompatibilityClass::Bit128,
+            Format::R64G64Sint => CompatibilityClass::Bit128,
+            Format::R64G64Sfloat => CompatibilityClass::Bit128,
+            Format::R64G64B64Uint => CompatibilityClass::Bit192,
+            Format::R64G64B64Sint => CompatibilityClass::Bit192,
+            Format::R64G64B64Sfloat => CompatibilityClass::Bit192,
+            Format::R64G64B64A64Uint => CompatibilityClass::Bit256,
+            Format::R64G64B64A64Sint => CompatibilityClass::Bit256,
+            Format::R64G64B64A64Sfloat => CompatibilityClass::Bit256,
+            Format::B10G11R11UfloatPack32 => CompatibilityClass::Bit32,
+            Format::E5B9G9R9UfloatPack32 => CompatibilityClass::Bit32,
+            Format::D16Unorm => CompatibilityClass::D16,
+            Format::X8D24UnormPack32 => CompatibilityClass::D24,
+            Format::D32Sfloat => CompatibilityClass::D32,
+            Format::S8Uint => CompatibilityClass::S8,
+            Format::D16UnormS8Uint => CompatibilityClass::D16S8,
+            Format::D24UnormS8Uint => CompatibilityClass::D24S8,
+            Format::D32SfloatS8Uint => CompatibilityClass::D32S8,
+            Format::Bc1RgbUnormBlock => CompatibilityClass::Bc1Rgb,
+            Format::Bc1RgbSrgbBlock => CompatibilityClass::Bc1Rgb,
+            Format::Bc1RgbaUnormBlock => CompatibilityClass::Bc1Rgba,
+            Format::Bc1RgbaSrgbBlock => CompatibilityClass::Bc1Rgba,
+            Format::Bc2UnormBlock => CompatibilityClass::Bc2,
+            Format::Bc2SrgbBlock => CompatibilityClass::Bc2,
+            Format::Bc3UnormBlock => CompatibilityClass::Bc3,
+            Format::Bc3SrgbBlock => CompatibilityClass::Bc3,
+            Format::Bc4UnormBlock => CompatibilityClass::Bc4,
+            Format::Bc4SnormBlock => CompatibilityClass::Bc4,
+            Format::Bc5UnormBlock => CompatibilityClass::Bc5,
+            Format::Bc5SnormBlock => CompatibilityClass::Bc5,
+            Format::Bc6HUfloatBlock => CompatibilityClass::Bc6H,
+            Format::Bc6HSfloatBlock => CompatibilityClass::Bc6H,
+            Format::Bc7UnormBlock => CompatibilityClass::Bc7,
+            Format::Bc7SrgbBlock => CompatibilityClass::Bc7,
+            Format::Etc2R8G8B8UnormBlock => CompatibilityClass::Etc2Rgb,
+            Format::Etc2R8G8B8SrgbBlock => CompatibilityClass::Etc2Rgb,
+            Format::Etc2R8G8B8A1UnormBlock => CompatibilityClass::Etc2Rgba,
+            Format::Etc2R8G8B8A1SrgbBlock => CompatibilityClass::Etc2Rgba,
+            Format::Etc2R8G8B8A8UnormBlock => CompatibilityClass::Etc2EacRgba,
+            Format::Etc2R8G8B8A8SrgbBlock => CompatibilityClass::Etc2EacRgba,
+            Format::EacR11UnormBlock => CompatibilityClass::EacR,
+            Format::EacR11SnormBlock => CompatibilityClass::EacR,
+            Format::EacR11G11UnormBlock => CompatibilityClass::EacRg,
+            Format::EacR11G11SnormBlock => CompatibilityClass::EacRg,
+            Format::Astc4x4UnormBlock => CompatibilityClass::Astc4x4,
+            Format::Astc4x4SrgbBlock => CompatibilityClass::Astc4x4,
+            Format::Astc5x4UnormBlock => CompatibilityClass::Astc5x4,
+            Format::Astc5x4SrgbBlock => CompatibilityClass::Astc5x4,
+            Format::Astc5x5UnormBlock => CompatibilityClass::Astc5x5,
+            Format::Astc5x5SrgbBlock => CompatibilityClass::Astc5x5,
+            Format::Astc6x5UnormBlock => CompatibilityClass::Astc6x5,
+            Format::Astc6x5SrgbBlock => CompatibilityClass::Astc6x5,
+            Format::Astc6x6UnormBlock => CompatibilityClass::Astc6x6,
+            Format::Astc6x6SrgbBlock => CompatibilityClass::Astc6x6,
+            Format::Astc8x5UnormBlock => CompatibilityClass::Astc8x5,
+            Format::Astc8x5SrgbBlock => CompatibilityClass::Astc8x5,
+            Format::Astc8x6UnormBlock => CompatibilityClass::Astc8x6,
+            Format::Astc8x6SrgbBlock => CompatibilityClass::Astc8x6,
+            Format::Astc8x8UnormBlock => CompatibilityClass::Astc8x8,
+            Format::Astc8x8SrgbBlock => CompatibilityClass::Astc8x8,
+            Format::Astc10x5UnormBlock => CompatibilityClass::Astc10x5,
+            Format::Astc10x5SrgbBlock => CompatibilityClass::Astc10x5,
+            Format::Astc10x6UnormBlock => CompatibilityClass::Astc10x6,
+            Format::Astc10x6SrgbBlock => CompatibilityClass::Astc10x6,
+            Format::Astc10x8UnormBlock => CompatibilityClass::Astc10x8,
+            Format::Astc10x8SrgbBlock => CompatibilityClass::Astc10x8,
+            Format::Astc10x10UnormBlock => CompatibilityClass::Astc10x10,
+            Format::Astc10x10SrgbBlock => CompatibilityClass::Astc10x10,
+            Format::Astc12x10UnormBlock => CompatibilityClass::Astc12x10,
+            Format::Astc12x10SrgbBlock => CompatibilityClass::Astc12x10,
+            Format::Astc12x12UnormBlock => CompatibilityClass::Astc12x12,
+            Format::Astc12x12SrgbBlock => CompatibilityClass::Astc12x12,
+            Format::G8B8G8R8422Unorm => CompatibilityClass::Bit32G8B8G8R8,
+            Format::B8G8R8G8422Unorm => CompatibilityClass::Bit32B8G8R8G8,
+            Format::G8B8R83Plane420Unorm => CompatibilityClass::Bit8Plane3Chroma420,
+            Format::G8B8R82Plane420Unorm => CompatibilityClass::Bit8Plane2Chroma420,
+            Format::G8B8R83Plane422Unorm => CompatibilityClass::Bit8Plane3Chroma422,
+            Format::G8B8R82Plane422Unorm => CompatibilityClass::Bit8Plane2Chroma422,
+            Format::G8B8R83Plane444Unorm => CompatibilityClass::Bit8Plane3Chroma444,
+            Format::R10X6UnormPack16 => CompatibilityClass::Bit16,
+            Format::R10X6G10X6Unorm2Pack16 => CompatibilityClass::Bit32,
+            Format::R10X6G10X6B10X6A10X6Unorm4Pack16 => CompatibilityClass::Bit64R10G10B10A10,
+            Format::G10X6B10X6G10X6R10X6422Unorm4Pack16 => CompatibilityClass::Bit64G10B10G10R10,
+            Format::B10X6G10X6R10X6G10X6422Unorm4Pack16 => CompatibilityClass::Bit64B10G10R10G10,
+            Format::G10X6B10X6R10X63Plane420Unorm3Pack16 => {
+                CompatibilityClass::Bit10Plane3Chroma420
+            }
+            Format::G10X6B10X6R10X62Plane420Unorm3Pack16 => {
+                CompatibilityClass::Bit10Plane2Chroma420
+            }
+            Format::G10X6B10X6R10X63Plane422Unorm3Pack16 => {
+                CompatibilityClass::Bit10Plane3Chroma422
+            }
+            Format::G10X6B10X6R10X62Plane422Unorm3Pack16 => {
+                CompatibilityClass::Bit10Plane2Chroma422
+            }
+            Format::G10X6B10X6R10X63Plane444Unorm3Pack16 => {
+                CompatibilityClass::Bit10Plane3Chroma444
+            }
+            Format::R12X4UnormPack16 => CompatibilityClass::Bit16,
+            Format::R12X4G12X4Unorm2Pack16 => CompatibilityClass::Bit32,
+            Format::R12X4G12X4B12X4A12X4Unorm4Pack16 => CompatibilityClass::Bit64R12G12B12A12,
+            Format::G12X4B12X4G12X4R12X4422Unorm4Pack16 => CompatibilityClass::Bit64G12B12G12R12,
+            Format::B12X4G12X4R12X4G12X4422Unorm4Pack16 => CompatibilityClass::Bit64B12G12R12G12,
+            Format::G12X4B12X4R12X43Plane420Unorm3Pack16 => {
+                CompatibilityClass::Bit12Plane3Chroma420
+            }
+            Format::G12X4B12X4R12X42Plane420Unorm3Pack16 => {
+                CompatibilityClass::Bit12Plane2Chroma420
+            }
+            Format::G12X4B12X4R12X43Plane422Unorm3Pack16 => {
+                CompatibilityClass::Bit12Plane3Chroma422
+            }
+            Format::G12X4B12X4R12X42Plane422Unorm3Pack16 => {
+                CompatibilityClass::Bit12Plane2Chroma422
+            }
+            Format::G12X4B12X4R12X43Plane444Unorm3Pack16 => {
+                CompatibilityClass::Bit12Plane3Chroma444
+            }
+            Format::G16B16G16R16422Unorm => CompatibilityClass::Bit64G16B16G16R16,
+            Format::B16G16R16G16422Unorm => CompatibilityClass::Bit64B16G16R16G16,
+            Format::G16B16R163Plane420Unorm => CompatibilityClass::Bit16Plane3Chroma420,
+            Format::G16B16R162Plane420Unorm => CompatibilityClass::Bit16Plane2Chroma420,
+            Format::G16B16R163Plane422Unorm => CompatibilityClass::Bit16Plane3Chroma422,
+            Format::G16B16R162Plane422Unorm => CompatibilityClass::Bit16Plane2Chroma422,
+            Format::G16B16R163Plane444Unorm => CompatibilityClass::Bit16Plane3Chroma444,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppUnormBlockIMG => CompatibilityClass::Pvrtc12Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppUnormBlockIMG => CompatibilityClass::Pvrtc14Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppUnormBlockIMG => CompatibilityClass::Pvrtc22Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppUnormBlockIMG => CompatibilityClass::Pvrtc24Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppSrgbBlockIMG => CompatibilityClass::Pvrtc12Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppSrgbBlockIMG => CompatibilityClass::Pvrtc14Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppSrgbBlockIMG => CompatibilityClass::Pvrtc22Bpp,
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppSrgbBlockIMG => CompatibilityClass::Pvrtc24Bpp,
+            Format::Astc4x4SfloatBlock => CompatibilityClass::Astc4x4,
+            Format::Astc5x4SfloatBlock => CompatibilityClass::Astc5x4,
+            Format::Astc5x5SfloatBlock => CompatibilityClass::Astc5x5,
+            Format::Astc6x5SfloatBlock => CompatibilityClass::Astc6x5,
+            Format::Astc6x6SfloatBlock => CompatibilityClass::Astc6x6,
+            Format::Astc8x5SfloatBlock => CompatibilityClass::Astc8x5,
+            Format::Astc8x6SfloatBlock => CompatibilityClass::Astc8x6,
+            Format::Astc8x8SfloatBlock => CompatibilityClass::Astc8x8,
+            Format::Astc10x5SfloatBlock => CompatibilityClass::Astc10x5,
+            Format::Astc10x6SfloatBlock => CompatibilityClass::Astc10x6,
+            Format::Astc10x8SfloatBlock => CompatibilityClass::Astc10x8,
+            Format::Astc10x10SfloatBlock => CompatibilityClass::Astc10x10,
+            Format::Astc12x10SfloatBlock => CompatibilityClass::Astc12x10,
+            Format::Astc12x12SfloatBlock => CompatibilityClass::Astc12x12,
+            Format::G8B8R82Plane444Unorm => CompatibilityClass::Bit8Plane2Chroma444,
+            Format::G10X6B10X6R10X62Plane444Unorm3Pack16 => {
+                CompatibilityClass::Bit10Plane2Chroma444
+            }
+            Format::G12X4B12X4R12X42Plane444Unorm3Pack16 => {
+                CompatibilityClass::Bit12Plane2Chroma444
+            }
+            Format::G16B16R162Plane444Unorm => CompatibilityClass::Bit16Plane2Chroma444,
+            Format::A4R4G4B4UnormPack16 => CompatibilityClass::Bit16,
+            Format::A4B4G4R4UnormPack16 => CompatibilityClass::Bit16,
+            #[cfg(feature = "vendor-nv")]
+            Format::R16G16Sfixed5NV => CompatibilityClass::Bit32,
+        }
+    }
+    #[doc = r" Return true if both formats belong to the same compatibility class,"]
+    #[doc = r" in which case an image view with one of these formats can be created for an image with the other one"]
+    pub const fn is_compatible_with(self, other: Format) -> bool {
+        self.compatibility_class() as u32 == other.compatibility_class() as u32
+    }
+    #[doc = r" Return true if both formats have the same texel block size,"]
+    #[doc = r" in which case images with these formats can be copied to one another"]
+    pub const fn is_size_compatible_with(self, other: Format) -> bool {
+        self.block_size() == other.block_size()
+    }
+    #[doc = r" If this format is a UNORM format with an sRGB counterpart, return this sRGB format"]
+    pub const fn srgb_counterpart(self) -> Option<Format> {
+        match self {
+            Format::R8Unorm => Some(Format::R8Srgb),
+            Format::R8G8Unorm => Some(Format::R8G8Srgb),
+            Format::R8G8B8Unorm => Some(Format::R8G8B8Srgb),
+            Format::B8G8R8Unorm => Some(Format::B8G8R8Srgb),
+            Format::R8G8B8A8Unorm => Some(Format::R8G8B8A8Srgb),
+            Format::B8G8R8A8Unorm => Some(Format::B8G8R8A8Srgb),
+            Format::A8B8G8R8UnormPack32 => Some(Format::A8B8G8R8SrgbPack32),
+            Format::Bc1RgbUnormBlock => Some(Format::Bc1RgbSrgbBlock),
+            Format::Bc1RgbaUnormBlock => Some(Format::Bc1RgbaSrgbBlock),
+            Format::Bc2UnormBlock => Some(Format::Bc2SrgbBlock),
+            Format::Bc3UnormBlock => Some(Format::Bc3SrgbBlock),
+            Format::Bc7UnormBlock => Some(Format::Bc7SrgbBlock),
+            Format::Etc2R8G8B8UnormBlock => Some(Format::Etc2R8G8B8SrgbBlock),
+            Format::Etc2R8G8B8A1UnormBlock => Some(Format::Etc2R8G8B8A1SrgbBlock),
+            Format::Etc2R8G8B8A8UnormBlock => Some(Format::Etc2R8G8B8A8SrgbBlock),
+            Format::Astc4x4UnormBlock => Some(Format::Astc4x4SrgbBlock),
+            Format::Astc5x4UnormBlock => Some(Format::Astc5x4SrgbBlock),
+            Format::Astc5x5UnormBlock => Some(Format::Astc5x5SrgbBlock),
+            Format::Astc6x5UnormBlock => Some(Format::Astc6x5SrgbBlock),
+            Format::Astc6x6UnormBlock => Some(Format::Astc6x6SrgbBlock),
+            Format::Astc8x5UnormBlock => Some(Format::Astc8x5SrgbBlock),
+            Format::Astc8x6UnormBlock => Some(Format::Astc8x6SrgbBlock),
+            Format::Astc8x8UnormBlock => Some(Format::Astc8x8SrgbBlock),
+            Format::Astc10x5UnormBlock => Some(Format::Astc10x5SrgbBlock),
+            Format::Astc10x6UnormBlock => Some(Format::Astc10x6SrgbBlock),
+            Format::Astc10x8UnormBlock => Some(Format::Astc10x8SrgbBlock),
+            Format::Astc10x10UnormBlock => Some(Format::Astc10x10SrgbBlock),
+            Format::Astc12x10UnormBlock => Some(Format::Astc12x10SrgbBlock),
+            Format::Astc12x12UnormBlock => Some(Format::Astc12x12SrgbBlock),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppUnormBlockIMG => Some(Format::Pvrtc12BppSrgbBlockIMG),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppUnormBlockIMG => Some(Format::Pvrtc14BppSrgbBlockIMG),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppUnormBlockIMG => Some(Format::Pvrtc22BppSrgbBlockIMG),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppUnormBlockIMG => Some(Format::Pvrtc24BppSrgbBlockIMG),
+            _ => None,
+        }
+    }
+    #[doc = r" If this format is an sRGB format, return its UNORM counterpart"]
+    pub const fn linear_counterpart(self) -> Option<Format> {
+        match self {
+            Format::R8Srgb => Some(Format::R8Unorm),
+            Format::R8G8Srgb => Some(Format::R8G8Unorm),
+            Format::R8G8B8Srgb => Some(Format::R8G8B8Unorm),
+            Format::B8G8R8Srgb => Some(Format::B8G8R8Unorm),
+            Format::R8G8B8A8Srgb => Some(Format::R8G8B8A8Unorm),
+            Format::B8G8R8A8Srgb => Some(Format::B8G8R8A8Unorm),
+            Format::A8B8G8R8SrgbPack32 => Some(Format::A8B8G8R8UnormPack32),
+            Format::Bc1RgbSrgbBlock => Some(Format::Bc1RgbUnormBlock),
+            Format::Bc1RgbaSrgbBlock => Some(Format::Bc1RgbaUnormBlock),
+            Format::Bc2SrgbBlock => Some(Format::Bc2UnormBlock),
+            Format::Bc3SrgbBlock => Some(Format::Bc3UnormBlock),
+            Format::Bc7SrgbBlock => Some(Format::Bc7UnormBlock),
+            Format::Etc2R8G8B8SrgbBlock => Some(Format::Etc2R8G8B8UnormBlock),
+            Format::Etc2R8G8B8A1SrgbBlock => Some(Format::Etc2R8G8B8A1UnormBlock),
+            Format::Etc2R8G8B8A8SrgbBlock => Some(Format::Etc2R8G8B8A8UnormBlock),
+            Format::Astc4x4SrgbBlock => Some(Format::Astc4x4UnormBlock),
+            Format::Astc5x4SrgbBlock => Some(Format::Astc5x4UnormBlock),
+            Format::Astc5x5SrgbBlock => Some(Format::Astc5x5UnormBlock),
+            Format::Astc6x5SrgbBlock => Some(Format::Astc6x5UnormBlock),
+            Format::Astc6x6SrgbBlock => Some(Format::Astc6x6UnormBlock),
+            Format::Astc8x5SrgbBlock => Some(Format::Astc8x5UnormBlock),
+            Format::Astc8x6SrgbBlock => Some(Format::Astc8x6UnormBlock),
+            Format::Astc8x8SrgbBlock => Some(Format::Astc8x8UnormBlock),
+            Format::Astc10x5SrgbBlock => Some(Format::Astc10x5UnormBlock),
+            Format::Astc10x6SrgbBlock => Some(Format::Astc10x6UnormBlock),
+            Format::Astc10x8SrgbBlock => Some(Format::Astc10x8UnormBlock),
+            Format::Astc10x10SrgbBlock => Some(Format::Astc10x10UnormBlock),
+            Format::Astc12x10SrgbBlock => Some(Format::Astc12x10UnormBlock),
+            Format::Astc12x12SrgbBlock => Some(Format::Astc12x12UnormBlock),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc12BppSrgbBlockIMG => Some(Format::Pvrtc12BppUnormBlockIMG),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc14BppSrgbBlockIMG => Some(Format::Pvrtc14BppUnormBlockIMG),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc22BppSrgbBlockIMG => Some(Format::Pvrtc22BppUnormBlockIMG),
+            #[cfg(feature = "vendor-img")]
+            Format::Pvrtc24BppSrgbBlockIMG => Some(Format::Pvrtc24BppUnormBlockIMG),
+            _ => None,
+        }
+    }
+    #[doc = r" Return the location in memory of each component of a texel of this format."]
+    #[doc = r" Returns None for compressed, multi-planar, subsampled and combined depth/stencil formats,"]
+    #[doc = r" whose texels cannot be accessed independently"]
+    pub const fn texel_layout(self) -> Option<&'static [TexelComponent]> {
+        match self {
+            Format::R4G4UnormPack8 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R4G4B4A4UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 12u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B4G4R4A4UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 12u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R5G6B5UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 11u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 6u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 5u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B5G6R5UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 11u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 6u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 5u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R5G5B5A1UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 11u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 1u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 1u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B5G5R5A1UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 11u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 1u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 1u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A1R5G5B5UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 1u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 15u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 5u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A1B5G5R5UnormPack16KHR => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 1u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 15u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 5u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 5u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8UnormKHR => Some(&[TexelComponent {
+                name: ComponentName::A,
+                numeric_format: NumericFormat::Unorm,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Unorm => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Unorm,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Snorm => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Snorm,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Uscaled => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Uscaled,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Sscaled => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sscaled,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Uint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Uint,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Sint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sint,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8Srgb => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Srgb,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R8G8Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8Srgb => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8Srgb => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8Srgb => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R8G8B8A8Srgb => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B8G8R8A8Srgb => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 1u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 2u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 3u8,
+                    size: 1u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8UnormPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8SnormPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8UscaledPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8SscaledPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8UintPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8SintPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A8B8G8R8SrgbPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 24u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 16u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Srgb,
+                    bits: 8u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2R10G10B10UnormPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2R10G10B10SnormPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2R10G10B10UscaledPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2R10G10B10SscaledPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2R10G10B10UintPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2R10G10B10SintPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2B10G10R10UnormPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2B10G10R10SnormPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2B10G10R10UscaledPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2B10G10R10SscaledPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2B10G10R10UintPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A2B10G10R10SintPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 2u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 30u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 20u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 10u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16Unorm => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Unorm,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16Snorm => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Snorm,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16Uscaled => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Uscaled,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16Sscaled => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sscaled,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16Uint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Uint,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16Sint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sint,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16Sfloat => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sfloat,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::R16G16Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Unorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Snorm => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Snorm,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Uscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uscaled,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Sscaled => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sscaled,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R16G16B16A16Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 16u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32Uint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Uint,
+                bits: 32u8,
+                offset: 0u8,
+                size: 4u8,
+                shift: 0u8,
+            }]),
+            Format::R32Sint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sint,
+                bits: 32u8,
+                offset: 0u8,
+                size: 4u8,
+                shift: 0u8,
+            }]),
+            Format::R32Sfloat => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sfloat,
+                bits: 32u8,
+                offset: 0u8,
+                size: 4u8,
+                shift: 0u8,
+            }]),
+            Format::R32G32Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32B32Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 8u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32B32Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 8u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32B32Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 8u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32B32A32Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 8u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 32u8,
+                    offset: 12u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32B32A32Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 8u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 32u8,
+                    offset: 12u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R32G32B32A32Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 4u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 8u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 32u8,
+                    offset: 12u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64Uint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Uint,
+                bits: 64u8,
+                offset: 0u8,
+                size: 8u8,
+                shift: 0u8,
+            }]),
+            Format::R64Sint => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sint,
+                bits: 64u8,
+                offset: 0u8,
+                size: 8u8,
+                shift: 0u8,
+            }]),
+            Format::R64Sfloat => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Sfloat,
+                bits: 64u8,
+                offset: 0u8,
+                size: 8u8,
+                shift: 0u8,
+            }]),
+            Format::R64G64Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64B64Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 16u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64B64Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 16u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64B64Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 16u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64B64A64Uint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 16u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Uint,
+                    bits: 64u8,
+                    offset: 24u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64B64A64Sint => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 16u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sint,
+                    bits: 64u8,
+                    offset: 24u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::R64G64B64A64Sfloat => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 0u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 8u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 16u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Sfloat,
+                    bits: 64u8,
+                    offset: 24u8,
+                    size: 8u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::B10G11R11UfloatPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Ufloat,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 22u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Ufloat,
+                    bits: 11u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 11u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Ufloat,
+                    bits: 11u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::E5B9G9R9UfloatPack32 => Some(&[
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Ufloat,
+                    bits: 9u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 18u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Ufloat,
+                    bits: 9u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 9u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Ufloat,
+                    bits: 9u8,
+                    offset: 0u8,
+                    size: 4u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::D16Unorm => Some(&[TexelComponent {
+                name: ComponentName::D,
+                numeric_format: NumericFormat::Unorm,
+                bits: 16u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 0u8,
+            }]),
+            Format::X8D24UnormPack32 => Some(&[TexelComponent {
+                name: ComponentName::D,
+                numeric_format: NumericFormat::Unorm,
+                bits: 24u8,
+                offset: 0u8,
+                size: 4u8,
+                shift: 0u8,
+            }]),
+            Format::D32Sfloat => Some(&[TexelComponent {
+                name: ComponentName::D,
+                numeric_format: NumericFormat::Sfloat,
+                bits: 32u8,
+                offset: 0u8,
+                size: 4u8,
+                shift: 0u8,
+            }]),
+            Format::S8Uint => Some(&[TexelComponent {
+                name: ComponentName::S,
+                numeric_format: NumericFormat::Uint,
+                bits: 8u8,
+                offset: 0u8,
+                size: 1u8,
+                shift: 0u8,
+            }]),
+            Format::R10X6UnormPack16 => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Unorm,
+                bits: 10u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 6u8,
+            }]),
+            Format::R10X6G10X6Unorm2Pack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+            ]),
+            Format::R10X6G10X6B10X6A10X6Unorm4Pack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 10u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 6u8,
+                },
+            ]),
+            Format::R12X4UnormPack16 => Some(&[TexelComponent {
+                name: ComponentName::R,
+                numeric_format: NumericFormat::Unorm,
+                bits: 12u8,
+                offset: 0u8,
+                size: 2u8,
+                shift: 4u8,
+            }]),
+            Format::R12X4G12X4Unorm2Pack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 12u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 12u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+            ]),
+            Format::R12X4G12X4B12X4A12X4Unorm4Pack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 12u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 12u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 12u8,
+                    offset: 4u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 12u8,
+                    offset: 6u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+            ]),
+            Format::A4R4G4B4UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 12u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            Format::A4B4G4R4UnormPack16 => Some(&[
+                TexelComponent {
+                    name: ComponentName::A,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 12u8,
+                },
+                TexelComponent {
+                    name: ComponentName::B,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 8u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 4u8,
+                },
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Unorm,
+                    bits: 4u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            #[cfg(feature = "vendor-nv")]
+            Format::R16G16Sfixed5NV => Some(&[
+                TexelComponent {
+                    name: ComponentName::R,
+                    numeric_format: NumericFormat::Sfixed5,
+                    bits: 16u8,
+                    offset: 0u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+                TexelComponent {
+                    name: ComponentName::G,
+                    numeric_format: NumericFormat::Sfixed5,
+                    bits: 16u8,
+                    offset: 2u8,
+                    size: 2u8,
+                    shift: 0u8,
+                },
+            ]),
+            _ => None,
+        }
+    }
 }
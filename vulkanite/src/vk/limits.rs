@@ -0,0 +1,1410 @@
+use super::*;
+impl PhysicalDeviceLimits {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self.max_image_dimension1_d >= required.max_image_dimension1_d
+            && self.max_image_dimension2_d >= required.max_image_dimension2_d
+            && self.max_image_dimension3_d >= required.max_image_dimension3_d
+            && self.max_image_dimension_cube >= required.max_image_dimension_cube
+            && self.max_image_array_layers >= required.max_image_array_layers
+            && self.max_texel_buffer_elements >= required.max_texel_buffer_elements
+            && self.max_uniform_buffer_range >= required.max_uniform_buffer_range
+            && self.max_storage_buffer_range >= required.max_storage_buffer_range
+            && self.max_push_constants_size >= required.max_push_constants_size
+            && self.max_memory_allocation_count >= required.max_memory_allocation_count
+            && self.max_sampler_allocation_count >= required.max_sampler_allocation_count
+            && self.buffer_image_granularity <= required.buffer_image_granularity
+            && self.sparse_address_space_size >= required.sparse_address_space_size
+            && self.max_bound_descriptor_sets >= required.max_bound_descriptor_sets
+            && self.max_per_stage_descriptor_samplers >= required.max_per_stage_descriptor_samplers
+            && self.max_per_stage_descriptor_uniform_buffers
+                >= required.max_per_stage_descriptor_uniform_buffers
+            && self.max_per_stage_descriptor_storage_buffers
+                >= required.max_per_stage_descriptor_storage_buffers
+            && self.max_per_stage_descriptor_sampled_images
+                >= required.max_per_stage_descriptor_sampled_images
+            && self.max_per_stage_descriptor_storage_images
+                >= required.max_per_stage_descriptor_storage_images
+            && self.max_per_stage_descriptor_input_attachments
+                >= required.max_per_stage_descriptor_input_attachments
+            && self.max_per_stage_resources >= required.max_per_stage_resources
+            && self.max_descriptor_set_samplers >= required.max_descriptor_set_samplers
+            && self.max_descriptor_set_uniform_buffers
+                >= required.max_descriptor_set_uniform_buffers
+            && self.max_descriptor_set_uniform_buffers_dynamic
+                >= required.max_descriptor_set_uniform_buffers_dynamic
+            && self.max_descriptor_set_storage_buffers
+                >= required.max_descriptor_set_storage_buffers
+            && self.max_descriptor_set_storage_buffers_dynamic
+                >= required.max_descriptor_set_storage_buffers_dynamic
+            && self.max_descriptor_set_sampled_images >= required.max_descriptor_set_sampled_images
+            && self.max_descriptor_set_storage_images >= required.max_descriptor_set_storage_images
+            && self.max_descriptor_set_input_attachments
+                >= required.max_descriptor_set_input_attachments
+            && self.max_vertex_input_attributes >= required.max_vertex_input_attributes
+            && self.max_vertex_input_bindings >= required.max_vertex_input_bindings
+            && self.max_vertex_input_attribute_offset >= required.max_vertex_input_attribute_offset
+            && self.max_vertex_input_binding_stride >= required.max_vertex_input_binding_stride
+            && self.max_vertex_output_components >= required.max_vertex_output_components
+            && self.max_tessellation_generation_level >= required.max_tessellation_generation_level
+            && self.max_tessellation_patch_size >= required.max_tessellation_patch_size
+            && self.max_tessellation_control_per_vertex_input_components
+                >= required.max_tessellation_control_per_vertex_input_components
+            && self.max_tessellation_control_per_vertex_output_components
+                >= required.max_tessellation_control_per_vertex_output_components
+            && self.max_tessellation_control_per_patch_output_components
+                >= required.max_tessellation_control_per_patch_output_components
+            && self.max_tessellation_control_total_output_components
+                >= required.max_tessellation_control_total_output_components
+            && self.max_tessellation_evaluation_input_components
+                >= required.max_tessellation_evaluation_input_components
+            && self.max_tessellation_evaluation_output_components
+                >= required.max_tessellation_evaluation_output_components
+            && self.max_geometry_shader_invocations >= required.max_geometry_shader_invocations
+            && self.max_geometry_input_components >= required.max_geometry_input_components
+            && self.max_geometry_output_components >= required.max_geometry_output_components
+            && self.max_geometry_output_vertices >= required.max_geometry_output_vertices
+            && self.max_geometry_total_output_components
+                >= required.max_geometry_total_output_components
+            && self.max_fragment_input_components >= required.max_fragment_input_components
+            && self.max_fragment_output_attachments >= required.max_fragment_output_attachments
+            && self.max_fragment_dual_src_attachments >= required.max_fragment_dual_src_attachments
+            && self.max_fragment_combined_output_resources
+                >= required.max_fragment_combined_output_resources
+            && self.max_compute_shared_memory_size >= required.max_compute_shared_memory_size
+            && self
+                .max_compute_work_group_count
+                .iter()
+                .zip(&required.max_compute_work_group_count)
+                .all(|(actual, required)| *actual >= *required)
+            && self.max_compute_work_group_invocations
+                >= required.max_compute_work_group_invocations
+            && self
+                .max_compute_work_group_size
+                .iter()
+                .zip(&required.max_compute_work_group_size)
+                .all(|(actual, required)| *actual >= *required)
+            && self.sub_pixel_precision_bits >= required.sub_pixel_precision_bits
+            && self.sub_texel_precision_bits >= required.sub_texel_precision_bits
+            && self.mipmap_precision_bits >= required.mipmap_precision_bits
+            && self.max_draw_indexed_index_value >= required.max_draw_indexed_index_value
+            && self.max_draw_indirect_count >= required.max_draw_indirect_count
+            && self.max_sampler_lod_bias >= required.max_sampler_lod_bias
+            && self.max_sampler_anisotropy >= required.max_sampler_anisotropy
+            && self.max_viewports >= required.max_viewports
+            && self
+                .max_viewport_dimensions
+                .iter()
+                .zip(&required.max_viewport_dimensions)
+                .all(|(actual, required)| *actual >= *required)
+            && self.viewport_bounds_range[0] <= required.viewport_bounds_range[0]
+            && self.viewport_bounds_range[1] >= required.viewport_bounds_range[1]
+            && self.viewport_sub_pixel_bits >= required.viewport_sub_pixel_bits
+            && self.min_memory_map_alignment <= required.min_memory_map_alignment
+            && self.min_texel_buffer_offset_alignment <= required.min_texel_buffer_offset_alignment
+            && self.min_uniform_buffer_offset_alignment
+                <= required.min_uniform_buffer_offset_alignment
+            && self.min_storage_buffer_offset_alignment
+                <= required.min_storage_buffer_offset_alignment
+            && self.min_texel_offset <= required.min_texel_offset
+            && self.max_texel_offset >= required.max_texel_offset
+            && self.min_texel_gather_offset <= required.min_texel_gather_offset
+            && self.max_texel_gather_offset >= required.max_texel_gather_offset
+            && self.min_interpolation_offset <= required.min_interpolation_offset
+            && self.max_interpolation_offset >= required.max_interpolation_offset
+            && self.sub_pixel_interpolation_offset_bits
+                >= required.sub_pixel_interpolation_offset_bits
+            && self.max_framebuffer_width >= required.max_framebuffer_width
+            && self.max_framebuffer_height >= required.max_framebuffer_height
+            && self.max_framebuffer_layers >= required.max_framebuffer_layers
+            && self.framebuffer_color_sample_counts & required.framebuffer_color_sample_counts
+                == required.framebuffer_color_sample_counts
+            && self.framebuffer_depth_sample_counts & required.framebuffer_depth_sample_counts
+                == required.framebuffer_depth_sample_counts
+            && self.framebuffer_stencil_sample_counts & required.framebuffer_stencil_sample_counts
+                == required.framebuffer_stencil_sample_counts
+            && self.framebuffer_no_attachments_sample_counts
+                & required.framebuffer_no_attachments_sample_counts
+                == required.framebuffer_no_attachments_sample_counts
+            && self.max_color_attachments >= required.max_color_attachments
+            && self.sampled_image_color_sample_counts & required.sampled_image_color_sample_counts
+                == required.sampled_image_color_sample_counts
+            && self.sampled_image_integer_sample_counts
+                & required.sampled_image_integer_sample_counts
+                == required.sampled_image_integer_sample_counts
+            && self.sampled_image_depth_sample_counts & required.sampled_image_depth_sample_counts
+                == required.sampled_image_depth_sample_counts
+            && self.sampled_image_stencil_sample_counts
+                & required.sampled_image_stencil_sample_counts
+                == required.sampled_image_stencil_sample_counts
+            && self.storage_image_sample_counts & required.storage_image_sample_counts
+                == required.storage_image_sample_counts
+            && self.max_sample_mask_words >= required.max_sample_mask_words
+            && (self.timestamp_compute_and_graphics as u32)
+                >= (required.timestamp_compute_and_graphics as u32)
+            && self.max_clip_distances >= required.max_clip_distances
+            && self.max_cull_distances >= required.max_cull_distances
+            && self.max_combined_clip_and_cull_distances
+                >= required.max_combined_clip_and_cull_distances
+            && self.discrete_queue_priorities >= required.discrete_queue_priorities
+            && self.point_size_range[0] <= required.point_size_range[0]
+            && self.point_size_range[1] >= required.point_size_range[1]
+            && self.line_width_range[0] <= required.line_width_range[0]
+            && self.line_width_range[1] >= required.line_width_range[1]
+            && self.point_size_granularity <= required.point_size_granularity
+            && self.line_width_granularity <= required.line_width_granularity
+            && (self.strict_lines as u32) >= (required.strict_lines as u32)
+            && (self.standard_sample_locations as u32)
+                >= (required.standard_sample_locations as u32)
+            && self.optimal_buffer_copy_offset_alignment
+                <= required.optimal_buffer_copy_offset_alignment
+            && self.optimal_buffer_copy_row_pitch_alignment
+                <= required.optimal_buffer_copy_row_pitch_alignment
+            && self.non_coherent_atom_size <= required.non_coherent_atom_size
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        if other.max_image_dimension1_d < self.max_image_dimension1_d {
+            self.max_image_dimension1_d = other.max_image_dimension1_d;
+        }
+        if other.max_image_dimension2_d < self.max_image_dimension2_d {
+            self.max_image_dimension2_d = other.max_image_dimension2_d;
+        }
+        if other.max_image_dimension3_d < self.max_image_dimension3_d {
+            self.max_image_dimension3_d = other.max_image_dimension3_d;
+        }
+        if other.max_image_dimension_cube < self.max_image_dimension_cube {
+            self.max_image_dimension_cube = other.max_image_dimension_cube;
+        }
+        if other.max_image_array_layers < self.max_image_array_layers {
+            self.max_image_array_layers = other.max_image_array_layers;
+        }
+        if other.max_texel_buffer_elements < self.max_texel_buffer_elements {
+            self.max_texel_buffer_elements = other.max_texel_buffer_elements;
+        }
+        if other.max_uniform_buffer_range < self.max_uniform_buffer_range {
+            self.max_uniform_buffer_range = other.max_uniform_buffer_range;
+        }
+        if other.max_storage_buffer_range < self.max_storage_buffer_range {
+            self.max_storage_buffer_range = other.max_storage_buffer_range;
+        }
+        if other.max_push_constants_size < self.max_push_constants_size {
+            self.max_push_constants_size = other.max_push_constants_size;
+        }
+        if other.max_memory_allocation_count < self.max_memory_allocation_count {
+            self.max_memory_allocation_count = other.max_memory_allocation_count;
+        }
+        if other.max_sampler_allocation_count < self.max_sampler_allocation_count {
+            self.max_sampler_allocation_count = other.max_sampler_allocation_count;
+        }
+        if other.buffer_image_granularity > self.buffer_image_granularity {
+            self.buffer_image_granularity = other.buffer_image_granularity;
+        }
+        if other.sparse_address_space_size < self.sparse_address_space_size {
+            self.sparse_address_space_size = other.sparse_address_space_size;
+        }
+        if other.max_bound_descriptor_sets < self.max_bound_descriptor_sets {
+            self.max_bound_descriptor_sets = other.max_bound_descriptor_sets;
+        }
+        if other.max_per_stage_descriptor_samplers < self.max_per_stage_descriptor_samplers {
+            self.max_per_stage_descriptor_samplers = other.max_per_stage_descriptor_samplers;
+        }
+        if other.max_per_stage_descriptor_uniform_buffers
+            < self.max_per_stage_descriptor_uniform_buffers
+        {
+            self.max_per_stage_descriptor_uniform_buffers =
+                other.max_per_stage_descriptor_uniform_buffers;
+        }
+        if other.max_per_stage_descriptor_storage_buffers
+            < self.max_per_stage_descriptor_storage_buffers
+        {
+            self.max_per_stage_descriptor_storage_buffers =
+                other.max_per_stage_descriptor_storage_buffers;
+        }
+        if other.max_per_stage_descriptor_sampled_images
+            < self.max_per_stage_descriptor_sampled_images
+        {
+            self.max_per_stage_descriptor_sampled_images =
+                other.max_per_stage_descriptor_sampled_images;
+        }
+        if other.max_per_stage_descriptor_storage_images
+            < self.max_per_stage_descriptor_storage_images
+        {
+            self.max_per_stage_descriptor_storage_images =
+                other.max_per_stage_descriptor_storage_images;
+        }
+        if other.max_per_stage_descriptor_input_attachments
+            < self.max_per_stage_descriptor_input_attachments
+        {
+            self.max_per_stage_descriptor_input_attachments =
+                other.max_per_stage_descriptor_input_attachments;
+        }
+        if other.max_per_stage_resources < self.max_per_stage_resources {
+            self.max_per_stage_resources = other.max_per_stage_resources;
+        }
+        if other.max_descriptor_set_samplers < self.max_descriptor_set_samplers {
+            self.max_descriptor_set_samplers = other.max_descriptor_set_samplers;
+        }
+        if other.max_descriptor_set_uniform_buffers < self.max_descriptor_set_uniform_buffers {
+            self.max_descriptor_set_uniform_buffers = other.max_descriptor_set_uniform_buffers;
+        }
+        if other.max_descriptor_set_uniform_buffers_dynamic
+            < self.max_descriptor_set_uniform_buffers_dynamic
+        {
+            self.max_descriptor_set_uniform_buffers_dynamic =
+                other.max_descriptor_set_uniform_buffers_dynamic;
+        }
+        if other.max_descriptor_set_storage_buffers < self.max_descriptor_set_storage_buffers {
+            self.max_descriptor_set_storage_buffers = other.max_descriptor_set_storage_buffers;
+        }
+        if other.max_descriptor_set_storage_buffers_dynamic
+            < self.max_descriptor_set_storage_buffers_dynamic
+        {
+            self.max_descriptor_set_storage_buffers_dynamic =
+                other.max_descriptor_set_storage_buffers_dynamic;
+        }
+        if other.max_descriptor_set_sampled_images < self.max_descriptor_set_sampled_images {
+            self.max_descriptor_set_sampled_images = other.max_descriptor_set_sampled_images;
+        }
+        if other.max_descriptor_set_storage_images < self.max_descriptor_set_storage_images {
+            self.max_descriptor_set_storage_images = other.max_descriptor_set_storage_images;
+        }
+        if other.max_descriptor_set_input_attachments < self.max_descriptor_set_input_attachments {
+            self.max_descriptor_set_input_attachments = other.max_descriptor_set_input_attachments;
+        }
+        if other.max_vertex_input_attributes < self.max_vertex_input_attributes {
+            self.max_vertex_input_attributes = other.max_vertex_input_attributes;
+        }
+        if other.max_vertex_input_bindings < self.max_vertex_input_bindings {
+            self.max_vertex_input_bindings = other.max_vertex_input_bindings;
+        }
+        if other.max_vertex_input_attribute_offset < self.max_vertex_input_attribute_offset {
+            self.max_vertex_input_attribute_offset = other.max_vertex_input_attribute_offset;
+        }
+        if other.max_vertex_input_binding_stride < self.max_vertex_input_binding_stride {
+            self.max_vertex_input_binding_stride = other.max_vertex_input_binding_stride;
+        }
+        if other.max_vertex_output_components < self.max_vertex_output_components {
+            self.max_vertex_output_components = other.max_vertex_output_components;
+        }
+        if other.max_tessellation_generation_level < self.max_tessellation_generation_level {
+            self.max_tessellation_generation_level = other.max_tessellation_generation_level;
+        }
+        if other.max_tessellation_patch_size < self.max_tessellation_patch_size {
+            self.max_tessellation_patch_size = other.max_tessellation_patch_size;
+        }
+        if other.max_tessellation_control_per_vertex_input_components
+            < self.max_tessellation_control_per_vertex_input_components
+        {
+            self.max_tessellation_control_per_vertex_input_components =
+                other.max_tessellation_control_per_vertex_input_components;
+        }
+        if other.max_tessellation_control_per_vertex_output_components
+            < self.max_tessellation_control_per_vertex_output_components
+        {
+            self.max_tessellation_control_per_vertex_output_components =
+                other.max_tessellation_control_per_vertex_output_components;
+        }
+        if other.max_tessellation_control_per_patch_output_components
+            < self.max_tessellation_control_per_patch_output_components
+        {
+            self.max_tessellation_control_per_patch_output_components =
+                other.max_tessellation_control_per_patch_output_components;
+        }
+        if other.max_tessellation_control_total_output_components
+            < self.max_tessellation_control_total_output_components
+        {
+            self.max_tessellation_control_total_output_components =
+                other.max_tessellation_control_total_output_components;
+        }
+        if other.max_tessellation_evaluation_input_components
+            < self.max_tessellation_evaluation_input_components
+        {
+            self.max_tessellation_evaluation_input_components =
+                other.max_tessellation_evaluation_input_components;
+        }
+        if other.max_tessellation_evaluation_output_components
+            < self.max_tessellation_evaluation_output_components
+        {
+            self.max_tessellation_evaluation_output_components =
+                other.max_tessellation_evaluation_output_components;
+        }
+        if other.max_geometry_shader_invocations < self.max_geometry_shader_invocations {
+            self.max_geometry_shader_invocations = other.max_geometry_shader_invocations;
+        }
+        if other.max_geometry_input_components < self.max_geometry_input_components {
+            self.max_geometry_input_components = other.max_geometry_input_components;
+        }
+        if other.max_geometry_output_components < self.max_geometry_output_components {
+            self.max_geometry_output_components = other.max_geometry_output_components;
+        }
+        if other.max_geometry_output_vertices < self.max_geometry_output_vertices {
+            self.max_geometry_output_vertices = other.max_geometry_output_vertices;
+        }
+        if other.max_geometry_total_output_components < self.max_geometry_total_output_components {
+            self.max_geometry_total_output_components = other.max_geometry_total_output_components;
+        }
+        if other.max_fragment_input_components < self.max_fragment_input_components {
+            self.max_fragment_input_components = other.max_fragment_input_components;
+        }
+        if other.max_fragment_output_attachments < self.max_fragment_output_attachments {
+            self.max_fragment_output_attachments = other.max_fragment_output_attachments;
+        }
+        if other.max_fragment_dual_src_attachments < self.max_fragment_dual_src_attachments {
+            self.max_fragment_dual_src_attachments = other.max_fragment_dual_src_attachments;
+        }
+        if other.max_fragment_combined_output_resources
+            < self.max_fragment_combined_output_resources
+        {
+            self.max_fragment_combined_output_resources =
+                other.max_fragment_combined_output_resources;
+        }
+        if other.max_compute_shared_memory_size < self.max_compute_shared_memory_size {
+            self.max_compute_shared_memory_size = other.max_compute_shared_memory_size;
+        }
+        for (actual, other) in self
+            .max_compute_work_group_count
+            .iter_mut()
+            .zip(&other.max_compute_work_group_count)
+        {
+            if *other < *actual {
+                *actual = *other;
+            }
+        }
+        if other.max_compute_work_group_invocations < self.max_compute_work_group_invocations {
+            self.max_compute_work_group_invocations = other.max_compute_work_group_invocations;
+        }
+        for (actual, other) in self
+            .max_compute_work_group_size
+            .iter_mut()
+            .zip(&other.max_compute_work_group_size)
+        {
+            if *other < *actual {
+                *actual = *other;
+            }
+        }
+        if other.sub_pixel_precision_bits < self.sub_pixel_precision_bits {
+            self.sub_pixel_precision_bits = other.sub_pixel_precision_bits;
+        }
+        if other.sub_texel_precision_bits < self.sub_texel_precision_bits {
+            self.sub_texel_precision_bits = other.sub_texel_precision_bits;
+        }
+        if other.mipmap_precision_bits < self.mipmap_precision_bits {
+            self.mipmap_precision_bits = other.mipmap_precision_bits;
+        }
+        if other.max_draw_indexed_index_value < self.max_draw_indexed_index_value {
+            self.max_draw_indexed_index_value = other.max_draw_indexed_index_value;
+        }
+        if other.max_draw_indirect_count < self.max_draw_indirect_count {
+            self.max_draw_indirect_count = other.max_draw_indirect_count;
+        }
+        if other.max_sampler_lod_bias < self.max_sampler_lod_bias {
+            self.max_sampler_lod_bias = other.max_sampler_lod_bias;
+        }
+        if other.max_sampler_anisotropy < self.max_sampler_anisotropy {
+            self.max_sampler_anisotropy = other.max_sampler_anisotropy;
+        }
+        if other.max_viewports < self.max_viewports {
+            self.max_viewports = other.max_viewports;
+        }
+        for (actual, other) in self
+            .max_viewport_dimensions
+            .iter_mut()
+            .zip(&other.max_viewport_dimensions)
+        {
+            if *other < *actual {
+                *actual = *other;
+            }
+        }
+        if other.viewport_bounds_range[0] > self.viewport_bounds_range[0] {
+            self.viewport_bounds_range[0] = other.viewport_bounds_range[0];
+        }
+        if other.viewport_bounds_range[1] < self.viewport_bounds_range[1] {
+            self.viewport_bounds_range[1] = other.viewport_bounds_range[1];
+        }
+        if other.viewport_sub_pixel_bits < self.viewport_sub_pixel_bits {
+            self.viewport_sub_pixel_bits = other.viewport_sub_pixel_bits;
+        }
+        if other.min_memory_map_alignment > self.min_memory_map_alignment {
+            self.min_memory_map_alignment = other.min_memory_map_alignment;
+        }
+        if other.min_texel_buffer_offset_alignment > self.min_texel_buffer_offset_alignment {
+            self.min_texel_buffer_offset_alignment = other.min_texel_buffer_offset_alignment;
+        }
+        if other.min_uniform_buffer_offset_alignment > self.min_uniform_buffer_offset_alignment {
+            self.min_uniform_buffer_offset_alignment = other.min_uniform_buffer_offset_alignment;
+        }
+        if other.min_storage_buffer_offset_alignment > self.min_storage_buffer_offset_alignment {
+            self.min_storage_buffer_offset_alignment = other.min_storage_buffer_offset_alignment;
+        }
+        if other.min_texel_offset > self.min_texel_offset {
+            self.min_texel_offset = other.min_texel_offset;
+        }
+        if other.max_texel_offset < self.max_texel_offset {
+            self.max_texel_offset = other.max_texel_offset;
+        }
+        if other.min_texel_gather_offset > self.min_texel_gather_offset {
+            self.min_texel_gather_offset = other.min_texel_gather_offset;
+        }
+        if other.max_texel_gather_offset < self.max_texel_gather_offset {
+            self.max_texel_gather_offset = other.max_texel_gather_offset;
+        }
+        if other.min_interpolation_offset > self.min_interpolation_offset {
+            self.min_interpolation_offset = other.min_interpolation_offset;
+        }
+        if other.max_interpolation_offset < self.max_interpolation_offset {
+            self.max_interpolation_offset = other.max_interpolation_offset;
+        }
+        if other.sub_pixel_interpolation_offset_bits < self.sub_pixel_interpolation_offset_bits {
+            self.sub_pixel_interpolation_offset_bits = other.sub_pixel_interpolation_offset_bits;
+        }
+        if other.max_framebuffer_width < self.max_framebuffer_width {
+            self.max_framebuffer_width = other.max_framebuffer_width;
+        }
+        if other.max_framebuffer_height < self.max_framebuffer_height {
+            self.max_framebuffer_height = other.max_framebuffer_height;
+        }
+        if other.max_framebuffer_layers < self.max_framebuffer_layers {
+            self.max_framebuffer_layers = other.max_framebuffer_layers;
+        }
+        self.framebuffer_color_sample_counts &= other.framebuffer_color_sample_counts;
+        self.framebuffer_depth_sample_counts &= other.framebuffer_depth_sample_counts;
+        self.framebuffer_stencil_sample_counts &= other.framebuffer_stencil_sample_counts;
+        self.framebuffer_no_attachments_sample_counts &=
+            other.framebuffer_no_attachments_sample_counts;
+        if other.max_color_attachments < self.max_color_attachments {
+            self.max_color_attachments = other.max_color_attachments;
+        }
+        self.sampled_image_color_sample_counts &= other.sampled_image_color_sample_counts;
+        self.sampled_image_integer_sample_counts &= other.sampled_image_integer_sample_counts;
+        self.sampled_image_depth_sample_counts &= other.sampled_image_depth_sample_counts;
+        self.sampled_image_stencil_sample_counts &= other.sampled_image_stencil_sample_counts;
+        self.storage_image_sample_counts &= other.storage_image_sample_counts;
+        if other.max_sample_mask_words < self.max_sample_mask_words {
+            self.max_sample_mask_words = other.max_sample_mask_words;
+        }
+        if (other.timestamp_compute_and_graphics as u32)
+            < (self.timestamp_compute_and_graphics as u32)
+        {
+            self.timestamp_compute_and_graphics = other.timestamp_compute_and_graphics;
+        }
+        if other.max_clip_distances < self.max_clip_distances {
+            self.max_clip_distances = other.max_clip_distances;
+        }
+        if other.max_cull_distances < self.max_cull_distances {
+            self.max_cull_distances = other.max_cull_distances;
+        }
+        if other.max_combined_clip_and_cull_distances < self.max_combined_clip_and_cull_distances {
+            self.max_combined_clip_and_cull_distances = other.max_combined_clip_and_cull_distances;
+        }
+        if other.discrete_queue_priorities < self.discrete_queue_priorities {
+            self.discrete_queue_priorities = other.discrete_queue_priorities;
+        }
+        if other.point_size_range[0] > self.point_size_range[0] {
+            self.point_size_range[0] = other.point_size_range[0];
+        }
+        if other.point_size_range[1] < self.point_size_range[1] {
+            self.point_size_range[1] = other.point_size_range[1];
+        }
+        if other.line_width_range[0] > self.line_width_range[0] {
+            self.line_width_range[0] = other.line_width_range[0];
+        }
+        if other.line_width_range[1] < self.line_width_range[1] {
+            self.line_width_range[1] = other.line_width_range[1];
+        }
+        if other.point_size_granularity > self.point_size_granularity {
+            self.point_size_granularity = other.point_size_granularity;
+        }
+        if other.line_width_granularity > self.line_width_granularity {
+            self.line_width_granularity = other.line_width_granularity;
+        }
+        if (other.strict_lines as u32) < (self.strict_lines as u32) {
+            self.strict_lines = other.strict_lines;
+        }
+        if (other.standard_sample_locations as u32) < (self.standard_sample_locations as u32) {
+            self.standard_sample_locations = other.standard_sample_locations;
+        }
+        if other.optimal_buffer_copy_offset_alignment > self.optimal_buffer_copy_offset_alignment {
+            self.optimal_buffer_copy_offset_alignment = other.optimal_buffer_copy_offset_alignment;
+        }
+        if other.optimal_buffer_copy_row_pitch_alignment
+            > self.optimal_buffer_copy_row_pitch_alignment
+        {
+            self.optimal_buffer_copy_row_pitch_alignment =
+                other.optimal_buffer_copy_row_pitch_alignment;
+        }
+        if other.non_coherent_atom_size > self.non_coherent_atom_size {
+            self.non_coherent_atom_size = other.non_coherent_atom_size;
+        }
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default()
+            .buffer_image_granularity(DeviceSize::MAX)
+            .viewport_bounds_range([f32::MAX, f32::MIN])
+            .min_memory_map_alignment(usize::MAX)
+            .min_texel_buffer_offset_alignment(DeviceSize::MAX)
+            .min_uniform_buffer_offset_alignment(DeviceSize::MAX)
+            .min_storage_buffer_offset_alignment(DeviceSize::MAX)
+            .min_texel_offset(i32::MAX)
+            .min_texel_gather_offset(i32::MAX)
+            .min_interpolation_offset(f32::MAX)
+            .point_size_range([f32::MAX, f32::MIN])
+            .line_width_range([f32::MAX, f32::MIN])
+            .point_size_granularity(f32::MAX)
+            .line_width_granularity(f32::MAX)
+            .optimal_buffer_copy_offset_alignment(DeviceSize::MAX)
+            .optimal_buffer_copy_row_pitch_alignment(DeviceSize::MAX)
+            .non_coherent_atom_size(DeviceSize::MAX)
+    }
+}
+impl PhysicalDeviceSparseProperties {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        (self.residency_standard2_dblock_shape as u32)
+            >= (required.residency_standard2_dblock_shape as u32)
+            && (self.residency_standard2_dmultisample_block_shape as u32)
+                >= (required.residency_standard2_dmultisample_block_shape as u32)
+            && (self.residency_standard3_dblock_shape as u32)
+                >= (required.residency_standard3_dblock_shape as u32)
+            && (self.residency_aligned_mip_size as u32)
+                >= (required.residency_aligned_mip_size as u32)
+            && (self.residency_non_resident_strict as u32)
+                >= (required.residency_non_resident_strict as u32)
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        if (other.residency_standard2_dblock_shape as u32)
+            < (self.residency_standard2_dblock_shape as u32)
+        {
+            self.residency_standard2_dblock_shape = other.residency_standard2_dblock_shape;
+        }
+        if (other.residency_standard2_dmultisample_block_shape as u32)
+            < (self.residency_standard2_dmultisample_block_shape as u32)
+        {
+            self.residency_standard2_dmultisample_block_shape =
+                other.residency_standard2_dmultisample_block_shape;
+        }
+        if (other.residency_standard3_dblock_shape as u32)
+            < (self.residency_standard3_dblock_shape as u32)
+        {
+            self.residency_standard3_dblock_shape = other.residency_standard3_dblock_shape;
+        }
+        if (other.residency_aligned_mip_size as u32) < (self.residency_aligned_mip_size as u32) {
+            self.residency_aligned_mip_size = other.residency_aligned_mip_size;
+        }
+        if (other.residency_non_resident_strict as u32)
+            < (self.residency_non_resident_strict as u32)
+        {
+            self.residency_non_resident_strict = other.residency_non_resident_strict;
+        }
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default()
+    }
+}
+impl PhysicalDeviceProperties {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self.limits.satisfies_limits(&required.limits)
+            && self
+                .sparse_properties
+                .satisfies_limits(&required.sparse_properties)
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        self.limits.merge_limits(&other.limits);
+        self.sparse_properties
+            .merge_limits(&other.sparse_properties);
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default().limits(PhysicalDeviceLimits::least_restrictive())
+    }
+}
+impl PhysicalDeviceProperties2<'_> {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self.properties.satisfies_limits(&required.properties)
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        self.properties.merge_limits(&other.properties);
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default().properties(PhysicalDeviceProperties::least_restrictive())
+    }
+}
+impl PhysicalDeviceSubgroupProperties<'_> {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self.subgroup_size >= required.subgroup_size
+            && self.supported_stages & required.supported_stages == required.supported_stages
+            && self.supported_operations & required.supported_operations
+                == required.supported_operations
+            && (self.quad_operations_in_all_stages as u32)
+                >= (required.quad_operations_in_all_stages as u32)
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        if other.subgroup_size < self.subgroup_size {
+            self.subgroup_size = other.subgroup_size;
+        }
+        self.supported_stages &= other.supported_stages;
+        self.supported_operations &= other.supported_operations;
+        if (other.quad_operations_in_all_stages as u32)
+            < (self.quad_operations_in_all_stages as u32)
+        {
+            self.quad_operations_in_all_stages = other.quad_operations_in_all_stages;
+        }
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default()
+    }
+}
+impl PhysicalDeviceVulkan11Properties<'_> {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self.subgroup_size >= required.subgroup_size
+            && self.subgroup_supported_stages & required.subgroup_supported_stages
+                == required.subgroup_supported_stages
+            && self.subgroup_supported_operations & required.subgroup_supported_operations
+                == required.subgroup_supported_operations
+            && (self.subgroup_quad_operations_in_all_stages as u32)
+                >= (required.subgroup_quad_operations_in_all_stages as u32)
+            && self.point_clipping_behavior == required.point_clipping_behavior
+            && self.max_multiview_view_count >= required.max_multiview_view_count
+            && self.max_multiview_instance_index >= required.max_multiview_instance_index
+            && (self.protected_no_fault as u32) >= (required.protected_no_fault as u32)
+            && self.max_per_set_descriptors >= required.max_per_set_descriptors
+            && self.max_memory_allocation_size >= required.max_memory_allocation_size
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        if other.subgroup_size < self.subgroup_size {
+            self.subgroup_size = other.subgroup_size;
+        }
+        self.subgroup_supported_stages &= other.subgroup_supported_stages;
+        self.subgroup_supported_operations &= other.subgroup_supported_operations;
+        if (other.subgroup_quad_operations_in_all_stages as u32)
+            < (self.subgroup_quad_operations_in_all_stages as u32)
+        {
+            self.subgroup_quad_operations_in_all_stages =
+                other.subgroup_quad_operations_in_all_stages;
+        }
+        if other.max_multiview_view_count < self.max_multiview_view_count {
+            self.max_multiview_view_count = other.max_multiview_view_count;
+        }
+        if other.max_multiview_instance_index < self.max_multiview_instance_index {
+            self.max_multiview_instance_index = other.max_multiview_instance_index;
+        }
+        if (other.protected_no_fault as u32) < (self.protected_no_fault as u32) {
+            self.protected_no_fault = other.protected_no_fault;
+        }
+        if other.max_per_set_descriptors < self.max_per_set_descriptors {
+            self.max_per_set_descriptors = other.max_per_set_descriptors;
+        }
+        if other.max_memory_allocation_size < self.max_memory_allocation_size {
+            self.max_memory_allocation_size = other.max_memory_allocation_size;
+        }
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default()
+    }
+}
+impl PhysicalDeviceVulkan12Properties<'_> {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self.denorm_behavior_independence == required.denorm_behavior_independence
+            && self.rounding_mode_independence == required.rounding_mode_independence
+            && (self.shader_signed_zero_inf_nan_preserve_float16 as u32)
+                >= (required.shader_signed_zero_inf_nan_preserve_float16 as u32)
+            && (self.shader_signed_zero_inf_nan_preserve_float32 as u32)
+                >= (required.shader_signed_zero_inf_nan_preserve_float32 as u32)
+            && (self.shader_signed_zero_inf_nan_preserve_float64 as u32)
+                >= (required.shader_signed_zero_inf_nan_preserve_float64 as u32)
+            && (self.shader_denorm_preserve_float16 as u32)
+                >= (required.shader_denorm_preserve_float16 as u32)
+            && (self.shader_denorm_preserve_float32 as u32)
+                >= (required.shader_denorm_preserve_float32 as u32)
+            && (self.shader_denorm_preserve_float64 as u32)
+                >= (required.shader_denorm_preserve_float64 as u32)
+            && (self.shader_denorm_flush_to_zero_float16 as u32)
+                >= (required.shader_denorm_flush_to_zero_float16 as u32)
+            && (self.shader_denorm_flush_to_zero_float32 as u32)
+                >= (required.shader_denorm_flush_to_zero_float32 as u32)
+            && (self.shader_denorm_flush_to_zero_float64 as u32)
+                >= (required.shader_denorm_flush_to_zero_float64 as u32)
+            && (self.shader_rounding_mode_rtefloat16 as u32)
+                >= (required.shader_rounding_mode_rtefloat16 as u32)
+            && (self.shader_rounding_mode_rtefloat32 as u32)
+                >= (required.shader_rounding_mode_rtefloat32 as u32)
+            && (self.shader_rounding_mode_rtefloat64 as u32)
+                >= (required.shader_rounding_mode_rtefloat64 as u32)
+            && (self.shader_rounding_mode_rtzfloat16 as u32)
+                >= (required.shader_rounding_mode_rtzfloat16 as u32)
+            && (self.shader_rounding_mode_rtzfloat32 as u32)
+                >= (required.shader_rounding_mode_rtzfloat32 as u32)
+            && (self.shader_rounding_mode_rtzfloat64 as u32)
+                >= (required.shader_rounding_mode_rtzfloat64 as u32)
+            && self.max_update_after_bind_descriptors_in_all_pools
+                >= required.max_update_after_bind_descriptors_in_all_pools
+            && (self.shader_uniform_buffer_array_non_uniform_indexing_native as u32)
+                >= (required.shader_uniform_buffer_array_non_uniform_indexing_native as u32)
+            && (self.shader_sampled_image_array_non_uniform_indexing_native as u32)
+                >= (required.shader_sampled_image_array_non_uniform_indexing_native as u32)
+            && (self.shader_storage_buffer_array_non_uniform_indexing_native as u32)
+                >= (required.shader_storage_buffer_array_non_uniform_indexing_native as u32)
+            && (self.shader_storage_image_array_non_uniform_indexing_native as u32)
+                >= (required.shader_storage_image_array_non_uniform_indexing_native as u32)
+            && (self.shader_input_attachment_array_non_uniform_indexing_native as u32)
+                >= (required.shader_input_attachment_array_non_uniform_indexing_native as u32)
+            && (self.robust_buffer_access_update_after_bind as u32)
+                >= (required.robust_buffer_access_update_after_bind as u32)
+            && (self.quad_divergent_implicit_lod as u32)
+                >= (required.quad_divergent_implicit_lod as u32)
+            && self.max_per_stage_descriptor_update_after_bind_samplers
+                >= required.max_per_stage_descriptor_update_after_bind_samplers
+            && self.max_per_stage_descriptor_update_after_bind_uniform_buffers
+                >= required.max_per_stage_descriptor_update_after_bind_uniform_buffers
+            && self.max_per_stage_descriptor_update_after_bind_storage_buffers
+                >= required.max_per_stage_descriptor_update_after_bind_storage_buffers
+            && self.max_per_stage_descriptor_update_after_bind_sampled_images
+                >= required.max_per_stage_descriptor_update_after_bind_sampled_images
+            && self.max_per_stage_descriptor_update_after_bind_storage_images
+                >= required.max_per_stage_descriptor_update_after_bind_storage_images
+            && self.max_per_stage_descriptor_update_after_bind_input_attachments
+                >= required.max_per_stage_descriptor_update_after_bind_input_attachments
+            && self.max_per_stage_update_after_bind_resources
+                >= required.max_per_stage_update_after_bind_resources
+            && self.max_descriptor_set_update_after_bind_samplers
+                >= required.max_descriptor_set_update_after_bind_samplers
+            && self.max_descriptor_set_update_after_bind_uniform_buffers
+                >= required.max_descriptor_set_update_after_bind_uniform_buffers
+            && self.max_descriptor_set_update_after_bind_uniform_buffers_dynamic
+                >= required.max_descriptor_set_update_after_bind_uniform_buffers_dynamic
+            && self.max_descriptor_set_update_after_bind_storage_buffers
+                >= required.max_descriptor_set_update_after_bind_storage_buffers
+            && self.max_descriptor_set_update_after_bind_storage_buffers_dynamic
+                >= required.max_descriptor_set_update_after_bind_storage_buffers_dynamic
+            && self.max_descriptor_set_update_after_bind_sampled_images
+                >= required.max_descriptor_set_update_after_bind_sampled_images
+            && self.max_descriptor_set_update_after_bind_storage_images
+                >= required.max_descriptor_set_update_after_bind_storage_images
+            && self.max_descriptor_set_update_after_bind_input_attachments
+                >= required.max_descriptor_set_update_after_bind_input_attachments
+            && self.supported_depth_resolve_modes & required.supported_depth_resolve_modes
+                == required.supported_depth_resolve_modes
+            && self.supported_stencil_resolve_modes & required.supported_stencil_resolve_modes
+                == required.supported_stencil_resolve_modes
+            && (self.independent_resolve_none as u32) >= (required.independent_resolve_none as u32)
+            && (self.independent_resolve as u32) >= (required.independent_resolve as u32)
+            && (self.filter_minmax_single_component_formats as u32)
+                >= (required.filter_minmax_single_component_formats as u32)
+            && (self.filter_minmax_image_component_mapping as u32)
+                >= (required.filter_minmax_image_component_mapping as u32)
+            && self.max_timeline_semaphore_value_difference
+                >= required.max_timeline_semaphore_value_difference
+            && self.framebuffer_integer_color_sample_counts
+                & required.framebuffer_integer_color_sample_counts
+                == required.framebuffer_integer_color_sample_counts
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        if (other.shader_signed_zero_inf_nan_preserve_float16 as u32)
+            < (self.shader_signed_zero_inf_nan_preserve_float16 as u32)
+        {
+            self.shader_signed_zero_inf_nan_preserve_float16 =
+                other.shader_signed_zero_inf_nan_preserve_float16;
+        }
+        if (other.shader_signed_zero_inf_nan_preserve_float32 as u32)
+            < (self.shader_signed_zero_inf_nan_preserve_float32 as u32)
+        {
+            self.shader_signed_zero_inf_nan_preserve_float32 =
+                other.shader_signed_zero_inf_nan_preserve_float32;
+        }
+        if (other.shader_signed_zero_inf_nan_preserve_float64 as u32)
+            < (self.shader_signed_zero_inf_nan_preserve_float64 as u32)
+        {
+            self.shader_signed_zero_inf_nan_preserve_float64 =
+                other.shader_signed_zero_inf_nan_preserve_float64;
+        }
+        if (other.shader_denorm_preserve_float16 as u32)
+            < (self.shader_denorm_preserve_float16 as u32)
+        {
+            self.shader_denorm_preserve_float16 = other.shader_denorm_preserve_float16;
+        }
+        if (other.shader_denorm_preserve_float32 as u32)
+            < (self.shader_denorm_preserve_float32 as u32)
+        {
+            self.shader_denorm_preserve_float32 = other.shader_denorm_preserve_float32;
+        }
+        if (other.shader_denorm_preserve_float64 as u32)
+            < (self.shader_denorm_preserve_float64 as u32)
+        {
+            self.shader_denorm_preserve_float64 = other.shader_denorm_preserve_float64;
+        }
+        if (other.shader_denorm_flush_to_zero_float16 as u32)
+            < (self.shader_denorm_flush_to_zero_float16 as u32)
+        {
+            self.shader_denorm_flush_to_zero_float16 = other.shader_denorm_flush_to_zero_float16;
+        }
+        if (other.shader_denorm_flush_to_zero_float32 as u32)
+            < (self.shader_denorm_flush_to_zero_float32 as u32)
+        {
+            self.shader_denorm_flush_to_zero_float32 = other.shader_denorm_flush_to_zero_float32;
+        }
+        if (other.shader_denorm_flush_to_zero_float64 as u32)
+            < (self.shader_denorm_flush_to_zero_float64 as u32)
+        {
+            self.shader_denorm_flush_to_zero_float64 = other.shader_denorm_flush_to_zero_float64;
+        }
+        if (other.shader_rounding_mode_rtefloat16 as u32)
+            < (self.shader_rounding_mode_rtefloat16 as u32)
+        {
+            self.shader_rounding_mode_rtefloat16 = other.shader_rounding_mode_rtefloat16;
+        }
+        if (other.shader_rounding_mode_rtefloat32 as u32)
+            < (self.shader_rounding_mode_rtefloat32 as u32)
+        {
+            self.shader_rounding_mode_rtefloat32 = other.shader_rounding_mode_rtefloat32;
+        }
+        if (other.shader_rounding_mode_rtefloat64 as u32)
+            < (self.shader_rounding_mode_rtefloat64 as u32)
+        {
+            self.shader_rounding_mode_rtefloat64 = other.shader_rounding_mode_rtefloat64;
+        }
+        if (other.shader_rounding_mode_rtzfloat16 as u32)
+            < (self.shader_rounding_mode_rtzfloat16 as u32)
+        {
+            self.shader_rounding_mode_rtzfloat16 = other.shader_rounding_mode_rtzfloat16;
+        }
+        if (other.shader_rounding_mode_rtzfloat32 as u32)
+            < (self.shader_rounding_mode_rtzfloat32 as u32)
+        {
+            self.shader_rounding_mode_rtzfloat32 = other.shader_rounding_mode_rtzfloat32;
+        }
+        if (other.shader_rounding_mode_rtzfloat64 as u32)
+            < (self.shader_rounding_mode_rtzfloat64 as u32)
+        {
+            self.shader_rounding_mode_rtzfloat64 = other.shader_rounding_mode_rtzfloat64;
+        }
+        if other.max_update_after_bind_descriptors_in_all_pools
+            < self.max_update_after_bind_descriptors_in_all_pools
+        {
+            self.max_update_after_bind_descriptors_in_all_pools =
+                other.max_update_after_bind_descriptors_in_all_pools;
+        }
+        if (other.shader_uniform_buffer_array_non_uniform_indexing_native as u32)
+            < (self.shader_uniform_buffer_array_non_uniform_indexing_native as u32)
+        {
+            self.shader_uniform_buffer_array_non_uniform_indexing_native =
+                other.shader_uniform_buffer_array_non_uniform_indexing_native;
+        }
+        if (other.shader_sampled_image_array_non_uniform_indexing_native as u32)
+            < (self.shader_sampled_image_array_non_uniform_indexing_native as u32)
+        {
+            self.shader_sampled_image_array_non_uniform_indexing_native =
+                other.shader_sampled_image_array_non_uniform_indexing_native;
+        }
+        if (other.shader_storage_buffer_array_non_uniform_indexing_native as u32)
+            < (self.shader_storage_buffer_array_non_uniform_indexing_native as u32)
+        {
+            self.shader_storage_buffer_array_non_uniform_indexing_native =
+                other.shader_storage_buffer_array_non_uniform_indexing_native;
+        }
+        if (other.shader_storage_image_array_non_uniform_indexing_native as u32)
+            < (self.shader_storage_image_array_non_uniform_indexing_native as u32)
+        {
+            self.shader_storage_image_array_non_uniform_indexing_native =
+                other.shader_storage_image_array_non_uniform_indexing_native;
+        }
+        if (other.shader_input_attachment_array_non_uniform_indexing_native as u32)
+            < (self.shader_input_attachment_array_non_uniform_indexing_native as u32)
+        {
+            self.shader_input_attachment_array_non_uniform_indexing_native =
+                other.shader_input_attachment_array_non_uniform_indexing_native;
+        }
+        if (other.robust_buffer_access_update_after_bind as u32)
+            < (self.robust_buffer_access_update_after_bind as u32)
+        {
+            self.robust_buffer_access_update_after_bind =
+                other.robust_buffer_access_update_after_bind;
+        }
+        if (other.quad_divergent_implicit_lod as u32) < (self.quad_divergent_implicit_lod as u32) {
+            self.quad_divergent_implicit_lod = other.quad_divergent_implicit_lod;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_samplers
+            < self.max_per_stage_descriptor_update_after_bind_samplers
+        {
+            self.max_per_stage_descriptor_update_after_bind_samplers =
+                other.max_per_stage_descriptor_update_after_bind_samplers;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_uniform_buffers
+            < self.max_per_stage_descriptor_update_after_bind_uniform_buffers
+        {
+            self.max_per_stage_descriptor_update_after_bind_uniform_buffers =
+                other.max_per_stage_descriptor_update_after_bind_uniform_buffers;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_storage_buffers
+            < self.max_per_stage_descriptor_update_after_bind_storage_buffers
+        {
+            self.max_per_stage_descriptor_update_after_bind_storage_buffers =
+                other.max_per_stage_descriptor_update_after_bind_storage_buffers;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_sampled_images
+            < self.max_per_stage_descriptor_update_after_bind_sampled_images
+        {
+            self.max_per_stage_descriptor_update_after_bind_sampled_images =
+                other.max_per_stage_descriptor_update_after_bind_sampled_images;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_storage_images
+            < self.max_per_stage_descriptor_update_after_bind_storage_images
+        {
+            self.max_per_stage_descriptor_update_after_bind_storage_images =
+                other.max_per_stage_descriptor_update_after_bind_storage_images;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_input_attachments
+            < self.max_per_stage_descriptor_update_after_bind_input_attachments
+        {
+            self.max_per_stage_descriptor_update_after_bind_input_attachments =
+                other.max_per_stage_descriptor_update_after_bind_input_attachments;
+        }
+        if other.max_per_stage_update_after_bind_resources
+            < self.max_per_stage_update_after_bind_resources
+        {
+            self.max_per_stage_update_after_bind_resources =
+                other.max_per_stage_update_after_bind_resources;
+        }
+        if other.max_descriptor_set_update_after_bind_samplers
+            < self.max_descriptor_set_update_after_bind_samplers
+        {
+            self.max_descriptor_set_update_after_bind_samplers =
+                other.max_descriptor_set_update_after_bind_samplers;
+        }
+        if other.max_descriptor_set_update_after_bind_uniform_buffers
+            < self.max_descriptor_set_update_after_bind_uniform_buffers
+        {
+            self.max_descriptor_set_update_after_bind_uniform_buffers =
+                other.max_descriptor_set_update_after_bind_uniform_buffers;
+        }
+        if other.max_descriptor_set_update_after_bind_uniform_buffers_dynamic
+            < self.max_descriptor_set_update_after_bind_uniform_buffers_dynamic
+        {
+            self.max_descriptor_set_update_after_bind_uniform_buffers_dynamic =
+                other.max_descriptor_set_update_after_bind_uniform_buffers_dynamic;
+        }
+        if other.max_descriptor_set_update_after_bind_storage_buffers
+            < self.max_descriptor_set_update_after_bind_storage_buffers
+        {
+            self.max_descriptor_set_update_after_bind_storage_buffers =
+                other.max_descriptor_set_update_after_bind_storage_buffers;
+        }
+        if other.max_descriptor_set_update_after_bind_storage_buffers_dynamic
+            < self.max_descriptor_set_update_after_bind_storage_buffers_dynamic
+        {
+            self.max_descriptor_set_update_after_bind_storage_buffers_dynamic =
+                other.max_descriptor_set_update_after_bind_storage_buffers_dynamic;
+        }
+        if other.max_descriptor_set_update_after_bind_sampled_images
+            < self.max_descriptor_set_update_after_bind_sampled_images
+        {
+            self.max_descriptor_set_update_after_bind_sampled_images =
+                other.max_descriptor_set_update_after_bind_sampled_images;
+        }
+        if other.max_descriptor_set_update_after_bind_storage_images
+            < self.max_descriptor_set_update_after_bind_storage_images
+        {
+            self.max_descriptor_set_update_after_bind_storage_images =
+                other.max_descriptor_set_update_after_bind_storage_images;
+        }
+        if other.max_descriptor_set_update_after_bind_input_attachments
+            < self.max_descriptor_set_update_after_bind_input_attachments
+        {
+            self.max_descriptor_set_update_after_bind_input_attachments =
+                other.max_descriptor_set_update_after_bind_input_attachments;
+        }
+        self.supported_depth_resolve_modes &= other.supported_depth_resolve_modes;
+        self.supported_stencil_resolve_modes &= other.supported_stencil_resolve_modes;
+        if (other.independent_resolve_none as u32) < (self.independent_resolve_none as u32) {
+            self.independent_resolve_none = other.independent_resolve_none;
+        }
+        if (other.independent_resolve as u32) < (self.independent_resolve as u32) {
+            self.independent_resolve = other.independent_resolve;
+        }
+        if (other.filter_minmax_single_component_formats as u32)
+            < (self.filter_minmax_single_component_formats as u32)
+        {
+            self.filter_minmax_single_component_formats =
+                other.filter_minmax_single_component_formats;
+        }
+        if (other.filter_minmax_image_component_mapping as u32)
+            < (self.filter_minmax_image_component_mapping as u32)
+        {
+            self.filter_minmax_image_component_mapping =
+                other.filter_minmax_image_component_mapping;
+        }
+        if other.max_timeline_semaphore_value_difference
+            < self.max_timeline_semaphore_value_difference
+        {
+            self.max_timeline_semaphore_value_difference =
+                other.max_timeline_semaphore_value_difference;
+        }
+        self.framebuffer_integer_color_sample_counts &=
+            other.framebuffer_integer_color_sample_counts;
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default()
+    }
+}
+impl PhysicalDeviceVulkan13Properties<'_> {
+    #[doc = r" Return true if these limits satisfy the `required` ones: maximum limits must be at least"]
+    #[doc = r" the required value, minimum limits at most the required value, bitmasks must contain"]
+    #[doc = r" all the required bits and ranges must contain the required range."]
+    #[doc = r" Boolean limits are only required when they are set to true."]
+    #[doc = r" Members which are not limits (identifiers, UUIDs, ...) are ignored"]
+    pub fn satisfies_limits(&self, required: &Self) -> bool {
+        self . min_subgroup_size <= required . min_subgroup_size && self . max_subgroup_size >= required . max_subgroup_size && self . max_compute_workgroup_subgroups >= required . max_compute_workgroup_subgroups && self . required_subgroup_size_stages & required . required_subgroup_size_stages == required . required_subgroup_size_stages && self . max_inline_uniform_block_size >= required . max_inline_uniform_block_size && self . max_per_stage_descriptor_inline_uniform_blocks >= required . max_per_stage_descriptor_inline_uniform_blocks && self . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks >= required . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks && self . max_descriptor_set_inline_uniform_blocks >= required . max_descriptor_set_inline_uniform_blocks && self . max_descriptor_set_update_after_bind_inline_uniform_blocks >= required . max_descriptor_set_update_after_bind_inline_uniform_blocks && self . max_inline_uniform_total_size >= required . max_inline_uniform_total_size && (self . integer_dot_product8_bit_unsigned_accelerated as u32) >= (required . integer_dot_product8_bit_unsigned_accelerated as u32) && (self . integer_dot_product8_bit_signed_accelerated as u32) >= (required . integer_dot_product8_bit_signed_accelerated as u32) && (self . integer_dot_product8_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product8_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product4x8_bit_packed_unsigned_accelerated as u32) >= (required . integer_dot_product4x8_bit_packed_unsigned_accelerated as u32) && (self . integer_dot_product4x8_bit_packed_signed_accelerated as u32) >= (required . integer_dot_product4x8_bit_packed_signed_accelerated as u32) && (self . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated as u32) >= (required . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated as u32) && (self . integer_dot_product16_bit_unsigned_accelerated as u32) >= (required . integer_dot_product16_bit_unsigned_accelerated as u32) && (self . integer_dot_product16_bit_signed_accelerated as u32) >= (required . integer_dot_product16_bit_signed_accelerated as u32) && (self . integer_dot_product16_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product16_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product32_bit_unsigned_accelerated as u32) >= (required . integer_dot_product32_bit_unsigned_accelerated as u32) && (self . integer_dot_product32_bit_signed_accelerated as u32) >= (required . integer_dot_product32_bit_signed_accelerated as u32) && (self . integer_dot_product32_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product32_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product64_bit_unsigned_accelerated as u32) >= (required . integer_dot_product64_bit_unsigned_accelerated as u32) && (self . integer_dot_product64_bit_signed_accelerated as u32) >= (required . integer_dot_product64_bit_signed_accelerated as u32) && (self . integer_dot_product64_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product64_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated as u32) && (self . integer_dot_product_accumulating_saturating8_bit_signed_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating8_bit_signed_accelerated as u32) && (self . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated as u32) && (self . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated as u32) && (self . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated as u32) && (self . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated as u32) && (self . integer_dot_product_accumulating_saturating16_bit_signed_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating16_bit_signed_accelerated as u32) && (self . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated as u32) && (self . integer_dot_product_accumulating_saturating32_bit_signed_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating32_bit_signed_accelerated as u32) && (self . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated as u32) && (self . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated as u32) && (self . integer_dot_product_accumulating_saturating64_bit_signed_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating64_bit_signed_accelerated as u32) && (self . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated as u32) >= (required . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated as u32) && self . storage_texel_buffer_offset_alignment_bytes <= required . storage_texel_buffer_offset_alignment_bytes && (self . storage_texel_buffer_offset_single_texel_alignment as u32) >= (required . storage_texel_buffer_offset_single_texel_alignment as u32) && self . uniform_texel_buffer_offset_alignment_bytes <= required . uniform_texel_buffer_offset_alignment_bytes && (self . uniform_texel_buffer_offset_single_texel_alignment as u32) >= (required . uniform_texel_buffer_offset_single_texel_alignment as u32) && self . max_buffer_size >= required . max_buffer_size
+    }
+    #[doc = r" Restrict these limits to the ones also supported by `other`. Merging the limits of"]
+    #[doc = r" several devices this way gives limits satisfied by all of them."]
+    #[doc = r" Limits which must match exactly keep their current value"]
+    pub fn merge_limits(&mut self, other: &Self) {
+        if other.min_subgroup_size > self.min_subgroup_size {
+            self.min_subgroup_size = other.min_subgroup_size;
+        }
+        if other.max_subgroup_size < self.max_subgroup_size {
+            self.max_subgroup_size = other.max_subgroup_size;
+        }
+        if other.max_compute_workgroup_subgroups < self.max_compute_workgroup_subgroups {
+            self.max_compute_workgroup_subgroups = other.max_compute_workgroup_subgroups;
+        }
+        self.required_subgroup_size_stages &= other.required_subgroup_size_stages;
+        if other.max_inline_uniform_block_size < self.max_inline_uniform_block_size {
+            self.max_inline_uniform_block_size = other.max_inline_uniform_block_size;
+        }
+        if other.max_per_stage_descriptor_inline_uniform_blocks
+            < self.max_per_stage_descriptor_inline_uniform_blocks
+        {
+            self.max_per_stage_descriptor_inline_uniform_blocks =
+                other.max_per_stage_descriptor_inline_uniform_blocks;
+        }
+        if other.max_per_stage_descriptor_update_after_bind_inline_uniform_blocks
+            < self.max_per_stage_descriptor_update_after_bind_inline_uniform_blocks
+        {
+            self.max_per_stage_descriptor_update_after_bind_inline_uniform_blocks =
+                other.max_per_stage_descriptor_update_after_bind_inline_uniform_blocks;
+        }
+        if other.max_descriptor_set_inline_uniform_blocks
+            < self.max_descriptor_set_inline_uniform_blocks
+        {
+            self.max_descriptor_set_inline_uniform_blocks =
+                other.max_descriptor_set_inline_uniform_blocks;
+        }
+        if other.max_descriptor_set_update_after_bind_inline_uniform_blocks
+            < self.max_descriptor_set_update_after_bind_inline_uniform_blocks
+        {
+            self.max_descriptor_set_update_after_bind_inline_uniform_blocks =
+                other.max_descriptor_set_update_after_bind_inline_uniform_blocks;
+        }
+        if other.max_inline_uniform_total_size < self.max_inline_uniform_total_size {
+            self.max_inline_uniform_total_size = other.max_inline_uniform_total_size;
+        }
+        if (other.integer_dot_product8_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product8_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product8_bit_unsigned_accelerated =
+                other.integer_dot_product8_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product8_bit_signed_accelerated as u32)
+            < (self.integer_dot_product8_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product8_bit_signed_accelerated =
+                other.integer_dot_product8_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product8_bit_mixed_signedness_accelerated as u32)
+            < (self.integer_dot_product8_bit_mixed_signedness_accelerated as u32)
+        {
+            self.integer_dot_product8_bit_mixed_signedness_accelerated =
+                other.integer_dot_product8_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product4x8_bit_packed_unsigned_accelerated as u32)
+            < (self.integer_dot_product4x8_bit_packed_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product4x8_bit_packed_unsigned_accelerated =
+                other.integer_dot_product4x8_bit_packed_unsigned_accelerated;
+        }
+        if (other.integer_dot_product4x8_bit_packed_signed_accelerated as u32)
+            < (self.integer_dot_product4x8_bit_packed_signed_accelerated as u32)
+        {
+            self.integer_dot_product4x8_bit_packed_signed_accelerated =
+                other.integer_dot_product4x8_bit_packed_signed_accelerated;
+        }
+        if (other.integer_dot_product4x8_bit_packed_mixed_signedness_accelerated as u32)
+            < (self.integer_dot_product4x8_bit_packed_mixed_signedness_accelerated as u32)
+        {
+            self.integer_dot_product4x8_bit_packed_mixed_signedness_accelerated =
+                other.integer_dot_product4x8_bit_packed_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product16_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product16_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product16_bit_unsigned_accelerated =
+                other.integer_dot_product16_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product16_bit_signed_accelerated as u32)
+            < (self.integer_dot_product16_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product16_bit_signed_accelerated =
+                other.integer_dot_product16_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product16_bit_mixed_signedness_accelerated as u32)
+            < (self.integer_dot_product16_bit_mixed_signedness_accelerated as u32)
+        {
+            self.integer_dot_product16_bit_mixed_signedness_accelerated =
+                other.integer_dot_product16_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product32_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product32_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product32_bit_unsigned_accelerated =
+                other.integer_dot_product32_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product32_bit_signed_accelerated as u32)
+            < (self.integer_dot_product32_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product32_bit_signed_accelerated =
+                other.integer_dot_product32_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product32_bit_mixed_signedness_accelerated as u32)
+            < (self.integer_dot_product32_bit_mixed_signedness_accelerated as u32)
+        {
+            self.integer_dot_product32_bit_mixed_signedness_accelerated =
+                other.integer_dot_product32_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product64_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product64_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product64_bit_unsigned_accelerated =
+                other.integer_dot_product64_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product64_bit_signed_accelerated as u32)
+            < (self.integer_dot_product64_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product64_bit_signed_accelerated =
+                other.integer_dot_product64_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product64_bit_mixed_signedness_accelerated as u32)
+            < (self.integer_dot_product64_bit_mixed_signedness_accelerated as u32)
+        {
+            self.integer_dot_product64_bit_mixed_signedness_accelerated =
+                other.integer_dot_product64_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated =
+                other.integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating8_bit_signed_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating8_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating8_bit_signed_accelerated =
+                other.integer_dot_product_accumulating_saturating8_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated
+            as u32)
+            < (self.integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated
+                as u32)
+        {
+            self.integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated =
+                other.integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated
+            as u32)
+            < (self.integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated
+                as u32)
+        {
+            self.integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated =
+                other
+                    .integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated
+            as u32)
+            < (self.integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated
+                as u32)
+        {
+            self.integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated =
+                other.integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated;
+        }
+        if (other . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated as u32) < (self . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated as u32) { self . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated = other . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated ; }
+        if (other.integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated =
+                other.integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating16_bit_signed_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating16_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating16_bit_signed_accelerated =
+                other.integer_dot_product_accumulating_saturating16_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated
+            as u32)
+            < (self.integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated
+                as u32)
+        {
+            self.integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated =
+                other
+                    .integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated =
+                other.integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating32_bit_signed_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating32_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating32_bit_signed_accelerated =
+                other.integer_dot_product_accumulating_saturating32_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated
+            as u32)
+            < (self.integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated
+                as u32)
+        {
+            self.integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated =
+                other
+                    .integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated =
+                other.integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating64_bit_signed_accelerated as u32)
+            < (self.integer_dot_product_accumulating_saturating64_bit_signed_accelerated as u32)
+        {
+            self.integer_dot_product_accumulating_saturating64_bit_signed_accelerated =
+                other.integer_dot_product_accumulating_saturating64_bit_signed_accelerated;
+        }
+        if (other.integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated
+            as u32)
+            < (self.integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated
+                as u32)
+        {
+            self.integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated =
+                other
+                    .integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated;
+        }
+        if other.storage_texel_buffer_offset_alignment_bytes
+            > self.storage_texel_buffer_offset_alignment_bytes
+        {
+            self.storage_texel_buffer_offset_alignment_bytes =
+                other.storage_texel_buffer_offset_alignment_bytes;
+        }
+        if (other.storage_texel_buffer_offset_single_texel_alignment as u32)
+            < (self.storage_texel_buffer_offset_single_texel_alignment as u32)
+        {
+            self.storage_texel_buffer_offset_single_texel_alignment =
+                other.storage_texel_buffer_offset_single_texel_alignment;
+        }
+        if other.uniform_texel_buffer_offset_alignment_bytes
+            > self.uniform_texel_buffer_offset_alignment_bytes
+        {
+            self.uniform_texel_buffer_offset_alignment_bytes =
+                other.uniform_texel_buffer_offset_alignment_bytes;
+        }
+        if (other.uniform_texel_buffer_offset_single_texel_alignment as u32)
+            < (self.uniform_texel_buffer_offset_single_texel_alignment as u32)
+        {
+            self.uniform_texel_buffer_offset_single_texel_alignment =
+                other.uniform_texel_buffer_offset_single_texel_alignment;
+        }
+        if other.max_buffer_size < self.max_buffer_size {
+            self.max_buffer_size = other.max_buffer_size;
+        }
+    }
+    #[doc = r" Limits satisfied by every device, to be used as the base of the `required` limits"]
+    #[doc = r" given to [satisfies_limits](Self::satisfies_limits): maximum limits are 0, minimum limits"]
+    #[doc = r" (alignments, granularities, ...) are the largest possible value and ranges are empty."]
+    #[doc = r" Limits which must match exactly and are not booleans keep their default value"]
+    pub fn least_restrictive() -> Self {
+        Self::default()
+            .min_subgroup_size(u32::MAX)
+            .storage_texel_buffer_offset_alignment_bytes(DeviceSize::MAX)
+            .uniform_texel_buffer_offset_alignment_bytes(DeviceSize::MAX)
+    }
+}
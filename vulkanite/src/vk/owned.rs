@@ -0,0 +1,323 @@
+//! Owned versions of the create-info structures
+//!
+//! Structures like [vk::GraphicsPipelineCreateInfo] borrow all the arrays and structures they point to,
+//! which makes them hard to build dynamically or to store. Each type of this module owns the storage for
+//! everything it points to (using `Vec`, `Box` and `CString`) as well as its `p_next` chain.
+//! The Vulkan structure can then be retrieved using `as_raw`, its lifetime is bound to the owned structure:
+//! ```ignore
+//! let stages = shaders.iter().map(|(stage, module)| {
+//!     vk::owned::PipelineShaderStageCreateInfo::default()
+//!         .stage(*stage)
+//!         .module(Some(module))
+//!         .name(c"main")
+//! });
+//! let info = vk::owned::GraphicsPipelineCreateInfo::default()
+//!     .stages(stages)
+//!     .vertex_input_state(Some(Default::default()))
+//!     .layout(Some(&layout))
+//!     .push_next(vk::owned::PipelineRenderingCreateInfo::default().color_attachment_formats(&formats));
+//! // the owned structure can be stored and cloned, the raw structure only borrows it
+//! let (_, pipelines): (_, Vec<_>) = device.create_graphics_pipelines(None, &info.as_raw())?;
+//! ```
+//! Handles are not owned: the handles given to an owned structure must outlive all the uses of this structure.
+//!
+//! Plain structures which can be found in a `p_next` chain (like [vk::PhysicalDeviceVulkan12Features])
+//! do not have an owned version, `vk::X<'static>` can be used instead.
+//!
+//! Owned structures implement [Eq] and [Hash] by comparing their content, including the arrays and structures
+//! they point to and their `p_next` chain, floats being compared bitwise. They can also be deep-cloned from the
+//! Vulkan structure using [From]. This allows them to be used as the key of a cache:
+//! ```ignore
+//! let mut pipelines: HashMap<vk::owned::GraphicsPipelineCreateInfo, vk::rs::Pipeline> = HashMap::new();
+//! let key = vk::owned::GraphicsPipelineCreateInfo::from(&create_info);
+//! let pipeline = match pipelines.entry(key) {
+//!     Entry::Occupied(entry) => entry.into_mut(),
+//!     Entry::Vacant(entry) => {
+//!         let (_, pipeline) = device.create_graphics_pipelines(None, &entry.key().as_raw())?;
+//!         entry.insert(pipeline)
+//!     }
+//! };
+//! ```
+
+use std::{
+    any::Any,
+    cell::Cell,
+    hash::{Hash, Hasher},
+    mem, ptr, slice,
+};
+
+use crate::{Alias, ExtendableStructure, Handle, Header};
+
+/// Structural equality and hashing of Vulkan structures
+///
+/// This is implemented for all the types implementing [Eq] and [Hash] as well as for the plain
+/// structures found in owned structures, in which case floats are compared using their bit representation
+/// so that the comparison is an equivalence relation
+pub trait StructuralEq {
+    fn structural_eq(&self, other: &Self) -> bool;
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H);
+}
+
+impl<T: Eq + Hash> StructuralEq for T {
+    fn structural_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state)
+    }
+}
+
+/// A structure owning all its content which can be pushed to the `p_next` chain of an owned structure
+/// For an owned structure X, [OwnedExtendable::Raw] is `vk::X<'static>`
+///
+/// # Safety
+/// The header returned by `raw_header` must be the header of a structure of type [OwnedExtendable::Raw]
+/// whose content stays valid as long as the owned structure is not modified
+pub unsafe trait OwnedExtendable: StructuralEq + Send + Sync + 'static {
+    type Raw: ExtendableStructure;
+
+    #[doc(hidden)]
+    fn raw_header(&self) -> &Header;
+
+    /// Clone the structure, the `p_next` pointer of the result is left unset
+    #[doc(hidden)]
+    fn clone_unlinked(&self) -> Self
+    where
+        Self: Sized;
+
+    /// Take ownership of the structure chain of this structure
+    #[doc(hidden)]
+    fn take_next(&mut self) -> OwnedChain {
+        OwnedChain::default()
+    }
+}
+
+/// Object safe part of [OwnedExtendable]
+trait ChainElement: Send + Sync {
+    fn header(&self) -> &Header;
+
+    fn clone_boxed(&self) -> Box<dyn ChainElement>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn dyn_eq(&self, other: &dyn ChainElement) -> bool;
+
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+impl<T: OwnedExtendable> ChainElement for T {
+    fn header(&self) -> &Header {
+        self.raw_header()
+    }
+
+    fn clone_boxed(&self) -> Box<dyn ChainElement> {
+        Box::new(self.clone_unlinked())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn ChainElement) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<T>()
+            .is_some_and(|other| self.structural_eq(other))
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.structural_hash(&mut state)
+    }
+}
+
+/// Structures in the `p_next` chain of an owned structure
+/// The structures are boxed so their address does not change when the chain is moved
+#[doc(hidden)]
+#[derive(Default)]
+pub struct OwnedChain(Vec<Box<dyn ChainElement>>);
+
+impl Clone for OwnedChain {
+    fn clone(&self) -> Self {
+        Self(self.0.iter().map(|ext| ext.clone_boxed()).collect())
+    }
+}
+
+/// Two chains are equal if they contain equal structures in the same order
+impl PartialEq for OwnedChain {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(ext, other)| ext.dyn_eq(other.as_ref()))
+    }
+}
+
+impl Eq for OwnedChain {}
+
+impl Hash for OwnedChain {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.len().hash(state);
+        for ext in &self.0 {
+            ext.header().s_type.hash(state);
+            ext.dyn_hash(state);
+        }
+    }
+}
+
+impl OwnedChain {
+    /// Deep copy of the Vulkan structure chain starting at next
+    ///
+    /// # Panics
+    /// If one of the structures of the chain does not have an owned version
+    unsafe fn from_raw(mut next: *const Header) -> Self {
+        let mut chain = Self::default();
+        while let Some(header) = next.as_ref() {
+            let ext = structs::clone_chain_element(header)
+                .unwrap_or_else(|| panic!("{:?} does not have an owned version", header.s_type));
+            chain.0.push(ext);
+            next = header.p_next.get();
+        }
+        chain
+    }
+
+    /// Add a structure at the end of the chain, followed by its own chain
+    fn push<T: OwnedExtendable>(&mut self, mut ext: T) {
+        let next = ext.take_next();
+        self.0.push(Box::new(ext));
+        self.0.extend(next.0);
+    }
+
+    /// Link head to the structures of the chain
+    fn link(&self, head: &Cell<*const Header>) {
+        let mut prev = head;
+        for ext in &self.0 {
+            let header = ext.header();
+            prev.set(ptr::from_ref(header));
+            prev = &header.p_next;
+        }
+        prev.set(ptr::null());
+    }
+}
+
+/// Pointer to the content of values, Vulkan expects a null pointer for empty arrays
+fn slice_ptr<T, U>(values: &[T]) -> *const U {
+    if values.is_empty() {
+        ptr::null()
+    } else {
+        values.as_ptr().cast()
+    }
+}
+
+/// Slice from a pointer found in a Vulkan structure, which can be null if the slice is empty
+unsafe fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    }
+}
+
+fn clone_handles<V: Alias<T>, T: Handle>(handles: &[V]) -> Vec<T> {
+    // SAFETY: V has the same memory representation as T
+    let handles: &[T] = unsafe { slice::from_raw_parts(handles.as_ptr().cast(), handles.len()) };
+    handles
+        .iter()
+        .map(|handle| unsafe { handle.clone() })
+        .collect()
+}
+
+/// Floats are compared using their bit representation, see [StructuralEq]
+trait FloatBits: Copy {
+    type Bits: Eq + Hash;
+
+    fn bits(self) -> Self::Bits;
+}
+
+impl FloatBits for f32 {
+    type Bits = u32;
+
+    fn bits(self) -> u32 {
+        self.to_bits()
+    }
+}
+
+impl FloatBits for f64 {
+    type Bits = u64;
+
+    fn bits(self) -> u64 {
+        self.to_bits()
+    }
+}
+
+fn floats_eq<F: FloatBits>(a: &[F], b: &[F]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.bits() == b.bits())
+}
+
+fn floats_hash<F: FloatBits, H: Hasher>(values: &[F], state: &mut H) {
+    values.len().hash(state);
+    for value in values {
+        value.bits().hash(state);
+    }
+}
+
+fn slice_eq<T: StructuralEq>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.structural_eq(b))
+}
+
+fn slice_hash<T: StructuralEq, H: Hasher>(values: &[T], state: &mut H) {
+    values.len().hash(state);
+    for value in values {
+        value.structural_hash(state);
+    }
+}
+
+fn option_eq<T: StructuralEq>(a: Option<&T>, b: Option<&T>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.structural_eq(b),
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
+
+fn option_hash<T: StructuralEq, H: Hasher>(value: Option<&T>, state: &mut H) {
+    value.is_some().hash(state);
+    if let Some(value) = value {
+        value.structural_hash(state);
+    }
+}
+
+/// Function pointers are compared using their address, like [std::ptr::fn_addr_eq]
+fn func_addr<F: Copy>(func: F) -> usize {
+    assert_eq!(mem::size_of::<F>(), mem::size_of::<usize>());
+    // SAFETY: F is a (nullable) function pointer
+    unsafe { mem::transmute_copy(&func) }
+}
+
+/// Unions do not know which of their members is used, they are compared byte by byte
+fn union_bytes<T: Copy>(value: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts(ptr::from_ref(value).cast(), mem::size_of::<T>()) }
+}
+
+macro_rules! impl_owned_extendable {
+    ($name:ident) => {
+        unsafe impl OwnedExtendable for vk::$name<'static> {
+            type Raw = Self;
+
+            fn raw_header(&self) -> &Header {
+                unsafe { &*ExtendableStructureBase::header(self) }
+            }
+
+            fn clone_unlinked(&self) -> Self {
+                // SAFETY: the structure only contains plain data
+                unsafe { ptr::read(self) }
+            }
+        }
+    };
+}
+
+mod structs;
+
+pub use structs::*;
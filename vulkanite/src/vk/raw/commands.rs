@@ -3,7 +3,9 @@
 use crate::vk::raw::{self, *};
 use crate::vk::*;
 use crate::*;
-use std::ffi::{c_int, CStr};
+// c_ulong is only used by platform commands
+#[allow(unused_imports)]
+use std::ffi::{c_int, c_ulong, CStr};
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateInstance.html>"]
 #[doc(alias = "vkCreateInstance")]
 pub unsafe fn create_instance(
@@ -1358,24 +1360,47 @@ pub unsafe fn destroy_pipeline_cache(
 }
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineCacheData.html>"]
 #[doc(alias = "vkGetPipelineCacheData")]
-pub unsafe fn get_pipeline_cache_data(
+pub unsafe fn get_pipeline_cache_data<R: DynamicArray<u8>>(
     device: &raw::Device,
     pipeline_cache: &raw::PipelineCache,
-    p_data: VoidPtr,
     dispatcher: &CommandsDispatcher,
-) -> Result<usize> {
+) -> Result<R> {
     let vulkan_command = dispatcher
         .get_pipeline_cache_data
         .get()
         .expect("Vulkan command not loaded.");
-    let mut p_data_size = MaybeUninit::uninit();
-    let vk_status = vulkan_command(
+    let mut vk_len = MaybeUninit::uninit();
+    let p_data_size = vk_len.as_mut_ptr();
+    let p_data = ptr::null_mut();
+    vulkan_command(
         Some(unsafe { device.clone() }),
         Some(unsafe { pipeline_cache.clone() }),
-        p_data_size.as_mut_ptr(),
-        p_data,
-    );
-    vk_status.map_success(|| p_data_size.assume_init())
+        p_data_size,
+        p_data as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_data_size = ptr::from_mut(&mut vk_len);
+    let mut p_data = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            Some(unsafe { pipeline_cache.clone() }),
+            p_data_size,
+            p_data as VoidPtr,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_data_size = ptr::from_mut(&mut vk_len);
+        p_data = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
 }
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMergePipelineCaches.html>"]
 #[doc(alias = "vkMergePipelineCaches")]
@@ -3166,6 +3191,27 @@ pub unsafe fn get_image_memory_requirements2<S: StructureChainOut<MemoryRequirem
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_image_memory_requirements2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2.html>"]
+#[doc(alias = "vkGetImageMemoryRequirements2")]
+pub unsafe fn get_image_memory_requirements2_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &ImageMemoryRequirementsInfo2,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_image_memory_requirements2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2KHR.html>"]
 #[doc(alias = "vkGetImageMemoryRequirements2KHR")]
 pub unsafe fn get_image_memory_requirements2_khr<
@@ -3189,6 +3235,27 @@ pub unsafe fn get_image_memory_requirements2_khr<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_image_memory_requirements2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2KHR.html>"]
+#[doc(alias = "vkGetImageMemoryRequirements2KHR")]
+pub unsafe fn get_image_memory_requirements2_khr_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &ImageMemoryRequirementsInfo2,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_image_memory_requirements2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2.html>"]
 #[doc(alias = "vkGetBufferMemoryRequirements2")]
 pub unsafe fn get_buffer_memory_requirements2<
@@ -3212,6 +3279,27 @@ pub unsafe fn get_buffer_memory_requirements2<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_buffer_memory_requirements2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2.html>"]
+#[doc(alias = "vkGetBufferMemoryRequirements2")]
+pub unsafe fn get_buffer_memory_requirements2_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &BufferMemoryRequirementsInfo2,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_buffer_memory_requirements2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2KHR.html>"]
 #[doc(alias = "vkGetBufferMemoryRequirements2KHR")]
 pub unsafe fn get_buffer_memory_requirements2_khr<
@@ -3235,6 +3323,27 @@ pub unsafe fn get_buffer_memory_requirements2_khr<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_buffer_memory_requirements2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2KHR.html>"]
+#[doc(alias = "vkGetBufferMemoryRequirements2KHR")]
+pub unsafe fn get_buffer_memory_requirements2_khr_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &BufferMemoryRequirementsInfo2,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_buffer_memory_requirements2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSparseMemoryRequirements2.html>"]
 #[doc(alias = "vkGetImageSparseMemoryRequirements2")]
 pub unsafe fn get_image_sparse_memory_requirements2<
@@ -3326,6 +3435,25 @@ pub unsafe fn get_physical_device_features2<
     S::setup_cleanup(p_features.as_mut_ptr());
     p_features.assume_init()
 }
+#[doc = "Same as [get_physical_device_features2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html>"]
+#[doc(alias = "vkGetPhysicalDeviceFeatures2")]
+pub unsafe fn get_physical_device_features2_into<
+    S: StructureChain<PhysicalDeviceFeatures2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_features: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_features2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_mut(p_features.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2KHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceFeatures2KHR")]
 pub unsafe fn get_physical_device_features2_khr<
@@ -3347,6 +3475,25 @@ pub unsafe fn get_physical_device_features2_khr<
     S::setup_cleanup(p_features.as_mut_ptr());
     p_features.assume_init()
 }
+#[doc = "Same as [get_physical_device_features2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2KHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceFeatures2KHR")]
+pub unsafe fn get_physical_device_features2_khr_into<
+    S: StructureChain<PhysicalDeviceFeatures2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_features: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_features2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_mut(p_features.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>"]
 #[doc(alias = "vkGetPhysicalDeviceProperties2")]
 pub unsafe fn get_physical_device_properties2<
@@ -3368,6 +3515,25 @@ pub unsafe fn get_physical_device_properties2<
     S::setup_cleanup(p_properties.as_mut_ptr());
     p_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_properties2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>"]
+#[doc(alias = "vkGetPhysicalDeviceProperties2")]
+pub unsafe fn get_physical_device_properties2_into<
+    S: StructureChain<PhysicalDeviceProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_properties2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2KHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceProperties2KHR")]
 pub unsafe fn get_physical_device_properties2_khr<
@@ -3389,6 +3555,25 @@ pub unsafe fn get_physical_device_properties2_khr<
     S::setup_cleanup(p_properties.as_mut_ptr());
     p_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_properties2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2KHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceProperties2KHR")]
+pub unsafe fn get_physical_device_properties2_khr_into<
+    S: StructureChain<PhysicalDeviceProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_properties2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>"]
 #[doc(alias = "vkGetPhysicalDeviceFormatProperties2")]
 pub unsafe fn get_physical_device_format_properties2<
@@ -3412,6 +3597,27 @@ pub unsafe fn get_physical_device_format_properties2<
     S::setup_cleanup(p_format_properties.as_mut_ptr());
     p_format_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_format_properties2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>"]
+#[doc(alias = "vkGetPhysicalDeviceFormatProperties2")]
+pub unsafe fn get_physical_device_format_properties2_into<
+    S: StructureChain<FormatProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    format: Format,
+    p_format_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_format_properties2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        format,
+        ptr::from_mut(p_format_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2KHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceFormatProperties2KHR")]
 pub unsafe fn get_physical_device_format_properties2_khr<
@@ -3435,6 +3641,27 @@ pub unsafe fn get_physical_device_format_properties2_khr<
     S::setup_cleanup(p_format_properties.as_mut_ptr());
     p_format_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_format_properties2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2KHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceFormatProperties2KHR")]
+pub unsafe fn get_physical_device_format_properties2_khr_into<
+    S: StructureChain<FormatProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    format: Format,
+    p_format_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_format_properties2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        format,
+        ptr::from_mut(p_format_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>"]
 #[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2")]
 pub unsafe fn get_physical_device_image_format_properties2<
@@ -3460,6 +3687,28 @@ pub unsafe fn get_physical_device_image_format_properties2<
         p_image_format_properties.assume_init()
     })
 }
+#[doc = "Same as [get_physical_device_image_format_properties2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>"]
+#[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2")]
+pub unsafe fn get_physical_device_image_format_properties2_into<
+    S: StructureChain<ImageFormatProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_image_format_info: &PhysicalDeviceImageFormatInfo2,
+    p_image_format_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_physical_device_image_format_properties2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_image_format_info),
+        ptr::from_mut(p_image_format_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2KHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2KHR")]
 pub unsafe fn get_physical_device_image_format_properties2_khr<
@@ -3485,6 +3734,28 @@ pub unsafe fn get_physical_device_image_format_properties2_khr<
         p_image_format_properties.assume_init()
     })
 }
+#[doc = "Same as [get_physical_device_image_format_properties2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2KHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2KHR")]
+pub unsafe fn get_physical_device_image_format_properties2_khr_into<
+    S: StructureChain<ImageFormatProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_image_format_info: &PhysicalDeviceImageFormatInfo2,
+    p_image_format_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_physical_device_image_format_properties2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_image_format_info),
+        ptr::from_mut(p_image_format_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html>"]
 #[doc(alias = "vkGetPhysicalDeviceQueueFamilyProperties2")]
 pub unsafe fn get_physical_device_queue_family_properties2<
@@ -3570,6 +3841,25 @@ pub unsafe fn get_physical_device_memory_properties2<
     S::setup_cleanup(p_memory_properties.as_mut_ptr());
     p_memory_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_memory_properties2], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html>"]
+#[doc(alias = "vkGetPhysicalDeviceMemoryProperties2")]
+pub unsafe fn get_physical_device_memory_properties2_into<
+    S: StructureChain<PhysicalDeviceMemoryProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_memory_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_memory_properties2
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_mut(p_memory_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2KHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceMemoryProperties2KHR")]
 pub unsafe fn get_physical_device_memory_properties2_khr<
@@ -3591,6 +3881,25 @@ pub unsafe fn get_physical_device_memory_properties2_khr<
     S::setup_cleanup(p_memory_properties.as_mut_ptr());
     p_memory_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_memory_properties2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2KHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceMemoryProperties2KHR")]
+pub unsafe fn get_physical_device_memory_properties2_khr_into<
+    S: StructureChain<PhysicalDeviceMemoryProperties2<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_memory_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_memory_properties2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_mut(p_memory_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html>"]
 #[doc(alias = "vkGetPhysicalDeviceSparseImageFormatProperties2")]
 pub unsafe fn get_physical_device_sparse_image_format_properties2<
@@ -3935,6 +4244,27 @@ pub unsafe fn get_physical_device_external_buffer_properties<
     S::setup_cleanup(p_external_buffer_properties.as_mut_ptr());
     p_external_buffer_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_external_buffer_properties], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferProperties.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalBufferProperties")]
+pub unsafe fn get_physical_device_external_buffer_properties_into<
+    S: StructureChain<ExternalBufferProperties<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_external_buffer_info: &PhysicalDeviceExternalBufferInfo,
+    p_external_buffer_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_external_buffer_properties
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_external_buffer_info),
+        ptr::from_mut(p_external_buffer_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferPropertiesKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceExternalBufferPropertiesKHR")]
 pub unsafe fn get_physical_device_external_buffer_properties_khr<
@@ -3958,8 +4288,29 @@ pub unsafe fn get_physical_device_external_buffer_properties_khr<
     S::setup_cleanup(p_external_buffer_properties.as_mut_ptr());
     p_external_buffer_properties.assume_init()
 }
-#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
-#[doc(alias = "vkGetPhysicalDeviceExternalFenceProperties")]
+#[doc = "Same as [get_physical_device_external_buffer_properties_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferPropertiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalBufferPropertiesKHR")]
+pub unsafe fn get_physical_device_external_buffer_properties_khr_into<
+    S: StructureChain<ExternalBufferProperties<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_external_buffer_info: &PhysicalDeviceExternalBufferInfo,
+    p_external_buffer_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_external_buffer_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_external_buffer_info),
+        ptr::from_mut(p_external_buffer_properties.as_mut()),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalFenceProperties")]
 pub unsafe fn get_physical_device_external_fence_properties<
     S: StructureChainOut<ExternalFenceProperties<'static>>,
 >(
@@ -3981,6 +4332,27 @@ pub unsafe fn get_physical_device_external_fence_properties<
     S::setup_cleanup(p_external_fence_properties.as_mut_ptr());
     p_external_fence_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_external_fence_properties], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalFenceProperties")]
+pub unsafe fn get_physical_device_external_fence_properties_into<
+    S: StructureChain<ExternalFenceProperties<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_external_fence_info: &PhysicalDeviceExternalFenceInfo,
+    p_external_fence_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_external_fence_properties
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_external_fence_info),
+        ptr::from_mut(p_external_fence_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFencePropertiesKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceExternalFencePropertiesKHR")]
 pub unsafe fn get_physical_device_external_fence_properties_khr<
@@ -4004,6 +4376,27 @@ pub unsafe fn get_physical_device_external_fence_properties_khr<
     S::setup_cleanup(p_external_fence_properties.as_mut_ptr());
     p_external_fence_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_external_fence_properties_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFencePropertiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalFencePropertiesKHR")]
+pub unsafe fn get_physical_device_external_fence_properties_khr_into<
+    S: StructureChain<ExternalFenceProperties<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_external_fence_info: &PhysicalDeviceExternalFenceInfo,
+    p_external_fence_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_external_fence_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_external_fence_info),
+        ptr::from_mut(p_external_fence_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>"]
 #[doc(alias = "vkGetPhysicalDeviceExternalSemaphoreProperties")]
 pub unsafe fn get_physical_device_external_semaphore_properties<
@@ -4027,6 +4420,27 @@ pub unsafe fn get_physical_device_external_semaphore_properties<
     S::setup_cleanup(p_external_semaphore_properties.as_mut_ptr());
     p_external_semaphore_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_external_semaphore_properties], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalSemaphoreProperties")]
+pub unsafe fn get_physical_device_external_semaphore_properties_into<
+    S: StructureChain<ExternalSemaphoreProperties<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_external_semaphore_info: &PhysicalDeviceExternalSemaphoreInfo,
+    p_external_semaphore_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_external_semaphore_properties
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_external_semaphore_info),
+        ptr::from_mut(p_external_semaphore_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphorePropertiesKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR")]
 pub unsafe fn get_physical_device_external_semaphore_properties_khr<
@@ -4050,6 +4464,27 @@ pub unsafe fn get_physical_device_external_semaphore_properties_khr<
     S::setup_cleanup(p_external_semaphore_properties.as_mut_ptr());
     p_external_semaphore_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_external_semaphore_properties_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphorePropertiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR")]
+pub unsafe fn get_physical_device_external_semaphore_properties_khr_into<
+    S: StructureChain<ExternalSemaphoreProperties<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_external_semaphore_info: &PhysicalDeviceExternalSemaphoreInfo,
+    p_external_semaphore_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_external_semaphore_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_external_semaphore_info),
+        ptr::from_mut(p_external_semaphore_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupport.html>"]
 #[doc(alias = "vkGetDescriptorSetLayoutSupport")]
 pub unsafe fn get_descriptor_set_layout_support<
@@ -4073,6 +4508,27 @@ pub unsafe fn get_descriptor_set_layout_support<
     S::setup_cleanup(p_support.as_mut_ptr());
     p_support.assume_init()
 }
+#[doc = "Same as [get_descriptor_set_layout_support], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupport.html>"]
+#[doc(alias = "vkGetDescriptorSetLayoutSupport")]
+pub unsafe fn get_descriptor_set_layout_support_into<
+    S: StructureChain<DescriptorSetLayoutSupport<'static>>,
+>(
+    device: &raw::Device,
+    p_create_info: &DescriptorSetLayoutCreateInfo,
+    p_support: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_descriptor_set_layout_support
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        ptr::from_mut(p_support.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupportKHR.html>"]
 #[doc(alias = "vkGetDescriptorSetLayoutSupportKHR")]
 pub unsafe fn get_descriptor_set_layout_support_khr<
@@ -4096,6 +4552,27 @@ pub unsafe fn get_descriptor_set_layout_support_khr<
     S::setup_cleanup(p_support.as_mut_ptr());
     p_support.assume_init()
 }
+#[doc = "Same as [get_descriptor_set_layout_support_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupportKHR.html>"]
+#[doc(alias = "vkGetDescriptorSetLayoutSupportKHR")]
+pub unsafe fn get_descriptor_set_layout_support_khr_into<
+    S: StructureChain<DescriptorSetLayoutSupport<'static>>,
+>(
+    device: &raw::Device,
+    p_create_info: &DescriptorSetLayoutCreateInfo,
+    p_support: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_descriptor_set_layout_support_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        ptr::from_mut(p_support.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCount.html>"]
 #[doc(alias = "vkCmdDrawIndirectCount")]
 pub unsafe fn cmd_draw_indirect_count(
@@ -4148,6 +4625,7 @@ pub unsafe fn cmd_draw_indirect_count_khr(
         stride,
     )
 }
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCountAMD.html>"]
 #[doc(alias = "vkCmdDrawIndirectCountAMD")]
 pub unsafe fn cmd_draw_indirect_count_amd(
@@ -4226,6 +4704,7 @@ pub unsafe fn cmd_draw_indexed_indirect_count_khr(
         stride,
     )
 }
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCountAMD.html>"]
 #[doc(alias = "vkCmdDrawIndexedIndirectCountAMD")]
 pub unsafe fn cmd_draw_indexed_indirect_count_amd(
@@ -5890,6 +6369,27 @@ pub unsafe fn get_device_buffer_memory_requirements<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_device_buffer_memory_requirements], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirements.html>"]
+#[doc(alias = "vkGetDeviceBufferMemoryRequirements")]
+pub unsafe fn get_device_buffer_memory_requirements_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &DeviceBufferMemoryRequirements,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_device_buffer_memory_requirements
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirementsKHR.html>"]
 #[doc(alias = "vkGetDeviceBufferMemoryRequirementsKHR")]
 pub unsafe fn get_device_buffer_memory_requirements_khr<
@@ -5913,6 +6413,27 @@ pub unsafe fn get_device_buffer_memory_requirements_khr<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_device_buffer_memory_requirements_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirementsKHR.html>"]
+#[doc(alias = "vkGetDeviceBufferMemoryRequirementsKHR")]
+pub unsafe fn get_device_buffer_memory_requirements_khr_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &DeviceBufferMemoryRequirements,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_device_buffer_memory_requirements_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirements.html>"]
 #[doc(alias = "vkGetDeviceImageMemoryRequirements")]
 pub unsafe fn get_device_image_memory_requirements<
@@ -5936,6 +6457,27 @@ pub unsafe fn get_device_image_memory_requirements<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_device_image_memory_requirements], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirements.html>"]
+#[doc(alias = "vkGetDeviceImageMemoryRequirements")]
+pub unsafe fn get_device_image_memory_requirements_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &DeviceImageMemoryRequirements,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_device_image_memory_requirements
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirementsKHR.html>"]
 #[doc(alias = "vkGetDeviceImageMemoryRequirementsKHR")]
 pub unsafe fn get_device_image_memory_requirements_khr<
@@ -5959,6 +6501,27 @@ pub unsafe fn get_device_image_memory_requirements_khr<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_device_image_memory_requirements_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirementsKHR.html>"]
+#[doc(alias = "vkGetDeviceImageMemoryRequirementsKHR")]
+pub unsafe fn get_device_image_memory_requirements_khr_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &DeviceImageMemoryRequirements,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_device_image_memory_requirements_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageSparseMemoryRequirements.html>"]
 #[doc(alias = "vkGetDeviceImageSparseMemoryRequirements")]
 pub unsafe fn get_device_image_sparse_memory_requirements<
@@ -6323,6 +6886,26 @@ pub unsafe fn get_device_group_present_capabilities_khr<
         p_device_group_present_capabilities.assume_init()
     })
 }
+#[doc = "Same as [get_device_group_present_capabilities_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupPresentCapabilitiesKHR.html>"]
+#[doc(alias = "vkGetDeviceGroupPresentCapabilitiesKHR")]
+pub unsafe fn get_device_group_present_capabilities_khr_into<
+    S: StructureChain<DeviceGroupPresentCapabilitiesKHR<'static>>,
+>(
+    device: &raw::Device,
+    p_device_group_present_capabilities: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_device_group_present_capabilities_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_mut(p_device_group_present_capabilities.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupSurfacePresentModesKHR.html>"]
 #[doc(alias = "vkGetDeviceGroupSurfacePresentModesKHR")]
 pub unsafe fn get_device_group_surface_present_modes_khr(
@@ -6671,6 +7254,14 @@ pub unsafe fn create_shared_swapchains_khr<'a, R: DynamicArray<SwapchainKHR>>(
         p_swapchains
     })
 }
+#[cfg(any(
+    feature = "platform-xlib",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXlibSurfaceKHR.html>"]
 #[doc(alias = "vkCreateXlibSurfaceKHR")]
 pub unsafe fn create_xlib_surface_khr(
@@ -6692,13 +7283,21 @@ pub unsafe fn create_xlib_surface_khr(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(
+    feature = "platform-xlib",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceXlibPresentationSupportKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceXlibPresentationSupportKHR")]
 pub unsafe fn get_physical_device_xlib_presentation_support_khr(
     physical_device: &raw::PhysicalDevice,
     queue_family_index: u32,
     dpy: &VoidPtr,
-    visual_id: VoidPtr,
+    visual_id: c_ulong,
     dispatcher: &CommandsDispatcher,
 ) -> bool {
     let vulkan_command = dispatcher
@@ -6713,6 +7312,14 @@ pub unsafe fn get_physical_device_xlib_presentation_support_khr(
     )
     .into()
 }
+#[cfg(any(
+    feature = "platform-xcb",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXcbSurfaceKHR.html>"]
 #[doc(alias = "vkCreateXcbSurfaceKHR")]
 pub unsafe fn create_xcb_surface_khr(
@@ -6734,13 +7341,21 @@ pub unsafe fn create_xcb_surface_khr(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(
+    feature = "platform-xcb",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceXcbPresentationSupportKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceXcbPresentationSupportKHR")]
 pub unsafe fn get_physical_device_xcb_presentation_support_khr(
     physical_device: &raw::PhysicalDevice,
     queue_family_index: u32,
     connection: &VoidPtr,
-    visualid: VoidPtr,
+    visualid: u32,
     dispatcher: &CommandsDispatcher,
 ) -> bool {
     let vulkan_command = dispatcher
@@ -6755,6 +7370,14 @@ pub unsafe fn get_physical_device_xcb_presentation_support_khr(
     )
     .into()
 }
+#[cfg(any(
+    feature = "platform-wayland",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWaylandSurfaceKHR.html>"]
 #[doc(alias = "vkCreateWaylandSurfaceKHR")]
 pub unsafe fn create_wayland_surface_khr(
@@ -6776,6 +7399,14 @@ pub unsafe fn create_wayland_surface_khr(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(
+    feature = "platform-wayland",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceWaylandPresentationSupportKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceWaylandPresentationSupportKHR")]
 pub unsafe fn get_physical_device_wayland_presentation_support_khr(
@@ -6795,6 +7426,7 @@ pub unsafe fn get_physical_device_wayland_presentation_support_khr(
     )
     .into()
 }
+#[cfg(any(feature = "platform-android", target_os = "android"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAndroidSurfaceKHR.html>"]
 #[doc(alias = "vkCreateAndroidSurfaceKHR")]
 pub unsafe fn create_android_surface_khr(
@@ -6816,6 +7448,7 @@ pub unsafe fn create_android_surface_khr(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWin32SurfaceKHR.html>"]
 #[doc(alias = "vkCreateWin32SurfaceKHR")]
 pub unsafe fn create_win32_surface_khr(
@@ -6837,6 +7470,7 @@ pub unsafe fn create_win32_surface_khr(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceWin32PresentationSupportKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceWin32PresentationSupportKHR")]
 pub unsafe fn get_physical_device_win32_presentation_support_khr(
@@ -6987,61 +7621,381 @@ pub unsafe fn cmd_debug_marker_insert_ext(
         ptr::from_ref(p_marker_info),
     )
 }
-#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html>"]
-#[doc(alias = "vkCmdBindTransformFeedbackBuffersEXT")]
-pub unsafe fn cmd_bind_transform_feedback_buffers_ext<'a, V3: Alias<raw::Buffer> + 'a>(
-    command_buffer: &raw::CommandBuffer,
-    first_binding: u32,
-    p_buffers: impl AsSlice<'a, V3>,
-    p_offsets: impl AsSlice<'a, DeviceSize>,
-    p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoCapabilitiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceVideoCapabilitiesKHR")]
+pub unsafe fn get_physical_device_video_capabilities_khr<
+    S: StructureChainOut<VideoCapabilitiesKHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_video_profile: &VideoProfileInfoKHR,
     dispatcher: &CommandsDispatcher,
-) {
+) -> Result<S> {
     let vulkan_command = dispatcher
-        .cmd_bind_transform_feedback_buffers_ext
+        .get_physical_device_video_capabilities_khr
         .get()
         .expect("Vulkan command not loaded.");
-    vulkan_command(
-        Some(unsafe { command_buffer.clone() }),
-        first_binding,
-        p_offsets.as_slice().len() as _,
-        p_buffers.as_slice().as_ptr().cast(),
-        p_offsets.as_slice().as_ptr().cast(),
-        p_sizes
-            .map(|p| p.as_slice().as_ptr().cast())
-            .unwrap_or(ptr::null()),
-    )
+    let mut p_capabilities = MaybeUninit::uninit();
+    S::setup_uninit(&mut p_capabilities);
+    let vk_status = vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_video_profile),
+        S::get_uninit_head_ptr(p_capabilities.as_mut_ptr()),
+    );
+    vk_status.map_success(|| {
+        S::setup_cleanup(p_capabilities.as_mut_ptr());
+        p_capabilities.assume_init()
+    })
 }
-#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginTransformFeedbackEXT.html>"]
-#[doc(alias = "vkCmdBeginTransformFeedbackEXT")]
-pub unsafe fn cmd_begin_transform_feedback_ext<'a, V3: Alias<raw::Buffer> + 'a>(
-    command_buffer: &raw::CommandBuffer,
-    first_counter_buffer: u32,
-    p_counter_buffers: impl AsSlice<'a, V3>,
-    p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
+#[doc = "Same as [get_physical_device_video_capabilities_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoCapabilitiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceVideoCapabilitiesKHR")]
+pub unsafe fn get_physical_device_video_capabilities_khr_into<
+    S: StructureChain<VideoCapabilitiesKHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_video_profile: &VideoProfileInfoKHR,
+    p_capabilities: &mut S,
     dispatcher: &CommandsDispatcher,
-) {
+) -> Result<()> {
     let vulkan_command = dispatcher
-        .cmd_begin_transform_feedback_ext
+        .get_physical_device_video_capabilities_khr
         .get()
         .expect("Vulkan command not loaded.");
     vulkan_command(
-        Some(unsafe { command_buffer.clone() }),
-        first_counter_buffer,
-        p_counter_buffers.as_slice().len() as _,
-        p_counter_buffers.as_slice().as_ptr().cast(),
-        p_counter_buffer_offsets
-            .map(|p| p.as_slice().as_ptr().cast())
-            .unwrap_or(ptr::null()),
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_video_profile),
+        ptr::from_mut(p_capabilities.as_mut()),
     )
+    .map_success(|| ())
 }
-#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndTransformFeedbackEXT.html>"]
-#[doc(alias = "vkCmdEndTransformFeedbackEXT")]
-pub unsafe fn cmd_end_transform_feedback_ext<'a, V3: Alias<raw::Buffer> + 'a>(
-    command_buffer: &raw::CommandBuffer,
-    first_counter_buffer: u32,
-    p_counter_buffers: impl AsSlice<'a, V3>,
-    p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceVideoFormatPropertiesKHR")]
+pub unsafe fn get_physical_device_video_format_properties_khr<
+    R: DynamicArray<VideoFormatPropertiesKHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_video_format_info: &PhysicalDeviceVideoFormatInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) -> Result<R> {
+    let vulkan_command = dispatcher
+        .get_physical_device_video_format_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut vk_len = MaybeUninit::uninit();
+    let p_video_format_property_count = vk_len.as_mut_ptr();
+    let p_video_format_properties = ptr::null_mut();
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_video_format_info),
+        p_video_format_property_count,
+        p_video_format_properties,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_video_format_property_count = ptr::from_mut(&mut vk_len);
+    let mut p_video_format_properties = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { physical_device.clone() }),
+            ptr::from_ref(p_video_format_info),
+            p_video_format_property_count,
+            p_video_format_properties,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_video_format_property_count = ptr::from_mut(&mut vk_len);
+        p_video_format_properties = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionKHR.html>"]
+#[doc(alias = "vkCreateVideoSessionKHR")]
+pub unsafe fn create_video_session_khr(
+    device: &raw::Device,
+    p_create_info: &VideoSessionCreateInfoKHR,
+    p_allocator: Option<&AllocationCallbacks>,
+    dispatcher: &CommandsDispatcher,
+) -> Result<VideoSessionKHR> {
+    let vulkan_command = dispatcher
+        .create_video_session_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_video_session = MaybeUninit::uninit();
+    let vk_status = vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
+        p_video_session.as_mut_ptr(),
+    );
+    vk_status.map_success(|| p_video_session.assume_init())
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionKHR.html>"]
+#[doc(alias = "vkDestroyVideoSessionKHR")]
+pub unsafe fn destroy_video_session_khr(
+    device: &raw::Device,
+    video_session: Option<&raw::VideoSessionKHR>,
+    p_allocator: Option<&AllocationCallbacks>,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .destroy_video_session_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        video_session.map(|v| unsafe { v.clone() }),
+        p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetVideoSessionMemoryRequirementsKHR.html>"]
+#[doc(alias = "vkGetVideoSessionMemoryRequirementsKHR")]
+pub unsafe fn get_video_session_memory_requirements_khr<
+    R: DynamicArray<VideoSessionMemoryRequirementsKHR<'static>>,
+>(
+    device: &raw::Device,
+    video_session: &raw::VideoSessionKHR,
+    dispatcher: &CommandsDispatcher,
+) -> Result<R> {
+    let vulkan_command = dispatcher
+        .get_video_session_memory_requirements_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut vk_len = MaybeUninit::uninit();
+    let p_memory_requirements_count = vk_len.as_mut_ptr();
+    let p_memory_requirements = ptr::null_mut();
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { video_session.clone() }),
+        p_memory_requirements_count,
+        p_memory_requirements,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_memory_requirements_count = ptr::from_mut(&mut vk_len);
+    let mut p_memory_requirements = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            Some(unsafe { video_session.clone() }),
+            p_memory_requirements_count,
+            p_memory_requirements,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_memory_requirements_count = ptr::from_mut(&mut vk_len);
+        p_memory_requirements = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindVideoSessionMemoryKHR.html>"]
+#[doc(alias = "vkBindVideoSessionMemoryKHR")]
+pub unsafe fn bind_video_session_memory_khr<'a>(
+    device: &raw::Device,
+    video_session: &raw::VideoSessionKHR,
+    p_bind_session_memory_infos: impl AsSlice<'a, BindVideoSessionMemoryInfoKHR<'a>>,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .bind_video_session_memory_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { video_session.clone() }),
+        p_bind_session_memory_infos.as_slice().len() as _,
+        p_bind_session_memory_infos.as_slice().as_ptr().cast(),
+    )
+    .map_success(|| ())
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionParametersKHR.html>"]
+#[doc(alias = "vkCreateVideoSessionParametersKHR")]
+pub unsafe fn create_video_session_parameters_khr(
+    device: &raw::Device,
+    p_create_info: &VideoSessionParametersCreateInfoKHR,
+    p_allocator: Option<&AllocationCallbacks>,
+    dispatcher: &CommandsDispatcher,
+) -> Result<VideoSessionParametersKHR> {
+    let vulkan_command = dispatcher
+        .create_video_session_parameters_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_video_session_parameters = MaybeUninit::uninit();
+    let vk_status = vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
+        p_video_session_parameters.as_mut_ptr(),
+    );
+    vk_status.map_success(|| p_video_session_parameters.assume_init())
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateVideoSessionParametersKHR.html>"]
+#[doc(alias = "vkUpdateVideoSessionParametersKHR")]
+pub unsafe fn update_video_session_parameters_khr(
+    device: &raw::Device,
+    video_session_parameters: &raw::VideoSessionParametersKHR,
+    p_update_info: &VideoSessionParametersUpdateInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .update_video_session_parameters_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { video_session_parameters.clone() }),
+        ptr::from_ref(p_update_info),
+    )
+    .map_success(|| ())
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionParametersKHR.html>"]
+#[doc(alias = "vkDestroyVideoSessionParametersKHR")]
+pub unsafe fn destroy_video_session_parameters_khr(
+    device: &raw::Device,
+    video_session_parameters: Option<&raw::VideoSessionParametersKHR>,
+    p_allocator: Option<&AllocationCallbacks>,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .destroy_video_session_parameters_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        video_session_parameters.map(|v| unsafe { v.clone() }),
+        p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginVideoCodingKHR.html>"]
+#[doc(alias = "vkCmdBeginVideoCodingKHR")]
+pub unsafe fn cmd_begin_video_coding_khr(
+    command_buffer: &raw::CommandBuffer,
+    p_begin_info: &VideoBeginCodingInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_begin_video_coding_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        ptr::from_ref(p_begin_info),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndVideoCodingKHR.html>"]
+#[doc(alias = "vkCmdEndVideoCodingKHR")]
+pub unsafe fn cmd_end_video_coding_khr(
+    command_buffer: &raw::CommandBuffer,
+    p_end_coding_info: &VideoEndCodingInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_end_video_coding_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        ptr::from_ref(p_end_coding_info),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdControlVideoCodingKHR.html>"]
+#[doc(alias = "vkCmdControlVideoCodingKHR")]
+pub unsafe fn cmd_control_video_coding_khr(
+    command_buffer: &raw::CommandBuffer,
+    p_coding_control_info: &VideoCodingControlInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_control_video_coding_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        ptr::from_ref(p_coding_control_info),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecodeVideoKHR.html>"]
+#[doc(alias = "vkCmdDecodeVideoKHR")]
+pub unsafe fn cmd_decode_video_khr(
+    command_buffer: &raw::CommandBuffer,
+    p_decode_info: &VideoDecodeInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_decode_video_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        ptr::from_ref(p_decode_info),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html>"]
+#[doc(alias = "vkCmdBindTransformFeedbackBuffersEXT")]
+pub unsafe fn cmd_bind_transform_feedback_buffers_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+    command_buffer: &raw::CommandBuffer,
+    first_binding: u32,
+    p_buffers: impl AsSlice<'a, V3>,
+    p_offsets: impl AsSlice<'a, DeviceSize>,
+    p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_bind_transform_feedback_buffers_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        first_binding,
+        p_offsets.as_slice().len() as _,
+        p_buffers.as_slice().as_ptr().cast(),
+        p_offsets.as_slice().as_ptr().cast(),
+        p_sizes
+            .map(|p| p.as_slice().as_ptr().cast())
+            .unwrap_or(ptr::null()),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginTransformFeedbackEXT.html>"]
+#[doc(alias = "vkCmdBeginTransformFeedbackEXT")]
+pub unsafe fn cmd_begin_transform_feedback_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+    command_buffer: &raw::CommandBuffer,
+    first_counter_buffer: u32,
+    p_counter_buffers: impl AsSlice<'a, V3>,
+    p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_begin_transform_feedback_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        first_counter_buffer,
+        p_counter_buffers.as_slice().len() as _,
+        p_counter_buffers.as_slice().as_ptr().cast(),
+        p_counter_buffer_offsets
+            .map(|p| p.as_slice().as_ptr().cast())
+            .unwrap_or(ptr::null()),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndTransformFeedbackEXT.html>"]
+#[doc(alias = "vkCmdEndTransformFeedbackEXT")]
+pub unsafe fn cmd_end_transform_feedback_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+    command_buffer: &raw::CommandBuffer,
+    first_counter_buffer: u32,
+    p_counter_buffers: impl AsSlice<'a, V3>,
+    p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
     dispatcher: &CommandsDispatcher,
 ) {
     let vulkan_command = dispatcher
@@ -7126,6 +8080,7 @@ pub unsafe fn cmd_draw_indirect_byte_count_ext(
         vertex_stride,
     )
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuModuleNVX.html>"]
 #[doc(alias = "vkCreateCuModuleNVX")]
 pub unsafe fn create_cu_module_nvx(
@@ -7147,6 +8102,7 @@ pub unsafe fn create_cu_module_nvx(
     );
     vk_status.map_success(|| p_module.assume_init())
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuFunctionNVX.html>"]
 #[doc(alias = "vkCreateCuFunctionNVX")]
 pub unsafe fn create_cu_function_nvx(
@@ -7168,6 +8124,7 @@ pub unsafe fn create_cu_function_nvx(
     );
     vk_status.map_success(|| p_function.assume_init())
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuModuleNVX.html>"]
 #[doc(alias = "vkDestroyCuModuleNVX")]
 pub unsafe fn destroy_cu_module_nvx(
@@ -7186,6 +8143,7 @@ pub unsafe fn destroy_cu_module_nvx(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuFunctionNVX.html>"]
 #[doc(alias = "vkDestroyCuFunctionNVX")]
 pub unsafe fn destroy_cu_function_nvx(
@@ -7204,6 +8162,7 @@ pub unsafe fn destroy_cu_function_nvx(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCuLaunchKernelNVX.html>"]
 #[doc(alias = "vkCmdCuLaunchKernelNVX")]
 pub unsafe fn cmd_cu_launch_kernel_nvx(
@@ -7220,6 +8179,7 @@ pub unsafe fn cmd_cu_launch_kernel_nvx(
         ptr::from_ref(p_launch_info),
     )
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewHandleNVX.html>"]
 #[doc(alias = "vkGetImageViewHandleNVX")]
 pub unsafe fn get_image_view_handle_nvx(
@@ -7233,6 +8193,7 @@ pub unsafe fn get_image_view_handle_nvx(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { device.clone() }), ptr::from_ref(p_info))
 }
+#[cfg(feature = "vendor-nvx")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewAddressNVX.html>"]
 #[doc(alias = "vkGetImageViewAddressNVX")]
 pub unsafe fn get_image_view_address_nvx<
@@ -7258,31 +8219,81 @@ pub unsafe fn get_image_view_address_nvx<
         p_properties.assume_init()
     })
 }
+#[cfg(feature = "vendor-nvx")]
+#[doc = "Same as [get_image_view_address_nvx], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewAddressNVX.html>"]
+#[doc(alias = "vkGetImageViewAddressNVX")]
+pub unsafe fn get_image_view_address_nvx_into<
+    S: StructureChain<ImageViewAddressPropertiesNVX<'static>>,
+>(
+    device: &raw::Device,
+    image_view: &raw::ImageView,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_image_view_address_nvx
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { image_view.clone() }),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderInfoAMD.html>"]
 #[doc(alias = "vkGetShaderInfoAMD")]
-pub unsafe fn get_shader_info_amd(
+pub unsafe fn get_shader_info_amd<R: DynamicArray<u8>>(
     device: &raw::Device,
     pipeline: &raw::Pipeline,
     shader_stage: ShaderStageFlags,
     info_type: ShaderInfoTypeAMD,
-    p_info: VoidPtr,
     dispatcher: &CommandsDispatcher,
-) -> Result<usize> {
+) -> Result<R> {
     let vulkan_command = dispatcher
         .get_shader_info_amd
         .get()
         .expect("Vulkan command not loaded.");
-    let mut p_info_size = MaybeUninit::uninit();
-    let vk_status = vulkan_command(
+    let mut vk_len = MaybeUninit::uninit();
+    let p_info_size = vk_len.as_mut_ptr();
+    let p_info = ptr::null_mut();
+    vulkan_command(
         Some(unsafe { device.clone() }),
         Some(unsafe { pipeline.clone() }),
         shader_stage,
         info_type,
-        p_info_size.as_mut_ptr(),
-        p_info,
-    );
-    vk_status.map_success(|| p_info_size.assume_init())
+        p_info_size,
+        p_info as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_info_size = ptr::from_mut(&mut vk_len);
+    let mut p_info = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            Some(unsafe { pipeline.clone() }),
+            shader_stage,
+            info_type,
+            p_info_size,
+            p_info as VoidPtr,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_info_size = ptr::from_mut(&mut vk_len);
+        p_info = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
 }
+#[cfg(feature = "platform-ggp")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateStreamDescriptorSurfaceGGP.html>"]
 #[doc(alias = "vkCreateStreamDescriptorSurfaceGGP")]
 pub unsafe fn create_stream_descriptor_surface_ggp(
@@ -7304,6 +8315,7 @@ pub unsafe fn create_stream_descriptor_surface_ggp(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalImageFormatPropertiesNV.html>"]
 #[doc(alias = "vkGetPhysicalDeviceExternalImageFormatPropertiesNV")]
 pub unsafe fn get_physical_device_external_image_format_properties_nv(
@@ -7333,6 +8345,10 @@ pub unsafe fn get_physical_device_external_image_format_properties_nv(
     );
     vk_status.map_success(|| p_external_image_format_properties.assume_init())
 }
+#[cfg(all(
+    any(feature = "platform-win32", target_os = "windows"),
+    feature = "vendor-nv"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleNV.html>"]
 #[doc(alias = "vkGetMemoryWin32HandleNV")]
 pub unsafe fn get_memory_win32_handle_nv(
@@ -7354,6 +8370,7 @@ pub unsafe fn get_memory_win32_handle_nv(
     );
     vk_status.map_success(|| p_handle.assume_init())
 }
+#[cfg(feature = "platform-vi")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateViSurfaceNN.html>"]
 #[doc(alias = "vkCreateViSurfaceNN")]
 pub unsafe fn create_vi_surface_nn(
@@ -7375,6 +8392,7 @@ pub unsafe fn create_vi_surface_nn(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleKHR.html>"]
 #[doc(alias = "vkGetMemoryWin32HandleKHR")]
 pub unsafe fn get_memory_win32_handle_khr(
@@ -7394,6 +8412,7 @@ pub unsafe fn get_memory_win32_handle_khr(
     );
     vk_status.map_success(|| p_handle.assume_init())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandlePropertiesKHR.html>"]
 #[doc(alias = "vkGetMemoryWin32HandlePropertiesKHR")]
 pub unsafe fn get_memory_win32_handle_properties_khr<
@@ -7421,6 +8440,31 @@ pub unsafe fn get_memory_win32_handle_properties_khr<
         p_memory_win32_handle_properties.assume_init()
     })
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
+#[doc = "Same as [get_memory_win32_handle_properties_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandlePropertiesKHR.html>"]
+#[doc(alias = "vkGetMemoryWin32HandlePropertiesKHR")]
+pub unsafe fn get_memory_win32_handle_properties_khr_into<
+    S: StructureChain<MemoryWin32HandlePropertiesKHR<'static>>,
+>(
+    device: &raw::Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    handle: VoidPtr,
+    p_memory_win32_handle_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_memory_win32_handle_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        handle_type,
+        handle,
+        ptr::from_mut(p_memory_win32_handle_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdKHR.html>"]
 #[doc(alias = "vkGetMemoryFdKHR")]
 pub unsafe fn get_memory_fd_khr(
@@ -7465,6 +8509,31 @@ pub unsafe fn get_memory_fd_properties_khr<S: StructureChainOut<MemoryFdProperti
         p_memory_fd_properties.assume_init()
     })
 }
+#[doc = "Same as [get_memory_fd_properties_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdPropertiesKHR.html>"]
+#[doc(alias = "vkGetMemoryFdPropertiesKHR")]
+pub unsafe fn get_memory_fd_properties_khr_into<
+    S: StructureChain<MemoryFdPropertiesKHR<'static>>,
+>(
+    device: &raw::Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_memory_fd_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        handle_type,
+        fd,
+        ptr::from_mut(p_memory_fd_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreWin32HandleKHR.html>"]
 #[doc(alias = "vkImportSemaphoreWin32HandleKHR")]
 pub unsafe fn import_semaphore_win32_handle_khr(
@@ -7482,6 +8551,7 @@ pub unsafe fn import_semaphore_win32_handle_khr(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreWin32HandleKHR.html>"]
 #[doc(alias = "vkGetSemaphoreWin32HandleKHR")]
 pub unsafe fn get_semaphore_win32_handle_khr(
@@ -7610,6 +8680,7 @@ pub unsafe fn cmd_end_conditional_rendering_ext(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { command_buffer.clone() }))
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingNV.html>"]
 #[doc(alias = "vkCmdSetViewportWScalingNV")]
 pub unsafe fn cmd_set_viewport_wscaling_nv<'a>(
@@ -7646,6 +8717,14 @@ pub unsafe fn release_display_ext(
     )
     .map_success(|| ())
 }
+#[cfg(any(
+    feature = "platform-xlib-xrandr",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireXlibDisplayEXT.html>"]
 #[doc(alias = "vkAcquireXlibDisplayEXT")]
 pub unsafe fn acquire_xlib_display_ext(
@@ -7665,12 +8744,20 @@ pub unsafe fn acquire_xlib_display_ext(
     )
     .map_success(|| ())
 }
+#[cfg(any(
+    feature = "platform-xlib-xrandr",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRandROutputDisplayEXT.html>"]
 #[doc(alias = "vkGetRandROutputDisplayEXT")]
 pub unsafe fn get_rand_routput_display_ext(
     physical_device: &raw::PhysicalDevice,
     dpy: &VoidPtr,
-    rr_output: VoidPtr,
+    rr_output: c_ulong,
     dispatcher: &CommandsDispatcher,
 ) -> Result<DisplayKHR> {
     let vulkan_command = dispatcher
@@ -7711,6 +8798,28 @@ pub unsafe fn get_physical_device_surface_capabilities2_ext<
         p_surface_capabilities.assume_init()
     })
 }
+#[doc = "Same as [get_physical_device_surface_capabilities2_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2EXT.html>"]
+#[doc(alias = "vkGetPhysicalDeviceSurfaceCapabilities2EXT")]
+pub unsafe fn get_physical_device_surface_capabilities2_ext_into<
+    S: StructureChain<SurfaceCapabilities2EXT<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    surface: &raw::SurfaceKHR,
+    p_surface_capabilities: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_physical_device_surface_capabilities2_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        Some(unsafe { surface.clone() }),
+        ptr::from_mut(p_surface_capabilities.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDisplayPowerControlEXT.html>"]
 #[doc(alias = "vkDisplayPowerControlEXT")]
 pub unsafe fn display_power_control_ext(
@@ -7795,6 +8904,7 @@ pub unsafe fn get_swapchain_counter_ext(
     );
     vk_status.map_success(|| p_counter_value.assume_init())
 }
+#[cfg(feature = "vendor-google")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRefreshCycleDurationGOOGLE.html>"]
 #[doc(alias = "vkGetRefreshCycleDurationGOOGLE")]
 pub unsafe fn get_refresh_cycle_duration_google(
@@ -7814,6 +8924,7 @@ pub unsafe fn get_refresh_cycle_duration_google(
     );
     vk_status.map_success(|| p_display_timing_properties.assume_init())
 }
+#[cfg(feature = "vendor-google")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPastPresentationTimingGOOGLE.html>"]
 #[doc(alias = "vkGetPastPresentationTimingGOOGLE")]
 pub unsafe fn get_past_presentation_timing_google<R: DynamicArray<PastPresentationTimingGOOGLE>>(
@@ -7945,6 +9056,7 @@ pub unsafe fn get_swapchain_status_khr(
     )
     .into_result()
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportFenceWin32HandleKHR.html>"]
 #[doc(alias = "vkImportFenceWin32HandleKHR")]
 pub unsafe fn import_fence_win32_handle_khr(
@@ -7962,6 +9074,7 @@ pub unsafe fn import_fence_win32_handle_khr(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceWin32HandleKHR.html>"]
 #[doc(alias = "vkGetFenceWin32HandleKHR")]
 pub unsafe fn get_fence_win32_handle_khr(
@@ -8017,6 +9130,68 @@ pub unsafe fn get_fence_fd_khr(
     );
     vk_status.map_success(|| p_fd.assume_init())
 }
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR.html>"]
+#[doc(alias = "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR")]
+pub unsafe fn enumerate_physical_device_queue_family_performance_query_counters_khr<
+    R1: DynamicArray<PerformanceCounterKHR<'static>>,
+    R2: DynamicArray<PerformanceCounterDescriptionKHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    queue_family_index: u32,
+    dispatcher: &CommandsDispatcher,
+) -> Result<(R1, R2)> {
+    let vulkan_command = dispatcher
+        .enumerate_physical_device_queue_family_performance_query_counters_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut vk_len = MaybeUninit::uninit();
+    let p_counter_count = vk_len.as_mut_ptr();
+    let p_counters = ptr::null_mut();
+    let p_counter_descriptions = ptr::null_mut();
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        queue_family_index,
+        p_counter_count,
+        p_counters,
+        p_counter_descriptions,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec_p_counters = R1::create_with_capacity(vk_len as _);
+    let mut vk_vec_p_counter_descriptions = R2::create_with_capacity(vk_len as _);
+    let mut p_counter_count = ptr::from_mut(&mut vk_len);
+    let mut p_counters = vk_vec_p_counters.get_content_mut_ptr();
+    let mut p_counter_descriptions = vk_vec_p_counter_descriptions.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { physical_device.clone() }),
+            queue_family_index,
+            p_counter_count,
+            p_counters,
+            p_counter_descriptions,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec_p_counters.update_with_capacity(vk_len as _);
+        vk_vec_p_counter_descriptions.update_with_capacity(vk_len as _);
+        p_counter_count = ptr::from_mut(&mut vk_len);
+        p_counters = vk_vec_p_counters.get_content_mut_ptr();
+        p_counter_descriptions = vk_vec_p_counter_descriptions.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        (
+            {
+                vk_vec_p_counters.resize_with_len(vk_len as _);
+                vk_vec_p_counters
+            },
+            {
+                vk_vec_p_counter_descriptions.resize_with_len(vk_len as _);
+                vk_vec_p_counter_descriptions
+            },
+        )
+    })
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR")]
 pub unsafe fn get_physical_device_queue_family_performance_query_passes_khr(
@@ -8083,6 +9258,28 @@ pub unsafe fn get_physical_device_surface_capabilities2_khr<
         p_surface_capabilities.assume_init()
     })
 }
+#[doc = "Same as [get_physical_device_surface_capabilities2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2KHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceSurfaceCapabilities2KHR")]
+pub unsafe fn get_physical_device_surface_capabilities2_khr_into<
+    S: StructureChain<SurfaceCapabilities2KHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_surface_info: &PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_capabilities: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_physical_device_surface_capabilities2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_surface_info),
+        ptr::from_mut(p_surface_capabilities.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormats2KHR.html>"]
 #[doc(alias = "vkGetPhysicalDeviceSurfaceFormats2KHR")]
 pub unsafe fn get_physical_device_surface_formats2_khr<
@@ -8286,6 +9483,29 @@ pub unsafe fn get_display_plane_capabilities2_khr<
         p_capabilities.assume_init()
     })
 }
+#[doc = "Same as [get_display_plane_capabilities2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneCapabilities2KHR.html>"]
+#[doc(alias = "vkGetDisplayPlaneCapabilities2KHR")]
+pub unsafe fn get_display_plane_capabilities2_khr_into<
+    S: StructureChain<DisplayPlaneCapabilities2KHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_display_plane_info: &DisplayPlaneInfo2KHR,
+    p_capabilities: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_display_plane_capabilities2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_display_plane_info),
+        ptr::from_mut(p_capabilities.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(any(feature = "platform-ios", target_os = "ios"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIOSSurfaceMVK.html>"]
 #[doc(alias = "vkCreateIOSSurfaceMVK")]
 pub unsafe fn create_iossurface_mvk(
@@ -8307,6 +9527,7 @@ pub unsafe fn create_iossurface_mvk(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(feature = "platform-macos", target_os = "macos"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMacOSSurfaceMVK.html>"]
 #[doc(alias = "vkCreateMacOSSurfaceMVK")]
 pub unsafe fn create_mac_ossurface_mvk(
@@ -8492,6 +9713,7 @@ pub unsafe fn submit_debug_utils_message_ext(
         ptr::from_ref(p_callback_data),
     )
 }
+#[cfg(any(feature = "platform-android", target_os = "android"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAndroidHardwareBufferPropertiesANDROID.html>"]
 #[doc(alias = "vkGetAndroidHardwareBufferPropertiesANDROID")]
 pub unsafe fn get_android_hardware_buffer_properties_android<
@@ -8517,6 +9739,30 @@ pub unsafe fn get_android_hardware_buffer_properties_android<
         p_properties.assume_init()
     })
 }
+#[cfg(any(feature = "platform-android", target_os = "android"))]
+#[doc = "Same as [get_android_hardware_buffer_properties_android], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAndroidHardwareBufferPropertiesANDROID.html>"]
+#[doc(alias = "vkGetAndroidHardwareBufferPropertiesANDROID")]
+pub unsafe fn get_android_hardware_buffer_properties_android_into<
+    S: StructureChain<AndroidHardwareBufferPropertiesANDROID<'static>>,
+>(
+    device: &raw::Device,
+    buffer: &AHardwareBuffer,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_android_hardware_buffer_properties_android
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(buffer),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(any(feature = "platform-android", target_os = "android"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryAndroidHardwareBufferANDROID.html>"]
 #[doc(alias = "vkGetMemoryAndroidHardwareBufferANDROID")]
 pub unsafe fn get_memory_android_hardware_buffer_android(
@@ -8536,6 +9782,7 @@ pub unsafe fn get_memory_android_hardware_buffer_android(
     )
     .map_success(|| ())
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateExecutionGraphPipelinesAMDX.html>"]
 #[doc(alias = "vkCreateExecutionGraphPipelinesAMDX")]
 pub unsafe fn create_execution_graph_pipelines_amdx<'a, R: DynamicArray<Pipeline>>(
@@ -8563,6 +9810,7 @@ pub unsafe fn create_execution_graph_pipelines_amdx<'a, R: DynamicArray<Pipeline
         p_pipelines
     })
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetExecutionGraphPipelineScratchSizeAMDX.html>"]
 #[doc(alias = "vkGetExecutionGraphPipelineScratchSizeAMDX")]
 pub unsafe fn get_execution_graph_pipeline_scratch_size_amdx<
@@ -8588,6 +9836,30 @@ pub unsafe fn get_execution_graph_pipeline_scratch_size_amdx<
         p_size_info.assume_init()
     })
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+#[doc = "Same as [get_execution_graph_pipeline_scratch_size_amdx], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetExecutionGraphPipelineScratchSizeAMDX.html>"]
+#[doc(alias = "vkGetExecutionGraphPipelineScratchSizeAMDX")]
+pub unsafe fn get_execution_graph_pipeline_scratch_size_amdx_into<
+    S: StructureChain<ExecutionGraphPipelineScratchSizeAMDX<'static>>,
+>(
+    device: &raw::Device,
+    execution_graph: &raw::Pipeline,
+    p_size_info: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_execution_graph_pipeline_scratch_size_amdx
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { execution_graph.clone() }),
+        ptr::from_mut(p_size_info.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetExecutionGraphPipelineNodeIndexAMDX.html>"]
 #[doc(alias = "vkGetExecutionGraphPipelineNodeIndexAMDX")]
 pub unsafe fn get_execution_graph_pipeline_node_index_amdx(
@@ -8609,6 +9881,7 @@ pub unsafe fn get_execution_graph_pipeline_node_index_amdx(
     );
     vk_status.map_success(|| p_node_index.assume_init())
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdInitializeGraphScratchMemoryAMDX.html>"]
 #[doc(alias = "vkCmdInitializeGraphScratchMemoryAMDX")]
 pub unsafe fn cmd_initialize_graph_scratch_memory_amdx(
@@ -8622,6 +9895,7 @@ pub unsafe fn cmd_initialize_graph_scratch_memory_amdx(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { command_buffer.clone() }), scratch)
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphAMDX.html>"]
 #[doc(alias = "vkCmdDispatchGraphAMDX")]
 pub unsafe fn cmd_dispatch_graph_amdx(
@@ -8640,6 +9914,7 @@ pub unsafe fn cmd_dispatch_graph_amdx(
         ptr::from_ref(p_count_info),
     )
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphIndirectAMDX.html>"]
 #[doc(alias = "vkCmdDispatchGraphIndirectAMDX")]
 pub unsafe fn cmd_dispatch_graph_indirect_amdx(
@@ -8658,6 +9933,7 @@ pub unsafe fn cmd_dispatch_graph_indirect_amdx(
         ptr::from_ref(p_count_info),
     )
 }
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphIndirectCountAMDX.html>"]
 #[doc(alias = "vkCmdDispatchGraphIndirectCountAMDX")]
 pub unsafe fn cmd_dispatch_graph_indirect_count_amdx(
@@ -8711,6 +9987,27 @@ pub unsafe fn get_physical_device_multisample_properties_ext<
     S::setup_cleanup(p_multisample_properties.as_mut_ptr());
     p_multisample_properties.assume_init()
 }
+#[doc = "Same as [get_physical_device_multisample_properties_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMultisamplePropertiesEXT.html>"]
+#[doc(alias = "vkGetPhysicalDeviceMultisamplePropertiesEXT")]
+pub unsafe fn get_physical_device_multisample_properties_ext_into<
+    S: StructureChain<MultisamplePropertiesEXT<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    samples: SampleCountFlags,
+    p_multisample_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_physical_device_multisample_properties_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        samples,
+        ptr::from_mut(p_multisample_properties.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureKHR.html>"]
 #[doc(alias = "vkCreateAccelerationStructureKHR")]
 pub unsafe fn create_acceleration_structure_khr(
@@ -9036,6 +10333,34 @@ pub unsafe fn get_acceleration_structure_build_sizes_khr<
     S::setup_cleanup(p_size_info.as_mut_ptr());
     p_size_info.assume_init()
 }
+#[doc = "Same as [get_acceleration_structure_build_sizes_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureBuildSizesKHR.html>"]
+#[doc(alias = "vkGetAccelerationStructureBuildSizesKHR")]
+pub unsafe fn get_acceleration_structure_build_sizes_khr_into<
+    'a,
+    S: StructureChain<AccelerationStructureBuildSizesInfoKHR<'static>>,
+>(
+    device: &raw::Device,
+    build_type: AccelerationStructureBuildTypeKHR,
+    p_build_info: &AccelerationStructureBuildGeometryInfoKHR,
+    p_max_primitive_counts: Option<impl AsSlice<'a, u32>>,
+    p_size_info: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_acceleration_structure_build_sizes_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        build_type,
+        ptr::from_ref(p_build_info),
+        p_max_primitive_counts
+            .map(|p| p.as_slice().as_ptr().cast())
+            .unwrap_or(ptr::null()),
+        ptr::from_mut(p_size_info.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysKHR.html>"]
 #[doc(alias = "vkCmdTraceRaysKHR")]
 pub unsafe fn cmd_trace_rays_khr(
@@ -9118,6 +10443,7 @@ pub unsafe fn get_ray_tracing_shader_group_handles_khr(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupHandlesNV.html>"]
 #[doc(alias = "vkGetRayTracingShaderGroupHandlesNV")]
 pub unsafe fn get_ray_tracing_shader_group_handles_nv(
@@ -9250,6 +10576,28 @@ pub unsafe fn get_image_drm_format_modifier_properties_ext<
         p_properties.assume_init()
     })
 }
+#[doc = "Same as [get_image_drm_format_modifier_properties_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageDrmFormatModifierPropertiesEXT.html>"]
+#[doc(alias = "vkGetImageDrmFormatModifierPropertiesEXT")]
+pub unsafe fn get_image_drm_format_modifier_properties_ext_into<
+    S: StructureChain<ImageDrmFormatModifierPropertiesEXT<'static>>,
+>(
+    device: &raw::Device,
+    image: &raw::Image,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_image_drm_format_modifier_properties_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { image.clone() }),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateValidationCacheEXT.html>"]
 #[doc(alias = "vkCreateValidationCacheEXT")]
 pub unsafe fn create_validation_cache_ext(
@@ -9311,25 +10659,49 @@ pub unsafe fn merge_validation_caches_ext<'a, V3: Alias<raw::ValidationCacheEXT>
 }
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetValidationCacheDataEXT.html>"]
 #[doc(alias = "vkGetValidationCacheDataEXT")]
-pub unsafe fn get_validation_cache_data_ext(
+pub unsafe fn get_validation_cache_data_ext<R: DynamicArray<u8>>(
     device: &raw::Device,
     validation_cache: &raw::ValidationCacheEXT,
-    p_data: VoidPtr,
     dispatcher: &CommandsDispatcher,
-) -> Result<usize> {
+) -> Result<R> {
     let vulkan_command = dispatcher
         .get_validation_cache_data_ext
         .get()
         .expect("Vulkan command not loaded.");
-    let mut p_data_size = MaybeUninit::uninit();
-    let vk_status = vulkan_command(
+    let mut vk_len = MaybeUninit::uninit();
+    let p_data_size = vk_len.as_mut_ptr();
+    let p_data = ptr::null_mut();
+    vulkan_command(
         Some(unsafe { device.clone() }),
         Some(unsafe { validation_cache.clone() }),
-        p_data_size.as_mut_ptr(),
-        p_data,
-    );
-    vk_status.map_success(|| p_data_size.assume_init())
+        p_data_size,
+        p_data as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_data_size = ptr::from_mut(&mut vk_len);
+    let mut p_data = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            Some(unsafe { validation_cache.clone() }),
+            p_data_size,
+            p_data as VoidPtr,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_data_size = ptr::from_mut(&mut vk_len);
+        p_data = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadingRateImageNV.html>"]
 #[doc(alias = "vkCmdBindShadingRateImageNV")]
 pub unsafe fn cmd_bind_shading_rate_image_nv(
@@ -9348,6 +10720,7 @@ pub unsafe fn cmd_bind_shading_rate_image_nv(
         image_layout,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportShadingRatePaletteNV.html>"]
 #[doc(alias = "vkCmdSetViewportShadingRatePaletteNV")]
 pub unsafe fn cmd_set_viewport_shading_rate_palette_nv<'a>(
@@ -9367,6 +10740,7 @@ pub unsafe fn cmd_set_viewport_shading_rate_palette_nv<'a>(
         p_shading_rate_palettes.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoarseSampleOrderNV.html>"]
 #[doc(alias = "vkCmdSetCoarseSampleOrderNV")]
 pub unsafe fn cmd_set_coarse_sample_order_nv<'a>(
@@ -9386,6 +10760,7 @@ pub unsafe fn cmd_set_coarse_sample_order_nv<'a>(
         p_custom_sample_orders.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureNV.html>"]
 #[doc(alias = "vkCreateAccelerationStructureNV")]
 pub unsafe fn create_acceleration_structure_nv(
@@ -9407,6 +10782,7 @@ pub unsafe fn create_acceleration_structure_nv(
     );
     vk_status.map_success(|| p_acceleration_structure.assume_init())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyAccelerationStructureNV.html>"]
 #[doc(alias = "vkDestroyAccelerationStructureNV")]
 pub unsafe fn destroy_acceleration_structure_nv(
@@ -9425,6 +10801,7 @@ pub unsafe fn destroy_acceleration_structure_nv(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureMemoryRequirementsNV.html>"]
 #[doc(alias = "vkGetAccelerationStructureMemoryRequirementsNV")]
 pub unsafe fn get_acceleration_structure_memory_requirements_nv<
@@ -9448,6 +10825,29 @@ pub unsafe fn get_acceleration_structure_memory_requirements_nv<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[cfg(feature = "vendor-nv")]
+#[doc = "Same as [get_acceleration_structure_memory_requirements_nv], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureMemoryRequirementsNV.html>"]
+#[doc(alias = "vkGetAccelerationStructureMemoryRequirementsNV")]
+pub unsafe fn get_acceleration_structure_memory_requirements_nv_into<
+    S: StructureChain<MemoryRequirements2KHR<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &AccelerationStructureMemoryRequirementsInfoNV,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_acceleration_structure_memory_requirements_nv
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindAccelerationStructureMemoryNV.html>"]
 #[doc(alias = "vkBindAccelerationStructureMemoryNV")]
 pub unsafe fn bind_acceleration_structure_memory_nv<'a>(
@@ -9466,6 +10866,7 @@ pub unsafe fn bind_acceleration_structure_memory_nv<'a>(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructureNV.html>"]
 #[doc(alias = "vkCmdBuildAccelerationStructureNV")]
 pub unsafe fn cmd_build_acceleration_structure_nv(
@@ -9496,6 +10897,7 @@ pub unsafe fn cmd_build_acceleration_structure_nv(
         scratch_offset,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureNV.html>"]
 #[doc(alias = "vkCmdCopyAccelerationStructureNV")]
 pub unsafe fn cmd_copy_acceleration_structure_nv(
@@ -9516,6 +10918,7 @@ pub unsafe fn cmd_copy_acceleration_structure_nv(
         mode,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysNV.html>"]
 #[doc(alias = "vkCmdTraceRaysNV")]
 pub unsafe fn cmd_trace_rays_nv(
@@ -9558,6 +10961,7 @@ pub unsafe fn cmd_trace_rays_nv(
         depth,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesNV.html>"]
 #[doc(alias = "vkCreateRayTracingPipelinesNV")]
 pub unsafe fn create_ray_tracing_pipelines_nv<'a, R: DynamicArray<Pipeline>>(
@@ -9585,6 +10989,7 @@ pub unsafe fn create_ray_tracing_pipelines_nv<'a, R: DynamicArray<Pipeline>>(
         p_pipelines
     })
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureHandleNV.html>"]
 #[doc(alias = "vkGetAccelerationStructureHandleNV")]
 pub unsafe fn get_acceleration_structure_handle_nv(
@@ -9606,6 +11011,7 @@ pub unsafe fn get_acceleration_structure_handle_nv(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesNV.html>"]
 #[doc(alias = "vkCmdWriteAccelerationStructuresPropertiesNV")]
 pub unsafe fn cmd_write_acceleration_structures_properties_nv<
@@ -9632,6 +11038,7 @@ pub unsafe fn cmd_write_acceleration_structures_properties_nv<
         first_query,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCompileDeferredNV.html>"]
 #[doc(alias = "vkCompileDeferredNV")]
 pub unsafe fn compile_deferred_nv(
@@ -9678,6 +11085,31 @@ pub unsafe fn get_memory_host_pointer_properties_ext<
         p_memory_host_pointer_properties.assume_init()
     })
 }
+#[doc = "Same as [get_memory_host_pointer_properties_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryHostPointerPropertiesEXT.html>"]
+#[doc(alias = "vkGetMemoryHostPointerPropertiesEXT")]
+pub unsafe fn get_memory_host_pointer_properties_ext_into<
+    S: StructureChain<MemoryHostPointerPropertiesEXT<'static>>,
+>(
+    device: &raw::Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    p_host_pointer: VoidPtr,
+    p_memory_host_pointer_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_memory_host_pointer_properties_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        handle_type,
+        p_host_pointer,
+        ptr::from_mut(p_memory_host_pointer_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarkerAMD.html>"]
 #[doc(alias = "vkCmdWriteBufferMarkerAMD")]
 pub unsafe fn cmd_write_buffer_marker_amd(
@@ -9700,6 +11132,7 @@ pub unsafe fn cmd_write_buffer_marker_amd(
         marker,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksNV.html>"]
 #[doc(alias = "vkCmdDrawMeshTasksNV")]
 pub unsafe fn cmd_draw_mesh_tasks_nv(
@@ -9718,6 +11151,7 @@ pub unsafe fn cmd_draw_mesh_tasks_nv(
         first_task,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectNV.html>"]
 #[doc(alias = "vkCmdDrawMeshTasksIndirectNV")]
 pub unsafe fn cmd_draw_mesh_tasks_indirect_nv(
@@ -9740,6 +11174,7 @@ pub unsafe fn cmd_draw_mesh_tasks_indirect_nv(
         stride,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectCountNV.html>"]
 #[doc(alias = "vkCmdDrawMeshTasksIndirectCountNV")]
 pub unsafe fn cmd_draw_mesh_tasks_indirect_count_nv(
@@ -9766,6 +11201,7 @@ pub unsafe fn cmd_draw_mesh_tasks_indirect_count_nv(
         stride,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorEnableNV.html>"]
 #[doc(alias = "vkCmdSetExclusiveScissorEnableNV")]
 pub unsafe fn cmd_set_exclusive_scissor_enable_nv<'a>(
@@ -9785,6 +11221,7 @@ pub unsafe fn cmd_set_exclusive_scissor_enable_nv<'a>(
         p_exclusive_scissor_enables.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorNV.html>"]
 #[doc(alias = "vkCmdSetExclusiveScissorNV")]
 pub unsafe fn cmd_set_exclusive_scissor_nv<'a>(
@@ -9804,6 +11241,7 @@ pub unsafe fn cmd_set_exclusive_scissor_nv<'a>(
         p_exclusive_scissors.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCheckpointNV.html>"]
 #[doc(alias = "vkCmdSetCheckpointNV")]
 pub unsafe fn cmd_set_checkpoint_nv(
@@ -9817,6 +11255,7 @@ pub unsafe fn cmd_set_checkpoint_nv(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { command_buffer.clone() }), p_checkpoint_marker)
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueueCheckpointDataNV.html>"]
 #[doc(alias = "vkGetQueueCheckpointDataNV")]
 pub unsafe fn get_queue_checkpoint_data_nv<R: DynamicArray<CheckpointDataNV<'static>>>(
@@ -9847,6 +11286,7 @@ pub unsafe fn get_queue_checkpoint_data_nv<R: DynamicArray<CheckpointDataNV<'sta
     vk_vec.resize_with_len(vk_len as _);
     vk_vec
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkInitializePerformanceApiINTEL.html>"]
 #[doc(alias = "vkInitializePerformanceApiINTEL")]
 pub unsafe fn initialize_performance_api_intel(
@@ -9864,6 +11304,7 @@ pub unsafe fn initialize_performance_api_intel(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUninitializePerformanceApiINTEL.html>"]
 #[doc(alias = "vkUninitializePerformanceApiINTEL")]
 pub unsafe fn uninitialize_performance_api_intel(
@@ -9876,6 +11317,7 @@ pub unsafe fn uninitialize_performance_api_intel(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { device.clone() }))
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceMarkerINTEL.html>"]
 #[doc(alias = "vkCmdSetPerformanceMarkerINTEL")]
 pub unsafe fn cmd_set_performance_marker_intel(
@@ -9893,6 +11335,7 @@ pub unsafe fn cmd_set_performance_marker_intel(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceStreamMarkerINTEL.html>"]
 #[doc(alias = "vkCmdSetPerformanceStreamMarkerINTEL")]
 pub unsafe fn cmd_set_performance_stream_marker_intel(
@@ -9910,6 +11353,7 @@ pub unsafe fn cmd_set_performance_stream_marker_intel(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceOverrideINTEL.html>"]
 #[doc(alias = "vkCmdSetPerformanceOverrideINTEL")]
 pub unsafe fn cmd_set_performance_override_intel(
@@ -9927,6 +11371,7 @@ pub unsafe fn cmd_set_performance_override_intel(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquirePerformanceConfigurationINTEL.html>"]
 #[doc(alias = "vkAcquirePerformanceConfigurationINTEL")]
 pub unsafe fn acquire_performance_configuration_intel(
@@ -9946,6 +11391,7 @@ pub unsafe fn acquire_performance_configuration_intel(
     );
     vk_status.map_success(|| p_configuration.assume_init())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleasePerformanceConfigurationINTEL.html>"]
 #[doc(alias = "vkReleasePerformanceConfigurationINTEL")]
 pub unsafe fn release_performance_configuration_intel(
@@ -9963,6 +11409,7 @@ pub unsafe fn release_performance_configuration_intel(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSetPerformanceConfigurationINTEL.html>"]
 #[doc(alias = "vkQueueSetPerformanceConfigurationINTEL")]
 pub unsafe fn queue_set_performance_configuration_intel(
@@ -9980,6 +11427,7 @@ pub unsafe fn queue_set_performance_configuration_intel(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-intel")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPerformanceParameterINTEL.html>"]
 #[doc(alias = "vkGetPerformanceParameterINTEL")]
 pub unsafe fn get_performance_parameter_intel(
@@ -9999,6 +11447,7 @@ pub unsafe fn get_performance_parameter_intel(
     );
     vk_status.map_success(|| p_value.assume_init())
 }
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLocalDimmingAMD.html>"]
 #[doc(alias = "vkSetLocalDimmingAMD")]
 pub unsafe fn set_local_dimming_amd(
@@ -10017,6 +11466,7 @@ pub unsafe fn set_local_dimming_amd(
         local_dimming_enable.into(),
     )
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImagePipeSurfaceFUCHSIA.html>"]
 #[doc(alias = "vkCreateImagePipeSurfaceFUCHSIA")]
 pub unsafe fn create_image_pipe_surface_fuchsia(
@@ -10038,6 +11488,7 @@ pub unsafe fn create_image_pipe_surface_fuchsia(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMetalSurfaceEXT.html>"]
 #[doc(alias = "vkCreateMetalSurfaceEXT")]
 pub unsafe fn create_metal_surface_ext(
@@ -10173,6 +11624,7 @@ pub unsafe fn wait_for_present_khr(
     )
     .into_result()
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html>"]
 #[doc(alias = "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV")]
 pub unsafe fn get_physical_device_cooperative_matrix_properties_nv<
@@ -10216,6 +11668,7 @@ pub unsafe fn get_physical_device_cooperative_matrix_properties_nv<
         vk_vec
     })
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV.html>"]
 #[doc(alias = "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV")]
 pub unsafe fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv<
@@ -10259,6 +11712,7 @@ pub unsafe fn get_physical_device_supported_framebuffer_mixed_samples_combinatio
         vk_vec
     })
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModes2EXT.html>"]
 #[doc(alias = "vkGetPhysicalDeviceSurfacePresentModes2EXT")]
 pub unsafe fn get_physical_device_surface_present_modes2_ext<R: DynamicArray<PresentModeKHR>>(
@@ -10303,6 +11757,7 @@ pub unsafe fn get_physical_device_surface_present_modes2_ext<R: DynamicArray<Pre
         vk_vec
     })
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireFullScreenExclusiveModeEXT.html>"]
 #[doc(alias = "vkAcquireFullScreenExclusiveModeEXT")]
 pub unsafe fn acquire_full_screen_exclusive_mode_ext(
@@ -10320,6 +11775,7 @@ pub unsafe fn acquire_full_screen_exclusive_mode_ext(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseFullScreenExclusiveModeEXT.html>"]
 #[doc(alias = "vkReleaseFullScreenExclusiveModeEXT")]
 pub unsafe fn release_full_screen_exclusive_mode_ext(
@@ -10337,6 +11793,7 @@ pub unsafe fn release_full_screen_exclusive_mode_ext(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-win32", target_os = "windows"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupSurfacePresentModes2EXT.html>"]
 #[doc(alias = "vkGetDeviceGroupSurfacePresentModes2EXT")]
 pub unsafe fn get_device_group_surface_present_modes2_ext(
@@ -10724,6 +12181,7 @@ pub unsafe fn release_swapchain_images_ext(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html>"]
 #[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsNV")]
 pub unsafe fn get_generated_commands_memory_requirements_nv<
@@ -10747,6 +12205,29 @@ pub unsafe fn get_generated_commands_memory_requirements_nv<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[cfg(feature = "vendor-nv")]
+#[doc = "Same as [get_generated_commands_memory_requirements_nv], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html>"]
+#[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsNV")]
+pub unsafe fn get_generated_commands_memory_requirements_nv_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &GeneratedCommandsMemoryRequirementsInfoNV,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_generated_commands_memory_requirements_nv
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html>"]
 #[doc(alias = "vkCmdPreprocessGeneratedCommandsNV")]
 pub unsafe fn cmd_preprocess_generated_commands_nv(
@@ -10763,6 +12244,7 @@ pub unsafe fn cmd_preprocess_generated_commands_nv(
         ptr::from_ref(p_generated_commands_info),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html>"]
 #[doc(alias = "vkCmdExecuteGeneratedCommandsNV")]
 pub unsafe fn cmd_execute_generated_commands_nv(
@@ -10781,6 +12263,7 @@ pub unsafe fn cmd_execute_generated_commands_nv(
         ptr::from_ref(p_generated_commands_info),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html>"]
 #[doc(alias = "vkCmdBindPipelineShaderGroupNV")]
 pub unsafe fn cmd_bind_pipeline_shader_group_nv(
@@ -10801,6 +12284,7 @@ pub unsafe fn cmd_bind_pipeline_shader_group_nv(
         group_index,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIndirectCommandsLayoutNV.html>"]
 #[doc(alias = "vkCreateIndirectCommandsLayoutNV")]
 pub unsafe fn create_indirect_commands_layout_nv(
@@ -10822,6 +12306,7 @@ pub unsafe fn create_indirect_commands_layout_nv(
     );
     vk_status.map_success(|| p_indirect_commands_layout.assume_init())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyIndirectCommandsLayoutNV.html>"]
 #[doc(alias = "vkDestroyIndirectCommandsLayoutNV")]
 pub unsafe fn destroy_indirect_commands_layout_nv(
@@ -10896,6 +12381,129 @@ pub unsafe fn get_drm_display_ext(
     );
     vk_status.map_success(|| display.assume_init())
 }
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEncodeVideoKHR.html>"]
+#[doc(alias = "vkCmdEncodeVideoKHR")]
+pub unsafe fn cmd_encode_video_khr(
+    command_buffer: &raw::CommandBuffer,
+    p_encode_info: &VideoEncodeInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .cmd_encode_video_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { command_buffer.clone() }),
+        ptr::from_ref(p_encode_info),
+    )
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR")]
+pub unsafe fn get_physical_device_video_encode_quality_level_properties_khr<
+    S: StructureChainOut<VideoEncodeQualityLevelPropertiesKHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_quality_level_info: &PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) -> Result<S> {
+    let vulkan_command = dispatcher
+        .get_physical_device_video_encode_quality_level_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_quality_level_properties = MaybeUninit::uninit();
+    S::setup_uninit(&mut p_quality_level_properties);
+    let vk_status = vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_quality_level_info),
+        S::get_uninit_head_ptr(p_quality_level_properties.as_mut_ptr()),
+    );
+    vk_status.map_success(|| {
+        S::setup_cleanup(p_quality_level_properties.as_mut_ptr());
+        p_quality_level_properties.assume_init()
+    })
+}
+#[doc = "Same as [get_physical_device_video_encode_quality_level_properties_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR.html>"]
+#[doc(alias = "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR")]
+pub unsafe fn get_physical_device_video_encode_quality_level_properties_khr_into<
+    S: StructureChain<VideoEncodeQualityLevelPropertiesKHR<'static>>,
+>(
+    physical_device: &raw::PhysicalDevice,
+    p_quality_level_info: &PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+    p_quality_level_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_physical_device_video_encode_quality_level_properties_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { physical_device.clone() }),
+        ptr::from_ref(p_quality_level_info),
+        ptr::from_mut(p_quality_level_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEncodedVideoSessionParametersKHR.html>"]
+#[doc(alias = "vkGetEncodedVideoSessionParametersKHR")]
+pub unsafe fn get_encoded_video_session_parameters_khr<
+    S: StructureChainOut<VideoEncodeSessionParametersFeedbackInfoKHR<'static>>,
+    R: DynamicArray<u8>,
+>(
+    device: &raw::Device,
+    p_video_session_parameters_info: &VideoEncodeSessionParametersGetInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) -> Result<(S, R)> {
+    let vulkan_command = dispatcher
+        .get_encoded_video_session_parameters_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_feedback_info = MaybeUninit::uninit();
+    S::setup_uninit(&mut p_feedback_info);
+    let mut vk_len = MaybeUninit::uninit();
+    let p_data_size = vk_len.as_mut_ptr();
+    let p_data = ptr::null_mut();
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_video_session_parameters_info),
+        S::get_uninit_head_ptr(p_feedback_info.as_mut_ptr()),
+        p_data_size,
+        p_data as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_data_size = ptr::from_mut(&mut vk_len);
+    let mut p_data = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            ptr::from_ref(p_video_session_parameters_info),
+            S::get_uninit_head_ptr(p_feedback_info.as_mut_ptr()),
+            p_data_size,
+            p_data as VoidPtr,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_data_size = ptr::from_mut(&mut vk_len);
+        p_data = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        (
+            {
+                S::setup_cleanup(p_feedback_info.as_mut_ptr());
+                p_feedback_info.assume_init()
+            },
+            {
+                vk_vec.resize_with_len(vk_len as _);
+                vk_vec
+            },
+        )
+    })
+}
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCudaModuleNV.html>"]
 #[doc(alias = "vkCreateCudaModuleNV")]
 pub unsafe fn create_cuda_module_nv(
@@ -10917,27 +12525,52 @@ pub unsafe fn create_cuda_module_nv(
     );
     vk_status.map_success(|| p_module.assume_init())
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCudaModuleCacheNV.html>"]
 #[doc(alias = "vkGetCudaModuleCacheNV")]
-pub unsafe fn get_cuda_module_cache_nv(
+pub unsafe fn get_cuda_module_cache_nv<R: DynamicArray<u8>>(
     device: &raw::Device,
     module: &raw::CudaModuleNV,
-    p_cache_data: VoidPtr,
     dispatcher: &CommandsDispatcher,
-) -> Result<usize> {
+) -> Result<R> {
     let vulkan_command = dispatcher
         .get_cuda_module_cache_nv
         .get()
         .expect("Vulkan command not loaded.");
-    let mut p_cache_size = MaybeUninit::uninit();
-    let vk_status = vulkan_command(
+    let mut vk_len = MaybeUninit::uninit();
+    let p_cache_size = vk_len.as_mut_ptr();
+    let p_cache_data = ptr::null_mut();
+    vulkan_command(
         Some(unsafe { device.clone() }),
         Some(unsafe { module.clone() }),
-        p_cache_size.as_mut_ptr(),
-        p_cache_data,
-    );
-    vk_status.map_success(|| p_cache_size.assume_init())
+        p_cache_size,
+        p_cache_data as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_cache_size = ptr::from_mut(&mut vk_len);
+    let mut p_cache_data = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            Some(unsafe { module.clone() }),
+            p_cache_size,
+            p_cache_data as VoidPtr,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_cache_size = ptr::from_mut(&mut vk_len);
+        p_cache_data = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCudaFunctionNV.html>"]
 #[doc(alias = "vkCreateCudaFunctionNV")]
 pub unsafe fn create_cuda_function_nv(
@@ -10959,6 +12592,7 @@ pub unsafe fn create_cuda_function_nv(
     );
     vk_status.map_success(|| p_function.assume_init())
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCudaModuleNV.html>"]
 #[doc(alias = "vkDestroyCudaModuleNV")]
 pub unsafe fn destroy_cuda_module_nv(
@@ -10977,6 +12611,7 @@ pub unsafe fn destroy_cuda_module_nv(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCudaFunctionNV.html>"]
 #[doc(alias = "vkDestroyCudaFunctionNV")]
 pub unsafe fn destroy_cuda_function_nv(
@@ -10995,6 +12630,7 @@ pub unsafe fn destroy_cuda_function_nv(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCudaLaunchKernelNV.html>"]
 #[doc(alias = "vkCmdCudaLaunchKernelNV")]
 pub unsafe fn cmd_cuda_launch_kernel_nv(
@@ -11011,6 +12647,7 @@ pub unsafe fn cmd_cuda_launch_kernel_nv(
         ptr::from_ref(p_launch_info),
     )
 }
+#[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkExportMetalObjectsEXT.html>"]
 #[doc(alias = "vkExportMetalObjectsEXT")]
 pub unsafe fn export_metal_objects_ext<S: StructureChainOut<ExportMetalObjectsInfoEXT<'static>>>(
@@ -11030,6 +12667,27 @@ pub unsafe fn export_metal_objects_ext<S: StructureChainOut<ExportMetalObjectsIn
     S::setup_cleanup(p_metal_objects_info.as_mut_ptr());
     p_metal_objects_info.assume_init()
 }
+#[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+#[doc = "Same as [export_metal_objects_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkExportMetalObjectsEXT.html>"]
+#[doc(alias = "vkExportMetalObjectsEXT")]
+pub unsafe fn export_metal_objects_ext_into<
+    S: StructureChain<ExportMetalObjectsInfoEXT<'static>>,
+>(
+    device: &raw::Device,
+    p_metal_objects_info: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .export_metal_objects_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_mut(p_metal_objects_info.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarker2AMD.html>"]
 #[doc(alias = "vkCmdWriteBufferMarker2AMD")]
 pub unsafe fn cmd_write_buffer_marker2_amd(
@@ -11052,6 +12710,7 @@ pub unsafe fn cmd_write_buffer_marker2_amd(
         marker,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueueCheckpointData2NV.html>"]
 #[doc(alias = "vkGetQueueCheckpointData2NV")]
 pub unsafe fn get_queue_checkpoint_data2_nv<R: DynamicArray<CheckpointData2NV<'static>>>(
@@ -11299,6 +12958,7 @@ pub unsafe fn get_acceleration_structure_opaque_capture_descriptor_data_ext(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateEnumNV.html>"]
 #[doc(alias = "vkCmdSetFragmentShadingRateEnumNV")]
 pub unsafe fn cmd_set_fragment_shading_rate_enum_nv(
@@ -11385,6 +13045,10 @@ pub unsafe fn cmd_draw_mesh_tasks_indirect_count_ext(
         stride,
     )
 }
+#[cfg(all(
+    any(feature = "platform-win32", target_os = "windows"),
+    feature = "vendor-nv"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireWinrtDisplayNV.html>"]
 #[doc(alias = "vkAcquireWinrtDisplayNV")]
 pub unsafe fn acquire_winrt_display_nv(
@@ -11402,6 +13066,10 @@ pub unsafe fn acquire_winrt_display_nv(
     )
     .map_success(|| ())
 }
+#[cfg(all(
+    any(feature = "platform-win32", target_os = "windows"),
+    feature = "vendor-nv"
+))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetWinrtDisplayNV.html>"]
 #[doc(alias = "vkGetWinrtDisplayNV")]
 pub unsafe fn get_winrt_display_nv(
@@ -11421,6 +13089,7 @@ pub unsafe fn get_winrt_display_nv(
     );
     vk_status.map_success(|| p_display.assume_init())
 }
+#[cfg(feature = "platform-directfb")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDirectFBSurfaceEXT.html>"]
 #[doc(alias = "vkCreateDirectFBSurfaceEXT")]
 pub unsafe fn create_direct_fbsurface_ext(
@@ -11442,6 +13111,7 @@ pub unsafe fn create_direct_fbsurface_ext(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(feature = "platform-directfb")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDirectFBPresentationSupportEXT.html>"]
 #[doc(alias = "vkGetPhysicalDeviceDirectFBPresentationSupportEXT")]
 pub unsafe fn get_physical_device_direct_fbpresentation_support_ext(
@@ -11481,13 +13151,14 @@ pub unsafe fn cmd_set_vertex_input_ext<'a>(
         p_vertex_attribute_descriptions.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandleFUCHSIA.html>"]
 #[doc(alias = "vkGetMemoryZirconHandleFUCHSIA")]
 pub unsafe fn get_memory_zircon_handle_fuchsia(
     device: &raw::Device,
     p_get_zircon_handle_info: &MemoryGetZirconHandleInfoFUCHSIA,
     dispatcher: &CommandsDispatcher,
-) -> Result<VoidPtr> {
+) -> Result<u32> {
     let vulkan_command = dispatcher
         .get_memory_zircon_handle_fuchsia
         .get()
@@ -11500,6 +13171,7 @@ pub unsafe fn get_memory_zircon_handle_fuchsia(
     );
     vk_status.map_success(|| p_zircon_handle.assume_init())
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandlePropertiesFUCHSIA.html>"]
 #[doc(alias = "vkGetMemoryZirconHandlePropertiesFUCHSIA")]
 pub unsafe fn get_memory_zircon_handle_properties_fuchsia<
@@ -11507,7 +13179,7 @@ pub unsafe fn get_memory_zircon_handle_properties_fuchsia<
 >(
     device: &raw::Device,
     handle_type: ExternalMemoryHandleTypeFlags,
-    zircon_handle: VoidPtr,
+    zircon_handle: u32,
     dispatcher: &CommandsDispatcher,
 ) -> Result<S> {
     let vulkan_command = dispatcher
@@ -11527,6 +13199,32 @@ pub unsafe fn get_memory_zircon_handle_properties_fuchsia<
         p_memory_zircon_handle_properties.assume_init()
     })
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+#[doc = "Same as [get_memory_zircon_handle_properties_fuchsia], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandlePropertiesFUCHSIA.html>"]
+#[doc(alias = "vkGetMemoryZirconHandlePropertiesFUCHSIA")]
+pub unsafe fn get_memory_zircon_handle_properties_fuchsia_into<
+    S: StructureChain<MemoryZirconHandlePropertiesFUCHSIA<'static>>,
+>(
+    device: &raw::Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    zircon_handle: u32,
+    p_memory_zircon_handle_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_memory_zircon_handle_properties_fuchsia
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        handle_type,
+        zircon_handle,
+        ptr::from_mut(p_memory_zircon_handle_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreZirconHandleFUCHSIA.html>"]
 #[doc(alias = "vkImportSemaphoreZirconHandleFUCHSIA")]
 pub unsafe fn import_semaphore_zircon_handle_fuchsia(
@@ -11544,13 +13242,14 @@ pub unsafe fn import_semaphore_zircon_handle_fuchsia(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreZirconHandleFUCHSIA.html>"]
 #[doc(alias = "vkGetSemaphoreZirconHandleFUCHSIA")]
 pub unsafe fn get_semaphore_zircon_handle_fuchsia(
     device: &raw::Device,
     p_get_zircon_handle_info: &SemaphoreGetZirconHandleInfoFUCHSIA,
     dispatcher: &CommandsDispatcher,
-) -> Result<VoidPtr> {
+) -> Result<u32> {
     let vulkan_command = dispatcher
         .get_semaphore_zircon_handle_fuchsia
         .get()
@@ -11563,6 +13262,7 @@ pub unsafe fn get_semaphore_zircon_handle_fuchsia(
     );
     vk_status.map_success(|| p_zircon_handle.assume_init())
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBufferCollectionFUCHSIA.html>"]
 #[doc(alias = "vkCreateBufferCollectionFUCHSIA")]
 pub unsafe fn create_buffer_collection_fuchsia(
@@ -11584,6 +13284,7 @@ pub unsafe fn create_buffer_collection_fuchsia(
     );
     vk_status.map_success(|| p_collection.assume_init())
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionImageConstraintsFUCHSIA.html>"]
 #[doc(alias = "vkSetBufferCollectionImageConstraintsFUCHSIA")]
 pub unsafe fn set_buffer_collection_image_constraints_fuchsia(
@@ -11603,6 +13304,7 @@ pub unsafe fn set_buffer_collection_image_constraints_fuchsia(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionBufferConstraintsFUCHSIA.html>"]
 #[doc(alias = "vkSetBufferCollectionBufferConstraintsFUCHSIA")]
 pub unsafe fn set_buffer_collection_buffer_constraints_fuchsia(
@@ -11622,6 +13324,7 @@ pub unsafe fn set_buffer_collection_buffer_constraints_fuchsia(
     )
     .map_success(|| ())
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBufferCollectionFUCHSIA.html>"]
 #[doc(alias = "vkDestroyBufferCollectionFUCHSIA")]
 pub unsafe fn destroy_buffer_collection_fuchsia(
@@ -11640,6 +13343,7 @@ pub unsafe fn destroy_buffer_collection_fuchsia(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferCollectionPropertiesFUCHSIA.html>"]
 #[doc(alias = "vkGetBufferCollectionPropertiesFUCHSIA")]
 pub unsafe fn get_buffer_collection_properties_fuchsia<
@@ -11665,6 +13369,30 @@ pub unsafe fn get_buffer_collection_properties_fuchsia<
         p_properties.assume_init()
     })
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+#[doc = "Same as [get_buffer_collection_properties_fuchsia], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferCollectionPropertiesFUCHSIA.html>"]
+#[doc(alias = "vkGetBufferCollectionPropertiesFUCHSIA")]
+pub unsafe fn get_buffer_collection_properties_fuchsia_into<
+    S: StructureChain<BufferCollectionPropertiesFUCHSIA<'static>>,
+>(
+    device: &raw::Device,
+    collection: &raw::BufferCollectionFUCHSIA,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_buffer_collection_properties_fuchsia
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { collection.clone() }),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(feature = "vendor-huawei")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI.html>"]
 #[doc(alias = "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI")]
 pub unsafe fn get_device_subpass_shading_max_workgroup_size_huawei<R: DynamicArray<Extent2D>>(
@@ -11687,6 +13415,7 @@ pub unsafe fn get_device_subpass_shading_max_workgroup_size_huawei<R: DynamicArr
         p_max_workgroup_size
     })
 }
+#[cfg(feature = "vendor-huawei")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSubpassShadingHUAWEI.html>"]
 #[doc(alias = "vkCmdSubpassShadingHUAWEI")]
 pub unsafe fn cmd_subpass_shading_huawei(
@@ -11699,6 +13428,7 @@ pub unsafe fn cmd_subpass_shading_huawei(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { command_buffer.clone() }))
 }
+#[cfg(feature = "vendor-huawei")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindInvocationMaskHUAWEI.html>"]
 #[doc(alias = "vkCmdBindInvocationMaskHUAWEI")]
 pub unsafe fn cmd_bind_invocation_mask_huawei(
@@ -11717,6 +13447,7 @@ pub unsafe fn cmd_bind_invocation_mask_huawei(
         image_layout,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryRemoteAddressNV.html>"]
 #[doc(alias = "vkGetMemoryRemoteAddressNV")]
 pub unsafe fn get_memory_remote_address_nv(
@@ -11784,6 +13515,7 @@ pub unsafe fn cmd_set_logic_op_ext(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { command_buffer.clone() }), logic_op)
 }
+#[cfg(any(feature = "platform-screen", target_os = "nto"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateScreenSurfaceQNX.html>"]
 #[doc(alias = "vkCreateScreenSurfaceQNX")]
 pub unsafe fn create_screen_surface_qnx(
@@ -11805,6 +13537,7 @@ pub unsafe fn create_screen_surface_qnx(
     );
     vk_status.map_success(|| p_surface.assume_init())
 }
+#[cfg(any(feature = "platform-screen", target_os = "nto"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceScreenPresentationSupportQNX.html>"]
 #[doc(alias = "vkGetPhysicalDeviceScreenPresentationSupportQNX")]
 pub unsafe fn get_physical_device_screen_presentation_support_qnx(
@@ -12181,6 +13914,30 @@ pub unsafe fn get_micromap_build_sizes_ext<
     S::setup_cleanup(p_size_info.as_mut_ptr());
     p_size_info.assume_init()
 }
+#[doc = "Same as [get_micromap_build_sizes_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMicromapBuildSizesEXT.html>"]
+#[doc(alias = "vkGetMicromapBuildSizesEXT")]
+pub unsafe fn get_micromap_build_sizes_ext_into<
+    S: StructureChain<MicromapBuildSizesInfoEXT<'static>>,
+>(
+    device: &raw::Device,
+    build_type: AccelerationStructureBuildTypeKHR,
+    p_build_info: &MicromapBuildInfoEXT,
+    p_size_info: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_micromap_build_sizes_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        build_type,
+        ptr::from_ref(p_build_info),
+        ptr::from_mut(p_size_info.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-huawei")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterHUAWEI.html>"]
 #[doc(alias = "vkCmdDrawClusterHUAWEI")]
 pub unsafe fn cmd_draw_cluster_huawei(
@@ -12201,6 +13958,7 @@ pub unsafe fn cmd_draw_cluster_huawei(
         group_count_z,
     )
 }
+#[cfg(feature = "vendor-huawei")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterIndirectHUAWEI.html>"]
 #[doc(alias = "vkCmdDrawClusterIndirectHUAWEI")]
 pub unsafe fn cmd_draw_cluster_indirect_huawei(
@@ -12237,6 +13995,7 @@ pub unsafe fn set_device_memory_priority_ext(
         priority,
     )
 }
+#[cfg(feature = "vendor-valve")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutHostMappingInfoVALVE.html>"]
 #[doc(alias = "vkGetDescriptorSetLayoutHostMappingInfoVALVE")]
 pub unsafe fn get_descriptor_set_layout_host_mapping_info_valve<
@@ -12260,6 +14019,29 @@ pub unsafe fn get_descriptor_set_layout_host_mapping_info_valve<
     S::setup_cleanup(p_host_mapping.as_mut_ptr());
     p_host_mapping.assume_init()
 }
+#[cfg(feature = "vendor-valve")]
+#[doc = "Same as [get_descriptor_set_layout_host_mapping_info_valve], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutHostMappingInfoVALVE.html>"]
+#[doc(alias = "vkGetDescriptorSetLayoutHostMappingInfoVALVE")]
+pub unsafe fn get_descriptor_set_layout_host_mapping_info_valve_into<
+    S: StructureChain<DescriptorSetLayoutHostMappingInfoVALVE<'static>>,
+>(
+    device: &raw::Device,
+    p_binding_reference: &DescriptorSetBindingReferenceVALVE,
+    p_host_mapping: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_descriptor_set_layout_host_mapping_info_valve
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_binding_reference),
+        ptr::from_mut(p_host_mapping.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-valve")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetHostMappingVALVE.html>"]
 #[doc(alias = "vkGetDescriptorSetHostMappingVALVE")]
 pub unsafe fn get_descriptor_set_host_mapping_valve(
@@ -12279,6 +14061,7 @@ pub unsafe fn get_descriptor_set_host_mapping_valve(
     );
     pp_data.assume_init()
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryIndirectNV.html>"]
 #[doc(alias = "vkCmdCopyMemoryIndirectNV")]
 pub unsafe fn cmd_copy_memory_indirect_nv(
@@ -12299,6 +14082,7 @@ pub unsafe fn cmd_copy_memory_indirect_nv(
         stride,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToImageIndirectNV.html>"]
 #[doc(alias = "vkCmdCopyMemoryToImageIndirectNV")]
 pub unsafe fn cmd_copy_memory_to_image_indirect_nv<'a>(
@@ -12324,6 +14108,7 @@ pub unsafe fn cmd_copy_memory_to_image_indirect_nv<'a>(
         p_image_subresources.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryNV.html>"]
 #[doc(alias = "vkCmdDecompressMemoryNV")]
 pub unsafe fn cmd_decompress_memory_nv<'a>(
@@ -12341,6 +14126,7 @@ pub unsafe fn cmd_decompress_memory_nv<'a>(
         p_decompress_memory_regions.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryIndirectCountNV.html>"]
 #[doc(alias = "vkCmdDecompressMemoryIndirectCountNV")]
 pub unsafe fn cmd_decompress_memory_indirect_count_nv(
@@ -12361,6 +14147,7 @@ pub unsafe fn cmd_decompress_memory_indirect_count_nv(
         stride,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineIndirectMemoryRequirementsNV.html>"]
 #[doc(alias = "vkGetPipelineIndirectMemoryRequirementsNV")]
 pub unsafe fn get_pipeline_indirect_memory_requirements_nv<
@@ -12384,6 +14171,29 @@ pub unsafe fn get_pipeline_indirect_memory_requirements_nv<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[cfg(feature = "vendor-nv")]
+#[doc = "Same as [get_pipeline_indirect_memory_requirements_nv], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineIndirectMemoryRequirementsNV.html>"]
+#[doc(alias = "vkGetPipelineIndirectMemoryRequirementsNV")]
+pub unsafe fn get_pipeline_indirect_memory_requirements_nv_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_create_info: &ComputePipelineCreateInfo,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_pipeline_indirect_memory_requirements_nv
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdUpdatePipelineIndirectBufferNV.html>"]
 #[doc(alias = "vkCmdUpdatePipelineIndirectBufferNV")]
 pub unsafe fn cmd_update_pipeline_indirect_buffer_nv(
@@ -12402,6 +14212,7 @@ pub unsafe fn cmd_update_pipeline_indirect_buffer_nv(
         Some(unsafe { pipeline.clone() }),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineIndirectDeviceAddressNV.html>"]
 #[doc(alias = "vkGetPipelineIndirectDeviceAddressNV")]
 pub unsafe fn get_pipeline_indirect_device_address_nv(
@@ -12759,6 +14570,7 @@ pub unsafe fn cmd_set_depth_clip_negative_one_to_one_ext(
         negative_one_to_one.into(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingEnableNV.html>"]
 #[doc(alias = "vkCmdSetViewportWScalingEnableNV")]
 pub unsafe fn cmd_set_viewport_wscaling_enable_nv(
@@ -12775,6 +14587,7 @@ pub unsafe fn cmd_set_viewport_wscaling_enable_nv(
         viewport_wscaling_enable.into(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportSwizzleNV.html>"]
 #[doc(alias = "vkCmdSetViewportSwizzleNV")]
 pub unsafe fn cmd_set_viewport_swizzle_nv<'a>(
@@ -12794,6 +14607,7 @@ pub unsafe fn cmd_set_viewport_swizzle_nv<'a>(
         p_viewport_swizzles.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorEnableNV.html>"]
 #[doc(alias = "vkCmdSetCoverageToColorEnableNV")]
 pub unsafe fn cmd_set_coverage_to_color_enable_nv(
@@ -12810,6 +14624,7 @@ pub unsafe fn cmd_set_coverage_to_color_enable_nv(
         coverage_to_color_enable.into(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorLocationNV.html>"]
 #[doc(alias = "vkCmdSetCoverageToColorLocationNV")]
 pub unsafe fn cmd_set_coverage_to_color_location_nv(
@@ -12826,6 +14641,7 @@ pub unsafe fn cmd_set_coverage_to_color_location_nv(
         coverage_to_color_location,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationModeNV.html>"]
 #[doc(alias = "vkCmdSetCoverageModulationModeNV")]
 pub unsafe fn cmd_set_coverage_modulation_mode_nv(
@@ -12842,6 +14658,7 @@ pub unsafe fn cmd_set_coverage_modulation_mode_nv(
         coverage_modulation_mode,
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableEnableNV.html>"]
 #[doc(alias = "vkCmdSetCoverageModulationTableEnableNV")]
 pub unsafe fn cmd_set_coverage_modulation_table_enable_nv(
@@ -12858,6 +14675,7 @@ pub unsafe fn cmd_set_coverage_modulation_table_enable_nv(
         coverage_modulation_table_enable.into(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableNV.html>"]
 #[doc(alias = "vkCmdSetCoverageModulationTableNV")]
 pub unsafe fn cmd_set_coverage_modulation_table_nv<'a>(
@@ -12875,6 +14693,7 @@ pub unsafe fn cmd_set_coverage_modulation_table_nv<'a>(
         p_coverage_modulation_table.as_slice().as_ptr().cast(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetShadingRateImageEnableNV.html>"]
 #[doc(alias = "vkCmdSetShadingRateImageEnableNV")]
 pub unsafe fn cmd_set_shading_rate_image_enable_nv(
@@ -12891,6 +14710,7 @@ pub unsafe fn cmd_set_shading_rate_image_enable_nv(
         shading_rate_image_enable.into(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRepresentativeFragmentTestEnableNV.html>"]
 #[doc(alias = "vkCmdSetRepresentativeFragmentTestEnableNV")]
 pub unsafe fn cmd_set_representative_fragment_test_enable_nv(
@@ -12907,6 +14727,7 @@ pub unsafe fn cmd_set_representative_fragment_test_enable_nv(
         representative_fragment_test_enable.into(),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageReductionModeNV.html>"]
 #[doc(alias = "vkCmdSetCoverageReductionModeNV")]
 pub unsafe fn cmd_set_coverage_reduction_mode_nv(
@@ -12946,6 +14767,27 @@ pub unsafe fn get_shader_module_identifier_ext<
     S::setup_cleanup(p_identifier.as_mut_ptr());
     p_identifier.assume_init()
 }
+#[doc = "Same as [get_shader_module_identifier_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleIdentifierEXT.html>"]
+#[doc(alias = "vkGetShaderModuleIdentifierEXT")]
+pub unsafe fn get_shader_module_identifier_ext_into<
+    S: StructureChain<ShaderModuleIdentifierEXT<'static>>,
+>(
+    device: &raw::Device,
+    shader_module: &raw::ShaderModule,
+    p_identifier: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_shader_module_identifier_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { shader_module.clone() }),
+        ptr::from_mut(p_identifier.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleCreateInfoIdentifierEXT.html>"]
 #[doc(alias = "vkGetShaderModuleCreateInfoIdentifierEXT")]
 pub unsafe fn get_shader_module_create_info_identifier_ext<
@@ -12969,6 +14811,28 @@ pub unsafe fn get_shader_module_create_info_identifier_ext<
     S::setup_cleanup(p_identifier.as_mut_ptr());
     p_identifier.assume_init()
 }
+#[doc = "Same as [get_shader_module_create_info_identifier_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleCreateInfoIdentifierEXT.html>"]
+#[doc(alias = "vkGetShaderModuleCreateInfoIdentifierEXT")]
+pub unsafe fn get_shader_module_create_info_identifier_ext_into<
+    S: StructureChain<ShaderModuleIdentifierEXT<'static>>,
+>(
+    device: &raw::Device,
+    p_create_info: &ShaderModuleCreateInfo,
+    p_identifier: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_shader_module_create_info_identifier_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        ptr::from_mut(p_identifier.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceOpticalFlowImageFormatsNV.html>"]
 #[doc(alias = "vkGetPhysicalDeviceOpticalFlowImageFormatsNV")]
 pub unsafe fn get_physical_device_optical_flow_image_formats_nv<
@@ -13015,6 +14879,7 @@ pub unsafe fn get_physical_device_optical_flow_image_formats_nv<
         vk_vec
     })
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateOpticalFlowSessionNV.html>"]
 #[doc(alias = "vkCreateOpticalFlowSessionNV")]
 pub unsafe fn create_optical_flow_session_nv(
@@ -13036,6 +14901,7 @@ pub unsafe fn create_optical_flow_session_nv(
     );
     vk_status.map_success(|| p_session.assume_init())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyOpticalFlowSessionNV.html>"]
 #[doc(alias = "vkDestroyOpticalFlowSessionNV")]
 pub unsafe fn destroy_optical_flow_session_nv(
@@ -13054,6 +14920,7 @@ pub unsafe fn destroy_optical_flow_session_nv(
         p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindOpticalFlowSessionImageNV.html>"]
 #[doc(alias = "vkBindOpticalFlowSessionImageNV")]
 pub unsafe fn bind_optical_flow_session_image_nv(
@@ -13077,6 +14944,7 @@ pub unsafe fn bind_optical_flow_session_image_nv(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdOpticalFlowExecuteNV.html>"]
 #[doc(alias = "vkCmdOpticalFlowExecuteNV")]
 pub unsafe fn cmd_optical_flow_execute_nv(
@@ -13159,6 +15027,27 @@ pub unsafe fn get_device_image_subresource_layout_khr<
     S::setup_cleanup(p_layout.as_mut_ptr());
     p_layout.assume_init()
 }
+#[doc = "Same as [get_device_image_subresource_layout_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageSubresourceLayoutKHR.html>"]
+#[doc(alias = "vkGetDeviceImageSubresourceLayoutKHR")]
+pub unsafe fn get_device_image_subresource_layout_khr_into<
+    S: StructureChain<SubresourceLayout2KHR<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &DeviceImageSubresourceInfoKHR,
+    p_layout: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_device_image_subresource_layout_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_layout.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2KHR.html>"]
 #[doc(alias = "vkGetImageSubresourceLayout2KHR")]
 pub unsafe fn get_image_subresource_layout2_khr<
@@ -13184,6 +15073,29 @@ pub unsafe fn get_image_subresource_layout2_khr<
     S::setup_cleanup(p_layout.as_mut_ptr());
     p_layout.assume_init()
 }
+#[doc = "Same as [get_image_subresource_layout2_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2KHR.html>"]
+#[doc(alias = "vkGetImageSubresourceLayout2KHR")]
+pub unsafe fn get_image_subresource_layout2_khr_into<
+    S: StructureChain<SubresourceLayout2KHR<'static>>,
+>(
+    device: &raw::Device,
+    image: &raw::Image,
+    p_subresource: &ImageSubresource2KHR,
+    p_layout: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_image_subresource_layout2_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { image.clone() }),
+        ptr::from_ref(p_subresource),
+        ptr::from_mut(p_layout.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2EXT.html>"]
 #[doc(alias = "vkGetImageSubresourceLayout2EXT")]
 pub unsafe fn get_image_subresource_layout2_ext<
@@ -13209,6 +15121,30 @@ pub unsafe fn get_image_subresource_layout2_ext<
     S::setup_cleanup(p_layout.as_mut_ptr());
     p_layout.assume_init()
 }
+#[doc = "Same as [get_image_subresource_layout2_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2EXT.html>"]
+#[doc(alias = "vkGetImageSubresourceLayout2EXT")]
+pub unsafe fn get_image_subresource_layout2_ext_into<
+    S: StructureChain<SubresourceLayout2KHR<'static>>,
+>(
+    device: &raw::Device,
+    image: &raw::Image,
+    p_subresource: &ImageSubresource2KHR,
+    p_layout: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_image_subresource_layout2_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { image.clone() }),
+        ptr::from_ref(p_subresource),
+        ptr::from_mut(p_layout.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-amd")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAntiLagUpdateAMD.html>"]
 #[doc(alias = "vkAntiLagUpdateAMD")]
 pub unsafe fn anti_lag_update_amd(
@@ -13267,24 +15203,47 @@ pub unsafe fn destroy_shader_ext(
 }
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderBinaryDataEXT.html>"]
 #[doc(alias = "vkGetShaderBinaryDataEXT")]
-pub unsafe fn get_shader_binary_data_ext(
+pub unsafe fn get_shader_binary_data_ext<R: DynamicArray<u8>>(
     device: &raw::Device,
     shader: &raw::ShaderEXT,
-    p_data: VoidPtr,
     dispatcher: &CommandsDispatcher,
-) -> Result<usize> {
+) -> Result<R> {
     let vulkan_command = dispatcher
         .get_shader_binary_data_ext
         .get()
         .expect("Vulkan command not loaded.");
-    let mut p_data_size = MaybeUninit::uninit();
-    let vk_status = vulkan_command(
+    let mut vk_len = MaybeUninit::uninit();
+    let p_data_size = vk_len.as_mut_ptr();
+    let p_data = ptr::null_mut();
+    vulkan_command(
         Some(unsafe { device.clone() }),
         Some(unsafe { shader.clone() }),
-        p_data_size.as_mut_ptr(),
-        p_data,
-    );
-    vk_status.map_success(|| p_data_size.assume_init())
+        p_data_size,
+        p_data as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_data_size = ptr::from_mut(&mut vk_len);
+    let mut p_data = vk_vec.get_content_mut_ptr();
+    let vk_status = loop {
+        let status = vulkan_command(
+            Some(unsafe { device.clone() }),
+            Some(unsafe { shader.clone() }),
+            p_data_size,
+            p_data as VoidPtr,
+        );
+        if status != Status::Incomplete {
+            break status;
+        }
+        vk_vec.update_with_capacity(vk_len as _);
+        p_data_size = ptr::from_mut(&mut vk_len);
+        p_data = vk_vec.get_content_mut_ptr();
+    };
+    vk_status.map_success(|| {
+        vk_vec.resize_with_len(vk_len as _);
+        vk_vec
+    })
 }
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadersEXT.html>"]
 #[doc(alias = "vkCmdBindShadersEXT")]
@@ -13352,6 +15311,30 @@ pub unsafe fn create_pipeline_binaries_khr<
         p_binaries.assume_init()
     })
 }
+#[doc = "Same as [create_pipeline_binaries_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineBinariesKHR.html>"]
+#[doc(alias = "vkCreatePipelineBinariesKHR")]
+pub unsafe fn create_pipeline_binaries_khr_into<
+    S: StructureChain<PipelineBinaryHandlesInfoKHR<'static>>,
+>(
+    device: &raw::Device,
+    p_create_info: &PipelineBinaryCreateInfoKHR,
+    p_allocator: Option<&AllocationCallbacks>,
+    p_binaries: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<Status> {
+    let vulkan_command = dispatcher
+        .create_pipeline_binaries_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_create_info),
+        p_allocator.map(|v| ptr::from_ref(v)).unwrap_or(ptr::null()),
+        ptr::from_mut(p_binaries.as_mut()),
+    )
+    .into_result()
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPipelineBinaryKHR.html>"]
 #[doc(alias = "vkDestroyPipelineBinaryKHR")]
 pub unsafe fn destroy_pipeline_binary_khr(
@@ -13395,6 +15378,79 @@ pub unsafe fn get_pipeline_key_khr<S: StructureChainOut<PipelineBinaryKeyKHR<'st
         p_pipeline_key.assume_init()
     })
 }
+#[doc = "Same as [get_pipeline_key_khr], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineKeyKHR.html>"]
+#[doc(alias = "vkGetPipelineKeyKHR")]
+pub unsafe fn get_pipeline_key_khr_into<S: StructureChain<PipelineBinaryKeyKHR<'static>>>(
+    device: &raw::Device,
+    p_pipeline_create_info: Option<&PipelineCreateInfoKHR>,
+    p_pipeline_key: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_pipeline_key_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        p_pipeline_create_info
+            .map(|v| ptr::from_ref(v))
+            .unwrap_or(ptr::null()),
+        ptr::from_mut(p_pipeline_key.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineBinaryDataKHR.html>"]
+#[doc(alias = "vkGetPipelineBinaryDataKHR")]
+pub unsafe fn get_pipeline_binary_data_khr<
+    S: StructureChainOut<PipelineBinaryKeyKHR<'static>>,
+    R: DynamicArray<u8>,
+>(
+    device: &raw::Device,
+    p_info: &PipelineBinaryDataInfoKHR,
+    dispatcher: &CommandsDispatcher,
+) -> Result<(S, R)> {
+    let vulkan_command = dispatcher
+        .get_pipeline_binary_data_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_pipeline_binary_key = MaybeUninit::uninit();
+    S::setup_uninit(&mut p_pipeline_binary_key);
+    let mut vk_len = MaybeUninit::uninit();
+    let p_pipeline_binary_data_size = vk_len.as_mut_ptr();
+    let p_pipeline_binary_data = ptr::null_mut();
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        S::get_uninit_head_ptr(p_pipeline_binary_key.as_mut_ptr()),
+        p_pipeline_binary_data_size,
+        p_pipeline_binary_data as VoidPtr,
+    )
+    .map_success(|| ())?;
+    let mut vk_len = vk_len.assume_init();
+    let mut vk_vec = R::create_with_capacity(vk_len as _);
+    let mut p_pipeline_binary_data_size = ptr::from_mut(&mut vk_len);
+    let mut p_pipeline_binary_data = vk_vec.get_content_mut_ptr();
+    let vk_status = vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        S::get_uninit_head_ptr(p_pipeline_binary_key.as_mut_ptr()),
+        p_pipeline_binary_data_size,
+        p_pipeline_binary_data as VoidPtr,
+    );
+    vk_status.map_success(|| {
+        (
+            {
+                S::setup_cleanup(p_pipeline_binary_key.as_mut_ptr());
+                p_pipeline_binary_key.assume_init()
+            },
+            {
+                vk_vec.resize_with_len(vk_len as _);
+                vk_vec
+            },
+        )
+    })
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseCapturedPipelineDataKHR.html>"]
 #[doc(alias = "vkReleaseCapturedPipelineDataKHR")]
 pub unsafe fn release_captured_pipeline_data_khr(
@@ -13414,6 +15470,7 @@ pub unsafe fn release_captured_pipeline_data_khr(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-qcom")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFramebufferTilePropertiesQCOM.html>"]
 #[doc(alias = "vkGetFramebufferTilePropertiesQCOM")]
 pub unsafe fn get_framebuffer_tile_properties_qcom<R: DynamicArray<TilePropertiesQCOM<'static>>>(
@@ -13458,6 +15515,7 @@ pub unsafe fn get_framebuffer_tile_properties_qcom<R: DynamicArray<TilePropertie
         vk_vec
     })
 }
+#[cfg(feature = "vendor-qcom")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDynamicRenderingTilePropertiesQCOM.html>"]
 #[doc(alias = "vkGetDynamicRenderingTilePropertiesQCOM")]
 pub unsafe fn get_dynamic_rendering_tile_properties_qcom<
@@ -13483,6 +15541,30 @@ pub unsafe fn get_dynamic_rendering_tile_properties_qcom<
         p_properties.assume_init()
     })
 }
+#[cfg(feature = "vendor-qcom")]
+#[doc = "Same as [get_dynamic_rendering_tile_properties_qcom], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDynamicRenderingTilePropertiesQCOM.html>"]
+#[doc(alias = "vkGetDynamicRenderingTilePropertiesQCOM")]
+pub unsafe fn get_dynamic_rendering_tile_properties_qcom_into<
+    S: StructureChain<TilePropertiesQCOM<'static>>,
+>(
+    device: &raw::Device,
+    p_rendering_info: &RenderingInfo,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_dynamic_rendering_tile_properties_qcom
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_rendering_info),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLatencySleepModeNV.html>"]
 #[doc(alias = "vkSetLatencySleepModeNV")]
 pub unsafe fn set_latency_sleep_mode_nv(
@@ -13502,6 +15584,7 @@ pub unsafe fn set_latency_sleep_mode_nv(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkLatencySleepNV.html>"]
 #[doc(alias = "vkLatencySleepNV")]
 pub unsafe fn latency_sleep_nv(
@@ -13521,6 +15604,7 @@ pub unsafe fn latency_sleep_nv(
     )
     .map_success(|| ())
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLatencyMarkerNV.html>"]
 #[doc(alias = "vkSetLatencyMarkerNV")]
 pub unsafe fn set_latency_marker_nv(
@@ -13539,6 +15623,7 @@ pub unsafe fn set_latency_marker_nv(
         ptr::from_ref(p_latency_marker_info),
     )
 }
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetLatencyTimingsNV.html>"]
 #[doc(alias = "vkGetLatencyTimingsNV")]
 pub unsafe fn get_latency_timings_nv<S: StructureChainOut<GetLatencyMarkerInfoNV<'static>>>(
@@ -13560,6 +15645,27 @@ pub unsafe fn get_latency_timings_nv<S: StructureChainOut<GetLatencyMarkerInfoNV
     S::setup_cleanup(p_latency_marker_info.as_mut_ptr());
     p_latency_marker_info.assume_init()
 }
+#[cfg(feature = "vendor-nv")]
+#[doc = "Same as [get_latency_timings_nv], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetLatencyTimingsNV.html>"]
+#[doc(alias = "vkGetLatencyTimingsNV")]
+pub unsafe fn get_latency_timings_nv_into<S: StructureChain<GetLatencyMarkerInfoNV<'static>>>(
+    device: &raw::Device,
+    swapchain: &raw::SwapchainKHR,
+    p_latency_marker_info: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_latency_timings_nv
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        Some(unsafe { swapchain.clone() }),
+        ptr::from_mut(p_latency_marker_info.as_mut()),
+    )
+}
+#[cfg(feature = "vendor-nv")]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueNotifyOutOfBandNV.html>"]
 #[doc(alias = "vkQueueNotifyOutOfBandNV")]
 pub unsafe fn queue_notify_out_of_band_nv(
@@ -13632,6 +15738,7 @@ pub unsafe fn cmd_set_attachment_feedback_loop_enable_ext(
         .expect("Vulkan command not loaded.");
     vulkan_command(Some(unsafe { command_buffer.clone() }), aspect_mask)
 }
+#[cfg(any(feature = "platform-screen", target_os = "nto"))]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetScreenBufferPropertiesQNX.html>"]
 #[doc(alias = "vkGetScreenBufferPropertiesQNX")]
 pub unsafe fn get_screen_buffer_properties_qnx<
@@ -13657,6 +15764,29 @@ pub unsafe fn get_screen_buffer_properties_qnx<
         p_properties.assume_init()
     })
 }
+#[cfg(any(feature = "platform-screen", target_os = "nto"))]
+#[doc = "Same as [get_screen_buffer_properties_qnx], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetScreenBufferPropertiesQNX.html>"]
+#[doc(alias = "vkGetScreenBufferPropertiesQNX")]
+pub unsafe fn get_screen_buffer_properties_qnx_into<
+    S: StructureChain<ScreenBufferPropertiesQNX<'static>>,
+>(
+    device: &raw::Device,
+    buffer: &VoidPtr,
+    p_properties: &mut S,
+    dispatcher: &CommandsDispatcher,
+) -> Result<()> {
+    let vulkan_command = dispatcher
+        .get_screen_buffer_properties_qnx
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(buffer),
+        ptr::from_mut(p_properties.as_mut()),
+    )
+    .map_success(|| ())
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleKHR.html>"]
 #[doc(alias = "vkCmdSetLineStippleKHR")]
 pub unsafe fn cmd_set_line_stipple_khr(
@@ -13775,6 +15905,66 @@ pub unsafe fn get_physical_device_calibrateable_time_domains_ext<R: DynamicArray
         vk_vec
     })
 }
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCalibratedTimestampsKHR.html>"]
+#[doc(alias = "vkGetCalibratedTimestampsKHR")]
+pub unsafe fn get_calibrated_timestamps_khr<'a, R: DynamicArray<u64>>(
+    device: &raw::Device,
+    p_timestamp_infos: impl AsSlice<'a, CalibratedTimestampInfoKHR<'a>>,
+    dispatcher: &CommandsDispatcher,
+) -> Result<(R, u64)> {
+    let vulkan_command = dispatcher
+        .get_calibrated_timestamps_khr
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_timestamps = R::create_with_capacity(p_timestamp_infos.as_slice().len() as _);
+    let mut p_max_deviation = MaybeUninit::uninit();
+    let vk_status = vulkan_command(
+        Some(unsafe { device.clone() }),
+        p_timestamp_infos.as_slice().len() as _,
+        p_timestamp_infos.as_slice().as_ptr().cast(),
+        p_timestamps.get_content_mut_ptr(),
+        p_max_deviation.as_mut_ptr(),
+    );
+    vk_status.map_success(|| {
+        (
+            {
+                p_timestamps.resize_with_len(p_timestamp_infos.as_slice().len() as _);
+                p_timestamps
+            },
+            { p_max_deviation.assume_init() },
+        )
+    })
+}
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCalibratedTimestampsEXT.html>"]
+#[doc(alias = "vkGetCalibratedTimestampsEXT")]
+pub unsafe fn get_calibrated_timestamps_ext<'a, R: DynamicArray<u64>>(
+    device: &raw::Device,
+    p_timestamp_infos: impl AsSlice<'a, CalibratedTimestampInfoKHR<'a>>,
+    dispatcher: &CommandsDispatcher,
+) -> Result<(R, u64)> {
+    let vulkan_command = dispatcher
+        .get_calibrated_timestamps_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    let mut p_timestamps = R::create_with_capacity(p_timestamp_infos.as_slice().len() as _);
+    let mut p_max_deviation = MaybeUninit::uninit();
+    let vk_status = vulkan_command(
+        Some(unsafe { device.clone() }),
+        p_timestamp_infos.as_slice().len() as _,
+        p_timestamp_infos.as_slice().as_ptr().cast(),
+        p_timestamps.get_content_mut_ptr(),
+        p_max_deviation.as_mut_ptr(),
+    );
+    vk_status.map_success(|| {
+        (
+            {
+                p_timestamps.resize_with_len(p_timestamp_infos.as_slice().len() as _);
+                p_timestamps
+            },
+            { p_max_deviation.assume_init() },
+        )
+    })
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorSets2KHR.html>"]
 #[doc(alias = "vkCmdBindDescriptorSets2KHR")]
 pub unsafe fn cmd_bind_descriptor_sets2_khr(
@@ -13894,6 +16084,27 @@ pub unsafe fn get_generated_commands_memory_requirements_ext<
     S::setup_cleanup(p_memory_requirements.as_mut_ptr());
     p_memory_requirements.assume_init()
 }
+#[doc = "Same as [get_generated_commands_memory_requirements_ext], filling the structure chain given by the caller"]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsEXT.html>"]
+#[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsEXT")]
+pub unsafe fn get_generated_commands_memory_requirements_ext_into<
+    S: StructureChain<MemoryRequirements2<'static>>,
+>(
+    device: &raw::Device,
+    p_info: &GeneratedCommandsMemoryRequirementsInfoEXT,
+    p_memory_requirements: &mut S,
+    dispatcher: &CommandsDispatcher,
+) {
+    let vulkan_command = dispatcher
+        .get_generated_commands_memory_requirements_ext
+        .get()
+        .expect("Vulkan command not loaded.");
+    vulkan_command(
+        Some(unsafe { device.clone() }),
+        ptr::from_ref(p_info),
+        ptr::from_mut(p_memory_requirements.as_mut()),
+    )
+}
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsEXT.html>"]
 #[doc(alias = "vkCmdPreprocessGeneratedCommandsEXT")]
 pub unsafe fn cmd_preprocess_generated_commands_ext(
@@ -46,19 +46,29 @@ handle_nondispatchable! { SwapchainKHR , SwapchainKHR , doc = "<https://www.khro
 handle_nondispatchable! { DisplayKHR , DisplayKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplayKHR.html>" , "VkDisplayKHR" }
 handle_nondispatchable! { DisplayModeKHR , DisplayModeKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplayModeKHR.html>" , "VkDisplayModeKHR" }
 handle_nondispatchable! { DebugReportCallbackEXT , DebugReportCallbackEXT , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugReportCallbackEXT.html>" , "VkDebugReportCallbackEXT" }
+handle_nondispatchable! { VideoSessionKHR , VideoSessionKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoSessionKHR.html>" , "VkVideoSessionKHR" }
+handle_nondispatchable! { VideoSessionParametersKHR , VideoSessionParametersKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoSessionParametersKHR.html>" , "VkVideoSessionParametersKHR" }
+#[cfg(feature = "vendor-nvx")]
 handle_nondispatchable! { CuModuleNVX , CuModuleNVX , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCuModuleNVX.html>" , "VkCuModuleNVX" }
+#[cfg(feature = "vendor-nvx")]
 handle_nondispatchable! { CuFunctionNVX , CuFunctionNVX , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCuFunctionNVX.html>" , "VkCuFunctionNVX" }
 handle_nondispatchable! { DebugUtilsMessengerEXT , DebugUtilsMessengerEXT , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessengerEXT.html>" , "VkDebugUtilsMessengerEXT" }
 handle_nondispatchable! { AccelerationStructureKHR , AccelerationStructureKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureKHR.html>" , "VkAccelerationStructureKHR" }
 handle_nondispatchable! { ValidationCacheEXT , ValidationCacheEXT , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkValidationCacheEXT.html>" , "VkValidationCacheEXT" }
 handle_nondispatchable! { AccelerationStructureNV , AccelerationStructureNV , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureNV.html>" , "VkAccelerationStructureNV" }
+#[cfg(feature = "vendor-intel")]
 handle_nondispatchable! { PerformanceConfigurationINTEL , PerformanceConfigurationINTEL , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceConfigurationINTEL.html>" , "VkPerformanceConfigurationINTEL" }
 handle_nondispatchable! { DeferredOperationKHR , DeferredOperationKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeferredOperationKHR.html>" , "VkDeferredOperationKHR" }
+#[cfg(feature = "vendor-nv")]
 handle_nondispatchable! { IndirectCommandsLayoutNV , IndirectCommandsLayoutNV , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsLayoutNV.html>" , "VkIndirectCommandsLayoutNV" }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 handle_nondispatchable! { CudaModuleNV , CudaModuleNV , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCudaModuleNV.html>" , "VkCudaModuleNV" }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 handle_nondispatchable! { CudaFunctionNV , CudaFunctionNV , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCudaFunctionNV.html>" , "VkCudaFunctionNV" }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 handle_nondispatchable! { BufferCollectionFUCHSIA , BufferCollectionFUCHSIA , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferCollectionFUCHSIA.html>" , "VkBufferCollectionFUCHSIA" }
 handle_nondispatchable! { MicromapEXT , MicromapEXT , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMicromapEXT.html>" , "VkMicromapEXT" }
+#[cfg(feature = "vendor-nv")]
 handle_nondispatchable! { OpticalFlowSessionNV , OpticalFlowSessionNV , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowSessionNV.html>" , "VkOpticalFlowSessionNV" }
 handle_nondispatchable! { ShaderEXT , ShaderEXT , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderEXT.html>" , "VkShaderEXT" }
 handle_nondispatchable! { PipelineBinaryKHR , PipelineBinaryKHR , doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineBinaryKHR.html>" , "VkPipelineBinaryKHR" }
@@ -0,0 +1,610 @@
+//! Recording state validation, enabled by the `recording-checks` feature in debug builds
+//!
+//! Every command buffer created or begun through [vk::rs] has its state tracked: whether it is recording,
+//! whether it is inside a render pass instance, the pipeline bind points with a bound pipeline,
+//! as well as its level and the capabilities of the queue family of its command pool.
+//! Each `vkCmd*` command is checked against the `queues`, `renderpass` and `cmdbufferlevel` attributes
+//! of vk.xml before being called and a violation panics with a message describing it.
+//!
+//! Command buffers must be begun using [vk::rs::CommandBuffer::begin] for their commands to be checked.
+//! The level and queue family checks are only done for command buffers allocated from a command pool and a device
+//! created with [vk::rs].
+
+use std::collections::HashMap;
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+use crate::{vk, Alias, Allocator, Dispatcher, DynamicArray, Handle};
+
+pub(crate) mod commands;
+
+/// Where a command can be recorded relative to a render pass instance
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenderPassScope {
+    Inside,
+    Outside,
+    Both,
+}
+
+/// Effect of a command on the tracked state
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StateChange {
+    None,
+    BeginRenderPass(RenderPassKind),
+    EndRenderPass(RenderPassKind),
+    BindShaders,
+}
+
+/// How a render pass instance was begun
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum RenderPassKind {
+    RenderPass,
+    Rendering,
+}
+
+/// Requirements of a command buffer command, as described by vk.xml
+pub(crate) struct CommandInfo {
+    pub name: &'static str,
+    /// Empty if the command is only supported by queues not tracked by the validator
+    pub queues: vk::QueueFlags,
+    pub primary: bool,
+    pub secondary: bool,
+    pub render_pass: RenderPassScope,
+    /// Pipeline bind point which must have a bound pipeline (or shader objects) for the command to be valid
+    pub bind_point: Option<vk::PipelineBindPoint>,
+    pub state_change: StateChange,
+}
+
+struct CommandBufferState {
+    pool: Option<NonZeroU64>,
+    level: Option<vk::CommandBufferLevel>,
+    queues: Option<vk::QueueFlags>,
+    recording: bool,
+    /// Render pass instance begun in this command buffer
+    render_pass: Option<RenderPassKind>,
+    /// Secondary command buffer begun with [vk::CommandBufferUsageFlags::RenderPassContinue]
+    render_pass_continue: bool,
+    bound_pipelines: Vec<vk::PipelineBindPoint>,
+}
+
+impl CommandBufferState {
+    fn new(
+        pool: Option<NonZeroU64>,
+        level: Option<vk::CommandBufferLevel>,
+        queues: Option<vk::QueueFlags>,
+    ) -> Self {
+        Self {
+            pool,
+            level,
+            queues,
+            recording: false,
+            render_pass: None,
+            render_pass_continue: false,
+            bound_pipelines: Vec::new(),
+        }
+    }
+
+    /// Move the command buffer back to the initial state
+    fn reset(&mut self) {
+        self.recording = false;
+        self.render_pass = None;
+        self.render_pass_continue = false;
+        self.bound_pipelines.clear();
+    }
+
+    fn is_inside_render_pass(&self) -> bool {
+        self.render_pass.is_some() || self.render_pass_continue
+    }
+}
+
+struct PoolState {
+    device: NonZeroUsize,
+    queues: Option<vk::QueueFlags>,
+}
+
+#[derive(Default)]
+struct Registry {
+    device_queues: HashMap<NonZeroUsize, Vec<vk::QueueFlags>>,
+    pools: HashMap<NonZeroU64, PoolState>,
+    command_buffers: HashMap<NonZeroUsize, CommandBufferState>,
+}
+
+impl Registry {
+    /// Forget the given command pools and the command buffers allocated from them
+    fn remove_pools(&mut self, mut is_removed: impl FnMut(NonZeroU64, &PoolState) -> bool) {
+        self.pools.retain(|&pool, state| !is_removed(pool, state));
+        let pools = &self.pools;
+        self.command_buffers
+            .retain(|_, state| state.pool.map_or(true, |pool| pools.contains_key(&pool)));
+    }
+}
+
+fn registry() -> MutexGuard<'static, Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY
+        .get_or_init(Default::default)
+        .lock()
+        // a failed check panics but leaves the registry in a valid state
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Panic outside of the registry lock
+fn report(result: Result<(), String>) {
+    if let Err(msg) = result {
+        panic!("{msg}");
+    }
+}
+
+pub(crate) fn create_device<D: Dispatcher, A: Allocator>(
+    physical_device: &vk::rs::PhysicalDevice<D, A>,
+    result: vk::Result<vk::rs::Device<D, A>>,
+) -> vk::Result<vk::rs::Device<D, A>> {
+    if let Ok(device) = &result {
+        let families: Vec<vk::QueueFamilyProperties> =
+            physical_device.get_queue_family_properties();
+        registry().device_queues.insert(
+            device.as_raw(),
+            families.iter().map(|family| family.queue_flags).collect(),
+        );
+    }
+    result
+}
+
+pub(crate) fn create_command_pool(
+    device: &vk::raw::Device,
+    create_info: &vk::CommandPoolCreateInfo,
+    result: vk::Result<vk::rs::CommandPool>,
+) -> vk::Result<vk::rs::CommandPool> {
+    if let Ok(pool) = &result {
+        let mut registry = registry();
+        let queues = registry
+            .device_queues
+            .get(&device.as_raw())
+            .and_then(|families| families.get(create_info.queue_family_index as usize))
+            .copied();
+        registry.pools.insert(
+            pool.as_raw(),
+            PoolState {
+                device: device.as_raw(),
+                queues,
+            },
+        );
+    }
+    result
+}
+
+pub(crate) fn destroy_device(device: &vk::raw::Device) {
+    let mut registry = registry();
+    registry.device_queues.remove(&device.as_raw());
+    // the handles of the objects of a destroyed device can be reused by the next one
+    registry.remove_pools(|_, pool| pool.device == device.as_raw());
+}
+
+pub(crate) fn reset_command_pool(command_pool: &vk::raw::CommandPool) {
+    for state in registry().command_buffers.values_mut() {
+        if state.pool == Some(command_pool.as_raw()) {
+            state.reset();
+        }
+    }
+}
+
+pub(crate) fn destroy_command_pool(command_pool: Option<&vk::raw::CommandPool>) {
+    if let Some(command_pool) = command_pool {
+        registry().remove_pools(|pool, _| pool == command_pool.as_raw());
+    }
+}
+
+pub(crate) fn allocate_command_buffers<D: Dispatcher, A: Allocator, R>(
+    allocate_info: &vk::CommandBufferAllocateInfo,
+    mut result: vk::Result<R>,
+) -> vk::Result<R>
+where
+    R: DynamicArray<vk::rs::CommandBuffer<D, A>>,
+{
+    if let Ok(command_buffers) = &mut result {
+        // Safety: on success, the array contains command_buffer_count command buffers
+        let command_buffers = unsafe {
+            std::slice::from_raw_parts(
+                command_buffers.get_content_mut_ptr(),
+                allocate_info.command_buffer_count as usize,
+            )
+        };
+        let pool = allocate_info
+            .command_pool
+            .as_ref()
+            .map(|pool| pool.as_raw());
+        let mut registry = registry();
+        let queues = pool
+            .and_then(|pool| registry.pools.get(&pool))
+            .and_then(|pool| pool.queues);
+        for command_buffer in command_buffers {
+            registry.command_buffers.insert(
+                command_buffer.as_raw(),
+                CommandBufferState::new(pool, Some(allocate_info.level), queues),
+            );
+        }
+    }
+    result
+}
+
+pub(crate) fn free_command_buffers<V: Alias<vk::raw::CommandBuffer>>(command_buffers: &[V]) {
+    // Safety: V has the same representation as a raw command buffer
+    let command_buffers: &[vk::raw::CommandBuffer] = unsafe {
+        std::slice::from_raw_parts(command_buffers.as_ptr().cast(), command_buffers.len())
+    };
+    let mut registry = registry();
+    for command_buffer in command_buffers {
+        registry.command_buffers.remove(&command_buffer.as_raw());
+    }
+}
+
+pub(crate) fn begin(
+    command_buffer: &vk::raw::CommandBuffer,
+    begin_info: &vk::CommandBufferBeginInfo,
+) {
+    let mut registry = registry();
+    let state = registry
+        .command_buffers
+        .entry(command_buffer.as_raw())
+        .or_insert_with(|| CommandBufferState::new(None, None, None));
+    // beginning a command buffer implicitly resets it
+    state.reset();
+    state.recording = true;
+    state.render_pass_continue = state.level != Some(vk::CommandBufferLevel::Primary)
+        && begin_info
+            .flags
+            .contains(vk::CommandBufferUsageFlags::RenderPassContinue);
+}
+
+pub(crate) fn end(command_buffer: &vk::raw::CommandBuffer) {
+    let result = (|| {
+        let mut registry = registry();
+        let Some(state) = registry.command_buffers.get_mut(&command_buffer.as_raw()) else {
+            return Ok(());
+        };
+        if !state.recording {
+            return Err(format!(
+                "vkEndCommandBuffer: command buffer {command_buffer} is not in the recording state"
+            ));
+        }
+        if let Some(kind) = state.render_pass {
+            return Err(format!(
+                "vkEndCommandBuffer: command buffer {command_buffer} is still inside a render pass instance begun with {}",
+                begin_name(kind)
+            ));
+        }
+        state.recording = false;
+        Ok(())
+    })();
+    report(result);
+}
+
+pub(crate) fn reset(command_buffer: &vk::raw::CommandBuffer) {
+    if let Some(state) = registry().command_buffers.get_mut(&command_buffer.as_raw()) {
+        state.reset();
+    }
+}
+
+pub(crate) fn bind_pipeline(
+    command_buffer: &vk::raw::CommandBuffer,
+    info: &CommandInfo,
+    bind_point: vk::PipelineBindPoint,
+) {
+    check(command_buffer, info);
+    if let Some(state) = registry().command_buffers.get_mut(&command_buffer.as_raw()) {
+        if !state.bound_pipelines.contains(&bind_point) {
+            state.bound_pipelines.push(bind_point);
+        }
+    }
+}
+
+fn begin_name(kind: RenderPassKind) -> &'static str {
+    match kind {
+        RenderPassKind::RenderPass => "vkCmdBeginRenderPass",
+        RenderPassKind::Rendering => "vkCmdBeginRendering",
+    }
+}
+
+/// Check that the command described by `info` can be recorded in `command_buffer` and update its state
+pub(crate) fn check(command_buffer: &vk::raw::CommandBuffer, info: &CommandInfo) {
+    let result = (|| {
+        let name = info.name;
+        let mut registry = registry();
+        let Some(state) = registry.command_buffers.get_mut(&command_buffer.as_raw()) else {
+            return Err(format!(
+                "{name}: command buffer {command_buffer} is not in the recording state (it was never begun)"
+            ));
+        };
+        if !state.recording {
+            return Err(format!(
+                "{name}: command buffer {command_buffer} is not in the recording state"
+            ));
+        }
+
+        match state.level {
+            Some(vk::CommandBufferLevel::Primary) if !info.primary => {
+                return Err(format!(
+                    "{name} cannot be recorded in a primary command buffer"
+                ))
+            }
+            Some(vk::CommandBufferLevel::Secondary) if !info.secondary => {
+                return Err(format!(
+                    "{name} cannot be recorded in a secondary command buffer"
+                ))
+            }
+            _ => {}
+        }
+
+        if let Some(queues) = state.queues {
+            if !info.queues.is_empty() && !queues.intersects(info.queues) {
+                return Err(format!(
+                    "{name} requires a queue family supporting one of {:?}, but command buffer {command_buffer} was allocated for a queue family supporting {queues:?}",
+                    info.queues
+                ));
+            }
+        }
+
+        let inside = state.is_inside_render_pass();
+        match info.render_pass {
+            RenderPassScope::Inside if !inside => {
+                return Err(format!(
+                    "{name} must be recorded inside a render pass instance"
+                ))
+            }
+            RenderPassScope::Outside if inside => {
+                return Err(format!(
+                    "{name} must be recorded outside of a render pass instance"
+                ))
+            }
+            _ => {}
+        }
+
+        if let Some(bind_point) = info.bind_point {
+            if !state.bound_pipelines.contains(&bind_point) {
+                return Err(format!(
+                    "{name} requires a pipeline to be bound to the {bind_point:?} bind point"
+                ));
+            }
+        }
+
+        match info.state_change {
+            StateChange::None => {}
+            StateChange::BeginRenderPass(kind) => state.render_pass = Some(kind),
+            StateChange::EndRenderPass(kind) => match state.render_pass {
+                Some(current) if current == kind => state.render_pass = None,
+                Some(current) => {
+                    return Err(format!(
+                        "{name} cannot end a render pass instance begun with {}",
+                        begin_name(current)
+                    ))
+                }
+                None => {
+                    return Err(format!(
+                    "{name}: no render pass instance was begun in command buffer {command_buffer}"
+                ))
+                }
+            },
+            StateChange::BindShaders => {
+                for bind_point in [
+                    vk::PipelineBindPoint::Graphics,
+                    vk::PipelineBindPoint::Compute,
+                ] {
+                    if !state.bound_pipelines.contains(&bind_point) {
+                        state.bound_pipelines.push(bind_point);
+                    }
+                }
+            }
+        }
+        Ok(())
+    })();
+    report(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroU64, NonZeroUsize};
+
+    use super::commands::{
+        CMD_BEGIN_RENDERING, CMD_BEGIN_RENDER_PASS, CMD_BIND_PIPELINE, CMD_DISPATCH, CMD_DRAW,
+        CMD_END_RENDERING, CMD_END_RENDER_PASS, CMD_SET_VIEWPORT,
+    };
+    use super::{begin, check, end, registry, CommandBufferState, PoolState};
+    use crate::{vk, Handle};
+
+    /// The registry is shared by all the tests, so each one uses its own handle values
+    fn device(id: usize) -> vk::raw::Device {
+        unsafe { vk::raw::Device::from_raw(NonZeroUsize::new(id).unwrap()) }
+    }
+
+    fn pool(id: u64) -> vk::raw::CommandPool {
+        unsafe { vk::raw::CommandPool::from_raw(NonZeroU64::new(id).unwrap()) }
+    }
+
+    /// Register a command buffer allocated from `pool` on `device` for a queue family supporting `queues`
+    fn allocate(
+        id: usize,
+        device: usize,
+        pool: u64,
+        level: vk::CommandBufferLevel,
+        queues: vk::QueueFlags,
+    ) -> vk::raw::CommandBuffer {
+        let command_buffer =
+            unsafe { vk::raw::CommandBuffer::from_raw(NonZeroUsize::new(id).unwrap()) };
+        let mut registry = registry();
+        registry.pools.insert(
+            NonZeroU64::new(pool).unwrap(),
+            PoolState {
+                device: NonZeroUsize::new(device).unwrap(),
+                queues: Some(queues),
+            },
+        );
+        registry.command_buffers.insert(
+            command_buffer.as_raw(),
+            CommandBufferState::new(NonZeroU64::new(pool), Some(level), Some(queues)),
+        );
+        command_buffer
+    }
+
+    fn primary(id: usize, pool: u64) -> vk::raw::CommandBuffer {
+        allocate(
+            id,
+            1,
+            pool,
+            vk::CommandBufferLevel::Primary,
+            vk::QueueFlags::Graphics | vk::QueueFlags::Compute,
+        )
+    }
+
+    fn is_registered(command_buffer: &vk::raw::CommandBuffer) -> bool {
+        registry()
+            .command_buffers
+            .contains_key(&command_buffer.as_raw())
+    }
+
+    fn is_recording(command_buffer: &vk::raw::CommandBuffer) -> bool {
+        registry().command_buffers[&command_buffer.as_raw()].recording
+    }
+
+    #[test]
+    fn valid_sequence() {
+        let cmd = primary(100, 100);
+        begin(&cmd, &Default::default());
+        check(&cmd, &CMD_BEGIN_RENDER_PASS);
+        super::bind_pipeline(&cmd, &CMD_BIND_PIPELINE, vk::PipelineBindPoint::Graphics);
+        check(&cmd, &CMD_DRAW);
+        check(&cmd, &CMD_END_RENDER_PASS);
+        check(&cmd, &CMD_BEGIN_RENDERING);
+        check(&cmd, &CMD_DRAW);
+        check(&cmd, &CMD_END_RENDERING);
+        end(&cmd);
+        assert!(!is_recording(&cmd));
+
+        // beginning the command buffer again forgets the bound pipelines
+        begin(&cmd, &Default::default());
+        assert!(registry().command_buffers[&cmd.as_raw()]
+            .bound_pipelines
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "vkCmdDraw must be recorded inside a render pass instance")]
+    fn draw_outside_render_pass() {
+        let cmd = primary(110, 110);
+        begin(&cmd, &Default::default());
+        super::bind_pipeline(&cmd, &CMD_BIND_PIPELINE, vk::PipelineBindPoint::Graphics);
+        check(&cmd, &CMD_DRAW);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "vkCmdDispatch requires a pipeline to be bound to the Compute bind point"
+    )]
+    fn dispatch_without_pipeline() {
+        let cmd = primary(120, 120);
+        begin(&cmd, &Default::default());
+        super::bind_pipeline(&cmd, &CMD_BIND_PIPELINE, vk::PipelineBindPoint::Graphics);
+        check(&cmd, &CMD_DISPATCH);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not in the recording state")]
+    fn command_after_end() {
+        let cmd = primary(130, 130);
+        begin(&cmd, &Default::default());
+        end(&cmd);
+        check(&cmd, &CMD_SET_VIEWPORT);
+    }
+
+    #[test]
+    #[should_panic(expected = "vkCmdBeginRenderPass requires a queue family supporting one of")]
+    fn graphics_command_on_compute_queue() {
+        let cmd = allocate(
+            140,
+            1,
+            140,
+            vk::CommandBufferLevel::Primary,
+            vk::QueueFlags::Compute | vk::QueueFlags::Transfer,
+        );
+        begin(&cmd, &Default::default());
+        check(&cmd, &CMD_BEGIN_RENDER_PASS);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "vkCmdEndRenderPass cannot end a render pass instance begun with vkCmdBeginRendering"
+    )]
+    fn mismatched_render_pass_end() {
+        let cmd = primary(150, 150);
+        begin(&cmd, &Default::default());
+        check(&cmd, &CMD_BEGIN_RENDERING);
+        check(&cmd, &CMD_END_RENDER_PASS);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "is still inside a render pass instance begun with vkCmdBeginRenderPass"
+    )]
+    fn end_inside_render_pass() {
+        let cmd = primary(160, 160);
+        begin(&cmd, &Default::default());
+        check(&cmd, &CMD_BEGIN_RENDER_PASS);
+        end(&cmd);
+    }
+
+    #[test]
+    fn render_pass_continue_secondary() {
+        let cmd = allocate(
+            170,
+            1,
+            170,
+            vk::CommandBufferLevel::Secondary,
+            vk::QueueFlags::Graphics,
+        );
+        begin(
+            &cmd,
+            &vk::CommandBufferBeginInfo::default()
+                .flags(vk::CommandBufferUsageFlags::RenderPassContinue),
+        );
+        super::bind_pipeline(&cmd, &CMD_BIND_PIPELINE, vk::PipelineBindPoint::Graphics);
+        check(&cmd, &CMD_DRAW);
+        end(&cmd);
+    }
+
+    #[test]
+    fn reset_pool_returns_to_initial_state() {
+        let cmd = primary(180, 180);
+        begin(&cmd, &Default::default());
+        check(&cmd, &CMD_BEGIN_RENDER_PASS);
+        super::reset_command_pool(&pool(180));
+
+        let registry = registry();
+        let state = &registry.command_buffers[&cmd.as_raw()];
+        assert!(!state.recording);
+        assert!(state.render_pass.is_none());
+    }
+
+    #[test]
+    fn free_and_destroy_forget_command_buffers() {
+        let freed = primary(190, 190);
+        let kept = primary(191, 190);
+        super::free_command_buffers(&[unsafe { freed.clone() }]);
+        assert!(!is_registered(&freed));
+        assert!(is_registered(&kept));
+
+        super::destroy_command_pool(Some(&pool(190)));
+        assert!(!is_registered(&kept));
+        assert!(!registry().pools.contains_key(&pool(190).as_raw()));
+    }
+
+    #[test]
+    fn destroy_device_forgets_its_pools() {
+        let queues = vk::QueueFlags::Graphics;
+        let destroyed = allocate(200, 200, 200, vk::CommandBufferLevel::Primary, queues);
+        let other = allocate(201, 201, 201, vk::CommandBufferLevel::Primary, queues);
+        super::destroy_device(&device(200));
+
+        assert!(!is_registered(&destroyed));
+        assert!(!registry().pools.contains_key(&pool(200).as_raw()));
+        assert!(is_registered(&other));
+    }
+}
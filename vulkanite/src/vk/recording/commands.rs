@@ -0,0 +1,2192 @@
+use super::{CommandInfo, RenderPassKind, RenderPassScope, StateChange};
+use crate::vk::{PipelineBindPoint, QueueFlags};
+pub(crate) const CMD_BIND_PIPELINE: CommandInfo = CommandInfo {
+    name: "vkCmdBindPipeline",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_VIEWPORT: CommandInfo = CommandInfo {
+    name: "vkCmdSetViewport",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_SCISSOR: CommandInfo = CommandInfo {
+    name: "vkCmdSetScissor",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_LINE_WIDTH: CommandInfo = CommandInfo {
+    name: "vkCmdSetLineWidth",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_BIAS: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthBias",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_BLEND_CONSTANTS: CommandInfo = CommandInfo {
+    name: "vkCmdSetBlendConstants",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_BOUNDS: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthBounds",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_STENCIL_COMPARE_MASK: CommandInfo = CommandInfo {
+    name: "vkCmdSetStencilCompareMask",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_STENCIL_WRITE_MASK: CommandInfo = CommandInfo {
+    name: "vkCmdSetStencilWriteMask",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_STENCIL_REFERENCE: CommandInfo = CommandInfo {
+    name: "vkCmdSetStencilReference",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_DESCRIPTOR_SETS: CommandInfo = CommandInfo {
+    name: "vkCmdBindDescriptorSets",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_INDEX_BUFFER: CommandInfo = CommandInfo {
+    name: "vkCmdBindIndexBuffer",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_VERTEX_BUFFERS: CommandInfo = CommandInfo {
+    name: "vkCmdBindVertexBuffers",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW: CommandInfo = CommandInfo {
+    name: "vkCmdDraw",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_INDEXED: CommandInfo = CommandInfo {
+    name: "vkCmdDrawIndexed",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_INDIRECT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawIndirect",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_INDEXED_INDIRECT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawIndexedIndirect",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DISPATCH: CommandInfo = CommandInfo {
+    name: "vkCmdDispatch",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::Compute),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DISPATCH_INDIRECT: CommandInfo = CommandInfo {
+    name: "vkCmdDispatchIndirect",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::Compute),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_BUFFER: CommandInfo = CommandInfo {
+    name: "vkCmdCopyBuffer",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_IMAGE: CommandInfo = CommandInfo {
+    name: "vkCmdCopyImage",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BLIT_IMAGE: CommandInfo = CommandInfo {
+    name: "vkCmdBlitImage",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_BUFFER_TO_IMAGE: CommandInfo = CommandInfo {
+    name: "vkCmdCopyBufferToImage",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_IMAGE_TO_BUFFER: CommandInfo = CommandInfo {
+    name: "vkCmdCopyImageToBuffer",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_UPDATE_BUFFER: CommandInfo = CommandInfo {
+    name: "vkCmdUpdateBuffer",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_FILL_BUFFER: CommandInfo = CommandInfo {
+    name: "vkCmdFillBuffer",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_CLEAR_COLOR_IMAGE: CommandInfo = CommandInfo {
+    name: "vkCmdClearColorImage",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_CLEAR_DEPTH_STENCIL_IMAGE: CommandInfo = CommandInfo {
+    name: "vkCmdClearDepthStencilImage",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_CLEAR_ATTACHMENTS: CommandInfo = CommandInfo {
+    name: "vkCmdClearAttachments",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_RESOLVE_IMAGE: CommandInfo = CommandInfo {
+    name: "vkCmdResolveImage",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_EVENT: CommandInfo = CommandInfo {
+    name: "vkCmdSetEvent",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_RESET_EVENT: CommandInfo = CommandInfo {
+    name: "vkCmdResetEvent",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_WAIT_EVENTS: CommandInfo = CommandInfo {
+    name: "vkCmdWaitEvents",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PIPELINE_BARRIER: CommandInfo = CommandInfo {
+    name: "vkCmdPipelineBarrier",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_QUERY: CommandInfo = CommandInfo {
+    name: "vkCmdBeginQuery",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_QUERY: CommandInfo = CommandInfo {
+    name: "vkCmdEndQuery",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_RESET_QUERY_POOL: CommandInfo = CommandInfo {
+    name: "vkCmdResetQueryPool",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_WRITE_TIMESTAMP: CommandInfo = CommandInfo {
+    name: "vkCmdWriteTimestamp",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_QUERY_POOL_RESULTS: CommandInfo = CommandInfo {
+    name: "vkCmdCopyQueryPoolResults",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PUSH_CONSTANTS: CommandInfo = CommandInfo {
+    name: "vkCmdPushConstants",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_RENDER_PASS: CommandInfo = CommandInfo {
+    name: "vkCmdBeginRenderPass",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::BeginRenderPass(RenderPassKind::RenderPass),
+};
+pub(crate) const CMD_NEXT_SUBPASS: CommandInfo = CommandInfo {
+    name: "vkCmdNextSubpass",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_RENDER_PASS: CommandInfo = CommandInfo {
+    name: "vkCmdEndRenderPass",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::EndRenderPass(RenderPassKind::RenderPass),
+};
+pub(crate) const CMD_EXECUTE_COMMANDS: CommandInfo = CommandInfo {
+    name: "vkCmdExecuteCommands",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEVICE_MASK: CommandInfo = CommandInfo {
+    name: "vkCmdSetDeviceMask",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DISPATCH_BASE: CommandInfo = CommandInfo {
+    name: "vkCmdDispatchBase",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::Compute),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_INDIRECT_COUNT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawIndirectCount",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_INDEXED_INDIRECT_COUNT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawIndexedIndirectCount",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_RENDER_PASS2: CommandInfo = CommandInfo {
+    name: "vkCmdBeginRenderPass2",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::BeginRenderPass(RenderPassKind::RenderPass),
+};
+pub(crate) const CMD_NEXT_SUBPASS2: CommandInfo = CommandInfo {
+    name: "vkCmdNextSubpass2",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_RENDER_PASS2: CommandInfo = CommandInfo {
+    name: "vkCmdEndRenderPass2",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::EndRenderPass(RenderPassKind::RenderPass),
+};
+pub(crate) const CMD_SET_EVENT2: CommandInfo = CommandInfo {
+    name: "vkCmdSetEvent2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_RESET_EVENT2: CommandInfo = CommandInfo {
+    name: "vkCmdResetEvent2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_WAIT_EVENTS2: CommandInfo = CommandInfo {
+    name: "vkCmdWaitEvents2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PIPELINE_BARRIER2: CommandInfo = CommandInfo {
+    name: "vkCmdPipelineBarrier2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_WRITE_TIMESTAMP2: CommandInfo = CommandInfo {
+    name: "vkCmdWriteTimestamp2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_BUFFER2: CommandInfo = CommandInfo {
+    name: "vkCmdCopyBuffer2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_IMAGE2: CommandInfo = CommandInfo {
+    name: "vkCmdCopyImage2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_BUFFER_TO_IMAGE2: CommandInfo = CommandInfo {
+    name: "vkCmdCopyBufferToImage2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_IMAGE_TO_BUFFER2: CommandInfo = CommandInfo {
+    name: "vkCmdCopyImageToBuffer2",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BLIT_IMAGE2: CommandInfo = CommandInfo {
+    name: "vkCmdBlitImage2",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_RESOLVE_IMAGE2: CommandInfo = CommandInfo {
+    name: "vkCmdResolveImage2",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_RENDERING: CommandInfo = CommandInfo {
+    name: "vkCmdBeginRendering",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::BeginRenderPass(RenderPassKind::Rendering),
+};
+pub(crate) const CMD_END_RENDERING: CommandInfo = CommandInfo {
+    name: "vkCmdEndRendering",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::EndRenderPass(RenderPassKind::Rendering),
+};
+pub(crate) const CMD_SET_CULL_MODE: CommandInfo = CommandInfo {
+    name: "vkCmdSetCullMode",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_FRONT_FACE: CommandInfo = CommandInfo {
+    name: "vkCmdSetFrontFace",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_PRIMITIVE_TOPOLOGY: CommandInfo = CommandInfo {
+    name: "vkCmdSetPrimitiveTopology",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_VIEWPORT_WITH_COUNT: CommandInfo = CommandInfo {
+    name: "vkCmdSetViewportWithCount",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_SCISSOR_WITH_COUNT: CommandInfo = CommandInfo {
+    name: "vkCmdSetScissorWithCount",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_VERTEX_BUFFERS2: CommandInfo = CommandInfo {
+    name: "vkCmdBindVertexBuffers2",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_TEST_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthTestEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_WRITE_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthWriteEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_COMPARE_OP: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthCompareOp",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_BOUNDS_TEST_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthBoundsTestEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_STENCIL_TEST_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetStencilTestEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_STENCIL_OP: CommandInfo = CommandInfo {
+    name: "vkCmdSetStencilOp",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_RASTERIZER_DISCARD_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetRasterizerDiscardEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_BIAS_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthBiasEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_PRIMITIVE_RESTART_ENABLE: CommandInfo = CommandInfo {
+    name: "vkCmdSetPrimitiveRestartEnable",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DEBUG_MARKER_BEGIN_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDebugMarkerBeginEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DEBUG_MARKER_END_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDebugMarkerEndEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DEBUG_MARKER_INSERT_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDebugMarkerInsertEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_VIDEO_CODING_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdBeginVideoCodingKHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::VideoDecodeKHR)
+        .union(QueueFlags::VideoEncodeKHR),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_VIDEO_CODING_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdEndVideoCodingKHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::VideoDecodeKHR)
+        .union(QueueFlags::VideoEncodeKHR),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_CONTROL_VIDEO_CODING_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdControlVideoCodingKHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::VideoDecodeKHR)
+        .union(QueueFlags::VideoEncodeKHR),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DECODE_VIDEO_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdDecodeVideoKHR",
+    queues: QueueFlags::empty().union(QueueFlags::VideoDecodeKHR),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_TRANSFORM_FEEDBACK_BUFFERS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBindTransformFeedbackBuffersEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_TRANSFORM_FEEDBACK_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBeginTransformFeedbackEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_TRANSFORM_FEEDBACK_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdEndTransformFeedbackEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_QUERY_INDEXED_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBeginQueryIndexedEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_QUERY_INDEXED_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdEndQueryIndexedEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_INDIRECT_BYTE_COUNT_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawIndirectByteCountEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nvx")]
+pub(crate) const CMD_CU_LAUNCH_KERNEL_NVX: CommandInfo = CommandInfo {
+    name: "vkCmdCuLaunchKernelNVX",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PUSH_DESCRIPTOR_SET_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdPushDescriptorSetKHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdPushDescriptorSetWithTemplateKHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_CONDITIONAL_RENDERING_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBeginConditionalRenderingEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_CONDITIONAL_RENDERING_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdEndConditionalRenderingEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_VIEWPORT_WSCALING_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetViewportWScalingNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DISCARD_RECTANGLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDiscardRectangleEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DISCARD_RECTANGLE_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDiscardRectangleEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DISCARD_RECTANGLE_MODE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDiscardRectangleModeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BEGIN_DEBUG_UTILS_LABEL_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBeginDebugUtilsLabelEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_END_DEBUG_UTILS_LABEL_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdEndDebugUtilsLabelEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_INSERT_DEBUG_UTILS_LABEL_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdInsertDebugUtilsLabelEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+pub(crate) const CMD_INITIALIZE_GRAPH_SCRATCH_MEMORY_AMDX: CommandInfo = CommandInfo {
+    name: "vkCmdInitializeGraphScratchMemoryAMDX",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+pub(crate) const CMD_DISPATCH_GRAPH_AMDX: CommandInfo = CommandInfo {
+    name: "vkCmdDispatchGraphAMDX",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+pub(crate) const CMD_DISPATCH_GRAPH_INDIRECT_AMDX: CommandInfo = CommandInfo {
+    name: "vkCmdDispatchGraphIndirectAMDX",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+pub(crate) const CMD_DISPATCH_GRAPH_INDIRECT_COUNT_AMDX: CommandInfo = CommandInfo {
+    name: "vkCmdDispatchGraphIndirectCountAMDX",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_SAMPLE_LOCATIONS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetSampleLocationsEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BUILD_ACCELERATION_STRUCTURES_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdBuildAccelerationStructuresKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BUILD_ACCELERATION_STRUCTURES_INDIRECT_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdBuildAccelerationStructuresIndirectKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_ACCELERATION_STRUCTURE_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdCopyAccelerationStructureKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdCopyAccelerationStructureToMemoryKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdCopyMemoryToAccelerationStructureKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdWriteAccelerationStructuresPropertiesKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_TRACE_RAYS_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdTraceRaysKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::RayTracingKHR),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_TRACE_RAYS_INDIRECT_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdTraceRaysIndirectKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::RayTracingKHR),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_RAY_TRACING_PIPELINE_STACK_SIZE_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdSetRayTracingPipelineStackSizeKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_BIND_SHADING_RATE_IMAGE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdBindShadingRateImageNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_VIEWPORT_SHADING_RATE_PALETTE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetViewportShadingRatePaletteNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COARSE_SAMPLE_ORDER_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoarseSampleOrderNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_BUILD_ACCELERATION_STRUCTURE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdBuildAccelerationStructureNV",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_COPY_ACCELERATION_STRUCTURE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdCopyAccelerationStructureNV",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_TRACE_RAYS_NV: CommandInfo = CommandInfo {
+    name: "vkCmdTraceRaysNV",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::RayTracingKHR),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_NV: CommandInfo = CommandInfo {
+    name: "vkCmdWriteAccelerationStructuresPropertiesNV",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-amd")]
+pub(crate) const CMD_WRITE_BUFFER_MARKER_AMD: CommandInfo = CommandInfo {
+    name: "vkCmdWriteBufferMarkerAMD",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_DRAW_MESH_TASKS_NV: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMeshTasksNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_DRAW_MESH_TASKS_INDIRECT_NV: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMeshTasksIndirectNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_NV: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMeshTasksIndirectCountNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_EXCLUSIVE_SCISSOR_ENABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetExclusiveScissorEnableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_EXCLUSIVE_SCISSOR_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetExclusiveScissorNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_CHECKPOINT_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCheckpointNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-intel")]
+pub(crate) const CMD_SET_PERFORMANCE_MARKER_INTEL: CommandInfo = CommandInfo {
+    name: "vkCmdSetPerformanceMarkerINTEL",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-intel")]
+pub(crate) const CMD_SET_PERFORMANCE_STREAM_MARKER_INTEL: CommandInfo = CommandInfo {
+    name: "vkCmdSetPerformanceStreamMarkerINTEL",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-intel")]
+pub(crate) const CMD_SET_PERFORMANCE_OVERRIDE_INTEL: CommandInfo = CommandInfo {
+    name: "vkCmdSetPerformanceOverrideINTEL",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_FRAGMENT_SHADING_RATE_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdSetFragmentShadingRateKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_RENDERING_ATTACHMENT_LOCATIONS_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdSetRenderingAttachmentLocationsKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_RENDERING_INPUT_ATTACHMENT_INDICES_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdSetRenderingInputAttachmentIndicesKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_PREPROCESS_GENERATED_COMMANDS_NV: CommandInfo = CommandInfo {
+    name: "vkCmdPreprocessGeneratedCommandsNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_EXECUTE_GENERATED_COMMANDS_NV: CommandInfo = CommandInfo {
+    name: "vkCmdExecuteGeneratedCommandsNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_BIND_PIPELINE_SHADER_GROUP_NV: CommandInfo = CommandInfo {
+    name: "vkCmdBindPipelineShaderGroupNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_BIAS2_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthBias2EXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_ENCODE_VIDEO_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdEncodeVideoKHR",
+    queues: QueueFlags::empty().union(QueueFlags::VideoEncodeKHR),
+    primary: true,
+    secondary: false,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+pub(crate) const CMD_CUDA_LAUNCH_KERNEL_NV: CommandInfo = CommandInfo {
+    name: "vkCmdCudaLaunchKernelNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-amd")]
+pub(crate) const CMD_WRITE_BUFFER_MARKER2_AMD: CommandInfo = CommandInfo {
+    name: "vkCmdWriteBufferMarker2AMD",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_DESCRIPTOR_BUFFERS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBindDescriptorBuffersEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DESCRIPTOR_BUFFER_OFFSETS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDescriptorBufferOffsetsEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_DESCRIPTOR_BUFFER_EMBEDDED_SAMPLERS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_FRAGMENT_SHADING_RATE_ENUM_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetFragmentShadingRateEnumNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_MESH_TASKS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMeshTasksEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_MESH_TASKS_INDIRECT_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMeshTasksIndirectEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMeshTasksIndirectCountEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_VERTEX_INPUT_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetVertexInputEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-huawei")]
+pub(crate) const CMD_SUBPASS_SHADING_HUAWEI: CommandInfo = CommandInfo {
+    name: "vkCmdSubpassShadingHUAWEI",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-huawei")]
+pub(crate) const CMD_BIND_INVOCATION_MASK_HUAWEI: CommandInfo = CommandInfo {
+    name: "vkCmdBindInvocationMaskHUAWEI",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_PATCH_CONTROL_POINTS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetPatchControlPointsEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_LOGIC_OP_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetLogicOpEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_COLOR_WRITE_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetColorWriteEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_TRACE_RAYS_INDIRECT2_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdTraceRaysIndirect2KHR",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: Some(PipelineBindPoint::RayTracingKHR),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_MULTI_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMultiEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_DRAW_MULTI_INDEXED_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdDrawMultiIndexedEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BUILD_MICROMAPS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBuildMicromapsEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_MICROMAP_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdCopyMicromapEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_MICROMAP_TO_MEMORY_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdCopyMicromapToMemoryEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_COPY_MEMORY_TO_MICROMAP_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdCopyMemoryToMicromapEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_WRITE_MICROMAPS_PROPERTIES_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdWriteMicromapsPropertiesEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-huawei")]
+pub(crate) const CMD_DRAW_CLUSTER_HUAWEI: CommandInfo = CommandInfo {
+    name: "vkCmdDrawClusterHUAWEI",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-huawei")]
+pub(crate) const CMD_DRAW_CLUSTER_INDIRECT_HUAWEI: CommandInfo = CommandInfo {
+    name: "vkCmdDrawClusterIndirectHUAWEI",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Inside,
+    bind_point: Some(PipelineBindPoint::Graphics),
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_COPY_MEMORY_INDIRECT_NV: CommandInfo = CommandInfo {
+    name: "vkCmdCopyMemoryIndirectNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_COPY_MEMORY_TO_IMAGE_INDIRECT_NV: CommandInfo = CommandInfo {
+    name: "vkCmdCopyMemoryToImageIndirectNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_DECOMPRESS_MEMORY_NV: CommandInfo = CommandInfo {
+    name: "vkCmdDecompressMemoryNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_DECOMPRESS_MEMORY_INDIRECT_COUNT_NV: CommandInfo = CommandInfo {
+    name: "vkCmdDecompressMemoryIndirectCountNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_UPDATE_PIPELINE_INDIRECT_BUFFER_NV: CommandInfo = CommandInfo {
+    name: "vkCmdUpdatePipelineIndirectBufferNV",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Transfer)
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_CLAMP_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthClampEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_POLYGON_MODE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetPolygonModeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_RASTERIZATION_SAMPLES_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetRasterizationSamplesEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_SAMPLE_MASK_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetSampleMaskEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_ALPHA_TO_COVERAGE_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetAlphaToCoverageEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_ALPHA_TO_ONE_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetAlphaToOneEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_LOGIC_OP_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetLogicOpEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_COLOR_BLEND_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetColorBlendEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_COLOR_BLEND_EQUATION_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetColorBlendEquationEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_COLOR_WRITE_MASK_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetColorWriteMaskEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_TESSELLATION_DOMAIN_ORIGIN_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetTessellationDomainOriginEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_RASTERIZATION_STREAM_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetRasterizationStreamEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_CONSERVATIVE_RASTERIZATION_MODE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetConservativeRasterizationModeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_EXTRA_PRIMITIVE_OVERESTIMATION_SIZE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_CLIP_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthClipEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_SAMPLE_LOCATIONS_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetSampleLocationsEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_COLOR_BLEND_ADVANCED_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetColorBlendAdvancedEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_PROVOKING_VERTEX_MODE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetProvokingVertexModeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_LINE_RASTERIZATION_MODE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetLineRasterizationModeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_LINE_STIPPLE_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetLineStippleEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DEPTH_CLIP_NEGATIVE_ONE_TO_ONE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthClipNegativeOneToOneEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_VIEWPORT_WSCALING_ENABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetViewportWScalingEnableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_VIEWPORT_SWIZZLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetViewportSwizzleNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COVERAGE_TO_COLOR_ENABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoverageToColorEnableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COVERAGE_TO_COLOR_LOCATION_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoverageToColorLocationNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COVERAGE_MODULATION_MODE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoverageModulationModeNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COVERAGE_MODULATION_TABLE_ENABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoverageModulationTableEnableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COVERAGE_MODULATION_TABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoverageModulationTableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_SHADING_RATE_IMAGE_ENABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetShadingRateImageEnableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_REPRESENTATIVE_FRAGMENT_TEST_ENABLE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetRepresentativeFragmentTestEnableNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_SET_COVERAGE_REDUCTION_MODE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdSetCoverageReductionModeNV",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+#[cfg(feature = "vendor-nv")]
+pub(crate) const CMD_OPTICAL_FLOW_EXECUTE_NV: CommandInfo = CommandInfo {
+    name: "vkCmdOpticalFlowExecuteNV",
+    queues: QueueFlags::empty().union(QueueFlags::OpticalFlowNV),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_INDEX_BUFFER2_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdBindIndexBuffer2KHR",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_SHADERS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBindShadersEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::BindShaders,
+};
+pub(crate) const CMD_SET_DEPTH_CLAMP_RANGE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDepthClampRangeEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetAttachmentFeedbackLoopEnableEXT",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_LINE_STIPPLE_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdSetLineStippleKHR",
+    queues: QueueFlags::empty().union(QueueFlags::Graphics),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_DESCRIPTOR_SETS2_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdBindDescriptorSets2KHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PUSH_CONSTANTS2_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdPushConstants2KHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PUSH_DESCRIPTOR_SET2_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdPushDescriptorSet2KHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE2_KHR: CommandInfo = CommandInfo {
+    name: "vkCmdPushDescriptorSetWithTemplate2KHR",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_SET_DESCRIPTOR_BUFFER_OFFSETS2_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdSetDescriptorBufferOffsets2EXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_BIND_DESCRIPTOR_BUFFER_EMBEDDED_SAMPLERS2_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdBindDescriptorBufferEmbeddedSamplers2EXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_PREPROCESS_GENERATED_COMMANDS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdPreprocessGeneratedCommandsEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Outside,
+    bind_point: None,
+    state_change: StateChange::None,
+};
+pub(crate) const CMD_EXECUTE_GENERATED_COMMANDS_EXT: CommandInfo = CommandInfo {
+    name: "vkCmdExecuteGeneratedCommandsEXT",
+    queues: QueueFlags::empty()
+        .union(QueueFlags::Graphics)
+        .union(QueueFlags::Compute),
+    primary: true,
+    secondary: true,
+    render_pass: RenderPassScope::Both,
+    bind_point: None,
+    state_change: StateChange::None,
+};
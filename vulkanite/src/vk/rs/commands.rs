@@ -1,12 +1,15 @@
 #![allow(unused_unsafe)]
 use crate::{
     vk::*, AdvancedDynamicArray, Alias, Allocator, AsSlice, DefaultAllocator, Dispatcher,
-    DynamicArray, DynamicDispatcher, Handle, StructureChainOut,
+    DynamicArray, DynamicDispatcher, Handle, StructureChain, StructureChainOut,
 };
 use std::{
     ffi::{c_int, CStr},
     ops::Deref,
 };
+// c_ulong is only used by platform commands
+#[allow(unused_imports)]
+use std::ffi::c_ulong;
 #[derive(Clone)]
 pub struct Entry<D: Dispatcher = DynamicDispatcher, A: Allocator = DefaultAllocator> {
     disp: D,
@@ -189,6 +192,14 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(
+        feature = "platform-xlib",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXlibSurfaceKHR.html>"]
     #[doc(alias = "vkCreateXlibSurfaceKHR")]
     pub fn create_xlib_surface_khr(
@@ -205,6 +216,14 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(
+        feature = "platform-xcb",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXcbSurfaceKHR.html>"]
     #[doc(alias = "vkCreateXcbSurfaceKHR")]
     pub fn create_xcb_surface_khr(
@@ -221,6 +240,14 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(
+        feature = "platform-wayland",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWaylandSurfaceKHR.html>"]
     #[doc(alias = "vkCreateWaylandSurfaceKHR")]
     pub fn create_wayland_surface_khr(
@@ -237,6 +264,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAndroidSurfaceKHR.html>"]
     #[doc(alias = "vkCreateAndroidSurfaceKHR")]
     pub fn create_android_surface_khr(
@@ -253,6 +281,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWin32SurfaceKHR.html>"]
     #[doc(alias = "vkCreateWin32SurfaceKHR")]
     pub fn create_win32_surface_khr(
@@ -326,6 +355,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
             )
         }
     }
+    #[cfg(feature = "platform-ggp")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateStreamDescriptorSurfaceGGP.html>"]
     #[doc(alias = "vkCreateStreamDescriptorSurfaceGGP")]
     pub fn create_stream_descriptor_surface_ggp(
@@ -342,6 +372,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(feature = "platform-vi")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateViSurfaceNN.html>"]
     #[doc(alias = "vkCreateViSurfaceNN")]
     pub fn create_vi_surface_nn(
@@ -358,6 +389,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-ios", target_os = "ios"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIOSSurfaceMVK.html>"]
     #[doc(alias = "vkCreateIOSSurfaceMVK")]
     pub fn create_iossurface_mvk(
@@ -374,6 +406,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-macos", target_os = "macos"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMacOSSurfaceMVK.html>"]
     #[doc(alias = "vkCreateMacOSSurfaceMVK")]
     pub fn create_mac_ossurface_mvk(
@@ -439,6 +472,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImagePipeSurfaceFUCHSIA.html>"]
     #[doc(alias = "vkCreateImagePipeSurfaceFUCHSIA")]
     pub fn create_image_pipe_surface_fuchsia(
@@ -455,6 +489,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMetalSurfaceEXT.html>"]
     #[doc(alias = "vkCreateMetalSurfaceEXT")]
     pub fn create_metal_surface_ext(
@@ -487,6 +522,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(feature = "platform-directfb")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDirectFBSurfaceEXT.html>"]
     #[doc(alias = "vkCreateDirectFBSurfaceEXT")]
     pub fn create_direct_fbsurface_ext(
@@ -503,6 +539,7 @@ impl<D: Dispatcher, A: Allocator> Instance<D, A> {
         };
         vk_result.map(|vk_result| unsafe { SurfaceKHR::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateScreenSurfaceQNX.html>"]
     #[doc(alias = "vkCreateScreenSurfaceQNX")]
     pub fn create_screen_surface_qnx(
@@ -618,19 +655,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDevice.html>"]
     #[doc(alias = "vkCreateDevice")]
+    #[allow(clippy::let_and_return)]
     pub fn create_device(&self, p_create_info: &DeviceCreateInfo) -> Result<Device<D, A>> {
-        let vk_result = unsafe {
-            raw::create_device(
-                self,
-                p_create_info,
-                self.alloc.get_allocation_callbacks().as_ref(),
-                self.disp.get_command_dispatcher(),
-            )
+        let result = {
+            let vk_result = unsafe {
+                raw::create_device(
+                    self,
+                    p_create_info,
+                    self.alloc.get_allocation_callbacks().as_ref(),
+                    self.disp.get_command_dispatcher(),
+                )
+            };
+            vk_result.map(|device| {
+                let disp = self.disp.clone_with_device(&device);
+                unsafe { Device::from_inner(device, disp, self.alloc.clone()) }
+            })
         };
-        vk_result.map(|device| {
-            let disp = self.disp.clone_with_device(&device);
-            unsafe { Device::from_inner(device, disp, self.alloc.clone()) }
-        })
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        let result = crate::vk::recording::create_device(self, result);
+        result
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceExtensionProperties.html>"]
     #[doc(alias = "vkEnumerateDeviceExtensionProperties")]
@@ -678,16 +721,61 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
     pub fn get_features2<S: StructureChainOut<PhysicalDeviceFeatures2<'static>>>(&self) -> S {
         unsafe { raw::get_physical_device_features2(self, self.disp.get_command_dispatcher()) }
     }
+    #[doc = "Same as [Self::get_features2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceFeatures2")]
+    pub fn get_features2_into<S: StructureChain<PhysicalDeviceFeatures2<'static>>>(
+        &self,
+        p_features: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_features2_into(
+                self,
+                p_features,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2KHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceFeatures2KHR")]
     pub fn get_features2_khr<S: StructureChainOut<PhysicalDeviceFeatures2<'static>>>(&self) -> S {
         unsafe { raw::get_physical_device_features2_khr(self, self.disp.get_command_dispatcher()) }
     }
+    #[doc = "Same as [Self::get_features2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2KHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceFeatures2KHR")]
+    pub fn get_features2_khr_into<S: StructureChain<PhysicalDeviceFeatures2<'static>>>(
+        &self,
+        p_features: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_features2_khr_into(
+                self,
+                p_features,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>"]
     #[doc(alias = "vkGetPhysicalDeviceProperties2")]
     pub fn get_properties2<S: StructureChainOut<PhysicalDeviceProperties2<'static>>>(&self) -> S {
         unsafe { raw::get_physical_device_properties2(self, self.disp.get_command_dispatcher()) }
     }
+    #[doc = "Same as [Self::get_properties2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceProperties2")]
+    pub fn get_properties2_into<S: StructureChain<PhysicalDeviceProperties2<'static>>>(
+        &self,
+        p_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_properties2_into(
+                self,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2KHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceProperties2KHR")]
     pub fn get_properties2_khr<S: StructureChainOut<PhysicalDeviceProperties2<'static>>>(
@@ -697,6 +785,21 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             raw::get_physical_device_properties2_khr(self, self.disp.get_command_dispatcher())
         }
     }
+    #[doc = "Same as [Self::get_properties2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2KHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceProperties2KHR")]
+    pub fn get_properties2_khr_into<S: StructureChain<PhysicalDeviceProperties2<'static>>>(
+        &self,
+        p_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_properties2_khr_into(
+                self,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>"]
     #[doc(alias = "vkGetPhysicalDeviceFormatProperties2")]
     pub fn get_format_properties2<S: StructureChainOut<FormatProperties2<'static>>>(
@@ -711,6 +814,23 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_format_properties2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceFormatProperties2")]
+    pub fn get_format_properties2_into<S: StructureChain<FormatProperties2<'static>>>(
+        &self,
+        format: Format,
+        p_format_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_format_properties2_into(
+                self,
+                format,
+                p_format_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2KHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceFormatProperties2KHR")]
     pub fn get_format_properties2_khr<S: StructureChainOut<FormatProperties2<'static>>>(
@@ -725,6 +845,23 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_format_properties2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2KHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceFormatProperties2KHR")]
+    pub fn get_format_properties2_khr_into<S: StructureChain<FormatProperties2<'static>>>(
+        &self,
+        format: Format,
+        p_format_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_format_properties2_khr_into(
+                self,
+                format,
+                p_format_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>"]
     #[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2")]
     pub fn get_image_format_properties2<S: StructureChainOut<ImageFormatProperties2<'static>>>(
@@ -739,6 +876,23 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_format_properties2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2")]
+    pub fn get_image_format_properties2_into<S: StructureChain<ImageFormatProperties2<'static>>>(
+        &self,
+        p_image_format_info: &PhysicalDeviceImageFormatInfo2,
+        p_image_format_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_physical_device_image_format_properties2_into(
+                self,
+                p_image_format_info,
+                p_image_format_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2KHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2KHR")]
     pub fn get_image_format_properties2_khr<
@@ -755,6 +909,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_format_properties2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2KHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceImageFormatProperties2KHR")]
+    pub fn get_image_format_properties2_khr_into<
+        S: StructureChain<ImageFormatProperties2<'static>>,
+    >(
+        &self,
+        p_image_format_info: &PhysicalDeviceImageFormatInfo2,
+        p_image_format_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_physical_device_image_format_properties2_khr_into(
+                self,
+                p_image_format_info,
+                p_image_format_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html>"]
     #[doc(alias = "vkGetPhysicalDeviceQueueFamilyProperties2")]
     pub fn get_queue_family_properties2<R: DynamicArray<QueueFamilyProperties2<'static>>>(
@@ -790,6 +963,23 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             raw::get_physical_device_memory_properties2(self, self.disp.get_command_dispatcher())
         }
     }
+    #[doc = "Same as [Self::get_memory_properties2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceMemoryProperties2")]
+    pub fn get_memory_properties2_into<
+        S: StructureChain<PhysicalDeviceMemoryProperties2<'static>>,
+    >(
+        &self,
+        p_memory_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_memory_properties2_into(
+                self,
+                p_memory_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2KHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceMemoryProperties2KHR")]
     pub fn get_memory_properties2_khr<
@@ -804,6 +994,23 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_memory_properties2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2KHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceMemoryProperties2KHR")]
+    pub fn get_memory_properties2_khr_into<
+        S: StructureChain<PhysicalDeviceMemoryProperties2<'static>>,
+    >(
+        &self,
+        p_memory_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_memory_properties2_khr_into(
+                self,
+                p_memory_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html>"]
     #[doc(alias = "vkGetPhysicalDeviceSparseImageFormatProperties2")]
     pub fn get_sparse_image_format_properties2<
@@ -852,6 +1059,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_external_buffer_properties], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferProperties.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceExternalBufferProperties")]
+    pub fn get_external_buffer_properties_into<
+        S: StructureChain<ExternalBufferProperties<'static>>,
+    >(
+        &self,
+        p_external_buffer_info: &PhysicalDeviceExternalBufferInfo,
+        p_external_buffer_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_external_buffer_properties_into(
+                self,
+                p_external_buffer_info,
+                p_external_buffer_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferPropertiesKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceExternalBufferPropertiesKHR")]
     pub fn get_external_buffer_properties_khr<
@@ -868,6 +1094,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_external_buffer_properties_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferPropertiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceExternalBufferPropertiesKHR")]
+    pub fn get_external_buffer_properties_khr_into<
+        S: StructureChain<ExternalBufferProperties<'static>>,
+    >(
+        &self,
+        p_external_buffer_info: &PhysicalDeviceExternalBufferInfo,
+        p_external_buffer_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_external_buffer_properties_khr_into(
+                self,
+                p_external_buffer_info,
+                p_external_buffer_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
     #[doc(alias = "vkGetPhysicalDeviceExternalFenceProperties")]
     pub fn get_external_fence_properties<S: StructureChainOut<ExternalFenceProperties<'static>>>(
@@ -882,6 +1127,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_external_fence_properties], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceExternalFenceProperties")]
+    pub fn get_external_fence_properties_into<
+        S: StructureChain<ExternalFenceProperties<'static>>,
+    >(
+        &self,
+        p_external_fence_info: &PhysicalDeviceExternalFenceInfo,
+        p_external_fence_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_external_fence_properties_into(
+                self,
+                p_external_fence_info,
+                p_external_fence_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFencePropertiesKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceExternalFencePropertiesKHR")]
     pub fn get_external_fence_properties_khr<
@@ -898,6 +1162,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_external_fence_properties_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFencePropertiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceExternalFencePropertiesKHR")]
+    pub fn get_external_fence_properties_khr_into<
+        S: StructureChain<ExternalFenceProperties<'static>>,
+    >(
+        &self,
+        p_external_fence_info: &PhysicalDeviceExternalFenceInfo,
+        p_external_fence_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_external_fence_properties_khr_into(
+                self,
+                p_external_fence_info,
+                p_external_fence_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>"]
     #[doc(alias = "vkGetPhysicalDeviceExternalSemaphoreProperties")]
     pub fn get_external_semaphore_properties<
@@ -914,6 +1197,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_external_semaphore_properties], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceExternalSemaphoreProperties")]
+    pub fn get_external_semaphore_properties_into<
+        S: StructureChain<ExternalSemaphoreProperties<'static>>,
+    >(
+        &self,
+        p_external_semaphore_info: &PhysicalDeviceExternalSemaphoreInfo,
+        p_external_semaphore_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_external_semaphore_properties_into(
+                self,
+                p_external_semaphore_info,
+                p_external_semaphore_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphorePropertiesKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR")]
     pub fn get_external_semaphore_properties_khr<
@@ -930,6 +1232,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_external_semaphore_properties_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphorePropertiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR")]
+    pub fn get_external_semaphore_properties_khr_into<
+        S: StructureChain<ExternalSemaphoreProperties<'static>>,
+    >(
+        &self,
+        p_external_semaphore_info: &PhysicalDeviceExternalSemaphoreInfo,
+        p_external_semaphore_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_external_semaphore_properties_khr_into(
+                self,
+                p_external_semaphore_info,
+                p_external_semaphore_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceToolProperties.html>"]
     #[doc(alias = "vkGetPhysicalDeviceToolProperties")]
     pub fn get_tool_properties<R: DynamicArray<PhysicalDeviceToolProperties<'static>>>(
@@ -1110,13 +1431,21 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(any(
+        feature = "platform-xlib",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceXlibPresentationSupportKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceXlibPresentationSupportKHR")]
     pub fn get_xlib_presentation_support_khr(
         &self,
         queue_family_index: u32,
         dpy: &VoidPtr,
-        visual_id: VoidPtr,
+        visual_id: c_ulong,
     ) -> bool {
         unsafe {
             raw::get_physical_device_xlib_presentation_support_khr(
@@ -1128,13 +1457,21 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(any(
+        feature = "platform-xcb",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceXcbPresentationSupportKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceXcbPresentationSupportKHR")]
     pub fn get_xcb_presentation_support_khr(
         &self,
         queue_family_index: u32,
         connection: &VoidPtr,
-        visualid: VoidPtr,
+        visualid: u32,
     ) -> bool {
         unsafe {
             raw::get_physical_device_xcb_presentation_support_khr(
@@ -1146,6 +1483,14 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(any(
+        feature = "platform-wayland",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceWaylandPresentationSupportKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceWaylandPresentationSupportKHR")]
     pub fn get_wayland_presentation_support_khr(
@@ -1162,6 +1507,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceWin32PresentationSupportKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceWin32PresentationSupportKHR")]
     pub fn get_win32_presentation_support_khr(&self, queue_family_index: u32) -> bool {
@@ -1173,6 +1519,52 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoCapabilitiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceVideoCapabilitiesKHR")]
+    pub fn get_video_capabilities_khr<S: StructureChainOut<VideoCapabilitiesKHR<'static>>>(
+        &self,
+        p_video_profile: &VideoProfileInfoKHR,
+    ) -> Result<S> {
+        unsafe {
+            raw::get_physical_device_video_capabilities_khr(
+                self,
+                p_video_profile,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "Same as [Self::get_video_capabilities_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoCapabilitiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceVideoCapabilitiesKHR")]
+    pub fn get_video_capabilities_khr_into<S: StructureChain<VideoCapabilitiesKHR<'static>>>(
+        &self,
+        p_video_profile: &VideoProfileInfoKHR,
+        p_capabilities: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_physical_device_video_capabilities_khr_into(
+                self,
+                p_video_profile,
+                p_capabilities,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceVideoFormatPropertiesKHR")]
+    pub fn get_video_format_properties_khr<R: DynamicArray<VideoFormatPropertiesKHR<'static>>>(
+        &self,
+        p_video_format_info: &PhysicalDeviceVideoFormatInfoKHR,
+    ) -> Result<R> {
+        unsafe {
+            raw::get_physical_device_video_format_properties_khr(
+                self,
+                p_video_format_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalImageFormatPropertiesNV.html>"]
     #[doc(alias = "vkGetPhysicalDeviceExternalImageFormatPropertiesNV")]
     pub fn get_external_image_format_properties_nv(
@@ -1202,6 +1594,14 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
     pub fn release_display_ext(&self, display: &raw::DisplayKHR) -> Result<()> {
         unsafe { raw::release_display_ext(self, display, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(any(
+        feature = "platform-xlib-xrandr",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireXlibDisplayEXT.html>"]
     #[doc(alias = "vkAcquireXlibDisplayEXT")]
     pub fn acquire_xlib_display_ext(&self, dpy: &VoidPtr, display: &raw::DisplayKHR) -> Result<()> {
@@ -1209,12 +1609,20 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             raw::acquire_xlib_display_ext(self, dpy, display, self.disp.get_command_dispatcher())
         }
     }
+    #[cfg(any(
+        feature = "platform-xlib-xrandr",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRandROutputDisplayEXT.html>"]
     #[doc(alias = "vkGetRandROutputDisplayEXT")]
     pub fn get_rand_routput_display_ext(
         &self,
         dpy: &VoidPtr,
-        rr_output: VoidPtr,
+        rr_output: c_ulong,
     ) -> Result<DisplayKHR> {
         let vk_result = unsafe {
             raw::get_rand_routput_display_ext(
@@ -1240,6 +1648,42 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_surface_capabilities2_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2EXT.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceSurfaceCapabilities2EXT")]
+    pub fn get_surface_capabilities2_ext_into<
+        S: StructureChain<SurfaceCapabilities2EXT<'static>>,
+    >(
+        &self,
+        surface: &raw::SurfaceKHR,
+        p_surface_capabilities: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_physical_device_surface_capabilities2_ext_into(
+                self,
+                surface,
+                p_surface_capabilities,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR.html>"]
+    #[doc(alias = "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR")]
+    pub fn enumerate_queue_family_performance_query_counters_khr<
+        R1: DynamicArray<PerformanceCounterKHR<'static>>,
+        R2: DynamicArray<PerformanceCounterDescriptionKHR<'static>>,
+    >(
+        &self,
+        queue_family_index: u32,
+    ) -> Result<(R1, R2)> {
+        unsafe {
+            raw::enumerate_physical_device_queue_family_performance_query_counters_khr(
+                self,
+                queue_family_index,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR")]
     pub fn get_queue_family_performance_query_passes_khr(
@@ -1268,6 +1712,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_surface_capabilities2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2KHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceSurfaceCapabilities2KHR")]
+    pub fn get_surface_capabilities2_khr_into<
+        S: StructureChain<SurfaceCapabilities2KHR<'static>>,
+    >(
+        &self,
+        p_surface_info: &PhysicalDeviceSurfaceInfo2KHR,
+        p_surface_capabilities: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_physical_device_surface_capabilities2_khr_into(
+                self,
+                p_surface_info,
+                p_surface_capabilities,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormats2KHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceSurfaceFormats2KHR")]
     pub fn get_surface_formats2_khr<R: DynamicArray<SurfaceFormat2KHR<'static>>>(
@@ -1334,6 +1797,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_display_plane_capabilities2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneCapabilities2KHR.html>"]
+    #[doc(alias = "vkGetDisplayPlaneCapabilities2KHR")]
+    pub fn get_display_plane_capabilities2_khr_into<
+        S: StructureChain<DisplayPlaneCapabilities2KHR<'static>>,
+    >(
+        &self,
+        p_display_plane_info: &DisplayPlaneInfo2KHR,
+        p_capabilities: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_display_plane_capabilities2_khr_into(
+                self,
+                p_display_plane_info,
+                p_capabilities,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMultisamplePropertiesEXT.html>"]
     #[doc(alias = "vkGetPhysicalDeviceMultisamplePropertiesEXT")]
     pub fn get_multisample_properties_ext<
@@ -1350,6 +1832,25 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_multisample_properties_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMultisamplePropertiesEXT.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceMultisamplePropertiesEXT")]
+    pub fn get_multisample_properties_ext_into<
+        S: StructureChain<MultisamplePropertiesEXT<'static>>,
+    >(
+        &self,
+        samples: SampleCountFlags,
+        p_multisample_properties: &mut S,
+    ) {
+        unsafe {
+            raw::get_physical_device_multisample_properties_ext_into(
+                self,
+                samples,
+                p_multisample_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFragmentShadingRatesKHR.html>"]
     #[doc(alias = "vkGetPhysicalDeviceFragmentShadingRatesKHR")]
     pub fn get_fragment_shading_rates_khr<
@@ -1364,6 +1865,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html>"]
     #[doc(alias = "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV")]
     pub fn get_cooperative_matrix_properties_nv<
@@ -1378,6 +1880,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV.html>"]
     #[doc(alias = "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV")]
     pub fn get_supported_framebuffer_mixed_samples_combinations_nv<
@@ -1392,6 +1895,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModes2EXT.html>"]
     #[doc(alias = "vkGetPhysicalDeviceSurfacePresentModes2EXT")]
     pub fn get_surface_present_modes2_ext<R: DynamicArray<PresentModeKHR>>(
@@ -1426,11 +1930,54 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
         };
         vk_result.map(|vk_result| unsafe { DisplayKHR::from_inner(vk_result) })
     }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR")]
+    pub fn get_video_encode_quality_level_properties_khr<
+        S: StructureChainOut<VideoEncodeQualityLevelPropertiesKHR<'static>>,
+    >(
+        &self,
+        p_quality_level_info: &PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+    ) -> Result<S> {
+        unsafe {
+            raw::get_physical_device_video_encode_quality_level_properties_khr(
+                self,
+                p_quality_level_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "Same as [Self::get_video_encode_quality_level_properties_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR.html>"]
+    #[doc(alias = "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR")]
+    pub fn get_video_encode_quality_level_properties_khr_into<
+        S: StructureChain<VideoEncodeQualityLevelPropertiesKHR<'static>>,
+    >(
+        &self,
+        p_quality_level_info: &PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+        p_quality_level_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_physical_device_video_encode_quality_level_properties_khr_into(
+                self,
+                p_quality_level_info,
+                p_quality_level_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireWinrtDisplayNV.html>"]
     #[doc(alias = "vkAcquireWinrtDisplayNV")]
     pub fn acquire_winrt_display_nv(&self, display: &raw::DisplayKHR) -> Result<()> {
         unsafe { raw::acquire_winrt_display_nv(self, display, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetWinrtDisplayNV.html>"]
     #[doc(alias = "vkGetWinrtDisplayNV")]
     pub fn get_winrt_display_nv(&self, device_relative_id: u32) -> Result<DisplayKHR> {
@@ -1439,6 +1986,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
         };
         vk_result.map(|vk_result| unsafe { DisplayKHR::from_inner(vk_result) })
     }
+    #[cfg(feature = "platform-directfb")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDirectFBPresentationSupportEXT.html>"]
     #[doc(alias = "vkGetPhysicalDeviceDirectFBPresentationSupportEXT")]
     pub fn get_direct_fbpresentation_support_ext(
@@ -1455,6 +2003,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceScreenPresentationSupportQNX.html>"]
     #[doc(alias = "vkGetPhysicalDeviceScreenPresentationSupportQNX")]
     pub fn get_screen_presentation_support_qnx(
@@ -1471,6 +2020,7 @@ impl<D: Dispatcher, A: Allocator> PhysicalDevice<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceOpticalFlowImageFormatsNV.html>"]
     #[doc(alias = "vkGetPhysicalDeviceOpticalFlowImageFormatsNV")]
     pub fn get_optical_flow_image_formats_nv<
@@ -1566,6 +2116,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDevice.html>"]
     #[doc(alias = "vkDestroyDevice")]
     pub unsafe fn destroy(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::destroy_device(self);
         unsafe {
             raw::destroy_device(
                 Some(self),
@@ -1589,8 +2141,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDeviceWaitIdle.html>"]
     #[doc(alias = "vkDeviceWaitIdle")]
-    pub fn wait_idle(&self) -> Result<()> {
-        unsafe { raw::device_wait_idle(self, self.disp.get_command_dispatcher()) }
+    pub fn wait_idle(&self) -> codes::DeviceWaitIdleResult<()> {
+        unsafe { raw::device_wait_idle(self, self.disp.get_command_dispatcher()).narrow_err() }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateMemory.html>"]
     #[doc(alias = "vkAllocateMemory")]
@@ -1776,8 +2328,15 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceStatus.html>"]
     #[doc(alias = "vkGetFenceStatus")]
-    pub fn get_fence_status(&self, fence: &raw::Fence) -> Result<Status> {
-        unsafe { raw::get_fence_status(self, fence, self.disp.get_command_dispatcher()) }
+    pub fn get_fence_status(
+        &self,
+        fence: &raw::Fence,
+    ) -> codes::GetFenceStatusResult<codes::GetFenceStatusStatus> {
+        unsafe {
+            raw::get_fence_status(self, fence, self.disp.get_command_dispatcher())
+                .and_then(Status::narrow_success)
+                .narrow_err()
+        }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitForFences.html>"]
     #[doc(alias = "vkWaitForFences")]
@@ -1786,7 +2345,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         p_fences: impl AsSlice<'a, V2>,
         wait_all: impl Into<Bool32>,
         timeout: u64,
-    ) -> Result<Status> {
+    ) -> codes::WaitForFencesResult<codes::WaitForFencesStatus> {
         unsafe {
             raw::wait_for_fences(
                 self,
@@ -1795,6 +2354,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
                 timeout,
                 self.disp.get_command_dispatcher(),
             )
+            .and_then(Status::narrow_success)
+            .narrow_err()
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSemaphore.html>"]
@@ -1849,8 +2410,15 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEventStatus.html>"]
     #[doc(alias = "vkGetEventStatus")]
-    pub fn get_event_status(&self, event: &raw::Event) -> Result<Status> {
-        unsafe { raw::get_event_status(self, event, self.disp.get_command_dispatcher()) }
+    pub fn get_event_status(
+        &self,
+        event: &raw::Event,
+    ) -> codes::GetEventStatusResult<codes::GetEventStatusStatus> {
+        unsafe {
+            raw::get_event_status(self, event, self.disp.get_command_dispatcher())
+                .and_then(Status::narrow_success)
+                .narrow_err()
+        }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetEvent.html>"]
     #[doc(alias = "vkSetEvent")]
@@ -2087,18 +2655,12 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineCacheData.html>"]
     #[doc(alias = "vkGetPipelineCacheData")]
-    pub fn get_pipeline_cache_data(
+    pub fn get_pipeline_cache_data<R: DynamicArray<u8>>(
         &self,
         pipeline_cache: &raw::PipelineCache,
-        p_data: VoidPtr,
-    ) -> Result<usize> {
+    ) -> Result<R> {
         unsafe {
-            raw::get_pipeline_cache_data(
-                self,
-                pipeline_cache,
-                p_data,
-                self.disp.get_command_dispatcher(),
-            )
+            raw::get_pipeline_cache_data(self, pipeline_cache, self.disp.get_command_dispatcher())
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMergePipelineCaches.html>"]
@@ -2416,23 +2978,31 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCommandPool.html>"]
     #[doc(alias = "vkCreateCommandPool")]
+    #[allow(clippy::let_and_return)]
     pub fn create_command_pool(
         &self,
         p_create_info: &CommandPoolCreateInfo,
     ) -> Result<CommandPool> {
-        let vk_result = unsafe {
-            raw::create_command_pool(
-                self,
-                p_create_info,
-                self.alloc.get_allocation_callbacks().as_ref(),
-                self.disp.get_command_dispatcher(),
-            )
+        let result = {
+            let vk_result = unsafe {
+                raw::create_command_pool(
+                    self,
+                    p_create_info,
+                    self.alloc.get_allocation_callbacks().as_ref(),
+                    self.disp.get_command_dispatcher(),
+                )
+            };
+            vk_result.map(|vk_result| unsafe { CommandPool::from_inner(vk_result) })
         };
-        vk_result.map(|vk_result| unsafe { CommandPool::from_inner(vk_result) })
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        let result = crate::vk::recording::create_command_pool(self, p_create_info, result);
+        result
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCommandPool.html>"]
     #[doc(alias = "vkDestroyCommandPool")]
     pub unsafe fn destroy_command_pool(&self, command_pool: Option<&raw::CommandPool>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::destroy_command_pool(command_pool);
         unsafe {
             raw::destroy_command_pool(
                 self,
@@ -2449,6 +3019,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         command_pool: &raw::CommandPool,
         flags: CommandPoolResetFlags,
     ) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::reset_command_pool(command_pool);
         unsafe {
             raw::reset_command_pool(
                 self,
@@ -2460,23 +3032,33 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateCommandBuffers.html>"]
     #[doc(alias = "vkAllocateCommandBuffers")]
+    #[allow(clippy::let_and_return)]
     pub fn allocate_command_buffers<
         R: AdvancedDynamicArray<CommandBuffer<D, A>, raw::CommandBuffer>,
     >(
         &self,
         p_allocate_info: &CommandBufferAllocateInfo,
     ) -> Result<R> {
-        let vk_result: Result<R::InnerArrayType> = unsafe {
-            raw::allocate_command_buffers(self, p_allocate_info, self.disp.get_command_dispatcher())
+        let result = {
+            let vk_result: Result<R::InnerArrayType> = unsafe {
+                raw::allocate_command_buffers(
+                    self,
+                    p_allocate_info,
+                    self.disp.get_command_dispatcher(),
+                )
+            };
+            vk_result.map(|vk_result| {
+                vk_result
+                    .into_iter()
+                    .map(|el| unsafe {
+                        CommandBuffer::from_inner(el, self.disp.clone(), self.alloc.clone())
+                    })
+                    .collect()
+            })
         };
-        vk_result.map(|vk_result| {
-            vk_result
-                .into_iter()
-                .map(|el| unsafe {
-                    CommandBuffer::from_inner(el, self.disp.clone(), self.alloc.clone())
-                })
-                .collect()
-        })
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        let result = crate::vk::recording::allocate_command_buffers(p_allocate_info, result);
+        result
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeCommandBuffers.html>"]
     #[doc(alias = "vkFreeCommandBuffers")]
@@ -2485,6 +3067,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         command_pool: &raw::CommandPool,
         p_command_buffers: impl AsSlice<'a, V3>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::free_command_buffers(p_command_buffers.as_slice());
         unsafe {
             raw::free_command_buffers(
                 self,
@@ -2576,6 +3160,23 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             raw::get_image_memory_requirements2(self, p_info, self.disp.get_command_dispatcher())
         }
     }
+    #[doc = "Same as [Self::get_image_memory_requirements2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2.html>"]
+    #[doc(alias = "vkGetImageMemoryRequirements2")]
+    pub fn get_image_memory_requirements2_into<S: StructureChain<MemoryRequirements2<'static>>>(
+        &self,
+        p_info: &ImageMemoryRequirementsInfo2,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_image_memory_requirements2_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2KHR.html>"]
     #[doc(alias = "vkGetImageMemoryRequirements2KHR")]
     pub fn get_image_memory_requirements2_khr<
@@ -2592,6 +3193,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_memory_requirements2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2KHR.html>"]
+    #[doc(alias = "vkGetImageMemoryRequirements2KHR")]
+    pub fn get_image_memory_requirements2_khr_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &ImageMemoryRequirementsInfo2,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_image_memory_requirements2_khr_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2.html>"]
     #[doc(alias = "vkGetBufferMemoryRequirements2")]
     pub fn get_buffer_memory_requirements2<S: StructureChainOut<MemoryRequirements2<'static>>>(
@@ -2602,6 +3222,23 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             raw::get_buffer_memory_requirements2(self, p_info, self.disp.get_command_dispatcher())
         }
     }
+    #[doc = "Same as [Self::get_buffer_memory_requirements2], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2.html>"]
+    #[doc(alias = "vkGetBufferMemoryRequirements2")]
+    pub fn get_buffer_memory_requirements2_into<S: StructureChain<MemoryRequirements2<'static>>>(
+        &self,
+        p_info: &BufferMemoryRequirementsInfo2,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_buffer_memory_requirements2_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2KHR.html>"]
     #[doc(alias = "vkGetBufferMemoryRequirements2KHR")]
     pub fn get_buffer_memory_requirements2_khr<
@@ -2618,6 +3255,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_buffer_memory_requirements2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2KHR.html>"]
+    #[doc(alias = "vkGetBufferMemoryRequirements2KHR")]
+    pub fn get_buffer_memory_requirements2_khr_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &BufferMemoryRequirementsInfo2,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_buffer_memory_requirements2_khr_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSparseMemoryRequirements2.html>"]
     #[doc(alias = "vkGetImageSparseMemoryRequirements2")]
     pub fn get_image_sparse_memory_requirements2<
@@ -2858,6 +3514,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_descriptor_set_layout_support], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupport.html>"]
+    #[doc(alias = "vkGetDescriptorSetLayoutSupport")]
+    pub fn get_descriptor_set_layout_support_into<
+        S: StructureChain<DescriptorSetLayoutSupport<'static>>,
+    >(
+        &self,
+        p_create_info: &DescriptorSetLayoutCreateInfo,
+        p_support: &mut S,
+    ) {
+        unsafe {
+            raw::get_descriptor_set_layout_support_into(
+                self,
+                p_create_info,
+                p_support,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupportKHR.html>"]
     #[doc(alias = "vkGetDescriptorSetLayoutSupportKHR")]
     pub fn get_descriptor_set_layout_support_khr<
@@ -2874,6 +3549,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_descriptor_set_layout_support_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupportKHR.html>"]
+    #[doc(alias = "vkGetDescriptorSetLayoutSupportKHR")]
+    pub fn get_descriptor_set_layout_support_khr_into<
+        S: StructureChain<DescriptorSetLayoutSupport<'static>>,
+    >(
+        &self,
+        p_create_info: &DescriptorSetLayoutCreateInfo,
+        p_support: &mut S,
+    ) {
+        unsafe {
+            raw::get_descriptor_set_layout_support_khr_into(
+                self,
+                p_create_info,
+                p_support,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRenderPass2.html>"]
     #[doc(alias = "vkCreateRenderPass2")]
     pub fn create_render_pass2(&self, p_create_info: &RenderPassCreateInfo2) -> Result<RenderPass> {
@@ -2959,7 +3653,11 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitSemaphores.html>"]
     #[doc(alias = "vkWaitSemaphores")]
-    pub fn wait_semaphores(&self, p_wait_info: &SemaphoreWaitInfo, timeout: u64) -> Result<Status> {
+    pub fn wait_semaphores(
+        &self,
+        p_wait_info: &SemaphoreWaitInfo,
+        timeout: u64,
+    ) -> codes::WaitSemaphoresResult<codes::WaitSemaphoresStatus> {
         unsafe {
             raw::wait_semaphores(
                 self,
@@ -2967,6 +3665,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
                 timeout,
                 self.disp.get_command_dispatcher(),
             )
+            .and_then(Status::narrow_success)
+            .narrow_err()
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitSemaphoresKHR.html>"]
@@ -2975,7 +3675,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         &self,
         p_wait_info: &SemaphoreWaitInfo,
         timeout: u64,
-    ) -> Result<Status> {
+    ) -> codes::WaitSemaphoresKHRResult<codes::WaitSemaphoresKHRStatus> {
         unsafe {
             raw::wait_semaphores_khr(
                 self,
@@ -2983,6 +3683,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
                 timeout,
                 self.disp.get_command_dispatcher(),
             )
+            .and_then(Status::narrow_success)
+            .narrow_err()
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSignalSemaphore.html>"]
@@ -3216,6 +3918,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_device_buffer_memory_requirements], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirements.html>"]
+    #[doc(alias = "vkGetDeviceBufferMemoryRequirements")]
+    pub fn get_device_buffer_memory_requirements_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &DeviceBufferMemoryRequirements,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_device_buffer_memory_requirements_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirementsKHR.html>"]
     #[doc(alias = "vkGetDeviceBufferMemoryRequirementsKHR")]
     pub fn get_buffer_memory_requirements_khr<
@@ -3232,6 +3953,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_buffer_memory_requirements_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirementsKHR.html>"]
+    #[doc(alias = "vkGetDeviceBufferMemoryRequirementsKHR")]
+    pub fn get_buffer_memory_requirements_khr_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &DeviceBufferMemoryRequirements,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_device_buffer_memory_requirements_khr_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirements.html>"]
     #[doc(alias = "vkGetDeviceImageMemoryRequirements")]
     pub fn get_device_image_memory_requirements<
@@ -3248,6 +3988,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_device_image_memory_requirements], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirements.html>"]
+    #[doc(alias = "vkGetDeviceImageMemoryRequirements")]
+    pub fn get_device_image_memory_requirements_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &DeviceImageMemoryRequirements,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_device_image_memory_requirements_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirementsKHR.html>"]
     #[doc(alias = "vkGetDeviceImageMemoryRequirementsKHR")]
     pub fn get_image_memory_requirements_khr<S: StructureChainOut<MemoryRequirements2<'static>>>(
@@ -3262,6 +4021,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_memory_requirements_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirementsKHR.html>"]
+    #[doc(alias = "vkGetDeviceImageMemoryRequirementsKHR")]
+    pub fn get_image_memory_requirements_khr_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &DeviceImageMemoryRequirements,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_device_image_memory_requirements_khr_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageSparseMemoryRequirements.html>"]
     #[doc(alias = "vkGetDeviceImageSparseMemoryRequirements")]
     pub fn get_device_image_sparse_memory_requirements<
@@ -3299,7 +4077,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     pub fn create_swapchain_khr(
         &self,
         p_create_info: &SwapchainCreateInfoKHR,
-    ) -> Result<SwapchainKHR> {
+    ) -> codes::CreateSwapchainKHRResult<SwapchainKHR> {
         let vk_result = unsafe {
             raw::create_swapchain_khr(
                 self,
@@ -3308,7 +4086,9 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
                 self.disp.get_command_dispatcher(),
             )
         };
-        vk_result.map(|vk_result| unsafe { SwapchainKHR::from_inner(vk_result) })
+        vk_result
+            .map(|vk_result| unsafe { SwapchainKHR::from_inner(vk_result) })
+            .narrow_err()
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySwapchainKHR.html>"]
     #[doc(alias = "vkDestroySwapchainKHR")]
@@ -3346,7 +4126,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         timeout: u64,
         semaphore: Option<&raw::Semaphore>,
         fence: Option<&raw::Fence>,
-    ) -> Result<(Status, u32)> {
+    ) -> codes::AcquireNextImageKHRResult<(codes::AcquireNextImageKHRStatus, u32)> {
         unsafe {
             raw::acquire_next_image_khr(
                 self,
@@ -3356,6 +4136,8 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
                 fence,
                 self.disp.get_command_dispatcher(),
             )
+            .and_then(|(status, value)| Ok((status.narrow_success()?, value)))
+            .narrow_err()
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupPresentCapabilitiesKHR.html>"]
@@ -3369,6 +4151,23 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             raw::get_device_group_present_capabilities_khr(self, self.disp.get_command_dispatcher())
         }
     }
+    #[doc = "Same as [Self::get_group_present_capabilities_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupPresentCapabilitiesKHR.html>"]
+    #[doc(alias = "vkGetDeviceGroupPresentCapabilitiesKHR")]
+    pub fn get_group_present_capabilities_khr_into<
+        S: StructureChain<DeviceGroupPresentCapabilitiesKHR<'static>>,
+    >(
+        &self,
+        p_device_group_present_capabilities: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_device_group_present_capabilities_khr_into(
+                self,
+                p_device_group_present_capabilities,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupSurfacePresentModesKHR.html>"]
     #[doc(alias = "vkGetDeviceGroupSurfacePresentModesKHR")]
     pub fn get_group_surface_present_modes_khr(
@@ -3390,61 +4189,169 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         p_acquire_info: &AcquireNextImageInfoKHR,
     ) -> Result<(Status, u32)> {
         unsafe {
-            raw::acquire_next_image2_khr(self, p_acquire_info, self.disp.get_command_dispatcher())
+            raw::acquire_next_image2_khr(self, p_acquire_info, self.disp.get_command_dispatcher())
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSharedSwapchainsKHR.html>"]
+    #[doc(alias = "vkCreateSharedSwapchainsKHR")]
+    pub fn create_shared_swapchains_khr<
+        'a,
+        R: AdvancedDynamicArray<SwapchainKHR, raw::SwapchainKHR>,
+    >(
+        &self,
+        p_create_infos: impl AsSlice<'a, SwapchainCreateInfoKHR<'a>>,
+    ) -> Result<R> {
+        let vk_result: Result<R::InnerArrayType> = unsafe {
+            raw::create_shared_swapchains_khr(
+                self,
+                p_create_infos,
+                self.alloc.get_allocation_callbacks().as_ref(),
+                self.disp.get_command_dispatcher(),
+            )
+        };
+        vk_result.map(|vk_result| {
+            vk_result
+                .into_iter()
+                .map(|el| unsafe { SwapchainKHR::from_inner(el) })
+                .collect()
+        })
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectTagEXT.html>"]
+    #[doc(alias = "vkDebugMarkerSetObjectTagEXT")]
+    pub fn debug_marker_set_object_tag_ext(
+        &self,
+        p_tag_info: &DebugMarkerObjectTagInfoEXT,
+    ) -> Result<()> {
+        unsafe {
+            raw::debug_marker_set_object_tag_ext(
+                self,
+                p_tag_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectNameEXT.html>"]
+    #[doc(alias = "vkDebugMarkerSetObjectNameEXT")]
+    pub fn debug_marker_set_object_name_ext(
+        &self,
+        p_name_info: &DebugMarkerObjectNameInfoEXT,
+    ) -> Result<()> {
+        unsafe {
+            raw::debug_marker_set_object_name_ext(
+                self,
+                p_name_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionKHR.html>"]
+    #[doc(alias = "vkCreateVideoSessionKHR")]
+    pub fn create_video_session_khr(
+        &self,
+        p_create_info: &VideoSessionCreateInfoKHR,
+    ) -> Result<VideoSessionKHR> {
+        let vk_result = unsafe {
+            raw::create_video_session_khr(
+                self,
+                p_create_info,
+                self.alloc.get_allocation_callbacks().as_ref(),
+                self.disp.get_command_dispatcher(),
+            )
+        };
+        vk_result.map(|vk_result| unsafe { VideoSessionKHR::from_inner(vk_result) })
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionKHR.html>"]
+    #[doc(alias = "vkDestroyVideoSessionKHR")]
+    pub unsafe fn destroy_video_session_khr(&self, video_session: Option<&raw::VideoSessionKHR>) {
+        unsafe {
+            raw::destroy_video_session_khr(
+                self,
+                video_session,
+                self.alloc.get_allocation_callbacks().as_ref(),
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetVideoSessionMemoryRequirementsKHR.html>"]
+    #[doc(alias = "vkGetVideoSessionMemoryRequirementsKHR")]
+    pub fn get_video_session_memory_requirements_khr<
+        R: DynamicArray<VideoSessionMemoryRequirementsKHR<'static>>,
+    >(
+        &self,
+        video_session: &raw::VideoSessionKHR,
+    ) -> Result<R> {
+        unsafe {
+            raw::get_video_session_memory_requirements_khr(
+                self,
+                video_session,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindVideoSessionMemoryKHR.html>"]
+    #[doc(alias = "vkBindVideoSessionMemoryKHR")]
+    pub fn bind_video_session_memory_khr<'a>(
+        &self,
+        video_session: &raw::VideoSessionKHR,
+        p_bind_session_memory_infos: impl AsSlice<'a, BindVideoSessionMemoryInfoKHR<'a>>,
+    ) -> Result<()> {
+        unsafe {
+            raw::bind_video_session_memory_khr(
+                self,
+                video_session,
+                p_bind_session_memory_infos,
+                self.disp.get_command_dispatcher(),
+            )
         }
     }
-    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSharedSwapchainsKHR.html>"]
-    #[doc(alias = "vkCreateSharedSwapchainsKHR")]
-    pub fn create_shared_swapchains_khr<
-        'a,
-        R: AdvancedDynamicArray<SwapchainKHR, raw::SwapchainKHR>,
-    >(
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionParametersKHR.html>"]
+    #[doc(alias = "vkCreateVideoSessionParametersKHR")]
+    pub fn create_video_session_parameters_khr(
         &self,
-        p_create_infos: impl AsSlice<'a, SwapchainCreateInfoKHR<'a>>,
-    ) -> Result<R> {
-        let vk_result: Result<R::InnerArrayType> = unsafe {
-            raw::create_shared_swapchains_khr(
+        p_create_info: &VideoSessionParametersCreateInfoKHR,
+    ) -> Result<VideoSessionParametersKHR> {
+        let vk_result = unsafe {
+            raw::create_video_session_parameters_khr(
                 self,
-                p_create_infos,
+                p_create_info,
                 self.alloc.get_allocation_callbacks().as_ref(),
                 self.disp.get_command_dispatcher(),
             )
         };
-        vk_result.map(|vk_result| {
-            vk_result
-                .into_iter()
-                .map(|el| unsafe { SwapchainKHR::from_inner(el) })
-                .collect()
-        })
+        vk_result.map(|vk_result| unsafe { VideoSessionParametersKHR::from_inner(vk_result) })
     }
-    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectTagEXT.html>"]
-    #[doc(alias = "vkDebugMarkerSetObjectTagEXT")]
-    pub fn debug_marker_set_object_tag_ext(
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateVideoSessionParametersKHR.html>"]
+    #[doc(alias = "vkUpdateVideoSessionParametersKHR")]
+    pub fn update_video_session_parameters_khr(
         &self,
-        p_tag_info: &DebugMarkerObjectTagInfoEXT,
+        video_session_parameters: &raw::VideoSessionParametersKHR,
+        p_update_info: &VideoSessionParametersUpdateInfoKHR,
     ) -> Result<()> {
         unsafe {
-            raw::debug_marker_set_object_tag_ext(
+            raw::update_video_session_parameters_khr(
                 self,
-                p_tag_info,
+                video_session_parameters,
+                p_update_info,
                 self.disp.get_command_dispatcher(),
             )
         }
     }
-    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectNameEXT.html>"]
-    #[doc(alias = "vkDebugMarkerSetObjectNameEXT")]
-    pub fn debug_marker_set_object_name_ext(
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionParametersKHR.html>"]
+    #[doc(alias = "vkDestroyVideoSessionParametersKHR")]
+    pub unsafe fn destroy_video_session_parameters_khr(
         &self,
-        p_name_info: &DebugMarkerObjectNameInfoEXT,
-    ) -> Result<()> {
+        video_session_parameters: Option<&raw::VideoSessionParametersKHR>,
+    ) {
         unsafe {
-            raw::debug_marker_set_object_name_ext(
+            raw::destroy_video_session_parameters_khr(
                 self,
-                p_name_info,
+                video_session_parameters,
+                self.alloc.get_allocation_callbacks().as_ref(),
                 self.disp.get_command_dispatcher(),
             )
         }
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuModuleNVX.html>"]
     #[doc(alias = "vkCreateCuModuleNVX")]
     pub fn create_cu_module_nvx(
@@ -3461,6 +4368,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { CuModuleNVX::from_inner(vk_result) })
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuFunctionNVX.html>"]
     #[doc(alias = "vkCreateCuFunctionNVX")]
     pub fn create_cu_function_nvx(
@@ -3477,6 +4385,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { CuFunctionNVX::from_inner(vk_result) })
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuModuleNVX.html>"]
     #[doc(alias = "vkDestroyCuModuleNVX")]
     pub unsafe fn destroy_cu_module_nvx(&self, module: &raw::CuModuleNVX) {
@@ -3489,6 +4398,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuFunctionNVX.html>"]
     #[doc(alias = "vkDestroyCuFunctionNVX")]
     pub unsafe fn destroy_cu_function_nvx(&self, function: &raw::CuFunctionNVX) {
@@ -3501,11 +4411,13 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewHandleNVX.html>"]
     #[doc(alias = "vkGetImageViewHandleNVX")]
     pub fn get_image_view_handle_nvx(&self, p_info: &ImageViewHandleInfoNVX) -> u32 {
         unsafe { raw::get_image_view_handle_nvx(self, p_info, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewAddressNVX.html>"]
     #[doc(alias = "vkGetImageViewAddressNVX")]
     pub fn get_image_view_address_nvx<
@@ -3518,26 +4430,49 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             raw::get_image_view_address_nvx(self, image_view, self.disp.get_command_dispatcher())
         }
     }
+    #[cfg(feature = "vendor-nvx")]
+    #[doc = "Same as [Self::get_image_view_address_nvx], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewAddressNVX.html>"]
+    #[doc(alias = "vkGetImageViewAddressNVX")]
+    pub fn get_image_view_address_nvx_into<
+        S: StructureChain<ImageViewAddressPropertiesNVX<'static>>,
+    >(
+        &self,
+        image_view: &raw::ImageView,
+        p_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_image_view_address_nvx_into(
+                self,
+                image_view,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderInfoAMD.html>"]
     #[doc(alias = "vkGetShaderInfoAMD")]
-    pub fn get_shader_info_amd(
+    pub fn get_shader_info_amd<R: DynamicArray<u8>>(
         &self,
         pipeline: &raw::Pipeline,
         shader_stage: ShaderStageFlags,
         info_type: ShaderInfoTypeAMD,
-        p_info: VoidPtr,
-    ) -> Result<usize> {
+    ) -> Result<R> {
         unsafe {
             raw::get_shader_info_amd(
                 self,
                 pipeline,
                 shader_stage,
                 info_type,
-                p_info,
                 self.disp.get_command_dispatcher(),
             )
         }
     }
+    #[cfg(all(
+        any(feature = "platform-win32", target_os = "windows"),
+        feature = "vendor-nv"
+    ))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleNV.html>"]
     #[doc(alias = "vkGetMemoryWin32HandleNV")]
     pub fn get_memory_win32_handle_nv(
@@ -3554,6 +4489,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleKHR.html>"]
     #[doc(alias = "vkGetMemoryWin32HandleKHR")]
     pub fn get_memory_win32_handle_khr(
@@ -3568,6 +4504,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandlePropertiesKHR.html>"]
     #[doc(alias = "vkGetMemoryWin32HandlePropertiesKHR")]
     pub fn get_memory_win32_handle_properties_khr<
@@ -3586,6 +4523,28 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
+    #[doc = "Same as [Self::get_memory_win32_handle_properties_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandlePropertiesKHR.html>"]
+    #[doc(alias = "vkGetMemoryWin32HandlePropertiesKHR")]
+    pub fn get_memory_win32_handle_properties_khr_into<
+        S: StructureChain<MemoryWin32HandlePropertiesKHR<'static>>,
+    >(
+        &self,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        handle: VoidPtr,
+        p_memory_win32_handle_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_memory_win32_handle_properties_khr_into(
+                self,
+                handle_type,
+                handle,
+                p_memory_win32_handle_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdKHR.html>"]
     #[doc(alias = "vkGetMemoryFdKHR")]
     pub fn get_memory_fd_khr(&self, p_get_fd_info: &MemoryGetFdInfoKHR) -> Result<c_int> {
@@ -3607,6 +4566,26 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_memory_fd_properties_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdPropertiesKHR.html>"]
+    #[doc(alias = "vkGetMemoryFdPropertiesKHR")]
+    pub fn get_memory_fd_properties_khr_into<S: StructureChain<MemoryFdPropertiesKHR<'static>>>(
+        &self,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        fd: c_int,
+        p_memory_fd_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_memory_fd_properties_khr_into(
+                self,
+                handle_type,
+                fd,
+                p_memory_fd_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreWin32HandleKHR.html>"]
     #[doc(alias = "vkImportSemaphoreWin32HandleKHR")]
     pub fn import_semaphore_win32_handle_khr(
@@ -3621,6 +4600,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreWin32HandleKHR.html>"]
     #[doc(alias = "vkGetSemaphoreWin32HandleKHR")]
     pub fn get_semaphore_win32_handle_khr(
@@ -3719,6 +4699,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-google")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRefreshCycleDurationGOOGLE.html>"]
     #[doc(alias = "vkGetRefreshCycleDurationGOOGLE")]
     pub fn get_refresh_cycle_duration_google(
@@ -3733,6 +4714,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-google")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPastPresentationTimingGOOGLE.html>"]
     #[doc(alias = "vkGetPastPresentationTimingGOOGLE")]
     pub fn get_past_presentation_timing_google<R: DynamicArray<PastPresentationTimingGOOGLE>>(
@@ -3770,6 +4752,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             raw::get_swapchain_status_khr(self, swapchain, self.disp.get_command_dispatcher())
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportFenceWin32HandleKHR.html>"]
     #[doc(alias = "vkImportFenceWin32HandleKHR")]
     pub fn import_fence_win32_handle_khr(
@@ -3784,6 +4767,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceWin32HandleKHR.html>"]
     #[doc(alias = "vkGetFenceWin32HandleKHR")]
     pub fn get_fence_win32_handle_khr(
@@ -3852,6 +4836,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAndroidHardwareBufferPropertiesANDROID.html>"]
     #[doc(alias = "vkGetAndroidHardwareBufferPropertiesANDROID")]
     pub fn get_android_hardware_buffer_properties_android<
@@ -3868,6 +4853,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
+    #[doc = "Same as [Self::get_android_hardware_buffer_properties_android], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAndroidHardwareBufferPropertiesANDROID.html>"]
+    #[doc(alias = "vkGetAndroidHardwareBufferPropertiesANDROID")]
+    pub fn get_android_hardware_buffer_properties_android_into<
+        S: StructureChain<AndroidHardwareBufferPropertiesANDROID<'static>>,
+    >(
+        &self,
+        buffer: &AHardwareBuffer,
+        p_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_android_hardware_buffer_properties_android_into(
+                self,
+                buffer,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(any(feature = "platform-android", target_os = "android"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryAndroidHardwareBufferANDROID.html>"]
     #[doc(alias = "vkGetMemoryAndroidHardwareBufferANDROID")]
     pub fn get_memory_android_hardware_buffer_android(
@@ -3884,6 +4890,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateExecutionGraphPipelinesAMDX.html>"]
     #[doc(alias = "vkCreateExecutionGraphPipelinesAMDX")]
     pub fn create_execution_graph_pipelines_amdx<
@@ -3913,6 +4920,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         })
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetExecutionGraphPipelineScratchSizeAMDX.html>"]
     #[doc(alias = "vkGetExecutionGraphPipelineScratchSizeAMDX")]
     pub fn get_execution_graph_pipeline_scratch_size_amdx<
@@ -3929,6 +4937,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    #[doc = "Same as [Self::get_execution_graph_pipeline_scratch_size_amdx], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetExecutionGraphPipelineScratchSizeAMDX.html>"]
+    #[doc(alias = "vkGetExecutionGraphPipelineScratchSizeAMDX")]
+    pub fn get_execution_graph_pipeline_scratch_size_amdx_into<
+        S: StructureChain<ExecutionGraphPipelineScratchSizeAMDX<'static>>,
+    >(
+        &self,
+        execution_graph: &raw::Pipeline,
+        p_size_info: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_execution_graph_pipeline_scratch_size_amdx_into(
+                self,
+                execution_graph,
+                p_size_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetExecutionGraphPipelineNodeIndexAMDX.html>"]
     #[doc(alias = "vkGetExecutionGraphPipelineNodeIndexAMDX")]
     pub fn get_execution_graph_pipeline_node_index_amdx(
@@ -4116,6 +5145,30 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_acceleration_structure_build_sizes_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureBuildSizesKHR.html>"]
+    #[doc(alias = "vkGetAccelerationStructureBuildSizesKHR")]
+    pub fn get_acceleration_structure_build_sizes_khr_into<
+        'a,
+        S: StructureChain<AccelerationStructureBuildSizesInfoKHR<'static>>,
+    >(
+        &self,
+        build_type: AccelerationStructureBuildTypeKHR,
+        p_build_info: &AccelerationStructureBuildGeometryInfoKHR,
+        p_max_primitive_counts: Option<impl AsSlice<'a, u32>>,
+        p_size_info: &mut S,
+    ) {
+        unsafe {
+            raw::get_acceleration_structure_build_sizes_khr_into(
+                self,
+                build_type,
+                p_build_info,
+                p_max_primitive_counts,
+                p_size_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesKHR.html>"]
     #[doc(alias = "vkCreateRayTracingPipelinesKHR")]
     pub fn create_ray_tracing_pipelines_khr<
@@ -4169,6 +5222,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupHandlesNV.html>"]
     #[doc(alias = "vkGetRayTracingShaderGroupHandlesNV")]
     pub fn get_ray_tracing_shader_group_handles_nv(
@@ -4247,6 +5301,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_drm_format_modifier_properties_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageDrmFormatModifierPropertiesEXT.html>"]
+    #[doc(alias = "vkGetImageDrmFormatModifierPropertiesEXT")]
+    pub fn get_image_drm_format_modifier_properties_ext_into<
+        S: StructureChain<ImageDrmFormatModifierPropertiesEXT<'static>>,
+    >(
+        &self,
+        image: &raw::Image,
+        p_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_image_drm_format_modifier_properties_ext_into(
+                self,
+                image,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateValidationCacheEXT.html>"]
     #[doc(alias = "vkCreateValidationCacheEXT")]
     pub fn create_validation_cache_ext(
@@ -4296,20 +5369,19 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetValidationCacheDataEXT.html>"]
     #[doc(alias = "vkGetValidationCacheDataEXT")]
-    pub fn get_validation_cache_data_ext(
+    pub fn get_validation_cache_data_ext<R: DynamicArray<u8>>(
         &self,
         validation_cache: &raw::ValidationCacheEXT,
-        p_data: VoidPtr,
-    ) -> Result<usize> {
+    ) -> Result<R> {
         unsafe {
             raw::get_validation_cache_data_ext(
                 self,
                 validation_cache,
-                p_data,
                 self.disp.get_command_dispatcher(),
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureNV.html>"]
     #[doc(alias = "vkCreateAccelerationStructureNV")]
     pub fn create_acceleration_structure_nv(
@@ -4326,6 +5398,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { AccelerationStructureNV::from_inner(vk_result) })
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyAccelerationStructureNV.html>"]
     #[doc(alias = "vkDestroyAccelerationStructureNV")]
     pub unsafe fn destroy_acceleration_structure_nv(
@@ -4341,6 +5414,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureMemoryRequirementsNV.html>"]
     #[doc(alias = "vkGetAccelerationStructureMemoryRequirementsNV")]
     pub fn get_acceleration_structure_memory_requirements_nv<
@@ -4357,6 +5431,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "Same as [Self::get_acceleration_structure_memory_requirements_nv], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureMemoryRequirementsNV.html>"]
+    #[doc(alias = "vkGetAccelerationStructureMemoryRequirementsNV")]
+    pub fn get_acceleration_structure_memory_requirements_nv_into<
+        S: StructureChain<MemoryRequirements2KHR<'static>>,
+    >(
+        &self,
+        p_info: &AccelerationStructureMemoryRequirementsInfoNV,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_acceleration_structure_memory_requirements_nv_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindAccelerationStructureMemoryNV.html>"]
     #[doc(alias = "vkBindAccelerationStructureMemoryNV")]
     pub fn bind_acceleration_structure_memory_nv<'a>(
@@ -4371,6 +5466,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesNV.html>"]
     #[doc(alias = "vkCreateRayTracingPipelinesNV")]
     pub fn create_ray_tracing_pipelines_nv<'a, R: AdvancedDynamicArray<Pipeline, raw::Pipeline>>(
@@ -4397,6 +5493,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         })
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureHandleNV.html>"]
     #[doc(alias = "vkGetAccelerationStructureHandleNV")]
     pub fn get_acceleration_structure_handle_nv(
@@ -4415,6 +5512,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCompileDeferredNV.html>"]
     #[doc(alias = "vkCompileDeferredNV")]
     pub fn compile_deferred_nv(&self, pipeline: &raw::Pipeline, shader: u32) -> Result<()> {
@@ -4440,6 +5538,28 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_memory_host_pointer_properties_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryHostPointerPropertiesEXT.html>"]
+    #[doc(alias = "vkGetMemoryHostPointerPropertiesEXT")]
+    pub fn get_memory_host_pointer_properties_ext_into<
+        S: StructureChain<MemoryHostPointerPropertiesEXT<'static>>,
+    >(
+        &self,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        p_host_pointer: VoidPtr,
+        p_memory_host_pointer_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_memory_host_pointer_properties_ext_into(
+                self,
+                handle_type,
+                p_host_pointer,
+                p_memory_host_pointer_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkInitializePerformanceApiINTEL.html>"]
     #[doc(alias = "vkInitializePerformanceApiINTEL")]
     pub fn initialize_performance_api_intel(
@@ -4454,11 +5574,13 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUninitializePerformanceApiINTEL.html>"]
     #[doc(alias = "vkUninitializePerformanceApiINTEL")]
     pub fn uninitialize_performance_api_intel(&self) {
         unsafe { raw::uninitialize_performance_api_intel(self, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquirePerformanceConfigurationINTEL.html>"]
     #[doc(alias = "vkAcquirePerformanceConfigurationINTEL")]
     pub fn acquire_performance_configuration_intel(
@@ -4474,6 +5596,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { PerformanceConfigurationINTEL::from_inner(vk_result) })
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleasePerformanceConfigurationINTEL.html>"]
     #[doc(alias = "vkReleasePerformanceConfigurationINTEL")]
     pub fn release_performance_configuration_intel(
@@ -4488,6 +5611,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPerformanceParameterINTEL.html>"]
     #[doc(alias = "vkGetPerformanceParameterINTEL")]
     pub fn get_performance_parameter_intel(
@@ -4502,6 +5626,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLocalDimmingAMD.html>"]
     #[doc(alias = "vkSetLocalDimmingAMD")]
     pub fn set_local_dimming_amd(
@@ -4536,6 +5661,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireFullScreenExclusiveModeEXT.html>"]
     #[doc(alias = "vkAcquireFullScreenExclusiveModeEXT")]
     pub fn acquire_full_screen_exclusive_mode_ext(
@@ -4550,6 +5676,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseFullScreenExclusiveModeEXT.html>"]
     #[doc(alias = "vkReleaseFullScreenExclusiveModeEXT")]
     pub fn release_full_screen_exclusive_mode_ext(
@@ -4564,6 +5691,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-win32", target_os = "windows"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupSurfacePresentModes2EXT.html>"]
     #[doc(alias = "vkGetDeviceGroupSurfacePresentModes2EXT")]
     pub fn get_group_surface_present_modes2_ext(
@@ -4777,6 +5905,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html>"]
     #[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsNV")]
     pub fn get_generated_commands_memory_requirements_nv<
@@ -4793,6 +5922,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "Same as [Self::get_generated_commands_memory_requirements_nv], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html>"]
+    #[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsNV")]
+    pub fn get_generated_commands_memory_requirements_nv_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &GeneratedCommandsMemoryRequirementsInfoNV,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_generated_commands_memory_requirements_nv_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIndirectCommandsLayoutNV.html>"]
     #[doc(alias = "vkCreateIndirectCommandsLayoutNV")]
     pub fn create_indirect_commands_layout_nv(
@@ -4809,6 +5959,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { IndirectCommandsLayoutNV::from_inner(vk_result) })
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyIndirectCommandsLayoutNV.html>"]
     #[doc(alias = "vkDestroyIndirectCommandsLayoutNV")]
     pub unsafe fn destroy_indirect_commands_layout_nv(
@@ -4824,6 +5975,24 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEncodedVideoSessionParametersKHR.html>"]
+    #[doc(alias = "vkGetEncodedVideoSessionParametersKHR")]
+    pub fn get_encoded_video_session_parameters_khr<
+        S: StructureChainOut<VideoEncodeSessionParametersFeedbackInfoKHR<'static>>,
+        R: DynamicArray<u8>,
+    >(
+        &self,
+        p_video_session_parameters_info: &VideoEncodeSessionParametersGetInfoKHR,
+    ) -> Result<(S, R)> {
+        unsafe {
+            raw::get_encoded_video_session_parameters_khr(
+                self,
+                p_video_session_parameters_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCudaModuleNV.html>"]
     #[doc(alias = "vkCreateCudaModuleNV")]
     pub fn create_cuda_module_nv(
@@ -4840,22 +6009,16 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { CudaModuleNV::from_inner(vk_result) })
     }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCudaModuleCacheNV.html>"]
     #[doc(alias = "vkGetCudaModuleCacheNV")]
-    pub fn get_cuda_module_cache_nv(
+    pub fn get_cuda_module_cache_nv<R: DynamicArray<u8>>(
         &self,
         module: &raw::CudaModuleNV,
-        p_cache_data: VoidPtr,
-    ) -> Result<usize> {
-        unsafe {
-            raw::get_cuda_module_cache_nv(
-                self,
-                module,
-                p_cache_data,
-                self.disp.get_command_dispatcher(),
-            )
-        }
+    ) -> Result<R> {
+        unsafe { raw::get_cuda_module_cache_nv(self, module, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCudaFunctionNV.html>"]
     #[doc(alias = "vkCreateCudaFunctionNV")]
     pub fn create_cuda_function_nv(
@@ -4872,6 +6035,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { CudaFunctionNV::from_inner(vk_result) })
     }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCudaModuleNV.html>"]
     #[doc(alias = "vkDestroyCudaModuleNV")]
     pub unsafe fn destroy_cuda_module_nv(&self, module: &raw::CudaModuleNV) {
@@ -4884,6 +6048,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCudaFunctionNV.html>"]
     #[doc(alias = "vkDestroyCudaFunctionNV")]
     pub unsafe fn destroy_cuda_function_nv(&self, function: &raw::CudaFunctionNV) {
@@ -4896,6 +6061,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkExportMetalObjectsEXT.html>"]
     #[doc(alias = "vkExportMetalObjectsEXT")]
     pub fn export_metal_objects_ext<S: StructureChainOut<ExportMetalObjectsInfoEXT<'static>>>(
@@ -4903,6 +6069,22 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     ) -> S {
         unsafe { raw::export_metal_objects_ext(self, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(any(feature = "platform-metal", target_os = "macos", target_os = "ios"))]
+    #[doc = "Same as [Self::export_metal_objects_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkExportMetalObjectsEXT.html>"]
+    #[doc(alias = "vkExportMetalObjectsEXT")]
+    pub fn export_metal_objects_ext_into<S: StructureChain<ExportMetalObjectsInfoEXT<'static>>>(
+        &self,
+        p_metal_objects_info: &mut S,
+    ) {
+        unsafe {
+            raw::export_metal_objects_ext_into(
+                self,
+                p_metal_objects_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSizeEXT.html>"]
     #[doc(alias = "vkGetDescriptorSetLayoutSizeEXT")]
     pub fn get_descriptor_set_layout_size_ext(
@@ -5031,12 +6213,13 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandleFUCHSIA.html>"]
     #[doc(alias = "vkGetMemoryZirconHandleFUCHSIA")]
     pub fn get_memory_zircon_handle_fuchsia(
         &self,
         p_get_zircon_handle_info: &MemoryGetZirconHandleInfoFUCHSIA,
-    ) -> Result<VoidPtr> {
+    ) -> Result<u32> {
         unsafe {
             raw::get_memory_zircon_handle_fuchsia(
                 self,
@@ -5045,6 +6228,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandlePropertiesFUCHSIA.html>"]
     #[doc(alias = "vkGetMemoryZirconHandlePropertiesFUCHSIA")]
     pub fn get_memory_zircon_handle_properties_fuchsia<
@@ -5052,7 +6236,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     >(
         &self,
         handle_type: ExternalMemoryHandleTypeFlags,
-        zircon_handle: VoidPtr,
+        zircon_handle: u32,
     ) -> Result<S> {
         unsafe {
             raw::get_memory_zircon_handle_properties_fuchsia(
@@ -5063,6 +6247,29 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    #[doc = "Same as [Self::get_memory_zircon_handle_properties_fuchsia], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandlePropertiesFUCHSIA.html>"]
+    #[doc(alias = "vkGetMemoryZirconHandlePropertiesFUCHSIA")]
+    pub fn get_memory_zircon_handle_properties_fuchsia_into<
+        S: StructureChain<MemoryZirconHandlePropertiesFUCHSIA<'static>>,
+    >(
+        &self,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        zircon_handle: u32,
+        p_memory_zircon_handle_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_memory_zircon_handle_properties_fuchsia_into(
+                self,
+                handle_type,
+                zircon_handle,
+                p_memory_zircon_handle_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreZirconHandleFUCHSIA.html>"]
     #[doc(alias = "vkImportSemaphoreZirconHandleFUCHSIA")]
     pub fn import_semaphore_zircon_handle_fuchsia(
@@ -5077,12 +6284,13 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreZirconHandleFUCHSIA.html>"]
     #[doc(alias = "vkGetSemaphoreZirconHandleFUCHSIA")]
     pub fn get_semaphore_zircon_handle_fuchsia(
         &self,
         p_get_zircon_handle_info: &SemaphoreGetZirconHandleInfoFUCHSIA,
-    ) -> Result<VoidPtr> {
+    ) -> Result<u32> {
         unsafe {
             raw::get_semaphore_zircon_handle_fuchsia(
                 self,
@@ -5091,6 +6299,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBufferCollectionFUCHSIA.html>"]
     #[doc(alias = "vkCreateBufferCollectionFUCHSIA")]
     pub fn create_buffer_collection_fuchsia(
@@ -5107,6 +6316,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { BufferCollectionFUCHSIA::from_inner(vk_result) })
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionImageConstraintsFUCHSIA.html>"]
     #[doc(alias = "vkSetBufferCollectionImageConstraintsFUCHSIA")]
     pub fn set_buffer_collection_image_constraints_fuchsia(
@@ -5123,6 +6333,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionBufferConstraintsFUCHSIA.html>"]
     #[doc(alias = "vkSetBufferCollectionBufferConstraintsFUCHSIA")]
     pub fn set_buffer_collection_buffer_constraints_fuchsia(
@@ -5139,6 +6350,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBufferCollectionFUCHSIA.html>"]
     #[doc(alias = "vkDestroyBufferCollectionFUCHSIA")]
     pub unsafe fn destroy_buffer_collection_fuchsia(
@@ -5154,6 +6366,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferCollectionPropertiesFUCHSIA.html>"]
     #[doc(alias = "vkGetBufferCollectionPropertiesFUCHSIA")]
     pub fn get_buffer_collection_properties_fuchsia<
@@ -5170,6 +6383,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
+    #[doc = "Same as [Self::get_buffer_collection_properties_fuchsia], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferCollectionPropertiesFUCHSIA.html>"]
+    #[doc(alias = "vkGetBufferCollectionPropertiesFUCHSIA")]
+    pub fn get_buffer_collection_properties_fuchsia_into<
+        S: StructureChain<BufferCollectionPropertiesFUCHSIA<'static>>,
+    >(
+        &self,
+        collection: &raw::BufferCollectionFUCHSIA,
+        p_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_buffer_collection_properties_fuchsia_into(
+                self,
+                collection,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-huawei")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI.html>"]
     #[doc(alias = "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI")]
     pub fn get_subpass_shading_max_workgroup_size_huawei<R: DynamicArray<Extent2D>>(
@@ -5184,6 +6418,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryRemoteAddressNV.html>"]
     #[doc(alias = "vkGetMemoryRemoteAddressNV")]
     pub fn get_memory_remote_address_nv(
@@ -5348,12 +6583,33 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         &self,
         build_type: AccelerationStructureBuildTypeKHR,
         p_build_info: &MicromapBuildInfoEXT,
-    ) -> S {
+    ) -> S {
+        unsafe {
+            raw::get_micromap_build_sizes_ext(
+                self,
+                build_type,
+                p_build_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "Same as [Self::get_micromap_build_sizes_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMicromapBuildSizesEXT.html>"]
+    #[doc(alias = "vkGetMicromapBuildSizesEXT")]
+    pub fn get_micromap_build_sizes_ext_into<
+        S: StructureChain<MicromapBuildSizesInfoEXT<'static>>,
+    >(
+        &self,
+        build_type: AccelerationStructureBuildTypeKHR,
+        p_build_info: &MicromapBuildInfoEXT,
+        p_size_info: &mut S,
+    ) {
         unsafe {
-            raw::get_micromap_build_sizes_ext(
+            raw::get_micromap_build_sizes_ext_into(
                 self,
                 build_type,
                 p_build_info,
+                p_size_info,
                 self.disp.get_command_dispatcher(),
             )
         }
@@ -5370,6 +6626,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-valve")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutHostMappingInfoVALVE.html>"]
     #[doc(alias = "vkGetDescriptorSetLayoutHostMappingInfoVALVE")]
     pub fn get_descriptor_set_layout_host_mapping_info_valve<
@@ -5386,6 +6643,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-valve")]
+    #[doc = "Same as [Self::get_descriptor_set_layout_host_mapping_info_valve], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutHostMappingInfoVALVE.html>"]
+    #[doc(alias = "vkGetDescriptorSetLayoutHostMappingInfoVALVE")]
+    pub fn get_descriptor_set_layout_host_mapping_info_valve_into<
+        S: StructureChain<DescriptorSetLayoutHostMappingInfoVALVE<'static>>,
+    >(
+        &self,
+        p_binding_reference: &DescriptorSetBindingReferenceVALVE,
+        p_host_mapping: &mut S,
+    ) {
+        unsafe {
+            raw::get_descriptor_set_layout_host_mapping_info_valve_into(
+                self,
+                p_binding_reference,
+                p_host_mapping,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-valve")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetHostMappingVALVE.html>"]
     #[doc(alias = "vkGetDescriptorSetHostMappingVALVE")]
     pub fn get_descriptor_set_host_mapping_valve(
@@ -5400,6 +6678,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineIndirectMemoryRequirementsNV.html>"]
     #[doc(alias = "vkGetPipelineIndirectMemoryRequirementsNV")]
     pub fn get_pipeline_indirect_memory_requirements_nv<
@@ -5416,6 +6695,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "Same as [Self::get_pipeline_indirect_memory_requirements_nv], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineIndirectMemoryRequirementsNV.html>"]
+    #[doc(alias = "vkGetPipelineIndirectMemoryRequirementsNV")]
+    pub fn get_pipeline_indirect_memory_requirements_nv_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_create_info: &ComputePipelineCreateInfo,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_pipeline_indirect_memory_requirements_nv_into(
+                self,
+                p_create_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineIndirectDeviceAddressNV.html>"]
     #[doc(alias = "vkGetPipelineIndirectDeviceAddressNV")]
     pub fn get_pipeline_indirect_address_nv(
@@ -5446,6 +6746,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_shader_module_identifier_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleIdentifierEXT.html>"]
+    #[doc(alias = "vkGetShaderModuleIdentifierEXT")]
+    pub fn get_shader_module_identifier_ext_into<
+        S: StructureChain<ShaderModuleIdentifierEXT<'static>>,
+    >(
+        &self,
+        shader_module: &raw::ShaderModule,
+        p_identifier: &mut S,
+    ) {
+        unsafe {
+            raw::get_shader_module_identifier_ext_into(
+                self,
+                shader_module,
+                p_identifier,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleCreateInfoIdentifierEXT.html>"]
     #[doc(alias = "vkGetShaderModuleCreateInfoIdentifierEXT")]
     pub fn get_shader_module_create_info_identifier_ext<
@@ -5462,6 +6781,26 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_shader_module_create_info_identifier_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleCreateInfoIdentifierEXT.html>"]
+    #[doc(alias = "vkGetShaderModuleCreateInfoIdentifierEXT")]
+    pub fn get_shader_module_create_info_identifier_ext_into<
+        S: StructureChain<ShaderModuleIdentifierEXT<'static>>,
+    >(
+        &self,
+        p_create_info: &ShaderModuleCreateInfo,
+        p_identifier: &mut S,
+    ) {
+        unsafe {
+            raw::get_shader_module_create_info_identifier_ext_into(
+                self,
+                p_create_info,
+                p_identifier,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateOpticalFlowSessionNV.html>"]
     #[doc(alias = "vkCreateOpticalFlowSessionNV")]
     pub fn create_optical_flow_session_nv(
@@ -5478,6 +6817,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
         };
         vk_result.map(|vk_result| unsafe { OpticalFlowSessionNV::from_inner(vk_result) })
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyOpticalFlowSessionNV.html>"]
     #[doc(alias = "vkDestroyOpticalFlowSessionNV")]
     pub unsafe fn destroy_optical_flow_session_nv(&self, session: &raw::OpticalFlowSessionNV) {
@@ -5490,6 +6830,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindOpticalFlowSessionImageNV.html>"]
     #[doc(alias = "vkBindOpticalFlowSessionImageNV")]
     pub fn bind_optical_flow_session_image_nv(
@@ -5540,6 +6881,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_subresource_layout_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageSubresourceLayoutKHR.html>"]
+    #[doc(alias = "vkGetDeviceImageSubresourceLayoutKHR")]
+    pub fn get_image_subresource_layout_khr_into<
+        S: StructureChain<SubresourceLayout2KHR<'static>>,
+    >(
+        &self,
+        p_info: &DeviceImageSubresourceInfoKHR,
+        p_layout: &mut S,
+    ) {
+        unsafe {
+            raw::get_device_image_subresource_layout_khr_into(
+                self,
+                p_info,
+                p_layout,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2KHR.html>"]
     #[doc(alias = "vkGetImageSubresourceLayout2KHR")]
     pub fn get_image_subresource_layout2_khr<
@@ -5558,6 +6918,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_subresource_layout2_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2KHR.html>"]
+    #[doc(alias = "vkGetImageSubresourceLayout2KHR")]
+    pub fn get_image_subresource_layout2_khr_into<
+        S: StructureChain<SubresourceLayout2KHR<'static>>,
+    >(
+        &self,
+        image: &raw::Image,
+        p_subresource: &ImageSubresource2KHR,
+        p_layout: &mut S,
+    ) {
+        unsafe {
+            raw::get_image_subresource_layout2_khr_into(
+                self,
+                image,
+                p_subresource,
+                p_layout,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2EXT.html>"]
     #[doc(alias = "vkGetImageSubresourceLayout2EXT")]
     pub fn get_image_subresource_layout2_ext<
@@ -5576,6 +6957,28 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_image_subresource_layout2_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2EXT.html>"]
+    #[doc(alias = "vkGetImageSubresourceLayout2EXT")]
+    pub fn get_image_subresource_layout2_ext_into<
+        S: StructureChain<SubresourceLayout2KHR<'static>>,
+    >(
+        &self,
+        image: &raw::Image,
+        p_subresource: &ImageSubresource2KHR,
+        p_layout: &mut S,
+    ) {
+        unsafe {
+            raw::get_image_subresource_layout2_ext_into(
+                self,
+                image,
+                p_subresource,
+                p_layout,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAntiLagUpdateAMD.html>"]
     #[doc(alias = "vkAntiLagUpdateAMD")]
     pub fn anti_lag_update_amd(&self, p_data: &AntiLagDataAMD) {
@@ -5619,33 +7022,45 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderBinaryDataEXT.html>"]
     #[doc(alias = "vkGetShaderBinaryDataEXT")]
-    pub fn get_shader_binary_data_ext(
+    pub fn get_shader_binary_data_ext<R: DynamicArray<u8>>(
         &self,
         shader: &raw::ShaderEXT,
-        p_data: VoidPtr,
-    ) -> Result<usize> {
+    ) -> Result<R> {
+        unsafe { raw::get_shader_binary_data_ext(self, shader, self.disp.get_command_dispatcher()) }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineBinariesKHR.html>"]
+    #[doc(alias = "vkCreatePipelineBinariesKHR")]
+    pub fn create_pipeline_binaries_khr<
+        S: StructureChainOut<PipelineBinaryHandlesInfoKHR<'static>>,
+    >(
+        &self,
+        p_create_info: &PipelineBinaryCreateInfoKHR,
+    ) -> Result<(Status, S)> {
         unsafe {
-            raw::get_shader_binary_data_ext(
+            raw::create_pipeline_binaries_khr(
                 self,
-                shader,
-                p_data,
+                p_create_info,
+                self.alloc.get_allocation_callbacks().as_ref(),
                 self.disp.get_command_dispatcher(),
             )
         }
     }
+    #[doc = "Same as [Self::create_pipeline_binaries_khr], filling the structure chain given by the caller"]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineBinariesKHR.html>"]
     #[doc(alias = "vkCreatePipelineBinariesKHR")]
-    pub fn create_pipeline_binaries_khr<
-        S: StructureChainOut<PipelineBinaryHandlesInfoKHR<'static>>,
+    pub fn create_pipeline_binaries_khr_into<
+        S: StructureChain<PipelineBinaryHandlesInfoKHR<'static>>,
     >(
         &self,
         p_create_info: &PipelineBinaryCreateInfoKHR,
-    ) -> Result<(Status, S)> {
+        p_binaries: &mut S,
+    ) -> Result<Status> {
         unsafe {
-            raw::create_pipeline_binaries_khr(
+            raw::create_pipeline_binaries_khr_into(
                 self,
                 p_create_info,
                 self.alloc.get_allocation_callbacks().as_ref(),
+                p_binaries,
                 self.disp.get_command_dispatcher(),
             )
         }
@@ -5679,6 +7094,36 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_pipeline_key_khr], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineKeyKHR.html>"]
+    #[doc(alias = "vkGetPipelineKeyKHR")]
+    pub fn get_pipeline_key_khr_into<S: StructureChain<PipelineBinaryKeyKHR<'static>>>(
+        &self,
+        p_pipeline_create_info: Option<&PipelineCreateInfoKHR>,
+        p_pipeline_key: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_pipeline_key_khr_into(
+                self,
+                p_pipeline_create_info,
+                p_pipeline_key,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineBinaryDataKHR.html>"]
+    #[doc(alias = "vkGetPipelineBinaryDataKHR")]
+    pub fn get_pipeline_binary_data_khr<
+        S: StructureChainOut<PipelineBinaryKeyKHR<'static>>,
+        R: DynamicArray<u8>,
+    >(
+        &self,
+        p_info: &PipelineBinaryDataInfoKHR,
+    ) -> Result<(S, R)> {
+        unsafe {
+            raw::get_pipeline_binary_data_khr(self, p_info, self.disp.get_command_dispatcher())
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseCapturedPipelineDataKHR.html>"]
     #[doc(alias = "vkReleaseCapturedPipelineDataKHR")]
     pub fn release_captured_pipeline_data_khr(
@@ -5694,6 +7139,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-qcom")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFramebufferTilePropertiesQCOM.html>"]
     #[doc(alias = "vkGetFramebufferTilePropertiesQCOM")]
     pub fn get_framebuffer_tile_properties_qcom<R: DynamicArray<TilePropertiesQCOM<'static>>>(
@@ -5708,6 +7154,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-qcom")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDynamicRenderingTilePropertiesQCOM.html>"]
     #[doc(alias = "vkGetDynamicRenderingTilePropertiesQCOM")]
     pub fn get_dynamic_rendering_tile_properties_qcom<
@@ -5724,6 +7171,27 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-qcom")]
+    #[doc = "Same as [Self::get_dynamic_rendering_tile_properties_qcom], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDynamicRenderingTilePropertiesQCOM.html>"]
+    #[doc(alias = "vkGetDynamicRenderingTilePropertiesQCOM")]
+    pub fn get_dynamic_rendering_tile_properties_qcom_into<
+        S: StructureChain<TilePropertiesQCOM<'static>>,
+    >(
+        &self,
+        p_rendering_info: &RenderingInfo,
+        p_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_dynamic_rendering_tile_properties_qcom_into(
+                self,
+                p_rendering_info,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLatencySleepModeNV.html>"]
     #[doc(alias = "vkSetLatencySleepModeNV")]
     pub fn set_latency_sleep_mode_nv(
@@ -5740,6 +7208,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkLatencySleepNV.html>"]
     #[doc(alias = "vkLatencySleepNV")]
     pub fn latency_sleep_nv(
@@ -5756,6 +7225,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLatencyMarkerNV.html>"]
     #[doc(alias = "vkSetLatencyMarkerNV")]
     pub fn set_latency_marker_nv(
@@ -5772,6 +7242,7 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetLatencyTimingsNV.html>"]
     #[doc(alias = "vkGetLatencyTimingsNV")]
     pub fn get_latency_timings_nv<S: StructureChainOut<GetLatencyMarkerInfoNV<'static>>>(
@@ -5780,6 +7251,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
     ) -> S {
         unsafe { raw::get_latency_timings_nv(self, swapchain, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "Same as [Self::get_latency_timings_nv], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetLatencyTimingsNV.html>"]
+    #[doc(alias = "vkGetLatencyTimingsNV")]
+    pub fn get_latency_timings_nv_into<S: StructureChain<GetLatencyMarkerInfoNV<'static>>>(
+        &self,
+        swapchain: &raw::SwapchainKHR,
+        p_latency_marker_info: &mut S,
+    ) {
+        unsafe {
+            raw::get_latency_timings_nv_into(
+                self,
+                swapchain,
+                p_latency_marker_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetScreenBufferPropertiesQNX.html>"]
     #[doc(alias = "vkGetScreenBufferPropertiesQNX")]
     pub fn get_screen_buffer_properties_qnx<
@@ -5792,6 +7282,54 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             raw::get_screen_buffer_properties_qnx(self, buffer, self.disp.get_command_dispatcher())
         }
     }
+    #[cfg(any(feature = "platform-screen", target_os = "nto"))]
+    #[doc = "Same as [Self::get_screen_buffer_properties_qnx], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetScreenBufferPropertiesQNX.html>"]
+    #[doc(alias = "vkGetScreenBufferPropertiesQNX")]
+    pub fn get_screen_buffer_properties_qnx_into<
+        S: StructureChain<ScreenBufferPropertiesQNX<'static>>,
+    >(
+        &self,
+        buffer: &VoidPtr,
+        p_properties: &mut S,
+    ) -> Result<()> {
+        unsafe {
+            raw::get_screen_buffer_properties_qnx_into(
+                self,
+                buffer,
+                p_properties,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCalibratedTimestampsKHR.html>"]
+    #[doc(alias = "vkGetCalibratedTimestampsKHR")]
+    pub fn get_calibrated_timestamps_khr<'a, R: DynamicArray<u64>>(
+        &self,
+        p_timestamp_infos: impl AsSlice<'a, CalibratedTimestampInfoKHR<'a>>,
+    ) -> Result<(R, u64)> {
+        unsafe {
+            raw::get_calibrated_timestamps_khr(
+                self,
+                p_timestamp_infos,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCalibratedTimestampsEXT.html>"]
+    #[doc(alias = "vkGetCalibratedTimestampsEXT")]
+    pub fn get_calibrated_timestamps_ext<'a, R: DynamicArray<u64>>(
+        &self,
+        p_timestamp_infos: impl AsSlice<'a, CalibratedTimestampInfoKHR<'a>>,
+    ) -> Result<(R, u64)> {
+        unsafe {
+            raw::get_calibrated_timestamps_ext(
+                self,
+                p_timestamp_infos,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsEXT.html>"]
     #[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsEXT")]
     pub fn get_generated_commands_memory_requirements_ext<
@@ -5808,6 +7346,25 @@ impl<D: Dispatcher, A: Allocator> Device<D, A> {
             )
         }
     }
+    #[doc = "Same as [Self::get_generated_commands_memory_requirements_ext], filling the structure chain given by the caller"]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsEXT.html>"]
+    #[doc(alias = "vkGetGeneratedCommandsMemoryRequirementsEXT")]
+    pub fn get_generated_commands_memory_requirements_ext_into<
+        S: StructureChain<MemoryRequirements2<'static>>,
+    >(
+        &self,
+        p_info: &GeneratedCommandsMemoryRequirementsInfoEXT,
+        p_memory_requirements: &mut S,
+    ) {
+        unsafe {
+            raw::get_generated_commands_memory_requirements_ext_into(
+                self,
+                p_info,
+                p_memory_requirements,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIndirectCommandsLayoutEXT.html>"]
     #[doc(alias = "vkCreateIndirectCommandsLayoutEXT")]
     pub fn create_indirect_commands_layout_ext(
@@ -5945,13 +7502,16 @@ impl<D: Dispatcher, A: Allocator> Queue<D, A> {
         &self,
         p_submits: impl AsSlice<'a, SubmitInfo<'a>>,
         fence: Option<&raw::Fence>,
-    ) -> Result<()> {
-        unsafe { raw::queue_submit(self, p_submits, fence, self.disp.get_command_dispatcher()) }
+    ) -> codes::QueueSubmitResult<()> {
+        unsafe {
+            raw::queue_submit(self, p_submits, fence, self.disp.get_command_dispatcher())
+                .narrow_err()
+        }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueWaitIdle.html>"]
     #[doc(alias = "vkQueueWaitIdle")]
-    pub fn wait_idle(&self) -> Result<()> {
-        unsafe { raw::queue_wait_idle(self, self.disp.get_command_dispatcher()) }
+    pub fn wait_idle(&self) -> codes::QueueWaitIdleResult<()> {
+        unsafe { raw::queue_wait_idle(self, self.disp.get_command_dispatcher()).narrow_err() }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueBindSparse.html>"]
     #[doc(alias = "vkQueueBindSparse")]
@@ -5970,8 +7530,11 @@ impl<D: Dispatcher, A: Allocator> Queue<D, A> {
         &self,
         p_submits: impl AsSlice<'a, SubmitInfo2<'a>>,
         fence: Option<&raw::Fence>,
-    ) -> Result<()> {
-        unsafe { raw::queue_submit2(self, p_submits, fence, self.disp.get_command_dispatcher()) }
+    ) -> codes::QueueSubmit2Result<()> {
+        unsafe {
+            raw::queue_submit2(self, p_submits, fence, self.disp.get_command_dispatcher())
+                .narrow_err()
+        }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSubmit2KHR.html>"]
     #[doc(alias = "vkQueueSubmit2KHR")]
@@ -5979,15 +7542,23 @@ impl<D: Dispatcher, A: Allocator> Queue<D, A> {
         &self,
         p_submits: impl AsSlice<'a, SubmitInfo2<'a>>,
         fence: Option<&raw::Fence>,
-    ) -> Result<()> {
+    ) -> codes::QueueSubmit2KHRResult<()> {
         unsafe {
             raw::queue_submit2_khr(self, p_submits, fence, self.disp.get_command_dispatcher())
+                .narrow_err()
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueuePresentKHR.html>"]
     #[doc(alias = "vkQueuePresentKHR")]
-    pub fn present_khr(&self, p_present_info: &PresentInfoKHR) -> Result<Status> {
-        unsafe { raw::queue_present_khr(self, p_present_info, self.disp.get_command_dispatcher()) }
+    pub fn present_khr(
+        &self,
+        p_present_info: &PresentInfoKHR,
+    ) -> codes::QueuePresentKHRResult<codes::QueuePresentKHRStatus> {
+        unsafe {
+            raw::queue_present_khr(self, p_present_info, self.disp.get_command_dispatcher())
+                .and_then(Status::narrow_success)
+                .narrow_err()
+        }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueBeginDebugUtilsLabelEXT.html>"]
     #[doc(alias = "vkQueueBeginDebugUtilsLabelEXT")]
@@ -6016,11 +7587,13 @@ impl<D: Dispatcher, A: Allocator> Queue<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueueCheckpointDataNV.html>"]
     #[doc(alias = "vkGetQueueCheckpointDataNV")]
     pub fn get_checkpoint_data_nv<R: DynamicArray<CheckpointDataNV<'static>>>(&self) -> R {
         unsafe { raw::get_queue_checkpoint_data_nv(self, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSetPerformanceConfigurationINTEL.html>"]
     #[doc(alias = "vkQueueSetPerformanceConfigurationINTEL")]
     pub fn set_performance_configuration_intel(
@@ -6035,11 +7608,13 @@ impl<D: Dispatcher, A: Allocator> Queue<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueueCheckpointData2NV.html>"]
     #[doc(alias = "vkGetQueueCheckpointData2NV")]
     pub fn get_checkpoint_data2_nv<R: DynamicArray<CheckpointData2NV<'static>>>(&self) -> R {
         unsafe { raw::get_queue_checkpoint_data2_nv(self, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueNotifyOutOfBandNV.html>"]
     #[doc(alias = "vkQueueNotifyOutOfBandNV")]
     pub fn notify_out_of_band_nv(&self, p_queue_type_info: &OutOfBandQueueTypeInfoNV) {
@@ -6511,21 +8086,33 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html>"]
     #[doc(alias = "vkBeginCommandBuffer")]
     pub fn begin(&self, p_begin_info: &CommandBufferBeginInfo) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::begin(self, p_begin_info);
         unsafe { raw::begin_command_buffer(self, p_begin_info, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEndCommandBuffer.html>"]
     #[doc(alias = "vkEndCommandBuffer")]
     pub fn end(&self) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::end(self);
         unsafe { raw::end_command_buffer(self, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandBuffer.html>"]
     #[doc(alias = "vkResetCommandBuffer")]
     pub fn reset(&self, flags: CommandBufferResetFlags) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::reset(self);
         unsafe { raw::reset_command_buffer(self, flags, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipeline.html>"]
     #[doc(alias = "vkCmdBindPipeline")]
     pub fn bind_pipeline(&self, pipeline_bind_point: PipelineBindPoint, pipeline: &raw::Pipeline) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::bind_pipeline(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_PIPELINE,
+            pipeline_bind_point,
+        );
         unsafe {
             raw::cmd_bind_pipeline(
                 self,
@@ -6538,6 +8125,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewport.html>"]
     #[doc(alias = "vkCmdSetViewport")]
     pub fn set_viewport<'a>(&self, first_viewport: u32, p_viewports: impl AsSlice<'a, Viewport>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_VIEWPORT);
         unsafe {
             raw::cmd_set_viewport(
                 self,
@@ -6550,6 +8139,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissor.html>"]
     #[doc(alias = "vkCmdSetScissor")]
     pub fn set_scissor<'a>(&self, first_scissor: u32, p_scissors: impl AsSlice<'a, Rect2D>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_SCISSOR);
         unsafe {
             raw::cmd_set_scissor(
                 self,
@@ -6562,6 +8153,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineWidth.html>"]
     #[doc(alias = "vkCmdSetLineWidth")]
     pub fn set_line_width(&self, line_width: f32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_LINE_WIDTH);
         unsafe { raw::cmd_set_line_width(self, line_width, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias.html>"]
@@ -6572,6 +8165,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         depth_bias_clamp: f32,
         depth_bias_slope_factor: f32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_DEPTH_BIAS);
         unsafe {
             raw::cmd_set_depth_bias(
                 self,
@@ -6585,6 +8180,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetBlendConstants.html>"]
     #[doc(alias = "vkCmdSetBlendConstants")]
     pub fn set_blend_constants(&self, blend_constants: [f32; 4u16 as _]) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_BLEND_CONSTANTS,
+        );
         unsafe {
             raw::cmd_set_blend_constants(self, blend_constants, self.disp.get_command_dispatcher())
         }
@@ -6592,6 +8192,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBounds.html>"]
     #[doc(alias = "vkCmdSetDepthBounds")]
     pub fn set_depth_bounds(&self, min_depth_bounds: f32, max_depth_bounds: f32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_DEPTH_BOUNDS);
         unsafe {
             raw::cmd_set_depth_bounds(
                 self,
@@ -6604,6 +8206,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilCompareMask.html>"]
     #[doc(alias = "vkCmdSetStencilCompareMask")]
     pub fn set_stencil_compare_mask(&self, face_mask: StencilFaceFlags, compare_mask: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_STENCIL_COMPARE_MASK,
+        );
         unsafe {
             raw::cmd_set_stencil_compare_mask(
                 self,
@@ -6616,6 +8223,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilWriteMask.html>"]
     #[doc(alias = "vkCmdSetStencilWriteMask")]
     pub fn set_stencil_write_mask(&self, face_mask: StencilFaceFlags, write_mask: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_STENCIL_WRITE_MASK,
+        );
         unsafe {
             raw::cmd_set_stencil_write_mask(
                 self,
@@ -6628,6 +8240,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilReference.html>"]
     #[doc(alias = "vkCmdSetStencilReference")]
     pub fn set_stencil_reference(&self, face_mask: StencilFaceFlags, reference: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_STENCIL_REFERENCE,
+        );
         unsafe {
             raw::cmd_set_stencil_reference(
                 self,
@@ -6647,6 +8264,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_descriptor_sets: impl AsSlice<'a, V5>,
         p_dynamic_offsets: impl AsSlice<'a, u32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_DESCRIPTOR_SETS,
+        );
         unsafe {
             raw::cmd_bind_descriptor_sets(
                 self,
@@ -6667,6 +8289,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         offset: DeviceSize,
         index_type: IndexType,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BIND_INDEX_BUFFER);
         unsafe {
             raw::cmd_bind_index_buffer(
                 self,
@@ -6685,6 +8309,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_buffers: impl AsSlice<'a, V3>,
         p_offsets: impl AsSlice<'a, DeviceSize>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_VERTEX_BUFFERS,
+        );
         unsafe {
             raw::cmd_bind_vertex_buffers(
                 self,
@@ -6704,6 +8333,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_vertex: u32,
         first_instance: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DRAW);
         unsafe {
             raw::cmd_draw(
                 self,
@@ -6725,6 +8356,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         vertex_offset: i32,
         first_instance: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DRAW_INDEXED);
         unsafe {
             raw::cmd_draw_indexed(
                 self,
@@ -6746,6 +8379,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DRAW_INDIRECT);
         unsafe {
             raw::cmd_draw_indirect(
                 self,
@@ -6766,6 +8401,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDEXED_INDIRECT,
+        );
         unsafe {
             raw::cmd_draw_indexed_indirect(
                 self,
@@ -6780,6 +8420,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatch.html>"]
     #[doc(alias = "vkCmdDispatch")]
     pub fn dispatch(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DISPATCH);
         unsafe {
             raw::cmd_dispatch(
                 self,
@@ -6793,6 +8435,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchIndirect.html>"]
     #[doc(alias = "vkCmdDispatchIndirect")]
     pub fn dispatch_indirect(&self, buffer: &raw::Buffer, offset: DeviceSize) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DISPATCH_INDIRECT);
         unsafe {
             raw::cmd_dispatch_indirect(self, buffer, offset, self.disp.get_command_dispatcher())
         }
@@ -6805,6 +8449,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_buffer: &raw::Buffer,
         p_regions: impl AsSlice<'a, BufferCopy>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_BUFFER);
         unsafe {
             raw::cmd_copy_buffer(
                 self,
@@ -6825,6 +8471,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_image_layout: ImageLayout,
         p_regions: impl AsSlice<'a, ImageCopy>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_IMAGE);
         unsafe {
             raw::cmd_copy_image(
                 self,
@@ -6848,6 +8496,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_regions: impl AsSlice<'a, ImageBlit>,
         filter: Filter,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BLIT_IMAGE);
         unsafe {
             raw::cmd_blit_image(
                 self,
@@ -6870,6 +8520,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_image_layout: ImageLayout,
         p_regions: impl AsSlice<'a, BufferImageCopy>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_BUFFER_TO_IMAGE,
+        );
         unsafe {
             raw::cmd_copy_buffer_to_image(
                 self,
@@ -6890,6 +8545,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_buffer: &raw::Buffer,
         p_regions: impl AsSlice<'a, BufferImageCopy>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_IMAGE_TO_BUFFER,
+        );
         unsafe {
             raw::cmd_copy_image_to_buffer(
                 self,
@@ -6910,6 +8570,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         data_size: DeviceSize,
         p_data: VoidPtr,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_UPDATE_BUFFER);
         unsafe {
             raw::cmd_update_buffer(
                 self,
@@ -6930,6 +8592,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         size: DeviceSize,
         data: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_FILL_BUFFER);
         unsafe {
             raw::cmd_fill_buffer(
                 self,
@@ -6950,6 +8614,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_color: &ClearColorValue,
         p_ranges: impl AsSlice<'a, ImageSubresourceRange>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_CLEAR_COLOR_IMAGE);
         unsafe {
             raw::cmd_clear_color_image(
                 self,
@@ -6970,6 +8636,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_depth_stencil: &ClearDepthStencilValue,
         p_ranges: impl AsSlice<'a, ImageSubresourceRange>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_CLEAR_DEPTH_STENCIL_IMAGE,
+        );
         unsafe {
             raw::cmd_clear_depth_stencil_image(
                 self,
@@ -6988,6 +8659,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_attachments: impl AsSlice<'a, ClearAttachment>,
         p_rects: impl AsSlice<'a, ClearRect>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_CLEAR_ATTACHMENTS);
         unsafe {
             raw::cmd_clear_attachments(
                 self,
@@ -7007,6 +8680,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_image_layout: ImageLayout,
         p_regions: impl AsSlice<'a, ImageResolve>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESOLVE_IMAGE);
         unsafe {
             raw::cmd_resolve_image(
                 self,
@@ -7022,11 +8697,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent.html>"]
     #[doc(alias = "vkCmdSetEvent")]
     pub fn set_event(&self, event: &raw::Event, stage_mask: PipelineStageFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_EVENT);
         unsafe { raw::cmd_set_event(self, event, stage_mask, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent.html>"]
     #[doc(alias = "vkCmdResetEvent")]
     pub fn reset_event(&self, event: &raw::Event, stage_mask: PipelineStageFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESET_EVENT);
         unsafe { raw::cmd_reset_event(self, event, stage_mask, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWaitEvents.html>"]
@@ -7040,6 +8719,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_buffer_memory_barriers: impl AsSlice<'a, BufferMemoryBarrier<'a>>,
         p_image_memory_barriers: impl AsSlice<'a, ImageMemoryBarrier<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_WAIT_EVENTS);
         unsafe {
             raw::cmd_wait_events(
                 self,
@@ -7064,6 +8745,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_buffer_memory_barriers: impl AsSlice<'a, BufferMemoryBarrier<'a>>,
         p_image_memory_barriers: impl AsSlice<'a, ImageMemoryBarrier<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_PIPELINE_BARRIER);
         unsafe {
             raw::cmd_pipeline_barrier(
                 self,
@@ -7080,6 +8763,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQuery.html>"]
     #[doc(alias = "vkCmdBeginQuery")]
     pub fn begin_query(&self, query_pool: &raw::QueryPool, query: u32, flags: QueryControlFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BEGIN_QUERY);
         unsafe {
             raw::cmd_begin_query(
                 self,
@@ -7093,6 +8778,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQuery.html>"]
     #[doc(alias = "vkCmdEndQuery")]
     pub fn end_query(&self, query_pool: &raw::QueryPool, query: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_END_QUERY);
         unsafe { raw::cmd_end_query(self, query_pool, query, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetQueryPool.html>"]
@@ -7103,6 +8790,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_query: u32,
         query_count: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESET_QUERY_POOL);
         unsafe {
             raw::cmd_reset_query_pool(
                 self,
@@ -7121,6 +8810,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         query_pool: &raw::QueryPool,
         query: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_WRITE_TIMESTAMP);
         unsafe {
             raw::cmd_write_timestamp(
                 self,
@@ -7143,6 +8834,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         stride: DeviceSize,
         flags: QueryResultFlags,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_QUERY_POOL_RESULTS,
+        );
         unsafe {
             raw::cmd_copy_query_pool_results(
                 self,
@@ -7167,6 +8863,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         size: u32,
         p_values: VoidPtr,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_PUSH_CONSTANTS);
         unsafe {
             raw::cmd_push_constants(
                 self,
@@ -7186,6 +8884,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_render_pass_begin: &RenderPassBeginInfo,
         contents: SubpassContents,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BEGIN_RENDER_PASS);
         unsafe {
             raw::cmd_begin_render_pass(
                 self,
@@ -7198,11 +8898,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdNextSubpass.html>"]
     #[doc(alias = "vkCmdNextSubpass")]
     pub fn next_subpass(&self, contents: SubpassContents) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_NEXT_SUBPASS);
         unsafe { raw::cmd_next_subpass(self, contents, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass.html>"]
     #[doc(alias = "vkCmdEndRenderPass")]
     pub fn end_render_pass(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_END_RENDER_PASS);
         unsafe { raw::cmd_end_render_pass(self, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteCommands.html>"]
@@ -7211,6 +8915,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_command_buffers: impl AsSlice<'a, V2>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_EXECUTE_COMMANDS);
         unsafe {
             raw::cmd_execute_commands(self, p_command_buffers, self.disp.get_command_dispatcher())
         }
@@ -7218,11 +8924,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDeviceMask.html>"]
     #[doc(alias = "vkCmdSetDeviceMask")]
     pub fn set_device_mask(&self, device_mask: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_DEVICE_MASK);
         unsafe { raw::cmd_set_device_mask(self, device_mask, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDeviceMaskKHR.html>"]
     #[doc(alias = "vkCmdSetDeviceMaskKHR")]
     pub fn set_device_mask_khr(&self, device_mask: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_DEVICE_MASK);
         unsafe {
             raw::cmd_set_device_mask_khr(self, device_mask, self.disp.get_command_dispatcher())
         }
@@ -7238,6 +8948,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DISPATCH_BASE);
         unsafe {
             raw::cmd_dispatch_base(
                 self,
@@ -7262,6 +8974,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DISPATCH_BASE);
         unsafe {
             raw::cmd_dispatch_base_khr(
                 self,
@@ -7286,6 +9000,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDIRECT_COUNT,
+        );
         unsafe {
             raw::cmd_draw_indirect_count(
                 self,
@@ -7310,6 +9029,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDIRECT_COUNT,
+        );
         unsafe {
             raw::cmd_draw_indirect_count_khr(
                 self,
@@ -7323,6 +9047,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCountAMD.html>"]
     #[doc(alias = "vkCmdDrawIndirectCountAMD")]
     pub fn draw_indirect_count_amd(
@@ -7334,6 +9059,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDIRECT_COUNT,
+        );
         unsafe {
             raw::cmd_draw_indirect_count_amd(
                 self,
@@ -7358,6 +9088,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDEXED_INDIRECT_COUNT,
+        );
         unsafe {
             raw::cmd_draw_indexed_indirect_count(
                 self,
@@ -7382,6 +9117,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDEXED_INDIRECT_COUNT,
+        );
         unsafe {
             raw::cmd_draw_indexed_indirect_count_khr(
                 self,
@@ -7395,6 +9135,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCountAMD.html>"]
     #[doc(alias = "vkCmdDrawIndexedIndirectCountAMD")]
     pub fn draw_indexed_indirect_count_amd(
@@ -7406,6 +9147,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDEXED_INDIRECT_COUNT,
+        );
         unsafe {
             raw::cmd_draw_indexed_indirect_count_amd(
                 self,
@@ -7426,6 +9172,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_render_pass_begin: &RenderPassBeginInfo,
         p_subpass_begin_info: &SubpassBeginInfo,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_RENDER_PASS2,
+        );
         unsafe {
             raw::cmd_begin_render_pass2(
                 self,
@@ -7442,6 +9193,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_render_pass_begin: &RenderPassBeginInfo,
         p_subpass_begin_info: &SubpassBeginInfo,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_RENDER_PASS2,
+        );
         unsafe {
             raw::cmd_begin_render_pass2_khr(
                 self,
@@ -7458,6 +9214,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_subpass_begin_info: &SubpassBeginInfo,
         p_subpass_end_info: &SubpassEndInfo,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_NEXT_SUBPASS2);
         unsafe {
             raw::cmd_next_subpass2(
                 self,
@@ -7474,6 +9232,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_subpass_begin_info: &SubpassBeginInfo,
         p_subpass_end_info: &SubpassEndInfo,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_NEXT_SUBPASS2);
         unsafe {
             raw::cmd_next_subpass2_khr(
                 self,
@@ -7486,6 +9246,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass2.html>"]
     #[doc(alias = "vkCmdEndRenderPass2")]
     pub fn end_render_pass2(&self, p_subpass_end_info: &SubpassEndInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_END_RENDER_PASS2);
         unsafe {
             raw::cmd_end_render_pass2(self, p_subpass_end_info, self.disp.get_command_dispatcher())
         }
@@ -7493,6 +9255,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass2KHR.html>"]
     #[doc(alias = "vkCmdEndRenderPass2KHR")]
     pub fn end_render_pass2_khr(&self, p_subpass_end_info: &SubpassEndInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_END_RENDER_PASS2);
         unsafe {
             raw::cmd_end_render_pass2_khr(
                 self,
@@ -7504,6 +9268,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent2.html>"]
     #[doc(alias = "vkCmdSetEvent2")]
     pub fn set_event2(&self, event: &raw::Event, p_dependency_info: &DependencyInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_EVENT2);
         unsafe {
             raw::cmd_set_event2(
                 self,
@@ -7516,6 +9282,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent2KHR.html>"]
     #[doc(alias = "vkCmdSetEvent2KHR")]
     pub fn set_event2_khr(&self, event: &raw::Event, p_dependency_info: &DependencyInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_EVENT2);
         unsafe {
             raw::cmd_set_event2_khr(
                 self,
@@ -7528,6 +9296,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent2.html>"]
     #[doc(alias = "vkCmdResetEvent2")]
     pub fn reset_event2(&self, event: &raw::Event, stage_mask: PipelineStageFlags2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESET_EVENT2);
         unsafe {
             raw::cmd_reset_event2(self, event, stage_mask, self.disp.get_command_dispatcher())
         }
@@ -7535,6 +9305,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent2KHR.html>"]
     #[doc(alias = "vkCmdResetEvent2KHR")]
     pub fn reset_event2_khr(&self, event: &raw::Event, stage_mask: PipelineStageFlags2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESET_EVENT2);
         unsafe {
             raw::cmd_reset_event2_khr(self, event, stage_mask, self.disp.get_command_dispatcher())
         }
@@ -7546,6 +9318,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_events: impl AsSlice<'a, V2>,
         p_dependency_infos: impl AsSlice<'a, DependencyInfo<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_WAIT_EVENTS2);
         unsafe {
             raw::cmd_wait_events2(
                 self,
@@ -7562,6 +9336,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_events: impl AsSlice<'a, V2>,
         p_dependency_infos: impl AsSlice<'a, DependencyInfo<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_WAIT_EVENTS2);
         unsafe {
             raw::cmd_wait_events2_khr(
                 self,
@@ -7574,6 +9350,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2.html>"]
     #[doc(alias = "vkCmdPipelineBarrier2")]
     pub fn pipeline_barrier2(&self, p_dependency_info: &DependencyInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_PIPELINE_BARRIER2);
         unsafe {
             raw::cmd_pipeline_barrier2(self, p_dependency_info, self.disp.get_command_dispatcher())
         }
@@ -7581,6 +9359,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2KHR.html>"]
     #[doc(alias = "vkCmdPipelineBarrier2KHR")]
     pub fn pipeline_barrier2_khr(&self, p_dependency_info: &DependencyInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_PIPELINE_BARRIER2);
         unsafe {
             raw::cmd_pipeline_barrier2_khr(
                 self,
@@ -7597,6 +9377,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         query_pool: &raw::QueryPool,
         query: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_WRITE_TIMESTAMP2);
         unsafe {
             raw::cmd_write_timestamp2(
                 self,
@@ -7615,6 +9397,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         query_pool: &raw::QueryPool,
         query: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_WRITE_TIMESTAMP2);
         unsafe {
             raw::cmd_write_timestamp2_khr(
                 self,
@@ -7628,6 +9412,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2.html>"]
     #[doc(alias = "vkCmdCopyBuffer2")]
     pub fn copy_buffer2(&self, p_copy_buffer_info: &CopyBufferInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_BUFFER2);
         unsafe {
             raw::cmd_copy_buffer2(self, p_copy_buffer_info, self.disp.get_command_dispatcher())
         }
@@ -7635,6 +9421,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2KHR.html>"]
     #[doc(alias = "vkCmdCopyBuffer2KHR")]
     pub fn copy_buffer2_khr(&self, p_copy_buffer_info: &CopyBufferInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_BUFFER2);
         unsafe {
             raw::cmd_copy_buffer2_khr(self, p_copy_buffer_info, self.disp.get_command_dispatcher())
         }
@@ -7642,11 +9430,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage2.html>"]
     #[doc(alias = "vkCmdCopyImage2")]
     pub fn copy_image2(&self, p_copy_image_info: &CopyImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_IMAGE2);
         unsafe { raw::cmd_copy_image2(self, p_copy_image_info, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage2KHR.html>"]
     #[doc(alias = "vkCmdCopyImage2KHR")]
     pub fn copy_image2_khr(&self, p_copy_image_info: &CopyImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_IMAGE2);
         unsafe {
             raw::cmd_copy_image2_khr(self, p_copy_image_info, self.disp.get_command_dispatcher())
         }
@@ -7654,6 +9446,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage2.html>"]
     #[doc(alias = "vkCmdCopyBufferToImage2")]
     pub fn copy_buffer_to_image2(&self, p_copy_buffer_to_image_info: &CopyBufferToImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_BUFFER_TO_IMAGE2,
+        );
         unsafe {
             raw::cmd_copy_buffer_to_image2(
                 self,
@@ -7665,6 +9462,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage2KHR.html>"]
     #[doc(alias = "vkCmdCopyBufferToImage2KHR")]
     pub fn copy_buffer_to_image2_khr(&self, p_copy_buffer_to_image_info: &CopyBufferToImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_BUFFER_TO_IMAGE2,
+        );
         unsafe {
             raw::cmd_copy_buffer_to_image2_khr(
                 self,
@@ -7676,6 +9478,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer2.html>"]
     #[doc(alias = "vkCmdCopyImageToBuffer2")]
     pub fn copy_image_to_buffer2(&self, p_copy_image_to_buffer_info: &CopyImageToBufferInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_IMAGE_TO_BUFFER2,
+        );
         unsafe {
             raw::cmd_copy_image_to_buffer2(
                 self,
@@ -7687,6 +9494,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer2KHR.html>"]
     #[doc(alias = "vkCmdCopyImageToBuffer2KHR")]
     pub fn copy_image_to_buffer2_khr(&self, p_copy_image_to_buffer_info: &CopyImageToBufferInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_IMAGE_TO_BUFFER2,
+        );
         unsafe {
             raw::cmd_copy_image_to_buffer2_khr(
                 self,
@@ -7698,11 +9510,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage2.html>"]
     #[doc(alias = "vkCmdBlitImage2")]
     pub fn blit_image2(&self, p_blit_image_info: &BlitImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BLIT_IMAGE2);
         unsafe { raw::cmd_blit_image2(self, p_blit_image_info, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage2KHR.html>"]
     #[doc(alias = "vkCmdBlitImage2KHR")]
     pub fn blit_image2_khr(&self, p_blit_image_info: &BlitImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BLIT_IMAGE2);
         unsafe {
             raw::cmd_blit_image2_khr(self, p_blit_image_info, self.disp.get_command_dispatcher())
         }
@@ -7710,6 +9526,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage2.html>"]
     #[doc(alias = "vkCmdResolveImage2")]
     pub fn resolve_image2(&self, p_resolve_image_info: &ResolveImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESOLVE_IMAGE2);
         unsafe {
             raw::cmd_resolve_image2(
                 self,
@@ -7721,6 +9539,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage2KHR.html>"]
     #[doc(alias = "vkCmdResolveImage2KHR")]
     pub fn resolve_image2_khr(&self, p_resolve_image_info: &ResolveImageInfo2) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_RESOLVE_IMAGE2);
         unsafe {
             raw::cmd_resolve_image2_khr(
                 self,
@@ -7732,6 +9552,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRendering.html>"]
     #[doc(alias = "vkCmdBeginRendering")]
     pub fn begin_rendering(&self, p_rendering_info: &RenderingInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BEGIN_RENDERING);
         unsafe {
             raw::cmd_begin_rendering(self, p_rendering_info, self.disp.get_command_dispatcher())
         }
@@ -7739,6 +9561,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderingKHR.html>"]
     #[doc(alias = "vkCmdBeginRenderingKHR")]
     pub fn begin_rendering_khr(&self, p_rendering_info: &RenderingInfo) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BEGIN_RENDERING);
         unsafe {
             raw::cmd_begin_rendering_khr(self, p_rendering_info, self.disp.get_command_dispatcher())
         }
@@ -7746,36 +9570,53 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRendering.html>"]
     #[doc(alias = "vkCmdEndRendering")]
     pub fn end_rendering(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_END_RENDERING);
         unsafe { raw::cmd_end_rendering(self, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderingKHR.html>"]
     #[doc(alias = "vkCmdEndRenderingKHR")]
     pub fn end_rendering_khr(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_END_RENDERING);
         unsafe { raw::cmd_end_rendering_khr(self, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullMode.html>"]
     #[doc(alias = "vkCmdSetCullMode")]
     pub fn set_cull_mode(&self, cull_mode: CullModeFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_CULL_MODE);
         unsafe { raw::cmd_set_cull_mode(self, cull_mode, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullModeEXT.html>"]
     #[doc(alias = "vkCmdSetCullModeEXT")]
     pub fn set_cull_mode_ext(&self, cull_mode: CullModeFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_CULL_MODE);
         unsafe { raw::cmd_set_cull_mode_ext(self, cull_mode, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFrontFace.html>"]
     #[doc(alias = "vkCmdSetFrontFace")]
     pub fn set_front_face(&self, front_face: FrontFace) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_FRONT_FACE);
         unsafe { raw::cmd_set_front_face(self, front_face, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFrontFaceEXT.html>"]
     #[doc(alias = "vkCmdSetFrontFaceEXT")]
     pub fn set_front_face_ext(&self, front_face: FrontFace) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_FRONT_FACE);
         unsafe { raw::cmd_set_front_face_ext(self, front_face, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveTopology.html>"]
     #[doc(alias = "vkCmdSetPrimitiveTopology")]
     pub fn set_primitive_topology(&self, primitive_topology: PrimitiveTopology) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PRIMITIVE_TOPOLOGY,
+        );
         unsafe {
             raw::cmd_set_primitive_topology(
                 self,
@@ -7787,6 +9628,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveTopologyEXT.html>"]
     #[doc(alias = "vkCmdSetPrimitiveTopologyEXT")]
     pub fn set_primitive_topology_ext(&self, primitive_topology: PrimitiveTopology) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PRIMITIVE_TOPOLOGY,
+        );
         unsafe {
             raw::cmd_set_primitive_topology_ext(
                 self,
@@ -7798,6 +9644,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCount.html>"]
     #[doc(alias = "vkCmdSetViewportWithCount")]
     pub fn set_viewport_with_count<'a>(&self, p_viewports: impl AsSlice<'a, Viewport>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VIEWPORT_WITH_COUNT,
+        );
         unsafe {
             raw::cmd_set_viewport_with_count(self, p_viewports, self.disp.get_command_dispatcher())
         }
@@ -7805,6 +9656,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCountEXT.html>"]
     #[doc(alias = "vkCmdSetViewportWithCountEXT")]
     pub fn set_viewport_with_count_ext<'a>(&self, p_viewports: impl AsSlice<'a, Viewport>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VIEWPORT_WITH_COUNT,
+        );
         unsafe {
             raw::cmd_set_viewport_with_count_ext(
                 self,
@@ -7816,6 +9672,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCount.html>"]
     #[doc(alias = "vkCmdSetScissorWithCount")]
     pub fn set_scissor_with_count<'a>(&self, p_scissors: impl AsSlice<'a, Rect2D>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_SCISSOR_WITH_COUNT,
+        );
         unsafe {
             raw::cmd_set_scissor_with_count(self, p_scissors, self.disp.get_command_dispatcher())
         }
@@ -7823,6 +9684,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCountEXT.html>"]
     #[doc(alias = "vkCmdSetScissorWithCountEXT")]
     pub fn set_scissor_with_count_ext<'a>(&self, p_scissors: impl AsSlice<'a, Rect2D>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_SCISSOR_WITH_COUNT,
+        );
         unsafe {
             raw::cmd_set_scissor_with_count_ext(
                 self,
@@ -7841,6 +9707,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
         p_strides: Option<impl AsSlice<'a, DeviceSize>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_VERTEX_BUFFERS2,
+        );
         unsafe {
             raw::cmd_bind_vertex_buffers2(
                 self,
@@ -7863,6 +9734,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
         p_strides: Option<impl AsSlice<'a, DeviceSize>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_VERTEX_BUFFERS2,
+        );
         unsafe {
             raw::cmd_bind_vertex_buffers2_ext(
                 self,
@@ -7878,6 +9754,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthTestEnable.html>"]
     #[doc(alias = "vkCmdSetDepthTestEnable")]
     pub fn set_depth_test_enable(&self, depth_test_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_TEST_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_test_enable(
                 self,
@@ -7889,6 +9770,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthTestEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDepthTestEnableEXT")]
     pub fn set_depth_test_enable_ext(&self, depth_test_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_TEST_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_test_enable_ext(
                 self,
@@ -7900,6 +9786,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthWriteEnable.html>"]
     #[doc(alias = "vkCmdSetDepthWriteEnable")]
     pub fn set_depth_write_enable(&self, depth_write_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_WRITE_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_write_enable(
                 self,
@@ -7911,6 +9802,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthWriteEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDepthWriteEnableEXT")]
     pub fn set_depth_write_enable_ext(&self, depth_write_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_WRITE_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_write_enable_ext(
                 self,
@@ -7922,6 +9818,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthCompareOp.html>"]
     #[doc(alias = "vkCmdSetDepthCompareOp")]
     pub fn set_depth_compare_op(&self, depth_compare_op: CompareOp) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_COMPARE_OP,
+        );
         unsafe {
             raw::cmd_set_depth_compare_op(
                 self,
@@ -7933,6 +9834,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthCompareOpEXT.html>"]
     #[doc(alias = "vkCmdSetDepthCompareOpEXT")]
     pub fn set_depth_compare_op_ext(&self, depth_compare_op: CompareOp) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_COMPARE_OP,
+        );
         unsafe {
             raw::cmd_set_depth_compare_op_ext(
                 self,
@@ -7944,6 +9850,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBoundsTestEnable.html>"]
     #[doc(alias = "vkCmdSetDepthBoundsTestEnable")]
     pub fn set_depth_bounds_test_enable(&self, depth_bounds_test_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_BOUNDS_TEST_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_bounds_test_enable(
                 self,
@@ -7955,6 +9866,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBoundsTestEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDepthBoundsTestEnableEXT")]
     pub fn set_depth_bounds_test_enable_ext(&self, depth_bounds_test_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_BOUNDS_TEST_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_bounds_test_enable_ext(
                 self,
@@ -7966,6 +9882,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilTestEnable.html>"]
     #[doc(alias = "vkCmdSetStencilTestEnable")]
     pub fn set_stencil_test_enable(&self, stencil_test_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_STENCIL_TEST_ENABLE,
+        );
         unsafe {
             raw::cmd_set_stencil_test_enable(
                 self,
@@ -7977,6 +9898,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilTestEnableEXT.html>"]
     #[doc(alias = "vkCmdSetStencilTestEnableEXT")]
     pub fn set_stencil_test_enable_ext(&self, stencil_test_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_STENCIL_TEST_ENABLE,
+        );
         unsafe {
             raw::cmd_set_stencil_test_enable_ext(
                 self,
@@ -7995,6 +9921,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         depth_fail_op: StencilOp,
         compare_op: CompareOp,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_STENCIL_OP);
         unsafe {
             raw::cmd_set_stencil_op(
                 self,
@@ -8017,6 +9945,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         depth_fail_op: StencilOp,
         compare_op: CompareOp,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_STENCIL_OP);
         unsafe {
             raw::cmd_set_stencil_op_ext(
                 self,
@@ -8032,6 +9962,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizerDiscardEnable.html>"]
     #[doc(alias = "vkCmdSetRasterizerDiscardEnable")]
     pub fn set_rasterizer_discard_enable(&self, rasterizer_discard_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RASTERIZER_DISCARD_ENABLE,
+        );
         unsafe {
             raw::cmd_set_rasterizer_discard_enable(
                 self,
@@ -8043,6 +9978,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizerDiscardEnableEXT.html>"]
     #[doc(alias = "vkCmdSetRasterizerDiscardEnableEXT")]
     pub fn set_rasterizer_discard_enable_ext(&self, rasterizer_discard_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RASTERIZER_DISCARD_ENABLE,
+        );
         unsafe {
             raw::cmd_set_rasterizer_discard_enable_ext(
                 self,
@@ -8054,6 +9994,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBiasEnable.html>"]
     #[doc(alias = "vkCmdSetDepthBiasEnable")]
     pub fn set_depth_bias_enable(&self, depth_bias_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_BIAS_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_bias_enable(
                 self,
@@ -8065,6 +10010,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBiasEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDepthBiasEnableEXT")]
     pub fn set_depth_bias_enable_ext(&self, depth_bias_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_BIAS_ENABLE,
+        );
         unsafe {
             raw::cmd_set_depth_bias_enable_ext(
                 self,
@@ -8076,6 +10026,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveRestartEnable.html>"]
     #[doc(alias = "vkCmdSetPrimitiveRestartEnable")]
     pub fn set_primitive_restart_enable(&self, primitive_restart_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PRIMITIVE_RESTART_ENABLE,
+        );
         unsafe {
             raw::cmd_set_primitive_restart_enable(
                 self,
@@ -8087,6 +10042,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveRestartEnableEXT.html>"]
     #[doc(alias = "vkCmdSetPrimitiveRestartEnableEXT")]
     pub fn set_primitive_restart_enable_ext(&self, primitive_restart_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PRIMITIVE_RESTART_ENABLE,
+        );
         unsafe {
             raw::cmd_set_primitive_restart_enable_ext(
                 self,
@@ -8098,6 +10058,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerBeginEXT.html>"]
     #[doc(alias = "vkCmdDebugMarkerBeginEXT")]
     pub fn debug_marker_begin_ext(&self, p_marker_info: &DebugMarkerMarkerInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DEBUG_MARKER_BEGIN_EXT,
+        );
         unsafe {
             raw::cmd_debug_marker_begin_ext(self, p_marker_info, self.disp.get_command_dispatcher())
         }
@@ -8105,11 +10070,21 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerEndEXT.html>"]
     #[doc(alias = "vkCmdDebugMarkerEndEXT")]
     pub fn debug_marker_end_ext(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DEBUG_MARKER_END_EXT,
+        );
         unsafe { raw::cmd_debug_marker_end_ext(self, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerInsertEXT.html>"]
     #[doc(alias = "vkCmdDebugMarkerInsertEXT")]
     pub fn debug_marker_insert_ext(&self, p_marker_info: &DebugMarkerMarkerInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DEBUG_MARKER_INSERT_EXT,
+        );
         unsafe {
             raw::cmd_debug_marker_insert_ext(
                 self,
@@ -8118,6 +10093,59 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginVideoCodingKHR.html>"]
+    #[doc(alias = "vkCmdBeginVideoCodingKHR")]
+    pub fn begin_video_coding_khr(&self, p_begin_info: &VideoBeginCodingInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_VIDEO_CODING_KHR,
+        );
+        unsafe {
+            raw::cmd_begin_video_coding_khr(self, p_begin_info, self.disp.get_command_dispatcher())
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndVideoCodingKHR.html>"]
+    #[doc(alias = "vkCmdEndVideoCodingKHR")]
+    pub fn end_video_coding_khr(&self, p_end_coding_info: &VideoEndCodingInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_END_VIDEO_CODING_KHR,
+        );
+        unsafe {
+            raw::cmd_end_video_coding_khr(
+                self,
+                p_end_coding_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdControlVideoCodingKHR.html>"]
+    #[doc(alias = "vkCmdControlVideoCodingKHR")]
+    pub fn control_video_coding_khr(&self, p_coding_control_info: &VideoCodingControlInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_CONTROL_VIDEO_CODING_KHR,
+        );
+        unsafe {
+            raw::cmd_control_video_coding_khr(
+                self,
+                p_coding_control_info,
+                self.disp.get_command_dispatcher(),
+            )
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecodeVideoKHR.html>"]
+    #[doc(alias = "vkCmdDecodeVideoKHR")]
+    pub fn decode_video_khr(&self, p_decode_info: &VideoDecodeInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DECODE_VIDEO_KHR);
+        unsafe {
+            raw::cmd_decode_video_khr(self, p_decode_info, self.disp.get_command_dispatcher())
+        }
+    }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html>"]
     #[doc(alias = "vkCmdBindTransformFeedbackBuffersEXT")]
     pub fn bind_transform_feedback_buffers_ext<'a, V3: Alias<raw::Buffer> + 'a>(
@@ -8127,6 +10155,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_offsets: impl AsSlice<'a, DeviceSize>,
         p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_TRANSFORM_FEEDBACK_BUFFERS_EXT,
+        );
         unsafe {
             raw::cmd_bind_transform_feedback_buffers_ext(
                 self,
@@ -8146,6 +10179,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_counter_buffers: impl AsSlice<'a, V3>,
         p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_TRANSFORM_FEEDBACK_EXT,
+        );
         unsafe {
             raw::cmd_begin_transform_feedback_ext(
                 self,
@@ -8164,6 +10202,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_counter_buffers: impl AsSlice<'a, V3>,
         p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_END_TRANSFORM_FEEDBACK_EXT,
+        );
         unsafe {
             raw::cmd_end_transform_feedback_ext(
                 self,
@@ -8183,6 +10226,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         flags: QueryControlFlags,
         index: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_QUERY_INDEXED_EXT,
+        );
         unsafe {
             raw::cmd_begin_query_indexed_ext(
                 self,
@@ -8197,6 +10245,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQueryIndexedEXT.html>"]
     #[doc(alias = "vkCmdEndQueryIndexedEXT")]
     pub fn end_query_indexed_ext(&self, query_pool: &raw::QueryPool, query: u32, index: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_END_QUERY_INDEXED_EXT,
+        );
         unsafe {
             raw::cmd_end_query_indexed_ext(
                 self,
@@ -8218,6 +10271,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         counter_offset: u32,
         vertex_stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_INDIRECT_BYTE_COUNT_EXT,
+        );
         unsafe {
             raw::cmd_draw_indirect_byte_count_ext(
                 self,
@@ -8231,9 +10289,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nvx")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCuLaunchKernelNVX.html>"]
     #[doc(alias = "vkCmdCuLaunchKernelNVX")]
     pub fn cu_launch_kernel_nvx(&self, p_launch_info: &CuLaunchInfoNVX) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_CU_LAUNCH_KERNEL_NVX,
+        );
         unsafe {
             raw::cmd_cu_launch_kernel_nvx(self, p_launch_info, self.disp.get_command_dispatcher())
         }
@@ -8247,6 +10311,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         set: u32,
         p_descriptor_writes: impl AsSlice<'a, WriteDescriptorSet<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PUSH_DESCRIPTOR_SET_KHR,
+        );
         unsafe {
             raw::cmd_push_descriptor_set_khr(
                 self,
@@ -8267,6 +10336,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         set: u32,
         p_data: VoidPtr,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE_KHR,
+        );
         unsafe {
             raw::cmd_push_descriptor_set_with_template_khr(
                 self,
@@ -8284,6 +10358,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_conditional_rendering_begin: &ConditionalRenderingBeginInfoEXT,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_CONDITIONAL_RENDERING_EXT,
+        );
         unsafe {
             raw::cmd_begin_conditional_rendering_ext(
                 self,
@@ -8295,8 +10374,14 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndConditionalRenderingEXT.html>"]
     #[doc(alias = "vkCmdEndConditionalRenderingEXT")]
     pub fn end_conditional_rendering_ext(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_END_CONDITIONAL_RENDERING_EXT,
+        );
         unsafe { raw::cmd_end_conditional_rendering_ext(self, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingNV.html>"]
     #[doc(alias = "vkCmdSetViewportWScalingNV")]
     pub fn set_viewport_wscaling_nv<'a>(
@@ -8304,6 +10389,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_viewport: u32,
         p_viewport_wscalings: impl AsSlice<'a, ViewportWScalingNV>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VIEWPORT_WSCALING_NV,
+        );
         unsafe {
             raw::cmd_set_viewport_wscaling_nv(
                 self,
@@ -8320,6 +10410,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_discard_rectangle: u32,
         p_discard_rectangles: impl AsSlice<'a, Rect2D>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DISCARD_RECTANGLE_EXT,
+        );
         unsafe {
             raw::cmd_set_discard_rectangle_ext(
                 self,
@@ -8332,6 +10427,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDiscardRectangleEnableEXT")]
     pub fn set_discard_rectangle_enable_ext(&self, discard_rectangle_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DISCARD_RECTANGLE_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_discard_rectangle_enable_ext(
                 self,
@@ -8343,6 +10443,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleModeEXT.html>"]
     #[doc(alias = "vkCmdSetDiscardRectangleModeEXT")]
     pub fn set_discard_rectangle_mode_ext(&self, discard_rectangle_mode: DiscardRectangleModeEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DISCARD_RECTANGLE_MODE_EXT,
+        );
         unsafe {
             raw::cmd_set_discard_rectangle_mode_ext(
                 self,
@@ -8354,6 +10459,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginDebugUtilsLabelEXT.html>"]
     #[doc(alias = "vkCmdBeginDebugUtilsLabelEXT")]
     pub fn begin_debug_utils_label_ext(&self, p_label_info: &DebugUtilsLabelEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BEGIN_DEBUG_UTILS_LABEL_EXT,
+        );
         unsafe {
             raw::cmd_begin_debug_utils_label_ext(
                 self,
@@ -8365,11 +10475,21 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndDebugUtilsLabelEXT.html>"]
     #[doc(alias = "vkCmdEndDebugUtilsLabelEXT")]
     pub fn end_debug_utils_label_ext(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_END_DEBUG_UTILS_LABEL_EXT,
+        );
         unsafe { raw::cmd_end_debug_utils_label_ext(self, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdInsertDebugUtilsLabelEXT.html>"]
     #[doc(alias = "vkCmdInsertDebugUtilsLabelEXT")]
     pub fn insert_debug_utils_label_ext(&self, p_label_info: &DebugUtilsLabelEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_INSERT_DEBUG_UTILS_LABEL_EXT,
+        );
         unsafe {
             raw::cmd_insert_debug_utils_label_ext(
                 self,
@@ -8378,9 +10498,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdInitializeGraphScratchMemoryAMDX.html>"]
     #[doc(alias = "vkCmdInitializeGraphScratchMemoryAMDX")]
     pub fn initialize_graph_scratch_memory_amdx(&self, scratch: DeviceAddress) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_INITIALIZE_GRAPH_SCRATCH_MEMORY_AMDX,
+        );
         unsafe {
             raw::cmd_initialize_graph_scratch_memory_amdx(
                 self,
@@ -8389,6 +10515,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphAMDX.html>"]
     #[doc(alias = "vkCmdDispatchGraphAMDX")]
     pub fn dispatch_graph_amdx(
@@ -8396,6 +10523,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         scratch: DeviceAddress,
         p_count_info: &DispatchGraphCountInfoAMDX,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DISPATCH_GRAPH_AMDX,
+        );
         unsafe {
             raw::cmd_dispatch_graph_amdx(
                 self,
@@ -8405,6 +10537,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphIndirectAMDX.html>"]
     #[doc(alias = "vkCmdDispatchGraphIndirectAMDX")]
     pub fn dispatch_graph_indirect_amdx(
@@ -8412,6 +10545,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         scratch: DeviceAddress,
         p_count_info: &DispatchGraphCountInfoAMDX,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DISPATCH_GRAPH_INDIRECT_AMDX,
+        );
         unsafe {
             raw::cmd_dispatch_graph_indirect_amdx(
                 self,
@@ -8421,6 +10559,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphIndirectCountAMDX.html>"]
     #[doc(alias = "vkCmdDispatchGraphIndirectCountAMDX")]
     pub fn dispatch_graph_indirect_count_amdx(
@@ -8428,6 +10567,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         scratch: DeviceAddress,
         count_info: DeviceAddress,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DISPATCH_GRAPH_INDIRECT_COUNT_AMDX,
+        );
         unsafe {
             raw::cmd_dispatch_graph_indirect_count_amdx(
                 self,
@@ -8440,6 +10584,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleLocationsEXT.html>"]
     #[doc(alias = "vkCmdSetSampleLocationsEXT")]
     pub fn set_sample_locations_ext(&self, p_sample_locations_info: &SampleLocationsInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_SAMPLE_LOCATIONS_EXT,
+        );
         unsafe {
             raw::cmd_set_sample_locations_ext(
                 self,
@@ -8455,6 +10604,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_infos: impl AsSlice<'a, AccelerationStructureBuildGeometryInfoKHR<'a>>,
         pp_build_range_infos: &&AccelerationStructureBuildRangeInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BUILD_ACCELERATION_STRUCTURES_KHR,
+        );
         unsafe {
             raw::cmd_build_acceleration_structures_khr(
                 self,
@@ -8473,6 +10627,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_indirect_strides: impl AsSlice<'a, u32>,
         pp_max_primitive_counts: &&u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BUILD_ACCELERATION_STRUCTURES_INDIRECT_KHR,
+        );
         unsafe {
             raw::cmd_build_acceleration_structures_indirect_khr(
                 self,
@@ -8487,6 +10646,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureKHR.html>"]
     #[doc(alias = "vkCmdCopyAccelerationStructureKHR")]
     pub fn copy_acceleration_structure_khr(&self, p_info: &CopyAccelerationStructureInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_ACCELERATION_STRUCTURE_KHR,
+        );
         unsafe {
             raw::cmd_copy_acceleration_structure_khr(
                 self,
@@ -8501,6 +10665,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_info: &CopyAccelerationStructureToMemoryInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR,
+        );
         unsafe {
             raw::cmd_copy_acceleration_structure_to_memory_khr(
                 self,
@@ -8515,6 +10684,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_info: &CopyMemoryToAccelerationStructureInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR,
+        );
         unsafe {
             raw::cmd_copy_memory_to_acceleration_structure_khr(
                 self,
@@ -8535,6 +10709,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         query_pool: &raw::QueryPool,
         first_query: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR,
+        );
         unsafe {
             raw::cmd_write_acceleration_structures_properties_khr(
                 self,
@@ -8558,6 +10737,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         height: u32,
         depth: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_TRACE_RAYS_KHR);
         unsafe {
             raw::cmd_trace_rays_khr(
                 self,
@@ -8582,6 +10763,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_callable_shader_binding_table: &StridedDeviceAddressRegionKHR,
         indirect_device_address: DeviceAddress,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_TRACE_RAYS_INDIRECT_KHR,
+        );
         unsafe {
             raw::cmd_trace_rays_indirect_khr(
                 self,
@@ -8597,6 +10783,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRayTracingPipelineStackSizeKHR.html>"]
     #[doc(alias = "vkCmdSetRayTracingPipelineStackSizeKHR")]
     pub fn set_ray_tracing_pipeline_stack_size_khr(&self, pipeline_stack_size: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RAY_TRACING_PIPELINE_STACK_SIZE_KHR,
+        );
         unsafe {
             raw::cmd_set_ray_tracing_pipeline_stack_size_khr(
                 self,
@@ -8605,6 +10796,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadingRateImageNV.html>"]
     #[doc(alias = "vkCmdBindShadingRateImageNV")]
     pub fn bind_shading_rate_image_nv(
@@ -8612,6 +10804,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         image_view: Option<&raw::ImageView>,
         image_layout: ImageLayout,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_SHADING_RATE_IMAGE_NV,
+        );
         unsafe {
             raw::cmd_bind_shading_rate_image_nv(
                 self,
@@ -8621,6 +10818,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportShadingRatePaletteNV.html>"]
     #[doc(alias = "vkCmdSetViewportShadingRatePaletteNV")]
     pub fn set_viewport_shading_rate_palette_nv<'a>(
@@ -8628,6 +10826,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_viewport: u32,
         p_shading_rate_palettes: impl AsSlice<'a, ShadingRatePaletteNV<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VIEWPORT_SHADING_RATE_PALETTE_NV,
+        );
         unsafe {
             raw::cmd_set_viewport_shading_rate_palette_nv(
                 self,
@@ -8637,6 +10840,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoarseSampleOrderNV.html>"]
     #[doc(alias = "vkCmdSetCoarseSampleOrderNV")]
     pub fn set_coarse_sample_order_nv<'a>(
@@ -8644,6 +10848,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         sample_order_type: CoarseSampleOrderTypeNV,
         p_custom_sample_orders: impl AsSlice<'a, CoarseSampleOrderCustomNV<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COARSE_SAMPLE_ORDER_NV,
+        );
         unsafe {
             raw::cmd_set_coarse_sample_order_nv(
                 self,
@@ -8653,6 +10862,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructureNV.html>"]
     #[doc(alias = "vkCmdBuildAccelerationStructureNV")]
     pub fn build_acceleration_structure_nv(
@@ -8666,6 +10876,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         scratch: &raw::Buffer,
         scratch_offset: DeviceSize,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BUILD_ACCELERATION_STRUCTURE_NV,
+        );
         unsafe {
             raw::cmd_build_acceleration_structure_nv(
                 self,
@@ -8681,6 +10896,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureNV.html>"]
     #[doc(alias = "vkCmdCopyAccelerationStructureNV")]
     pub fn copy_acceleration_structure_nv(
@@ -8689,6 +10905,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         src: &raw::AccelerationStructureNV,
         mode: CopyAccelerationStructureModeKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_ACCELERATION_STRUCTURE_NV,
+        );
         unsafe {
             raw::cmd_copy_acceleration_structure_nv(
                 self,
@@ -8699,6 +10920,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysNV.html>"]
     #[doc(alias = "vkCmdTraceRaysNV")]
     pub fn trace_rays_nv(
@@ -8718,6 +10940,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         height: u32,
         depth: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_TRACE_RAYS_NV);
         unsafe {
             raw::cmd_trace_rays_nv(
                 self,
@@ -8739,6 +10963,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesNV.html>"]
     #[doc(alias = "vkCmdWriteAccelerationStructuresPropertiesNV")]
     pub fn write_acceleration_structures_properties_nv<
@@ -8751,6 +10976,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         query_pool: &raw::QueryPool,
         first_query: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_NV,
+        );
         unsafe {
             raw::cmd_write_acceleration_structures_properties_nv(
                 self,
@@ -8762,6 +10992,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarkerAMD.html>"]
     #[doc(alias = "vkCmdWriteBufferMarkerAMD")]
     pub fn write_buffer_marker_amd(
@@ -8771,6 +11002,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_offset: DeviceSize,
         marker: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_WRITE_BUFFER_MARKER_AMD,
+        );
         unsafe {
             raw::cmd_write_buffer_marker_amd(
                 self,
@@ -8782,9 +11018,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksNV.html>"]
     #[doc(alias = "vkCmdDrawMeshTasksNV")]
     pub fn draw_mesh_tasks_nv(&self, task_count: u32, first_task: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MESH_TASKS_NV,
+        );
         unsafe {
             raw::cmd_draw_mesh_tasks_nv(
                 self,
@@ -8794,6 +11036,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectNV.html>"]
     #[doc(alias = "vkCmdDrawMeshTasksIndirectNV")]
     pub fn draw_mesh_tasks_indirect_nv(
@@ -8803,6 +11046,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MESH_TASKS_INDIRECT_NV,
+        );
         unsafe {
             raw::cmd_draw_mesh_tasks_indirect_nv(
                 self,
@@ -8814,6 +11062,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectCountNV.html>"]
     #[doc(alias = "vkCmdDrawMeshTasksIndirectCountNV")]
     pub fn draw_mesh_tasks_indirect_count_nv(
@@ -8825,6 +11074,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_NV,
+        );
         unsafe {
             raw::cmd_draw_mesh_tasks_indirect_count_nv(
                 self,
@@ -8838,6 +11092,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorEnableNV.html>"]
     #[doc(alias = "vkCmdSetExclusiveScissorEnableNV")]
     pub fn set_exclusive_scissor_enable_nv<'a>(
@@ -8845,6 +11100,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_exclusive_scissor: u32,
         p_exclusive_scissor_enables: impl AsSlice<'a, Bool32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_EXCLUSIVE_SCISSOR_ENABLE_NV,
+        );
         unsafe {
             raw::cmd_set_exclusive_scissor_enable_nv(
                 self,
@@ -8854,6 +11114,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorNV.html>"]
     #[doc(alias = "vkCmdSetExclusiveScissorNV")]
     pub fn set_exclusive_scissor_nv<'a>(
@@ -8861,6 +11122,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_exclusive_scissor: u32,
         p_exclusive_scissors: impl AsSlice<'a, Rect2D>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_EXCLUSIVE_SCISSOR_NV,
+        );
         unsafe {
             raw::cmd_set_exclusive_scissor_nv(
                 self,
@@ -8870,9 +11136,12 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCheckpointNV.html>"]
     #[doc(alias = "vkCmdSetCheckpointNV")]
     pub fn set_checkpoint_nv(&self, p_checkpoint_marker: VoidPtr) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_CHECKPOINT_NV);
         unsafe {
             raw::cmd_set_checkpoint_nv(
                 self,
@@ -8881,12 +11150,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceMarkerINTEL.html>"]
     #[doc(alias = "vkCmdSetPerformanceMarkerINTEL")]
     pub fn set_performance_marker_intel(
         &self,
         p_marker_info: &PerformanceMarkerInfoINTEL,
     ) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PERFORMANCE_MARKER_INTEL,
+        );
         unsafe {
             raw::cmd_set_performance_marker_intel(
                 self,
@@ -8895,12 +11170,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceStreamMarkerINTEL.html>"]
     #[doc(alias = "vkCmdSetPerformanceStreamMarkerINTEL")]
     pub fn set_performance_stream_marker_intel(
         &self,
         p_marker_info: &PerformanceStreamMarkerInfoINTEL,
     ) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PERFORMANCE_STREAM_MARKER_INTEL,
+        );
         unsafe {
             raw::cmd_set_performance_stream_marker_intel(
                 self,
@@ -8909,12 +11190,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-intel")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceOverrideINTEL.html>"]
     #[doc(alias = "vkCmdSetPerformanceOverrideINTEL")]
     pub fn set_performance_override_intel(
         &self,
         p_override_info: &PerformanceOverrideInfoINTEL,
     ) -> Result<()> {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PERFORMANCE_OVERRIDE_INTEL,
+        );
         unsafe {
             raw::cmd_set_performance_override_intel(
                 self,
@@ -8930,6 +11217,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_fragment_size: &Extent2D,
         combiner_ops: [FragmentShadingRateCombinerOpKHR; 2u16 as _],
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_FRAGMENT_SHADING_RATE_KHR,
+        );
         unsafe {
             raw::cmd_set_fragment_shading_rate_khr(
                 self,
@@ -8945,6 +11237,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_location_info: &RenderingAttachmentLocationInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RENDERING_ATTACHMENT_LOCATIONS_KHR,
+        );
         unsafe {
             raw::cmd_set_rendering_attachment_locations_khr(
                 self,
@@ -8959,6 +11256,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_input_attachment_index_info: &RenderingInputAttachmentIndexInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RENDERING_INPUT_ATTACHMENT_INDICES_KHR,
+        );
         unsafe {
             raw::cmd_set_rendering_input_attachment_indices_khr(
                 self,
@@ -8967,12 +11269,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html>"]
     #[doc(alias = "vkCmdPreprocessGeneratedCommandsNV")]
     pub fn preprocess_generated_commands_nv(
         &self,
         p_generated_commands_info: &GeneratedCommandsInfoNV,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PREPROCESS_GENERATED_COMMANDS_NV,
+        );
         unsafe {
             raw::cmd_preprocess_generated_commands_nv(
                 self,
@@ -8981,6 +11289,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html>"]
     #[doc(alias = "vkCmdExecuteGeneratedCommandsNV")]
     pub fn execute_generated_commands_nv(
@@ -8988,6 +11297,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         is_preprocessed: impl Into<Bool32>,
         p_generated_commands_info: &GeneratedCommandsInfoNV,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_EXECUTE_GENERATED_COMMANDS_NV,
+        );
         unsafe {
             raw::cmd_execute_generated_commands_nv(
                 self,
@@ -8997,6 +11311,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html>"]
     #[doc(alias = "vkCmdBindPipelineShaderGroupNV")]
     pub fn bind_pipeline_shader_group_nv(
@@ -9005,6 +11320,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         pipeline: &raw::Pipeline,
         group_index: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_PIPELINE_SHADER_GROUP_NV,
+        );
         unsafe {
             raw::cmd_bind_pipeline_shader_group_nv(
                 self,
@@ -9018,6 +11338,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias2EXT.html>"]
     #[doc(alias = "vkCmdSetDepthBias2EXT")]
     pub fn set_depth_bias2_ext(&self, p_depth_bias_info: &DepthBiasInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_BIAS2_EXT,
+        );
         unsafe {
             raw::cmd_set_depth_bias2_ext(
                 self,
@@ -9026,13 +11351,29 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEncodeVideoKHR.html>"]
+    #[doc(alias = "vkCmdEncodeVideoKHR")]
+    pub fn encode_video_khr(&self, p_encode_info: &VideoEncodeInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_ENCODE_VIDEO_KHR);
+        unsafe {
+            raw::cmd_encode_video_khr(self, p_encode_info, self.disp.get_command_dispatcher())
+        }
+    }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCudaLaunchKernelNV.html>"]
     #[doc(alias = "vkCmdCudaLaunchKernelNV")]
     pub fn cuda_launch_kernel_nv(&self, p_launch_info: &CudaLaunchInfoNV) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_CUDA_LAUNCH_KERNEL_NV,
+        );
         unsafe {
             raw::cmd_cuda_launch_kernel_nv(self, p_launch_info, self.disp.get_command_dispatcher())
         }
     }
+    #[cfg(feature = "vendor-amd")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarker2AMD.html>"]
     #[doc(alias = "vkCmdWriteBufferMarker2AMD")]
     pub fn write_buffer_marker2_amd(
@@ -9042,6 +11383,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_offset: DeviceSize,
         marker: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_WRITE_BUFFER_MARKER2_AMD,
+        );
         unsafe {
             raw::cmd_write_buffer_marker2_amd(
                 self,
@@ -9059,6 +11405,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_binding_infos: impl AsSlice<'a, DescriptorBufferBindingInfoEXT<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_DESCRIPTOR_BUFFERS_EXT,
+        );
         unsafe {
             raw::cmd_bind_descriptor_buffers_ext(
                 self,
@@ -9077,6 +11428,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_buffer_indices: impl AsSlice<'a, u32>,
         p_offsets: impl AsSlice<'a, DeviceSize>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DESCRIPTOR_BUFFER_OFFSETS_EXT,
+        );
         unsafe {
             raw::cmd_set_descriptor_buffer_offsets_ext(
                 self,
@@ -9097,6 +11453,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         layout: &raw::PipelineLayout,
         set: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_DESCRIPTOR_BUFFER_EMBEDDED_SAMPLERS_EXT,
+        );
         unsafe {
             raw::cmd_bind_descriptor_buffer_embedded_samplers_ext(
                 self,
@@ -9107,6 +11468,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateEnumNV.html>"]
     #[doc(alias = "vkCmdSetFragmentShadingRateEnumNV")]
     pub fn set_fragment_shading_rate_enum_nv(
@@ -9114,6 +11476,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         shading_rate: FragmentShadingRateNV,
         combiner_ops: [FragmentShadingRateCombinerOpKHR; 2u16 as _],
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_FRAGMENT_SHADING_RATE_ENUM_NV,
+        );
         unsafe {
             raw::cmd_set_fragment_shading_rate_enum_nv(
                 self,
@@ -9126,6 +11493,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksEXT.html>"]
     #[doc(alias = "vkCmdDrawMeshTasksEXT")]
     pub fn draw_mesh_tasks_ext(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MESH_TASKS_EXT,
+        );
         unsafe {
             raw::cmd_draw_mesh_tasks_ext(
                 self,
@@ -9145,6 +11517,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MESH_TASKS_INDIRECT_EXT,
+        );
         unsafe {
             raw::cmd_draw_mesh_tasks_indirect_ext(
                 self,
@@ -9167,6 +11544,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_EXT,
+        );
         unsafe {
             raw::cmd_draw_mesh_tasks_indirect_count_ext(
                 self,
@@ -9187,6 +11569,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_vertex_binding_descriptions: impl AsSlice<'a, VertexInputBindingDescription2EXT<'a>>,
         p_vertex_attribute_descriptions: impl AsSlice<'a, VertexInputAttributeDescription2EXT<'a>>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VERTEX_INPUT_EXT,
+        );
         unsafe {
             raw::cmd_set_vertex_input_ext(
                 self,
@@ -9196,11 +11583,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-huawei")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSubpassShadingHUAWEI.html>"]
     #[doc(alias = "vkCmdSubpassShadingHUAWEI")]
     pub fn subpass_shading_huawei(&self) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SUBPASS_SHADING_HUAWEI,
+        );
         unsafe { raw::cmd_subpass_shading_huawei(self, self.disp.get_command_dispatcher()) }
     }
+    #[cfg(feature = "vendor-huawei")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindInvocationMaskHUAWEI.html>"]
     #[doc(alias = "vkCmdBindInvocationMaskHUAWEI")]
     pub fn bind_invocation_mask_huawei(
@@ -9208,6 +11602,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         image_view: Option<&raw::ImageView>,
         image_layout: ImageLayout,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_INVOCATION_MASK_HUAWEI,
+        );
         unsafe {
             raw::cmd_bind_invocation_mask_huawei(
                 self,
@@ -9220,6 +11619,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPatchControlPointsEXT.html>"]
     #[doc(alias = "vkCmdSetPatchControlPointsEXT")]
     pub fn set_patch_control_points_ext(&self, patch_control_points: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PATCH_CONTROL_POINTS_EXT,
+        );
         unsafe {
             raw::cmd_set_patch_control_points_ext(
                 self,
@@ -9231,11 +11635,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLogicOpEXT.html>"]
     #[doc(alias = "vkCmdSetLogicOpEXT")]
     pub fn set_logic_op_ext(&self, logic_op: LogicOp) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_SET_LOGIC_OP_EXT);
         unsafe { raw::cmd_set_logic_op_ext(self, logic_op, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteEnableEXT.html>"]
     #[doc(alias = "vkCmdSetColorWriteEnableEXT")]
     pub fn set_color_write_enable_ext<'a>(&self, p_color_write_enables: impl AsSlice<'a, Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COLOR_WRITE_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_color_write_enable_ext(
                 self,
@@ -9247,6 +11658,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirect2KHR.html>"]
     #[doc(alias = "vkCmdTraceRaysIndirect2KHR")]
     pub fn trace_rays_indirect2_khr(&self, indirect_device_address: DeviceAddress) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_TRACE_RAYS_INDIRECT2_KHR,
+        );
         unsafe {
             raw::cmd_trace_rays_indirect2_khr(
                 self,
@@ -9264,6 +11680,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_instance: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_DRAW_MULTI_EXT);
         unsafe {
             raw::cmd_draw_multi_ext(
                 self,
@@ -9285,6 +11703,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         stride: u32,
         p_vertex_offset: Option<&i32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_MULTI_INDEXED_EXT,
+        );
         unsafe {
             raw::cmd_draw_multi_indexed_ext(
                 self,
@@ -9300,16 +11723,28 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildMicromapsEXT.html>"]
     #[doc(alias = "vkCmdBuildMicromapsEXT")]
     pub fn build_micromaps_ext<'a>(&self, p_infos: impl AsSlice<'a, MicromapBuildInfoEXT<'a>>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BUILD_MICROMAPS_EXT,
+        );
         unsafe { raw::cmd_build_micromaps_ext(self, p_infos, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapEXT.html>"]
     #[doc(alias = "vkCmdCopyMicromapEXT")]
     pub fn copy_micromap_ext(&self, p_info: &CopyMicromapInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_COPY_MICROMAP_EXT);
         unsafe { raw::cmd_copy_micromap_ext(self, p_info, self.disp.get_command_dispatcher()) }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapToMemoryEXT.html>"]
     #[doc(alias = "vkCmdCopyMicromapToMemoryEXT")]
     pub fn copy_micromap_to_memory_ext(&self, p_info: &CopyMicromapToMemoryInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_MICROMAP_TO_MEMORY_EXT,
+        );
         unsafe {
             raw::cmd_copy_micromap_to_memory_ext(self, p_info, self.disp.get_command_dispatcher())
         }
@@ -9317,6 +11752,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToMicromapEXT.html>"]
     #[doc(alias = "vkCmdCopyMemoryToMicromapEXT")]
     pub fn copy_memory_to_micromap_ext(&self, p_info: &CopyMemoryToMicromapInfoEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_MEMORY_TO_MICROMAP_EXT,
+        );
         unsafe {
             raw::cmd_copy_memory_to_micromap_ext(self, p_info, self.disp.get_command_dispatcher())
         }
@@ -9330,6 +11770,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         query_pool: &raw::QueryPool,
         first_query: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_WRITE_MICROMAPS_PROPERTIES_EXT,
+        );
         unsafe {
             raw::cmd_write_micromaps_properties_ext(
                 self,
@@ -9341,9 +11786,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-huawei")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterHUAWEI.html>"]
     #[doc(alias = "vkCmdDrawClusterHUAWEI")]
     pub fn draw_cluster_huawei(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_CLUSTER_HUAWEI,
+        );
         unsafe {
             raw::cmd_draw_cluster_huawei(
                 self,
@@ -9354,9 +11805,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-huawei")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterIndirectHUAWEI.html>"]
     #[doc(alias = "vkCmdDrawClusterIndirectHUAWEI")]
     pub fn draw_cluster_indirect_huawei(&self, buffer: &raw::Buffer, offset: DeviceSize) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DRAW_CLUSTER_INDIRECT_HUAWEI,
+        );
         unsafe {
             raw::cmd_draw_cluster_indirect_huawei(
                 self,
@@ -9366,6 +11823,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryIndirectNV.html>"]
     #[doc(alias = "vkCmdCopyMemoryIndirectNV")]
     pub fn copy_memory_indirect_nv(
@@ -9374,6 +11832,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         copy_count: u32,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_MEMORY_INDIRECT_NV,
+        );
         unsafe {
             raw::cmd_copy_memory_indirect_nv(
                 self,
@@ -9384,6 +11847,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToImageIndirectNV.html>"]
     #[doc(alias = "vkCmdCopyMemoryToImageIndirectNV")]
     pub fn copy_memory_to_image_indirect_nv<'a>(
@@ -9394,6 +11858,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         dst_image_layout: ImageLayout,
         p_image_subresources: impl AsSlice<'a, ImageSubresourceLayers>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_COPY_MEMORY_TO_IMAGE_INDIRECT_NV,
+        );
         unsafe {
             raw::cmd_copy_memory_to_image_indirect_nv(
                 self,
@@ -9406,12 +11875,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryNV.html>"]
     #[doc(alias = "vkCmdDecompressMemoryNV")]
     pub fn decompress_memory_nv<'a>(
         &self,
         p_decompress_memory_regions: impl AsSlice<'a, DecompressMemoryRegionNV>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DECOMPRESS_MEMORY_NV,
+        );
         unsafe {
             raw::cmd_decompress_memory_nv(
                 self,
@@ -9420,6 +11895,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryIndirectCountNV.html>"]
     #[doc(alias = "vkCmdDecompressMemoryIndirectCountNV")]
     pub fn decompress_memory_indirect_count_nv(
@@ -9428,6 +11904,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         indirect_commands_count_address: DeviceAddress,
         stride: u32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_DECOMPRESS_MEMORY_INDIRECT_COUNT_NV,
+        );
         unsafe {
             raw::cmd_decompress_memory_indirect_count_nv(
                 self,
@@ -9438,6 +11919,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdUpdatePipelineIndirectBufferNV.html>"]
     #[doc(alias = "vkCmdUpdatePipelineIndirectBufferNV")]
     pub fn update_pipeline_indirect_buffer_nv(
@@ -9445,6 +11927,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         pipeline_bind_point: PipelineBindPoint,
         pipeline: &raw::Pipeline,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_UPDATE_PIPELINE_INDIRECT_BUFFER_NV,
+        );
         unsafe {
             raw::cmd_update_pipeline_indirect_buffer_nv(
                 self,
@@ -9457,6 +11944,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClampEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDepthClampEnableEXT")]
     pub fn set_depth_clamp_enable_ext(&self, depth_clamp_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_CLAMP_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_depth_clamp_enable_ext(
                 self,
@@ -9468,6 +11960,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPolygonModeEXT.html>"]
     #[doc(alias = "vkCmdSetPolygonModeEXT")]
     pub fn set_polygon_mode_ext(&self, polygon_mode: PolygonMode) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_POLYGON_MODE_EXT,
+        );
         unsafe {
             raw::cmd_set_polygon_mode_ext(self, polygon_mode, self.disp.get_command_dispatcher())
         }
@@ -9475,6 +11972,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationSamplesEXT.html>"]
     #[doc(alias = "vkCmdSetRasterizationSamplesEXT")]
     pub fn set_rasterization_samples_ext(&self, rasterization_samples: SampleCountFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RASTERIZATION_SAMPLES_EXT,
+        );
         unsafe {
             raw::cmd_set_rasterization_samples_ext(
                 self,
@@ -9490,6 +11992,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         samples: SampleCountFlags,
         p_sample_mask: impl AsSlice<'a, SampleMask>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_SAMPLE_MASK_EXT,
+        );
         unsafe {
             raw::cmd_set_sample_mask_ext(
                 self,
@@ -9502,6 +12009,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAlphaToCoverageEnableEXT.html>"]
     #[doc(alias = "vkCmdSetAlphaToCoverageEnableEXT")]
     pub fn set_alpha_to_coverage_enable_ext(&self, alpha_to_coverage_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_ALPHA_TO_COVERAGE_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_alpha_to_coverage_enable_ext(
                 self,
@@ -9513,6 +12025,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAlphaToOneEnableEXT.html>"]
     #[doc(alias = "vkCmdSetAlphaToOneEnableEXT")]
     pub fn set_alpha_to_one_enable_ext(&self, alpha_to_one_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_ALPHA_TO_ONE_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_alpha_to_one_enable_ext(
                 self,
@@ -9524,6 +12041,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLogicOpEnableEXT.html>"]
     #[doc(alias = "vkCmdSetLogicOpEnableEXT")]
     pub fn set_logic_op_enable_ext(&self, logic_op_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_LOGIC_OP_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_logic_op_enable_ext(
                 self,
@@ -9539,6 +12061,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_attachment: u32,
         p_color_blend_enables: impl AsSlice<'a, Bool32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COLOR_BLEND_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_color_blend_enable_ext(
                 self,
@@ -9555,6 +12082,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_attachment: u32,
         p_color_blend_equations: impl AsSlice<'a, ColorBlendEquationEXT>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COLOR_BLEND_EQUATION_EXT,
+        );
         unsafe {
             raw::cmd_set_color_blend_equation_ext(
                 self,
@@ -9571,6 +12103,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_attachment: u32,
         p_color_write_masks: impl AsSlice<'a, ColorComponentFlags>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COLOR_WRITE_MASK_EXT,
+        );
         unsafe {
             raw::cmd_set_color_write_mask_ext(
                 self,
@@ -9583,6 +12120,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetTessellationDomainOriginEXT.html>"]
     #[doc(alias = "vkCmdSetTessellationDomainOriginEXT")]
     pub fn set_tessellation_domain_origin_ext(&self, domain_origin: TessellationDomainOrigin) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_TESSELLATION_DOMAIN_ORIGIN_EXT,
+        );
         unsafe {
             raw::cmd_set_tessellation_domain_origin_ext(
                 self,
@@ -9594,6 +12136,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationStreamEXT.html>"]
     #[doc(alias = "vkCmdSetRasterizationStreamEXT")]
     pub fn set_rasterization_stream_ext(&self, rasterization_stream: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_RASTERIZATION_STREAM_EXT,
+        );
         unsafe {
             raw::cmd_set_rasterization_stream_ext(
                 self,
@@ -9608,6 +12155,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         conservative_rasterization_mode: ConservativeRasterizationModeEXT,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_CONSERVATIVE_RASTERIZATION_MODE_EXT,
+        );
         unsafe {
             raw::cmd_set_conservative_rasterization_mode_ext(
                 self,
@@ -9622,6 +12174,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         extra_primitive_overestimation_size: f32,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_EXTRA_PRIMITIVE_OVERESTIMATION_SIZE_EXT,
+        );
         unsafe {
             raw::cmd_set_extra_primitive_overestimation_size_ext(
                 self,
@@ -9633,6 +12190,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClipEnableEXT.html>"]
     #[doc(alias = "vkCmdSetDepthClipEnableEXT")]
     pub fn set_depth_clip_enable_ext(&self, depth_clip_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_CLIP_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_depth_clip_enable_ext(
                 self,
@@ -9644,6 +12206,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleLocationsEnableEXT.html>"]
     #[doc(alias = "vkCmdSetSampleLocationsEnableEXT")]
     pub fn set_sample_locations_enable_ext(&self, sample_locations_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_SAMPLE_LOCATIONS_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_sample_locations_enable_ext(
                 self,
@@ -9659,6 +12226,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_attachment: u32,
         p_color_blend_advanced: impl AsSlice<'a, ColorBlendAdvancedEXT>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COLOR_BLEND_ADVANCED_EXT,
+        );
         unsafe {
             raw::cmd_set_color_blend_advanced_ext(
                 self,
@@ -9671,6 +12243,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetProvokingVertexModeEXT.html>"]
     #[doc(alias = "vkCmdSetProvokingVertexModeEXT")]
     pub fn set_provoking_vertex_mode_ext(&self, provoking_vertex_mode: ProvokingVertexModeEXT) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_PROVOKING_VERTEX_MODE_EXT,
+        );
         unsafe {
             raw::cmd_set_provoking_vertex_mode_ext(
                 self,
@@ -9685,6 +12262,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         line_rasterization_mode: LineRasterizationModeEXT,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_LINE_RASTERIZATION_MODE_EXT,
+        );
         unsafe {
             raw::cmd_set_line_rasterization_mode_ext(
                 self,
@@ -9696,6 +12278,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEnableEXT.html>"]
     #[doc(alias = "vkCmdSetLineStippleEnableEXT")]
     pub fn set_line_stipple_enable_ext(&self, stippled_line_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_LINE_STIPPLE_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_line_stipple_enable_ext(
                 self,
@@ -9707,6 +12294,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClipNegativeOneToOneEXT.html>"]
     #[doc(alias = "vkCmdSetDepthClipNegativeOneToOneEXT")]
     pub fn set_depth_clip_negative_one_to_one_ext(&self, negative_one_to_one: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_CLIP_NEGATIVE_ONE_TO_ONE_EXT,
+        );
         unsafe {
             raw::cmd_set_depth_clip_negative_one_to_one_ext(
                 self,
@@ -9715,9 +12307,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingEnableNV.html>"]
     #[doc(alias = "vkCmdSetViewportWScalingEnableNV")]
     pub fn set_viewport_wscaling_enable_nv(&self, viewport_wscaling_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VIEWPORT_WSCALING_ENABLE_NV,
+        );
         unsafe {
             raw::cmd_set_viewport_wscaling_enable_nv(
                 self,
@@ -9726,6 +12324,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportSwizzleNV.html>"]
     #[doc(alias = "vkCmdSetViewportSwizzleNV")]
     pub fn set_viewport_swizzle_nv<'a>(
@@ -9733,6 +12332,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         first_viewport: u32,
         p_viewport_swizzles: impl AsSlice<'a, ViewportSwizzleNV>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_VIEWPORT_SWIZZLE_NV,
+        );
         unsafe {
             raw::cmd_set_viewport_swizzle_nv(
                 self,
@@ -9742,9 +12346,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorEnableNV.html>"]
     #[doc(alias = "vkCmdSetCoverageToColorEnableNV")]
     pub fn set_coverage_to_color_enable_nv(&self, coverage_to_color_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COVERAGE_TO_COLOR_ENABLE_NV,
+        );
         unsafe {
             raw::cmd_set_coverage_to_color_enable_nv(
                 self,
@@ -9753,9 +12363,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorLocationNV.html>"]
     #[doc(alias = "vkCmdSetCoverageToColorLocationNV")]
     pub fn set_coverage_to_color_location_nv(&self, coverage_to_color_location: u32) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COVERAGE_TO_COLOR_LOCATION_NV,
+        );
         unsafe {
             raw::cmd_set_coverage_to_color_location_nv(
                 self,
@@ -9764,12 +12380,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationModeNV.html>"]
     #[doc(alias = "vkCmdSetCoverageModulationModeNV")]
     pub fn set_coverage_modulation_mode_nv(
         &self,
         coverage_modulation_mode: CoverageModulationModeNV,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COVERAGE_MODULATION_MODE_NV,
+        );
         unsafe {
             raw::cmd_set_coverage_modulation_mode_nv(
                 self,
@@ -9778,12 +12400,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableEnableNV.html>"]
     #[doc(alias = "vkCmdSetCoverageModulationTableEnableNV")]
     pub fn set_coverage_modulation_table_enable_nv(
         &self,
         coverage_modulation_table_enable: impl Into<Bool32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COVERAGE_MODULATION_TABLE_ENABLE_NV,
+        );
         unsafe {
             raw::cmd_set_coverage_modulation_table_enable_nv(
                 self,
@@ -9792,12 +12420,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableNV.html>"]
     #[doc(alias = "vkCmdSetCoverageModulationTableNV")]
     pub fn set_coverage_modulation_table_nv<'a>(
         &self,
         p_coverage_modulation_table: impl AsSlice<'a, f32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COVERAGE_MODULATION_TABLE_NV,
+        );
         unsafe {
             raw::cmd_set_coverage_modulation_table_nv(
                 self,
@@ -9806,9 +12440,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetShadingRateImageEnableNV.html>"]
     #[doc(alias = "vkCmdSetShadingRateImageEnableNV")]
     pub fn set_shading_rate_image_enable_nv(&self, shading_rate_image_enable: impl Into<Bool32>) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_SHADING_RATE_IMAGE_ENABLE_NV,
+        );
         unsafe {
             raw::cmd_set_shading_rate_image_enable_nv(
                 self,
@@ -9817,12 +12457,18 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRepresentativeFragmentTestEnableNV.html>"]
     #[doc(alias = "vkCmdSetRepresentativeFragmentTestEnableNV")]
     pub fn set_representative_fragment_test_enable_nv(
         &self,
         representative_fragment_test_enable: impl Into<Bool32>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_REPRESENTATIVE_FRAGMENT_TEST_ENABLE_NV,
+        );
         unsafe {
             raw::cmd_set_representative_fragment_test_enable_nv(
                 self,
@@ -9831,9 +12477,15 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageReductionModeNV.html>"]
     #[doc(alias = "vkCmdSetCoverageReductionModeNV")]
     pub fn set_coverage_reduction_mode_nv(&self, coverage_reduction_mode: CoverageReductionModeNV) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_COVERAGE_REDUCTION_MODE_NV,
+        );
         unsafe {
             raw::cmd_set_coverage_reduction_mode_nv(
                 self,
@@ -9842,6 +12494,7 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
             )
         }
     }
+    #[cfg(feature = "vendor-nv")]
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdOpticalFlowExecuteNV.html>"]
     #[doc(alias = "vkCmdOpticalFlowExecuteNV")]
     pub fn optical_flow_execute_nv(
@@ -9849,6 +12502,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         session: &raw::OpticalFlowSessionNV,
         p_execute_info: &OpticalFlowExecuteInfoNV,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_OPTICAL_FLOW_EXECUTE_NV,
+        );
         unsafe {
             raw::cmd_optical_flow_execute_nv(
                 self,
@@ -9867,6 +12525,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         size: DeviceSize,
         index_type: IndexType,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_INDEX_BUFFER2_KHR,
+        );
         unsafe {
             raw::cmd_bind_index_buffer2_khr(
                 self,
@@ -9885,6 +12548,8 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_stages: impl AsSlice<'a, ShaderStageFlags>,
         p_shaders: impl AsSlice<'a, V3>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(self, &crate::vk::recording::commands::CMD_BIND_SHADERS_EXT);
         unsafe {
             raw::cmd_bind_shaders_ext(
                 self,
@@ -9901,6 +12566,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         depth_clamp_mode: DepthClampModeEXT,
         p_depth_clamp_range: Option<&DepthClampRangeEXT>,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DEPTH_CLAMP_RANGE_EXT,
+        );
         unsafe {
             raw::cmd_set_depth_clamp_range_ext(
                 self,
@@ -9913,6 +12583,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAttachmentFeedbackLoopEnableEXT.html>"]
     #[doc(alias = "vkCmdSetAttachmentFeedbackLoopEnableEXT")]
     pub fn set_attachment_feedback_loop_enable_ext(&self, aspect_mask: ImageAspectFlags) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT,
+        );
         unsafe {
             raw::cmd_set_attachment_feedback_loop_enable_ext(
                 self,
@@ -9924,6 +12599,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleKHR.html>"]
     #[doc(alias = "vkCmdSetLineStippleKHR")]
     pub fn set_line_stipple_khr(&self, line_stipple_factor: u32, line_stipple_pattern: u16) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_LINE_STIPPLE_KHR,
+        );
         unsafe {
             raw::cmd_set_line_stipple_khr(
                 self,
@@ -9936,6 +12616,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEXT.html>"]
     #[doc(alias = "vkCmdSetLineStippleEXT")]
     pub fn set_line_stipple_ext(&self, line_stipple_factor: u32, line_stipple_pattern: u16) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_LINE_STIPPLE_KHR,
+        );
         unsafe {
             raw::cmd_set_line_stipple_ext(
                 self,
@@ -9951,6 +12636,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_bind_descriptor_sets_info: &BindDescriptorSetsInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_DESCRIPTOR_SETS2_KHR,
+        );
         unsafe {
             raw::cmd_bind_descriptor_sets2_khr(
                 self,
@@ -9962,6 +12652,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushConstants2KHR.html>"]
     #[doc(alias = "vkCmdPushConstants2KHR")]
     pub fn push_constants2_khr(&self, p_push_constants_info: &PushConstantsInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PUSH_CONSTANTS2_KHR,
+        );
         unsafe {
             raw::cmd_push_constants2_khr(
                 self,
@@ -9973,6 +12668,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSet2KHR.html>"]
     #[doc(alias = "vkCmdPushDescriptorSet2KHR")]
     pub fn push_descriptor_set2_khr(&self, p_push_descriptor_set_info: &PushDescriptorSetInfoKHR) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PUSH_DESCRIPTOR_SET2_KHR,
+        );
         unsafe {
             raw::cmd_push_descriptor_set2_khr(
                 self,
@@ -9987,6 +12687,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_push_descriptor_set_with_template_info: &PushDescriptorSetWithTemplateInfoKHR,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE2_KHR,
+        );
         unsafe {
             raw::cmd_push_descriptor_set_with_template2_khr(
                 self,
@@ -10001,6 +12706,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_set_descriptor_buffer_offsets_info: &SetDescriptorBufferOffsetsInfoEXT,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_SET_DESCRIPTOR_BUFFER_OFFSETS2_EXT,
+        );
         unsafe {
             raw::cmd_set_descriptor_buffer_offsets2_ext(
                 self,
@@ -10015,6 +12725,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         &self,
         p_bind_descriptor_buffer_embedded_samplers_info : & BindDescriptorBufferEmbeddedSamplersInfoEXT,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_BIND_DESCRIPTOR_BUFFER_EMBEDDED_SAMPLERS2_EXT,
+        );
         unsafe {
             raw::cmd_bind_descriptor_buffer_embedded_samplers2_ext(
                 self,
@@ -10030,6 +12745,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         p_generated_commands_info: &GeneratedCommandsInfoEXT,
         state_command_buffer: &raw::CommandBuffer,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_PREPROCESS_GENERATED_COMMANDS_EXT,
+        );
         unsafe {
             raw::cmd_preprocess_generated_commands_ext(
                 self,
@@ -10046,6 +12766,11 @@ impl<D: Dispatcher, A: Allocator> CommandBuffer<D, A> {
         is_preprocessed: impl Into<Bool32>,
         p_generated_commands_info: &GeneratedCommandsInfoEXT,
     ) {
+        #[cfg(all(feature = "recording-checks", debug_assertions))]
+        crate::vk::recording::check(
+            self,
+            &crate::vk::recording::commands::CMD_EXECUTE_GENERATED_COMMANDS_EXT,
+        );
         unsafe {
             raw::cmd_execute_generated_commands_ext(
                 self,
@@ -10235,18 +12960,64 @@ impl DebugReportCallbackEXT {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoSessionKHR.html>"]
+#[doc(alias = "VkVideoSessionKHR")]
+pub struct VideoSessionKHR {
+    inner: <raw::VideoSessionKHR as Handle>::InnerType,
+}
+unsafe impl Alias<raw::VideoSessionKHR> for VideoSessionKHR {}
+impl Deref for VideoSessionKHR {
+    type Target = raw::VideoSessionKHR;
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(&self.inner) }
+    }
+}
+impl VideoSessionKHR {
+    pub fn from_inner(handle: raw::VideoSessionKHR) -> Self {
+        Self {
+            inner: handle.as_raw(),
+        }
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoSessionParametersKHR.html>"]
+#[doc(alias = "VkVideoSessionParametersKHR")]
+pub struct VideoSessionParametersKHR {
+    inner: <raw::VideoSessionParametersKHR as Handle>::InnerType,
+}
+unsafe impl Alias<raw::VideoSessionParametersKHR> for VideoSessionParametersKHR {}
+impl Deref for VideoSessionParametersKHR {
+    type Target = raw::VideoSessionParametersKHR;
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(&self.inner) }
+    }
+}
+impl VideoSessionParametersKHR {
+    pub fn from_inner(handle: raw::VideoSessionParametersKHR) -> Self {
+        Self {
+            inner: handle.as_raw(),
+        }
+    }
+}
+#[cfg(feature = "vendor-nvx")]
+#[repr(C)]
+#[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCuModuleNVX.html>"]
 #[doc(alias = "VkCuModuleNVX")]
 pub struct CuModuleNVX {
     inner: <raw::CuModuleNVX as Handle>::InnerType,
 }
+#[cfg(feature = "vendor-nvx")]
 unsafe impl Alias<raw::CuModuleNVX> for CuModuleNVX {}
+#[cfg(feature = "vendor-nvx")]
 impl Deref for CuModuleNVX {
     type Target = raw::CuModuleNVX;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(feature = "vendor-nvx")]
 impl CuModuleNVX {
     pub fn from_inner(handle: raw::CuModuleNVX) -> Self {
         Self {
@@ -10254,6 +13025,7 @@ impl CuModuleNVX {
         }
     }
 }
+#[cfg(feature = "vendor-nvx")]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCuFunctionNVX.html>"]
@@ -10261,13 +13033,16 @@ impl CuModuleNVX {
 pub struct CuFunctionNVX {
     inner: <raw::CuFunctionNVX as Handle>::InnerType,
 }
+#[cfg(feature = "vendor-nvx")]
 unsafe impl Alias<raw::CuFunctionNVX> for CuFunctionNVX {}
+#[cfg(feature = "vendor-nvx")]
 impl Deref for CuFunctionNVX {
     type Target = raw::CuFunctionNVX;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(feature = "vendor-nvx")]
 impl CuFunctionNVX {
     pub fn from_inner(handle: raw::CuFunctionNVX) -> Self {
         Self {
@@ -10359,6 +13134,7 @@ impl AccelerationStructureNV {
         }
     }
 }
+#[cfg(feature = "vendor-intel")]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceConfigurationINTEL.html>"]
@@ -10366,13 +13142,16 @@ impl AccelerationStructureNV {
 pub struct PerformanceConfigurationINTEL {
     inner: <raw::PerformanceConfigurationINTEL as Handle>::InnerType,
 }
+#[cfg(feature = "vendor-intel")]
 unsafe impl Alias<raw::PerformanceConfigurationINTEL> for PerformanceConfigurationINTEL {}
+#[cfg(feature = "vendor-intel")]
 impl Deref for PerformanceConfigurationINTEL {
     type Target = raw::PerformanceConfigurationINTEL;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(feature = "vendor-intel")]
 impl PerformanceConfigurationINTEL {
     pub fn from_inner(handle: raw::PerformanceConfigurationINTEL) -> Self {
         Self {
@@ -10401,6 +13180,7 @@ impl DeferredOperationKHR {
         }
     }
 }
+#[cfg(feature = "vendor-nv")]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsLayoutNV.html>"]
@@ -10408,13 +13188,16 @@ impl DeferredOperationKHR {
 pub struct IndirectCommandsLayoutNV {
     inner: <raw::IndirectCommandsLayoutNV as Handle>::InnerType,
 }
+#[cfg(feature = "vendor-nv")]
 unsafe impl Alias<raw::IndirectCommandsLayoutNV> for IndirectCommandsLayoutNV {}
+#[cfg(feature = "vendor-nv")]
 impl Deref for IndirectCommandsLayoutNV {
     type Target = raw::IndirectCommandsLayoutNV;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(feature = "vendor-nv")]
 impl IndirectCommandsLayoutNV {
     pub fn from_inner(handle: raw::IndirectCommandsLayoutNV) -> Self {
         Self {
@@ -10422,6 +13205,7 @@ impl IndirectCommandsLayoutNV {
         }
     }
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCudaModuleNV.html>"]
@@ -10429,13 +13213,16 @@ impl IndirectCommandsLayoutNV {
 pub struct CudaModuleNV {
     inner: <raw::CudaModuleNV as Handle>::InnerType,
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 unsafe impl Alias<raw::CudaModuleNV> for CudaModuleNV {}
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 impl Deref for CudaModuleNV {
     type Target = raw::CudaModuleNV;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 impl CudaModuleNV {
     pub fn from_inner(handle: raw::CudaModuleNV) -> Self {
         Self {
@@ -10443,6 +13230,7 @@ impl CudaModuleNV {
         }
     }
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCudaFunctionNV.html>"]
@@ -10450,13 +13238,16 @@ impl CudaModuleNV {
 pub struct CudaFunctionNV {
     inner: <raw::CudaFunctionNV as Handle>::InnerType,
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 unsafe impl Alias<raw::CudaFunctionNV> for CudaFunctionNV {}
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 impl Deref for CudaFunctionNV {
     type Target = raw::CudaFunctionNV;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(all(feature = "vendor-nv", feature = "provisional"))]
 impl CudaFunctionNV {
     pub fn from_inner(handle: raw::CudaFunctionNV) -> Self {
         Self {
@@ -10464,6 +13255,7 @@ impl CudaFunctionNV {
         }
     }
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferCollectionFUCHSIA.html>"]
@@ -10471,13 +13263,16 @@ impl CudaFunctionNV {
 pub struct BufferCollectionFUCHSIA {
     inner: <raw::BufferCollectionFUCHSIA as Handle>::InnerType,
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 unsafe impl Alias<raw::BufferCollectionFUCHSIA> for BufferCollectionFUCHSIA {}
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 impl Deref for BufferCollectionFUCHSIA {
     type Target = raw::BufferCollectionFUCHSIA;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(any(feature = "platform-fuchsia", target_os = "fuchsia"))]
 impl BufferCollectionFUCHSIA {
     pub fn from_inner(handle: raw::BufferCollectionFUCHSIA) -> Self {
         Self {
@@ -10506,6 +13301,7 @@ impl MicromapEXT {
         }
     }
 }
+#[cfg(feature = "vendor-nv")]
 #[repr(C)]
 #[derive(Clone, Copy)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowSessionNV.html>"]
@@ -10513,13 +13309,16 @@ impl MicromapEXT {
 pub struct OpticalFlowSessionNV {
     inner: <raw::OpticalFlowSessionNV as Handle>::InnerType,
 }
+#[cfg(feature = "vendor-nv")]
 unsafe impl Alias<raw::OpticalFlowSessionNV> for OpticalFlowSessionNV {}
+#[cfg(feature = "vendor-nv")]
 impl Deref for OpticalFlowSessionNV {
     type Target = raw::OpticalFlowSessionNV;
     fn deref(&self) -> &Self::Target {
         unsafe { std::mem::transmute(&self.inner) }
     }
 }
+#[cfg(feature = "vendor-nv")]
 impl OpticalFlowSessionNV {
     pub fn from_inner(handle: raw::OpticalFlowSessionNV) -> Self {
         Self {
@@ -0,0 +1,39 @@
+//! Helpers used by the serde implementations of the generated structs
+
+/// (De)serialize a fixed-size null-terminated string such as [vk::PhysicalDeviceProperties::device_name](crate::vk::PhysicalDeviceProperties::device_name)
+/// as a regular string
+pub(crate) mod char_array {
+    use std::ffi::{c_char, CStr};
+
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        value: &[c_char; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let bytes: &[u8; N] = unsafe { &*(value as *const [c_char; N]).cast() };
+        let value = CStr::from_bytes_until_nul(bytes).map_err(serde::ser::Error::custom)?;
+        value.to_string_lossy().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[c_char; N], D::Error> {
+        let value = String::deserialize(deserializer)?;
+        // keep space for the null terminator
+        if value.len() >= N {
+            return Err(D::Error::custom(format!(
+                "string of length {} does not fit in an array of size {N}",
+                value.len()
+            )));
+        }
+        if value.contains('\0') {
+            return Err(D::Error::custom("string contains a null character"));
+        }
+        let mut result = [0; N];
+        for (dst, src) in result.iter_mut().zip(value.bytes()) {
+            *dst = src as c_char;
+        }
+        Ok(result)
+    }
+}
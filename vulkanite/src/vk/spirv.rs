@@ -0,0 +1,3126 @@
+use super::*;
+use crate::ExtendableStructure;
+use std::{mem::offset_of, ptr};
+#[doc = r" Image format of an OpTypeImage instruction, along with the matching [Format]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpirvImageFormat {
+    R8,
+    R8Snorm,
+    R8ui,
+    R8i,
+    Rg8,
+    Rg8Snorm,
+    Rg8ui,
+    Rg8i,
+    Rgba8,
+    Rgba8Snorm,
+    Rgba8ui,
+    Rgba8i,
+    Rgb10A2,
+    Rgb10a2ui,
+    R16,
+    R16Snorm,
+    R16ui,
+    R16i,
+    R16f,
+    Rg16,
+    Rg16Snorm,
+    Rg16ui,
+    Rg16i,
+    Rg16f,
+    Rgba16,
+    Rgba16Snorm,
+    Rgba16ui,
+    Rgba16i,
+    Rgba16f,
+    R32ui,
+    R32i,
+    R32f,
+    Rg32ui,
+    Rg32i,
+    Rg32f,
+    Rgba32ui,
+    Rgba32i,
+    Rgba32f,
+    R64ui,
+    R64i,
+    R11fG11fB10f,
+}
+impl SpirvImageFormat {
+    #[doc = r" Name of this image format in the SPIR-V specification"]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::R8 => "R8",
+            Self::R8Snorm => "R8Snorm",
+            Self::R8ui => "R8ui",
+            Self::R8i => "R8i",
+            Self::Rg8 => "Rg8",
+            Self::Rg8Snorm => "Rg8Snorm",
+            Self::Rg8ui => "Rg8ui",
+            Self::Rg8i => "Rg8i",
+            Self::Rgba8 => "Rgba8",
+            Self::Rgba8Snorm => "Rgba8Snorm",
+            Self::Rgba8ui => "Rgba8ui",
+            Self::Rgba8i => "Rgba8i",
+            Self::Rgb10A2 => "Rgb10A2",
+            Self::Rgb10a2ui => "Rgb10a2ui",
+            Self::R16 => "R16",
+            Self::R16Snorm => "R16Snorm",
+            Self::R16ui => "R16ui",
+            Self::R16i => "R16i",
+            Self::R16f => "R16f",
+            Self::Rg16 => "Rg16",
+            Self::Rg16Snorm => "Rg16Snorm",
+            Self::Rg16ui => "Rg16ui",
+            Self::Rg16i => "Rg16i",
+            Self::Rg16f => "Rg16f",
+            Self::Rgba16 => "Rgba16",
+            Self::Rgba16Snorm => "Rgba16Snorm",
+            Self::Rgba16ui => "Rgba16ui",
+            Self::Rgba16i => "Rgba16i",
+            Self::Rgba16f => "Rgba16f",
+            Self::R32ui => "R32ui",
+            Self::R32i => "R32i",
+            Self::R32f => "R32f",
+            Self::Rg32ui => "Rg32ui",
+            Self::Rg32i => "Rg32i",
+            Self::Rg32f => "Rg32f",
+            Self::Rgba32ui => "Rgba32ui",
+            Self::Rgba32i => "Rgba32i",
+            Self::Rgba32f => "Rgba32f",
+            Self::R64ui => "R64ui",
+            Self::R64i => "R64i",
+            Self::R11fG11fB10f => "R11fG11fB10f",
+        }
+    }
+    #[doc = r" Return the image format with the given SPIR-V name"]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "R8" => Some(Self::R8),
+            "R8Snorm" => Some(Self::R8Snorm),
+            "R8ui" => Some(Self::R8ui),
+            "R8i" => Some(Self::R8i),
+            "Rg8" => Some(Self::Rg8),
+            "Rg8Snorm" => Some(Self::Rg8Snorm),
+            "Rg8ui" => Some(Self::Rg8ui),
+            "Rg8i" => Some(Self::Rg8i),
+            "Rgba8" => Some(Self::Rgba8),
+            "Rgba8Snorm" => Some(Self::Rgba8Snorm),
+            "Rgba8ui" => Some(Self::Rgba8ui),
+            "Rgba8i" => Some(Self::Rgba8i),
+            "Rgb10A2" => Some(Self::Rgb10A2),
+            "Rgb10a2ui" => Some(Self::Rgb10a2ui),
+            "R16" => Some(Self::R16),
+            "R16Snorm" => Some(Self::R16Snorm),
+            "R16ui" => Some(Self::R16ui),
+            "R16i" => Some(Self::R16i),
+            "R16f" => Some(Self::R16f),
+            "Rg16" => Some(Self::Rg16),
+            "Rg16Snorm" => Some(Self::Rg16Snorm),
+            "Rg16ui" => Some(Self::Rg16ui),
+            "Rg16i" => Some(Self::Rg16i),
+            "Rg16f" => Some(Self::Rg16f),
+            "Rgba16" => Some(Self::Rgba16),
+            "Rgba16Snorm" => Some(Self::Rgba16Snorm),
+            "Rgba16ui" => Some(Self::Rgba16ui),
+            "Rgba16i" => Some(Self::Rgba16i),
+            "Rgba16f" => Some(Self::Rgba16f),
+            "R32ui" => Some(Self::R32ui),
+            "R32i" => Some(Self::R32i),
+            "R32f" => Some(Self::R32f),
+            "Rg32ui" => Some(Self::Rg32ui),
+            "Rg32i" => Some(Self::Rg32i),
+            "Rg32f" => Some(Self::Rg32f),
+            "Rgba32ui" => Some(Self::Rgba32ui),
+            "Rgba32i" => Some(Self::Rgba32i),
+            "Rgba32f" => Some(Self::Rgba32f),
+            "R64ui" => Some(Self::R64ui),
+            "R64i" => Some(Self::R64i),
+            "R11fG11fB10f" => Some(Self::R11fG11fB10f),
+            _ => None,
+        }
+    }
+    #[doc = r" Vulkan format matching this image format"]
+    pub const fn format(self) -> Format {
+        match self {
+            Self::R8 => Format::R8Unorm,
+            Self::R8Snorm => Format::R8Snorm,
+            Self::R8ui => Format::R8Uint,
+            Self::R8i => Format::R8Sint,
+            Self::Rg8 => Format::R8G8Unorm,
+            Self::Rg8Snorm => Format::R8G8Snorm,
+            Self::Rg8ui => Format::R8G8Uint,
+            Self::Rg8i => Format::R8G8Sint,
+            Self::Rgba8 => Format::R8G8B8A8Unorm,
+            Self::Rgba8Snorm => Format::R8G8B8A8Snorm,
+            Self::Rgba8ui => Format::R8G8B8A8Uint,
+            Self::Rgba8i => Format::R8G8B8A8Sint,
+            Self::Rgb10A2 => Format::A2B10G10R10UnormPack32,
+            Self::Rgb10a2ui => Format::A2B10G10R10UintPack32,
+            Self::R16 => Format::R16Unorm,
+            Self::R16Snorm => Format::R16Snorm,
+            Self::R16ui => Format::R16Uint,
+            Self::R16i => Format::R16Sint,
+            Self::R16f => Format::R16Sfloat,
+            Self::Rg16 => Format::R16G16Unorm,
+            Self::Rg16Snorm => Format::R16G16Snorm,
+            Self::Rg16ui => Format::R16G16Uint,
+            Self::Rg16i => Format::R16G16Sint,
+            Self::Rg16f => Format::R16G16Sfloat,
+            Self::Rgba16 => Format::R16G16B16A16Unorm,
+            Self::Rgba16Snorm => Format::R16G16B16A16Snorm,
+            Self::Rgba16ui => Format::R16G16B16A16Uint,
+            Self::Rgba16i => Format::R16G16B16A16Sint,
+            Self::Rgba16f => Format::R16G16B16A16Sfloat,
+            Self::R32ui => Format::R32Uint,
+            Self::R32i => Format::R32Sint,
+            Self::R32f => Format::R32Sfloat,
+            Self::Rg32ui => Format::R32G32Uint,
+            Self::Rg32i => Format::R32G32Sint,
+            Self::Rg32f => Format::R32G32Sfloat,
+            Self::Rgba32ui => Format::R32G32B32A32Uint,
+            Self::Rgba32i => Format::R32G32B32A32Sint,
+            Self::Rgba32f => Format::R32G32B32A32Sfloat,
+            Self::R64ui => Format::R64Uint,
+            Self::R64i => Format::R64Sint,
+            Self::R11fG11fB10f => Format::B10G11R11UfloatPack32,
+        }
+    }
+}
+impl Format {
+    #[doc = r" SPIR-V image format matching this format, if any"]
+    pub const fn spirv_image_format(self) -> Option<SpirvImageFormat> {
+        match self {
+            Format::R8Unorm => Some(SpirvImageFormat::R8),
+            Format::R8Snorm => Some(SpirvImageFormat::R8Snorm),
+            Format::R8Uint => Some(SpirvImageFormat::R8ui),
+            Format::R8Sint => Some(SpirvImageFormat::R8i),
+            Format::R8G8Unorm => Some(SpirvImageFormat::Rg8),
+            Format::R8G8Snorm => Some(SpirvImageFormat::Rg8Snorm),
+            Format::R8G8Uint => Some(SpirvImageFormat::Rg8ui),
+            Format::R8G8Sint => Some(SpirvImageFormat::Rg8i),
+            Format::R8G8B8A8Unorm => Some(SpirvImageFormat::Rgba8),
+            Format::R8G8B8A8Snorm => Some(SpirvImageFormat::Rgba8Snorm),
+            Format::R8G8B8A8Uint => Some(SpirvImageFormat::Rgba8ui),
+            Format::R8G8B8A8Sint => Some(SpirvImageFormat::Rgba8i),
+            Format::A2B10G10R10UnormPack32 => Some(SpirvImageFormat::Rgb10A2),
+            Format::A2B10G10R10UintPack32 => Some(SpirvImageFormat::Rgb10a2ui),
+            Format::R16Unorm => Some(SpirvImageFormat::R16),
+            Format::R16Snorm => Some(SpirvImageFormat::R16Snorm),
+            Format::R16Uint => Some(SpirvImageFormat::R16ui),
+            Format::R16Sint => Some(SpirvImageFormat::R16i),
+            Format::R16Sfloat => Some(SpirvImageFormat::R16f),
+            Format::R16G16Unorm => Some(SpirvImageFormat::Rg16),
+            Format::R16G16Snorm => Some(SpirvImageFormat::Rg16Snorm),
+            Format::R16G16Uint => Some(SpirvImageFormat::Rg16ui),
+            Format::R16G16Sint => Some(SpirvImageFormat::Rg16i),
+            Format::R16G16Sfloat => Some(SpirvImageFormat::Rg16f),
+            Format::R16G16B16A16Unorm => Some(SpirvImageFormat::Rgba16),
+            Format::R16G16B16A16Snorm => Some(SpirvImageFormat::Rgba16Snorm),
+            Format::R16G16B16A16Uint => Some(SpirvImageFormat::Rgba16ui),
+            Format::R16G16B16A16Sint => Some(SpirvImageFormat::Rgba16i),
+            Format::R16G16B16A16Sfloat => Some(SpirvImageFormat::Rgba16f),
+            Format::R32Uint => Some(SpirvImageFormat::R32ui),
+            Format::R32Sint => Some(SpirvImageFormat::R32i),
+            Format::R32Sfloat => Some(SpirvImageFormat::R32f),
+            Format::R32G32Uint => Some(SpirvImageFormat::Rg32ui),
+            Format::R32G32Sint => Some(SpirvImageFormat::Rg32i),
+            Format::R32G32Sfloat => Some(SpirvImageFormat::Rg32f),
+            Format::R32G32B32A32Uint => Some(SpirvImageFormat::Rgba32ui),
+            Format::R32G32B32A32Sint => Some(SpirvImageFormat::Rgba32i),
+            Format::R32G32B32A32Sfloat => Some(SpirvImageFormat::Rgba32f),
+            Format::R64Uint => Some(SpirvImageFormat::R64ui),
+            Format::R64Sint => Some(SpirvImageFormat::R64i),
+            Format::B10G11R11UfloatPack32 => Some(SpirvImageFormat::R11fG11fB10f),
+            _ => None,
+        }
+    }
+}
+#[doc = r" Requirement allowing a shader module to use a SPIR-V capability or extension."]
+#[doc = r" A capability or extension can be used if any of its requirements is satisfied"]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpirvRequirement {
+    #[doc = r" The device supports this Vulkan version"]
+    Version(ApiVersion),
+    #[doc = r" This device extension is enabled"]
+    Extension(DeviceExtension),
+    #[doc = r" This device feature is enabled"]
+    Feature(SpirvFeature),
+    #[doc = r" This device property contains the given value"]
+    Property(SpirvProperty),
+}
+#[doc = r" Boolean feature of a structure extending [PhysicalDeviceFeatures2]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpirvFeature {
+    #[doc = r" Structure type of the structure containing this feature,"]
+    #[doc = r" the features of [PhysicalDeviceFeatures] are part of [PhysicalDeviceFeatures2]"]
+    pub structure_type: StructureType,
+    #[doc = r" Offset in bytes of the [Bool32] feature in this structure"]
+    pub offset: usize,
+    #[doc = r" Name of the structure in the Vulkan specification"]
+    pub structure: &'static str,
+    #[doc = r" Name of the feature in the Vulkan specification"]
+    pub feature: &'static str,
+}
+impl SpirvFeature {
+    #[doc = r" Return whether this feature is enabled in the given structure"]
+    #[doc = r" or None if this feature is not part of this structure"]
+    pub fn is_enabled_in<S: ExtendableStructure>(&self, structure: &S) -> Option<bool> {
+        if S::STRUCTURE_TYPE != self.structure_type {
+            return None;
+        }
+        let value = unsafe {
+            ptr::from_ref(structure)
+                .cast::<u8>()
+                .add(self.offset)
+                .cast::<u32>()
+                .read()
+        };
+        Some(value != 0)
+    }
+}
+#[doc = r" 32-bit member of a structure extending [PhysicalDeviceProperties2]"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpirvProperty {
+    #[doc = r" Structure type of the structure containing this property"]
+    pub structure_type: StructureType,
+    #[doc = r" Offset in bytes of the property in this structure"]
+    pub offset: usize,
+    #[doc = r" Name of the structure in the Vulkan specification"]
+    pub structure: &'static str,
+    #[doc = r" Name of the member in the Vulkan specification"]
+    pub member: &'static str,
+    #[doc = r" Bits which must be set in the property, [TRUE] is represented as 1"]
+    pub value: u32,
+}
+impl SpirvProperty {
+    #[doc = r" Return whether this property contains the required value in the given structure"]
+    #[doc = r" or None if this property is not part of this structure"]
+    pub fn is_satisfied_by<S: ExtendableStructure>(&self, structure: &S) -> Option<bool> {
+        if S::STRUCTURE_TYPE != self.structure_type {
+            return None;
+        }
+        let value = unsafe {
+            ptr::from_ref(structure)
+                .cast::<u8>()
+                .add(self.offset)
+                .cast::<u32>()
+                .read()
+        };
+        Some(value & self.value == self.value)
+    }
+}
+#[doc = r" SPIR-V extension a shader module can declare with OpExtension"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpirvExtension {
+    #[doc = "SPV_KHR_variable_pointers"]
+    KhrVariablePointers,
+    #[doc = "SPV_AMD_shader_explicit_vertex_parameter"]
+    AmdShaderExplicitVertexParameter,
+    #[doc = "SPV_AMD_gcn_shader"]
+    AmdGcnShader,
+    #[doc = "SPV_AMD_gpu_shader_half_float"]
+    AmdGpuShaderHalfFloat,
+    #[doc = "SPV_AMD_gpu_shader_int16"]
+    AmdGpuShaderInt16,
+    #[doc = "SPV_AMD_shader_ballot"]
+    AmdShaderBallot,
+    #[doc = "SPV_AMD_shader_fragment_mask"]
+    AmdShaderFragmentMask,
+    #[doc = "SPV_AMD_shader_image_load_store_lod"]
+    AmdShaderImageLoadStoreLod,
+    #[doc = "SPV_AMD_shader_trinary_minmax"]
+    AmdShaderTrinaryMinmax,
+    #[doc = "SPV_AMD_texture_gather_bias_lod"]
+    AmdTextureGatherBiasLod,
+    #[doc = "SPV_KHR_shader_draw_parameters"]
+    KhrShaderDrawParameters,
+    #[doc = "SPV_KHR_8bit_storage"]
+    Khr8BitStorage,
+    #[doc = "SPV_KHR_16bit_storage"]
+    Khr16BitStorage,
+    #[doc = "SPV_KHR_device_group"]
+    KhrDeviceGroup,
+    #[doc = "SPV_KHR_multiview"]
+    KhrMultiview,
+    #[doc = "SPV_NV_sample_mask_override_coverage"]
+    NvSampleMaskOverrideCoverage,
+    #[doc = "SPV_NV_geometry_shader_passthrough"]
+    NvGeometryShaderPassthrough,
+    #[doc = "SPV_NV_viewport_array2"]
+    NvViewportArray2,
+    #[doc = "SPV_NV_stereo_view_rendering"]
+    NvStereoViewRendering,
+    #[doc = "SPV_NVX_multiview_per_view_attributes"]
+    NvxMultiviewPerViewAttributes,
+    #[doc = "SPV_EXT_shader_stencil_export"]
+    ExtShaderStencilExport,
+    #[doc = "SPV_EXT_shader_viewport_index_layer"]
+    ExtShaderViewportIndexLayer,
+    #[doc = "SPV_KHR_shader_ballot"]
+    KhrShaderBallot,
+    #[doc = "SPV_KHR_subgroup_vote"]
+    KhrSubgroupVote,
+    #[doc = "SPV_KHR_storage_buffer_storage_class"]
+    KhrStorageBufferStorageClass,
+    #[doc = "SPV_KHR_post_depth_coverage"]
+    KhrPostDepthCoverage,
+    #[doc = "SPV_EXT_descriptor_indexing"]
+    ExtDescriptorIndexing,
+    #[doc = "SPV_KHR_vulkan_memory_model"]
+    KhrVulkanMemoryModel,
+    #[doc = "SPV_NV_shader_subgroup_partitioned"]
+    NvShaderSubgroupPartitioned,
+    #[doc = "SPV_EXT_shader_atomic_float_add"]
+    ExtShaderAtomicFloatAdd,
+    #[doc = "SPV_KHR_shader_clock"]
+    KhrShaderClock,
+    #[doc = "SPV_KHR_float_controls"]
+    KhrFloatControls,
+    #[doc = "SPV_KHR_physical_storage_buffer"]
+    KhrPhysicalStorageBuffer,
+    #[doc = "SPV_EXT_physical_storage_buffer"]
+    ExtPhysicalStorageBuffer,
+    #[doc = "SPV_EXT_fragment_shader_interlock"]
+    ExtFragmentShaderInterlock,
+    #[doc = "SPV_EXT_demote_to_helper_invocation"]
+    ExtDemoteToHelperInvocation,
+    #[doc = "SPV_KHR_fragment_shading_rate"]
+    KhrFragmentShadingRate,
+    #[doc = "SPV_KHR_non_semantic_info"]
+    KhrNonSemanticInfo,
+    #[doc = "SPV_KHR_terminate_invocation"]
+    KhrTerminateInvocation,
+    #[doc = "SPV_KHR_ray_tracing"]
+    KhrRayTracing,
+    #[doc = "SPV_KHR_ray_query"]
+    KhrRayQuery,
+    #[doc = "SPV_KHR_integer_dot_product"]
+    KhrIntegerDotProduct,
+    #[doc = "SPV_KHR_workgroup_memory_explicit_layout"]
+    KhrWorkgroupMemoryExplicitLayout,
+    #[doc = "SPV_KHR_fragment_shader_barycentric"]
+    KhrFragmentShaderBarycentric,
+    #[doc = "SPV_KHR_subgroup_uniform_control_flow"]
+    KhrSubgroupUniformControlFlow,
+    #[doc = "SPV_EXT_shader_image_int64"]
+    ExtShaderImageInt64,
+    #[doc = "SPV_EXT_mesh_shader"]
+    ExtMeshShader,
+    #[doc = "SPV_GOOGLE_decorate_string"]
+    GoogleDecorateString,
+    #[doc = "SPV_GOOGLE_hlsl_functionality1"]
+    GoogleHlslFunctionality1,
+    #[doc = "SPV_GOOGLE_user_type"]
+    GoogleUserType,
+    #[doc = "SPV_INTEL_shader_integer_functions2"]
+    IntelShaderIntegerFunctions2,
+    #[doc = "SPV_NV_shader_sm_builtins"]
+    NvShaderSmBuiltins,
+    #[doc = "SPV_NV_shading_rate"]
+    NvShadingRate,
+    #[doc = "SPV_NV_shader_image_footprint"]
+    NvShaderImageFootprint,
+    #[doc = "SPV_NV_cooperative_matrix"]
+    NvCooperativeMatrix,
+    #[doc = "SPV_NV_mesh_shader"]
+    NvMeshShader,
+    #[doc = "SPV_NV_ray_tracing"]
+    NvRayTracing,
+    #[doc = "SPV_EXT_fragment_invocation_density"]
+    ExtFragmentInvocationDensity,
+    #[doc = "SPV_KHR_shader_subgroup_rotate"]
+    KhrShaderSubgroupRotate,
+}
+impl SpirvExtension {
+    #[doc = r" Name of this extension in the SPIR-V specification"]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::KhrVariablePointers => "SPV_KHR_variable_pointers",
+            Self::AmdShaderExplicitVertexParameter => "SPV_AMD_shader_explicit_vertex_parameter",
+            Self::AmdGcnShader => "SPV_AMD_gcn_shader",
+            Self::AmdGpuShaderHalfFloat => "SPV_AMD_gpu_shader_half_float",
+            Self::AmdGpuShaderInt16 => "SPV_AMD_gpu_shader_int16",
+            Self::AmdShaderBallot => "SPV_AMD_shader_ballot",
+            Self::AmdShaderFragmentMask => "SPV_AMD_shader_fragment_mask",
+            Self::AmdShaderImageLoadStoreLod => "SPV_AMD_shader_image_load_store_lod",
+            Self::AmdShaderTrinaryMinmax => "SPV_AMD_shader_trinary_minmax",
+            Self::AmdTextureGatherBiasLod => "SPV_AMD_texture_gather_bias_lod",
+            Self::KhrShaderDrawParameters => "SPV_KHR_shader_draw_parameters",
+            Self::Khr8BitStorage => "SPV_KHR_8bit_storage",
+            Self::Khr16BitStorage => "SPV_KHR_16bit_storage",
+            Self::KhrDeviceGroup => "SPV_KHR_device_group",
+            Self::KhrMultiview => "SPV_KHR_multiview",
+            Self::NvSampleMaskOverrideCoverage => "SPV_NV_sample_mask_override_coverage",
+            Self::NvGeometryShaderPassthrough => "SPV_NV_geometry_shader_passthrough",
+            Self::NvViewportArray2 => "SPV_NV_viewport_array2",
+            Self::NvStereoViewRendering => "SPV_NV_stereo_view_rendering",
+            Self::NvxMultiviewPerViewAttributes => "SPV_NVX_multiview_per_view_attributes",
+            Self::ExtShaderStencilExport => "SPV_EXT_shader_stencil_export",
+            Self::ExtShaderViewportIndexLayer => "SPV_EXT_shader_viewport_index_layer",
+            Self::KhrShaderBallot => "SPV_KHR_shader_ballot",
+            Self::KhrSubgroupVote => "SPV_KHR_subgroup_vote",
+            Self::KhrStorageBufferStorageClass => "SPV_KHR_storage_buffer_storage_class",
+            Self::KhrPostDepthCoverage => "SPV_KHR_post_depth_coverage",
+            Self::ExtDescriptorIndexing => "SPV_EXT_descriptor_indexing",
+            Self::KhrVulkanMemoryModel => "SPV_KHR_vulkan_memory_model",
+            Self::NvShaderSubgroupPartitioned => "SPV_NV_shader_subgroup_partitioned",
+            Self::ExtShaderAtomicFloatAdd => "SPV_EXT_shader_atomic_float_add",
+            Self::KhrShaderClock => "SPV_KHR_shader_clock",
+            Self::KhrFloatControls => "SPV_KHR_float_controls",
+            Self::KhrPhysicalStorageBuffer => "SPV_KHR_physical_storage_buffer",
+            Self::ExtPhysicalStorageBuffer => "SPV_EXT_physical_storage_buffer",
+            Self::ExtFragmentShaderInterlock => "SPV_EXT_fragment_shader_interlock",
+            Self::ExtDemoteToHelperInvocation => "SPV_EXT_demote_to_helper_invocation",
+            Self::KhrFragmentShadingRate => "SPV_KHR_fragment_shading_rate",
+            Self::KhrNonSemanticInfo => "SPV_KHR_non_semantic_info",
+            Self::KhrTerminateInvocation => "SPV_KHR_terminate_invocation",
+            Self::KhrRayTracing => "SPV_KHR_ray_tracing",
+            Self::KhrRayQuery => "SPV_KHR_ray_query",
+            Self::KhrIntegerDotProduct => "SPV_KHR_integer_dot_product",
+            Self::KhrWorkgroupMemoryExplicitLayout => "SPV_KHR_workgroup_memory_explicit_layout",
+            Self::KhrFragmentShaderBarycentric => "SPV_KHR_fragment_shader_barycentric",
+            Self::KhrSubgroupUniformControlFlow => "SPV_KHR_subgroup_uniform_control_flow",
+            Self::ExtShaderImageInt64 => "SPV_EXT_shader_image_int64",
+            Self::ExtMeshShader => "SPV_EXT_mesh_shader",
+            Self::GoogleDecorateString => "SPV_GOOGLE_decorate_string",
+            Self::GoogleHlslFunctionality1 => "SPV_GOOGLE_hlsl_functionality1",
+            Self::GoogleUserType => "SPV_GOOGLE_user_type",
+            Self::IntelShaderIntegerFunctions2 => "SPV_INTEL_shader_integer_functions2",
+            Self::NvShaderSmBuiltins => "SPV_NV_shader_sm_builtins",
+            Self::NvShadingRate => "SPV_NV_shading_rate",
+            Self::NvShaderImageFootprint => "SPV_NV_shader_image_footprint",
+            Self::NvCooperativeMatrix => "SPV_NV_cooperative_matrix",
+            Self::NvMeshShader => "SPV_NV_mesh_shader",
+            Self::NvRayTracing => "SPV_NV_ray_tracing",
+            Self::ExtFragmentInvocationDensity => "SPV_EXT_fragment_invocation_density",
+            Self::KhrShaderSubgroupRotate => "SPV_KHR_shader_subgroup_rotate",
+        }
+    }
+    #[doc = r" Return the extension with the given SPIR-V name"]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SPV_KHR_variable_pointers" => Some(Self::KhrVariablePointers),
+            "SPV_AMD_shader_explicit_vertex_parameter" => {
+                Some(Self::AmdShaderExplicitVertexParameter)
+            }
+            "SPV_AMD_gcn_shader" => Some(Self::AmdGcnShader),
+            "SPV_AMD_gpu_shader_half_float" => Some(Self::AmdGpuShaderHalfFloat),
+            "SPV_AMD_gpu_shader_int16" => Some(Self::AmdGpuShaderInt16),
+            "SPV_AMD_shader_ballot" => Some(Self::AmdShaderBallot),
+            "SPV_AMD_shader_fragment_mask" => Some(Self::AmdShaderFragmentMask),
+            "SPV_AMD_shader_image_load_store_lod" => Some(Self::AmdShaderImageLoadStoreLod),
+            "SPV_AMD_shader_trinary_minmax" => Some(Self::AmdShaderTrinaryMinmax),
+            "SPV_AMD_texture_gather_bias_lod" => Some(Self::AmdTextureGatherBiasLod),
+            "SPV_KHR_shader_draw_parameters" => Some(Self::KhrShaderDrawParameters),
+            "SPV_KHR_8bit_storage" => Some(Self::Khr8BitStorage),
+            "SPV_KHR_16bit_storage" => Some(Self::Khr16BitStorage),
+            "SPV_KHR_device_group" => Some(Self::KhrDeviceGroup),
+            "SPV_KHR_multiview" => Some(Self::KhrMultiview),
+            "SPV_NV_sample_mask_override_coverage" => Some(Self::NvSampleMaskOverrideCoverage),
+            "SPV_NV_geometry_shader_passthrough" => Some(Self::NvGeometryShaderPassthrough),
+            "SPV_NV_viewport_array2" => Some(Self::NvViewportArray2),
+            "SPV_NV_stereo_view_rendering" => Some(Self::NvStereoViewRendering),
+            "SPV_NVX_multiview_per_view_attributes" => Some(Self::NvxMultiviewPerViewAttributes),
+            "SPV_EXT_shader_stencil_export" => Some(Self::ExtShaderStencilExport),
+            "SPV_EXT_shader_viewport_index_layer" => Some(Self::ExtShaderViewportIndexLayer),
+            "SPV_KHR_shader_ballot" => Some(Self::KhrShaderBallot),
+            "SPV_KHR_subgroup_vote" => Some(Self::KhrSubgroupVote),
+            "SPV_KHR_storage_buffer_storage_class" => Some(Self::KhrStorageBufferStorageClass),
+            "SPV_KHR_post_depth_coverage" => Some(Self::KhrPostDepthCoverage),
+            "SPV_EXT_descriptor_indexing" => Some(Self::ExtDescriptorIndexing),
+            "SPV_KHR_vulkan_memory_model" => Some(Self::KhrVulkanMemoryModel),
+            "SPV_NV_shader_subgroup_partitioned" => Some(Self::NvShaderSubgroupPartitioned),
+            "SPV_EXT_shader_atomic_float_add" => Some(Self::ExtShaderAtomicFloatAdd),
+            "SPV_KHR_shader_clock" => Some(Self::KhrShaderClock),
+            "SPV_KHR_float_controls" => Some(Self::KhrFloatControls),
+            "SPV_KHR_physical_storage_buffer" => Some(Self::KhrPhysicalStorageBuffer),
+            "SPV_EXT_physical_storage_buffer" => Some(Self::ExtPhysicalStorageBuffer),
+            "SPV_EXT_fragment_shader_interlock" => Some(Self::ExtFragmentShaderInterlock),
+            "SPV_EXT_demote_to_helper_invocation" => Some(Self::ExtDemoteToHelperInvocation),
+            "SPV_KHR_fragment_shading_rate" => Some(Self::KhrFragmentShadingRate),
+            "SPV_KHR_non_semantic_info" => Some(Self::KhrNonSemanticInfo),
+            "SPV_KHR_terminate_invocation" => Some(Self::KhrTerminateInvocation),
+            "SPV_KHR_ray_tracing" => Some(Self::KhrRayTracing),
+            "SPV_KHR_ray_query" => Some(Self::KhrRayQuery),
+            "SPV_KHR_integer_dot_product" => Some(Self::KhrIntegerDotProduct),
+            "SPV_KHR_workgroup_memory_explicit_layout" => {
+                Some(Self::KhrWorkgroupMemoryExplicitLayout)
+            }
+            "SPV_KHR_fragment_shader_barycentric" => Some(Self::KhrFragmentShaderBarycentric),
+            "SPV_KHR_subgroup_uniform_control_flow" => Some(Self::KhrSubgroupUniformControlFlow),
+            "SPV_EXT_shader_image_int64" => Some(Self::ExtShaderImageInt64),
+            "SPV_EXT_mesh_shader" => Some(Self::ExtMeshShader),
+            "SPV_GOOGLE_decorate_string" => Some(Self::GoogleDecorateString),
+            "SPV_GOOGLE_hlsl_functionality1" => Some(Self::GoogleHlslFunctionality1),
+            "SPV_GOOGLE_user_type" => Some(Self::GoogleUserType),
+            "SPV_INTEL_shader_integer_functions2" => Some(Self::IntelShaderIntegerFunctions2),
+            "SPV_NV_shader_sm_builtins" => Some(Self::NvShaderSmBuiltins),
+            "SPV_NV_shading_rate" => Some(Self::NvShadingRate),
+            "SPV_NV_shader_image_footprint" => Some(Self::NvShaderImageFootprint),
+            "SPV_NV_cooperative_matrix" => Some(Self::NvCooperativeMatrix),
+            "SPV_NV_mesh_shader" => Some(Self::NvMeshShader),
+            "SPV_NV_ray_tracing" => Some(Self::NvRayTracing),
+            "SPV_EXT_fragment_invocation_density" => Some(Self::ExtFragmentInvocationDensity),
+            "SPV_KHR_shader_subgroup_rotate" => Some(Self::KhrShaderSubgroupRotate),
+            _ => None,
+        }
+    }
+    #[doc = r" Ways to allow a shader module to use this extension, only one of them needs to be satisfied"]
+    pub fn requirements(self) -> &'static [SpirvRequirement] {
+        match self {
+            Self::KhrVariablePointers => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_VARIABLE_POINTERS),
+                ];
+                REQUIREMENTS
+            }
+            Self::AmdShaderExplicitVertexParameter => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    AMD_SHADER_EXPLICIT_VERTEX_PARAMETER,
+                )];
+                REQUIREMENTS
+            }
+            Self::AmdGcnShader => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_GCN_SHADER)];
+                REQUIREMENTS
+            }
+            Self::AmdGpuShaderHalfFloat => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_GPU_SHADER_HALF_FLOAT)];
+                REQUIREMENTS
+            }
+            Self::AmdGpuShaderInt16 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_GPU_SHADER_INT16)];
+                REQUIREMENTS
+            }
+            Self::AmdShaderBallot => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_SHADER_BALLOT)];
+                REQUIREMENTS
+            }
+            Self::AmdShaderFragmentMask => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_SHADER_FRAGMENT_MASK)];
+                REQUIREMENTS
+            }
+            Self::AmdShaderImageLoadStoreLod => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_SHADER_IMAGE_LOAD_STORE_LOD)];
+                REQUIREMENTS
+            }
+            Self::AmdShaderTrinaryMinmax => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_SHADER_TRINARY_MINMAX)];
+                REQUIREMENTS
+            }
+            Self::AmdTextureGatherBiasLod => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_TEXTURE_GATHER_BIAS_LOD)];
+                REQUIREMENTS
+            }
+            Self::KhrShaderDrawParameters => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_SHADER_DRAW_PARAMETERS),
+                ];
+                REQUIREMENTS
+            }
+            Self::Khr8BitStorage => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(KHR_8BIT_STORAGE),
+                ];
+                REQUIREMENTS
+            }
+            Self::Khr16BitStorage => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_16BIT_STORAGE),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrDeviceGroup => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_DEVICE_GROUP),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrMultiview => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_MULTIVIEW),
+                ];
+                REQUIREMENTS
+            }
+            Self::NvSampleMaskOverrideCoverage => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    NV_SAMPLE_MASK_OVERRIDE_COVERAGE,
+                )];
+                REQUIREMENTS
+            }
+            Self::NvGeometryShaderPassthrough => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_GEOMETRY_SHADER_PASSTHROUGH)];
+                REQUIREMENTS
+            }
+            Self::NvViewportArray2 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_VIEWPORT_ARRAY2)];
+                REQUIREMENTS
+            }
+            Self::NvStereoViewRendering => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_VIEWPORT_ARRAY2)];
+                REQUIREMENTS
+            }
+            Self::NvxMultiviewPerViewAttributes => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    NVX_MULTIVIEW_PER_VIEW_ATTRIBUTES,
+                )];
+                REQUIREMENTS
+            }
+            Self::ExtShaderStencilExport => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_STENCIL_EXPORT)];
+                REQUIREMENTS
+            }
+            Self::ExtShaderViewportIndexLayer => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(EXT_SHADER_VIEWPORT_INDEX_LAYER),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrShaderBallot => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_SUBGROUP_BALLOT)];
+                REQUIREMENTS
+            }
+            Self::KhrSubgroupVote => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_SUBGROUP_VOTE)];
+                REQUIREMENTS
+            }
+            Self::KhrStorageBufferStorageClass => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_STORAGE_BUFFER_STORAGE_CLASS),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrPostDepthCoverage => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_POST_DEPTH_COVERAGE)];
+                REQUIREMENTS
+            }
+            Self::ExtDescriptorIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(EXT_DESCRIPTOR_INDEXING),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrVulkanMemoryModel => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(KHR_VULKAN_MEMORY_MODEL),
+                ];
+                REQUIREMENTS
+            }
+            Self::NvShaderSubgroupPartitioned => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_SHADER_SUBGROUP_PARTITIONED)];
+                REQUIREMENTS
+            }
+            Self::ExtShaderAtomicFloatAdd => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_ATOMIC_FLOAT)];
+                REQUIREMENTS
+            }
+            Self::KhrShaderClock => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_SHADER_CLOCK)];
+                REQUIREMENTS
+            }
+            Self::KhrFloatControls => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(KHR_SHADER_FLOAT_CONTROLS),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrPhysicalStorageBuffer => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(KHR_BUFFER_DEVICE_ADDRESS),
+                ];
+                REQUIREMENTS
+            }
+            Self::ExtPhysicalStorageBuffer => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_BUFFER_DEVICE_ADDRESS)];
+                REQUIREMENTS
+            }
+            Self::ExtFragmentShaderInterlock => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_FRAGMENT_SHADER_INTERLOCK)];
+                REQUIREMENTS
+            }
+            Self::ExtDemoteToHelperInvocation => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 3u32, 0)),
+                    SpirvRequirement::Extension(EXT_SHADER_DEMOTE_TO_HELPER_INVOCATION),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrFragmentShadingRate => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_FRAGMENT_SHADING_RATE)];
+                REQUIREMENTS
+            }
+            Self::KhrNonSemanticInfo => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 3u32, 0)),
+                    SpirvRequirement::Extension(KHR_SHADER_NON_SEMANTIC_INFO),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrTerminateInvocation => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 3u32, 0)),
+                    SpirvRequirement::Extension(KHR_SHADER_TERMINATE_INVOCATION),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrRayTracing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_RAY_TRACING_PIPELINE)];
+                REQUIREMENTS
+            }
+            Self::KhrRayQuery => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_RAY_QUERY)];
+                REQUIREMENTS
+            }
+            Self::KhrIntegerDotProduct => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 3u32, 0)),
+                    SpirvRequirement::Extension(KHR_SHADER_INTEGER_DOT_PRODUCT),
+                ];
+                REQUIREMENTS
+            }
+            Self::KhrWorkgroupMemoryExplicitLayout => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    KHR_WORKGROUP_MEMORY_EXPLICIT_LAYOUT,
+                )];
+                REQUIREMENTS
+            }
+            Self::KhrFragmentShaderBarycentric => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_FRAGMENT_SHADER_BARYCENTRIC)];
+                REQUIREMENTS
+            }
+            Self::KhrSubgroupUniformControlFlow => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    KHR_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW,
+                )];
+                REQUIREMENTS
+            }
+            Self::ExtShaderImageInt64 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_IMAGE_ATOMIC_INT64)];
+                REQUIREMENTS
+            }
+            Self::ExtMeshShader => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_MESH_SHADER)];
+                REQUIREMENTS
+            }
+            Self::GoogleDecorateString => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(GOOGLE_DECORATE_STRING)];
+                REQUIREMENTS
+            }
+            Self::GoogleHlslFunctionality1 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(GOOGLE_HLSL_FUNCTIONALITY1)];
+                REQUIREMENTS
+            }
+            Self::GoogleUserType => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(GOOGLE_USER_TYPE)];
+                REQUIREMENTS
+            }
+            Self::IntelShaderIntegerFunctions2 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(INTEL_SHADER_INTEGER_FUNCTIONS2)];
+                REQUIREMENTS
+            }
+            Self::NvShaderSmBuiltins => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_SHADER_SM_BUILTINS)];
+                REQUIREMENTS
+            }
+            Self::NvShadingRate => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_SHADING_RATE_IMAGE)];
+                REQUIREMENTS
+            }
+            Self::NvShaderImageFootprint => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_SHADER_IMAGE_FOOTPRINT)];
+                REQUIREMENTS
+            }
+            Self::NvCooperativeMatrix => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_COOPERATIVE_MATRIX)];
+                REQUIREMENTS
+            }
+            Self::NvMeshShader => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_MESH_SHADER)];
+                REQUIREMENTS
+            }
+            Self::NvRayTracing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_RAY_TRACING)];
+                REQUIREMENTS
+            }
+            Self::ExtFragmentInvocationDensity => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_FRAGMENT_DENSITY_MAP)];
+                REQUIREMENTS
+            }
+            Self::KhrShaderSubgroupRotate => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_SHADER_SUBGROUP_ROTATE)];
+                REQUIREMENTS
+            }
+        }
+    }
+}
+#[doc = r" SPIR-V capability a shader module can declare with OpCapability"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpirvCapability {
+    Matrix,
+    Shader,
+    InputAttachment,
+    Sampled1D,
+    Image1D,
+    SampledBuffer,
+    ImageBuffer,
+    ImageQuery,
+    DerivativeControl,
+    Geometry,
+    Tessellation,
+    Float64,
+    Int64,
+    Int64Atomics,
+    AtomicFloat32AddEXT,
+    AtomicFloat64AddEXT,
+    Int64ImageEXT,
+    Int16,
+    TessellationPointSize,
+    GeometryPointSize,
+    ImageGatherExtended,
+    StorageImageMultisample,
+    UniformBufferArrayDynamicIndexing,
+    SampledImageArrayDynamicIndexing,
+    StorageBufferArrayDynamicIndexing,
+    StorageImageArrayDynamicIndexing,
+    ClipDistance,
+    CullDistance,
+    ImageCubeArray,
+    SampleRateShading,
+    SparseResidency,
+    MinLod,
+    SampledCubeArray,
+    ImageMSArray,
+    StorageImageExtendedFormats,
+    InterpolationFunction,
+    StorageImageReadWithoutFormat,
+    StorageImageWriteWithoutFormat,
+    MultiViewport,
+    DrawParameters,
+    MultiView,
+    DeviceGroup,
+    VariablePointersStorageBuffer,
+    VariablePointers,
+    ShaderClockKHR,
+    StencilExportEXT,
+    SubgroupBallotKHR,
+    SubgroupVoteKHR,
+    ImageReadWriteLodAMD,
+    ImageGatherBiasLodAMD,
+    FragmentMaskAMD,
+    SampleMaskOverrideCoverageNV,
+    GeometryShaderPassthroughNV,
+    ShaderViewportIndex,
+    ShaderLayer,
+    ShaderViewportIndexLayerEXT,
+    ShaderViewportIndexLayerNV,
+    ShaderViewportMaskNV,
+    PerViewAttributesNV,
+    StorageBuffer16BitAccess,
+    UniformAndStorageBuffer16BitAccess,
+    StoragePushConstant16,
+    StorageInputOutput16,
+    GroupNonUniform,
+    GroupNonUniformVote,
+    GroupNonUniformArithmetic,
+    GroupNonUniformBallot,
+    GroupNonUniformShuffle,
+    GroupNonUniformShuffleRelative,
+    GroupNonUniformClustered,
+    GroupNonUniformQuad,
+    GroupNonUniformPartitionedNV,
+    SampleMaskPostDepthCoverage,
+    ShaderNonUniform,
+    RuntimeDescriptorArray,
+    InputAttachmentArrayDynamicIndexing,
+    UniformTexelBufferArrayDynamicIndexing,
+    StorageTexelBufferArrayDynamicIndexing,
+    UniformBufferArrayNonUniformIndexing,
+    SampledImageArrayNonUniformIndexing,
+    StorageBufferArrayNonUniformIndexing,
+    StorageImageArrayNonUniformIndexing,
+    InputAttachmentArrayNonUniformIndexing,
+    UniformTexelBufferArrayNonUniformIndexing,
+    StorageTexelBufferArrayNonUniformIndexing,
+    FragmentFullyCoveredEXT,
+    Float16,
+    Int8,
+    StorageBuffer8BitAccess,
+    UniformAndStorageBuffer8BitAccess,
+    StoragePushConstant8,
+    VulkanMemoryModel,
+    VulkanMemoryModelDeviceScope,
+    DenormPreserve,
+    DenormFlushToZero,
+    SignedZeroInfNanPreserve,
+    RoundingModeRTE,
+    RoundingModeRTZ,
+    ImageFootprintNV,
+    ShadingRateNV,
+    MeshShadingNV,
+    RayTracingNV,
+    RayTracingKHR,
+    RayQueryKHR,
+    RayTraversalPrimitiveCullingKHR,
+    TransformFeedback,
+    GeometryStreams,
+    FragmentDensityEXT,
+    PhysicalStorageBufferAddresses,
+    CooperativeMatrixNV,
+    IntegerFunctions2INTEL,
+    ShaderSMBuiltinsNV,
+    FragmentShaderSampleInterlockEXT,
+    FragmentShaderPixelInterlockEXT,
+    FragmentShaderShadingRateInterlockEXT,
+    DemoteToHelperInvocation,
+    FragmentShadingRateKHR,
+    WorkgroupMemoryExplicitLayoutKHR,
+    WorkgroupMemoryExplicitLayout8BitAccessKHR,
+    WorkgroupMemoryExplicitLayout16BitAccessKHR,
+    DotProductInputAll,
+    DotProductInput4x8Bit,
+    DotProductInput4x8BitPacked,
+    DotProduct,
+    FragmentBarycentricKHR,
+    MeshShadingEXT,
+    GroupNonUniformRotateKHR,
+}
+impl SpirvCapability {
+    #[doc = r" Name of this capability in the SPIR-V specification"]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Matrix => "Matrix",
+            Self::Shader => "Shader",
+            Self::InputAttachment => "InputAttachment",
+            Self::Sampled1D => "Sampled1D",
+            Self::Image1D => "Image1D",
+            Self::SampledBuffer => "SampledBuffer",
+            Self::ImageBuffer => "ImageBuffer",
+            Self::ImageQuery => "ImageQuery",
+            Self::DerivativeControl => "DerivativeControl",
+            Self::Geometry => "Geometry",
+            Self::Tessellation => "Tessellation",
+            Self::Float64 => "Float64",
+            Self::Int64 => "Int64",
+            Self::Int64Atomics => "Int64Atomics",
+            Self::AtomicFloat32AddEXT => "AtomicFloat32AddEXT",
+            Self::AtomicFloat64AddEXT => "AtomicFloat64AddEXT",
+            Self::Int64ImageEXT => "Int64ImageEXT",
+            Self::Int16 => "Int16",
+            Self::TessellationPointSize => "TessellationPointSize",
+            Self::GeometryPointSize => "GeometryPointSize",
+            Self::ImageGatherExtended => "ImageGatherExtended",
+            Self::StorageImageMultisample => "StorageImageMultisample",
+            Self::UniformBufferArrayDynamicIndexing => "UniformBufferArrayDynamicIndexing",
+            Self::SampledImageArrayDynamicIndexing => "SampledImageArrayDynamicIndexing",
+            Self::StorageBufferArrayDynamicIndexing => "StorageBufferArrayDynamicIndexing",
+            Self::StorageImageArrayDynamicIndexing => "StorageImageArrayDynamicIndexing",
+            Self::ClipDistance => "ClipDistance",
+            Self::CullDistance => "CullDistance",
+            Self::ImageCubeArray => "ImageCubeArray",
+            Self::SampleRateShading => "SampleRateShading",
+            Self::SparseResidency => "SparseResidency",
+            Self::MinLod => "MinLod",
+            Self::SampledCubeArray => "SampledCubeArray",
+            Self::ImageMSArray => "ImageMSArray",
+            Self::StorageImageExtendedFormats => "StorageImageExtendedFormats",
+            Self::InterpolationFunction => "InterpolationFunction",
+            Self::StorageImageReadWithoutFormat => "StorageImageReadWithoutFormat",
+            Self::StorageImageWriteWithoutFormat => "StorageImageWriteWithoutFormat",
+            Self::MultiViewport => "MultiViewport",
+            Self::DrawParameters => "DrawParameters",
+            Self::MultiView => "MultiView",
+            Self::DeviceGroup => "DeviceGroup",
+            Self::VariablePointersStorageBuffer => "VariablePointersStorageBuffer",
+            Self::VariablePointers => "VariablePointers",
+            Self::ShaderClockKHR => "ShaderClockKHR",
+            Self::StencilExportEXT => "StencilExportEXT",
+            Self::SubgroupBallotKHR => "SubgroupBallotKHR",
+            Self::SubgroupVoteKHR => "SubgroupVoteKHR",
+            Self::ImageReadWriteLodAMD => "ImageReadWriteLodAMD",
+            Self::ImageGatherBiasLodAMD => "ImageGatherBiasLodAMD",
+            Self::FragmentMaskAMD => "FragmentMaskAMD",
+            Self::SampleMaskOverrideCoverageNV => "SampleMaskOverrideCoverageNV",
+            Self::GeometryShaderPassthroughNV => "GeometryShaderPassthroughNV",
+            Self::ShaderViewportIndex => "ShaderViewportIndex",
+            Self::ShaderLayer => "ShaderLayer",
+            Self::ShaderViewportIndexLayerEXT => "ShaderViewportIndexLayerEXT",
+            Self::ShaderViewportIndexLayerNV => "ShaderViewportIndexLayerNV",
+            Self::ShaderViewportMaskNV => "ShaderViewportMaskNV",
+            Self::PerViewAttributesNV => "PerViewAttributesNV",
+            Self::StorageBuffer16BitAccess => "StorageBuffer16BitAccess",
+            Self::UniformAndStorageBuffer16BitAccess => "UniformAndStorageBuffer16BitAccess",
+            Self::StoragePushConstant16 => "StoragePushConstant16",
+            Self::StorageInputOutput16 => "StorageInputOutput16",
+            Self::GroupNonUniform => "GroupNonUniform",
+            Self::GroupNonUniformVote => "GroupNonUniformVote",
+            Self::GroupNonUniformArithmetic => "GroupNonUniformArithmetic",
+            Self::GroupNonUniformBallot => "GroupNonUniformBallot",
+            Self::GroupNonUniformShuffle => "GroupNonUniformShuffle",
+            Self::GroupNonUniformShuffleRelative => "GroupNonUniformShuffleRelative",
+            Self::GroupNonUniformClustered => "GroupNonUniformClustered",
+            Self::GroupNonUniformQuad => "GroupNonUniformQuad",
+            Self::GroupNonUniformPartitionedNV => "GroupNonUniformPartitionedNV",
+            Self::SampleMaskPostDepthCoverage => "SampleMaskPostDepthCoverage",
+            Self::ShaderNonUniform => "ShaderNonUniform",
+            Self::RuntimeDescriptorArray => "RuntimeDescriptorArray",
+            Self::InputAttachmentArrayDynamicIndexing => "InputAttachmentArrayDynamicIndexing",
+            Self::UniformTexelBufferArrayDynamicIndexing => {
+                "UniformTexelBufferArrayDynamicIndexing"
+            }
+            Self::StorageTexelBufferArrayDynamicIndexing => {
+                "StorageTexelBufferArrayDynamicIndexing"
+            }
+            Self::UniformBufferArrayNonUniformIndexing => "UniformBufferArrayNonUniformIndexing",
+            Self::SampledImageArrayNonUniformIndexing => "SampledImageArrayNonUniformIndexing",
+            Self::StorageBufferArrayNonUniformIndexing => "StorageBufferArrayNonUniformIndexing",
+            Self::StorageImageArrayNonUniformIndexing => "StorageImageArrayNonUniformIndexing",
+            Self::InputAttachmentArrayNonUniformIndexing => {
+                "InputAttachmentArrayNonUniformIndexing"
+            }
+            Self::UniformTexelBufferArrayNonUniformIndexing => {
+                "UniformTexelBufferArrayNonUniformIndexing"
+            }
+            Self::StorageTexelBufferArrayNonUniformIndexing => {
+                "StorageTexelBufferArrayNonUniformIndexing"
+            }
+            Self::FragmentFullyCoveredEXT => "FragmentFullyCoveredEXT",
+            Self::Float16 => "Float16",
+            Self::Int8 => "Int8",
+            Self::StorageBuffer8BitAccess => "StorageBuffer8BitAccess",
+            Self::UniformAndStorageBuffer8BitAccess => "UniformAndStorageBuffer8BitAccess",
+            Self::StoragePushConstant8 => "StoragePushConstant8",
+            Self::VulkanMemoryModel => "VulkanMemoryModel",
+            Self::VulkanMemoryModelDeviceScope => "VulkanMemoryModelDeviceScope",
+            Self::DenormPreserve => "DenormPreserve",
+            Self::DenormFlushToZero => "DenormFlushToZero",
+            Self::SignedZeroInfNanPreserve => "SignedZeroInfNanPreserve",
+            Self::RoundingModeRTE => "RoundingModeRTE",
+            Self::RoundingModeRTZ => "RoundingModeRTZ",
+            Self::ImageFootprintNV => "ImageFootprintNV",
+            Self::ShadingRateNV => "ShadingRateNV",
+            Self::MeshShadingNV => "MeshShadingNV",
+            Self::RayTracingNV => "RayTracingNV",
+            Self::RayTracingKHR => "RayTracingKHR",
+            Self::RayQueryKHR => "RayQueryKHR",
+            Self::RayTraversalPrimitiveCullingKHR => "RayTraversalPrimitiveCullingKHR",
+            Self::TransformFeedback => "TransformFeedback",
+            Self::GeometryStreams => "GeometryStreams",
+            Self::FragmentDensityEXT => "FragmentDensityEXT",
+            Self::PhysicalStorageBufferAddresses => "PhysicalStorageBufferAddresses",
+            Self::CooperativeMatrixNV => "CooperativeMatrixNV",
+            Self::IntegerFunctions2INTEL => "IntegerFunctions2INTEL",
+            Self::ShaderSMBuiltinsNV => "ShaderSMBuiltinsNV",
+            Self::FragmentShaderSampleInterlockEXT => "FragmentShaderSampleInterlockEXT",
+            Self::FragmentShaderPixelInterlockEXT => "FragmentShaderPixelInterlockEXT",
+            Self::FragmentShaderShadingRateInterlockEXT => "FragmentShaderShadingRateInterlockEXT",
+            Self::DemoteToHelperInvocation => "DemoteToHelperInvocation",
+            Self::FragmentShadingRateKHR => "FragmentShadingRateKHR",
+            Self::WorkgroupMemoryExplicitLayoutKHR => "WorkgroupMemoryExplicitLayoutKHR",
+            Self::WorkgroupMemoryExplicitLayout8BitAccessKHR => {
+                "WorkgroupMemoryExplicitLayout8BitAccessKHR"
+            }
+            Self::WorkgroupMemoryExplicitLayout16BitAccessKHR => {
+                "WorkgroupMemoryExplicitLayout16BitAccessKHR"
+            }
+            Self::DotProductInputAll => "DotProductInputAll",
+            Self::DotProductInput4x8Bit => "DotProductInput4x8Bit",
+            Self::DotProductInput4x8BitPacked => "DotProductInput4x8BitPacked",
+            Self::DotProduct => "DotProduct",
+            Self::FragmentBarycentricKHR => "FragmentBarycentricKHR",
+            Self::MeshShadingEXT => "MeshShadingEXT",
+            Self::GroupNonUniformRotateKHR => "GroupNonUniformRotateKHR",
+        }
+    }
+    #[doc = r" Return the capability with the given SPIR-V name"]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Matrix" => Some(Self::Matrix),
+            "Shader" => Some(Self::Shader),
+            "InputAttachment" => Some(Self::InputAttachment),
+            "Sampled1D" => Some(Self::Sampled1D),
+            "Image1D" => Some(Self::Image1D),
+            "SampledBuffer" => Some(Self::SampledBuffer),
+            "ImageBuffer" => Some(Self::ImageBuffer),
+            "ImageQuery" => Some(Self::ImageQuery),
+            "DerivativeControl" => Some(Self::DerivativeControl),
+            "Geometry" => Some(Self::Geometry),
+            "Tessellation" => Some(Self::Tessellation),
+            "Float64" => Some(Self::Float64),
+            "Int64" => Some(Self::Int64),
+            "Int64Atomics" => Some(Self::Int64Atomics),
+            "AtomicFloat32AddEXT" => Some(Self::AtomicFloat32AddEXT),
+            "AtomicFloat64AddEXT" => Some(Self::AtomicFloat64AddEXT),
+            "Int64ImageEXT" => Some(Self::Int64ImageEXT),
+            "Int16" => Some(Self::Int16),
+            "TessellationPointSize" => Some(Self::TessellationPointSize),
+            "GeometryPointSize" => Some(Self::GeometryPointSize),
+            "ImageGatherExtended" => Some(Self::ImageGatherExtended),
+            "StorageImageMultisample" => Some(Self::StorageImageMultisample),
+            "UniformBufferArrayDynamicIndexing" => Some(Self::UniformBufferArrayDynamicIndexing),
+            "SampledImageArrayDynamicIndexing" => Some(Self::SampledImageArrayDynamicIndexing),
+            "StorageBufferArrayDynamicIndexing" => Some(Self::StorageBufferArrayDynamicIndexing),
+            "StorageImageArrayDynamicIndexing" => Some(Self::StorageImageArrayDynamicIndexing),
+            "ClipDistance" => Some(Self::ClipDistance),
+            "CullDistance" => Some(Self::CullDistance),
+            "ImageCubeArray" => Some(Self::ImageCubeArray),
+            "SampleRateShading" => Some(Self::SampleRateShading),
+            "SparseResidency" => Some(Self::SparseResidency),
+            "MinLod" => Some(Self::MinLod),
+            "SampledCubeArray" => Some(Self::SampledCubeArray),
+            "ImageMSArray" => Some(Self::ImageMSArray),
+            "StorageImageExtendedFormats" => Some(Self::StorageImageExtendedFormats),
+            "InterpolationFunction" => Some(Self::InterpolationFunction),
+            "StorageImageReadWithoutFormat" => Some(Self::StorageImageReadWithoutFormat),
+            "StorageImageWriteWithoutFormat" => Some(Self::StorageImageWriteWithoutFormat),
+            "MultiViewport" => Some(Self::MultiViewport),
+            "DrawParameters" => Some(Self::DrawParameters),
+            "MultiView" => Some(Self::MultiView),
+            "DeviceGroup" => Some(Self::DeviceGroup),
+            "VariablePointersStorageBuffer" => Some(Self::VariablePointersStorageBuffer),
+            "VariablePointers" => Some(Self::VariablePointers),
+            "ShaderClockKHR" => Some(Self::ShaderClockKHR),
+            "StencilExportEXT" => Some(Self::StencilExportEXT),
+            "SubgroupBallotKHR" => Some(Self::SubgroupBallotKHR),
+            "SubgroupVoteKHR" => Some(Self::SubgroupVoteKHR),
+            "ImageReadWriteLodAMD" => Some(Self::ImageReadWriteLodAMD),
+            "ImageGatherBiasLodAMD" => Some(Self::ImageGatherBiasLodAMD),
+            "FragmentMaskAMD" => Some(Self::FragmentMaskAMD),
+            "SampleMaskOverrideCoverageNV" => Some(Self::SampleMaskOverrideCoverageNV),
+            "GeometryShaderPassthroughNV" => Some(Self::GeometryShaderPassthroughNV),
+            "ShaderViewportIndex" => Some(Self::ShaderViewportIndex),
+            "ShaderLayer" => Some(Self::ShaderLayer),
+            "ShaderViewportIndexLayerEXT" => Some(Self::ShaderViewportIndexLayerEXT),
+            "ShaderViewportIndexLayerNV" => Some(Self::ShaderViewportIndexLayerNV),
+            "ShaderViewportMaskNV" => Some(Self::ShaderViewportMaskNV),
+            "PerViewAttributesNV" => Some(Self::PerViewAttributesNV),
+            "StorageBuffer16BitAccess" => Some(Self::StorageBuffer16BitAccess),
+            "UniformAndStorageBuffer16BitAccess" => Some(Self::UniformAndStorageBuffer16BitAccess),
+            "StoragePushConstant16" => Some(Self::StoragePushConstant16),
+            "StorageInputOutput16" => Some(Self::StorageInputOutput16),
+            "GroupNonUniform" => Some(Self::GroupNonUniform),
+            "GroupNonUniformVote" => Some(Self::GroupNonUniformVote),
+            "GroupNonUniformArithmetic" => Some(Self::GroupNonUniformArithmetic),
+            "GroupNonUniformBallot" => Some(Self::GroupNonUniformBallot),
+            "GroupNonUniformShuffle" => Some(Self::GroupNonUniformShuffle),
+            "GroupNonUniformShuffleRelative" => Some(Self::GroupNonUniformShuffleRelative),
+            "GroupNonUniformClustered" => Some(Self::GroupNonUniformClustered),
+            "GroupNonUniformQuad" => Some(Self::GroupNonUniformQuad),
+            "GroupNonUniformPartitionedNV" => Some(Self::GroupNonUniformPartitionedNV),
+            "SampleMaskPostDepthCoverage" => Some(Self::SampleMaskPostDepthCoverage),
+            "ShaderNonUniform" => Some(Self::ShaderNonUniform),
+            "RuntimeDescriptorArray" => Some(Self::RuntimeDescriptorArray),
+            "InputAttachmentArrayDynamicIndexing" => {
+                Some(Self::InputAttachmentArrayDynamicIndexing)
+            }
+            "UniformTexelBufferArrayDynamicIndexing" => {
+                Some(Self::UniformTexelBufferArrayDynamicIndexing)
+            }
+            "StorageTexelBufferArrayDynamicIndexing" => {
+                Some(Self::StorageTexelBufferArrayDynamicIndexing)
+            }
+            "UniformBufferArrayNonUniformIndexing" => {
+                Some(Self::UniformBufferArrayNonUniformIndexing)
+            }
+            "SampledImageArrayNonUniformIndexing" => {
+                Some(Self::SampledImageArrayNonUniformIndexing)
+            }
+            "StorageBufferArrayNonUniformIndexing" => {
+                Some(Self::StorageBufferArrayNonUniformIndexing)
+            }
+            "StorageImageArrayNonUniformIndexing" => {
+                Some(Self::StorageImageArrayNonUniformIndexing)
+            }
+            "InputAttachmentArrayNonUniformIndexing" => {
+                Some(Self::InputAttachmentArrayNonUniformIndexing)
+            }
+            "UniformTexelBufferArrayNonUniformIndexing" => {
+                Some(Self::UniformTexelBufferArrayNonUniformIndexing)
+            }
+            "StorageTexelBufferArrayNonUniformIndexing" => {
+                Some(Self::StorageTexelBufferArrayNonUniformIndexing)
+            }
+            "FragmentFullyCoveredEXT" => Some(Self::FragmentFullyCoveredEXT),
+            "Float16" => Some(Self::Float16),
+            "Int8" => Some(Self::Int8),
+            "StorageBuffer8BitAccess" => Some(Self::StorageBuffer8BitAccess),
+            "UniformAndStorageBuffer8BitAccess" => Some(Self::UniformAndStorageBuffer8BitAccess),
+            "StoragePushConstant8" => Some(Self::StoragePushConstant8),
+            "VulkanMemoryModel" => Some(Self::VulkanMemoryModel),
+            "VulkanMemoryModelDeviceScope" => Some(Self::VulkanMemoryModelDeviceScope),
+            "DenormPreserve" => Some(Self::DenormPreserve),
+            "DenormFlushToZero" => Some(Self::DenormFlushToZero),
+            "SignedZeroInfNanPreserve" => Some(Self::SignedZeroInfNanPreserve),
+            "RoundingModeRTE" => Some(Self::RoundingModeRTE),
+            "RoundingModeRTZ" => Some(Self::RoundingModeRTZ),
+            "ImageFootprintNV" => Some(Self::ImageFootprintNV),
+            "ShadingRateNV" => Some(Self::ShadingRateNV),
+            "MeshShadingNV" => Some(Self::MeshShadingNV),
+            "RayTracingNV" => Some(Self::RayTracingNV),
+            "RayTracingKHR" => Some(Self::RayTracingKHR),
+            "RayQueryKHR" => Some(Self::RayQueryKHR),
+            "RayTraversalPrimitiveCullingKHR" => Some(Self::RayTraversalPrimitiveCullingKHR),
+            "TransformFeedback" => Some(Self::TransformFeedback),
+            "GeometryStreams" => Some(Self::GeometryStreams),
+            "FragmentDensityEXT" => Some(Self::FragmentDensityEXT),
+            "PhysicalStorageBufferAddresses" => Some(Self::PhysicalStorageBufferAddresses),
+            "CooperativeMatrixNV" => Some(Self::CooperativeMatrixNV),
+            "IntegerFunctions2INTEL" => Some(Self::IntegerFunctions2INTEL),
+            "ShaderSMBuiltinsNV" => Some(Self::ShaderSMBuiltinsNV),
+            "FragmentShaderSampleInterlockEXT" => Some(Self::FragmentShaderSampleInterlockEXT),
+            "FragmentShaderPixelInterlockEXT" => Some(Self::FragmentShaderPixelInterlockEXT),
+            "FragmentShaderShadingRateInterlockEXT" => {
+                Some(Self::FragmentShaderShadingRateInterlockEXT)
+            }
+            "DemoteToHelperInvocation" => Some(Self::DemoteToHelperInvocation),
+            "FragmentShadingRateKHR" => Some(Self::FragmentShadingRateKHR),
+            "WorkgroupMemoryExplicitLayoutKHR" => Some(Self::WorkgroupMemoryExplicitLayoutKHR),
+            "WorkgroupMemoryExplicitLayout8BitAccessKHR" => {
+                Some(Self::WorkgroupMemoryExplicitLayout8BitAccessKHR)
+            }
+            "WorkgroupMemoryExplicitLayout16BitAccessKHR" => {
+                Some(Self::WorkgroupMemoryExplicitLayout16BitAccessKHR)
+            }
+            "DotProductInputAll" => Some(Self::DotProductInputAll),
+            "DotProductInput4x8Bit" => Some(Self::DotProductInput4x8Bit),
+            "DotProductInput4x8BitPacked" => Some(Self::DotProductInput4x8BitPacked),
+            "DotProduct" => Some(Self::DotProduct),
+            "FragmentBarycentricKHR" => Some(Self::FragmentBarycentricKHR),
+            "MeshShadingEXT" => Some(Self::MeshShadingEXT),
+            "GroupNonUniformRotateKHR" => Some(Self::GroupNonUniformRotateKHR),
+            _ => None,
+        }
+    }
+    #[doc = r" Ways to allow a shader module to use this capability, only one of them needs to be satisfied"]
+    pub fn requirements(self) -> &'static [SpirvRequirement] {
+        match self {
+            Self::Matrix => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::Shader => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::InputAttachment => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::Sampled1D => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::Image1D => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::SampledBuffer => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::ImageBuffer => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::ImageQuery => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::DerivativeControl => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::Geometry => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, geometry_shader),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "geometryShader",
+                    })];
+                REQUIREMENTS
+            }
+            Self::Tessellation => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, tessellation_shader),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "tessellationShader",
+                    })];
+                REQUIREMENTS
+            }
+            Self::Float64 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_float64),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderFloat64",
+                    })];
+                REQUIREMENTS
+            }
+            Self::Int64 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_int64),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderInt64",
+                    })];
+                REQUIREMENTS
+            }
+            Self::Int64Atomics => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_buffer_int64_atomics
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderBufferInt64Atomics",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_shared_int64_atomics
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderSharedInt64Atomics",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderImageAtomicInt64FeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderImageAtomicInt64FeaturesEXT,
+                            shader_image_int64_atomics
+                        ),
+                        structure: "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+                        feature: "shaderImageInt64Atomics",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::AtomicFloat32AddEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderAtomicFloatFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+                            shader_buffer_float32_atomic_add
+                        ),
+                        structure: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                        feature: "shaderBufferFloat32AtomicAdd",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderAtomicFloatFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+                            shader_shared_float32_atomic_add
+                        ),
+                        structure: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                        feature: "shaderSharedFloat32AtomicAdd",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderAtomicFloatFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+                            shader_image_float32_atomic_add
+                        ),
+                        structure: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                        feature: "shaderImageFloat32AtomicAdd",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::AtomicFloat64AddEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderAtomicFloatFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+                            shader_buffer_float64_atomic_add
+                        ),
+                        structure: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                        feature: "shaderBufferFloat64AtomicAdd",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderAtomicFloatFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+                            shader_shared_float64_atomic_add
+                        ),
+                        structure: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                        feature: "shaderSharedFloat64AtomicAdd",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::Int64ImageEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderImageAtomicInt64FeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderImageAtomicInt64FeaturesEXT,
+                            shader_image_int64_atomics
+                        ),
+                        structure: "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+                        feature: "shaderImageInt64Atomics",
+                    })];
+                REQUIREMENTS
+            }
+            Self::Int16 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_int16),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderInt16",
+                    })];
+                REQUIREMENTS
+            }
+            Self::TessellationPointSize => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_tessellation_and_geometry_point_size
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderTessellationAndGeometryPointSize",
+                    })];
+                REQUIREMENTS
+            }
+            Self::GeometryPointSize => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_tessellation_and_geometry_point_size
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderTessellationAndGeometryPointSize",
+                    })];
+                REQUIREMENTS
+            }
+            Self::ImageGatherExtended => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_image_gather_extended),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderImageGatherExtended",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageImageMultisample => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_storage_image_multisample),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderStorageImageMultisample",
+                    })];
+                REQUIREMENTS
+            }
+            Self::UniformBufferArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_uniform_buffer_array_dynamic_indexing
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderUniformBufferArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::SampledImageArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_sampled_image_array_dynamic_indexing
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderSampledImageArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageBufferArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_storage_buffer_array_dynamic_indexing
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderStorageBufferArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageImageArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_storage_image_array_dynamic_indexing
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderStorageImageArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::ClipDistance => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_clip_distance),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderClipDistance",
+                    })];
+                REQUIREMENTS
+            }
+            Self::CullDistance => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_cull_distance),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderCullDistance",
+                    })];
+                REQUIREMENTS
+            }
+            Self::ImageCubeArray => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, image_cube_array),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "imageCubeArray",
+                    })];
+                REQUIREMENTS
+            }
+            Self::SampleRateShading => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, sample_rate_shading),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "sampleRateShading",
+                    })];
+                REQUIREMENTS
+            }
+            Self::SparseResidency => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_resource_residency),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderResourceResidency",
+                    })];
+                REQUIREMENTS
+            }
+            Self::MinLod => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_resource_min_lod),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderResourceMinLod",
+                    })];
+                REQUIREMENTS
+            }
+            Self::SampledCubeArray => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, image_cube_array),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "imageCubeArray",
+                    })];
+                REQUIREMENTS
+            }
+            Self::ImageMSArray => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, shader_storage_image_multisample),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderStorageImageMultisample",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageImageExtendedFormats => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Version(ApiVersion::new(0, 1u32, 0u32, 0))];
+                REQUIREMENTS
+            }
+            Self::InterpolationFunction => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, sample_rate_shading),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "sampleRateShading",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageImageReadWithoutFormat => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_storage_image_read_without_format
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderStorageImageReadWithoutFormat",
+                    }),
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 3u32, 0)),
+                    SpirvRequirement::Extension(KHR_FORMAT_FEATURE_FLAGS2),
+                ];
+                REQUIREMENTS
+            }
+            Self::StorageImageWriteWithoutFormat => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(
+                                PhysicalDeviceFeatures,
+                                shader_storage_image_write_without_format
+                            ),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "shaderStorageImageWriteWithoutFormat",
+                    }),
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 3u32, 0)),
+                    SpirvRequirement::Extension(KHR_FORMAT_FEATURE_FLAGS2),
+                ];
+                REQUIREMENTS
+            }
+            Self::MultiViewport => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFeatures2::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceFeatures2, features)
+                            + offset_of!(PhysicalDeviceFeatures, multi_viewport),
+                        structure: "VkPhysicalDeviceFeatures",
+                        feature: "multiViewport",
+                    })];
+                REQUIREMENTS
+            }
+            Self::DrawParameters => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan11Features, shader_draw_parameters),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "shaderDrawParameters",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderDrawParametersFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderDrawParametersFeatures,
+                            shader_draw_parameters
+                        ),
+                        structure: "VkPhysicalDeviceShaderDrawParametersFeatures",
+                        feature: "shaderDrawParameters",
+                    }),
+                    SpirvRequirement::Extension(KHR_SHADER_DRAW_PARAMETERS),
+                ];
+                REQUIREMENTS
+            }
+            Self::MultiView => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan11Features, multiview),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "multiview",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceMultiviewFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceMultiviewFeatures, multiview),
+                        structure: "VkPhysicalDeviceMultiviewFeatures",
+                        feature: "multiview",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::DeviceGroup => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 1u32, 0)),
+                    SpirvRequirement::Extension(KHR_DEVICE_GROUP),
+                ];
+                REQUIREMENTS
+            }
+            Self::VariablePointersStorageBuffer => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Features,
+                            variable_pointers_storage_buffer
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "variablePointersStorageBuffer",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVariablePointersFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVariablePointersFeatures,
+                            variable_pointers_storage_buffer
+                        ),
+                        structure: "VkPhysicalDeviceVariablePointersFeatures",
+                        feature: "variablePointersStorageBuffer",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::VariablePointers => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan11Features, variable_pointers),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "variablePointers",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVariablePointersFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVariablePointersFeatures,
+                            variable_pointers
+                        ),
+                        structure: "VkPhysicalDeviceVariablePointersFeatures",
+                        feature: "variablePointers",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::ShaderClockKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(KHR_SHADER_CLOCK)];
+                REQUIREMENTS
+            }
+            Self::StencilExportEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_STENCIL_EXPORT)];
+                REQUIREMENTS
+            }
+            Self::SubgroupBallotKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_SUBGROUP_BALLOT)];
+                REQUIREMENTS
+            }
+            Self::SubgroupVoteKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_SUBGROUP_VOTE)];
+                REQUIREMENTS
+            }
+            Self::ImageReadWriteLodAMD => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_SHADER_IMAGE_LOAD_STORE_LOD)];
+                REQUIREMENTS
+            }
+            Self::ImageGatherBiasLodAMD => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_TEXTURE_GATHER_BIAS_LOD)];
+                REQUIREMENTS
+            }
+            Self::FragmentMaskAMD => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(AMD_SHADER_FRAGMENT_MASK)];
+                REQUIREMENTS
+            }
+            Self::SampleMaskOverrideCoverageNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    NV_SAMPLE_MASK_OVERRIDE_COVERAGE,
+                )];
+                REQUIREMENTS
+            }
+            Self::GeometryShaderPassthroughNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_GEOMETRY_SHADER_PASSTHROUGH)];
+                REQUIREMENTS
+            }
+            Self::ShaderViewportIndex => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_output_viewport_index
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderOutputViewportIndex",
+                    })];
+                REQUIREMENTS
+            }
+            Self::ShaderLayer => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan12Features, shader_output_layer),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderOutputLayer",
+                    })];
+                REQUIREMENTS
+            }
+            Self::ShaderViewportIndexLayerEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_SHADER_VIEWPORT_INDEX_LAYER)];
+                REQUIREMENTS
+            }
+            Self::ShaderViewportIndexLayerNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_VIEWPORT_ARRAY2)];
+                REQUIREMENTS
+            }
+            Self::ShaderViewportMaskNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_VIEWPORT_ARRAY2)];
+                REQUIREMENTS
+            }
+            Self::PerViewAttributesNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[SpirvRequirement::Extension(
+                    NVX_MULTIVIEW_PER_VIEW_ATTRIBUTES,
+                )];
+                REQUIREMENTS
+            }
+            Self::StorageBuffer16BitAccess => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Features,
+                            storage_buffer16_bit_access
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "storageBuffer16BitAccess",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDevice16BitStorageFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDevice16BitStorageFeatures,
+                            storage_buffer16_bit_access
+                        ),
+                        structure: "VkPhysicalDevice16BitStorageFeatures",
+                        feature: "storageBuffer16BitAccess",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::UniformAndStorageBuffer16BitAccess => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Features,
+                            uniform_and_storage_buffer16_bit_access
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "uniformAndStorageBuffer16BitAccess",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDevice16BitStorageFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDevice16BitStorageFeatures,
+                            uniform_and_storage_buffer16_bit_access
+                        ),
+                        structure: "VkPhysicalDevice16BitStorageFeatures",
+                        feature: "uniformAndStorageBuffer16BitAccess",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::StoragePushConstant16 => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan11Features, storage_push_constant16),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "storagePushConstant16",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDevice16BitStorageFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDevice16BitStorageFeatures,
+                            storage_push_constant16
+                        ),
+                        structure: "VkPhysicalDevice16BitStorageFeatures",
+                        feature: "storagePushConstant16",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::StorageInputOutput16 => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan11Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan11Features, storage_input_output16),
+                        structure: "VkPhysicalDeviceVulkan11Features",
+                        feature: "storageInputOutput16",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDevice16BitStorageFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDevice16BitStorageFeatures,
+                            storage_input_output16
+                        ),
+                        structure: "VkPhysicalDevice16BitStorageFeatures",
+                        feature: "storageInputOutput16",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniform => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Basic.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformVote => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Vote.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformArithmetic => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Arithmetic.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformBallot => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Ballot.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformShuffle => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Shuffle.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformShuffleRelative => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::ShuffleRelative.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformClustered => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Clustered.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformQuad => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::Quad.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-nv"))]
+            Self::GroupNonUniformPartitionedNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-nv")]
+            Self::GroupNonUniformPartitionedNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan11Properties,
+                            subgroup_supported_operations
+                        ),
+                        structure: "VkPhysicalDeviceVulkan11Properties",
+                        member: "subgroupSupportedOperations",
+                        value: SubgroupFeatureFlags::PartitionedNV.bits(),
+                    })];
+                REQUIREMENTS
+            }
+            Self::SampleMaskPostDepthCoverage => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_POST_DEPTH_COVERAGE)];
+                REQUIREMENTS
+            }
+            Self::ShaderNonUniform => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Version(ApiVersion::new(0, 1u32, 2u32, 0)),
+                    SpirvRequirement::Extension(EXT_DESCRIPTOR_INDEXING),
+                ];
+                REQUIREMENTS
+            }
+            Self::RuntimeDescriptorArray => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            runtime_descriptor_array
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "runtimeDescriptorArray",
+                    })];
+                REQUIREMENTS
+            }
+            Self::InputAttachmentArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_input_attachment_array_dynamic_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderInputAttachmentArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::UniformTexelBufferArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_uniform_texel_buffer_array_dynamic_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderUniformTexelBufferArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageTexelBufferArrayDynamicIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_storage_texel_buffer_array_dynamic_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderStorageTexelBufferArrayDynamicIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::UniformBufferArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_uniform_buffer_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderUniformBufferArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::SampledImageArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_sampled_image_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderSampledImageArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageBufferArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_storage_buffer_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderStorageBufferArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageImageArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_storage_image_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderStorageImageArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::InputAttachmentArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_input_attachment_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderInputAttachmentArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::UniformTexelBufferArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_uniform_texel_buffer_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderUniformTexelBufferArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageTexelBufferArrayNonUniformIndexing => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            shader_storage_texel_buffer_array_non_uniform_indexing
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderStorageTexelBufferArrayNonUniformIndexing",
+                    })];
+                REQUIREMENTS
+            }
+            Self::FragmentFullyCoveredEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_CONSERVATIVE_RASTERIZATION)];
+                REQUIREMENTS
+            }
+            Self::Float16 => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan12Features, shader_float16),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderFloat16",
+                    }),
+                    SpirvRequirement::Extension(AMD_GPU_SHADER_HALF_FLOAT),
+                ];
+                REQUIREMENTS
+            }
+            Self::Int8 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan12Features, shader_int8),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "shaderInt8",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StorageBuffer8BitAccess => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            storage_buffer8_bit_access
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "storageBuffer8BitAccess",
+                    })];
+                REQUIREMENTS
+            }
+            Self::UniformAndStorageBuffer8BitAccess => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            uniform_and_storage_buffer8_bit_access
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "uniformAndStorageBuffer8BitAccess",
+                    })];
+                REQUIREMENTS
+            }
+            Self::StoragePushConstant8 => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan12Features, storage_push_constant8),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "storagePushConstant8",
+                    })];
+                REQUIREMENTS
+            }
+            Self::VulkanMemoryModel => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan12Features, vulkan_memory_model),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "vulkanMemoryModel",
+                    })];
+                REQUIREMENTS
+            }
+            Self::VulkanMemoryModelDeviceScope => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Features,
+                            vulkan_memory_model_device_scope
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "vulkanMemoryModelDeviceScope",
+                    })];
+                REQUIREMENTS
+            }
+            Self::DenormPreserve => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_denorm_preserve_float16
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderDenormPreserveFloat16",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_denorm_preserve_float32
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderDenormPreserveFloat32",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_denorm_preserve_float64
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderDenormPreserveFloat64",
+                        value: 1,
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::DenormFlushToZero => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_denorm_flush_to_zero_float16
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderDenormFlushToZeroFloat16",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_denorm_flush_to_zero_float32
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderDenormFlushToZeroFloat32",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_denorm_flush_to_zero_float64
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderDenormFlushToZeroFloat64",
+                        value: 1,
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::SignedZeroInfNanPreserve => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_signed_zero_inf_nan_preserve_float16
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderSignedZeroInfNanPreserveFloat16",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_signed_zero_inf_nan_preserve_float32
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderSignedZeroInfNanPreserveFloat32",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_signed_zero_inf_nan_preserve_float64
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderSignedZeroInfNanPreserveFloat64",
+                        value: 1,
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::RoundingModeRTE => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_rounding_mode_rtefloat16
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderRoundingModeRTEFloat16",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_rounding_mode_rtefloat32
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderRoundingModeRTEFloat32",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_rounding_mode_rtefloat64
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderRoundingModeRTEFloat64",
+                        value: 1,
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::RoundingModeRTZ => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_rounding_mode_rtzfloat16
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderRoundingModeRTZFloat16",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_rounding_mode_rtzfloat32
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderRoundingModeRTZFloat32",
+                        value: 1,
+                    }),
+                    SpirvRequirement::Property(SpirvProperty {
+                        structure_type: PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan12Properties,
+                            shader_rounding_mode_rtzfloat64
+                        ),
+                        structure: "VkPhysicalDeviceVulkan12Properties",
+                        member: "shaderRoundingModeRTZFloat64",
+                        value: 1,
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-nv"))]
+            Self::ImageFootprintNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-nv")]
+            Self::ImageFootprintNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderImageFootprintFeaturesNV::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderImageFootprintFeaturesNV,
+                            image_footprint
+                        ),
+                        structure: "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+                        feature: "imageFootprint",
+                    })];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-nv"))]
+            Self::ShadingRateNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-nv")]
+            Self::ShadingRateNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShadingRateImageFeaturesNV::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShadingRateImageFeaturesNV,
+                            shading_rate_image
+                        ),
+                        structure: "VkPhysicalDeviceShadingRateImageFeaturesNV",
+                        feature: "shadingRateImage",
+                    })];
+                REQUIREMENTS
+            }
+            Self::MeshShadingNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_MESH_SHADER)];
+                REQUIREMENTS
+            }
+            Self::RayTracingNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(NV_RAY_TRACING)];
+                REQUIREMENTS
+            }
+            Self::RayTracingKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceRayTracingPipelineFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceRayTracingPipelineFeaturesKHR,
+                            ray_tracing_pipeline
+                        ),
+                        structure: "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+                        feature: "rayTracingPipeline",
+                    })];
+                REQUIREMENTS
+            }
+            Self::RayQueryKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceRayQueryFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceRayQueryFeaturesKHR, ray_query),
+                        structure: "VkPhysicalDeviceRayQueryFeaturesKHR",
+                        feature: "rayQuery",
+                    })];
+                REQUIREMENTS
+            }
+            Self::RayTraversalPrimitiveCullingKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceRayTracingPipelineFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceRayTracingPipelineFeaturesKHR,
+                            ray_traversal_primitive_culling
+                        ),
+                        structure: "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+                        feature: "rayTraversalPrimitiveCulling",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceRayQueryFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceRayQueryFeaturesKHR, ray_query),
+                        structure: "VkPhysicalDeviceRayQueryFeaturesKHR",
+                        feature: "rayQuery",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::TransformFeedback => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceTransformFeedbackFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceTransformFeedbackFeaturesEXT,
+                            transform_feedback
+                        ),
+                        structure: "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+                        feature: "transformFeedback",
+                    })];
+                REQUIREMENTS
+            }
+            Self::GeometryStreams => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceTransformFeedbackFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceTransformFeedbackFeaturesEXT,
+                            geometry_streams
+                        ),
+                        structure: "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+                        feature: "geometryStreams",
+                    })];
+                REQUIREMENTS
+            }
+            Self::FragmentDensityEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceFragmentDensityMapFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentDensityMapFeaturesEXT,
+                            fragment_density_map
+                        ),
+                        structure: "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+                        feature: "fragmentDensityMap",
+                    })];
+                REQUIREMENTS
+            }
+            Self::PhysicalStorageBufferAddresses => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan12Features::STRUCTURE_TYPE,
+                        offset: offset_of!(PhysicalDeviceVulkan12Features, buffer_device_address),
+                        structure: "VkPhysicalDeviceVulkan12Features",
+                        feature: "bufferDeviceAddress",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceBufferDeviceAddressFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceBufferDeviceAddressFeaturesEXT,
+                            buffer_device_address
+                        ),
+                        structure: "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+                        feature: "bufferDeviceAddress",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-nv"))]
+            Self::CooperativeMatrixNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-nv")]
+            Self::CooperativeMatrixNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceCooperativeMatrixFeaturesNV::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceCooperativeMatrixFeaturesNV,
+                            cooperative_matrix
+                        ),
+                        structure: "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+                        feature: "cooperativeMatrix",
+                    })];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-intel"))]
+            Self::IntegerFunctions2INTEL => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-intel")]
+            Self::IntegerFunctions2INTEL => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL,
+                            shader_integer_functions2
+                        ),
+                        structure: "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL",
+                        feature: "shaderIntegerFunctions2",
+                    })];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-nv"))]
+            Self::ShaderSMBuiltinsNV => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-nv")]
+            Self::ShaderSMBuiltinsNV => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShaderSMBuiltinsFeaturesNV::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderSMBuiltinsFeaturesNV,
+                            shader_smbuiltins
+                        ),
+                        structure: "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+                        feature: "shaderSMBuiltins",
+                    })];
+                REQUIREMENTS
+            }
+            Self::FragmentShaderSampleInterlockEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+                            fragment_shader_sample_interlock
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+                        feature: "fragmentShaderSampleInterlock",
+                    })];
+                REQUIREMENTS
+            }
+            Self::FragmentShaderPixelInterlockEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+                            fragment_shader_pixel_interlock
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+                        feature: "fragmentShaderPixelInterlock",
+                    })];
+                REQUIREMENTS
+            }
+            #[cfg(not(feature = "vendor-nv"))]
+            Self::FragmentShaderShadingRateInterlockEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+                            fragment_shader_shading_rate_interlock
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+                        feature: "fragmentShaderShadingRateInterlock",
+                    })];
+                REQUIREMENTS
+            }
+            #[cfg(feature = "vendor-nv")]
+            Self::FragmentShaderShadingRateInterlockEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+                            fragment_shader_shading_rate_interlock
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+                        feature: "fragmentShaderShadingRateInterlock",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceShadingRateImageFeaturesNV::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShadingRateImageFeaturesNV,
+                            shading_rate_image
+                        ),
+                        structure: "VkPhysicalDeviceShadingRateImageFeaturesNV",
+                        feature: "shadingRateImage",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::DemoteToHelperInvocation => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan13Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan13Features,
+                            shader_demote_to_helper_invocation
+                        ),
+                        structure: "VkPhysicalDeviceVulkan13Features",
+                        feature: "shaderDemoteToHelperInvocation",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderDemoteToHelperInvocationFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderDemoteToHelperInvocationFeatures,
+                            shader_demote_to_helper_invocation
+                        ),
+                        structure: "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures",
+                        feature: "shaderDemoteToHelperInvocation",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::FragmentShadingRateKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShadingRateFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShadingRateFeaturesKHR,
+                            pipeline_fragment_shading_rate
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+                        feature: "pipelineFragmentShadingRate",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShadingRateFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShadingRateFeaturesKHR,
+                            primitive_fragment_shading_rate
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+                        feature: "primitiveFragmentShadingRate",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShadingRateFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShadingRateFeaturesKHR,
+                            attachment_fragment_shading_rate
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+                        feature: "attachmentFragmentShadingRate",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::WorkgroupMemoryExplicitLayoutKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR,
+                            workgroup_memory_explicit_layout
+                        ),
+                        structure: "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+                        feature: "workgroupMemoryExplicitLayout",
+                    })];
+                REQUIREMENTS
+            }
+            Self::WorkgroupMemoryExplicitLayout8BitAccessKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR,
+                            workgroup_memory_explicit_layout8_bit_access
+                        ),
+                        structure: "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+                        feature: "workgroupMemoryExplicitLayout8BitAccess",
+                    })];
+                REQUIREMENTS
+            }
+            Self::WorkgroupMemoryExplicitLayout16BitAccessKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR,
+                            workgroup_memory_explicit_layout16_bit_access
+                        ),
+                        structure: "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+                        feature: "workgroupMemoryExplicitLayout16BitAccess",
+                    })];
+                REQUIREMENTS
+            }
+            Self::DotProductInputAll => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan13Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan13Features,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceVulkan13Features",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderIntegerDotProductFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderIntegerDotProductFeatures,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::DotProductInput4x8Bit => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan13Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan13Features,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceVulkan13Features",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderIntegerDotProductFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderIntegerDotProductFeatures,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::DotProductInput4x8BitPacked => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan13Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan13Features,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceVulkan13Features",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderIntegerDotProductFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderIntegerDotProductFeatures,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::DotProduct => {
+                const REQUIREMENTS: &[SpirvRequirement] = &[
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type: PhysicalDeviceVulkan13Features::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceVulkan13Features,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceVulkan13Features",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                    SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderIntegerDotProductFeatures::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderIntegerDotProductFeatures,
+                            shader_integer_dot_product
+                        ),
+                        structure: "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                        feature: "shaderIntegerDotProduct",
+                    }),
+                ];
+                REQUIREMENTS
+            }
+            Self::FragmentBarycentricKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceFragmentShaderBarycentricFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceFragmentShaderBarycentricFeaturesKHR,
+                            fragment_shader_barycentric
+                        ),
+                        structure: "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR",
+                        feature: "fragmentShaderBarycentric",
+                    })];
+                REQUIREMENTS
+            }
+            Self::MeshShadingEXT => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Extension(EXT_MESH_SHADER)];
+                REQUIREMENTS
+            }
+            Self::GroupNonUniformRotateKHR => {
+                const REQUIREMENTS: &[SpirvRequirement] =
+                    &[SpirvRequirement::Feature(SpirvFeature {
+                        structure_type:
+                            PhysicalDeviceShaderSubgroupRotateFeaturesKHR::STRUCTURE_TYPE,
+                        offset: offset_of!(
+                            PhysicalDeviceShaderSubgroupRotateFeaturesKHR,
+                            shader_subgroup_rotate
+                        ),
+                        structure: "VkPhysicalDeviceShaderSubgroupRotateFeaturesKHR",
+                        feature: "shaderSubgroupRotate",
+                    })];
+                REQUIREMENTS
+            }
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use super::{AccessFlags2, PipelineStageFlags2, QueueFlags};
+impl PipelineStageFlags2 {
+    #[doc = r" Add to these stages all the stages the meta stages they contain are equivalent to."]
+    #[doc = r" For example [PipelineStageFlags2::AllGraphics] adds all the graphics stages."]
+    #[doc = r" [PipelineStageFlags2::AllCommands] is not expanded, as it depends on the queue"]
+    pub const fn expand_meta_stages(self) -> Self {
+        let mut stages = self.bits();
+        if self.contains(PipelineStageFlags2::VertexInput) {
+            stages |= PipelineStageFlags2::IndexInput.bits()
+                | PipelineStageFlags2::VertexAttributeInput.bits();
+        }
+        if self.contains(PipelineStageFlags2::AllTransfer) {
+            stages |= PipelineStageFlags2::Copy.bits()
+                | PipelineStageFlags2::Blit.bits()
+                | PipelineStageFlags2::Resolve.bits()
+                | PipelineStageFlags2::Clear.bits()
+                | PipelineStageFlags2::AccelerationStructureCopyKHR.bits();
+        }
+        if self.contains(PipelineStageFlags2::AllGraphics) {
+            stages |= PipelineStageFlags2::DrawIndirect.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::VertexInput.bits()
+                | PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::EarlyFragmentTests.bits()
+                | PipelineStageFlags2::LateFragmentTests.bits()
+                | PipelineStageFlags2::ColorAttachmentOutput.bits()
+                | PipelineStageFlags2::ConditionalRenderingEXT.bits()
+                | PipelineStageFlags2::TransformFeedbackEXT.bits()
+                | PipelineStageFlags2::FragmentShadingRateAttachmentKHR.bits()
+                | PipelineStageFlags2::FragmentDensityProcessEXT.bits()
+                | PipelineStageFlags2::IndexInput.bits()
+                | PipelineStageFlags2::VertexAttributeInput.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::InvocationMaskHUAWEI.bits()
+                    | PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(PipelineStageFlags2::PreRasterizationShaders) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits();
+            }
+        }
+        Self::from_bits_retain(stages)
+    }
+}
+impl QueueFlags {
+    #[doc = r" Pipeline stages which can be used on a queue with these capabilities"]
+    pub const fn supported_stages(self) -> PipelineStageFlags2 {
+        let mut stages = 0;
+        stages |= PipelineStageFlags2::TopOfPipe.bits()
+            | PipelineStageFlags2::BottomOfPipe.bits()
+            | PipelineStageFlags2::Host.bits()
+            | PipelineStageFlags2::AllCommands.bits();
+        if self.contains(QueueFlags::Graphics) {
+            stages |= PipelineStageFlags2::DrawIndirect.bits()
+                | PipelineStageFlags2::VertexInput.bits()
+                | PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::EarlyFragmentTests.bits()
+                | PipelineStageFlags2::LateFragmentTests.bits()
+                | PipelineStageFlags2::ColorAttachmentOutput.bits()
+                | PipelineStageFlags2::AllTransfer.bits()
+                | PipelineStageFlags2::AllGraphics.bits()
+                | PipelineStageFlags2::Copy.bits()
+                | PipelineStageFlags2::Resolve.bits()
+                | PipelineStageFlags2::Blit.bits()
+                | PipelineStageFlags2::Clear.bits()
+                | PipelineStageFlags2::IndexInput.bits()
+                | PipelineStageFlags2::VertexAttributeInput.bits()
+                | PipelineStageFlags2::PreRasterizationShaders.bits()
+                | PipelineStageFlags2::TransformFeedbackEXT.bits()
+                | PipelineStageFlags2::ConditionalRenderingEXT.bits()
+                | PipelineStageFlags2::CommandPreprocessNV.bits()
+                | PipelineStageFlags2::FragmentShadingRateAttachmentKHR.bits()
+                | PipelineStageFlags2::FragmentDensityProcessEXT.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::SubpassShaderHUAWEI.bits()
+                    | PipelineStageFlags2::InvocationMaskHUAWEI.bits()
+                    | PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(QueueFlags::Compute) {
+            stages |= PipelineStageFlags2::DrawIndirect.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::AllTransfer.bits()
+                | PipelineStageFlags2::Copy.bits()
+                | PipelineStageFlags2::Clear.bits()
+                | PipelineStageFlags2::ConditionalRenderingEXT.bits()
+                | PipelineStageFlags2::CommandPreprocessNV.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureCopyKHR.bits()
+                | PipelineStageFlags2::MicromapBuildEXT.bits();
+        }
+        if self.contains(QueueFlags::Transfer) {
+            stages |= PipelineStageFlags2::AllTransfer.bits() | PipelineStageFlags2::Copy.bits();
+        }
+        if self.contains(QueueFlags::VideoDecodeKHR) {
+            stages |= PipelineStageFlags2::VideoDecodeKHR.bits();
+        }
+        if self.contains(QueueFlags::VideoEncodeKHR) {
+            stages |= PipelineStageFlags2::VideoEncodeKHR.bits();
+        }
+        #[cfg(feature = "vendor-nv")]
+        if self.contains(QueueFlags::OpticalFlowNV) {
+            stages |= PipelineStageFlags2::OpticalFlowNV.bits();
+        }
+        PipelineStageFlags2::from_bits_retain(stages)
+    }
+}
+impl AccessFlags2 {
+    #[doc = r" Pipeline stages with which these accesses can be used."]
+    #[doc = r" An access is valid in a barrier if its stage mask contains one of its supported stages,"]
+    #[doc = r" accesses supported by [PipelineStageFlags2::AllCommands] are valid with any stage"]
+    pub const fn supported_stages(self) -> PipelineStageFlags2 {
+        let mut stages = 0;
+        if self.contains(AccessFlags2::IndirectCommandRead) {
+            stages |= PipelineStageFlags2::DrawIndirect.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits();
+        }
+        if self.contains(AccessFlags2::IndexRead) {
+            stages |= PipelineStageFlags2::IndexInput.bits();
+        }
+        if self.contains(AccessFlags2::VertexAttributeRead) {
+            stages |= PipelineStageFlags2::VertexAttributeInput.bits();
+        }
+        if self.contains(AccessFlags2::UniformRead) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::InputAttachmentRead) {
+            stages |= PipelineStageFlags2::FragmentShader.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::ShaderRead) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::MicromapBuildEXT.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::ShaderWrite) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::ColorAttachmentRead) {
+            stages |= PipelineStageFlags2::ColorAttachmentOutput.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::ColorAttachmentWrite) {
+            stages |= PipelineStageFlags2::ColorAttachmentOutput.bits();
+        }
+        if self.contains(AccessFlags2::DepthStencilAttachmentRead) {
+            stages |= PipelineStageFlags2::EarlyFragmentTests.bits()
+                | PipelineStageFlags2::LateFragmentTests.bits();
+        }
+        if self.contains(AccessFlags2::DepthStencilAttachmentWrite) {
+            stages |= PipelineStageFlags2::EarlyFragmentTests.bits()
+                | PipelineStageFlags2::LateFragmentTests.bits();
+        }
+        if self.contains(AccessFlags2::TransferRead) {
+            stages |= PipelineStageFlags2::AllTransfer.bits()
+                | PipelineStageFlags2::Copy.bits()
+                | PipelineStageFlags2::Resolve.bits()
+                | PipelineStageFlags2::Blit.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureCopyKHR.bits()
+                | PipelineStageFlags2::MicromapBuildEXT.bits();
+        }
+        if self.contains(AccessFlags2::TransferWrite) {
+            stages |= PipelineStageFlags2::AllTransfer.bits()
+                | PipelineStageFlags2::Copy.bits()
+                | PipelineStageFlags2::Resolve.bits()
+                | PipelineStageFlags2::Blit.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureCopyKHR.bits()
+                | PipelineStageFlags2::MicromapBuildEXT.bits()
+                | PipelineStageFlags2::Clear.bits();
+        }
+        if self.contains(AccessFlags2::HostRead) {
+            stages |= PipelineStageFlags2::Host.bits();
+        }
+        if self.contains(AccessFlags2::HostWrite) {
+            stages |= PipelineStageFlags2::Host.bits();
+        }
+        if self.contains(AccessFlags2::MemoryRead) {
+            stages |= PipelineStageFlags2::AllCommands.bits();
+        }
+        if self.contains(AccessFlags2::MemoryWrite) {
+            stages |= PipelineStageFlags2::AllCommands.bits();
+        }
+        if self.contains(AccessFlags2::ShaderSampledRead) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::ShaderStorageRead) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::MicromapBuildEXT.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::ShaderStorageWrite) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::VideoDecodeReadKHR) {
+            stages |= PipelineStageFlags2::VideoDecodeKHR.bits();
+        }
+        if self.contains(AccessFlags2::VideoDecodeWriteKHR) {
+            stages |= PipelineStageFlags2::VideoDecodeKHR.bits();
+        }
+        if self.contains(AccessFlags2::VideoEncodeReadKHR) {
+            stages |= PipelineStageFlags2::VideoEncodeKHR.bits();
+        }
+        if self.contains(AccessFlags2::VideoEncodeWriteKHR) {
+            stages |= PipelineStageFlags2::VideoEncodeKHR.bits();
+        }
+        if self.contains(AccessFlags2::TransformFeedbackWriteEXT) {
+            stages |= PipelineStageFlags2::TransformFeedbackEXT.bits();
+        }
+        if self.contains(AccessFlags2::TransformFeedbackCounterReadEXT) {
+            stages |= PipelineStageFlags2::DrawIndirect.bits()
+                | PipelineStageFlags2::TransformFeedbackEXT.bits();
+        }
+        if self.contains(AccessFlags2::TransformFeedbackCounterWriteEXT) {
+            stages |= PipelineStageFlags2::TransformFeedbackEXT.bits();
+        }
+        if self.contains(AccessFlags2::ConditionalRenderingReadEXT) {
+            stages |= PipelineStageFlags2::ConditionalRenderingEXT.bits();
+        }
+        if self.contains(AccessFlags2::CommandPreprocessReadNV) {
+            stages |= PipelineStageFlags2::CommandPreprocessNV.bits();
+        }
+        if self.contains(AccessFlags2::CommandPreprocessWriteNV) {
+            stages |= PipelineStageFlags2::CommandPreprocessNV.bits();
+        }
+        if self.contains(AccessFlags2::FragmentShadingRateAttachmentReadKHR) {
+            stages |= PipelineStageFlags2::FragmentShadingRateAttachmentKHR.bits();
+        }
+        if self.contains(AccessFlags2::AccelerationStructureReadKHR) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureCopyKHR.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        if self.contains(AccessFlags2::AccelerationStructureWriteKHR) {
+            stages |= PipelineStageFlags2::AccelerationStructureBuildKHR.bits()
+                | PipelineStageFlags2::AccelerationStructureCopyKHR.bits();
+        }
+        if self.contains(AccessFlags2::FragmentDensityMapReadEXT) {
+            stages |= PipelineStageFlags2::FragmentDensityProcessEXT.bits();
+        }
+        if self.contains(AccessFlags2::ColorAttachmentReadNoncoherentEXT) {
+            stages |= PipelineStageFlags2::ColorAttachmentOutput.bits();
+        }
+        if self.contains(AccessFlags2::DescriptorBufferReadEXT) {
+            stages |= PipelineStageFlags2::VertexShader.bits()
+                | PipelineStageFlags2::TessellationControlShader.bits()
+                | PipelineStageFlags2::TessellationEvaluationShader.bits()
+                | PipelineStageFlags2::GeometryShader.bits()
+                | PipelineStageFlags2::FragmentShader.bits()
+                | PipelineStageFlags2::ComputeShader.bits()
+                | PipelineStageFlags2::TaskShaderEXT.bits()
+                | PipelineStageFlags2::MeshShaderEXT.bits()
+                | PipelineStageFlags2::RayTracingShaderKHR.bits();
+            #[cfg(feature = "vendor-huawei")]
+            {
+                stages |= PipelineStageFlags2::ClusterCullingShaderHUAWEI.bits()
+                    | PipelineStageFlags2::SubpassShaderHUAWEI.bits();
+            }
+        }
+        #[cfg(feature = "vendor-huawei")]
+        if self.contains(AccessFlags2::InvocationMaskReadHUAWEI) {
+            stages |= PipelineStageFlags2::InvocationMaskHUAWEI.bits();
+        }
+        if self.contains(AccessFlags2::ShaderBindingTableReadKHR) {
+            stages |= PipelineStageFlags2::RayTracingShaderKHR.bits();
+        }
+        if self.contains(AccessFlags2::MicromapReadEXT) {
+            stages |= PipelineStageFlags2::MicromapBuildEXT.bits()
+                | PipelineStageFlags2::AccelerationStructureBuildKHR.bits();
+        }
+        if self.contains(AccessFlags2::MicromapWriteEXT) {
+            stages |= PipelineStageFlags2::MicromapBuildEXT.bits();
+        }
+        #[cfg(feature = "vendor-nv")]
+        if self.contains(AccessFlags2::OpticalFlowReadNV) {
+            stages |= PipelineStageFlags2::OpticalFlowNV.bits();
+        }
+        #[cfg(feature = "vendor-nv")]
+        if self.contains(AccessFlags2::OpticalFlowWriteNV) {
+            stages |= PipelineStageFlags2::OpticalFlowNV.bits();
+        }
+        PipelineStageFlags2::from_bits_retain(stages)
+    }
+}
@@ -0,0 +1,541 @@
+use super::*;
+
+#[test]
+fn numeric_format_skips_alpha_first() {
+    assert_eq!(
+        Format::A8B8G8R8SrgbPack32.numeric_format(),
+        NumericFormat::Srgb
+    );
+    assert_eq!(
+        Format::A8B8G8R8UnormPack32.numeric_format(),
+        NumericFormat::Unorm
+    );
+    assert_eq!(
+        Format::A2B10G10R10UnormPack32.numeric_format(),
+        NumericFormat::Unorm
+    );
+    assert_eq!(
+        Format::A2B10G10R10SnormPack32.numeric_format(),
+        NumericFormat::Snorm
+    );
+    assert_eq!(
+        Format::A2B10G10R10UintPack32.numeric_format(),
+        NumericFormat::Uint
+    );
+    // formats with only an alpha component keep its numeric format
+    assert_eq!(Format::A8UnormKHR.numeric_format(), NumericFormat::Unorm);
+}
+
+/// Limits of a device supporting the minimum values required by the specification
+fn minimum_device_limits() -> PhysicalDeviceLimits {
+    PhysicalDeviceLimits::default()
+        .max_image_dimension2_d(4096)
+        .buffer_image_granularity(131072)
+        .min_memory_map_alignment(64)
+        .min_texel_buffer_offset_alignment(256)
+        .min_uniform_buffer_offset_alignment(256)
+        .min_storage_buffer_offset_alignment(256)
+        .min_texel_offset(-8)
+        .max_texel_offset(7)
+        .min_interpolation_offset(-0.5)
+        .point_size_range([1.0, 64.0])
+        .line_width_range([1.0, 1.0])
+        .viewport_bounds_range([-8192.0, 8191.0])
+        .point_size_granularity(1.0)
+        .line_width_granularity(1.0)
+        .optimal_buffer_copy_offset_alignment(1)
+        .optimal_buffer_copy_row_pitch_alignment(1)
+        .non_coherent_atom_size(256)
+}
+
+#[test]
+fn least_restrictive_limits_are_satisfied() {
+    let device = minimum_device_limits();
+    let required = PhysicalDeviceLimits::least_restrictive();
+    assert!(device.satisfies_limits(&required));
+    assert!(PhysicalDeviceLimits::default().satisfies_limits(&required));
+    // the default value requires alignments of 0, which no device supports
+    assert!(!device.satisfies_limits(&PhysicalDeviceLimits::default()));
+
+    let properties = PhysicalDeviceProperties::default().limits(device);
+    assert!(properties.satisfies_limits(&PhysicalDeviceProperties::least_restrictive()));
+    let properties2 = PhysicalDeviceProperties2::default().properties(properties);
+    assert!(properties2.satisfies_limits(&PhysicalDeviceProperties2::least_restrictive()));
+}
+
+#[test]
+fn limits_are_compared_against_requirements() {
+    let device = minimum_device_limits();
+    let required = PhysicalDeviceLimits::least_restrictive()
+        .max_image_dimension2_d(4096)
+        .min_uniform_buffer_offset_alignment(256)
+        .point_size_range([1.0, 32.0]);
+    assert!(device.satisfies_limits(&required));
+    assert!(!device.satisfies_limits(&required.max_image_dimension2_d(8192)));
+    assert!(!device.satisfies_limits(&required.min_uniform_buffer_offset_alignment(64)));
+    assert!(!device.satisfies_limits(&required.point_size_range([0.5, 32.0])));
+}
+
+#[test]
+fn boolean_limits_are_only_required_when_true() {
+    let non_strict = minimum_device_limits().strict_lines(Bool32::False);
+    let strict = minimum_device_limits().strict_lines(Bool32::True);
+    let required = PhysicalDeviceLimits::least_restrictive();
+    assert!(non_strict.satisfies_limits(&required));
+    assert!(strict.satisfies_limits(&required));
+
+    let required = required.strict_lines(Bool32::True);
+    assert!(!non_strict.satisfies_limits(&required));
+    assert!(strict.satisfies_limits(&required));
+
+    let mut merged = strict;
+    merged.merge_limits(&non_strict);
+    assert_eq!(merged.strict_lines, Bool32::False);
+}
+
+/// Encode then decode a color, checking each component against `expected` within `epsilon`
+fn round_trip_f32(format: Format, color: [f32; 4], expected: [f32; 4], epsilon: f32) -> Vec<u8> {
+    let mut texel = vec![0; format.block_size() as usize];
+    format.encode_texel_f32(color, &mut texel).unwrap();
+    let decoded = format.decode_texel_f32(&texel).unwrap();
+    for (decoded, expected) in decoded.iter().zip(expected) {
+        assert!(
+            (decoded - expected).abs() <= epsilon,
+            "{format:?}: decoded {decoded} instead of {expected}"
+        );
+    }
+    texel
+}
+
+#[test]
+fn texel_round_trips() {
+    let color = [0.25, 0.5, 0.75, 1.0];
+    let texel = round_trip_f32(Format::R8G8B8A8Srgb, color, color, 1.0 / 255.0);
+    // the sRGB transfer function is applied to the color components only
+    assert_eq!(texel, [137, 188, 225, 255]);
+
+    round_trip_f32(Format::A2B10G10R10UnormPack32, color, color, 1.0 / 1023.0);
+    let texel = round_trip_f32(Format::A8B8G8R8UnormPack32, color, color, 1.0 / 255.0);
+    assert_eq!(u32::from_ne_bytes(texel.try_into().unwrap()), 0xffbf8040);
+    round_trip_f32(
+        Format::B10G11R11UfloatPack32,
+        [1.5, 0.125, 64.0, 0.0],
+        [1.5, 0.125, 64.0, 1.0],
+        0.0,
+    );
+    round_trip_f32(
+        Format::E5B9G9R9UfloatPack32,
+        [1.0, 0.5, 0.25, 0.0],
+        [1.0, 0.5, 0.25, 1.0],
+        0.0,
+    );
+
+    let half = [1.0, -2.5, 65504.0, 0.000061035156];
+    let texel = round_trip_f32(Format::R16G16B16A16Sfloat, half, half, 0.0);
+    assert_eq!(&texel[..2], 0x3c00u16.to_ne_bytes());
+
+    let snorm = [-1.0, -0.5, 0.5, 1.0];
+    let texel = round_trip_f32(Format::R8G8B8A8Snorm, snorm, snorm, 1.0 / 127.0);
+    assert_eq!(texel, [0x81, 0xc0, 0x40, 0x7f]);
+    round_trip_f32(Format::R8Snorm, [-2.0; 4], [-1.0, 0.0, 0.0, 1.0], 0.0);
+}
+
+#[test]
+fn depth_stencil_texel_round_trips() {
+    round_trip_f32(
+        Format::D16Unorm,
+        [0.5; 4],
+        [0.5, 0.0, 0.0, 1.0],
+        1.0 / 65535.0,
+    );
+    round_trip_f32(Format::D32Sfloat, [0.3; 4], [0.3, 0.0, 0.0, 1.0], 0.0);
+
+    // combined formats use the layout of the accessed aspect
+    let texel = round_trip_f32(Format::D24UnormS8Uint, [1.0; 4], [1.0, 0.0, 0.0, 1.0], 0.0);
+    assert_eq!(u32::from_ne_bytes(texel.try_into().unwrap()), 0x00ff_ffff);
+    round_trip_f32(
+        Format::D32SfloatS8Uint,
+        [0.75; 4],
+        [0.75, 0.0, 0.0, 1.0],
+        0.0,
+    );
+    for format in [
+        Format::D16UnormS8Uint,
+        Format::D24UnormS8Uint,
+        Format::D32SfloatS8Uint,
+    ] {
+        let mut stencil = [0; 1];
+        format
+            .encode_texel_u32([300, 0, 0, 0], &mut stencil)
+            .unwrap();
+        assert_eq!(stencil, [255]);
+        assert_eq!(format.decode_texel_u32(&stencil), Ok([255, 0, 0, 1]));
+    }
+}
+
+#[test]
+fn unsupported_texels_are_errors() {
+    let mut texel = [0; 16];
+    for format in [
+        Format::Bc1RgbUnormBlock,
+        Format::G8B8G8R8422Unorm,
+        Format::G8B8R83Plane420Unorm,
+    ] {
+        assert_eq!(
+            format.encode_texel_f32([0.0; 4], &mut texel),
+            Err(TexelError::NoTexelLayout(format))
+        );
+        assert_eq!(
+            format.decode_texel_f32(&texel),
+            Err(TexelError::NoTexelLayout(format))
+        );
+    }
+    assert_eq!(
+        Format::R8Uint.decode_texel_f32(&texel),
+        Err(TexelError::NumericFormatMismatch(Format::R8Uint))
+    );
+    assert_eq!(
+        Format::D32Sfloat.decode_texel_u32(&texel),
+        Err(TexelError::NumericFormatMismatch(Format::D32Sfloat))
+    );
+    assert_eq!(
+        Format::R32G32Sfloat.decode_texel_f32(&texel[..4]),
+        Err(TexelError::TexelTooSmall {
+            expected: 8,
+            actual: 4
+        })
+    );
+}
+
+#[test]
+fn structure_chain_vec_accesses_the_requested_structure() {
+    use crate::{Header, StructureChain, StructureChainVec};
+
+    let mut chain = StructureChainVec::new(PhysicalDeviceFeatures2::default());
+    chain.push(PhysicalDeviceVulkan11Features::default().multiview(true));
+    chain.push(PhysicalDeviceVulkan12Features::default().timeline_semaphore(true));
+    let chain_types = |chain: &StructureChainVec<PhysicalDeviceFeatures2>| {
+        Header::of(chain.as_ref())
+            .iter()
+            .map(|header| header.structure_type())
+            .collect::<Vec<_>>()
+    };
+
+    // the head is returned as itself and not as the whole chain
+    chain
+        .get_mut::<PhysicalDeviceFeatures2>()
+        .features
+        .geometry_shader = Bool32::True;
+    assert_eq!(chain.as_ref().features.geometry_shader, Bool32::True);
+    assert_eq!(
+        chain.get::<PhysicalDeviceFeatures2>().s_type,
+        StructureType::PhysicalDeviceFeatures2
+    );
+
+    // structures are looked up using their own type, not the one of the head
+    assert_eq!(
+        chain
+            .get::<PhysicalDeviceVulkan12Features>()
+            .timeline_semaphore,
+        Bool32::True
+    );
+    assert_eq!(
+        chain.get::<PhysicalDeviceVulkan11Features>().multiview,
+        Bool32::True
+    );
+    chain
+        .get_mut::<PhysicalDeviceVulkan12Features>()
+        .timeline_semaphore = Bool32::False;
+    assert_eq!(
+        chain
+            .get::<PhysicalDeviceVulkan12Features>()
+            .timeline_semaphore,
+        Bool32::False
+    );
+    assert_eq!(
+        chain.get::<PhysicalDeviceVulkan11Features>().multiview,
+        Bool32::True
+    );
+
+    chain.unlink::<PhysicalDeviceVulkan12Features>();
+    assert_eq!(
+        chain_types(&chain),
+        [
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::PhysicalDeviceVulkan11Features
+        ]
+    );
+    chain.link::<PhysicalDeviceVulkan12Features>();
+    assert_eq!(
+        chain_types(&chain),
+        [
+            StructureType::PhysicalDeviceFeatures2,
+            StructureType::PhysicalDeviceVulkan12Features,
+            StructureType::PhysicalDeviceVulkan11Features
+        ]
+    );
+}
+
+#[test]
+fn srgb_counterparts_round_trip() {
+    for srgb in [
+        Format::R8Srgb,
+        Format::R8G8B8A8Srgb,
+        Format::B8G8R8A8Srgb,
+        Format::A8B8G8R8SrgbPack32,
+        Format::Bc1RgbaSrgbBlock,
+        Format::Bc7SrgbBlock,
+        Format::Etc2R8G8B8A8SrgbBlock,
+        Format::Astc8x8SrgbBlock,
+    ] {
+        let linear = srgb.linear_counterpart().unwrap();
+        assert_eq!(linear.srgb_counterpart(), Some(srgb));
+        // both formats can be used by the views of a single mutable format image
+        assert_eq!(linear.compatibility_class(), srgb.compatibility_class());
+        assert!(linear.is_compatible_with(srgb));
+        assert!(linear.is_size_compatible_with(srgb));
+    }
+
+    assert_eq!(Format::R8G8B8A8Snorm.srgb_counterpart(), None);
+    assert_eq!(Format::R16Unorm.srgb_counterpart(), None);
+    assert_eq!(Format::R8G8B8A8Unorm.linear_counterpart(), None);
+}
+
+#[test]
+fn compatibility_classes() {
+    assert_eq!(
+        Format::R8G8B8A8Unorm.compatibility_class(),
+        CompatibilityClass::Bit32
+    );
+    assert!(Format::R8G8B8A8Unorm.is_compatible_with(Format::R32Sfloat));
+    assert!(Format::B10G11R11UfloatPack32.is_compatible_with(Format::R16G16Uint));
+    assert!(!Format::R8G8B8A8Unorm.is_compatible_with(Format::R16G16B16A16Sfloat));
+
+    // depth formats and block-compressed formats have their own classes, even with matching sizes
+    assert_eq!(
+        Format::D32Sfloat.compatibility_class(),
+        CompatibilityClass::D32
+    );
+    assert!(!Format::D32Sfloat.is_compatible_with(Format::R32Sfloat));
+    assert!(Format::D32Sfloat.is_size_compatible_with(Format::R32Sfloat));
+    assert_eq!(
+        Format::Bc1RgbUnormBlock.compatibility_class(),
+        CompatibilityClass::Bc1Rgb
+    );
+    assert!(!Format::Bc1RgbUnormBlock.is_compatible_with(Format::Bc1RgbaUnormBlock));
+    assert!(Format::Bc1RgbUnormBlock.is_size_compatible_with(Format::R16G16B16A16Sfloat));
+}
+
+#[test]
+fn spirv_image_formats_round_trip() {
+    use SpirvImageFormat::*;
+
+    let formats = [
+        R8,
+        R8Snorm,
+        R8ui,
+        R8i,
+        Rg8,
+        Rg8Snorm,
+        Rg8ui,
+        Rg8i,
+        Rgba8,
+        Rgba8Snorm,
+        Rgba8ui,
+        Rgba8i,
+        Rgb10A2,
+        Rgb10a2ui,
+        R16,
+        R16Snorm,
+        R16ui,
+        R16i,
+        R16f,
+        Rg16,
+        Rg16Snorm,
+        Rg16ui,
+        Rg16i,
+        Rg16f,
+        Rgba16,
+        Rgba16Snorm,
+        Rgba16ui,
+        Rgba16i,
+        Rgba16f,
+        R32ui,
+        R32i,
+        R32f,
+        Rg32ui,
+        Rg32i,
+        Rg32f,
+        Rgba32ui,
+        Rgba32i,
+        Rgba32f,
+        R64ui,
+        R64i,
+        R11fG11fB10f,
+    ];
+    for spirv_format in formats {
+        assert_eq!(
+            SpirvImageFormat::from_name(spirv_format.name()),
+            Some(spirv_format)
+        );
+        assert_eq!(
+            spirv_format.format().spirv_image_format(),
+            Some(spirv_format)
+        );
+    }
+
+    assert_eq!(Rgba8.format(), Format::R8G8B8A8Unorm);
+    assert_eq!(R11fG11fB10f.format(), Format::B10G11R11UfloatPack32);
+    // sRGB and depth formats cannot be used with storage images
+    assert_eq!(Format::R8G8B8A8Srgb.spirv_image_format(), None);
+    assert_eq!(Format::D32Sfloat.spirv_image_format(), None);
+    assert_eq!(SpirvImageFormat::from_name("Unknown"), None);
+}
+
+#[test]
+fn spirv_capability_requirements() {
+    let capability = SpirvCapability::from_name("Float64").unwrap();
+    assert_eq!(capability, SpirvCapability::Float64);
+    assert_eq!(capability.name(), "Float64");
+
+    let [SpirvRequirement::Feature(feature)] = capability.requirements() else {
+        panic!("Float64 should only require the shaderFloat64 feature");
+    };
+    assert_eq!(feature.feature, "shaderFloat64");
+    let mut features = PhysicalDeviceFeatures2::default();
+    assert_eq!(feature.is_enabled_in(&features), Some(false));
+    features.features.shader_float64 = Bool32::True;
+    assert_eq!(feature.is_enabled_in(&features), Some(true));
+    // the feature is not part of other structures
+    assert_eq!(
+        feature.is_enabled_in(&PhysicalDeviceVulkan12Features::default()),
+        None
+    );
+
+    let extension = SpirvExtension::from_name("SPV_KHR_variable_pointers").unwrap();
+    assert_eq!(extension.name(), "SPV_KHR_variable_pointers");
+    assert!(extension
+        .requirements()
+        .contains(&SpirvRequirement::Extension(KHR_VARIABLE_POINTERS)));
+}
+
+fn barrier(
+    src_stages: PipelineStageFlags2,
+    src_access: AccessFlags2,
+    dst_stages: PipelineStageFlags2,
+    dst_access: AccessFlags2,
+) -> MemoryBarrier2<'static> {
+    MemoryBarrier2::default()
+        .src_stage_mask(src_stages)
+        .src_access_mask(src_access)
+        .dst_stage_mask(dst_stages)
+        .dst_access_mask(dst_access)
+}
+
+#[test]
+fn valid_barriers() {
+    let render_to_sample = barrier(
+        PipelineStageFlags2::ColorAttachmentOutput,
+        AccessFlags2::ColorAttachmentWrite,
+        PipelineStageFlags2::FragmentShader,
+        AccessFlags2::ShaderSampledRead,
+    );
+    assert_eq!(
+        validate_barrier(&render_to_sample, QueueFlags::Graphics),
+        Ok(())
+    );
+
+    let copy_to_compute = barrier(
+        PipelineStageFlags2::Copy,
+        AccessFlags2::TransferWrite,
+        PipelineStageFlags2::ComputeShader,
+        AccessFlags2::ShaderStorageRead,
+    );
+    assert_eq!(
+        validate_barrier(&copy_to_compute, QueueFlags::Compute),
+        Ok(())
+    );
+}
+
+#[test]
+fn barrier_stage_unsupported_by_queue() {
+    let barrier = barrier(
+        PipelineStageFlags2::Copy,
+        AccessFlags2::TransferWrite,
+        PipelineStageFlags2::FragmentShader,
+        AccessFlags2::ShaderSampledRead,
+    );
+    assert_eq!(
+        validate_barrier(&barrier, QueueFlags::Transfer),
+        Err(BarrierError::UnsupportedStages(
+            PipelineStageFlags2::FragmentShader
+        ))
+    );
+    assert_eq!(validate_barrier(&barrier, QueueFlags::Graphics), Ok(()));
+}
+
+#[test]
+fn barrier_access_on_wrong_stage() {
+    let barrier = barrier(
+        PipelineStageFlags2::Copy,
+        AccessFlags2::ColorAttachmentWrite,
+        PipelineStageFlags2::FragmentShader,
+        AccessFlags2::ShaderSampledRead,
+    );
+    assert_eq!(
+        validate_barrier(&barrier, QueueFlags::Graphics),
+        Err(BarrierError::UnsupportedAccess {
+            access: AccessFlags2::ColorAttachmentWrite,
+            stages: PipelineStageFlags2::Copy,
+        })
+    );
+}
+
+#[test]
+fn barrier_meta_stages_are_expanded() {
+    let expanded = PipelineStageFlags2::AllGraphics.expand_meta_stages();
+    assert!(expanded
+        .contains(PipelineStageFlags2::FragmentShader | PipelineStageFlags2::VertexAttributeInput));
+    assert!(!expanded.contains(PipelineStageFlags2::ComputeShader));
+    assert!(PipelineStageFlags2::AllTransfer
+        .expand_meta_stages()
+        .contains(PipelineStageFlags2::Copy | PipelineStageFlags2::Blit));
+
+    let graphics_to_transfer = barrier(
+        PipelineStageFlags2::AllGraphics,
+        AccessFlags2::ColorAttachmentWrite | AccessFlags2::ShaderWrite,
+        PipelineStageFlags2::AllTransfer,
+        AccessFlags2::TransferRead,
+    );
+    assert_eq!(
+        validate_barrier(&graphics_to_transfer, QueueFlags::Graphics),
+        Ok(())
+    );
+
+    // the transfer stages do not access shader resources
+    let transfer_shader_read = barrier(
+        PipelineStageFlags2::AllTransfer,
+        AccessFlags2::TransferWrite,
+        PipelineStageFlags2::AllTransfer,
+        AccessFlags2::ShaderRead,
+    );
+    assert_eq!(
+        validate_barrier(&transfer_shader_read, QueueFlags::Graphics),
+        Err(BarrierError::UnsupportedAccess {
+            access: AccessFlags2::ShaderRead,
+            stages: PipelineStageFlags2::AllTransfer,
+        })
+    );
+}
+
+#[test]
+fn barrier_all_commands_accepts_any_access() {
+    let barrier = barrier(
+        PipelineStageFlags2::AllCommands,
+        AccessFlags2::ColorAttachmentWrite | AccessFlags2::TransferWrite,
+        PipelineStageFlags2::AllCommands,
+        AccessFlags2::MemoryRead,
+    );
+    assert_eq!(validate_barrier(&barrier, QueueFlags::Graphics), Ok(()));
+    assert_eq!(validate_barrier(&barrier, QueueFlags::Transfer), Ok(()));
+}
@@ -0,0 +1,404 @@
+//! CPU-side conversion between colors and texels of uncompressed formats, using the texel layouts
+//! generated from the registry.
+//!
+//! Combined depth/stencil formats are accessed one aspect at a time, with the layout used when copying
+//! this aspect between an image and a buffer: the float functions access the depth aspect (stored like
+//! [Format::D16Unorm], [Format::X8D24UnormPack32] or [Format::D32Sfloat]) and the integer functions
+//! the stencil aspect (stored like [Format::S8Uint]).
+//! Compressed, multi-planar and subsampled (422) formats are not supported
+use std::fmt;
+
+use super::{ComponentName, Format, NumericFormat, TexelComponent};
+
+/// Position of the shared exponent of [Format::E5B9G9R9UfloatPack32]
+const SHARED_EXPONENT_SHIFT: u8 = 27;
+
+/// Reason why a texel cannot be encoded or decoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TexelError {
+    /// This format is compressed, multi-planar or subsampled and has no texel layout
+    NoTexelLayout(Format),
+    /// This integer format is accessed as floats, or this non-integer format as integers
+    NumericFormatMismatch(Format),
+    /// The texel is smaller than the size of a texel of the format (or of the accessed aspect)
+    TexelTooSmall { expected: usize, actual: usize },
+}
+
+impl fmt::Display for TexelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoTexelLayout(format) => write!(f, "{format:?} has no texel layout"),
+            Self::NumericFormatMismatch(format) => {
+                write!(
+                    f,
+                    "the numeric format of {format:?} does not match the color type"
+                )
+            }
+            Self::TexelTooSmall { expected, actual } => {
+                write!(
+                    f,
+                    "texel of {actual} bytes, at least {expected} bytes are needed"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TexelError {}
+
+impl Format {
+    /// Encode a color into a texel of this format, `texel` must be at least [Format::block_size] bytes long
+    /// (the size of the depth aspect for combined depth/stencil formats).
+    /// The color is given in the RGBA order, the depth being the first component, and each component
+    /// is converted following the rules of the Vulkan specification for its numeric format.
+    /// Fails if this format has no texel layout (see [Format::texel_layout]) or if it is an integer format
+    /// (use [Format::encode_texel_u32] instead)
+    pub fn encode_texel_f32(self, color: [f32; 4], texel: &mut [u8]) -> Result<(), TexelError> {
+        let (format, layout) = self.checked_texel_layout(false)?;
+        let size = checked_texel_size(format, texel.len())?;
+        let texel = &mut texel[..size];
+        texel.fill(0);
+
+        if matches!(format, Format::E5B9G9R9UfloatPack32) {
+            let (mantissas, exponent) = encode_shared_exponent(color);
+            for component in layout {
+                let value = mantissas[component.channel()];
+                write_bits(texel, component, component.shift, component.bits, value);
+            }
+            write_bits(texel, &layout[0], SHARED_EXPONENT_SHIFT, 5, exponent);
+            return Ok(());
+        }
+
+        for component in layout {
+            let value = encode_float(component, color[component.channel()]);
+            write_bits(texel, component, component.shift, component.bits, value);
+        }
+        Ok(())
+    }
+
+    /// Decode a texel of this format into a color in the RGBA order, the depth being the first component.
+    /// Missing color components are set to 0, except for the alpha component which is set to 1.
+    /// Fails if this format has no texel layout (see [Format::texel_layout]), if it is an integer format
+    /// (use [Format::decode_texel_u32] instead) or if `texel` is too small
+    pub fn decode_texel_f32(self, texel: &[u8]) -> Result<[f32; 4], TexelError> {
+        let (format, layout) = self.checked_texel_layout(false)?;
+        let size = checked_texel_size(format, texel.len())?;
+        let texel = &texel[..size];
+        let mut color = [0.0, 0.0, 0.0, 1.0];
+
+        if matches!(format, Format::E5B9G9R9UfloatPack32) {
+            let exponent = read_bits(texel, &layout[0], SHARED_EXPONENT_SHIFT, 5) as i32;
+            for component in layout {
+                let mantissa = read_bits(texel, component, component.shift, component.bits);
+                color[component.channel()] = mantissa as f32 * 2f32.powi(exponent - 15 - 9);
+            }
+            return Ok(color);
+        }
+
+        for component in layout {
+            let value = read_bits(texel, component, component.shift, component.bits);
+            color[component.channel()] = decode_float(component, value);
+        }
+        Ok(color)
+    }
+
+    /// Encode an integer color into a texel of this integer format, `texel` must be at least [Format::block_size] bytes long
+    /// (the size of the stencil aspect for combined depth/stencil formats).
+    /// The color is given in the RGBA order, the stencil being the first component.
+    /// Values of signed formats are given as the bits of an i32 and values which do not fit in a component are clamped.
+    /// Fails if this format has no texel layout (see [Format::texel_layout]) or if it is not an integer format
+    /// (use [Format::encode_texel_f32] instead)
+    pub fn encode_texel_u32(self, color: [u32; 4], texel: &mut [u8]) -> Result<(), TexelError> {
+        let (format, layout) = self.checked_texel_layout(true)?;
+        let size = checked_texel_size(format, texel.len())?;
+        let texel = &mut texel[..size];
+        texel.fill(0);
+
+        for component in layout {
+            let value = color[component.channel()];
+            let value = match component.numeric_format {
+                NumericFormat::Sint => {
+                    let max = max_signed(component.bits);
+                    (value as i32 as i64).clamp(-max - 1, max) as u64
+                }
+                _ => (value as u64).min(mask(component.bits)),
+            };
+            write_bits(texel, component, component.shift, component.bits, value);
+        }
+        Ok(())
+    }
+
+    /// Decode a texel of this integer format into a color in the RGBA order, the stencil being the first component.
+    /// Values of signed formats are returned as the bits of an i32 and 64-bit values are clamped to 32 bits.
+    /// Missing color components are set to 0, except for the alpha component which is set to 1.
+    /// Fails if this format has no texel layout (see [Format::texel_layout]), if it is not an integer format
+    /// (use [Format::decode_texel_f32] instead) or if `texel` is too small
+    pub fn decode_texel_u32(self, texel: &[u8]) -> Result<[u32; 4], TexelError> {
+        let (format, layout) = self.checked_texel_layout(true)?;
+        let size = checked_texel_size(format, texel.len())?;
+        let texel = &texel[..size];
+        let mut color = [0, 0, 0, 1];
+
+        for component in layout {
+            let value = read_bits(texel, component, component.shift, component.bits);
+            color[component.channel()] = match component.numeric_format {
+                NumericFormat::Sint => sign_extend(value, component.bits)
+                    .clamp(i32::MIN as i64, i32::MAX as i64)
+                    as i32 as u32,
+                _ => value.min(u32::MAX as u64) as u32,
+            };
+        }
+        Ok(color)
+    }
+
+    /// Format of the aspect accessed by the float (depth) or integer (stencil) functions
+    /// for combined depth/stencil formats, this format otherwise
+    fn texel_aspect(self, integer: bool) -> Format {
+        match (self, integer) {
+            (Format::D16UnormS8Uint, false) => Format::D16Unorm,
+            (Format::D24UnormS8Uint, false) => Format::X8D24UnormPack32,
+            (Format::D32SfloatS8Uint, false) => Format::D32Sfloat,
+            (Format::D16UnormS8Uint | Format::D24UnormS8Uint | Format::D32SfloatS8Uint, true) => {
+                Format::S8Uint
+            }
+            _ => self,
+        }
+    }
+
+    fn checked_texel_layout(
+        self,
+        integer: bool,
+    ) -> Result<(Format, &'static [TexelComponent]), TexelError> {
+        let format = self.texel_aspect(integer);
+        let layout = format
+            .texel_layout()
+            .ok_or(TexelError::NoTexelLayout(self))?;
+        let is_integer = matches!(
+            layout[0].numeric_format,
+            NumericFormat::Uint | NumericFormat::Sint
+        );
+        if integer != is_integer {
+            return Err(TexelError::NumericFormatMismatch(self));
+        }
+        Ok((format, layout))
+    }
+}
+
+/// Return the size of a texel of `format`, checking that it fits in a texel of `actual` bytes
+fn checked_texel_size(format: Format, actual: usize) -> Result<usize, TexelError> {
+    let expected = format.block_size() as usize;
+    if actual < expected {
+        return Err(TexelError::TexelTooSmall { expected, actual });
+    }
+    Ok(expected)
+}
+
+impl TexelComponent {
+    /// Index of this component in a RGBA color
+    fn channel(&self) -> usize {
+        match self.name {
+            ComponentName::R | ComponentName::D | ComponentName::S => 0,
+            ComponentName::G => 1,
+            ComponentName::B => 2,
+            ComponentName::A => 3,
+        }
+    }
+}
+
+fn mask(bits: u8) -> u64 {
+    u64::MAX >> (64 - bits as u32)
+}
+
+fn max_signed(bits: u8) -> i64 {
+    (mask(bits) >> 1) as i64
+}
+
+fn sign_extend(value: u64, bits: u8) -> i64 {
+    let unused_bits = 64 - bits as u32;
+    ((value << unused_bits) as i64) >> unused_bits
+}
+
+fn read_bits(texel: &[u8], component: &TexelComponent, shift: u8, bits: u8) -> u64 {
+    let element = &texel[component.offset as usize..][..component.size as usize];
+    let element = match *element {
+        [a] => a as u64,
+        [a, b] => u16::from_ne_bytes([a, b]) as u64,
+        [a, b, c, d] => u32::from_ne_bytes([a, b, c, d]) as u64,
+        _ => u64::from_ne_bytes(element.try_into().unwrap()),
+    };
+    (element >> shift) & mask(bits)
+}
+
+fn write_bits(texel: &mut [u8], component: &TexelComponent, shift: u8, bits: u8, value: u64) {
+    let element = read_bits(texel, component, 0, component.size * 8);
+    let element = element | ((value & mask(bits)) << shift);
+    let bytes = element.to_ne_bytes();
+    // the element is in the least significant bytes of the u64
+    let bytes = if cfg!(target_endian = "little") {
+        &bytes[..component.size as usize]
+    } else {
+        &bytes[8 - component.size as usize..]
+    };
+    texel[component.offset as usize..][..component.size as usize].copy_from_slice(bytes);
+}
+
+fn encode_float(component: &TexelComponent, value: f32) -> u64 {
+    let bits = component.bits;
+    let value = value as f64;
+    // NaN values are converted to 0 by the saturating float to int casts
+    match component.numeric_format {
+        NumericFormat::Unorm => (value.clamp(0.0, 1.0) * mask(bits) as f64).round() as u64,
+        NumericFormat::Srgb => {
+            (linear_to_srgb(value).clamp(0.0, 1.0) * mask(bits) as f64).round() as u64
+        }
+        NumericFormat::Snorm => {
+            let max = max_signed(bits) as f64;
+            ((value.clamp(-1.0, 1.0) * max).round() as i64) as u64 & mask(bits)
+        }
+        NumericFormat::Uscaled => value.round().clamp(0.0, mask(bits) as f64) as u64,
+        NumericFormat::Sscaled => {
+            let max = max_signed(bits) as f64;
+            (value.round().clamp(-max - 1.0, max) as i64) as u64 & mask(bits)
+        }
+        NumericFormat::Sfixed5 => {
+            let max = max_signed(bits) as f64;
+            ((value * 32.0).round().clamp(-max - 1.0, max) as i64) as u64 & mask(bits)
+        }
+        NumericFormat::Sfloat => match bits {
+            16 => encode_small_float(value as f32, 10, true),
+            32 => (value as f32).to_bits() as u64,
+            _ => value.to_bits(),
+        },
+        // unsigned 10 and 11-bit floats have a 5-bit exponent
+        NumericFormat::Ufloat => encode_small_float(value as f32, bits as u32 - 5, false),
+        NumericFormat::Uint | NumericFormat::Sint => unreachable!(),
+    }
+}
+
+fn decode_float(component: &TexelComponent, value: u64) -> f32 {
+    let bits = component.bits;
+    match component.numeric_format {
+        NumericFormat::Unorm => (value as f64 / mask(bits) as f64) as f32,
+        NumericFormat::Srgb => srgb_to_linear(value as f64 / mask(bits) as f64) as f32,
+        NumericFormat::Snorm => {
+            (sign_extend(value, bits) as f64 / max_signed(bits) as f64).max(-1.0) as f32
+        }
+        NumericFormat::Uscaled => value as f32,
+        NumericFormat::Sscaled => sign_extend(value, bits) as f32,
+        NumericFormat::Sfixed5 => sign_extend(value, bits) as f32 / 32.0,
+        NumericFormat::Sfloat => match bits {
+            16 => decode_small_float(value, 10, true),
+            32 => f32::from_bits(value as u32),
+            _ => f64::from_bits(value) as f32,
+        },
+        NumericFormat::Ufloat => decode_small_float(value, bits as u32 - 5, false),
+        NumericFormat::Uint | NumericFormat::Sint => unreachable!(),
+    }
+}
+
+fn linear_to_srgb(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn srgb_to_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Shift the value to the right, rounding to the nearest even value
+fn round_shift(value: u64, shift: u32) -> u64 {
+    if shift == 0 {
+        return value;
+    } else if shift >= 64 {
+        return 0;
+    }
+    let truncated = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Convert a f32 to a float with a 5-bit exponent, used by half floats and unsigned 10 and 11-bit floats
+fn encode_small_float(value: f32, mantissa_bits: u32, signed: bool) -> u64 {
+    let bits = value.to_bits();
+    let negative = bits >> 31 == 1;
+    let infinity = 0x1f << mantissa_bits;
+    if value.is_nan() {
+        return infinity | (1 << (mantissa_bits - 1));
+    } else if negative && !signed {
+        return 0;
+    }
+    let sign = if negative {
+        1 << (mantissa_bits + 5)
+    } else {
+        0
+    };
+
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | infinity;
+    }
+    let mantissa = (bits & 0x7f_ffff) as u64;
+    let magnitude = if exponent <= 0 {
+        // denormalized value, the implicit leading bit becomes explicit
+        round_shift(
+            mantissa | 0x80_0000,
+            23 - mantissa_bits + (1 - exponent) as u32,
+        )
+    } else {
+        // a carry of the rounding correctly increments the exponent
+        ((exponent as u64) << mantissa_bits) + round_shift(mantissa, 23 - mantissa_bits)
+    };
+    sign | magnitude
+}
+
+fn decode_small_float(value: u64, mantissa_bits: u32, signed: bool) -> f32 {
+    let exponent = ((value >> mantissa_bits) & 0x1f) as i32;
+    let mantissa = (value & ((1 << mantissa_bits) - 1)) as f32;
+    let magnitude = match exponent {
+        0 => mantissa * 2f32.powi(-14 - mantissa_bits as i32),
+        0x1f if mantissa == 0.0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => (1.0 + mantissa / (1 << mantissa_bits) as f32) * 2f32.powi(exponent - 15),
+    };
+    if signed && (value >> (mantissa_bits + 5)) & 1 == 1 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Return the mantissas of the RGB components and the shared exponent of an E5B9G9R9 texel,
+/// as described in the Shared Exponent section of the Vulkan specification
+fn encode_shared_exponent(color: [f32; 4]) -> ([u64; 3], u64) {
+    const MANTISSA_BITS: i32 = 9;
+    const BIAS: i32 = 15;
+    const MAX_EXPONENT: i32 = 31;
+
+    let max_value = ((1 << MANTISSA_BITS) - 1) as f32 / (1 << MANTISSA_BITS) as f32
+        * 2f32.powi(MAX_EXPONENT - BIAS);
+    // this also turns NaN values into 0
+    let rgb = [color[0], color[1], color[2]].map(|c| if c > 0.0 { c.min(max_value) } else { 0.0 });
+    let max_rgb = rgb[0].max(rgb[1]).max(rgb[2]);
+
+    // log2(0) is -inf, which saturates to i32::MIN
+    let mut exponent = (max_rgb.log2().floor() as i32).max(-BIAS - 1) + 1 + BIAS;
+    let max_mantissa = (max_rgb / 2f32.powi(exponent - BIAS - MANTISSA_BITS) + 0.5).floor();
+    if max_mantissa as i32 == 1 << MANTISSA_BITS {
+        exponent += 1;
+    }
+    let mantissas =
+        rgb.map(|c| (c / 2f32.powi(exponent - BIAS - MANTISSA_BITS) + 0.5).floor() as u64);
+    (mantissas, exponent as u64)
+}
@@ -0,0 +1,397 @@
+//! Command buffers whose type tracks what can be recorded in them
+//!
+//! A [CommandBuffer] of this module is a [vk::rs::CommandBuffer] with three additional type parameters:
+//! - the capabilities of the queue family it was allocated for ([Graphics], [Compute], [Transfer] or [GraphicsCompute])
+//! - its level ([Primary] or [Secondary])
+//! - its state: [Initial], [Executable] or recording, in which case it also tracks whether it is recording inside a
+//!   render pass instance ([OutsideRenderPass], [InsideRenderPass] or [InsideRendering])
+//!
+//! Commands can only be recorded while the command buffer is recording, and each `cmd_*` method is only available
+//! when it is valid for the command buffer state according to the Vulkan specification.
+//! Beginning, ending, resetting or changing the render pass instance of the command buffer consumes it and returns it with its new state:
+//! ```ignore
+//! let cmd_buffer: CommandBuffer<Graphics, Primary, Initial> =
+//!     unsafe { CommandBuffer::from_untyped(cmd_buffer) };
+//! let cmd_buffer = cmd_buffer.begin(&Default::default())?;
+//! let cmd_buffer = cmd_buffer.begin_rendering(&rendering_info);
+//! cmd_buffer.bind_pipeline(vk::PipelineBindPoint::Graphics, &pipeline);
+//! cmd_buffer.draw(3, 1, 0, 0);
+//! // cmd_buffer.dispatch(1, 1, 1) would not compile: dispatch is not allowed inside a render pass
+//! let cmd_buffer = cmd_buffer.end_rendering();
+//! let cmd_buffer: CommandBuffer<Graphics, Primary, Executable> = cmd_buffer.end()?;
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::{vk, Allocator, DefaultAllocator, Dispatcher, DynamicDispatcher};
+
+mod commands;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Capabilities of the queue family a command buffer was allocated for
+pub trait QueueType: private::Sealed {
+    const FLAGS: vk::QueueFlags;
+}
+
+/// Queue types supporting graphics commands
+pub trait SupportsGraphics: QueueType {}
+/// Queue types supporting compute commands
+pub trait SupportsCompute: QueueType {}
+/// Queue types supporting transfer commands
+pub trait SupportsTransfer: QueueType {}
+/// Queue types supporting graphics or compute commands
+pub trait SupportsGraphicsOrCompute: QueueType {}
+/// Queue types supporting graphics or transfer commands
+pub trait SupportsGraphicsOrTransfer: QueueType {}
+/// Queue types supporting compute or transfer commands
+pub trait SupportsComputeOrTransfer: QueueType {}
+
+/// Queue family supporting graphics (and therefore transfer) commands
+pub enum Graphics {}
+/// Queue family supporting compute (and therefore transfer) commands
+pub enum Compute {}
+/// Queue family only supporting transfer commands
+pub enum Transfer {}
+/// Queue family supporting both graphics and compute commands, this is the case of most graphics queue families
+pub enum GraphicsCompute {}
+
+macro_rules! impl_queue_type {
+    ($name:ident, $flags:expr, [$($supports:ident),*]) => {
+        impl private::Sealed for $name {}
+        impl QueueType for $name {
+            const FLAGS: vk::QueueFlags = $flags;
+        }
+        $(impl $supports for $name {})*
+    };
+}
+
+// queue families supporting graphics or compute operations implicitly support transfer operations
+impl_queue_type!(
+    Graphics,
+    vk::QueueFlags::Graphics,
+    [
+        SupportsGraphics,
+        SupportsTransfer,
+        SupportsGraphicsOrCompute,
+        SupportsGraphicsOrTransfer,
+        SupportsComputeOrTransfer
+    ]
+);
+impl_queue_type!(
+    Compute,
+    vk::QueueFlags::Compute,
+    [
+        SupportsCompute,
+        SupportsTransfer,
+        SupportsGraphicsOrCompute,
+        SupportsGraphicsOrTransfer,
+        SupportsComputeOrTransfer
+    ]
+);
+impl_queue_type!(
+    Transfer,
+    vk::QueueFlags::Transfer,
+    [
+        SupportsTransfer,
+        SupportsGraphicsOrTransfer,
+        SupportsComputeOrTransfer
+    ]
+);
+impl_queue_type!(
+    GraphicsCompute,
+    vk::QueueFlags::Graphics.union(vk::QueueFlags::Compute),
+    [
+        SupportsGraphics,
+        SupportsCompute,
+        SupportsTransfer,
+        SupportsGraphicsOrCompute,
+        SupportsGraphicsOrTransfer,
+        SupportsComputeOrTransfer
+    ]
+);
+
+/// Level of a command buffer
+pub trait Level: private::Sealed {
+    const LEVEL: vk::CommandBufferLevel;
+}
+
+pub enum Primary {}
+pub enum Secondary {}
+
+impl private::Sealed for Primary {}
+impl Level for Primary {
+    const LEVEL: vk::CommandBufferLevel = vk::CommandBufferLevel::Primary;
+}
+impl private::Sealed for Secondary {}
+impl Level for Secondary {
+    const LEVEL: vk::CommandBufferLevel = vk::CommandBufferLevel::Secondary;
+}
+
+/// State of a command buffer
+pub trait CommandBufferState: private::Sealed {}
+/// Recording states, which track whether the command buffer is recording inside a render pass instance
+pub trait RenderPassState: CommandBufferState {}
+/// States inside a render pass instance, begun either with a render pass object or with dynamic rendering
+pub trait InsideRenderPassState: RenderPassState {}
+
+/// The command buffer has just been allocated or reset, it must be begun before recording commands
+pub enum Initial {}
+/// The command buffer has been ended and can be submitted
+pub enum Executable {}
+/// The command buffer is recording outside of any render pass instance
+pub enum OutsideRenderPass {}
+/// The command buffer is recording inside a render pass instance begun with [CommandBuffer::begin_render_pass]
+/// or a secondary command buffer begun with [vk::CommandBufferUsageFlags::RenderPassContinue]
+pub enum InsideRenderPass {}
+/// The command buffer is recording inside a render pass instance begun with [CommandBuffer::begin_rendering]
+/// or a secondary command buffer inheriting a dynamic render pass instance
+pub enum InsideRendering {}
+
+impl private::Sealed for Initial {}
+impl CommandBufferState for Initial {}
+impl private::Sealed for Executable {}
+impl CommandBufferState for Executable {}
+impl private::Sealed for OutsideRenderPass {}
+impl CommandBufferState for OutsideRenderPass {}
+impl RenderPassState for OutsideRenderPass {}
+impl private::Sealed for InsideRenderPass {}
+impl CommandBufferState for InsideRenderPass {}
+impl RenderPassState for InsideRenderPass {}
+impl InsideRenderPassState for InsideRenderPass {}
+impl private::Sealed for InsideRendering {}
+impl CommandBufferState for InsideRendering {}
+impl RenderPassState for InsideRendering {}
+impl InsideRenderPassState for InsideRendering {}
+
+/// Command buffer only exposing the commands valid in its current state, see the [module level documentation](self)
+/// Contrary to [vk::rs::CommandBuffer], this type cannot be copied or cloned as this would allow keeping a command buffer
+/// in a state it is no longer in
+pub struct CommandBuffer<
+    Q: QueueType,
+    L: Level,
+    S: CommandBufferState,
+    D: Dispatcher = DynamicDispatcher,
+    A: Allocator = DefaultAllocator,
+> {
+    inner: vk::rs::CommandBuffer<D, A>,
+    phantom: PhantomData<(Q, L, S)>,
+}
+
+impl<Q: QueueType, L: Level, S: CommandBufferState, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, S, D, A>
+{
+    /// Wrap an untyped command buffer
+    /// # Safety
+    /// The command buffer must have been allocated with level `L` from a command pool whose queue family
+    /// supports the operations of `Q`
+    /// `S` must match the state of the command buffer and, if it is recording, the render pass instance it is recording in
+    pub unsafe fn from_untyped(command_buffer: vk::rs::CommandBuffer<D, A>) -> Self {
+        Self {
+            inner: command_buffer,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn untyped(&self) -> &vk::rs::CommandBuffer<D, A> {
+        &self.inner
+    }
+
+    pub fn into_untyped(self) -> vk::rs::CommandBuffer<D, A> {
+        self.inner
+    }
+
+    /// Reset the command buffer, it goes back to its initial state
+    /// The command pool it was allocated from must have been created with [vk::CommandPoolCreateFlags::ResetCommandBuffer]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandBuffer.html>"]
+    #[doc(alias = "vkResetCommandBuffer")]
+    pub fn reset(
+        self,
+        flags: vk::CommandBufferResetFlags,
+    ) -> vk::Result<CommandBuffer<Q, L, Initial, D, A>> {
+        self.inner.reset(flags)?;
+        Ok(self.transition())
+    }
+
+    fn transition<S2: CommandBufferState>(self) -> CommandBuffer<Q, L, S2, D, A> {
+        CommandBuffer {
+            inner: self.inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Q: QueueType, L: Level, D: Dispatcher, A: Allocator> CommandBuffer<Q, L, Initial, D, A> {
+    /// Begin recording the command buffer, which is then outside of any render pass instance
+    /// A secondary command buffer recording inside a render pass instance is begun with [CommandBuffer::begin_inside]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html>"]
+    #[doc(alias = "vkBeginCommandBuffer")]
+    pub fn begin(
+        self,
+        p_begin_info: &vk::CommandBufferBeginInfo,
+    ) -> vk::Result<CommandBuffer<Q, L, OutsideRenderPass, D, A>> {
+        self.inner.begin(p_begin_info)?;
+        Ok(self.transition())
+    }
+}
+
+impl<Q: QueueType, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, OutsideRenderPass, D, A>
+{
+    /// End recording the command buffer, which can then be submitted
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEndCommandBuffer.html>"]
+    #[doc(alias = "vkEndCommandBuffer")]
+    pub fn end(self) -> vk::Result<CommandBuffer<Q, L, Executable, D, A>> {
+        self.inner.end()?;
+        Ok(self.transition())
+    }
+}
+
+impl<Q: QueueType, D: Dispatcher, A: Allocator> CommandBuffer<Q, Secondary, Initial, D, A> {
+    /// Begin recording a secondary command buffer entirely inside a render pass instance
+    /// `P2` is [InsideRenderPass] if the inheritance info references a render pass object and [InsideRendering]
+    /// if it inherits a dynamic render pass instance
+    /// # Panics
+    /// Panics if the begin info does not have the [vk::CommandBufferUsageFlags::RenderPassContinue] flag
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html>"]
+    #[doc(alias = "vkBeginCommandBuffer")]
+    pub fn begin_inside<P2: InsideRenderPassState>(
+        self,
+        p_begin_info: &vk::CommandBufferBeginInfo,
+    ) -> vk::Result<CommandBuffer<Q, Secondary, P2, D, A>> {
+        assert!(
+            p_begin_info
+                .flags
+                .contains(vk::CommandBufferUsageFlags::RenderPassContinue),
+            "A secondary command buffer can only begin inside a render pass instance with the RenderPassContinue flag"
+        );
+        self.inner.begin(p_begin_info)?;
+        Ok(self.transition())
+    }
+}
+
+impl<Q: QueueType, P: InsideRenderPassState, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, Secondary, P, D, A>
+{
+    /// End a secondary command buffer recorded entirely inside a render pass instance
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEndCommandBuffer.html>"]
+    #[doc(alias = "vkEndCommandBuffer")]
+    pub fn end(self) -> vk::Result<CommandBuffer<Q, Secondary, Executable, D, A>> {
+        self.inner.end()?;
+        Ok(self.transition())
+    }
+}
+
+impl<Q: SupportsGraphics, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, Primary, OutsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderPass.html>"]
+    #[doc(alias = "vkCmdBeginRenderPass")]
+    pub fn begin_render_pass(
+        self,
+        p_render_pass_begin: &vk::RenderPassBeginInfo,
+        contents: vk::SubpassContents,
+    ) -> CommandBuffer<Q, Primary, InsideRenderPass, D, A> {
+        self.inner.begin_render_pass(p_render_pass_begin, contents);
+        self.transition()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderPass2.html>"]
+    #[doc(alias = "vkCmdBeginRenderPass2")]
+    pub fn begin_render_pass2(
+        self,
+        p_render_pass_begin: &vk::RenderPassBeginInfo,
+        p_subpass_begin_info: &vk::SubpassBeginInfo,
+    ) -> CommandBuffer<Q, Primary, InsideRenderPass, D, A> {
+        self.inner
+            .begin_render_pass2(p_render_pass_begin, p_subpass_begin_info);
+        self.transition()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderPass2KHR.html>"]
+    #[doc(alias = "vkCmdBeginRenderPass2KHR")]
+    pub fn begin_render_pass2_khr(
+        self,
+        p_render_pass_begin: &vk::RenderPassBeginInfo,
+        p_subpass_begin_info: &vk::SubpassBeginInfo,
+    ) -> CommandBuffer<Q, Primary, InsideRenderPass, D, A> {
+        self.inner
+            .begin_render_pass2_khr(p_render_pass_begin, p_subpass_begin_info);
+        self.transition()
+    }
+}
+
+impl<Q: SupportsGraphics, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, Primary, InsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass.html>"]
+    #[doc(alias = "vkCmdEndRenderPass")]
+    pub fn end_render_pass(self) -> CommandBuffer<Q, Primary, OutsideRenderPass, D, A> {
+        self.inner.end_render_pass();
+        self.transition()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass2.html>"]
+    #[doc(alias = "vkCmdEndRenderPass2")]
+    pub fn end_render_pass2(
+        self,
+        p_subpass_end_info: &vk::SubpassEndInfo,
+    ) -> CommandBuffer<Q, Primary, OutsideRenderPass, D, A> {
+        self.inner.end_render_pass2(p_subpass_end_info);
+        self.transition()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass2KHR.html>"]
+    #[doc(alias = "vkCmdEndRenderPass2KHR")]
+    pub fn end_render_pass2_khr(
+        self,
+        p_subpass_end_info: &vk::SubpassEndInfo,
+    ) -> CommandBuffer<Q, Primary, OutsideRenderPass, D, A> {
+        self.inner.end_render_pass2_khr(p_subpass_end_info);
+        self.transition()
+    }
+}
+
+impl<Q: SupportsGraphics, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, OutsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRendering.html>"]
+    #[doc(alias = "vkCmdBeginRendering")]
+    pub fn begin_rendering(
+        self,
+        p_rendering_info: &vk::RenderingInfo,
+    ) -> CommandBuffer<Q, L, InsideRendering, D, A> {
+        self.inner.begin_rendering(p_rendering_info);
+        self.transition()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderingKHR.html>"]
+    #[doc(alias = "vkCmdBeginRenderingKHR")]
+    pub fn begin_rendering_khr(
+        self,
+        p_rendering_info: &vk::RenderingInfo,
+    ) -> CommandBuffer<Q, L, InsideRendering, D, A> {
+        self.inner.begin_rendering_khr(p_rendering_info);
+        self.transition()
+    }
+}
+
+impl<Q: SupportsGraphics, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, InsideRendering, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRendering.html>"]
+    #[doc(alias = "vkCmdEndRendering")]
+    pub fn end_rendering(self) -> CommandBuffer<Q, L, OutsideRenderPass, D, A> {
+        self.inner.end_rendering();
+        self.transition()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderingKHR.html>"]
+    #[doc(alias = "vkCmdEndRenderingKHR")]
+    pub fn end_rendering_khr(self) -> CommandBuffer<Q, L, OutsideRenderPass, D, A> {
+        self.inner.end_rendering_khr();
+        self.transition()
+    }
+}
@@ -0,0 +1,2279 @@
+#![allow(clippy::too_many_arguments)]
+use crate::{vk::typed::*, vk::*, Alias, Allocator, AsSlice, Dispatcher};
+impl<Q: SupportsGraphicsOrCompute, L: Level, P: RenderPassState, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, P, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipeline.html>"]
+    #[doc(alias = "vkCmdBindPipeline")]
+    pub fn bind_pipeline(&self, pipeline_bind_point: PipelineBindPoint, pipeline: &raw::Pipeline) {
+        self.inner.bind_pipeline(pipeline_bind_point, pipeline)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorSets.html>"]
+    #[doc(alias = "vkCmdBindDescriptorSets")]
+    pub fn bind_descriptor_sets<'a, V5: Alias<raw::DescriptorSet> + 'a>(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: &raw::PipelineLayout,
+        first_set: u32,
+        p_descriptor_sets: impl AsSlice<'a, V5>,
+        p_dynamic_offsets: impl AsSlice<'a, u32>,
+    ) {
+        self.inner.bind_descriptor_sets(
+            pipeline_bind_point,
+            layout,
+            first_set,
+            p_descriptor_sets,
+            p_dynamic_offsets,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWaitEvents.html>"]
+    #[doc(alias = "vkCmdWaitEvents")]
+    pub fn wait_events<'a, V2: Alias<raw::Event> + 'a>(
+        &self,
+        p_events: impl AsSlice<'a, V2>,
+        src_stage_mask: PipelineStageFlags,
+        dst_stage_mask: PipelineStageFlags,
+        p_memory_barriers: impl AsSlice<'a, MemoryBarrier<'a>>,
+        p_buffer_memory_barriers: impl AsSlice<'a, BufferMemoryBarrier<'a>>,
+        p_image_memory_barriers: impl AsSlice<'a, ImageMemoryBarrier<'a>>,
+    ) {
+        self.inner.wait_events(
+            p_events,
+            src_stage_mask,
+            dst_stage_mask,
+            p_memory_barriers,
+            p_buffer_memory_barriers,
+            p_image_memory_barriers,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQuery.html>"]
+    #[doc(alias = "vkCmdBeginQuery")]
+    pub fn begin_query(&self, query_pool: &raw::QueryPool, query: u32, flags: QueryControlFlags) {
+        self.inner.begin_query(query_pool, query, flags)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQuery.html>"]
+    #[doc(alias = "vkCmdEndQuery")]
+    pub fn end_query(&self, query_pool: &raw::QueryPool, query: u32) {
+        self.inner.end_query(query_pool, query)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushConstants.html>"]
+    #[doc(alias = "vkCmdPushConstants")]
+    pub fn push_constants(
+        &self,
+        layout: &raw::PipelineLayout,
+        stage_flags: ShaderStageFlags,
+        offset: u32,
+        size: u32,
+        p_values: VoidPtr,
+    ) {
+        self.inner
+            .push_constants(layout, stage_flags, offset, size, p_values)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWaitEvents2.html>"]
+    #[doc(alias = "vkCmdWaitEvents2")]
+    pub fn wait_events2<'a, V2: Alias<raw::Event> + 'a>(
+        &self,
+        p_events: impl AsSlice<'a, V2>,
+        p_dependency_infos: impl AsSlice<'a, DependencyInfo<'a>>,
+    ) {
+        self.inner.wait_events2(p_events, p_dependency_infos)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWaitEvents2KHR.html>"]
+    #[doc(alias = "vkCmdWaitEvents2KHR")]
+    pub fn wait_events2_khr<'a, V2: Alias<raw::Event> + 'a>(
+        &self,
+        p_events: impl AsSlice<'a, V2>,
+        p_dependency_infos: impl AsSlice<'a, DependencyInfo<'a>>,
+    ) {
+        self.inner.wait_events2_khr(p_events, p_dependency_infos)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerBeginEXT.html>"]
+    #[doc(alias = "vkCmdDebugMarkerBeginEXT")]
+    pub fn debug_marker_begin_ext(&self, p_marker_info: &DebugMarkerMarkerInfoEXT) {
+        self.inner.debug_marker_begin_ext(p_marker_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerEndEXT.html>"]
+    #[doc(alias = "vkCmdDebugMarkerEndEXT")]
+    pub fn debug_marker_end_ext(&self) {
+        self.inner.debug_marker_end_ext()
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerInsertEXT.html>"]
+    #[doc(alias = "vkCmdDebugMarkerInsertEXT")]
+    pub fn debug_marker_insert_ext(&self, p_marker_info: &DebugMarkerMarkerInfoEXT) {
+        self.inner.debug_marker_insert_ext(p_marker_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQueryIndexedEXT.html>"]
+    #[doc(alias = "vkCmdBeginQueryIndexedEXT")]
+    pub fn begin_query_indexed_ext(
+        &self,
+        query_pool: &raw::QueryPool,
+        query: u32,
+        flags: QueryControlFlags,
+        index: u32,
+    ) {
+        self.inner
+            .begin_query_indexed_ext(query_pool, query, flags, index)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQueryIndexedEXT.html>"]
+    #[doc(alias = "vkCmdEndQueryIndexedEXT")]
+    pub fn end_query_indexed_ext(&self, query_pool: &raw::QueryPool, query: u32, index: u32) {
+        self.inner.end_query_indexed_ext(query_pool, query, index)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetKHR.html>"]
+    #[doc(alias = "vkCmdPushDescriptorSetKHR")]
+    pub fn push_descriptor_set_khr<'a>(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: &raw::PipelineLayout,
+        set: u32,
+        p_descriptor_writes: impl AsSlice<'a, WriteDescriptorSet<'a>>,
+    ) {
+        self.inner
+            .push_descriptor_set_khr(pipeline_bind_point, layout, set, p_descriptor_writes)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetWithTemplateKHR.html>"]
+    #[doc(alias = "vkCmdPushDescriptorSetWithTemplateKHR")]
+    pub fn push_descriptor_set_with_template_khr(
+        &self,
+        descriptor_update_template: &raw::DescriptorUpdateTemplate,
+        layout: &raw::PipelineLayout,
+        set: u32,
+        p_data: VoidPtr,
+    ) {
+        self.inner.push_descriptor_set_with_template_khr(
+            descriptor_update_template,
+            layout,
+            set,
+            p_data,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginConditionalRenderingEXT.html>"]
+    #[doc(alias = "vkCmdBeginConditionalRenderingEXT")]
+    pub fn begin_conditional_rendering_ext(
+        &self,
+        p_conditional_rendering_begin: &ConditionalRenderingBeginInfoEXT,
+    ) {
+        self.inner
+            .begin_conditional_rendering_ext(p_conditional_rendering_begin)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndConditionalRenderingEXT.html>"]
+    #[doc(alias = "vkCmdEndConditionalRenderingEXT")]
+    pub fn end_conditional_rendering_ext(&self) {
+        self.inner.end_conditional_rendering_ext()
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginDebugUtilsLabelEXT.html>"]
+    #[doc(alias = "vkCmdBeginDebugUtilsLabelEXT")]
+    pub fn begin_debug_utils_label_ext(&self, p_label_info: &DebugUtilsLabelEXT) {
+        self.inner.begin_debug_utils_label_ext(p_label_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndDebugUtilsLabelEXT.html>"]
+    #[doc(alias = "vkCmdEndDebugUtilsLabelEXT")]
+    pub fn end_debug_utils_label_ext(&self) {
+        self.inner.end_debug_utils_label_ext()
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdInsertDebugUtilsLabelEXT.html>"]
+    #[doc(alias = "vkCmdInsertDebugUtilsLabelEXT")]
+    pub fn insert_debug_utils_label_ext(&self, p_label_info: &DebugUtilsLabelEXT) {
+        self.inner.insert_debug_utils_label_ext(p_label_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBuffersEXT.html>"]
+    #[doc(alias = "vkCmdBindDescriptorBuffersEXT")]
+    pub fn bind_descriptor_buffers_ext<'a>(
+        &self,
+        p_binding_infos: impl AsSlice<'a, DescriptorBufferBindingInfoEXT<'a>>,
+    ) {
+        self.inner.bind_descriptor_buffers_ext(p_binding_infos)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDescriptorBufferOffsetsEXT.html>"]
+    #[doc(alias = "vkCmdSetDescriptorBufferOffsetsEXT")]
+    pub fn set_descriptor_buffer_offsets_ext<'a>(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: &raw::PipelineLayout,
+        first_set: u32,
+        p_buffer_indices: impl AsSlice<'a, u32>,
+        p_offsets: impl AsSlice<'a, DeviceSize>,
+    ) {
+        self.inner.set_descriptor_buffer_offsets_ext(
+            pipeline_bind_point,
+            layout,
+            first_set,
+            p_buffer_indices,
+            p_offsets,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBufferEmbeddedSamplersEXT.html>"]
+    #[doc(alias = "vkCmdBindDescriptorBufferEmbeddedSamplersEXT")]
+    pub fn bind_descriptor_buffer_embedded_samplers_ext(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: &raw::PipelineLayout,
+        set: u32,
+    ) {
+        self.inner
+            .bind_descriptor_buffer_embedded_samplers_ext(pipeline_bind_point, layout, set)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadersEXT.html>"]
+    #[doc(alias = "vkCmdBindShadersEXT")]
+    pub fn bind_shaders_ext<'a, V3: Alias<raw::ShaderEXT> + 'a>(
+        &self,
+        p_stages: impl AsSlice<'a, ShaderStageFlags>,
+        p_shaders: impl AsSlice<'a, V3>,
+    ) {
+        self.inner.bind_shaders_ext(p_stages, p_shaders)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorSets2KHR.html>"]
+    #[doc(alias = "vkCmdBindDescriptorSets2KHR")]
+    pub fn bind_descriptor_sets2_khr(
+        &self,
+        p_bind_descriptor_sets_info: &BindDescriptorSetsInfoKHR,
+    ) {
+        self.inner
+            .bind_descriptor_sets2_khr(p_bind_descriptor_sets_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushConstants2KHR.html>"]
+    #[doc(alias = "vkCmdPushConstants2KHR")]
+    pub fn push_constants2_khr(&self, p_push_constants_info: &PushConstantsInfoKHR) {
+        self.inner.push_constants2_khr(p_push_constants_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSet2KHR.html>"]
+    #[doc(alias = "vkCmdPushDescriptorSet2KHR")]
+    pub fn push_descriptor_set2_khr(&self, p_push_descriptor_set_info: &PushDescriptorSetInfoKHR) {
+        self.inner
+            .push_descriptor_set2_khr(p_push_descriptor_set_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetWithTemplate2KHR.html>"]
+    #[doc(alias = "vkCmdPushDescriptorSetWithTemplate2KHR")]
+    pub fn push_descriptor_set_with_template2_khr(
+        &self,
+        p_push_descriptor_set_with_template_info: &PushDescriptorSetWithTemplateInfoKHR,
+    ) {
+        self.inner
+            .push_descriptor_set_with_template2_khr(p_push_descriptor_set_with_template_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDescriptorBufferOffsets2EXT.html>"]
+    #[doc(alias = "vkCmdSetDescriptorBufferOffsets2EXT")]
+    pub fn set_descriptor_buffer_offsets2_ext(
+        &self,
+        p_set_descriptor_buffer_offsets_info: &SetDescriptorBufferOffsetsInfoEXT,
+    ) {
+        self.inner
+            .set_descriptor_buffer_offsets2_ext(p_set_descriptor_buffer_offsets_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBufferEmbeddedSamplers2EXT.html>"]
+    #[doc(alias = "vkCmdBindDescriptorBufferEmbeddedSamplers2EXT")]
+    pub fn bind_descriptor_buffer_embedded_samplers2_ext(
+        &self,
+        p_bind_descriptor_buffer_embedded_samplers_info : & BindDescriptorBufferEmbeddedSamplersInfoEXT,
+    ) {
+        self.inner.bind_descriptor_buffer_embedded_samplers2_ext(
+            p_bind_descriptor_buffer_embedded_samplers_info,
+        )
+    }
+    #[cfg(feature = "vendor-nvx")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCuLaunchKernelNVX.html>"]
+    #[doc(alias = "vkCmdCuLaunchKernelNVX")]
+    pub fn cu_launch_kernel_nvx(&self, p_launch_info: &CuLaunchInfoNVX) {
+        self.inner.cu_launch_kernel_nvx(p_launch_info)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html>"]
+    #[doc(alias = "vkCmdExecuteGeneratedCommandsNV")]
+    pub fn execute_generated_commands_nv(
+        &self,
+        is_preprocessed: impl Into<Bool32>,
+        p_generated_commands_info: &GeneratedCommandsInfoNV,
+    ) {
+        self.inner
+            .execute_generated_commands_nv(is_preprocessed, p_generated_commands_info)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html>"]
+    #[doc(alias = "vkCmdBindPipelineShaderGroupNV")]
+    pub fn bind_pipeline_shader_group_nv(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        pipeline: &raw::Pipeline,
+        group_index: u32,
+    ) {
+        self.inner
+            .bind_pipeline_shader_group_nv(pipeline_bind_point, pipeline, group_index)
+    }
+    #[cfg(all(feature = "vendor-nv", feature = "provisional"))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCudaLaunchKernelNV.html>"]
+    #[doc(alias = "vkCmdCudaLaunchKernelNV")]
+    pub fn cuda_launch_kernel_nv(&self, p_launch_info: &CudaLaunchInfoNV) {
+        self.inner.cuda_launch_kernel_nv(p_launch_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteGeneratedCommandsEXT.html>"]
+    #[doc(alias = "vkCmdExecuteGeneratedCommandsEXT")]
+    pub fn execute_generated_commands_ext(
+        &self,
+        is_preprocessed: impl Into<Bool32>,
+        p_generated_commands_info: &GeneratedCommandsInfoEXT,
+    ) {
+        self.inner
+            .execute_generated_commands_ext(is_preprocessed, p_generated_commands_info)
+    }
+}
+impl<Q: SupportsGraphics, L: Level, P: RenderPassState, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, P, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewport.html>"]
+    #[doc(alias = "vkCmdSetViewport")]
+    pub fn set_viewport<'a>(&self, first_viewport: u32, p_viewports: impl AsSlice<'a, Viewport>) {
+        self.inner.set_viewport(first_viewport, p_viewports)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissor.html>"]
+    #[doc(alias = "vkCmdSetScissor")]
+    pub fn set_scissor<'a>(&self, first_scissor: u32, p_scissors: impl AsSlice<'a, Rect2D>) {
+        self.inner.set_scissor(first_scissor, p_scissors)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineWidth.html>"]
+    #[doc(alias = "vkCmdSetLineWidth")]
+    pub fn set_line_width(&self, line_width: f32) {
+        self.inner.set_line_width(line_width)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias.html>"]
+    #[doc(alias = "vkCmdSetDepthBias")]
+    pub fn set_depth_bias(
+        &self,
+        depth_bias_constant_factor: f32,
+        depth_bias_clamp: f32,
+        depth_bias_slope_factor: f32,
+    ) {
+        self.inner.set_depth_bias(
+            depth_bias_constant_factor,
+            depth_bias_clamp,
+            depth_bias_slope_factor,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetBlendConstants.html>"]
+    #[doc(alias = "vkCmdSetBlendConstants")]
+    pub fn set_blend_constants(&self, blend_constants: [f32; 4u16 as _]) {
+        self.inner.set_blend_constants(blend_constants)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBounds.html>"]
+    #[doc(alias = "vkCmdSetDepthBounds")]
+    pub fn set_depth_bounds(&self, min_depth_bounds: f32, max_depth_bounds: f32) {
+        self.inner
+            .set_depth_bounds(min_depth_bounds, max_depth_bounds)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilCompareMask.html>"]
+    #[doc(alias = "vkCmdSetStencilCompareMask")]
+    pub fn set_stencil_compare_mask(&self, face_mask: StencilFaceFlags, compare_mask: u32) {
+        self.inner.set_stencil_compare_mask(face_mask, compare_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilWriteMask.html>"]
+    #[doc(alias = "vkCmdSetStencilWriteMask")]
+    pub fn set_stencil_write_mask(&self, face_mask: StencilFaceFlags, write_mask: u32) {
+        self.inner.set_stencil_write_mask(face_mask, write_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilReference.html>"]
+    #[doc(alias = "vkCmdSetStencilReference")]
+    pub fn set_stencil_reference(&self, face_mask: StencilFaceFlags, reference: u32) {
+        self.inner.set_stencil_reference(face_mask, reference)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindIndexBuffer.html>"]
+    #[doc(alias = "vkCmdBindIndexBuffer")]
+    pub fn bind_index_buffer(
+        &self,
+        buffer: Option<&raw::Buffer>,
+        offset: DeviceSize,
+        index_type: IndexType,
+    ) {
+        self.inner.bind_index_buffer(buffer, offset, index_type)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindVertexBuffers.html>"]
+    #[doc(alias = "vkCmdBindVertexBuffers")]
+    pub fn bind_vertex_buffers<'a, V3: Alias<raw::Buffer> + 'a>(
+        &self,
+        first_binding: u32,
+        p_buffers: impl AsSlice<'a, V3>,
+        p_offsets: impl AsSlice<'a, DeviceSize>,
+    ) {
+        self.inner
+            .bind_vertex_buffers(first_binding, p_buffers, p_offsets)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullMode.html>"]
+    #[doc(alias = "vkCmdSetCullMode")]
+    pub fn set_cull_mode(&self, cull_mode: CullModeFlags) {
+        self.inner.set_cull_mode(cull_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullModeEXT.html>"]
+    #[doc(alias = "vkCmdSetCullModeEXT")]
+    pub fn set_cull_mode_ext(&self, cull_mode: CullModeFlags) {
+        self.inner.set_cull_mode_ext(cull_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFrontFace.html>"]
+    #[doc(alias = "vkCmdSetFrontFace")]
+    pub fn set_front_face(&self, front_face: FrontFace) {
+        self.inner.set_front_face(front_face)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFrontFaceEXT.html>"]
+    #[doc(alias = "vkCmdSetFrontFaceEXT")]
+    pub fn set_front_face_ext(&self, front_face: FrontFace) {
+        self.inner.set_front_face_ext(front_face)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveTopology.html>"]
+    #[doc(alias = "vkCmdSetPrimitiveTopology")]
+    pub fn set_primitive_topology(&self, primitive_topology: PrimitiveTopology) {
+        self.inner.set_primitive_topology(primitive_topology)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveTopologyEXT.html>"]
+    #[doc(alias = "vkCmdSetPrimitiveTopologyEXT")]
+    pub fn set_primitive_topology_ext(&self, primitive_topology: PrimitiveTopology) {
+        self.inner.set_primitive_topology_ext(primitive_topology)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCount.html>"]
+    #[doc(alias = "vkCmdSetViewportWithCount")]
+    pub fn set_viewport_with_count<'a>(&self, p_viewports: impl AsSlice<'a, Viewport>) {
+        self.inner.set_viewport_with_count(p_viewports)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCountEXT.html>"]
+    #[doc(alias = "vkCmdSetViewportWithCountEXT")]
+    pub fn set_viewport_with_count_ext<'a>(&self, p_viewports: impl AsSlice<'a, Viewport>) {
+        self.inner.set_viewport_with_count_ext(p_viewports)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCount.html>"]
+    #[doc(alias = "vkCmdSetScissorWithCount")]
+    pub fn set_scissor_with_count<'a>(&self, p_scissors: impl AsSlice<'a, Rect2D>) {
+        self.inner.set_scissor_with_count(p_scissors)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCountEXT.html>"]
+    #[doc(alias = "vkCmdSetScissorWithCountEXT")]
+    pub fn set_scissor_with_count_ext<'a>(&self, p_scissors: impl AsSlice<'a, Rect2D>) {
+        self.inner.set_scissor_with_count_ext(p_scissors)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindVertexBuffers2.html>"]
+    #[doc(alias = "vkCmdBindVertexBuffers2")]
+    pub fn bind_vertex_buffers2<'a, V3: Alias<raw::Buffer> + 'a>(
+        &self,
+        first_binding: u32,
+        p_buffers: impl AsSlice<'a, V3>,
+        p_offsets: impl AsSlice<'a, DeviceSize>,
+        p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
+        p_strides: Option<impl AsSlice<'a, DeviceSize>>,
+    ) {
+        self.inner
+            .bind_vertex_buffers2(first_binding, p_buffers, p_offsets, p_sizes, p_strides)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindVertexBuffers2EXT.html>"]
+    #[doc(alias = "vkCmdBindVertexBuffers2EXT")]
+    pub fn bind_vertex_buffers2_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+        &self,
+        first_binding: u32,
+        p_buffers: impl AsSlice<'a, V3>,
+        p_offsets: impl AsSlice<'a, DeviceSize>,
+        p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
+        p_strides: Option<impl AsSlice<'a, DeviceSize>>,
+    ) {
+        self.inner
+            .bind_vertex_buffers2_ext(first_binding, p_buffers, p_offsets, p_sizes, p_strides)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthTestEnable.html>"]
+    #[doc(alias = "vkCmdSetDepthTestEnable")]
+    pub fn set_depth_test_enable(&self, depth_test_enable: impl Into<Bool32>) {
+        self.inner.set_depth_test_enable(depth_test_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthTestEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthTestEnableEXT")]
+    pub fn set_depth_test_enable_ext(&self, depth_test_enable: impl Into<Bool32>) {
+        self.inner.set_depth_test_enable_ext(depth_test_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthWriteEnable.html>"]
+    #[doc(alias = "vkCmdSetDepthWriteEnable")]
+    pub fn set_depth_write_enable(&self, depth_write_enable: impl Into<Bool32>) {
+        self.inner.set_depth_write_enable(depth_write_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthWriteEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthWriteEnableEXT")]
+    pub fn set_depth_write_enable_ext(&self, depth_write_enable: impl Into<Bool32>) {
+        self.inner.set_depth_write_enable_ext(depth_write_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthCompareOp.html>"]
+    #[doc(alias = "vkCmdSetDepthCompareOp")]
+    pub fn set_depth_compare_op(&self, depth_compare_op: CompareOp) {
+        self.inner.set_depth_compare_op(depth_compare_op)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthCompareOpEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthCompareOpEXT")]
+    pub fn set_depth_compare_op_ext(&self, depth_compare_op: CompareOp) {
+        self.inner.set_depth_compare_op_ext(depth_compare_op)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBoundsTestEnable.html>"]
+    #[doc(alias = "vkCmdSetDepthBoundsTestEnable")]
+    pub fn set_depth_bounds_test_enable(&self, depth_bounds_test_enable: impl Into<Bool32>) {
+        self.inner
+            .set_depth_bounds_test_enable(depth_bounds_test_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBoundsTestEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthBoundsTestEnableEXT")]
+    pub fn set_depth_bounds_test_enable_ext(&self, depth_bounds_test_enable: impl Into<Bool32>) {
+        self.inner
+            .set_depth_bounds_test_enable_ext(depth_bounds_test_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilTestEnable.html>"]
+    #[doc(alias = "vkCmdSetStencilTestEnable")]
+    pub fn set_stencil_test_enable(&self, stencil_test_enable: impl Into<Bool32>) {
+        self.inner.set_stencil_test_enable(stencil_test_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilTestEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetStencilTestEnableEXT")]
+    pub fn set_stencil_test_enable_ext(&self, stencil_test_enable: impl Into<Bool32>) {
+        self.inner.set_stencil_test_enable_ext(stencil_test_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilOp.html>"]
+    #[doc(alias = "vkCmdSetStencilOp")]
+    pub fn set_stencil_op(
+        &self,
+        face_mask: StencilFaceFlags,
+        fail_op: StencilOp,
+        pass_op: StencilOp,
+        depth_fail_op: StencilOp,
+        compare_op: CompareOp,
+    ) {
+        self.inner
+            .set_stencil_op(face_mask, fail_op, pass_op, depth_fail_op, compare_op)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilOpEXT.html>"]
+    #[doc(alias = "vkCmdSetStencilOpEXT")]
+    pub fn set_stencil_op_ext(
+        &self,
+        face_mask: StencilFaceFlags,
+        fail_op: StencilOp,
+        pass_op: StencilOp,
+        depth_fail_op: StencilOp,
+        compare_op: CompareOp,
+    ) {
+        self.inner
+            .set_stencil_op_ext(face_mask, fail_op, pass_op, depth_fail_op, compare_op)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizerDiscardEnable.html>"]
+    #[doc(alias = "vkCmdSetRasterizerDiscardEnable")]
+    pub fn set_rasterizer_discard_enable(&self, rasterizer_discard_enable: impl Into<Bool32>) {
+        self.inner
+            .set_rasterizer_discard_enable(rasterizer_discard_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizerDiscardEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetRasterizerDiscardEnableEXT")]
+    pub fn set_rasterizer_discard_enable_ext(&self, rasterizer_discard_enable: impl Into<Bool32>) {
+        self.inner
+            .set_rasterizer_discard_enable_ext(rasterizer_discard_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBiasEnable.html>"]
+    #[doc(alias = "vkCmdSetDepthBiasEnable")]
+    pub fn set_depth_bias_enable(&self, depth_bias_enable: impl Into<Bool32>) {
+        self.inner.set_depth_bias_enable(depth_bias_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBiasEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthBiasEnableEXT")]
+    pub fn set_depth_bias_enable_ext(&self, depth_bias_enable: impl Into<Bool32>) {
+        self.inner.set_depth_bias_enable_ext(depth_bias_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveRestartEnable.html>"]
+    #[doc(alias = "vkCmdSetPrimitiveRestartEnable")]
+    pub fn set_primitive_restart_enable(&self, primitive_restart_enable: impl Into<Bool32>) {
+        self.inner
+            .set_primitive_restart_enable(primitive_restart_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveRestartEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetPrimitiveRestartEnableEXT")]
+    pub fn set_primitive_restart_enable_ext(&self, primitive_restart_enable: impl Into<Bool32>) {
+        self.inner
+            .set_primitive_restart_enable_ext(primitive_restart_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html>"]
+    #[doc(alias = "vkCmdBindTransformFeedbackBuffersEXT")]
+    pub fn bind_transform_feedback_buffers_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+        &self,
+        first_binding: u32,
+        p_buffers: impl AsSlice<'a, V3>,
+        p_offsets: impl AsSlice<'a, DeviceSize>,
+        p_sizes: Option<impl AsSlice<'a, DeviceSize>>,
+    ) {
+        self.inner
+            .bind_transform_feedback_buffers_ext(first_binding, p_buffers, p_offsets, p_sizes)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingNV.html>"]
+    #[doc(alias = "vkCmdSetViewportWScalingNV")]
+    pub fn set_viewport_wscaling_nv<'a>(
+        &self,
+        first_viewport: u32,
+        p_viewport_wscalings: impl AsSlice<'a, ViewportWScalingNV>,
+    ) {
+        self.inner
+            .set_viewport_wscaling_nv(first_viewport, p_viewport_wscalings)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEXT.html>"]
+    #[doc(alias = "vkCmdSetDiscardRectangleEXT")]
+    pub fn set_discard_rectangle_ext<'a>(
+        &self,
+        first_discard_rectangle: u32,
+        p_discard_rectangles: impl AsSlice<'a, Rect2D>,
+    ) {
+        self.inner
+            .set_discard_rectangle_ext(first_discard_rectangle, p_discard_rectangles)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDiscardRectangleEnableEXT")]
+    pub fn set_discard_rectangle_enable_ext(&self, discard_rectangle_enable: impl Into<Bool32>) {
+        self.inner
+            .set_discard_rectangle_enable_ext(discard_rectangle_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleModeEXT.html>"]
+    #[doc(alias = "vkCmdSetDiscardRectangleModeEXT")]
+    pub fn set_discard_rectangle_mode_ext(&self, discard_rectangle_mode: DiscardRectangleModeEXT) {
+        self.inner
+            .set_discard_rectangle_mode_ext(discard_rectangle_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleLocationsEXT.html>"]
+    #[doc(alias = "vkCmdSetSampleLocationsEXT")]
+    pub fn set_sample_locations_ext(&self, p_sample_locations_info: &SampleLocationsInfoEXT) {
+        self.inner.set_sample_locations_ext(p_sample_locations_info)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadingRateImageNV.html>"]
+    #[doc(alias = "vkCmdBindShadingRateImageNV")]
+    pub fn bind_shading_rate_image_nv(
+        &self,
+        image_view: Option<&raw::ImageView>,
+        image_layout: ImageLayout,
+    ) {
+        self.inner
+            .bind_shading_rate_image_nv(image_view, image_layout)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportShadingRatePaletteNV.html>"]
+    #[doc(alias = "vkCmdSetViewportShadingRatePaletteNV")]
+    pub fn set_viewport_shading_rate_palette_nv<'a>(
+        &self,
+        first_viewport: u32,
+        p_shading_rate_palettes: impl AsSlice<'a, ShadingRatePaletteNV<'a>>,
+    ) {
+        self.inner
+            .set_viewport_shading_rate_palette_nv(first_viewport, p_shading_rate_palettes)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoarseSampleOrderNV.html>"]
+    #[doc(alias = "vkCmdSetCoarseSampleOrderNV")]
+    pub fn set_coarse_sample_order_nv<'a>(
+        &self,
+        sample_order_type: CoarseSampleOrderTypeNV,
+        p_custom_sample_orders: impl AsSlice<'a, CoarseSampleOrderCustomNV<'a>>,
+    ) {
+        self.inner
+            .set_coarse_sample_order_nv(sample_order_type, p_custom_sample_orders)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorEnableNV.html>"]
+    #[doc(alias = "vkCmdSetExclusiveScissorEnableNV")]
+    pub fn set_exclusive_scissor_enable_nv<'a>(
+        &self,
+        first_exclusive_scissor: u32,
+        p_exclusive_scissor_enables: impl AsSlice<'a, Bool32>,
+    ) {
+        self.inner
+            .set_exclusive_scissor_enable_nv(first_exclusive_scissor, p_exclusive_scissor_enables)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorNV.html>"]
+    #[doc(alias = "vkCmdSetExclusiveScissorNV")]
+    pub fn set_exclusive_scissor_nv<'a>(
+        &self,
+        first_exclusive_scissor: u32,
+        p_exclusive_scissors: impl AsSlice<'a, Rect2D>,
+    ) {
+        self.inner
+            .set_exclusive_scissor_nv(first_exclusive_scissor, p_exclusive_scissors)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateKHR.html>"]
+    #[doc(alias = "vkCmdSetFragmentShadingRateKHR")]
+    pub fn set_fragment_shading_rate_khr(
+        &self,
+        p_fragment_size: &Extent2D,
+        combiner_ops: [FragmentShadingRateCombinerOpKHR; 2u16 as _],
+    ) {
+        self.inner
+            .set_fragment_shading_rate_khr(p_fragment_size, combiner_ops)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias2EXT.html>"]
+    #[doc(alias = "vkCmdSetDepthBias2EXT")]
+    pub fn set_depth_bias2_ext(&self, p_depth_bias_info: &DepthBiasInfoEXT) {
+        self.inner.set_depth_bias2_ext(p_depth_bias_info)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateEnumNV.html>"]
+    #[doc(alias = "vkCmdSetFragmentShadingRateEnumNV")]
+    pub fn set_fragment_shading_rate_enum_nv(
+        &self,
+        shading_rate: FragmentShadingRateNV,
+        combiner_ops: [FragmentShadingRateCombinerOpKHR; 2u16 as _],
+    ) {
+        self.inner
+            .set_fragment_shading_rate_enum_nv(shading_rate, combiner_ops)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetVertexInputEXT.html>"]
+    #[doc(alias = "vkCmdSetVertexInputEXT")]
+    pub fn set_vertex_input_ext<'a>(
+        &self,
+        p_vertex_binding_descriptions: impl AsSlice<'a, VertexInputBindingDescription2EXT<'a>>,
+        p_vertex_attribute_descriptions: impl AsSlice<'a, VertexInputAttributeDescription2EXT<'a>>,
+    ) {
+        self.inner.set_vertex_input_ext(
+            p_vertex_binding_descriptions,
+            p_vertex_attribute_descriptions,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPatchControlPointsEXT.html>"]
+    #[doc(alias = "vkCmdSetPatchControlPointsEXT")]
+    pub fn set_patch_control_points_ext(&self, patch_control_points: u32) {
+        self.inner
+            .set_patch_control_points_ext(patch_control_points)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLogicOpEXT.html>"]
+    #[doc(alias = "vkCmdSetLogicOpEXT")]
+    pub fn set_logic_op_ext(&self, logic_op: LogicOp) {
+        self.inner.set_logic_op_ext(logic_op)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetColorWriteEnableEXT")]
+    pub fn set_color_write_enable_ext<'a>(&self, p_color_write_enables: impl AsSlice<'a, Bool32>) {
+        self.inner.set_color_write_enable_ext(p_color_write_enables)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClampEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthClampEnableEXT")]
+    pub fn set_depth_clamp_enable_ext(&self, depth_clamp_enable: impl Into<Bool32>) {
+        self.inner.set_depth_clamp_enable_ext(depth_clamp_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPolygonModeEXT.html>"]
+    #[doc(alias = "vkCmdSetPolygonModeEXT")]
+    pub fn set_polygon_mode_ext(&self, polygon_mode: PolygonMode) {
+        self.inner.set_polygon_mode_ext(polygon_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationSamplesEXT.html>"]
+    #[doc(alias = "vkCmdSetRasterizationSamplesEXT")]
+    pub fn set_rasterization_samples_ext(&self, rasterization_samples: SampleCountFlags) {
+        self.inner
+            .set_rasterization_samples_ext(rasterization_samples)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleMaskEXT.html>"]
+    #[doc(alias = "vkCmdSetSampleMaskEXT")]
+    pub fn set_sample_mask_ext<'a>(
+        &self,
+        samples: SampleCountFlags,
+        p_sample_mask: impl AsSlice<'a, SampleMask>,
+    ) {
+        self.inner.set_sample_mask_ext(samples, p_sample_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAlphaToCoverageEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetAlphaToCoverageEnableEXT")]
+    pub fn set_alpha_to_coverage_enable_ext(&self, alpha_to_coverage_enable: impl Into<Bool32>) {
+        self.inner
+            .set_alpha_to_coverage_enable_ext(alpha_to_coverage_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAlphaToOneEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetAlphaToOneEnableEXT")]
+    pub fn set_alpha_to_one_enable_ext(&self, alpha_to_one_enable: impl Into<Bool32>) {
+        self.inner.set_alpha_to_one_enable_ext(alpha_to_one_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLogicOpEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetLogicOpEnableEXT")]
+    pub fn set_logic_op_enable_ext(&self, logic_op_enable: impl Into<Bool32>) {
+        self.inner.set_logic_op_enable_ext(logic_op_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetColorBlendEnableEXT")]
+    pub fn set_color_blend_enable_ext<'a>(
+        &self,
+        first_attachment: u32,
+        p_color_blend_enables: impl AsSlice<'a, Bool32>,
+    ) {
+        self.inner
+            .set_color_blend_enable_ext(first_attachment, p_color_blend_enables)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEquationEXT.html>"]
+    #[doc(alias = "vkCmdSetColorBlendEquationEXT")]
+    pub fn set_color_blend_equation_ext<'a>(
+        &self,
+        first_attachment: u32,
+        p_color_blend_equations: impl AsSlice<'a, ColorBlendEquationEXT>,
+    ) {
+        self.inner
+            .set_color_blend_equation_ext(first_attachment, p_color_blend_equations)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteMaskEXT.html>"]
+    #[doc(alias = "vkCmdSetColorWriteMaskEXT")]
+    pub fn set_color_write_mask_ext<'a>(
+        &self,
+        first_attachment: u32,
+        p_color_write_masks: impl AsSlice<'a, ColorComponentFlags>,
+    ) {
+        self.inner
+            .set_color_write_mask_ext(first_attachment, p_color_write_masks)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetTessellationDomainOriginEXT.html>"]
+    #[doc(alias = "vkCmdSetTessellationDomainOriginEXT")]
+    pub fn set_tessellation_domain_origin_ext(&self, domain_origin: TessellationDomainOrigin) {
+        self.inner.set_tessellation_domain_origin_ext(domain_origin)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationStreamEXT.html>"]
+    #[doc(alias = "vkCmdSetRasterizationStreamEXT")]
+    pub fn set_rasterization_stream_ext(&self, rasterization_stream: u32) {
+        self.inner
+            .set_rasterization_stream_ext(rasterization_stream)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetConservativeRasterizationModeEXT.html>"]
+    #[doc(alias = "vkCmdSetConservativeRasterizationModeEXT")]
+    pub fn set_conservative_rasterization_mode_ext(
+        &self,
+        conservative_rasterization_mode: ConservativeRasterizationModeEXT,
+    ) {
+        self.inner
+            .set_conservative_rasterization_mode_ext(conservative_rasterization_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExtraPrimitiveOverestimationSizeEXT.html>"]
+    #[doc(alias = "vkCmdSetExtraPrimitiveOverestimationSizeEXT")]
+    pub fn set_extra_primitive_overestimation_size_ext(
+        &self,
+        extra_primitive_overestimation_size: f32,
+    ) {
+        self.inner
+            .set_extra_primitive_overestimation_size_ext(extra_primitive_overestimation_size)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClipEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthClipEnableEXT")]
+    pub fn set_depth_clip_enable_ext(&self, depth_clip_enable: impl Into<Bool32>) {
+        self.inner.set_depth_clip_enable_ext(depth_clip_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleLocationsEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetSampleLocationsEnableEXT")]
+    pub fn set_sample_locations_enable_ext(&self, sample_locations_enable: impl Into<Bool32>) {
+        self.inner
+            .set_sample_locations_enable_ext(sample_locations_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendAdvancedEXT.html>"]
+    #[doc(alias = "vkCmdSetColorBlendAdvancedEXT")]
+    pub fn set_color_blend_advanced_ext<'a>(
+        &self,
+        first_attachment: u32,
+        p_color_blend_advanced: impl AsSlice<'a, ColorBlendAdvancedEXT>,
+    ) {
+        self.inner
+            .set_color_blend_advanced_ext(first_attachment, p_color_blend_advanced)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetProvokingVertexModeEXT.html>"]
+    #[doc(alias = "vkCmdSetProvokingVertexModeEXT")]
+    pub fn set_provoking_vertex_mode_ext(&self, provoking_vertex_mode: ProvokingVertexModeEXT) {
+        self.inner
+            .set_provoking_vertex_mode_ext(provoking_vertex_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineRasterizationModeEXT.html>"]
+    #[doc(alias = "vkCmdSetLineRasterizationModeEXT")]
+    pub fn set_line_rasterization_mode_ext(
+        &self,
+        line_rasterization_mode: LineRasterizationModeEXT,
+    ) {
+        self.inner
+            .set_line_rasterization_mode_ext(line_rasterization_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetLineStippleEnableEXT")]
+    pub fn set_line_stipple_enable_ext(&self, stippled_line_enable: impl Into<Bool32>) {
+        self.inner.set_line_stipple_enable_ext(stippled_line_enable)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClipNegativeOneToOneEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthClipNegativeOneToOneEXT")]
+    pub fn set_depth_clip_negative_one_to_one_ext(&self, negative_one_to_one: impl Into<Bool32>) {
+        self.inner
+            .set_depth_clip_negative_one_to_one_ext(negative_one_to_one)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingEnableNV.html>"]
+    #[doc(alias = "vkCmdSetViewportWScalingEnableNV")]
+    pub fn set_viewport_wscaling_enable_nv(&self, viewport_wscaling_enable: impl Into<Bool32>) {
+        self.inner
+            .set_viewport_wscaling_enable_nv(viewport_wscaling_enable)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportSwizzleNV.html>"]
+    #[doc(alias = "vkCmdSetViewportSwizzleNV")]
+    pub fn set_viewport_swizzle_nv<'a>(
+        &self,
+        first_viewport: u32,
+        p_viewport_swizzles: impl AsSlice<'a, ViewportSwizzleNV>,
+    ) {
+        self.inner
+            .set_viewport_swizzle_nv(first_viewport, p_viewport_swizzles)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorEnableNV.html>"]
+    #[doc(alias = "vkCmdSetCoverageToColorEnableNV")]
+    pub fn set_coverage_to_color_enable_nv(&self, coverage_to_color_enable: impl Into<Bool32>) {
+        self.inner
+            .set_coverage_to_color_enable_nv(coverage_to_color_enable)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorLocationNV.html>"]
+    #[doc(alias = "vkCmdSetCoverageToColorLocationNV")]
+    pub fn set_coverage_to_color_location_nv(&self, coverage_to_color_location: u32) {
+        self.inner
+            .set_coverage_to_color_location_nv(coverage_to_color_location)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationModeNV.html>"]
+    #[doc(alias = "vkCmdSetCoverageModulationModeNV")]
+    pub fn set_coverage_modulation_mode_nv(
+        &self,
+        coverage_modulation_mode: CoverageModulationModeNV,
+    ) {
+        self.inner
+            .set_coverage_modulation_mode_nv(coverage_modulation_mode)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableEnableNV.html>"]
+    #[doc(alias = "vkCmdSetCoverageModulationTableEnableNV")]
+    pub fn set_coverage_modulation_table_enable_nv(
+        &self,
+        coverage_modulation_table_enable: impl Into<Bool32>,
+    ) {
+        self.inner
+            .set_coverage_modulation_table_enable_nv(coverage_modulation_table_enable)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableNV.html>"]
+    #[doc(alias = "vkCmdSetCoverageModulationTableNV")]
+    pub fn set_coverage_modulation_table_nv<'a>(
+        &self,
+        p_coverage_modulation_table: impl AsSlice<'a, f32>,
+    ) {
+        self.inner
+            .set_coverage_modulation_table_nv(p_coverage_modulation_table)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetShadingRateImageEnableNV.html>"]
+    #[doc(alias = "vkCmdSetShadingRateImageEnableNV")]
+    pub fn set_shading_rate_image_enable_nv(&self, shading_rate_image_enable: impl Into<Bool32>) {
+        self.inner
+            .set_shading_rate_image_enable_nv(shading_rate_image_enable)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRepresentativeFragmentTestEnableNV.html>"]
+    #[doc(alias = "vkCmdSetRepresentativeFragmentTestEnableNV")]
+    pub fn set_representative_fragment_test_enable_nv(
+        &self,
+        representative_fragment_test_enable: impl Into<Bool32>,
+    ) {
+        self.inner
+            .set_representative_fragment_test_enable_nv(representative_fragment_test_enable)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageReductionModeNV.html>"]
+    #[doc(alias = "vkCmdSetCoverageReductionModeNV")]
+    pub fn set_coverage_reduction_mode_nv(&self, coverage_reduction_mode: CoverageReductionModeNV) {
+        self.inner
+            .set_coverage_reduction_mode_nv(coverage_reduction_mode)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindIndexBuffer2KHR.html>"]
+    #[doc(alias = "vkCmdBindIndexBuffer2KHR")]
+    pub fn bind_index_buffer2_khr(
+        &self,
+        buffer: Option<&raw::Buffer>,
+        offset: DeviceSize,
+        size: DeviceSize,
+        index_type: IndexType,
+    ) {
+        self.inner
+            .bind_index_buffer2_khr(buffer, offset, size, index_type)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClampRangeEXT.html>"]
+    #[doc(alias = "vkCmdSetDepthClampRangeEXT")]
+    pub fn set_depth_clamp_range_ext(
+        &self,
+        depth_clamp_mode: DepthClampModeEXT,
+        p_depth_clamp_range: Option<&DepthClampRangeEXT>,
+    ) {
+        self.inner
+            .set_depth_clamp_range_ext(depth_clamp_mode, p_depth_clamp_range)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAttachmentFeedbackLoopEnableEXT.html>"]
+    #[doc(alias = "vkCmdSetAttachmentFeedbackLoopEnableEXT")]
+    pub fn set_attachment_feedback_loop_enable_ext(&self, aspect_mask: ImageAspectFlags) {
+        self.inner
+            .set_attachment_feedback_loop_enable_ext(aspect_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleKHR.html>"]
+    #[doc(alias = "vkCmdSetLineStippleKHR")]
+    pub fn set_line_stipple_khr(&self, line_stipple_factor: u32, line_stipple_pattern: u16) {
+        self.inner
+            .set_line_stipple_khr(line_stipple_factor, line_stipple_pattern)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEXT.html>"]
+    #[doc(alias = "vkCmdSetLineStippleEXT")]
+    pub fn set_line_stipple_ext(&self, line_stipple_factor: u32, line_stipple_pattern: u16) {
+        self.inner
+            .set_line_stipple_ext(line_stipple_factor, line_stipple_pattern)
+    }
+}
+impl<Q: SupportsGraphics, L: Level, P: InsideRenderPassState, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, P, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDraw.html>"]
+    #[doc(alias = "vkCmdDraw")]
+    pub fn draw(
+        &self,
+        vertex_count: u32,
+        instance_count: u32,
+        first_vertex: u32,
+        first_instance: u32,
+    ) {
+        self.inner
+            .draw(vertex_count, instance_count, first_vertex, first_instance)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexed.html>"]
+    #[doc(alias = "vkCmdDrawIndexed")]
+    pub fn draw_indexed(
+        &self,
+        index_count: u32,
+        instance_count: u32,
+        first_index: u32,
+        vertex_offset: i32,
+        first_instance: u32,
+    ) {
+        self.inner.draw_indexed(
+            index_count,
+            instance_count,
+            first_index,
+            vertex_offset,
+            first_instance,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirect.html>"]
+    #[doc(alias = "vkCmdDrawIndirect")]
+    pub fn draw_indirect(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indirect(buffer, offset, draw_count, stride)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirect.html>"]
+    #[doc(alias = "vkCmdDrawIndexedIndirect")]
+    pub fn draw_indexed_indirect(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner
+            .draw_indexed_indirect(buffer, offset, draw_count, stride)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdClearAttachments.html>"]
+    #[doc(alias = "vkCmdClearAttachments")]
+    pub fn clear_attachments<'a>(
+        &self,
+        p_attachments: impl AsSlice<'a, ClearAttachment>,
+        p_rects: impl AsSlice<'a, ClearRect>,
+    ) {
+        self.inner.clear_attachments(p_attachments, p_rects)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCount.html>"]
+    #[doc(alias = "vkCmdDrawIndirectCount")]
+    pub fn draw_indirect_count(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indirect_count(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCountKHR.html>"]
+    #[doc(alias = "vkCmdDrawIndirectCountKHR")]
+    pub fn draw_indirect_count_khr(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indirect_count_khr(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[cfg(feature = "vendor-amd")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCountAMD.html>"]
+    #[doc(alias = "vkCmdDrawIndirectCountAMD")]
+    pub fn draw_indirect_count_amd(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indirect_count_amd(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCount.html>"]
+    #[doc(alias = "vkCmdDrawIndexedIndirectCount")]
+    pub fn draw_indexed_indirect_count(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indexed_indirect_count(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCountKHR.html>"]
+    #[doc(alias = "vkCmdDrawIndexedIndirectCountKHR")]
+    pub fn draw_indexed_indirect_count_khr(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indexed_indirect_count_khr(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[cfg(feature = "vendor-amd")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCountAMD.html>"]
+    #[doc(alias = "vkCmdDrawIndexedIndirectCountAMD")]
+    pub fn draw_indexed_indirect_count_amd(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_indexed_indirect_count_amd(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginTransformFeedbackEXT.html>"]
+    #[doc(alias = "vkCmdBeginTransformFeedbackEXT")]
+    pub fn begin_transform_feedback_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+        &self,
+        first_counter_buffer: u32,
+        p_counter_buffers: impl AsSlice<'a, V3>,
+        p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
+    ) {
+        self.inner.begin_transform_feedback_ext(
+            first_counter_buffer,
+            p_counter_buffers,
+            p_counter_buffer_offsets,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndTransformFeedbackEXT.html>"]
+    #[doc(alias = "vkCmdEndTransformFeedbackEXT")]
+    pub fn end_transform_feedback_ext<'a, V3: Alias<raw::Buffer> + 'a>(
+        &self,
+        first_counter_buffer: u32,
+        p_counter_buffers: impl AsSlice<'a, V3>,
+        p_counter_buffer_offsets: Option<impl AsSlice<'a, DeviceSize>>,
+    ) {
+        self.inner.end_transform_feedback_ext(
+            first_counter_buffer,
+            p_counter_buffers,
+            p_counter_buffer_offsets,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectByteCountEXT.html>"]
+    #[doc(alias = "vkCmdDrawIndirectByteCountEXT")]
+    pub fn draw_indirect_byte_count_ext(
+        &self,
+        instance_count: u32,
+        first_instance: u32,
+        counter_buffer: &raw::Buffer,
+        counter_buffer_offset: DeviceSize,
+        counter_offset: u32,
+        vertex_stride: u32,
+    ) {
+        self.inner.draw_indirect_byte_count_ext(
+            instance_count,
+            first_instance,
+            counter_buffer,
+            counter_buffer_offset,
+            counter_offset,
+            vertex_stride,
+        )
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksNV.html>"]
+    #[doc(alias = "vkCmdDrawMeshTasksNV")]
+    pub fn draw_mesh_tasks_nv(&self, task_count: u32, first_task: u32) {
+        self.inner.draw_mesh_tasks_nv(task_count, first_task)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectNV.html>"]
+    #[doc(alias = "vkCmdDrawMeshTasksIndirectNV")]
+    pub fn draw_mesh_tasks_indirect_nv(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner
+            .draw_mesh_tasks_indirect_nv(buffer, offset, draw_count, stride)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectCountNV.html>"]
+    #[doc(alias = "vkCmdDrawMeshTasksIndirectCountNV")]
+    pub fn draw_mesh_tasks_indirect_count_nv(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_mesh_tasks_indirect_count_nv(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRenderingAttachmentLocationsKHR.html>"]
+    #[doc(alias = "vkCmdSetRenderingAttachmentLocationsKHR")]
+    pub fn set_rendering_attachment_locations_khr(
+        &self,
+        p_location_info: &RenderingAttachmentLocationInfoKHR,
+    ) {
+        self.inner
+            .set_rendering_attachment_locations_khr(p_location_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRenderingInputAttachmentIndicesKHR.html>"]
+    #[doc(alias = "vkCmdSetRenderingInputAttachmentIndicesKHR")]
+    pub fn set_rendering_input_attachment_indices_khr(
+        &self,
+        p_input_attachment_index_info: &RenderingInputAttachmentIndexInfoKHR,
+    ) {
+        self.inner
+            .set_rendering_input_attachment_indices_khr(p_input_attachment_index_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksEXT.html>"]
+    #[doc(alias = "vkCmdDrawMeshTasksEXT")]
+    pub fn draw_mesh_tasks_ext(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        self.inner
+            .draw_mesh_tasks_ext(group_count_x, group_count_y, group_count_z)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectEXT.html>"]
+    #[doc(alias = "vkCmdDrawMeshTasksIndirectEXT")]
+    pub fn draw_mesh_tasks_indirect_ext(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner
+            .draw_mesh_tasks_indirect_ext(buffer, offset, draw_count, stride)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectCountEXT.html>"]
+    #[doc(alias = "vkCmdDrawMeshTasksIndirectCountEXT")]
+    pub fn draw_mesh_tasks_indirect_count_ext(
+        &self,
+        buffer: &raw::Buffer,
+        offset: DeviceSize,
+        count_buffer: &raw::Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        self.inner.draw_mesh_tasks_indirect_count_ext(
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMultiEXT.html>"]
+    #[doc(alias = "vkCmdDrawMultiEXT")]
+    pub fn draw_multi_ext<'a>(
+        &self,
+        p_vertex_info: impl AsSlice<'a, MultiDrawInfoEXT>,
+        instance_count: u32,
+        first_instance: u32,
+        stride: u32,
+    ) {
+        self.inner
+            .draw_multi_ext(p_vertex_info, instance_count, first_instance, stride)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMultiIndexedEXT.html>"]
+    #[doc(alias = "vkCmdDrawMultiIndexedEXT")]
+    pub fn draw_multi_indexed_ext<'a>(
+        &self,
+        p_index_info: impl AsSlice<'a, MultiDrawIndexedInfoEXT>,
+        instance_count: u32,
+        first_instance: u32,
+        stride: u32,
+        p_vertex_offset: Option<&i32>,
+    ) {
+        self.inner.draw_multi_indexed_ext(
+            p_index_info,
+            instance_count,
+            first_instance,
+            stride,
+            p_vertex_offset,
+        )
+    }
+    #[cfg(feature = "vendor-huawei")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSubpassShadingHUAWEI.html>"]
+    #[doc(alias = "vkCmdSubpassShadingHUAWEI")]
+    pub fn subpass_shading_huawei(&self) {
+        self.inner.subpass_shading_huawei()
+    }
+    #[cfg(feature = "vendor-huawei")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterHUAWEI.html>"]
+    #[doc(alias = "vkCmdDrawClusterHUAWEI")]
+    pub fn draw_cluster_huawei(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        self.inner
+            .draw_cluster_huawei(group_count_x, group_count_y, group_count_z)
+    }
+    #[cfg(feature = "vendor-huawei")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterIndirectHUAWEI.html>"]
+    #[doc(alias = "vkCmdDrawClusterIndirectHUAWEI")]
+    pub fn draw_cluster_indirect_huawei(&self, buffer: &raw::Buffer, offset: DeviceSize) {
+        self.inner.draw_cluster_indirect_huawei(buffer, offset)
+    }
+}
+impl<Q: SupportsCompute, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, OutsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatch.html>"]
+    #[doc(alias = "vkCmdDispatch")]
+    pub fn dispatch(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        self.inner
+            .dispatch(group_count_x, group_count_y, group_count_z)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchIndirect.html>"]
+    #[doc(alias = "vkCmdDispatchIndirect")]
+    pub fn dispatch_indirect(&self, buffer: &raw::Buffer, offset: DeviceSize) {
+        self.inner.dispatch_indirect(buffer, offset)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchBase.html>"]
+    #[doc(alias = "vkCmdDispatchBase")]
+    pub fn dispatch_base(
+        &self,
+        base_group_x: u32,
+        base_group_y: u32,
+        base_group_z: u32,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) {
+        self.inner.dispatch_base(
+            base_group_x,
+            base_group_y,
+            base_group_z,
+            group_count_x,
+            group_count_y,
+            group_count_z,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchBaseKHR.html>"]
+    #[doc(alias = "vkCmdDispatchBaseKHR")]
+    pub fn dispatch_base_khr(
+        &self,
+        base_group_x: u32,
+        base_group_y: u32,
+        base_group_z: u32,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) {
+        self.inner.dispatch_base_khr(
+            base_group_x,
+            base_group_y,
+            base_group_z,
+            group_count_x,
+            group_count_y,
+            group_count_z,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructuresKHR.html>"]
+    #[doc(alias = "vkCmdBuildAccelerationStructuresKHR")]
+    pub fn build_acceleration_structures_khr<'a>(
+        &self,
+        p_infos: impl AsSlice<'a, AccelerationStructureBuildGeometryInfoKHR<'a>>,
+        pp_build_range_infos: &&AccelerationStructureBuildRangeInfoKHR,
+    ) {
+        self.inner
+            .build_acceleration_structures_khr(p_infos, pp_build_range_infos)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructuresIndirectKHR.html>"]
+    #[doc(alias = "vkCmdBuildAccelerationStructuresIndirectKHR")]
+    pub fn build_acceleration_structures_indirect_khr<'a>(
+        &self,
+        p_infos: impl AsSlice<'a, AccelerationStructureBuildGeometryInfoKHR<'a>>,
+        p_indirect_device_addresses: impl AsSlice<'a, DeviceAddress>,
+        p_indirect_strides: impl AsSlice<'a, u32>,
+        pp_max_primitive_counts: &&u32,
+    ) {
+        self.inner.build_acceleration_structures_indirect_khr(
+            p_infos,
+            p_indirect_device_addresses,
+            p_indirect_strides,
+            pp_max_primitive_counts,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureKHR.html>"]
+    #[doc(alias = "vkCmdCopyAccelerationStructureKHR")]
+    pub fn copy_acceleration_structure_khr(&self, p_info: &CopyAccelerationStructureInfoKHR) {
+        self.inner.copy_acceleration_structure_khr(p_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureToMemoryKHR.html>"]
+    #[doc(alias = "vkCmdCopyAccelerationStructureToMemoryKHR")]
+    pub fn copy_acceleration_structure_to_memory_khr(
+        &self,
+        p_info: &CopyAccelerationStructureToMemoryInfoKHR,
+    ) {
+        self.inner.copy_acceleration_structure_to_memory_khr(p_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToAccelerationStructureKHR.html>"]
+    #[doc(alias = "vkCmdCopyMemoryToAccelerationStructureKHR")]
+    pub fn copy_memory_to_acceleration_structure_khr(
+        &self,
+        p_info: &CopyMemoryToAccelerationStructureInfoKHR,
+    ) {
+        self.inner.copy_memory_to_acceleration_structure_khr(p_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesKHR.html>"]
+    #[doc(alias = "vkCmdWriteAccelerationStructuresPropertiesKHR")]
+    pub fn write_acceleration_structures_properties_khr<
+        'a,
+        V2: Alias<raw::AccelerationStructureKHR> + 'a,
+    >(
+        &self,
+        p_acceleration_structures: impl AsSlice<'a, V2>,
+        query_type: QueryType,
+        query_pool: &raw::QueryPool,
+        first_query: u32,
+    ) {
+        self.inner.write_acceleration_structures_properties_khr(
+            p_acceleration_structures,
+            query_type,
+            query_pool,
+            first_query,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysKHR.html>"]
+    #[doc(alias = "vkCmdTraceRaysKHR")]
+    pub fn trace_rays_khr(
+        &self,
+        p_raygen_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        p_miss_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        p_hit_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        p_callable_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) {
+        self.inner.trace_rays_khr(
+            p_raygen_shader_binding_table,
+            p_miss_shader_binding_table,
+            p_hit_shader_binding_table,
+            p_callable_shader_binding_table,
+            width,
+            height,
+            depth,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirectKHR.html>"]
+    #[doc(alias = "vkCmdTraceRaysIndirectKHR")]
+    pub fn trace_rays_indirect_khr(
+        &self,
+        p_raygen_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        p_miss_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        p_hit_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        p_callable_shader_binding_table: &StridedDeviceAddressRegionKHR,
+        indirect_device_address: DeviceAddress,
+    ) {
+        self.inner.trace_rays_indirect_khr(
+            p_raygen_shader_binding_table,
+            p_miss_shader_binding_table,
+            p_hit_shader_binding_table,
+            p_callable_shader_binding_table,
+            indirect_device_address,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRayTracingPipelineStackSizeKHR.html>"]
+    #[doc(alias = "vkCmdSetRayTracingPipelineStackSizeKHR")]
+    pub fn set_ray_tracing_pipeline_stack_size_khr(&self, pipeline_stack_size: u32) {
+        self.inner
+            .set_ray_tracing_pipeline_stack_size_khr(pipeline_stack_size)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructureNV.html>"]
+    #[doc(alias = "vkCmdBuildAccelerationStructureNV")]
+    pub fn build_acceleration_structure_nv(
+        &self,
+        p_info: &AccelerationStructureInfoNV,
+        instance_data: Option<&raw::Buffer>,
+        instance_offset: DeviceSize,
+        update: impl Into<Bool32>,
+        dst: &raw::AccelerationStructureNV,
+        src: Option<&raw::AccelerationStructureNV>,
+        scratch: &raw::Buffer,
+        scratch_offset: DeviceSize,
+    ) {
+        self.inner.build_acceleration_structure_nv(
+            p_info,
+            instance_data,
+            instance_offset,
+            update,
+            dst,
+            src,
+            scratch,
+            scratch_offset,
+        )
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureNV.html>"]
+    #[doc(alias = "vkCmdCopyAccelerationStructureNV")]
+    pub fn copy_acceleration_structure_nv(
+        &self,
+        dst: &raw::AccelerationStructureNV,
+        src: &raw::AccelerationStructureNV,
+        mode: CopyAccelerationStructureModeKHR,
+    ) {
+        self.inner.copy_acceleration_structure_nv(dst, src, mode)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysNV.html>"]
+    #[doc(alias = "vkCmdTraceRaysNV")]
+    pub fn trace_rays_nv(
+        &self,
+        raygen_shader_binding_table_buffer: &raw::Buffer,
+        raygen_shader_binding_offset: DeviceSize,
+        miss_shader_binding_table_buffer: Option<&raw::Buffer>,
+        miss_shader_binding_offset: DeviceSize,
+        miss_shader_binding_stride: DeviceSize,
+        hit_shader_binding_table_buffer: Option<&raw::Buffer>,
+        hit_shader_binding_offset: DeviceSize,
+        hit_shader_binding_stride: DeviceSize,
+        callable_shader_binding_table_buffer: Option<&raw::Buffer>,
+        callable_shader_binding_offset: DeviceSize,
+        callable_shader_binding_stride: DeviceSize,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) {
+        self.inner.trace_rays_nv(
+            raygen_shader_binding_table_buffer,
+            raygen_shader_binding_offset,
+            miss_shader_binding_table_buffer,
+            miss_shader_binding_offset,
+            miss_shader_binding_stride,
+            hit_shader_binding_table_buffer,
+            hit_shader_binding_offset,
+            hit_shader_binding_stride,
+            callable_shader_binding_table_buffer,
+            callable_shader_binding_offset,
+            callable_shader_binding_stride,
+            width,
+            height,
+            depth,
+        )
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesNV.html>"]
+    #[doc(alias = "vkCmdWriteAccelerationStructuresPropertiesNV")]
+    pub fn write_acceleration_structures_properties_nv<
+        'a,
+        V2: Alias<raw::AccelerationStructureNV> + 'a,
+    >(
+        &self,
+        p_acceleration_structures: impl AsSlice<'a, V2>,
+        query_type: QueryType,
+        query_pool: &raw::QueryPool,
+        first_query: u32,
+    ) {
+        self.inner.write_acceleration_structures_properties_nv(
+            p_acceleration_structures,
+            query_type,
+            query_pool,
+            first_query,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirect2KHR.html>"]
+    #[doc(alias = "vkCmdTraceRaysIndirect2KHR")]
+    pub fn trace_rays_indirect2_khr(&self, indirect_device_address: DeviceAddress) {
+        self.inner.trace_rays_indirect2_khr(indirect_device_address)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildMicromapsEXT.html>"]
+    #[doc(alias = "vkCmdBuildMicromapsEXT")]
+    pub fn build_micromaps_ext<'a>(&self, p_infos: impl AsSlice<'a, MicromapBuildInfoEXT<'a>>) {
+        self.inner.build_micromaps_ext(p_infos)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapEXT.html>"]
+    #[doc(alias = "vkCmdCopyMicromapEXT")]
+    pub fn copy_micromap_ext(&self, p_info: &CopyMicromapInfoEXT) {
+        self.inner.copy_micromap_ext(p_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapToMemoryEXT.html>"]
+    #[doc(alias = "vkCmdCopyMicromapToMemoryEXT")]
+    pub fn copy_micromap_to_memory_ext(&self, p_info: &CopyMicromapToMemoryInfoEXT) {
+        self.inner.copy_micromap_to_memory_ext(p_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToMicromapEXT.html>"]
+    #[doc(alias = "vkCmdCopyMemoryToMicromapEXT")]
+    pub fn copy_memory_to_micromap_ext(&self, p_info: &CopyMemoryToMicromapInfoEXT) {
+        self.inner.copy_memory_to_micromap_ext(p_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteMicromapsPropertiesEXT.html>"]
+    #[doc(alias = "vkCmdWriteMicromapsPropertiesEXT")]
+    pub fn write_micromaps_properties_ext<'a, V2: Alias<raw::MicromapEXT> + 'a>(
+        &self,
+        p_micromaps: impl AsSlice<'a, V2>,
+        query_type: QueryType,
+        query_pool: &raw::QueryPool,
+        first_query: u32,
+    ) {
+        self.inner
+            .write_micromaps_properties_ext(p_micromaps, query_type, query_pool, first_query)
+    }
+    #[cfg(feature = "vendor-huawei")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindInvocationMaskHUAWEI.html>"]
+    #[doc(alias = "vkCmdBindInvocationMaskHUAWEI")]
+    pub fn bind_invocation_mask_huawei(
+        &self,
+        image_view: Option<&raw::ImageView>,
+        image_layout: ImageLayout,
+    ) {
+        self.inner
+            .bind_invocation_mask_huawei(image_view, image_layout)
+    }
+}
+impl<Q: QueueType, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, OutsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer.html>"]
+    #[doc(alias = "vkCmdCopyBuffer")]
+    pub fn copy_buffer<'a>(
+        &self,
+        src_buffer: &raw::Buffer,
+        dst_buffer: &raw::Buffer,
+        p_regions: impl AsSlice<'a, BufferCopy>,
+    ) {
+        self.inner.copy_buffer(src_buffer, dst_buffer, p_regions)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage.html>"]
+    #[doc(alias = "vkCmdCopyImage")]
+    pub fn copy_image<'a>(
+        &self,
+        src_image: &raw::Image,
+        src_image_layout: ImageLayout,
+        dst_image: &raw::Image,
+        dst_image_layout: ImageLayout,
+        p_regions: impl AsSlice<'a, ImageCopy>,
+    ) {
+        self.inner.copy_image(
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            p_regions,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage.html>"]
+    #[doc(alias = "vkCmdCopyBufferToImage")]
+    pub fn copy_buffer_to_image<'a>(
+        &self,
+        src_buffer: &raw::Buffer,
+        dst_image: &raw::Image,
+        dst_image_layout: ImageLayout,
+        p_regions: impl AsSlice<'a, BufferImageCopy>,
+    ) {
+        self.inner
+            .copy_buffer_to_image(src_buffer, dst_image, dst_image_layout, p_regions)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer.html>"]
+    #[doc(alias = "vkCmdCopyImageToBuffer")]
+    pub fn copy_image_to_buffer<'a>(
+        &self,
+        src_image: &raw::Image,
+        src_image_layout: ImageLayout,
+        dst_buffer: &raw::Buffer,
+        p_regions: impl AsSlice<'a, BufferImageCopy>,
+    ) {
+        self.inner
+            .copy_image_to_buffer(src_image, src_image_layout, dst_buffer, p_regions)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdUpdateBuffer.html>"]
+    #[doc(alias = "vkCmdUpdateBuffer")]
+    pub fn update_buffer(
+        &self,
+        dst_buffer: &raw::Buffer,
+        dst_offset: DeviceSize,
+        data_size: DeviceSize,
+        p_data: VoidPtr,
+    ) {
+        self.inner
+            .update_buffer(dst_buffer, dst_offset, data_size, p_data)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdFillBuffer.html>"]
+    #[doc(alias = "vkCmdFillBuffer")]
+    pub fn fill_buffer(
+        &self,
+        dst_buffer: &raw::Buffer,
+        dst_offset: DeviceSize,
+        size: DeviceSize,
+        data: u32,
+    ) {
+        self.inner.fill_buffer(dst_buffer, dst_offset, size, data)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2.html>"]
+    #[doc(alias = "vkCmdCopyBuffer2")]
+    pub fn copy_buffer2(&self, p_copy_buffer_info: &CopyBufferInfo2) {
+        self.inner.copy_buffer2(p_copy_buffer_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2KHR.html>"]
+    #[doc(alias = "vkCmdCopyBuffer2KHR")]
+    pub fn copy_buffer2_khr(&self, p_copy_buffer_info: &CopyBufferInfo2) {
+        self.inner.copy_buffer2_khr(p_copy_buffer_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage2.html>"]
+    #[doc(alias = "vkCmdCopyImage2")]
+    pub fn copy_image2(&self, p_copy_image_info: &CopyImageInfo2) {
+        self.inner.copy_image2(p_copy_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage2KHR.html>"]
+    #[doc(alias = "vkCmdCopyImage2KHR")]
+    pub fn copy_image2_khr(&self, p_copy_image_info: &CopyImageInfo2) {
+        self.inner.copy_image2_khr(p_copy_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage2.html>"]
+    #[doc(alias = "vkCmdCopyBufferToImage2")]
+    pub fn copy_buffer_to_image2(&self, p_copy_buffer_to_image_info: &CopyBufferToImageInfo2) {
+        self.inner
+            .copy_buffer_to_image2(p_copy_buffer_to_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage2KHR.html>"]
+    #[doc(alias = "vkCmdCopyBufferToImage2KHR")]
+    pub fn copy_buffer_to_image2_khr(&self, p_copy_buffer_to_image_info: &CopyBufferToImageInfo2) {
+        self.inner
+            .copy_buffer_to_image2_khr(p_copy_buffer_to_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer2.html>"]
+    #[doc(alias = "vkCmdCopyImageToBuffer2")]
+    pub fn copy_image_to_buffer2(&self, p_copy_image_to_buffer_info: &CopyImageToBufferInfo2) {
+        self.inner
+            .copy_image_to_buffer2(p_copy_image_to_buffer_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer2KHR.html>"]
+    #[doc(alias = "vkCmdCopyImageToBuffer2KHR")]
+    pub fn copy_image_to_buffer2_khr(&self, p_copy_image_to_buffer_info: &CopyImageToBufferInfo2) {
+        self.inner
+            .copy_image_to_buffer2_khr(p_copy_image_to_buffer_info)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryIndirectNV.html>"]
+    #[doc(alias = "vkCmdCopyMemoryIndirectNV")]
+    pub fn copy_memory_indirect_nv(
+        &self,
+        copy_buffer_address: DeviceAddress,
+        copy_count: u32,
+        stride: u32,
+    ) {
+        self.inner
+            .copy_memory_indirect_nv(copy_buffer_address, copy_count, stride)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToImageIndirectNV.html>"]
+    #[doc(alias = "vkCmdCopyMemoryToImageIndirectNV")]
+    pub fn copy_memory_to_image_indirect_nv<'a>(
+        &self,
+        copy_buffer_address: DeviceAddress,
+        stride: u32,
+        dst_image: &raw::Image,
+        dst_image_layout: ImageLayout,
+        p_image_subresources: impl AsSlice<'a, ImageSubresourceLayers>,
+    ) {
+        self.inner.copy_memory_to_image_indirect_nv(
+            copy_buffer_address,
+            stride,
+            dst_image,
+            dst_image_layout,
+            p_image_subresources,
+        )
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdUpdatePipelineIndirectBufferNV.html>"]
+    #[doc(alias = "vkCmdUpdatePipelineIndirectBufferNV")]
+    pub fn update_pipeline_indirect_buffer_nv(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        pipeline: &raw::Pipeline,
+    ) {
+        self.inner
+            .update_pipeline_indirect_buffer_nv(pipeline_bind_point, pipeline)
+    }
+}
+impl<Q: SupportsGraphics, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, OutsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage.html>"]
+    #[doc(alias = "vkCmdBlitImage")]
+    pub fn blit_image<'a>(
+        &self,
+        src_image: &raw::Image,
+        src_image_layout: ImageLayout,
+        dst_image: &raw::Image,
+        dst_image_layout: ImageLayout,
+        p_regions: impl AsSlice<'a, ImageBlit>,
+        filter: Filter,
+    ) {
+        self.inner.blit_image(
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            p_regions,
+            filter,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdClearDepthStencilImage.html>"]
+    #[doc(alias = "vkCmdClearDepthStencilImage")]
+    pub fn clear_depth_stencil_image<'a>(
+        &self,
+        image: &raw::Image,
+        image_layout: ImageLayout,
+        p_depth_stencil: &ClearDepthStencilValue,
+        p_ranges: impl AsSlice<'a, ImageSubresourceRange>,
+    ) {
+        self.inner
+            .clear_depth_stencil_image(image, image_layout, p_depth_stencil, p_ranges)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage.html>"]
+    #[doc(alias = "vkCmdResolveImage")]
+    pub fn resolve_image<'a>(
+        &self,
+        src_image: &raw::Image,
+        src_image_layout: ImageLayout,
+        dst_image: &raw::Image,
+        dst_image_layout: ImageLayout,
+        p_regions: impl AsSlice<'a, ImageResolve>,
+    ) {
+        self.inner.resolve_image(
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            p_regions,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage2.html>"]
+    #[doc(alias = "vkCmdBlitImage2")]
+    pub fn blit_image2(&self, p_blit_image_info: &BlitImageInfo2) {
+        self.inner.blit_image2(p_blit_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage2KHR.html>"]
+    #[doc(alias = "vkCmdBlitImage2KHR")]
+    pub fn blit_image2_khr(&self, p_blit_image_info: &BlitImageInfo2) {
+        self.inner.blit_image2_khr(p_blit_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage2.html>"]
+    #[doc(alias = "vkCmdResolveImage2")]
+    pub fn resolve_image2(&self, p_resolve_image_info: &ResolveImageInfo2) {
+        self.inner.resolve_image2(p_resolve_image_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage2KHR.html>"]
+    #[doc(alias = "vkCmdResolveImage2KHR")]
+    pub fn resolve_image2_khr(&self, p_resolve_image_info: &ResolveImageInfo2) {
+        self.inner.resolve_image2_khr(p_resolve_image_info)
+    }
+}
+impl<Q: SupportsGraphicsOrCompute, L: Level, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, OutsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdClearColorImage.html>"]
+    #[doc(alias = "vkCmdClearColorImage")]
+    pub fn clear_color_image<'a>(
+        &self,
+        image: &raw::Image,
+        image_layout: ImageLayout,
+        p_color: &ClearColorValue,
+        p_ranges: impl AsSlice<'a, ImageSubresourceRange>,
+    ) {
+        self.inner
+            .clear_color_image(image, image_layout, p_color, p_ranges)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent.html>"]
+    #[doc(alias = "vkCmdSetEvent")]
+    pub fn set_event(&self, event: &raw::Event, stage_mask: PipelineStageFlags) {
+        self.inner.set_event(event, stage_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent.html>"]
+    #[doc(alias = "vkCmdResetEvent")]
+    pub fn reset_event(&self, event: &raw::Event, stage_mask: PipelineStageFlags) {
+        self.inner.reset_event(event, stage_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetQueryPool.html>"]
+    #[doc(alias = "vkCmdResetQueryPool")]
+    pub fn reset_query_pool(
+        &self,
+        query_pool: &raw::QueryPool,
+        first_query: u32,
+        query_count: u32,
+    ) {
+        self.inner
+            .reset_query_pool(query_pool, first_query, query_count)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyQueryPoolResults.html>"]
+    #[doc(alias = "vkCmdCopyQueryPoolResults")]
+    pub fn copy_query_pool_results(
+        &self,
+        query_pool: &raw::QueryPool,
+        first_query: u32,
+        query_count: u32,
+        dst_buffer: &raw::Buffer,
+        dst_offset: DeviceSize,
+        stride: DeviceSize,
+        flags: QueryResultFlags,
+    ) {
+        self.inner.copy_query_pool_results(
+            query_pool,
+            first_query,
+            query_count,
+            dst_buffer,
+            dst_offset,
+            stride,
+            flags,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent2.html>"]
+    #[doc(alias = "vkCmdSetEvent2")]
+    pub fn set_event2(&self, event: &raw::Event, p_dependency_info: &DependencyInfo) {
+        self.inner.set_event2(event, p_dependency_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent2KHR.html>"]
+    #[doc(alias = "vkCmdSetEvent2KHR")]
+    pub fn set_event2_khr(&self, event: &raw::Event, p_dependency_info: &DependencyInfo) {
+        self.inner.set_event2_khr(event, p_dependency_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent2.html>"]
+    #[doc(alias = "vkCmdResetEvent2")]
+    pub fn reset_event2(&self, event: &raw::Event, stage_mask: PipelineStageFlags2) {
+        self.inner.reset_event2(event, stage_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent2KHR.html>"]
+    #[doc(alias = "vkCmdResetEvent2KHR")]
+    pub fn reset_event2_khr(&self, event: &raw::Event, stage_mask: PipelineStageFlags2) {
+        self.inner.reset_event2_khr(event, stage_mask)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryNV.html>"]
+    #[doc(alias = "vkCmdDecompressMemoryNV")]
+    pub fn decompress_memory_nv<'a>(
+        &self,
+        p_decompress_memory_regions: impl AsSlice<'a, DecompressMemoryRegionNV>,
+    ) {
+        self.inner.decompress_memory_nv(p_decompress_memory_regions)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryIndirectCountNV.html>"]
+    #[doc(alias = "vkCmdDecompressMemoryIndirectCountNV")]
+    pub fn decompress_memory_indirect_count_nv(
+        &self,
+        indirect_commands_address: DeviceAddress,
+        indirect_commands_count_address: DeviceAddress,
+        stride: u32,
+    ) {
+        self.inner.decompress_memory_indirect_count_nv(
+            indirect_commands_address,
+            indirect_commands_count_address,
+            stride,
+        )
+    }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdInitializeGraphScratchMemoryAMDX.html>"]
+    #[doc(alias = "vkCmdInitializeGraphScratchMemoryAMDX")]
+    pub fn initialize_graph_scratch_memory_amdx(&self, scratch: DeviceAddress) {
+        self.inner.initialize_graph_scratch_memory_amdx(scratch)
+    }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphAMDX.html>"]
+    #[doc(alias = "vkCmdDispatchGraphAMDX")]
+    pub fn dispatch_graph_amdx(
+        &self,
+        scratch: DeviceAddress,
+        p_count_info: &DispatchGraphCountInfoAMDX,
+    ) {
+        self.inner.dispatch_graph_amdx(scratch, p_count_info)
+    }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphIndirectAMDX.html>"]
+    #[doc(alias = "vkCmdDispatchGraphIndirectAMDX")]
+    pub fn dispatch_graph_indirect_amdx(
+        &self,
+        scratch: DeviceAddress,
+        p_count_info: &DispatchGraphCountInfoAMDX,
+    ) {
+        self.inner
+            .dispatch_graph_indirect_amdx(scratch, p_count_info)
+    }
+    #[cfg(all(feature = "vendor-amdx", feature = "provisional"))]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchGraphIndirectCountAMDX.html>"]
+    #[doc(alias = "vkCmdDispatchGraphIndirectCountAMDX")]
+    pub fn dispatch_graph_indirect_count_amdx(
+        &self,
+        scratch: DeviceAddress,
+        count_info: DeviceAddress,
+    ) {
+        self.inner
+            .dispatch_graph_indirect_count_amdx(scratch, count_info)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html>"]
+    #[doc(alias = "vkCmdPreprocessGeneratedCommandsNV")]
+    pub fn preprocess_generated_commands_nv(
+        &self,
+        p_generated_commands_info: &GeneratedCommandsInfoNV,
+    ) {
+        self.inner
+            .preprocess_generated_commands_nv(p_generated_commands_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsEXT.html>"]
+    #[doc(alias = "vkCmdPreprocessGeneratedCommandsEXT")]
+    pub fn preprocess_generated_commands_ext(
+        &self,
+        p_generated_commands_info: &GeneratedCommandsInfoEXT,
+        state_command_buffer: &raw::CommandBuffer,
+    ) {
+        self.inner
+            .preprocess_generated_commands_ext(p_generated_commands_info, state_command_buffer)
+    }
+}
+impl<Q: QueueType, L: Level, P: RenderPassState, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, L, P, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier.html>"]
+    #[doc(alias = "vkCmdPipelineBarrier")]
+    pub fn pipeline_barrier<'a>(
+        &self,
+        src_stage_mask: PipelineStageFlags,
+        dst_stage_mask: PipelineStageFlags,
+        dependency_flags: DependencyFlags,
+        p_memory_barriers: impl AsSlice<'a, MemoryBarrier<'a>>,
+        p_buffer_memory_barriers: impl AsSlice<'a, BufferMemoryBarrier<'a>>,
+        p_image_memory_barriers: impl AsSlice<'a, ImageMemoryBarrier<'a>>,
+    ) {
+        self.inner.pipeline_barrier(
+            src_stage_mask,
+            dst_stage_mask,
+            dependency_flags,
+            p_memory_barriers,
+            p_buffer_memory_barriers,
+            p_image_memory_barriers,
+        )
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteTimestamp.html>"]
+    #[doc(alias = "vkCmdWriteTimestamp")]
+    pub fn write_timestamp(
+        &self,
+        pipeline_stage: PipelineStageFlags,
+        query_pool: &raw::QueryPool,
+        query: u32,
+    ) {
+        self.inner
+            .write_timestamp(pipeline_stage, query_pool, query)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteCommands.html>"]
+    #[doc(alias = "vkCmdExecuteCommands")]
+    pub fn execute_commands<'a, V2: Alias<raw::CommandBuffer> + 'a>(
+        &self,
+        p_command_buffers: impl AsSlice<'a, V2>,
+    ) {
+        self.inner.execute_commands(p_command_buffers)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDeviceMask.html>"]
+    #[doc(alias = "vkCmdSetDeviceMask")]
+    pub fn set_device_mask(&self, device_mask: u32) {
+        self.inner.set_device_mask(device_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDeviceMaskKHR.html>"]
+    #[doc(alias = "vkCmdSetDeviceMaskKHR")]
+    pub fn set_device_mask_khr(&self, device_mask: u32) {
+        self.inner.set_device_mask_khr(device_mask)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2.html>"]
+    #[doc(alias = "vkCmdPipelineBarrier2")]
+    pub fn pipeline_barrier2(&self, p_dependency_info: &DependencyInfo) {
+        self.inner.pipeline_barrier2(p_dependency_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2KHR.html>"]
+    #[doc(alias = "vkCmdPipelineBarrier2KHR")]
+    pub fn pipeline_barrier2_khr(&self, p_dependency_info: &DependencyInfo) {
+        self.inner.pipeline_barrier2_khr(p_dependency_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteTimestamp2.html>"]
+    #[doc(alias = "vkCmdWriteTimestamp2")]
+    pub fn write_timestamp2(
+        &self,
+        stage: PipelineStageFlags2,
+        query_pool: &raw::QueryPool,
+        query: u32,
+    ) {
+        self.inner.write_timestamp2(stage, query_pool, query)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteTimestamp2KHR.html>"]
+    #[doc(alias = "vkCmdWriteTimestamp2KHR")]
+    pub fn write_timestamp2_khr(
+        &self,
+        stage: PipelineStageFlags2,
+        query_pool: &raw::QueryPool,
+        query: u32,
+    ) {
+        self.inner.write_timestamp2_khr(stage, query_pool, query)
+    }
+    #[cfg(feature = "vendor-amd")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarkerAMD.html>"]
+    #[doc(alias = "vkCmdWriteBufferMarkerAMD")]
+    pub fn write_buffer_marker_amd(
+        &self,
+        pipeline_stage: PipelineStageFlags,
+        dst_buffer: &raw::Buffer,
+        dst_offset: DeviceSize,
+        marker: u32,
+    ) {
+        self.inner
+            .write_buffer_marker_amd(pipeline_stage, dst_buffer, dst_offset, marker)
+    }
+    #[cfg(feature = "vendor-nv")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCheckpointNV.html>"]
+    #[doc(alias = "vkCmdSetCheckpointNV")]
+    pub fn set_checkpoint_nv(&self, p_checkpoint_marker: VoidPtr) {
+        self.inner.set_checkpoint_nv(p_checkpoint_marker)
+    }
+    #[cfg(feature = "vendor-intel")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceMarkerINTEL.html>"]
+    #[doc(alias = "vkCmdSetPerformanceMarkerINTEL")]
+    pub fn set_performance_marker_intel(
+        &self,
+        p_marker_info: &PerformanceMarkerInfoINTEL,
+    ) -> Result<()> {
+        self.inner.set_performance_marker_intel(p_marker_info)
+    }
+    #[cfg(feature = "vendor-intel")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceStreamMarkerINTEL.html>"]
+    #[doc(alias = "vkCmdSetPerformanceStreamMarkerINTEL")]
+    pub fn set_performance_stream_marker_intel(
+        &self,
+        p_marker_info: &PerformanceStreamMarkerInfoINTEL,
+    ) -> Result<()> {
+        self.inner
+            .set_performance_stream_marker_intel(p_marker_info)
+    }
+    #[cfg(feature = "vendor-intel")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceOverrideINTEL.html>"]
+    #[doc(alias = "vkCmdSetPerformanceOverrideINTEL")]
+    pub fn set_performance_override_intel(
+        &self,
+        p_override_info: &PerformanceOverrideInfoINTEL,
+    ) -> Result<()> {
+        self.inner.set_performance_override_intel(p_override_info)
+    }
+    #[cfg(feature = "vendor-amd")]
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarker2AMD.html>"]
+    #[doc(alias = "vkCmdWriteBufferMarker2AMD")]
+    pub fn write_buffer_marker2_amd(
+        &self,
+        stage: PipelineStageFlags2,
+        dst_buffer: &raw::Buffer,
+        dst_offset: DeviceSize,
+        marker: u32,
+    ) {
+        self.inner
+            .write_buffer_marker2_amd(stage, dst_buffer, dst_offset, marker)
+    }
+}
+impl<Q: SupportsGraphics, D: Dispatcher, A: Allocator>
+    CommandBuffer<Q, Primary, InsideRenderPass, D, A>
+{
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdNextSubpass.html>"]
+    #[doc(alias = "vkCmdNextSubpass")]
+    pub fn next_subpass(&self, contents: SubpassContents) {
+        self.inner.next_subpass(contents)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdNextSubpass2.html>"]
+    #[doc(alias = "vkCmdNextSubpass2")]
+    pub fn next_subpass2(
+        &self,
+        p_subpass_begin_info: &SubpassBeginInfo,
+        p_subpass_end_info: &SubpassEndInfo,
+    ) {
+        self.inner
+            .next_subpass2(p_subpass_begin_info, p_subpass_end_info)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdNextSubpass2KHR.html>"]
+    #[doc(alias = "vkCmdNextSubpass2KHR")]
+    pub fn next_subpass2_khr(
+        &self,
+        p_subpass_begin_info: &SubpassBeginInfo,
+        p_subpass_end_info: &SubpassEndInfo,
+    ) {
+        self.inner
+            .next_subpass2_khr(p_subpass_begin_info, p_subpass_end_info)
+    }
+}
@@ -0,0 +1,7361 @@
+use std::{array, ffi::CStr, marker::PhantomData, ptr};
+#[doc = r" Equivalent of the `VK_MAKE_VIDEO_STD_VERSION` macro"]
+pub const fn make_video_std_version(major: u32, minor: u32, patch: u32) -> u32 {
+    (major << 22) | (minor << 12) | patch
+}
+pub const STD_VIDEO_H264_CPB_CNT_LIST_SIZE: u32 = 32;
+pub const STD_VIDEO_H264_SCALING_LIST_4X4_NUM_LISTS: u32 = 6;
+pub const STD_VIDEO_H264_SCALING_LIST_4X4_NUM_ELEMENTS: u32 = 16;
+pub const STD_VIDEO_H264_SCALING_LIST_8X8_NUM_LISTS: u32 = 6;
+pub const STD_VIDEO_H264_SCALING_LIST_8X8_NUM_ELEMENTS: u32 = 64;
+pub const STD_VIDEO_H264_MAX_NUM_LIST_REF: u32 = 32;
+pub const STD_VIDEO_H264_MAX_CHROMA_PLANES: u32 = 2;
+pub const STD_VIDEO_H264_NO_REFERENCE_PICTURE: u32 = 0xFF;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264ChromaFormatIdc {
+    Monochrome = 0,
+    Format420 = 1,
+    Format422 = 2,
+    Format444 = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264ProfileIdc {
+    Baseline = 66,
+    Main = 77,
+    High = 100,
+    High444Predictive = 244,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264LevelIdc {
+    Level10 = 0,
+    Level11 = 1,
+    Level12 = 2,
+    Level13 = 3,
+    Level20 = 4,
+    Level21 = 5,
+    Level22 = 6,
+    Level30 = 7,
+    Level31 = 8,
+    Level32 = 9,
+    Level40 = 10,
+    Level41 = 11,
+    Level42 = 12,
+    Level50 = 13,
+    Level51 = 14,
+    Level52 = 15,
+    Level60 = 16,
+    Level61 = 17,
+    Level62 = 18,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264PocType {
+    Type0 = 0,
+    Type1 = 1,
+    Type2 = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264AspectRatioIdc {
+    Unspecified = 0,
+    Square = 1,
+    Ratio1211 = 2,
+    Ratio1011 = 3,
+    Ratio1611 = 4,
+    Ratio4033 = 5,
+    Ratio2411 = 6,
+    Ratio2011 = 7,
+    Ratio3211 = 8,
+    Ratio8033 = 9,
+    Ratio1811 = 10,
+    Ratio1511 = 11,
+    Ratio6433 = 12,
+    Ratio16099 = 13,
+    Ratio43 = 14,
+    Ratio32 = 15,
+    Ratio21 = 16,
+    ExtendedSar = 255,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264WeightedBipredIdc {
+    Default = 0,
+    Explicit = 1,
+    Implicit = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264ModificationOfPicNumsIdc {
+    ShortTermSubtract = 0,
+    ShortTermAdd = 1,
+    LongTerm = 2,
+    End = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264MemMgmtControlOp {
+    End = 0,
+    UnmarkShortTerm = 1,
+    UnmarkLongTerm = 2,
+    MarkLongTerm = 3,
+    SetMaxLongTermIndex = 4,
+    UnmarkAll = 5,
+    MarkCurrentAsLongTerm = 6,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264CabacInitIdc {
+    Idc0 = 0,
+    Idc1 = 1,
+    Idc2 = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264DisableDeblockingFilterIdc {
+    Disabled = 0,
+    Enabled = 1,
+    Partial = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264SliceType {
+    P = 0,
+    B = 1,
+    I = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264PictureType {
+    P = 0,
+    B = 1,
+    I = 2,
+    Idr = 5,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH264NonVclNaluType {
+    Sps = 0,
+    Pps = 1,
+    Aud = 2,
+    Prefix = 3,
+    EndOfSequence = 4,
+    EndOfStream = 5,
+    Precoded = 6,
+    Invalid = 0x7FFFFFFF,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH264SpsVuiFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH264SpsVuiFlags {}
+unsafe impl Sync for StdVideoH264SpsVuiFlags {}
+impl StdVideoH264SpsVuiFlags {
+    #[inline]
+    pub fn aspect_ratio_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_aspect_ratio_info_present_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn overscan_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_overscan_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn overscan_appropriate_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_overscan_appropriate_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn video_signal_type_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_video_signal_type_present_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn video_full_range_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_video_full_range_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn color_description_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_color_description_present_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn chroma_loc_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_chroma_loc_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn timing_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_timing_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn fixed_frame_rate_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_fixed_frame_rate_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn bitstream_restriction_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_bitstream_restriction_flag(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn nal_hrd_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_nal_hrd_parameters_present_flag(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn vcl_hrd_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_vcl_hrd_parameters_present_flag(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH264HrdParameters {
+    pub cpb_cnt_minus1: u8,
+    pub bit_rate_scale: u8,
+    pub cpb_size_scale: u8,
+    pub reserved1: u8,
+    pub bit_rate_value_minus1: [u32; STD_VIDEO_H264_CPB_CNT_LIST_SIZE as _],
+    pub cpb_size_value_minus1: [u32; STD_VIDEO_H264_CPB_CNT_LIST_SIZE as _],
+    pub cbr_flag: [u8; STD_VIDEO_H264_CPB_CNT_LIST_SIZE as _],
+    pub initial_cpb_removal_delay_length_minus1: u32,
+    pub cpb_removal_delay_length_minus1: u32,
+    pub dpb_output_delay_length_minus1: u32,
+    pub time_offset_length: u32,
+}
+unsafe impl Send for StdVideoH264HrdParameters {}
+unsafe impl Sync for StdVideoH264HrdParameters {}
+impl Default for StdVideoH264HrdParameters {
+    fn default() -> Self {
+        Self {
+            cpb_cnt_minus1: Default::default(),
+            bit_rate_scale: Default::default(),
+            cpb_size_scale: Default::default(),
+            reserved1: Default::default(),
+            bit_rate_value_minus1: array::from_fn(|_| Default::default()),
+            cpb_size_value_minus1: array::from_fn(|_| Default::default()),
+            cbr_flag: array::from_fn(|_| Default::default()),
+            initial_cpb_removal_delay_length_minus1: Default::default(),
+            cpb_removal_delay_length_minus1: Default::default(),
+            dpb_output_delay_length_minus1: Default::default(),
+            time_offset_length: Default::default(),
+        }
+    }
+}
+impl StdVideoH264HrdParameters {
+    #[inline]
+    pub fn cpb_cnt_minus1(mut self, value: u8) -> Self {
+        self.cpb_cnt_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn bit_rate_scale(mut self, value: u8) -> Self {
+        self.bit_rate_scale = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_size_scale(mut self, value: u8) -> Self {
+        self.cpb_size_scale = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn bit_rate_value_minus1(
+        mut self,
+        value: [u32; STD_VIDEO_H264_CPB_CNT_LIST_SIZE as _],
+    ) -> Self {
+        self.bit_rate_value_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_size_value_minus1(
+        mut self,
+        value: [u32; STD_VIDEO_H264_CPB_CNT_LIST_SIZE as _],
+    ) -> Self {
+        self.cpb_size_value_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cbr_flag(mut self, value: [u8; STD_VIDEO_H264_CPB_CNT_LIST_SIZE as _]) -> Self {
+        self.cbr_flag = value;
+        self
+    }
+    #[inline]
+    pub fn initial_cpb_removal_delay_length_minus1(mut self, value: u32) -> Self {
+        self.initial_cpb_removal_delay_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_removal_delay_length_minus1(mut self, value: u32) -> Self {
+        self.cpb_removal_delay_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn dpb_output_delay_length_minus1(mut self, value: u32) -> Self {
+        self.dpb_output_delay_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn time_offset_length(mut self, value: u32) -> Self {
+        self.time_offset_length = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoH264SequenceParameterSetVui<'a> {
+    pub flags: StdVideoH264SpsVuiFlags,
+    pub aspect_ratio_idc: StdVideoH264AspectRatioIdc,
+    pub sar_width: u16,
+    pub sar_height: u16,
+    pub video_format: u8,
+    pub colour_primaries: u8,
+    pub transfer_characteristics: u8,
+    pub matrix_coefficients: u8,
+    pub num_units_in_tick: u32,
+    pub time_scale: u32,
+    pub max_num_reorder_frames: u8,
+    pub max_dec_frame_buffering: u8,
+    pub chroma_sample_loc_type_top_field: u8,
+    pub chroma_sample_loc_type_bottom_field: u8,
+    pub reserved1: u32,
+    pub p_hrd_parameters: *const StdVideoH264HrdParameters,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH264SequenceParameterSetVui<'a> {}
+unsafe impl<'a> Sync for StdVideoH264SequenceParameterSetVui<'a> {}
+impl<'a> Default for StdVideoH264SequenceParameterSetVui<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            aspect_ratio_idc: StdVideoH264AspectRatioIdc::Unspecified,
+            sar_width: Default::default(),
+            sar_height: Default::default(),
+            video_format: Default::default(),
+            colour_primaries: Default::default(),
+            transfer_characteristics: Default::default(),
+            matrix_coefficients: Default::default(),
+            num_units_in_tick: Default::default(),
+            time_scale: Default::default(),
+            max_num_reorder_frames: Default::default(),
+            max_dec_frame_buffering: Default::default(),
+            chroma_sample_loc_type_top_field: Default::default(),
+            chroma_sample_loc_type_bottom_field: Default::default(),
+            reserved1: Default::default(),
+            p_hrd_parameters: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH264SequenceParameterSetVui<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH264SpsVuiFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn aspect_ratio_idc(mut self, value: StdVideoH264AspectRatioIdc) -> Self {
+        self.aspect_ratio_idc = value;
+        self
+    }
+    #[inline]
+    pub fn sar_width(mut self, value: u16) -> Self {
+        self.sar_width = value;
+        self
+    }
+    #[inline]
+    pub fn sar_height(mut self, value: u16) -> Self {
+        self.sar_height = value;
+        self
+    }
+    #[inline]
+    pub fn video_format(mut self, value: u8) -> Self {
+        self.video_format = value;
+        self
+    }
+    #[inline]
+    pub fn colour_primaries(mut self, value: u8) -> Self {
+        self.colour_primaries = value;
+        self
+    }
+    #[inline]
+    pub fn transfer_characteristics(mut self, value: u8) -> Self {
+        self.transfer_characteristics = value;
+        self
+    }
+    #[inline]
+    pub fn matrix_coefficients(mut self, value: u8) -> Self {
+        self.matrix_coefficients = value;
+        self
+    }
+    #[inline]
+    pub fn num_units_in_tick(mut self, value: u32) -> Self {
+        self.num_units_in_tick = value;
+        self
+    }
+    #[inline]
+    pub fn time_scale(mut self, value: u32) -> Self {
+        self.time_scale = value;
+        self
+    }
+    #[inline]
+    pub fn max_num_reorder_frames(mut self, value: u8) -> Self {
+        self.max_num_reorder_frames = value;
+        self
+    }
+    #[inline]
+    pub fn max_dec_frame_buffering(mut self, value: u8) -> Self {
+        self.max_dec_frame_buffering = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_sample_loc_type_top_field(mut self, value: u8) -> Self {
+        self.chroma_sample_loc_type_top_field = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_sample_loc_type_bottom_field(mut self, value: u8) -> Self {
+        self.chroma_sample_loc_type_bottom_field = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u32) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn hrd_parameters(mut self, value: &'a StdVideoH264HrdParameters) -> Self {
+        self.p_hrd_parameters = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH264SpsFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH264SpsFlags {}
+unsafe impl Sync for StdVideoH264SpsFlags {}
+impl StdVideoH264SpsFlags {
+    #[inline]
+    pub fn constraint_set0_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_constraint_set0_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn constraint_set1_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_constraint_set1_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn constraint_set2_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_constraint_set2_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn constraint_set3_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_constraint_set3_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn constraint_set4_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_constraint_set4_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn constraint_set5_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_constraint_set5_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn direct_8x8_inference_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_direct_8x8_inference_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn mb_adaptive_frame_field_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_mb_adaptive_frame_field_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn frame_mbs_only_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_frame_mbs_only_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn delta_pic_order_always_zero_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_delta_pic_order_always_zero_flag(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn separate_colour_plane_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_separate_colour_plane_flag(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn gaps_in_frame_num_value_allowed_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_gaps_in_frame_num_value_allowed_flag(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+    #[inline]
+    pub fn qpprime_y_zero_transform_bypass_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 12)) | ((value as u32) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_qpprime_y_zero_transform_bypass_flag(&self) -> bool {
+        self.bitfields & (1 << 12) != 0
+    }
+    #[inline]
+    pub fn frame_cropping_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 13)) | ((value as u32) << 13);
+        self
+    }
+    #[inline]
+    pub fn get_frame_cropping_flag(&self) -> bool {
+        self.bitfields & (1 << 13) != 0
+    }
+    #[inline]
+    pub fn seq_scaling_matrix_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 14)) | ((value as u32) << 14);
+        self
+    }
+    #[inline]
+    pub fn get_seq_scaling_matrix_present_flag(&self) -> bool {
+        self.bitfields & (1 << 14) != 0
+    }
+    #[inline]
+    pub fn vui_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 15)) | ((value as u32) << 15);
+        self
+    }
+    #[inline]
+    pub fn get_vui_parameters_present_flag(&self) -> bool {
+        self.bitfields & (1 << 15) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH264ScalingLists {
+    pub scaling_list_present_mask: u16,
+    pub use_default_scaling_matrix_mask: u16,
+    pub scaling_list4x4: [[u8; STD_VIDEO_H264_SCALING_LIST_4X4_NUM_ELEMENTS as _];
+        STD_VIDEO_H264_SCALING_LIST_4X4_NUM_LISTS as _],
+    pub scaling_list8x8: [[u8; STD_VIDEO_H264_SCALING_LIST_8X8_NUM_ELEMENTS as _];
+        STD_VIDEO_H264_SCALING_LIST_8X8_NUM_LISTS as _],
+}
+unsafe impl Send for StdVideoH264ScalingLists {}
+unsafe impl Sync for StdVideoH264ScalingLists {}
+impl Default for StdVideoH264ScalingLists {
+    fn default() -> Self {
+        Self {
+            scaling_list_present_mask: Default::default(),
+            use_default_scaling_matrix_mask: Default::default(),
+            scaling_list4x4: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            scaling_list8x8: array::from_fn(|_| array::from_fn(|_| Default::default())),
+        }
+    }
+}
+impl StdVideoH264ScalingLists {
+    #[inline]
+    pub fn scaling_list_present_mask(mut self, value: u16) -> Self {
+        self.scaling_list_present_mask = value;
+        self
+    }
+    #[inline]
+    pub fn use_default_scaling_matrix_mask(mut self, value: u16) -> Self {
+        self.use_default_scaling_matrix_mask = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list4x4(
+        mut self,
+        value: [[u8; STD_VIDEO_H264_SCALING_LIST_4X4_NUM_ELEMENTS as _];
+            STD_VIDEO_H264_SCALING_LIST_4X4_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list4x4 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list8x8(
+        mut self,
+        value: [[u8; STD_VIDEO_H264_SCALING_LIST_8X8_NUM_ELEMENTS as _];
+            STD_VIDEO_H264_SCALING_LIST_8X8_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list8x8 = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoH264SequenceParameterSet<'a> {
+    pub flags: StdVideoH264SpsFlags,
+    pub profile_idc: StdVideoH264ProfileIdc,
+    pub level_idc: StdVideoH264LevelIdc,
+    pub chroma_format_idc: StdVideoH264ChromaFormatIdc,
+    pub seq_parameter_set_id: u8,
+    pub bit_depth_luma_minus8: u8,
+    pub bit_depth_chroma_minus8: u8,
+    pub log2_max_frame_num_minus4: u8,
+    pub pic_order_cnt_type: StdVideoH264PocType,
+    pub offset_for_non_ref_pic: i32,
+    pub offset_for_top_to_bottom_field: i32,
+    pub log2_max_pic_order_cnt_lsb_minus4: u8,
+    pub num_ref_frames_in_pic_order_cnt_cycle: u8,
+    pub max_num_ref_frames: u8,
+    pub reserved1: u8,
+    pub pic_width_in_mbs_minus1: u32,
+    pub pic_height_in_map_units_minus1: u32,
+    pub frame_crop_left_offset: u32,
+    pub frame_crop_right_offset: u32,
+    pub frame_crop_top_offset: u32,
+    pub frame_crop_bottom_offset: u32,
+    pub reserved2: u32,
+    pub p_offset_for_ref_frame: *const i32,
+    pub p_scaling_lists: *const StdVideoH264ScalingLists,
+    pub p_sequence_parameter_set_vui: *const StdVideoH264SequenceParameterSetVui<'a>,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH264SequenceParameterSet<'a> {}
+unsafe impl<'a> Sync for StdVideoH264SequenceParameterSet<'a> {}
+impl<'a> Default for StdVideoH264SequenceParameterSet<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            profile_idc: StdVideoH264ProfileIdc::Baseline,
+            level_idc: StdVideoH264LevelIdc::Level10,
+            chroma_format_idc: StdVideoH264ChromaFormatIdc::Monochrome,
+            seq_parameter_set_id: Default::default(),
+            bit_depth_luma_minus8: Default::default(),
+            bit_depth_chroma_minus8: Default::default(),
+            log2_max_frame_num_minus4: Default::default(),
+            pic_order_cnt_type: StdVideoH264PocType::Type0,
+            offset_for_non_ref_pic: Default::default(),
+            offset_for_top_to_bottom_field: Default::default(),
+            log2_max_pic_order_cnt_lsb_minus4: Default::default(),
+            num_ref_frames_in_pic_order_cnt_cycle: Default::default(),
+            max_num_ref_frames: Default::default(),
+            reserved1: Default::default(),
+            pic_width_in_mbs_minus1: Default::default(),
+            pic_height_in_map_units_minus1: Default::default(),
+            frame_crop_left_offset: Default::default(),
+            frame_crop_right_offset: Default::default(),
+            frame_crop_top_offset: Default::default(),
+            frame_crop_bottom_offset: Default::default(),
+            reserved2: Default::default(),
+            p_offset_for_ref_frame: ptr::null(),
+            p_scaling_lists: ptr::null(),
+            p_sequence_parameter_set_vui: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH264SequenceParameterSet<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH264SpsFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn profile_idc(mut self, value: StdVideoH264ProfileIdc) -> Self {
+        self.profile_idc = value;
+        self
+    }
+    #[inline]
+    pub fn level_idc(mut self, value: StdVideoH264LevelIdc) -> Self {
+        self.level_idc = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_format_idc(mut self, value: StdVideoH264ChromaFormatIdc) -> Self {
+        self.chroma_format_idc = value;
+        self
+    }
+    #[inline]
+    pub fn seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn bit_depth_luma_minus8(mut self, value: u8) -> Self {
+        self.bit_depth_luma_minus8 = value;
+        self
+    }
+    #[inline]
+    pub fn bit_depth_chroma_minus8(mut self, value: u8) -> Self {
+        self.bit_depth_chroma_minus8 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_max_frame_num_minus4(mut self, value: u8) -> Self {
+        self.log2_max_frame_num_minus4 = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt_type(mut self, value: StdVideoH264PocType) -> Self {
+        self.pic_order_cnt_type = value;
+        self
+    }
+    #[inline]
+    pub fn offset_for_non_ref_pic(mut self, value: i32) -> Self {
+        self.offset_for_non_ref_pic = value;
+        self
+    }
+    #[inline]
+    pub fn offset_for_top_to_bottom_field(mut self, value: i32) -> Self {
+        self.offset_for_top_to_bottom_field = value;
+        self
+    }
+    #[inline]
+    pub fn log2_max_pic_order_cnt_lsb_minus4(mut self, value: u8) -> Self {
+        self.log2_max_pic_order_cnt_lsb_minus4 = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_frames_in_pic_order_cnt_cycle(mut self, value: u8) -> Self {
+        self.num_ref_frames_in_pic_order_cnt_cycle = value;
+        self
+    }
+    #[inline]
+    pub fn max_num_ref_frames(mut self, value: u8) -> Self {
+        self.max_num_ref_frames = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn pic_width_in_mbs_minus1(mut self, value: u32) -> Self {
+        self.pic_width_in_mbs_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn pic_height_in_map_units_minus1(mut self, value: u32) -> Self {
+        self.pic_height_in_map_units_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn frame_crop_left_offset(mut self, value: u32) -> Self {
+        self.frame_crop_left_offset = value;
+        self
+    }
+    #[inline]
+    pub fn frame_crop_right_offset(mut self, value: u32) -> Self {
+        self.frame_crop_right_offset = value;
+        self
+    }
+    #[inline]
+    pub fn frame_crop_top_offset(mut self, value: u32) -> Self {
+        self.frame_crop_top_offset = value;
+        self
+    }
+    #[inline]
+    pub fn frame_crop_bottom_offset(mut self, value: u32) -> Self {
+        self.frame_crop_bottom_offset = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u32) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn offset_for_ref_frame(mut self, value: &'a i32) -> Self {
+        self.p_offset_for_ref_frame = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn scaling_lists(mut self, value: &'a StdVideoH264ScalingLists) -> Self {
+        self.p_scaling_lists = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn sequence_parameter_set_vui(
+        mut self,
+        value: &'a StdVideoH264SequenceParameterSetVui<'a>,
+    ) -> Self {
+        self.p_sequence_parameter_set_vui = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH264PpsFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH264PpsFlags {}
+unsafe impl Sync for StdVideoH264PpsFlags {}
+impl StdVideoH264PpsFlags {
+    #[inline]
+    pub fn transform_8x8_mode_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_transform_8x8_mode_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn redundant_pic_cnt_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_redundant_pic_cnt_present_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn constrained_intra_pred_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_constrained_intra_pred_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn deblocking_filter_control_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_deblocking_filter_control_present_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn weighted_pred_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_weighted_pred_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn bottom_field_pic_order_in_frame_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_bottom_field_pic_order_in_frame_present_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn entropy_coding_mode_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_entropy_coding_mode_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn pic_scaling_matrix_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_pic_scaling_matrix_present_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoH264PictureParameterSet<'a> {
+    pub flags: StdVideoH264PpsFlags,
+    pub seq_parameter_set_id: u8,
+    pub pic_parameter_set_id: u8,
+    pub num_ref_idx_l0_default_active_minus1: u8,
+    pub num_ref_idx_l1_default_active_minus1: u8,
+    pub weighted_bipred_idc: StdVideoH264WeightedBipredIdc,
+    pub pic_init_qp_minus26: i8,
+    pub pic_init_qs_minus26: i8,
+    pub chroma_qp_index_offset: i8,
+    pub second_chroma_qp_index_offset: i8,
+    pub p_scaling_lists: *const StdVideoH264ScalingLists,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH264PictureParameterSet<'a> {}
+unsafe impl<'a> Sync for StdVideoH264PictureParameterSet<'a> {}
+impl<'a> Default for StdVideoH264PictureParameterSet<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            seq_parameter_set_id: Default::default(),
+            pic_parameter_set_id: Default::default(),
+            num_ref_idx_l0_default_active_minus1: Default::default(),
+            num_ref_idx_l1_default_active_minus1: Default::default(),
+            weighted_bipred_idc: StdVideoH264WeightedBipredIdc::Default,
+            pic_init_qp_minus26: Default::default(),
+            pic_init_qs_minus26: Default::default(),
+            chroma_qp_index_offset: Default::default(),
+            second_chroma_qp_index_offset: Default::default(),
+            p_scaling_lists: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH264PictureParameterSet<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH264PpsFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pic_parameter_set_id(mut self, value: u8) -> Self {
+        self.pic_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l0_default_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l0_default_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l1_default_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l1_default_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn weighted_bipred_idc(mut self, value: StdVideoH264WeightedBipredIdc) -> Self {
+        self.weighted_bipred_idc = value;
+        self
+    }
+    #[inline]
+    pub fn pic_init_qp_minus26(mut self, value: i8) -> Self {
+        self.pic_init_qp_minus26 = value;
+        self
+    }
+    #[inline]
+    pub fn pic_init_qs_minus26(mut self, value: i8) -> Self {
+        self.pic_init_qs_minus26 = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_qp_index_offset(mut self, value: i8) -> Self {
+        self.chroma_qp_index_offset = value;
+        self
+    }
+    #[inline]
+    pub fn second_chroma_qp_index_offset(mut self, value: i8) -> Self {
+        self.second_chroma_qp_index_offset = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_lists(mut self, value: &'a StdVideoH264ScalingLists) -> Self {
+        self.p_scaling_lists = ptr::from_ref(value);
+        self
+    }
+}
+pub const STD_VULKAN_VIDEO_CODEC_H264_DECODE_SPEC_VERSION: u32 = make_video_std_version(1, 0, 0);
+pub const STD_VULKAN_VIDEO_CODEC_H264_DECODE_EXTENSION_NAME: &CStr =
+    c"VK_STD_vulkan_video_codec_h264_decode";
+pub const STD_VIDEO_DECODE_H264_FIELD_ORDER_COUNT_LIST_SIZE: u32 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoDecodeH264FieldOrderCount {
+    Top = 0,
+    Bottom = 1,
+    Invalid = 0x7FFFFFFF,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeH264PictureInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoDecodeH264PictureInfoFlags {}
+unsafe impl Sync for StdVideoDecodeH264PictureInfoFlags {}
+impl StdVideoDecodeH264PictureInfoFlags {
+    #[inline]
+    pub fn field_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_field_pic_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn is_intra(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_is_intra(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn idr_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_idr_pic_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn bottom_field_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_bottom_field_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn is_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_is_reference(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn complementary_field_pair(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_complementary_field_pair(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoDecodeH264PictureInfo {
+    pub flags: StdVideoDecodeH264PictureInfoFlags,
+    pub seq_parameter_set_id: u8,
+    pub pic_parameter_set_id: u8,
+    pub reserved1: u8,
+    pub reserved2: u8,
+    pub frame_num: u16,
+    pub idr_pic_id: u16,
+    pub pic_order_cnt: [i32; STD_VIDEO_DECODE_H264_FIELD_ORDER_COUNT_LIST_SIZE as _],
+}
+unsafe impl Send for StdVideoDecodeH264PictureInfo {}
+unsafe impl Sync for StdVideoDecodeH264PictureInfo {}
+impl Default for StdVideoDecodeH264PictureInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            seq_parameter_set_id: Default::default(),
+            pic_parameter_set_id: Default::default(),
+            reserved1: Default::default(),
+            reserved2: Default::default(),
+            frame_num: Default::default(),
+            idr_pic_id: Default::default(),
+            pic_order_cnt: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoDecodeH264PictureInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoDecodeH264PictureInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pic_parameter_set_id(mut self, value: u8) -> Self {
+        self.pic_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u8) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn frame_num(mut self, value: u16) -> Self {
+        self.frame_num = value;
+        self
+    }
+    #[inline]
+    pub fn idr_pic_id(mut self, value: u16) -> Self {
+        self.idr_pic_id = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt(
+        mut self,
+        value: [i32; STD_VIDEO_DECODE_H264_FIELD_ORDER_COUNT_LIST_SIZE as _],
+    ) -> Self {
+        self.pic_order_cnt = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeH264ReferenceInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoDecodeH264ReferenceInfoFlags {}
+unsafe impl Sync for StdVideoDecodeH264ReferenceInfoFlags {}
+impl StdVideoDecodeH264ReferenceInfoFlags {
+    #[inline]
+    pub fn top_field_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_top_field_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn bottom_field_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_bottom_field_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn used_for_long_term_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_used_for_long_term_reference(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn is_non_existing(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_is_non_existing(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoDecodeH264ReferenceInfo {
+    pub flags: StdVideoDecodeH264ReferenceInfoFlags,
+    pub frame_num: u16,
+    pub reserved: u16,
+    pub pic_order_cnt: [i32; STD_VIDEO_DECODE_H264_FIELD_ORDER_COUNT_LIST_SIZE as _],
+}
+unsafe impl Send for StdVideoDecodeH264ReferenceInfo {}
+unsafe impl Sync for StdVideoDecodeH264ReferenceInfo {}
+impl Default for StdVideoDecodeH264ReferenceInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            frame_num: Default::default(),
+            reserved: Default::default(),
+            pic_order_cnt: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoDecodeH264ReferenceInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoDecodeH264ReferenceInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn frame_num(mut self, value: u16) -> Self {
+        self.frame_num = value;
+        self
+    }
+    #[inline]
+    pub fn reserved(mut self, value: u16) -> Self {
+        self.reserved = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt(
+        mut self,
+        value: [i32; STD_VIDEO_DECODE_H264_FIELD_ORDER_COUNT_LIST_SIZE as _],
+    ) -> Self {
+        self.pic_order_cnt = value;
+        self
+    }
+}
+pub const STD_VULKAN_VIDEO_CODEC_H264_ENCODE_SPEC_VERSION: u32 = make_video_std_version(1, 0, 0);
+pub const STD_VULKAN_VIDEO_CODEC_H264_ENCODE_EXTENSION_NAME: &CStr =
+    c"VK_STD_vulkan_video_codec_h264_encode";
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH264WeightTableFlags {
+    pub luma_weight_l0_flag: u32,
+    pub chroma_weight_l0_flag: u32,
+    pub luma_weight_l1_flag: u32,
+    pub chroma_weight_l1_flag: u32,
+}
+unsafe impl Send for StdVideoEncodeH264WeightTableFlags {}
+unsafe impl Sync for StdVideoEncodeH264WeightTableFlags {}
+impl StdVideoEncodeH264WeightTableFlags {
+    #[inline]
+    pub fn luma_weight_l0_flag(mut self, value: u32) -> Self {
+        self.luma_weight_l0_flag = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_weight_l0_flag(mut self, value: u32) -> Self {
+        self.chroma_weight_l0_flag = value;
+        self
+    }
+    #[inline]
+    pub fn luma_weight_l1_flag(mut self, value: u32) -> Self {
+        self.luma_weight_l1_flag = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_weight_l1_flag(mut self, value: u32) -> Self {
+        self.chroma_weight_l1_flag = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH264WeightTable {
+    pub flags: StdVideoEncodeH264WeightTableFlags,
+    pub luma_log2_weight_denom: u8,
+    pub chroma_log2_weight_denom: u8,
+    pub luma_weight_l0: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub luma_offset_l0: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub chroma_weight_l0:
+        [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub chroma_offset_l0:
+        [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub luma_weight_l1: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub luma_offset_l1: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub chroma_weight_l1:
+        [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub chroma_offset_l1:
+        [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+}
+unsafe impl Send for StdVideoEncodeH264WeightTable {}
+unsafe impl Sync for StdVideoEncodeH264WeightTable {}
+impl Default for StdVideoEncodeH264WeightTable {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            luma_log2_weight_denom: Default::default(),
+            chroma_log2_weight_denom: Default::default(),
+            luma_weight_l0: array::from_fn(|_| Default::default()),
+            luma_offset_l0: array::from_fn(|_| Default::default()),
+            chroma_weight_l0: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            chroma_offset_l0: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            luma_weight_l1: array::from_fn(|_| Default::default()),
+            luma_offset_l1: array::from_fn(|_| Default::default()),
+            chroma_weight_l1: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            chroma_offset_l1: array::from_fn(|_| array::from_fn(|_| Default::default())),
+        }
+    }
+}
+impl StdVideoEncodeH264WeightTable {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH264WeightTableFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn luma_log2_weight_denom(mut self, value: u8) -> Self {
+        self.luma_log2_weight_denom = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_log2_weight_denom(mut self, value: u8) -> Self {
+        self.chroma_log2_weight_denom = value;
+        self
+    }
+    #[inline]
+    pub fn luma_weight_l0(mut self, value: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _]) -> Self {
+        self.luma_weight_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn luma_offset_l0(mut self, value: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _]) -> Self {
+        self.luma_offset_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_weight_l0(
+        mut self,
+        value: [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.chroma_weight_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_offset_l0(
+        mut self,
+        value: [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.chroma_offset_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn luma_weight_l1(mut self, value: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _]) -> Self {
+        self.luma_weight_l1 = value;
+        self
+    }
+    #[inline]
+    pub fn luma_offset_l1(mut self, value: [i8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _]) -> Self {
+        self.luma_offset_l1 = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_weight_l1(
+        mut self,
+        value: [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.chroma_weight_l1 = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_offset_l1(
+        mut self,
+        value: [[i8; STD_VIDEO_H264_MAX_CHROMA_PLANES as _]; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.chroma_offset_l1 = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH264SliceHeaderFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH264SliceHeaderFlags {}
+unsafe impl Sync for StdVideoEncodeH264SliceHeaderFlags {}
+impl StdVideoEncodeH264SliceHeaderFlags {
+    #[inline]
+    pub fn direct_spatial_mv_pred_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_direct_spatial_mv_pred_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn num_ref_idx_active_override_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_num_ref_idx_active_override_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH264PictureInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH264PictureInfoFlags {}
+unsafe impl Sync for StdVideoEncodeH264PictureInfoFlags {}
+impl StdVideoEncodeH264PictureInfoFlags {
+    #[inline]
+    pub fn idr_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_idr_pic_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn is_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_is_reference(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn no_output_of_prior_pics_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_no_output_of_prior_pics_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn long_term_reference_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_long_term_reference_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn adaptive_ref_pic_marking_mode_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_adaptive_ref_pic_marking_mode_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH264ReferenceInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH264ReferenceInfoFlags {}
+unsafe impl Sync for StdVideoEncodeH264ReferenceInfoFlags {}
+impl StdVideoEncodeH264ReferenceInfoFlags {
+    #[inline]
+    pub fn used_for_long_term_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_used_for_long_term_reference(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH264ReferenceListsInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH264ReferenceListsInfoFlags {}
+unsafe impl Sync for StdVideoEncodeH264ReferenceListsInfoFlags {}
+impl StdVideoEncodeH264ReferenceListsInfoFlags {
+    #[inline]
+    pub fn ref_pic_list_modification_flag_l0(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_ref_pic_list_modification_flag_l0(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn ref_pic_list_modification_flag_l1(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_ref_pic_list_modification_flag_l1(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH264RefListModEntry {
+    pub modification_of_pic_nums_idc: StdVideoH264ModificationOfPicNumsIdc,
+    pub abs_diff_pic_num_minus1: u16,
+    pub long_term_pic_num: u16,
+}
+unsafe impl Send for StdVideoEncodeH264RefListModEntry {}
+unsafe impl Sync for StdVideoEncodeH264RefListModEntry {}
+impl Default for StdVideoEncodeH264RefListModEntry {
+    fn default() -> Self {
+        Self {
+            modification_of_pic_nums_idc: StdVideoH264ModificationOfPicNumsIdc::ShortTermSubtract,
+            abs_diff_pic_num_minus1: Default::default(),
+            long_term_pic_num: Default::default(),
+        }
+    }
+}
+impl StdVideoEncodeH264RefListModEntry {
+    #[inline]
+    pub fn modification_of_pic_nums_idc(
+        mut self,
+        value: StdVideoH264ModificationOfPicNumsIdc,
+    ) -> Self {
+        self.modification_of_pic_nums_idc = value;
+        self
+    }
+    #[inline]
+    pub fn abs_diff_pic_num_minus1(mut self, value: u16) -> Self {
+        self.abs_diff_pic_num_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn long_term_pic_num(mut self, value: u16) -> Self {
+        self.long_term_pic_num = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH264RefPicMarkingEntry {
+    pub memory_management_control_operation: StdVideoH264MemMgmtControlOp,
+    pub difference_of_pic_nums_minus1: u16,
+    pub long_term_pic_num: u16,
+    pub long_term_frame_idx: u16,
+    pub max_long_term_frame_idx_plus1: u16,
+}
+unsafe impl Send for StdVideoEncodeH264RefPicMarkingEntry {}
+unsafe impl Sync for StdVideoEncodeH264RefPicMarkingEntry {}
+impl Default for StdVideoEncodeH264RefPicMarkingEntry {
+    fn default() -> Self {
+        Self {
+            memory_management_control_operation: StdVideoH264MemMgmtControlOp::End,
+            difference_of_pic_nums_minus1: Default::default(),
+            long_term_pic_num: Default::default(),
+            long_term_frame_idx: Default::default(),
+            max_long_term_frame_idx_plus1: Default::default(),
+        }
+    }
+}
+impl StdVideoEncodeH264RefPicMarkingEntry {
+    #[inline]
+    pub fn memory_management_control_operation(
+        mut self,
+        value: StdVideoH264MemMgmtControlOp,
+    ) -> Self {
+        self.memory_management_control_operation = value;
+        self
+    }
+    #[inline]
+    pub fn difference_of_pic_nums_minus1(mut self, value: u16) -> Self {
+        self.difference_of_pic_nums_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn long_term_pic_num(mut self, value: u16) -> Self {
+        self.long_term_pic_num = value;
+        self
+    }
+    #[inline]
+    pub fn long_term_frame_idx(mut self, value: u16) -> Self {
+        self.long_term_frame_idx = value;
+        self
+    }
+    #[inline]
+    pub fn max_long_term_frame_idx_plus1(mut self, value: u16) -> Self {
+        self.max_long_term_frame_idx_plus1 = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoEncodeH264ReferenceListsInfo<'a> {
+    pub flags: StdVideoEncodeH264ReferenceListsInfoFlags,
+    pub num_ref_idx_l0_active_minus1: u8,
+    pub num_ref_idx_l1_active_minus1: u8,
+    pub ref_pic_list0: [u8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub ref_pic_list1: [u8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _],
+    pub ref_list0_mod_op_count: u8,
+    pub ref_list1_mod_op_count: u8,
+    pub ref_pic_marking_op_count: u8,
+    pub reserved1: [u8; 7u16 as _],
+    pub p_ref_list0_mod_operations: *const StdVideoEncodeH264RefListModEntry,
+    pub p_ref_list1_mod_operations: *const StdVideoEncodeH264RefListModEntry,
+    pub p_ref_pic_marking_operations: *const StdVideoEncodeH264RefPicMarkingEntry,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoEncodeH264ReferenceListsInfo<'a> {}
+unsafe impl<'a> Sync for StdVideoEncodeH264ReferenceListsInfo<'a> {}
+impl<'a> Default for StdVideoEncodeH264ReferenceListsInfo<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            num_ref_idx_l0_active_minus1: Default::default(),
+            num_ref_idx_l1_active_minus1: Default::default(),
+            ref_pic_list0: array::from_fn(|_| Default::default()),
+            ref_pic_list1: array::from_fn(|_| Default::default()),
+            ref_list0_mod_op_count: Default::default(),
+            ref_list1_mod_op_count: Default::default(),
+            ref_pic_marking_op_count: Default::default(),
+            reserved1: array::from_fn(|_| Default::default()),
+            p_ref_list0_mod_operations: ptr::null(),
+            p_ref_list1_mod_operations: ptr::null(),
+            p_ref_pic_marking_operations: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoEncodeH264ReferenceListsInfo<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH264ReferenceListsInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l0_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l0_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l1_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l1_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_list0(mut self, value: [u8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _]) -> Self {
+        self.ref_pic_list0 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_list1(mut self, value: [u8; STD_VIDEO_H264_MAX_NUM_LIST_REF as _]) -> Self {
+        self.ref_pic_list1 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_list0_mod_op_count(mut self, value: u8) -> Self {
+        self.ref_list0_mod_op_count = value;
+        self
+    }
+    #[inline]
+    pub fn ref_list1_mod_op_count(mut self, value: u8) -> Self {
+        self.ref_list1_mod_op_count = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_marking_op_count(mut self, value: u8) -> Self {
+        self.ref_pic_marking_op_count = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: [u8; 7u16 as _]) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_list0_mod_operations(
+        mut self,
+        value: &'a StdVideoEncodeH264RefListModEntry,
+    ) -> Self {
+        self.p_ref_list0_mod_operations = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn ref_list1_mod_operations(
+        mut self,
+        value: &'a StdVideoEncodeH264RefListModEntry,
+    ) -> Self {
+        self.p_ref_list1_mod_operations = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn ref_pic_marking_operations(
+        mut self,
+        value: &'a StdVideoEncodeH264RefPicMarkingEntry,
+    ) -> Self {
+        self.p_ref_pic_marking_operations = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoEncodeH264PictureInfo<'a> {
+    pub flags: StdVideoEncodeH264PictureInfoFlags,
+    pub seq_parameter_set_id: u8,
+    pub pic_parameter_set_id: u8,
+    pub idr_pic_id: u16,
+    pub primary_pic_type: StdVideoH264PictureType,
+    pub frame_num: u32,
+    pub pic_order_cnt: i32,
+    pub temporal_id: u8,
+    pub reserved1: [u8; 3u16 as _],
+    pub p_ref_lists: *const StdVideoEncodeH264ReferenceListsInfo<'a>,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoEncodeH264PictureInfo<'a> {}
+unsafe impl<'a> Sync for StdVideoEncodeH264PictureInfo<'a> {}
+impl<'a> Default for StdVideoEncodeH264PictureInfo<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            seq_parameter_set_id: Default::default(),
+            pic_parameter_set_id: Default::default(),
+            idr_pic_id: Default::default(),
+            primary_pic_type: StdVideoH264PictureType::P,
+            frame_num: Default::default(),
+            pic_order_cnt: Default::default(),
+            temporal_id: Default::default(),
+            reserved1: array::from_fn(|_| Default::default()),
+            p_ref_lists: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoEncodeH264PictureInfo<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH264PictureInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pic_parameter_set_id(mut self, value: u8) -> Self {
+        self.pic_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn idr_pic_id(mut self, value: u16) -> Self {
+        self.idr_pic_id = value;
+        self
+    }
+    #[inline]
+    pub fn primary_pic_type(mut self, value: StdVideoH264PictureType) -> Self {
+        self.primary_pic_type = value;
+        self
+    }
+    #[inline]
+    pub fn frame_num(mut self, value: u32) -> Self {
+        self.frame_num = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt(mut self, value: i32) -> Self {
+        self.pic_order_cnt = value;
+        self
+    }
+    #[inline]
+    pub fn temporal_id(mut self, value: u8) -> Self {
+        self.temporal_id = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: [u8; 3u16 as _]) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_lists(mut self, value: &'a StdVideoEncodeH264ReferenceListsInfo<'a>) -> Self {
+        self.p_ref_lists = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH264ReferenceInfo {
+    pub flags: StdVideoEncodeH264ReferenceInfoFlags,
+    pub primary_pic_type: StdVideoH264PictureType,
+    pub frame_num: u32,
+    pub pic_order_cnt: i32,
+    pub long_term_pic_num: u16,
+    pub long_term_frame_idx: u16,
+    pub temporal_id: u8,
+}
+unsafe impl Send for StdVideoEncodeH264ReferenceInfo {}
+unsafe impl Sync for StdVideoEncodeH264ReferenceInfo {}
+impl Default for StdVideoEncodeH264ReferenceInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            primary_pic_type: StdVideoH264PictureType::P,
+            frame_num: Default::default(),
+            pic_order_cnt: Default::default(),
+            long_term_pic_num: Default::default(),
+            long_term_frame_idx: Default::default(),
+            temporal_id: Default::default(),
+        }
+    }
+}
+impl StdVideoEncodeH264ReferenceInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH264ReferenceInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn primary_pic_type(mut self, value: StdVideoH264PictureType) -> Self {
+        self.primary_pic_type = value;
+        self
+    }
+    #[inline]
+    pub fn frame_num(mut self, value: u32) -> Self {
+        self.frame_num = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt(mut self, value: i32) -> Self {
+        self.pic_order_cnt = value;
+        self
+    }
+    #[inline]
+    pub fn long_term_pic_num(mut self, value: u16) -> Self {
+        self.long_term_pic_num = value;
+        self
+    }
+    #[inline]
+    pub fn long_term_frame_idx(mut self, value: u16) -> Self {
+        self.long_term_frame_idx = value;
+        self
+    }
+    #[inline]
+    pub fn temporal_id(mut self, value: u8) -> Self {
+        self.temporal_id = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoEncodeH264SliceHeader<'a> {
+    pub flags: StdVideoEncodeH264SliceHeaderFlags,
+    pub first_mb_in_slice: u32,
+    pub slice_type: StdVideoH264SliceType,
+    pub slice_alpha_c0_offset_div2: i8,
+    pub slice_beta_offset_div2: i8,
+    pub slice_qp_delta: i8,
+    pub reserved1: u8,
+    pub cabac_init_idc: StdVideoH264CabacInitIdc,
+    pub disable_deblocking_filter_idc: StdVideoH264DisableDeblockingFilterIdc,
+    pub p_weight_table: *const StdVideoEncodeH264WeightTable,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoEncodeH264SliceHeader<'a> {}
+unsafe impl<'a> Sync for StdVideoEncodeH264SliceHeader<'a> {}
+impl<'a> Default for StdVideoEncodeH264SliceHeader<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            first_mb_in_slice: Default::default(),
+            slice_type: StdVideoH264SliceType::P,
+            slice_alpha_c0_offset_div2: Default::default(),
+            slice_beta_offset_div2: Default::default(),
+            slice_qp_delta: Default::default(),
+            reserved1: Default::default(),
+            cabac_init_idc: StdVideoH264CabacInitIdc::Idc0,
+            disable_deblocking_filter_idc: StdVideoH264DisableDeblockingFilterIdc::Disabled,
+            p_weight_table: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoEncodeH264SliceHeader<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH264SliceHeaderFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn first_mb_in_slice(mut self, value: u32) -> Self {
+        self.first_mb_in_slice = value;
+        self
+    }
+    #[inline]
+    pub fn slice_type(mut self, value: StdVideoH264SliceType) -> Self {
+        self.slice_type = value;
+        self
+    }
+    #[inline]
+    pub fn slice_alpha_c0_offset_div2(mut self, value: i8) -> Self {
+        self.slice_alpha_c0_offset_div2 = value;
+        self
+    }
+    #[inline]
+    pub fn slice_beta_offset_div2(mut self, value: i8) -> Self {
+        self.slice_beta_offset_div2 = value;
+        self
+    }
+    #[inline]
+    pub fn slice_qp_delta(mut self, value: i8) -> Self {
+        self.slice_qp_delta = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn cabac_init_idc(mut self, value: StdVideoH264CabacInitIdc) -> Self {
+        self.cabac_init_idc = value;
+        self
+    }
+    #[inline]
+    pub fn disable_deblocking_filter_idc(
+        mut self,
+        value: StdVideoH264DisableDeblockingFilterIdc,
+    ) -> Self {
+        self.disable_deblocking_filter_idc = value;
+        self
+    }
+    #[inline]
+    pub fn weight_table(mut self, value: &'a StdVideoEncodeH264WeightTable) -> Self {
+        self.p_weight_table = ptr::from_ref(value);
+        self
+    }
+}
+pub const STD_VIDEO_H265_CPB_CNT_LIST_SIZE: u32 = 32;
+pub const STD_VIDEO_H265_SUBLAYERS_LIST_SIZE: u32 = 7;
+pub const STD_VIDEO_H265_SCALING_LIST_4X4_NUM_LISTS: u32 = 6;
+pub const STD_VIDEO_H265_SCALING_LIST_4X4_NUM_ELEMENTS: u32 = 16;
+pub const STD_VIDEO_H265_SCALING_LIST_8X8_NUM_LISTS: u32 = 6;
+pub const STD_VIDEO_H265_SCALING_LIST_8X8_NUM_ELEMENTS: u32 = 64;
+pub const STD_VIDEO_H265_SCALING_LIST_16X16_NUM_LISTS: u32 = 6;
+pub const STD_VIDEO_H265_SCALING_LIST_16X16_NUM_ELEMENTS: u32 = 64;
+pub const STD_VIDEO_H265_SCALING_LIST_32X32_NUM_LISTS: u32 = 2;
+pub const STD_VIDEO_H265_SCALING_LIST_32X32_NUM_ELEMENTS: u32 = 64;
+pub const STD_VIDEO_H265_CHROMA_QP_OFFSET_LIST_SIZE: u32 = 6;
+pub const STD_VIDEO_H265_CHROMA_QP_OFFSET_TILE_COLS_LIST_SIZE: u32 = 19;
+pub const STD_VIDEO_H265_CHROMA_QP_OFFSET_TILE_ROWS_LIST_SIZE: u32 = 21;
+pub const STD_VIDEO_H265_PREDICTOR_PALETTE_COMPONENTS_LIST_SIZE: u32 = 3;
+pub const STD_VIDEO_H265_PREDICTOR_PALETTE_COMP_ENTRIES_LIST_SIZE: u32 = 128;
+pub const STD_VIDEO_H265_MAX_NUM_LIST_REF: u32 = 15;
+pub const STD_VIDEO_H265_MAX_CHROMA_PLANES: u32 = 2;
+pub const STD_VIDEO_H265_MAX_SHORT_TERM_REF_PIC_SETS: u32 = 64;
+pub const STD_VIDEO_H265_MAX_DPB_SIZE: u32 = 16;
+pub const STD_VIDEO_H265_MAX_LONG_TERM_REF_PICS_SPS: u32 = 32;
+pub const STD_VIDEO_H265_MAX_LONG_TERM_PICS: u32 = 16;
+pub const STD_VIDEO_H265_MAX_DELTA_POC: u32 = 48;
+pub const STD_VIDEO_H265_NO_REFERENCE_PICTURE: u32 = 0xFF;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH265ChromaFormatIdc {
+    Monochrome = 0,
+    Format420 = 1,
+    Format422 = 2,
+    Format444 = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH265ProfileIdc {
+    Main = 1,
+    Main10 = 2,
+    MainStillPicture = 3,
+    FormatRangeExtensions = 4,
+    SccExtensions = 9,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH265LevelIdc {
+    Level10 = 0,
+    Level20 = 1,
+    Level21 = 2,
+    Level30 = 3,
+    Level31 = 4,
+    Level40 = 5,
+    Level41 = 6,
+    Level50 = 7,
+    Level51 = 8,
+    Level52 = 9,
+    Level60 = 10,
+    Level61 = 11,
+    Level62 = 12,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH265SliceType {
+    B = 0,
+    P = 1,
+    I = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH265PictureType {
+    P = 0,
+    B = 1,
+    I = 2,
+    Idr = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoH265AspectRatioIdc {
+    Unspecified = 0,
+    Square = 1,
+    Ratio1211 = 2,
+    Ratio1011 = 3,
+    Ratio1611 = 4,
+    Ratio4033 = 5,
+    Ratio2411 = 6,
+    Ratio2011 = 7,
+    Ratio3211 = 8,
+    Ratio8033 = 9,
+    Ratio1811 = 10,
+    Ratio1511 = 11,
+    Ratio6433 = 12,
+    Ratio16099 = 13,
+    Ratio43 = 14,
+    Ratio32 = 15,
+    Ratio21 = 16,
+    ExtendedSar = 255,
+    Invalid = 0x7FFFFFFF,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265DecPicBufMgr {
+    pub max_latency_increase_plus1: [u32; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    pub max_dec_pic_buffering_minus1: [u8; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    pub max_num_reorder_pics: [u8; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+}
+unsafe impl Send for StdVideoH265DecPicBufMgr {}
+unsafe impl Sync for StdVideoH265DecPicBufMgr {}
+impl Default for StdVideoH265DecPicBufMgr {
+    fn default() -> Self {
+        Self {
+            max_latency_increase_plus1: array::from_fn(|_| Default::default()),
+            max_dec_pic_buffering_minus1: array::from_fn(|_| Default::default()),
+            max_num_reorder_pics: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoH265DecPicBufMgr {
+    #[inline]
+    pub fn max_latency_increase_plus1(
+        mut self,
+        value: [u32; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    ) -> Self {
+        self.max_latency_increase_plus1 = value;
+        self
+    }
+    #[inline]
+    pub fn max_dec_pic_buffering_minus1(
+        mut self,
+        value: [u8; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    ) -> Self {
+        self.max_dec_pic_buffering_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn max_num_reorder_pics(
+        mut self,
+        value: [u8; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    ) -> Self {
+        self.max_num_reorder_pics = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265SubLayerHrdParameters {
+    pub bit_rate_value_minus1: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    pub cpb_size_value_minus1: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    pub cpb_size_du_value_minus1: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    pub bit_rate_du_value_minus1: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    pub cbr_flag: u32,
+}
+unsafe impl Send for StdVideoH265SubLayerHrdParameters {}
+unsafe impl Sync for StdVideoH265SubLayerHrdParameters {}
+impl Default for StdVideoH265SubLayerHrdParameters {
+    fn default() -> Self {
+        Self {
+            bit_rate_value_minus1: array::from_fn(|_| Default::default()),
+            cpb_size_value_minus1: array::from_fn(|_| Default::default()),
+            cpb_size_du_value_minus1: array::from_fn(|_| Default::default()),
+            bit_rate_du_value_minus1: array::from_fn(|_| Default::default()),
+            cbr_flag: Default::default(),
+        }
+    }
+}
+impl StdVideoH265SubLayerHrdParameters {
+    #[inline]
+    pub fn bit_rate_value_minus1(
+        mut self,
+        value: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    ) -> Self {
+        self.bit_rate_value_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_size_value_minus1(
+        mut self,
+        value: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    ) -> Self {
+        self.cpb_size_value_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_size_du_value_minus1(
+        mut self,
+        value: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    ) -> Self {
+        self.cpb_size_du_value_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn bit_rate_du_value_minus1(
+        mut self,
+        value: [u32; STD_VIDEO_H265_CPB_CNT_LIST_SIZE as _],
+    ) -> Self {
+        self.bit_rate_du_value_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cbr_flag(mut self, value: u32) -> Self {
+        self.cbr_flag = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265HrdFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265HrdFlags {}
+unsafe impl Sync for StdVideoH265HrdFlags {}
+impl StdVideoH265HrdFlags {
+    #[inline]
+    pub fn nal_hrd_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_nal_hrd_parameters_present_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn vcl_hrd_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_vcl_hrd_parameters_present_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn sub_pic_hrd_params_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_sub_pic_hrd_params_present_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn sub_pic_cpb_params_in_pic_timing_sei_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_sub_pic_cpb_params_in_pic_timing_sei_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn fixed_pic_rate_general_flag(mut self, value: u32) -> Self {
+        self.bitfields = (self.bitfields & !(255 << 4)) | ((value & 255) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_fixed_pic_rate_general_flag(&self) -> u32 {
+        (self.bitfields >> 4) & 255
+    }
+    #[inline]
+    pub fn fixed_pic_rate_within_cvs_flag(mut self, value: u32) -> Self {
+        self.bitfields = (self.bitfields & !(255 << 12)) | ((value & 255) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_fixed_pic_rate_within_cvs_flag(&self) -> u32 {
+        (self.bitfields >> 12) & 255
+    }
+    #[inline]
+    pub fn low_delay_hrd_flag(mut self, value: u32) -> Self {
+        self.bitfields = (self.bitfields & !(255 << 20)) | ((value & 255) << 20);
+        self
+    }
+    #[inline]
+    pub fn get_low_delay_hrd_flag(&self) -> u32 {
+        (self.bitfields >> 20) & 255
+    }
+}
+#[repr(C)]
+pub struct StdVideoH265HrdParameters<'a> {
+    pub flags: StdVideoH265HrdFlags,
+    pub tick_divisor_minus2: u8,
+    pub du_cpb_removal_delay_increment_length_minus1: u8,
+    pub dpb_output_delay_du_length_minus1: u8,
+    pub bit_rate_scale: u8,
+    pub cpb_size_scale: u8,
+    pub cpb_size_du_scale: u8,
+    pub initial_cpb_removal_delay_length_minus1: u8,
+    pub au_cpb_removal_delay_length_minus1: u8,
+    pub dpb_output_delay_length_minus1: u8,
+    pub cpb_cnt_minus1: [u8; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    pub elemental_duration_in_tc_minus1: [u16; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    pub reserved: [u16; 3u16 as _],
+    pub p_sub_layer_hrd_parameters_nal: *const StdVideoH265SubLayerHrdParameters,
+    pub p_sub_layer_hrd_parameters_vcl: *const StdVideoH265SubLayerHrdParameters,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH265HrdParameters<'a> {}
+unsafe impl<'a> Sync for StdVideoH265HrdParameters<'a> {}
+impl<'a> Default for StdVideoH265HrdParameters<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            tick_divisor_minus2: Default::default(),
+            du_cpb_removal_delay_increment_length_minus1: Default::default(),
+            dpb_output_delay_du_length_minus1: Default::default(),
+            bit_rate_scale: Default::default(),
+            cpb_size_scale: Default::default(),
+            cpb_size_du_scale: Default::default(),
+            initial_cpb_removal_delay_length_minus1: Default::default(),
+            au_cpb_removal_delay_length_minus1: Default::default(),
+            dpb_output_delay_length_minus1: Default::default(),
+            cpb_cnt_minus1: array::from_fn(|_| Default::default()),
+            elemental_duration_in_tc_minus1: array::from_fn(|_| Default::default()),
+            reserved: array::from_fn(|_| Default::default()),
+            p_sub_layer_hrd_parameters_nal: ptr::null(),
+            p_sub_layer_hrd_parameters_vcl: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH265HrdParameters<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265HrdFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn tick_divisor_minus2(mut self, value: u8) -> Self {
+        self.tick_divisor_minus2 = value;
+        self
+    }
+    #[inline]
+    pub fn du_cpb_removal_delay_increment_length_minus1(mut self, value: u8) -> Self {
+        self.du_cpb_removal_delay_increment_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn dpb_output_delay_du_length_minus1(mut self, value: u8) -> Self {
+        self.dpb_output_delay_du_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn bit_rate_scale(mut self, value: u8) -> Self {
+        self.bit_rate_scale = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_size_scale(mut self, value: u8) -> Self {
+        self.cpb_size_scale = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_size_du_scale(mut self, value: u8) -> Self {
+        self.cpb_size_du_scale = value;
+        self
+    }
+    #[inline]
+    pub fn initial_cpb_removal_delay_length_minus1(mut self, value: u8) -> Self {
+        self.initial_cpb_removal_delay_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn au_cpb_removal_delay_length_minus1(mut self, value: u8) -> Self {
+        self.au_cpb_removal_delay_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn dpb_output_delay_length_minus1(mut self, value: u8) -> Self {
+        self.dpb_output_delay_length_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cpb_cnt_minus1(mut self, value: [u8; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _]) -> Self {
+        self.cpb_cnt_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn elemental_duration_in_tc_minus1(
+        mut self,
+        value: [u16; STD_VIDEO_H265_SUBLAYERS_LIST_SIZE as _],
+    ) -> Self {
+        self.elemental_duration_in_tc_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved(mut self, value: [u16; 3u16 as _]) -> Self {
+        self.reserved = value;
+        self
+    }
+    #[inline]
+    pub fn sub_layer_hrd_parameters_nal(
+        mut self,
+        value: &'a StdVideoH265SubLayerHrdParameters,
+    ) -> Self {
+        self.p_sub_layer_hrd_parameters_nal = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn sub_layer_hrd_parameters_vcl(
+        mut self,
+        value: &'a StdVideoH265SubLayerHrdParameters,
+    ) -> Self {
+        self.p_sub_layer_hrd_parameters_vcl = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265VpsFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265VpsFlags {}
+unsafe impl Sync for StdVideoH265VpsFlags {}
+impl StdVideoH265VpsFlags {
+    #[inline]
+    pub fn vps_temporal_id_nesting_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_vps_temporal_id_nesting_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn vps_sub_layer_ordering_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_vps_sub_layer_ordering_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn vps_timing_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_vps_timing_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn vps_poc_proportional_to_timing_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_vps_poc_proportional_to_timing_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265ProfileTierLevelFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265ProfileTierLevelFlags {}
+unsafe impl Sync for StdVideoH265ProfileTierLevelFlags {}
+impl StdVideoH265ProfileTierLevelFlags {
+    #[inline]
+    pub fn general_tier_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_general_tier_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn general_progressive_source_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_general_progressive_source_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn general_interlaced_source_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_general_interlaced_source_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn general_non_packed_constraint_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_general_non_packed_constraint_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn general_frame_only_constraint_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_general_frame_only_constraint_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265ProfileTierLevel {
+    pub flags: StdVideoH265ProfileTierLevelFlags,
+    pub general_profile_idc: StdVideoH265ProfileIdc,
+    pub general_level_idc: StdVideoH265LevelIdc,
+}
+unsafe impl Send for StdVideoH265ProfileTierLevel {}
+unsafe impl Sync for StdVideoH265ProfileTierLevel {}
+impl Default for StdVideoH265ProfileTierLevel {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            general_profile_idc: StdVideoH265ProfileIdc::Main,
+            general_level_idc: StdVideoH265LevelIdc::Level10,
+        }
+    }
+}
+impl StdVideoH265ProfileTierLevel {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265ProfileTierLevelFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn general_profile_idc(mut self, value: StdVideoH265ProfileIdc) -> Self {
+        self.general_profile_idc = value;
+        self
+    }
+    #[inline]
+    pub fn general_level_idc(mut self, value: StdVideoH265LevelIdc) -> Self {
+        self.general_level_idc = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoH265VideoParameterSet<'a> {
+    pub flags: StdVideoH265VpsFlags,
+    pub vps_video_parameter_set_id: u8,
+    pub vps_max_sub_layers_minus1: u8,
+    pub reserved1: u8,
+    pub reserved2: u8,
+    pub vps_num_units_in_tick: u32,
+    pub vps_time_scale: u32,
+    pub vps_num_ticks_poc_diff_one_minus1: u32,
+    pub reserved3: u32,
+    pub p_dec_pic_buf_mgr: *const StdVideoH265DecPicBufMgr,
+    pub p_hrd_parameters: *const StdVideoH265HrdParameters<'a>,
+    pub p_profile_tier_level: *const StdVideoH265ProfileTierLevel,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH265VideoParameterSet<'a> {}
+unsafe impl<'a> Sync for StdVideoH265VideoParameterSet<'a> {}
+impl<'a> Default for StdVideoH265VideoParameterSet<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            vps_video_parameter_set_id: Default::default(),
+            vps_max_sub_layers_minus1: Default::default(),
+            reserved1: Default::default(),
+            reserved2: Default::default(),
+            vps_num_units_in_tick: Default::default(),
+            vps_time_scale: Default::default(),
+            vps_num_ticks_poc_diff_one_minus1: Default::default(),
+            reserved3: Default::default(),
+            p_dec_pic_buf_mgr: ptr::null(),
+            p_hrd_parameters: ptr::null(),
+            p_profile_tier_level: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH265VideoParameterSet<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265VpsFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn vps_video_parameter_set_id(mut self, value: u8) -> Self {
+        self.vps_video_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn vps_max_sub_layers_minus1(mut self, value: u8) -> Self {
+        self.vps_max_sub_layers_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u8) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn vps_num_units_in_tick(mut self, value: u32) -> Self {
+        self.vps_num_units_in_tick = value;
+        self
+    }
+    #[inline]
+    pub fn vps_time_scale(mut self, value: u32) -> Self {
+        self.vps_time_scale = value;
+        self
+    }
+    #[inline]
+    pub fn vps_num_ticks_poc_diff_one_minus1(mut self, value: u32) -> Self {
+        self.vps_num_ticks_poc_diff_one_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved3(mut self, value: u32) -> Self {
+        self.reserved3 = value;
+        self
+    }
+    #[inline]
+    pub fn dec_pic_buf_mgr(mut self, value: &'a StdVideoH265DecPicBufMgr) -> Self {
+        self.p_dec_pic_buf_mgr = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn hrd_parameters(mut self, value: &'a StdVideoH265HrdParameters<'a>) -> Self {
+        self.p_hrd_parameters = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn profile_tier_level(mut self, value: &'a StdVideoH265ProfileTierLevel) -> Self {
+        self.p_profile_tier_level = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265ScalingLists {
+    pub scaling_list4x4: [[u8; STD_VIDEO_H265_SCALING_LIST_4X4_NUM_ELEMENTS as _];
+        STD_VIDEO_H265_SCALING_LIST_4X4_NUM_LISTS as _],
+    pub scaling_list8x8: [[u8; STD_VIDEO_H265_SCALING_LIST_8X8_NUM_ELEMENTS as _];
+        STD_VIDEO_H265_SCALING_LIST_8X8_NUM_LISTS as _],
+    pub scaling_list16x16: [[u8; STD_VIDEO_H265_SCALING_LIST_16X16_NUM_ELEMENTS as _];
+        STD_VIDEO_H265_SCALING_LIST_16X16_NUM_LISTS as _],
+    pub scaling_list32x32: [[u8; STD_VIDEO_H265_SCALING_LIST_32X32_NUM_ELEMENTS as _];
+        STD_VIDEO_H265_SCALING_LIST_32X32_NUM_LISTS as _],
+    pub scaling_list_dccoef16x16: [u8; STD_VIDEO_H265_SCALING_LIST_16X16_NUM_LISTS as _],
+    pub scaling_list_dccoef32x32: [u8; STD_VIDEO_H265_SCALING_LIST_32X32_NUM_LISTS as _],
+}
+unsafe impl Send for StdVideoH265ScalingLists {}
+unsafe impl Sync for StdVideoH265ScalingLists {}
+impl Default for StdVideoH265ScalingLists {
+    fn default() -> Self {
+        Self {
+            scaling_list4x4: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            scaling_list8x8: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            scaling_list16x16: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            scaling_list32x32: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            scaling_list_dccoef16x16: array::from_fn(|_| Default::default()),
+            scaling_list_dccoef32x32: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoH265ScalingLists {
+    #[inline]
+    pub fn scaling_list4x4(
+        mut self,
+        value: [[u8; STD_VIDEO_H265_SCALING_LIST_4X4_NUM_ELEMENTS as _];
+            STD_VIDEO_H265_SCALING_LIST_4X4_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list4x4 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list8x8(
+        mut self,
+        value: [[u8; STD_VIDEO_H265_SCALING_LIST_8X8_NUM_ELEMENTS as _];
+            STD_VIDEO_H265_SCALING_LIST_8X8_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list8x8 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list16x16(
+        mut self,
+        value: [[u8; STD_VIDEO_H265_SCALING_LIST_16X16_NUM_ELEMENTS as _];
+            STD_VIDEO_H265_SCALING_LIST_16X16_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list16x16 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list32x32(
+        mut self,
+        value: [[u8; STD_VIDEO_H265_SCALING_LIST_32X32_NUM_ELEMENTS as _];
+            STD_VIDEO_H265_SCALING_LIST_32X32_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list32x32 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list_dccoef16x16(
+        mut self,
+        value: [u8; STD_VIDEO_H265_SCALING_LIST_16X16_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list_dccoef16x16 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_list_dccoef32x32(
+        mut self,
+        value: [u8; STD_VIDEO_H265_SCALING_LIST_32X32_NUM_LISTS as _],
+    ) -> Self {
+        self.scaling_list_dccoef32x32 = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265SpsVuiFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265SpsVuiFlags {}
+unsafe impl Sync for StdVideoH265SpsVuiFlags {}
+impl StdVideoH265SpsVuiFlags {
+    #[inline]
+    pub fn aspect_ratio_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_aspect_ratio_info_present_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn overscan_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_overscan_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn overscan_appropriate_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_overscan_appropriate_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn video_signal_type_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_video_signal_type_present_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn video_full_range_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_video_full_range_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn colour_description_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_colour_description_present_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn chroma_loc_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_chroma_loc_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn neutral_chroma_indication_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_neutral_chroma_indication_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn field_seq_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_field_seq_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn frame_field_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_frame_field_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn default_display_window_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_default_display_window_flag(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn vui_timing_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_vui_timing_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+    #[inline]
+    pub fn vui_poc_proportional_to_timing_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 12)) | ((value as u32) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_vui_poc_proportional_to_timing_flag(&self) -> bool {
+        self.bitfields & (1 << 12) != 0
+    }
+    #[inline]
+    pub fn vui_hrd_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 13)) | ((value as u32) << 13);
+        self
+    }
+    #[inline]
+    pub fn get_vui_hrd_parameters_present_flag(&self) -> bool {
+        self.bitfields & (1 << 13) != 0
+    }
+    #[inline]
+    pub fn bitstream_restriction_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 14)) | ((value as u32) << 14);
+        self
+    }
+    #[inline]
+    pub fn get_bitstream_restriction_flag(&self) -> bool {
+        self.bitfields & (1 << 14) != 0
+    }
+    #[inline]
+    pub fn tiles_fixed_structure_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 15)) | ((value as u32) << 15);
+        self
+    }
+    #[inline]
+    pub fn get_tiles_fixed_structure_flag(&self) -> bool {
+        self.bitfields & (1 << 15) != 0
+    }
+    #[inline]
+    pub fn motion_vectors_over_pic_boundaries_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 16)) | ((value as u32) << 16);
+        self
+    }
+    #[inline]
+    pub fn get_motion_vectors_over_pic_boundaries_flag(&self) -> bool {
+        self.bitfields & (1 << 16) != 0
+    }
+    #[inline]
+    pub fn restricted_ref_pic_lists_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 17)) | ((value as u32) << 17);
+        self
+    }
+    #[inline]
+    pub fn get_restricted_ref_pic_lists_flag(&self) -> bool {
+        self.bitfields & (1 << 17) != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoH265SequenceParameterSetVui<'a> {
+    pub flags: StdVideoH265SpsVuiFlags,
+    pub aspect_ratio_idc: StdVideoH265AspectRatioIdc,
+    pub sar_width: u16,
+    pub sar_height: u16,
+    pub video_format: u8,
+    pub colour_primaries: u8,
+    pub transfer_characteristics: u8,
+    pub matrix_coeffs: u8,
+    pub chroma_sample_loc_type_top_field: u8,
+    pub chroma_sample_loc_type_bottom_field: u8,
+    pub reserved1: u8,
+    pub reserved2: u8,
+    pub def_disp_win_left_offset: u16,
+    pub def_disp_win_right_offset: u16,
+    pub def_disp_win_top_offset: u16,
+    pub def_disp_win_bottom_offset: u16,
+    pub vui_num_units_in_tick: u32,
+    pub vui_time_scale: u32,
+    pub vui_num_ticks_poc_diff_one_minus1: u32,
+    pub min_spatial_segmentation_idc: u16,
+    pub reserved3: u16,
+    pub max_bytes_per_pic_denom: u8,
+    pub max_bits_per_min_cu_denom: u8,
+    pub log2_max_mv_length_horizontal: u8,
+    pub log2_max_mv_length_vertical: u8,
+    pub p_hrd_parameters: *const StdVideoH265HrdParameters<'a>,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH265SequenceParameterSetVui<'a> {}
+unsafe impl<'a> Sync for StdVideoH265SequenceParameterSetVui<'a> {}
+impl<'a> Default for StdVideoH265SequenceParameterSetVui<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            aspect_ratio_idc: StdVideoH265AspectRatioIdc::Unspecified,
+            sar_width: Default::default(),
+            sar_height: Default::default(),
+            video_format: Default::default(),
+            colour_primaries: Default::default(),
+            transfer_characteristics: Default::default(),
+            matrix_coeffs: Default::default(),
+            chroma_sample_loc_type_top_field: Default::default(),
+            chroma_sample_loc_type_bottom_field: Default::default(),
+            reserved1: Default::default(),
+            reserved2: Default::default(),
+            def_disp_win_left_offset: Default::default(),
+            def_disp_win_right_offset: Default::default(),
+            def_disp_win_top_offset: Default::default(),
+            def_disp_win_bottom_offset: Default::default(),
+            vui_num_units_in_tick: Default::default(),
+            vui_time_scale: Default::default(),
+            vui_num_ticks_poc_diff_one_minus1: Default::default(),
+            min_spatial_segmentation_idc: Default::default(),
+            reserved3: Default::default(),
+            max_bytes_per_pic_denom: Default::default(),
+            max_bits_per_min_cu_denom: Default::default(),
+            log2_max_mv_length_horizontal: Default::default(),
+            log2_max_mv_length_vertical: Default::default(),
+            p_hrd_parameters: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH265SequenceParameterSetVui<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265SpsVuiFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn aspect_ratio_idc(mut self, value: StdVideoH265AspectRatioIdc) -> Self {
+        self.aspect_ratio_idc = value;
+        self
+    }
+    #[inline]
+    pub fn sar_width(mut self, value: u16) -> Self {
+        self.sar_width = value;
+        self
+    }
+    #[inline]
+    pub fn sar_height(mut self, value: u16) -> Self {
+        self.sar_height = value;
+        self
+    }
+    #[inline]
+    pub fn video_format(mut self, value: u8) -> Self {
+        self.video_format = value;
+        self
+    }
+    #[inline]
+    pub fn colour_primaries(mut self, value: u8) -> Self {
+        self.colour_primaries = value;
+        self
+    }
+    #[inline]
+    pub fn transfer_characteristics(mut self, value: u8) -> Self {
+        self.transfer_characteristics = value;
+        self
+    }
+    #[inline]
+    pub fn matrix_coeffs(mut self, value: u8) -> Self {
+        self.matrix_coeffs = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_sample_loc_type_top_field(mut self, value: u8) -> Self {
+        self.chroma_sample_loc_type_top_field = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_sample_loc_type_bottom_field(mut self, value: u8) -> Self {
+        self.chroma_sample_loc_type_bottom_field = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u8) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn def_disp_win_left_offset(mut self, value: u16) -> Self {
+        self.def_disp_win_left_offset = value;
+        self
+    }
+    #[inline]
+    pub fn def_disp_win_right_offset(mut self, value: u16) -> Self {
+        self.def_disp_win_right_offset = value;
+        self
+    }
+    #[inline]
+    pub fn def_disp_win_top_offset(mut self, value: u16) -> Self {
+        self.def_disp_win_top_offset = value;
+        self
+    }
+    #[inline]
+    pub fn def_disp_win_bottom_offset(mut self, value: u16) -> Self {
+        self.def_disp_win_bottom_offset = value;
+        self
+    }
+    #[inline]
+    pub fn vui_num_units_in_tick(mut self, value: u32) -> Self {
+        self.vui_num_units_in_tick = value;
+        self
+    }
+    #[inline]
+    pub fn vui_time_scale(mut self, value: u32) -> Self {
+        self.vui_time_scale = value;
+        self
+    }
+    #[inline]
+    pub fn vui_num_ticks_poc_diff_one_minus1(mut self, value: u32) -> Self {
+        self.vui_num_ticks_poc_diff_one_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn min_spatial_segmentation_idc(mut self, value: u16) -> Self {
+        self.min_spatial_segmentation_idc = value;
+        self
+    }
+    #[inline]
+    pub fn reserved3(mut self, value: u16) -> Self {
+        self.reserved3 = value;
+        self
+    }
+    #[inline]
+    pub fn max_bytes_per_pic_denom(mut self, value: u8) -> Self {
+        self.max_bytes_per_pic_denom = value;
+        self
+    }
+    #[inline]
+    pub fn max_bits_per_min_cu_denom(mut self, value: u8) -> Self {
+        self.max_bits_per_min_cu_denom = value;
+        self
+    }
+    #[inline]
+    pub fn log2_max_mv_length_horizontal(mut self, value: u8) -> Self {
+        self.log2_max_mv_length_horizontal = value;
+        self
+    }
+    #[inline]
+    pub fn log2_max_mv_length_vertical(mut self, value: u8) -> Self {
+        self.log2_max_mv_length_vertical = value;
+        self
+    }
+    #[inline]
+    pub fn hrd_parameters(mut self, value: &'a StdVideoH265HrdParameters<'a>) -> Self {
+        self.p_hrd_parameters = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265PredictorPaletteEntries {
+    pub predictor_palette_entries: [[u16; STD_VIDEO_H265_PREDICTOR_PALETTE_COMP_ENTRIES_LIST_SIZE
+        as _];
+        STD_VIDEO_H265_PREDICTOR_PALETTE_COMPONENTS_LIST_SIZE as _],
+}
+unsafe impl Send for StdVideoH265PredictorPaletteEntries {}
+unsafe impl Sync for StdVideoH265PredictorPaletteEntries {}
+impl Default for StdVideoH265PredictorPaletteEntries {
+    fn default() -> Self {
+        Self {
+            predictor_palette_entries: array::from_fn(|_| array::from_fn(|_| Default::default())),
+        }
+    }
+}
+impl StdVideoH265PredictorPaletteEntries {
+    #[inline]
+    pub fn predictor_palette_entries(
+        mut self,
+        value: [[u16; STD_VIDEO_H265_PREDICTOR_PALETTE_COMP_ENTRIES_LIST_SIZE as _];
+            STD_VIDEO_H265_PREDICTOR_PALETTE_COMPONENTS_LIST_SIZE as _],
+    ) -> Self {
+        self.predictor_palette_entries = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265SpsFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265SpsFlags {}
+unsafe impl Sync for StdVideoH265SpsFlags {}
+impl StdVideoH265SpsFlags {
+    #[inline]
+    pub fn sps_temporal_id_nesting_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_sps_temporal_id_nesting_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn separate_colour_plane_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_separate_colour_plane_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn conformance_window_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_conformance_window_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn sps_sub_layer_ordering_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_sps_sub_layer_ordering_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn scaling_list_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_scaling_list_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn sps_scaling_list_data_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_sps_scaling_list_data_present_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn amp_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_amp_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn sample_adaptive_offset_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_sample_adaptive_offset_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn pcm_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_pcm_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn pcm_loop_filter_disabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_pcm_loop_filter_disabled_flag(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn long_term_ref_pics_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_long_term_ref_pics_present_flag(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn sps_temporal_mvp_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_sps_temporal_mvp_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+    #[inline]
+    pub fn strong_intra_smoothing_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 12)) | ((value as u32) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_strong_intra_smoothing_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 12) != 0
+    }
+    #[inline]
+    pub fn vui_parameters_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 13)) | ((value as u32) << 13);
+        self
+    }
+    #[inline]
+    pub fn get_vui_parameters_present_flag(&self) -> bool {
+        self.bitfields & (1 << 13) != 0
+    }
+    #[inline]
+    pub fn sps_extension_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 14)) | ((value as u32) << 14);
+        self
+    }
+    #[inline]
+    pub fn get_sps_extension_present_flag(&self) -> bool {
+        self.bitfields & (1 << 14) != 0
+    }
+    #[inline]
+    pub fn sps_range_extension_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 15)) | ((value as u32) << 15);
+        self
+    }
+    #[inline]
+    pub fn get_sps_range_extension_flag(&self) -> bool {
+        self.bitfields & (1 << 15) != 0
+    }
+    #[inline]
+    pub fn transform_skip_rotation_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 16)) | ((value as u32) << 16);
+        self
+    }
+    #[inline]
+    pub fn get_transform_skip_rotation_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 16) != 0
+    }
+    #[inline]
+    pub fn transform_skip_context_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 17)) | ((value as u32) << 17);
+        self
+    }
+    #[inline]
+    pub fn get_transform_skip_context_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 17) != 0
+    }
+    #[inline]
+    pub fn implicit_rdpcm_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 18)) | ((value as u32) << 18);
+        self
+    }
+    #[inline]
+    pub fn get_implicit_rdpcm_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 18) != 0
+    }
+    #[inline]
+    pub fn explicit_rdpcm_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 19)) | ((value as u32) << 19);
+        self
+    }
+    #[inline]
+    pub fn get_explicit_rdpcm_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 19) != 0
+    }
+    #[inline]
+    pub fn extended_precision_processing_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 20)) | ((value as u32) << 20);
+        self
+    }
+    #[inline]
+    pub fn get_extended_precision_processing_flag(&self) -> bool {
+        self.bitfields & (1 << 20) != 0
+    }
+    #[inline]
+    pub fn intra_smoothing_disabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 21)) | ((value as u32) << 21);
+        self
+    }
+    #[inline]
+    pub fn get_intra_smoothing_disabled_flag(&self) -> bool {
+        self.bitfields & (1 << 21) != 0
+    }
+    #[inline]
+    pub fn high_precision_offsets_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 22)) | ((value as u32) << 22);
+        self
+    }
+    #[inline]
+    pub fn get_high_precision_offsets_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 22) != 0
+    }
+    #[inline]
+    pub fn persistent_rice_adaptation_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 23)) | ((value as u32) << 23);
+        self
+    }
+    #[inline]
+    pub fn get_persistent_rice_adaptation_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 23) != 0
+    }
+    #[inline]
+    pub fn cabac_bypass_alignment_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 24)) | ((value as u32) << 24);
+        self
+    }
+    #[inline]
+    pub fn get_cabac_bypass_alignment_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 24) != 0
+    }
+    #[inline]
+    pub fn sps_scc_extension_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 25)) | ((value as u32) << 25);
+        self
+    }
+    #[inline]
+    pub fn get_sps_scc_extension_flag(&self) -> bool {
+        self.bitfields & (1 << 25) != 0
+    }
+    #[inline]
+    pub fn sps_curr_pic_ref_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 26)) | ((value as u32) << 26);
+        self
+    }
+    #[inline]
+    pub fn get_sps_curr_pic_ref_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 26) != 0
+    }
+    #[inline]
+    pub fn palette_mode_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 27)) | ((value as u32) << 27);
+        self
+    }
+    #[inline]
+    pub fn get_palette_mode_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 27) != 0
+    }
+    #[inline]
+    pub fn sps_palette_predictor_initializers_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 28)) | ((value as u32) << 28);
+        self
+    }
+    #[inline]
+    pub fn get_sps_palette_predictor_initializers_present_flag(&self) -> bool {
+        self.bitfields & (1 << 28) != 0
+    }
+    #[inline]
+    pub fn intra_boundary_filtering_disabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 29)) | ((value as u32) << 29);
+        self
+    }
+    #[inline]
+    pub fn get_intra_boundary_filtering_disabled_flag(&self) -> bool {
+        self.bitfields & (1 << 29) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265ShortTermRefPicSetFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265ShortTermRefPicSetFlags {}
+unsafe impl Sync for StdVideoH265ShortTermRefPicSetFlags {}
+impl StdVideoH265ShortTermRefPicSetFlags {
+    #[inline]
+    pub fn inter_ref_pic_set_prediction_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_inter_ref_pic_set_prediction_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn delta_rps_sign(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_delta_rps_sign(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265ShortTermRefPicSet {
+    pub flags: StdVideoH265ShortTermRefPicSetFlags,
+    pub delta_idx_minus1: u32,
+    pub use_delta_flag: u16,
+    pub abs_delta_rps_minus1: u16,
+    pub used_by_curr_pic_flag: u16,
+    pub used_by_curr_pic_s0_flag: u16,
+    pub used_by_curr_pic_s1_flag: u16,
+    pub reserved1: u16,
+    pub reserved2: u8,
+    pub reserved3: u8,
+    pub num_negative_pics: u8,
+    pub num_positive_pics: u8,
+    pub delta_poc_s0_minus1: [u16; STD_VIDEO_H265_MAX_DPB_SIZE as _],
+    pub delta_poc_s1_minus1: [u16; STD_VIDEO_H265_MAX_DPB_SIZE as _],
+}
+unsafe impl Send for StdVideoH265ShortTermRefPicSet {}
+unsafe impl Sync for StdVideoH265ShortTermRefPicSet {}
+impl Default for StdVideoH265ShortTermRefPicSet {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            delta_idx_minus1: Default::default(),
+            use_delta_flag: Default::default(),
+            abs_delta_rps_minus1: Default::default(),
+            used_by_curr_pic_flag: Default::default(),
+            used_by_curr_pic_s0_flag: Default::default(),
+            used_by_curr_pic_s1_flag: Default::default(),
+            reserved1: Default::default(),
+            reserved2: Default::default(),
+            reserved3: Default::default(),
+            num_negative_pics: Default::default(),
+            num_positive_pics: Default::default(),
+            delta_poc_s0_minus1: array::from_fn(|_| Default::default()),
+            delta_poc_s1_minus1: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoH265ShortTermRefPicSet {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265ShortTermRefPicSetFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn delta_idx_minus1(mut self, value: u32) -> Self {
+        self.delta_idx_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn use_delta_flag(mut self, value: u16) -> Self {
+        self.use_delta_flag = value;
+        self
+    }
+    #[inline]
+    pub fn abs_delta_rps_minus1(mut self, value: u16) -> Self {
+        self.abs_delta_rps_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn used_by_curr_pic_flag(mut self, value: u16) -> Self {
+        self.used_by_curr_pic_flag = value;
+        self
+    }
+    #[inline]
+    pub fn used_by_curr_pic_s0_flag(mut self, value: u16) -> Self {
+        self.used_by_curr_pic_s0_flag = value;
+        self
+    }
+    #[inline]
+    pub fn used_by_curr_pic_s1_flag(mut self, value: u16) -> Self {
+        self.used_by_curr_pic_s1_flag = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u16) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u8) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved3(mut self, value: u8) -> Self {
+        self.reserved3 = value;
+        self
+    }
+    #[inline]
+    pub fn num_negative_pics(mut self, value: u8) -> Self {
+        self.num_negative_pics = value;
+        self
+    }
+    #[inline]
+    pub fn num_positive_pics(mut self, value: u8) -> Self {
+        self.num_positive_pics = value;
+        self
+    }
+    #[inline]
+    pub fn delta_poc_s0_minus1(mut self, value: [u16; STD_VIDEO_H265_MAX_DPB_SIZE as _]) -> Self {
+        self.delta_poc_s0_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_poc_s1_minus1(mut self, value: [u16; STD_VIDEO_H265_MAX_DPB_SIZE as _]) -> Self {
+        self.delta_poc_s1_minus1 = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoH265LongTermRefPicsSps {
+    pub used_by_curr_pic_lt_sps_flag: u32,
+    pub lt_ref_pic_poc_lsb_sps: [u32; STD_VIDEO_H265_MAX_LONG_TERM_REF_PICS_SPS as _],
+}
+unsafe impl Send for StdVideoH265LongTermRefPicsSps {}
+unsafe impl Sync for StdVideoH265LongTermRefPicsSps {}
+impl Default for StdVideoH265LongTermRefPicsSps {
+    fn default() -> Self {
+        Self {
+            used_by_curr_pic_lt_sps_flag: Default::default(),
+            lt_ref_pic_poc_lsb_sps: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoH265LongTermRefPicsSps {
+    #[inline]
+    pub fn used_by_curr_pic_lt_sps_flag(mut self, value: u32) -> Self {
+        self.used_by_curr_pic_lt_sps_flag = value;
+        self
+    }
+    #[inline]
+    pub fn lt_ref_pic_poc_lsb_sps(
+        mut self,
+        value: [u32; STD_VIDEO_H265_MAX_LONG_TERM_REF_PICS_SPS as _],
+    ) -> Self {
+        self.lt_ref_pic_poc_lsb_sps = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoH265SequenceParameterSet<'a> {
+    pub flags: StdVideoH265SpsFlags,
+    pub chroma_format_idc: StdVideoH265ChromaFormatIdc,
+    pub pic_width_in_luma_samples: u32,
+    pub pic_height_in_luma_samples: u32,
+    pub sps_video_parameter_set_id: u8,
+    pub sps_max_sub_layers_minus1: u8,
+    pub sps_seq_parameter_set_id: u8,
+    pub bit_depth_luma_minus8: u8,
+    pub bit_depth_chroma_minus8: u8,
+    pub log2_max_pic_order_cnt_lsb_minus4: u8,
+    pub log2_min_luma_coding_block_size_minus3: u8,
+    pub log2_diff_max_min_luma_coding_block_size: u8,
+    pub log2_min_luma_transform_block_size_minus2: u8,
+    pub log2_diff_max_min_luma_transform_block_size: u8,
+    pub max_transform_hierarchy_depth_inter: u8,
+    pub max_transform_hierarchy_depth_intra: u8,
+    pub num_short_term_ref_pic_sets: u8,
+    pub num_long_term_ref_pics_sps: u8,
+    pub pcm_sample_bit_depth_luma_minus1: u8,
+    pub pcm_sample_bit_depth_chroma_minus1: u8,
+    pub log2_min_pcm_luma_coding_block_size_minus3: u8,
+    pub log2_diff_max_min_pcm_luma_coding_block_size: u8,
+    pub reserved1: u8,
+    pub reserved2: u8,
+    pub palette_max_size: u8,
+    pub delta_palette_max_predictor_size: u8,
+    pub motion_vector_resolution_control_idc: u8,
+    pub sps_num_palette_predictor_initializers_minus1: u8,
+    pub conf_win_left_offset: u32,
+    pub conf_win_right_offset: u32,
+    pub conf_win_top_offset: u32,
+    pub conf_win_bottom_offset: u32,
+    pub p_profile_tier_level: *const StdVideoH265ProfileTierLevel,
+    pub p_dec_pic_buf_mgr: *const StdVideoH265DecPicBufMgr,
+    pub p_scaling_lists: *const StdVideoH265ScalingLists,
+    pub p_short_term_ref_pic_set: *const StdVideoH265ShortTermRefPicSet,
+    pub p_long_term_ref_pics_sps: *const StdVideoH265LongTermRefPicsSps,
+    pub p_sequence_parameter_set_vui: *const StdVideoH265SequenceParameterSetVui<'a>,
+    pub p_predictor_palette_entries: *const StdVideoH265PredictorPaletteEntries,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH265SequenceParameterSet<'a> {}
+unsafe impl<'a> Sync for StdVideoH265SequenceParameterSet<'a> {}
+impl<'a> Default for StdVideoH265SequenceParameterSet<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            chroma_format_idc: StdVideoH265ChromaFormatIdc::Monochrome,
+            pic_width_in_luma_samples: Default::default(),
+            pic_height_in_luma_samples: Default::default(),
+            sps_video_parameter_set_id: Default::default(),
+            sps_max_sub_layers_minus1: Default::default(),
+            sps_seq_parameter_set_id: Default::default(),
+            bit_depth_luma_minus8: Default::default(),
+            bit_depth_chroma_minus8: Default::default(),
+            log2_max_pic_order_cnt_lsb_minus4: Default::default(),
+            log2_min_luma_coding_block_size_minus3: Default::default(),
+            log2_diff_max_min_luma_coding_block_size: Default::default(),
+            log2_min_luma_transform_block_size_minus2: Default::default(),
+            log2_diff_max_min_luma_transform_block_size: Default::default(),
+            max_transform_hierarchy_depth_inter: Default::default(),
+            max_transform_hierarchy_depth_intra: Default::default(),
+            num_short_term_ref_pic_sets: Default::default(),
+            num_long_term_ref_pics_sps: Default::default(),
+            pcm_sample_bit_depth_luma_minus1: Default::default(),
+            pcm_sample_bit_depth_chroma_minus1: Default::default(),
+            log2_min_pcm_luma_coding_block_size_minus3: Default::default(),
+            log2_diff_max_min_pcm_luma_coding_block_size: Default::default(),
+            reserved1: Default::default(),
+            reserved2: Default::default(),
+            palette_max_size: Default::default(),
+            delta_palette_max_predictor_size: Default::default(),
+            motion_vector_resolution_control_idc: Default::default(),
+            sps_num_palette_predictor_initializers_minus1: Default::default(),
+            conf_win_left_offset: Default::default(),
+            conf_win_right_offset: Default::default(),
+            conf_win_top_offset: Default::default(),
+            conf_win_bottom_offset: Default::default(),
+            p_profile_tier_level: ptr::null(),
+            p_dec_pic_buf_mgr: ptr::null(),
+            p_scaling_lists: ptr::null(),
+            p_short_term_ref_pic_set: ptr::null(),
+            p_long_term_ref_pics_sps: ptr::null(),
+            p_sequence_parameter_set_vui: ptr::null(),
+            p_predictor_palette_entries: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH265SequenceParameterSet<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265SpsFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_format_idc(mut self, value: StdVideoH265ChromaFormatIdc) -> Self {
+        self.chroma_format_idc = value;
+        self
+    }
+    #[inline]
+    pub fn pic_width_in_luma_samples(mut self, value: u32) -> Self {
+        self.pic_width_in_luma_samples = value;
+        self
+    }
+    #[inline]
+    pub fn pic_height_in_luma_samples(mut self, value: u32) -> Self {
+        self.pic_height_in_luma_samples = value;
+        self
+    }
+    #[inline]
+    pub fn sps_video_parameter_set_id(mut self, value: u8) -> Self {
+        self.sps_video_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn sps_max_sub_layers_minus1(mut self, value: u8) -> Self {
+        self.sps_max_sub_layers_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn sps_seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.sps_seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn bit_depth_luma_minus8(mut self, value: u8) -> Self {
+        self.bit_depth_luma_minus8 = value;
+        self
+    }
+    #[inline]
+    pub fn bit_depth_chroma_minus8(mut self, value: u8) -> Self {
+        self.bit_depth_chroma_minus8 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_max_pic_order_cnt_lsb_minus4(mut self, value: u8) -> Self {
+        self.log2_max_pic_order_cnt_lsb_minus4 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_min_luma_coding_block_size_minus3(mut self, value: u8) -> Self {
+        self.log2_min_luma_coding_block_size_minus3 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_diff_max_min_luma_coding_block_size(mut self, value: u8) -> Self {
+        self.log2_diff_max_min_luma_coding_block_size = value;
+        self
+    }
+    #[inline]
+    pub fn log2_min_luma_transform_block_size_minus2(mut self, value: u8) -> Self {
+        self.log2_min_luma_transform_block_size_minus2 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_diff_max_min_luma_transform_block_size(mut self, value: u8) -> Self {
+        self.log2_diff_max_min_luma_transform_block_size = value;
+        self
+    }
+    #[inline]
+    pub fn max_transform_hierarchy_depth_inter(mut self, value: u8) -> Self {
+        self.max_transform_hierarchy_depth_inter = value;
+        self
+    }
+    #[inline]
+    pub fn max_transform_hierarchy_depth_intra(mut self, value: u8) -> Self {
+        self.max_transform_hierarchy_depth_intra = value;
+        self
+    }
+    #[inline]
+    pub fn num_short_term_ref_pic_sets(mut self, value: u8) -> Self {
+        self.num_short_term_ref_pic_sets = value;
+        self
+    }
+    #[inline]
+    pub fn num_long_term_ref_pics_sps(mut self, value: u8) -> Self {
+        self.num_long_term_ref_pics_sps = value;
+        self
+    }
+    #[inline]
+    pub fn pcm_sample_bit_depth_luma_minus1(mut self, value: u8) -> Self {
+        self.pcm_sample_bit_depth_luma_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn pcm_sample_bit_depth_chroma_minus1(mut self, value: u8) -> Self {
+        self.pcm_sample_bit_depth_chroma_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_min_pcm_luma_coding_block_size_minus3(mut self, value: u8) -> Self {
+        self.log2_min_pcm_luma_coding_block_size_minus3 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_diff_max_min_pcm_luma_coding_block_size(mut self, value: u8) -> Self {
+        self.log2_diff_max_min_pcm_luma_coding_block_size = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u8) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn palette_max_size(mut self, value: u8) -> Self {
+        self.palette_max_size = value;
+        self
+    }
+    #[inline]
+    pub fn delta_palette_max_predictor_size(mut self, value: u8) -> Self {
+        self.delta_palette_max_predictor_size = value;
+        self
+    }
+    #[inline]
+    pub fn motion_vector_resolution_control_idc(mut self, value: u8) -> Self {
+        self.motion_vector_resolution_control_idc = value;
+        self
+    }
+    #[inline]
+    pub fn sps_num_palette_predictor_initializers_minus1(mut self, value: u8) -> Self {
+        self.sps_num_palette_predictor_initializers_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn conf_win_left_offset(mut self, value: u32) -> Self {
+        self.conf_win_left_offset = value;
+        self
+    }
+    #[inline]
+    pub fn conf_win_right_offset(mut self, value: u32) -> Self {
+        self.conf_win_right_offset = value;
+        self
+    }
+    #[inline]
+    pub fn conf_win_top_offset(mut self, value: u32) -> Self {
+        self.conf_win_top_offset = value;
+        self
+    }
+    #[inline]
+    pub fn conf_win_bottom_offset(mut self, value: u32) -> Self {
+        self.conf_win_bottom_offset = value;
+        self
+    }
+    #[inline]
+    pub fn profile_tier_level(mut self, value: &'a StdVideoH265ProfileTierLevel) -> Self {
+        self.p_profile_tier_level = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn dec_pic_buf_mgr(mut self, value: &'a StdVideoH265DecPicBufMgr) -> Self {
+        self.p_dec_pic_buf_mgr = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn scaling_lists(mut self, value: &'a StdVideoH265ScalingLists) -> Self {
+        self.p_scaling_lists = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn short_term_ref_pic_set(mut self, value: &'a StdVideoH265ShortTermRefPicSet) -> Self {
+        self.p_short_term_ref_pic_set = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn long_term_ref_pics_sps(mut self, value: &'a StdVideoH265LongTermRefPicsSps) -> Self {
+        self.p_long_term_ref_pics_sps = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn sequence_parameter_set_vui(
+        mut self,
+        value: &'a StdVideoH265SequenceParameterSetVui<'a>,
+    ) -> Self {
+        self.p_sequence_parameter_set_vui = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn predictor_palette_entries(
+        mut self,
+        value: &'a StdVideoH265PredictorPaletteEntries,
+    ) -> Self {
+        self.p_predictor_palette_entries = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoH265PpsFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoH265PpsFlags {}
+unsafe impl Sync for StdVideoH265PpsFlags {}
+impl StdVideoH265PpsFlags {
+    #[inline]
+    pub fn dependent_slice_segments_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_dependent_slice_segments_enabled_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn output_flag_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_output_flag_present_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn sign_data_hiding_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_sign_data_hiding_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn cabac_init_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_cabac_init_present_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn constrained_intra_pred_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_constrained_intra_pred_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn transform_skip_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_transform_skip_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn cu_qp_delta_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_cu_qp_delta_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn pps_slice_chroma_qp_offsets_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_pps_slice_chroma_qp_offsets_present_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn weighted_pred_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_weighted_pred_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn weighted_bipred_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_weighted_bipred_flag(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn transquant_bypass_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_transquant_bypass_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn tiles_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_tiles_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+    #[inline]
+    pub fn entropy_coding_sync_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 12)) | ((value as u32) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_entropy_coding_sync_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 12) != 0
+    }
+    #[inline]
+    pub fn uniform_spacing_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 13)) | ((value as u32) << 13);
+        self
+    }
+    #[inline]
+    pub fn get_uniform_spacing_flag(&self) -> bool {
+        self.bitfields & (1 << 13) != 0
+    }
+    #[inline]
+    pub fn loop_filter_across_tiles_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 14)) | ((value as u32) << 14);
+        self
+    }
+    #[inline]
+    pub fn get_loop_filter_across_tiles_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 14) != 0
+    }
+    #[inline]
+    pub fn pps_loop_filter_across_slices_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 15)) | ((value as u32) << 15);
+        self
+    }
+    #[inline]
+    pub fn get_pps_loop_filter_across_slices_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 15) != 0
+    }
+    #[inline]
+    pub fn deblocking_filter_control_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 16)) | ((value as u32) << 16);
+        self
+    }
+    #[inline]
+    pub fn get_deblocking_filter_control_present_flag(&self) -> bool {
+        self.bitfields & (1 << 16) != 0
+    }
+    #[inline]
+    pub fn deblocking_filter_override_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 17)) | ((value as u32) << 17);
+        self
+    }
+    #[inline]
+    pub fn get_deblocking_filter_override_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 17) != 0
+    }
+    #[inline]
+    pub fn pps_deblocking_filter_disabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 18)) | ((value as u32) << 18);
+        self
+    }
+    #[inline]
+    pub fn get_pps_deblocking_filter_disabled_flag(&self) -> bool {
+        self.bitfields & (1 << 18) != 0
+    }
+    #[inline]
+    pub fn pps_scaling_list_data_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 19)) | ((value as u32) << 19);
+        self
+    }
+    #[inline]
+    pub fn get_pps_scaling_list_data_present_flag(&self) -> bool {
+        self.bitfields & (1 << 19) != 0
+    }
+    #[inline]
+    pub fn lists_modification_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 20)) | ((value as u32) << 20);
+        self
+    }
+    #[inline]
+    pub fn get_lists_modification_present_flag(&self) -> bool {
+        self.bitfields & (1 << 20) != 0
+    }
+    #[inline]
+    pub fn slice_segment_header_extension_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 21)) | ((value as u32) << 21);
+        self
+    }
+    #[inline]
+    pub fn get_slice_segment_header_extension_present_flag(&self) -> bool {
+        self.bitfields & (1 << 21) != 0
+    }
+    #[inline]
+    pub fn pps_extension_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 22)) | ((value as u32) << 22);
+        self
+    }
+    #[inline]
+    pub fn get_pps_extension_present_flag(&self) -> bool {
+        self.bitfields & (1 << 22) != 0
+    }
+    #[inline]
+    pub fn cross_component_prediction_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 23)) | ((value as u32) << 23);
+        self
+    }
+    #[inline]
+    pub fn get_cross_component_prediction_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 23) != 0
+    }
+    #[inline]
+    pub fn chroma_qp_offset_list_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 24)) | ((value as u32) << 24);
+        self
+    }
+    #[inline]
+    pub fn get_chroma_qp_offset_list_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 24) != 0
+    }
+    #[inline]
+    pub fn pps_curr_pic_ref_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 25)) | ((value as u32) << 25);
+        self
+    }
+    #[inline]
+    pub fn get_pps_curr_pic_ref_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 25) != 0
+    }
+    #[inline]
+    pub fn residual_adaptive_colour_transform_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 26)) | ((value as u32) << 26);
+        self
+    }
+    #[inline]
+    pub fn get_residual_adaptive_colour_transform_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 26) != 0
+    }
+    #[inline]
+    pub fn pps_slice_act_qp_offsets_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 27)) | ((value as u32) << 27);
+        self
+    }
+    #[inline]
+    pub fn get_pps_slice_act_qp_offsets_present_flag(&self) -> bool {
+        self.bitfields & (1 << 27) != 0
+    }
+    #[inline]
+    pub fn pps_palette_predictor_initializers_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 28)) | ((value as u32) << 28);
+        self
+    }
+    #[inline]
+    pub fn get_pps_palette_predictor_initializers_present_flag(&self) -> bool {
+        self.bitfields & (1 << 28) != 0
+    }
+    #[inline]
+    pub fn monochrome_palette_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 29)) | ((value as u32) << 29);
+        self
+    }
+    #[inline]
+    pub fn get_monochrome_palette_flag(&self) -> bool {
+        self.bitfields & (1 << 29) != 0
+    }
+    #[inline]
+    pub fn pps_range_extension_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 30)) | ((value as u32) << 30);
+        self
+    }
+    #[inline]
+    pub fn get_pps_range_extension_flag(&self) -> bool {
+        self.bitfields & (1 << 30) != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoH265PictureParameterSet<'a> {
+    pub flags: StdVideoH265PpsFlags,
+    pub pps_pic_parameter_set_id: u8,
+    pub pps_seq_parameter_set_id: u8,
+    pub sps_video_parameter_set_id: u8,
+    pub num_extra_slice_header_bits: u8,
+    pub num_ref_idx_l0_default_active_minus1: u8,
+    pub num_ref_idx_l1_default_active_minus1: u8,
+    pub init_qp_minus26: i8,
+    pub diff_cu_qp_delta_depth: u8,
+    pub pps_cb_qp_offset: i8,
+    pub pps_cr_qp_offset: i8,
+    pub pps_beta_offset_div2: i8,
+    pub pps_tc_offset_div2: i8,
+    pub log2_parallel_merge_level_minus2: u8,
+    pub log2_max_transform_skip_block_size_minus2: u8,
+    pub diff_cu_chroma_qp_offset_depth: u8,
+    pub chroma_qp_offset_list_len_minus1: u8,
+    pub cb_qp_offset_list: [i8; STD_VIDEO_H265_CHROMA_QP_OFFSET_LIST_SIZE as _],
+    pub cr_qp_offset_list: [i8; STD_VIDEO_H265_CHROMA_QP_OFFSET_LIST_SIZE as _],
+    pub log2_sao_offset_scale_luma: u8,
+    pub log2_sao_offset_scale_chroma: u8,
+    pub pps_act_y_qp_offset_plus5: i8,
+    pub pps_act_cb_qp_offset_plus5: i8,
+    pub pps_act_cr_qp_offset_plus3: i8,
+    pub pps_num_palette_predictor_initializers: u8,
+    pub luma_bit_depth_entry_minus8: u8,
+    pub chroma_bit_depth_entry_minus8: u8,
+    pub num_tile_columns_minus1: u8,
+    pub num_tile_rows_minus1: u8,
+    pub reserved1: u8,
+    pub reserved2: u8,
+    pub column_width_minus1: [u16; STD_VIDEO_H265_CHROMA_QP_OFFSET_TILE_COLS_LIST_SIZE as _],
+    pub row_height_minus1: [u16; STD_VIDEO_H265_CHROMA_QP_OFFSET_TILE_ROWS_LIST_SIZE as _],
+    pub reserved3: u32,
+    pub p_scaling_lists: *const StdVideoH265ScalingLists,
+    pub p_predictor_palette_entries: *const StdVideoH265PredictorPaletteEntries,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoH265PictureParameterSet<'a> {}
+unsafe impl<'a> Sync for StdVideoH265PictureParameterSet<'a> {}
+impl<'a> Default for StdVideoH265PictureParameterSet<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            pps_pic_parameter_set_id: Default::default(),
+            pps_seq_parameter_set_id: Default::default(),
+            sps_video_parameter_set_id: Default::default(),
+            num_extra_slice_header_bits: Default::default(),
+            num_ref_idx_l0_default_active_minus1: Default::default(),
+            num_ref_idx_l1_default_active_minus1: Default::default(),
+            init_qp_minus26: Default::default(),
+            diff_cu_qp_delta_depth: Default::default(),
+            pps_cb_qp_offset: Default::default(),
+            pps_cr_qp_offset: Default::default(),
+            pps_beta_offset_div2: Default::default(),
+            pps_tc_offset_div2: Default::default(),
+            log2_parallel_merge_level_minus2: Default::default(),
+            log2_max_transform_skip_block_size_minus2: Default::default(),
+            diff_cu_chroma_qp_offset_depth: Default::default(),
+            chroma_qp_offset_list_len_minus1: Default::default(),
+            cb_qp_offset_list: array::from_fn(|_| Default::default()),
+            cr_qp_offset_list: array::from_fn(|_| Default::default()),
+            log2_sao_offset_scale_luma: Default::default(),
+            log2_sao_offset_scale_chroma: Default::default(),
+            pps_act_y_qp_offset_plus5: Default::default(),
+            pps_act_cb_qp_offset_plus5: Default::default(),
+            pps_act_cr_qp_offset_plus3: Default::default(),
+            pps_num_palette_predictor_initializers: Default::default(),
+            luma_bit_depth_entry_minus8: Default::default(),
+            chroma_bit_depth_entry_minus8: Default::default(),
+            num_tile_columns_minus1: Default::default(),
+            num_tile_rows_minus1: Default::default(),
+            reserved1: Default::default(),
+            reserved2: Default::default(),
+            column_width_minus1: array::from_fn(|_| Default::default()),
+            row_height_minus1: array::from_fn(|_| Default::default()),
+            reserved3: Default::default(),
+            p_scaling_lists: ptr::null(),
+            p_predictor_palette_entries: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoH265PictureParameterSet<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoH265PpsFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn pps_pic_parameter_set_id(mut self, value: u8) -> Self {
+        self.pps_pic_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pps_seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.pps_seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn sps_video_parameter_set_id(mut self, value: u8) -> Self {
+        self.sps_video_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn num_extra_slice_header_bits(mut self, value: u8) -> Self {
+        self.num_extra_slice_header_bits = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l0_default_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l0_default_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l1_default_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l1_default_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn init_qp_minus26(mut self, value: i8) -> Self {
+        self.init_qp_minus26 = value;
+        self
+    }
+    #[inline]
+    pub fn diff_cu_qp_delta_depth(mut self, value: u8) -> Self {
+        self.diff_cu_qp_delta_depth = value;
+        self
+    }
+    #[inline]
+    pub fn pps_cb_qp_offset(mut self, value: i8) -> Self {
+        self.pps_cb_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn pps_cr_qp_offset(mut self, value: i8) -> Self {
+        self.pps_cr_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn pps_beta_offset_div2(mut self, value: i8) -> Self {
+        self.pps_beta_offset_div2 = value;
+        self
+    }
+    #[inline]
+    pub fn pps_tc_offset_div2(mut self, value: i8) -> Self {
+        self.pps_tc_offset_div2 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_parallel_merge_level_minus2(mut self, value: u8) -> Self {
+        self.log2_parallel_merge_level_minus2 = value;
+        self
+    }
+    #[inline]
+    pub fn log2_max_transform_skip_block_size_minus2(mut self, value: u8) -> Self {
+        self.log2_max_transform_skip_block_size_minus2 = value;
+        self
+    }
+    #[inline]
+    pub fn diff_cu_chroma_qp_offset_depth(mut self, value: u8) -> Self {
+        self.diff_cu_chroma_qp_offset_depth = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_qp_offset_list_len_minus1(mut self, value: u8) -> Self {
+        self.chroma_qp_offset_list_len_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn cb_qp_offset_list(
+        mut self,
+        value: [i8; STD_VIDEO_H265_CHROMA_QP_OFFSET_LIST_SIZE as _],
+    ) -> Self {
+        self.cb_qp_offset_list = value;
+        self
+    }
+    #[inline]
+    pub fn cr_qp_offset_list(
+        mut self,
+        value: [i8; STD_VIDEO_H265_CHROMA_QP_OFFSET_LIST_SIZE as _],
+    ) -> Self {
+        self.cr_qp_offset_list = value;
+        self
+    }
+    #[inline]
+    pub fn log2_sao_offset_scale_luma(mut self, value: u8) -> Self {
+        self.log2_sao_offset_scale_luma = value;
+        self
+    }
+    #[inline]
+    pub fn log2_sao_offset_scale_chroma(mut self, value: u8) -> Self {
+        self.log2_sao_offset_scale_chroma = value;
+        self
+    }
+    #[inline]
+    pub fn pps_act_y_qp_offset_plus5(mut self, value: i8) -> Self {
+        self.pps_act_y_qp_offset_plus5 = value;
+        self
+    }
+    #[inline]
+    pub fn pps_act_cb_qp_offset_plus5(mut self, value: i8) -> Self {
+        self.pps_act_cb_qp_offset_plus5 = value;
+        self
+    }
+    #[inline]
+    pub fn pps_act_cr_qp_offset_plus3(mut self, value: i8) -> Self {
+        self.pps_act_cr_qp_offset_plus3 = value;
+        self
+    }
+    #[inline]
+    pub fn pps_num_palette_predictor_initializers(mut self, value: u8) -> Self {
+        self.pps_num_palette_predictor_initializers = value;
+        self
+    }
+    #[inline]
+    pub fn luma_bit_depth_entry_minus8(mut self, value: u8) -> Self {
+        self.luma_bit_depth_entry_minus8 = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_bit_depth_entry_minus8(mut self, value: u8) -> Self {
+        self.chroma_bit_depth_entry_minus8 = value;
+        self
+    }
+    #[inline]
+    pub fn num_tile_columns_minus1(mut self, value: u8) -> Self {
+        self.num_tile_columns_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn num_tile_rows_minus1(mut self, value: u8) -> Self {
+        self.num_tile_rows_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: u8) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn column_width_minus1(
+        mut self,
+        value: [u16; STD_VIDEO_H265_CHROMA_QP_OFFSET_TILE_COLS_LIST_SIZE as _],
+    ) -> Self {
+        self.column_width_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn row_height_minus1(
+        mut self,
+        value: [u16; STD_VIDEO_H265_CHROMA_QP_OFFSET_TILE_ROWS_LIST_SIZE as _],
+    ) -> Self {
+        self.row_height_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved3(mut self, value: u32) -> Self {
+        self.reserved3 = value;
+        self
+    }
+    #[inline]
+    pub fn scaling_lists(mut self, value: &'a StdVideoH265ScalingLists) -> Self {
+        self.p_scaling_lists = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn predictor_palette_entries(
+        mut self,
+        value: &'a StdVideoH265PredictorPaletteEntries,
+    ) -> Self {
+        self.p_predictor_palette_entries = ptr::from_ref(value);
+        self
+    }
+}
+pub const STD_VULKAN_VIDEO_CODEC_H265_DECODE_SPEC_VERSION: u32 = make_video_std_version(1, 0, 0);
+pub const STD_VULKAN_VIDEO_CODEC_H265_DECODE_EXTENSION_NAME: &CStr =
+    c"VK_STD_vulkan_video_codec_h265_decode";
+pub const STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE: u32 = 8;
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeH265PictureInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoDecodeH265PictureInfoFlags {}
+unsafe impl Sync for StdVideoDecodeH265PictureInfoFlags {}
+impl StdVideoDecodeH265PictureInfoFlags {
+    #[inline]
+    pub fn irap_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_irap_pic_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn idr_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_idr_pic_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn is_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_is_reference(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn short_term_ref_pic_set_sps_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_short_term_ref_pic_set_sps_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoDecodeH265PictureInfo {
+    pub flags: StdVideoDecodeH265PictureInfoFlags,
+    pub sps_video_parameter_set_id: u8,
+    pub pps_seq_parameter_set_id: u8,
+    pub pps_pic_parameter_set_id: u8,
+    pub num_delta_pocs_of_ref_rps_idx: u8,
+    pub pic_order_cnt_val: i32,
+    pub num_bits_for_stref_pic_set_in_slice: u16,
+    pub reserved: u16,
+    pub ref_pic_set_st_curr_before: [u8; STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE as _],
+    pub ref_pic_set_st_curr_after: [u8; STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE as _],
+    pub ref_pic_set_lt_curr: [u8; STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE as _],
+}
+unsafe impl Send for StdVideoDecodeH265PictureInfo {}
+unsafe impl Sync for StdVideoDecodeH265PictureInfo {}
+impl Default for StdVideoDecodeH265PictureInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            sps_video_parameter_set_id: Default::default(),
+            pps_seq_parameter_set_id: Default::default(),
+            pps_pic_parameter_set_id: Default::default(),
+            num_delta_pocs_of_ref_rps_idx: Default::default(),
+            pic_order_cnt_val: Default::default(),
+            num_bits_for_stref_pic_set_in_slice: Default::default(),
+            reserved: Default::default(),
+            ref_pic_set_st_curr_before: array::from_fn(|_| Default::default()),
+            ref_pic_set_st_curr_after: array::from_fn(|_| Default::default()),
+            ref_pic_set_lt_curr: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoDecodeH265PictureInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoDecodeH265PictureInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn sps_video_parameter_set_id(mut self, value: u8) -> Self {
+        self.sps_video_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pps_seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.pps_seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pps_pic_parameter_set_id(mut self, value: u8) -> Self {
+        self.pps_pic_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn num_delta_pocs_of_ref_rps_idx(mut self, value: u8) -> Self {
+        self.num_delta_pocs_of_ref_rps_idx = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt_val(mut self, value: i32) -> Self {
+        self.pic_order_cnt_val = value;
+        self
+    }
+    #[inline]
+    pub fn num_bits_for_stref_pic_set_in_slice(mut self, value: u16) -> Self {
+        self.num_bits_for_stref_pic_set_in_slice = value;
+        self
+    }
+    #[inline]
+    pub fn reserved(mut self, value: u16) -> Self {
+        self.reserved = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_set_st_curr_before(
+        mut self,
+        value: [u8; STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE as _],
+    ) -> Self {
+        self.ref_pic_set_st_curr_before = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_set_st_curr_after(
+        mut self,
+        value: [u8; STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE as _],
+    ) -> Self {
+        self.ref_pic_set_st_curr_after = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_set_lt_curr(
+        mut self,
+        value: [u8; STD_VIDEO_DECODE_H265_REF_PIC_SET_LIST_SIZE as _],
+    ) -> Self {
+        self.ref_pic_set_lt_curr = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeH265ReferenceInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoDecodeH265ReferenceInfoFlags {}
+unsafe impl Sync for StdVideoDecodeH265ReferenceInfoFlags {}
+impl StdVideoDecodeH265ReferenceInfoFlags {
+    #[inline]
+    pub fn used_for_long_term_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_used_for_long_term_reference(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn unused_for_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_unused_for_reference(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeH265ReferenceInfo {
+    pub flags: StdVideoDecodeH265ReferenceInfoFlags,
+    pub pic_order_cnt_val: i32,
+}
+unsafe impl Send for StdVideoDecodeH265ReferenceInfo {}
+unsafe impl Sync for StdVideoDecodeH265ReferenceInfo {}
+impl StdVideoDecodeH265ReferenceInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoDecodeH265ReferenceInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt_val(mut self, value: i32) -> Self {
+        self.pic_order_cnt_val = value;
+        self
+    }
+}
+pub const STD_VULKAN_VIDEO_CODEC_H265_ENCODE_SPEC_VERSION: u32 = make_video_std_version(1, 0, 0);
+pub const STD_VULKAN_VIDEO_CODEC_H265_ENCODE_EXTENSION_NAME: &CStr =
+    c"VK_STD_vulkan_video_codec_h265_encode";
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH265WeightTableFlags {
+    pub luma_weight_l0_flag: u16,
+    pub chroma_weight_l0_flag: u16,
+    pub luma_weight_l1_flag: u16,
+    pub chroma_weight_l1_flag: u16,
+}
+unsafe impl Send for StdVideoEncodeH265WeightTableFlags {}
+unsafe impl Sync for StdVideoEncodeH265WeightTableFlags {}
+impl StdVideoEncodeH265WeightTableFlags {
+    #[inline]
+    pub fn luma_weight_l0_flag(mut self, value: u16) -> Self {
+        self.luma_weight_l0_flag = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_weight_l0_flag(mut self, value: u16) -> Self {
+        self.chroma_weight_l0_flag = value;
+        self
+    }
+    #[inline]
+    pub fn luma_weight_l1_flag(mut self, value: u16) -> Self {
+        self.luma_weight_l1_flag = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_weight_l1_flag(mut self, value: u16) -> Self {
+        self.chroma_weight_l1_flag = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH265WeightTable {
+    pub flags: StdVideoEncodeH265WeightTableFlags,
+    pub luma_log2_weight_denom: u8,
+    pub delta_chroma_log2_weight_denom: i8,
+    pub delta_luma_weight_l0: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub luma_offset_l0: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub delta_chroma_weight_l0:
+        [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub delta_chroma_offset_l0:
+        [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub delta_luma_weight_l1: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub luma_offset_l1: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub delta_chroma_weight_l1:
+        [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub delta_chroma_offset_l1:
+        [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+}
+unsafe impl Send for StdVideoEncodeH265WeightTable {}
+unsafe impl Sync for StdVideoEncodeH265WeightTable {}
+impl Default for StdVideoEncodeH265WeightTable {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            luma_log2_weight_denom: Default::default(),
+            delta_chroma_log2_weight_denom: Default::default(),
+            delta_luma_weight_l0: array::from_fn(|_| Default::default()),
+            luma_offset_l0: array::from_fn(|_| Default::default()),
+            delta_chroma_weight_l0: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            delta_chroma_offset_l0: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            delta_luma_weight_l1: array::from_fn(|_| Default::default()),
+            luma_offset_l1: array::from_fn(|_| Default::default()),
+            delta_chroma_weight_l1: array::from_fn(|_| array::from_fn(|_| Default::default())),
+            delta_chroma_offset_l1: array::from_fn(|_| array::from_fn(|_| Default::default())),
+        }
+    }
+}
+impl StdVideoEncodeH265WeightTable {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH265WeightTableFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn luma_log2_weight_denom(mut self, value: u8) -> Self {
+        self.luma_log2_weight_denom = value;
+        self
+    }
+    #[inline]
+    pub fn delta_chroma_log2_weight_denom(mut self, value: i8) -> Self {
+        self.delta_chroma_log2_weight_denom = value;
+        self
+    }
+    #[inline]
+    pub fn delta_luma_weight_l0(
+        mut self,
+        value: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.delta_luma_weight_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn luma_offset_l0(mut self, value: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _]) -> Self {
+        self.luma_offset_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_chroma_weight_l0(
+        mut self,
+        value: [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.delta_chroma_weight_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_chroma_offset_l0(
+        mut self,
+        value: [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.delta_chroma_offset_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_luma_weight_l1(
+        mut self,
+        value: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.delta_luma_weight_l1 = value;
+        self
+    }
+    #[inline]
+    pub fn luma_offset_l1(mut self, value: [i8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _]) -> Self {
+        self.luma_offset_l1 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_chroma_weight_l1(
+        mut self,
+        value: [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.delta_chroma_weight_l1 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_chroma_offset_l1(
+        mut self,
+        value: [[i8; STD_VIDEO_H265_MAX_CHROMA_PLANES as _]; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    ) -> Self {
+        self.delta_chroma_offset_l1 = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH265SliceSegmentHeaderFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH265SliceSegmentHeaderFlags {}
+unsafe impl Sync for StdVideoEncodeH265SliceSegmentHeaderFlags {}
+impl StdVideoEncodeH265SliceSegmentHeaderFlags {
+    #[inline]
+    pub fn first_slice_segment_in_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_first_slice_segment_in_pic_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn dependent_slice_segment_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_dependent_slice_segment_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn slice_sao_luma_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_slice_sao_luma_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn slice_sao_chroma_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_slice_sao_chroma_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn num_ref_idx_active_override_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_num_ref_idx_active_override_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn mvd_l1_zero_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_mvd_l1_zero_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn cabac_init_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_cabac_init_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn cu_chroma_qp_offset_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_cu_chroma_qp_offset_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn deblocking_filter_override_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_deblocking_filter_override_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn slice_deblocking_filter_disabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_slice_deblocking_filter_disabled_flag(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn collocated_from_l0_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_collocated_from_l0_flag(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn slice_loop_filter_across_slices_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_slice_loop_filter_across_slices_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoEncodeH265SliceSegmentHeader<'a> {
+    pub flags: StdVideoEncodeH265SliceSegmentHeaderFlags,
+    pub slice_type: StdVideoH265SliceType,
+    pub slice_segment_address: u32,
+    pub collocated_ref_idx: u8,
+    pub max_num_merge_cand: u8,
+    pub slice_cb_qp_offset: i8,
+    pub slice_cr_qp_offset: i8,
+    pub slice_beta_offset_div2: i8,
+    pub slice_tc_offset_div2: i8,
+    pub slice_act_y_qp_offset: i8,
+    pub slice_act_cb_qp_offset: i8,
+    pub slice_act_cr_qp_offset: i8,
+    pub slice_qp_delta: i8,
+    pub reserved1: u16,
+    pub p_weight_table: *const StdVideoEncodeH265WeightTable,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoEncodeH265SliceSegmentHeader<'a> {}
+unsafe impl<'a> Sync for StdVideoEncodeH265SliceSegmentHeader<'a> {}
+impl<'a> Default for StdVideoEncodeH265SliceSegmentHeader<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            slice_type: StdVideoH265SliceType::B,
+            slice_segment_address: Default::default(),
+            collocated_ref_idx: Default::default(),
+            max_num_merge_cand: Default::default(),
+            slice_cb_qp_offset: Default::default(),
+            slice_cr_qp_offset: Default::default(),
+            slice_beta_offset_div2: Default::default(),
+            slice_tc_offset_div2: Default::default(),
+            slice_act_y_qp_offset: Default::default(),
+            slice_act_cb_qp_offset: Default::default(),
+            slice_act_cr_qp_offset: Default::default(),
+            slice_qp_delta: Default::default(),
+            reserved1: Default::default(),
+            p_weight_table: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoEncodeH265SliceSegmentHeader<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH265SliceSegmentHeaderFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn slice_type(mut self, value: StdVideoH265SliceType) -> Self {
+        self.slice_type = value;
+        self
+    }
+    #[inline]
+    pub fn slice_segment_address(mut self, value: u32) -> Self {
+        self.slice_segment_address = value;
+        self
+    }
+    #[inline]
+    pub fn collocated_ref_idx(mut self, value: u8) -> Self {
+        self.collocated_ref_idx = value;
+        self
+    }
+    #[inline]
+    pub fn max_num_merge_cand(mut self, value: u8) -> Self {
+        self.max_num_merge_cand = value;
+        self
+    }
+    #[inline]
+    pub fn slice_cb_qp_offset(mut self, value: i8) -> Self {
+        self.slice_cb_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn slice_cr_qp_offset(mut self, value: i8) -> Self {
+        self.slice_cr_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn slice_beta_offset_div2(mut self, value: i8) -> Self {
+        self.slice_beta_offset_div2 = value;
+        self
+    }
+    #[inline]
+    pub fn slice_tc_offset_div2(mut self, value: i8) -> Self {
+        self.slice_tc_offset_div2 = value;
+        self
+    }
+    #[inline]
+    pub fn slice_act_y_qp_offset(mut self, value: i8) -> Self {
+        self.slice_act_y_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn slice_act_cb_qp_offset(mut self, value: i8) -> Self {
+        self.slice_act_cb_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn slice_act_cr_qp_offset(mut self, value: i8) -> Self {
+        self.slice_act_cr_qp_offset = value;
+        self
+    }
+    #[inline]
+    pub fn slice_qp_delta(mut self, value: i8) -> Self {
+        self.slice_qp_delta = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u16) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn weight_table(mut self, value: &'a StdVideoEncodeH265WeightTable) -> Self {
+        self.p_weight_table = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH265ReferenceListsInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH265ReferenceListsInfoFlags {}
+unsafe impl Sync for StdVideoEncodeH265ReferenceListsInfoFlags {}
+impl StdVideoEncodeH265ReferenceListsInfoFlags {
+    #[inline]
+    pub fn ref_pic_list_modification_flag_l0(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_ref_pic_list_modification_flag_l0(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn ref_pic_list_modification_flag_l1(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_ref_pic_list_modification_flag_l1(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH265ReferenceListsInfo {
+    pub flags: StdVideoEncodeH265ReferenceListsInfoFlags,
+    pub num_ref_idx_l0_active_minus1: u8,
+    pub num_ref_idx_l1_active_minus1: u8,
+    pub ref_pic_list0: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub ref_pic_list1: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub list_entry_l0: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+    pub list_entry_l1: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _],
+}
+unsafe impl Send for StdVideoEncodeH265ReferenceListsInfo {}
+unsafe impl Sync for StdVideoEncodeH265ReferenceListsInfo {}
+impl Default for StdVideoEncodeH265ReferenceListsInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            num_ref_idx_l0_active_minus1: Default::default(),
+            num_ref_idx_l1_active_minus1: Default::default(),
+            ref_pic_list0: array::from_fn(|_| Default::default()),
+            ref_pic_list1: array::from_fn(|_| Default::default()),
+            list_entry_l0: array::from_fn(|_| Default::default()),
+            list_entry_l1: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoEncodeH265ReferenceListsInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH265ReferenceListsInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l0_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l0_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn num_ref_idx_l1_active_minus1(mut self, value: u8) -> Self {
+        self.num_ref_idx_l1_active_minus1 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_list0(mut self, value: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _]) -> Self {
+        self.ref_pic_list0 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_pic_list1(mut self, value: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _]) -> Self {
+        self.ref_pic_list1 = value;
+        self
+    }
+    #[inline]
+    pub fn list_entry_l0(mut self, value: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _]) -> Self {
+        self.list_entry_l0 = value;
+        self
+    }
+    #[inline]
+    pub fn list_entry_l1(mut self, value: [u8; STD_VIDEO_H265_MAX_NUM_LIST_REF as _]) -> Self {
+        self.list_entry_l1 = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH265PictureInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH265PictureInfoFlags {}
+unsafe impl Sync for StdVideoEncodeH265PictureInfoFlags {}
+impl StdVideoEncodeH265PictureInfoFlags {
+    #[inline]
+    pub fn is_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_is_reference(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn irap_pic_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_irap_pic_flag(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn used_for_long_term_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_used_for_long_term_reference(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn discardable_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_discardable_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn cross_layer_bla_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_cross_layer_bla_flag(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn pic_output_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_pic_output_flag(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn no_output_of_prior_pics_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_no_output_of_prior_pics_flag(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn short_term_ref_pic_set_sps_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_short_term_ref_pic_set_sps_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn slice_temporal_mvp_enabled_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_slice_temporal_mvp_enabled_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH265LongTermRefPics {
+    pub num_long_term_sps: u8,
+    pub num_long_term_pics: u8,
+    pub lt_idx_sps: [u8; STD_VIDEO_H265_MAX_LONG_TERM_REF_PICS_SPS as _],
+    pub poc_lsb_lt: [u8; STD_VIDEO_H265_MAX_LONG_TERM_PICS as _],
+    pub used_by_curr_pic_lt_flag: u16,
+    pub delta_poc_msb_present_flag: [u8; STD_VIDEO_H265_MAX_DELTA_POC as _],
+    pub delta_poc_msb_cycle_lt: [u8; STD_VIDEO_H265_MAX_DELTA_POC as _],
+}
+unsafe impl Send for StdVideoEncodeH265LongTermRefPics {}
+unsafe impl Sync for StdVideoEncodeH265LongTermRefPics {}
+impl Default for StdVideoEncodeH265LongTermRefPics {
+    fn default() -> Self {
+        Self {
+            num_long_term_sps: Default::default(),
+            num_long_term_pics: Default::default(),
+            lt_idx_sps: array::from_fn(|_| Default::default()),
+            poc_lsb_lt: array::from_fn(|_| Default::default()),
+            used_by_curr_pic_lt_flag: Default::default(),
+            delta_poc_msb_present_flag: array::from_fn(|_| Default::default()),
+            delta_poc_msb_cycle_lt: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoEncodeH265LongTermRefPics {
+    #[inline]
+    pub fn num_long_term_sps(mut self, value: u8) -> Self {
+        self.num_long_term_sps = value;
+        self
+    }
+    #[inline]
+    pub fn num_long_term_pics(mut self, value: u8) -> Self {
+        self.num_long_term_pics = value;
+        self
+    }
+    #[inline]
+    pub fn lt_idx_sps(
+        mut self,
+        value: [u8; STD_VIDEO_H265_MAX_LONG_TERM_REF_PICS_SPS as _],
+    ) -> Self {
+        self.lt_idx_sps = value;
+        self
+    }
+    #[inline]
+    pub fn poc_lsb_lt(mut self, value: [u8; STD_VIDEO_H265_MAX_LONG_TERM_PICS as _]) -> Self {
+        self.poc_lsb_lt = value;
+        self
+    }
+    #[inline]
+    pub fn used_by_curr_pic_lt_flag(mut self, value: u16) -> Self {
+        self.used_by_curr_pic_lt_flag = value;
+        self
+    }
+    #[inline]
+    pub fn delta_poc_msb_present_flag(
+        mut self,
+        value: [u8; STD_VIDEO_H265_MAX_DELTA_POC as _],
+    ) -> Self {
+        self.delta_poc_msb_present_flag = value;
+        self
+    }
+    #[inline]
+    pub fn delta_poc_msb_cycle_lt(
+        mut self,
+        value: [u8; STD_VIDEO_H265_MAX_DELTA_POC as _],
+    ) -> Self {
+        self.delta_poc_msb_cycle_lt = value;
+        self
+    }
+}
+#[repr(C)]
+pub struct StdVideoEncodeH265PictureInfo<'a> {
+    pub flags: StdVideoEncodeH265PictureInfoFlags,
+    pub pic_type: StdVideoH265PictureType,
+    pub sps_video_parameter_set_id: u8,
+    pub pps_seq_parameter_set_id: u8,
+    pub pps_pic_parameter_set_id: u8,
+    pub short_term_ref_pic_set_idx: u8,
+    pub pic_order_cnt_val: i32,
+    pub temporal_id: u8,
+    pub reserved1: [u8; 7u16 as _],
+    pub p_ref_lists: *const StdVideoEncodeH265ReferenceListsInfo,
+    pub p_short_term_ref_pic_set: *const StdVideoH265ShortTermRefPicSet,
+    pub p_long_term_ref_pics: *const StdVideoEncodeH265LongTermRefPics,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoEncodeH265PictureInfo<'a> {}
+unsafe impl<'a> Sync for StdVideoEncodeH265PictureInfo<'a> {}
+impl<'a> Default for StdVideoEncodeH265PictureInfo<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            pic_type: StdVideoH265PictureType::P,
+            sps_video_parameter_set_id: Default::default(),
+            pps_seq_parameter_set_id: Default::default(),
+            pps_pic_parameter_set_id: Default::default(),
+            short_term_ref_pic_set_idx: Default::default(),
+            pic_order_cnt_val: Default::default(),
+            temporal_id: Default::default(),
+            reserved1: array::from_fn(|_| Default::default()),
+            p_ref_lists: ptr::null(),
+            p_short_term_ref_pic_set: ptr::null(),
+            p_long_term_ref_pics: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoEncodeH265PictureInfo<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH265PictureInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn pic_type(mut self, value: StdVideoH265PictureType) -> Self {
+        self.pic_type = value;
+        self
+    }
+    #[inline]
+    pub fn sps_video_parameter_set_id(mut self, value: u8) -> Self {
+        self.sps_video_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pps_seq_parameter_set_id(mut self, value: u8) -> Self {
+        self.pps_seq_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn pps_pic_parameter_set_id(mut self, value: u8) -> Self {
+        self.pps_pic_parameter_set_id = value;
+        self
+    }
+    #[inline]
+    pub fn short_term_ref_pic_set_idx(mut self, value: u8) -> Self {
+        self.short_term_ref_pic_set_idx = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt_val(mut self, value: i32) -> Self {
+        self.pic_order_cnt_val = value;
+        self
+    }
+    #[inline]
+    pub fn temporal_id(mut self, value: u8) -> Self {
+        self.temporal_id = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: [u8; 7u16 as _]) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn ref_lists(mut self, value: &'a StdVideoEncodeH265ReferenceListsInfo) -> Self {
+        self.p_ref_lists = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn short_term_ref_pic_set(mut self, value: &'a StdVideoH265ShortTermRefPicSet) -> Self {
+        self.p_short_term_ref_pic_set = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn long_term_ref_pics(mut self, value: &'a StdVideoEncodeH265LongTermRefPics) -> Self {
+        self.p_long_term_ref_pics = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoEncodeH265ReferenceInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoEncodeH265ReferenceInfoFlags {}
+unsafe impl Sync for StdVideoEncodeH265ReferenceInfoFlags {}
+impl StdVideoEncodeH265ReferenceInfoFlags {
+    #[inline]
+    pub fn used_for_long_term_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_used_for_long_term_reference(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn unused_for_reference(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_unused_for_reference(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoEncodeH265ReferenceInfo {
+    pub flags: StdVideoEncodeH265ReferenceInfoFlags,
+    pub pic_type: StdVideoH265PictureType,
+    pub pic_order_cnt_val: i32,
+    pub temporal_id: u8,
+}
+unsafe impl Send for StdVideoEncodeH265ReferenceInfo {}
+unsafe impl Sync for StdVideoEncodeH265ReferenceInfo {}
+impl Default for StdVideoEncodeH265ReferenceInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            pic_type: StdVideoH265PictureType::P,
+            pic_order_cnt_val: Default::default(),
+            temporal_id: Default::default(),
+        }
+    }
+}
+impl StdVideoEncodeH265ReferenceInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoEncodeH265ReferenceInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn pic_type(mut self, value: StdVideoH265PictureType) -> Self {
+        self.pic_type = value;
+        self
+    }
+    #[inline]
+    pub fn pic_order_cnt_val(mut self, value: i32) -> Self {
+        self.pic_order_cnt_val = value;
+        self
+    }
+    #[inline]
+    pub fn temporal_id(mut self, value: u8) -> Self {
+        self.temporal_id = value;
+        self
+    }
+}
+pub const STD_VIDEO_AV1_NUM_REF_FRAMES: u32 = 8;
+pub const STD_VIDEO_AV1_REFS_PER_FRAME: u32 = 7;
+pub const STD_VIDEO_AV1_TOTAL_REFS_PER_FRAME: u32 = 8;
+pub const STD_VIDEO_AV1_MAX_TILE_COLS: u32 = 64;
+pub const STD_VIDEO_AV1_MAX_TILE_ROWS: u32 = 64;
+pub const STD_VIDEO_AV1_MAX_SEGMENTS: u32 = 8;
+pub const STD_VIDEO_AV1_SEG_LVL_MAX: u32 = 8;
+pub const STD_VIDEO_AV1_PRIMARY_REF_NONE: u32 = 7;
+pub const STD_VIDEO_AV1_SELECT_INTEGER_MV: u32 = 2;
+pub const STD_VIDEO_AV1_SELECT_SCREEN_CONTENT_TOOLS: u32 = 2;
+pub const STD_VIDEO_AV1_SKIP_MODE_FRAMES: u32 = 2;
+pub const STD_VIDEO_AV1_MAX_LOOP_FILTER_STRENGTHS: u32 = 4;
+pub const STD_VIDEO_AV1_LOOP_FILTER_ADJUSTMENTS: u32 = 2;
+pub const STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS: u32 = 8;
+pub const STD_VIDEO_AV1_MAX_NUM_PLANES: u32 = 3;
+pub const STD_VIDEO_AV1_GLOBAL_MOTION_PARAMS: u32 = 6;
+pub const STD_VIDEO_AV1_MAX_NUM_Y_POINTS: u32 = 14;
+pub const STD_VIDEO_AV1_MAX_NUM_CB_POINTS: u32 = 10;
+pub const STD_VIDEO_AV1_MAX_NUM_CR_POINTS: u32 = 10;
+pub const STD_VIDEO_AV1_MAX_NUM_POS_LUMA: u32 = 24;
+pub const STD_VIDEO_AV1_MAX_NUM_POS_CHROMA: u32 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1Profile {
+    Main = 0,
+    High = 1,
+    Professional = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1Level {
+    Level20 = 0,
+    Level21 = 1,
+    Level22 = 2,
+    Level23 = 3,
+    Level30 = 4,
+    Level31 = 5,
+    Level32 = 6,
+    Level33 = 7,
+    Level40 = 8,
+    Level41 = 9,
+    Level42 = 10,
+    Level43 = 11,
+    Level50 = 12,
+    Level51 = 13,
+    Level52 = 14,
+    Level53 = 15,
+    Level60 = 16,
+    Level61 = 17,
+    Level62 = 18,
+    Level63 = 19,
+    Level70 = 20,
+    Level71 = 21,
+    Level72 = 22,
+    Level73 = 23,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1FrameType {
+    Key = 0,
+    Inter = 1,
+    IntraOnly = 2,
+    Switch = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1ReferenceName {
+    IntraFrame = 0,
+    LastFrame = 1,
+    Last2Frame = 2,
+    Last3Frame = 3,
+    GoldenFrame = 4,
+    BwdrefFrame = 5,
+    Altref2Frame = 6,
+    AltrefFrame = 7,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1InterpolationFilter {
+    Eighttap = 0,
+    EighttapSmooth = 1,
+    EighttapSharp = 2,
+    Bilinear = 3,
+    Switchable = 4,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1TxMode {
+    Only4X4 = 0,
+    Largest = 1,
+    Select = 2,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1FrameRestorationType {
+    None = 0,
+    Wiener = 1,
+    Sgrproj = 2,
+    Switchable = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1ColorPrimaries {
+    Bt709 = 1,
+    BtUnspecified = 2,
+    Bt470M = 4,
+    Bt470BG = 5,
+    Bt601 = 6,
+    Smpte240 = 7,
+    GenericFilm = 8,
+    Bt2020 = 9,
+    Xyz = 10,
+    Smpte431 = 11,
+    Smpte432 = 12,
+    Ebu3213 = 22,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1TransferCharacteristics {
+    Reserved0 = 0,
+    Bt709 = 1,
+    Unspecified = 2,
+    Reserved3 = 3,
+    Bt470M = 4,
+    Bt470BG = 5,
+    Bt601 = 6,
+    Smpte240 = 7,
+    Linear = 8,
+    Log100 = 9,
+    Log100Sqrt10 = 10,
+    Iec61966 = 11,
+    Bt1361 = 12,
+    Srgb = 13,
+    Bt202010Bit = 14,
+    Bt202012Bit = 15,
+    Smpte2084 = 16,
+    Smpte428 = 17,
+    Hlg = 18,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1MatrixCoefficients {
+    Identity = 0,
+    Bt709 = 1,
+    Unspecified = 2,
+    Reserved3 = 3,
+    Fcc = 4,
+    Bt470BG = 5,
+    Bt601 = 6,
+    Smpte240 = 7,
+    SmpteYcgco = 8,
+    Bt2020Ncl = 9,
+    Bt2020Cl = 10,
+    Smpte2085 = 11,
+    ChromatNcl = 12,
+    ChromatCl = 13,
+    Ictcp = 14,
+    Invalid = 0x7FFFFFFF,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum StdVideoAV1ChromaSamplePosition {
+    Unknown = 0,
+    Vertical = 1,
+    Colocated = 2,
+    Reserved = 3,
+    Invalid = 0x7FFFFFFF,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1ColorConfigFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1ColorConfigFlags {}
+unsafe impl Sync for StdVideoAV1ColorConfigFlags {}
+impl StdVideoAV1ColorConfigFlags {
+    #[inline]
+    pub fn mono_chrome(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_mono_chrome(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn color_range(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_color_range(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn separate_uv_delta_q(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_separate_uv_delta_q(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn color_description_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_color_description_present_flag(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1ColorConfig {
+    pub flags: StdVideoAV1ColorConfigFlags,
+    pub bit_depth: u8,
+    pub subsampling_x: u8,
+    pub subsampling_y: u8,
+    pub reserved1: u8,
+    pub color_primaries: StdVideoAV1ColorPrimaries,
+    pub transfer_characteristics: StdVideoAV1TransferCharacteristics,
+    pub matrix_coefficients: StdVideoAV1MatrixCoefficients,
+    pub chroma_sample_position: StdVideoAV1ChromaSamplePosition,
+}
+unsafe impl Send for StdVideoAV1ColorConfig {}
+unsafe impl Sync for StdVideoAV1ColorConfig {}
+impl Default for StdVideoAV1ColorConfig {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            bit_depth: Default::default(),
+            subsampling_x: Default::default(),
+            subsampling_y: Default::default(),
+            reserved1: Default::default(),
+            color_primaries: StdVideoAV1ColorPrimaries::Bt709,
+            transfer_characteristics: StdVideoAV1TransferCharacteristics::Reserved0,
+            matrix_coefficients: StdVideoAV1MatrixCoefficients::Identity,
+            chroma_sample_position: StdVideoAV1ChromaSamplePosition::Unknown,
+        }
+    }
+}
+impl StdVideoAV1ColorConfig {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1ColorConfigFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn bit_depth(mut self, value: u8) -> Self {
+        self.bit_depth = value;
+        self
+    }
+    #[inline]
+    pub fn subsampling_x(mut self, value: u8) -> Self {
+        self.subsampling_x = value;
+        self
+    }
+    #[inline]
+    pub fn subsampling_y(mut self, value: u8) -> Self {
+        self.subsampling_y = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn color_primaries(mut self, value: StdVideoAV1ColorPrimaries) -> Self {
+        self.color_primaries = value;
+        self
+    }
+    #[inline]
+    pub fn transfer_characteristics(mut self, value: StdVideoAV1TransferCharacteristics) -> Self {
+        self.transfer_characteristics = value;
+        self
+    }
+    #[inline]
+    pub fn matrix_coefficients(mut self, value: StdVideoAV1MatrixCoefficients) -> Self {
+        self.matrix_coefficients = value;
+        self
+    }
+    #[inline]
+    pub fn chroma_sample_position(mut self, value: StdVideoAV1ChromaSamplePosition) -> Self {
+        self.chroma_sample_position = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1TimingInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1TimingInfoFlags {}
+unsafe impl Sync for StdVideoAV1TimingInfoFlags {}
+impl StdVideoAV1TimingInfoFlags {
+    #[inline]
+    pub fn equal_picture_interval(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_equal_picture_interval(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1TimingInfo {
+    pub flags: StdVideoAV1TimingInfoFlags,
+    pub num_units_in_display_tick: u32,
+    pub time_scale: u32,
+    pub num_ticks_per_picture_minus_1: u32,
+}
+unsafe impl Send for StdVideoAV1TimingInfo {}
+unsafe impl Sync for StdVideoAV1TimingInfo {}
+impl StdVideoAV1TimingInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1TimingInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn num_units_in_display_tick(mut self, value: u32) -> Self {
+        self.num_units_in_display_tick = value;
+        self
+    }
+    #[inline]
+    pub fn time_scale(mut self, value: u32) -> Self {
+        self.time_scale = value;
+        self
+    }
+    #[inline]
+    pub fn num_ticks_per_picture_minus_1(mut self, value: u32) -> Self {
+        self.num_ticks_per_picture_minus_1 = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1LoopFilterFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1LoopFilterFlags {}
+unsafe impl Sync for StdVideoAV1LoopFilterFlags {}
+impl StdVideoAV1LoopFilterFlags {
+    #[inline]
+    pub fn loop_filter_delta_enabled(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_loop_filter_delta_enabled(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn loop_filter_delta_update(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_loop_filter_delta_update(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1LoopFilter {
+    pub flags: StdVideoAV1LoopFilterFlags,
+    pub loop_filter_level: [u8; STD_VIDEO_AV1_MAX_LOOP_FILTER_STRENGTHS as _],
+    pub loop_filter_sharpness: u8,
+    pub update_ref_delta: u8,
+    pub loop_filter_ref_deltas: [i8; STD_VIDEO_AV1_TOTAL_REFS_PER_FRAME as _],
+    pub update_mode_delta: u8,
+    pub loop_filter_mode_deltas: [i8; STD_VIDEO_AV1_LOOP_FILTER_ADJUSTMENTS as _],
+}
+unsafe impl Send for StdVideoAV1LoopFilter {}
+unsafe impl Sync for StdVideoAV1LoopFilter {}
+impl Default for StdVideoAV1LoopFilter {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            loop_filter_level: array::from_fn(|_| Default::default()),
+            loop_filter_sharpness: Default::default(),
+            update_ref_delta: Default::default(),
+            loop_filter_ref_deltas: array::from_fn(|_| Default::default()),
+            update_mode_delta: Default::default(),
+            loop_filter_mode_deltas: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoAV1LoopFilter {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1LoopFilterFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn loop_filter_level(
+        mut self,
+        value: [u8; STD_VIDEO_AV1_MAX_LOOP_FILTER_STRENGTHS as _],
+    ) -> Self {
+        self.loop_filter_level = value;
+        self
+    }
+    #[inline]
+    pub fn loop_filter_sharpness(mut self, value: u8) -> Self {
+        self.loop_filter_sharpness = value;
+        self
+    }
+    #[inline]
+    pub fn update_ref_delta(mut self, value: u8) -> Self {
+        self.update_ref_delta = value;
+        self
+    }
+    #[inline]
+    pub fn loop_filter_ref_deltas(
+        mut self,
+        value: [i8; STD_VIDEO_AV1_TOTAL_REFS_PER_FRAME as _],
+    ) -> Self {
+        self.loop_filter_ref_deltas = value;
+        self
+    }
+    #[inline]
+    pub fn update_mode_delta(mut self, value: u8) -> Self {
+        self.update_mode_delta = value;
+        self
+    }
+    #[inline]
+    pub fn loop_filter_mode_deltas(
+        mut self,
+        value: [i8; STD_VIDEO_AV1_LOOP_FILTER_ADJUSTMENTS as _],
+    ) -> Self {
+        self.loop_filter_mode_deltas = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1QuantizationFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1QuantizationFlags {}
+unsafe impl Sync for StdVideoAV1QuantizationFlags {}
+impl StdVideoAV1QuantizationFlags {
+    #[inline]
+    pub fn using_qmatrix(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_using_qmatrix(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn diff_uv_delta(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_diff_uv_delta(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1Quantization {
+    pub flags: StdVideoAV1QuantizationFlags,
+    pub base_q_idx: u8,
+    pub delta_qydc: i8,
+    pub delta_qudc: i8,
+    pub delta_quac: i8,
+    pub delta_qvdc: i8,
+    pub delta_qvac: i8,
+    pub qm_y: u8,
+    pub qm_u: u8,
+    pub qm_v: u8,
+}
+unsafe impl Send for StdVideoAV1Quantization {}
+unsafe impl Sync for StdVideoAV1Quantization {}
+impl StdVideoAV1Quantization {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1QuantizationFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn base_q_idx(mut self, value: u8) -> Self {
+        self.base_q_idx = value;
+        self
+    }
+    #[inline]
+    pub fn delta_qydc(mut self, value: i8) -> Self {
+        self.delta_qydc = value;
+        self
+    }
+    #[inline]
+    pub fn delta_qudc(mut self, value: i8) -> Self {
+        self.delta_qudc = value;
+        self
+    }
+    #[inline]
+    pub fn delta_quac(mut self, value: i8) -> Self {
+        self.delta_quac = value;
+        self
+    }
+    #[inline]
+    pub fn delta_qvdc(mut self, value: i8) -> Self {
+        self.delta_qvdc = value;
+        self
+    }
+    #[inline]
+    pub fn delta_qvac(mut self, value: i8) -> Self {
+        self.delta_qvac = value;
+        self
+    }
+    #[inline]
+    pub fn qm_y(mut self, value: u8) -> Self {
+        self.qm_y = value;
+        self
+    }
+    #[inline]
+    pub fn qm_u(mut self, value: u8) -> Self {
+        self.qm_u = value;
+        self
+    }
+    #[inline]
+    pub fn qm_v(mut self, value: u8) -> Self {
+        self.qm_v = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1Segmentation {
+    pub feature_enabled: [u8; STD_VIDEO_AV1_MAX_SEGMENTS as _],
+    pub feature_data: [[i16; STD_VIDEO_AV1_SEG_LVL_MAX as _]; STD_VIDEO_AV1_MAX_SEGMENTS as _],
+}
+unsafe impl Send for StdVideoAV1Segmentation {}
+unsafe impl Sync for StdVideoAV1Segmentation {}
+impl Default for StdVideoAV1Segmentation {
+    fn default() -> Self {
+        Self {
+            feature_enabled: array::from_fn(|_| Default::default()),
+            feature_data: array::from_fn(|_| array::from_fn(|_| Default::default())),
+        }
+    }
+}
+impl StdVideoAV1Segmentation {
+    #[inline]
+    pub fn feature_enabled(mut self, value: [u8; STD_VIDEO_AV1_MAX_SEGMENTS as _]) -> Self {
+        self.feature_enabled = value;
+        self
+    }
+    #[inline]
+    pub fn feature_data(
+        mut self,
+        value: [[i16; STD_VIDEO_AV1_SEG_LVL_MAX as _]; STD_VIDEO_AV1_MAX_SEGMENTS as _],
+    ) -> Self {
+        self.feature_data = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1TileInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1TileInfoFlags {}
+unsafe impl Sync for StdVideoAV1TileInfoFlags {}
+impl StdVideoAV1TileInfoFlags {
+    #[inline]
+    pub fn uniform_tile_spacing_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_uniform_tile_spacing_flag(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoAV1TileInfo<'a> {
+    pub flags: StdVideoAV1TileInfoFlags,
+    pub tile_cols: u8,
+    pub tile_rows: u8,
+    pub context_update_tile_id: u16,
+    pub tile_size_bytes_minus_1: u8,
+    pub reserved1: [u8; 7u16 as _],
+    pub p_mi_col_starts: *const u16,
+    pub p_mi_row_starts: *const u16,
+    pub p_width_in_sbs_minus1: *const u16,
+    pub p_height_in_sbs_minus1: *const u16,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoAV1TileInfo<'a> {}
+unsafe impl<'a> Sync for StdVideoAV1TileInfo<'a> {}
+impl<'a> Default for StdVideoAV1TileInfo<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            tile_cols: Default::default(),
+            tile_rows: Default::default(),
+            context_update_tile_id: Default::default(),
+            tile_size_bytes_minus_1: Default::default(),
+            reserved1: array::from_fn(|_| Default::default()),
+            p_mi_col_starts: ptr::null(),
+            p_mi_row_starts: ptr::null(),
+            p_width_in_sbs_minus1: ptr::null(),
+            p_height_in_sbs_minus1: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoAV1TileInfo<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1TileInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn tile_cols(mut self, value: u8) -> Self {
+        self.tile_cols = value;
+        self
+    }
+    #[inline]
+    pub fn tile_rows(mut self, value: u8) -> Self {
+        self.tile_rows = value;
+        self
+    }
+    #[inline]
+    pub fn context_update_tile_id(mut self, value: u16) -> Self {
+        self.context_update_tile_id = value;
+        self
+    }
+    #[inline]
+    pub fn tile_size_bytes_minus_1(mut self, value: u8) -> Self {
+        self.tile_size_bytes_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: [u8; 7u16 as _]) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn mi_col_starts(mut self, value: &'a u16) -> Self {
+        self.p_mi_col_starts = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn mi_row_starts(mut self, value: &'a u16) -> Self {
+        self.p_mi_row_starts = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn width_in_sbs_minus1(mut self, value: &'a u16) -> Self {
+        self.p_width_in_sbs_minus1 = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn height_in_sbs_minus1(mut self, value: &'a u16) -> Self {
+        self.p_height_in_sbs_minus1 = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1CDEF {
+    pub cdef_damping_minus_3: u8,
+    pub cdef_bits: u8,
+    pub cdef_y_pri_strength: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    pub cdef_y_sec_strength: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    pub cdef_uv_pri_strength: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    pub cdef_uv_sec_strength: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+}
+unsafe impl Send for StdVideoAV1CDEF {}
+unsafe impl Sync for StdVideoAV1CDEF {}
+impl Default for StdVideoAV1CDEF {
+    fn default() -> Self {
+        Self {
+            cdef_damping_minus_3: Default::default(),
+            cdef_bits: Default::default(),
+            cdef_y_pri_strength: array::from_fn(|_| Default::default()),
+            cdef_y_sec_strength: array::from_fn(|_| Default::default()),
+            cdef_uv_pri_strength: array::from_fn(|_| Default::default()),
+            cdef_uv_sec_strength: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoAV1CDEF {
+    #[inline]
+    pub fn cdef_damping_minus_3(mut self, value: u8) -> Self {
+        self.cdef_damping_minus_3 = value;
+        self
+    }
+    #[inline]
+    pub fn cdef_bits(mut self, value: u8) -> Self {
+        self.cdef_bits = value;
+        self
+    }
+    #[inline]
+    pub fn cdef_y_pri_strength(
+        mut self,
+        value: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    ) -> Self {
+        self.cdef_y_pri_strength = value;
+        self
+    }
+    #[inline]
+    pub fn cdef_y_sec_strength(
+        mut self,
+        value: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    ) -> Self {
+        self.cdef_y_sec_strength = value;
+        self
+    }
+    #[inline]
+    pub fn cdef_uv_pri_strength(
+        mut self,
+        value: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    ) -> Self {
+        self.cdef_uv_pri_strength = value;
+        self
+    }
+    #[inline]
+    pub fn cdef_uv_sec_strength(
+        mut self,
+        value: [u8; STD_VIDEO_AV1_MAX_CDEF_FILTER_STRENGTHS as _],
+    ) -> Self {
+        self.cdef_uv_sec_strength = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1LoopRestoration {
+    pub frame_restoration_type:
+        [StdVideoAV1FrameRestorationType; STD_VIDEO_AV1_MAX_NUM_PLANES as _],
+    pub loop_restoration_size: [u16; STD_VIDEO_AV1_MAX_NUM_PLANES as _],
+}
+unsafe impl Send for StdVideoAV1LoopRestoration {}
+unsafe impl Sync for StdVideoAV1LoopRestoration {}
+impl Default for StdVideoAV1LoopRestoration {
+    fn default() -> Self {
+        Self {
+            frame_restoration_type: array::from_fn(|_| StdVideoAV1FrameRestorationType::None),
+            loop_restoration_size: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoAV1LoopRestoration {
+    #[inline]
+    pub fn frame_restoration_type(
+        mut self,
+        value: [StdVideoAV1FrameRestorationType; STD_VIDEO_AV1_MAX_NUM_PLANES as _],
+    ) -> Self {
+        self.frame_restoration_type = value;
+        self
+    }
+    #[inline]
+    pub fn loop_restoration_size(
+        mut self,
+        value: [u16; STD_VIDEO_AV1_MAX_NUM_PLANES as _],
+    ) -> Self {
+        self.loop_restoration_size = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1GlobalMotion {
+    pub gm_type: [u8; STD_VIDEO_AV1_NUM_REF_FRAMES as _],
+    pub gm_params:
+        [[i32; STD_VIDEO_AV1_GLOBAL_MOTION_PARAMS as _]; STD_VIDEO_AV1_NUM_REF_FRAMES as _],
+}
+unsafe impl Send for StdVideoAV1GlobalMotion {}
+unsafe impl Sync for StdVideoAV1GlobalMotion {}
+impl Default for StdVideoAV1GlobalMotion {
+    fn default() -> Self {
+        Self {
+            gm_type: array::from_fn(|_| Default::default()),
+            gm_params: array::from_fn(|_| array::from_fn(|_| Default::default())),
+        }
+    }
+}
+impl StdVideoAV1GlobalMotion {
+    #[inline]
+    pub fn gm_type(mut self, value: [u8; STD_VIDEO_AV1_NUM_REF_FRAMES as _]) -> Self {
+        self.gm_type = value;
+        self
+    }
+    #[inline]
+    pub fn gm_params(
+        mut self,
+        value: [[i32; STD_VIDEO_AV1_GLOBAL_MOTION_PARAMS as _]; STD_VIDEO_AV1_NUM_REF_FRAMES as _],
+    ) -> Self {
+        self.gm_params = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1FilmGrainFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1FilmGrainFlags {}
+unsafe impl Sync for StdVideoAV1FilmGrainFlags {}
+impl StdVideoAV1FilmGrainFlags {
+    #[inline]
+    pub fn apply_grain(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_apply_grain(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn chroma_scaling_from_luma(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_chroma_scaling_from_luma(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn overlap_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_overlap_flag(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn clip_to_restricted_range(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_clip_to_restricted_range(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn update_grain(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_update_grain(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoAV1FilmGrain {
+    pub flags: StdVideoAV1FilmGrainFlags,
+    pub grain_scaling_minus_8: u8,
+    pub ar_coeff_lag: u8,
+    pub ar_coeff_shift_minus_6: u8,
+    pub grain_scale_shift: u8,
+    pub grain_seed: u16,
+    pub film_grain_params_ref_idx: u8,
+    pub num_y_points: u8,
+    pub point_y_value: [u8; STD_VIDEO_AV1_MAX_NUM_Y_POINTS as _],
+    pub point_y_scaling: [u8; STD_VIDEO_AV1_MAX_NUM_Y_POINTS as _],
+    pub num_cb_points: u8,
+    pub point_cb_value: [u8; STD_VIDEO_AV1_MAX_NUM_CB_POINTS as _],
+    pub point_cb_scaling: [u8; STD_VIDEO_AV1_MAX_NUM_CB_POINTS as _],
+    pub num_cr_points: u8,
+    pub point_cr_value: [u8; STD_VIDEO_AV1_MAX_NUM_CR_POINTS as _],
+    pub point_cr_scaling: [u8; STD_VIDEO_AV1_MAX_NUM_CR_POINTS as _],
+    pub ar_coeffs_y_plus_128: [i8; STD_VIDEO_AV1_MAX_NUM_POS_LUMA as _],
+    pub ar_coeffs_cb_plus_128: [i8; STD_VIDEO_AV1_MAX_NUM_POS_CHROMA as _],
+    pub ar_coeffs_cr_plus_128: [i8; STD_VIDEO_AV1_MAX_NUM_POS_CHROMA as _],
+    pub cb_mult: u8,
+    pub cb_luma_mult: u8,
+    pub cb_offset: u16,
+    pub cr_mult: u8,
+    pub cr_luma_mult: u8,
+    pub cr_offset: u16,
+}
+unsafe impl Send for StdVideoAV1FilmGrain {}
+unsafe impl Sync for StdVideoAV1FilmGrain {}
+impl Default for StdVideoAV1FilmGrain {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            grain_scaling_minus_8: Default::default(),
+            ar_coeff_lag: Default::default(),
+            ar_coeff_shift_minus_6: Default::default(),
+            grain_scale_shift: Default::default(),
+            grain_seed: Default::default(),
+            film_grain_params_ref_idx: Default::default(),
+            num_y_points: Default::default(),
+            point_y_value: array::from_fn(|_| Default::default()),
+            point_y_scaling: array::from_fn(|_| Default::default()),
+            num_cb_points: Default::default(),
+            point_cb_value: array::from_fn(|_| Default::default()),
+            point_cb_scaling: array::from_fn(|_| Default::default()),
+            num_cr_points: Default::default(),
+            point_cr_value: array::from_fn(|_| Default::default()),
+            point_cr_scaling: array::from_fn(|_| Default::default()),
+            ar_coeffs_y_plus_128: array::from_fn(|_| Default::default()),
+            ar_coeffs_cb_plus_128: array::from_fn(|_| Default::default()),
+            ar_coeffs_cr_plus_128: array::from_fn(|_| Default::default()),
+            cb_mult: Default::default(),
+            cb_luma_mult: Default::default(),
+            cb_offset: Default::default(),
+            cr_mult: Default::default(),
+            cr_luma_mult: Default::default(),
+            cr_offset: Default::default(),
+        }
+    }
+}
+impl StdVideoAV1FilmGrain {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1FilmGrainFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn grain_scaling_minus_8(mut self, value: u8) -> Self {
+        self.grain_scaling_minus_8 = value;
+        self
+    }
+    #[inline]
+    pub fn ar_coeff_lag(mut self, value: u8) -> Self {
+        self.ar_coeff_lag = value;
+        self
+    }
+    #[inline]
+    pub fn ar_coeff_shift_minus_6(mut self, value: u8) -> Self {
+        self.ar_coeff_shift_minus_6 = value;
+        self
+    }
+    #[inline]
+    pub fn grain_scale_shift(mut self, value: u8) -> Self {
+        self.grain_scale_shift = value;
+        self
+    }
+    #[inline]
+    pub fn grain_seed(mut self, value: u16) -> Self {
+        self.grain_seed = value;
+        self
+    }
+    #[inline]
+    pub fn film_grain_params_ref_idx(mut self, value: u8) -> Self {
+        self.film_grain_params_ref_idx = value;
+        self
+    }
+    #[inline]
+    pub fn num_y_points(mut self, value: u8) -> Self {
+        self.num_y_points = value;
+        self
+    }
+    #[inline]
+    pub fn point_y_value(mut self, value: [u8; STD_VIDEO_AV1_MAX_NUM_Y_POINTS as _]) -> Self {
+        self.point_y_value = value;
+        self
+    }
+    #[inline]
+    pub fn point_y_scaling(mut self, value: [u8; STD_VIDEO_AV1_MAX_NUM_Y_POINTS as _]) -> Self {
+        self.point_y_scaling = value;
+        self
+    }
+    #[inline]
+    pub fn num_cb_points(mut self, value: u8) -> Self {
+        self.num_cb_points = value;
+        self
+    }
+    #[inline]
+    pub fn point_cb_value(mut self, value: [u8; STD_VIDEO_AV1_MAX_NUM_CB_POINTS as _]) -> Self {
+        self.point_cb_value = value;
+        self
+    }
+    #[inline]
+    pub fn point_cb_scaling(mut self, value: [u8; STD_VIDEO_AV1_MAX_NUM_CB_POINTS as _]) -> Self {
+        self.point_cb_scaling = value;
+        self
+    }
+    #[inline]
+    pub fn num_cr_points(mut self, value: u8) -> Self {
+        self.num_cr_points = value;
+        self
+    }
+    #[inline]
+    pub fn point_cr_value(mut self, value: [u8; STD_VIDEO_AV1_MAX_NUM_CR_POINTS as _]) -> Self {
+        self.point_cr_value = value;
+        self
+    }
+    #[inline]
+    pub fn point_cr_scaling(mut self, value: [u8; STD_VIDEO_AV1_MAX_NUM_CR_POINTS as _]) -> Self {
+        self.point_cr_scaling = value;
+        self
+    }
+    #[inline]
+    pub fn ar_coeffs_y_plus_128(
+        mut self,
+        value: [i8; STD_VIDEO_AV1_MAX_NUM_POS_LUMA as _],
+    ) -> Self {
+        self.ar_coeffs_y_plus_128 = value;
+        self
+    }
+    #[inline]
+    pub fn ar_coeffs_cb_plus_128(
+        mut self,
+        value: [i8; STD_VIDEO_AV1_MAX_NUM_POS_CHROMA as _],
+    ) -> Self {
+        self.ar_coeffs_cb_plus_128 = value;
+        self
+    }
+    #[inline]
+    pub fn ar_coeffs_cr_plus_128(
+        mut self,
+        value: [i8; STD_VIDEO_AV1_MAX_NUM_POS_CHROMA as _],
+    ) -> Self {
+        self.ar_coeffs_cr_plus_128 = value;
+        self
+    }
+    #[inline]
+    pub fn cb_mult(mut self, value: u8) -> Self {
+        self.cb_mult = value;
+        self
+    }
+    #[inline]
+    pub fn cb_luma_mult(mut self, value: u8) -> Self {
+        self.cb_luma_mult = value;
+        self
+    }
+    #[inline]
+    pub fn cb_offset(mut self, value: u16) -> Self {
+        self.cb_offset = value;
+        self
+    }
+    #[inline]
+    pub fn cr_mult(mut self, value: u8) -> Self {
+        self.cr_mult = value;
+        self
+    }
+    #[inline]
+    pub fn cr_luma_mult(mut self, value: u8) -> Self {
+        self.cr_luma_mult = value;
+        self
+    }
+    #[inline]
+    pub fn cr_offset(mut self, value: u16) -> Self {
+        self.cr_offset = value;
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoAV1SequenceHeaderFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoAV1SequenceHeaderFlags {}
+unsafe impl Sync for StdVideoAV1SequenceHeaderFlags {}
+impl StdVideoAV1SequenceHeaderFlags {
+    #[inline]
+    pub fn still_picture(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_still_picture(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn reduced_still_picture_header(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_reduced_still_picture_header(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn use_128x128_superblock(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_use_128x128_superblock(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn enable_filter_intra(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_enable_filter_intra(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn enable_intra_edge_filter(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_enable_intra_edge_filter(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn enable_interintra_compound(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_enable_interintra_compound(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn enable_masked_compound(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_enable_masked_compound(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn enable_warped_motion(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_enable_warped_motion(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn enable_dual_filter(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_enable_dual_filter(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn enable_order_hint(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_enable_order_hint(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn enable_jnt_comp(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_enable_jnt_comp(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn enable_ref_frame_mvs(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_enable_ref_frame_mvs(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+    #[inline]
+    pub fn frame_id_numbers_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 12)) | ((value as u32) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_frame_id_numbers_present_flag(&self) -> bool {
+        self.bitfields & (1 << 12) != 0
+    }
+    #[inline]
+    pub fn enable_superres(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 13)) | ((value as u32) << 13);
+        self
+    }
+    #[inline]
+    pub fn get_enable_superres(&self) -> bool {
+        self.bitfields & (1 << 13) != 0
+    }
+    #[inline]
+    pub fn enable_cdef(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 14)) | ((value as u32) << 14);
+        self
+    }
+    #[inline]
+    pub fn get_enable_cdef(&self) -> bool {
+        self.bitfields & (1 << 14) != 0
+    }
+    #[inline]
+    pub fn enable_restoration(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 15)) | ((value as u32) << 15);
+        self
+    }
+    #[inline]
+    pub fn get_enable_restoration(&self) -> bool {
+        self.bitfields & (1 << 15) != 0
+    }
+    #[inline]
+    pub fn film_grain_params_present(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 16)) | ((value as u32) << 16);
+        self
+    }
+    #[inline]
+    pub fn get_film_grain_params_present(&self) -> bool {
+        self.bitfields & (1 << 16) != 0
+    }
+    #[inline]
+    pub fn timing_info_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 17)) | ((value as u32) << 17);
+        self
+    }
+    #[inline]
+    pub fn get_timing_info_present_flag(&self) -> bool {
+        self.bitfields & (1 << 17) != 0
+    }
+    #[inline]
+    pub fn initial_display_delay_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 18)) | ((value as u32) << 18);
+        self
+    }
+    #[inline]
+    pub fn get_initial_display_delay_present_flag(&self) -> bool {
+        self.bitfields & (1 << 18) != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoAV1SequenceHeader<'a> {
+    pub flags: StdVideoAV1SequenceHeaderFlags,
+    pub seq_profile: StdVideoAV1Profile,
+    pub frame_width_bits_minus_1: u8,
+    pub frame_height_bits_minus_1: u8,
+    pub max_frame_width_minus_1: u16,
+    pub max_frame_height_minus_1: u16,
+    pub delta_frame_id_length_minus_2: u8,
+    pub additional_frame_id_length_minus_1: u8,
+    pub order_hint_bits_minus_1: u8,
+    pub seq_force_integer_mv: u8,
+    pub seq_force_screen_content_tools: u8,
+    pub reserved1: [u8; 5u16 as _],
+    pub p_color_config: *const StdVideoAV1ColorConfig,
+    pub p_timing_info: *const StdVideoAV1TimingInfo,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoAV1SequenceHeader<'a> {}
+unsafe impl<'a> Sync for StdVideoAV1SequenceHeader<'a> {}
+impl<'a> Default for StdVideoAV1SequenceHeader<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            seq_profile: StdVideoAV1Profile::Main,
+            frame_width_bits_minus_1: Default::default(),
+            frame_height_bits_minus_1: Default::default(),
+            max_frame_width_minus_1: Default::default(),
+            max_frame_height_minus_1: Default::default(),
+            delta_frame_id_length_minus_2: Default::default(),
+            additional_frame_id_length_minus_1: Default::default(),
+            order_hint_bits_minus_1: Default::default(),
+            seq_force_integer_mv: Default::default(),
+            seq_force_screen_content_tools: Default::default(),
+            reserved1: array::from_fn(|_| Default::default()),
+            p_color_config: ptr::null(),
+            p_timing_info: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoAV1SequenceHeader<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoAV1SequenceHeaderFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn seq_profile(mut self, value: StdVideoAV1Profile) -> Self {
+        self.seq_profile = value;
+        self
+    }
+    #[inline]
+    pub fn frame_width_bits_minus_1(mut self, value: u8) -> Self {
+        self.frame_width_bits_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn frame_height_bits_minus_1(mut self, value: u8) -> Self {
+        self.frame_height_bits_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn max_frame_width_minus_1(mut self, value: u16) -> Self {
+        self.max_frame_width_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn max_frame_height_minus_1(mut self, value: u16) -> Self {
+        self.max_frame_height_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn delta_frame_id_length_minus_2(mut self, value: u8) -> Self {
+        self.delta_frame_id_length_minus_2 = value;
+        self
+    }
+    #[inline]
+    pub fn additional_frame_id_length_minus_1(mut self, value: u8) -> Self {
+        self.additional_frame_id_length_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn order_hint_bits_minus_1(mut self, value: u8) -> Self {
+        self.order_hint_bits_minus_1 = value;
+        self
+    }
+    #[inline]
+    pub fn seq_force_integer_mv(mut self, value: u8) -> Self {
+        self.seq_force_integer_mv = value;
+        self
+    }
+    #[inline]
+    pub fn seq_force_screen_content_tools(mut self, value: u8) -> Self {
+        self.seq_force_screen_content_tools = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: [u8; 5u16 as _]) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn color_config(mut self, value: &'a StdVideoAV1ColorConfig) -> Self {
+        self.p_color_config = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn timing_info(mut self, value: &'a StdVideoAV1TimingInfo) -> Self {
+        self.p_timing_info = ptr::from_ref(value);
+        self
+    }
+}
+pub const STD_VULKAN_VIDEO_CODEC_AV1_DECODE_SPEC_VERSION: u32 = make_video_std_version(1, 0, 0);
+pub const STD_VULKAN_VIDEO_CODEC_AV1_DECODE_EXTENSION_NAME: &CStr =
+    c"VK_STD_vulkan_video_codec_av1_decode";
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeAV1PictureInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoDecodeAV1PictureInfoFlags {}
+unsafe impl Sync for StdVideoDecodeAV1PictureInfoFlags {}
+impl StdVideoDecodeAV1PictureInfoFlags {
+    #[inline]
+    pub fn error_resilient_mode(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_error_resilient_mode(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn disable_cdf_update(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_disable_cdf_update(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn use_superres(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 2)) | ((value as u32) << 2);
+        self
+    }
+    #[inline]
+    pub fn get_use_superres(&self) -> bool {
+        self.bitfields & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn render_and_frame_size_different(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 3)) | ((value as u32) << 3);
+        self
+    }
+    #[inline]
+    pub fn get_render_and_frame_size_different(&self) -> bool {
+        self.bitfields & (1 << 3) != 0
+    }
+    #[inline]
+    pub fn allow_screen_content_tools(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 4)) | ((value as u32) << 4);
+        self
+    }
+    #[inline]
+    pub fn get_allow_screen_content_tools(&self) -> bool {
+        self.bitfields & (1 << 4) != 0
+    }
+    #[inline]
+    pub fn is_filter_switchable(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 5)) | ((value as u32) << 5);
+        self
+    }
+    #[inline]
+    pub fn get_is_filter_switchable(&self) -> bool {
+        self.bitfields & (1 << 5) != 0
+    }
+    #[inline]
+    pub fn force_integer_mv(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 6)) | ((value as u32) << 6);
+        self
+    }
+    #[inline]
+    pub fn get_force_integer_mv(&self) -> bool {
+        self.bitfields & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn frame_size_override_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 7)) | ((value as u32) << 7);
+        self
+    }
+    #[inline]
+    pub fn get_frame_size_override_flag(&self) -> bool {
+        self.bitfields & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn buffer_removal_time_present_flag(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 8)) | ((value as u32) << 8);
+        self
+    }
+    #[inline]
+    pub fn get_buffer_removal_time_present_flag(&self) -> bool {
+        self.bitfields & (1 << 8) != 0
+    }
+    #[inline]
+    pub fn allow_intrabc(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 9)) | ((value as u32) << 9);
+        self
+    }
+    #[inline]
+    pub fn get_allow_intrabc(&self) -> bool {
+        self.bitfields & (1 << 9) != 0
+    }
+    #[inline]
+    pub fn frame_refs_short_signaling(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 10)) | ((value as u32) << 10);
+        self
+    }
+    #[inline]
+    pub fn get_frame_refs_short_signaling(&self) -> bool {
+        self.bitfields & (1 << 10) != 0
+    }
+    #[inline]
+    pub fn allow_high_precision_mv(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 11)) | ((value as u32) << 11);
+        self
+    }
+    #[inline]
+    pub fn get_allow_high_precision_mv(&self) -> bool {
+        self.bitfields & (1 << 11) != 0
+    }
+    #[inline]
+    pub fn is_motion_mode_switchable(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 12)) | ((value as u32) << 12);
+        self
+    }
+    #[inline]
+    pub fn get_is_motion_mode_switchable(&self) -> bool {
+        self.bitfields & (1 << 12) != 0
+    }
+    #[inline]
+    pub fn use_ref_frame_mvs(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 13)) | ((value as u32) << 13);
+        self
+    }
+    #[inline]
+    pub fn get_use_ref_frame_mvs(&self) -> bool {
+        self.bitfields & (1 << 13) != 0
+    }
+    #[inline]
+    pub fn disable_frame_end_update_cdf(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 14)) | ((value as u32) << 14);
+        self
+    }
+    #[inline]
+    pub fn get_disable_frame_end_update_cdf(&self) -> bool {
+        self.bitfields & (1 << 14) != 0
+    }
+    #[inline]
+    pub fn allow_warped_motion(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 15)) | ((value as u32) << 15);
+        self
+    }
+    #[inline]
+    pub fn get_allow_warped_motion(&self) -> bool {
+        self.bitfields & (1 << 15) != 0
+    }
+    #[inline]
+    pub fn reduced_tx_set(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 16)) | ((value as u32) << 16);
+        self
+    }
+    #[inline]
+    pub fn get_reduced_tx_set(&self) -> bool {
+        self.bitfields & (1 << 16) != 0
+    }
+    #[inline]
+    pub fn reference_select(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 17)) | ((value as u32) << 17);
+        self
+    }
+    #[inline]
+    pub fn get_reference_select(&self) -> bool {
+        self.bitfields & (1 << 17) != 0
+    }
+    #[inline]
+    pub fn skip_mode_present(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 18)) | ((value as u32) << 18);
+        self
+    }
+    #[inline]
+    pub fn get_skip_mode_present(&self) -> bool {
+        self.bitfields & (1 << 18) != 0
+    }
+    #[inline]
+    pub fn delta_q_present(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 19)) | ((value as u32) << 19);
+        self
+    }
+    #[inline]
+    pub fn get_delta_q_present(&self) -> bool {
+        self.bitfields & (1 << 19) != 0
+    }
+    #[inline]
+    pub fn delta_lf_present(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 20)) | ((value as u32) << 20);
+        self
+    }
+    #[inline]
+    pub fn get_delta_lf_present(&self) -> bool {
+        self.bitfields & (1 << 20) != 0
+    }
+    #[inline]
+    pub fn delta_lf_multi(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 21)) | ((value as u32) << 21);
+        self
+    }
+    #[inline]
+    pub fn get_delta_lf_multi(&self) -> bool {
+        self.bitfields & (1 << 21) != 0
+    }
+    #[inline]
+    pub fn segmentation_enabled(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 22)) | ((value as u32) << 22);
+        self
+    }
+    #[inline]
+    pub fn get_segmentation_enabled(&self) -> bool {
+        self.bitfields & (1 << 22) != 0
+    }
+    #[inline]
+    pub fn segmentation_update_map(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 23)) | ((value as u32) << 23);
+        self
+    }
+    #[inline]
+    pub fn get_segmentation_update_map(&self) -> bool {
+        self.bitfields & (1 << 23) != 0
+    }
+    #[inline]
+    pub fn segmentation_temporal_update(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 24)) | ((value as u32) << 24);
+        self
+    }
+    #[inline]
+    pub fn get_segmentation_temporal_update(&self) -> bool {
+        self.bitfields & (1 << 24) != 0
+    }
+    #[inline]
+    pub fn segmentation_update_data(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 25)) | ((value as u32) << 25);
+        self
+    }
+    #[inline]
+    pub fn get_segmentation_update_data(&self) -> bool {
+        self.bitfields & (1 << 25) != 0
+    }
+    #[inline]
+    pub fn uses_lr(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 26)) | ((value as u32) << 26);
+        self
+    }
+    #[inline]
+    pub fn get_uses_lr(&self) -> bool {
+        self.bitfields & (1 << 26) != 0
+    }
+    #[inline]
+    pub fn uses_chroma_lr(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 27)) | ((value as u32) << 27);
+        self
+    }
+    #[inline]
+    pub fn get_uses_chroma_lr(&self) -> bool {
+        self.bitfields & (1 << 27) != 0
+    }
+    #[inline]
+    pub fn apply_grain(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 28)) | ((value as u32) << 28);
+        self
+    }
+    #[inline]
+    pub fn get_apply_grain(&self) -> bool {
+        self.bitfields & (1 << 28) != 0
+    }
+}
+#[repr(C)]
+pub struct StdVideoDecodeAV1PictureInfo<'a> {
+    pub flags: StdVideoDecodeAV1PictureInfoFlags,
+    pub frame_type: StdVideoAV1FrameType,
+    pub current_frame_id: u32,
+    pub order_hint: u8,
+    pub primary_ref_frame: u8,
+    pub refresh_frame_flags: u8,
+    pub reserved1: u8,
+    pub interpolation_filter: StdVideoAV1InterpolationFilter,
+    pub tx_mode: StdVideoAV1TxMode,
+    pub delta_q_res: u8,
+    pub delta_lf_res: u8,
+    pub skip_mode_frame: [u8; STD_VIDEO_AV1_SKIP_MODE_FRAMES as _],
+    pub coded_denom: u8,
+    pub reserved2: [u8; 3u16 as _],
+    pub order_hints: [u8; STD_VIDEO_AV1_NUM_REF_FRAMES as _],
+    pub expected_frame_id: [u32; STD_VIDEO_AV1_NUM_REF_FRAMES as _],
+    pub p_tile_info: *const StdVideoAV1TileInfo<'a>,
+    pub p_quantization: *const StdVideoAV1Quantization,
+    pub p_segmentation: *const StdVideoAV1Segmentation,
+    pub p_loop_filter: *const StdVideoAV1LoopFilter,
+    pub p_cdef: *const StdVideoAV1CDEF,
+    pub p_loop_restoration: *const StdVideoAV1LoopRestoration,
+    pub p_global_motion: *const StdVideoAV1GlobalMotion,
+    pub p_film_grain: *const StdVideoAV1FilmGrain,
+    phantom: PhantomData<&'a ()>,
+}
+unsafe impl<'a> Send for StdVideoDecodeAV1PictureInfo<'a> {}
+unsafe impl<'a> Sync for StdVideoDecodeAV1PictureInfo<'a> {}
+impl<'a> Default for StdVideoDecodeAV1PictureInfo<'a> {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            frame_type: StdVideoAV1FrameType::Key,
+            current_frame_id: Default::default(),
+            order_hint: Default::default(),
+            primary_ref_frame: Default::default(),
+            refresh_frame_flags: Default::default(),
+            reserved1: Default::default(),
+            interpolation_filter: StdVideoAV1InterpolationFilter::Eighttap,
+            tx_mode: StdVideoAV1TxMode::Only4X4,
+            delta_q_res: Default::default(),
+            delta_lf_res: Default::default(),
+            skip_mode_frame: array::from_fn(|_| Default::default()),
+            coded_denom: Default::default(),
+            reserved2: array::from_fn(|_| Default::default()),
+            order_hints: array::from_fn(|_| Default::default()),
+            expected_frame_id: array::from_fn(|_| Default::default()),
+            p_tile_info: ptr::null(),
+            p_quantization: ptr::null(),
+            p_segmentation: ptr::null(),
+            p_loop_filter: ptr::null(),
+            p_cdef: ptr::null(),
+            p_loop_restoration: ptr::null(),
+            p_global_motion: ptr::null(),
+            p_film_grain: ptr::null(),
+            phantom: PhantomData,
+        }
+    }
+}
+impl<'a> StdVideoDecodeAV1PictureInfo<'a> {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoDecodeAV1PictureInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn frame_type(mut self, value: StdVideoAV1FrameType) -> Self {
+        self.frame_type = value;
+        self
+    }
+    #[inline]
+    pub fn current_frame_id(mut self, value: u32) -> Self {
+        self.current_frame_id = value;
+        self
+    }
+    #[inline]
+    pub fn order_hint(mut self, value: u8) -> Self {
+        self.order_hint = value;
+        self
+    }
+    #[inline]
+    pub fn primary_ref_frame(mut self, value: u8) -> Self {
+        self.primary_ref_frame = value;
+        self
+    }
+    #[inline]
+    pub fn refresh_frame_flags(mut self, value: u8) -> Self {
+        self.refresh_frame_flags = value;
+        self
+    }
+    #[inline]
+    pub fn reserved1(mut self, value: u8) -> Self {
+        self.reserved1 = value;
+        self
+    }
+    #[inline]
+    pub fn interpolation_filter(mut self, value: StdVideoAV1InterpolationFilter) -> Self {
+        self.interpolation_filter = value;
+        self
+    }
+    #[inline]
+    pub fn tx_mode(mut self, value: StdVideoAV1TxMode) -> Self {
+        self.tx_mode = value;
+        self
+    }
+    #[inline]
+    pub fn delta_q_res(mut self, value: u8) -> Self {
+        self.delta_q_res = value;
+        self
+    }
+    #[inline]
+    pub fn delta_lf_res(mut self, value: u8) -> Self {
+        self.delta_lf_res = value;
+        self
+    }
+    #[inline]
+    pub fn skip_mode_frame(mut self, value: [u8; STD_VIDEO_AV1_SKIP_MODE_FRAMES as _]) -> Self {
+        self.skip_mode_frame = value;
+        self
+    }
+    #[inline]
+    pub fn coded_denom(mut self, value: u8) -> Self {
+        self.coded_denom = value;
+        self
+    }
+    #[inline]
+    pub fn reserved2(mut self, value: [u8; 3u16 as _]) -> Self {
+        self.reserved2 = value;
+        self
+    }
+    #[inline]
+    pub fn order_hints(mut self, value: [u8; STD_VIDEO_AV1_NUM_REF_FRAMES as _]) -> Self {
+        self.order_hints = value;
+        self
+    }
+    #[inline]
+    pub fn expected_frame_id(mut self, value: [u32; STD_VIDEO_AV1_NUM_REF_FRAMES as _]) -> Self {
+        self.expected_frame_id = value;
+        self
+    }
+    #[inline]
+    pub fn tile_info(mut self, value: &'a StdVideoAV1TileInfo<'a>) -> Self {
+        self.p_tile_info = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn quantization(mut self, value: &'a StdVideoAV1Quantization) -> Self {
+        self.p_quantization = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn segmentation(mut self, value: &'a StdVideoAV1Segmentation) -> Self {
+        self.p_segmentation = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn loop_filter(mut self, value: &'a StdVideoAV1LoopFilter) -> Self {
+        self.p_loop_filter = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn cdef(mut self, value: &'a StdVideoAV1CDEF) -> Self {
+        self.p_cdef = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn loop_restoration(mut self, value: &'a StdVideoAV1LoopRestoration) -> Self {
+        self.p_loop_restoration = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn global_motion(mut self, value: &'a StdVideoAV1GlobalMotion) -> Self {
+        self.p_global_motion = ptr::from_ref(value);
+        self
+    }
+    #[inline]
+    pub fn film_grain(mut self, value: &'a StdVideoAV1FilmGrain) -> Self {
+        self.p_film_grain = ptr::from_ref(value);
+        self
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct StdVideoDecodeAV1ReferenceInfoFlags {
+    pub(crate) bitfields: u32,
+}
+unsafe impl Send for StdVideoDecodeAV1ReferenceInfoFlags {}
+unsafe impl Sync for StdVideoDecodeAV1ReferenceInfoFlags {}
+impl StdVideoDecodeAV1ReferenceInfoFlags {
+    #[inline]
+    pub fn disable_frame_end_update_cdf(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !1) | (value as u32);
+        self
+    }
+    #[inline]
+    pub fn get_disable_frame_end_update_cdf(&self) -> bool {
+        self.bitfields & 1 != 0
+    }
+    #[inline]
+    pub fn segmentation_enabled(mut self, value: bool) -> Self {
+        self.bitfields = (self.bitfields & !(1 << 1)) | ((value as u32) << 1);
+        self
+    }
+    #[inline]
+    pub fn get_segmentation_enabled(&self) -> bool {
+        self.bitfields & (1 << 1) != 0
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StdVideoDecodeAV1ReferenceInfo {
+    pub flags: StdVideoDecodeAV1ReferenceInfoFlags,
+    pub frame_type: u8,
+    pub ref_frame_sign_bias: u8,
+    pub order_hint: u8,
+    pub saved_order_hints: [u8; STD_VIDEO_AV1_NUM_REF_FRAMES as _],
+}
+unsafe impl Send for StdVideoDecodeAV1ReferenceInfo {}
+unsafe impl Sync for StdVideoDecodeAV1ReferenceInfo {}
+impl Default for StdVideoDecodeAV1ReferenceInfo {
+    fn default() -> Self {
+        Self {
+            flags: Default::default(),
+            frame_type: Default::default(),
+            ref_frame_sign_bias: Default::default(),
+            order_hint: Default::default(),
+            saved_order_hints: array::from_fn(|_| Default::default()),
+        }
+    }
+}
+impl StdVideoDecodeAV1ReferenceInfo {
+    #[inline]
+    pub fn flags(mut self, value: StdVideoDecodeAV1ReferenceInfoFlags) -> Self {
+        self.flags = value;
+        self
+    }
+    #[inline]
+    pub fn frame_type(mut self, value: u8) -> Self {
+        self.frame_type = value;
+        self
+    }
+    #[inline]
+    pub fn ref_frame_sign_bias(mut self, value: u8) -> Self {
+        self.ref_frame_sign_bias = value;
+        self
+    }
+    #[inline]
+    pub fn order_hint(mut self, value: u8) -> Self {
+        self.order_hint = value;
+        self
+    }
+    #[inline]
+    pub fn saved_order_hints(mut self, value: [u8; STD_VIDEO_AV1_NUM_REF_FRAMES as _]) -> Self {
+        self.saved_order_hints = value;
+        self
+    }
+}
@@ -37,8 +37,6 @@ pub fn enumerate_required_extensions(
 }
 
 pub mod raw {
-    use std::os::raw::c_void;
-
     use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
 
     use crate::vk;
@@ -54,7 +52,10 @@ pub mod raw {
         window_handle: &RawWindowHandle,
     ) -> vk::Result<vk::raw::SurfaceKHR> {
         match (display_handle, window_handle) {
+            #[cfg(any(feature = "platform-win32", target_os = "windows"))]
             (RawDisplayHandle::Windows(_), RawWindowHandle::Win32(window)) => {
+                use std::os::raw::c_void;
+
                 let surface_desc = vk::Win32SurfaceCreateInfoKHR::default()
                     .hwnd(window.hwnd.get() as *const c_void)
                     .hinstance(
@@ -66,6 +67,14 @@ pub mod raw {
                 vk::raw::create_win32_surface_khr(instance, &surface_desc, allocator, dispatcher)
             }
 
+            #[cfg(any(
+                feature = "platform-wayland",
+                target_os = "linux",
+                target_os = "freebsd",
+                target_os = "dragonfly",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
             (RawDisplayHandle::Wayland(display), RawWindowHandle::Wayland(window)) => {
                 let surface_desc = vk::WaylandSurfaceCreateInfoKHR::default()
                     .display(unsafe { Some(display.display.cast().as_ref()) })
@@ -73,6 +82,14 @@ pub mod raw {
                 vk::raw::create_wayland_surface_khr(instance, &surface_desc, allocator, dispatcher)
             }
 
+            #[cfg(any(
+                feature = "platform-xlib",
+                target_os = "linux",
+                target_os = "freebsd",
+                target_os = "dragonfly",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
             (RawDisplayHandle::Xlib(display), RawWindowHandle::Xlib(window)) => {
                 let surface_desc = vk::XlibSurfaceCreateInfoKHR::default()
                     .dpy(unsafe {
@@ -88,6 +105,14 @@ pub mod raw {
                 vk::raw::create_xlib_surface_khr(instance, &surface_desc, allocator, dispatcher)
             }
 
+            #[cfg(any(
+                feature = "platform-xcb",
+                target_os = "linux",
+                target_os = "freebsd",
+                target_os = "dragonfly",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
             (RawDisplayHandle::Xcb(display), RawWindowHandle::Xcb(window)) => {
                 let surface_desc = vk::XcbSurfaceCreateInfoKHR::default()
                     .connection(unsafe {
@@ -103,6 +128,7 @@ pub mod raw {
                 vk::raw::create_xcb_surface_khr(instance, &surface_desc, allocator, dispatcher)
             }
 
+            #[cfg(any(feature = "platform-android", target_os = "android"))]
             (RawDisplayHandle::Android(_), RawWindowHandle::AndroidNdk(window)) => {
                 let surface_desc = vk::AndroidSurfaceCreateInfoKHR::default()
                     .window(unsafe { Some(window.a_native_window.cast().as_ref()) });